@@ -1253,15 +1253,14 @@ fn enforce_no_unsafe_string_ops() {
         eprintln!("String byte operations can break on multi-byte UTF-8 characters.");
         eprintln!("Characters like emoji or non-ASCII take multiple bytes.");
         eprintln!();
-        eprintln!("FOR DISPLAY TRUNCATION use character-based operations:");
+        eprintln!("FOR DISPLAY TRUNCATION use the width-aware helpers in tui/ui/util.rs:");
         eprintln!();
         eprintln!("    // WRONG");
         eprintln!("    if name.len() > 15 {{ name.get(..12) }}");
         eprintln!();
         eprintln!("    // CORRECT");
-        eprintln!("    if name.chars().count() > 15 {{");
-        eprintln!("        name.chars().take(12).collect::<String>()");
-        eprintln!("    }}");
+        eprintln!("    use crate::tui::ui::util::truncate_to_width;");
+        eprintln!("    let label = truncate_to_width(name, 15);");
         eprintln!();
         eprintln!("FOR CURSOR-BASED SLICING use tui/cursor_utils.rs helpers:");
         eprintln!();
@@ -178,7 +178,7 @@ pub fn credential_file_paths() -> Vec<PathBuf> {
 }
 
 /// Reads all credentials and converts to CredentialInfo for RPC reporting.
-/// This extracts email from tokens where possible and includes tokens for API calls.
+/// This extracts email from tokens where possible; the tokens themselves stay local.
 pub fn read_all_credential_info() -> Vec<crate::rpc::host_service::CredentialInfo> {
     use crate::rpc::host_service::CredentialInfo;
 
@@ -209,7 +209,6 @@ pub fn read_all_credential_info() -> Vec<crate::rpc::host_service::CredentialInf
             email,
             token_valid,
             expires_at,
-            access_token,
             account_id: None,
         });
     }
@@ -238,7 +237,6 @@ pub fn read_all_credential_info() -> Vec<crate::rpc::host_service::CredentialInf
             email,
             token_valid,
             expires_at,
-            access_token,
             account_id: None,
         });
     }
@@ -259,7 +257,6 @@ pub fn read_all_credential_info() -> Vec<crate::rpc::host_service::CredentialInf
             email,
             token_valid,
             expires_at,
-            access_token,
             account_id: Some(account_id),
         });
     }
@@ -20,29 +20,43 @@ pub fn fetch_usage_with_credentials(
     container_id: Option<&str>,
 ) {
     for (provider, creds) in credentials {
-        let result = fetch_usage_for_provider(&provider, &creds);
-        if let Some(usage) = result.usage {
-            store.update_account(usage, container_id);
-        } else if let Some(error) = result.error {
-            // Create an error record
-            let email = extract_email_from_creds(&creds).unwrap_or_else(|| "unknown".to_string());
-            let usage = AccountUsageState {
-                account_id: AccountId::new(&provider, &email),
-                provider: provider.clone(),
-                email,
-                plan_type: None,
-                rate_limit_tier: None,
-                session_window: UsageWindow::default(),
-                weekly_window: UsageWindow::default(),
-                fetched_at: chrono::Utc::now().to_rfc3339(),
-                error: Some(error.clone()),
-                token_valid: result.token_valid,
-            };
+        if let Some(usage) = fetch_account_usage(&provider, &creds) {
             store.update_account(usage, container_id);
         }
     }
 }
 
+/// Fetches usage for the local credentials without storing it.
+/// Daemons report this to the host instead of their tokens.
+pub fn fetch_local_usage() -> Vec<AccountUsageState> {
+    read_all_credentials()
+        .into_iter()
+        .filter_map(|(provider, creds)| fetch_account_usage(&provider, &creds))
+        .collect()
+}
+
+/// Usage of one account, or an error record when the fetch failed.
+fn fetch_account_usage(provider: &str, creds: &ProviderCredentials) -> Option<AccountUsageState> {
+    let result = fetch_usage_for_provider(provider, creds);
+    if let Some(usage) = result.usage {
+        return Some(usage);
+    }
+    let error = result.error?;
+    let email = extract_email_from_creds(creds).unwrap_or_else(|| "unknown".to_string());
+    Some(AccountUsageState {
+        account_id: AccountId::new(provider, &email),
+        provider: provider.to_string(),
+        email,
+        plan_type: None,
+        rate_limit_tier: None,
+        session_window: UsageWindow::default(),
+        weekly_window: UsageWindow::default(),
+        fetched_at: chrono::Utc::now().to_rfc3339(),
+        error: Some(error),
+        token_valid: result.token_valid,
+    })
+}
+
 /// Extracts email from credentials (used for error reporting).
 fn extract_email_from_creds(creds: &ProviderCredentials) -> Option<String> {
     match creds {
@@ -0,0 +1,230 @@
+//! Agent CLI capability detection.
+//!
+//! At session start every configured local agent CLI is probed once per process:
+//! `<command> --version` for its version and `<command> [subcommands] --help` for
+//! the features the workflow relies on. The profile is kept for the rest of the
+//! process. An agent whose CLI lists no conversation resume runs stateless instead
+//! of failing on `--resume`, and configured flags the CLI does not list are
+//! reported before the first phase rather than when the invocation fails.
+//!
+//! Remote agents are not probed; a CLI that cannot be probed is assumed to support
+//! everything, as before.
+
+use crate::config::AgentConfig;
+use std::collections::{BTreeMap, HashMap};
+use std::process::Stdio;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Time a `--version` or `--help` call may take.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What an agent CLI was found to support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityProfile {
+    /// The CLI that was probed
+    pub command: String,
+    /// First line of `--version`, if it answered
+    pub version: Option<String>,
+    /// Lists MCP server support
+    pub mcp: bool,
+    /// Lists conversation resume
+    pub resume: bool,
+    /// Lists JSON (or stream-JSON) output
+    pub json_output: bool,
+    /// Configured `--flags` the help output does not mention
+    pub unsupported_flags: Vec<String>,
+    /// Why the CLI could not be probed
+    pub probe_error: Option<String>,
+}
+
+impl CapabilityProfile {
+    /// Profile from the CLI's help output, checking the configured `args` against it.
+    pub fn from_help(command: &str, version: Option<String>, help: &str, args: &[String]) -> Self {
+        let lower = help.to_lowercase();
+        let unsupported_flags = args
+            .iter()
+            .filter_map(|arg| arg.strip_prefix("--"))
+            .map(|flag| format!("--{}", flag.split('=').next().unwrap_or(flag)))
+            .filter(|flag| !help.contains(flag.as_str()))
+            .collect();
+        Self {
+            command: command.to_string(),
+            version,
+            mcp: lower.contains("mcp"),
+            resume: lower.contains("resume"),
+            json_output: lower.contains("--output-format") || lower.contains("--json"),
+            unsupported_flags,
+            probe_error: None,
+        }
+    }
+
+    /// Profile of a CLI that could not be probed; every feature is assumed present.
+    pub fn unprobed(command: &str, error: String) -> Self {
+        Self {
+            command: command.to_string(),
+            version: None,
+            mcp: true,
+            resume: true,
+            json_output: true,
+            unsupported_flags: Vec::new(),
+            probe_error: Some(error),
+        }
+    }
+
+    /// Problems worth telling the user about before the workflow starts.
+    pub fn warnings(&self, agent: &str) -> Vec<String> {
+        if let Some(error) = &self.probe_error {
+            return vec![format!(
+                "Agent '{}': could not probe `{}` ({})",
+                agent, self.command, error
+            )];
+        }
+        let mut warnings = Vec::new();
+        if !self.resume {
+            warnings.push(format!(
+                "Agent '{}': `{}` lists no conversation resume, running it stateless",
+                agent, self.command
+            ));
+        }
+        if !self.json_output {
+            warnings.push(format!(
+                "Agent '{}': `{}` lists no JSON output, its output may not parse",
+                agent, self.command
+            ));
+        }
+        if !self.unsupported_flags.is_empty() {
+            warnings.push(format!(
+                "Agent '{}': `{}` does not list {} in its help, the invocation may fail",
+                agent,
+                self.command,
+                self.unsupported_flags.join(", ")
+            ));
+        }
+        warnings
+    }
+
+    /// Short feature list for the stats panel, e.g. `resume json mcp`.
+    pub fn feature_tags(&self) -> String {
+        [
+            (self.resume, "resume"),
+            (self.json_output, "json"),
+            (self.mcp, "mcp"),
+        ]
+        .iter()
+        .filter(|(supported, _)| *supported)
+        .map(|(_, tag)| *tag)
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+/// Profiles by agent name, shared by all sessions of the process.
+fn registry() -> &'static Mutex<BTreeMap<String, CapabilityProfile>> {
+    static PROFILES: OnceLock<Mutex<BTreeMap<String, CapabilityProfile>>> = OnceLock::new();
+    PROFILES.get_or_init(|| Mutex::new(BTreeMap::new()))
+}
+
+/// The profiles detected so far, by agent name.
+pub fn profiles() -> BTreeMap<String, CapabilityProfile> {
+    registry()
+        .lock()
+        .map(|profiles| profiles.clone())
+        .unwrap_or_default()
+}
+
+/// Whether `agent` can resume a conversation. True when it was not probed.
+pub fn supports_resume(agent: &str) -> bool {
+    registry()
+        .lock()
+        .ok()
+        .and_then(|profiles| profiles.get(agent).map(|profile| profile.resume))
+        .unwrap_or(true)
+}
+
+/// Probes the local agents not probed yet and returns the warnings for all of them.
+pub async fn probe_agents(agents: &HashMap<String, AgentConfig>) -> Vec<String> {
+    let known = profiles();
+    let pending: Vec<(&String, &AgentConfig)> = agents
+        .iter()
+        .filter(|(_, config)| !super::remote::is_remote(config))
+        .filter(|(name, config)| {
+            known
+                .get(*name)
+                .is_none_or(|profile| profile.command != config.command)
+        })
+        .collect();
+    let probed = futures::future::join_all(
+        pending
+            .into_iter()
+            .map(|(name, config)| async move { (name.clone(), probe(config).await) }),
+    )
+    .await;
+    if let Ok(mut profiles) = registry().lock() {
+        profiles.extend(probed);
+    }
+
+    let profiles = profiles();
+    let mut names: Vec<&String> = agents.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .filter_map(|name| profiles.get(name).map(|profile| profile.warnings(name)))
+        .flatten()
+        .collect()
+}
+
+async fn probe(config: &AgentConfig) -> CapabilityProfile {
+    let program = match which::which(&config.command) {
+        Ok(program) => program,
+        Err(_) => return CapabilityProfile::unprobed(&config.command, "not on PATH".to_string()),
+    };
+    // Flags belong to the subcommand the args start with, e.g. `codex exec`
+    let subcommands: Vec<&str> = config
+        .args
+        .iter()
+        .take_while(|arg| !arg.starts_with('-'))
+        .map(String::as_str)
+        .collect();
+    let version = run(&program, &["--version"]).await.ok().and_then(|output| {
+        output
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string)
+    });
+    let help_args: Vec<&str> = subcommands.into_iter().chain(["--help"]).collect();
+    match run(&program, &help_args).await {
+        Ok(help) => CapabilityProfile::from_help(&config.command, version, &help, &config.args),
+        Err(e) => CapabilityProfile {
+            version,
+            ..CapabilityProfile::unprobed(&config.command, format!("{:#}", e))
+        },
+    }
+}
+
+/// Stdout and stderr of a probe call; help text goes to either depending on the CLI.
+async fn run(program: &std::path::Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = tokio::time::timeout(
+        PROBE_TIMEOUT,
+        tokio::process::Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("`{}` timed out", args.join(" ")))??;
+    if !output.status.success() {
+        anyhow::bail!("`{}` exited with {}", args.join(" "), output.status);
+    }
+    Ok(format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    ))
+}
+
+#[cfg(test)]
+#[path = "tests/capabilities_tests.rs"]
+mod tests;
@@ -0,0 +1,46 @@
+use super::*;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_from_help_detects_features_and_unlisted_flags() {
+    let help = "Usage: claude [options]\n\
+                --output-format <format>  Output format\n\
+                -r, --resume [value]      Resume a conversation\n\
+                --mcp-config <configs>    Load MCP servers\n";
+    let profile = CapabilityProfile::from_help(
+        "claude",
+        Some("2.0.1".to_string()),
+        help,
+        &args(&["-p", "--output-format=stream-json", "--verbose"]),
+    );
+
+    assert!(profile.resume && profile.json_output && profile.mcp);
+    assert_eq!(profile.unsupported_flags, vec!["--verbose".to_string()]);
+    assert_eq!(profile.feature_tags(), "resume json mcp");
+    assert_eq!(profile.warnings("claude").len(), 1);
+}
+
+#[test]
+fn test_missing_resume_is_reported() {
+    let profile = CapabilityProfile::from_help("codex", None, "Usage: codex exec --json", &[]);
+
+    assert!(!profile.resume);
+    assert_eq!(
+        profile.warnings("reviewer"),
+        vec!["Agent 'reviewer': `codex` lists no conversation resume, running it stateless"]
+    );
+}
+
+#[test]
+fn test_unprobed_cli_keeps_every_feature() {
+    let profile = CapabilityProfile::unprobed("gemini", "not on PATH".to_string());
+
+    assert!(profile.resume && profile.json_output && profile.mcp);
+    assert_eq!(
+        profile.warnings("gemini"),
+        vec!["Agent 'gemini': could not probe `gemini` (not on PATH)"]
+    );
+}
@@ -1,15 +1,23 @@
 use super::parser::ClaudeParser;
+use super::warm_pool::{self, WarmKey};
 use crate::agents::log::AgentLogger;
+use crate::agents::permissions::gate::PermissionGate;
+use crate::agents::pricing::model_hint;
+use crate::agents::process::AgentProcess;
 use crate::agents::prompt::PreparedPrompt;
+use crate::agents::protocol::AgentOutput;
+use crate::agents::remote::{is_remote, remote_command, translate_text};
 use crate::agents::runner::{
-    run_agent_process, ContextEmitter, EventEmitter, RunnerConfig, DEFAULT_ACTIVITY_TIMEOUT,
-    DEFAULT_OVERALL_TIMEOUT,
+    run_agent_process, run_agent_turn, ContextEmitter, EventEmitter, RunnerConfig,
+    DEFAULT_ACTIVITY_TIMEOUT, DEFAULT_OVERALL_TIMEOUT,
 };
 use crate::agents::{AgentContext, AgentResult};
 use crate::config::AgentConfig;
 use crate::domain::types::ResumeStrategy;
 use anyhow::Result;
 use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::Command;
 
@@ -33,11 +41,18 @@ impl ClaudeAgent {
         }
     }
 
-    #[cfg(test)]
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    pub fn config(&self) -> &AgentConfig {
+        &self.config
+    }
+
+    pub(crate) fn config_mut(&mut self) -> &mut AgentConfig {
+        &mut self.config
+    }
+
     /// Execute with a centrally-prepared prompt.
     /// The PreparedPrompt already has system_prompt and max_turns handled appropriately.
     pub async fn execute_streaming_with_prepared(
@@ -45,7 +60,7 @@ impl ClaudeAgent {
         prepared: PreparedPrompt,
         context: AgentContext,
     ) -> Result<AgentResult> {
-        let emitter = ContextEmitter::new(context.clone(), self.name.clone());
+        let emitter = ContextEmitter::new(context.clone(), self.name.clone(), "claude");
         self.execute_streaming_internal(prepared, &emitter, Some(&context))
             .await
     }
@@ -60,22 +75,140 @@ impl ClaudeAgent {
         self.log_start(&logger, &prepared, context.is_some());
         self.log_timeout(&logger);
 
-        let cmd = self.build_command(&prepared, context);
         let mut config = RunnerConfig::new(self.name.clone(), self.working_dir.clone())
             .with_activity_timeout(self.activity_timeout)
-            .with_overall_timeout(self.overall_timeout);
+            .with_overall_timeout(self.overall_timeout)
+            .with_memory_limit(self.config.memory_limit_mb);
         if let Some(ctx) = context {
             config = config.with_session_logger(ctx.session_logger.clone());
+            config = config.with_permission_prompts(self.config.permission_prompts);
+            config = config.with_pricing(ctx.pricing.clone(), model_hint(&self.config));
+            config = config.with_fault_injector(ctx.fault_injector.clone());
             if let Some(cancel_rx) = ctx.cancel_rx.clone() {
                 config = config.with_cancel_rx(cancel_rx);
             }
         }
         let mut parser = ClaudeParser::new();
 
+        if let Some(ctx) = context.filter(|_| self.runs_warm(context)) {
+            let output = self
+                .run_warm(&prepared, ctx, &config, &mut parser, emitter)
+                .await?;
+            return Ok(output.into());
+        }
+
+        let mut cmd = self.build_command(&prepared, context);
+        // Held until the process exits; dropping the gate removes its socket
+        let gate = self.bind_permission_gate(&logger, context);
+        if let Some((gate, settings)) = &gate {
+            cmd.arg("--settings").arg(settings);
+            config = config.with_permission_gate(gate.clone());
+        }
+        let cmd = remote_command(cmd, &self.config, &self.working_dir)?;
         let output = run_agent_process(cmd, &config, &mut parser, emitter).await?;
         Ok(output.into())
     }
 
+    /// Runs the prompt on a persistent CLI, reusing a parked one that holds the
+    /// resumed conversation, and parks the process again after a successful turn.
+    async fn run_warm(
+        &self,
+        prepared: &PreparedPrompt,
+        context: &AgentContext,
+        config: &RunnerConfig,
+        parser: &mut ClaudeParser,
+        emitter: &dyn EventEmitter,
+    ) -> Result<AgentOutput> {
+        let key = WarmKey::new(
+            context.session_logger.session_id(),
+            &self.name,
+            prepared.system_prompt_arg.as_deref(),
+            prepared.max_turns_arg,
+            self.resumed_conversation(context).map(str::to_string),
+        );
+        let mut process = match warm_pool::take(&key) {
+            Some(process) => {
+                emitter.send_output(format!("[agent:{}] Reusing warm process", self.name));
+                process
+            }
+            None => {
+                emitter.send_output(format!("[agent:{}] Starting...", self.name));
+                let mut cmd = self.build_command(prepared, Some(context));
+                cmd.stdin(Stdio::piped());
+                let cmd = remote_command(cmd, &self.config, &self.working_dir)?;
+                AgentProcess::spawn(cmd, config)?
+            }
+        };
+
+        // remote_command only translates arguments, and the prompt now goes over stdin
+        let prompt = if is_remote(&self.config) {
+            translate_text(&prepared.prompt, &self.config.path_map)
+        } else {
+            prepared.prompt.clone()
+        };
+        process.send_line(&warm_pool::user_message(&prompt)).await?;
+        let output = run_agent_turn(&mut process, config, parser, emitter).await?;
+
+        let conversation = output
+            .conversation_id
+            .clone()
+            .or_else(|| key.conversation_id().map(str::to_string));
+        if let Some(conversation) = conversation.filter(|_| !output.is_error) {
+            if process.is_alive() {
+                warm_pool::park(key.continued(conversation), process);
+            }
+        }
+        Ok(output)
+    }
+
+    /// Whether risky tool calls are gated by a `PreToolUse` hook before they run.
+    /// Remote agents cannot reach the local hook socket and are paused instead.
+    fn gates_tools(&self, context: Option<&AgentContext>) -> bool {
+        context.is_some() && self.config.permission_prompts && !is_remote(&self.config)
+    }
+
+    /// Whether the invocation runs on a warm process. A parked process outlives
+    /// the invocation, so it cannot keep a per-invocation permission hook.
+    fn runs_warm(&self, context: Option<&AgentContext>) -> bool {
+        self.config.warm_pool && context.is_some() && !self.gates_tools(context)
+    }
+
+    /// Binds the socket the permission hook asks, with the `--settings` value
+    /// that registers the hook. Falls back to pausing the agent if that fails.
+    fn bind_permission_gate(
+        &self,
+        logger: &Option<AgentLogger>,
+        context: Option<&AgentContext>,
+    ) -> Option<(Arc<PermissionGate>, String)> {
+        if !self.gates_tools(context) {
+            return None;
+        }
+        let bound = PermissionGate::bind().and_then(|gate| {
+            let settings = gate.hook_settings()?;
+            Ok((Arc::new(gate), settings))
+        });
+        match bound {
+            Ok(bound) => Some(bound),
+            Err(e) => {
+                if let Some(ref logger) = logger {
+                    logger.log_line("permission_gate", &format!("unavailable: {:#}", e));
+                }
+                None
+            }
+        }
+    }
+
+    /// The conversation this invocation continues, if it resumes one.
+    fn resumed_conversation<'a>(&self, context: &'a AgentContext) -> Option<&'a str> {
+        if self.config.session_persistence.enabled
+            && context.resume_strategy == ResumeStrategy::ConversationResume
+        {
+            context.conversation_id.as_deref()
+        } else {
+            None
+        }
+    }
+
     fn build_command(&self, prepared: &PreparedPrompt, context: Option<&AgentContext>) -> Command {
         let mut cmd = Command::new(&self.config.command);
 
@@ -83,7 +216,12 @@ impl ClaudeAgent {
             cmd.arg(arg);
         }
 
-        cmd.arg(&prepared.prompt);
+        if self.runs_warm(context) {
+            // Prompts arrive over stdin, one stream-json message per turn
+            cmd.arg("--input-format").arg("stream-json");
+        } else {
+            cmd.arg(&prepared.prompt);
+        }
 
         if let Some(ref sys_prompt) = prepared.system_prompt_arg {
             cmd.arg("--append-system-prompt").arg(sys_prompt);
@@ -98,16 +236,10 @@ impl ClaudeAgent {
             cmd.arg("--max-turns").arg(turns.to_string());
         }
 
-        if self.config.session_persistence.enabled {
-            if let Some(ctx) = context {
-                if ctx.resume_strategy == ResumeStrategy::ConversationResume {
-                    if let Some(ref conv_id) = ctx.conversation_id {
-                        // Use --resume to continue an existing conversation
-                        // This requires a conversation ID captured from a previous run
-                        cmd.arg("--resume").arg(conv_id);
-                    }
-                }
-            }
+        if let Some(conv_id) = context.and_then(|ctx| self.resumed_conversation(ctx)) {
+            // Use --resume to continue an existing conversation
+            // This requires a conversation ID captured from a previous run
+            cmd.arg("--resume").arg(conv_id);
         }
 
         cmd
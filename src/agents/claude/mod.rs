@@ -1,5 +1,6 @@
 mod agent;
 mod parser;
 mod util;
+pub mod warm_pool;
 
 pub use agent::ClaudeAgent;
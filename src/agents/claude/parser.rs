@@ -106,6 +106,22 @@ impl ClaudeParser {
                     }
                 }
             }
+            // Partial message updates (`--include-partial-messages`) wrap raw API
+            // stream events; only text deltas are used, for live display
+            "stream_event" => {
+                let delta = json
+                    .get("event")
+                    .filter(|e| {
+                        e.get("type").and_then(|t| t.as_str()) == Some("content_block_delta")
+                    })
+                    .and_then(|e| e.get("delta"))
+                    .filter(|d| d.get("type").and_then(|t| t.as_str()) == Some("text_delta"));
+                if let Some(text) = delta.and_then(|d| d.get("text")).and_then(|t| t.as_str()) {
+                    if !text.is_empty() {
+                        events.push(AgentEvent::PartialText(text.to_string()));
+                    }
+                }
+            }
             "result" => {
                 let output = json.get("result").and_then(|r| r.as_str()).map(Into::into);
                 let cost = json.get("total_cost_usd").and_then(|c| c.as_f64());
@@ -1,4 +1,5 @@
 use super::*;
+use crate::agents::pricing::PricingTable;
 use crate::agents::AgentContext;
 use crate::config::SessionPersistenceConfig;
 use crate::session_daemon::SessionLogger;
@@ -15,6 +16,11 @@ fn make_agent(session_persistence_enabled: bool) -> ClaudeAgent {
             enabled: session_persistence_enabled,
             strategy: ResumeStrategy::ConversationResume,
         },
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     ClaudeAgent::new("claude".to_string(), config, PathBuf::from("."))
 }
@@ -35,6 +41,11 @@ fn make_context(conversation_id: Option<String>, resume_strategy: ResumeStrategy
         resume_strategy,
         cancel_rx: None,
         session_logger,
+        pricing: PricingTable::default(),
+        provider_concurrency: Default::default(),
+        cost_alerts: Default::default(),
+        context_limits: Default::default(),
+        fault_injector: None,
     }
 }
 
@@ -64,6 +75,11 @@ fn test_claude_agent_new() {
         args: vec!["-p".to_string()],
         allowed_tools: vec!["Read".to_string()],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let agent = ClaudeAgent::new("claude".to_string(), config, PathBuf::from("."));
     assert_eq!(agent.activity_timeout, DEFAULT_ACTIVITY_TIMEOUT);
@@ -165,3 +181,50 @@ fn test_build_command_no_resume_when_no_context() {
         args
     );
 }
+
+#[test]
+fn test_build_command_warm_pool_reads_prompt_from_stdin() {
+    let mut agent = make_agent(true);
+    agent.config.warm_pool = true;
+    let prepared = make_prepared_prompt();
+    let ctx = make_context(None, ResumeStrategy::ConversationResume);
+    let args = get_args(&agent.build_command(&prepared, Some(&ctx)));
+
+    assert!(
+        args.contains(&"--input-format".to_string()),
+        "Args: {:?}",
+        args
+    );
+    assert!(
+        !args.contains(&"test prompt".to_string()),
+        "Prompt should be sent over stdin. Args: {:?}",
+        args
+    );
+
+    // Without a context the agent runs one-shot with the prompt as an argument
+    let args = get_args(&agent.build_command(&prepared, None));
+    assert!(
+        args.contains(&"test prompt".to_string()),
+        "Args: {:?}",
+        args
+    );
+}
+
+#[test]
+fn test_permission_prompts_run_one_shot_with_a_gate() {
+    let mut agent = make_agent(true);
+    agent.config.warm_pool = true;
+    agent.config.permission_prompts = true;
+    let prepared = make_prepared_prompt();
+    let ctx = make_context(None, ResumeStrategy::ConversationResume);
+
+    assert!(agent.gates_tools(Some(&ctx)));
+    assert!(!agent.runs_warm(Some(&ctx)));
+    let args = get_args(&agent.build_command(&prepared, Some(&ctx)));
+    assert!(
+        args.contains(&"test prompt".to_string()),
+        "A gated invocation cannot be parked. Args: {:?}",
+        args
+    );
+    assert!(!agent.gates_tools(None));
+}
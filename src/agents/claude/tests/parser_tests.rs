@@ -96,3 +96,27 @@ fn test_parse_system_non_init_ignored() {
     let events = parser.parse_line_multi(line).unwrap();
     assert!(events.is_empty());
 }
+
+#[test]
+fn test_parse_stream_event_text_delta_keeps_raw_text() {
+    let mut parser = ClaudeParser::new();
+    let line = r#"{"type":"stream_event","event":{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello,\n wor"}}}"#;
+    let events = parser.parse_line_multi(line).unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        AgentEvent::PartialText(text) => assert_eq!(text, "Hello,\n wor"),
+        _ => panic!("Expected PartialText event"),
+    }
+}
+
+#[test]
+fn test_parse_stream_event_ignores_non_text_deltas() {
+    let mut parser = ClaudeParser::new();
+    let lines = [
+        r#"{"type":"stream_event","event":{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"path\""}}}"#,
+        r#"{"type":"stream_event","event":{"type":"message_start","message":{"model":"claude"}}}"#,
+    ];
+    for line in lines {
+        assert!(parser.parse_line_multi(line).unwrap().is_empty());
+    }
+}
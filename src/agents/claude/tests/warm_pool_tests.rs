@@ -0,0 +1,158 @@
+use super::*;
+use crate::agents::claude::parser::ClaudeParser;
+use crate::agents::pricing::PricingTable;
+use crate::agents::runner::{run_agent_turn, ContextEmitter, RunnerConfig};
+use crate::agents::AgentContext;
+use crate::session_daemon::SessionLogger;
+use crate::tui::SessionEventSender;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+/// Upper bound for a fake agent turn; exceeding it means the runner deadlocked.
+const TURN_DEADLINE: Duration = Duration::from_secs(10);
+
+/// Answers every stdin line with an init and a result message, like `claude --input-format stream-json`.
+const FAKE_CLAUDE: &str = r#"n=0; while read line; do n=$((n+1)); echo '{"type":"system","subtype":"init","session_id":"conv-1"}'; echo "{\"type\":\"result\",\"result\":\"turn $n\",\"is_error\":false}"; done"#;
+
+fn runner_config() -> RunnerConfig {
+    RunnerConfig::new("fake".to_string(), PathBuf::from(".")).with_fault_injector(None)
+}
+
+fn spawn_shell(script: &str) -> AgentProcess {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(script).stdin(Stdio::piped());
+    AgentProcess::spawn(command, &runner_config()).expect("spawn fake agent")
+}
+
+fn make_emitter() -> ContextEmitter {
+    let session_id = format!("test-{}", uuid::Uuid::new_v4());
+    let session_logger = Arc::new(SessionLogger::new(&session_id).expect("test logger"));
+    let (tx, _rx) = mpsc::unbounded_channel();
+    let context = AgentContext {
+        session_sender: SessionEventSender::new(0, 0, tx),
+        phase: "Testing".to_string(),
+        conversation_id: None,
+        resume_strategy: Default::default(),
+        cancel_rx: None,
+        session_logger,
+        pricing: PricingTable::default(),
+        provider_concurrency: Default::default(),
+        cost_alerts: Default::default(),
+        context_limits: Default::default(),
+        fault_injector: None,
+    };
+    ContextEmitter::new(context, "fake".to_string(), "claude")
+}
+
+fn unique_session() -> String {
+    format!("warm-{}", uuid::Uuid::new_v4())
+}
+
+#[test]
+fn test_key_continued_only_changes_conversation() {
+    let key = WarmKey::new("s1", "claude", Some("system"), Some(5), None);
+    let continued = key.continued("conv-1".to_string());
+    assert_eq!(continued.conversation_id(), Some("conv-1"));
+    assert_eq!(
+        continued,
+        WarmKey::new(
+            "s1",
+            "claude",
+            Some("system"),
+            Some(5),
+            Some("conv-1".to_string())
+        )
+    );
+    assert_ne!(
+        continued,
+        WarmKey::new(
+            "s1",
+            "claude",
+            Some("other"),
+            Some(5),
+            Some("conv-1".to_string())
+        )
+    );
+}
+
+#[test]
+fn test_user_message_is_stream_json() {
+    let line = user_message("Plan \"it\"\nnow");
+    assert!(!line.contains('\n'));
+    let json: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(json["type"], "user");
+    assert_eq!(json["message"]["content"][0]["text"], "Plan \"it\"\nnow");
+}
+
+#[tokio::test]
+async fn test_take_returns_parked_process_once() {
+    let session = unique_session();
+    let key = WarmKey::new(&session, "claude", None, None, Some("conv-1".to_string()));
+    park(key.clone(), spawn_shell("sleep 30"));
+
+    let other = WarmKey::new(&session, "claude", None, None, Some("conv-2".to_string()));
+    assert!(take(&other).is_none());
+    assert!(take(&key).is_some());
+    assert!(take(&key).is_none());
+}
+
+#[tokio::test]
+async fn test_take_skips_exited_process() {
+    let session = unique_session();
+    let key = WarmKey::new(&session, "claude", None, None, Some("conv-1".to_string()));
+    let mut process = spawn_shell("exit 0");
+    for _ in 0..50 {
+        if !process.is_alive() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    park(key.clone(), process);
+    assert!(take(&key).is_none());
+}
+
+#[tokio::test]
+async fn test_release_session_drops_only_that_session() {
+    let session = unique_session();
+    let other_session = unique_session();
+    let key = |session: &str, conv: &str| {
+        WarmKey::new(session, "claude", None, None, Some(conv.to_string()))
+    };
+    park(key(&session, "a"), spawn_shell("sleep 30"));
+    park(key(&session, "b"), spawn_shell("sleep 30"));
+    park(key(&other_session, "a"), spawn_shell("sleep 30"));
+
+    {
+        let _guard = WarmPoolGuard::new(&session);
+    }
+    assert!(take(&key(&session, "a")).is_none());
+    assert!(take(&key(&session, "b")).is_none());
+    assert!(take(&key(&other_session, "a")).is_some());
+}
+
+#[tokio::test]
+async fn test_persistent_process_serves_consecutive_turns() {
+    let emitter = make_emitter();
+    let config = runner_config();
+    let mut process = spawn_shell(FAKE_CLAUDE);
+
+    for turn in 1..=2 {
+        process.send_line(&user_message("next")).await.unwrap();
+        let output = tokio::time::timeout(
+            TURN_DEADLINE,
+            run_agent_turn(&mut process, &config, &mut ClaudeParser::new(), &emitter),
+        )
+        .await
+        .expect("turn deadlocked")
+        .expect("turn succeeds");
+
+        assert_eq!(output.output, format!("turn {}", turn));
+        assert_eq!(output.conversation_id.as_deref(), Some("conv-1"));
+        assert!(!output.is_error);
+        assert!(process.is_alive());
+    }
+}
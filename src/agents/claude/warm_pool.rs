@@ -0,0 +1,120 @@
+//! Warm pool of persistent Claude CLI processes.
+//!
+//! An agent with `warm_pool: true` starts the CLI with `--input-format stream-json`,
+//! so it reads prompts from stdin and keeps running after each result. When a turn
+//! succeeds, the process is parked here under the conversation it now holds; a later
+//! invocation in the same session that resumes that conversation with the same
+//! system prompt and turn limit is handed the parked process instead of spawning a
+//! new CLI. This covers the repeated phases of a session (revisions, re-reviews,
+//! implementation rounds). Parked processes are killed when their session's
+//! workflow run ends.
+
+use crate::agents::process::AgentProcess;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// Identifies which invocations a parked process can serve.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WarmKey {
+    session_id: String,
+    agent_name: String,
+    /// Fixed when the CLI starts, so a process can't switch them later.
+    system_prompt: Option<String>,
+    max_turns: Option<u32>,
+    /// Conversation the process holds; None until it has run a turn.
+    conversation_id: Option<String>,
+}
+
+impl WarmKey {
+    pub fn new(
+        session_id: &str,
+        agent_name: &str,
+        system_prompt: Option<&str>,
+        max_turns: Option<u32>,
+        conversation_id: Option<String>,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            agent_name: agent_name.to_string(),
+            system_prompt: system_prompt.map(str::to_string),
+            max_turns,
+            conversation_id,
+        }
+    }
+
+    /// The key of the same process after a turn in `conversation_id`.
+    pub fn continued(&self, conversation_id: String) -> Self {
+        Self {
+            conversation_id: Some(conversation_id),
+            ..self.clone()
+        }
+    }
+
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.conversation_id.as_deref()
+    }
+}
+
+fn pool() -> &'static Mutex<HashMap<WarmKey, AgentProcess>> {
+    static POOL: OnceLock<Mutex<HashMap<WarmKey, AgentProcess>>> = OnceLock::new();
+    POOL.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Takes the parked process for `key`, if one is still running.
+pub fn take(key: &WarmKey) -> Option<AgentProcess> {
+    let mut process = pool().lock().ok()?.remove(key)?;
+    process.is_alive().then_some(process)
+}
+
+/// Parks a process between invocations. A process already parked under the same key is killed.
+pub fn park(key: WarmKey, process: AgentProcess) {
+    if let Ok(mut pool) = pool().lock() {
+        pool.insert(key, process);
+    }
+}
+
+/// Kills every process parked for a session. Returns how many were released.
+pub fn release_session(session_id: &str) -> usize {
+    let Ok(mut pool) = pool().lock() else {
+        return 0;
+    };
+    let before = pool.len();
+    // Dropping a process kills it (kill_on_drop)
+    pool.retain(|key, _| key.session_id != session_id);
+    before - pool.len()
+}
+
+/// Releases a session's warm processes when its workflow run ends.
+pub struct WarmPoolGuard {
+    session_id: String,
+}
+
+impl WarmPoolGuard {
+    pub fn new(session_id: &str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+        }
+    }
+}
+
+impl Drop for WarmPoolGuard {
+    fn drop(&mut self) {
+        release_session(&self.session_id);
+    }
+}
+
+/// Encodes a prompt as a stream-json user message line.
+pub fn user_message(prompt: &str) -> String {
+    serde_json::json!({
+        "type": "user",
+        "message": {
+            "role": "user",
+            "content": [{ "type": "text", "text": prompt }],
+        },
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+#[path = "tests/warm_pool_tests.rs"]
+mod tests;
@@ -3,7 +3,7 @@
 //! Parses JSON line output from the Codex CLI tool and converts it to
 //! unified AgentEvent types.
 
-use crate::agents::protocol::{AgentEvent, AgentStreamParser, ParseError};
+use crate::agents::protocol::{AgentEvent, AgentStreamParser, AgentTokenUsage, ParseError};
 use serde_json::Value;
 
 /// Parser for Codex CLI JSON output.
@@ -13,6 +13,7 @@ use serde_json::Value;
 /// - `item.completed`, `item.delta` - structured item events
 /// - `function_call`, `tool_call` - tool invocation events
 /// - `function_result`, `tool_result` - tool result events
+/// - `turn.completed` - token usage for the turn
 /// - `done`, `complete`, `finished` - completion events
 /// - `error` - error events
 #[derive(Debug, Clone, Default)]
@@ -207,6 +208,20 @@ impl CodexParser {
                         has_more,
                     });
                 }
+                "turn.completed" => {
+                    // Cached input is part of `input_tokens`; split it out for pricing
+                    if let Some(usage) = json.get("usage") {
+                        let count =
+                            |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+                        let cached = count("cached_input_tokens");
+                        events.push(AgentEvent::TokenUsage(AgentTokenUsage {
+                            input_tokens: count("input_tokens").saturating_sub(cached),
+                            output_tokens: count("output_tokens"),
+                            cache_creation_tokens: 0,
+                            cache_read_tokens: cached,
+                        }));
+                    }
+                }
                 "done" | "complete" | "finished" => {
                     let output = json
                         .get("message")
@@ -1,4 +1,5 @@
 use super::*;
+use crate::agents::pricing::PricingTable;
 use crate::agents::AgentContext;
 use crate::config::SessionPersistenceConfig;
 use crate::session_daemon::SessionLogger;
@@ -15,6 +16,11 @@ fn make_agent(session_persistence_enabled: bool) -> CodexAgent {
             enabled: session_persistence_enabled,
             strategy: ResumeStrategy::ConversationResume,
         },
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     CodexAgent::new("codex".to_string(), config, PathBuf::from("."))
 }
@@ -32,6 +38,11 @@ fn make_context(conversation_id: Option<String>, resume_strategy: ResumeStrategy
         resume_strategy,
         cancel_rx: None,
         session_logger,
+        pricing: PricingTable::default(),
+        provider_concurrency: Default::default(),
+        cost_alerts: Default::default(),
+        context_limits: Default::default(),
+        fault_injector: None,
     }
 }
 
@@ -52,6 +63,11 @@ fn test_codex_agent_new() {
         args: vec!["exec".to_string(), "--json".to_string()],
         allowed_tools: vec![],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let agent = CodexAgent::new("codex".to_string(), config, PathBuf::from("."));
     assert_eq!(agent.activity_timeout, DEFAULT_ACTIVITY_TIMEOUT);
@@ -225,3 +225,19 @@ fn test_parse_thread_started_captures_conversation_id() {
         _ => panic!("Expected ConversationIdCaptured event"),
     }
 }
+
+#[test]
+fn test_parse_turn_completed_splits_cached_input() {
+    let mut parser = CodexParser::new();
+    let line = r#"{"type":"turn.completed","usage":{"input_tokens":1200,"cached_input_tokens":1000,"output_tokens":300}}"#;
+    let events = parser.parse_line_multi(line).unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        AgentEvent::TokenUsage(usage) => {
+            assert_eq!(usage.input_tokens, 200);
+            assert_eq!(usage.cache_read_tokens, 1000);
+            assert_eq!(usage.output_tokens, 300);
+        }
+        _ => panic!("Expected TokenUsage event"),
+    }
+}
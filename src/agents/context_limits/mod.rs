@@ -0,0 +1,361 @@
+//! Prompt size accounting against the model's context window.
+//!
+//! Before each invocation the prompt's size is estimated (about four characters
+//! per token) and compared with the window of the agent's model: its
+//! `--model`/`-m` arg, otherwise its CLI. Windows come from the built-in table
+//! below, extended by the workflow config's `context_limits.windows`. A prompt at
+//! `warn_percent` of the window or more is reported in the output panel, and one
+//! that does not fit gets the configured mitigation:
+//! - `summarize`: the agent condenses the prompt's accumulated user feedback first
+//! - `truncate_feedback`: the oldest user feedback items are dropped
+//! - `switch_model`: the invocation runs with `fallback_model` instead
+//!
+//! The estimated prompt size and the response size of every invocation are
+//! written to the agent's log.
+
+use super::log::AgentLogger;
+use super::pricing::model_hint;
+use super::prompt::PreparedPrompt;
+use super::{AgentContext, AgentResult, AgentType};
+use crate::config::AgentConfig;
+use crate::domain::types::ResumeStrategy;
+use crate::prompt_format::PromptBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Built-in context windows in tokens, keyed by model name prefix. The bare CLI
+/// names are fallbacks for agents whose model is not set in their args.
+const DEFAULT_WINDOWS: [(&str, u64); 4] = [
+    ("claude", 200_000),
+    ("codex", 272_000),
+    ("gpt-5", 272_000),
+    ("gemini", 1_048_576),
+];
+
+/// Opening tag of the accumulated user feedback in planning prompts.
+const FEEDBACK_OPEN: &str = "<user-feedback>";
+const FEEDBACK_CLOSE: &str = "</user-feedback>";
+
+/// What to do with a prompt that does not fit the model's context window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextMitigation {
+    /// Only warn; the provider decides what happens
+    #[default]
+    None,
+    /// Have the agent condense the accumulated user feedback
+    Summarize,
+    /// Drop the oldest user feedback items
+    TruncateFeedback,
+    /// Run the invocation with `fallback_model`
+    SwitchModel,
+}
+
+/// Context window limits from the workflow config.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ContextLimitsConfig {
+    /// Share of the window, in percent, at which a prompt is reported
+    #[serde(default = "default_warn_percent")]
+    pub warn_percent: u8,
+    #[serde(default)]
+    pub mitigation: ContextMitigation,
+    /// Model run by `switch_model`, e.g. `gemini-2.5-pro`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_model: Option<String>,
+    /// Windows in tokens keyed by model name prefix, on top of the built-in ones
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub windows: BTreeMap<String, u64>,
+}
+
+fn default_warn_percent() -> u8 {
+    85
+}
+
+impl Default for ContextLimitsConfig {
+    fn default() -> Self {
+        Self {
+            warn_percent: default_warn_percent(),
+            mitigation: ContextMitigation::default(),
+            fallback_model: None,
+            windows: BTreeMap::new(),
+        }
+    }
+}
+
+impl ContextLimitsConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !(1..=100).contains(&self.warn_percent) {
+            anyhow::bail!("context_limits.warn_percent must be between 1 and 100");
+        }
+        if self.mitigation == ContextMitigation::SwitchModel && self.fallback_model.is_none() {
+            anyhow::bail!("context_limits.mitigation switch_model needs a fallback_model");
+        }
+        if let Some((model, _)) = self.windows.iter().find(|(_, tokens)| **tokens == 0) {
+            anyhow::bail!("context_limits.windows.{} must be at least 1", model);
+        }
+        Ok(())
+    }
+
+    /// Window of a model: the entry with the longest name prefix of `model`.
+    pub fn window(&self, model: &str) -> Option<u64> {
+        let model = model.to_lowercase();
+        DEFAULT_WINDOWS
+            .iter()
+            .filter(|(prefix, _)| !self.windows.contains_key(*prefix))
+            .map(|(prefix, tokens)| (prefix.to_string(), *tokens))
+            .chain(
+                self.windows
+                    .iter()
+                    .map(|(prefix, tokens)| (prefix.to_lowercase(), *tokens)),
+            )
+            .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, tokens)| tokens)
+    }
+}
+
+/// Estimated token count of `text`.
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+fn prompt_tokens(prepared: &PreparedPrompt) -> u64 {
+    estimate_tokens(&prepared.prompt)
+        + prepared
+            .system_prompt_arg
+            .as_deref()
+            .map(estimate_tokens)
+            .unwrap_or(0)
+}
+
+/// The prompt around its user feedback block, and the block's contents.
+fn split_feedback(prompt: &str) -> Option<(&str, &str, &str)> {
+    let (before, rest) = prompt.split_once(FEEDBACK_OPEN)?;
+    let (feedback, after) = rest.split_once(FEEDBACK_CLOSE)?;
+    Some((before, feedback, after))
+}
+
+fn with_feedback(before: &str, feedback: &str, after: &str) -> String {
+    format!(
+        "{}{}{}{}{}",
+        before, FEEDBACK_OPEN, feedback, FEEDBACK_CLOSE, after
+    )
+}
+
+/// Drops the oldest numbered items of the prompt's user feedback until the prompt
+/// is at most `max_tokens`, or no item is left. Returns the prompt and the number
+/// of items dropped, or None without a feedback block.
+pub fn drop_oldest_feedback(prompt: &str, max_tokens: u64) -> Option<(String, usize)> {
+    let (before, feedback, after) = split_feedback(prompt)?;
+    let mut items: Vec<String> = Vec::new();
+    for line in feedback.lines() {
+        let starts_item = line
+            .split_once(". ")
+            .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        match items.last_mut() {
+            Some(item) if !starts_item => {
+                item.push('\n');
+                item.push_str(line);
+            }
+            _ => items.push(line.to_string()),
+        }
+    }
+    let mut dropped = 0;
+    let mut trimmed = prompt.to_string();
+    while estimate_tokens(&trimmed) > max_tokens && dropped < items.len() {
+        dropped += 1;
+        let mut kept = vec![format!(
+            "[{} earlier feedback item(s) omitted to fit the context window]",
+            dropped
+        )];
+        kept.extend(items.iter().skip(dropped).cloned());
+        trimmed = with_feedback(before, &kept.join("\n"), after);
+    }
+    Some((trimmed, dropped))
+}
+
+/// `args` running `model`: any `--model`/`-m` arg is replaced.
+pub fn set_model_arg(args: &mut Vec<String>, model: &str) {
+    let mut old = std::mem::take(args).into_iter();
+    while let Some(arg) = old.next() {
+        if arg == "--model" || arg == "-m" {
+            old.next();
+        } else if !arg.starts_with("--model=") {
+            args.push(arg);
+        }
+    }
+    args.push("--model".to_string());
+    args.push(model.to_string());
+}
+
+impl AgentType {
+    fn config(&self) -> &AgentConfig {
+        match self {
+            Self::Claude(agent) => agent.config(),
+            Self::Codex(agent) => agent.config(),
+            Self::Gemini(agent) => agent.config(),
+        }
+    }
+
+    /// This agent running `model`.
+    fn with_model(&self, model: &str) -> Self {
+        let mut agent = self.clone();
+        let config = match &mut agent {
+            Self::Claude(agent) => agent.config_mut(),
+            Self::Codex(agent) => agent.config_mut(),
+            Self::Gemini(agent) => agent.config_mut(),
+        };
+        set_model_arg(&mut config.args, model);
+        agent
+    }
+
+    /// Measures the prompt against the model's context window, reporting a prompt
+    /// at `warn_percent` or more and mitigating one that does not fit. Returns the
+    /// agent to run instead, if the model was switched, and the prompt to send.
+    pub(super) async fn fit_context_window(
+        &self,
+        prepared: PreparedPrompt,
+        context: &AgentContext,
+    ) -> (Option<AgentType>, PreparedPrompt) {
+        let limits = &context.context_limits;
+        let model = model_hint(self.config());
+        let Some(window) = limits
+            .window(&model)
+            .or_else(|| limits.window(self.provider()))
+        else {
+            return (None, prepared);
+        };
+        let tokens = prompt_tokens(&prepared);
+        let percent = tokens * 100 / window;
+        AgentLogger::new(self.name(), context.session_logger.clone()).log_line(
+            "context",
+            &format!(
+                "prompt ~{} tokens, {}% of the {} token window of {}",
+                tokens, percent, window, model
+            ),
+        );
+        if percent < u64::from(limits.warn_percent) {
+            return (None, prepared);
+        }
+        let sender = &context.session_sender;
+        sender.send_output(format!(
+            "[agent:{}] Prompt is {}% of the context window for {} (~{} of {} tokens)",
+            self.name(),
+            percent,
+            model,
+            tokens,
+            window
+        ));
+        if tokens <= window {
+            return (None, prepared);
+        }
+
+        let target = window * u64::from(limits.warn_percent) / 100;
+        match limits.mitigation {
+            ContextMitigation::None => (None, prepared),
+            ContextMitigation::SwitchModel => {
+                let Some(fallback) = limits.fallback_model.as_deref() else {
+                    return (None, prepared);
+                };
+                sender.send_output(format!(
+                    "[agent:{}] Running this prompt with {} instead",
+                    self.name(),
+                    fallback
+                ));
+                (Some(self.with_model(fallback)), prepared)
+            }
+            ContextMitigation::Summarize => {
+                let summarized = match split_feedback(&prepared.prompt) {
+                    Some((before, feedback, after)) => self
+                        .summarize_feedback(feedback, context)
+                        .await
+                        .map(|summary| with_feedback(before, &format!("\n{}\n", summary), after)),
+                    None => None,
+                };
+                match summarized {
+                    Some(prompt)
+                        if estimate_tokens(&prompt) < estimate_tokens(&prepared.prompt) =>
+                    {
+                        sender.send_output(format!(
+                            "[agent:{}] Condensed the user feedback (~{} tokens now)",
+                            self.name(),
+                            estimate_tokens(&prompt)
+                        ));
+                        (None, PreparedPrompt { prompt, ..prepared })
+                    }
+                    // A failed summary falls back to dropping the oldest feedback
+                    _ => (None, self.truncate_feedback(prepared, target, context)),
+                }
+            }
+            ContextMitigation::TruncateFeedback => {
+                (None, self.truncate_feedback(prepared, target, context))
+            }
+        }
+    }
+
+    fn truncate_feedback(
+        &self,
+        prepared: PreparedPrompt,
+        max_tokens: u64,
+        context: &AgentContext,
+    ) -> PreparedPrompt {
+        let sender = &context.session_sender;
+        match drop_oldest_feedback(&prepared.prompt, max_tokens) {
+            Some((prompt, dropped)) if dropped > 0 => {
+                sender.send_output(format!(
+                    "[agent:{}] Dropped the {} oldest user feedback item(s) (~{} tokens now)",
+                    self.name(),
+                    dropped,
+                    estimate_tokens(&prompt)
+                ));
+                PreparedPrompt { prompt, ..prepared }
+            }
+            _ => {
+                sender.send_output(format!(
+                    "[agent:{}] No user feedback to trim; sending the prompt as is",
+                    self.name()
+                ));
+                prepared
+            }
+        }
+    }
+
+    /// Has the agent condense `feedback` in a stateless invocation of its own.
+    async fn summarize_feedback(&self, feedback: &str, context: &AgentContext) -> Option<String> {
+        let prompt = PromptBuilder::new()
+            .phase("summarizing")
+            .instructions(
+                "Condense the numbered user feedback below so it fits a smaller context. \
+                 Keep every distinct request, constraint and decision, newest last; drop \
+                 repetition and chatter. Reply with the condensed numbered list only.",
+            )
+            .input("user-feedback", feedback)
+            .build();
+        let mut context = context.clone();
+        context.conversation_id = None;
+        context.resume_strategy = ResumeStrategy::Stateless;
+        let prepared = self.prepare_prompt(prompt, None, Some(1));
+        match self.execute_prepared(prepared, context).await {
+            Ok(result) if !result.is_error && !result.output.trim().is_empty() => {
+                Some(result.output.trim().to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Writes the response size of an invocation to the agent's log.
+pub(super) fn log_response_size(agent: &AgentType, context: &AgentContext, result: &AgentResult) {
+    AgentLogger::new(agent.name(), context.session_logger.clone()).log_line(
+        "context",
+        &format!("response ~{} tokens", estimate_tokens(&result.output)),
+    );
+}
+
+#[cfg(test)]
+#[path = "tests/context_limits_tests.rs"]
+mod tests;
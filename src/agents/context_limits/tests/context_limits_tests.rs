@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn test_window_uses_the_longest_prefix_and_overrides() {
+    let mut limits = ContextLimitsConfig::default();
+    assert_eq!(limits.window("gemini-2.5-pro"), Some(1_048_576));
+    assert_eq!(limits.window("opus"), None);
+
+    limits.windows.insert("gemini".to_string(), 500_000);
+    limits
+        .windows
+        .insert("claude-sonnet-4".to_string(), 1_000_000);
+    assert_eq!(limits.window("gemini-2.5-pro"), Some(500_000));
+    assert_eq!(limits.window("claude-sonnet-4-5"), Some(1_000_000));
+    assert_eq!(limits.window("claude-opus-4"), Some(200_000));
+}
+
+#[test]
+fn test_validate_needs_a_fallback_model_to_switch_to() {
+    let mut limits = ContextLimitsConfig {
+        mitigation: ContextMitigation::SwitchModel,
+        ..Default::default()
+    };
+    assert!(limits.validate().is_err());
+    limits.fallback_model = Some("gemini-2.5-pro".to_string());
+    assert!(limits.validate().is_ok());
+    limits.warn_percent = 0;
+    assert!(limits.validate().is_err());
+}
+
+#[test]
+fn test_drop_oldest_feedback_keeps_the_newest_items() {
+    let old = "x".repeat(400);
+    let prompt = format!(
+        "<inputs>\n<user-feedback>1. {}\n2. keep the\nAPI stable\n3. add tests</user-feedback>\n</inputs>",
+        old
+    );
+    let (trimmed, dropped) = drop_oldest_feedback(&prompt, 50).unwrap();
+
+    assert_eq!(dropped, 1);
+    assert!(!trimmed.contains(&old));
+    assert!(trimmed.contains(
+        "<user-feedback>[1 earlier feedback item(s) omitted to fit the context window]\n2. keep the\nAPI stable\n3. add tests</user-feedback>"
+    ));
+    assert!(drop_oldest_feedback("<objective>x</objective>", 1).is_none());
+}
+
+#[test]
+fn test_set_model_arg_replaces_any_model_arg() {
+    let mut args: Vec<String> = [
+        "-p",
+        "--model",
+        "sonnet",
+        "--model=opus",
+        "-m",
+        "haiku",
+        "-v",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    set_model_arg(&mut args, "gemini-2.5-pro");
+    assert_eq!(args, ["-p", "-v", "--model", "gemini-2.5-pro"]);
+}
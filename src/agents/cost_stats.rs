@@ -0,0 +1,307 @@
+//! Historical per-phase agent usage and workflow cost estimation.
+//!
+//! Each agent invocation records its token count and cost in the session's
+//! usage ledger. When a planning workflow completes, the ledger is folded into
+//! `~/.planning-agent/cost-stats.json`, and new workflows use the resulting
+//! per-invocation averages to estimate a cost range before any agent runs.
+//!
+//! Invocations are also appended to the session's `usage.jsonl` as they finish,
+//! which `planning usage` aggregates across sessions.
+
+use crate::config::WorkflowConfig;
+use crate::planning_paths;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+/// Usage reported by a single agent invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvocationUsage {
+    /// Phase without its iteration suffix (e.g. "Reviewing")
+    pub phase: String,
+    /// Agent config name (e.g. "claude")
+    pub agent: String,
+    /// Input, output and cache-creation tokens (cache reads are excluded)
+    pub tokens: u64,
+    /// Cost in USD, if the agent reports one
+    pub cost: Option<f64>,
+}
+
+/// Running totals for one (phase, agent) pair.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    pub invocations: u64,
+    pub tokens: u64,
+    /// Invocations that reported a cost; `cost` is their sum
+    pub priced_invocations: u64,
+    pub cost: f64,
+}
+
+impl UsageTotals {
+    pub fn add(&mut self, other: &UsageTotals) {
+        self.invocations += other.invocations;
+        self.tokens += other.tokens;
+        self.priced_invocations += other.priced_invocations;
+        self.cost += other.cost;
+    }
+
+    /// Average tokens per invocation.
+    pub fn avg_tokens(&self) -> Option<f64> {
+        (self.invocations > 0).then(|| self.tokens as f64 / self.invocations as f64)
+    }
+
+    /// Average cost per priced invocation.
+    pub fn avg_cost(&self) -> Option<f64> {
+        (self.priced_invocations > 0).then(|| self.cost / self.priced_invocations as f64)
+    }
+}
+
+/// Strips the iteration suffix from a phase label ("Reviewing #2" -> "Reviewing").
+pub fn phase_key(phase: &str) -> &str {
+    phase.split(" #").next().unwrap_or(phase).trim()
+}
+
+/// Phases that `cost_alerts` thresholds can be set for.
+pub const COST_ALERT_PHASES: &[&str] = &[
+    "planning",
+    "reviewing",
+    "revising",
+    "implementation",
+    "implementation_review",
+];
+
+/// The `cost_alerts` key for a phase label ("Implementation Review #2" ->
+/// "implementation_review").
+pub fn cost_alert_key(phase: &str) -> String {
+    phase_key(phase).to_lowercase().replace(' ', "_")
+}
+
+/// The round a phase label belongs to, so recovery attempts count towards it
+/// ("Reviewing #2 (recovery)" -> "Reviewing #2").
+pub fn cost_round(phase: &str) -> &str {
+    phase.split(" (").next().unwrap_or(phase).trim()
+}
+
+/// Historical usage keyed by phase, then agent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CostStats {
+    #[serde(default)]
+    phases: BTreeMap<String, BTreeMap<String, UsageTotals>>,
+}
+
+impl CostStats {
+    /// Loads stats from `~/.planning-agent/cost-stats.json` (empty if absent).
+    pub fn load() -> Result<Self> {
+        let path = planning_paths::cost_stats_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path).context("Failed to read cost stats")?;
+        serde_json::from_str(&content).context("Failed to parse cost stats")
+    }
+
+    /// Persists stats to `~/.planning-agent/cost-stats.json`.
+    pub fn save(&self) -> Result<()> {
+        let path = planning_paths::cost_stats_path()?;
+        let content = serde_json::to_string_pretty(self).context("Failed to serialize stats")?;
+        std::fs::write(&path, content).context("Failed to write cost stats")?;
+        Ok(())
+    }
+
+    /// Adds a session's invocations to the totals.
+    pub fn record(&mut self, usage: &[InvocationUsage]) {
+        for invocation in usage {
+            let totals = self
+                .phases
+                .entry(invocation.phase.clone())
+                .or_default()
+                .entry(invocation.agent.clone())
+                .or_default();
+            totals.add(&UsageTotals {
+                invocations: 1,
+                tokens: invocation.tokens,
+                priced_invocations: u64::from(invocation.cost.is_some()),
+                cost: invocation.cost.unwrap_or(0.0),
+            });
+        }
+    }
+
+    /// Totals for an agent in a phase, falling back to all agents in that phase.
+    pub fn totals(&self, phase: &str, agent: &str) -> Option<UsageTotals> {
+        let agents = self.phases.get(phase)?;
+        if let Some(totals) = agents.get(agent) {
+            return Some(*totals);
+        }
+        let mut merged = UsageTotals::default();
+        for totals in agents.values() {
+            merged.add(totals);
+        }
+        (merged.invocations > 0).then_some(merged)
+    }
+}
+
+/// Folds a completed session's usage into the stored historical averages.
+pub fn record_session_usage(usage: &[InvocationUsage]) -> Result<()> {
+    if usage.is_empty() {
+        return Ok(());
+    }
+    // A corrupt stats file is replaced rather than blocking future estimates
+    let mut stats = CostStats::load().unwrap_or_default();
+    stats.record(usage);
+    stats.save()
+}
+
+/// File name of a session's usage ledger.
+pub const USAGE_LEDGER_FILE: &str = "usage.jsonl";
+
+/// One line of a session's `usage.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub recorded_at: DateTime<Utc>,
+    /// CLI the agent runs (e.g. "codex")
+    pub provider: String,
+    pub agent: String,
+    pub phase: String,
+    pub tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+}
+
+/// Appends a finished invocation to the session's `usage.jsonl`.
+pub fn append_usage_record(
+    session_id: &str,
+    provider: &str,
+    usage: &InvocationUsage,
+) -> Result<()> {
+    let record = UsageRecord {
+        recorded_at: Utc::now(),
+        provider: provider.to_string(),
+        agent: usage.agent.clone(),
+        phase: usage.phase.clone(),
+        tokens: usage.tokens,
+        cost: usage.cost,
+    };
+    let path = planning_paths::session_usage_path(session_id)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open usage ledger: {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)
+        .with_context(|| format!("Failed to write usage ledger: {}", path.display()))
+}
+
+/// Reads a `usage.jsonl` ledger. Lines that don't parse (e.g. cut off by a
+/// crash) are skipped.
+pub fn load_usage_records(path: &Path) -> Result<Vec<UsageRecord>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read usage ledger: {}", path.display()))?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Expected cost and token range for a planning workflow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    pub min_cost: f64,
+    pub max_cost: f64,
+    pub min_tokens: u64,
+    pub max_tokens: u64,
+}
+
+impl CostEstimate {
+    /// One-line summary, e.g. "Estimated: $3–8 (~200k–900k tokens)".
+    pub fn summary(&self) -> String {
+        let cost = format_range(self.min_cost, self.max_cost, format_usd);
+        let tokens = format_range(self.min_tokens as f64, self.max_tokens as f64, |t| {
+            crate::tui::ui::util::format_tokens(t.round() as u64)
+        });
+        format!("Estimated: ${} (~{} tokens)", cost, tokens)
+    }
+}
+
+fn format_usd(value: f64) -> String {
+    if value < 1.0 {
+        format!("{:.2}", value)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+fn format_range(min: f64, max: f64, format: impl Fn(f64) -> String) -> String {
+    let (min, max) = (format(min), format(max));
+    if min == max {
+        min
+    } else {
+        format!("{}–{}", min, max)
+    }
+}
+
+/// Estimates the cost of a planning workflow from historical averages.
+///
+/// The low end assumes the plan is approved in the first review round; the high
+/// end assumes every iteration is used (one review round per iteration and a
+/// revision between rounds). Revisions without history use planning averages.
+/// Returns None when some phase has no cost history.
+pub fn estimate_workflow_cost(
+    config: &WorkflowConfig,
+    max_iterations: u32,
+    stats: &CostStats,
+) -> Option<CostEstimate> {
+    let max_iterations = u64::from(max_iterations.max(1));
+    let planning_agent = config.workflow.planning.agent.as_str();
+
+    // (phase, agent, min invocations, max invocations)
+    let mut slots = vec![("Planning", planning_agent, 1, 1)];
+    let reviewers = config.workflow.reviewing.enabled_agents();
+    for reviewer in &reviewers {
+        slots.push(("Reviewing", reviewer.agent_name(), 1, max_iterations));
+    }
+    slots.push(("Revising", planning_agent, 0, max_iterations - 1));
+
+    let mut estimate = CostEstimate {
+        min_cost: 0.0,
+        max_cost: 0.0,
+        min_tokens: 0,
+        max_tokens: 0,
+    };
+    for (phase, agent, min, max) in slots {
+        if max == 0 {
+            continue;
+        }
+        let totals = stats.totals(phase, agent).or_else(|| {
+            (phase == "Revising")
+                .then(|| stats.totals("Planning", agent))
+                .flatten()
+        })?;
+        let avg_cost = totals.avg_cost()?;
+        let avg_tokens = totals.avg_tokens()?;
+        estimate.min_cost += avg_cost * min as f64;
+        estimate.max_cost += avg_cost * max as f64;
+        estimate.min_tokens += (avg_tokens * min as f64).round() as u64;
+        estimate.max_tokens += (avg_tokens * max as f64).round() as u64;
+    }
+    Some(estimate)
+}
+
+/// Describes the expected cost of a workflow for display before it starts.
+pub fn describe_estimate(config: &WorkflowConfig, max_iterations: u32) -> String {
+    let stats = CostStats::load().unwrap_or_default();
+    match estimate_workflow_cost(config, max_iterations, &stats) {
+        Some(estimate) => format!(
+            "{} for up to {} iteration(s)",
+            estimate.summary(),
+            max_iterations
+        ),
+        None => "Estimated: no cost history yet".to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cost_stats_tests.rs"]
+mod tests;
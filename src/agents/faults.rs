@@ -0,0 +1,162 @@
+//! Fault injection for agent runs.
+//!
+//! A hidden `--fault-inject <spec>` option makes agent invocations randomly fail,
+//! stall, or return malformed output so the retry, timeout, and cancellation paths
+//! can be exercised without a flaky CLI. The injector travels with each
+//! invocation's `AgentContext`, so tests can fault single runs.
+//! The spec is a comma-separated list of `key=value` pairs:
+//!
+//! - `fail`, `stall`, `malformed`: probability (0.0-1.0) of each fault per run
+//! - `stall_ms`: how long a stalled agent hangs before it is killed (default 5000)
+//! - `seed`: seeds the random draws so a run can be reproduced
+
+use anyhow::{Context, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Default time a stalled agent hangs before it is treated as unresponsive.
+const DEFAULT_STALL: Duration = Duration::from_secs(5);
+
+/// Output substituted for the agent's answer by a malformed-output fault.
+pub const MALFORMED_OUTPUT: &str = "{\"type\":\"result\",\"result\":\u{fffd}<<truncated";
+
+/// A fault applied to a single agent invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The invocation errors right after the process starts.
+    Fail,
+    /// The process produces no output until it is killed as unresponsive.
+    Stall,
+    /// The process runs normally but its final output is replaced with garbage.
+    Malformed,
+}
+
+/// Parsed `--fault-inject` spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultSpec {
+    pub fail: f64,
+    pub stall: f64,
+    pub malformed: f64,
+    pub stall_for: Duration,
+    pub seed: Option<u64>,
+}
+
+impl Default for FaultSpec {
+    fn default() -> Self {
+        Self {
+            fail: 0.0,
+            stall: 0.0,
+            malformed: 0.0,
+            stall_for: DEFAULT_STALL,
+            seed: None,
+        }
+    }
+}
+
+impl FromStr for FaultSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let mut parsed = FaultSpec::default();
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .with_context(|| format!("Expected key=value in fault spec, got '{}'", pair))?;
+            let value = value.trim();
+            match key.trim() {
+                "fail" => parsed.fail = parse_probability(key, value)?,
+                "stall" => parsed.stall = parse_probability(key, value)?,
+                "malformed" => parsed.malformed = parse_probability(key, value)?,
+                "stall_ms" => {
+                    let ms: u64 = value
+                        .parse()
+                        .with_context(|| format!("Invalid stall_ms '{}'", value))?;
+                    parsed.stall_for = Duration::from_millis(ms);
+                }
+                "seed" => {
+                    parsed.seed = Some(
+                        value
+                            .parse()
+                            .with_context(|| format!("Invalid seed '{}'", value))?,
+                    );
+                }
+                other => anyhow::bail!("Unknown fault spec key '{}'", other),
+            }
+        }
+        if parsed.fail + parsed.stall + parsed.malformed > 1.0 {
+            anyhow::bail!("Fault probabilities must not add up to more than 1.0");
+        }
+        Ok(parsed)
+    }
+}
+
+fn parse_probability(key: &str, value: &str) -> Result<f64> {
+    let p: f64 = value
+        .parse()
+        .with_context(|| format!("Invalid {} probability '{}'", key, value))?;
+    if !(0.0..=1.0).contains(&p) {
+        anyhow::bail!("{} probability must be between 0.0 and 1.0, got {}", key, p);
+    }
+    Ok(p)
+}
+
+/// Draws faults for agent invocations according to a [`FaultSpec`].
+#[derive(Debug, Clone)]
+pub struct FaultInjector {
+    spec: FaultSpec,
+    rng: Arc<Mutex<StdRng>>,
+}
+
+impl FaultInjector {
+    pub fn new(spec: FaultSpec) -> Self {
+        let rng = match spec.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            spec,
+            rng: Arc::new(Mutex::new(rng)),
+        }
+    }
+
+    /// How long a stalled agent hangs before it is killed.
+    pub fn stall_for(&self) -> Duration {
+        self.spec.stall_for
+    }
+
+    /// Picks the fault (if any) for the next invocation.
+    pub fn draw(&self) -> Option<Fault> {
+        let roll: f64 = self.rng.lock().ok()?.gen();
+        let spec = &self.spec;
+        if roll < spec.fail {
+            Some(Fault::Fail)
+        } else if roll < spec.fail + spec.stall {
+            Some(Fault::Stall)
+        } else if roll < spec.fail + spec.stall + spec.malformed {
+            Some(Fault::Malformed)
+        } else {
+            None
+        }
+    }
+}
+
+/// Injector built from `--fault-inject`, set once at startup.
+static CLI_INJECTOR: OnceLock<FaultInjector> = OnceLock::new();
+
+/// Enables `--fault-inject` for the agent contexts built from now on.
+pub fn enable_cli_fault_injection(spec: FaultSpec) {
+    let _ = CLI_INJECTOR.set(FaultInjector::new(spec));
+}
+
+/// Injector for a new agent context: the `--fault-inject` one, sharing its
+/// random draws so a seeded run is reproducible, or None.
+pub fn cli_fault_injector() -> Option<FaultInjector> {
+    CLI_INJECTOR.get().cloned()
+}
+
+#[cfg(test)]
+#[path = "tests/faults_tests.rs"]
+mod tests;
@@ -1,6 +1,8 @@
 use super::parser::GeminiParser;
 use crate::agents::log::AgentLogger;
+use crate::agents::pricing::model_hint;
 use crate::agents::prompt::PreparedPrompt;
+use crate::agents::remote::remote_command;
 use crate::agents::runner::{
     run_agent_process, ContextEmitter, EventEmitter, RunnerConfig, DEFAULT_ACTIVITY_TIMEOUT,
     DEFAULT_OVERALL_TIMEOUT,
@@ -33,11 +35,18 @@ impl GeminiAgent {
         }
     }
 
-    #[cfg(test)]
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    pub fn config(&self) -> &AgentConfig {
+        &self.config
+    }
+
+    pub(crate) fn config_mut(&mut self) -> &mut AgentConfig {
+        &mut self.config
+    }
+
     /// Execute with a centrally-prepared prompt.
     /// The PreparedPrompt already has system_prompt merged into the prompt for Gemini.
     pub async fn execute_streaming_with_prepared(
@@ -45,7 +54,7 @@ impl GeminiAgent {
         prepared: PreparedPrompt,
         context: AgentContext,
     ) -> Result<AgentResult> {
-        let emitter = ContextEmitter::new(context.clone(), self.name.clone());
+        let emitter = ContextEmitter::new(context.clone(), self.name.clone(), "gemini");
         self.execute_streaming_internal(prepared, &emitter, Some(&context))
             .await
     }
@@ -60,11 +69,16 @@ impl GeminiAgent {
         self.log_start(&logger, &prepared.prompt, context.is_some());
 
         let cmd = self.build_command(&prepared.prompt, context);
+        let cmd = remote_command(cmd, &self.config, &self.working_dir)?;
         let mut config = RunnerConfig::new(self.name.clone(), self.working_dir.clone())
             .with_activity_timeout(self.activity_timeout)
-            .with_overall_timeout(self.overall_timeout);
+            .with_overall_timeout(self.overall_timeout)
+            .with_memory_limit(self.config.memory_limit_mb);
         if let Some(ctx) = context {
             config = config.with_session_logger(ctx.session_logger.clone());
+            config = config.with_permission_prompts(self.config.permission_prompts);
+            config = config.with_pricing(ctx.pricing.clone(), model_hint(&self.config));
+            config = config.with_fault_injector(ctx.fault_injector.clone());
             if let Some(cancel_rx) = ctx.cancel_rx.clone() {
                 config = config.with_cancel_rx(cancel_rx);
             }
@@ -1,6 +1,16 @@
+use super::redaction::redact;
+use crate::planning_paths;
 use crate::session_daemon::SessionLogger;
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Stderr lines kept for the error overlay when an invocation fails.
+pub const STDERR_TAIL_LINES: usize = 10;
+
 /// Agent logger that uses SessionLogger for all agent output logging.
 pub struct AgentLogger {
     agent_name: String,
@@ -22,3 +32,78 @@ impl AgentLogger {
             .log_agent_stream(&self.agent_name, kind, line);
     }
 }
+
+/// Stderr of one agent invocation, kept apart from its stdout.
+///
+/// Lines are written to their own file under the session's `logs/stderr/`,
+/// created on the first line, and the last few are kept for error reports.
+pub struct StderrCapture {
+    path: Option<PathBuf>,
+    file: Option<File>,
+    tail: VecDeque<String>,
+    /// Session the redactions in the file are counted against
+    session_id: String,
+}
+
+impl StderrCapture {
+    /// Without a session logger only the tail is kept.
+    pub fn new(session_logger: Option<&Arc<SessionLogger>>, agent_name: &str) -> Self {
+        let path = session_logger
+            .and_then(|logger| planning_paths::session_logs_dir(logger.session_id()).ok())
+            .map(|dir| {
+                dir.join("stderr").join(format!(
+                    "{}-{}.log",
+                    agent_name,
+                    Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+                ))
+            });
+        Self {
+            path,
+            file: None,
+            tail: VecDeque::with_capacity(STDERR_TAIL_LINES),
+            session_id: session_logger
+                .map(|logger| logger.session_id().to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn push(&mut self, line: &str) {
+        if self.tail.len() == STDERR_TAIL_LINES {
+            self.tail.pop_front();
+        }
+        self.tail.push_back(line.to_string());
+
+        let Some(path) = &self.path else {
+            return;
+        };
+        if self.file.is_none() {
+            // Best-effort like the other agent logs: a file that can't be opened
+            // is given up on, the tail is still kept
+            self.file = path
+                .parent()
+                .and_then(|dir| fs::create_dir_all(dir).ok())
+                .and_then(|_| OpenOptions::new().create(true).append(true).open(path).ok());
+            if self.file.is_none() {
+                self.path = None;
+                return;
+            }
+        }
+        if let Some(file) = self.file.as_mut() {
+            let _ = writeln!(file, "{}", redact(line, &self.session_id));
+        }
+    }
+
+    /// The last stderr lines, oldest first.
+    pub fn tail(&self) -> Vec<String> {
+        self.tail.iter().cloned().collect()
+    }
+
+    /// The invocation's stderr file, once a line has been written to it.
+    pub fn log_path(&self) -> Option<&Path> {
+        self.file.as_ref().and(self.path.as_deref())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/log_tests.rs"]
+mod tests;
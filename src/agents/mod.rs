@@ -1,20 +1,34 @@
+pub mod capabilities;
 pub mod claude;
 pub mod codex;
+pub mod context_limits;
+pub mod cost_stats;
+pub mod faults;
 pub mod gemini;
 pub(crate) mod log;
+pub mod orphans;
+pub mod permissions;
+pub mod pricing;
+pub mod process;
 pub mod prompt;
 pub mod protocol;
+pub mod redaction;
+pub mod remote;
+pub mod resources;
 pub mod runner;
+pub mod streaming;
 
 use crate::config::AgentConfig;
 use crate::domain::types::ResumeStrategy;
 use crate::session_daemon::SessionLogger;
 use crate::tui::SessionEventSender;
 use anyhow::Result;
+use pricing::PricingTable;
 use prompt::{prepare_prompt, AgentCapabilities, PreparedPrompt, PromptRequest};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
-use std::sync::Arc;
-use tokio::sync::watch;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::{watch, OwnedSemaphorePermit, Semaphore};
 
 #[derive(Clone)]
 pub struct AgentContext {
@@ -27,6 +41,16 @@ pub struct AgentContext {
     pub cancel_rx: Option<watch::Receiver<bool>>,
     /// Session logger for agent events.
     pub session_logger: Arc<SessionLogger>,
+    /// Rates for estimating the cost when the agent doesn't report one.
+    pub pricing: PricingTable,
+    /// Maximum simultaneous invocations per provider (`provider_concurrency`).
+    pub provider_concurrency: BTreeMap<String, usize>,
+    /// Per-round cost thresholds by phase (`cost_alerts`).
+    pub cost_alerts: BTreeMap<String, f64>,
+    /// Context window warnings and mitigation (`context_limits`).
+    pub context_limits: context_limits::ContextLimitsConfig,
+    /// Faults to inject into this invocation (`--fault-inject`), if any.
+    pub fault_injector: Option<faults::FaultInjector>,
 }
 
 #[derive(Debug, Clone)]
@@ -39,6 +63,18 @@ pub struct AgentResult {
     pub stop_reason: Option<String>,
 }
 
+/// Helper to convert AgentOutput to AgentResult for compatibility.
+impl From<protocol::AgentOutput> for AgentResult {
+    fn from(output: protocol::AgentOutput) -> Self {
+        AgentResult {
+            output: output.output,
+            is_error: output.is_error,
+            conversation_id: output.conversation_id,
+            stop_reason: output.stop_reason,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum AgentType {
     Claude(claude::ClaudeAgent),
@@ -78,15 +114,24 @@ impl AgentType {
     }
 
     /// Returns true if this agent type supports conversation resume.
-    /// All agents (Claude, Codex, Gemini) support this feature:
+    /// All agents (Claude, Codex, Gemini) support this feature, unless the
+    /// installed CLI was probed without it:
     /// - Claude: uses --resume <conversation_id>
     /// - Codex: uses exec resume <thread_id> <prompt>
     /// - Gemini: uses --resume <session_id>
     pub fn supports_session_resume(&self) -> bool {
-        true
+        capabilities::supports_resume(self.name())
+    }
+
+    /// The CLI this agent runs, which `provider_concurrency` is keyed by.
+    fn provider(&self) -> &'static str {
+        match self {
+            Self::Claude(_) => "claude",
+            Self::Codex(_) => "codex",
+            Self::Gemini(_) => "gemini",
+        }
     }
 
-    #[cfg(test)]
     pub fn name(&self) -> &str {
         match self {
             Self::Claude(agent) => agent.name(),
@@ -118,11 +163,75 @@ impl AgentType {
         prompt: String,
         system_prompt: Option<String>,
         max_turns: Option<u32>,
-        context: AgentContext,
+        mut context: AgentContext,
     ) -> Result<AgentResult> {
+        if !self.supports_session_resume() {
+            context.resume_strategy = ResumeStrategy::Stateless;
+        }
+        let session_id = context.session_logger.session_id();
+        let prompt = redaction::redact(&prompt, session_id).into_owned();
+        let system_prompt =
+            system_prompt.map(|system| redaction::redact(&system, session_id).into_owned());
         // Prepare prompt centrally - handles system prompt merging for non-Claude agents
         let prepared = self.prepare_prompt(prompt, system_prompt, max_turns);
 
+        // Held until the agent finishes, so queued invocations start as slots free up
+        let _slot = match context.provider_concurrency.get(self.provider()) {
+            Some(&limit) => match self.wait_for_provider_slot(limit, &context).await {
+                Some(permit) => Some(permit),
+                None => {
+                    return Ok(AgentResult {
+                        output: String::new(),
+                        is_error: true,
+                        conversation_id: context.conversation_id,
+                        stop_reason: Some("cancelled".to_string()),
+                    })
+                }
+            },
+            None => None,
+        };
+
+        // Measured after the slot is held, as summarizing runs the agent too
+        let (switched, prepared) = self.fit_context_window(prepared, &context).await;
+        let agent = switched.as_ref().unwrap_or(self);
+
+        // An invocation killed for exceeding its memory limit is run again
+        let mut attempt = 0;
+        loop {
+            let result = agent
+                .execute_prepared(prepared.clone(), context.clone())
+                .await;
+            if let Ok(result) = &result {
+                context_limits::log_response_size(agent, &context, result);
+            }
+            let exceeded = match &result {
+                Err(e) if attempt < resources::MEMORY_LIMIT_RETRIES => {
+                    e.downcast_ref::<resources::ResourceLimitExceeded>()
+                }
+                _ => None,
+            };
+            let Some(exceeded) = exceeded else {
+                return result;
+            };
+            attempt += 1;
+            let sender = &context.session_sender;
+            sender.send_output(format!(
+                "[agent:{}] Retrying after the memory limit was exceeded ({}/{})",
+                self.name(),
+                attempt,
+                resources::MEMORY_LIMIT_RETRIES
+            ));
+            if let Some(failure) = exceeded.retried_failure(&context.phase, attempt) {
+                sender.record_failure(failure);
+            }
+        }
+    }
+
+    async fn execute_prepared(
+        &self,
+        prepared: PreparedPrompt,
+        context: AgentContext,
+    ) -> Result<AgentResult> {
         match self {
             Self::Claude(agent) => {
                 agent
@@ -141,6 +250,61 @@ impl AgentType {
             }
         }
     }
+
+    /// Waits for one of the `limit` slots of this agent's provider, shared by all
+    /// tabs. A queued invocation shows in the CLI instances panel until it gets a
+    /// slot. Returns None if the invocation is cancelled while waiting.
+    async fn wait_for_provider_slot(
+        &self,
+        limit: usize,
+        context: &AgentContext,
+    ) -> Option<OwnedSemaphorePermit> {
+        let semaphore = provider_semaphore(self.provider(), limit);
+        if let Ok(permit) = semaphore.clone().try_acquire_owned() {
+            return Some(permit);
+        }
+
+        let sender = &context.session_sender;
+        let id = sender.next_cli_instance_id();
+        sender.send_cli_instance_queued(id, self.name().to_string());
+        sender.send_output(format!(
+            "[agent:{}] Waiting for a {} slot ({} in use)",
+            self.name(),
+            self.provider(),
+            limit
+        ));
+        let mut cancel_rx = context.cancel_rx.clone();
+        let permit = tokio::select! {
+            permit = semaphore.acquire_owned() => permit.ok(),
+            _ = runner::wait_for_cancel(&mut cancel_rx) => None,
+        };
+        sender.send_cli_instance_finished(id);
+        permit
+    }
+}
+
+/// Semaphores limiting each provider's concurrent invocations across all tabs,
+/// with the limit each was created for.
+fn provider_slots() -> &'static Mutex<HashMap<String, (usize, Arc<Semaphore>)>> {
+    static SLOTS: OnceLock<Mutex<HashMap<String, (usize, Arc<Semaphore>)>>> = OnceLock::new();
+    SLOTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The shared semaphore for `provider`. A changed limit replaces it; invocations
+/// holding slots of the old one finish unaffected.
+fn provider_semaphore(provider: &str, limit: usize) -> Arc<Semaphore> {
+    let Ok(mut slots) = provider_slots().lock() else {
+        // A poisoned registry stops limiting rather than blocking every agent
+        return Arc::new(Semaphore::new(limit.max(1)));
+    };
+    match slots.get(provider) {
+        Some((current, semaphore)) if *current == limit => semaphore.clone(),
+        _ => {
+            let semaphore = Arc::new(Semaphore::new(limit.max(1)));
+            slots.insert(provider.to_string(), (limit, semaphore.clone()));
+            semaphore
+        }
+    }
 }
 
 #[cfg(test)]
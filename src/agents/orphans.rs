@@ -0,0 +1,317 @@
+//! Tracking and reaping of agent processes left behind by a dead session.
+//!
+//! Each agent process is recorded in its session's `agent-processes.json` while it
+//! runs, along with the PID of the planning process that owns it. If the owner is
+//! killed hard, the entry outlives it: the next TUI start finds agents whose owner
+//! is gone and offers to kill them, and `--kill-orphans` kills them directly.
+//!
+//! On Linux, agents are additionally spawned with a parent-death signal so the
+//! kernel kills them when the owner dies, before they become orphans at all.
+
+use crate::planning_paths;
+use anyhow::{Context, Result};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Registry file in each session directory.
+pub const AGENT_PROCESSES_FILE: &str = "agent-processes.json";
+
+/// Time a terminated orphan gets to exit before it is killed outright.
+const TERMINATE_GRACE: Duration = Duration::from_secs(2);
+
+/// A running agent process recorded in a session's registry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentProcessRecord {
+    pub pid: u32,
+    pub agent_name: String,
+    /// PID of the planning process that spawned the agent.
+    pub owner_pid: u32,
+    /// Kernel start time of the agent, guarding against PID reuse (Linux only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_ticks: Option<u64>,
+    /// Process group the agent leads with the tools it started (Unix only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pgid: Option<u32>,
+    pub started_at: String,
+}
+
+impl AgentProcessRecord {
+    /// Record for an agent just spawned by this process.
+    pub fn new(pid: u32, agent_name: &str) -> Self {
+        Self {
+            pid,
+            agent_name: agent_name.to_string(),
+            owner_pid: std::process::id(),
+            start_ticks: process_start_ticks(pid),
+            pgid: led_process_group(pid),
+            started_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Signals the agent's process group, or only the agent if it leads none.
+    fn signal(&self, force: bool) {
+        match self.pgid {
+            Some(pgid) => signal_process(-(pgid as i32), force),
+            None => signal_process(self.pid as i32, force),
+        }
+    }
+
+    /// Whether the recorded process is still the agent that was spawned.
+    fn is_running(&self) -> bool {
+        process_alive(self.pid)
+            && match (self.start_ticks, process_start_ticks(self.pid)) {
+                (Some(recorded), Some(current)) => recorded == current,
+                _ => true,
+            }
+    }
+}
+
+/// An agent process whose owning planning process is gone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrphanedAgent {
+    pub session_id: String,
+    pub record: AgentProcessRecord,
+}
+
+/// Reads, edits and rewrites a registry file under an exclusive lock.
+fn update_registry<T>(
+    path: &Path,
+    edit: impl FnOnce(&mut Vec<AgentProcessRecord>) -> T,
+) -> Result<T> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .with_context(|| format!("Failed to open agent registry: {}", path.display()))?;
+    file.lock_exclusive()
+        .context("Failed to lock agent registry")?;
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .context("Failed to read agent registry")?;
+    // An unreadable registry only loses tracking; start over rather than fail the run
+    let mut records: Vec<AgentProcessRecord> = serde_json::from_str(&content).unwrap_or_default();
+    let result = edit(&mut records);
+
+    let content = serde_json::to_string_pretty(&records)?;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(content.as_bytes())
+        .context("Failed to write agent registry")?;
+    Ok(result)
+}
+
+/// Registry entry removed again when the agent exits or its run is dropped.
+#[derive(Debug)]
+pub struct TrackedAgentProcess {
+    registry: PathBuf,
+    pid: u32,
+}
+
+impl TrackedAgentProcess {
+    /// Records the agent in its session's registry.
+    pub fn register(session_id: &str, pid: u32, agent_name: &str) -> Result<Self> {
+        let registry = planning_paths::session_agent_processes_path(session_id)?;
+        Self::register_at(registry, AgentProcessRecord::new(pid, agent_name))
+    }
+
+    fn register_at(registry: PathBuf, record: AgentProcessRecord) -> Result<Self> {
+        let pid = record.pid;
+        update_registry(&registry, |records| {
+            records.retain(|r| r.pid != pid);
+            records.push(record);
+        })?;
+        Ok(Self { registry, pid })
+    }
+}
+
+impl Drop for TrackedAgentProcess {
+    fn drop(&mut self) {
+        let pid = self.pid;
+        // Best-effort: a stale entry is pruned by the next orphan scan
+        let _ = update_registry(&self.registry, |records| records.retain(|r| r.pid != pid));
+    }
+}
+
+/// Finds agents whose owner has exited, across all sessions.
+///
+/// Entries for agents that have already exited are pruned along the way.
+pub fn find_orphaned_agents() -> Result<Vec<OrphanedAgent>> {
+    let mut orphans = Vec::new();
+    for entry in fs::read_dir(planning_paths::sessions_dir()?)?.flatten() {
+        let registry = entry.path().join(AGENT_PROCESSES_FILE);
+        if !registry.exists() {
+            continue;
+        }
+        let session_id = entry.file_name().to_string_lossy().to_string();
+        let found = update_registry(&registry, |records| {
+            records.retain(AgentProcessRecord::is_running);
+            records
+                .iter()
+                .filter(|r| !process_alive(r.owner_pid))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        match found {
+            Ok(found) => orphans.extend(found.into_iter().map(|record| OrphanedAgent {
+                session_id: session_id.clone(),
+                record,
+            })),
+            Err(e) => tracing::warn!("Skipping agent registry {}: {}", registry.display(), e),
+        }
+    }
+    Ok(orphans)
+}
+
+/// Terminates the orphaned agents, escalating to SIGKILL for any that linger.
+///
+/// Returns the number of agents that are no longer running.
+pub async fn kill_orphaned_agents(orphans: &[OrphanedAgent]) -> usize {
+    let running: Vec<&OrphanedAgent> = orphans.iter().filter(|o| o.record.is_running()).collect();
+    for orphan in &running {
+        orphan.record.signal(false);
+    }
+    let deadline = tokio::time::Instant::now() + TERMINATE_GRACE;
+    while running.iter().any(|o| o.record.is_running()) && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    for orphan in &running {
+        // Tools the agent started may outlive it in its group
+        if orphan.record.pgid.is_some() || orphan.record.is_running() {
+            orphan.record.signal(true);
+        }
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    for orphan in orphans {
+        if let Ok(registry) = planning_paths::session_agent_processes_path(&orphan.session_id) {
+            let pid = orphan.record.pid;
+            let _ = update_registry(&registry, |records| records.retain(|r| r.pid != pid));
+        }
+    }
+    orphans.iter().filter(|o| !o.record.is_running()).count()
+}
+
+/// Asks the kernel to kill the agent when this process dies (Linux only).
+///
+/// The signal follows the spawning thread; agents are spawned from runtime worker
+/// threads, which live as long as the process.
+pub fn kill_with_parent(command: &mut Command) {
+    #[cfg(target_os = "linux")]
+    {
+        let parent = std::process::id() as nix::libc::pid_t;
+        // SAFETY: only async-signal-safe syscalls run between fork and exec
+        unsafe {
+            command.pre_exec(move || {
+                nix::libc::prctl(
+                    nix::libc::PR_SET_PDEATHSIG,
+                    nix::libc::SIGKILL as nix::libc::c_ulong,
+                );
+                // The parent may have died before the signal was armed
+                if nix::libc::getppid() != parent {
+                    nix::libc::_exit(1);
+                }
+                Ok(())
+            });
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = command;
+    }
+}
+
+/// Makes the agent lead a new process group (Unix only), so that it can be
+/// paused or signalled together with the tool subprocesses it starts.
+pub fn own_process_group(command: &mut Command) {
+    #[cfg(unix)]
+    command.process_group(0);
+    #[cfg(not(unix))]
+    {
+        let _ = command;
+    }
+}
+
+/// Kills the agent together with the tool subprocesses in its process group.
+pub async fn kill_agent(child: &mut tokio::process::Child) {
+    if let Some(pid) = child.id() {
+        kill_process_group(pid);
+    }
+    let _ = child.kill().await;
+}
+
+/// Kills every process left in the group the agent `pid` leads (Unix only).
+pub fn kill_process_group(pid: u32) {
+    signal_process(-(pid as i32), true);
+}
+
+/// The process group `pid` leads, if it leads one (Unix only). A process in
+/// someone else's group, such as planning's own, is never signalled as a group.
+fn led_process_group(pid: u32) -> Option<u32> {
+    #[cfg(unix)]
+    {
+        let pgid = unsafe { nix::libc::getpgid(pid as nix::libc::pid_t) };
+        (pgid > 0 && pgid as u32 == pid).then_some(pid)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Signals a process, or with a negative target the process group `-target`.
+fn signal_process(target: i32, force: bool) {
+    #[cfg(unix)]
+    {
+        let signal = if force {
+            nix::libc::SIGKILL
+        } else {
+            nix::libc::SIGTERM
+        };
+        // Failure means the process already exited
+        unsafe {
+            nix::libc::kill(target as nix::libc::pid_t, signal);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (target, force);
+    }
+}
+
+/// Whether a process exists and has not exited (zombies count as exited).
+pub fn process_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        if let Ok(stat) = fs::read_to_string(format!("/proc/{}/stat", pid)) {
+            return !stat.contains(") Z ");
+        }
+        let result = unsafe { nix::libc::kill(pid as nix::libc::pid_t, 0) };
+        result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(nix::libc::EPERM)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        false
+    }
+}
+
+/// Process start time in clock ticks since boot, from `/proc/<pid>/stat`.
+fn process_start_ticks(pid: u32) -> Option<u64> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // Fields after the parenthesised command name; starttime is field 22 overall
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    fields.get(19)?.parse().ok()
+}
+
+#[cfg(test)]
+#[path = "tests/orphans_tests.rs"]
+mod tests;
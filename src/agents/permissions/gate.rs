@@ -0,0 +1,236 @@
+//! Permission gate for agent CLIs that run a hook before each tool call.
+//!
+//! Claude runs a `PreToolUse` hook command before every Bash, WebFetch or
+//! WebSearch call. The hook is this binary with `--permission-hook <socket>`: it
+//! classifies the call and, for a high-risk one, asks the runner over the socket
+//! of the agent invocation and waits for the user's answer. A denied call is
+//! blocked before it runs (the hook exits with status 2 and Claude is told why).
+//! A hook that cannot reach the runner denies the call.
+
+use super::{classify_tool_call, ToolRisk};
+use crate::app::util::shell_quote_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+/// Claude tools the hook is registered for; no other Claude tool is high-risk.
+const HOOKED_TOOLS: &str = "Bash|WebFetch|WebSearch";
+
+/// Exit status with which a `PreToolUse` hook blocks the tool call.
+const HOOK_BLOCK_STATUS: i32 = 2;
+
+/// Seconds Claude waits for the hook, long enough for the user to answer.
+const HOOK_TIMEOUT_SECS: u64 = 86_400;
+
+/// Distinguishes the sockets of concurrent agent invocations.
+static NEXT_GATE: AtomicU64 = AtomicU64::new(0);
+
+/// A tool call the hook asks about, as sent over the socket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookQuery {
+    pub display_name: String,
+    pub input_preview: String,
+}
+
+impl HookQuery {
+    /// The tool call of a `PreToolUse` hook input.
+    pub fn from_hook_input(input: &serde_json::Value) -> Self {
+        let tool = input
+            .get("tool_name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default();
+        Self {
+            display_name: tool.to_string(),
+            input_preview: input
+                .get("tool_input")
+                .map(|tool_input| tool_input.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn risk(&self) -> Option<ToolRisk> {
+        classify_tool_call(&self.display_name, &self.input_preview)
+    }
+}
+
+/// A high-risk tool call the hook is holding back until it is answered.
+#[derive(Debug)]
+pub struct GateRequest {
+    pub risk: ToolRisk,
+    pub query: HookQuery,
+    reply: oneshot::Sender<bool>,
+}
+
+impl GateRequest {
+    /// Lets the call run, or blocks it.
+    pub fn answer(self, allow: bool) {
+        // The hook may have given up waiting; the call is then blocked anyway
+        let _ = self.reply.send(allow);
+    }
+}
+
+/// Socket that the permission hook of one agent invocation asks.
+pub struct PermissionGate {
+    path: PathBuf,
+    requests: Mutex<mpsc::Receiver<GateRequest>>,
+    server: tokio::task::JoinHandle<()>,
+}
+
+impl PermissionGate {
+    /// Listens on a fresh owner-only socket in the temp directory.
+    #[cfg(unix)]
+    pub fn bind() -> Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "planning-gate-{}-{}.sock",
+            std::process::id(),
+            NEXT_GATE.fetch_add(1, Ordering::Relaxed)
+        ));
+        let listener = crate::rpc::local_socket::bind(&path)
+            .with_context(|| format!("Failed to listen on {}", path.display()))?;
+        let (tx, rx) = mpsc::channel(8);
+        Ok(Self {
+            path,
+            requests: Mutex::new(rx),
+            server: tokio::spawn(serve(listener, tx)),
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn bind() -> Result<Self> {
+        let _ = NEXT_GATE.load(Ordering::Relaxed);
+        anyhow::bail!("permission hooks need Unix domain sockets")
+    }
+
+    /// `--settings` value that registers the hook for this gate.
+    pub fn hook_settings(&self) -> Result<String> {
+        let exe = std::env::current_exe().context("Failed to find planning binary")?;
+        let command = format!(
+            "{} --permission-hook {}",
+            shell_quote_path(&exe),
+            shell_quote_path(&self.path)
+        );
+        let settings = serde_json::json!({
+            "hooks": {
+                "PreToolUse": [{
+                    "matcher": HOOKED_TOOLS,
+                    "hooks": [{
+                        "type": "command",
+                        "command": command,
+                        "timeout": HOOK_TIMEOUT_SECS,
+                    }],
+                }],
+            },
+        });
+        Ok(settings.to_string())
+    }
+
+    /// The next high-risk call waiting for a decision.
+    pub async fn next_request(&self) -> Option<GateRequest> {
+        self.requests.lock().await.recv().await
+    }
+}
+
+/// The next call held back by `gate`; never resolves without a gate.
+pub async fn next_gate_request(gate: &Option<Arc<PermissionGate>>) -> Option<GateRequest> {
+    match gate {
+        Some(gate) => gate.next_request().await,
+        None => std::future::pending().await,
+    }
+}
+
+impl Drop for PermissionGate {
+    fn drop(&mut self) {
+        self.server.abort();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(unix)]
+async fn serve(listener: tokio::net::UnixListener, requests: mpsc::Sender<GateRequest>) {
+    let uid = crate::rpc::local_socket::current_uid();
+    while let Ok((stream, _)) = listener.accept().await {
+        // Only this user's processes may ask; the socket is owner-only as well
+        if crate::rpc::local_socket::peer_uid(&stream).ok() == Some(uid) {
+            tokio::spawn(answer_hook(stream, requests.clone()));
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn answer_hook(stream: tokio::net::UnixStream, requests: mpsc::Sender<GateRequest>) {
+    let (read, mut write) = stream.into_split();
+    let mut line = String::new();
+    if BufReader::new(read).read_line(&mut line).await.is_err() {
+        return;
+    }
+    let allow = match serde_json::from_str::<HookQuery>(&line) {
+        Ok(query) => match query.risk() {
+            None => true,
+            Some(risk) => {
+                let (reply, decision) = oneshot::channel();
+                let request = GateRequest { risk, query, reply };
+                // A runner that no longer listens cannot ask the user, so the call is blocked
+                requests.send(request).await.is_ok() && decision.await.unwrap_or(false)
+            }
+        },
+        Err(_) => false,
+    };
+    let answer = if allow { "allow\n" } else { "deny\n" };
+    let _ = write.write_all(answer.as_bytes()).await;
+}
+
+/// Runs as Claude's `PreToolUse` hook (`--permission-hook <socket>`).
+///
+/// Reads the tool call from stdin. Low-risk calls pass at once; a high-risk one
+/// waits for the user and exits with status 2, which blocks it, unless allowed.
+pub async fn run_permission_hook(socket: &Path) -> Result<()> {
+    let mut input = String::new();
+    tokio::io::stdin().read_to_string(&mut input).await?;
+    let Ok(input) = serde_json::from_str::<serde_json::Value>(&input) else {
+        block("planning could not read the tool call, so it is blocked.");
+    };
+    let query = HookQuery::from_hook_input(&input);
+    if query.risk().is_none() {
+        return Ok(());
+    }
+    match ask_gate(socket, &query).await {
+        Ok(true) => Ok(()),
+        Ok(false) => block("The user denied this tool call. Do not retry it."),
+        Err(e) => block(&format!(
+            "planning could not ask the user about this tool call ({:#}), so it is blocked.",
+            e
+        )),
+    }
+}
+
+/// Ends the hook, blocking the call and telling the agent why.
+fn block(reason: &str) -> ! {
+    eprintln!("{}", reason);
+    std::process::exit(HOOK_BLOCK_STATUS)
+}
+
+#[cfg(unix)]
+async fn ask_gate(socket: &Path, query: &HookQuery) -> Result<bool> {
+    let stream = tokio::net::UnixStream::connect(socket)
+        .await
+        .with_context(|| format!("Failed to connect to {}", socket.display()))?;
+    let (read, mut write) = stream.into_split();
+    let line = format!("{}\n", serde_json::to_string(query)?);
+    write.write_all(line.as_bytes()).await?;
+    let mut answer = String::new();
+    BufReader::new(read).read_line(&mut answer).await?;
+    Ok(answer.trim() == "allow")
+}
+
+#[cfg(not(unix))]
+async fn ask_gate(_socket: &Path, _query: &HookQuery) -> Result<bool> {
+    anyhow::bail!("permission hooks need Unix domain sockets")
+}
+
+#[cfg(test)]
+#[path = "tests/gate_tests.rs"]
+mod tests;
@@ -0,0 +1,273 @@
+//! Interactive permission prompts for high-risk agent tool calls.
+//!
+//! Agents run with a static `allowed_tools` list, which cannot distinguish a
+//! harmless `git status` from a `git push`. When an agent has
+//! `permission_prompts` enabled, risky tool calls are classified and the user is
+//! asked to allow or deny them.
+//!
+//! Local Claude agents are gated before a call runs, through a `PreToolUse` hook
+//! (see [`gate`]), so a denied call never starts. Other agents only report a call
+//! in their stream once it has started: the runner then stops the agent's whole
+//! process group (Unix) while the prompt is open, and a denial stops the agent,
+//! but it cannot undo what the call already did.
+
+pub mod gate;
+
+use crate::agents::log::AgentLogger;
+use crate::agents::runner::{wait_for_cancel, EventEmitter, RunnerConfig};
+use gate::GateRequest;
+use tokio::sync::{mpsc, watch};
+
+/// Category of a high-risk tool call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolRisk {
+    FileDeletion,
+    GitPush,
+    Network,
+}
+
+impl ToolRisk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ToolRisk::FileDeletion => "file deletion",
+            ToolRisk::GitPush => "git push",
+            ToolRisk::Network => "network access",
+        }
+    }
+}
+
+/// The user's answer to a permission prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolPermissionDecision {
+    Allow,
+    /// Allow this call and every later call of the same risk in the session
+    AllowForSession,
+    Deny,
+}
+
+/// A high-risk tool call to ask the user about.
+#[derive(Debug, Clone)]
+pub struct ToolPermissionPrompt {
+    pub risk: ToolRisk,
+    pub display_name: String,
+    pub input_preview: String,
+}
+
+/// A pending permission prompt, carrying the channel used to answer it.
+#[derive(Debug, Clone)]
+pub struct ToolPermissionRequest {
+    pub agent_name: String,
+    pub phase: String,
+    pub risk: ToolRisk,
+    pub display_name: String,
+    pub input_preview: String,
+    pub responder: mpsc::Sender<ToolPermissionDecision>,
+}
+
+impl ToolPermissionRequest {
+    /// Sends the decision back to the waiting agent runner.
+    pub fn respond(&self, decision: ToolPermissionDecision) {
+        // A full or closed channel means the agent already finished or was cancelled
+        let _ = self.responder.try_send(decision);
+    }
+}
+
+/// Shell commands that delete files.
+const DELETION_COMMANDS: &[&str] = &["rm", "rmdir", "unlink", "shred", "git clean"];
+
+/// Shell commands that reach the network.
+const NETWORK_COMMANDS: &[&str] = &["curl", "wget", "ssh", "scp", "rsync", "nc", "ftp", "telnet"];
+
+/// Tool names (lowercased) that delete files.
+const DELETION_TOOLS: &[&str] = &["delete_file", "deletefile", "remove_file"];
+
+/// Tool names (lowercased) that reach the network.
+const NETWORK_TOOLS: &[&str] = &[
+    "webfetch",
+    "websearch",
+    "web_fetch",
+    "web_search",
+    "google_web_search",
+];
+
+/// Returns true if `command` appears in `haystack` as a standalone command word.
+///
+/// The match must start the haystack or follow a separator (whitespace, quote,
+/// `;`, `&`, `|`, `(` or a backtick) and be followed by whitespace, a quote or
+/// the end, so `rm` does not match `format` or `rm_cache`.
+fn contains_command(haystack: &str, command: &str) -> bool {
+    let is_separator = |c: char| c.is_whitespace() || "\"'`;&|(".contains(c);
+    haystack.match_indices(command).any(|(start, _)| {
+        let before_ok = haystack[..start]
+            .chars()
+            .next_back()
+            .is_none_or(is_separator);
+        let after_ok = haystack[start + command.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| c.is_whitespace() || c == '"' || c == '\'');
+        before_ok && after_ok
+    })
+}
+
+/// Classifies a tool call from its display name and input preview.
+///
+/// Returns None for calls that are not considered high-risk.
+pub fn classify_tool_call(display_name: &str, input_preview: &str) -> Option<ToolRisk> {
+    let name = display_name.trim().to_lowercase();
+    let haystack = format!("{} {}", display_name, input_preview).to_lowercase();
+
+    if contains_command(&haystack, "git push") {
+        return Some(ToolRisk::GitPush);
+    }
+    if DELETION_TOOLS.contains(&name.as_str())
+        || DELETION_COMMANDS
+            .iter()
+            .any(|cmd| contains_command(&haystack, cmd))
+        || (contains_command(&haystack, "find") && haystack.contains("-delete"))
+    {
+        return Some(ToolRisk::FileDeletion);
+    }
+    if NETWORK_TOOLS.contains(&name.as_str())
+        || NETWORK_COMMANDS
+            .iter()
+            .any(|cmd| contains_command(&haystack, cmd))
+    {
+        return Some(ToolRisk::Network);
+    }
+    None
+}
+
+/// Suspends the agent and the tool subprocesses it started while a permission
+/// prompt is open. Agents lead their own process group (see
+/// `orphans::own_process_group`), so the whole group is stopped.
+///
+/// Returns true if the group was paused. Only supported on Unix; elsewhere
+/// the agent keeps running while the prompt is shown.
+pub fn pause_process(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        unsafe { nix::libc::kill(-(pid as nix::libc::pid_t), nix::libc::SIGSTOP) == 0 }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        false
+    }
+}
+
+/// Resumes an agent process group paused by [`pause_process`].
+pub fn resume_process(pid: u32) {
+    #[cfg(unix)]
+    {
+        // Failure means the group already exited; there is nothing to resume
+        unsafe {
+            nix::libc::kill(-(pid as nix::libc::pid_t), nix::libc::SIGCONT);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+    }
+}
+
+/// Asks the user whether a high-risk tool call may proceed, pausing the agent
+/// with `pid` meanwhile.
+///
+/// The agent is resumed before returning. Returns None when cancellation was
+/// requested while the prompt was open; the caller's cancel branch handles it.
+pub(crate) async fn await_tool_permission(
+    config: &RunnerConfig,
+    logger: &Option<AgentLogger>,
+    emitter: &dyn EventEmitter,
+    pid: Option<u32>,
+    prompt: ToolPermissionPrompt,
+    cancel_rx: &mut Option<watch::Receiver<bool>>,
+) -> Option<ToolPermissionDecision> {
+    let paused_pid = pid.filter(|&pid| pause_process(pid));
+    if let Some(ref logger) = logger {
+        logger.log_line(
+            "permission",
+            &format!(
+                "requested for {}: {}",
+                prompt.risk.label(),
+                prompt.display_name
+            ),
+        );
+    }
+    emitter.send_output(format!(
+        "[agent:{}] Waiting for permission: {} ({})",
+        config.agent_name,
+        prompt.risk.label(),
+        prompt.display_name
+    ));
+
+    let mut decision_rx = emitter.send_tool_permission_request(prompt);
+    let decision = tokio::select! {
+        // A dropped prompt (e.g. the tab was closed) counts as a denial
+        decision = decision_rx.recv() => Some(decision.unwrap_or(ToolPermissionDecision::Deny)),
+        _ = wait_for_cancel(cancel_rx) => None,
+    };
+
+    if let Some(pid) = paused_pid {
+        resume_process(pid);
+    }
+    if let (Some(ref logger), Some(decision)) = (logger, decision) {
+        logger.log_line("permission", &format!("decision: {:?}", decision));
+    }
+    decision
+}
+
+/// Asks about a high-risk call the agent reported as started, pausing the
+/// agent with `pid` meanwhile. Returns the risk and name of a denied call.
+pub(crate) async fn review_started_tool(
+    config: &RunnerConfig,
+    logger: &Option<AgentLogger>,
+    emitter: &dyn EventEmitter,
+    pid: Option<u32>,
+    (display_name, input_preview): (String, String),
+    cancel_rx: &mut Option<watch::Receiver<bool>>,
+) -> Option<(ToolRisk, String)> {
+    let risk = classify_tool_call(&display_name, &input_preview)?;
+    let prompt = ToolPermissionPrompt {
+        risk,
+        display_name: display_name.clone(),
+        input_preview,
+    };
+    let decision = await_tool_permission(config, logger, emitter, pid, prompt, cancel_rx).await;
+    (decision == Some(ToolPermissionDecision::Deny)).then_some((risk, display_name))
+}
+
+/// Asks the user about a call the permission hook is holding back, then lets
+/// the hook run or block it. The agent keeps running meanwhile.
+pub(crate) async fn answer_gate_request(
+    config: &RunnerConfig,
+    logger: &Option<AgentLogger>,
+    emitter: &dyn EventEmitter,
+    request: GateRequest,
+    cancel_rx: &mut Option<watch::Receiver<bool>>,
+) {
+    let prompt = ToolPermissionPrompt {
+        risk: request.risk,
+        display_name: request.query.display_name.clone(),
+        input_preview: request.query.input_preview.clone(),
+    };
+    let decision = await_tool_permission(config, logger, emitter, None, prompt, cancel_rx).await;
+    if decision == Some(ToolPermissionDecision::Deny) {
+        emitter.send_output(format!(
+            "[agent:{}] Denied {} ({}) - the call was blocked",
+            config.agent_name,
+            request.risk.label(),
+            request.query.display_name
+        ));
+    }
+    // A cancelled prompt blocks the call; the runner stops the agent
+    request.answer(matches!(
+        decision,
+        Some(ToolPermissionDecision::Allow | ToolPermissionDecision::AllowForSession)
+    ));
+}
+
+#[cfg(test)]
+#[path = "tests/permissions_tests.rs"]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn test_hook_query_from_hook_input() {
+    let input = serde_json::json!({
+        "hook_event_name": "PreToolUse",
+        "tool_name": "Bash",
+        "tool_input": { "command": "git push origin main" },
+    });
+    let query = HookQuery::from_hook_input(&input);
+    assert_eq!(query.display_name, "Bash");
+    assert!(query.input_preview.contains("git push origin main"));
+    assert_eq!(query.risk(), Some(ToolRisk::GitPush));
+
+    let query = HookQuery::from_hook_input(&serde_json::json!({}));
+    assert_eq!(query.display_name, "");
+    assert_eq!(query.risk(), None);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_gate_holds_risky_calls_until_answered() {
+    let gate = PermissionGate::bind().unwrap();
+    let settings: serde_json::Value = serde_json::from_str(&gate.hook_settings().unwrap()).unwrap();
+    let hook = &settings["hooks"]["PreToolUse"][0];
+    assert_eq!(hook["matcher"], HOOKED_TOOLS);
+    assert!(hook["hooks"][0]["command"]
+        .as_str()
+        .unwrap()
+        .contains("--permission-hook"));
+
+    let query = HookQuery {
+        display_name: "Bash".to_string(),
+        input_preview: r#"{"command":"git push"}"#.to_string(),
+    };
+    let asked = tokio::spawn({
+        let socket = gate.path.clone();
+        let query = query.clone();
+        async move { ask_gate(&socket, &query).await.unwrap() }
+    });
+    let request = gate.next_request().await.unwrap();
+    assert_eq!(request.risk, ToolRisk::GitPush);
+    assert_eq!(request.query, query);
+    request.answer(false);
+    assert!(!asked.await.unwrap());
+
+    // Calls the hook does not consider risky pass without asking
+    let safe = HookQuery {
+        display_name: "Bash".to_string(),
+        input_preview: r#"{"command":"ls"}"#.to_string(),
+    };
+    assert!(ask_gate(&gate.path, &safe).await.unwrap());
+
+    let socket = gate.path.clone();
+    drop(gate);
+    assert!(!socket.exists());
+}
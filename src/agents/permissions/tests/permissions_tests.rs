@@ -0,0 +1,98 @@
+use super::*;
+
+#[test]
+fn test_classify_git_push() {
+    assert_eq!(
+        classify_tool_call("git", r#"{"command":"git push origin main"}"#),
+        Some(ToolRisk::GitPush)
+    );
+    assert_eq!(
+        classify_tool_call("bash -lc 'git push --force'", ""),
+        Some(ToolRisk::GitPush)
+    );
+}
+
+#[test]
+fn test_classify_file_deletion() {
+    assert_eq!(
+        classify_tool_call("rm", r#"{"command":"rm -rf target"}"#),
+        Some(ToolRisk::FileDeletion)
+    );
+    assert_eq!(
+        classify_tool_call("cd", r#"{"command":"cd src && rmdir old"}"#),
+        Some(ToolRisk::FileDeletion)
+    );
+    assert_eq!(
+        classify_tool_call("find", r#"{"command":"find . -name '*.tmp' -delete"}"#),
+        Some(ToolRisk::FileDeletion)
+    );
+    assert_eq!(
+        classify_tool_call("delete_file", r#"{"path":"a.rs"}"#),
+        Some(ToolRisk::FileDeletion)
+    );
+}
+
+#[test]
+fn test_classify_network() {
+    assert_eq!(
+        classify_tool_call("curl", r#"{"command":"curl https://example.com"}"#),
+        Some(ToolRisk::Network)
+    );
+    assert_eq!(
+        classify_tool_call("WebFetch", r#"{"url":"https://example.com"}"#),
+        Some(ToolRisk::Network)
+    );
+    assert_eq!(
+        classify_tool_call("google_web_search", r#"{"query":"rust"}"#),
+        Some(ToolRisk::Network)
+    );
+}
+
+#[test]
+fn test_classify_safe_calls() {
+    assert_eq!(
+        classify_tool_call("Read", r#"{"file_path":"src/main.rs"}"#),
+        None
+    );
+    assert_eq!(
+        classify_tool_call("cargo", r#"{"command":"cargo fmt"}"#),
+        None
+    );
+    assert_eq!(
+        classify_tool_call("git", r#"{"command":"git status"}"#),
+        None
+    );
+    assert_eq!(
+        classify_tool_call("Grep", r#"{"pattern":"rm_cache"}"#),
+        None
+    );
+}
+
+#[test]
+fn test_contains_command_requires_word_boundaries() {
+    assert!(contains_command("rm -rf x", "rm"));
+    assert!(contains_command("\"rm\"", "rm"));
+    assert!(contains_command("a;rm b", "rm"));
+    assert!(!contains_command("format", "rm"));
+    assert!(!contains_command("rmx", "rm"));
+}
+
+#[tokio::test]
+async fn test_request_respond_delivers_decision() {
+    let (tx, mut rx) = mpsc::channel(1);
+    let request = ToolPermissionRequest {
+        agent_name: "claude".to_string(),
+        phase: "Planning".to_string(),
+        risk: ToolRisk::Network,
+        display_name: "curl".to_string(),
+        input_preview: String::new(),
+        responder: tx,
+    };
+    request.respond(ToolPermissionDecision::AllowForSession);
+    // A second answer is dropped rather than blocking
+    request.respond(ToolPermissionDecision::Deny);
+    assert_eq!(
+        rx.recv().await,
+        Some(ToolPermissionDecision::AllowForSession)
+    );
+}
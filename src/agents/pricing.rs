@@ -0,0 +1,134 @@
+//! Local cost estimation from token usage.
+//!
+//! Claude reports the cost of each invocation, but Codex and Gemini often do not.
+//! For those, the runner prices the accumulated token usage with a per-model rate
+//! table: the built-in defaults below, overridden or extended by the workflow's
+//! `pricing` section. Costs computed this way are reported as estimates.
+
+use crate::agents::protocol::AgentTokenUsage;
+use crate::config::AgentConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Token rates for one model, in USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    /// Rate for cache writes. Default: the input rate
+    #[serde(default)]
+    pub cache_write_per_mtok: Option<f64>,
+    /// Rate for cache reads. Default: the input rate
+    #[serde(default)]
+    pub cache_read_per_mtok: Option<f64>,
+}
+
+impl ModelPricing {
+    const fn new(input: f64, output: f64, cache_write: f64, cache_read: f64) -> Self {
+        Self {
+            input_per_mtok: input,
+            output_per_mtok: output,
+            cache_write_per_mtok: Some(cache_write),
+            cache_read_per_mtok: Some(cache_read),
+        }
+    }
+
+    /// Cost in USD of the given usage.
+    pub fn cost(&self, usage: &AgentTokenUsage) -> f64 {
+        let cache_write = self.cache_write_per_mtok.unwrap_or(self.input_per_mtok);
+        let cache_read = self.cache_read_per_mtok.unwrap_or(self.input_per_mtok);
+        (usage.input_tokens as f64 * self.input_per_mtok
+            + usage.output_tokens as f64 * self.output_per_mtok
+            + usage.cache_creation_tokens as f64 * cache_write
+            + usage.cache_read_tokens as f64 * cache_read)
+            / 1_000_000.0
+    }
+}
+
+/// Built-in rates keyed by model name prefix. The bare CLI names are fallbacks
+/// for agents whose model is neither reported nor set in their args.
+const DEFAULT_PRICING: [(&str, ModelPricing); 11] = [
+    ("claude", ModelPricing::new(3.0, 15.0, 3.75, 0.30)),
+    ("claude-opus-4", ModelPricing::new(15.0, 75.0, 18.75, 1.50)),
+    ("claude-opus-4-5", ModelPricing::new(5.0, 25.0, 6.25, 0.50)),
+    ("claude-sonnet-4", ModelPricing::new(3.0, 15.0, 3.75, 0.30)),
+    ("claude-haiku-4", ModelPricing::new(1.0, 5.0, 1.25, 0.10)),
+    ("codex", ModelPricing::new(1.25, 10.0, 1.25, 0.125)),
+    ("gpt-5", ModelPricing::new(1.25, 10.0, 1.25, 0.125)),
+    ("gpt-5-mini", ModelPricing::new(0.25, 2.0, 0.25, 0.025)),
+    ("gemini", ModelPricing::new(1.25, 10.0, 1.25, 0.31)),
+    ("gemini-2.5-pro", ModelPricing::new(1.25, 10.0, 1.25, 0.31)),
+    (
+        "gemini-2.5-flash",
+        ModelPricing::new(0.30, 2.50, 0.30, 0.075),
+    ),
+];
+
+/// Per-model rates used to price token usage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricingTable {
+    models: BTreeMap<String, ModelPricing>,
+}
+
+impl Default for PricingTable {
+    fn default() -> Self {
+        Self {
+            models: DEFAULT_PRICING
+                .iter()
+                .map(|(model, pricing)| (model.to_string(), *pricing))
+                .collect(),
+        }
+    }
+}
+
+impl PricingTable {
+    /// Built-in rates with the workflow's `pricing` entries applied on top.
+    pub fn with_overrides(overrides: &BTreeMap<String, ModelPricing>) -> Self {
+        let mut table = Self::default();
+        for (model, pricing) in overrides {
+            table.models.insert(model.to_lowercase(), *pricing);
+        }
+        table
+    }
+
+    /// Rates for a model: the entry with the longest name prefix of `model`.
+    pub fn lookup(&self, model: &str) -> Option<&ModelPricing> {
+        let model = model.to_lowercase();
+        self.models
+            .iter()
+            .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, pricing)| pricing)
+    }
+
+    /// Estimated cost of `usage` for `model`, if the model is priced.
+    pub fn estimate(&self, model: &str, usage: &AgentTokenUsage) -> Option<f64> {
+        self.lookup(model).map(|pricing| pricing.cost(usage))
+    }
+}
+
+/// Model an agent is configured to run: its `--model`/`-m` arg, otherwise the
+/// CLI name (e.g. "codex"), which matches the fallback entries.
+pub fn model_hint(config: &AgentConfig) -> String {
+    let mut args = config.args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(model) = arg.strip_prefix("--model=") {
+            return model.to_string();
+        }
+        if arg == "--model" || arg == "-m" {
+            if let Some(model) = args.next() {
+                return model.clone();
+            }
+        }
+    }
+    Path::new(&config.command)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| config.command.clone())
+}
+
+#[cfg(test)]
+#[path = "tests/pricing_tests.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+//! Agent CLI processes.
+//!
+//! The process is spawned in its own process group and recorded for reaping, so
+//! neither the agent nor the tools it started outlive this process.
+
+use crate::agents::log::AgentLogger;
+use crate::agents::orphans::{
+    kill_process_group, kill_with_parent, own_process_group, TrackedAgentProcess,
+};
+use crate::agents::runner::RunnerConfig;
+use anyhow::{Context, Result};
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
+
+/// A spawned agent CLI with its output streams.
+///
+/// One-shot runs read it until its output closes; warm-pool runs keep it alive
+/// between prompts sent over stdin (see `run_agent_turn`).
+pub struct AgentProcess {
+    pub(super) child: Child,
+    stdin: Option<ChildStdin>,
+    pub(super) stdout: Lines<BufReader<ChildStdout>>,
+    pub(super) stderr: Lines<BufReader<ChildStderr>>,
+    /// Recorded so the agent can be reaped if this process is killed hard
+    _tracked: Option<TrackedAgentProcess>,
+}
+
+impl Drop for AgentProcess {
+    // kill_on_drop only reaches the agent, not the tool subprocesses in its group
+    fn drop(&mut self) {
+        if let Some(pid) = self.child.id() {
+            kill_process_group(pid);
+        }
+    }
+}
+
+impl AgentProcess {
+    /// Spawns `command` in the runner's working directory with piped output.
+    /// Stdin is only piped if the command was configured that way.
+    pub fn spawn(mut command: Command, config: &RunnerConfig) -> Result<Self> {
+        command.current_dir(&config.working_dir);
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+        // Don't orphan the agent if this process is dropped mid-run
+        command.kill_on_drop(true);
+        kill_with_parent(&mut command);
+        own_process_group(&mut command);
+
+        let mut child = command
+            .spawn()
+            .with_context(|| format!("Failed to spawn {} process", config.agent_name))?;
+
+        let tracked = match (child.id(), &config.session_logger) {
+            (Some(pid), Some(session_logger)) => {
+                TrackedAgentProcess::register(session_logger.session_id(), pid, &config.agent_name)
+                    .map_err(|e| {
+                        AgentLogger::new(&config.agent_name, session_logger.clone())
+                            .log_line("tracking", &format!("failed to record pid: {}", e));
+                    })
+                    .ok()
+            }
+            _ => None,
+        };
+
+        let stdout = child
+            .stdout
+            .take()
+            .context("Failed to get stdout from process")?;
+        let stderr = child
+            .stderr
+            .take()
+            .context("Failed to get stderr from process")?;
+
+        Ok(Self {
+            stdin: child.stdin.take(),
+            stdout: BufReader::new(stdout).lines(),
+            stderr: BufReader::new(stderr).lines(),
+            child,
+            _tracked: tracked,
+        })
+    }
+
+    /// Writes one line to the agent's stdin.
+    pub async fn send_line(&mut self, line: &str) -> Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .context("Agent process was spawned without a stdin pipe")?;
+        stdin.write_all(line.as_bytes()).await?;
+        stdin.write_all(b"\n").await?;
+        stdin.flush().await?;
+        Ok(())
+    }
+
+    /// Returns true while the agent hasn't exited.
+    pub fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+}
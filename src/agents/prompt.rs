@@ -7,6 +7,9 @@
 //!
 //! For agents without system prompt support, the system prompt is merged
 //! into the user prompt to ensure consistent behavior.
+//!
+//! The output of user slash commands queued in the workflow view is added to
+//! the next planner or implementer prompt by [`with_command_output`].
 
 /// Represents a prompt request before agent-specific preparation.
 #[derive(Debug, Clone)]
@@ -107,6 +110,19 @@ pub fn prepare_prompt(request: PromptRequest, capabilities: AgentCapabilities) -
     }
 }
 
+/// Appends the output of the user's slash commands to `prompt` in a
+/// `<user-context>` block.
+pub fn with_command_output(prompt: String, outputs: &[String]) -> String {
+    if outputs.is_empty() {
+        return prompt;
+    }
+    format!(
+        "{}\n\n<user-context>\n{}\n</user-context>",
+        prompt,
+        outputs.join("\n\n")
+    )
+}
+
 #[cfg(test)]
 #[path = "tests/prompt_tests.rs"]
 mod tests;
@@ -66,6 +66,12 @@ pub enum AgentEvent {
     /// Incremental content (maps from ParsedEvent::ContentDelta)
     ContentDelta(String),
 
+    /// Raw text fragment of the response being generated, for live display only.
+    ///
+    /// Unlike `ContentDelta` it is not split into lines. The complete text still
+    /// arrives as `TextContent` once the message finishes.
+    PartialText(String),
+
     /// Final result (maps from ParsedEvent::Result)
     Result {
         output: Option<String>,
@@ -96,6 +102,15 @@ pub struct AgentTokenUsage {
     pub cache_read_tokens: u64,
 }
 
+impl AgentTokenUsage {
+    pub fn add(&mut self, other: &AgentTokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_creation_tokens += other.cache_creation_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+    }
+}
+
 impl From<AgentTokenUsage> for TokenUsage {
     fn from(usage: AgentTokenUsage) -> Self {
         TokenUsage {
@@ -0,0 +1,147 @@
+//! Secret redaction for logs and prompts.
+//!
+//! Agent output is scanned before it is written to the session logs and the
+//! JSONL event log, and prompts are scanned before they are sent to a provider.
+//! Matches are replaced with `[REDACTED:<name>]`, e.g. `[REDACTED:aws-key]`.
+//! Built-in patterns cover common API keys, tokens and private keys; the workflow
+//! config's `redaction.patterns` adds named regexes. Patterns are shared by the
+//! whole process, and redactions are counted per session for the stats panel.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+/// Built-in patterns, in the order they are applied.
+const BUILTIN_PATTERNS: &[(&str, &str)] = &[
+    (
+        "private-key",
+        r"(?s)-----BEGIN [A-Z ]*PRIVATE KEY-----.*?-----END [A-Z ]*PRIVATE KEY-----",
+    ),
+    ("aws-key", r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b"),
+    (
+        "github-token",
+        r"\b(?:gh[pousr]_[A-Za-z0-9]{36,}|github_pat_[A-Za-z0-9_]{22,})",
+    ),
+    ("anthropic-key", r"\bsk-ant-[A-Za-z0-9_-]{20,}"),
+    ("openai-key", r"\bsk-(?:proj-)?[A-Za-z0-9_-]{20,}"),
+    ("google-api-key", r"\bAIza[0-9A-Za-z_-]{35}"),
+    ("slack-token", r"\bxox[abprs]-[A-Za-z0-9-]{10,}"),
+    ("bearer-token", r"(?i)\bbearer\s+[A-Za-z0-9._~+/-]{20,}=*"),
+];
+
+/// Extra secret patterns from the workflow config.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RedactionConfig {
+    /// Regexes keyed by the name shown in the placeholder
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub patterns: BTreeMap<String, String>,
+}
+
+impl RedactionConfig {
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+/// A set of named secret patterns.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    patterns: Vec<(String, Regex)>,
+}
+
+impl Redactor {
+    /// The built-in patterns.
+    pub fn builtin() -> Self {
+        Self {
+            patterns: BUILTIN_PATTERNS
+                .iter()
+                .filter_map(|(name, pattern)| {
+                    Regex::new(pattern).ok().map(|re| (name.to_string(), re))
+                })
+                .collect(),
+        }
+    }
+
+    /// Adds the configured patterns, replacing any with the same name.
+    pub fn with_config(mut self, config: &RedactionConfig) -> anyhow::Result<Self> {
+        for (name, pattern) in &config.patterns {
+            let re = Regex::new(pattern)
+                .map_err(|e| anyhow::anyhow!("Invalid redaction pattern '{}': {}", name, e))?;
+            self.patterns.retain(|(existing, _)| existing != name);
+            self.patterns.push((name.clone(), re));
+        }
+        Ok(self)
+    }
+
+    /// `text` with every match replaced, and the number of replacements.
+    pub fn redact<'a>(&self, text: &'a str) -> (Cow<'a, str>, usize) {
+        let mut redacted = Cow::Borrowed(text);
+        let mut count = 0;
+        for (name, re) in &self.patterns {
+            let matches = re.find_iter(&redacted).count();
+            if matches > 0 {
+                count += matches;
+                let placeholder = format!("[REDACTED:{}]", name);
+                redacted = Cow::Owned(
+                    re.replace_all(&redacted, regex::NoExpand(&placeholder))
+                        .into_owned(),
+                );
+            }
+        }
+        (redacted, count)
+    }
+}
+
+fn active() -> &'static RwLock<Arc<Redactor>> {
+    static ACTIVE: OnceLock<RwLock<Arc<Redactor>>> = OnceLock::new();
+    ACTIVE.get_or_init(|| RwLock::new(Arc::new(Redactor::builtin())))
+}
+
+fn counts() -> &'static Mutex<HashMap<String, usize>> {
+    static COUNTS: OnceLock<Mutex<HashMap<String, usize>>> = OnceLock::new();
+    COUNTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Adds the workflow's patterns to the process-wide set.
+pub fn configure(config: &RedactionConfig) -> anyhow::Result<()> {
+    if config.patterns.is_empty() {
+        return Ok(());
+    }
+    let Ok(mut active) = active().write() else {
+        return Ok(());
+    };
+    let redactor = Redactor::clone(&active).with_config(config)?;
+    *active = Arc::new(redactor);
+    Ok(())
+}
+
+/// Redacts `text` with the process-wide patterns, counting the redactions
+/// against `session_id`.
+pub fn redact<'a>(text: &'a str, session_id: &str) -> Cow<'a, str> {
+    let redactor = match active().read() {
+        Ok(active) => active.clone(),
+        Err(_) => return Cow::Borrowed(text),
+    };
+    let (redacted, count) = redactor.redact(text);
+    if count > 0 {
+        if let Ok(mut counts) = counts().lock() {
+            *counts.entry(session_id.to_string()).or_default() += count;
+        }
+    }
+    redacted
+}
+
+/// Secrets redacted so far in `session_id`'s logs and prompts.
+pub fn redaction_count(session_id: &str) -> usize {
+    counts()
+        .lock()
+        .ok()
+        .and_then(|counts| counts.get(session_id).copied())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[path = "tests/redaction_tests.rs"]
+mod tests;
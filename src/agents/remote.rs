@@ -0,0 +1,181 @@
+//! Remote agent execution over SSH.
+//!
+//! An agent configured with `execution: ssh://user@host[:port]` runs its CLI on
+//! that machine instead of locally. The locally built command is wrapped in an
+//! `ssh` invocation that changes into the translated working directory and execs
+//! the agent there; stdout and stderr stream back over the SSH channel, so the
+//! runner and parsers work unchanged.
+//!
+//! Paths are translated with the agent's `path_map` table (local prefix → remote
+//! prefix). The longest matching prefix wins, and it is applied both to the
+//! working directory and to paths embedded in arguments such as the prompt.
+
+use crate::config::AgentConfig;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Program used to reach remote hosts.
+const SSH_PROGRAM: &str = "ssh";
+
+/// An SSH destination parsed from an agent's `execution` setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshTarget {
+    /// `user@host` (or just `host`) as passed to ssh.
+    pub destination: String,
+    pub port: Option<u16>,
+}
+
+impl SshTarget {
+    /// Parses an `execution` value. Returns `None` for local execution.
+    pub fn parse(execution: &str) -> Result<Option<Self>> {
+        let execution = execution.trim();
+        if execution.is_empty() || execution == "local" {
+            return Ok(None);
+        }
+        let rest = execution.strip_prefix("ssh://").with_context(|| {
+            format!(
+                "Unsupported agent execution '{}': expected 'local' or 'ssh://user@host[:port]'",
+                execution
+            )
+        })?;
+        let rest = rest.trim_end_matches('/');
+        let (destination, port) = match rest.rsplit_once(':') {
+            Some((dest, port)) => {
+                let port = port
+                    .parse::<u16>()
+                    .with_context(|| format!("Invalid SSH port in '{}'", execution))?;
+                (dest, Some(port))
+            }
+            None => (rest, None),
+        };
+        let host = destination.rsplit('@').next().unwrap_or_default();
+        if host.is_empty() || destination.contains('/') {
+            anyhow::bail!("Invalid SSH destination in agent execution '{}'", execution);
+        }
+        Ok(Some(Self {
+            destination: destination.to_string(),
+            port,
+        }))
+    }
+}
+
+/// Translates a local path to its remote location using the longest matching prefix.
+/// Paths outside every mapped prefix are returned unchanged.
+pub fn translate_path(path: &Path, path_map: &BTreeMap<PathBuf, PathBuf>) -> PathBuf {
+    path_map
+        .iter()
+        .filter_map(|(local, remote)| {
+            path.strip_prefix(local)
+                .ok()
+                .map(|rest| (local.components().count(), remote.join(rest)))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, translated)| translated)
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Rewrites every mapped local path prefix occurring in `text`, longest prefix first.
+pub fn translate_text(text: &str, path_map: &BTreeMap<PathBuf, PathBuf>) -> String {
+    let mut mappings: Vec<(String, String)> = path_map
+        .iter()
+        .map(|(local, remote)| {
+            (
+                local.to_string_lossy().into_owned(),
+                remote.to_string_lossy().into_owned(),
+            )
+        })
+        .filter(|(local, _)| !local.is_empty())
+        .collect();
+    mappings.sort_by_key(|(local, _)| std::cmp::Reverse(local.len()));
+
+    // Replace via placeholders so a remote path is never rewritten by a shorter mapping
+    let mut out = text.to_string();
+    for (i, (local, _)) in mappings.iter().enumerate() {
+        out = out.replace(local.as_str(), &format!("\u{0}{}\u{0}", i));
+    }
+    for (i, (_, remote)) in mappings.iter().enumerate() {
+        out = out.replace(&format!("\u{0}{}\u{0}", i), remote);
+    }
+    out
+}
+
+/// Quotes a word for a POSIX shell.
+fn shell_quote(word: &str) -> String {
+    if !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:@,+".contains(c))
+    {
+        return word.to_string();
+    }
+    format!("'{}'", word.replace('\'', r"'\''"))
+}
+
+/// Builds the remote shell command: change into the working directory and exec the agent CLI.
+fn remote_script(
+    command: &Command,
+    remote_dir: &Path,
+    path_map: &BTreeMap<PathBuf, PathBuf>,
+) -> String {
+    let std_cmd = command.as_std();
+    let mut words = vec![
+        "cd".to_string(),
+        shell_quote(&remote_dir.to_string_lossy()),
+        "&&".to_string(),
+        "exec".to_string(),
+    ];
+    words.push(shell_quote(&std_cmd.get_program().to_string_lossy()));
+    for arg in std_cmd.get_args() {
+        words.push(shell_quote(&translate_text(
+            &arg.to_string_lossy(),
+            path_map,
+        )));
+    }
+    words.join(" ")
+}
+
+/// Returns true if the agent is configured to run on a remote host.
+pub fn is_remote(config: &AgentConfig) -> bool {
+    config
+        .execution
+        .as_deref()
+        .is_some_and(|execution| matches!(SshTarget::parse(execution), Ok(Some(_))))
+}
+
+/// Wraps `command` so it runs on the agent's remote host, if one is configured.
+/// Local agents get their command back unchanged.
+pub fn remote_command(
+    command: Command,
+    config: &AgentConfig,
+    working_dir: &Path,
+) -> Result<Command> {
+    let Some(target) = config
+        .execution
+        .as_deref()
+        .map(SshTarget::parse)
+        .transpose()?
+        .flatten()
+    else {
+        return Ok(command);
+    };
+
+    let remote_dir = translate_path(working_dir, &config.path_map);
+    let mut ssh = Command::new(SSH_PROGRAM);
+    // No TTY keeps stdout and stderr separate; BatchMode fails fast instead of prompting
+    ssh.arg("-T").arg("-o").arg("BatchMode=yes");
+    if let Some(port) = target.port {
+        ssh.arg("-p").arg(port.to_string());
+    }
+    ssh.arg(&target.destination).arg("--").arg(remote_script(
+        &command,
+        &remote_dir,
+        &config.path_map,
+    ));
+    Ok(ssh)
+}
+
+#[cfg(test)]
+#[path = "tests/remote_tests.rs"]
+mod tests;
@@ -0,0 +1,244 @@
+//! CPU and memory usage of running agent processes.
+//!
+//! While an agent runs, the runner samples its process tree (the CLI and every
+//! process it spawned) from `/proc` every few seconds and reports the usage to
+//! the TUI's stats panel. Outside Linux nothing is sampled.
+//!
+//! An agent with `memory_limit_mb` set is killed once its tree's resident memory
+//! passes the limit. The invocation is then retried, and the kill is recorded as
+//! a `ResourceLimitExceeded` failure.
+
+use crate::agents::cost_stats::cost_alert_key;
+use crate::domain::failure::{FailureContext, FailureKind, RecoveryAction};
+use crate::domain::types::{AgentId, PhaseLabel, TimestampUtc};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Time between two samples of an agent's process tree.
+pub const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often an invocation killed for exceeding its memory limit is re-run.
+pub const MEMORY_LIMIT_RETRIES: u32 = 1;
+
+/// Usage of an agent's process tree at one sample.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProcessUsage {
+    /// CPU time used since the previous sample, in percent of one core.
+    pub cpu_percent: f64,
+    pub rss_bytes: u64,
+}
+
+/// An invocation was killed because its process tree used more memory than
+/// its agent's `memory_limit_mb`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceLimitExceeded {
+    pub agent_name: String,
+    pub rss_mb: u64,
+    pub limit_mb: u64,
+}
+
+impl fmt::Display for ResourceLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} exceeded its memory limit of {} MB ({} MB resident)",
+            self.agent_name, self.limit_mb, self.rss_mb
+        )
+    }
+}
+
+impl std::error::Error for ResourceLimitExceeded {}
+
+impl ResourceLimitExceeded {
+    /// The failure recorded for a kill that is being retried. Returns None for
+    /// phases without a workflow phase label (e.g. summaries).
+    pub fn retried_failure(&self, phase: &str, attempt: u32) -> Option<FailureContext> {
+        let phase = match cost_alert_key(phase).as_str() {
+            "planning" => PhaseLabel::Planning,
+            "reviewing" => PhaseLabel::Reviewing,
+            "revising" => PhaseLabel::Revising,
+            "implementation" => PhaseLabel::Implementing,
+            "implementation_review" => PhaseLabel::ImplementationReview,
+            _ => return None,
+        };
+        Some(FailureContext::new(
+            FailureKind::ResourceLimitExceeded {
+                rss_mb: self.rss_mb,
+                limit_mb: self.limit_mb,
+            },
+            phase,
+            Some(AgentId::from(self.agent_name.as_str())),
+            attempt,
+            MEMORY_LIMIT_RETRIES,
+            TimestampUtc::now(),
+            Some(RecoveryAction::Retried),
+        ))
+    }
+}
+
+/// Samples one agent process tree and enforces its memory limit.
+pub struct ResourceMonitor {
+    pid: Option<u32>,
+    limit_mb: Option<u64>,
+    next_sample: Instant,
+    /// When the previous sample was taken and the tree's CPU ticks at the time.
+    previous: Option<(Instant, u64)>,
+}
+
+impl ResourceMonitor {
+    pub fn new(pid: Option<u32>, limit_mb: Option<u64>) -> Self {
+        Self {
+            pid,
+            limit_mb,
+            next_sample: Instant::now() + SAMPLE_INTERVAL,
+            previous: None,
+        }
+    }
+
+    /// Resolves when the next sample is due; never resolves without a pid.
+    pub async fn tick(&mut self) {
+        if self.pid.is_none() {
+            return std::future::pending().await;
+        }
+        tokio::time::sleep_until(self.next_sample).await;
+        self.next_sample = Instant::now() + SAMPLE_INTERVAL;
+    }
+
+    /// Samples the process tree. Returns None once the process is gone or
+    /// where `/proc` is unavailable.
+    pub fn sample(&mut self) -> Option<ProcessUsage> {
+        let (ticks, rss_bytes) = tree_usage(self.pid?)?;
+        let now = Instant::now();
+        let cpu_percent = match self.previous {
+            Some((at, previous_ticks)) => {
+                cpu_percent(ticks.saturating_sub(previous_ticks), now - at)
+            }
+            None => 0.0,
+        };
+        self.previous = Some((now, ticks));
+        Some(ProcessUsage {
+            cpu_percent,
+            rss_bytes,
+        })
+    }
+
+    /// The limit `usage` breaks, if any.
+    pub fn exceeded(
+        &self,
+        agent_name: &str,
+        usage: &ProcessUsage,
+    ) -> Option<ResourceLimitExceeded> {
+        let limit_mb = self.limit_mb?;
+        let rss_mb = usage.rss_bytes / (1024 * 1024);
+        (rss_mb > limit_mb).then(|| ResourceLimitExceeded {
+            agent_name: agent_name.to_string(),
+            rss_mb,
+            limit_mb,
+        })
+    }
+}
+
+/// One process's parent, CPU ticks (user + system) and resident pages, from
+/// `/proc/<pid>/stat`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ProcStat {
+    ppid: u32,
+    cpu_ticks: u64,
+    rss_pages: u64,
+}
+
+fn parse_stat(content: &str) -> Option<ProcStat> {
+    // The command name is in parentheses and may itself contain spaces or ')'
+    let (_, rest) = content.rsplit_once(')')?;
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    // Fields after the name start at `state` (field 3 in proc(5))
+    let field = |n: usize| fields.get(n - 3)?.parse::<u64>().ok();
+    Some(ProcStat {
+        ppid: field(4)? as u32,
+        cpu_ticks: field(14)? + field(15)?,
+        rss_pages: field(24)?,
+    })
+}
+
+/// `root` and all its descendants, given each process's parent.
+fn descendants(root: u32, parents: &HashMap<u32, u32>) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&pid, &ppid) in parents {
+        if pid != root {
+            children.entry(ppid).or_default().push(pid);
+        }
+    }
+    let mut tree = vec![root];
+    let mut next = 0;
+    while next < tree.len() {
+        if let Some(kids) = children.get_mut(&tree[next]) {
+            kids.sort_unstable();
+            tree.extend(kids.iter().copied());
+        }
+        next += 1;
+    }
+    tree
+}
+
+fn cpu_percent(ticks: u64, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs <= 0.0 {
+        return 0.0;
+    }
+    ticks as f64 / clock_ticks_per_sec() / secs * 100.0
+}
+
+/// Total CPU ticks and resident bytes of `root`'s process tree.
+fn tree_usage(root: u32) -> Option<(u64, u64)> {
+    let mut stats = HashMap::new();
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse().ok()) else {
+            continue;
+        };
+        let stat = std::fs::read_to_string(entry.path().join("stat"))
+            .ok()
+            .and_then(|content| parse_stat(&content));
+        if let Some(stat) = stat {
+            stats.insert(pid, stat);
+        }
+    }
+    if !stats.contains_key(&root) {
+        return None;
+    }
+    let parents = stats.iter().map(|(&pid, stat)| (pid, stat.ppid)).collect();
+    let (ticks, pages) = descendants(root, &parents)
+        .iter()
+        .filter_map(|pid| stats.get(pid))
+        .fold((0, 0), |(ticks, pages), stat| {
+            (ticks + stat.cpu_ticks, pages + stat.rss_pages)
+        });
+    Some((ticks, pages * page_size()))
+}
+
+fn clock_ticks_per_sec() -> f64 {
+    #[cfg(unix)]
+    {
+        let ticks = unsafe { nix::libc::sysconf(nix::libc::_SC_CLK_TCK) };
+        if ticks > 0 {
+            return ticks as f64;
+        }
+    }
+    100.0
+}
+
+fn page_size() -> u64 {
+    #[cfg(unix)]
+    {
+        let size = unsafe { nix::libc::sysconf(nix::libc::_SC_PAGESIZE) };
+        if size > 0 {
+            return size as u64;
+        }
+    }
+    4096
+}
+
+#[cfg(test)]
+#[path = "tests/resources_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+
+const STAT: &str = "4242 (node (worker) x) S 4100 4242 4100 0 -1 4194560 1200 0 0 0 \
+                    350 50 0 0 20 0 11 0 98765 1234567890 2048 18446744073709551615";
+
+#[test]
+fn test_parse_stat_handles_parentheses_in_command_name() {
+    assert_eq!(
+        parse_stat(STAT),
+        Some(ProcStat {
+            ppid: 4100,
+            cpu_ticks: 400,
+            rss_pages: 2048,
+        })
+    );
+    assert_eq!(parse_stat("4242 (node) S 4100"), None);
+    assert_eq!(parse_stat("garbage"), None);
+}
+
+#[test]
+fn test_descendants_walks_the_whole_tree() {
+    let parents: HashMap<u32, u32> = [(10, 1), (11, 10), (12, 10), (20, 11), (30, 1), (101, 30)]
+        .into_iter()
+        .collect();
+    assert_eq!(descendants(10, &parents), vec![10, 11, 12, 20]);
+    assert_eq!(descendants(99, &parents), vec![99]);
+}
+
+#[test]
+fn test_exceeded_only_with_a_limit_above_it() {
+    let usage = ProcessUsage {
+        cpu_percent: 12.0,
+        rss_bytes: 600 * 1024 * 1024,
+    };
+    assert_eq!(
+        ResourceMonitor::new(Some(1), None).exceeded("claude", &usage),
+        None
+    );
+    assert_eq!(
+        ResourceMonitor::new(Some(1), Some(600)).exceeded("claude", &usage),
+        None
+    );
+
+    let exceeded = ResourceMonitor::new(Some(1), Some(512))
+        .exceeded("claude", &usage)
+        .unwrap();
+    assert_eq!(exceeded.rss_mb, 600);
+    assert_eq!(
+        exceeded.to_string(),
+        "claude exceeded its memory limit of 512 MB (600 MB resident)"
+    );
+}
+
+#[test]
+fn test_retried_failure_maps_workflow_phases() {
+    let exceeded = ResourceLimitExceeded {
+        agent_name: "codex".to_string(),
+        rss_mb: 2100,
+        limit_mb: 2048,
+    };
+
+    let failure = exceeded.retried_failure("Revising #2", 1).unwrap();
+    assert_eq!(failure.phase(), &PhaseLabel::Revising);
+    assert!(failure.is_resolved());
+    assert_eq!(
+        failure.kind(),
+        &FailureKind::ResourceLimitExceeded {
+            rss_mb: 2100,
+            limit_mb: 2048,
+        }
+    );
+    assert!(exceeded.retried_failure("Summary", 1).is_none());
+}
+
+// Sampling reads /proc, which only Linux has
+#[cfg(target_os = "linux")]
+#[test]
+fn test_sample_reads_own_process_tree() {
+    let mut monitor = ResourceMonitor::new(Some(std::process::id()), None);
+    let usage = monitor.sample().unwrap();
+    assert!(usage.rss_bytes > 0);
+    assert_eq!(usage.cpu_percent, 0.0);
+    assert!(monitor.sample().is_some());
+}
@@ -3,19 +3,31 @@
 //! This module provides a unified process spawning, I/O handling, and timeout
 //! management layer for all agent types (Claude, Codex, Gemini).
 
-use crate::agents::log::AgentLogger;
-use crate::agents::protocol::{AgentEvent, AgentOutput, AgentStreamParser};
-use crate::agents::{AgentContext, AgentResult};
-use crate::session_daemon::SessionLogger;
+use crate::agents::cost_stats::{
+    append_usage_record, cost_alert_key, cost_round, phase_key, InvocationUsage,
+};
+use crate::agents::faults::{Fault, FaultInjector, MALFORMED_OUTPUT};
+use crate::agents::log::{AgentLogger, StderrCapture};
+use crate::agents::orphans::kill_agent;
+use crate::agents::permissions::gate::{next_gate_request, PermissionGate};
+use crate::agents::permissions::{
+    answer_gate_request, review_started_tool, ToolPermissionDecision, ToolPermissionPrompt,
+    ToolPermissionRequest, ToolRisk,
+};
+use crate::agents::pricing::PricingTable;
+use crate::agents::process::AgentProcess;
+use crate::agents::protocol::{AgentEvent, AgentOutput, AgentStreamParser, AgentTokenUsage};
+use crate::agents::resources::{ProcessUsage, ResourceMonitor};
+use crate::agents::streaming::{PartialTextCoalescer, PARTIAL_FLUSH_INTERVAL};
+use crate::agents::AgentContext;
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::{CliInstanceId, TokenUsage, ToolResultSummary};
-use anyhow::{Context, Result};
+use anyhow::Result;
 use std::path::PathBuf;
-use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
-use tokio::sync::watch;
+use tokio::sync::{mpsc, watch};
 use tokio::time::Instant;
 
 /// Default timeout for activity (no output) before killing the process.
@@ -48,6 +60,18 @@ pub struct RunnerConfig {
     /// Optional cancellation signal receiver.
     /// When the sender sends `true`, the agent process will be killed.
     pub cancel_rx: Option<watch::Receiver<bool>>,
+    /// Whether high-risk tool calls pause the agent and prompt the user.
+    pub permission_prompts: bool,
+    /// Gate the agent's permission hook asks before running a call; replaces pausing.
+    pub permission_gate: Option<Arc<PermissionGate>>,
+    /// Rates for estimating the cost when the agent doesn't report one.
+    pub pricing: Option<PricingTable>,
+    /// Configured model, used for pricing until the agent reports its model.
+    pub model_hint: Option<String>,
+    /// Injects failures, stalls, or malformed output (see `--fault-inject`).
+    pub fault_injector: Option<FaultInjector>,
+    /// Resident memory (MB) of the agent's process tree at which it is killed.
+    pub memory_limit_mb: Option<u64>,
 }
 
 impl std::fmt::Debug for RunnerConfig {
@@ -59,6 +83,11 @@ impl std::fmt::Debug for RunnerConfig {
             .field("overall_timeout", &self.overall_timeout)
             .field("session_logger", &self.session_logger.is_some())
             .field("cancel_rx", &self.cancel_rx.is_some())
+            .field("permission_prompts", &self.permission_prompts)
+            .field("permission_gate", &self.permission_gate.is_some())
+            .field("model_hint", &self.model_hint)
+            .field("fault_injector", &self.fault_injector.is_some())
+            .field("memory_limit_mb", &self.memory_limit_mb)
             .finish()
     }
 }
@@ -72,6 +101,12 @@ impl RunnerConfig {
             overall_timeout: DEFAULT_OVERALL_TIMEOUT,
             session_logger: None,
             cancel_rx: None,
+            permission_prompts: false,
+            permission_gate: None,
+            pricing: None,
+            model_hint: None,
+            fault_injector: None,
+            memory_limit_mb: None,
         }
     }
 
@@ -85,6 +120,11 @@ impl RunnerConfig {
         self
     }
 
+    pub fn with_memory_limit(mut self, limit_mb: Option<u64>) -> Self {
+        self.memory_limit_mb = limit_mb;
+        self
+    }
+
     pub fn with_session_logger(mut self, logger: Arc<SessionLogger>) -> Self {
         self.session_logger = Some(logger);
         self
@@ -96,6 +136,31 @@ impl RunnerConfig {
         self.cancel_rx = Some(cancel_rx);
         self
     }
+
+    /// Enables interactive permission prompts for high-risk tool calls.
+    pub fn with_permission_prompts(mut self, enabled: bool) -> Self {
+        self.permission_prompts = enabled;
+        self
+    }
+
+    /// Answers the agent's permission hook instead of pausing on streamed calls.
+    pub fn with_permission_gate(mut self, gate: Arc<PermissionGate>) -> Self {
+        self.permission_gate = Some(gate);
+        self
+    }
+
+    /// Enables local cost estimation for agents that don't report a cost.
+    pub fn with_pricing(mut self, pricing: PricingTable, model_hint: String) -> Self {
+        self.pricing = Some(pricing);
+        self.model_hint = Some(model_hint);
+        self
+    }
+
+    /// Overrides the process-wide fault injection for this runner.
+    pub fn with_fault_injector(mut self, injector: Option<FaultInjector>) -> Self {
+        self.fault_injector = injector;
+        self
+    }
 }
 
 /// Trait for sending events during agent execution.
@@ -109,6 +174,8 @@ pub trait EventEmitter: Send + Sync {
     fn send_model_detected(&self, model: String);
     fn send_stop_reason(&self, reason: String);
     fn send_token_usage(&self, usage: TokenUsage);
+    /// Record a finished invocation's token count and cost for the cost stats.
+    fn record_invocation_usage(&self, tokens: u64, cost: Option<f64>);
     fn send_tool_started(
         &self,
         tool_id: Option<String>,
@@ -123,7 +190,17 @@ pub trait EventEmitter: Send + Sync {
         summary: ToolResultSummary,
     );
     fn send_agent_message(&self, msg: String);
+    /// Report the last stderr lines of an invocation that failed.
+    fn send_agent_stderr(&self, lines: Vec<String>, log_path: Option<PathBuf>);
+    /// Stream part of the response being generated; `None` ends the stream.
+    fn send_partial_text(&self, delta: Option<String>);
     fn send_todos_update(&self, items: Vec<crate::tui::TodoItem>);
+    /// Ask the user whether a high-risk tool call may proceed.
+    /// The decision arrives on the returned receiver.
+    fn send_tool_permission_request(
+        &self,
+        prompt: ToolPermissionPrompt,
+    ) -> mpsc::Receiver<ToolPermissionDecision>;
 
     // CLI instance lifecycle methods
     /// Allocate a new unique CLI instance ID.
@@ -137,6 +214,8 @@ pub trait EventEmitter: Send + Sync {
     );
     /// Send a CLI instance activity event.
     fn send_cli_instance_activity(&self, id: CliInstanceId, activity_at: std::time::Instant);
+    /// Report the latest CPU and memory sample of a running CLI instance.
+    fn send_cli_instance_resources(&self, id: CliInstanceId, usage: ProcessUsage);
     /// Send a CLI instance finished event.
     fn send_cli_instance_finished(&self, id: CliInstanceId);
 }
@@ -145,13 +224,16 @@ pub trait EventEmitter: Send + Sync {
 pub struct ContextEmitter {
     context: AgentContext,
     agent_name: String,
+    /// CLI the agent runs, recorded in the session's usage ledger.
+    provider: &'static str,
 }
 
 impl ContextEmitter {
-    pub fn new(context: AgentContext, agent_name: String) -> Self {
+    pub fn new(context: AgentContext, agent_name: String, provider: &'static str) -> Self {
         Self {
             context,
             agent_name,
+            provider,
         }
     }
 }
@@ -178,6 +260,30 @@ impl EventEmitter for ContextEmitter {
     fn send_token_usage(&self, usage: TokenUsage) {
         self.context.session_sender.send_token_usage(usage);
     }
+    fn record_invocation_usage(&self, tokens: u64, cost: Option<f64>) {
+        let usage = InvocationUsage {
+            phase: phase_key(&self.context.phase).to_string(),
+            agent: self.agent_name.clone(),
+            tokens,
+            cost,
+        };
+        let logger = &self.context.session_logger;
+        if let Err(e) = append_usage_record(logger.session_id(), self.provider, &usage) {
+            logger.log(LogLevel::Warn, LogCategory::Workflow, &format!("{:#}", e));
+        }
+        self.context.session_sender.record_invocation_usage(usage);
+        let threshold = self
+            .context
+            .cost_alerts
+            .get(&cost_alert_key(&self.context.phase));
+        if let (Some(cost), Some(threshold)) = (cost, threshold) {
+            self.context.session_sender.record_round_cost(
+                cost_round(&self.context.phase),
+                cost,
+                *threshold,
+            );
+        }
+    }
     fn send_tool_started(
         &self,
         tool_id: Option<String>,
@@ -218,11 +324,40 @@ impl EventEmitter for ContextEmitter {
             msg,
         );
     }
+    fn send_agent_stderr(&self, lines: Vec<String>, log_path: Option<PathBuf>) {
+        self.context
+            .session_sender
+            .send_agent_stderr(self.agent_name.clone(), lines, log_path);
+    }
+    fn send_partial_text(&self, delta: Option<String>) {
+        self.context.session_sender.send_partial_text(
+            self.agent_name.clone(),
+            self.context.phase.clone(),
+            delta,
+        );
+    }
     fn send_todos_update(&self, items: Vec<crate::tui::TodoItem>) {
         self.context
             .session_sender
             .send_todos_update(self.agent_name.clone(), items);
     }
+    fn send_tool_permission_request(
+        &self,
+        prompt: ToolPermissionPrompt,
+    ) -> mpsc::Receiver<ToolPermissionDecision> {
+        let (responder, rx) = mpsc::channel(1);
+        self.context
+            .session_sender
+            .send_tool_permission_request(ToolPermissionRequest {
+                agent_name: self.agent_name.clone(),
+                phase: self.context.phase.clone(),
+                risk: prompt.risk,
+                display_name: prompt.display_name,
+                input_preview: prompt.input_preview,
+                responder,
+            });
+        rx
+    }
     fn next_cli_instance_id(&self) -> CliInstanceId {
         self.context.session_sender.next_cli_instance_id()
     }
@@ -244,6 +379,11 @@ impl EventEmitter for ContextEmitter {
             .session_sender
             .send_cli_instance_activity(id, activity_at);
     }
+    fn send_cli_instance_resources(&self, id: CliInstanceId, usage: ProcessUsage) {
+        self.context
+            .session_sender
+            .send_cli_instance_resources(id, usage);
+    }
     fn send_cli_instance_finished(&self, id: CliInstanceId) {
         self.context.session_sender.send_cli_instance_finished(id);
     }
@@ -327,6 +467,9 @@ fn emit_agent_event(event: AgentEvent, emitter: &dyn EventEmitter) {
             emitter.send_streaming(text.clone());
             emitter.send_agent_message(text);
         }
+        AgentEvent::PartialText(_) => {
+            // Coalesced in run_agent_process before reaching the emitter
+        }
         AgentEvent::Result { .. } => {
             // Result events are handled separately for final output
         }
@@ -348,51 +491,105 @@ fn emit_agent_event(event: AgentEvent, emitter: &dyn EventEmitter) {
 /// - Event parsing and emission
 /// - Graceful process termination
 pub async fn run_agent_process<P: AgentStreamParser>(
-    mut command: Command,
+    command: Command,
+    config: &RunnerConfig,
+    parser: &mut P,
+    emitter: &dyn EventEmitter,
+) -> Result<AgentOutput> {
+    emitter.send_output(format!("[agent:{}] Starting...", config.agent_name));
+    let mut process = AgentProcess::spawn(command, config)?;
+    drive_agent_process(&mut process, config, parser, emitter, false).await
+}
+
+/// Runs one prompt on a persistent agent process that was already sent its input.
+///
+/// Returns at the turn's result event and leaves the process running, so it can
+/// be handed the next prompt. Timeouts and cancellation still kill it.
+pub async fn run_agent_turn<P: AgentStreamParser>(
+    process: &mut AgentProcess,
+    config: &RunnerConfig,
+    parser: &mut P,
+    emitter: &dyn EventEmitter,
+) -> Result<AgentOutput> {
+    drive_agent_process(process, config, parser, emitter, true).await
+}
+
+/// Reads the agent's output until its streams close, or until the first result
+/// event when `until_result` is set. A failed invocation reports its stderr tail.
+async fn drive_agent_process<P: AgentStreamParser>(
+    process: &mut AgentProcess,
+    config: &RunnerConfig,
+    parser: &mut P,
+    emitter: &dyn EventEmitter,
+    until_result: bool,
+) -> Result<AgentOutput> {
+    let mut stderr = StderrCapture::new(config.session_logger.as_ref(), &config.agent_name);
+    let result =
+        read_agent_output(process, config, parser, emitter, until_result, &mut stderr).await;
+    let failed = result.as_ref().map_or(true, |output| output.is_error);
+    if failed && !stderr.tail().is_empty() {
+        emitter.send_agent_stderr(stderr.tail(), stderr.log_path().map(PathBuf::from));
+    }
+    result
+}
+
+async fn read_agent_output<P: AgentStreamParser>(
+    process: &mut AgentProcess,
     config: &RunnerConfig,
     parser: &mut P,
     emitter: &dyn EventEmitter,
+    until_result: bool,
+    stderr: &mut StderrCapture,
 ) -> Result<AgentOutput> {
     let logger = config
         .session_logger
         .as_ref()
         .map(|sl| AgentLogger::new(&config.agent_name, sl.clone()));
 
-    command.current_dir(&config.working_dir);
-    command.stdout(Stdio::piped()).stderr(Stdio::piped());
-
-    emitter.send_output(format!("[agent:{}] Starting...", config.agent_name));
-
-    let mut child = command
-        .spawn()
-        .with_context(|| format!("Failed to spawn {} process", config.agent_name))?;
-
     // Allocate CLI instance ID and emit started event
     let cli_instance_id = emitter.next_cli_instance_id();
-    let pid = child.id();
+    let pid = process.child.id();
     let std_started_at = std::time::Instant::now();
     emitter.send_cli_instance_started(cli_instance_id, pid, std_started_at);
 
     // Create RAII guard to ensure finished event is always emitted
     let mut _cli_guard = CliInstanceGuard::new(cli_instance_id, emitter);
+    let mut resources = ResourceMonitor::new(pid, config.memory_limit_mb);
 
-    let stdout = child
-        .stdout
-        .take()
-        .context("Failed to get stdout from process")?;
-    let stderr = child
-        .stderr
-        .take()
-        .context("Failed to get stderr from process")?;
-
-    let mut stdout_reader = BufReader::new(stdout).lines();
-    let mut stderr_reader = BufReader::new(stderr).lines();
+    let fault = config.fault_injector.as_ref().and_then(FaultInjector::draw);
+    if let Some(fault) = fault {
+        if let Some(ref logger) = logger {
+            logger.log_line("fault", &format!("injecting {:?}", fault));
+        }
+        emitter.send_output(format!(
+            "[agent:{}] Injecting fault: {:?}",
+            config.agent_name, fault
+        ));
+    }
+    if fault == Some(Fault::Fail) {
+        kill_agent(&mut process.child).await;
+        anyhow::bail!(
+            "{} process failed with exit code 1 (injected fault)",
+            config.agent_name
+        );
+    }
+    // A stalled agent's output is ignored until it is killed as unresponsive
+    let stall_deadline = config
+        .fault_injector
+        .as_ref()
+        .filter(|_| fault == Some(Fault::Stall))
+        .map(|injector| Instant::now() + injector.stall_for().min(config.activity_timeout));
 
     let mut final_output = String::new();
     let mut total_cost: Option<f64> = None;
+    let mut total_tokens: u64 = 0;
+    let mut usage_totals = AgentTokenUsage::default();
+    let mut detected_model: Option<String> = None;
     let mut is_error = false;
     let mut captured_conversation_id: Option<String> = None;
     let mut last_stop_reason: Option<String> = None;
+    let mut denied_tool: Option<(ToolRisk, String)> = None;
+    let mut turn_complete = false;
 
     let start_time = Instant::now();
     let mut last_activity = Instant::now();
@@ -401,17 +598,18 @@ pub async fn run_agent_process<P: AgentStreamParser>(
 
     // Clone cancel_rx if present for use in select! loop
     let mut cancel_rx = config.cancel_rx.clone();
+    let mut partial_text = PartialTextCoalescer::new(PARTIAL_FLUSH_INTERVAL);
 
     loop {
         // Check overall timeout
         if start_time.elapsed() > config.overall_timeout {
-            handle_overall_timeout(config, &logger, emitter, &mut child).await?;
+            handle_overall_timeout(config, &logger, emitter, &mut process.child).await?;
         }
 
-        let activity_deadline = last_activity + config.activity_timeout;
+        let activity_deadline = stall_deadline.unwrap_or(last_activity + config.activity_timeout);
 
         tokio::select! {
-            line = stdout_reader.next_line() => {
+            line = process.stdout.next_line(), if stall_deadline.is_none() => {
                 last_activity = Instant::now();
                 // Emit throttled activity event for CLI instance tracking
                 if last_activity.duration_since(last_activity_emit) >= ACTIVITY_EMIT_MIN_INTERVAL {
@@ -430,6 +628,22 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                             Ok(events) => {
                                 for event in events {
                                     // Handle special events
+                                    if let AgentEvent::PartialText(delta) = &event {
+                                        if let Some(text) = partial_text.push(delta, Instant::now()) {
+                                            emitter.send_partial_text(Some(text));
+                                        }
+                                        continue;
+                                    }
+                                    // The complete message supersedes the streamed text
+                                    let message_done = matches!(
+                                        event,
+                                        AgentEvent::TextContent(_)
+                                            | AgentEvent::ToolStarted { .. }
+                                            | AgentEvent::Result { .. }
+                                    );
+                                    if message_done && partial_text.finish() {
+                                        emitter.send_partial_text(None);
+                                    }
                                     match &event {
                                         AgentEvent::Result { output, cost, is_error: err } => {
                                             if let Some(out) = output {
@@ -437,6 +651,7 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                                             }
                                             total_cost = *cost;
                                             is_error = *err;
+                                            turn_complete = until_result;
                                         }
                                         AgentEvent::ConversationIdCaptured(id) => {
                                             captured_conversation_id = Some(id.clone());
@@ -448,15 +663,58 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                                             last_stop_reason = Some(reason.clone());
                                             emit_agent_event(event, emitter);
                                         }
+                                        AgentEvent::TokenUsage(usage) => {
+                                            // Cache reads are re-counted every turn; keep them out
+                                            total_tokens += usage.input_tokens
+                                                + usage.output_tokens
+                                                + usage.cache_creation_tokens;
+                                            usage_totals.add(usage);
+                                            emit_agent_event(event, emitter);
+                                        }
+                                        AgentEvent::ModelDetected(model) => {
+                                            detected_model = Some(model.clone());
+                                            emit_agent_event(event, emitter);
+                                        }
                                         AgentEvent::TextContent(text) => {
                                             final_output.push_str(text);
                                             emit_agent_event(event, emitter);
                                         }
+                                        AgentEvent::ToolStarted { display_name, input_preview, .. }
+                                            if config.permission_prompts
+                                                && config.permission_gate.is_none() =>
+                                        {
+                                            let call = (display_name.clone(), input_preview.clone());
+                                            emit_agent_event(event, emitter);
+                                            denied_tool = review_started_tool(
+                                                config, &logger, emitter, pid, call, &mut cancel_rx,
+                                            )
+                                            .await;
+                                            // Time spent on a prompt is not agent inactivity
+                                            last_activity = Instant::now();
+                                            if denied_tool.is_some() {
+                                                break;
+                                            }
+                                        }
                                         _ => {
                                             emit_agent_event(event, emitter);
                                         }
                                     }
                                 }
+                                if let Some((risk, ref display_name)) = denied_tool {
+                                    emitter.send_output(format!(
+                                        "[agent:{}] Denied {} ({}), terminating...",
+                                        config.agent_name,
+                                        risk.label(),
+                                        display_name
+                                    ));
+                                    kill_agent(&mut process.child).await;
+                                    last_stop_reason = Some("permission_denied".to_string());
+                                    is_error = true;
+                                    break;
+                                }
+                                if turn_complete {
+                                    break;
+                                }
                             }
                             Err(e) => {
                                 if let Some(ref logger) = logger {
@@ -476,7 +734,7 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                     }
                 }
             }
-            line = stderr_reader.next_line() => {
+            line = process.stderr.next_line(), if stall_deadline.is_none() => {
                 last_activity = Instant::now();
                 // Emit throttled activity event for CLI instance tracking
                 if last_activity.duration_since(last_activity_emit) >= ACTIVITY_EMIT_MIN_INTERVAL {
@@ -487,26 +745,37 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                     if let Some(ref logger) = logger {
                         logger.log_line("stderr", &line);
                     }
-                    emitter.send_streaming(format!("[stderr] {}", line));
+                    stderr.push(&line);
+                    // Parallel agents share the panel, so each line names its agent
+                    emitter.send_streaming(format!("[stderr] [{}] {}", config.agent_name, line));
+                }
+            }
+            _ = sleep_until_due(partial_text.deadline()) => {
+                if let Some(text) = partial_text.flush(Instant::now()) {
+                    emitter.send_partial_text(Some(text));
                 }
             }
             _ = tokio::time::sleep_until(activity_deadline) => {
-                handle_activity_timeout(config, &logger, emitter, &mut child).await?;
+                handle_activity_timeout(config, &logger, emitter, &mut process.child).await?;
             }
-            _ = async {
-                if let Some(ref mut rx) = cancel_rx {
-                    // Wait for cancel signal
-                    loop {
-                        rx.changed().await.ok();
-                        if *rx.borrow() {
-                            return;
-                        }
+            _ = resources.tick() => {
+                let Some(usage) = resources.sample() else { continue };
+                emitter.send_cli_instance_resources(cli_instance_id, usage);
+                if let Some(exceeded) = resources.exceeded(&config.agent_name, &usage) {
+                    if let Some(ref logger) = logger {
+                        logger.log_line("resources", &exceeded.to_string());
                     }
-                } else {
-                    // No cancel_rx, never resolves
-                    std::future::pending::<()>().await
+                    emitter.send_output(format!("[agent:{}] ERROR: {}, terminating...", config.agent_name, exceeded));
+                    kill_agent(&mut process.child).await;
+                    return Err(exceeded.into());
                 }
-            } => {
+            }
+            Some(request) = next_gate_request(&config.permission_gate) => {
+                answer_gate_request(config, &logger, emitter, request, &mut cancel_rx).await;
+                // Time spent on the prompt is not agent inactivity
+                last_activity = Instant::now();
+            }
+            _ = wait_for_cancel(&mut cancel_rx) => {
                 // Cancellation requested
                 if let Some(ref logger) = logger {
                     logger.log_line("cancelled", "cancellation signal received");
@@ -515,7 +784,7 @@ pub async fn run_agent_process<P: AgentStreamParser>(
                     "[agent:{}] Cancellation requested, terminating...",
                     config.agent_name
                 ));
-                let _ = child.kill().await;
+                kill_agent(&mut process.child).await;
                 // Set stop reason and return early with partial output
                 last_stop_reason = Some("cancelled".to_string());
                 is_error = false; // Cancellation is not an error
@@ -524,23 +793,40 @@ pub async fn run_agent_process<P: AgentStreamParser>(
         }
     }
 
-    // Wait for process to exit (skip if cancelled - process already killed)
+    if partial_text.finish() {
+        emitter.send_partial_text(None);
+    }
+
+    // Wait for process to exit (skip if cancelled - process already killed - or
+    // if a persistent process finished its turn and stays running)
     let was_cancelled = last_stop_reason.as_deref() == Some("cancelled");
     if !was_cancelled {
-        let status = wait_for_process(config, &logger, emitter, &mut child).await?;
+        if !turn_complete {
+            let status = wait_for_process(config, &logger, emitter, &mut process.child).await?;
 
-        if let Some(ref logger) = logger {
-            logger.log_line("exit", &format!("status: {}", status));
-        }
+            if let Some(ref logger) = logger {
+                logger.log_line("exit", &format!("status: {}", status));
+            }
 
-        if !status.success() {
-            is_error = true;
+            if !status.success() {
+                is_error = true;
+            }
+        }
+        if fault == Some(Fault::Malformed) {
+            final_output = MALFORMED_OUTPUT.to_string();
         }
     }
 
     if let Some(cost) = total_cost {
         emitter.send_output(format!("[agent:{}] Cost: ${:.4}", config.agent_name, cost));
+    } else if let Some(cost) = estimate_cost(config, detected_model.as_deref(), &usage_totals) {
+        emitter.send_output(format!(
+            "[agent:{}] Cost: ~${:.4} (estimated)",
+            config.agent_name, cost
+        ));
+        total_cost = Some(cost);
     }
+    emitter.record_invocation_usage(total_tokens, total_cost);
 
     if was_cancelled {
         emitter.send_output(format!("[agent:{}] Cancelled", config.agent_name));
@@ -556,6 +842,46 @@ pub async fn run_agent_process<P: AgentStreamParser>(
     })
 }
 
+/// Waits until buffered partial text is due, or forever if nothing is buffered.
+async fn sleep_until_due(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Prices the invocation's token usage when the agent didn't report a cost.
+fn estimate_cost(
+    config: &RunnerConfig,
+    detected_model: Option<&str>,
+    usage: &AgentTokenUsage,
+) -> Option<f64> {
+    let pricing = config.pricing.as_ref()?;
+    let model = detected_model.or(config.model_hint.as_deref())?;
+    let has_usage = usage.input_tokens
+        + usage.output_tokens
+        + usage.cache_creation_tokens
+        + usage.cache_read_tokens
+        > 0;
+    has_usage.then(|| pricing.estimate(model, usage)).flatten()
+}
+
+/// Resolves once cancellation is requested; never resolves without a cancel receiver.
+pub(crate) async fn wait_for_cancel(cancel_rx: &mut Option<watch::Receiver<bool>>) {
+    let Some(rx) = cancel_rx else {
+        return std::future::pending().await;
+    };
+    loop {
+        if *rx.borrow_and_update() {
+            return;
+        }
+        if rx.changed().await.is_err() {
+            // Sender dropped without cancelling
+            return std::future::pending().await;
+        }
+    }
+}
+
 async fn handle_overall_timeout(
     config: &RunnerConfig,
     logger: &Option<AgentLogger>,
@@ -569,7 +895,7 @@ async fn handle_overall_timeout(
         "[agent:{}] ERROR: Exceeded overall timeout of {:?}",
         config.agent_name, config.overall_timeout
     ));
-    let _ = child.kill().await;
+    kill_agent(child).await;
     anyhow::bail!(
         "{} invocation exceeded overall timeout of {:?}",
         config.agent_name,
@@ -590,7 +916,7 @@ async fn handle_activity_timeout(
         "[agent:{}] WARNING: No activity for {:?}, terminating...",
         config.agent_name, config.activity_timeout
     ));
-    let _ = child.kill().await;
+    kill_agent(child).await;
     anyhow::bail!(
         "{} subprocess became unresponsive (no output for {:?})",
         config.agent_name,
@@ -626,7 +952,7 @@ async fn wait_for_process(
                 "[agent:{}] WARNING: Process did not exit within {:?}, force killing...",
                 config.agent_name, PROCESS_WAIT_TIMEOUT
             ));
-            let _ = child.kill().await;
+            kill_agent(child).await;
             anyhow::bail!(
                 "{} process did not exit within {:?} after stream closed",
                 config.agent_name,
@@ -636,18 +962,6 @@ async fn wait_for_process(
     }
 }
 
-/// Helper to convert AgentOutput to AgentResult for compatibility.
-impl From<AgentOutput> for AgentResult {
-    fn from(output: AgentOutput) -> Self {
-        AgentResult {
-            output: output.output,
-            is_error: output.is_error,
-            conversation_id: output.conversation_id,
-            stop_reason: output.stop_reason,
-        }
-    }
-}
-
 #[cfg(test)]
 #[path = "tests/runner_tests.rs"]
 mod tests;
@@ -0,0 +1,77 @@
+//! Coalescing of token-level text deltas for live display.
+//!
+//! Agents with partial messages enabled emit a delta per few tokens. Forwarding each
+//! one would redraw the TUI hundreds of times per second, so deltas are buffered and
+//! released at most once per flush interval.
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Minimum time between two partial text updates of one agent.
+pub const PARTIAL_FLUSH_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Buffers text deltas between flushes.
+#[derive(Debug)]
+pub struct PartialTextCoalescer {
+    pending: String,
+    interval: Duration,
+    last_flush: Option<Instant>,
+    /// Whether any text was released since the last `finish`.
+    streaming: bool,
+}
+
+impl PartialTextCoalescer {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            pending: String::new(),
+            interval,
+            last_flush: None,
+            streaming: false,
+        }
+    }
+
+    /// Buffers a delta, returning the pending text if the interval has elapsed.
+    ///
+    /// The first fragment goes out immediately so output appears promptly.
+    pub fn push(&mut self, delta: &str, now: Instant) -> Option<String> {
+        self.pending.push_str(delta);
+        match self.last_flush {
+            Some(last) if now < last + self.interval => None,
+            _ => self.flush(now),
+        }
+    }
+
+    /// When buffered text becomes due, or `None` if nothing is buffered.
+    pub fn deadline(&self) -> Option<Instant> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(
+            self.last_flush
+                .map_or_else(Instant::now, |last| last + self.interval),
+        )
+    }
+
+    /// Releases the buffered text, if any.
+    pub fn flush(&mut self, now: Instant) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        self.last_flush = Some(now);
+        self.streaming = true;
+        Some(std::mem::take(&mut self.pending))
+    }
+
+    /// Drops buffered text once the complete message has arrived.
+    ///
+    /// Returns whether a stream was in progress and should be ended.
+    pub fn finish(&mut self) -> bool {
+        self.pending.clear();
+        self.last_flush = None;
+        std::mem::take(&mut self.streaming)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/streaming_tests.rs"]
+mod tests;
@@ -0,0 +1,186 @@
+use super::*;
+use crate::planning_paths::{set_home_for_test, TestHomeGuard};
+use tempfile::tempdir;
+
+fn test_env() -> (tempfile::TempDir, TestHomeGuard) {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let guard = set_home_for_test(dir.path().to_path_buf());
+    (dir, guard)
+}
+
+fn usage(phase: &str, agent: &str, tokens: u64, cost: Option<f64>) -> InvocationUsage {
+    InvocationUsage {
+        phase: phase.to_string(),
+        agent: agent.to_string(),
+        tokens,
+        cost,
+    }
+}
+
+/// Stats with one $1 / 10k-token invocation for every phase of the config.
+fn uniform_stats(config: &WorkflowConfig) -> CostStats {
+    let planner = config.workflow.planning.agent.as_str();
+    let mut invocations = vec![
+        usage("Planning", planner, 10_000, Some(1.0)),
+        usage("Revising", planner, 10_000, Some(1.0)),
+    ];
+    for reviewer in &config.workflow.reviewing.agents {
+        invocations.push(usage("Reviewing", reviewer.agent_name(), 10_000, Some(1.0)));
+    }
+    let mut stats = CostStats::default();
+    stats.record(&invocations);
+    stats
+}
+
+#[test]
+fn test_phase_key_strips_iteration() {
+    assert_eq!(phase_key("Planning"), "Planning");
+    assert_eq!(phase_key("Reviewing #2"), "Reviewing");
+    assert_eq!(
+        phase_key("Implementation Review #1"),
+        "Implementation Review"
+    );
+}
+
+#[test]
+fn test_cost_alert_key_and_round() {
+    assert_eq!(cost_alert_key("Reviewing #2 (recovery)"), "reviewing");
+    assert_eq!(
+        cost_alert_key("Implementation Review #1"),
+        "implementation_review"
+    );
+    assert_eq!(cost_round("Reviewing #2 (recovery)"), "Reviewing #2");
+    assert_eq!(cost_round("Planning"), "Planning");
+}
+
+#[test]
+fn test_record_averages_per_phase_and_agent() {
+    let mut stats = CostStats::default();
+    stats.record(&[
+        usage("Planning", "claude", 1_000, Some(2.0)),
+        usage("Planning", "claude", 3_000, Some(4.0)),
+        usage("Planning", "claude", 2_000, None),
+    ]);
+
+    let totals = stats.totals("Planning", "claude").unwrap();
+    assert_eq!(totals.invocations, 3);
+    assert_eq!(totals.avg_tokens(), Some(2_000.0));
+    // Unpriced invocations don't drag the cost average down
+    assert_eq!(totals.avg_cost(), Some(3.0));
+}
+
+#[test]
+fn test_totals_fall_back_to_phase_wide_average() {
+    let mut stats = CostStats::default();
+    stats.record(&[
+        usage("Reviewing", "codex", 1_000, Some(1.0)),
+        usage("Reviewing", "gemini", 3_000, Some(3.0)),
+    ]);
+
+    let totals = stats.totals("Reviewing", "claude").unwrap();
+    assert_eq!(totals.avg_cost(), Some(2.0));
+    assert!(stats.totals("Planning", "claude").is_none());
+}
+
+#[test]
+fn test_estimate_range_spans_iterations() {
+    let config = WorkflowConfig::default_config();
+    let reviewers = config.workflow.reviewing.agents.len() as f64;
+    let stats = uniform_stats(&config);
+
+    let estimate = estimate_workflow_cost(&config, 3, &stats).unwrap();
+    // Best case: plan + one review round
+    assert_eq!(estimate.min_cost, 1.0 + reviewers);
+    // Worst case: plan + three review rounds + two revisions
+    assert_eq!(estimate.max_cost, 1.0 + 3.0 * reviewers + 2.0);
+    assert_eq!(estimate.min_tokens, 10_000 * (1 + reviewers as u64));
+}
+
+#[test]
+fn test_estimate_requires_history() {
+    let config = WorkflowConfig::default_config();
+    assert!(estimate_workflow_cost(&config, 3, &CostStats::default()).is_none());
+}
+
+#[test]
+fn test_estimate_revising_uses_planning_history() {
+    let config = WorkflowConfig::default_config();
+    let planner = config.workflow.planning.agent.clone();
+    let mut invocations = vec![usage("Planning", &planner, 10_000, Some(2.0))];
+    for reviewer in &config.workflow.reviewing.agents {
+        invocations.push(usage("Reviewing", reviewer.agent_name(), 0, Some(0.0)));
+    }
+    let mut stats = CostStats::default();
+    stats.record(&invocations);
+
+    let estimate = estimate_workflow_cost(&config, 2, &stats).unwrap();
+    assert_eq!(estimate.min_cost, 2.0);
+    assert_eq!(estimate.max_cost, 4.0);
+}
+
+#[test]
+fn test_estimate_summary_format() {
+    let estimate = CostEstimate {
+        min_cost: 3.2,
+        max_cost: 7.8,
+        min_tokens: 200_000,
+        max_tokens: 900_000,
+    };
+    assert_eq!(
+        estimate.summary(),
+        "Estimated: $3–8 (~200.0k–900.0k tokens)"
+    );
+
+    let cheap = CostEstimate {
+        min_cost: 0.25,
+        max_cost: 0.25,
+        min_tokens: 5_000,
+        max_tokens: 5_000,
+    };
+    assert_eq!(cheap.summary(), "Estimated: $0.25 (~5.0k tokens)");
+}
+
+#[test]
+fn test_record_session_usage_round_trip() {
+    let (_dir, _guard) = test_env();
+    let config = WorkflowConfig::default_config();
+    assert_eq!(
+        describe_estimate(&config, 3),
+        "Estimated: no cost history yet"
+    );
+
+    let planner = config.workflow.planning.agent.as_str();
+    let mut invocations = vec![usage("Planning", planner, 10_000, Some(1.0))];
+    for reviewer in &config.workflow.reviewing.agents {
+        invocations.push(usage("Reviewing", reviewer.agent_name(), 10_000, Some(1.0)));
+    }
+    record_session_usage(&invocations).unwrap();
+
+    let loaded = CostStats::load().unwrap();
+    assert_eq!(loaded.totals("Planning", planner).unwrap().invocations, 1);
+    assert!(describe_estimate(&config, 3).starts_with("Estimated: $"));
+}
+
+#[test]
+fn test_usage_ledger_round_trip_skips_torn_lines() {
+    let (_dir, _guard) = test_env();
+    append_usage_record("s1", "codex", &usage("Reviewing #1", "codex", 4_000, None)).unwrap();
+    append_usage_record(
+        "s1",
+        "claude",
+        &usage("Planning", "claude", 9_000, Some(0.5)),
+    )
+    .unwrap();
+    let path = planning_paths::session_usage_path("s1").unwrap();
+    let mut content = std::fs::read_to_string(&path).unwrap();
+    content.push_str("{\"recorded_at\":");
+    std::fs::write(&path, content).unwrap();
+
+    let records = load_usage_records(&path).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].provider, "codex");
+    assert_eq!(records[0].phase, "Reviewing #1");
+    assert_eq!(records[0].cost, None);
+    assert_eq!(records[1].tokens, 9_000);
+    assert_eq!(records[1].cost, Some(0.5));
+}
@@ -0,0 +1,255 @@
+use super::*;
+use crate::agents::codex::parser::CodexParser;
+use crate::agents::pricing::PricingTable;
+use crate::agents::runner::{run_agent_process, ContextEmitter, RunnerConfig};
+use crate::agents::AgentContext;
+use crate::session_daemon::SessionLogger;
+use crate::tui::{Event, SessionEventSender};
+use std::path::PathBuf;
+use tokio::process::Command;
+use tokio::sync::{mpsc, watch};
+
+/// Upper bound for any faulted run; exceeding it means the runner deadlocked.
+const RUN_DEADLINE: Duration = Duration::from_secs(10);
+
+fn spec(s: &str) -> FaultSpec {
+    s.parse().expect("valid fault spec")
+}
+
+fn make_emitter() -> (ContextEmitter, mpsc::UnboundedReceiver<Event>) {
+    let session_id = format!("test-{}", uuid::Uuid::new_v4());
+    let session_logger = Arc::new(SessionLogger::new(&session_id).expect("test logger"));
+    let (tx, rx) = mpsc::unbounded_channel();
+    let context = AgentContext {
+        session_sender: SessionEventSender::new(0, 0, tx),
+        phase: "Testing".to_string(),
+        conversation_id: None,
+        resume_strategy: Default::default(),
+        cancel_rx: None,
+        session_logger,
+        pricing: PricingTable::default(),
+        provider_concurrency: Default::default(),
+        cost_alerts: Default::default(),
+        context_limits: Default::default(),
+        fault_injector: None,
+    };
+    (
+        ContextEmitter::new(context, "fake".to_string(), "codex"),
+        rx,
+    )
+}
+
+fn shell(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(script);
+    command
+}
+
+fn faulted_config(fault_spec: &str) -> RunnerConfig {
+    RunnerConfig::new("fake".to_string(), PathBuf::from("."))
+        .with_fault_injector(Some(FaultInjector::new(spec(fault_spec))))
+}
+
+/// The agent's pid, which is also its process group id.
+fn started_pid(rx: &mut mpsc::UnboundedReceiver<Event>) -> u32 {
+    while let Ok(event) = rx.try_recv() {
+        if let Event::SessionCliInstanceStarted { pid: Some(pid), .. } = event {
+            return pid;
+        }
+    }
+    panic!("no CLI instance was started");
+}
+
+/// Waits (briefly) for the agent and every process in its group to be gone,
+/// reaped or not.
+async fn assert_exited(pgid: u32) {
+    for _ in 0..50 {
+        if group_members(pgid).is_empty() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!(
+        "processes {:?} of agent group {} were orphaned",
+        group_members(pgid),
+        pgid
+    );
+}
+
+/// Live (non-zombie) processes in the process group.
+fn group_members(pgid: u32) -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| {
+            let Ok(stat) = std::fs::read_to_string(format!("/proc/{}/stat", pid)) else {
+                return false;
+            };
+            // Fields after the command name: state, ppid, pgrp
+            let fields: Vec<&str> = stat
+                .rsplit_once(')')
+                .map(|(_, rest)| rest.split_whitespace().collect())
+                .unwrap_or_default();
+            fields.first() != Some(&"Z")
+                && fields.get(2).and_then(|pgrp| pgrp.parse().ok()) == Some(pgid)
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_fault_spec() {
+    let parsed = spec("fail=0.1, stall=0.2,malformed=0.3,stall_ms=250,seed=7");
+    assert_eq!(parsed.fail, 0.1);
+    assert_eq!(parsed.stall, 0.2);
+    assert_eq!(parsed.malformed, 0.3);
+    assert_eq!(parsed.stall_for, Duration::from_millis(250));
+    assert_eq!(parsed.seed, Some(7));
+    assert_eq!(spec(""), FaultSpec::default());
+
+    for invalid in [
+        "fail",
+        "fail=2",
+        "stall=-0.1",
+        "bogus=1",
+        "fail=0.6,stall=0.6",
+    ] {
+        assert!(invalid.parse::<FaultSpec>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_seeded_draws_are_reproducible() {
+    let draws = |seed: u64| {
+        let injector = FaultInjector::new(spec(&format!(
+            "fail=0.25,stall=0.25,malformed=0.25,seed={}",
+            seed
+        )));
+        (0..32).map(|_| injector.draw()).collect::<Vec<_>>()
+    };
+    let first = draws(42);
+    assert_eq!(first, draws(42));
+    assert!(first.contains(&None));
+    assert!(first.contains(&Some(Fault::Fail)));
+
+    let never = FaultInjector::new(spec("seed=1"));
+    assert!((0..32).all(|_| never.draw().is_none()));
+}
+
+#[test]
+fn test_runners_only_inject_faults_they_are_given() {
+    // Nothing is injected unless the invocation's context carries an injector
+    assert!(RunnerConfig::new("fake".to_string(), PathBuf::from("."))
+        .fault_injector
+        .is_none());
+    assert!(cli_fault_injector().is_none());
+
+    let config = faulted_config("stall=1");
+    let injector = config.fault_injector.expect("injector");
+    assert_eq!(injector.draw(), Some(Fault::Stall));
+}
+
+#[tokio::test]
+async fn test_injected_failure_kills_agent() {
+    let (emitter, mut rx) = make_emitter();
+    let result = tokio::time::timeout(
+        RUN_DEADLINE,
+        run_agent_process(
+            shell("sleep 30 & wait"),
+            &faulted_config("fail=1"),
+            &mut CodexParser::new(),
+            &emitter,
+        ),
+    )
+    .await
+    .expect("faulted run deadlocked");
+
+    let err = result.expect_err("injected failure").to_string();
+    assert!(err.contains("injected fault"), "{}", err);
+    assert_exited(started_pid(&mut rx)).await;
+}
+
+#[tokio::test]
+async fn test_injected_stall_times_out_and_kills_agent() {
+    let (emitter, mut rx) = make_emitter();
+    let result = tokio::time::timeout(
+        RUN_DEADLINE,
+        run_agent_process(
+            shell("echo working; sleep 30 & wait"),
+            &faulted_config("stall=1,stall_ms=100"),
+            &mut CodexParser::new(),
+            &emitter,
+        ),
+    )
+    .await
+    .expect("stalled run deadlocked");
+
+    let err = result.expect_err("stalled agent").to_string();
+    assert!(err.contains("unresponsive"), "{}", err);
+    assert_exited(started_pid(&mut rx)).await;
+}
+
+#[tokio::test]
+async fn test_cancel_during_stall_kills_agent() {
+    let (emitter, mut rx) = make_emitter();
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    let config = faulted_config("stall=1,stall_ms=60000").with_cancel_rx(cancel_rx);
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let _ = cancel_tx.send(true);
+    });
+
+    let output = tokio::time::timeout(
+        RUN_DEADLINE,
+        run_agent_process(
+            shell("sleep 30 & wait"),
+            &config,
+            &mut CodexParser::new(),
+            &emitter,
+        ),
+    )
+    .await
+    .expect("cancelled run deadlocked")
+    .expect("cancellation is not an error");
+
+    assert_eq!(output.stop_reason.as_deref(), Some("cancelled"));
+    assert_exited(started_pid(&mut rx)).await;
+}
+
+#[tokio::test]
+async fn test_injected_malformed_output_replaces_answer() {
+    let (emitter, _rx) = make_emitter();
+    let output = tokio::time::timeout(
+        RUN_DEADLINE,
+        run_agent_process(
+            shell("echo plan is ready"),
+            &faulted_config("malformed=1"),
+            &mut CodexParser::new(),
+            &emitter,
+        ),
+    )
+    .await
+    .expect("malformed run deadlocked")
+    .expect("malformed output still completes");
+
+    assert_eq!(output.output, MALFORMED_OUTPUT);
+}
+
+#[tokio::test]
+async fn test_dropped_run_does_not_orphan_agent() {
+    let (emitter, mut rx) = make_emitter();
+    let config =
+        RunnerConfig::new("fake".to_string(), PathBuf::from(".")).with_fault_injector(None);
+    let run = run_agent_process(
+        shell("sleep 30 & wait"),
+        &config,
+        &mut CodexParser::new(),
+        &emitter,
+    );
+
+    assert!(tokio::time::timeout(Duration::from_millis(200), run)
+        .await
+        .is_err());
+    assert_exited(started_pid(&mut rx)).await;
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn test_stderr_capture_keeps_the_last_lines() {
+    let mut capture = StderrCapture::new(None, "claude");
+    assert!(capture.tail().is_empty());
+
+    for i in 0..STDERR_TAIL_LINES + 3 {
+        capture.push(&format!("warning {}", i));
+    }
+
+    let tail = capture.tail();
+    assert_eq!(tail.len(), STDERR_TAIL_LINES);
+    assert_eq!(tail.first().map(String::as_str), Some("warning 3"));
+    assert_eq!(
+        tail.last(),
+        Some(&format!("warning {}", STDERR_TAIL_LINES + 2))
+    );
+    // Nothing is written without a session to log to
+    assert!(capture.log_path().is_none());
+}
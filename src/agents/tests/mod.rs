@@ -8,6 +8,11 @@ fn test_agent_type_from_config_claude() {
         args: vec!["-p".to_string()],
         allowed_tools: vec![],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let agent = AgentType::from_config("claude", &config, PathBuf::from(".")).unwrap();
     assert_eq!(agent.name(), "claude");
@@ -20,6 +25,11 @@ fn test_agent_type_from_config_codex() {
         args: vec!["exec".to_string()],
         allowed_tools: vec![],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let agent = AgentType::from_config("codex", &config, PathBuf::from(".")).unwrap();
     assert_eq!(agent.name(), "codex");
@@ -32,6 +42,11 @@ fn test_agent_type_from_config_gemini() {
         args: vec!["-p".to_string()],
         allowed_tools: vec![],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let agent = AgentType::from_config("gemini", &config, PathBuf::from(".")).unwrap();
     assert_eq!(agent.name(), "gemini");
@@ -44,7 +59,29 @@ fn test_agent_type_from_config_unknown() {
         args: vec![],
         allowed_tools: vec![],
         session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
     };
     let result = AgentType::from_config("unknown", &config, PathBuf::from("."));
     assert!(result.is_err());
 }
+
+#[test]
+fn test_provider_semaphore_is_shared_until_limit_changes() {
+    let first = provider_semaphore("test-provider", 2);
+    let permit = first.clone().try_acquire_owned().unwrap();
+    assert_eq!(
+        provider_semaphore("test-provider", 2).available_permits(),
+        1
+    );
+
+    // A new limit starts fresh; the old permit stays valid until dropped
+    let resized = provider_semaphore("test-provider", 1);
+    assert!(!Arc::ptr_eq(&first, &resized));
+    assert_eq!(resized.available_permits(), 1);
+    drop(permit);
+    assert_eq!(first.available_permits(), 2);
+}
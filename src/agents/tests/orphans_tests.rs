@@ -0,0 +1,159 @@
+use super::*;
+use tempfile::tempdir;
+
+/// PID of a process that has exited and been reaped.
+fn dead_pid() -> u32 {
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+fn registry(session_id: &str) -> PathBuf {
+    planning_paths::session_agent_processes_path(session_id).unwrap()
+}
+
+fn record(pid: u32, owner_pid: u32) -> AgentProcessRecord {
+    AgentProcessRecord {
+        owner_pid,
+        ..AgentProcessRecord::new(pid, "claude")
+    }
+}
+
+#[test]
+fn test_tracked_process_is_removed_on_drop() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+
+    let tracked = TrackedAgentProcess::register("session-a", std::process::id(), "claude").unwrap();
+    let content = fs::read_to_string(registry("session-a")).unwrap();
+    let records: Vec<AgentProcessRecord> = serde_json::from_str(&content).unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].owner_pid, std::process::id());
+
+    drop(tracked);
+    let content = fs::read_to_string(registry("session-a")).unwrap();
+    assert_eq!(content.trim(), "[]");
+}
+
+#[test]
+fn test_only_agents_of_dead_owners_are_orphans() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+    let mut agent = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    let dead_owner = dead_pid();
+
+    let _orphan =
+        TrackedAgentProcess::register_at(registry("killed"), record(agent.id(), dead_owner))
+            .unwrap();
+    let _owned =
+        TrackedAgentProcess::register_at(registry("live"), record(agent.id(), std::process::id()))
+            .unwrap();
+    let _exited =
+        TrackedAgentProcess::register_at(registry("killed"), record(dead_pid(), dead_owner))
+            .unwrap();
+
+    let orphans = find_orphaned_agents().unwrap();
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].session_id, "killed");
+    assert_eq!(orphans[0].record.pid, agent.id());
+    // The exited agent was pruned from the registry
+    let content = fs::read_to_string(registry("killed")).unwrap();
+    assert_eq!(content.matches("\"pid\"").count(), 1);
+
+    let _ = agent.kill();
+    let _ = agent.wait();
+}
+
+#[test]
+fn test_reused_pid_is_not_the_recorded_agent() {
+    let recycled = AgentProcessRecord {
+        start_ticks: process_start_ticks(std::process::id()).map(|t| t + 1),
+        ..record(std::process::id(), dead_pid())
+    };
+    if recycled.start_ticks.is_some() {
+        assert!(!recycled.is_running());
+    }
+    assert!(record(std::process::id(), dead_pid()).is_running());
+}
+
+#[tokio::test]
+async fn test_kill_orphaned_agents() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+    let mut agent = std::process::Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap();
+    // An agent in planning's own group is only ever signalled on its own
+    assert_eq!(record(agent.id(), dead_pid()).pgid, None);
+    let _orphan =
+        TrackedAgentProcess::register_at(registry("killed"), record(agent.id(), dead_pid()))
+            .unwrap();
+
+    let orphans = find_orphaned_agents().unwrap();
+    assert_eq!(kill_orphaned_agents(&orphans).await, 1);
+    assert!(
+        agent.wait().unwrap().code().is_none(),
+        "agent was signalled"
+    );
+    assert!(find_orphaned_agents().unwrap().is_empty());
+}
+
+/// Live (non-zombie) processes in the process group.
+fn group_members(pgid: u32) -> Vec<u32> {
+    fs::read_dir("/proc")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| {
+            let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap_or_default();
+            // Fields after the command name: state, ppid, pgrp
+            let fields: Vec<&str> = stat
+                .rsplit_once(')')
+                .map(|(_, rest)| rest.split_whitespace().collect())
+                .unwrap_or_default();
+            fields.first() != Some(&"Z")
+                && fields.get(2).and_then(|pgrp| pgrp.parse().ok()) == Some(pgid)
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_kill_orphaned_agents_kills_their_tools() {
+    use std::os::unix::process::CommandExt;
+
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+    let mut agent = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 30 & wait")
+        .process_group(0)
+        .spawn()
+        .unwrap();
+    let pgid = agent.id();
+    let orphan = record(pgid, dead_pid());
+    assert_eq!(orphan.pgid, Some(pgid));
+    let _orphan = TrackedAgentProcess::register_at(registry("killed"), orphan).unwrap();
+
+    let orphans = find_orphaned_agents().unwrap();
+    assert_eq!(kill_orphaned_agents(&orphans).await, 1);
+    let _ = agent.wait();
+    let mut survivors = group_members(pgid);
+    for _ in 0..50 {
+        if survivors.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        survivors = group_members(pgid);
+    }
+    assert!(
+        survivors.is_empty(),
+        "tools {:?} of the agent survived",
+        survivors
+    );
+}
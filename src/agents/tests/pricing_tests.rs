@@ -0,0 +1,65 @@
+use super::*;
+use crate::config::SessionPersistenceConfig;
+
+fn agent_config(command: &str, args: &[&str]) -> AgentConfig {
+    AgentConfig {
+        command: command.to_string(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        allowed_tools: vec![],
+        session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: None,
+        path_map: Default::default(),
+        warm_pool: false,
+        memory_limit_mb: None,
+    }
+}
+
+#[test]
+fn test_lookup_uses_longest_prefix() {
+    let table = PricingTable::default();
+    let opus = table.lookup("claude-opus-4-5-20251101").unwrap();
+    assert_eq!(opus.input_per_mtok, 5.0);
+    let flash = table.lookup("Gemini-2.5-Flash").unwrap();
+    assert_eq!(flash.output_per_mtok, 2.50);
+    assert!(table.lookup("llama-3").is_none());
+}
+
+#[test]
+fn test_overrides_replace_defaults_and_cache_rates_fall_back_to_input() {
+    let overrides = BTreeMap::from([(
+        "gpt-5".to_string(),
+        ModelPricing {
+            input_per_mtok: 2.0,
+            output_per_mtok: 8.0,
+            cache_write_per_mtok: None,
+            cache_read_per_mtok: None,
+        },
+    )]);
+    let table = PricingTable::with_overrides(&overrides);
+    let usage = AgentTokenUsage {
+        input_tokens: 500_000,
+        output_tokens: 250_000,
+        cache_creation_tokens: 0,
+        cache_read_tokens: 500_000,
+    };
+    // 0.5M * 2 + 0.25M * 8 + 0.5M * 2 (cache reads at the input rate)
+    let cost = table.estimate("gpt-5-codex", &usage).unwrap();
+    assert!((cost - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_model_hint_prefers_model_arg_over_command() {
+    assert_eq!(
+        model_hint(&agent_config("codex", &["exec", "-m", "gpt-5-mini"])),
+        "gpt-5-mini"
+    );
+    assert_eq!(
+        model_hint(&agent_config("gemini", &["--model=gemini-2.5-flash"])),
+        "gemini-2.5-flash"
+    );
+    assert_eq!(
+        model_hint(&agent_config("/usr/local/bin/codex", &["exec"])),
+        "codex"
+    );
+}
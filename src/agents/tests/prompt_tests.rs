@@ -40,3 +40,15 @@ fn test_prepare_prompt_no_system() {
     assert_eq!(prepared.prompt, "user prompt");
     assert_eq!(prepared.system_prompt_arg, None);
 }
+
+#[test]
+fn test_command_output_is_added_as_user_context() {
+    assert_eq!(with_command_output("plan".to_string(), &[]), "plan");
+
+    let outputs = vec!["tests: 2 failed".to_string(), "lint: clean".to_string()];
+    let prompt = with_command_output("plan".to_string(), &outputs);
+    assert_eq!(
+        prompt,
+        "plan\n\n<user-context>\ntests: 2 failed\n\nlint: clean\n</user-context>"
+    );
+}
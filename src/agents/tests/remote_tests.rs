@@ -0,0 +1,108 @@
+use super::*;
+use crate::config::SessionPersistenceConfig;
+
+fn path_map() -> BTreeMap<PathBuf, PathBuf> {
+    BTreeMap::from([
+        (PathBuf::from("/home/me"), PathBuf::from("/data/me")),
+        (PathBuf::from("/home/me/repo"), PathBuf::from("/srv/repo")),
+    ])
+}
+
+fn agent_config(execution: Option<&str>) -> AgentConfig {
+    AgentConfig {
+        command: "claude".to_string(),
+        args: vec!["-p".to_string()],
+        allowed_tools: vec![],
+        session_persistence: SessionPersistenceConfig::default(),
+        permission_prompts: false,
+        execution: execution.map(str::to_string),
+        path_map: path_map(),
+        warm_pool: false,
+        memory_limit_mb: None,
+    }
+}
+
+#[test]
+fn test_parse_ssh_target() {
+    assert_eq!(SshTarget::parse("local").unwrap(), None);
+    assert_eq!(
+        SshTarget::parse("ssh://me@bigbox").unwrap(),
+        Some(SshTarget {
+            destination: "me@bigbox".to_string(),
+            port: None,
+        })
+    );
+    assert_eq!(
+        SshTarget::parse("ssh://bigbox:2222").unwrap(),
+        Some(SshTarget {
+            destination: "bigbox".to_string(),
+            port: Some(2222),
+        })
+    );
+    assert!(SshTarget::parse("docker://box").is_err());
+    assert!(SshTarget::parse("ssh://me@").is_err());
+    assert!(SshTarget::parse("ssh://box:port").is_err());
+}
+
+#[test]
+fn test_translate_path_uses_longest_prefix() {
+    let map = path_map();
+    assert_eq!(
+        translate_path(Path::new("/home/me/repo/src"), &map),
+        PathBuf::from("/srv/repo/src")
+    );
+    assert_eq!(
+        translate_path(Path::new("/home/me/notes"), &map),
+        PathBuf::from("/data/me/notes")
+    );
+    assert_eq!(
+        translate_path(Path::new("/tmp/x"), &map),
+        PathBuf::from("/tmp/x")
+    );
+}
+
+#[test]
+fn test_translate_text_rewrites_embedded_paths() {
+    let text = "Plan file: /home/me/repo/plan.md\nNotes: /home/me/notes.md";
+    assert_eq!(
+        translate_text(text, &path_map()),
+        "Plan file: /srv/repo/plan.md\nNotes: /data/me/notes.md"
+    );
+}
+
+#[test]
+fn test_remote_command_wraps_in_ssh() {
+    let mut cmd = Command::new("claude");
+    cmd.arg("-p")
+        .arg("Review /home/me/repo/plan.md, it's ready");
+    let config = agent_config(Some("ssh://me@bigbox:2222"));
+
+    let ssh = remote_command(cmd, &config, Path::new("/home/me/repo")).unwrap();
+    let std_cmd = ssh.as_std();
+    assert_eq!(std_cmd.get_program(), "ssh");
+    let args: Vec<String> = std_cmd
+        .get_args()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(
+        args,
+        vec![
+            "-T",
+            "-o",
+            "BatchMode=yes",
+            "-p",
+            "2222",
+            "me@bigbox",
+            "--",
+            r"cd /srv/repo && exec claude -p 'Review /srv/repo/plan.md, it'\''s ready'",
+        ]
+    );
+}
+
+#[test]
+fn test_remote_command_leaves_local_agents_unchanged() {
+    let cmd = Command::new("claude");
+    let config = agent_config(None);
+    let cmd = remote_command(cmd, &config, Path::new("/home/me/repo")).unwrap();
+    assert_eq!(cmd.as_std().get_program(), "claude");
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::agents::AgentResult;
 
 #[test]
 fn test_runner_config_defaults() {
@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn test_first_delta_is_released_immediately() {
+    let mut coalescer = PartialTextCoalescer::new(PARTIAL_FLUSH_INTERVAL);
+    let now = Instant::now();
+    assert_eq!(coalescer.push("Hel", now), Some("Hel".to_string()));
+    assert!(coalescer.deadline().is_none());
+}
+
+#[test]
+fn test_deltas_within_interval_are_coalesced() {
+    let mut coalescer = PartialTextCoalescer::new(PARTIAL_FLUSH_INTERVAL);
+    let start = Instant::now();
+    coalescer.push("a", start);
+
+    let soon = start + Duration::from_millis(10);
+    assert_eq!(coalescer.push("b", soon), None);
+    assert_eq!(coalescer.push("c", soon), None);
+    assert_eq!(coalescer.deadline(), Some(start + PARTIAL_FLUSH_INTERVAL));
+
+    let due = start + PARTIAL_FLUSH_INTERVAL;
+    assert_eq!(coalescer.push("d", due), Some("bcd".to_string()));
+}
+
+#[test]
+fn test_flush_releases_pending_text() {
+    let mut coalescer = PartialTextCoalescer::new(PARTIAL_FLUSH_INTERVAL);
+    let start = Instant::now();
+    coalescer.push("a", start);
+    coalescer.push("b", start);
+    assert_eq!(coalescer.flush(start), Some("b".to_string()));
+    assert_eq!(coalescer.flush(start), None);
+}
+
+#[test]
+fn test_finish_drops_pending_text_and_reports_stream() {
+    let mut coalescer = PartialTextCoalescer::new(PARTIAL_FLUSH_INTERVAL);
+    assert!(!coalescer.finish());
+
+    let start = Instant::now();
+    coalescer.push("a", start);
+    coalescer.push("b", start);
+    assert!(coalescer.finish());
+    assert!(coalescer.deadline().is_none());
+    assert!(!coalescer.finish());
+    // A new stream starts without waiting for the old interval
+    assert_eq!(coalescer.push("c", start), Some("c".to_string()));
+}
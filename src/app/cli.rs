@@ -1,4 +1,8 @@
-use clap::Parser;
+use crate::app::self_update::UpdateChannel;
+use crate::config::AgentArgs;
+use crate::domain::types::WatchSpec;
+use crate::tui::NotifyMode;
+use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -6,6 +10,9 @@ use std::path::PathBuf;
 #[command(about = "Iterative planning workflow orchestrator using Claude Code")]
 #[command(version)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+
     #[arg(trailing_var_arg = true)]
     pub objective: Vec<String>,
 
@@ -32,6 +39,11 @@ pub struct Cli {
     #[arg(long)]
     pub resume_session: Option<String>,
 
+    /// With --resume-session, take over a session open in another process
+    /// (its workflow stops at the next phase boundary) instead of following it read-only
+    #[arg(long, requires = "resume_session")]
+    pub takeover: bool,
+
     /// List all available session snapshots
     #[arg(long)]
     pub list_sessions: bool,
@@ -44,10 +56,26 @@ pub struct Cli {
     #[arg(long)]
     pub older_than: Option<u32>,
 
+    /// Kill agent processes left running by planning sessions that were killed
+    #[arg(long)]
+    pub kill_orphans: bool,
+
+    /// Show this user's session daemon, its connection details and isolation
+    #[arg(long)]
+    pub daemon_status: bool,
+
     /// Internal: Run as session daemon (used by connect-or-spawn)
     #[arg(long, hide = true)]
     pub session_daemon: bool,
 
+    /// Internal: Run as an agent's tool permission hook, asking the session at SOCKET
+    #[arg(long, hide = true, value_name = "SOCKET")]
+    pub permission_hook: Option<PathBuf>,
+
+    /// Testing: randomly fail, stall, or corrupt agent runs (e.g. "fail=0.1,stall=0.05,seed=7")
+    #[arg(long, hide = true, value_name = "SPEC")]
+    pub fault_inject: Option<String>,
+
     /// Disable session tracking (useful for debugging)
     #[arg(long)]
     pub no_daemon: bool,
@@ -71,4 +99,224 @@ pub struct Cli {
     /// Port for host mode TCP server (default: 17717)
     #[arg(long, default_value = "17717")]
     pub port: u16,
+
+    /// Serve a read-only REST API of the host's sessions on this port (host mode)
+    #[arg(long, value_name = "PORT", requires = "host")]
+    pub api_port: Option<u16>,
+
+    /// Run the planning workflow without a TUI, auto-answering decision prompts
+    #[arg(long)]
+    pub headless: bool,
+
+    /// Archive the session as a ZIP when a headless run finishes (used with --headless)
+    #[arg(long)]
+    pub auto_archive: bool,
+
+    /// Import the objective and context from a Jira or Linear issue (e.g. JIRA-1234)
+    #[arg(long, value_name = "ID")]
+    pub from_issue: Option<String>,
+
+    /// Attach trimmed logs of a failing GitHub Actions run (run URL or ID) to the planning prompt
+    #[arg(long, value_name = "URL_OR_ID")]
+    pub attach_ci_run: Option<String>,
+
+    /// Target one package of a cargo or pnpm workspace: plan, index and implement
+    /// inside it, with its boundaries spelled out to the planner and reviewers
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+
+    /// Review with a named reviewer preset from the workflow config (e.g. fast, thorough)
+    #[arg(long, value_name = "PRESET")]
+    pub reviewers: Option<String>,
+
+    /// Extra CLI arguments for one agent in this run only (repeatable), e.g.
+    /// --agent-arg claude="--model opus-4"
+    #[arg(long = "agent-arg", value_name = "AGENT=ARGS", value_parser = AgentArgs::parse)]
+    pub agent_args: Vec<AgentArgs>,
+
+    /// Suspend sessions left waiting for approval this long (0 disables)
+    #[arg(long, value_name = "MINUTES", default_value = "120")]
+    pub idle_suspend_minutes: u64,
+
+    /// Record the TUI to an asciinema-compatible file (replay with `planning play`)
+    #[arg(long, value_name = "PATH")]
+    pub record_ui: Option<PathBuf>,
+
+    /// After the plan is accepted, watch files matching this glob (repeatable,
+    /// e.g. 'src/api/**') and offer to re-plan with the diff when they change
+    #[arg(long, value_name = "GLOB", conflicts_with = "headless")]
+    pub watch: Vec<String>,
+
+    /// With --watch, start re-planning on changes without asking
+    #[arg(long, requires = "watch")]
+    pub auto: bool,
+
+    /// Alert on background tab events with the terminal bell or a desktop notification
+    #[arg(long, value_enum, value_name = "MODE", default_value = "off")]
+    pub notify: NotifyMode,
+}
+
+impl Cli {
+    /// The `--watch` settings, if any glob was given.
+    pub fn watch_spec(&self) -> Option<WatchSpec> {
+        (!self.watch.is_empty()).then(|| WatchSpec {
+            globs: self.watch.clone(),
+            auto: self.auto,
+        })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum CliCommand {
+    /// Manage daemon-side scheduled workflows
+    Schedule {
+        #[command(subcommand)]
+        action: ScheduleAction,
+    },
+    /// Manage versioned skill packs and this project's pins
+    Skills {
+        #[command(subcommand)]
+        action: SkillsAction,
+    },
+    /// Check agent CLIs, auth, git, the daemon and the terminal, and suggest fixes
+    Doctor,
+    /// Install the newest release on the update channel, verified and with rollback
+    Update {
+        /// Switch to this channel (saved for later updates and `/update`)
+        #[arg(long, value_enum)]
+        channel: Option<UpdateChannel>,
+    },
+    /// Score reviewer configurations on bundled plans with known defects
+    Eval {
+        #[command(subcommand)]
+        action: EvalAction,
+    },
+    /// Plan one objective several times under each of several workflow configs
+    /// (unattended, like --headless) and compare iterations, cost and reviewer
+    /// verdict stability
+    Experiment {
+        /// Objective every run plans
+        #[arg(long)]
+        objective: String,
+        /// Workflow config files to compare, comma separated
+        #[arg(long, value_delimiter = ',', required = true)]
+        arms: Vec<PathBuf>,
+        /// Runs per arm
+        #[arg(long, default_value = "3")]
+        runs: u32,
+    },
+    /// Report token and cost usage across sessions by provider, repository and feature
+    Usage {
+        /// Only count usage since this age (`24h`, `7d`, `2w`) or date (`2026-10-01`)
+        #[arg(long, default_value = "7d")]
+        since: String,
+
+        /// Also write one row per provider, repository and feature to this CSV file
+        #[arg(long, value_name = "PATH")]
+        csv: Option<PathBuf>,
+    },
+    /// Replay a TUI recording made with --record-ui
+    Play {
+        /// Recording file
+        recording: PathBuf,
+
+        /// Playback speed multiplier (`+`/`-` adjust it while playing)
+        #[arg(long, default_value = "1.0")]
+        speed: f64,
+
+        /// Cap pauses between frames at this many seconds
+        #[arg(long, value_name = "SECONDS", default_value = "2.0")]
+        max_idle: f64,
+    },
+    /// Implement an existing plan without planning or review, then run the
+    /// implementation review loop (unattended, like --headless)
+    Implement {
+        /// Plan markdown file, recorded in the session as already approved
+        #[arg(long, value_name = "PATH")]
+        plan: PathBuf,
+    },
+    /// Print a read-only share token for a session, for use with `planning watch`
+    Share {
+        /// Workflow session ID (from --list-sessions)
+        session_id: String,
+    },
+    /// Stream a shared session's events and agent output from a host
+    Watch {
+        /// Share token printed by `planning share`
+        token: String,
+
+        /// Host address, as HOST or HOST:PORT (default port 17717)
+        #[arg(long, value_name = "ADDR", default_value = "localhost")]
+        host: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EvalAction {
+    /// Run the reviewer panel on each fixture plan and report detection rates
+    /// and false positives (pick the panel with --config, --reviewers, --agent-arg)
+    Reviewers {
+        /// Only review this fixture (repeatable; default: all)
+        #[arg(long = "fixture", value_name = "ID")]
+        fixtures: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ScheduleAction {
+    /// Schedule a recurring headless planning run
+    Add {
+        /// When to run, e.g. "every monday 09:00", "daily 18:30", "every 6h"
+        #[arg(long)]
+        when: String,
+
+        /// Repository to plan in (defaults to the current directory)
+        #[arg(long)]
+        repo: Option<PathBuf>,
+
+        #[arg(short, long, default_value = "3")]
+        max_iterations: u32,
+
+        /// Archive each finished session as a ZIP under ~/.planning-agent/archive/
+        #[arg(long)]
+        auto_archive: bool,
+
+        #[arg(required = true, trailing_var_arg = true)]
+        objective: Vec<String>,
+    },
+    /// List scheduled workflows
+    List,
+    /// Remove a scheduled workflow
+    Rm {
+        /// Schedule ID (from `planning schedule list`)
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SkillsAction {
+    /// List built-in skills, installed packs and this project's pins
+    List,
+    /// Install a skill pack from a directory containing pack.json
+    Install {
+        /// Pack directory (pack.json plus <skill>/SKILL.md files)
+        source: PathBuf,
+
+        /// Pin the installed version in this project's .planning-agent.toml
+        #[arg(long)]
+        pin: bool,
+    },
+    /// Reinstall packs from their source directories and move project pins along
+    Update {
+        /// Pack to update (defaults to every installed pack)
+        name: Option<String>,
+    },
+    /// Remove an installed pack and unpin it from this project
+    Remove {
+        name: String,
+
+        /// Only remove this version
+        #[arg(long)]
+        version: Option<String>,
+    },
 }
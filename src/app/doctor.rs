@@ -0,0 +1,497 @@
+//! Environment diagnostics for `planning doctor`.
+//!
+//! Each check reports ok, a warning or a failure, with a suggested fix for anything
+//! that is not ok. The command exits with an error when any check fails, so it can
+//! also gate scripted setups.
+
+use crate::account_usage::credentials;
+use crate::agents::orphans::process_alive;
+use crate::app::tui_runner::load_workflow_from_selection;
+use crate::planning_paths;
+use crate::rpc::PortFileContent;
+use crate::session_daemon::rpc_client::probe_daemon;
+use crate::update::BUILD_SHA;
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+
+/// Time a `--version` call may take before the CLI counts as broken.
+const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time the daemon gets to answer the reachability probe.
+const DAEMON_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Agent CLIs the built-in workflows use, with install and login hints.
+pub(crate) const KNOWN_CLIS: &[KnownCli] = &[
+    KnownCli {
+        command: "claude",
+        install: "npm install -g @anthropic-ai/claude-code",
+        login: "run `claude` and use /login, or set ANTHROPIC_API_KEY",
+    },
+    KnownCli {
+        command: "codex",
+        install: "npm install -g @openai/codex",
+        login: "run `codex login`",
+    },
+    KnownCli {
+        command: "gemini",
+        install: "npm install -g @google/gemini-cli",
+        login: "run `gemini` and sign in",
+    },
+];
+
+pub(crate) struct KnownCli {
+    pub command: &'static str,
+    pub install: &'static str,
+    pub login: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Ok,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Warn => "warn",
+            CheckStatus::Fail => "FAIL",
+        }
+    }
+}
+
+/// Outcome of a single diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// Suggested fix, shown for warnings and failures.
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn ok(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            status: CheckStatus::Fail,
+            ..Self::warn(name, detail, fix)
+        }
+    }
+}
+
+/// Runs every check without printing; the TUI shows the report in a session's output.
+pub async fn run_checks(working_dir: &Path) -> Vec<DoctorCheck> {
+    let mut checks = check_agent_clis(working_dir).await;
+    checks.extend(check_git(working_dir).await);
+    checks.extend(check_planning_dir());
+    checks.extend(check_daemon().await);
+    checks.extend(check_terminal());
+    checks
+}
+
+/// Runs all checks, prints the report and fails if any check failed.
+pub async fn run_doctor(working_dir: &Path) -> Result<()> {
+    let checks = run_checks(working_dir).await;
+
+    print!("{}", format_report(&checks));
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{} check(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Renders the checks as an aligned list with fixes beneath problems.
+pub fn format_report(checks: &[DoctorCheck]) -> String {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for check in checks {
+        out.push_str(&format!(
+            "[{:<4}] {:<width$}  {}\n",
+            check.status.label(),
+            check.name,
+            check.detail,
+        ));
+        if let Some(fix) = check
+            .fix
+            .as_ref()
+            .filter(|_| check.status != CheckStatus::Ok)
+        {
+            out.push_str(&format!("       {:<width$}  fix: {}\n", "", fix));
+        }
+    }
+    let warnings = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Warn)
+        .count();
+    let failures = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    out.push_str(&format!(
+        "\n{} check(s): {} warning(s), {} failure(s)\n",
+        checks.len(),
+        warnings,
+        failures
+    ));
+    out
+}
+
+/// First non-empty output line of `<program> --version`.
+pub(crate) async fn command_version(program: &Path) -> Result<String> {
+    let output = tokio::time::timeout(
+        VERSION_TIMEOUT,
+        tokio::process::Command::new(program)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("`--version` timed out"))??;
+    if !output.status.success() {
+        anyhow::bail!("`--version` exited with {}", output.status);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("unknown version")
+        .trim()
+        .to_string())
+}
+
+/// Checks every agent CLI the workflow uses, plus the known ones it does not.
+async fn check_agent_clis(working_dir: &Path) -> Vec<DoctorCheck> {
+    let config = load_workflow_from_selection(working_dir);
+    let used: BTreeSet<String> = config
+        .agents
+        .values()
+        .map(|agent| agent.command.clone())
+        .collect();
+
+    let mut commands: Vec<String> = KNOWN_CLIS.iter().map(|c| c.command.to_string()).collect();
+    let custom: Vec<String> = used
+        .iter()
+        .filter(|c| !commands.contains(c))
+        .cloned()
+        .collect();
+    commands.extend(custom);
+
+    let mut checks = Vec::new();
+    for command in commands {
+        let known = KNOWN_CLIS.iter().find(|c| c.command == command);
+        let required = used.contains(&command);
+        let install = known
+            .map(|k| format!("install with `{}`", k.install))
+            .unwrap_or_else(|| format!("install `{}` or fix the workflow's agents", command));
+
+        let path = match which::which(&command) {
+            Ok(path) => path,
+            Err(_) if required => {
+                checks.push(DoctorCheck::fail(
+                    &command,
+                    format!("not found on PATH (used by workflow '{}')", config.name),
+                    install,
+                ));
+                continue;
+            }
+            Err(_) => {
+                checks.push(DoctorCheck::warn(
+                    &command,
+                    "not found on PATH (not used by the selected workflow)",
+                    install,
+                ));
+                continue;
+            }
+        };
+        match command_version(&path).await {
+            Ok(version) => checks.push(DoctorCheck::ok(
+                &command,
+                format!("{} ({})", version, path.display()),
+            )),
+            Err(e) => checks.push(DoctorCheck::fail(
+                &command,
+                format!("{} is broken: {}", path.display(), e),
+                format!("reinstall it; {}", install),
+            )),
+        }
+
+        if let Some(known) = known {
+            checks.push(check_auth(known));
+        }
+    }
+    checks
+}
+
+/// Looks for the provider's stored login without contacting it.
+pub(crate) fn check_auth(cli: &KnownCli) -> DoctorCheck {
+    let name = format!("{} auth", cli.command);
+    let found = match cli.command {
+        "claude" if std::env::var("ANTHROPIC_API_KEY").is_ok_and(|k| !k.is_empty()) => {
+            return DoctorCheck::ok(name, "ANTHROPIC_API_KEY is set");
+        }
+        "claude" => credentials::read_claude_credentials(),
+        "codex" => credentials::read_codex_credentials(),
+        _ => credentials::read_gemini_credentials(),
+    };
+    match found {
+        Ok(Some(_)) => DoctorCheck::ok(name, "credentials found"),
+        // Claude on macOS keeps its login in the keychain, so absence is not conclusive
+        Ok(None) => DoctorCheck::warn(name, "no stored credentials found", cli.login),
+        Err(e) => DoctorCheck::warn(name, format!("credentials unreadable: {:#}", e), cli.login),
+    }
+}
+
+async fn check_git(working_dir: &Path) -> Vec<DoctorCheck> {
+    let path = match which::which("git") {
+        Ok(path) => path,
+        Err(_) => {
+            return vec![DoctorCheck::fail(
+                "git",
+                "not found on PATH",
+                "install git; worktrees and change tracking depend on it",
+            )]
+        }
+    };
+    let version = match command_version(&path).await {
+        Ok(version) => DoctorCheck::ok("git", version),
+        Err(e) => DoctorCheck::fail("git", e.to_string(), "reinstall git"),
+    };
+    let repo = if crate::git_worktree::is_git_repo(working_dir) {
+        DoctorCheck::ok("git repo", working_dir.display().to_string())
+    } else {
+        DoctorCheck::warn(
+            "git repo",
+            format!("{} is not a git repository", working_dir.display()),
+            "run `git init`, or use --working-dir to point at a repository",
+        )
+    };
+    vec![version, repo]
+}
+
+/// Checks that the planning home and sessions directory are writable and private.
+pub fn check_planning_dir() -> Vec<DoctorCheck> {
+    let dirs = [
+        ("planning home", planning_paths::planning_agent_home_dir()),
+        ("sessions dir", planning_paths::sessions_dir()),
+    ];
+    dirs.into_iter()
+        .map(|(name, dir)| match dir {
+            Ok(dir) => check_writable_dir(name, &dir),
+            Err(e) => DoctorCheck::fail(
+                name,
+                format!("{:#}", e),
+                "set HOME or PLANNING_AGENT_HOME to a writable directory",
+            ),
+        })
+        .collect()
+}
+
+fn check_writable_dir(name: &str, dir: &Path) -> DoctorCheck {
+    let probe = dir.join(format!(".doctor-{}", std::process::id()));
+    if let Err(e) = std::fs::write(&probe, b"").and_then(|_| std::fs::remove_file(&probe)) {
+        return DoctorCheck::fail(
+            name,
+            format!("{} is not writable: {}", dir.display(), e),
+            format!("fix ownership, e.g. `chown -R $USER {}`", dir.display()),
+        );
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(dir)
+            .map(|m| m.permissions().mode())
+            .unwrap_or(0);
+        if mode & 0o022 != 0 {
+            return DoctorCheck::warn(
+                name,
+                format!(
+                    "{} is writable by other users ({:o})",
+                    dir.display(),
+                    mode & 0o777
+                ),
+                format!("run `chmod go-w {}`", dir.display()),
+            );
+        }
+    }
+    DoctorCheck::ok(name, dir.display().to_string())
+}
+
+/// Checks the daemon's files against each other and probes the daemon itself.
+pub async fn check_daemon() -> Vec<DoctorCheck> {
+    let (Ok(port_path), Ok(pid_path)) = (
+        planning_paths::sessiond_port_path(),
+        planning_paths::sessiond_pid_path(),
+    ) else {
+        return vec![];
+    };
+    let pid = std::fs::read_to_string(&pid_path)
+        .ok()
+        .and_then(|p| p.trim().parse::<u32>().ok());
+
+    let content = match std::fs::read_to_string(&port_path) {
+        Ok(content) => content,
+        Err(_) if pid.is_some_and(pid_running) => {
+            return vec![DoctorCheck::warn(
+                "daemon",
+                format!("running (pid {}) but has no port file", pid.unwrap_or(0)),
+                "restart it with `kill <pid>`; the next session starts a fresh one",
+            )]
+        }
+        Err(_) => {
+            return vec![DoctorCheck::ok(
+                "daemon",
+                "not running (starts with the first session)",
+            )]
+        }
+    };
+    let port_info: PortFileContent = match serde_json::from_str(&content) {
+        Ok(info) => info,
+        Err(e) => {
+            return vec![DoctorCheck::fail(
+                "daemon port file",
+                format!("{} is corrupt: {}", port_path.display(), e),
+                format!("delete {}", port_path.display()),
+            )]
+        }
+    };
+
+    let mut checks = Vec::new();
+    match pid {
+        Some(pid) if pid_running(pid) => {
+            checks.push(DoctorCheck::ok("daemon port file", format!("pid {}", pid)))
+        }
+        _ => {
+            checks.push(DoctorCheck::warn(
+                "daemon port file",
+                "left behind by a daemon that is no longer running",
+                format!(
+                    "delete {}; the next session starts a fresh daemon",
+                    port_path.display()
+                ),
+            ));
+            return checks;
+        }
+    }
+    if let Some(socket) = port_info.socket_path.as_ref().filter(|s| !s.exists()) {
+        checks.push(DoctorCheck::warn(
+            "daemon socket",
+            format!("{} is missing; clients fall back to TCP", socket.display()),
+            "restart the daemon",
+        ));
+    }
+    let sha = planning_paths::sessiond_build_sha_path()
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok());
+    if let Some(sha) = sha.filter(|s| s.trim() != BUILD_SHA) {
+        checks.push(DoctorCheck::warn(
+            "daemon version",
+            format!("daemon runs build {}, this is {}", sha.trim(), BUILD_SHA),
+            "it is upgraded automatically when the next session starts",
+        ));
+    }
+    checks.push(match probe_daemon(&port_info, DAEMON_PROBE_TIMEOUT).await {
+        Ok(count) => DoctorCheck::ok(
+            "daemon",
+            format!("reachable, {} session(s) registered", count),
+        ),
+        Err(e) => DoctorCheck::fail(
+            "daemon",
+            format!("unreachable: {:#}", e),
+            format!(
+                "restart it with `kill {}`; the next session starts a fresh one",
+                pid.unwrap_or(0)
+            ),
+        ),
+    });
+    checks
+}
+
+fn pid_running(pid: u32) -> bool {
+    // Liveness cannot be checked off Unix; trust the PID file there
+    !cfg!(unix) || process_alive(pid)
+}
+
+fn check_terminal() -> Vec<DoctorCheck> {
+    if !std::io::stdout().is_terminal() {
+        return vec![DoctorCheck::warn(
+            "terminal",
+            "stdout is not a terminal; skipping terminal checks",
+            "run `planning doctor` directly in the terminal you use for the TUI",
+        )];
+    }
+    let term = std::env::var("TERM").unwrap_or_default();
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    let mut checks = vec![color_check(&term, &colorterm)];
+
+    checks.push(match crossterm::terminal::supports_keyboard_enhancement() {
+        Ok(true) => DoctorCheck::ok("keyboard", "enhanced key reporting supported"),
+        Ok(false) => DoctorCheck::warn(
+            "keyboard",
+            "no enhanced key reporting; Shift+Enter cannot insert newlines",
+            "use a terminal with the kitty keyboard protocol (kitty, WezTerm, foot, Ghostty)",
+        ),
+        Err(e) => DoctorCheck::warn(
+            "keyboard",
+            format!("could not query the terminal: {}", e),
+            "use a terminal with the kitty keyboard protocol (kitty, WezTerm, foot, Ghostty)",
+        ),
+    });
+    checks
+}
+
+/// Classifies color support from `TERM` and `COLORTERM`.
+pub fn color_check(term: &str, colorterm: &str) -> DoctorCheck {
+    if term == "dumb" {
+        return DoctorCheck::fail(
+            "colors",
+            "TERM=dumb; the TUI cannot draw",
+            "run in a full terminal emulator",
+        );
+    }
+    if matches!(colorterm, "truecolor" | "24bit") {
+        return DoctorCheck::ok("colors", "24-bit color");
+    }
+    if term.contains("256color") {
+        return DoctorCheck::ok("colors", "256 colors");
+    }
+    DoctorCheck::warn(
+        "colors",
+        format!(
+            "TERM={} advertises basic colors only",
+            if term.is_empty() { "(unset)" } else { term }
+        ),
+        "set TERM=xterm-256color (or COLORTERM=truecolor) if your terminal supports it",
+    )
+}
+
+#[cfg(test)]
+#[path = "tests/doctor_tests.rs"]
+mod tests;
@@ -0,0 +1,200 @@
+//! Plans bundled with `planning eval reviewers`.
+//!
+//! Each fixture is a plan with the defects planted in it. A defect counts as found
+//! when a reviewer's feedback has a word starting with one of its keywords; a
+//! fixture without defects is a sound plan that a reviewer should approve.
+
+/// A defect planted in a fixture plan.
+#[derive(Debug, Clone, Copy)]
+pub struct KnownDefect {
+    pub id: &'static str,
+    pub description: &'static str,
+    /// Lowercase word prefixes, any of which in the feedback counts as finding it.
+    pub keywords: &'static [&'static str],
+}
+
+/// A plan for reviewers to judge.
+#[derive(Debug, Clone, Copy)]
+pub struct EvalFixture {
+    pub id: &'static str,
+    pub objective: &'static str,
+    pub plan: &'static str,
+    pub defects: &'static [KnownDefect],
+}
+
+impl EvalFixture {
+    /// A fixture without planted defects, which reviewers should approve.
+    pub fn is_clean(&self) -> bool {
+        self.defects.is_empty()
+    }
+}
+
+pub const FIXTURES: &[EvalFixture] = &[
+    EvalFixture {
+        id: "cache-without-invalidation",
+        objective: "Cache user profile lookups to cut database load on the profile page",
+        plan: r#"# Plan: Cache user profile lookups
+
+## Goals
+- Serve `GET /users/{id}/profile` from an in-process cache.
+
+## Non-goals
+- Caching other endpoints.
+
+## Steps
+1. Add a `ProfileCache` wrapping a `HashMap<UserId, Profile>` behind a `RwLock`.
+2. In `ProfileService::get`, return the cached profile when present; otherwise load it
+   from the database and insert it.
+3. Keep `ProfileService::update` unchanged: it writes the new profile to the database.
+4. Expose a `profile_cache_hits` counter for the metrics endpoint.
+
+## Risks
+- Memory use grows with the number of users seen since start-up.
+
+## Test plan
+- Unit test: a second `get` for the same user does not query the database.
+"#,
+        defects: &[
+            KnownDefect {
+                id: "no-invalidation",
+                description:
+                    "Profile updates never invalidate the cache, so reads return stale data",
+                keywords: &["invalidat", "stale", "evict"],
+            },
+            KnownDefect {
+                id: "unbounded-growth",
+                description: "The cache has no size limit or expiry",
+                keywords: &["unbounded", "ttl", "expir", "lru", "size limit", "capacity"],
+            },
+        ],
+    },
+    EvalFixture {
+        id: "migration-without-rollback",
+        objective: "Replace the `users.full_name` column with separate first and last name columns",
+        plan: r#"# Plan: Split full_name
+
+## Goals
+- Store first and last names separately.
+
+## Non-goals
+- Changing the signup form.
+
+## Steps
+1. Write migration `0042_split_name`: add `first_name` and `last_name`, then
+   `ALTER TABLE users DROP COLUMN full_name`.
+2. Update the `User` model and every query to read the new columns.
+3. Deploy the migration and the new code together.
+
+## Risks
+- Some names do not split cleanly into first and last name.
+
+## Test plan
+- Run the migration against an empty test database and check the columns exist.
+"#,
+        defects: &[
+            KnownDefect {
+                id: "data-loss",
+                description: "Existing names are dropped without being copied into the new columns",
+                keywords: &["backfill", "data loss", "lose", "lost", "copy", "populate"],
+            },
+            KnownDefect {
+                id: "no-rollback",
+                description: "There is no rollback or down migration",
+                keywords: &[
+                    "rollback",
+                    "roll back",
+                    "down migration",
+                    "revert",
+                    "irreversib",
+                ],
+            },
+        ],
+    },
+    EvalFixture {
+        id: "token-logging",
+        objective: "Make failed API authentication easier to debug",
+        plan: r#"# Plan: Debuggable authentication failures
+
+## Goals
+- Explain in the logs why an API request failed authentication.
+
+## Non-goals
+- Changing the token format.
+
+## Steps
+1. In `AuthMiddleware`, on failure log the request path, the client IP and the full
+   `Authorization` header value at `info` level.
+2. Return `401` with the reason (`expired`, `malformed`, `unknown key`) in the body.
+3. Ship the log lines to the shared log index, which the support team can search.
+
+## Risks
+- More log volume on endpoints under credential-stuffing attacks.
+
+## Test plan
+- Unit test each failure reason produces the expected log line and response body.
+"#,
+        defects: &[KnownDefect {
+            id: "secret-in-logs",
+            description: "Full credentials are written to logs that a wider team can read",
+            keywords: &["redact", "secret", "sensitive", "leak", "mask", "plaintext"],
+        }],
+    },
+    EvalFixture {
+        id: "counter-race",
+        objective: "Count downloads per release for the project dashboard",
+        plan: r#"# Plan: Download counter
+
+## Goals
+- Show a download count per release.
+
+## Non-goals
+- Per-user download history.
+
+## Steps
+1. Store counts in `downloads.json`, a map from release tag to count.
+2. In the download handler, which runs on the multi-threaded request pool, read
+   `downloads.json`, increment the release's count and write the file back.
+3. The dashboard reads `downloads.json` when it renders.
+
+## Risks
+- The file grows by one entry per release.
+
+## Test plan
+- Unit test: one download of `v1.2.0` makes its count 1.
+"#,
+        defects: &[KnownDefect {
+            id: "lost-updates",
+            description:
+                "Concurrent downloads read and write the file without locking, losing increments",
+            keywords: &["race", "lock", "atomic", "concurren", "lost update"],
+        }],
+    },
+    EvalFixture {
+        id: "clean-flag-rename",
+        objective: "Rename the `--out` CLI flag to `--output` without breaking existing scripts",
+        plan: r#"# Plan: Rename --out to --output
+
+## Goals
+- `--output` is the documented flag; `--out` keeps working.
+
+## Non-goals
+- Changing what the flag does.
+
+## Steps
+1. Rename the clap argument to `output` and add `visible_alias = "out"` so both
+   spellings parse to the same field.
+2. When `--out` is used, print a one-line deprecation notice to stderr.
+3. Update the README usage table and the `--help` example to `--output`.
+4. Note the rename and the alias in the changelog's next release section.
+
+## Risks
+- Scripts that parse stderr may see the new deprecation notice; it is one line and
+  only printed for the old spelling.
+
+## Test plan
+- CLI test: `--output x` and `--out x` both set the output path to `x`.
+- CLI test: only `--out` prints the deprecation notice.
+"#,
+        defects: &[],
+    },
+];
@@ -0,0 +1,319 @@
+//! `planning eval reviewers`: scores the configured reviewer panel on bundled plans.
+//!
+//! Each fixture plan is written to a scratch session and reviewed by every enabled
+//! reviewer, like a first review round of a real workflow. A reviewer finds a
+//! planted defect when its feedback mentions it, and rejecting the defect-free
+//! plan counts as a false positive. The scratch sessions are removed afterwards.
+//!
+//! The config is loaded as for `--headless`, so `--config`, `--reviewers` and
+//! `--agent-arg` select the panel being compared.
+
+mod fixtures;
+
+use crate::agents::permissions::ToolPermissionDecision;
+use crate::app::cli::Cli;
+use crate::app::headless::headless_setup;
+use crate::config::{AgentRef, WorkflowConfig};
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkflowId,
+    WorkingDir,
+};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowEvent;
+use crate::phases::reviewing::{
+    run_multi_agent_review_with_context, ReviewBatchResult, ReviewTargets,
+};
+use crate::planning_paths;
+use crate::session_daemon::create_session_logger;
+use crate::skills;
+use crate::tui::{Event, SessionEventSender};
+use anyhow::Result;
+use fixtures::{EvalFixture, FIXTURES};
+use std::path::Path;
+use tokio::sync::mpsc;
+
+/// What one reviewer made of one fixture plan.
+#[derive(Debug, Clone, PartialEq)]
+struct FixtureOutcome {
+    reviewer: String,
+    /// None when the review failed.
+    needs_revision: Option<bool>,
+    /// Planted defects the feedback mentions.
+    found: Vec<&'static str>,
+    /// Descriptions of the planted defects it doesn't.
+    missed: Vec<&'static str>,
+    defects: usize,
+    clean: bool,
+}
+
+/// A reviewer's totals across the fixtures.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ReviewerScore {
+    reviewer: String,
+    defects_found: usize,
+    defects_total: usize,
+    /// Rejections of plans without planted defects.
+    false_positives: usize,
+    clean_plans: usize,
+    /// Approvals of plans with planted defects.
+    approved_flawed: usize,
+    failures: usize,
+}
+
+/// Returns true if a word in `text` starts with `keyword` (both lowercase), so
+/// "lock" matches "locking" but not "blocking".
+fn mentions(text: &str, keyword: &str) -> bool {
+    text.match_indices(keyword).any(|(i, _)| {
+        !text[..i]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric)
+    })
+}
+
+fn judge(fixture: &EvalFixture, reviewer: &str, review: Option<(bool, &str)>) -> FixtureOutcome {
+    let mut found = Vec::new();
+    let mut missed = Vec::new();
+    if let Some((_, feedback)) = review {
+        let feedback = feedback.to_lowercase();
+        for defect in fixture.defects {
+            if defect.keywords.iter().any(|k| mentions(&feedback, k)) {
+                found.push(defect.id);
+            } else {
+                missed.push(defect.description);
+            }
+        }
+    }
+    FixtureOutcome {
+        reviewer: reviewer.to_string(),
+        needs_revision: review.map(|(needs_revision, _)| needs_revision),
+        found,
+        missed,
+        defects: fixture.defects.len(),
+        clean: fixture.is_clean(),
+    }
+}
+
+/// Totals per reviewer, in the order reviewers first appear. Failed reviews only
+/// count as failures, so a flaky reviewer isn't also scored as missing defects.
+fn score(outcomes: &[FixtureOutcome]) -> Vec<ReviewerScore> {
+    let mut scores: Vec<ReviewerScore> = Vec::new();
+    for outcome in outcomes {
+        let index = match scores.iter().position(|s| s.reviewer == outcome.reviewer) {
+            Some(index) => index,
+            None => {
+                scores.push(ReviewerScore {
+                    reviewer: outcome.reviewer.clone(),
+                    ..Default::default()
+                });
+                scores.len() - 1
+            }
+        };
+        let score = &mut scores[index];
+        let Some(needs_revision) = outcome.needs_revision else {
+            score.failures += 1;
+            continue;
+        };
+        score.defects_found += outcome.found.len();
+        score.defects_total += outcome.defects;
+        if outcome.clean {
+            score.clean_plans += 1;
+            score.false_positives += usize::from(needs_revision);
+        } else {
+            score.approved_flawed += usize::from(!needs_revision);
+        }
+    }
+    scores
+}
+
+fn describe_outcome(outcome: &FixtureOutcome) -> String {
+    match outcome.needs_revision {
+        None => "review failed".to_string(),
+        Some(true) if outcome.clean => "rejected (false positive)".to_string(),
+        Some(false) if outcome.clean => "approved".to_string(),
+        Some(needs_revision) => format!(
+            "{}, found {}/{} defect(s){}",
+            if needs_revision {
+                "rejected"
+            } else {
+                "approved"
+            },
+            outcome.found.len(),
+            outcome.defects,
+            if outcome.found.is_empty() {
+                String::new()
+            } else {
+                format!(": {}", outcome.found.join(", "))
+            }
+        ),
+    }
+}
+
+fn format_report(scores: &[ReviewerScore]) -> String {
+    let width = scores
+        .iter()
+        .map(|s| s.reviewer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Reviewer".len());
+    let mut report = format!(
+        "{:<width$}  {:>14}  {:>15}  {:>15}  {:>8}\n",
+        "Reviewer",
+        "Defects found",
+        "False positives",
+        "Approved flawed",
+        "Failures",
+        width = width
+    );
+    for score in scores {
+        let found = match score.defects_total {
+            0 => "-".to_string(),
+            total => format!(
+                "{}/{} ({:.0}%)",
+                score.defects_found,
+                total,
+                score.defects_found as f64 * 100.0 / total as f64
+            ),
+        };
+        report.push_str(&format!(
+            "{:<width$}  {:>14}  {:>15}  {:>15}  {:>8}\n",
+            score.reviewer,
+            found,
+            format!("{}/{}", score.false_positives, score.clean_plans),
+            score.approved_flawed,
+            score.failures,
+            width = width
+        ));
+    }
+    report
+}
+
+/// Runs the reviewer panel on the bundled fixtures (or the ones named in `only`)
+/// and prints each verdict and the per-reviewer scores.
+pub async fn run_eval_reviewers(cli: &Cli, only: &[String]) -> Result<()> {
+    if let Some(unknown) = only
+        .iter()
+        .find(|id| !FIXTURES.iter().any(|f| f.id == id.as_str()))
+    {
+        let ids: Vec<&str> = FIXTURES.iter().map(|f| f.id).collect();
+        anyhow::bail!(
+            "Unknown fixture '{}'; available: {}",
+            unknown,
+            ids.join(", ")
+        );
+    }
+    let fixtures: Vec<&EvalFixture> = FIXTURES
+        .iter()
+        .filter(|f| only.is_empty() || only.iter().any(|id| id == f.id))
+        .collect();
+
+    let (working_dir, config, _) = headless_setup(cli)?;
+    if let Err(e) = skills::install_skills_if_needed(&working_dir) {
+        eprintln!("[eval] Warning: Failed to install skills: {}", e);
+    }
+    let reviewers = config.workflow.reviewing.enabled_agents();
+    if reviewers.is_empty() {
+        anyhow::bail!("No reviewers are enabled in the workflow config");
+    }
+    let names: Vec<&str> = reviewers.iter().map(|r| r.display_id()).collect();
+    println!(
+        "[eval] {} reviewer(s) on {} fixture plan(s): {}",
+        reviewers.len(),
+        fixtures.len(),
+        names.join(", ")
+    );
+
+    let mut outcomes = Vec::new();
+    for fixture in fixtures {
+        println!("[eval] {}", fixture.id);
+        let batch = review_fixture(fixture, &config, &reviewers).await?;
+        for review in &batch.reviews {
+            let outcome = judge(
+                fixture,
+                &review.agent_name,
+                Some((review.needs_revision, &review.feedback)),
+            );
+            println!("  {}: {}", outcome.reviewer, describe_outcome(&outcome));
+            for description in &outcome.missed {
+                println!("    missed: {}", description);
+            }
+            outcomes.push(outcome);
+        }
+        for failure in &batch.failures {
+            println!("  {}: review failed: {}", failure.agent_name, failure.error);
+            outcomes.push(judge(fixture, &failure.agent_name, None));
+        }
+    }
+
+    println!();
+    print!("{}", format_report(&score(&outcomes)));
+    Ok(())
+}
+
+/// Reviews one fixture plan in a scratch session, which is removed afterwards.
+async fn review_fixture(
+    fixture: &EvalFixture,
+    config: &WorkflowConfig,
+    reviewers: &[AgentRef],
+) -> Result<ReviewBatchResult> {
+    let session_id = WorkflowId::new().to_string();
+    let dir = planning_paths::session_dir(&session_id)?;
+    let result = review_in(&dir, &session_id, fixture, config, reviewers).await;
+    // Nothing in a scratch session is worth resuming
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+async fn review_in(
+    dir: &Path,
+    session_id: &str,
+    fixture: &EvalFixture,
+    config: &WorkflowConfig,
+    reviewers: &[AgentRef],
+) -> Result<ReviewBatchResult> {
+    let plan_path = dir.join("plan.md");
+    std::fs::write(&plan_path, fixture.plan)?;
+
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        session_id,
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from(fixture.id),
+            objective: Objective::from(fixture.objective),
+            working_dir: WorkingDir(dir.to_path_buf()),
+            max_iterations: MaxIterations(1),
+            plan_path: PlanPath(plan_path),
+            feedback_path: FeedbackPath::from(dir.join("feedback.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+
+    let (output_tx, mut output_rx) = mpsc::unbounded_channel::<Event>();
+    tokio::spawn(async move {
+        while let Some(event) = output_rx.recv().await {
+            // Nobody can answer the prompt, as in headless runs
+            if let Event::SessionToolPermissionRequest { request, .. } = event {
+                request.respond(ToolPermissionDecision::Deny);
+            }
+        }
+    });
+
+    run_multi_agent_review_with_context(
+        &view,
+        dir,
+        config,
+        reviewers,
+        SessionEventSender::new(0, 1, output_tx),
+        1,
+        create_session_logger(session_id)?,
+        false,
+        None,
+        &ReviewTargets::default(),
+    )
+    .await
+}
+
+#[cfg(test)]
+#[path = "tests/eval_tests.rs"]
+mod tests;
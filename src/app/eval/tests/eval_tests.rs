@@ -0,0 +1,88 @@
+use super::*;
+
+fn fixture(id: &str) -> &'static EvalFixture {
+    FIXTURES.iter().find(|f| f.id == id).unwrap()
+}
+
+#[test]
+fn test_mentions_matches_word_prefixes_only() {
+    assert!(mentions("needs locking around the write", "lock"));
+    assert!(mentions("lock the file", "lock"));
+    assert!(!mentions("a blocking call", "lock"));
+    assert!(mentions("there is no down migration", "down migration"));
+}
+
+#[test]
+fn test_fixtures_have_unique_ids_and_one_clean_plan() {
+    let mut ids: Vec<&str> = FIXTURES.iter().map(|f| f.id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), FIXTURES.len());
+    assert!(FIXTURES.iter().any(|f| f.is_clean()));
+    for defect in FIXTURES.iter().flat_map(|f| f.defects) {
+        assert!(defect.keywords.iter().all(|k| *k == k.to_lowercase()));
+    }
+}
+
+#[test]
+fn test_judge_finds_defects_mentioned_in_feedback() {
+    let outcome = judge(
+        fixture("cache-without-invalidation"),
+        "claude",
+        Some((true, "Updates leave STALE entries behind.")),
+    );
+    assert_eq!(outcome.needs_revision, Some(true));
+    assert_eq!(outcome.found, vec!["no-invalidation"]);
+    assert_eq!(
+        outcome.missed,
+        vec!["The cache has no size limit or expiry"]
+    );
+    assert!(!outcome.clean);
+}
+
+#[test]
+fn test_score_counts_false_positives_and_failures() {
+    let outcomes = vec![
+        judge(
+            fixture("counter-race"),
+            "claude",
+            Some((false, "Looks fine, though the write is not atomic.")),
+        ),
+        judge(fixture("clean-flag-rename"), "claude", Some((true, "Nope"))),
+        judge(fixture("counter-race"), "codex", None),
+        judge(fixture("clean-flag-rename"), "codex", Some((false, "LGTM"))),
+    ];
+    let scores = score(&outcomes);
+    assert_eq!(
+        scores,
+        vec![
+            ReviewerScore {
+                reviewer: "claude".to_string(),
+                defects_found: 1,
+                defects_total: 1,
+                false_positives: 1,
+                clean_plans: 1,
+                approved_flawed: 1,
+                failures: 0,
+            },
+            ReviewerScore {
+                reviewer: "codex".to_string(),
+                defects_found: 0,
+                defects_total: 0,
+                false_positives: 0,
+                clean_plans: 1,
+                approved_flawed: 0,
+                failures: 1,
+            },
+        ]
+    );
+
+    let report = format_report(&scores);
+    assert!(report.contains("1/1 (100%)"));
+    assert!(report.lines().nth(2).unwrap().contains(" - "));
+    assert_eq!(describe_outcome(&outcomes[1]), "rejected (false positive)");
+    assert_eq!(
+        describe_outcome(&outcomes[0]),
+        "approved, found 1/1 defect(s): lost-updates"
+    );
+}
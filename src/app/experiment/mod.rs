@@ -0,0 +1,402 @@
+//! `planning experiment`: runs one objective under several workflow configs.
+//!
+//! Each arm is a workflow config file. Every arm plans the objective `--runs`
+//! times as a headless run, with decision prompts answered the way `--headless`
+//! answers them, and the runs are compared on plan acceptance, iterations, cost,
+//! time and reviewer verdict stability. Completion actions and event webhooks
+//! are switched off so experiment runs don't publish anything. The sessions are
+//! kept, so the plans of each run can be inspected or resumed afterwards.
+
+use crate::agents::permissions::ToolPermissionDecision;
+use crate::app::cli::Cli;
+use crate::app::headless::{headless_response, headless_setup};
+use crate::app::workflow::{run_workflow_with_config, WorkflowResult, WorkflowRunConfig};
+use crate::config::WorkflowConfig;
+use crate::domain::input::{NewWorkflowInput, WorkflowInput};
+use crate::skills;
+use crate::tui::session::parse_cost_output;
+use crate::tui::{Event, ReviewKind, UserApprovalResponse, WorkflowCommand, WorkflowUpdate};
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// Session ID used for events of the workflow being run.
+const EXPERIMENT_SESSION_ID: usize = 0;
+
+/// A workflow config being compared.
+struct Arm {
+    name: String,
+    config: WorkflowConfig,
+}
+
+/// What one run of an arm produced, gathered from its events.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct RunOutcome {
+    /// How the run ended: accepted, aborted, stopped or failed.
+    result: String,
+    accepted: bool,
+    iterations: u32,
+    cost: f64,
+    /// Some of the cost was estimated from token usage.
+    cost_estimated: bool,
+    /// First plan review round verdicts, reviewer to approved.
+    first_verdicts: Vec<(String, bool)>,
+    reviewer_rejections: u32,
+    seconds: u64,
+    session_id: Option<String>,
+}
+
+impl RunOutcome {
+    /// Updates the outcome from a workflow event.
+    fn observe(&mut self, event: &Event) {
+        match event {
+            Event::Output(line) | Event::SessionOutput { line, .. } => {
+                if let Some((cost, estimated)) = parse_cost_output(line) {
+                    self.cost += cost;
+                    self.cost_estimated |= estimated;
+                }
+            }
+            Event::SessionViewUpdate { view, .. } => {
+                if let Some(id) = view.workflow_id() {
+                    self.session_id = Some(id.to_string());
+                }
+                if let Some(iteration) = view.iteration() {
+                    self.iterations = iteration.0;
+                }
+            }
+            Event::SessionReviewerCompleted {
+                kind: ReviewKind::Plan,
+                round,
+                display_id,
+                approved,
+                ..
+            } => {
+                if *round == 1 {
+                    self.first_verdicts.push((display_id.clone(), *approved));
+                }
+                self.reviewer_rejections += u32::from(!approved);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An arm's totals across its runs.
+#[derive(Debug, Clone, PartialEq)]
+struct ArmSummary {
+    arm: String,
+    runs: usize,
+    accepted: usize,
+    avg_iterations: f64,
+    avg_cost: f64,
+    cost_estimated: bool,
+    avg_rejections: f64,
+    avg_seconds: f64,
+    /// None when no reviewer finished a first round.
+    verdict_stability: Option<f64>,
+}
+
+/// How consistently reviewers judge the first plan of the same objective: per
+/// reviewer, the share of its first-round verdicts agreeing with its majority
+/// verdict, averaged over reviewers. 100% means every reviewer decided the same
+/// way in every run.
+fn verdict_stability(runs: &[RunOutcome]) -> Option<f64> {
+    let mut verdicts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for (reviewer, approved) in runs.iter().flat_map(|run| &run.first_verdicts) {
+        let counts = verdicts.entry(reviewer.as_str()).or_default();
+        if *approved {
+            counts.0 += 1;
+        } else {
+            counts.1 += 1;
+        }
+    }
+    if verdicts.is_empty() {
+        return None;
+    }
+    let total: f64 = verdicts
+        .values()
+        .map(|(approved, rejected)| {
+            (*approved).max(*rejected) as f64 / (approved + rejected) as f64
+        })
+        .sum();
+    Some(total / verdicts.len() as f64)
+}
+
+fn summarize(arm: &str, runs: &[RunOutcome]) -> ArmSummary {
+    let count = runs.len().max(1) as f64;
+    let average = |value: fn(&RunOutcome) -> f64| runs.iter().map(value).sum::<f64>() / count;
+    ArmSummary {
+        arm: arm.to_string(),
+        runs: runs.len(),
+        accepted: runs.iter().filter(|run| run.accepted).count(),
+        avg_iterations: average(|run| f64::from(run.iterations)),
+        avg_cost: average(|run| run.cost),
+        cost_estimated: runs.iter().any(|run| run.cost_estimated),
+        avg_rejections: average(|run| f64::from(run.reviewer_rejections)),
+        avg_seconds: average(|run| run.seconds as f64),
+        verdict_stability: verdict_stability(runs),
+    }
+}
+
+fn describe_run(run: &RunOutcome) -> String {
+    let verdicts = run
+        .first_verdicts
+        .iter()
+        .map(|(reviewer, approved)| {
+            format!(
+                "{} {}",
+                reviewer,
+                if *approved { "approved" } else { "rejected" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut text = format!(
+        "{} after {} iteration(s), {}${:.2}, {}s",
+        run.result,
+        run.iterations,
+        if run.cost_estimated { "~" } else { "" },
+        run.cost,
+        run.seconds
+    );
+    if !verdicts.is_empty() {
+        text.push_str(&format!("; first review: {}", verdicts));
+    }
+    text
+}
+
+fn format_report(summaries: &[ArmSummary]) -> String {
+    let width = summaries
+        .iter()
+        .map(|s| s.arm.len())
+        .max()
+        .unwrap_or(0)
+        .max("Arm".len());
+    let mut report = format!(
+        "{:<width$}  {:>8}  {:>10}  {:>9}  {:>10}  {:>9}  {:>17}\n",
+        "Arm",
+        "Accepted",
+        "Iterations",
+        "Cost",
+        "Rejections",
+        "Time",
+        "Verdict stability",
+        width = width
+    );
+    for summary in summaries {
+        let stability = summary
+            .verdict_stability
+            .map(|s| format!("{:.0}%", s * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        report.push_str(&format!(
+            "{:<width$}  {:>8}  {:>10.1}  {:>9}  {:>10.1}  {:>8.0}s  {:>17}\n",
+            summary.arm,
+            format!("{}/{}", summary.accepted, summary.runs),
+            summary.avg_iterations,
+            format!(
+                "{}${:.2}",
+                if summary.cost_estimated { "~" } else { "" },
+                summary.avg_cost
+            ),
+            summary.avg_rejections,
+            summary.avg_seconds,
+            stability,
+            width = width
+        ));
+    }
+    report
+}
+
+/// Arm names: config file stems, numbered by position when two stems collide.
+/// They become part of the runs' feature names.
+fn arm_names(paths: &[PathBuf]) -> Vec<String> {
+    let stems: Vec<String> = paths
+        .iter()
+        .map(|path| {
+            path.file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|| "arm".to_string())
+        })
+        .collect();
+    stems
+        .iter()
+        .enumerate()
+        .map(|(index, stem)| {
+            if stems.iter().filter(|s| *s == stem).count() > 1 {
+                format!("{}-{}", stem, index + 1)
+            } else {
+                stem.clone()
+            }
+        })
+        .collect()
+}
+
+/// Loads an arm's config with the run's `--reviewers` and `--agent-arg`.
+fn load_arm(path: &Path, name: String, cli: &Cli) -> Result<Arm> {
+    let mut config = WorkflowConfig::load(path)
+        .with_context(|| format!("Failed to load arm {}", path.display()))?;
+    if config.name.is_empty() {
+        config.name = name.clone();
+    }
+    if let Some(preset) = &cli.reviewers {
+        config.apply_reviewer_preset(preset)?;
+    }
+    config.apply_agent_args(&cli.agent_args)?;
+    // Accepted experiment plans must not be copied, posted or announced
+    config.completion_actions.clear();
+    config.event_webhooks.clear();
+    config.undo_grace_secs = 0;
+    config.approval_gates.clear();
+    Ok(Arm { name, config })
+}
+
+/// Runs `objective` `runs` times under each arm's config and prints how the
+/// arms compare.
+pub async fn run_experiment(cli: &Cli, objective: &str, arms: &[PathBuf], runs: u32) -> Result<()> {
+    let objective = objective.trim();
+    if objective.is_empty() {
+        anyhow::bail!("--objective must not be empty");
+    }
+    if runs == 0 {
+        anyhow::bail!("--runs must be at least 1");
+    }
+    let arms = arm_names(arms)
+        .into_iter()
+        .zip(arms)
+        .map(|(name, path)| load_arm(path, name, cli))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (working_dir, _, package) = headless_setup(cli)?;
+    if let Err(e) = skills::install_skills_if_needed(&working_dir) {
+        eprintln!("[experiment] Warning: Failed to install skills: {}", e);
+    }
+    println!(
+        "[experiment] {} arm(s) x {} run(s) in {}",
+        arms.len(),
+        runs,
+        working_dir.display()
+    );
+
+    let feature = cli.name.as_deref().unwrap_or("experiment");
+    let mut summaries = Vec::new();
+    let mut interrupted = false;
+    for arm in &arms {
+        let mut outcomes = Vec::new();
+        for run in 1..=runs {
+            let mut input = NewWorkflowInput::new(
+                format!("{}-{}-{}", feature, arm.name, run),
+                objective.to_string(),
+                cli.max_iterations,
+            );
+            if let Some(package) = &package {
+                input = input.with_package_context(package.prompt_context());
+            }
+            println!("[experiment] {} run {}/{}...", arm.name, run, runs);
+            let outcome = run_once(cli, &working_dir, &arm.config, input, &mut interrupted).await;
+            println!(
+                "[experiment] {} run {}/{}: {}",
+                arm.name,
+                run,
+                runs,
+                describe_run(&outcome)
+            );
+            if let Some(session_id) = &outcome.session_id {
+                println!("[experiment]   session {}", session_id);
+            }
+            outcomes.push(outcome);
+            if interrupted {
+                break;
+            }
+        }
+        summaries.push(summarize(&arm.name, &outcomes));
+        if interrupted {
+            println!("[experiment] Interrupted; comparing the runs finished so far");
+            break;
+        }
+    }
+
+    println!();
+    print!("{}", format_report(&summaries));
+    Ok(())
+}
+
+/// Runs one workflow to completion, answering prompts as `--headless` does.
+/// Sets `interrupted` if Ctrl+C stopped it.
+async fn run_once(
+    cli: &Cli,
+    working_dir: &Path,
+    config: &WorkflowConfig,
+    input: NewWorkflowInput,
+    interrupted: &mut bool,
+) -> RunOutcome {
+    let (output_tx, mut output_rx) = mpsc::unbounded_channel::<Event>();
+    let (approval_tx, approval_rx) = mpsc::channel::<UserApprovalResponse>(1);
+    let (control_tx, control_rx) = mpsc::channel::<WorkflowCommand>(1);
+    let (_, update_rx) = mpsc::unbounded_channel::<WorkflowUpdate>();
+
+    let started = Instant::now();
+    let mut workflow_handle = tokio::spawn(run_workflow_with_config(
+        WorkflowInput::New(input),
+        WorkflowRunConfig {
+            working_dir: working_dir.to_path_buf(),
+            config: config.clone(),
+            output_tx,
+            approval_rx,
+            control_rx,
+            update_rx,
+            session_id: EXPERIMENT_SESSION_ID,
+            run_id: 1,
+            no_daemon: cli.no_daemon,
+        },
+    ));
+
+    let mut outcome = RunOutcome::default();
+    let joined = loop {
+        tokio::select! {
+            joined = &mut workflow_handle => break joined,
+            Some(event) = output_rx.recv() => {
+                outcome.observe(&event);
+                if let Event::SessionToolPermissionRequest { request, .. } = &event {
+                    // Nobody can answer the prompt, as in headless runs
+                    request.respond(ToolPermissionDecision::Deny);
+                }
+                if let Some(response) = headless_response(&event) {
+                    // A full or closed channel means the workflow already moved on or exited
+                    let _ = approval_tx.try_send(response);
+                }
+            }
+            _ = tokio::signal::ctrl_c(), if !*interrupted => {
+                println!("[experiment] Interrupted, stopping the run...");
+                *interrupted = true;
+                // Workflow may have already exited - the join branch reports the result
+                let _ = control_tx.send(WorkflowCommand::Stop).await;
+            }
+        }
+    };
+    // Events sent just before the workflow returned
+    while let Ok(event) = output_rx.try_recv() {
+        outcome.observe(&event);
+    }
+
+    outcome.seconds = started.elapsed().as_secs();
+    outcome.result = match joined.map_err(anyhow::Error::from).and_then(|r| r) {
+        Ok(WorkflowResult::Accepted | WorkflowResult::ImplementationRequested) => {
+            outcome.accepted = true;
+            "accepted".to_string()
+        }
+        Ok(WorkflowResult::Aborted { reason }) => format!("aborted ({})", reason),
+        Ok(WorkflowResult::Stopped) => "stopped".to_string(),
+        Ok(WorkflowResult::NeedsRestart { .. }) => "restart requested".to_string(),
+        Ok(WorkflowResult::Following { holder_pid }) => {
+            format!("held by PID {}", holder_pid)
+        }
+        Err(e) => format!("failed ({:#})", e),
+    };
+    outcome
+}
+
+#[cfg(test)]
+#[path = "tests/experiment_tests.rs"]
+mod tests;
@@ -0,0 +1,169 @@
+use super::*;
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkingDir,
+};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowEvent;
+use clap::Parser;
+use tempfile::tempdir;
+
+fn reviewer_completed(round: u32, reviewer: &str, approved: bool) -> Event {
+    Event::SessionReviewerCompleted {
+        session_id: 0,
+        kind: ReviewKind::Plan,
+        round,
+        display_id: reviewer.to_string(),
+        approved,
+        summary: String::new(),
+        duration_ms: 0,
+    }
+}
+
+fn run(verdicts: &[(&str, bool)]) -> RunOutcome {
+    RunOutcome {
+        first_verdicts: verdicts
+            .iter()
+            .map(|(reviewer, approved)| (reviewer.to_string(), *approved))
+            .collect(),
+        ..RunOutcome::default()
+    }
+}
+
+#[test]
+fn test_run_outcome_observes_cost_iterations_and_first_verdicts() {
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        "550e8400-e29b-41d4-a716-446655440000",
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from("experiment-a-1"),
+            objective: Objective::from("objective"),
+            working_dir: WorkingDir(PathBuf::from("/repo")),
+            max_iterations: MaxIterations(3),
+            plan_path: PlanPath(PathBuf::from("/plan.md")),
+            feedback_path: FeedbackPath::from(PathBuf::from("/feedback.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+
+    let mut outcome = RunOutcome::default();
+    for event in [
+        Event::SessionOutput {
+            session_id: 0,
+            line: "[planning:claude] Cost: $0.50".to_string(),
+        },
+        Event::SessionViewUpdate {
+            session_id: 0,
+            view: Box::new(view),
+        },
+        reviewer_completed(1, "claude", true),
+        reviewer_completed(1, "codex", false),
+        reviewer_completed(2, "codex", true),
+        Event::Output("[review:codex] Cost: ~$0.25".to_string()),
+    ] {
+        outcome.observe(&event);
+    }
+
+    assert_eq!(outcome.cost, 0.75);
+    assert!(outcome.cost_estimated);
+    assert_eq!(outcome.iterations, 1);
+    assert_eq!(
+        outcome.session_id.as_deref(),
+        Some("550e8400-e29b-41d4-a716-446655440000")
+    );
+    assert_eq!(
+        outcome.first_verdicts,
+        vec![("claude".to_string(), true), ("codex".to_string(), false)]
+    );
+    assert_eq!(outcome.reviewer_rejections, 1);
+}
+
+#[test]
+fn test_verdict_stability_averages_majority_agreement_per_reviewer() {
+    assert_eq!(verdict_stability(&[run(&[])]), None);
+    let runs = [
+        run(&[("claude", true), ("codex", false)]),
+        run(&[("claude", true), ("codex", true)]),
+        run(&[("claude", true), ("codex", false)]),
+        run(&[("claude", true), ("codex", true)]),
+    ];
+    // claude always agrees (1.0), codex splits 2/2 (0.5)
+    assert_eq!(verdict_stability(&runs), Some(0.75));
+}
+
+#[test]
+fn test_summarize_and_report() {
+    let mut accepted = run(&[("claude", true)]);
+    accepted.accepted = true;
+    accepted.iterations = 1;
+    accepted.cost = 1.0;
+    accepted.seconds = 60;
+    let mut aborted = run(&[("claude", true)]);
+    aborted.iterations = 3;
+    aborted.cost = 2.0;
+    aborted.reviewer_rejections = 2;
+    aborted.seconds = 120;
+
+    let summary = summarize("strict", &[accepted, aborted]);
+    assert_eq!(summary.accepted, 1);
+    assert_eq!(summary.avg_iterations, 2.0);
+    assert_eq!(summary.avg_cost, 1.5);
+    assert_eq!(summary.avg_rejections, 1.0);
+    assert_eq!(summary.verdict_stability, Some(1.0));
+
+    let report = format_report(&[summary]);
+    let row = report.lines().nth(1).unwrap();
+    assert!(row.starts_with("strict"));
+    assert!(row.contains("1/2"));
+    assert!(row.contains("$1.50"));
+    assert!(row.contains("90s"));
+    assert!(row.ends_with("100%"));
+}
+
+#[test]
+fn test_arm_names_number_colliding_stems() {
+    let paths = [
+        PathBuf::from("configs/fast.yaml"),
+        PathBuf::from("a/thorough.yaml"),
+        PathBuf::from("b/thorough.yaml"),
+    ];
+    assert_eq!(arm_names(&paths), vec!["fast", "thorough-2", "thorough-3"]);
+}
+
+#[test]
+fn test_load_arm_switches_off_completion_actions_and_webhooks() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("strict.yaml");
+    std::fs::write(
+        &path,
+        r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+completion_actions:
+  - type: shell
+    command: ./publish.sh
+
+event_webhooks:
+  - url: https://example.com/hook
+"#,
+    )
+    .unwrap();
+
+    let cli = Cli::parse_from(["planning"]);
+    let arm = load_arm(&path, "strict".to_string(), &cli).unwrap();
+    assert_eq!(arm.name, "strict");
+    assert_eq!(arm.config.name, "strict");
+    assert!(arm.config.completion_actions.is_empty());
+    assert!(arm.config.event_webhooks.is_empty());
+
+    let missing = dir.path().join("missing.yaml");
+    assert!(load_arm(&missing, "missing".to_string(), &cli).is_err());
+}
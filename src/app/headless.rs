@@ -0,0 +1,304 @@
+//! Headless workflow execution.
+//!
+//! Runs the planning workflow without a TUI, answering decision prompts with
+//! fixed unattended defaults. Used by `planning --headless` and by the daemon's
+//! scheduled workflows, which launch headless runs as detached workers.
+//! `planning implement --plan` also runs here, starting from an existing plan.
+
+use crate::agents::permissions::ToolPermissionDecision;
+use crate::app::cli::Cli;
+use crate::app::package::{resolve_package, WorkspacePackage};
+use crate::app::tui_runner::{export_session_zip_async, load_workflow_from_selection};
+use crate::app::util::extract_feature_name;
+use crate::app::workflow::{run_workflow_with_config, WorkflowResult, WorkflowRunConfig};
+use crate::ci_logs::fetch_ci_failure_async;
+use crate::config::WorkflowConfig;
+use crate::domain::input::{NewWorkflowInput, WorkflowInput};
+use crate::domain::review::TriageDecision;
+use crate::issue_tracker::fetch_issue_async;
+use crate::planning_paths;
+use crate::tui::{Event, UserApprovalResponse, WorkflowCommand, WorkflowUpdate};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+/// Session ID used for events emitted by the single headless workflow.
+const HEADLESS_SESSION_ID: usize = 0;
+
+/// Returns the unattended answer for a decision prompt, or None for non-prompt events.
+///
+/// Plans are accepted, reviews continue with partial results, and hitting the
+/// iteration limit proceeds without approval so every run produces a plan.
+/// Failed plan generation or reviews abort; other workflow failures stop the
+/// session so it can be resumed interactively. Feedback triage accepts every
+/// item. Tool permission prompts are answered separately and always denied.
+pub fn headless_response(event: &Event) -> Option<UserApprovalResponse> {
+    match event {
+        Event::SessionApprovalRequest { .. } => Some(UserApprovalResponse::Accept),
+        Event::SessionReviewDecisionRequest { .. } => Some(UserApprovalResponse::ReviewContinue),
+        Event::SessionMaxIterationsReached { .. } => {
+            Some(UserApprovalResponse::ProceedWithoutApproval)
+        }
+        Event::SessionPlanGenerationFailed { .. } | Event::SessionAllReviewersFailed { .. } => {
+            Some(UserApprovalResponse::AbortWorkflow)
+        }
+        Event::SessionWorkflowFailure { .. } => Some(UserApprovalResponse::WorkflowFailureStop),
+        Event::SessionFeedbackTriageRequest { items, .. } => Some(
+            UserApprovalResponse::FeedbackTriaged(vec![TriageDecision::Accept; items.len()]),
+        ),
+        _ => None,
+    }
+}
+
+/// Runs a new planning workflow for the CLI objective without a TUI.
+///
+/// Workflow output is printed to stdout. With `--auto-archive`, the finished
+/// session is exported to `~/.planning-agent/archive/`.
+pub async fn run_headless(cli: Cli) -> Result<()> {
+    let objective = cli.objective.join(" ").trim().to_string();
+    if objective.is_empty() && cli.from_issue.is_none() && cli.attach_ci_run.is_none() {
+        anyhow::bail!("--headless requires an objective, --from-issue or --attach-ci-run");
+    }
+
+    let (working_dir, workflow_config, package) = headless_setup(&cli)?;
+
+    let (objective, issue_import) = match cli.from_issue.clone() {
+        Some(issue_id) => {
+            println!("[planning] Importing issue {}...", issue_id);
+            let issue = fetch_issue_async(issue_id, workflow_config.issue_tracker.clone()).await?;
+            (issue.objective(&objective), Some(issue.into_import()))
+        }
+        None => (objective, None),
+    };
+
+    let (objective, ci_context) = match cli.attach_ci_run.clone() {
+        Some(run) => {
+            println!("[planning] Downloading failing CI logs for {}...", run);
+            let failure = fetch_ci_failure_async(run, working_dir.clone()).await?;
+            let objective = if objective.is_empty() {
+                failure.default_objective()
+            } else {
+                objective
+            };
+            (objective, Some(failure.context_markdown()))
+        }
+        None => (objective, None),
+    };
+
+    let feature_name = match cli.name.clone() {
+        Some(name) => name,
+        None => extract_feature_name(&objective, &working_dir, None).await?,
+    };
+    println!(
+        "[planning] Headless run for '{}' in {}",
+        feature_name,
+        working_dir.display()
+    );
+
+    let mut new_input = NewWorkflowInput::new(feature_name, objective, cli.max_iterations);
+    if let Some(issue) = issue_import {
+        new_input = new_input.with_issue(issue);
+    }
+    if let Some(ci_context) = ci_context {
+        new_input = new_input.with_ci_context(ci_context);
+    }
+    if let Some(package) = package {
+        new_input = new_input.with_package_context(package.prompt_context());
+    }
+    run_headless_workflow(&cli, working_dir, workflow_config, new_input).await
+}
+
+/// Implements an existing plan without a TUI (`planning implement --plan`).
+///
+/// The plan is recorded as approved, so planning and review are skipped and
+/// the implementation workflow runs with its own review loop.
+pub async fn run_implement(cli: Cli, plan: PathBuf) -> Result<()> {
+    let plan = std::fs::canonicalize(&plan)
+        .with_context(|| format!("Plan file not found: {}", plan.display()))?;
+    let content = std::fs::read_to_string(&plan)
+        .with_context(|| format!("Failed to read plan {}", plan.display()))?;
+    let (working_dir, workflow_config, package) = headless_setup(&cli)?;
+
+    let objective = match cli.objective.join(" ").trim() {
+        "" => plan_objective(&content, &plan),
+        objective => objective.to_string(),
+    };
+    let feature_name = match cli.name.clone() {
+        Some(name) => name,
+        None => extract_feature_name(&objective, &working_dir, None).await?,
+    };
+    println!(
+        "[planning] Implementing {} as '{}' in {}",
+        plan.display(),
+        feature_name,
+        working_dir.display()
+    );
+
+    let mut new_input =
+        NewWorkflowInput::new(feature_name, objective, cli.max_iterations).with_imported_plan(plan);
+    if let Some(package) = package {
+        new_input = new_input.with_package_context(package.prompt_context());
+    }
+    run_headless_workflow(&cli, working_dir, workflow_config, new_input).await
+}
+
+/// Objective for an imported plan: its first heading, or the file name.
+fn plan_objective(plan: &str, path: &Path) -> String {
+    plan.lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            let name = path.file_name().unwrap_or(path.as_os_str());
+            format!("Implement {}", name.to_string_lossy())
+        })
+}
+
+/// Resolves the working directory (the `--package` directory if given) and loads
+/// its workflow config, applying `--reviewers` and `--agent-arg`.
+pub(crate) fn headless_setup(
+    cli: &Cli,
+) -> Result<(PathBuf, WorkflowConfig, Option<WorkspacePackage>)> {
+    let working_dir = match cli.working_dir.clone() {
+        Some(dir) => dir,
+        None => std::env::current_dir()?,
+    };
+    let working_dir = std::fs::canonicalize(&working_dir).unwrap_or(working_dir);
+    let package = match cli.package.as_deref() {
+        Some(name) => Some(resolve_package(&working_dir, name)?),
+        None => None,
+    };
+    let working_dir = match &package {
+        Some(package) => package.dir.clone(),
+        None => working_dir,
+    };
+    let mut workflow_config = load_workflow_from_selection(&working_dir);
+    if let Some(preset) = &cli.reviewers {
+        workflow_config.apply_reviewer_preset(preset)?;
+    }
+    workflow_config.apply_agent_args(&cli.agent_args)?;
+    // Nobody is there to `/undo` the auto-answered plan decision or stop at a gate
+    workflow_config.undo_grace_secs = 0;
+    workflow_config.approval_gates.clear();
+    Ok((working_dir, workflow_config, package))
+}
+
+/// Runs a new workflow to completion, printing its output and answering prompts.
+async fn run_headless_workflow(
+    cli: &Cli,
+    working_dir: PathBuf,
+    workflow_config: WorkflowConfig,
+    new_input: NewWorkflowInput,
+) -> Result<()> {
+    let input = WorkflowInput::New(new_input);
+
+    let (output_tx, mut output_rx) = mpsc::unbounded_channel::<Event>();
+    let (approval_tx, approval_rx) = mpsc::channel::<UserApprovalResponse>(1);
+    let (control_tx, control_rx) = mpsc::channel::<WorkflowCommand>(1);
+    // Headless runs have no command prompt to send updates from
+    let (_, update_rx) = mpsc::unbounded_channel::<WorkflowUpdate>();
+
+    let mut workflow_handle = tokio::spawn(run_workflow_with_config(
+        input,
+        WorkflowRunConfig {
+            working_dir: working_dir.clone(),
+            config: workflow_config,
+            output_tx,
+            approval_rx,
+            control_rx,
+            update_rx,
+            session_id: HEADLESS_SESSION_ID,
+            run_id: 1,
+            no_daemon: cli.no_daemon,
+        },
+    ));
+
+    // New workflows generate their session ID internally; learn it from view updates.
+    let mut workflow_session_id: Option<String> = None;
+
+    let result = loop {
+        tokio::select! {
+            joined = &mut workflow_handle => break joined?,
+            Some(event) = output_rx.recv() => {
+                match &event {
+                    Event::Output(line) | Event::SessionOutput { line, .. } => println!("{}", line),
+                    Event::SessionViewUpdate { view, .. } => {
+                        if let Some(id) = view.workflow_id() {
+                            workflow_session_id = Some(id.to_string());
+                        }
+                    }
+                    Event::SessionToolPermissionRequest { request, .. } => {
+                        // Nobody can answer the prompt, so high-risk tool calls are denied
+                        println!(
+                            "[planning] Denying {} in headless mode: {}",
+                            request.risk.label(),
+                            request.display_name
+                        );
+                        request.respond(ToolPermissionDecision::Deny);
+                    }
+                    Event::SessionWorkspaceTrustRequest { request, .. } => {
+                        // Leaving the prompt unanswered restricts the run without remembering it
+                        println!(
+                            "[planning] {} is not trusted yet, implementing with restricted tools",
+                            request.workspace.display()
+                        );
+                    }
+                    _ => {}
+                }
+                if let Some(response) = headless_response(&event) {
+                    println!("[planning] Auto-answering decision prompt: {:?}", response);
+                    // A full or closed channel means the workflow already moved on or exited
+                    let _ = approval_tx.try_send(response);
+                }
+            }
+            _ = tokio::signal::ctrl_c() => {
+                println!("[planning] Interrupted, stopping workflow...");
+                // Workflow may have already exited - the join branch reports the result
+                let _ = control_tx.send(WorkflowCommand::Stop).await;
+            }
+        }
+    };
+
+    if cli.auto_archive {
+        match workflow_session_id {
+            Some(ref session_id) => {
+                let zip_path =
+                    export_session_zip_async(session_id, &planning_paths::archive_dir()?).await?;
+                println!("[planning] Session archived to {}", zip_path.display());
+            }
+            None => eprintln!("[planning] Nothing to archive: workflow did not start"),
+        }
+    }
+
+    match result? {
+        WorkflowResult::Accepted | WorkflowResult::ImplementationRequested => {
+            println!("[planning] Plan accepted");
+        }
+        WorkflowResult::NeedsRestart { user_feedback } => {
+            println!("[planning] Workflow requested restart: {}", user_feedback);
+        }
+        WorkflowResult::Aborted { reason } => {
+            anyhow::bail!("Workflow aborted: {}", reason);
+        }
+        WorkflowResult::Following { holder_pid } => {
+            anyhow::bail!(
+                "Session is open in another process (PID {}); resume it with --takeover",
+                holder_pid
+            );
+        }
+        WorkflowResult::Stopped => {
+            if let Some(session_id) = workflow_session_id {
+                println!(
+                    "[planning] Workflow stopped. Resume with: planning --resume-session {}",
+                    session_id
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/headless_tests.rs"]
+mod tests;
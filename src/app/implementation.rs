@@ -4,19 +4,25 @@
 //! the implementation -> review loop until approval or max iterations.
 
 use crate::app::compute_change_fingerprint;
+use crate::app::workflow::approval_gates::{ask_at_gate, GateAnswer};
 use crate::app::workflow_decisions::{
     await_max_iterations_decision, IterativePhase, MaxIterationsDecision,
 };
+use crate::app::workspace_trust::{
+    resolve_workspace_trust, restrict_config, WorkspaceTrust, RESTRICTED_ALLOWED_TOOLS,
+};
 use crate::config::WorkflowConfig;
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::types::{
-    AwaitingDecisionReason, ConversationId, ImplementationPhase, ImplementationVerdict, Iteration,
-    ResumeStrategy,
+    ApprovalGate, AwaitingDecisionReason, ConversationId, GateStep, ImplementationPhase,
+    ImplementationVerdict, Iteration, ResumeStrategy,
 };
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
 use crate::phases::implementation::run_implementation_phase;
-use crate::phases::implementation_review::run_implementation_review_phase;
+use crate::phases::implementation_review::{
+    run_implementation_review_phase, write_implementation_diff,
+};
 use crate::phases::implementing_conversation_key;
 use crate::phases::verdict::VerificationVerdictResult;
 use crate::session_daemon::session_tracking::{
@@ -157,11 +163,36 @@ pub async fn run_implementation_workflow(
     };
 
     // Validate config
-    let impl_config = &config.implementation;
-    if !impl_config.enabled {
+    if !config.implementation.enabled {
         anyhow::bail!("Implementation is disabled in config");
     }
 
+    // A session moved into a worktree after it started implements in the worktree
+    let moved_dir = view
+        .worktree_info()
+        .filter(|wt| wt.worktree_path().exists() && !working_dir.starts_with(wt.worktree_path()))
+        .map(|wt| match working_dir.strip_prefix(wt.original_dir()) {
+            Ok(relative) => wt.worktree_path().join(relative),
+            Err(_) => wt.worktree_path().to_path_buf(),
+        });
+    let working_dir = moved_dir.as_deref().unwrap_or(working_dir);
+
+    // Untrusted repositories run every agent with the restricted tool set
+    let restricted_config;
+    let config = match resolve_workspace_trust(working_dir, &session_sender, control_rx).await? {
+        Some(WorkspaceTrust::Trusted) => config,
+        Some(WorkspaceTrust::Restricted) => {
+            session_sender.send_output(format!(
+                "[implementation] Repository is not trusted, agents may only use {}",
+                RESTRICTED_ALLOWED_TOOLS.join(", ")
+            ));
+            restricted_config = restrict_config(config);
+            &restricted_config
+        }
+        None => return Ok(ImplementationWorkflowResult::Cancelled { iterations_used: 0 }),
+    };
+    let impl_config = &config.implementation;
+
     let config_max_iterations = impl_config.max_iterations;
 
     // Get initial state from view (should exist - UserRequestedImplementation was called earlier)
@@ -400,11 +431,28 @@ pub async fn run_implementation_workflow(
             }
         }
 
+        // Write the round's diff for the reviewer; worktree sessions diff against their source branch
+        let diff_base = view.worktree_info().and_then(|wt| wt.source_branch());
+        let diff = match view.workflow_id().map(|id| {
+            write_implementation_diff(working_dir, diff_base, &id.0.to_string(), local_iteration)
+        }) {
+            Some(Ok(diff)) => diff,
+            None => None,
+            Some(Err(e)) => {
+                session_sender.send_output(format!(
+                    "[implementation] Warning: Could not write the implementation diff: {}",
+                    e
+                ));
+                None
+            }
+        };
+
         // Dispatch ImplementationRoundCompleted command
         let fingerprint = compute_change_fingerprint(working_dir).unwrap_or(0);
         dispatch_impl_cmd(DomainCommand::ImplementationRoundCompleted {
             iteration: Iteration(local_iteration),
             fingerprint,
+            diff_path: diff.as_ref().map(|diff| diff.path.clone()),
         })
         .await;
 
@@ -425,6 +473,44 @@ pub async fn run_implementation_workflow(
             )
             .await;
 
+        // The implementation_round approval gate runs the round again on rejection
+        let gate = ApprovalGate::ImplementationRound;
+        if config.approval_gates.contains(&gate) {
+            dispatch_impl_cmd(DomainCommand::RecordApprovalGate {
+                gate,
+                step: GateStep::Opened {
+                    iteration: Iteration(local_iteration),
+                },
+            })
+            .await;
+            session_sender.send_output(format!(
+                "[implementation] Approval gate {} - waiting for your approval...",
+                gate.as_str()
+            ));
+            let summary = format!(
+                "Approval gate: {} (round {}/{})\n\n\
+                 Accept to have the changes reviewed, or decline with feedback to run the round again.",
+                gate.as_str(),
+                local_iteration,
+                local_max_iterations
+            );
+            let answer = ask_at_gate(summary, &session_sender, approval_rx, control_rx).await;
+            let Some(step) = answer.step() else {
+                dispatch_impl_cmd(DomainCommand::ImplementationCancelled {
+                    reason: "Stopped at the implementation_round approval gate".to_string(),
+                })
+                .await;
+                return Ok(ImplementationWorkflowResult::Cancelled {
+                    iterations_used: local_iteration,
+                });
+            };
+            dispatch_impl_cmd(DomainCommand::RecordApprovalGate { gate, step }).await;
+            if let GateAnswer::Reject(feedback) = answer {
+                current_feedback = Some(feedback);
+                continue;
+            }
+        }
+
         // === Review Phase ===
         session_sender.send_phase_started("Implementation Review".to_string());
         session_sender.send_output(format!(
@@ -438,6 +524,7 @@ pub async fn run_implementation_workflow(
             working_dir,
             local_iteration,
             Some(&impl_result.log_path),
+            diff.as_ref(),
             session_sender.clone(),
             session_logger.clone(),
         )
@@ -807,6 +894,8 @@ where
                 .send_output("[implementation] Restarting with new feedback...".to_string());
             Ok(None) // Continue the loop from restart
         }
+        // Appeals are only offered at planning max iterations; prompt again
+        MaxIterationsDecision::Appeal(_) => Ok(None),
         MaxIterationsDecision::Abort => {
             dispatch_impl_cmd(DomainCommand::ImplementationDeclined {
                 reason: "User declined at max iterations".to_string(),
@@ -2,13 +2,22 @@ pub mod change_fingerprint;
 pub mod cli;
 pub mod cli_usage;
 pub mod diagnostics;
+pub mod doctor;
+pub mod eval;
+pub mod experiment;
+pub mod headless;
 pub mod implementation;
+pub mod package;
+pub mod self_update;
+pub mod setup;
 pub mod tui_runner;
+pub mod usage_report;
 pub mod util;
 pub mod workflow;
 pub mod workflow_common;
 pub mod workflow_decisions;
 pub mod workflow_selection;
+pub mod workspace_trust;
 
 pub use change_fingerprint::*;
 pub use cli_usage::*;
@@ -0,0 +1,283 @@
+//! `--package`: targets one member of a cargo or pnpm workspace.
+//!
+//! The package's directory becomes the session's working directory, so the file
+//! index, the agents and the implementation all run inside it. Its boundaries are
+//! saved with the session and spelled out in the planner and reviewer prompts.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directories never searched for pnpm packages.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "dist"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    Cargo,
+    Pnpm,
+}
+
+impl fmt::Display for WorkspaceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cargo => write!(f, "cargo"),
+            Self::Pnpm => write!(f, "pnpm"),
+        }
+    }
+}
+
+/// A workspace member selected with `--package`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspacePackage {
+    pub name: String,
+    pub kind: WorkspaceKind,
+    pub workspace_root: PathBuf,
+    pub dir: PathBuf,
+}
+
+impl WorkspacePackage {
+    /// The package boundaries, as given to the planner and reviewers.
+    ///
+    /// Paths are relative so they still hold in a session worktree.
+    pub fn prompt_context(&self) -> String {
+        let relative = self
+            .dir
+            .strip_prefix(&self.workspace_root)
+            .unwrap_or(&self.dir);
+        let (member, root) = if relative.as_os_str().is_empty() {
+            (".".to_string(), ".".to_string())
+        } else {
+            let up: Vec<&str> = relative.components().map(|_| "..").collect();
+            (relative.display().to_string(), up.join("/"))
+        };
+        let test_command = match self.kind {
+            WorkspaceKind::Cargo => format!("cargo test -p {}", self.name),
+            WorkspaceKind::Pnpm => format!("pnpm --filter {} test", self.name),
+        };
+        format!(
+            "Target package: `{name}`, the {kind} workspace member at `{member}`.\n\
+             The workspace directory is this package's directory; the workspace root is `{root}` from it.\n\
+             - Keep the plan and its changes inside the package. Other members and the workspace root may be read for context.\n\
+             - Changes outside the package (the root manifest, lockfile, shared config or other members) must be needed for the objective and listed explicitly as cross-package changes.\n\
+             - Build and test the package on its own where possible (e.g. `{test_command}`), plus any dependents a cross-package change affects.",
+            name = self.name,
+            kind = self.kind,
+            member = member,
+            root = root,
+            test_command = test_command,
+        )
+    }
+}
+
+/// Finds the workspace containing `start` and its member called `name`.
+///
+/// Scoped pnpm names (`@acme/api`) also match by their last part (`api`) when
+/// that is unambiguous.
+pub fn resolve_package(start: &Path, name: &str) -> Result<WorkspacePackage> {
+    let start = std::fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+    let Some(root) = start.ancestors().find(|dir| is_workspace_root(dir)) else {
+        anyhow::bail!(
+            "--package needs a cargo or pnpm workspace, but none contains {}",
+            start.display()
+        );
+    };
+
+    let mut members = Vec::new();
+    if is_cargo_workspace(root) {
+        members.extend(
+            cargo_members(root)?
+                .into_iter()
+                .map(|(name, dir)| (WorkspaceKind::Cargo, name, dir)),
+        );
+    }
+    if root.join("pnpm-workspace.yaml").is_file() {
+        members.extend(
+            pnpm_members(root)?
+                .into_iter()
+                .map(|(name, dir)| (WorkspaceKind::Pnpm, name, dir)),
+        );
+    }
+
+    let exact: Vec<_> = members.iter().filter(|(_, n, _)| n == name).collect();
+    let matches = if exact.is_empty() {
+        members
+            .iter()
+            .filter(|(_, n, _)| n.rsplit_once('/').is_some_and(|(_, last)| last == name))
+            .collect()
+    } else {
+        exact
+    };
+    match matches.as_slice() {
+        [(kind, name, dir)] => Ok(WorkspacePackage {
+            name: name.clone(),
+            kind: *kind,
+            workspace_root: root.to_path_buf(),
+            dir: dir.clone(),
+        }),
+        [] => {
+            let mut names: Vec<&str> = members.iter().map(|(_, n, _)| n.as_str()).collect();
+            names.sort_unstable();
+            anyhow::bail!(
+                "No package '{}' in the workspace at {}; members: {}",
+                name,
+                root.display(),
+                names.join(", ")
+            )
+        }
+        several => {
+            let dirs: Vec<String> = several
+                .iter()
+                .map(|(kind, n, dir)| format!("{} ({} at {})", n, kind, dir.display()))
+                .collect();
+            anyhow::bail!("Package name '{}' is ambiguous: {}", name, dirs.join(", "))
+        }
+    }
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    is_cargo_workspace(dir) || dir.join("pnpm-workspace.yaml").is_file()
+}
+
+fn is_cargo_workspace(dir: &Path) -> bool {
+    std::fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest| {
+        manifest
+            .lines()
+            .any(|line| line.trim_start().starts_with("[workspace"))
+    })
+}
+
+#[derive(Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+}
+
+#[derive(Deserialize)]
+struct CargoPackage {
+    name: String,
+    manifest_path: PathBuf,
+}
+
+/// Members of a cargo workspace, from `cargo metadata`.
+fn cargo_members(root: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .current_dir(root)
+        .output()
+        .context("Failed to run cargo metadata")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "cargo metadata failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    parse_cargo_metadata(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse_cargo_metadata(json: &str) -> Result<Vec<(String, PathBuf)>> {
+    let metadata: CargoMetadata =
+        serde_json::from_str(json).context("Failed to parse cargo metadata")?;
+    Ok(metadata
+        .packages
+        .into_iter()
+        .filter_map(|package| {
+            let dir = package.manifest_path.parent()?.to_path_buf();
+            Some((package.name, dir))
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+/// Members of a pnpm workspace: directories with a named `package.json` that
+/// match the `packages` globs of `pnpm-workspace.yaml` (`!` globs exclude).
+fn pnpm_members(root: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let path = root.join("pnpm-workspace.yaml");
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let workspace: PnpmWorkspace = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    let (excludes, includes): (Vec<&str>, Vec<&str>) = workspace
+        .packages
+        .iter()
+        .map(|glob| glob.trim_start_matches("./").trim_end_matches('/'))
+        .partition(|glob| glob.starts_with('!'));
+
+    let mut dirs = Vec::new();
+    collect_package_dirs(root, &mut dirs);
+    let mut members = Vec::new();
+    for dir in dirs {
+        let Ok(relative) = dir.strip_prefix(root) else {
+            continue;
+        };
+        let segments: Vec<&str> = relative.iter().filter_map(|s| s.to_str()).collect();
+        let matched = |glob: &&str| {
+            let glob: Vec<&str> = glob.trim_start_matches('!').split('/').collect();
+            glob_match(&glob, &segments)
+        };
+        if segments.is_empty() || !includes.iter().any(matched) || excludes.iter().any(matched) {
+            continue;
+        }
+        let name = std::fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+            .and_then(|json| json.get("name")?.as_str().map(str::to_string));
+        if let Some(name) = name {
+            members.push((name, dir));
+        }
+    }
+    Ok(members)
+}
+
+/// Directories under `dir` (included) with a `package.json`, skipping hidden
+/// and build directories.
+fn collect_package_dirs(dir: &Path, found: &mut Vec<PathBuf>) {
+    if dir.join("package.json").is_file() {
+        found.push(dir.to_path_buf());
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref()) {
+            continue;
+        }
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            collect_package_dirs(&entry.path(), found);
+        }
+    }
+}
+
+/// Matches path segments against glob segments, where `**` spans any number of
+/// segments and `*` any part of one.
+pub(crate) fn glob_match(glob: &[&str], path: &[&str]) -> bool {
+    match glob.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| glob_match(rest, &path[skip..])),
+        Some((segment, rest)) => path
+            .split_first()
+            .is_some_and(|(name, path)| segment_match(segment, name) && glob_match(rest, path)),
+    }
+}
+
+fn segment_match(glob: &str, name: &str) -> bool {
+    match glob.split_once('*') {
+        None => glob == name,
+        Some((prefix, rest)) => name.strip_prefix(prefix).is_some_and(|tail| {
+            (0..=tail.len())
+                .filter(|&i| tail.is_char_boundary(i))
+                .any(|i| segment_match(rest, &tail[i..]))
+        }),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/package_tests.rs"]
+mod tests;
@@ -0,0 +1,107 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write(path: PathBuf, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn pnpm_workspace() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root.join("pnpm-workspace.yaml"),
+        "packages:\n  - 'packages/*'\n  - 'apps/**'\n  - '!packages/legacy'\n",
+    );
+    write(root.join("package.json"), r#"{"name": "monorepo"}"#);
+    write(
+        root.join("packages/api/package.json"),
+        r#"{"name": "@acme/api"}"#,
+    );
+    write(
+        root.join("packages/legacy/package.json"),
+        r#"{"name": "legacy"}"#,
+    );
+    write(
+        root.join("apps/web/site/package.json"),
+        r#"{"name": "site"}"#,
+    );
+    write(
+        root.join("packages/api/node_modules/dep/package.json"),
+        r#"{"name": "dep"}"#,
+    );
+    dir
+}
+
+#[test]
+fn test_glob_match() {
+    assert!(glob_match(&["packages", "*"], &["packages", "api"]));
+    assert!(!glob_match(&["packages", "*"], &["packages", "api", "src"]));
+    assert!(glob_match(&["apps", "**"], &["apps", "web", "site"]));
+    assert!(glob_match(&["**", "test"], &["test"]));
+    assert!(glob_match(&["tools", "cli-*"], &["tools", "cli-gen"]));
+    assert!(!glob_match(&["tools", "cli-*"], &["tools", "gen"]));
+}
+
+#[test]
+fn test_resolve_pnpm_package_by_unscoped_name() {
+    let workspace = pnpm_workspace();
+    let root = std::fs::canonicalize(workspace.path()).unwrap();
+
+    let package = resolve_package(&root.join("apps/web"), "api").unwrap();
+    assert_eq!(package.name, "@acme/api");
+    assert_eq!(package.kind, WorkspaceKind::Pnpm);
+    assert_eq!(package.workspace_root, root);
+    assert_eq!(package.dir, root.join("packages/api"));
+
+    let site = resolve_package(&root, "site").unwrap();
+    assert_eq!(site.dir, root.join("apps/web/site"));
+}
+
+#[test]
+fn test_resolve_package_lists_members_when_unknown() {
+    let workspace = pnpm_workspace();
+
+    let err = resolve_package(workspace.path(), "legacy")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("members: @acme/api, site"), "{}", err);
+    assert!(resolve_package(workspace.path(), "dep").is_err());
+}
+
+#[test]
+fn test_resolve_package_outside_workspace() {
+    let dir = TempDir::new().unwrap();
+    let err = resolve_package(dir.path(), "api").unwrap_err().to_string();
+    assert!(err.contains("needs a cargo or pnpm workspace"), "{}", err);
+}
+
+#[test]
+fn test_parse_cargo_metadata() {
+    let json = r#"{"packages": [
+        {"name": "core", "manifest_path": "/repo/crates/core/Cargo.toml", "version": "0.1.0"},
+        {"name": "cli", "manifest_path": "/repo/Cargo.toml", "version": "0.1.0"}
+    ], "workspace_root": "/repo"}"#;
+    assert_eq!(
+        parse_cargo_metadata(json).unwrap(),
+        vec![
+            ("core".to_string(), PathBuf::from("/repo/crates/core")),
+            ("cli".to_string(), PathBuf::from("/repo")),
+        ]
+    );
+}
+
+#[test]
+fn test_prompt_context_uses_relative_paths() {
+    let package = WorkspacePackage {
+        name: "core".to_string(),
+        kind: WorkspaceKind::Cargo,
+        workspace_root: PathBuf::from("/repo"),
+        dir: PathBuf::from("/repo/crates/core"),
+    };
+    let context = package.prompt_context();
+    assert!(context.contains("cargo workspace member at `crates/core`"));
+    assert!(context.contains("the workspace root is `../..` from it"));
+    assert!(context.contains("`cargo test -p core`"));
+    assert!(!context.contains("/repo"));
+}
@@ -0,0 +1,203 @@
+//! Verified, resumable release downloads.
+
+use super::Release;
+use crate::planning_paths::hex_encode;
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Checksum list every release ships, in `sha256sum` format.
+pub(super) const SUMS_ASSET: &str = "SHA256SUMS";
+
+/// SSH signature of the checksum list (`ssh-keygen -Y sign -n file`).
+pub(super) const SIGNATURE_ASSET: &str = "SHA256SUMS.sig";
+
+/// Principal the release signing key must be listed under in the signers file.
+const SIGNER_IDENTITY: &str = "releases@planning-agent";
+
+/// What a range request's response means for the partial download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeReply {
+    /// The server sent the rest of the file.
+    Append,
+    /// The server ignored the range and sent the whole file.
+    Restart,
+    /// Nothing is left to download.
+    Complete,
+}
+
+/// Interprets the status of a request for the bytes from `offset` on.
+pub fn range_reply(status: u16, offset: u64) -> Result<RangeReply> {
+    match status {
+        206 if offset > 0 => Ok(RangeReply::Append),
+        200 => Ok(RangeReply::Restart),
+        416 if offset > 0 => Ok(RangeReply::Complete),
+        _ => anyhow::bail!("Unexpected download response: HTTP {}", status),
+    }
+}
+
+/// Looks up `name`'s checksum in a `sha256sum`-style list.
+pub fn parse_checksum(sums: &str, name: &str) -> Option<String> {
+    sums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next()?;
+        let file = parts.next()?.trim_start_matches('*');
+        (file == name && hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+            .then(|| hash.to_ascii_lowercase())
+    })
+}
+
+/// Result of fetching a release binary.
+pub(super) enum Fetched {
+    /// The running binary already matches the release's checksum.
+    UpToDate,
+    /// Path of the downloaded and verified binary.
+    Binary(PathBuf),
+}
+
+/// Downloads `release`'s binary into `dir` and verifies it. Blocking.
+///
+/// The checksum list is fetched fresh and its signature verified before the
+/// binary is downloaded, so a tampered list is refused without downloading
+/// anything large. A binary that fails its checksum is deleted so the next
+/// attempt starts over instead of resuming it.
+pub(super) fn fetch_verified(
+    release: &Release,
+    asset: &str,
+    dir: &Path,
+    signers: &Path,
+    current: &Path,
+) -> Result<Fetched> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create download directory: {}", dir.display()))?;
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_connect(Some(Duration::from_secs(10)))
+        .build()
+        .into();
+
+    let sums_path = dir.join(SUMS_ASSET);
+    let signature_path = dir.join(SIGNATURE_ASSET);
+    download_small(&agent, &release.sums_url, &sums_path)?;
+    download_small(&agent, &release.signature_url, &signature_path)?;
+    verify_signature(&sums_path, &signature_path, signers)
+        .with_context(|| format!("Release {} failed signature verification", release.tag))?;
+
+    let sums = fs::read_to_string(&sums_path)?;
+    let expected = parse_checksum(&sums, asset)
+        .with_context(|| format!("{} of {} lists no {}", SUMS_ASSET, release.tag, asset))?;
+    if sha256_file(current).is_ok_and(|sum| sum == expected) {
+        return Ok(Fetched::UpToDate);
+    }
+
+    let binary = dir.join(asset);
+    if !sha256_file(&binary).is_ok_and(|sum| sum == expected) {
+        download_resumable(&agent, &release.binary_url, &binary)?;
+        let actual = sha256_file(&binary)?;
+        if actual != expected {
+            let _ = fs::remove_file(&binary);
+            anyhow::bail!(
+                "Checksum mismatch for {} of {}: expected {}, got {}",
+                asset,
+                release.tag,
+                expected,
+                actual
+            );
+        }
+    }
+    Ok(Fetched::Binary(binary))
+}
+
+fn user_agent() -> String {
+    format!("planning-agent/{}", env!("CARGO_PKG_VERSION"))
+}
+
+fn download_small(agent: &ureq::Agent, url: &str, dest: &Path) -> Result<()> {
+    let body = agent
+        .get(url)
+        .header("User-Agent", user_agent())
+        .call()
+        .with_context(|| format!("Failed to download {}", url))?
+        .body_mut()
+        .read_to_string()
+        .context("Failed to read download")?;
+    fs::write(dest, body).with_context(|| format!("Failed to write {}", dest.display()))
+}
+
+/// Downloads `url` to `dest` through `<dest>.part`, continuing from the bytes
+/// an interrupted attempt left there.
+fn download_resumable(agent: &ureq::Agent, url: &str, dest: &Path) -> Result<()> {
+    let mut partial = dest.as_os_str().to_owned();
+    partial.push(".part");
+    let partial = PathBuf::from(partial);
+    let offset = fs::metadata(&partial).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = agent.get(url).header("User-Agent", user_agent());
+    if offset > 0 {
+        request = request.header("Range", format!("bytes={}-", offset));
+    }
+    let response = match request.call() {
+        Ok(response) => Some(response),
+        Err(ureq::Error::StatusCode(416)) => None,
+        Err(e) => return Err(e).with_context(|| format!("Failed to download {}", url)),
+    };
+
+    if let Some(mut response) = response {
+        let reply = range_reply(response.status().as_u16(), offset)?;
+        let mut options = OpenOptions::new();
+        if reply == RangeReply::Append {
+            options.append(true);
+        } else {
+            options.write(true).create(true).truncate(true);
+        }
+        let mut file = options
+            .open(&partial)
+            .with_context(|| format!("Failed to open {}", partial.display()))?;
+        std::io::copy(&mut response.body_mut().as_reader(), &mut file)
+            .with_context(|| format!("Download of {} was interrupted; rerun to resume", url))?;
+    } else {
+        range_reply(416, offset)?;
+    }
+    fs::rename(&partial, dest).with_context(|| format!("Failed to move {}", partial.display()))
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file =
+        File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex_encode(&hasher.finalize()))
+}
+
+/// Verifies `signature` over `data` with `ssh-keygen -Y verify`.
+fn verify_signature(data: &Path, signature: &Path, signers: &Path) -> Result<()> {
+    let ssh_keygen =
+        which::which("ssh-keygen").context("ssh-keygen is required to verify releases")?;
+    let input = File::open(data).with_context(|| format!("Failed to open {}", data.display()))?;
+    let output = Command::new(ssh_keygen)
+        .args(["-Y", "verify", "-f"])
+        .arg(signers)
+        .args(["-I", SIGNER_IDENTITY, "-n", "file", "-s"])
+        .arg(signature)
+        .stdin(Stdio::from(input))
+        .output()
+        .context("Failed to run ssh-keygen")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "signature not made by a key trusted as {}: {}",
+            SIGNER_IDENTITY,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
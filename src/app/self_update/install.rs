@@ -0,0 +1,73 @@
+//! Staged installation of a verified binary, with rollback.
+
+use crate::app::doctor::command_version;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Replaces `target` with `binary`, keeping the previous binary as `<name>.old`.
+///
+/// The binary is copied next to `target` first, so the swap is a rename on one
+/// filesystem, and must pass a self-check there. The installed binary is checked
+/// again after the swap; if it fails, the previous binary is moved back. Returns
+/// the backup path.
+pub async fn install_staged(binary: &Path, target: &Path) -> Result<PathBuf> {
+    let name = target
+        .file_name()
+        .context("Binary path has no file name")?
+        .to_string_lossy()
+        .into_owned();
+    let staged = target.with_file_name(format!("{}.staged", name));
+    let backup = target.with_file_name(format!("{}.old", name));
+
+    fs::copy(binary, &staged)
+        .with_context(|| format!("Failed to stage update at {}", staged.display()))?;
+    make_executable(&staged)?;
+    if let Err(e) = self_check(&staged).await {
+        let _ = fs::remove_file(&staged);
+        return Err(e.context("New binary failed its self-check; nothing was installed"));
+    }
+
+    fs::rename(target, &backup)
+        .with_context(|| format!("Failed to back up {}", target.display()))?;
+    if let Err(e) = fs::rename(&staged, target) {
+        fs::rename(&backup, target).context("Failed to restore the previous binary")?;
+        let _ = fs::remove_file(&staged);
+        return Err(e).context("Failed to install the new binary; kept the previous one");
+    }
+
+    if let Err(e) = self_check(target).await {
+        fs::rename(&backup, target).with_context(|| {
+            format!(
+                "Installed binary failed its self-check and restoring {} failed",
+                backup.display()
+            )
+        })?;
+        anyhow::bail!(
+            "Installed binary failed its self-check, restored the previous one: {:#}",
+            e
+        );
+    }
+    Ok(backup)
+}
+
+/// Runs `<binary> --version` and expects it to report itself as `planning`.
+async fn self_check(binary: &Path) -> Result<()> {
+    let version = command_version(binary).await?;
+    if !version.starts_with("planning") {
+        anyhow::bail!("unexpected `--version` output: {}", version);
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,279 @@
+//! Release updates for `planning update` and the TUI's `/update`.
+//!
+//! An update installs the prebuilt binary of the newest GitHub release on the
+//! chosen channel: `stable` takes full releases only, `beta` also prereleases.
+//! The binary is downloaded into `~/.planning-agent/updates/<tag>/`, resuming a
+//! partial download from an earlier attempt. Its SHA-256 must match the
+//! release's `SHA256SUMS`, whose SSH signature must verify against the signers
+//! in `~/.planning-agent/release-signers`. The binary is then staged next to the
+//! running one and swapped in only after passing a `--version` self-check; if
+//! the swapped-in binary fails the check, the previous one is restored.
+//!
+//! Without a signers file, or without a release binary for this platform, the
+//! update builds from source with `cargo install` as before.
+
+mod download;
+mod install;
+
+use crate::planning_paths;
+use crate::update::{self, UpdateResult};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/metjm/planning-agent/releases?per_page=30";
+
+/// Which releases an update may install.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum UpdateChannel {
+    /// Full releases only.
+    #[default]
+    Stable,
+    /// Full releases and prereleases.
+    Beta,
+}
+
+impl UpdateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "stable" => Some(Self::Stable),
+            "beta" => Some(Self::Beta),
+            _ => None,
+        }
+    }
+}
+
+/// Channel saved by the last `planning update --channel`, stable by default.
+pub fn load_channel() -> UpdateChannel {
+    planning_paths::update_channel_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| UpdateChannel::parse(content.trim()))
+        .unwrap_or_default()
+}
+
+pub fn save_channel(channel: UpdateChannel) -> Result<()> {
+    let path = planning_paths::update_channel_path()?;
+    std::fs::write(&path, channel.as_str())
+        .with_context(|| format!("Failed to save update channel: {}", path.display()))
+}
+
+/// Name of this platform's binary among the release assets, e.g. `planning-x86_64-linux`.
+pub fn platform_asset_name() -> String {
+    format!(
+        "planning-{}-{}",
+        std::env::consts::ARCH,
+        std::env::consts::OS
+    )
+}
+
+/// Download URLs of a release's binary and its signed checksum list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Release {
+    pub tag: String,
+    pub binary_url: String,
+    pub sums_url: String,
+    pub signature_url: String,
+}
+
+/// Picks the newest published release on `channel` from a GitHub releases
+/// listing (newest first) and returns its download URLs for `asset`.
+///
+/// Older releases are never picked because the newest lacks an asset; that
+/// would downgrade.
+pub fn select_release(releases: &Value, channel: UpdateChannel, asset: &str) -> Result<Release> {
+    let release = releases
+        .as_array()
+        .context("Expected array response from GitHub")?
+        .iter()
+        .filter(|r| !r["draft"].as_bool().unwrap_or(false))
+        .find(|r| channel == UpdateChannel::Beta || !r["prerelease"].as_bool().unwrap_or(false))
+        .with_context(|| format!("No {} release published", channel.as_str()))?;
+    let tag = release["tag_name"]
+        .as_str()
+        .context("Missing tag_name field")?;
+    let url = |name: &str| -> Result<String> {
+        release["assets"]
+            .as_array()
+            .and_then(|assets| assets.iter().find(|a| a["name"] == name))
+            .and_then(|a| a["browser_download_url"].as_str())
+            .map(str::to_string)
+            .with_context(|| format!("Release {} has no {} asset", tag, name))
+    };
+    Ok(Release {
+        tag: tag.to_string(),
+        binary_url: url(asset)?,
+        sums_url: url(download::SUMS_ASSET)?,
+        signature_url: url(download::SIGNATURE_ASSET)?,
+    })
+}
+
+fn fetch_releases() -> Result<Value> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(10)))
+        .build()
+        .into();
+    let mut request = agent
+        .get(RELEASES_URL)
+        .header(
+            "User-Agent",
+            format!("planning-agent/{}", env!("CARGO_PKG_VERSION")),
+        )
+        .header("Accept", "application/vnd.github+json");
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+    let body = request
+        .call()
+        .context("Failed to fetch releases from GitHub")?
+        .body_mut()
+        .read_to_string()
+        .context("Failed to read response body")?;
+    serde_json::from_str(&body).context("Failed to parse GitHub response")
+}
+
+/// Outcome of updating from a release.
+#[derive(Debug)]
+enum ReleaseUpdate {
+    Installed {
+        tag: String,
+        binary: PathBuf,
+        backup: PathBuf,
+    },
+    /// The running binary already is the release's.
+    UpToDate(String),
+    /// No verifiable release binary; carries why.
+    Unavailable(String),
+}
+
+/// Updates the running binary from the newest release on `channel`.
+///
+/// Errors mean a release was found but failed verification or installation;
+/// those never fall back to a source build.
+async fn update_from_release(channel: UpdateChannel) -> Result<ReleaseUpdate> {
+    let signers = planning_paths::release_signers_path()?;
+    if !signers.exists() {
+        return Ok(ReleaseUpdate::Unavailable(format!(
+            "no trusted release signers in {}",
+            signers.display()
+        )));
+    }
+    let asset = platform_asset_name();
+    let release = {
+        let asset = asset.clone();
+        match tokio::task::spawn_blocking(move || {
+            fetch_releases().and_then(|releases| select_release(&releases, channel, &asset))
+        })
+        .await?
+        {
+            Ok(release) => release,
+            Err(e) => return Ok(ReleaseUpdate::Unavailable(format!("{:#}", e))),
+        }
+    };
+
+    let target = std::env::current_exe().context("Failed to locate the running binary")?;
+    let dir = planning_paths::updates_dir()?.join(release.tag.replace(['/', '\\'], "_"));
+    let fetched = {
+        let (release, target) = (release.clone(), target.clone());
+        tokio::task::spawn_blocking(move || {
+            download::fetch_verified(&release, &asset, &dir, &signers, &target)
+        })
+        .await??
+    };
+    let binary = match fetched {
+        download::Fetched::UpToDate => return Ok(ReleaseUpdate::UpToDate(release.tag)),
+        download::Fetched::Binary(binary) => binary,
+    };
+    let backup = install::install_staged(&binary, &target).await?;
+    Ok(ReleaseUpdate::Installed {
+        tag: release.tag,
+        binary: target,
+        backup,
+    })
+}
+
+/// Runs `planning update`, first switching channels when one is given.
+pub async fn run_update(channel: Option<UpdateChannel>) -> Result<()> {
+    let channel = match channel {
+        Some(channel) => {
+            save_channel(channel)?;
+            println!("Update channel set to {}", channel.as_str());
+            channel
+        }
+        None => load_channel(),
+    };
+
+    match update_from_release(channel).await? {
+        ReleaseUpdate::UpToDate(tag) => {
+            println!(
+                "Already up to date ({} on the {} channel)",
+                tag,
+                channel.as_str()
+            );
+            return Ok(());
+        }
+        ReleaseUpdate::Installed {
+            tag,
+            binary,
+            backup,
+        } => {
+            println!("Updated {} to {}", binary.display(), tag);
+            println!("Previous binary kept at {}", backup.display());
+        }
+        ReleaseUpdate::Unavailable(reason) => {
+            println!("No verified release binary ({}).", reason);
+            println!("Building the latest source with cargo install...");
+            match tokio::task::spawn_blocking(update::perform_update).await? {
+                UpdateResult::Success(binary, features_msg) => {
+                    println!("Installed {}{}", binary.display(), features_msg);
+                }
+                UpdateResult::GitNotFound => anyhow::bail!("Update requires git"),
+                UpdateResult::CargoNotFound => anyhow::bail!("Update requires cargo"),
+                UpdateResult::InstallFailed(err, _) => anyhow::bail!("{}", err),
+                UpdateResult::BinaryNotFound => {
+                    anyhow::bail!("Update installed but binary not found")
+                }
+            }
+        }
+    }
+    // Marker failure is non-fatal - the TUI just won't show the success notice
+    if let Err(e) = update::write_update_marker() {
+        eprintln!("[planning] Warning: Failed to write update marker: {}", e);
+    }
+    Ok(())
+}
+
+/// Update for the TUI's `/update`: from a release on the saved channel, else
+/// from source.
+pub async fn perform_update() -> UpdateResult {
+    let channel = load_channel();
+    match update_from_release(channel).await {
+        Ok(ReleaseUpdate::Installed { binary, .. }) => UpdateResult::Success(binary, String::new()),
+        Ok(ReleaseUpdate::UpToDate(tag)) => UpdateResult::InstallFailed(
+            format!(
+                "Already running {}, the newest {} release",
+                tag,
+                channel.as_str()
+            ),
+            false,
+        ),
+        Ok(ReleaseUpdate::Unavailable(_)) => tokio::task::spawn_blocking(update::perform_update)
+            .await
+            .unwrap_or_else(|_| {
+                UpdateResult::InstallFailed("Update task panicked".to_string(), false)
+            }),
+        Err(e) => UpdateResult::InstallFailed(format!("{:#}", e), false),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/self_update_tests.rs"]
+mod tests;
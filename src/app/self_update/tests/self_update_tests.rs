@@ -0,0 +1,141 @@
+use super::download::{parse_checksum, range_reply, RangeReply};
+use super::*;
+use crate::planning_paths::set_home_for_test;
+use serde_json::json;
+use tempfile::tempdir;
+
+fn release(tag: &str, prerelease: bool, assets: &[&str]) -> Value {
+    let assets: Vec<Value> = assets
+        .iter()
+        .map(|name| {
+            json!({
+                "name": name,
+                "browser_download_url": format!("https://example.com/{}/{}", tag, name),
+            })
+        })
+        .collect();
+    json!({ "tag_name": tag, "draft": false, "prerelease": prerelease, "assets": assets })
+}
+
+#[test]
+fn test_select_release_follows_channel() {
+    let all = ["planning-x86_64-linux", "SHA256SUMS", "SHA256SUMS.sig"];
+    let mut draft = release("v0.9.0", false, &all);
+    draft["draft"] = json!(true);
+    let releases = json!([
+        draft,
+        release("v0.8.0-beta.1", true, &all),
+        release("v0.7.0", false, &all),
+    ]);
+
+    let stable = select_release(&releases, UpdateChannel::Stable, "planning-x86_64-linux");
+    let stable = stable.unwrap();
+    assert_eq!(stable.tag, "v0.7.0");
+    assert_eq!(
+        stable.binary_url,
+        "https://example.com/v0.7.0/planning-x86_64-linux"
+    );
+    assert_eq!(
+        stable.signature_url,
+        "https://example.com/v0.7.0/SHA256SUMS.sig"
+    );
+
+    let beta = select_release(&releases, UpdateChannel::Beta, "planning-x86_64-linux");
+    assert_eq!(beta.unwrap().tag, "v0.8.0-beta.1");
+}
+
+#[test]
+fn test_select_release_does_not_fall_back_to_older_releases() {
+    let releases = json!([
+        release("v0.8.0", false, &["SHA256SUMS", "SHA256SUMS.sig"]),
+        release(
+            "v0.7.0",
+            false,
+            &["planning-x86_64-linux", "SHA256SUMS", "SHA256SUMS.sig"]
+        ),
+    ]);
+    let err = select_release(&releases, UpdateChannel::Stable, "planning-x86_64-linux")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("v0.8.0 has no planning-x86_64-linux"));
+
+    let only_beta = json!([release("v0.8.0-rc.1", true, &[])]);
+    assert!(select_release(&only_beta, UpdateChannel::Stable, "planning-x86_64-linux").is_err());
+}
+
+#[test]
+fn test_parse_checksum() {
+    let hash = "a".repeat(64);
+    let sums = format!(
+        "{}  planning-aarch64-macos\n{} *planning-x86_64-linux\nnot-a-hash  planning-x86_64-windows\n",
+        "b".repeat(64),
+        hash.to_uppercase()
+    );
+    assert_eq!(parse_checksum(&sums, "planning-x86_64-linux"), Some(hash));
+    assert_eq!(parse_checksum(&sums, "planning-x86_64-windows"), None);
+    assert_eq!(parse_checksum(&sums, "planning-x86_64"), None);
+}
+
+#[test]
+fn test_range_reply() {
+    assert_eq!(range_reply(206, 1024).unwrap(), RangeReply::Append);
+    assert_eq!(range_reply(200, 1024).unwrap(), RangeReply::Restart);
+    assert_eq!(range_reply(200, 0).unwrap(), RangeReply::Restart);
+    assert_eq!(range_reply(416, 1024).unwrap(), RangeReply::Complete);
+    assert!(range_reply(416, 0).is_err());
+    assert!(range_reply(206, 0).is_err());
+    assert!(range_reply(404, 1024).is_err());
+}
+
+#[test]
+fn test_channel_is_saved() {
+    let dir = tempdir().unwrap();
+    let _guard = set_home_for_test(dir.path().to_path_buf());
+
+    assert_eq!(load_channel(), UpdateChannel::Stable);
+    save_channel(UpdateChannel::Beta).unwrap();
+    assert_eq!(load_channel(), UpdateChannel::Beta);
+}
+
+#[cfg(unix)]
+fn write_script(path: &std::path::Path, body: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::write(path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_install_staged_swaps_in_a_working_binary() {
+    let dir = tempdir().unwrap();
+    let target = dir.path().join("planning");
+    let download = dir.path().join("download");
+    write_script(&target, "echo planning 0.7.0");
+    write_script(&download, "echo planning 0.8.0");
+
+    let backup = install::install_staged(&download, &target).await.unwrap();
+
+    assert_eq!(backup, dir.path().join("planning.old"));
+    assert!(std::fs::read_to_string(&target).unwrap().contains("0.8.0"));
+    assert!(std::fs::read_to_string(&backup).unwrap().contains("0.7.0"));
+    assert!(!dir.path().join("planning.staged").exists());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_install_staged_keeps_the_current_binary_when_the_new_one_fails() {
+    let dir = tempdir().unwrap();
+    let target = dir.path().join("planning");
+    let download = dir.path().join("download");
+    write_script(&target, "echo planning 0.7.0");
+    write_script(&download, "exit 1");
+
+    let err = install::install_staged(&download, &target)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("nothing was installed"));
+    assert!(std::fs::read_to_string(&target).unwrap().contains("0.7.0"));
+    assert!(!dir.path().join("planning.staged").exists());
+    assert!(!dir.path().join("planning.old").exists());
+}
@@ -0,0 +1,188 @@
+//! First-run setup: picks the agents and worktree default for a new install.
+//!
+//! On the first TUI launch without any workflow config, a wizard detects the
+//! installed agent CLIs and their stored logins, asks for the planner, the
+//! reviewers and whether sessions use worktrees, and writes the result to
+//! `~/.planning-agent/workflows/`. The written workflow becomes the default
+//! selection, so every working directory without its own selection uses it.
+
+pub mod wizard;
+
+use crate::app::doctor::{check_auth, command_version, CheckStatus, KNOWN_CLIS};
+use crate::app::{list_available_workflows_for_display, workflows_dir, WorkflowSelection};
+use crate::config::{AgentRef, WorkflowConfig};
+use crate::planning_paths;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Name of the workflow the wizard writes.
+pub const SETUP_WORKFLOW_NAME: &str = "my-workflow";
+
+/// An agent CLI as found on this machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedCli {
+    pub command: &'static str,
+    /// `--version` output, or None when the CLI is missing or broken.
+    pub version: Option<String>,
+    /// Path and version, or why the CLI cannot be used.
+    pub detail: String,
+    /// Whether a stored login or API key was found.
+    pub authenticated: bool,
+    /// What was found about the login, or how to log in.
+    pub auth_detail: String,
+}
+
+impl DetectedCli {
+    pub fn installed(&self) -> bool {
+        self.version.is_some()
+    }
+}
+
+/// Finds the known agent CLIs and checks their stored credentials.
+pub async fn detect_clis() -> Vec<DetectedCli> {
+    let mut clis = Vec::new();
+    for known in KNOWN_CLIS {
+        let install = format!("install with `{}`", known.install);
+        let (version, detail) = match which::which(known.command) {
+            Err(_) => (None, format!("not found; {}", install)),
+            Ok(path) => match command_version(&path).await {
+                Ok(version) => (Some(version.clone()), version),
+                Err(e) => (None, format!("{} is broken: {}", path.display(), e)),
+            },
+        };
+        let auth = check_auth(known);
+        let auth_detail = match auth.fix {
+            Some(fix) if auth.status != CheckStatus::Ok => format!("{}; {}", auth.detail, fix),
+            _ => auth.detail,
+        };
+        clis.push(DetectedCli {
+            command: known.command,
+            version,
+            detail,
+            authenticated: auth.status == CheckStatus::Ok,
+            auth_detail,
+        });
+    }
+    clis
+}
+
+/// What the user picked in the wizard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupChoices {
+    /// Agent that plans, revises and implements.
+    pub planner: String,
+    /// Agents reviewing each plan, in order.
+    pub reviewers: Vec<String>,
+    /// Whether sessions get a git worktree by default.
+    pub worktree: bool,
+}
+
+/// Builds the workflow for the choices.
+///
+/// With a single CLI the matching built-in `<cli>-only` workflow is used, which
+/// brings its own reviewer and implementation-review agents. Otherwise the default
+/// workflow's phases are pointed at the chosen agents.
+pub fn build_workflow(choices: &SetupChoices) -> Result<WorkflowConfig> {
+    let single_cli = choices.reviewers.iter().all(|r| *r == choices.planner);
+    let mut config = if single_cli {
+        match choices.planner.as_str() {
+            "claude" => WorkflowConfig::claude_only_config(),
+            "codex" => WorkflowConfig::codex_only_config(),
+            "gemini" => WorkflowConfig::gemini_only_config(),
+            other => anyhow::bail!("Unknown agent CLI '{}'", other),
+        }
+    } else {
+        let mut config = WorkflowConfig::default_config();
+        config.workflow.planning.agent = choices.planner.clone();
+        config.workflow.reviewing.agents = choices
+            .reviewers
+            .iter()
+            .map(|r| AgentRef::Simple(r.clone()))
+            .collect();
+        // The picked reviewers replace the panel, including its disabled ones
+        config.workflow.reviewing.disabled.clear();
+        // Re-derived from the new phases: the planner implements and the first
+        // other reviewer reviews the implementation
+        config.implementation.implementing = None;
+        config.implementation.reviewing = None;
+        config.implementation.normalize(&config.workflow)?;
+        config
+    };
+
+    // Drop the agents nothing uses, so only picked CLIs are required, and the
+    // reviewer presets that need them
+    let mut used: HashSet<String> = config
+        .workflow
+        .reviewing
+        .enabled_agents()
+        .iter()
+        .map(|r| r.agent_name().to_string())
+        .collect();
+    used.insert(config.workflow.planning.agent.clone());
+    used.extend(
+        config
+            .implementation
+            .implementing_agent()
+            .map(str::to_string),
+    );
+    used.extend(config.implementation.reviewing_agent().map(str::to_string));
+    config.agents.retain(|name, _| used.contains(name));
+    config.reviewer_presets.retain(|_, preset| {
+        preset
+            .agents
+            .iter()
+            .all(|a| config.agents.contains_key(a.agent_name()))
+    });
+
+    config.name = SETUP_WORKFLOW_NAME.to_string();
+    config.worktree.enabled = choices.worktree;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Writes the workflow for the choices and makes it the default selection.
+pub fn save_setup(choices: &SetupChoices) -> Result<PathBuf> {
+    let config = build_workflow(choices)?;
+    let path = workflows_dir()?.join(format!("{}.yaml", SETUP_WORKFLOW_NAME));
+    let content = serde_yaml::to_string(&config).context("Failed to serialize workflow")?;
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write workflow: {}", path.display()))?;
+    WorkflowSelection {
+        workflow: SETUP_WORKFLOW_NAME.to_string(),
+    }
+    .save_default()?;
+    Ok(path)
+}
+
+/// Records that setup was skipped, so the wizard is not shown again; the
+/// built-in default workflow stays selected.
+pub fn skip_setup() -> Result<()> {
+    WorkflowSelection::default().save_default()
+}
+
+/// Whether this looks like a first launch: no workflow selection, no project or
+/// user workflow, and no earlier sessions.
+pub fn needs_setup(working_dir: &Path) -> bool {
+    let configured = || -> Result<bool> {
+        if WorkflowSelection::default_path()?.exists()
+            || WorkflowSelection::selection_path(working_dir)?.exists()
+            || working_dir.join("workflow.yaml").exists()
+        {
+            return Ok(true);
+        }
+        let user_workflows = list_available_workflows_for_display()?
+            .iter()
+            .any(|w| w.source != "built-in");
+        let sessions = std::fs::read_dir(planning_paths::sessions_dir()?)?
+            .next()
+            .is_some();
+        Ok(user_workflows || sessions)
+    };
+    // An unusable planning home is reported by the session itself; no wizard then
+    configured().is_ok_and(|configured| !configured)
+}
+
+#[cfg(test)]
+#[path = "tests/setup_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+use crate::config::WorkflowConfig;
+use tempfile::tempdir;
+
+fn choices(planner: &str, reviewers: &[&str]) -> SetupChoices {
+    SetupChoices {
+        planner: planner.to_string(),
+        reviewers: reviewers.iter().map(|r| r.to_string()).collect(),
+        worktree: true,
+    }
+}
+
+#[test]
+fn test_build_workflow_narrows_default_to_chosen_agents() {
+    let config = build_workflow(&choices("claude", &["codex"])).unwrap();
+
+    assert_eq!(config.name, SETUP_WORKFLOW_NAME);
+    assert!(config.worktree.enabled);
+    assert_eq!(config.workflow.planning.agent, "claude");
+    let reviewers: Vec<&str> = config
+        .workflow
+        .reviewing
+        .agents
+        .iter()
+        .map(|r| r.agent_name())
+        .collect();
+    assert_eq!(reviewers, vec!["codex"]);
+    assert!(!config.agents.contains_key("gemini"));
+    assert_eq!(config.implementation.implementing_agent(), Some("claude"));
+    // Presets naming gemini are dropped with it
+    assert!(config.reviewer_presets.contains_key("fast"));
+    assert!(config.reviewer_presets.contains_key("security"));
+    assert!(!config.reviewer_presets.contains_key("thorough"));
+}
+
+#[test]
+fn test_build_workflow_single_cli_uses_builtin() {
+    let config = build_workflow(&choices("codex", &["codex"])).unwrap();
+    let builtin = WorkflowConfig::codex_only_config();
+
+    assert_eq!(config.name, SETUP_WORKFLOW_NAME);
+    assert_eq!(
+        config.workflow.planning.agent,
+        builtin.workflow.planning.agent
+    );
+    assert!(config.agents.values().all(|agent| agent.command == "codex"));
+
+    assert!(build_workflow(&choices("aider", &["aider"])).is_err());
+}
+
+#[test]
+fn test_save_setup_selects_written_workflow() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let project = tempdir().unwrap();
+    assert!(needs_setup(project.path()));
+
+    let path = save_setup(&choices("gemini", &["claude", "gemini"])).unwrap();
+    let loaded = WorkflowConfig::load(&path).unwrap();
+    assert_eq!(loaded.workflow.planning.agent, "gemini");
+    assert_eq!(
+        WorkflowSelection::load(project.path()).unwrap().workflow,
+        SETUP_WORKFLOW_NAME
+    );
+    assert!(!needs_setup(project.path()));
+}
+
+#[test]
+fn test_needs_setup_not_after_skip_or_with_project_workflow() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let project = tempdir().unwrap();
+
+    std::fs::write(project.path().join("workflow.yaml"), "").unwrap();
+    assert!(!needs_setup(project.path()));
+
+    let other = tempdir().unwrap();
+    skip_setup().unwrap();
+    assert!(!needs_setup(other.path()));
+    assert_eq!(
+        WorkflowSelection::load(other.path()).unwrap().workflow,
+        "claude-only"
+    );
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+fn cli(command: &'static str, installed: bool, authenticated: bool) -> DetectedCli {
+    DetectedCli {
+        command,
+        version: installed.then(|| "1.0.0".to_string()),
+        detail: String::new(),
+        authenticated,
+        auth_detail: String::new(),
+    }
+}
+
+fn press(wizard: &mut Wizard, code: KeyCode) -> WizardAction {
+    wizard.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_defaults_follow_logins() {
+    let wizard = Wizard::new(vec![
+        cli("claude", true, false),
+        cli("codex", false, false),
+        cli("gemini", true, true),
+    ]);
+    let choices = wizard.choices();
+    assert_eq!(choices.planner, "gemini");
+    assert_eq!(choices.reviewers, vec!["gemini"]);
+    assert!(!choices.worktree);
+}
+
+#[test]
+fn test_walks_through_steps_to_save() {
+    let mut wizard = Wizard::new(vec![
+        cli("claude", true, true),
+        cli("codex", true, true),
+        cli("gemini", false, false),
+    ]);
+    assert_eq!(press(&mut wizard, KeyCode::Enter), WizardAction::None);
+    assert_eq!(wizard.step, Step::Planner);
+
+    // Only installed CLIs are offered
+    press(&mut wizard, KeyCode::Down);
+    press(&mut wizard, KeyCode::Down);
+    press(&mut wizard, KeyCode::Enter);
+    assert_eq!(wizard.step, Step::Reviewers);
+
+    // Unticking every reviewer blocks the step
+    press(&mut wizard, KeyCode::Char(' '));
+    press(&mut wizard, KeyCode::Down);
+    press(&mut wizard, KeyCode::Char(' '));
+    press(&mut wizard, KeyCode::Enter);
+    assert_eq!(wizard.step, Step::Reviewers);
+    assert!(wizard.error.is_some());
+    press(&mut wizard, KeyCode::Up);
+    press(&mut wizard, KeyCode::Char(' '));
+    press(&mut wizard, KeyCode::Enter);
+    assert_eq!(wizard.step, Step::Worktree);
+
+    press(&mut wizard, KeyCode::Up);
+    press(&mut wizard, KeyCode::Enter);
+    assert_eq!(wizard.step, Step::Confirm);
+    assert_eq!(
+        wizard.choices(),
+        SetupChoices {
+            planner: "codex".to_string(),
+            reviewers: vec!["claude".to_string()],
+            worktree: true,
+        }
+    );
+    assert_eq!(press(&mut wizard, KeyCode::Enter), WizardAction::Save);
+}
+
+#[test]
+fn test_esc_goes_back_then_skips() {
+    let mut wizard = Wizard::new(vec![cli("claude", true, true)]);
+    press(&mut wizard, KeyCode::Enter);
+    assert_eq!(press(&mut wizard, KeyCode::Esc), WizardAction::None);
+    assert_eq!(wizard.step, Step::Detect);
+    assert_eq!(press(&mut wizard, KeyCode::Esc), WizardAction::Skip);
+}
+
+#[test]
+fn test_nothing_installed_only_closes() {
+    let mut wizard = Wizard::new(vec![cli("claude", false, false)]);
+    assert_eq!(press(&mut wizard, KeyCode::Enter), WizardAction::Close);
+    assert_eq!(press(&mut wizard, KeyCode::Esc), WizardAction::Close);
+}
@@ -0,0 +1,364 @@
+//! The setup wizard's screens and key handling.
+
+use super::{detect_clis, save_setup, skip_setup, DetectedCli, SetupChoices};
+use anyhow::Result;
+use crossterm::event::{
+    self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Detected CLIs and their logins
+    Detect,
+    Planner,
+    Reviewers,
+    Worktree,
+    /// Summary before writing the workflow
+    Confirm,
+}
+
+/// What a key press asks the runner to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardAction {
+    None,
+    /// Write the workflow for the current choices
+    Save,
+    /// Leave and don't ask again
+    Skip,
+    /// Leave for now; the wizard comes back on the next launch
+    Close,
+}
+
+pub struct Wizard {
+    pub clis: Vec<DetectedCli>,
+    pub step: Step,
+    pub cursor: usize,
+    /// Index into `installed()` of the planner
+    pub planner: usize,
+    /// Per entry of `installed()`, whether it reviews
+    pub reviewers: Vec<bool>,
+    pub worktree: bool,
+    pub error: Option<String>,
+}
+
+impl Wizard {
+    /// Starts with the first logged-in CLI planning and every logged-in CLI reviewing.
+    pub fn new(clis: Vec<DetectedCli>) -> Self {
+        let installed: Vec<&DetectedCli> = clis.iter().filter(|c| c.installed()).collect();
+        let planner = installed.iter().position(|c| c.authenticated).unwrap_or(0);
+        let mut reviewers: Vec<bool> = installed.iter().map(|c| c.authenticated).collect();
+        if !reviewers.contains(&true) {
+            reviewers.iter_mut().for_each(|r| *r = true);
+        }
+        Self {
+            clis,
+            step: Step::Detect,
+            cursor: 0,
+            planner,
+            reviewers,
+            worktree: false,
+            error: None,
+        }
+    }
+
+    /// CLIs that can be picked.
+    pub fn installed(&self) -> Vec<&DetectedCli> {
+        self.clis.iter().filter(|c| c.installed()).collect()
+    }
+
+    pub fn choices(&self) -> SetupChoices {
+        let installed = self.installed();
+        SetupChoices {
+            planner: installed[self.planner].command.to_string(),
+            reviewers: installed
+                .iter()
+                .zip(&self.reviewers)
+                .filter(|(_, on)| **on)
+                .map(|(cli, _)| cli.command.to_string())
+                .collect(),
+            worktree: self.worktree,
+        }
+    }
+
+    fn go_to(&mut self, step: Step) {
+        self.cursor = match step {
+            Step::Planner => self.planner,
+            Step::Worktree => usize::from(!self.worktree),
+            _ => 0,
+        };
+        self.step = step;
+        self.error = None;
+    }
+
+    /// Number of entries the cursor moves over on this step.
+    fn options(&self) -> usize {
+        match self.step {
+            Step::Planner | Step::Reviewers => self.installed().len(),
+            Step::Worktree => 2,
+            Step::Detect | Step::Confirm => 0,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> WizardAction {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return WizardAction::Close;
+        }
+        match key.code {
+            KeyCode::Up => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Down if self.cursor + 1 < self.options() => self.cursor += 1,
+            KeyCode::Char(' ') if self.step == Step::Reviewers => {
+                self.reviewers[self.cursor] = !self.reviewers[self.cursor];
+                self.error = None;
+            }
+            KeyCode::Esc => match self.step {
+                Step::Detect if self.installed().is_empty() => return WizardAction::Close,
+                Step::Detect => return WizardAction::Skip,
+                Step::Planner => self.go_to(Step::Detect),
+                Step::Reviewers => self.go_to(Step::Planner),
+                Step::Worktree => self.go_to(Step::Reviewers),
+                Step::Confirm => self.go_to(Step::Worktree),
+            },
+            KeyCode::Enter => match self.step {
+                Step::Detect if self.installed().is_empty() => return WizardAction::Close,
+                Step::Detect => self.go_to(Step::Planner),
+                Step::Planner => {
+                    self.planner = self.cursor;
+                    self.go_to(Step::Reviewers);
+                }
+                Step::Reviewers if !self.reviewers.contains(&true) => {
+                    self.error = Some("Pick at least one reviewer".to_string());
+                }
+                Step::Reviewers => self.go_to(Step::Worktree),
+                Step::Worktree => {
+                    self.worktree = self.cursor == 0;
+                    self.go_to(Step::Confirm);
+                }
+                Step::Confirm => return WizardAction::Save,
+            },
+            _ => {}
+        }
+        WizardAction::None
+    }
+}
+
+type SetupTerminal = Terminal<CrosstermBackend<std::io::Stdout>>;
+
+/// Runs the wizard in the TUI's terminal. Returns the written workflow, or None
+/// when the user skipped or closed it.
+pub async fn run_setup_wizard(terminal: &mut SetupTerminal) -> Result<Option<PathBuf>> {
+    terminal.draw(|frame| {
+        draw_frame(
+            frame,
+            vec![Line::from(" Detecting agent CLIs...")],
+            Vec::new(),
+        )
+    })?;
+    let mut wizard = Wizard::new(detect_clis().await);
+
+    loop {
+        terminal.draw(|frame| draw(frame, &wizard))?;
+        let CrosstermEvent::Key(key) = tokio::task::block_in_place(event::read)? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match wizard.handle_key(key) {
+            WizardAction::None => {}
+            WizardAction::Close => return Ok(None),
+            WizardAction::Skip => {
+                skip_setup()?;
+                return Ok(None);
+            }
+            WizardAction::Save => match save_setup(&wizard.choices()) {
+                Ok(path) => return Ok(Some(path)),
+                Err(e) => wizard.error = Some(format!("{:#}", e)),
+            },
+        }
+    }
+}
+
+fn hint(key: &'static str, label: &'static str) -> Vec<Span<'static>> {
+    vec![
+        Span::styled(key, Style::default().fg(Color::Yellow)),
+        Span::raw(format!(" {}  ", label)),
+    ]
+}
+
+fn draw(frame: &mut Frame, wizard: &Wizard) {
+    let installed = wizard.installed();
+    let mut lines = Vec::new();
+    let hints = match wizard.step {
+        Step::Detect => {
+            lines.push(Line::from(
+                " No workflow config was found. Let's pick the agents to use.",
+            ));
+            lines.push(Line::from(""));
+            for cli in &wizard.clis {
+                let (mark, color) = match (cli.installed(), cli.authenticated) {
+                    (false, _) => ("x", Color::Red),
+                    (true, false) => ("!", Color::Yellow),
+                    (true, true) => ("✓", Color::Green),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!(" {} ", mark), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{:<8}", cli.command),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(cli.detail.clone()),
+                ]));
+                if cli.installed() {
+                    lines.push(Line::from(Span::styled(
+                        format!("            login: {}", cli.auth_detail),
+                        Style::default().fg(Color::DarkGray),
+                    )));
+                }
+            }
+            lines.push(Line::from(""));
+            if installed.is_empty() {
+                lines.push(Line::from(
+                    " No agent CLI was found. Install one and start planning again.",
+                ));
+                [hint("[Esc]", "continue without setup")].concat()
+            } else {
+                [hint("[Enter]", "continue"), hint("[Esc]", "skip setup")].concat()
+            }
+        }
+        Step::Planner => {
+            lines.push(Line::from(
+                " Which agent writes and revises plans, and implements them?",
+            ));
+            lines.push(Line::from(""));
+            for (i, cli) in installed.iter().enumerate() {
+                lines.push(option_line(wizard.cursor == i, cli.command.to_string()));
+            }
+            [
+                hint("[↑/↓]", "move"),
+                hint("[Enter]", "choose"),
+                hint("[Esc]", "back"),
+            ]
+            .concat()
+        }
+        Step::Reviewers => {
+            lines.push(Line::from(" Which agents review each plan?"));
+            lines.push(Line::from(""));
+            for (i, cli) in installed.iter().enumerate() {
+                let check = if wizard.reviewers[i] { "[x]" } else { "[ ]" };
+                lines.push(option_line(
+                    wizard.cursor == i,
+                    format!("{} {}", check, cli.command),
+                ));
+            }
+            [
+                hint("[Space]", "toggle"),
+                hint("[Enter]", "continue"),
+                hint("[Esc]", "back"),
+            ]
+            .concat()
+        }
+        Step::Worktree => {
+            lines.push(Line::from(
+                " Give each session its own git worktree and branch by default?",
+            ));
+            lines.push(Line::from(""));
+            lines.push(option_line(wizard.cursor == 0, "Yes".to_string()));
+            lines.push(option_line(wizard.cursor == 1, "No".to_string()));
+            [
+                hint("[↑/↓]", "move"),
+                hint("[Enter]", "choose"),
+                hint("[Esc]", "back"),
+            ]
+            .concat()
+        }
+        Step::Confirm => {
+            let choices = wizard.choices();
+            lines.push(Line::from(format!(" Planner:    {}", choices.planner)));
+            lines.push(Line::from(format!(
+                " Reviewers:  {}",
+                choices.reviewers.join(", ")
+            )));
+            lines.push(Line::from(format!(
+                " Worktrees:  {}",
+                if choices.worktree { "on" } else { "off" }
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                " Saved as ~/.planning-agent/workflows/{}.yaml and used wherever no other",
+                super::SETUP_WORKFLOW_NAME
+            )));
+            lines.push(Line::from(
+                " workflow is selected. Change it later with /workflow or /config.",
+            ));
+            [hint("[Enter]", "save"), hint("[Esc]", "back")].concat()
+        }
+    };
+    if let Some(error) = &wizard.error {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    draw_frame(frame, lines, hints);
+}
+
+fn option_line(selected: bool, label: String) -> Line<'static> {
+    if selected {
+        Line::from(Span::styled(
+            format!(" > {}", label),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+    } else {
+        Line::from(format!("   {}", label))
+    }
+}
+
+fn draw_frame(frame: &mut Frame, lines: Vec<Line<'static>>, hints: Vec<Span<'static>>) {
+    let area = frame.area();
+    let popup_width = 76u16.min(area.width.saturating_sub(4));
+    let popup_height = (lines.len() as u16 + 5).min(area.height.saturating_sub(2));
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(popup_area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Planning Agent Setup ")
+        .title_alignment(Alignment::Center);
+    let mut content = vec![Line::from("")];
+    content.extend(lines);
+    frame.render_widget(
+        Paragraph::new(content)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        chunks[0],
+    );
+    let mut footer = vec![Span::raw("  ")];
+    footer.extend(hints);
+    frame.render_widget(Paragraph::new(Line::from(footer)), chunks[1]);
+}
+
+#[cfg(test)]
+#[path = "tests/wizard_tests.rs"]
+mod tests;
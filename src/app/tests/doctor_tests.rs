@@ -0,0 +1,116 @@
+use super::*;
+use tempfile::tempdir;
+
+/// PID of a process that has exited and been reaped.
+fn dead_pid() -> u32 {
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    pid
+}
+
+fn port_file(token: &str) -> PortFileContent {
+    PortFileContent {
+        port: 1,
+        subscriber_port: 2,
+        file_service_port: 3,
+        token: token.to_string(),
+        socket_path: None,
+    }
+}
+
+#[test]
+fn test_format_report_shows_fixes_for_problems_only() {
+    let checks = vec![
+        DoctorCheck::ok("git", "git version 2.43.0"),
+        DoctorCheck::fail("claude", "not found on PATH", "install it"),
+        DoctorCheck {
+            fix: Some("hidden".to_string()),
+            ..DoctorCheck::ok("colors", "24-bit color")
+        },
+    ];
+
+    let report = format_report(&checks);
+
+    assert!(report.contains("[ok  ] git     git version 2.43.0"));
+    assert!(report.contains("[FAIL] claude  not found on PATH"));
+    assert!(report.contains("fix: install it"));
+    assert!(!report.contains("hidden"));
+    assert!(report.contains("3 check(s): 0 warning(s), 1 failure(s)"));
+}
+
+#[test]
+fn test_color_check() {
+    assert_eq!(color_check("xterm", "truecolor").status, CheckStatus::Ok);
+    assert_eq!(color_check("xterm-256color", "").status, CheckStatus::Ok);
+    assert_eq!(color_check("xterm", "").status, CheckStatus::Warn);
+    assert_eq!(color_check("dumb", "truecolor").status, CheckStatus::Fail);
+}
+
+#[test]
+fn test_planning_dir_is_writable() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+
+    let checks = check_planning_dir();
+
+    assert_eq!(checks.len(), 2);
+    assert!(checks.iter().all(|c| c.status == CheckStatus::Ok));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_world_writable_planning_dir_warns() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = tempdir().unwrap();
+    std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o777)).unwrap();
+
+    let check = check_writable_dir("planning home", dir.path());
+
+    assert_eq!(check.status, CheckStatus::Warn);
+    assert!(check.fix.unwrap().contains("chmod go-w"));
+}
+
+#[tokio::test]
+async fn test_daemon_not_running_is_ok() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+
+    let checks = check_daemon().await;
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].status, CheckStatus::Ok);
+    assert!(checks[0].detail.contains("not running"));
+}
+
+#[tokio::test]
+async fn test_stale_port_file_warns() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+    port_file("token")
+        .write_private(&planning_paths::sessiond_port_path().unwrap())
+        .unwrap();
+    std::fs::write(
+        planning_paths::sessiond_pid_path().unwrap(),
+        dead_pid().to_string(),
+    )
+    .unwrap();
+
+    let checks = check_daemon().await;
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].status, CheckStatus::Warn);
+    assert!(checks[0].detail.contains("no longer running"));
+}
+
+#[tokio::test]
+async fn test_corrupt_port_file_fails() {
+    let dir = tempdir().unwrap();
+    let _home = planning_paths::set_home_for_test(dir.path().to_path_buf());
+    std::fs::write(planning_paths::sessiond_port_path().unwrap(), "{not json").unwrap();
+
+    let checks = check_daemon().await;
+
+    assert_eq!(checks[0].status, CheckStatus::Fail);
+    assert!(checks[0].fix.as_ref().unwrap().starts_with("delete "));
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::app::workflow_decisions::IterativePhase;
+
+#[test]
+fn test_headless_accepts_plan_approval() {
+    let event = Event::SessionApprovalRequest {
+        session_id: HEADLESS_SESSION_ID,
+        summary: "Plan approved".to_string(),
+    };
+    assert!(matches!(
+        headless_response(&event),
+        Some(UserApprovalResponse::Accept)
+    ));
+}
+
+#[test]
+fn test_headless_continues_reviews_and_iterations() {
+    let review = Event::SessionReviewDecisionRequest {
+        session_id: HEADLESS_SESSION_ID,
+        summary: "1 of 2 reviewers failed".to_string(),
+    };
+    assert!(matches!(
+        headless_response(&review),
+        Some(UserApprovalResponse::ReviewContinue)
+    ));
+
+    let max_iterations = Event::SessionMaxIterationsReached {
+        session_id: HEADLESS_SESSION_ID,
+        phase: IterativePhase::Planning,
+        summary: "Max iterations reached".to_string(),
+    };
+    assert!(matches!(
+        headless_response(&max_iterations),
+        Some(UserApprovalResponse::ProceedWithoutApproval)
+    ));
+}
+
+#[test]
+fn test_headless_failure_responses() {
+    let plan_failed = Event::SessionPlanGenerationFailed {
+        session_id: HEADLESS_SESSION_ID,
+        error: "agent crashed".to_string(),
+    };
+    assert!(matches!(
+        headless_response(&plan_failed),
+        Some(UserApprovalResponse::AbortWorkflow)
+    ));
+
+    let workflow_failed = Event::SessionWorkflowFailure {
+        session_id: HEADLESS_SESSION_ID,
+        summary: "timeout".to_string(),
+    };
+    assert!(matches!(
+        headless_response(&workflow_failed),
+        Some(UserApprovalResponse::WorkflowFailureStop)
+    ));
+}
+
+#[test]
+fn test_headless_accepts_all_triaged_feedback() {
+    let item = crate::domain::review::FeedbackItem {
+        reviewer: "claude".to_string(),
+        section: None,
+        text: "Add tests".to_string(),
+    };
+    let event = Event::SessionFeedbackTriageRequest {
+        session_id: HEADLESS_SESSION_ID,
+        items: vec![item.clone(), item],
+    };
+    match headless_response(&event) {
+        Some(UserApprovalResponse::FeedbackTriaged(decisions)) => {
+            assert_eq!(decisions, vec![TriageDecision::Accept; 2]);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_headless_ignores_non_prompt_events() {
+    let output = Event::SessionOutput {
+        session_id: HEADLESS_SESSION_ID,
+        line: "[planning] Planning...".to_string(),
+    };
+    assert!(headless_response(&output).is_none());
+}
+
+#[test]
+fn test_plan_objective_uses_heading_or_file_name() {
+    let path = Path::new("/tmp/plans/retry-uploads.md");
+    assert_eq!(
+        plan_objective("Intro\n# Retry failed uploads \n\n## Steps", path),
+        "Retry failed uploads"
+    );
+    assert_eq!(
+        plan_objective("## Steps\n1. Do it", path),
+        "Implement retry-uploads.md"
+    );
+}
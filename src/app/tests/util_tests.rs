@@ -141,3 +141,45 @@ fn test_shell_quote_path_with_glob() {
     let quoted = shell_quote_path(path);
     assert_eq!(quoted, "\"/path/with*glob\"");
 }
+
+#[test]
+fn test_terminal_progress_escape_sequences() {
+    assert_eq!(
+        TerminalProgress::Normal(40).escape_sequence(),
+        "\x1b]9;4;1;40\x07"
+    );
+    assert_eq!(
+        TerminalProgress::Attention(150).escape_sequence(),
+        "\x1b]9;4;4;100\x07"
+    );
+    assert_eq!(
+        TerminalProgress::Hidden.escape_sequence(),
+        "\x1b]9;4;0;0\x07"
+    );
+}
+
+#[test]
+fn test_terminal_progress_flags_any_tab_awaiting_approval() {
+    use crate::tui::SessionStatus;
+
+    let mut tab_manager = TabManager::new();
+    assert_eq!(
+        format_terminal_progress(&tab_manager),
+        TerminalProgress::Hidden
+    );
+
+    // Running without a workflow view yet: progress unknown
+    tab_manager.active_mut().status = SessionStatus::Planning;
+    assert_eq!(
+        format_terminal_progress(&tab_manager),
+        TerminalProgress::Indeterminate
+    );
+
+    // A background tab waiting for approval takes priority
+    tab_manager.add_session().status = SessionStatus::AwaitingApproval;
+    tab_manager.switch_to_tab(0);
+    assert_eq!(
+        format_terminal_progress(&tab_manager),
+        TerminalProgress::Attention(100)
+    );
+}
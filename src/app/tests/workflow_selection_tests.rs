@@ -179,3 +179,86 @@ fn test_gemini_reviewer_has_same_command_as_gemini() {
     let gemini_reviewer = gemini_only.agents.get("gemini-reviewer").unwrap();
     assert_eq!(gemini.command, gemini_reviewer.command);
 }
+
+#[test]
+fn test_update_workflow_value_rewrites_and_validates() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("workflow.yaml");
+    std::fs::write(
+        &path,
+        "agents:\n  claude:\n    command: claude\nworkflow:\n  planning:\n    agent: claude\n  reviewing:\n    agents: [claude]\nimplementation:\n  enabled: false\n",
+    )
+    .unwrap();
+
+    let config = update_workflow_value(
+        &path,
+        &["workflow", "planning", "max_turns"],
+        serde_yaml::Value::from(25),
+    )
+    .unwrap();
+    assert_eq!(config.workflow.planning.max_turns, Some(25));
+
+    // Disabling the only reviewer fails validation and leaves the file untouched
+    let before = std::fs::read_to_string(&path).unwrap();
+    let result = update_workflow_value(
+        &path,
+        &["workflow", "reviewing", "disabled"],
+        serde_yaml::Value::Sequence(vec![serde_yaml::Value::from("claude")]),
+    );
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+    assert!(!path.with_extension("yaml.tmp").exists());
+}
+
+#[test]
+fn test_update_workflow_value_keeps_comments_and_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("workflow.yaml");
+    let original = "# Team workflow\nworkflow:\n  planning:\n    agent: claude # main planner\n    max_turns: 10\n  reviewing:\n    agents: [claude]\nagents:\n  claude:\n    command: claude\nimplementation:\n  enabled: false\n";
+    std::fs::write(&path, original).unwrap();
+
+    update_workflow_value(
+        &path,
+        &["workflow", "planning", "max_turns"],
+        serde_yaml::Value::from(25),
+    )
+    .unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        content,
+        original.replace("max_turns: 10", "max_turns: 25"),
+        "Only the edited line changes"
+    );
+
+    let config = update_workflow_value(
+        &path,
+        &["workflow", "reviewing", "disabled"],
+        serde_yaml::Value::Sequence(Vec::new()),
+    )
+    .unwrap();
+    assert!(config.workflow.reviewing.disabled.is_empty());
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("    agents: [claude]\n    disabled: []\nagents:"));
+    assert!(content.starts_with("# Team workflow\n"));
+    assert!(!path.with_extension("yaml.bak").exists());
+}
+
+#[test]
+fn test_update_workflow_value_backs_up_before_rewriting_flow_style() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("workflow.yaml");
+    let original = "# Flow style\nagents: {claude: {command: claude}}\nworkflow: {planning: {agent: claude}, reviewing: {agents: [claude]}}\nimplementation: {enabled: false}\n";
+    std::fs::write(&path, original).unwrap();
+
+    let config = update_workflow_value(
+        &path,
+        &["workflow", "planning", "max_turns"],
+        serde_yaml::Value::from(25),
+    )
+    .unwrap();
+    assert_eq!(config.workflow.planning.max_turns, Some(25));
+    assert_eq!(
+        std::fs::read_to_string(path.with_extension("yaml.bak")).unwrap(),
+        original
+    );
+}
@@ -0,0 +1,188 @@
+//! Warnings for live sessions sharing a working directory.
+//!
+//! Two sessions implementing in the same checkout can overwrite each other's
+//! edits. While sessions run, the TUI periodically lists the daemon registry and
+//! flags its sessions whose working directory overlaps (is, contains or lies
+//! inside) another live session's. The flagged tab shows a banner, and `W` moves
+//! the session into a git worktree: the worktree is recorded in the session's
+//! event log and daemon record, and implementation runs there.
+
+use crate::domain::types::WorktreeState;
+use crate::git_worktree::{create_session_worktree, WorktreeSetupResult};
+use crate::planning_paths;
+use crate::session_daemon::{LivenessState, RpcClient, SessionRecord};
+use crate::tui::{DirConflict, Event, Session, TabManager, WorkflowUpdate};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// How often the daemon registry is checked for overlapping sessions.
+pub const CONFLICT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Conflicts per workflow session ID among the running sessions in `records`.
+pub fn find_conflicts(records: &[SessionRecord]) -> HashMap<String, DirConflict> {
+    let live: Vec<(&SessionRecord, PathBuf)> = records
+        .iter()
+        .filter(|record| record.liveness == LivenessState::Running)
+        .map(|record| (record, canonical(&record.working_dir)))
+        .collect();
+
+    let mut conflicts = HashMap::new();
+    for (record, dir) in &live {
+        let others: Vec<String> = live
+            .iter()
+            .filter(|(other, other_dir)| {
+                other.workflow_session_id != record.workflow_session_id
+                    && (other_dir.starts_with(dir) || dir.starts_with(other_dir))
+            })
+            .map(|(other, _)| other.feature_name.clone())
+            .collect();
+        if !others.is_empty() {
+            let conflict = DirConflict {
+                working_dir: dir.clone(),
+                others,
+            };
+            conflicts.insert(record.workflow_session_id.clone(), conflict);
+        }
+    }
+    conflicts
+}
+
+/// Lists the daemon registry in the background and reports conflicts as an event.
+/// Without running sessions the warnings are cleared instead.
+pub fn check_conflicts(tab_manager: &mut TabManager, output_tx: &mpsc::UnboundedSender<Event>) {
+    if !tab_manager.sessions.iter().any(|session| session.running) {
+        apply_conflicts(tab_manager, HashMap::new());
+        return;
+    }
+    let tx = output_tx.clone();
+    tokio::spawn(async move {
+        let client = RpcClient::new(false).await;
+        if !client.is_connected() {
+            return;
+        }
+        if let Ok(records) = client.list().await {
+            // Receiver dropped means TUI is shutting down - safe to ignore
+            let _ = tx.send(Event::DirConflictsChecked {
+                conflicts: find_conflicts(&records),
+            });
+        }
+    });
+}
+
+/// Updates each tab's warning, noting new conflicts in its output panel.
+pub fn apply_conflicts(tab_manager: &mut TabManager, conflicts: HashMap<String, DirConflict>) {
+    for session in tab_manager.sessions_mut() {
+        let conflict = session
+            .workflow_view
+            .as_ref()
+            .and_then(|view| view.workflow_id())
+            .and_then(|id| conflicts.get(&id.to_string()))
+            .cloned();
+        if let Some(ref conflict) = conflict {
+            if session.dir_conflict.is_none() {
+                session.add_output(format!(
+                    "[planning] Warning: {} also works in {}; press W to move this session into a worktree",
+                    conflict.others.join(", "),
+                    conflict.working_dir.display()
+                ));
+            }
+        }
+        session.dir_conflict = conflict;
+    }
+}
+
+/// Moves a running session into a new git worktree of its working directory.
+///
+/// Only sessions that have not started implementation are moved: their edits so
+/// far are in the shared checkout and would not follow them.
+pub fn move_to_worktree(session: &mut Session) {
+    let Some(view) = session.workflow_view.as_ref() else {
+        return;
+    };
+    let Some(workflow_id) = view.workflow_id().map(|id| id.to_string()) else {
+        return;
+    };
+    if view.worktree_info().is_some() {
+        session.add_output("[planning] This session already has a worktree".to_string());
+        return;
+    }
+    if view.implementation_state().is_some() {
+        session.add_output(
+            "[planning] Implementation already started here; move the other session instead"
+                .to_string(),
+        );
+        return;
+    }
+    let Some(update_tx) = session.workflow_update_tx.clone() else {
+        session.add_output("[planning] Only a running session can be moved".to_string());
+        return;
+    };
+    let Some(working_dir) = session
+        .context
+        .as_ref()
+        .map(|ctx| ctx.effective_working_dir.clone())
+    else {
+        return;
+    };
+    let feature_name = view
+        .feature_name()
+        .map(|name| name.0.clone())
+        .unwrap_or_else(|| session.name.clone());
+    let session_dir = match planning_paths::session_dir(&workflow_id) {
+        Ok(dir) => dir,
+        Err(e) => {
+            session.add_output(format!("[planning] Could not move session: {}", e));
+            return;
+        }
+    };
+
+    let info = match create_session_worktree(
+        &working_dir,
+        &workflow_id,
+        &feature_name,
+        &session_dir,
+        None,
+    ) {
+        WorktreeSetupResult::Created(info) => info,
+        WorktreeSetupResult::NotAGitRepo => {
+            session.add_output(format!(
+                "[planning] {} is not a git repository, cannot create a worktree",
+                working_dir.display()
+            ));
+            return;
+        }
+        WorktreeSetupResult::Failed(err) => {
+            session.add_output(format!("[planning] Worktree setup failed: {}", err));
+            return;
+        }
+    };
+
+    let effective_dir = info.worktree_dir_for(&working_dir);
+    session.add_output(format!(
+        "[planning] Moved to worktree {} on branch {}",
+        info.worktree_path.display(),
+        info.branch_name
+    ));
+    let worktree = WorktreeState::new(
+        info.worktree_path,
+        info.branch_name,
+        info.source_branch,
+        info.original_dir,
+    );
+    // The workflow is running, so its listener is there to record the worktree
+    let _ = update_tx.send(WorkflowUpdate::AttachWorktree(worktree));
+    if let Some(ctx) = session.context.as_mut() {
+        ctx.effective_working_dir = effective_dir;
+    }
+    session.dir_conflict = None;
+}
+
+#[cfg(test)]
+#[path = "tests/dir_conflicts_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+use crate::domain::cqrs::events::WorkflowEvent;
+use crate::domain::types::TimestampUtc;
+use crate::domain::view::WorkflowView;
+use tempfile::tempdir;
+
+fn record(id: &str, feature: &str, working_dir: &Path, liveness: LivenessState) -> SessionRecord {
+    let mut record = SessionRecord::new(
+        id.to_string(),
+        feature.to_string(),
+        working_dir.to_path_buf(),
+        PathBuf::from("/tmp/session"),
+        "Planning".to_string(),
+        1,
+        "Planning".to_string(),
+        1,
+    );
+    record.liveness = liveness;
+    record
+}
+
+fn view_for(workflow_id: &str) -> WorkflowView {
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        workflow_id,
+        &WorkflowEvent::PlanningStarted {
+            started_at: TimestampUtc::default(),
+        },
+        1,
+    );
+    view
+}
+
+#[test]
+fn test_find_conflicts_flags_shared_and_nested_dirs() {
+    let repo = tempdir().unwrap();
+    let nested = repo.path().join("crates/api");
+    std::fs::create_dir_all(&nested).unwrap();
+    let elsewhere = tempdir().unwrap();
+
+    let records = vec![
+        record("a", "add-sso", repo.path(), LivenessState::Running),
+        record("b", "fix-login", &nested, LivenessState::Running),
+        record("c", "docs", elsewhere.path(), LivenessState::Running),
+    ];
+    let conflicts = find_conflicts(&records);
+
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(conflicts["a"].others, vec!["fix-login"]);
+    assert_eq!(conflicts["b"].others, vec!["add-sso"]);
+    assert_eq!(
+        conflicts["a"].working_dir,
+        repo.path().canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn test_find_conflicts_ignores_sessions_that_are_not_running() {
+    let repo = tempdir().unwrap();
+    let records = vec![
+        record("a", "add-sso", repo.path(), LivenessState::Running),
+        record("b", "fix-login", repo.path(), LivenessState::Stopped),
+        record("c", "docs", repo.path(), LivenessState::Unresponsive),
+    ];
+    assert!(find_conflicts(&records).is_empty());
+}
+
+#[test]
+fn test_apply_conflicts_warns_once_and_clears() {
+    let workflow_id = "5b6a4f0e-1c2d-4e3f-8a9b-0c1d2e3f4a5b";
+    let mut manager = TabManager::new();
+    manager.sessions[0].workflow_view = Some(view_for(workflow_id));
+    let conflict = DirConflict {
+        working_dir: PathBuf::from("/repo"),
+        others: vec!["fix-login".to_string()],
+    };
+    let conflicts = HashMap::from([(workflow_id.to_string(), conflict.clone())]);
+
+    apply_conflicts(&mut manager, conflicts.clone());
+    apply_conflicts(&mut manager, conflicts);
+    let session = &manager.sessions[0];
+    assert_eq!(session.dir_conflict, Some(conflict));
+    let warnings = session
+        .output_lines
+        .iter()
+        .filter(|line| line.contains("also works in /repo"))
+        .count();
+    assert_eq!(warnings, 1);
+
+    apply_conflicts(&mut manager, HashMap::new());
+    assert_eq!(manager.sessions[0].dir_conflict, None);
+}
+
+#[test]
+fn test_move_to_worktree_requires_a_running_workflow() {
+    let mut session = Session::new(0);
+    session.workflow_view = Some(view_for("5b6a4f0e-1c2d-4e3f-8a9b-0c1d2e3f4a5b"));
+    session.dir_conflict = Some(DirConflict {
+        working_dir: PathBuf::from("/repo"),
+        others: vec!["fix-login".to_string()],
+    });
+
+    move_to_worktree(&mut session);
+    assert!(session
+        .output_lines
+        .iter()
+        .any(|line| line.contains("Only a running session can be moved")));
+    assert!(session.dir_conflict.is_some());
+}
@@ -4,8 +4,9 @@ use anyhow::Result;
 use std::path::Path;
 use tokio::sync::mpsc;
 
-use super::input::handle_key_event;
 use super::input::mouse_input::{handle_mouse_click, handle_mouse_scroll};
+use super::input::worktree_input;
+use super::input::{flush_macro_prefix, handle_key_event};
 use super::input::{is_summary_panel_visible, is_todo_panel_visible};
 use super::session_events::handle_session_event;
 use super::snapshot_helper::create_and_save_snapshot;
@@ -27,6 +28,8 @@ pub async fn process_event(
 
     match event {
         Event::Key(key) => {
+            // Any keypress counts as activity for the idle-suspend timer
+            tab_manager.active_mut().awaiting_approval_since = None;
             should_quit = handle_key_event(
                 key,
                 tab_manager,
@@ -40,8 +43,12 @@ pub async fn process_event(
         }
         Event::Mouse(mouse) => {
             // Check browser overlay states first (on TabManager, not Session)
-            let browser_overlay_active =
-                tab_manager.session_browser.open || tab_manager.workflow_browser.open;
+            let browser_overlay_active = tab_manager.session_browser.open
+                || tab_manager.workflow_browser.open
+                || tab_manager.config_editor.open
+                || tab_manager.notifications.open
+                || tab_manager.approval_queue.open
+                || tab_manager.recent_tabs.open;
 
             let session = tab_manager.active_mut();
 
@@ -51,6 +58,7 @@ pub async fn process_event(
                 || session.plan_modal_open
                 || session.review_modal_open
                 || session.implementation_success_modal.is_some()
+                || session.completion_summary.is_some()
                 || session.approval_mode != ApprovalMode::None;
 
             if !modal_active {
@@ -73,6 +81,15 @@ pub async fn process_event(
         }
         Event::Tick => {
             handle_tick_event(tab_manager, output_tx, working_dir);
+            should_quit = flush_macro_prefix(
+                tab_manager,
+                terminal,
+                output_tx,
+                working_dir,
+                cli,
+                init_handle,
+            )
+            .await?;
         }
         Event::Resize => {
             handle_resize_event(tab_manager);
@@ -84,6 +101,11 @@ pub async fn process_event(
         Event::Output(line) => {
             handle_legacy_output(first_session_id, line, tab_manager);
         }
+        Event::WorktreeGitDone { session_id, step } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                worktree_input::handle_worktree_git_done(session, step, output_tx);
+            }
+        }
         Event::SnapshotRequest => {
             // Save snapshot for all active sessions (periodic auto-save)
             // Use each session's context base_working_dir if available
@@ -131,6 +153,7 @@ fn handle_tick_event(
         }
         session.advance_summary_spinners();
         session.advance_review_history_spinner();
+        session.autosave_draft();
     }
     if tab_manager.update_in_progress {
         tab_manager.update_spinner_frame = tab_manager.update_spinner_frame.wrapping_add(1);
@@ -192,13 +215,7 @@ fn handle_paste_event(text: String, tab_manager: &mut TabManager) {
 
 fn handle_legacy_output(session_id: usize, line: String, tab_manager: &mut TabManager) {
     if let Some(session) = tab_manager.session_by_id_mut(session_id) {
-        if line.contains("Cost: $") {
-            if let Some(cost_str) = line.split('$').nth(1) {
-                if let Ok(cost) = cost_str.trim().parse::<f64>() {
-                    session.total_cost += cost;
-                }
-            }
-        }
+        session.record_cost_output(&line);
         session.add_output(line);
     }
 }
@@ -0,0 +1,99 @@
+//! Redraw and tick pacing for the TUI main loop.
+//!
+//! The loop only calls `terminal.draw` when the last batch of events could have
+//! changed what is on screen: any input or workflow event, or a tick while a
+//! spinner is animating. Elapsed-time text and state changed outside events
+//! (completions, idle suspension) are picked up by a redraw at least every
+//! `MAX_FRAME_INTERVAL`. With nothing animating the tick rate drops from
+//! `ACTIVE_TICK` to `IDLE_TICK`, so an idle or waiting TUI wakes up rarely.
+
+use crate::tui::{Event, SessionStatus, TabManager};
+use std::time::{Duration, Instant};
+
+/// Tick rate while spinners animate or a macro prefix waits for its timeout.
+pub const ACTIVE_TICK: Duration = Duration::from_millis(100);
+
+/// Tick rate while the TUI is idle or awaiting input.
+pub const IDLE_TICK: Duration = Duration::from_secs(1);
+
+/// Longest time between frames, so clocks and elapsed times stay current.
+/// Slightly under `IDLE_TICK`, so every idle tick draws.
+pub const MAX_FRAME_INTERVAL: Duration = Duration::from_millis(900);
+
+/// Whether anything on screen animates on every tick.
+pub fn is_animating(tab_manager: &TabManager) -> bool {
+    tab_manager.update_in_progress
+        || tab_manager.session_browser.open
+        || tab_manager.macros.has_pending_prefix()
+        || tab_manager.sessions.iter().any(|session| {
+            session.running
+                || matches!(
+                    session.status,
+                    SessionStatus::Planning | SessionStatus::GeneratingSummary
+                )
+                || session.has_generating_summary()
+                || session.has_running_reviewer()
+        })
+}
+
+/// The tick rate for the TUI's current state.
+pub fn tick_rate(tab_manager: &TabManager) -> Duration {
+    if is_animating(tab_manager) {
+        ACTIVE_TICK
+    } else {
+        IDLE_TICK
+    }
+}
+
+/// Tracks whether the screen needs redrawing.
+#[derive(Debug)]
+pub struct FramePacer {
+    dirty: bool,
+    last_frame: Instant,
+}
+
+impl FramePacer {
+    /// Starts dirty, so the first loop iteration draws.
+    pub fn new() -> Self {
+        Self {
+            dirty: true,
+            last_frame: Instant::now(),
+        }
+    }
+
+    /// Marks the screen dirty if the event can change what is drawn.
+    /// `animating` is whether a tick advances a spinner.
+    pub fn observe(&mut self, event: &Event, animating: bool) {
+        let changes_view = match event {
+            Event::Tick => animating,
+            Event::SnapshotRequest => false,
+            _ => true,
+        };
+        self.dirty |= changes_view;
+    }
+
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Whether the next loop iteration should draw a frame.
+    pub fn should_draw(&self, now: Instant) -> bool {
+        self.dirty || now.duration_since(self.last_frame) >= MAX_FRAME_INTERVAL
+    }
+
+    /// Records that a frame was drawn.
+    pub fn drawn(&mut self, now: Instant) {
+        self.dirty = false;
+        self.last_frame = now;
+    }
+}
+
+impl Default for FramePacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/frame_pacing_tests.rs"]
+mod tests;
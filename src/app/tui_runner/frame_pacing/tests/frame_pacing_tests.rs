@@ -0,0 +1,41 @@
+use super::*;
+use crate::tui::SummaryState;
+
+#[test]
+fn test_pacer_draws_only_after_visual_changes_or_max_interval() {
+    let start = Instant::now();
+    let mut pacer = FramePacer::new();
+    assert!(pacer.should_draw(start));
+    pacer.drawn(start);
+    assert!(!pacer.should_draw(start));
+
+    pacer.observe(&Event::Tick, false);
+    pacer.observe(&Event::SnapshotRequest, false);
+    assert!(!pacer.should_draw(start));
+    assert!(pacer.should_draw(start + MAX_FRAME_INTERVAL));
+
+    pacer.observe(&Event::Tick, true);
+    assert!(pacer.should_draw(start));
+    pacer.drawn(start);
+
+    pacer.observe(&Event::Resize, false);
+    assert!(pacer.should_draw(start));
+}
+
+#[test]
+fn test_tick_rate_drops_when_nothing_animates() {
+    let mut tab_manager = TabManager::new();
+    assert!(!is_animating(&tab_manager));
+    assert_eq!(tick_rate(&tab_manager), IDLE_TICK);
+
+    tab_manager.active_mut().status = SessionStatus::Planning;
+    assert_eq!(tick_rate(&tab_manager), ACTIVE_TICK);
+
+    tab_manager.active_mut().status = SessionStatus::AwaitingApproval;
+    assert_eq!(tick_rate(&tab_manager), IDLE_TICK);
+
+    let session = tab_manager.active_mut();
+    session.add_run_tab("Planning".to_string());
+    session.run_tabs[0].summary_state = SummaryState::Generating;
+    assert_eq!(tick_rate(&tab_manager), ACTIVE_TICK);
+}
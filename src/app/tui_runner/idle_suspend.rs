@@ -0,0 +1,150 @@
+//! Automatic suspension of sessions left idle at an approval prompt.
+//!
+//! A session that sits in `AwaitingApproval` longer than `--idle-suspend-minutes`
+//! is snapshotted and sent `WorkflowCommand::Stop`, which marks it Stopped in the
+//! daemon. Once the workflow has stopped, its tab is freed and the session is
+//! remembered so Ctrl+R can resume it from the snapshot.
+
+use super::input::session_browser_input::resume_snapshot_in_new_tab;
+use super::snapshot_helper;
+use crate::tui::{
+    Event, InputMode, Session, SessionStatus, SuspendedSession, TabManager, WorkflowCommand,
+};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Converts the `--idle-suspend-minutes` flag into a timeout (0 disables suspension).
+pub fn idle_timeout(minutes: u64) -> Option<Duration> {
+    (minutes > 0).then(|| Duration::from_secs(minutes * 60))
+}
+
+/// Starts, clears or checks the session's approval idle timer.
+///
+/// Returns true when the session has been awaiting approval for at least `timeout`.
+pub fn idle_timer_expired(session: &mut Session, now: Instant, timeout: Duration) -> bool {
+    if session.status != SessionStatus::AwaitingApproval || session.idle_suspended {
+        session.awaiting_approval_since = None;
+        return false;
+    }
+    let since = *session.awaiting_approval_since.get_or_insert(now);
+    now.saturating_duration_since(since) >= timeout
+}
+
+/// Snapshots and stops every running session that has idled past `timeout`.
+pub fn suspend_idle_sessions(
+    tab_manager: &mut TabManager,
+    timeout: Option<Duration>,
+    working_dir: &Path,
+) {
+    let Some(timeout) = timeout else {
+        return;
+    };
+    let now = Instant::now();
+
+    for session in tab_manager.sessions_mut() {
+        if session.workflow_handle.is_none() || !idle_timer_expired(session, now, timeout) {
+            continue;
+        }
+        let (Some(view), Some(tx)) = (&session.workflow_view, session.workflow_control_tx.clone())
+        else {
+            continue;
+        };
+        let session_working_dir = session
+            .context
+            .as_ref()
+            .map(|ctx| ctx.base_working_dir.clone())
+            .unwrap_or_else(|| working_dir.to_path_buf());
+        if let Err(e) =
+            snapshot_helper::create_and_save_snapshot(session, view, &session_working_dir)
+        {
+            // Without a snapshot the session couldn't be resumed; leave it waiting
+            session.add_output(format!("[planning] Idle suspend skipped: {}", e));
+            session.awaiting_approval_since = None;
+            continue;
+        }
+        // The workflow marks the session Stopped in the daemon when it handles Stop
+        if tx.try_send(WorkflowCommand::Stop).is_ok() {
+            session.idle_suspended = true;
+            session.add_output(format!(
+                "[planning] Suspending after {} min awaiting approval...",
+                timeout.as_secs() / 60
+            ));
+        }
+    }
+}
+
+/// Frees the tabs of idle-suspended sessions whose workflows have stopped.
+pub fn close_suspended_tabs(tab_manager: &mut TabManager, working_dir: &Path) {
+    while let Some(index) = tab_manager.sessions.iter().position(|s| {
+        s.idle_suspended && s.status == SessionStatus::Stopped && s.workflow_handle.is_none()
+    }) {
+        let session = &tab_manager.sessions[index];
+        let suspended = session.workflow_view.as_ref().and_then(|view| {
+            Some(SuspendedSession {
+                feature_name: view.feature_name()?.0.clone(),
+                session_id: view.workflow_id()?.to_string(),
+                working_dir: session
+                    .context
+                    .as_ref()
+                    .map(|ctx| ctx.base_working_dir.clone())
+                    .unwrap_or_else(|| working_dir.to_path_buf()),
+            })
+        });
+
+        // close_tab keeps the last tab, so open a fresh one to take its place
+        if tab_manager.len() == 1 {
+            tab_manager.add_session().input_mode = InputMode::NamingTab;
+        }
+        tab_manager.close_tab(index);
+
+        if let Some(suspended) = suspended {
+            tab_manager.command_notice = Some(format!(
+                "'{}' was suspended after idling for approval. Press Ctrl+R to resume.",
+                suspended.feature_name
+            ));
+            tab_manager.suspended_sessions.push(suspended);
+        }
+    }
+}
+
+/// Resumes the most recently suspended session in a new tab (Ctrl+R).
+pub fn resume_last_suspended(
+    tab_manager: &mut TabManager,
+    global_working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    let Some(suspended) = tab_manager.suspended_sessions.pop() else {
+        return;
+    };
+    tab_manager.command_notice = None;
+    let previous_tab = tab_manager.active_tab;
+    let previous_is_empty = {
+        let session = tab_manager.active();
+        session.name.is_empty() && session.output_lines.is_empty()
+    };
+
+    match resume_snapshot_in_new_tab(
+        tab_manager,
+        &suspended.session_id,
+        &suspended.feature_name,
+        &suspended.working_dir,
+        suspended.working_dir == global_working_dir,
+        output_tx,
+    ) {
+        // Drop the blank tab that stood in for the suspended one
+        Ok(()) if previous_is_empty => tab_manager.close_tab(previous_tab),
+        Ok(()) => {}
+        Err(err) => {
+            let message = format!("Failed to resume '{}': {}", suspended.feature_name, err);
+            tab_manager
+                .active_mut()
+                .add_output(format!("[planning] {}", message));
+            tab_manager.command_error = Some(message);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/idle_suspend_tests.rs"]
+mod tests;
@@ -1,14 +1,18 @@
 //! Approval-related input handling for the TUI.
 
+mod session_commands;
+
 use crate::app::workflow::{run_workflow_with_config, WorkflowRunConfig};
+use crate::domain::review::TriageDecision;
 use crate::tui::file_index::FileIndex;
 use crate::tui::mention::update_mention_state;
 use crate::tui::{
-    ApprovalContext, ApprovalMode, Event, FeedbackTarget, Session, SessionStatus,
-    UserApprovalResponse, WorkflowCommand,
+    ApprovalContext, ApprovalMode, DraftKind, Event, FeedbackTarget, Session, SessionStatus,
+    UserApprovalResponse, WorkflowCommand, WorkflowUpdate,
 };
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
+use session_commands::{run_session_command, save_notes, submit_objective};
 use std::path::Path;
 use tokio::sync::mpsc;
 
@@ -63,25 +67,41 @@ pub async fn handle_plan_approval_input(
 ) -> Result<bool> {
     match key.code {
         KeyCode::Char('a') | KeyCode::Char('A') => {
-            if let Some(tx) = session.approval_tx.take() {
+            let at_gate = session.at_approval_gate();
+            let response = session.take_accept_response(false);
+            if let Some(tx) = session.take_approval_tx(false) {
                 // Channel send may fail if workflow already completed - safe to ignore
-                let _ = tx.send(UserApprovalResponse::Accept).await;
+                let _ = tx.send(response).await;
             }
             session.approval_mode = ApprovalMode::None;
-            session.status = SessionStatus::Complete;
+            session.status = if at_gate {
+                SessionStatus::Planning
+            } else {
+                SessionStatus::Complete
+            };
         }
         KeyCode::Char('i') | KeyCode::Char('I') => {
-            if let Some(tx) = session.approval_tx.take() {
+            let at_gate = session.at_approval_gate();
+            let response = session.take_accept_response(true);
+            if let Some(tx) = session.take_approval_tx(true) {
                 // Channel send may fail if workflow already completed - safe to ignore
-                let _ = tx.send(UserApprovalResponse::Implement).await;
+                let _ = tx.send(response).await;
             }
             session.approval_mode = ApprovalMode::None;
             session.status = SessionStatus::Planning;
-            session.add_output("[planning] Starting implementation...".to_string());
+            if !at_gate {
+                session.add_output("[planning] Starting implementation...".to_string());
+            }
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            session.start_conditions_input();
         }
         KeyCode::Char('d') | KeyCode::Char('D') => {
             session.start_feedback_input();
         }
+        KeyCode::Char('p') | KeyCode::Char('P') if session.can_appeal_rejection() => {
+            session.start_feedback_input_for(FeedbackTarget::Appeal);
+        }
         KeyCode::Char('j') | KeyCode::Down => {
             let max_scroll = compute_plan_summary_max_scroll(&session.plan_summary);
             session.scroll_summary_down(max_scroll);
@@ -97,6 +117,31 @@ pub async fn handle_plan_approval_input(
     Ok(false)
 }
 
+/// Handles keys for the feedback triage overlay; Enter sends the decisions.
+pub async fn handle_feedback_triage_input(key: crossterm::event::KeyEvent, session: &mut Session) {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => session.feedback_triage_select_next(),
+        KeyCode::Char('k') | KeyCode::Up => session.feedback_triage_select_prev(),
+        KeyCode::Char('a') => session.set_feedback_triage_decision(TriageDecision::Accept),
+        KeyCode::Char('p') => session.set_feedback_triage_decision(TriageDecision::Park),
+        KeyCode::Char('d') => session.set_feedback_triage_decision(TriageDecision::Dismiss),
+        KeyCode::Char('A') => session.set_all_feedback_triage_decisions(TriageDecision::Accept),
+        KeyCode::Char('P') => session.set_all_feedback_triage_decisions(TriageDecision::Park),
+        KeyCode::Char('D') => session.set_all_feedback_triage_decisions(TriageDecision::Dismiss),
+        KeyCode::Enter => {
+            if let Some(decisions) = session.submit_feedback_triage() {
+                if let Some(tx) = session.approval_tx.clone() {
+                    // Channel send may fail if workflow already completed - safe to ignore
+                    let _ = tx
+                        .send(UserApprovalResponse::FeedbackTriaged(decisions))
+                        .await;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 pub async fn handle_review_decision_input(
     key: crossterm::event::KeyEvent,
     session: &mut Session,
@@ -222,6 +267,9 @@ pub async fn handle_all_reviewers_failed_input(
 
                     let (new_control_tx, new_control_rx) = mpsc::channel::<WorkflowCommand>(1);
                     session.workflow_control_tx = Some(new_control_tx);
+                    let (new_update_tx, new_update_rx) =
+                        mpsc::unbounded_channel::<WorkflowUpdate>();
+                    session.workflow_update_tx = Some(new_update_tx);
 
                     // Increment run_id
                     session.current_run_id += 1;
@@ -240,7 +288,7 @@ pub async fn handle_all_reviewers_failed_input(
                         let sid = session.id;
                         async move {
                             let input = crate::domain::input::WorkflowInput::Resume(
-                                crate::domain::input::ResumeWorkflowInput { workflow_id },
+                                crate::domain::input::ResumeWorkflowInput::new(workflow_id),
                             );
                             run_workflow_with_config(
                                 input,
@@ -250,6 +298,7 @@ pub async fn handle_all_reviewers_failed_input(
                                     output_tx: tx,
                                     approval_rx: new_approval_rx,
                                     control_rx: new_control_rx,
+                                    update_rx: new_update_rx,
                                     session_id: sid,
                                     run_id,
                                     no_daemon: false,
@@ -357,6 +406,9 @@ pub async fn handle_workflow_failure_input(
 
                     let (new_control_tx, new_control_rx) = mpsc::channel::<WorkflowCommand>(1);
                     session.workflow_control_tx = Some(new_control_tx);
+                    let (new_update_tx, new_update_rx) =
+                        mpsc::unbounded_channel::<WorkflowUpdate>();
+                    session.workflow_update_tx = Some(new_update_tx);
 
                     // Increment run_id
                     session.current_run_id += 1;
@@ -375,7 +427,7 @@ pub async fn handle_workflow_failure_input(
                         let sid = session.id;
                         async move {
                             let input = crate::domain::input::WorkflowInput::Resume(
-                                crate::domain::input::ResumeWorkflowInput { workflow_id },
+                                crate::domain::input::ResumeWorkflowInput::new(workflow_id),
                             );
                             run_workflow_with_config(
                                 input,
@@ -385,6 +437,7 @@ pub async fn handle_workflow_failure_input(
                                     output_tx: tx,
                                     approval_rx: new_approval_rx,
                                     control_rx: new_control_rx,
+                                    update_rx: new_update_rx,
                                     session_id: sid,
                                     run_id,
                                     no_daemon: false,
@@ -473,6 +526,9 @@ pub async fn handle_max_iterations_input(
         KeyCode::Char('d') | KeyCode::Char('D') => {
             session.start_feedback_input();
         }
+        KeyCode::Char('p') | KeyCode::Char('P') if session.can_appeal_rejection() => {
+            session.start_feedback_input_for(FeedbackTarget::Appeal);
+        }
         KeyCode::Char('a') | KeyCode::Char('A') => {
             if let Some(tx) = session.approval_tx.clone() {
                 // Channel send may fail if workflow already completed - safe to ignore
@@ -506,14 +562,18 @@ pub async fn handle_user_override_input(
 ) -> Result<bool> {
     match key.code {
         KeyCode::Char('i') | KeyCode::Char('I') => {
-            if let Some(tx) = session.approval_tx.take() {
+            let response = session.take_accept_response(true);
+            if let Some(tx) = session.take_approval_tx(true) {
                 // Channel send may fail if workflow already completed - safe to ignore
-                let _ = tx.send(UserApprovalResponse::Implement).await;
+                let _ = tx.send(response).await;
             }
             session.approval_mode = ApprovalMode::None;
             session.status = SessionStatus::Planning;
             session.add_output("[planning] Starting implementation...".to_string());
         }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            session.start_conditions_input();
+        }
         KeyCode::Char('d') | KeyCode::Char('D') => {
             session.start_feedback_input();
         }
@@ -536,6 +596,8 @@ pub async fn handle_entering_feedback_input(
     key: crossterm::event::KeyEvent,
     session: &mut Session,
     file_index: &FileIndex,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
 ) -> Result<bool> {
     // Handle @-mention dropdown navigation when active
     if session.feedback_mention_state.active && !session.feedback_mention_state.matches.is_empty() {
@@ -586,6 +648,43 @@ pub async fn handle_entering_feedback_input(
             session.insert_feedback_newline();
             session.last_key_was_backslash = false;
         }
+        KeyCode::Enter if session.feedback_target == FeedbackTarget::Command => {
+            let command = session.get_submit_text_feedback();
+            close_command_prompt(session);
+            run_session_command(command.trim(), session, working_dir, output_tx);
+        }
+        KeyCode::Esc if session.feedback_target == FeedbackTarget::Command => {
+            close_command_prompt(session);
+        }
+        KeyCode::Enter if session.feedback_target == FeedbackTarget::Objective => {
+            let objective = session.get_submit_text_feedback();
+            close_command_prompt(session);
+            submit_objective(objective.trim(), session).await;
+        }
+        KeyCode::Enter if session.feedback_target == FeedbackTarget::Notes => {
+            let notes = session.get_submit_text_feedback();
+            close_command_prompt(session);
+            save_notes(session, &notes);
+        }
+        KeyCode::Esc
+            if matches!(
+                session.feedback_target,
+                FeedbackTarget::Objective | FeedbackTarget::Notes
+            ) =>
+        {
+            close_command_prompt(session);
+        }
+        KeyCode::Enter if session.feedback_target == FeedbackTarget::ApprovalConditions => {
+            // Conditions wait for the accept choice; submitting empty text clears them
+            let conditions = session.get_submit_text_feedback();
+            session.approval_conditions = Some(conditions).filter(|c| !c.trim().is_empty());
+            session.user_feedback.clear();
+            session.cursor_position = 0;
+            session.feedback_scroll = 0;
+            session.clear_feedback_pastes();
+            session.approval_mode = ApprovalMode::AwaitingChoice;
+            session.feedback_target = FeedbackTarget::default();
+        }
         KeyCode::Enter => {
             let has_content =
                 !session.user_feedback.trim().is_empty() || session.has_feedback_pastes();
@@ -595,10 +694,11 @@ pub async fn handle_entering_feedback_input(
                 match session.feedback_target {
                     FeedbackTarget::ApprovalDecline => {
                         // Existing behavior: decline with feedback via approval channel
-                        if let Some(tx) = session.approval_tx.take() {
+                        if let Some(tx) = session.take_approval_tx(false) {
                             // Channel send may fail if workflow already completed - safe to ignore
                             let _ = tx.send(UserApprovalResponse::Decline(feedback)).await;
                         }
+                        session.discard_draft(DraftKind::Decline);
                     }
                     FeedbackTarget::WorkflowInterrupt => {
                         // New behavior: send interrupt command via control channel
@@ -606,7 +706,22 @@ pub async fn handle_entering_feedback_input(
                             // Channel send may fail if workflow already stopped - safe to ignore
                             let _ = tx.send(WorkflowCommand::Interrupt { feedback }).await;
                         }
+                        session.discard_draft(DraftKind::Interrupt);
+                    }
+                    FeedbackTarget::Appeal => {
+                        // The prompt returns if a reviewer sustains its rejection
+                        if let Some(tx) = session.approval_tx.clone() {
+                            // Channel send may fail if workflow already completed - safe to ignore
+                            let _ = tx.send(UserApprovalResponse::Appeal(feedback)).await;
+                        }
+                        session.add_output(
+                            "[planning] Appeal sent to the rejecting reviewer(s)".to_string(),
+                        );
                     }
+                    FeedbackTarget::ApprovalConditions
+                    | FeedbackTarget::Command
+                    | FeedbackTarget::Objective
+                    | FeedbackTarget::Notes => {}
                 }
 
                 session.user_feedback.clear();
@@ -619,6 +734,8 @@ pub async fn handle_entering_feedback_input(
             }
         }
         KeyCode::Esc => {
+            // Keep the text as a draft; re-entering the feedback restores it
+            session.flush_draft();
             session.user_feedback.clear();
             session.cursor_position = 0;
             session.feedback_scroll = 0;
@@ -666,6 +783,15 @@ pub async fn handle_entering_feedback_input(
     Ok(false)
 }
 
+fn close_command_prompt(session: &mut Session) {
+    session.user_feedback.clear();
+    session.cursor_position = 0;
+    session.feedback_scroll = 0;
+    session.clear_feedback_pastes();
+    session.approval_mode = ApprovalMode::None;
+    session.feedback_target = FeedbackTarget::default();
+}
+
 /// Handle input when entering the number of additional iterations.
 pub async fn handle_entering_iterations_input(
     key: crossterm::event::KeyEvent,
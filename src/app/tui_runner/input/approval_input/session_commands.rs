@@ -0,0 +1,216 @@
+//! Slash commands typed at a running session's command prompt.
+
+use crate::app::tui_runner::input::compute_plan_modal_max_scroll;
+use crate::app::tui_runner::input::user_command_input::{run_user_command, user_command_names};
+use crate::app::tui_runner::session_names::request_rename;
+use crate::app::tui_runner::slash_commands::{
+    apply_reviewers_command, parse_slash_command, SlashCommand,
+};
+use crate::phases::feedback_items::{load_session_notes, save_session_notes, SESSION_NOTES_FILE};
+use crate::planning_paths;
+use crate::tui::{
+    copy_to_clipboard, Event, FeedbackTarget, Session, WorkflowCommand, WorkflowUpdate,
+};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+/// Runs a slash command typed at a running session's command prompt. Commands
+/// not built in are looked up in the workflow config's `slash_commands`.
+pub(super) fn run_session_command(
+    command: &str,
+    session: &mut Session,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    match parse_slash_command(command) {
+        Some((SlashCommand::Tail(target), _)) => {
+            if session.open_tail_modal(target, working_dir) {
+                // Start at the end, like `tail -f`
+                session.plan_modal_scroll =
+                    compute_plan_modal_max_scroll(&session.plan_modal_content);
+            } else {
+                session.add_output("[command] /tail: this session has no plan yet".to_string());
+            }
+        }
+        Some((SlashCommand::Rename(name), _)) => request_rename(session, &name, working_dir),
+        Some((SlashCommand::Reviewers(preset), _)) => request_reviewer_preset(session, preset),
+        Some((SlashCommand::Sequential(sequential), _)) => request_review_mode(session, sequential),
+        Some((SlashCommand::EditObjective, _)) => start_objective_edit(session),
+        Some((SlashCommand::ExportTab, _)) => export_run_tab(session),
+        Some((SlashCommand::Undo, _)) => request_undo(session),
+        Some((SlashCommand::Notes, _)) => start_notes_edit(session),
+        _ if run_user_command(session, command, output_tx) => {}
+        _ => {
+            let mut available = vec![
+                "/tail plan".to_string(),
+                "/tail feedback".to_string(),
+                "/rename <name>".to_string(),
+                "/reviewers [preset]".to_string(),
+                "/review-mode sequential|parallel".to_string(),
+                "/edit-objective".to_string(),
+                "/export-tab".to_string(),
+                "/undo".to_string(),
+                "/notes".to_string(),
+            ];
+            available.extend(user_command_names(session));
+            session.add_output(format!(
+                "[command] Unknown command: {} (available: {})",
+                command,
+                available.join(", ")
+            ));
+        }
+    }
+}
+
+/// Asks the workflow to take back the final plan decision with `/undo`. The
+/// workflow honors it only during the decision's grace window.
+fn request_undo(session: &mut Session) {
+    match &session.workflow_update_tx {
+        Some(tx) if tx.send(WorkflowUpdate::UndoDecision).is_ok() => {
+            session.add_output(
+                "[command] Undo requested; it applies only within the grace window after the plan decision"
+                    .to_string(),
+            );
+        }
+        _ => session.add_output("[command] /undo: the workflow is not running".to_string()),
+    }
+}
+
+/// Writes the focused run tab to the session directory with `/export-tab` and
+/// copies the file's path to the clipboard.
+fn export_run_tab(session: &mut Session) {
+    match session.export_active_run_tab() {
+        Ok(path) => {
+            let path = path.display().to_string();
+            copy_to_clipboard(&path);
+            session.add_output(format!(
+                "[command] Run tab exported to {} (path copied to the clipboard)",
+                path
+            ));
+        }
+        Err(e) => session.add_output(format!("[command] /export-tab: {:#}", e)),
+    }
+}
+
+/// Opens the current objective for editing with `/edit-objective`.
+fn start_objective_edit(session: &mut Session) {
+    let Some(view) = session.workflow_view.as_ref() else {
+        session.add_output("[command] /edit-objective: no workflow is loaded".to_string());
+        return;
+    };
+    if view.implementation_state().is_some() {
+        session.add_output(
+            "[command] /edit-objective: implementation has started; the objective can only change while planning"
+                .to_string(),
+        );
+        return;
+    }
+    let objective = view.objective().map(|o| o.0.clone()).unwrap_or_default();
+    session.start_feedback_input_for(FeedbackTarget::Objective);
+    session.cursor_position = objective.len();
+    session.user_feedback = objective;
+}
+
+/// The session directory `/notes` are kept in.
+fn notes_dir(session: &Session) -> Option<PathBuf> {
+    let workflow_id = session.workflow_view.as_ref()?.workflow_id()?;
+    planning_paths::session_dir(&workflow_id.to_string()).ok()
+}
+
+/// Opens the session's notes for editing with `/notes`.
+fn start_notes_edit(session: &mut Session) {
+    let Some(dir) = notes_dir(session) else {
+        session.add_output("[command] /notes: no workflow is loaded".to_string());
+        return;
+    };
+    let notes = load_session_notes(&dir).unwrap_or_default();
+    session.start_feedback_input_for(FeedbackTarget::Notes);
+    session.cursor_position = notes.len();
+    session.user_feedback = notes;
+}
+
+/// Writes the edited notes to the session directory; blank notes remove them.
+pub(super) fn save_notes(session: &mut Session, notes: &str) {
+    let Some(dir) = notes_dir(session) else {
+        session.add_output("[command] /notes: no workflow is loaded".to_string());
+        return;
+    };
+    let message = match save_session_notes(&dir, notes) {
+        Ok(()) if notes.trim().is_empty() => "[command] Notes cleared".to_string(),
+        Ok(()) => format!("[command] Notes saved to {}", SESSION_NOTES_FILE),
+        Err(e) => format!("[command] /notes: {:#}", e),
+    };
+    session.add_output(message);
+}
+
+/// Interrupts the workflow so it restarts planning from the edited objective.
+/// The interrupt lands at the next phase boundary, and the restart applies the
+/// pending objective before resuming.
+pub(super) async fn submit_objective(objective: &str, session: &mut Session) {
+    let unchanged = session
+        .workflow_view
+        .as_ref()
+        .and_then(|view| view.objective())
+        .is_some_and(|current| current.0.trim() == objective);
+    if objective.is_empty() || unchanged {
+        session.add_output("[command] /edit-objective: objective unchanged".to_string());
+        return;
+    }
+    let Some(tx) = session.workflow_control_tx.clone() else {
+        session.add_output("[command] /edit-objective: the workflow is not running".to_string());
+        return;
+    };
+    session.pending_objective = Some(objective.to_string());
+    let feedback = "The objective was edited with /edit-objective".to_string();
+    if tx
+        .send(WorkflowCommand::Interrupt { feedback })
+        .await
+        .is_err()
+    {
+        session.pending_objective = None;
+        session.add_output("[command] /edit-objective: the workflow is not running".to_string());
+        return;
+    }
+    session.add_output(
+        "[command] Objective updated; planning restarts once the current step stops".to_string(),
+    );
+}
+
+/// Handles `/reviewers [preset]` at a running session's command prompt. The
+/// session's config keeps the preset for restarts, and the workflow switches its
+/// panel before the next review round.
+fn request_reviewer_preset(session: &mut Session, preset: Option<String>) {
+    let Some(ctx) = session.context.as_mut() else {
+        session.add_output("[command] /reviewers: no active workflow config".to_string());
+        return;
+    };
+    let message = match apply_reviewers_command(&mut ctx.workflow_config, preset.as_deref()) {
+        Ok(message) => message,
+        Err(e) => {
+            session.add_output(format!("[command] /reviewers: {}", e));
+            return;
+        }
+    };
+    if let (Some(preset), Some(tx)) = (preset, &session.workflow_update_tx) {
+        // A stopped workflow picks the preset up from the config when it restarts
+        let _ = tx.send(WorkflowUpdate::ReviewerPreset(preset));
+    }
+    session.add_output(format!("[command] {}", message));
+}
+
+/// Handles `/review-mode` at a running session's command prompt. The session's
+/// config keeps the mode for restarts, and the workflow switches before the next
+/// review round.
+fn request_review_mode(session: &mut Session, sequential: bool) {
+    if let Some(ctx) = session.context.as_mut() {
+        ctx.workflow_config.workflow.reviewing.sequential = sequential;
+    }
+    if let Some(tx) = &session.workflow_update_tx {
+        let _ = tx.send(WorkflowUpdate::ReviewMode(sequential));
+    }
+    let mode = if sequential { "sequential" } else { "parallel" };
+    session.add_output(format!(
+        "[command] Review mode: {} (effective at next review round)",
+        mode
+    ));
+}
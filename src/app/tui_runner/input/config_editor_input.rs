@@ -0,0 +1,71 @@
+//! Config editor input handling.
+//!
+//! This module handles keyboard input for the config editor overlay,
+//! including tree navigation, value editing and reviewer toggles.
+
+use crate::tui::config_editor::ConfigChange;
+use crate::tui::{Event, TabManager};
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tokio::sync::mpsc;
+
+/// Handle input when the config editor overlay is open.
+pub async fn handle_config_editor_input(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+    _output_tx: &mpsc::UnboundedSender<Event>,
+) -> Result<bool> {
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Ok(true);
+    }
+
+    let editor = &mut tab_manager.config_editor;
+    if let Some(buffer) = editor.editing.as_mut() {
+        match key.code {
+            KeyCode::Enter => {
+                let change = editor.commit_edit();
+                apply_config_change(tab_manager, change);
+            }
+            KeyCode::Esc => editor.cancel_edit(),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Char(c) => buffer.push(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => editor.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => editor.select_prev(),
+        KeyCode::Char('l') | KeyCode::Right => editor.expand_selected(),
+        KeyCode::Char('h') | KeyCode::Left => editor.collapse_selected(),
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            let change = editor.activate_selected();
+            apply_config_change(tab_manager, change);
+        }
+        KeyCode::Esc | KeyCode::Char('q') => editor.close(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Applies a saved edit to the running TUI.
+fn apply_config_change(tab_manager: &mut TabManager, change: Option<ConfigChange>) {
+    match change {
+        Some(ConfigChange::Workflow(config)) => {
+            // Update active session's workflow config
+            let session = tab_manager.active_mut();
+            if let Some(ref mut ctx) = session.context {
+                ctx.workflow_config = *config;
+            }
+            tab_manager.cost_estimate = None;
+        }
+        Some(ConfigChange::MaxIterations(n)) => {
+            tab_manager.max_iterations_override = Some(n);
+            tab_manager.cost_estimate = None;
+        }
+        None => {}
+    }
+}
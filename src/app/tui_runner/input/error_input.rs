@@ -0,0 +1,129 @@
+//! Input handling for the error overlay and its suggested actions.
+
+use super::super::workflow_lifecycle::retry_failed_workflow;
+use super::compute_plan_modal_max_scroll;
+use crate::app::doctor;
+use crate::tui::ui::error_overlay_lines;
+use crate::tui::ui::util::compute_wrapped_line_count;
+use crate::tui::{
+    ErrorReport, Event, Session, SessionEventSender, SuggestedAction, TabManager, TailTarget,
+};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::Path;
+use tokio::sync::mpsc;
+
+/// Compute the max scroll for the error overlay based on content and terminal size.
+fn compute_error_overlay_max_scroll(report: &ErrorReport) -> usize {
+    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+
+    // Match draw_error_overlay: 70% width, max 90
+    let popup_width = (term_width as f32 * 0.7).min(90.0) as u16;
+    let inner_width = popup_width.saturating_sub(2);
+    let total_content_lines = compute_wrapped_line_count(&error_overlay_lines(report), inner_width);
+
+    // Popup height calculation (matching draw_error_overlay)
+    let max_popup_height = (term_height as f32 * 0.8) as u16;
+    let min_popup_height = 8u16;
+    let ideal_popup_height = (total_content_lines as u16).saturating_add(3);
+    let popup_height = ideal_popup_height.clamp(min_popup_height, max_popup_height);
+
+    // Visible height = popup_height - borders (2) - instructions (1)
+    let visible_height = popup_height.saturating_sub(3) as usize;
+
+    total_content_lines.saturating_sub(visible_height)
+}
+
+/// Handles keys while the active session shows the error overlay.
+/// Enter runs the first suggested action; each action also has its own key.
+pub(super) fn handle_error_overlay_input(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    if key.code == KeyCode::Char('w') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        tab_manager.close_tab(tab_manager.active_tab);
+        return;
+    }
+
+    let session = tab_manager.active_mut();
+    let Some(report) = session.error_report() else {
+        return;
+    };
+    let action = match key.code {
+        KeyCode::Esc => {
+            session.clear_error();
+            None
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            let max_scroll = compute_error_overlay_max_scroll(&report);
+            session.error_scroll_down(max_scroll);
+            None
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            session.error_scroll_up();
+            None
+        }
+        KeyCode::Enter => report.actions.first().copied(),
+        KeyCode::Char(c) => {
+            SuggestedAction::from_key(c).filter(|action| report.actions.contains(action))
+        }
+        _ => None,
+    };
+    if let Some(action) = action {
+        run_suggested_action(action, &report, session, working_dir, output_tx);
+    }
+}
+
+/// Closes the overlay and runs `action`. The error stays in the output panel.
+fn run_suggested_action(
+    action: SuggestedAction,
+    report: &ErrorReport,
+    session: &mut Session,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    session.add_output(format!(
+        "[error] {} ({}): {}",
+        report.severity.label(),
+        report.component,
+        report.message
+    ));
+    session.clear_error();
+
+    match action {
+        SuggestedAction::RetryPhase => {
+            if !retry_failed_workflow(session, working_dir, output_tx) {
+                session.add_output(
+                    "[planning] Nothing to retry: the workflow is still running or never started"
+                        .to_string(),
+                );
+            }
+        }
+        SuggestedAction::OpenLogs => {
+            if session.open_tail_modal(TailTarget::Log, working_dir) {
+                // Start at the end, like `/tail`
+                session.plan_modal_scroll =
+                    compute_plan_modal_max_scroll(&session.plan_modal_content);
+            } else {
+                session.add_output("[planning] This session has no log yet".to_string());
+            }
+        }
+        SuggestedAction::RunDoctor => {
+            session.add_output("[doctor] Running checks...".to_string());
+            let doctor_dir = session
+                .context
+                .as_ref()
+                .map(|ctx| ctx.effective_working_dir.clone())
+                .unwrap_or_else(|| working_dir.to_path_buf());
+            let sender =
+                SessionEventSender::new(session.id, session.current_run_id, output_tx.clone());
+            tokio::spawn(async move {
+                let checks = doctor::run_checks(&doctor_dir).await;
+                for line in doctor::format_report(&checks).lines() {
+                    sender.send_output(format!("[doctor] {}", line));
+                }
+            });
+        }
+    }
+}
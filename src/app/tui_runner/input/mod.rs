@@ -1,23 +1,30 @@
 pub mod approval_input;
+pub mod config_editor_input;
+mod error_input;
 pub mod mouse_input;
+mod nav_input;
+mod notification_input;
+pub mod review_modal_input;
 pub mod session_browser_input;
+mod user_command_input;
 pub mod workflow_browser_input;
+pub mod worktree_input;
 
 use approval_input::{
     handle_awaiting_choice_input, handle_entering_feedback_input, handle_entering_iterations_input,
 };
 
+use crate::agents::permissions::ToolPermissionDecision;
 use crate::app::cli::Cli;
+use crate::app::workspace_trust::WorkspaceTrust;
 use crate::phases::implementation::{
-    build_merge_worktree_prompt, run_implementation_interaction, IMPLEMENTATION_FOLLOWUP_PHASE,
+    run_implementation_interaction, IMPLEMENTATION_FOLLOWUP_PHASE,
 };
 use crate::tui::file_index::FileIndex;
-use crate::tui::ui::util::{
-    compute_summary_panel_inner_size, compute_wrapped_line_count, compute_wrapped_line_count_text,
-};
+use crate::tui::ui::util::{compute_summary_panel_inner_size, compute_wrapped_line_count};
 use crate::tui::{
-    ApprovalMode, Event, FeedbackTarget, FocusedPanel, InputMode, Session, SessionEventSender,
-    SummaryState, TabManager, WorkflowCommand,
+    ApprovalMode, DraftKind, Event, FeedbackTarget, FocusedPanel, InputMode, MacroKeys, Session,
+    SessionEventSender, SummaryState, TabManager, WorkflowCommand,
 };
 use anyhow::Result;
 
@@ -33,34 +40,6 @@ use super::slash_commands::{parse_slash_command, SlashCommand};
 use super::InitHandle;
 use crate::tui::ui::util::{compute_plan_modal_inner_size, parse_markdown_line};
 
-use crate::domain::types::WorktreeState;
-
-/// Validation result for /merge-worktree command.
-/// This is extracted as a separate type to enable unit testing.
-#[derive(Debug, PartialEq)]
-pub enum MergeWorktreeValidation {
-    /// Valid worktree, ready to merge
-    Valid(WorktreeState),
-    /// No active worktree in this session
-    NoWorktree,
-    /// Worktree directory was deleted externally
-    WorktreeDeleted,
-}
-
-/// Validates worktree state for /merge-worktree command.
-/// Extracted from handler to enable unit testing.
-pub fn validate_merge_worktree(worktree_info: Option<&WorktreeState>) -> MergeWorktreeValidation {
-    let Some(wt_state) = worktree_info else {
-        return MergeWorktreeValidation::NoWorktree;
-    };
-
-    if !wt_state.worktree_path().exists() {
-        return MergeWorktreeValidation::WorktreeDeleted;
-    }
-
-    MergeWorktreeValidation::Valid(wt_state.clone())
-}
-
 /// Compute the max scroll for the run-tab summary panel based on wrapped lines and terminal size.
 pub(crate) fn compute_run_tab_summary_max_scroll(summary_text: &str) -> usize {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
@@ -115,7 +94,7 @@ fn compute_todo_panel_inner_size(
 /// Compute the max scroll for the Todo panel based on wrapped lines and terminal size.
 pub(crate) fn compute_todo_panel_max_scroll(session: &Session) -> usize {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-    let has_todos = !session.todos.is_empty();
+    let has_todos = session.shows_todos();
     let (inner_width, inner_height, visible) =
         compute_todo_panel_inner_size(term_width, term_height, has_todos);
 
@@ -134,7 +113,7 @@ pub(crate) fn compute_todo_panel_max_scroll(session: &Session) -> usize {
 /// Todos panel requires: terminal width >= 80 AND todos exist.
 pub(crate) fn is_todo_panel_visible(session: &Session) -> bool {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-    let has_todos = !session.todos.is_empty();
+    let has_todos = session.shows_todos();
     let (_, _, visible) = compute_todo_panel_inner_size(term_width, term_height, has_todos);
     visible
 }
@@ -162,62 +141,45 @@ pub(crate) fn compute_plan_modal_max_scroll(content: &str) -> usize {
     total_lines.saturating_sub(visible_height as usize)
 }
 
-/// Compute the visible height of the plan modal for page scrolling.
-fn compute_plan_modal_visible_height() -> usize {
-    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-    let (_, visible_height) = compute_plan_modal_inner_size(term_width, term_height);
-    visible_height as usize
+/// How often plan modals opened with `/tail` re-read their file.
+pub const TAIL_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Reloads plan modals that follow a file; a view scrolled to the end stays at the end.
+pub(crate) fn refresh_tail_modals(tab_manager: &mut TabManager, working_dir: &Path) {
+    for session in tab_manager.sessions_mut() {
+        if session.plan_modal_tail.is_none() {
+            continue;
+        }
+        let at_end =
+            session.plan_modal_scroll >= compute_plan_modal_max_scroll(&session.plan_modal_content);
+        if session.refresh_tail_modal(working_dir) && at_end {
+            session.plan_modal_scroll = compute_plan_modal_max_scroll(&session.plan_modal_content);
+        }
+    }
 }
 
-/// Compute the max scroll for the review modal based on wrapped lines and terminal size.
-pub(crate) fn compute_review_modal_max_scroll(content: &str) -> usize {
+/// Compute the visible height of the plan modal for page scrolling.
+/// Scroll position showing the given plan content line a few rows below the top.
+fn compute_plan_modal_scroll_to_line(content: &str, plan_line: usize) -> usize {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-    let (inner_width, visible_height) = compute_review_modal_inner_size(term_width, term_height);
-
-    let content_lines: Vec<Line> = content.lines().map(parse_markdown_line).collect();
-    let total_lines = compute_wrapped_line_count(&content_lines, inner_width);
-
-    total_lines.saturating_sub(visible_height as usize)
+    let (inner_width, visible_height) = compute_plan_modal_inner_size(term_width, term_height);
+    let lines_before: Vec<Line> = content
+        .lines()
+        .take(plan_line)
+        .map(parse_markdown_line)
+        .collect();
+    let rows_before = compute_wrapped_line_count(&lines_before, inner_width);
+    rows_before
+        .saturating_sub(visible_height as usize / 3)
+        .min(compute_plan_modal_max_scroll(content))
 }
 
-/// Compute the visible height of the review modal for page scrolling.
-fn compute_review_modal_visible_height() -> usize {
+fn compute_plan_modal_visible_height() -> usize {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-    let (_, visible_height) = compute_review_modal_inner_size(term_width, term_height);
+    let (_, visible_height) = compute_plan_modal_inner_size(term_width, term_height);
     visible_height as usize
 }
 
-/// Compute the available width for review modal tabs.
-/// Returns inner width of title block for tab rendering.
-///
-/// The title block is: popup_width (80% of terminal) minus 2 for borders.
-/// This matches the actual rendering in draw_review_modal() where
-/// the title Paragraph is rendered inside a Block with Borders::ALL.
-fn compute_review_modal_tab_width() -> usize {
-    let (term_width, _) = crossterm::terminal::size().unwrap_or((80, 24));
-    let popup_width = (term_width as f32 * 0.8) as u16;
-    // Title block inner width = popup_width - 2 (left and right borders)
-    popup_width.saturating_sub(2) as usize
-}
-
-/// Compute the inner dimensions of the review modal.
-/// Returns (inner_width, visible_height) for content area.
-fn compute_review_modal_inner_size(term_width: u16, term_height: u16) -> (u16, u16) {
-    let popup_width = (term_width as f32 * 0.8) as u16;
-    let popup_height = (term_height as f32 * 0.8) as u16;
-
-    // Vertical layout: Title (3) + Content (min 0) + Instructions (3)
-    // Content block has borders (2), so inner_height = content_chunk_height - 2
-    // Title and instructions take 6 lines total
-    let content_chunk_height = popup_height.saturating_sub(6);
-    let inner_height = content_chunk_height.saturating_sub(2);
-
-    // Content block has borders (2)
-    let inner_width = popup_width.saturating_sub(2);
-
-    (inner_width, inner_height)
-}
-
 /// Compute the max scroll for the review history panel.
 /// The panel is shown when terminal width >= 100, taking up 30% of the chat area.
 pub(crate) fn compute_review_history_max_scroll(
@@ -257,34 +219,21 @@ pub(crate) fn compute_review_history_max_scroll(
     content_lines.saturating_sub(visible_height)
 }
 
-/// Compute the max scroll for the error overlay based on wrapped lines and terminal size.
-pub(crate) fn compute_error_overlay_max_scroll(error: &str) -> usize {
-    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
-
-    // Match draw_error_overlay: 60% width, max 70
-    let popup_width = (term_width as f32 * 0.6).min(70.0) as u16;
-    let inner_width = popup_width.saturating_sub(2);
-
-    // Compute wrapped line count for the error text
-    let wrapped_error_lines = compute_wrapped_line_count_text(error, inner_width);
-
-    // Popup height calculation (matching draw_error_overlay)
-    let max_popup_height = (term_height as f32 * 0.8) as u16;
-    let min_popup_height = 8u16;
-    let ideal_popup_height = (wrapped_error_lines as u16).saturating_add(5);
-    let popup_height = ideal_popup_height.clamp(min_popup_height, max_popup_height);
-
-    // Visible height = popup_height - borders (2) - instructions (1)
-    let visible_height = popup_height.saturating_sub(3) as usize;
-
-    // Total content = empty line + error text + empty line
-    let total_content_lines = wrapped_error_lines + 2;
+/// Compute the max scroll for the output panel based on content and terminal size.
+pub(crate) fn compute_output_panel_max_scroll(session: &Session) -> usize {
+    let (inner_width, inner_height) = output_panel_inner_size(session);
+    let lines: Vec<Line> = session
+        .output_lines
+        .iter()
+        .map(|s| Line::from(s.as_str()))
+        .collect();
+    let total_lines = compute_wrapped_line_count(&lines, inner_width);
 
-    total_content_lines.saturating_sub(visible_height)
+    total_lines.saturating_sub(inner_height)
 }
 
-/// Compute the max scroll for the output panel based on content and terminal size.
-pub(crate) fn compute_output_panel_max_scroll(session: &Session) -> usize {
+/// Compute the (width, height) inside the output panel's borders.
+pub(crate) fn output_panel_inner_size(session: &Session) -> (u16, usize) {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
 
     // Main layout: header (2) + footer (3) = 5
@@ -295,7 +244,7 @@ pub(crate) fn compute_output_panel_max_scroll(session: &Session) -> usize {
     let output_height = (main_height as f32 * 0.40) as u16;
 
     // Todos visible when width >= 80 and todos exist
-    let todos_visible = left_width >= 80 && !session.todos.is_empty();
+    let todos_visible = left_width >= 80 && session.shows_todos();
     let output_width = if todos_visible {
         (left_width as f32 * 0.65) as u16
     } else {
@@ -304,15 +253,7 @@ pub(crate) fn compute_output_panel_max_scroll(session: &Session) -> usize {
 
     let inner_height = output_height.saturating_sub(2) as usize; // borders
     let inner_width = output_width.saturating_sub(2);
-
-    let lines: Vec<Line> = session
-        .output_lines
-        .iter()
-        .map(|s| Line::from(s.as_str()))
-        .collect();
-    let total_lines = compute_wrapped_line_count(&lines, inner_width);
-
-    total_lines.saturating_sub(inner_height)
+    (inner_width, inner_height)
 }
 
 /// Compute the max scroll for chat content panel.
@@ -320,7 +261,21 @@ pub(crate) fn compute_chat_content_max_scroll(session: &Session) -> usize {
     let Some(tab) = session.run_tabs.get(session.active_run_tab) else {
         return 0;
     };
+    let (_inner_width, inner_height) = chat_panel_inner_size();
+
+    // Count entries shown at the session's chat verbosity
+    let entry_count = tab
+        .entries
+        .iter()
+        .filter(|entry| session.chat_verbosity.shows(entry))
+        .count();
+    let total_lines = entry_count.max(1);
 
+    total_lines.saturating_sub(inner_height)
+}
+
+/// Compute the (width, height) inside the chat content panel's borders.
+pub(crate) fn chat_panel_inner_size() -> (u16, usize) {
     let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
 
     // Main layout: header (2) + footer (3) = 5
@@ -340,16 +295,21 @@ pub(crate) fn compute_chat_content_max_scroll(session: &Session) -> usize {
     };
 
     let inner_height = content_height.saturating_sub(2) as usize; // borders
-    let _inner_width = chat_width.saturating_sub(2);
-
-    // Count entries from tab
-    let entry_count = tab.entries.len();
-    let total_lines = entry_count.max(1);
+    let inner_width = chat_width.saturating_sub(2);
+    (inner_width, inner_height)
+}
 
-    total_lines.saturating_sub(inner_height)
+/// True while the active session takes typed text, where every key is input.
+fn is_text_input(session: &Session) -> bool {
+    session.input_mode != InputMode::Normal
+        || session.approval_mode == ApprovalMode::EnteringFeedback
+        || session.approval_mode == ApprovalMode::EnteringIterations
+        || session.focused_panel == FocusedPanel::ChatInput
+        || session.review_annotation_input.is_some()
+        || (session.nav.is_some() && session.approval_mode == ApprovalMode::None)
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Handles a typed key, recording and replaying keyboard macros.
 pub async fn handle_key_event(
     key: crossterm::event::KeyEvent,
     tab_manager: &mut TabManager,
@@ -358,6 +318,74 @@ pub async fn handle_key_event(
     working_dir: &Path,
     cli: &Cli,
     init_handle: &mut InitHandle,
+) -> Result<bool> {
+    // Overlays and text fields read `q` and `@` as themselves
+    let commands_allowed = !(tab_manager.session_browser.open
+        || tab_manager.workflow_browser.open
+        || tab_manager.config_editor.open
+        || tab_manager.notifications.open
+        || tab_manager.approval_queue.open
+        || tab_manager.recent_tabs.open
+        || is_text_input(tab_manager.active()));
+    let keys = match tab_manager.macros.handle_key(key, commands_allowed) {
+        MacroKeys::Dispatch(keys) => keys,
+        MacroKeys::Consumed => return Ok(false),
+    };
+    for key in keys {
+        if dispatch_key(
+            key,
+            tab_manager,
+            terminal,
+            output_tx,
+            working_dir,
+            cli,
+            init_handle,
+        )
+        .await?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Handles a `q` or `@` that was not followed by a register key in time.
+pub async fn flush_macro_prefix(
+    tab_manager: &mut TabManager,
+    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    output_tx: &mpsc::UnboundedSender<Event>,
+    working_dir: &Path,
+    cli: &Cli,
+    init_handle: &mut InitHandle,
+) -> Result<bool> {
+    match tab_manager
+        .macros
+        .take_expired_prefix(std::time::Instant::now())
+    {
+        Some(key) => {
+            dispatch_key(
+                key,
+                tab_manager,
+                terminal,
+                output_tx,
+                working_dir,
+                cli,
+                init_handle,
+            )
+            .await
+        }
+        None => Ok(false),
+    }
+}
+
+async fn dispatch_key(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    output_tx: &mpsc::UnboundedSender<Event>,
+    working_dir: &Path,
+    cli: &Cli,
+    init_handle: &mut InitHandle,
 ) -> Result<bool> {
     #[allow(unused_assignments)]
     let mut should_quit = false;
@@ -380,6 +408,13 @@ pub async fn handle_key_event(
         return Ok(should_quit);
     }
 
+    // Handle config editor overlay input when it's open
+    if tab_manager.config_editor.open {
+        should_quit =
+            config_editor_input::handle_config_editor_input(key, tab_manager, output_tx).await?;
+        return Ok(should_quit);
+    }
+
     // Handle workflow browser overlay input when it's open
     if tab_manager.workflow_browser.open {
         should_quit =
@@ -388,29 +423,82 @@ pub async fn handle_key_event(
         return Ok(should_quit);
     }
 
+    if tab_manager.approval_queue.open {
+        return Ok(notification_input::handle_approval_queue_input(key, tab_manager).await);
+    }
+
+    // Handle notification center input when it's open
+    if tab_manager.notifications.open {
+        return Ok(notification_input::handle_notification_input(
+            key,
+            tab_manager,
+        ));
+    }
+
+    // Handle the recent-tab switcher, which Ctrl+Tab opens from anywhere else
+    if tab_manager.recent_tabs.open {
+        return Ok(notification_input::handle_recent_tabs_input(
+            key,
+            tab_manager,
+        ));
+    }
+    if key.code == KeyCode::Tab && key.modifiers.contains(KeyModifiers::CONTROL) {
+        tab_manager.open_recent_tabs();
+        return Ok(false);
+    }
+
+    // Resume the most recently idle-suspended session
+    if key.code == KeyCode::Char('r')
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+        && !tab_manager.suspended_sessions.is_empty()
+    {
+        super::idle_suspend::resume_last_suspended(tab_manager, working_dir, output_tx);
+        return Ok(false);
+    }
+
     let session = tab_manager.active_mut();
 
-    if let Some(ref error) = session.error_state.clone() {
+    if session.error_state.is_some() {
+        error_input::handle_error_overlay_input(key, tab_manager, working_dir, output_tx);
+        return Ok(false);
+    }
+
+    // Handle tool permission prompt input (the agent is paused until answered)
+    if session.current_tool_permission().is_some() {
+        let decision = match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => Some(ToolPermissionDecision::Allow),
+            KeyCode::Char('a') => Some(ToolPermissionDecision::AllowForSession),
+            KeyCode::Char('n') | KeyCode::Esc => Some(ToolPermissionDecision::Deny),
+            _ => None,
+        };
+        if let Some(decision) = decision {
+            session.answer_tool_permission(decision);
+        }
+        return Ok(false);
+    }
+
+    // Handle workspace trust prompt input (implementation waits for the answer)
+    if session.workspace_trust_request.is_some() {
         match key.code {
-            KeyCode::Esc => {
-                session.clear_error();
-                return Ok(false);
-            }
-            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                tab_manager.close_tab(tab_manager.active_tab);
-                return Ok(false);
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                let max_scroll = compute_error_overlay_max_scroll(error);
-                session.error_scroll_down(max_scroll);
-                return Ok(false);
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                session.error_scroll_up();
-                return Ok(false);
+            KeyCode::Char('t') => session.answer_workspace_trust(WorkspaceTrust::Trusted),
+            KeyCode::Char('r') | KeyCode::Esc => {
+                session.answer_workspace_trust(WorkspaceTrust::Restricted)
             }
-            _ => return Ok(false),
+            _ => {}
         }
+        return Ok(false);
+    }
+
+    // Handle feedback triage overlay input (the revision waits for the decisions)
+    if session.feedback_triage.is_some() {
+        approval_input::handle_feedback_triage_input(key, session).await;
+        return Ok(false);
+    }
+
+    // Handle worktree conflict overlay input (intercept keys before other handlers)
+    if session.conflict_modal.is_some() {
+        worktree_input::handle_conflict_modal_input(key, session, output_tx);
+        return Ok(false);
     }
 
     // Handle implementation success modal input (intercept keys before other handlers)
@@ -421,6 +509,7 @@ pub async fn handle_key_event(
                 // After dismissing success modal with Enter, focus ChatInput if interaction is available
                 if key.code == KeyCode::Enter && session.can_interact_with_implementation() {
                     session.focused_panel = FocusedPanel::ChatInput;
+                    session.restore_draft();
                 }
             }
             _ => {}
@@ -428,12 +517,17 @@ pub async fn handle_key_event(
         return Ok(false);
     }
 
+    // Handle completion summary input (intercept keys before other handlers)
+    if session.completion_summary.is_some() {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Enter) {
+            session.completion_summary = None;
+        }
+        return Ok(false);
+    }
+
     // Handle 'p' to toggle plan modal (global hotkey, works from any mode except error state or input areas)
     // ChatInput is included because users need to type characters like 'p' in follow-up messages
-    let in_text_input = session.input_mode != InputMode::Normal
-        || session.approval_mode == ApprovalMode::EnteringFeedback
-        || session.approval_mode == ApprovalMode::EnteringIterations
-        || session.focused_panel == FocusedPanel::ChatInput;
+    let in_text_input = is_text_input(session);
     if key.code == KeyCode::Char('p') && session.workflow_view.is_some() && !in_text_input {
         session.toggle_plan_modal(working_dir);
         return Ok(false);
@@ -445,44 +539,30 @@ pub async fn handle_key_event(
         return Ok(false);
     }
 
+    // Handle 'n' to open the notification center (global hotkey, except in input areas)
+    if key.code == KeyCode::Char('n') && key.modifiers.is_empty() && !in_text_input {
+        tab_manager.notifications.toggle();
+        return Ok(false);
+    }
+
     // Handle review modal input when it's open (intercept keys before other handlers)
     if session.review_modal_open {
-        let content = session.current_review_content().to_string();
+        review_modal_input::handle_review_modal_input(key, session);
+        return Ok(false);
+    }
+
+    // Handle the file viewer opened from a plan reference, shown over the plan modal
+    if session.plan_modal_open && session.file_viewer.is_some() {
+        let visible_height = compute_plan_modal_visible_height();
+        let page = visible_height as isize;
         match key.code {
-            KeyCode::Esc | KeyCode::Char('v') => {
-                session.close_review_modal();
-            }
-            KeyCode::Tab | KeyCode::Right => {
-                let tab_width = compute_review_modal_tab_width();
-                session.review_modal_next_tab(tab_width);
-            }
-            KeyCode::BackTab | KeyCode::Left => {
-                let tab_width = compute_review_modal_tab_width();
-                session.review_modal_prev_tab(tab_width);
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                let max_scroll = compute_review_modal_max_scroll(&content);
-                session.review_modal_scroll_down(max_scroll);
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                session.review_modal_scroll_up();
-            }
-            KeyCode::Char('g') => {
-                session.review_modal_scroll_to_top();
-            }
-            KeyCode::Char('G') => {
-                let max_scroll = compute_review_modal_max_scroll(&content);
-                session.review_modal_scroll_to_bottom(max_scroll);
-            }
-            KeyCode::PageDown => {
-                let visible_height = compute_review_modal_visible_height();
-                let max_scroll = compute_review_modal_max_scroll(&content);
-                session.review_modal_page_down(visible_height, max_scroll);
-            }
-            KeyCode::PageUp => {
-                let visible_height = compute_review_modal_visible_height();
-                session.review_modal_page_up(visible_height);
-            }
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Backspace => session.close_file_viewer(),
+            KeyCode::Char('j') | KeyCode::Down => session.file_viewer_scroll_by(1, visible_height),
+            KeyCode::Char('k') | KeyCode::Up => session.file_viewer_scroll_by(-1, visible_height),
+            KeyCode::PageDown => session.file_viewer_scroll_by(page, visible_height),
+            KeyCode::PageUp => session.file_viewer_scroll_by(-page, visible_height),
+            KeyCode::Char('g') => session.file_viewer_scroll_by(isize::MIN, visible_height),
+            KeyCode::Char('G') => session.file_viewer_scroll_by(isize::MAX, visible_height),
             _ => {}
         }
         return Ok(false);
@@ -492,6 +572,24 @@ pub async fn handle_key_event(
     if session.plan_modal_open {
         let content = session.plan_modal_content.clone();
         match key.code {
+            KeyCode::Tab | KeyCode::BackTab => {
+                let active = tab_manager.active_tab;
+                let session = &mut tab_manager.sessions[active];
+                let forward = key.code == KeyCode::Tab;
+                if session.select_plan_reference(forward, &tab_manager.file_index) {
+                    let plan_line = session.plan_modal_reference.as_ref().map(|r| r.plan_line);
+                    session.plan_modal_scroll =
+                        compute_plan_modal_scroll_to_line(&content, plan_line.unwrap_or(0));
+                }
+            }
+            KeyCode::Enter => {
+                let repo_root = tab_manager.file_index.repo_root.clone();
+                let repo_root = repo_root.unwrap_or_else(|| working_dir.to_path_buf());
+                let visible_height = compute_plan_modal_visible_height();
+                tab_manager
+                    .active_mut()
+                    .open_file_viewer(&repo_root, visible_height);
+            }
             KeyCode::Esc | KeyCode::Char('p') => {
                 session.close_plan_modal();
             }
@@ -537,6 +635,17 @@ pub async fn handle_key_event(
         return Ok(should_quit);
     }
 
+    // Handle 'a' to open the approval queue, unless this tab is itself being answered
+    let session = tab_manager.active_mut();
+    if key.code == KeyCode::Char('a')
+        && key.modifiers.is_empty()
+        && session.approval_mode == ApprovalMode::None
+        && !is_text_input(session)
+    {
+        tab_manager.toggle_approval_queue();
+        return Ok(false);
+    }
+
     let session = tab_manager.active_mut();
     if session.approval_mode == ApprovalMode::None && handle_tab_switching(key, tab_manager) {
         return Ok(false);
@@ -603,8 +712,7 @@ async fn handle_implementation_chat_input(
 
             // Check if this is a /merge-worktree command
             let input_trimmed = session.tab_input.trim().to_string();
-            if let Some((SlashCommand::MergeWorktree, _args)) = parse_slash_command(&input_trimmed)
-            {
+            if let Some((SlashCommand::MergeWorktree, args)) = parse_slash_command(&input_trimmed) {
                 // Clear input first
                 session.tab_input.clear();
                 session.tab_input_cursor = 0;
@@ -613,102 +721,7 @@ async fn handle_implementation_chat_input(
                 session.tab_mention_state.clear();
                 session.tab_slash_state.clear();
 
-                // Validate worktree state using the extracted helper function
-                let worktree_info = session
-                    .workflow_view
-                    .as_ref()
-                    .and_then(|v| v.worktree_info());
-
-                let wt_state = match validate_merge_worktree(worktree_info) {
-                    MergeWorktreeValidation::Valid(state) => state,
-                    MergeWorktreeValidation::NoWorktree => {
-                        session.add_output(
-                            "[merge-worktree] No active worktree in this session".to_string(),
-                        );
-                        return Ok(false);
-                    }
-                    MergeWorktreeValidation::WorktreeDeleted => {
-                        session.add_output(
-                            "[merge-worktree] Error: Worktree directory no longer exists"
-                                .to_string(),
-                        );
-                        return Ok(false);
-                    }
-                };
-
-                // Get required context
-                let Some(context) = session.context.clone() else {
-                    session.add_output(
-                        "[merge-worktree] Unavailable: missing session context".to_string(),
-                    );
-                    return Ok(false);
-                };
-                let Some(view) = session.workflow_view.clone() else {
-                    session.add_output(
-                        "[merge-worktree] Unavailable: missing workflow view".to_string(),
-                    );
-                    return Ok(false);
-                };
-                let Some(workflow_id) = view.workflow_id() else {
-                    session.add_output(
-                        "[merge-worktree] Unavailable: missing workflow ID".to_string(),
-                    );
-                    return Ok(false);
-                };
-
-                // Build merge prompt
-                let message = build_merge_worktree_prompt(&wt_state);
-
-                // Set up interaction state
-                let workflow_session_id = workflow_id.to_string();
-                let ui_session_id = session.id;
-                let run_id = session.current_run_id;
-                let (cancel_tx, cancel_rx) = watch::channel(false);
-
-                session.implementation_interaction.running = true;
-                session.implementation_interaction.cancel_tx = Some(cancel_tx);
-
-                // Add user-visible message to chat
-                session.add_chat_message(
-                    "user",
-                    IMPLEMENTATION_FOLLOWUP_PHASE,
-                    "/merge-worktree".to_string(),
-                );
-                session
-                    .add_output("[merge-worktree] Sending merge request to agent...".to_string());
-
-                // Prepare task parameters
-                let working_dir = context.effective_working_dir.clone();
-                let workflow_config = context.workflow_config.clone();
-                let session_sender =
-                    SessionEventSender::new(ui_session_id, run_id, output_tx.clone());
-
-                let session_logger = match SessionLogger::new(&workflow_session_id) {
-                    Ok(logger) => Arc::new(logger),
-                    Err(e) => {
-                        session.implementation_interaction.running = false;
-                        session.implementation_interaction.cancel_tx = None;
-                        session
-                            .add_output(format!("[merge-worktree] Failed to create logger: {}", e));
-                        return Ok(false);
-                    }
-                };
-
-                // Spawn the interaction task
-                tokio::spawn(async move {
-                    let _ = run_implementation_interaction(
-                        &view,
-                        &workflow_config,
-                        &working_dir,
-                        &message,
-                        session_sender,
-                        session_logger,
-                        cancel_rx,
-                        None,
-                    )
-                    .await;
-                });
-
+                worktree_input::handle_merge_worktree_command(session, &args, output_tx);
                 return Ok(false);
             }
 
@@ -759,6 +772,7 @@ async fn handle_implementation_chat_input(
             session.clear_tab_input_pastes();
             session.tab_mention_state.clear();
             session.tab_slash_state.clear();
+            session.discard_draft(DraftKind::Chat);
 
             let working_dir = context.effective_working_dir.clone();
             let workflow_config = context.workflow_config.clone();
@@ -865,14 +879,29 @@ async fn handle_approval_mode_input(
         return handle_implementation_chat_input(key, session, output_tx, working_dir).await;
     }
 
+    // Shift+W moves a session that shares its checkout with another into a worktree
+    if key.code == KeyCode::Char('W')
+        && session.dir_conflict.is_some()
+        && matches!(
+            session.approval_mode,
+            ApprovalMode::None | ApprovalMode::AwaitingChoice
+        )
+    {
+        super::dir_conflicts::move_to_worktree(session);
+        return Ok(false);
+    }
+
     match session.approval_mode {
         ApprovalMode::AwaitingChoice => {
             handle_awaiting_choice_input(key, session, terminal, working_dir, output_tx).await
         }
         ApprovalMode::EnteringFeedback => {
-            handle_entering_feedback_input(key, session, file_index).await
+            handle_entering_feedback_input(key, session, file_index, working_dir, output_tx).await
         }
         ApprovalMode::EnteringIterations => handle_entering_iterations_input(key, session).await,
+        ApprovalMode::None if session.nav.is_some() => {
+            Ok(nav_input::handle_nav_input(key, session))
+        }
         ApprovalMode::None => handle_none_mode_input(key, session),
     }
 }
@@ -1002,6 +1031,18 @@ fn handle_none_mode_input(key: crossterm::event::KeyEvent, session: &mut Session
                 session.review_history_scroll_to_bottom(max_scroll);
             }
         }
+        KeyCode::Char('/') if session.workflow_view.is_some() => {
+            session.start_feedback_input_for(FeedbackTarget::Command);
+            session.insert_char('/');
+        }
+        KeyCode::Char('x') if session.focused_panel == FocusedPanel::Summary => {
+            if session.cancel_active_summary() {
+                session.add_output("[planning] Summary cancelled".to_string());
+            }
+        }
+        KeyCode::Char('N') => nav_input::start_nav(session),
+        KeyCode::Char('V') => session.cycle_chat_verbosity(),
+        KeyCode::Char('T') => session.toggle_todos_collapsed(),
         KeyCode::Left => {
             if session.focused_panel == FocusedPanel::Chat
                 || session.focused_panel == FocusedPanel::Summary
@@ -1020,7 +1061,3 @@ fn handle_none_mode_input(key: crossterm::event::KeyEvent, session: &mut Session
     }
     Ok(false)
 }
-
-#[cfg(test)]
-#[path = "tests/merge_worktree_tests.rs"]
-mod merge_worktree_tests;
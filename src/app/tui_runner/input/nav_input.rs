@@ -0,0 +1,151 @@
+//! Navigation mode input handling for the output and chat panels.
+//!
+//! `N` on a focused output or chat panel puts a cursor on its last line. The
+//! cursor moves with Vim keys (`j`/`k`, `d`/`u` half a page, `gg`/`G`), `/`
+//! searches and `n`/`N` repeat the search, `v` starts a selection and `y`
+//! copies the selection (or the cursor line) to the clipboard. `Esc` drops the
+//! selection, then leaves navigation mode.
+
+use super::{chat_panel_inner_size, output_panel_inner_size};
+use crate::tui::scroll::{scroll_to_show, NavState};
+use crate::tui::ui::util::compute_wrapped_line_count;
+use crate::tui::{copy_to_clipboard, FocusedPanel, Session};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::text::Line;
+
+/// Enters navigation mode on the focused panel, if it is the output or chat panel.
+pub fn start_nav(session: &mut Session) {
+    let panel = session.focused_panel;
+    if !matches!(panel, FocusedPanel::Output | FocusedPanel::Chat) {
+        return;
+    }
+    let lines = session.nav_lines(panel);
+    session.nav = Some(NavState::new(panel, lines.len().saturating_sub(1)));
+    reveal_cursor(session, &lines);
+}
+
+/// Handle input in navigation mode. Returns true to quit.
+pub fn handle_nav_input(key: crossterm::event::KeyEvent, session: &mut Session) -> bool {
+    let Some(panel) = session.nav.as_ref().map(|nav| nav.panel) else {
+        return false;
+    };
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return true;
+    }
+    let lines = session.nav_lines(panel);
+    let line_count = lines.len();
+    let half_page = (panel_inner_size(session, panel).1 / 2).max(1) as isize;
+    let Some(nav) = session.nav.as_mut() else {
+        return false;
+    };
+    nav.message = None;
+
+    if let Some(input) = nav.search_input.as_mut() {
+        match key.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                if input.pop().is_none() {
+                    nav.search_input = None;
+                }
+            }
+            KeyCode::Enter => {
+                let pattern = nav.search_input.take().unwrap_or_default();
+                if !pattern.is_empty() {
+                    search(nav, &lines, &pattern, true);
+                    nav.last_search = Some(pattern);
+                }
+            }
+            KeyCode::Esc => nav.search_input = None,
+            _ => {}
+        }
+    } else {
+        let pending_g = std::mem::take(&mut nav.pending_g);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => nav.move_by(1, line_count),
+            KeyCode::Char('k') | KeyCode::Up => nav.move_by(-1, line_count),
+            KeyCode::Char('d') => nav.move_by(half_page, line_count),
+            KeyCode::Char('u') => nav.move_by(-half_page, line_count),
+            KeyCode::Char('g') if pending_g => nav.move_to(0, line_count),
+            KeyCode::Char('g') => nav.pending_g = true,
+            KeyCode::Char('G') => nav.move_to(usize::MAX, line_count),
+            KeyCode::Char('/') => nav.search_input = Some(String::new()),
+            KeyCode::Char(c @ ('n' | 'N')) => {
+                if let Some(pattern) = nav.last_search.clone() {
+                    search(nav, &lines, &pattern, c == 'n');
+                }
+            }
+            KeyCode::Char('v') => {
+                nav.anchor = match nav.anchor {
+                    Some(_) => None,
+                    None => Some(nav.cursor),
+                };
+            }
+            KeyCode::Char('y') if line_count > 0 => {
+                let (first, last) = nav.selection();
+                copy_to_clipboard(&nav.selected_text(&lines));
+                let count = last + 1 - first;
+                nav.message = Some(format!(
+                    "Yanked {} line{}",
+                    count,
+                    if count == 1 { "" } else { "s" }
+                ));
+                nav.anchor = None;
+            }
+            KeyCode::Esc if nav.anchor.is_some() => nav.anchor = None,
+            KeyCode::Esc | KeyCode::Char('q') => {
+                session.nav = None;
+                return false;
+            }
+            _ => {}
+        }
+    }
+
+    reveal_cursor(session, &lines);
+    false
+}
+
+fn search(nav: &mut NavState, lines: &[String], pattern: &str, forward: bool) {
+    if !nav.find(lines, pattern, forward) {
+        nav.message = Some(format!("Pattern not found: {}", pattern));
+    }
+}
+
+fn panel_inner_size(session: &Session, panel: FocusedPanel) -> (u16, usize) {
+    match panel {
+        FocusedPanel::Chat => chat_panel_inner_size(),
+        _ => output_panel_inner_size(session),
+    }
+}
+
+/// Scrolls the navigated panel as little as possible to show the cursor line.
+fn reveal_cursor(session: &mut Session, lines: &[String]) {
+    let Some(nav) = session.nav.as_ref() else {
+        return;
+    };
+    let (panel, cursor) = (nav.panel, nav.cursor);
+    let Some(cursor_line) = lines.get(cursor) else {
+        return;
+    };
+    let (width, height) = panel_inner_size(session, panel);
+    let to_lines = |lines: &[String]| -> Vec<Line> {
+        lines.iter().map(|line| Line::from(line.as_str())).collect()
+    };
+    let first = match cursor {
+        0 => 0,
+        _ => compute_wrapped_line_count(&to_lines(&lines[..cursor]), width),
+    };
+    let rows = compute_wrapped_line_count(&to_lines(std::slice::from_ref(cursor_line)), width);
+    let last = first + rows.max(1) - 1;
+    let max_scroll = compute_wrapped_line_count(&to_lines(lines), width).saturating_sub(height);
+
+    let scroll = match panel {
+        FocusedPanel::Chat => match session.run_tabs.get_mut(session.active_run_tab) {
+            Some(tab) => &mut tab.chat_scroll,
+            None => return,
+        },
+        _ => &mut session.output_scroll,
+    };
+    let position = scroll.effective_position(max_scroll);
+    scroll.position = scroll_to_show(position, first, last, height).min(max_scroll);
+    scroll.follow = false;
+}
@@ -0,0 +1,171 @@
+//! Notification center, approval queue and recent-tab switcher input handling.
+//!
+//! This module handles keyboard input for the notification overlay, moving
+//! through background tab events and jumping to the tab one came from, for the
+//! approval queue answering plan approvals of any tab without switching to it,
+//! and for the Ctrl+Tab switcher cycling through recently used tabs.
+
+use crate::tui::{ApprovalMode, SessionStatus, TabManager, UserApprovalResponse};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Handle input when the notification overlay is open.
+pub fn handle_notification_input(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+) -> bool {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            tab_manager.notifications.select_next();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            tab_manager.notifications.select_prev();
+        }
+        KeyCode::Enter => {
+            if let Some(entry) = tab_manager.notifications.selected_entry() {
+                let (session_id, tab_name) = (entry.session_id, entry.tab_name.clone());
+                if !tab_manager.switch_to_session(session_id) {
+                    tab_manager.command_error = Some(format!("Tab '{}' is closed", tab_name));
+                }
+                tab_manager.notifications.close();
+            }
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            return true;
+        }
+        KeyCode::Char('c') => {
+            tab_manager.notifications.clear();
+        }
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('n') => {
+            tab_manager.notifications.close();
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Handle input when the approval queue is open.
+pub async fn handle_approval_queue_input(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+) -> bool {
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return true;
+    }
+    let selected = tab_manager
+        .pending_approvals()
+        .get(tab_manager.approval_queue.selected_idx)
+        .map(|session| session.id);
+
+    if let Some(feedback) = tab_manager.approval_queue.decline_input.as_mut() {
+        match key.code {
+            KeyCode::Enter if !feedback.trim().is_empty() => {
+                let feedback = std::mem::take(feedback);
+                tab_manager.approval_queue.decline_input = None;
+                if let Some(session_id) = selected {
+                    answer_approval(
+                        tab_manager,
+                        session_id,
+                        Some(UserApprovalResponse::Decline(feedback)),
+                    )
+                    .await;
+                }
+            }
+            KeyCode::Esc => tab_manager.approval_queue.decline_input = None,
+            KeyCode::Backspace => {
+                feedback.pop();
+            }
+            KeyCode::Char(c) => feedback.push(c),
+            _ => {}
+        }
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            let len = tab_manager.pending_approvals().len();
+            tab_manager.approval_queue.select_next(len);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            tab_manager.approval_queue.select_prev();
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            if let Some(session_id) = selected {
+                answer_approval(tab_manager, session_id, None).await;
+            }
+        }
+        KeyCode::Char('d') | KeyCode::Char('D') if selected.is_some() => {
+            tab_manager.approval_queue.decline_input = Some(String::new());
+        }
+        KeyCode::Enter => {
+            if let Some(session_id) = selected {
+                tab_manager.switch_to_session(session_id);
+                tab_manager.approval_queue.close();
+            }
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            tab_manager.approval_queue.close();
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Sends the answer to the plan approval of `session_id`, accepting it when
+/// `response` is None, as `a` in the tab itself does.
+async fn answer_approval(
+    tab_manager: &mut TabManager,
+    session_id: usize,
+    response: Option<UserApprovalResponse>,
+) {
+    let Some(session) = tab_manager.session_by_id_mut(session_id) else {
+        return;
+    };
+    let at_gate = session.at_approval_gate();
+    let (response, status, note) = match response {
+        Some(response) => (response, SessionStatus::Planning, "declined"),
+        None => {
+            let status = if at_gate {
+                SessionStatus::Planning
+            } else {
+                SessionStatus::Complete
+            };
+            (session.take_accept_response(false), status, "approved")
+        }
+    };
+    if let Some(tx) = session.take_approval_tx(false) {
+        // Channel send may fail if workflow already completed - safe to ignore
+        let _ = tx.send(response).await;
+    }
+    session.approval_mode = ApprovalMode::None;
+    session.status = status;
+    session.add_output(format!("[planning] Plan {} from the approval queue", note));
+    let len = tab_manager.pending_approvals().len();
+    tab_manager.approval_queue.clamp(len);
+}
+
+/// Handle input when the recent-tab switcher is open.
+pub fn handle_recent_tabs_input(
+    key: crossterm::event::KeyEvent,
+    tab_manager: &mut TabManager,
+) -> bool {
+    let len = tab_manager.sessions.len();
+    match key.code {
+        KeyCode::Tab | KeyCode::Char('j') | KeyCode::Down => {
+            tab_manager.recent_tabs.select_next(len);
+        }
+        KeyCode::BackTab | KeyCode::Char('k') | KeyCode::Up => {
+            tab_manager.recent_tabs.select_prev(len);
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            tab_manager.accept_recent_tab();
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            return true;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            tab_manager.recent_tabs.close();
+        }
+        _ => {}
+    }
+    false
+}
@@ -0,0 +1,173 @@
+//! Input handling for the review modal: switching reviews, scrolling, and
+//! selecting lines to annotate.
+
+use crate::phases::feedback_items::AnnotationStance;
+use crate::tui::ui::review_modal_lines;
+use crate::tui::ui::util::{compute_wrapped_line_count, parse_markdown_line};
+use crate::tui::Session;
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Compute the max scroll for the review modal based on wrapped lines and terminal size.
+pub(crate) fn compute_review_modal_max_scroll(session: &Session) -> usize {
+    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let (inner_width, visible_height) = compute_review_modal_inner_size(term_width, term_height);
+
+    let (content_lines, _) = review_modal_lines(session);
+    let total_lines = compute_wrapped_line_count(&content_lines, inner_width);
+
+    total_lines.saturating_sub(visible_height as usize)
+}
+
+/// Compute the visible height of the review modal for page scrolling.
+fn compute_review_modal_visible_height() -> usize {
+    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let (_, visible_height) = compute_review_modal_inner_size(term_width, term_height);
+    visible_height as usize
+}
+
+/// Compute the available width for review modal tabs.
+/// Returns inner width of title block for tab rendering.
+///
+/// The title block is: popup_width (80% of terminal) minus 2 for borders.
+/// This matches the actual rendering in draw_review_modal() where
+/// the title Paragraph is rendered inside a Block with Borders::ALL.
+fn compute_review_modal_tab_width() -> usize {
+    let (term_width, _) = crossterm::terminal::size().unwrap_or((80, 24));
+    let popup_width = (term_width as f32 * 0.8) as u16;
+    // Title block inner width = popup_width - 2 (left and right borders)
+    popup_width.saturating_sub(2) as usize
+}
+
+/// Compute the inner dimensions of the review modal.
+/// Returns (inner_width, visible_height) for content area.
+fn compute_review_modal_inner_size(term_width: u16, term_height: u16) -> (u16, u16) {
+    let popup_width = (term_width as f32 * 0.8) as u16;
+    let popup_height = (term_height as f32 * 0.8) as u16;
+
+    // Vertical layout: Title (3) + Content (min 0) + Instructions (3)
+    // Content block has borders (2), so inner_height = content_chunk_height - 2
+    // Title and instructions take 6 lines total
+    let content_chunk_height = popup_height.saturating_sub(6);
+    let inner_height = content_chunk_height.saturating_sub(2);
+
+    // Content block has borders (2)
+    let inner_width = popup_width.saturating_sub(2);
+
+    (inner_width, inner_height)
+}
+
+/// Content line shown at the top of the modal, where line selection starts.
+///
+/// Notes below lines are not counted; the cursor is scrolled into view afterwards.
+fn first_visible_review_line(session: &Session) -> usize {
+    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let (inner_width, _) = compute_review_modal_inner_size(term_width, term_height);
+    let scroll = session
+        .review_modal_scroll
+        .min(compute_review_modal_max_scroll(session));
+    let content = session.current_review_content();
+    let mut rows = 0;
+    for (i, text) in content.lines().enumerate() {
+        if rows >= scroll {
+            return i;
+        }
+        rows += compute_wrapped_line_count(&[parse_markdown_line(text)], inner_width).max(1);
+    }
+    0
+}
+
+/// Scrolls the modal so the selected line (and any note below it) is visible.
+fn keep_review_cursor_visible(session: &mut Session) {
+    let (term_width, term_height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let (inner_width, visible_height) = compute_review_modal_inner_size(term_width, term_height);
+    let (lines, Some(cursor)) = review_modal_lines(session) else {
+        return;
+    };
+    let top = compute_wrapped_line_count(&lines[..cursor], inner_width);
+    let bottom = compute_wrapped_line_count(&lines[..(cursor + 2).min(lines.len())], inner_width);
+    let visible_height = visible_height as usize;
+    if top < session.review_modal_scroll {
+        session.review_modal_scroll = top;
+    } else if bottom > session.review_modal_scroll + visible_height {
+        session.review_modal_scroll = bottom.saturating_sub(visible_height).min(top);
+    }
+}
+
+/// Handles a key press while the review modal is open.
+pub fn handle_review_modal_input(key: KeyEvent, session: &mut Session) {
+    if session.review_annotation_input.is_some() {
+        match key.code {
+            KeyCode::Enter => session.submit_review_annotation(),
+            KeyCode::Esc => session.cancel_review_annotation(),
+            KeyCode::Backspace => session.review_annotation_backspace(),
+            KeyCode::Char(c) => session.review_annotation_insert_char(c),
+            _ => {}
+        }
+        keep_review_cursor_visible(session);
+        return;
+    }
+
+    if session.review_modal_cursor.is_some() {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('s') => session.review_modal_cursor = None,
+            KeyCode::Char('j') | KeyCode::Down => session.review_cursor_down(),
+            KeyCode::Char('k') | KeyCode::Up => session.review_cursor_up(),
+            KeyCode::Char('a') => session.start_review_annotation(AnnotationStance::Agree),
+            KeyCode::Char('d') => session.start_review_annotation(AnnotationStance::Disagree),
+            KeyCode::Char('c') => session.start_review_annotation(AnnotationStance::Context),
+            KeyCode::Char('x') => session.remove_review_annotation(),
+            KeyCode::Tab | KeyCode::Right => {
+                session.review_modal_next_tab(compute_review_modal_tab_width())
+            }
+            KeyCode::BackTab | KeyCode::Left => {
+                session.review_modal_prev_tab(compute_review_modal_tab_width())
+            }
+            _ => {}
+        }
+        keep_review_cursor_visible(session);
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('v') => {
+            session.close_review_modal();
+        }
+        KeyCode::Tab | KeyCode::Right => {
+            let tab_width = compute_review_modal_tab_width();
+            session.review_modal_next_tab(tab_width);
+        }
+        KeyCode::BackTab | KeyCode::Left => {
+            let tab_width = compute_review_modal_tab_width();
+            session.review_modal_prev_tab(tab_width);
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            let max_scroll = compute_review_modal_max_scroll(session);
+            session.review_modal_scroll_down(max_scroll);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            session.review_modal_scroll_up();
+        }
+        KeyCode::Char('g') => {
+            session.review_modal_scroll_to_top();
+        }
+        KeyCode::Char('G') => {
+            let max_scroll = compute_review_modal_max_scroll(session);
+            session.review_modal_scroll_to_bottom(max_scroll);
+        }
+        KeyCode::Char('s') => {
+            let first_line = first_visible_review_line(session);
+            session.toggle_review_line_selection(first_line);
+            keep_review_cursor_visible(session);
+        }
+        KeyCode::PageDown => {
+            let visible_height = compute_review_modal_visible_height();
+            let max_scroll = compute_review_modal_max_scroll(session);
+            session.review_modal_page_down(visible_height, max_scroll);
+        }
+        KeyCode::PageUp => {
+            let visible_height = compute_review_modal_visible_height();
+            session.review_modal_page_up(visible_height);
+        }
+        _ => {}
+    }
+}
@@ -1,11 +1,13 @@
 //! Session browser input handling.
 //!
 //! This module handles keyboard input for the session browser overlay,
-//! including navigation, resume, force-stop, and confirmation dialogs.
+//! including navigation, resume, force-stop, bulk actions on marked sessions,
+//! and confirmation dialogs.
 
 use crate::tui::session::context::{
     compute_effective_working_dir, validate_working_dir, SessionContext,
 };
+use crate::tui::session_browser::BulkAction;
 use crate::tui::{Event, InputMode, TabManager};
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -37,6 +39,41 @@ pub async fn handle_session_browser_input(
         KeyCode::Char('f') => {
             tab_manager.session_browser.toggle_filter();
         }
+        KeyCode::Char('g') => {
+            tab_manager.session_browser.toggle_group_by_repo();
+        }
+        KeyCode::Char(' ') => {
+            tab_manager.session_browser.toggle_mark_selected();
+        }
+        KeyCode::Char('u') => {
+            tab_manager.session_browser.clear_marks();
+        }
+        KeyCode::Char('a') => {
+            // Archive marked sessions (or the selected one)
+            if !tab_manager.session_browser.bulk_in_progress {
+                tab_manager
+                    .session_browser
+                    .start_bulk_confirmation(BulkAction::Archive);
+            }
+        }
+        KeyCode::Char('d') => {
+            // Delete snapshots of marked sessions (or the selected one)
+            if !tab_manager.session_browser.bulk_in_progress {
+                tab_manager
+                    .session_browser
+                    .start_bulk_confirmation(BulkAction::DeleteSnapshots);
+            }
+        }
+        KeyCode::Char('s') if !tab_manager.session_browser.marked.is_empty() => {
+            tab_manager
+                .session_browser
+                .start_bulk_confirmation(BulkAction::ForceStop);
+        }
+        KeyCode::Enter if !tab_manager.session_browser.marked.is_empty() => {
+            tab_manager
+                .session_browser
+                .start_bulk_confirmation(BulkAction::Resume);
+        }
         KeyCode::Char('s') => {
             // Force-stop the selected session (with confirmation)
             if let Some(entry) = tab_manager.session_browser.selected_entry().cloned() {
@@ -118,6 +155,37 @@ async fn handle_confirmation_input(
                         output_tx,
                     );
                 }
+                Some(ConfirmationState::Bulk {
+                    action,
+                    session_ids,
+                    ..
+                }) => match action {
+                    BulkAction::Resume => {
+                        execute_bulk_resume(tab_manager, &session_ids, working_dir, output_tx);
+                    }
+                    BulkAction::ForceStop => {
+                        execute_bulk_force_stop(tab_manager, &session_ids, working_dir, output_tx)
+                            .await;
+                    }
+                    BulkAction::Archive => {
+                        trigger_bulk_removal(
+                            tab_manager,
+                            session_ids,
+                            true,
+                            working_dir,
+                            output_tx,
+                        );
+                    }
+                    BulkAction::DeleteSnapshots => {
+                        trigger_bulk_removal(
+                            tab_manager,
+                            session_ids,
+                            false,
+                            working_dir,
+                            output_tx,
+                        );
+                    }
+                },
                 None => {}
             }
             tab_manager.session_browser.cancel_confirmation();
@@ -155,6 +223,141 @@ async fn execute_force_stop(
     }
 }
 
+/// Force-stop several sessions, then refresh the list.
+async fn execute_bulk_force_stop(
+    tab_manager: &mut TabManager,
+    session_ids: &[String],
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    let client = crate::session_daemon::RpcClient::new(false).await;
+    if !client.is_connected() {
+        tab_manager.session_browser.error = Some("Daemon not connected".to_string());
+        return;
+    }
+
+    let mut failures = Vec::new();
+    for session_id in session_ids {
+        if let Err(e) = client.force_stop(session_id).await {
+            failures.push(format!("{}: {}", session_id, e));
+        }
+    }
+    tab_manager.session_browser.clear_marks();
+    trigger_refresh(tab_manager, working_dir, output_tx);
+    if let Some(first) = failures.first() {
+        tab_manager.session_browser.error = Some(format!(
+            "Force-stop failed for {} session(s): {}",
+            failures.len(),
+            first
+        ));
+    }
+}
+
+/// Resume several sessions, each in its own tab.
+///
+/// Sessions from other directories resume in their own working directory
+/// without the per-session cross-directory prompt; the bulk confirmation covers them.
+fn execute_bulk_resume(
+    tab_manager: &mut TabManager,
+    session_ids: &[String],
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    // Resuming closes the browser and clears its entries, so collect them first
+    let entries: Vec<_> = session_ids
+        .iter()
+        .filter_map(|id| {
+            tab_manager
+                .session_browser
+                .entries
+                .iter()
+                .find(|e| &e.session_id == id)
+                .cloned()
+        })
+        .collect();
+
+    let mut failures = Vec::new();
+    for entry in &entries {
+        tab_manager.session_browser.error = None;
+        resume_session_in_current_process(tab_manager, entry, working_dir, output_tx);
+        if let Some(err) = tab_manager.session_browser.error.take() {
+            failures.push(format!("{}: {}", entry.feature_name, err));
+        }
+    }
+
+    if tab_manager.session_browser.open {
+        // Nothing resumed; keep the browser open with the first failure
+        tab_manager.session_browser.error = failures.first().cloned();
+    } else if let Some(first) = failures.first() {
+        tab_manager.active_mut().add_output(format!(
+            "[planning] Could not resume {} session(s): {}",
+            failures.len(),
+            first
+        ));
+    }
+}
+
+/// Archive or delete the snapshots of several sessions in the background.
+///
+/// Archiving exports each session to `~/.planning-agent/archive/` before deleting
+/// its snapshot. The list is refreshed once all sessions are processed.
+fn trigger_bulk_removal(
+    tab_manager: &mut TabManager,
+    session_ids: Vec<String>,
+    archive: bool,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    tab_manager.session_browser.bulk_in_progress = true;
+    tab_manager.session_browser.error = None;
+    tab_manager.session_browser.clear_marks();
+
+    let wd = working_dir.to_path_buf();
+    let tx = output_tx.clone();
+
+    tokio::spawn(async move {
+        let mut removed = 0;
+        let mut failures = Vec::new();
+        for session_id in &session_ids {
+            let result = if archive {
+                archive_session(session_id).await
+            } else {
+                crate::session_daemon::delete_snapshot(session_id)
+            };
+            match result {
+                Ok(()) => removed += 1,
+                Err(e) => failures.push(format!("{}: {}", session_id, e)),
+            }
+        }
+
+        let mut summary = if archive {
+            format!("Archived {} session(s)", removed)
+        } else {
+            format!("Deleted {} snapshot(s)", removed)
+        };
+        if let Some(first) = failures.first() {
+            summary.push_str(&format!("; {} failed ({})", failures.len(), first));
+        }
+
+        let (entries, daemon_connected, error) =
+            crate::tui::session_browser::SessionBrowserState::refresh_async(&wd).await;
+        // Receiver dropped means TUI is shutting down - safe to ignore
+        let _ = tx.send(Event::SessionBrowserRefreshComplete {
+            entries,
+            daemon_connected,
+            error,
+        });
+        let _ = tx.send(Event::SessionBrowserBulkComplete { summary });
+    });
+}
+
+/// Export a session to the archive directory, then delete its snapshot.
+async fn archive_session(session_id: &str) -> anyhow::Result<()> {
+    let archive_dir = crate::planning_paths::archive_dir()?;
+    export_session_zip_async(session_id, &archive_dir).await?;
+    crate::session_daemon::delete_snapshot(session_id)
+}
+
 /// Execute cross-directory resume in the current process.
 ///
 /// This function resumes a session from a different directory using session context
@@ -196,6 +399,7 @@ fn execute_cross_directory_resume_in_process(
                 last_seen_at: String::new(),
                 last_seen_relative: String::new(),
                 working_dir: target_dir.to_path_buf(),
+                repository: crate::tui::session_browser::repository_root(target_dir),
                 is_current_dir: false,
                 has_snapshot: true, // Assume snapshot exists since we're trying to resume
                 is_resumable: true,
@@ -207,12 +411,9 @@ fn execute_cross_directory_resume_in_process(
     }
 }
 
-/// Resume a session in the current process.
+/// Resume a session in the current process from the session browser.
 ///
-/// This function now supports both same-directory and cross-directory resume
-/// by creating a SessionContext with the appropriate working directories.
-/// Workflow config is loaded from the snapshot's stored workflow name to ensure
-/// the resumed session uses the same workflow that was originally used.
+/// Failures are shown in the browser's error line.
 fn resume_session_in_current_process(
     tab_manager: &mut TabManager,
     entry: &crate::tui::session_browser::SessionEntry,
@@ -220,112 +421,130 @@ fn resume_session_in_current_process(
     output_tx: &mpsc::UnboundedSender<Event>,
 ) {
     tab_manager.session_browser.resuming = true;
-
-    // Validate that the base working directory exists
-    if let Err(err) = validate_working_dir(&entry.working_dir) {
+    if let Err(err) = resume_snapshot_in_new_tab(
+        tab_manager,
+        &entry.session_id,
+        &entry.feature_name,
+        &entry.working_dir,
+        entry.is_current_dir,
+        output_tx,
+    ) {
         tab_manager.session_browser.error = Some(err);
         tab_manager.session_browser.resuming = false;
-        return;
     }
+}
 
-    // Load the snapshot
-    let load_result = crate::session_daemon::load_snapshot(&entry.session_id);
-
-    match load_result {
-        Ok(snapshot) => {
-            // Load workflow config from snapshot's stored workflow name
-            // This ensures the resumed session uses the same workflow that was originally used
-            let workflow_config =
-                crate::app::tui_runner::workflow_loading::load_workflow_from_snapshot(&snapshot);
+/// Resume a session snapshot in a new tab of the current process.
+///
+/// This function supports both same-directory and cross-directory resume
+/// by creating a SessionContext with the appropriate working directories.
+/// Workflow config is loaded from the snapshot's stored workflow name to ensure
+/// the resumed session uses the same workflow that was originally used.
+pub(crate) fn resume_snapshot_in_new_tab(
+    tab_manager: &mut TabManager,
+    session_id: &str,
+    feature_name: &str,
+    working_dir: &Path,
+    is_current_dir: bool,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) -> Result<(), String> {
+    // Validate that the base working directory exists
+    validate_working_dir(working_dir)?;
 
-            // Close the browser first to release the borrow
-            tab_manager.session_browser.close();
+    // Load the snapshot
+    let snapshot = crate::session_daemon::load_snapshot(session_id)
+        .map_err(|e| format!("Failed to load: {}", e))?;
+
+    // Load workflow config from snapshot's stored workflow name
+    // This ensures the resumed session uses the same workflow that was originally used
+    let workflow_config =
+        crate::app::tui_runner::workflow_loading::load_workflow_from_snapshot(&snapshot);
+
+    // Close the browser first to release the borrow
+    tab_manager.session_browser.close();
+
+    // Create a new tab for the resumed session
+    let session = tab_manager.add_session_with_name(feature_name.to_string());
+    let tab_id = session.id;
+
+    // Restore the session from snapshot
+    let restored_view = snapshot.workflow_view.clone();
+    *session =
+        crate::tui::Session::from_ui_state(snapshot.ui_state.clone(), Some(restored_view.clone()));
+    session.id = tab_id;
+    session.adjust_start_time_for_previous_elapsed(snapshot.total_elapsed_before_resume_ms);
+
+    // Compute effective_working_dir from worktree_info if present
+    let effective_working_dir =
+        compute_effective_working_dir(&snapshot.working_dir, restored_view.worktree_info());
+
+    // Create and set session context BEFORE starting the workflow
+    let context = SessionContext::from_snapshot(
+        snapshot.working_dir.clone(),
+        snapshot.state_path.clone(),
+        restored_view.worktree_info(),
+        workflow_config.clone(),
+    );
+    session.context = Some(context);
+
+    // Log resume information
+    session.add_output(format!("[planning] Resumed session: {}", session_id));
+    let feature_name = restored_view
+        .feature_name()
+        .map(|f| f.0.as_str())
+        .unwrap_or("<unknown>");
+    let phase = restored_view
+        .planning_phase()
+        .unwrap_or(crate::domain::types::Phase::Planning);
+    let iteration = restored_view.iteration().map(|i| i.0).unwrap_or(1);
+    session.add_output(format!(
+        "[planning] Feature: {}, Phase: {:?}, Iteration: {}",
+        feature_name, phase, iteration
+    ));
+    for note in &snapshot.integrity_notes {
+        session.add_output(format!("[planning] Warning: {}", note));
+    }
 
-            // Create a new tab for the resumed session
-            let session = tab_manager.add_session_with_name(entry.feature_name.clone());
-            let session_id = session.id;
-
-            // Restore the session from snapshot
-            let restored_view = snapshot.workflow_view.clone();
-            *session = crate::tui::Session::from_ui_state(
-                snapshot.ui_state.clone(),
-                Some(restored_view.clone()),
-            );
-            session.id = session_id;
-            session.adjust_start_time_for_previous_elapsed(snapshot.total_elapsed_before_resume_ms);
-
-            // Compute effective_working_dir from worktree_info if present
-            let effective_working_dir =
-                compute_effective_working_dir(&snapshot.working_dir, restored_view.worktree_info());
-
-            // Create and set session context BEFORE starting the workflow
-            let context = SessionContext::from_snapshot(
-                snapshot.working_dir.clone(),
-                snapshot.state_path.clone(),
-                restored_view.worktree_info(),
-                workflow_config.clone(),
-            );
-            session.context = Some(context);
-
-            // Log resume information
-            session.add_output(format!("[planning] Resumed session: {}", entry.session_id));
-            let feature_name = restored_view
-                .feature_name()
-                .map(|f| f.0.as_str())
-                .unwrap_or("<unknown>");
-            let phase = restored_view
-                .planning_phase()
-                .unwrap_or(crate::domain::types::Phase::Planning);
-            let iteration = restored_view.iteration().map(|i| i.0).unwrap_or(1);
-            session.add_output(format!(
-                "[planning] Feature: {}, Phase: {:?}, Iteration: {}",
-                feature_name, phase, iteration
-            ));
-
-            // Log working directory info if cross-directory or using worktree
-            if !entry.is_current_dir {
-                session.add_output(format!(
-                    "[planning] Base directory: {}",
-                    snapshot.working_dir.display()
-                ));
-            }
-            if effective_working_dir != snapshot.working_dir {
-                session.add_output(format!(
-                    "[planning] Working in worktree: {}",
-                    effective_working_dir.display()
-                ));
-            }
+    // Log working directory info if cross-directory or using worktree
+    if !is_current_dir {
+        session.add_output(format!(
+            "[planning] Base directory: {}",
+            snapshot.working_dir.display()
+        ));
+    }
+    if effective_working_dir != snapshot.working_dir {
+        session.add_output(format!(
+            "[planning] Working in worktree: {}",
+            effective_working_dir.display()
+        ));
+    }
 
-            // Set up for workflow continuation
-            session.input_mode = InputMode::Normal;
-            session.total_cost = snapshot.ui_state.total_cost;
+    // The resume log lines above force follow mode; keep the saved position
+    session.output_scroll = snapshot.ui_state.output_scroll.clone();
 
-            // Start the actual workflow
-            let input = if let Some(workflow_id) = restored_view.workflow_id() {
-                crate::domain::WorkflowInput::Resume(crate::domain::ResumeWorkflowInput {
-                    workflow_id: workflow_id.clone(),
-                })
-            } else {
-                tab_manager.session_browser.error =
-                    Some("Failed to resume: workflow ID missing from snapshot".to_string());
-                tab_manager.session_browser.resuming = false;
-                return;
-            };
+    // Set up for workflow continuation
+    session.input_mode = InputMode::Normal;
+    session.total_cost = snapshot.ui_state.total_cost;
+    session.cost_estimated = snapshot.ui_state.cost_estimated;
 
-            super::super::workflow_lifecycle::start_resumed_workflow(
-                session,
-                input,
-                restored_view,
-                &snapshot.working_dir,
-                &workflow_config,
-                output_tx,
-            );
-        }
-        Err(e) => {
-            tab_manager.session_browser.error = Some(format!("Failed to load: {}", e));
-            tab_manager.session_browser.resuming = false;
-        }
-    }
+    // Start the actual workflow
+    let input = if let Some(workflow_id) = restored_view.workflow_id() {
+        crate::domain::WorkflowInput::Resume(crate::domain::ResumeWorkflowInput::new(
+            workflow_id.clone(),
+        ))
+    } else {
+        return Err("Failed to resume: workflow ID missing from snapshot".to_string());
+    };
+
+    super::super::workflow_lifecycle::start_resumed_workflow(
+        session,
+        input,
+        restored_view,
+        &snapshot.working_dir,
+        &workflow_config,
+        output_tx,
+    );
+    Ok(())
 }
 
 /// Trigger an async refresh of the session browser.
@@ -381,10 +600,10 @@ fn trigger_zip_export(
 ///
 /// The ZIP is named `{YYYYMMDD}_{session-id}.zip` and placed in `output_dir`.
 ///
-/// Note: We construct the session path manually instead of using `planning_paths::session_dir()`
-/// because that function auto-creates directories via `create_dir_all`, which would prevent
-/// us from detecting non-existent sessions.
-async fn export_session_zip_async(
+/// Note: We construct the session path from `planning_paths::sessions_dir()` instead of using
+/// `planning_paths::session_dir()` because the latter auto-creates directories via
+/// `create_dir_all`, which would prevent us from detecting non-existent sessions.
+pub(crate) async fn export_session_zip_async(
     session_id: &str,
     output_dir: &Path,
 ) -> anyhow::Result<std::path::PathBuf> {
@@ -394,12 +613,7 @@ async fn export_session_zip_async(
 
     // Construct session path manually to avoid auto-creation.
     // planning_paths::session_dir() calls create_dir_all, so we can't use it for existence checks.
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-    let session_dir = home_dir
-        .join(".planning-agent")
-        .join("sessions")
-        .join(session_id);
+    let session_dir = crate::planning_paths::sessions_dir()?.join(session_id);
 
     if !session_dir.exists() {
         anyhow::bail!("Session directory not found: {}", session_dir.display());
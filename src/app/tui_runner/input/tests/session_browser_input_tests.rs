@@ -41,7 +41,7 @@ async fn test_export_session_zip_success() {
     let output_dir = tempdir().expect("Failed to create output dir");
 
     // Mock home_dir by directly testing the add_directory_to_zip function
-    // since export_session_zip_async uses dirs::home_dir() which we can't mock
+    // since export_session_zip_async resolves the real planning home directory
     let zip_name = format!(
         "{}_{}.zip",
         chrono::Local::now().format("%Y%m%d"),
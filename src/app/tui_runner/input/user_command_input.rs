@@ -0,0 +1,180 @@
+//! User-defined slash commands from the workflow config's `slash_commands`.
+//!
+//! A command runs its shell snippet in the session's working directory. Output
+//! streams into a run tab named after the command, and with `inject_output` the
+//! finished output is sent to the running workflow, which records it in the
+//! event log for the next planner or implementer prompt.
+
+use crate::tui::{Event, Session, SessionEventSender, WorkflowUpdate};
+use std::path::PathBuf;
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+/// Output lines handed to the agent when a command's output is injected.
+const INJECTED_OUTPUT_LINES: usize = 200;
+
+/// Names of the session's user commands, with their leading `/`.
+pub fn user_command_names(session: &Session) -> Vec<String> {
+    session
+        .context
+        .as_ref()
+        .map(|ctx| {
+            ctx.workflow_config
+                .slash_commands
+                .keys()
+                .map(|name| format!("/{}", name))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Runs `command_line` if its command is one of the session's user commands.
+///
+/// Returns false when the session's config defines no such command.
+pub fn run_user_command(
+    session: &mut Session,
+    command_line: &str,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) -> bool {
+    let mut parts = command_line.split_whitespace();
+    let Some(name) = parts.next().and_then(|c| c.strip_prefix('/')) else {
+        return false;
+    };
+    let args: Vec<String> = parts.map(str::to_string).collect();
+    let Some(ctx) = session.context.as_ref() else {
+        return false;
+    };
+    let Some(command) = ctx.workflow_config.slash_commands.get(name).cloned() else {
+        return false;
+    };
+    let working_dir = ctx.effective_working_dir.clone();
+    let name = name.to_string();
+
+    let script = match command.script(&args) {
+        Ok(script) => script,
+        Err(e) => {
+            session.add_output(format!("[command] /{}: {}", name, e));
+            return true;
+        }
+    };
+
+    let inject_into = if command.inject_output() {
+        let update_tx = session.workflow_update_tx.clone();
+        if update_tx.is_none() {
+            session.add_output(format!(
+                "[command] /{}: no running workflow to add the output to; it is only shown",
+                name
+            ));
+        }
+        update_tx
+    } else {
+        None
+    };
+
+    let phase = format!("/{}", name);
+    session.add_chat_message("user", &phase, command_line.to_string());
+    if let Some(tab) = session.run_tabs.iter().position(|tab| tab.phase == phase) {
+        session.active_run_tab = tab;
+    }
+    session.add_output(format!(
+        "[command] Running /{} in {}",
+        name,
+        working_dir.display()
+    ));
+
+    let sender = SessionEventSender::new(session.id, session.current_run_id, output_tx.clone());
+    tokio::spawn(run_command(
+        name,
+        script,
+        args,
+        working_dir,
+        sender,
+        inject_into,
+    ));
+    true
+}
+
+/// Runs the script with stderr merged into stdout, streaming each line to the
+/// command's run tab.
+async fn run_command(
+    name: String,
+    script: String,
+    args: Vec<String>,
+    working_dir: PathBuf,
+    sender: SessionEventSender,
+    inject_into: Option<mpsc::UnboundedSender<WorkflowUpdate>>,
+) {
+    let phase = format!("/{}", name);
+    let spawned = Command::new("sh")
+        .arg("-c")
+        .arg(format!("exec 2>&1\n{}", script))
+        .arg("sh")
+        .args(&args)
+        .current_dir(&working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            sender.send_agent_message(name, phase, format!("Failed to run: {}", e));
+            return;
+        }
+    };
+
+    let mut output = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = BufReader::new(stdout);
+        let mut buf = Vec::new();
+        while reader
+            .read_until(b'\n', &mut buf)
+            .await
+            .is_ok_and(|read| read > 0)
+        {
+            let line = String::from_utf8_lossy(&buf)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+            buf.clear();
+            sender.send_partial_text(name.clone(), phase.clone(), Some(format!("{}\n", line)));
+            output.push(line);
+        }
+    }
+    let status = match child.wait().await {
+        Ok(status) => status.to_string(),
+        Err(e) => format!("failed: {}", e),
+    };
+
+    // The finished message replaces the streamed lines
+    sender.send_agent_message(
+        name.clone(),
+        phase,
+        format!("{}\n[{}]", output.join("\n"), status),
+    );
+
+    if let Some(update_tx) = inject_into {
+        let tail = &output[output.len().saturating_sub(INJECTED_OUTPUT_LINES)..];
+        let note = format!(
+            "Output of the user's `/{}` command ({}):\n{}",
+            name,
+            status,
+            tail.join("\n")
+        );
+        if update_tx
+            .send(WorkflowUpdate::QueueCommandOutput(note))
+            .is_ok()
+        {
+            sender.send_output(format!(
+                "[command] /{} output will be added to the next planner or implementer prompt",
+                name
+            ));
+        } else {
+            sender.send_output(format!(
+                "[command] /{}: the workflow ended before the output could be added",
+                name
+            ));
+        }
+    }
+}
@@ -42,6 +42,7 @@ pub async fn handle_workflow_browser_input(
                         }
                     }
                     tab_manager.command_notice = Some(format!("Workflow set to: {}", name));
+                    tab_manager.cost_estimate = None;
                 }
                 tab_manager.workflow_browser.close();
             }
@@ -0,0 +1,308 @@
+//! Input handling for `/merge-worktree` and the conflict resolution overlay.
+
+use crate::domain::types::WorktreeState;
+use crate::git_worktree::{self, ConflictChoice, SourceSyncMethod};
+use crate::phases::implementation::{
+    build_conflict_resolution_prompt, build_merge_worktree_prompt, run_implementation_interaction,
+    IMPLEMENTATION_FOLLOWUP_PHASE,
+};
+use crate::session_daemon::session_logger::SessionLogger;
+use crate::tui::session::WorktreeGitStep;
+use crate::tui::{Event, Session, SessionEventSender};
+use crossterm::event::KeyCode;
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+
+/// Validation result for /merge-worktree command.
+/// This is extracted as a separate type to enable unit testing.
+#[derive(Debug, PartialEq)]
+pub enum MergeWorktreeValidation {
+    /// Valid worktree, ready to merge
+    Valid(WorktreeState),
+    /// No active worktree in this session
+    NoWorktree,
+    /// Worktree directory was deleted externally
+    WorktreeDeleted,
+}
+
+/// Validates worktree state for /merge-worktree command.
+/// Extracted from handler to enable unit testing.
+pub fn validate_merge_worktree(worktree_info: Option<&WorktreeState>) -> MergeWorktreeValidation {
+    let Some(wt_state) = worktree_info else {
+        return MergeWorktreeValidation::NoWorktree;
+    };
+
+    if !wt_state.worktree_path().exists() {
+        return MergeWorktreeValidation::WorktreeDeleted;
+    }
+
+    MergeWorktreeValidation::Valid(wt_state.clone())
+}
+
+/// Handles `/merge-worktree [rebase|merge]`.
+///
+/// When a sync method is given (or configured via `worktree.sync_before_merge`)
+/// and the source branch moved, the worktree branch is updated first, off the
+/// input path. Conflicts open the resolution overlay instead of sending the
+/// merge request.
+pub fn handle_merge_worktree_command(
+    session: &mut Session,
+    args: &[String],
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    let worktree_info = session
+        .workflow_view
+        .as_ref()
+        .and_then(|v| v.worktree_info());
+
+    let wt_state = match validate_merge_worktree(worktree_info) {
+        MergeWorktreeValidation::Valid(state) => state,
+        MergeWorktreeValidation::NoWorktree => {
+            session.add_output("[merge-worktree] No active worktree in this session".to_string());
+            return;
+        }
+        MergeWorktreeValidation::WorktreeDeleted => {
+            session.add_output(
+                "[merge-worktree] Error: Worktree directory no longer exists".to_string(),
+            );
+            return;
+        }
+    };
+
+    let requested_method = match args.first() {
+        Some(arg) => match SourceSyncMethod::parse(arg) {
+            Some(method) => Some(method),
+            None => {
+                session.add_output(format!(
+                    "[merge-worktree] Unknown sync method '{}' (expected rebase or merge)",
+                    arg
+                ));
+                return;
+            }
+        },
+        None => session
+            .context
+            .as_ref()
+            .and_then(|c| c.workflow_config.worktree.sync_before_merge),
+    };
+
+    let source = wt_state.source_branch().map(str::to_string);
+    if let (Some(method), Some(source)) = (requested_method, source) {
+        session.add_output(format!(
+            "[merge-worktree] Checking {} for new commits ({})...",
+            source,
+            method.label()
+        ));
+        spawn_git_step(session.id, output_tx, move || {
+            let outcome = git_worktree::sync_with_source(wt_state.worktree_path(), &source, method)
+                .map_err(|e| e.to_string());
+            WorktreeGitStep::PreMergeSync {
+                worktree: wt_state,
+                method,
+                source,
+                outcome,
+            }
+        });
+        return;
+    }
+
+    let message = build_merge_worktree_prompt(&wt_state);
+    if spawn_worktree_interaction(session, output_tx, "/merge-worktree", message) {
+        session.add_output("[merge-worktree] Sending merge request to agent...".to_string());
+    }
+}
+
+/// Handles keys while the conflict resolution overlay is open.
+pub fn handle_conflict_modal_input(
+    key: crossterm::event::KeyEvent,
+    session: &mut Session,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => session.conflict_modal_select_next(),
+        KeyCode::Char('k') | KeyCode::Up => session.conflict_modal_select_prev(),
+        KeyCode::Char('o') => resolve_selected(session, output_tx, ConflictChoice::Ours),
+        KeyCode::Char('t') => resolve_selected(session, output_tx, ConflictChoice::Theirs),
+        KeyCode::Char('e') => resolve_selected(session, output_tx, ConflictChoice::Edited),
+        KeyCode::Char('r') => {
+            if let Some((path, _)) = session.begin_conflict_git("Refreshing...") {
+                spawn_git_step(session.id, output_tx, move || {
+                    WorktreeGitStep::Refreshed(
+                        git_worktree::conflicted_files(&path).map_err(|e| e.to_string()),
+                    )
+                });
+            }
+        }
+        KeyCode::Char('a') => {
+            let Some(modal) = session.conflict_modal.as_ref() else {
+                return;
+            };
+            let pending: Vec<String> = modal
+                .files
+                .iter()
+                .filter(|f| f.resolution.is_none())
+                .map(|f| f.path.clone())
+                .collect();
+            if pending.is_empty() {
+                return;
+            }
+            let message = build_conflict_resolution_prompt(&modal.worktree, modal.method, &pending);
+            if spawn_worktree_interaction(session, output_tx, "/resolve-conflicts", message) {
+                if let Some(modal) = session.conflict_modal.as_mut() {
+                    modal.status = Some(
+                        "Agent resolving conflicts - press r to refresh when it finishes"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+        KeyCode::Enter => {
+            if !session.all_conflicts_resolved() {
+                if let Some(modal) = session.conflict_modal.as_mut() {
+                    modal.status = Some("Resolve every file before continuing".to_string());
+                }
+                return;
+            }
+            if let Some((path, method)) = session.begin_conflict_git("Continuing...") {
+                spawn_git_step(session.id, output_tx, move || {
+                    WorktreeGitStep::Continued(
+                        git_worktree::continue_source_sync(&path, method)
+                            .map_err(|e| e.to_string()),
+                    )
+                });
+            }
+        }
+        KeyCode::Esc => {
+            if let Some((path, method)) = session.begin_conflict_git("Aborting...") {
+                spawn_git_step(session.id, output_tx, move || {
+                    WorktreeGitStep::Aborted(
+                        git_worktree::abort_source_sync(&path, method).map_err(|e| e.to_string()),
+                    )
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Applies `choice` to the selected conflicted file.
+fn resolve_selected(
+    session: &mut Session,
+    output_tx: &mpsc::UnboundedSender<Event>,
+    choice: ConflictChoice,
+) {
+    let Some(file) = session.selected_conflict_file() else {
+        return;
+    };
+    if let Some((path, method)) = session.begin_conflict_git("Staging...") {
+        spawn_git_step(session.id, output_tx, move || {
+            let result = git_worktree::resolve_conflict(&path, &file, method, choice)
+                .map_err(|e| e.to_string());
+            WorktreeGitStep::Resolved {
+                path: file,
+                choice,
+                result,
+            }
+        });
+    }
+}
+
+/// Applies a finished worktree git operation to its session and sends the
+/// merge request once the sync is done.
+pub fn handle_worktree_git_done(
+    session: &mut Session,
+    step: WorktreeGitStep,
+    output_tx: &mpsc::UnboundedSender<Event>,
+) {
+    if let Some(wt_state) = session.apply_worktree_git_step(step) {
+        let message = build_merge_worktree_prompt(&wt_state);
+        if spawn_worktree_interaction(session, output_tx, "/merge-worktree", message) {
+            session.add_output("[merge-worktree] Sending merge request to agent...".to_string());
+        }
+    }
+}
+
+/// Runs a git operation on a blocking thread, so large rebases and merges do
+/// not freeze the UI, and reports its result as `Event::WorktreeGitDone`.
+fn spawn_git_step<F>(session_id: usize, output_tx: &mpsc::UnboundedSender<Event>, op: F)
+where
+    F: FnOnce() -> WorktreeGitStep + Send + 'static,
+{
+    let tx = output_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let step = op();
+        // Receiver dropped means TUI is shutting down - safe to ignore
+        let _ = tx.send(Event::WorktreeGitDone { session_id, step });
+    });
+}
+
+/// Sends a worktree-related request to the implementation agent.
+///
+/// Returns false (after reporting why) if no follow-up interaction can be started.
+fn spawn_worktree_interaction(
+    session: &mut Session,
+    output_tx: &mpsc::UnboundedSender<Event>,
+    chat_label: &str,
+    message: String,
+) -> bool {
+    if session.implementation_interaction.running {
+        session.add_output(
+            "[merge-worktree] Agent is busy - wait for the current request to finish".to_string(),
+        );
+        return false;
+    }
+    let Some(context) = session.context.clone() else {
+        session.add_output("[merge-worktree] Unavailable: missing session context".to_string());
+        return false;
+    };
+    let Some(view) = session.workflow_view.clone() else {
+        session.add_output("[merge-worktree] Unavailable: missing workflow view".to_string());
+        return false;
+    };
+    let Some(workflow_id) = view.workflow_id() else {
+        session.add_output("[merge-worktree] Unavailable: missing workflow ID".to_string());
+        return false;
+    };
+
+    let session_logger = match SessionLogger::new(&workflow_id.to_string()) {
+        Ok(logger) => Arc::new(logger),
+        Err(e) => {
+            session.add_output(format!("[merge-worktree] Failed to create logger: {}", e));
+            return false;
+        }
+    };
+
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    session.implementation_interaction.running = true;
+    session.implementation_interaction.cancel_tx = Some(cancel_tx);
+    session.add_chat_message(
+        "user",
+        IMPLEMENTATION_FOLLOWUP_PHASE,
+        chat_label.to_string(),
+    );
+
+    let working_dir = context.effective_working_dir.clone();
+    let workflow_config = context.workflow_config.clone();
+    let session_sender =
+        SessionEventSender::new(session.id, session.current_run_id, output_tx.clone());
+
+    tokio::spawn(async move {
+        let _ = run_implementation_interaction(
+            &view,
+            &workflow_config,
+            &working_dir,
+            &message,
+            session_sender,
+            session_logger,
+            cancel_rx,
+            None,
+        )
+        .await;
+    });
+
+    true
+}
+
+#[cfg(test)]
+#[path = "tests/merge_worktree_tests.rs"]
+mod tests;
@@ -1,11 +1,15 @@
 use crate::app::cli::Cli;
+use crate::app::package::resolve_package;
+use crate::app::self_update;
 use crate::app::util::extract_feature_name;
 use crate::app::workflow_common::pre_create_session_folder_with_working_dir;
+use crate::config::AgentArgs;
 use crate::domain::input::{NewWorkflowInput, WorkflowInput};
 use crate::domain::types::WorkflowId;
 
 use super::workflow_lifecycle::InitResult;
 use crate::planning_paths;
+use crate::tui::config_editor::RuntimeSettings;
 use crate::tui::mention::update_mention_state;
 use crate::tui::slash::update_slash_state;
 use crate::tui::{Event, InputMode, SessionStatus, TabManager};
@@ -15,7 +19,9 @@ use crossterm::event::{KeyCode, KeyModifiers};
 use std::path::Path;
 use tokio::sync::mpsc;
 
-use super::slash_commands::{apply_dangerous_defaults, parse_slash_command, SlashCommand};
+use super::slash_commands::{
+    apply_dangerous_defaults, apply_reviewers_command, parse_slash_command, SlashCommand,
+};
 use super::InitHandle;
 
 pub(crate) async fn handle_naming_tab_input(
@@ -36,6 +42,13 @@ pub(crate) async fn handle_naming_tab_input(
 
     // Clone file_index before getting mutable session reference
     let file_index = tab_manager.file_index.clone();
+    let max_iterations_override = tab_manager.max_iterations_override;
+    let agent_args: Vec<AgentArgs> = cli
+        .agent_args
+        .iter()
+        .chain(&tab_manager.agent_args_override)
+        .cloned()
+        .collect();
     let session = tab_manager.active_mut();
 
     // Handle @-mention dropdown navigation when active (takes priority over slash)
@@ -169,15 +182,7 @@ pub(crate) async fn handle_naming_tab_input(
 
                                 let update_tx = output_tx.clone();
                                 tokio::spawn(async move {
-                                    let result =
-                                        tokio::task::spawn_blocking(update::perform_update)
-                                            .await
-                                            .unwrap_or_else(|_| {
-                                                update::UpdateResult::InstallFailed(
-                                                    "Update task panicked".to_string(),
-                                                    false, // Not a feature-related error (task panic)
-                                                )
-                                            });
+                                    let result = self_update::perform_update().await;
                                     // Receiver dropped means TUI is shutting down - safe to ignore
                                     let _ = update_tx.send(Event::UpdateInstallFinished(result));
                                 });
@@ -221,10 +226,27 @@ pub(crate) async fn handle_naming_tab_input(
                                 }
                             });
                         }
+                        SlashCommand::Config => {
+                            let base_working_dir = session
+                                .context
+                                .as_ref()
+                                .map(|ctx| ctx.base_working_dir.clone())
+                                .unwrap_or_else(|| working_dir.to_path_buf());
+                            let settings = RuntimeSettings {
+                                max_iterations: max_iterations_override
+                                    .unwrap_or(cli.max_iterations),
+                                max_iterations_overridden: max_iterations_override.is_some(),
+                                worktree_flag: cli.worktree,
+                            };
+                            tab_manager.config_editor.open(&base_working_dir, settings);
+                        }
                         SlashCommand::Sessions => {
                             // Open the session browser overlay
                             tab_manager.session_browser.open(working_dir);
                         }
+                        SlashCommand::Recent => {
+                            tab_manager.open_recent_tabs();
+                        }
                         SlashCommand::MaxIterations(n) => {
                             if let Some(ref view) = session.workflow_view {
                                 let old_value = view.max_iterations().map(|m| m.0).unwrap_or(0);
@@ -235,10 +257,10 @@ pub(crate) async fn handle_naming_tab_input(
                                     old_value, n
                                 ));
                             } else {
-                                tab_manager.command_notice = Some(format!(
-                                    "max-iterations set to {} (no active workflow)",
-                                    n
-                                ));
+                                tab_manager.max_iterations_override = Some(n);
+                                tab_manager.cost_estimate = None;
+                                tab_manager.command_notice =
+                                    Some(format!("max-iterations set to {} for new workflows", n));
                             }
                         }
                         SlashCommand::Sequential(enabled) => {
@@ -330,6 +352,7 @@ pub(crate) async fn handle_naming_tab_input(
                                                     base_working_dir.display()
                                                 ));
                                             }
+                                            tab_manager.cost_estimate = None;
                                         }
                                     } else {
                                         tab_manager.command_error = Some(format!(
@@ -347,6 +370,74 @@ pub(crate) async fn handle_naming_tab_input(
                                     .to_string(),
                             );
                         }
+                        SlashCommand::Tail(_) => {
+                            tab_manager.command_error = Some(
+                                "/tail is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::Rename(_) => {
+                            tab_manager.command_error = Some(
+                                "/rename is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::EditObjective => {
+                            tab_manager.command_error = Some(
+                                "/edit-objective is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::ExportTab => {
+                            tab_manager.command_error = Some(
+                                "/export-tab is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::Undo => {
+                            tab_manager.command_error = Some(
+                                "/undo is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::Notes => {
+                            tab_manager.command_error = Some(
+                                "/notes is only available in a running session (press / there)"
+                                    .to_string(),
+                            );
+                        }
+                        SlashCommand::AgentArg(Some(spec)) => match AgentArgs::parse(&spec) {
+                            Ok(entry) => {
+                                tab_manager.command_notice =
+                                    Some(format!("Agent args for new workflows: {}", entry));
+                                tab_manager.agent_args_override.push(entry);
+                            }
+                            Err(e) => {
+                                tab_manager.command_error = Some(format!("/agent-arg: {:#}", e))
+                            }
+                        },
+                        SlashCommand::AgentArg(None) => {
+                            tab_manager.agent_args_override.clear();
+                            tab_manager.command_notice = Some(if cli.agent_args.is_empty() {
+                                "Agent args cleared".to_string()
+                            } else {
+                                "Agent args cleared (--agent-arg still applies)".to_string()
+                            });
+                        }
+                        SlashCommand::Reviewers(preset) => {
+                            if let Some(ref mut ctx) = session.context {
+                                match apply_reviewers_command(
+                                    &mut ctx.workflow_config,
+                                    preset.as_deref(),
+                                ) {
+                                    Ok(notice) => tab_manager.command_notice = Some(notice),
+                                    Err(e) => tab_manager.command_error = Some(e.to_string()),
+                                }
+                            } else {
+                                tab_manager.command_notice =
+                                    Some("No active workflow config".to_string());
+                            }
+                        }
                     }
                     return Ok(false);
                 }
@@ -364,12 +455,13 @@ pub(crate) async fn handle_naming_tab_input(
                 let session_id = session.id;
                 let tx = output_tx.clone();
                 let wd = working_dir.to_path_buf();
-                let max_iter = cli.max_iterations;
+                let max_iter = max_iterations_override.unwrap_or(cli.max_iterations);
 
                 // Capture worktree-related CLI flags
                 let worktree_flag = cli.worktree;
                 let custom_worktree_dir = cli.worktree_dir.clone();
                 let custom_worktree_branch = cli.worktree_branch.clone();
+                let package = cli.package.clone();
 
                 let new_init_handle = tokio::spawn(async move {
                     // Receiver dropped means TUI is shutting down - safe to ignore for all sends in this block
@@ -378,7 +470,7 @@ pub(crate) async fn handle_naming_tab_input(
                         line: "[planning] Initializing...".to_string(),
                     });
 
-                    let feature_name = extract_feature_name(&objective, Some(&tx)).await?;
+                    let feature_name = extract_feature_name(&objective, &wd, Some(&tx)).await?;
 
                     let state_path = planning_paths::state_path(&wd, &feature_name)?;
 
@@ -395,7 +487,16 @@ pub(crate) async fn handle_naming_tab_input(
                     let workflow_id = WorkflowId::new();
                     let workflow_session_id = workflow_id.to_string();
                     let mut input =
-                        NewWorkflowInput::new(feature_name.clone(), objective.clone(), max_iter);
+                        NewWorkflowInput::new(feature_name.clone(), objective.clone(), max_iter)
+                            .with_agent_args(agent_args);
+                    if let Some(watch) = cli.watch_spec() {
+                        input = input.with_replan_watch(watch);
+                    }
+                    // The working directory already is the --package directory
+                    if let Some(name) = package {
+                        let package = resolve_package(&wd, &name)?;
+                        input = input.with_package_context(package.prompt_context());
+                    }
 
                     // Set up git worktree if enabled via --worktree or workflow config
                     // CLI flag takes priority; workflow config provides a per-project default
@@ -474,14 +575,20 @@ pub(crate) async fn handle_naming_tab_input(
                                             .to_string(),
                                     });
                                 }
+                                // A targeted package is worked on at its place in the worktree
+                                let effective_dir = if input.package_context.is_some() {
+                                    info.worktree_dir_for(&wd)
+                                } else {
+                                    info.worktree_path.clone()
+                                };
                                 let wt_state = crate::domain::types::WorktreeState::new(
-                                    info.worktree_path.clone(),
+                                    info.worktree_path,
                                     info.branch_name,
                                     info.source_branch,
                                     info.original_dir,
                                 );
                                 input.worktree_info = Some(wt_state);
-                                info.worktree_path
+                                effective_dir
                             }
                             crate::git_worktree::WorktreeSetupResult::NotAGitRepo => {
                                 // Receiver dropped means TUI is shutting down - safe to ignore
@@ -1,26 +1,38 @@
+mod dir_conflicts;
 mod events;
+mod frame_pacing;
+mod idle_suspend;
 mod input;
 mod input_naming;
+mod plan_change_notice;
+pub mod recording;
 mod session_events;
+mod session_names;
 pub mod slash_commands;
 pub mod snapshot_helper;
 mod workflow_lifecycle;
 mod workflow_loading;
 
 use super::cli_usage;
+use super::package;
+use super::setup;
+use crate::agents::cost_stats;
 use crate::app::cli::Cli;
 use crate::app::util::{
-    build_resume_command, debug_log, extract_feature_name, format_window_title,
+    build_resume_command, debug_log, extract_feature_name, format_terminal_progress,
+    format_window_title,
 };
 // Re-export for submodules
 pub(crate) use crate::app::workflow::run_workflow_with_config;
+use crate::ci_logs;
 use crate::domain::input::{NewWorkflowInput, WorkflowInput};
 use crate::domain::types::WorktreeState;
 use crate::domain::view::WorkflowView;
+use crate::issue_tracker;
 use crate::planning_paths;
 use crate::tui::{
-    Event, EventHandler, InputMode, SessionStatus, TabManager, TerminalTitleManager,
-    WorkflowCommand,
+    Event, EventHandler, InputMode, SessionStatus, TabManager, TerminalProgress,
+    TerminalTitleManager, WorkflowCommand,
 };
 use crate::update;
 use anyhow::{Context, Result};
@@ -28,75 +40,50 @@ use crossterm::event::{KeyboardEnhancementFlags, PushKeyboardEnhancementFlags};
 use std::time::Duration;
 
 pub use events::process_event;
+pub(crate) use input::session_browser_input::export_session_zip_async;
 pub use workflow_lifecycle::{check_workflow_completions, handle_init_completion, InitResult};
-pub use workflow_loading::{restore_terminal, ResumableSession};
+pub use workflow_loading::{load_workflow_from_selection, restore_terminal, ResumableSession};
 
 /// Handle to the initialization task for a new session.
 /// Contains (session_id, join_handle) where join_handle resolves to InitResult.
 pub type InitHandle = Option<(usize, tokio::task::JoinHandle<Result<InitResult>>)>;
 
-/// Finds a session ID by feature name for --continue workflow support.
-///
-/// Searches through session_info.json files to find a session matching the feature name
-/// that was started from the given working directory.
-async fn find_session_by_feature_name(
-    feature_name: &str,
-    working_dir: &std::path::Path,
-) -> Result<String> {
-    let sessions_dir = planning_paths::sessions_dir()?;
-    if !sessions_dir.exists() {
-        anyhow::bail!(
-            "No sessions found. Cannot continue workflow '{}'",
-            feature_name
-        );
-    }
-
-    let mut entries: Vec<_> = std::fs::read_dir(&sessions_dir)?
-        .filter_map(|e| e.ok())
-        .collect();
-
-    // Sort by modification time (most recent first)
-    entries.sort_by(|a, b| {
-        let a_time = a.metadata().and_then(|m| m.modified()).ok();
-        let b_time = b.metadata().and_then(|m| m.modified()).ok();
-        b_time.cmp(&a_time)
-    });
-
-    for entry in entries {
-        let session_id = entry.file_name().to_string_lossy().to_string();
-        let info_path = sessions_dir.join(&session_id).join("session_info.json");
-        if !info_path.exists() {
-            continue;
-        }
+pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
+    debug_log(start, "run_tui starting");
 
-        if let Ok(content) = std::fs::read_to_string(&info_path) {
-            if let Ok(info) = serde_json::from_str::<serde_json::Value>(&content) {
-                let matches_name = info
-                    .get("feature_name")
-                    .and_then(|v| v.as_str())
-                    .is_some_and(|n| n == feature_name);
+    let working_dir = cli
+        .working_dir
+        .clone()
+        .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current directory"));
 
-                let matches_dir = info
-                    .get("working_dir")
-                    .and_then(|v| v.as_str())
-                    .is_some_and(|d| std::path::Path::new(d) == working_dir);
+    // Canonicalize working_dir for absolute paths in prompts (matching headless behavior)
+    let working_dir = std::fs::canonicalize(&working_dir).unwrap_or(working_dir);
 
-                if matches_name && matches_dir {
-                    return Ok(session_id);
-                }
-            }
-        }
-    }
+    // With --package the session works in the package's directory
+    let package = match cli.package.as_deref() {
+        Some(name) => Some(package::resolve_package(&working_dir, name)?),
+        None => None,
+    };
+    let working_dir = match &package {
+        Some(package) => package.dir.clone(),
+        None => working_dir,
+    };
 
-    anyhow::bail!(
-        "No session found for feature '{}' in directory '{}'",
-        feature_name,
-        working_dir.display()
-    )
-}
+    // Resolved before raw mode so a name shared by several sessions can be asked about
+    let continue_session_id = match cli.name.as_deref().filter(|_| cli.continue_workflow) {
+        Some(name) => Some(
+            session_names::choose_continue_session(name, &working_dir)
+                .context("Failed to find session for --continue")?,
+        ),
+        None => None,
+    };
 
-pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
-    debug_log(start, "run_tui starting");
+    // Created before entering raw mode so a bad path fails with a readable error
+    let mut ui_recorder = cli
+        .record_ui
+        .as_deref()
+        .map(recording::UiRecorder::create)
+        .transpose()?;
 
     crossterm::terminal::enable_raw_mode()?;
     debug_log(start, "raw mode enabled");
@@ -105,7 +92,8 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         stdout,
         crossterm::terminal::EnterAlternateScreen,
         crossterm::event::EnableMouseCapture,
-        crossterm::event::EnableBracketedPaste
+        crossterm::event::EnableBracketedPaste,
+        crossterm::event::EnableFocusChange
     )?;
     debug_log(start, "alternate screen entered");
 
@@ -119,6 +107,7 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             crossterm::terminal::LeaveAlternateScreen,
             crossterm::event::DisableMouseCapture,
             crossterm::event::DisableBracketedPaste,
+            crossterm::event::DisableFocusChange,
             crossterm::cursor::Show
         );
         // Call the original panic hook
@@ -147,16 +136,38 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
     let mut terminal = ratatui::Terminal::new(backend)?;
     debug_log(start, "terminal created");
 
+    // Flags that pick a workflow or a session make the wizard moot
+    let explicit_workflow = cli.config.is_some() || cli.claude || cli.resume_session.is_some();
+    let setup_workflow = if !explicit_workflow && setup::needs_setup(&working_dir) {
+        debug_log(start, "first launch, running setup wizard");
+        match setup::wizard::run_setup_wizard(&mut terminal).await {
+            Ok(path) => path,
+            Err(e) => {
+                restore_terminal(&mut terminal)?;
+                return Err(e.context("Setup wizard failed"));
+            }
+        }
+    } else {
+        None
+    };
+
     let title_manager = TerminalTitleManager::new();
     title_manager.save_title();
     title_manager.set_title("Planning Agent");
     let mut last_title = "Planning Agent".to_string();
+    let mut last_progress = TerminalProgress::Hidden;
     debug_log(start, "title manager initialized");
 
     let mut tab_manager = TabManager::new();
+    if let Some(path) = setup_workflow {
+        tab_manager.active_mut().add_output(format!(
+            "[planning] Setup saved the workflow to {} and selected it",
+            path.display()
+        ));
+    }
     let mut scroll_regions = crate::tui::ScrollableRegions::new();
     debug_log(start, "tab manager created");
-    let mut event_handler = EventHandler::new(Duration::from_millis(100));
+    let mut event_handler = EventHandler::new(frame_pacing::ACTIVE_TICK);
     debug_log(start, "event handler created");
     let output_tx = event_handler.sender();
 
@@ -212,22 +223,14 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         debug_log(start, "version info task spawned");
     }
 
-    // Spawn background file index task for @-mention auto-complete
+    // Spawn background file index task for @-mention auto-complete; it keeps
+    // watching the repository so files created later show up too
     {
-        let file_index_tx = event_handler.sender();
-        let file_index_working_dir = cli
-            .working_dir
-            .clone()
-            .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current directory"));
-        tokio::spawn(async move {
-            let index = tokio::task::spawn_blocking(move || {
-                crate::tui::file_index::build_file_index(&file_index_working_dir)
-            })
-            .await
-            .unwrap_or_else(|_| crate::tui::file_index::FileIndex::with_error());
-            // Receiver dropped means TUI is shutting down - safe to ignore
-            let _ = file_index_tx.send(Event::FileIndexReady(index));
-        });
+        tokio::spawn(crate::tui::file_index::watch_file_index(
+            working_dir.clone(),
+            load_workflow_from_selection(&working_dir).file_index,
+            event_handler.sender(),
+        ));
         debug_log(start, "file index task spawned");
     }
 
@@ -252,11 +255,15 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
     // Spawn daemon subscription task for push notifications
     {
         let daemon_tx = event_handler.sender();
+        let mut attended = event_handler.attended();
         tokio::spawn(async move {
-            use crate::session_daemon::rpc_subscription::{RpcSubscription, SubscriptionEvent};
+            use crate::session_daemon::rpc_subscription::{
+                ReconnectBackoff, RpcSubscription, SubscriptionEvent,
+            };
 
             crate::daemon_log::daemon_log("tui_runner", "subscription task started");
             let mut consecutive_failures: u32 = 0;
+            let mut backoff = ReconnectBackoff::default();
             loop {
                 crate::daemon_log::daemon_log("tui_runner", "attempting to connect...");
 
@@ -276,6 +283,7 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
                 // Try to connect and subscribe via tarpc
                 if let Some(mut subscription) = RpcSubscription::connect().await {
                     consecutive_failures = 0;
+                    backoff.reset();
                     crate::daemon_log::daemon_log(
                         "tui_runner",
                         "connected! sending DaemonReconnected event",
@@ -307,14 +315,10 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
                                 break;
                             }
                             SubscriptionEvent::WorkflowEvent { session_id, event } => {
-                                // CQRS workflow events - logged for debugging, not yet used in UI
-                                crate::daemon_log::daemon_log(
-                                    "tui_runner",
-                                    &format!(
-                                        "received workflow event for {}: {:?}",
-                                        session_id, event
-                                    ),
-                                );
+                                // Applied by tabs following the session read-only
+                                // Receiver dropped means TUI is shutting down - safe to ignore
+                                let _ = daemon_tx
+                                    .send(Event::DaemonWorkflowEvent { session_id, event });
                             }
                         }
                     }
@@ -335,8 +339,22 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
                     );
                 }
 
-                crate::daemon_log::daemon_log("tui_runner", "waiting 500ms before retry...");
-                tokio::time::sleep(Duration::from_millis(500)).await;
+                let delay = backoff.next_delay();
+                crate::daemon_log::daemon_log(
+                    "tui_runner",
+                    &format!("waiting {}ms before retry...", delay.as_millis()),
+                );
+                tokio::time::sleep(delay).await;
+
+                // Nobody is looking at the TUI: hold off until they come back
+                if !*attended.borrow() {
+                    crate::daemon_log::daemon_log("tui_runner", "TUI idle, pausing retries");
+                    while !*attended.borrow_and_update() {
+                        if attended.changed().await.is_err() {
+                            return;
+                        }
+                    }
+                }
             }
         });
         debug_log(start, "daemon subscription task spawned");
@@ -370,14 +388,6 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         debug_log(start, "periodic snapshot task spawned");
     }
 
-    let working_dir = cli
-        .working_dir
-        .clone()
-        .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current directory"));
-
-    // Canonicalize working_dir for absolute paths in prompts (matching headless behavior)
-    let working_dir = std::fs::canonicalize(&working_dir).unwrap_or(working_dir);
-
     if update::consume_update_marker() {
         tab_manager.update_notice = Some("Update installed successfully!".to_string());
         debug_log(start, "update-installed marker consumed");
@@ -432,10 +442,16 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             "[planning] Feature: {}, Phase: {}, Iteration: {}",
             feature_name, phase_str, iteration
         ));
+        for note in &snapshot.integrity_notes {
+            first_session.add_output(format!("[planning] Warning: {}", note));
+        }
         first_session.add_output("[planning] Continuing workflow...".to_string());
+        // The resume log lines above force follow mode; keep the saved position
+        first_session.output_scroll = snapshot.ui_state.output_scroll.clone();
 
         // Restore elapsed time and cost from previous resume cycles
         first_session.total_cost = snapshot.ui_state.total_cost;
+        first_session.cost_estimated = snapshot.ui_state.cost_estimated;
         first_session
             .adjust_start_time_for_previous_elapsed(snapshot.total_elapsed_before_resume_ms);
 
@@ -447,7 +463,9 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             workflow_loading::load_workflow_config_for_resume(&cli, &snapshot, start);
 
         // Create WorkflowInput for resuming
-        let workflow_input = match WorkflowInput::resume(session_id) {
+        let workflow_input = match crate::domain::ResumeWorkflowInput::from_session_id(session_id)
+            .map(|resume| WorkflowInput::Resume(resume.with_takeover(cli.takeover)))
+        {
             Ok(input) => input,
             Err(e) => {
                 restore_terminal(&mut terminal)?;
@@ -482,7 +500,7 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             &output_tx,
         );
         debug_log(start, "resume workflow started via start_resumed_workflow");
-    } else if objective.is_empty() {
+    } else if objective.is_empty() && cli.from_issue.is_none() && cli.attach_ci_run.is_none() {
         let first_session = tab_manager.active_mut();
         first_session.input_mode = InputMode::NamingTab;
         first_session.status = SessionStatus::InputPending;
@@ -495,8 +513,14 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         let init_tx = output_tx.clone();
         let init_working_dir = working_dir.clone();
         let init_objective = objective.clone();
+        let init_from_issue = cli.from_issue.clone();
+        let init_ci_run = cli.attach_ci_run.clone();
+        let init_reviewers = cli.reviewers.clone();
+        let init_agent_args = cli.agent_args.clone();
+        let init_watch = cli.watch_spec();
+        let init_package_context = package.as_ref().map(|p| p.prompt_context());
         let init_name = cli.name.clone();
-        let init_continue = cli.continue_workflow;
+        let init_continue_session = continue_session_id;
         let init_max_iterations = cli.max_iterations;
 
         // Capture worktree-related CLI flags before tokio::spawn
@@ -509,26 +533,58 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             // Receiver dropped means TUI is shutting down - safe to ignore
             let _ = init_tx.send(Event::Output("[planning] Initializing...".to_string()));
 
+            // With --from-issue, the issue title becomes the objective and any
+            // CLI objective text is kept as extra instructions
+            let (init_objective, issue_import) = match init_from_issue {
+                Some(issue_id) => {
+                    // Receiver dropped means TUI is shutting down - safe to ignore
+                    let _ = init_tx.send(Event::Output(format!(
+                        "[planning] Importing issue {}...",
+                        issue_id
+                    )));
+                    let tracker_config =
+                        load_workflow_from_selection(&init_working_dir).issue_tracker;
+                    let issue = issue_tracker::fetch_issue_async(issue_id, tracker_config).await?;
+                    (issue.objective(&init_objective), Some(issue.into_import()))
+                }
+                None => (init_objective, None),
+            };
+
+            // With --attach-ci-run, the failing job logs become planning context
+            let (init_objective, ci_context) = match init_ci_run {
+                Some(run) => {
+                    // Receiver dropped means TUI is shutting down - safe to ignore
+                    let _ = init_tx.send(Event::Output(format!(
+                        "[planning] Downloading failing CI logs for {}...",
+                        run
+                    )));
+                    let failure =
+                        ci_logs::fetch_ci_failure_async(run, init_working_dir.clone()).await?;
+                    let objective = if init_objective.is_empty() {
+                        failure.default_objective()
+                    } else {
+                        init_objective
+                    };
+                    (objective, Some(failure.context_markdown()))
+                }
+                None => (init_objective, None),
+            };
+
             let feature_name = if let Some(name) = init_name {
                 name
             } else {
-                extract_feature_name(&init_objective, Some(&init_tx)).await?
+                extract_feature_name(&init_objective, &init_working_dir, Some(&init_tx)).await?
             };
 
             // For --continue, find the session and resume it
             // For new workflows, create WorkflowInput::New
-            if init_continue {
+            if let Some(session_id) = init_continue_session {
                 // Receiver dropped means TUI is shutting down - safe to ignore
                 let _ = init_tx.send(Event::Output(format!(
                     "[planning] Loading existing workflow: {}",
                     feature_name
                 )));
 
-                // Find existing session by feature name
-                let session_id = find_session_by_feature_name(&feature_name, &init_working_dir)
-                    .await
-                    .context("Failed to find session for --continue")?;
-
                 let state_path = planning_paths::session_state_path(&session_id)?;
 
                 // Bootstrap view from event log
@@ -590,13 +646,34 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
                     "[planning] Objective: {}",
                     init_objective
                 )));
+                if let Some(warning) =
+                    session_names::collision_warning(&feature_name, &init_working_dir)
+                {
+                    let _ = init_tx.send(Event::Output(warning));
+                }
 
                 // Create new workflow input
                 let mut new_input = NewWorkflowInput::new(
                     feature_name.clone(),
                     init_objective.clone(),
                     init_max_iterations,
-                );
+                )
+                .with_agent_args(init_agent_args);
+                if let Some(issue) = issue_import {
+                    new_input = new_input.with_issue(issue);
+                }
+                if let Some(ci_context) = ci_context {
+                    new_input = new_input.with_ci_context(ci_context);
+                }
+                if let Some(preset) = init_reviewers {
+                    new_input = new_input.with_reviewer_preset(preset);
+                }
+                if let Some(context) = init_package_context {
+                    new_input = new_input.with_package_context(context);
+                }
+                if let Some(watch) = init_watch {
+                    new_input = new_input.with_replan_watch(watch);
+                }
 
                 // Generate a new workflow session ID
                 let workflow_id = crate::domain::types::WorkflowId::new();
@@ -673,14 +750,20 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
                                 ));
                             }
 
+                            // A targeted package is worked on at its place in the worktree
+                            let effective_dir = if new_input.package_context.is_some() {
+                                info.worktree_dir_for(&init_working_dir)
+                            } else {
+                                info.worktree_path.clone()
+                            };
                             let wt_state = WorktreeState::new(
-                                info.worktree_path.clone(),
+                                info.worktree_path,
                                 info.branch_name,
                                 info.source_branch,
                                 info.original_dir,
                             );
                             new_input = new_input.with_worktree(wt_state);
-                            info.worktree_path
+                            effective_dir
                         }
                         crate::git_worktree::WorktreeSetupResult::NotAGitRepo => {
                             // Receiver dropped means TUI is shutting down - safe to ignore
@@ -720,13 +803,40 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
     }
     let mut resumable_sessions: Vec<ResumableSession> = Vec::new();
     let mut quit_requested = false;
+    let idle_timeout = idle_suspend::idle_timeout(cli.idle_suspend_minutes);
+    let mut last_plan_check = std::time::Instant::now();
+    let mut last_tail_refresh = std::time::Instant::now();
+    let mut last_conflict_check = std::time::Instant::now();
 
     debug_log(start, "entering main loop");
 
     const MAX_EVENTS_PER_FRAME: usize = 50;
+    let mut pacer = frame_pacing::FramePacer::new();
 
     loop {
-        terminal.draw(|frame| crate::tui::ui::draw(frame, &tab_manager, &mut scroll_regions))?;
+        if tab_manager.cost_estimate.is_none() {
+            tab_manager.cost_estimate = Some(cost_stats::describe_estimate(
+                &load_workflow_from_selection(&working_dir),
+                tab_manager
+                    .max_iterations_override
+                    .unwrap_or(cli.max_iterations),
+            ));
+        }
+
+        let active_id = tab_manager.active().id;
+        tab_manager.recent_tabs.touch(active_id);
+        let now = std::time::Instant::now();
+        if pacer.should_draw(now) {
+            let frame = terminal
+                .draw(|frame| crate::tui::ui::draw(frame, &tab_manager, &mut scroll_regions))?;
+            pacer.drawn(now);
+            if let Some(recorder) = ui_recorder.as_mut() {
+                if let Err(e) = recorder.record(frame.buffer) {
+                    debug_log(start, &format!("UI recording stopped: {}", e));
+                    ui_recorder = None;
+                }
+            }
+        }
 
         let first_event = event_handler.next().await?;
         let mut events_to_process = vec![first_event];
@@ -738,7 +848,9 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             }
         }
 
+        let animating = frame_pacing::is_animating(&tab_manager);
         for event in events_to_process {
+            pacer.observe(&event, animating);
             if process_event(
                 event,
                 &mut tab_manager,
@@ -783,7 +895,10 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         if quit_requested {
             debug_log(start, "Quit requested, saving snapshots");
             for session in tab_manager.sessions_mut() {
-                // Save snapshot if we have workflow view
+                // Save snapshot if we have workflow view, unless another process holds it
+                if session.following_pid.is_some() {
+                    continue;
+                }
                 if let Some(ref view) = session.workflow_view {
                     let session_id = view
                         .workflow_id()
@@ -836,9 +951,15 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
             title_manager.set_title(&new_title);
             last_title = new_title;
         }
+        let new_progress = format_terminal_progress(&tab_manager);
+        if new_progress != last_progress {
+            title_manager.set_progress(new_progress);
+            last_progress = new_progress;
+        }
 
         if let Some((session_id, handle)) = init_handle.take() {
             if handle.is_finished() {
+                pacer.mark_dirty();
                 handle_init_completion(
                     session_id,
                     handle,
@@ -856,6 +977,30 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
         let completed =
             check_workflow_completions(&mut tab_manager, &working_dir, &output_tx).await;
         resumable_sessions.extend(completed);
+
+        if let Some(alert) = tab_manager.notifications.take_alert() {
+            title_manager.alert(
+                cli.notify,
+                &format!("planning: {}", alert.tab_name),
+                &alert.message,
+            );
+        }
+
+        idle_suspend::close_suspended_tabs(&mut tab_manager, &working_dir);
+        idle_suspend::suspend_idle_sessions(&mut tab_manager, idle_timeout, &working_dir);
+        if last_plan_check.elapsed() >= plan_change_notice::PLAN_CHECK_INTERVAL {
+            last_plan_check = std::time::Instant::now();
+            plan_change_notice::notify_plan_changes(&mut tab_manager);
+        }
+        if last_tail_refresh.elapsed() >= input::TAIL_REFRESH_INTERVAL {
+            last_tail_refresh = std::time::Instant::now();
+            input::refresh_tail_modals(&mut tab_manager, &working_dir);
+        }
+        if last_conflict_check.elapsed() >= dir_conflicts::CONFLICT_CHECK_INTERVAL {
+            last_conflict_check = std::time::Instant::now();
+            dir_conflicts::check_conflicts(&mut tab_manager, &output_tx);
+        }
+        event_handler.set_tick_rate(frame_pacing::tick_rate(&tab_manager));
     }
 
     debug_log(start, "Loop exited, starting cleanup");
@@ -876,6 +1021,7 @@ pub async fn run_tui(cli: Cli, start: std::time::Instant) -> Result<()> {
     debug_log(start, &format!("Aborted {} workflow handles", abort_count));
 
     debug_log(start, "Restoring title");
+    title_manager.set_progress(TerminalProgress::Hidden);
     title_manager.restore_title();
     debug_log(start, "Restoring terminal");
     restore_terminal(&mut terminal)?;
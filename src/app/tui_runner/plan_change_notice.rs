@@ -0,0 +1,36 @@
+//! "Plan changed on disk" notices for sessions waiting on the user.
+//!
+//! While a workflow waits at a prompt it can't notice edits to the plan file, so
+//! the TUI polls the plan's modification time and tells the user their edits were
+//! seen. The workflow records the edit itself once it continues.
+
+use crate::app::workflow::plan_modified_on_disk;
+use crate::tui::{SessionStatus, TabManager};
+use std::time::Duration;
+
+/// How often plan files of waiting sessions are checked.
+pub const PLAN_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Adds a notice to each waiting session whose plan file changed since the last notice.
+pub fn notify_plan_changes(tab_manager: &mut TabManager) {
+    for session in tab_manager.sessions_mut() {
+        if session.status != SessionStatus::AwaitingApproval {
+            continue;
+        }
+        let Some(modified) = session
+            .workflow_view
+            .as_ref()
+            .and_then(plan_modified_on_disk)
+        else {
+            continue;
+        };
+        if session.plan_change_noticed == Some(modified) {
+            continue;
+        }
+        session.plan_change_noticed = Some(modified);
+        session.add_output(
+            "[planning] Plan changed on disk - the next review cycle will use your edits"
+                .to_string(),
+        );
+    }
+}
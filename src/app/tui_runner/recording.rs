@@ -0,0 +1,340 @@
+//! TUI session recording (`--record-ui`) and playback (`planning play`).
+//!
+//! Recordings use the asciinema v2 format: a JSON header line followed by one
+//! `[seconds, "o", data]` line per rendered frame. Each frame stores only the
+//! cells that changed since the previous one, encoded as terminal escape
+//! sequences, so recordings stay small and also play in `asciinema play`.
+//! A terminal resize is stored as an `[seconds, "r", "COLSxROWS"]` event
+//! followed by a full redraw.
+
+use anyhow::{Context, Result};
+use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEventKind};
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use serde::Deserialize;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+/// Recording format version (asciinema v2).
+const RECORDING_VERSION: u32 = 2;
+
+/// Slowest and fastest playback speeds reachable with `-` / `+`.
+const MIN_SPEED: f64 = 0.125;
+const MAX_SPEED: f64 = 64.0;
+
+/// Writes rendered TUI frames to a recording file.
+pub struct UiRecorder {
+    out: BufWriter<File>,
+    started: Instant,
+    previous: Option<Buffer>,
+}
+
+impl UiRecorder {
+    /// Creates the recording file. The header is written with the first frame,
+    /// once the terminal size is known.
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create UI recording: {}", path.display()))?;
+        Ok(Self {
+            out: BufWriter::new(file),
+            started: Instant::now(),
+            previous: None,
+        })
+    }
+
+    /// Records a rendered frame, storing only the cells that changed.
+    pub fn record(&mut self, buffer: &Buffer) -> Result<()> {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let data = match &self.previous {
+            Some(previous) if previous.area == buffer.area => encode_diff(previous, buffer),
+            Some(_) => {
+                let size = format!("{}x{}", buffer.area.width, buffer.area.height);
+                self.write_event(elapsed, "r", &size)?;
+                full_redraw(buffer)
+            }
+            None => {
+                let header = serde_json::json!({
+                    "version": RECORDING_VERSION,
+                    "width": buffer.area.width,
+                    "height": buffer.area.height,
+                    "timestamp": chrono::Utc::now().timestamp(),
+                    "title": "planning-agent",
+                });
+                writeln!(self.out, "{}", header)?;
+                full_redraw(buffer)
+            }
+        };
+        if !data.is_empty() {
+            self.write_event(elapsed, "o", &data)?;
+        }
+        // Flushed per frame so a crash still leaves a playable recording
+        self.out.flush()?;
+        self.previous = Some(buffer.clone());
+        Ok(())
+    }
+
+    fn write_event(&mut self, elapsed: f64, kind: &str, data: &str) -> Result<()> {
+        let event = serde_json::to_string(&(round_time(elapsed), kind, data))?;
+        writeln!(self.out, "{}", event)?;
+        Ok(())
+    }
+}
+
+fn round_time(seconds: f64) -> f64 {
+    (seconds * 1_000_000.0).round() / 1_000_000.0
+}
+
+/// Clears the screen and draws every non-blank cell of `buffer`.
+fn full_redraw(buffer: &Buffer) -> String {
+    format!(
+        "\x1b[0m\x1b[2J{}",
+        encode_diff(&Buffer::empty(buffer.area), buffer)
+    )
+}
+
+/// Encodes the cells that differ between two same-sized buffers as escape sequences.
+pub fn encode_diff(previous: &Buffer, next: &Buffer) -> String {
+    let mut out = String::new();
+    let mut cursor: Option<(u16, u16)> = None;
+    let mut style: Option<(Color, Color, Modifier)> = None;
+
+    for (x, y, cell) in previous.diff(next) {
+        if cursor != Some((x, y)) {
+            let _ = write!(out, "\x1b[{};{}H", y + 1, x + 1);
+        }
+        let cell_style = (cell.fg, cell.bg, cell.modifier);
+        if style != Some(cell_style) {
+            out.push_str(&sgr(cell));
+            style = Some(cell_style);
+        }
+        let symbol = cell.symbol();
+        out.push_str(symbol);
+        cursor = Some((x + symbol.width().max(1) as u16, y));
+    }
+    if style.is_some() {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Select Graphic Rendition sequence for a cell's colors and modifiers.
+fn sgr(cell: &Cell) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.push(color_code(cell.fg, false));
+    codes.push(color_code(cell.bg, true));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> String {
+    let base = |code: u8| (if background { code + 10 } else { code }).to_string();
+    let extended = if background { "48" } else { "38" };
+    match color {
+        Color::Reset => base(39),
+        Color::Black => base(30),
+        Color::Red => base(31),
+        Color::Green => base(32),
+        Color::Yellow => base(33),
+        Color::Blue => base(34),
+        Color::Magenta => base(35),
+        Color::Cyan => base(36),
+        Color::Gray => base(37),
+        Color::DarkGray => base(90),
+        Color::LightRed => base(91),
+        Color::LightGreen => base(92),
+        Color::LightYellow => base(93),
+        Color::LightBlue => base(94),
+        Color::LightMagenta => base(95),
+        Color::LightCyan => base(96),
+        Color::White => base(97),
+        Color::Indexed(i) => format!("{};5;{}", extended, i),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", extended, r, g, b),
+    }
+}
+
+/// Recording header fields needed for playback.
+#[derive(Debug, Deserialize)]
+pub struct RecordingHeader {
+    pub version: u32,
+    pub width: u16,
+    pub height: u16,
+}
+
+/// A recorded event: seconds since start, event kind and payload.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct RecordedEvent(pub f64, pub String, pub String);
+
+/// Reads a recording file into its header and events.
+pub fn read_recording(path: &Path) -> Result<(RecordingHeader, Vec<RecordedEvent>)> {
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open recording: {}", path.display()))?;
+    let mut lines = BufReader::new(file).lines();
+    let header_line = lines
+        .next()
+        .context("Recording is empty")?
+        .context("Failed to read recording header")?;
+    let header: RecordingHeader =
+        serde_json::from_str(&header_line).context("Invalid recording header")?;
+    if header.version != RECORDING_VERSION {
+        anyhow::bail!(
+            "Unsupported recording version {} (expected {})",
+            header.version,
+            RECORDING_VERSION
+        );
+    }
+
+    let mut events = Vec::new();
+    for (index, line) in lines.enumerate() {
+        let line = line.context("Failed to read recording")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // A crash mid-write can leave a truncated last line; play what came before it
+        match serde_json::from_str::<RecordedEvent>(&line) {
+            Ok(event) => events.push(event),
+            Err(e) => {
+                eprintln!(
+                    "[planning-agent] Stopping at malformed recording line {}: {}",
+                    index + 2,
+                    e
+                );
+                break;
+            }
+        }
+    }
+    Ok((header, events))
+}
+
+/// Restores the terminal when playback ends, even on error.
+struct PlaybackTerminal;
+
+impl PlaybackTerminal {
+    fn enter() -> Result<Self> {
+        crossterm::terminal::enable_raw_mode()?;
+        crossterm::execute!(
+            std::io::stdout(),
+            crossterm::terminal::EnterAlternateScreen,
+            crossterm::cursor::Hide
+        )?;
+        Ok(Self)
+    }
+}
+
+impl Drop for PlaybackTerminal {
+    fn drop(&mut self) {
+        let _ = crossterm::execute!(
+            std::io::stdout(),
+            crossterm::cursor::Show,
+            crossterm::terminal::LeaveAlternateScreen
+        );
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+/// What the viewer asked for while waiting for the next frame.
+enum PlaybackControl {
+    Continue,
+    Quit,
+}
+
+/// Replays a recording in the terminal.
+///
+/// Keys: `space` pauses, `+`/`-` double or halve the speed, `q`/`Esc` quits.
+/// Gaps between frames are capped at `max_idle` seconds (before speed scaling).
+pub fn play_recording(path: PathBuf, speed: f64, max_idle: f64) -> Result<()> {
+    if speed <= 0.0 {
+        anyhow::bail!("Playback speed must be positive");
+    }
+    let (header, events) = read_recording(&path)?;
+    let (cols, rows) = crossterm::terminal::size().unwrap_or((0, 0));
+    if cols < header.width || rows < header.height {
+        eprintln!(
+            "[planning-agent] Recording is {}x{} but the terminal is {}x{}; output may wrap",
+            header.width, header.height, cols, rows
+        );
+    }
+
+    let _terminal = PlaybackTerminal::enter()?;
+    let mut stdout = std::io::stdout();
+    let mut speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+    let mut last_time = 0.0;
+
+    for RecordedEvent(time, kind, data) in events {
+        let gap = (time - last_time).clamp(0.0, max_idle.max(0.0));
+        last_time = time;
+        if let PlaybackControl::Quit = wait_for_frame(gap, &mut speed)? {
+            return Ok(());
+        }
+        match kind.as_str() {
+            "o" => stdout.write_all(data.as_bytes())?,
+            "r" => stdout.write_all(b"\x1b[0m\x1b[2J")?,
+            _ => {}
+        }
+        stdout.flush()?;
+    }
+
+    // Keep the final frame on screen until the viewer leaves
+    loop {
+        if let CrosstermEvent::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Waits `gap` recorded seconds (scaled by `speed`) while handling playback keys.
+fn wait_for_frame(gap: f64, speed: &mut f64) -> Result<PlaybackControl> {
+    let mut remaining = gap;
+    let mut paused = false;
+    loop {
+        let timeout = if paused {
+            Duration::from_millis(250)
+        } else if remaining <= 0.0 {
+            return Ok(PlaybackControl::Continue);
+        } else {
+            Duration::from_secs_f64(remaining / *speed)
+        };
+        let waited_from = Instant::now();
+        if event::poll(timeout)? {
+            if let CrosstermEvent::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(PlaybackControl::Quit),
+                        KeyCode::Char(' ') => paused = !paused,
+                        KeyCode::Char('+') | KeyCode::Char('=') => {
+                            *speed = (*speed * 2.0).min(MAX_SPEED)
+                        }
+                        KeyCode::Char('-') => *speed = (*speed / 2.0).max(MIN_SPEED),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        if !paused {
+            remaining -= waited_from.elapsed().as_secs_f64() * *speed;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/recording_tests.rs"]
+mod tests;
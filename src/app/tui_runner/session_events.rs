@@ -5,7 +5,11 @@
 
 use crate::app::util::shorten_model_name;
 use crate::session_daemon;
-use crate::tui::{Event, TabManager, ToolKind, ToolTimelineEntry};
+use crate::tui::session::{AgentStderr, CompletionSummary};
+use crate::tui::ui::util::truncate_to_width;
+use crate::tui::{
+    ApprovalMode, Event, NotificationKind, Session, TabManager, ToolKind, ToolTimelineEntry,
+};
 use crate::update;
 use anyhow::Result;
 use std::path::Path;
@@ -24,13 +28,7 @@ pub async fn handle_session_event(
     match event {
         Event::SessionOutput { session_id, line } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
-                if line.contains("Cost: $") {
-                    if let Some(cost_str) = line.split('$').nth(1) {
-                        if let Ok(cost) = cost_str.trim().parse::<f64>() {
-                            session.total_cost += cost;
-                        }
-                    }
-                }
+                session.record_cost_output(&line);
                 session.add_output(line);
             }
         }
@@ -55,6 +53,11 @@ pub async fn handle_session_event(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_approval(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::ApprovalRequired,
+                "Plan ready for approval".to_string(),
+            );
         }
         Event::SessionReviewDecisionRequest {
             session_id,
@@ -63,6 +66,11 @@ pub async fn handle_session_event(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_review_decision(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::ApprovalRequired,
+                "Review needs a decision".to_string(),
+            );
         }
         Event::SessionReviewRoundStarted {
             session_id,
@@ -107,6 +115,17 @@ pub async fn handle_session_event(
                 session.reviewer_failed(kind, round, display_id, error);
             }
         }
+        Event::SessionReviewerSubstituted {
+            session_id,
+            kind,
+            round,
+            failed_id,
+            substitute_id,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.reviewer_substituted(kind, round, failed_id, substitute_id);
+            }
+        }
         Event::SessionReviewRoundCompleted {
             session_id,
             kind,
@@ -164,7 +183,13 @@ pub async fn handle_session_event(
             }
         }
         Event::SessionPhaseStarted { session_id, phase } => {
+            let mut completed_phase = None;
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                completed_phase = session
+                    .current_phase_start
+                    .as_ref()
+                    .map(|(previous, _)| previous.clone())
+                    .filter(|previous| *previous != phase);
                 session.start_phase(phase);
 
                 // Save snapshot on phase transition (natural checkpoint for recovery)
@@ -184,6 +209,13 @@ pub async fn handle_session_event(
                     }
                 }
             }
+            if let Some(previous) = completed_phase {
+                tab_manager.notify(
+                    session_id,
+                    NotificationKind::PhaseComplete,
+                    format!("{} complete", previous),
+                );
+            }
         }
         Event::SessionTurnCompleted { session_id } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
@@ -235,10 +267,38 @@ pub async fn handle_session_event(
                 session.last_stop_reason = Some(reason);
             }
         }
+        Event::SessionToolPermissionRequest {
+            session_id,
+            request,
+        } => {
+            // Dropping the request for a closed tab denies the tool call
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.queue_tool_permission(request);
+            }
+        }
+        Event::SessionWorkspaceTrustRequest {
+            session_id,
+            request,
+        } => {
+            // Dropping the request for a closed tab restricts this run
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.workspace_trust_request = Some(request);
+            }
+        }
+        Event::SessionFeedbackTriageRequest { session_id, items } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.start_feedback_triage(items);
+            }
+        }
         Event::SessionPlanGenerationFailed { session_id, error } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_plan_generation_failed(error);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::Error,
+                "Plan generation failed".to_string(),
+            );
         }
         Event::SessionMaxIterationsReached {
             session_id,
@@ -253,6 +313,11 @@ pub async fn handle_session_event(
                 ));
                 session.start_max_iterations_prompt(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::ApprovalRequired,
+                "Max iterations reached".to_string(),
+            );
         }
         Event::SessionUserOverrideApproval {
             session_id,
@@ -261,6 +326,11 @@ pub async fn handle_session_event(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_user_override_approval(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::ApprovalRequired,
+                "Override approval requested".to_string(),
+            );
         }
         Event::SessionAllReviewersFailed {
             session_id,
@@ -269,6 +339,11 @@ pub async fn handle_session_event(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_all_reviewers_failed(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::Error,
+                "All reviewers failed".to_string(),
+            );
         }
         Event::SessionWorkflowFailure {
             session_id,
@@ -277,6 +352,11 @@ pub async fn handle_session_event(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.start_workflow_failure(summary);
             }
+            tab_manager.notify(
+                session_id,
+                NotificationKind::Error,
+                "Workflow failed".to_string(),
+            );
         }
         Event::SessionAgentMessage {
             session_id,
@@ -288,6 +368,19 @@ pub async fn handle_session_event(
                 session.add_chat_message(&agent_name, &phase, message);
             }
         }
+        Event::SessionPartialText {
+            session_id,
+            agent_name,
+            phase,
+            delta,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                match delta {
+                    Some(delta) => session.append_partial_text(&agent_name, &phase, &delta),
+                    None => session.clear_partial_text(&agent_name, &phase),
+                }
+            }
+        }
         Event::SessionTodosUpdate {
             session_id,
             agent_name,
@@ -301,10 +394,23 @@ pub async fn handle_session_event(
             session_id,
             phase,
             run_id,
+            cancel_tx,
         } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 if session.current_run_id == run_id {
-                    session.set_summary_generating(&phase);
+                    session.set_summary_generating(&phase, cancel_tx);
+                }
+            }
+        }
+        Event::SessionRunTabSummaryDelta {
+            session_id,
+            phase,
+            delta,
+            run_id,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                if session.current_run_id == run_id {
+                    session.append_summary_text(&phase, &delta);
                 }
             }
         }
@@ -346,6 +452,11 @@ pub async fn handle_session_event(
         Event::FileIndexReady(index) => {
             tab_manager.file_index = index;
         }
+        Event::FileIndexUpdated(delta) => {
+            tab_manager
+                .file_index
+                .apply_delta(delta, std::time::Instant::now());
+        }
         Event::SlashCommandResult {
             command,
             summary,
@@ -362,6 +473,9 @@ pub async fn handle_session_event(
                 tab_manager.command_error = None;
             }
         }
+        Event::DirConflictsChecked { conflicts } => {
+            super::dir_conflicts::apply_conflicts(tab_manager, conflicts);
+        }
         Event::SessionBrowserRefreshComplete {
             entries,
             daemon_connected,
@@ -385,9 +499,33 @@ pub async fn handle_session_event(
                 tab_manager.session_browser.error = Some(format!("Exported: {}", filename));
             }
         }
+        Event::SessionBrowserBulkComplete { summary } => {
+            tab_manager.session_browser.bulk_in_progress = false;
+            tab_manager.session_browser.error = Some(summary);
+        }
         Event::DaemonSessionChanged(record) => {
+            // This process's own record is the run a lost lease is winding down
+            let pid = record.pid;
+            let stopped = record.liveness == session_daemon::LivenessState::Stopped;
+            let tabs = following_sessions(tab_manager, &record.workflow_session_id);
+            for session in tabs.filter(|_| pid != std::process::id()) {
+                if stopped && session.following_pid == Some(pid) {
+                    session.add_output(format!(
+                        "[planning] PID {} released the session; resume it to continue",
+                        pid
+                    ));
+                } else if !stopped && session.following_pid != Some(pid) {
+                    session.following_pid = Some(pid);
+                    session.add_output(format!("[planning] Now following PID {}", pid));
+                }
+            }
             tab_manager.session_browser.apply_session_update(record);
         }
+        Event::DaemonWorkflowEvent { session_id, event } => {
+            for session in following_sessions(tab_manager, &session_id) {
+                session.apply_followed_event(&event);
+            }
+        }
         Event::DaemonDisconnected => {
             tab_manager.session_browser.daemon_connected = false;
             tab_manager.daemon_connected = false;
@@ -419,6 +557,25 @@ pub async fn handle_session_event(
                 session.cli_instance_activity(id, activity_at);
             }
         }
+        Event::SessionCliInstanceResources {
+            session_id,
+            id,
+            usage,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.cli_instance_resources(id, usage);
+            }
+        }
+        Event::SessionCliInstanceQueued {
+            session_id,
+            id,
+            agent_name,
+            queued_at,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.cli_instance_queued(id, agent_name, queued_at);
+            }
+        }
         Event::SessionCliInstanceFinished { session_id, id } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.cli_instance_finished(id);
@@ -432,6 +589,59 @@ pub async fn handle_session_event(
                 session.open_implementation_success(iterations_used);
             }
         }
+        Event::SessionAgentStderr {
+            session_id,
+            agent_name,
+            lines,
+            log_path,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.agent_stderr = Some(AgentStderr {
+                    agent_name,
+                    lines,
+                    log_path,
+                });
+            }
+        }
+        Event::SessionCompletionSummary {
+            session_id,
+            metrics,
+            outcomes,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.completion_summary = Some(CompletionSummary { metrics, outcomes });
+            }
+        }
+        Event::SessionPhaseDurationExceeded {
+            session_id,
+            limit_minutes,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.phase_overrun_minutes = Some(limit_minutes);
+            }
+        }
+        Event::SessionCostAlert {
+            session_id,
+            message,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.cost_alert = Some(message.clone());
+            }
+            tab_manager.notify(session_id, NotificationKind::BudgetAlert, message);
+        }
+        Event::SessionFollowing {
+            session_id,
+            holder_pid,
+        } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.following_pid = Some(holder_pid);
+            }
+        }
+        Event::SessionRemoteAnswer { session_id } => {
+            if let Some(session) = tab_manager.session_by_id_mut(session_id) {
+                session.approval_mode = ApprovalMode::None;
+            }
+        }
         Event::SessionImplementationInteractionFinished { session_id } => {
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 session.implementation_interaction.running = false;
@@ -443,6 +653,21 @@ pub async fn handle_session_event(
     Ok(())
 }
 
+/// Tabs following the workflow session read-only.
+fn following_sessions<'a>(
+    tab_manager: &'a mut TabManager,
+    workflow_session_id: &'a str,
+) -> impl Iterator<Item = &'a mut Session> + 'a {
+    tab_manager.sessions_mut().filter(move |session| {
+        session.following_pid.is_some()
+            && session
+                .workflow_view
+                .as_ref()
+                .and_then(|view| view.workflow_id())
+                .is_some_and(|id| id.to_string() == workflow_session_id)
+    })
+}
+
 async fn handle_update_install_finished(
     result: update::UpdateResult,
     tab_manager: &mut TabManager,
@@ -516,12 +741,7 @@ async fn handle_update_install_finished(
                     update::BUILD_FEATURES,
                 ));
             } else {
-                let short_err = if err.chars().count() > 60 {
-                    format!("{}...", err.chars().take(57).collect::<String>())
-                } else {
-                    err
-                };
-                tab_manager.update_error = Some(short_err);
+                tab_manager.update_error = Some(truncate_to_width(&err, 60));
             }
         }
         update::UpdateResult::BinaryNotFound => {
@@ -0,0 +1,146 @@
+//! Feature name collisions between sessions, and the `/rename` command.
+//!
+//! Feature names are not unique: two sessions started in the same directory can
+//! share one, which makes `--continue --name <name>` ambiguous. When it is, the
+//! user is asked which session to continue before the TUI starts; without a
+//! terminal to ask on, the matches are listed and `--resume-session` suggested.
+//! A running session can be given a new name with `/rename <name>`.
+
+use crate::domain::types::FeatureName;
+use crate::planning_paths::{self, SessionInfo};
+use crate::tui::{Session, WorkflowUpdate};
+use anyhow::Result;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::Path;
+
+/// Objective preview length in the list of matching sessions.
+const OBJECTIVE_PREVIEW_CHARS: usize = 60;
+
+/// Resolves `--continue --name <feature_name>` to a session ID, asking on stdin
+/// when several sessions in `working_dir` share the name.
+pub fn choose_continue_session(feature_name: &str, working_dir: &Path) -> Result<String> {
+    let matches = planning_paths::find_sessions_by_feature_name(feature_name, working_dir)?;
+    match matches.as_slice() {
+        [] => anyhow::bail!(
+            "No session found for feature '{}' in directory '{}'",
+            feature_name,
+            working_dir.display()
+        ),
+        [only] => Ok(only.session_id.clone()),
+        _ if std::io::stdin().is_terminal() => pick_session(
+            feature_name,
+            &matches,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stderr(),
+        ),
+        _ => anyhow::bail!(
+            "{}Continue one of them with --resume-session <id>",
+            describe_matches(feature_name, &matches)
+        ),
+    }
+}
+
+/// Lists the matching sessions and reads the number of the one to continue.
+fn pick_session(
+    feature_name: &str,
+    matches: &[SessionInfo],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<String> {
+    write!(output, "{}", describe_matches(feature_name, matches))?;
+    loop {
+        write!(output, "Continue which session? [1-{}]: ", matches.len())?;
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            anyhow::bail!("No session chosen for --continue");
+        }
+        match line.trim().parse::<usize>() {
+            Ok(n) if (1..=matches.len()).contains(&n) => {
+                return Ok(matches[n - 1].session_id.clone());
+            }
+            _ => writeln!(output, "Enter a number from 1 to {}", matches.len())?,
+        }
+    }
+}
+
+/// One numbered line per matching session, most recent first.
+fn describe_matches(feature_name: &str, matches: &[SessionInfo]) -> String {
+    let mut text = format!(
+        "{} sessions in this directory are named '{}':\n",
+        matches.len(),
+        feature_name
+    );
+    for (i, info) in matches.iter().enumerate() {
+        let mut objective: String = info
+            .objective
+            .chars()
+            .take(OBJECTIVE_PREVIEW_CHARS)
+            .collect();
+        if info.objective.chars().count() > OBJECTIVE_PREVIEW_CHARS {
+            objective.push_str("...");
+        }
+        text.push_str(&format!(
+            "  {}. {}  {} (iteration {}), updated {}\n     {}\n",
+            i + 1,
+            info.session_id,
+            info.phase.status_label(),
+            info.iteration,
+            info.updated_at,
+            objective
+        ));
+    }
+    text
+}
+
+/// Warns when a new session takes a name other sessions in `working_dir` already use.
+pub fn collision_warning(feature_name: &str, working_dir: &Path) -> Option<String> {
+    let existing = planning_paths::find_sessions_by_feature_name(feature_name, working_dir)
+        .unwrap_or_default()
+        .len();
+    (existing > 0).then(|| {
+        format!(
+            "[planning] Note: {} other session(s) here are also named '{}'; use /rename to tell them apart",
+            existing, feature_name
+        )
+    })
+}
+
+/// Turns user input into a feature name slug, like extracted names.
+pub fn normalize_session_name(name: &str) -> Option<String> {
+    name.chars()
+        .any(|c| c.is_ascii_alphanumeric())
+        .then(|| FeatureName::slugify(name).0)
+}
+
+/// Handles `/rename <name>` typed at a running session's command prompt.
+///
+/// The workflow records the new name and updates session_info.json, the daemon
+/// record and the saved snapshot; the tab and window title follow its view.
+pub fn request_rename(session: &mut Session, name: &str, working_dir: &Path) {
+    let Some(name) = normalize_session_name(name) else {
+        session.add_output("[command] Usage: /rename <new-name>".to_string());
+        return;
+    };
+    if session.feature_name() == name {
+        session.add_output(format!("[command] Session is already named '{}'", name));
+        return;
+    }
+    if collision_warning(&name, working_dir).is_some() {
+        session.add_output(format!(
+            "[command] /rename: another session in this directory is already named '{}'",
+            name
+        ));
+        return;
+    }
+    match &session.workflow_update_tx {
+        Some(tx) if tx.send(WorkflowUpdate::Rename(name.clone())).is_ok() => {
+            session.add_output(format!("[command] Renaming session to '{}'...", name));
+        }
+        _ => session.add_output("[command] /rename: the workflow is not running".to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/session_names_tests.rs"]
+mod tests;
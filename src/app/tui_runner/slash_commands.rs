@@ -1,8 +1,13 @@
 //! Slash command parsing and execution for the NamingTab input.
 //!
-//! Supports commands like `/update`, `/config-dangerous`, and `/config dangerous`.
-
-use crate::config::AggregationMode;
+//! Supports commands like `/update`, `/config`, `/config-dangerous`, `/config dangerous` and `/recent`.
+//! A running session also accepts `/tail plan`, `/tail feedback`, `/rename <name>`,
+//! `/export-tab`, `/undo` and `/notes` from its command prompt. `/reviewers [preset]` and
+//! `/review-mode sequential|parallel` work in both places. `/agent-arg AGENT=ARGS`
+//! adds per-run agent arguments to the workflows started from the NamingTab.
+
+use crate::config::{AggregationMode, WorkflowConfig};
+use crate::tui::TailTarget;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -14,8 +19,12 @@ pub enum SlashCommand {
     Update,
     /// Configure CLI tools to bypass approvals/sandbox.
     ConfigDangerous,
+    /// Open the workflow config editor.
+    Config,
     /// View and resume workflow sessions.
     Sessions,
+    /// Switch to a recently used tab.
+    Recent,
     /// Set maximum iterations for the workflow.
     MaxIterations(u32),
     /// Set sequential (true) or parallel (false) review mode.
@@ -27,6 +36,24 @@ pub enum SlashCommand {
     Workflow(Option<String>),
     /// Merge worktree changes to the original branch (ChatInput only).
     MergeWorktree,
+    /// Follow the plan or latest feedback file live (running session only).
+    Tail(TailTarget),
+    /// Rename the running session (running session only).
+    Rename(String),
+    /// Edit the objective and restart planning (running session only).
+    EditObjective,
+    /// Export the focused run tab's chat as markdown (running session only).
+    ExportTab,
+    /// Take back the final plan decision during its grace window (running session only).
+    Undo,
+    /// Edit the session's notes (running session only).
+    Notes,
+    /// Select a reviewer preset.
+    /// None = list presets, Some(name) = select preset.
+    Reviewers(Option<String>),
+    /// Add extra CLI arguments for an agent to new workflows, as `AGENT=ARGS`.
+    /// None = clear the arguments added so far.
+    AgentArg(Option<String>),
 }
 
 /// Parse a slash command from input text.
@@ -54,6 +81,7 @@ pub fn parse_slash_command(input: &str) -> Option<(SlashCommand, Vec<String>)> {
         "/update" => Some((SlashCommand::Update, args)),
         "/config-dangerous" => Some((SlashCommand::ConfigDangerous, args)),
         "/sessions" => Some((SlashCommand::Sessions, args)),
+        "/recent" if args.is_empty() => Some((SlashCommand::Recent, vec![])),
         "/max-iterations" => {
             if args.len() != 1 {
                 return None;
@@ -65,6 +93,11 @@ pub fn parse_slash_command(input: &str) -> Option<(SlashCommand, Vec<String>)> {
         }
         "/sequential" => Some((SlashCommand::Sequential(true), vec![])),
         "/parallel" => Some((SlashCommand::Sequential(false), vec![])),
+        "/review-mode" => match args.as_slice() {
+            [mode] if mode == "sequential" => Some((SlashCommand::Sequential(true), vec![])),
+            [mode] if mode == "parallel" => Some((SlashCommand::Sequential(false), vec![])),
+            _ => None,
+        },
         "/aggregation" => {
             if args.len() != 1 {
                 return None;
@@ -82,6 +115,8 @@ pub fn parse_slash_command(input: &str) -> Option<(SlashCommand, Vec<String>)> {
             if args.first().map(|s| s.as_str()) == Some("dangerous") {
                 let remaining_args: Vec<String> = args[1..].to_vec();
                 Some((SlashCommand::ConfigDangerous, remaining_args))
+            } else if args.is_empty() {
+                Some((SlashCommand::Config, vec![]))
             } else {
                 None
             }
@@ -94,10 +129,66 @@ pub fn parse_slash_command(input: &str) -> Option<(SlashCommand, Vec<String>)> {
             }
         }
         "/merge-worktree" => Some((SlashCommand::MergeWorktree, args)),
+        "/tail" => {
+            if args.len() != 1 {
+                return None;
+            }
+            TailTarget::parse(&args[0]).map(|target| (SlashCommand::Tail(target), vec![]))
+        }
+        "/rename" => {
+            if args.is_empty() {
+                return None;
+            }
+            Some((SlashCommand::Rename(args.join(" ")), vec![]))
+        }
+        "/edit-objective" if args.is_empty() => Some((SlashCommand::EditObjective, vec![])),
+        "/export-tab" if args.is_empty() => Some((SlashCommand::ExportTab, vec![])),
+        "/undo" if args.is_empty() => Some((SlashCommand::Undo, vec![])),
+        "/notes" if args.is_empty() => Some((SlashCommand::Notes, vec![])),
+        "/reviewers" => match args.as_slice() {
+            [] => Some((SlashCommand::Reviewers(None), vec![])),
+            [preset] => Some((SlashCommand::Reviewers(Some(preset.clone())), vec![])),
+            _ => None,
+        },
+        // ARGS may be quoted, so it is taken verbatim rather than re-joined
+        "/agent-arg" => {
+            let spec = trimmed[command.len()..].trim();
+            let spec = (!spec.is_empty()).then(|| spec.to_string());
+            Some((SlashCommand::AgentArg(spec), vec![]))
+        }
         _ => None,
     }
 }
 
+/// Runs `/reviewers` against a workflow config: lists the presets, or switches the
+/// review panel to the named one. Returns the message to show.
+pub fn apply_reviewers_command(
+    config: &mut WorkflowConfig,
+    preset: Option<&str>,
+) -> anyhow::Result<String> {
+    let Some(preset) = preset else {
+        if config.reviewer_presets.is_empty() {
+            return Ok("No reviewer presets configured".to_string());
+        }
+        let names: Vec<&str> = config.reviewer_presets.keys().map(String::as_str).collect();
+        return Ok(format!(
+            "Reviewer presets: {} (current: {})",
+            names.join(", "),
+            config
+                .workflow
+                .reviewing
+                .preset
+                .as_deref()
+                .unwrap_or("none")
+        ));
+    };
+    config.apply_reviewer_preset(preset)?;
+    Ok(format!(
+        "Reviewers: preset '{}' (effective at next review phase)",
+        preset
+    ))
+}
+
 /// Result of applying dangerous defaults to a single agent config.
 #[derive(Debug, Clone)]
 pub struct AgentConfigResult {
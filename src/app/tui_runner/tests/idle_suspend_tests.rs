@@ -0,0 +1,83 @@
+//! Tests for idle-suspend timing and tab handling.
+
+use super::*;
+use std::path::PathBuf;
+
+const TIMEOUT: Duration = Duration::from_secs(120 * 60);
+
+#[test]
+fn test_idle_timeout_zero_disables() {
+    assert_eq!(idle_timeout(0), None);
+    assert_eq!(idle_timeout(120), Some(TIMEOUT));
+}
+
+#[test]
+fn test_idle_timer_starts_and_expires() {
+    let mut session = Session::new(0);
+    session.status = SessionStatus::AwaitingApproval;
+    let start = Instant::now();
+
+    assert!(!idle_timer_expired(&mut session, start, TIMEOUT));
+    assert_eq!(session.awaiting_approval_since, Some(start));
+    assert!(!idle_timer_expired(
+        &mut session,
+        start + Duration::from_secs(60),
+        TIMEOUT
+    ));
+    assert!(idle_timer_expired(&mut session, start + TIMEOUT, TIMEOUT));
+}
+
+#[test]
+fn test_idle_timer_resets_when_not_awaiting_approval() {
+    let mut session = Session::new(0);
+    session.status = SessionStatus::AwaitingApproval;
+    let start = Instant::now();
+    idle_timer_expired(&mut session, start, TIMEOUT);
+
+    session.status = SessionStatus::Planning;
+    assert!(!idle_timer_expired(&mut session, start + TIMEOUT, TIMEOUT));
+    assert_eq!(session.awaiting_approval_since, None);
+
+    // Waiting again restarts the clock rather than resuming the old one
+    session.status = SessionStatus::AwaitingApproval;
+    assert!(!idle_timer_expired(&mut session, start + TIMEOUT, TIMEOUT));
+}
+
+#[test]
+fn test_suspended_session_is_not_suspended_twice() {
+    let mut session = Session::new(0);
+    session.status = SessionStatus::AwaitingApproval;
+    session.idle_suspended = true;
+    let start = Instant::now();
+    idle_timer_expired(&mut session, start, TIMEOUT);
+    assert!(!idle_timer_expired(&mut session, start + TIMEOUT, TIMEOUT));
+}
+
+#[test]
+fn test_close_suspended_last_tab_opens_fresh_tab() {
+    let mut tab_manager = TabManager::new();
+    let session = tab_manager.active_mut();
+    session.name = "feature".to_string();
+    session.status = SessionStatus::Stopped;
+    session.idle_suspended = true;
+
+    close_suspended_tabs(&mut tab_manager, &PathBuf::from("/tmp"));
+
+    assert_eq!(tab_manager.len(), 1);
+    assert!(tab_manager.active().name.is_empty());
+    assert_eq!(tab_manager.active().input_mode, InputMode::NamingTab);
+}
+
+#[test]
+fn test_close_suspended_keeps_other_tabs() {
+    let mut tab_manager = TabManager::new();
+    tab_manager.active_mut().name = "first".to_string();
+    let second = tab_manager.add_session_with_name("second".to_string());
+    second.status = SessionStatus::Stopped;
+    second.idle_suspended = true;
+
+    close_suspended_tabs(&mut tab_manager, &PathBuf::from("/tmp"));
+
+    assert_eq!(tab_manager.len(), 1);
+    assert_eq!(tab_manager.active().name, "first");
+}
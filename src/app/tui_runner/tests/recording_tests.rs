@@ -0,0 +1,94 @@
+//! Tests for TUI recording encoding and the recording file format.
+
+use super::*;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use tempfile::tempdir;
+
+fn frame(width: u16, height: u16) -> Buffer {
+    Buffer::empty(Rect::new(0, 0, width, height))
+}
+
+#[test]
+fn test_encode_diff_only_emits_changed_cells() {
+    let previous = frame(10, 2);
+    let mut next = frame(10, 2);
+    next.set_string(2, 1, "hi", Style::default());
+
+    assert_eq!(
+        encode_diff(&previous, &next),
+        "\x1b[2;3H\x1b[0;39;49mhi\x1b[0m"
+    );
+    assert_eq!(encode_diff(&next, &next), "");
+}
+
+#[test]
+fn test_encode_diff_styles_and_cursor_moves() {
+    let previous = frame(10, 2);
+    let mut next = frame(10, 2);
+    next.set_string(0, 0, "a", Style::default().fg(Color::Red));
+    next.set_string(
+        5,
+        0,
+        "b",
+        Style::default()
+            .fg(Color::Rgb(1, 2, 3))
+            .bg(Color::Indexed(200))
+            .add_modifier(Modifier::BOLD),
+    );
+
+    assert_eq!(
+        encode_diff(&previous, &next),
+        "\x1b[1;1H\x1b[0;31;49ma\x1b[1;6H\x1b[0;1;38;2;1;2;3;48;5;200mb\x1b[0m"
+    );
+}
+
+#[test]
+fn test_recording_round_trip_with_resize() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("session.cast");
+
+    let mut recorder = UiRecorder::create(&path).unwrap();
+    let mut first = frame(8, 2);
+    first.set_string(0, 0, "one", Style::default());
+    recorder.record(&first).unwrap();
+    // An identical frame produces no output event
+    recorder.record(&first).unwrap();
+    let mut resized = frame(12, 3);
+    resized.set_string(0, 2, "two", Style::default());
+    recorder.record(&resized).unwrap();
+    drop(recorder);
+
+    let (header, events) = read_recording(&path).unwrap();
+    assert_eq!(header.version, 2);
+    assert_eq!((header.width, header.height), (8, 2));
+
+    let kinds: Vec<&str> = events.iter().map(|e| e.1.as_str()).collect();
+    assert_eq!(kinds, vec!["o", "r", "o"]);
+    assert!(events[0].2.starts_with("\x1b[0m\x1b[2J"));
+    assert!(events[0].2.contains("one"));
+    assert_eq!(events[1].2, "12x3");
+    assert!(events[2].2.contains("\x1b[3;1H"));
+    assert!(events.windows(2).all(|w| w[0].0 <= w[1].0));
+}
+
+#[test]
+fn test_read_recording_rejects_other_versions_and_stops_at_truncation() {
+    let dir = tempdir().unwrap();
+
+    let old = dir.path().join("old.cast");
+    std::fs::write(&old, "{\"version\":1,\"width\":80,\"height\":24}\n").unwrap();
+    assert!(read_recording(&old).is_err());
+
+    let truncated = dir.path().join("truncated.cast");
+    std::fs::write(
+        &truncated,
+        "{\"version\":2,\"width\":80,\"height\":24}\n[0.5,\"o\",\"hello\"]\n[0.9,\"o\",\"wor",
+    )
+    .unwrap();
+    let (_, events) = read_recording(&truncated).unwrap();
+    assert_eq!(
+        events,
+        vec![RecordedEvent(0.5, "o".to_string(), "hello".to_string())]
+    );
+}
@@ -0,0 +1,60 @@
+use super::*;
+use crate::domain::types::Phase;
+use std::io::Cursor;
+
+fn session(id: &str, objective: &str) -> SessionInfo {
+    SessionInfo::new(
+        id,
+        "auth",
+        objective,
+        Path::new("/repo"),
+        Phase::Reviewing,
+        2,
+    )
+}
+
+#[test]
+fn test_pick_session_retries_until_valid_number() {
+    let matches = vec![session("s-new", "Add login"), session("s-old", "Add OAuth")];
+    let mut input = Cursor::new("x\n3\n2\n");
+    let mut output = Vec::new();
+
+    let chosen = pick_session("auth", &matches, &mut input, &mut output).unwrap();
+
+    assert_eq!(chosen, "s-old");
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("2 sessions in this directory are named 'auth'"));
+    assert!(output.contains("1. s-new"));
+    assert_eq!(output.matches("Enter a number from 1 to 2").count(), 2);
+}
+
+#[test]
+fn test_pick_session_fails_at_end_of_input() {
+    let matches = vec![session("a", "One"), session("b", "Two")];
+    let mut output = Vec::new();
+    assert!(pick_session("auth", &matches, &mut Cursor::new(""), &mut output).is_err());
+}
+
+#[test]
+fn test_describe_matches_truncates_long_objectives() {
+    let long = "x".repeat(OBJECTIVE_PREVIEW_CHARS + 10);
+    let text = describe_matches("auth", &[session("a", &long)]);
+    assert!(text.contains(&format!("{}...", "x".repeat(OBJECTIVE_PREVIEW_CHARS))));
+}
+
+#[test]
+fn test_normalize_session_name() {
+    assert_eq!(
+        normalize_session_name("  OAuth Login_v2 ").as_deref(),
+        Some("oauth-login-v2")
+    );
+    assert_eq!(
+        normalize_session_name("api/rate limits!").as_deref(),
+        Some("api-rate-limits")
+    );
+    assert_eq!(normalize_session_name(" !! "), None);
+    assert_eq!(
+        normalize_session_name(&"a".repeat(80)).map(|n| n.len()),
+        Some(FeatureName::MAX_LEN)
+    );
+}
@@ -14,6 +14,15 @@ fn test_parse_update_command() {
     );
 }
 
+#[test]
+fn test_parse_recent_command() {
+    assert_eq!(
+        parse_slash_command("/recent"),
+        Some((SlashCommand::Recent, vec![]))
+    );
+    assert_eq!(parse_slash_command("/recent 2"), None);
+}
+
 #[test]
 fn test_parse_config_dangerous_hyphen() {
     assert_eq!(
@@ -30,6 +39,14 @@ fn test_parse_config_dangerous_space() {
     );
 }
 
+#[test]
+fn test_parse_config_opens_editor() {
+    assert_eq!(
+        parse_slash_command("/config"),
+        Some((SlashCommand::Config, vec![]))
+    );
+}
+
 #[test]
 fn test_parse_unknown_command() {
     assert_eq!(parse_slash_command("/not-a-command"), None);
@@ -212,6 +229,20 @@ fn test_parse_parallel() {
     );
 }
 
+#[test]
+fn test_parse_review_mode() {
+    assert_eq!(
+        parse_slash_command("/review-mode sequential"),
+        Some((SlashCommand::Sequential(true), vec![]))
+    );
+    assert_eq!(
+        parse_slash_command("/review-mode parallel"),
+        Some((SlashCommand::Sequential(false), vec![]))
+    );
+    assert_eq!(parse_slash_command("/review-mode"), None);
+    assert_eq!(parse_slash_command("/review-mode both"), None);
+}
+
 #[test]
 fn test_parse_aggregation_any_rejects() {
     assert_eq!(
@@ -318,3 +349,110 @@ fn test_parse_merge_worktree() {
         Some((SlashCommand::MergeWorktree, vec![]))
     );
 }
+
+#[test]
+fn test_parse_tail() {
+    assert_eq!(
+        parse_slash_command("/tail plan"),
+        Some((SlashCommand::Tail(TailTarget::Plan), vec![]))
+    );
+    assert_eq!(
+        parse_slash_command("/tail Feedback"),
+        Some((SlashCommand::Tail(TailTarget::Feedback), vec![]))
+    );
+    assert_eq!(parse_slash_command("/tail"), None);
+    assert_eq!(parse_slash_command("/tail review"), None);
+}
+
+#[test]
+fn test_parse_rename() {
+    assert_eq!(
+        parse_slash_command("/rename oauth login"),
+        Some((SlashCommand::Rename("oauth login".to_string()), vec![]))
+    );
+    assert_eq!(parse_slash_command("/rename"), None);
+}
+
+#[test]
+fn test_parse_edit_objective() {
+    assert_eq!(
+        parse_slash_command("/edit-objective"),
+        Some((SlashCommand::EditObjective, vec![]))
+    );
+    assert_eq!(parse_slash_command("/edit-objective now"), None);
+}
+
+#[test]
+fn test_parse_export_tab() {
+    assert_eq!(
+        parse_slash_command("/export-tab"),
+        Some((SlashCommand::ExportTab, vec![]))
+    );
+    assert_eq!(parse_slash_command("/export-tab review"), None);
+}
+
+#[test]
+fn test_parse_undo() {
+    assert_eq!(
+        parse_slash_command("/undo"),
+        Some((SlashCommand::Undo, vec![]))
+    );
+    assert_eq!(parse_slash_command("/undo approval"), None);
+}
+
+#[test]
+fn test_parse_notes() {
+    assert_eq!(
+        parse_slash_command("/notes"),
+        Some((SlashCommand::Notes, vec![]))
+    );
+    assert_eq!(parse_slash_command("/notes add"), None);
+}
+
+#[test]
+fn test_parse_reviewers() {
+    assert_eq!(
+        parse_slash_command("/reviewers"),
+        Some((SlashCommand::Reviewers(None), vec![]))
+    );
+    assert_eq!(
+        parse_slash_command("/reviewers thorough"),
+        Some((
+            SlashCommand::Reviewers(Some("thorough".to_string())),
+            vec![]
+        ))
+    );
+    assert_eq!(parse_slash_command("/reviewers fast thorough"), None);
+}
+
+#[test]
+fn test_apply_reviewers_command_lists_and_selects() {
+    let mut config = crate::config::WorkflowConfig::default_config();
+    let listing = apply_reviewers_command(&mut config, None).unwrap();
+    assert_eq!(
+        listing,
+        "Reviewer presets: fast, security, thorough (current: none)"
+    );
+
+    apply_reviewers_command(&mut config, Some("fast")).unwrap();
+    assert_eq!(config.workflow.reviewing.preset.as_deref(), Some("fast"));
+    assert!(apply_reviewers_command(&mut config, None)
+        .unwrap()
+        .ends_with("(current: fast)"));
+    assert!(apply_reviewers_command(&mut config, Some("nope")).is_err());
+}
+
+#[test]
+fn test_parse_agent_arg_keeps_quoting() {
+    assert_eq!(
+        parse_slash_command(r#"/agent-arg claude="--model  opus-4""#),
+        Some((
+            SlashCommand::AgentArg(Some(r#"claude="--model  opus-4""#.to_string())),
+            vec![]
+        ))
+    );
+    assert_eq!(
+        parse_slash_command("/agent-arg"),
+        Some((SlashCommand::AgentArg(None), vec![]))
+    );
+}
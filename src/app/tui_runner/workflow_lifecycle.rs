@@ -9,7 +9,9 @@ use crate::app::workflow::{WorkflowResult, WorkflowRunConfig};
 use crate::config::WorkflowConfig;
 use crate::domain::{WorkflowInput, WorkflowView};
 use crate::tui::session::context::compute_effective_working_dir;
-use crate::tui::{Session, SessionStatus, TabManager, UserApprovalResponse, WorkflowCommand};
+use crate::tui::{
+    Session, SessionStatus, TabManager, UserApprovalResponse, WorkflowCommand, WorkflowUpdate,
+};
 use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 
@@ -45,6 +47,8 @@ pub fn start_resumed_workflow(
 
     let (new_control_tx, new_control_rx) = mpsc::channel::<WorkflowCommand>(1);
     session.workflow_control_tx = Some(new_control_tx);
+    let (new_update_tx, new_update_rx) = mpsc::unbounded_channel::<WorkflowUpdate>();
+    session.workflow_update_tx = Some(new_update_tx);
 
     session.current_run_id += 1;
     let run_id = session.current_run_id;
@@ -71,6 +75,7 @@ pub fn start_resumed_workflow(
                     output_tx: tx,
                     approval_rx: new_approval_rx,
                     control_rx: new_control_rx,
+                    update_rx: new_update_rx,
                     session_id: sid,
                     run_id,
                     no_daemon: false,
@@ -83,6 +88,45 @@ pub fn start_resumed_workflow(
     session.workflow_handle = Some(workflow_handle);
 }
 
+/// Resumes a failed or aborted workflow from its event log, re-running the phase it
+/// stopped in. Used by the error overlay's "Retry the phase" action.
+/// Returns false if the session has no stopped workflow to resume.
+pub fn retry_failed_workflow(
+    session: &mut Session,
+    working_dir: &Path,
+    output_tx: &mpsc::UnboundedSender<crate::tui::Event>,
+) -> bool {
+    if !session.can_retry_workflow() {
+        return false;
+    }
+    let Some(view) = session.workflow_view.clone() else {
+        return false;
+    };
+    let Some(workflow_id) = view.workflow_id().cloned() else {
+        return false;
+    };
+
+    let (base_working_dir, workflow_config) = match session.context {
+        Some(ref ctx) => (ctx.base_working_dir.clone(), ctx.workflow_config.clone()),
+        None => (
+            working_dir.to_path_buf(),
+            super::workflow_loading::load_workflow_from_selection(working_dir),
+        ),
+    };
+    let input = WorkflowInput::Resume(crate::domain::ResumeWorkflowInput::new(workflow_id));
+
+    session.add_output("[planning] Retrying the failed phase...".to_string());
+    start_resumed_workflow(
+        session,
+        input,
+        view,
+        &base_working_dir,
+        &workflow_config,
+        output_tx,
+    );
+    true
+}
+
 /// Result from initialization task containing all data needed to start a workflow.
 pub struct InitResult {
     /// The workflow input (New or Resume).
@@ -125,10 +169,19 @@ pub async fn handle_init_completion(
             if let Some(session) = tab_manager.session_by_id_mut(session_id) {
                 // Load workflow config from persisted selection for this working directory
                 // This ensures /workflow changes are respected for new sessions
-                let workflow_config =
+                let mut workflow_config =
                     crate::app::tui_runner::workflow_loading::load_workflow_from_selection(
                         base_working_dir,
                     );
+                // Per-run agent args live in this session's config copy only
+                if let WorkflowInput::New(new_input) = &input {
+                    for entry in &new_input.agent_args {
+                        session.add_output(format!("[planning] Extra agent args: {}", entry));
+                    }
+                    if let Err(e) = workflow_config.apply_agent_args(&new_input.agent_args) {
+                        session.add_output(format!("[planning] Warning: {:#}", e));
+                    }
+                }
 
                 session.name = feature_name;
                 // For resume: view is populated from events. For new: None (will be set via CQRS).
@@ -169,6 +222,8 @@ pub async fn handle_init_completion(
                 // Create control channel for workflow interrupts
                 let (new_control_tx, new_control_rx) = mpsc::channel::<WorkflowCommand>(1);
                 session.workflow_control_tx = Some(new_control_tx);
+                let (new_update_tx, new_update_rx) = mpsc::unbounded_channel::<WorkflowUpdate>();
+                session.workflow_update_tx = Some(new_update_tx);
 
                 // Increment run_id for this new workflow
                 session.current_run_id += 1;
@@ -188,6 +243,7 @@ pub async fn handle_init_completion(
                                 output_tx: tx,
                                 approval_rx: new_approval_rx,
                                 control_rx: new_control_rx,
+                                update_rx: new_update_rx,
                                 session_id: sid,
                                 run_id,
                                 no_daemon: false,
@@ -232,12 +288,14 @@ pub async fn check_workflow_completions(
                         session.status = SessionStatus::Complete;
                         session.running = false;
                         session.workflow_control_tx = None;
+                        session.workflow_update_tx = None;
                     }
                     Ok(Ok(WorkflowResult::Aborted { reason })) => {
                         session.status = SessionStatus::Error;
                         session.running = false;
                         session.error_state = Some(reason);
                         session.workflow_control_tx = None;
+                        session.workflow_update_tx = None;
                     }
                     Ok(Ok(WorkflowResult::NeedsRestart { user_feedback })) => {
                         // handle_workflow_restart now loads config internally
@@ -248,6 +306,20 @@ pub async fn check_workflow_completions(
                             resumable_sessions.push(resumable);
                         }
                     }
+                    Ok(Ok(WorkflowResult::Following { holder_pid })) => {
+                        session.following_pid = Some(holder_pid);
+                        session.running = false;
+                        session.workflow_control_tx = None;
+                        session.workflow_update_tx = None;
+                        session.add_output(format!(
+                            "[planning] Session is open in PID {}; following it read-only",
+                            holder_pid
+                        ));
+                        session.add_output(
+                            "[planning] Resume with --takeover to stop it and continue here"
+                                .to_string(),
+                        );
+                    }
                     Ok(Ok(WorkflowResult::ImplementationRequested)) => {
                         session.status = SessionStatus::Planning;
                     }
@@ -362,15 +434,19 @@ fn handle_workflow_restart(
     };
 
     // Create resume input - the workflow engine will handle the restart logic
-    let input = WorkflowInput::Resume(crate::domain::ResumeWorkflowInput {
-        workflow_id: workflow_id.clone(),
-    });
+    let mut resume = crate::domain::ResumeWorkflowInput::new(workflow_id.clone());
+    if let Some(objective) = session.pending_objective.take() {
+        resume = resume.with_amended_objective(crate::domain::types::Objective(objective));
+    }
+    let input = WorkflowInput::Resume(resume);
 
     let (new_approval_tx, new_approval_rx) = mpsc::channel::<UserApprovalResponse>(1);
     session.approval_tx = Some(new_approval_tx);
 
     let (new_control_tx, new_control_rx) = mpsc::channel::<WorkflowCommand>(1);
     session.workflow_control_tx = Some(new_control_tx);
+    let (new_update_tx, new_update_rx) = mpsc::unbounded_channel::<WorkflowUpdate>();
+    session.workflow_update_tx = Some(new_update_tx);
 
     session.current_run_id += 1;
     let run_id = session.current_run_id;
@@ -389,6 +465,7 @@ fn handle_workflow_restart(
                     output_tx: tx,
                     approval_rx: new_approval_rx,
                     control_rx: new_control_rx,
+                    update_rx: new_update_rx,
                     session_id: sid,
                     run_id,
                     no_daemon: false,
@@ -423,7 +500,9 @@ fn handle_workflow_stopped(
 
     session.status = SessionStatus::Stopped;
     session.running = false;
+    session.approval_tx = None;
     session.workflow_control_tx = None;
+    session.workflow_update_tx = None;
     session.add_output("".to_string());
     session.add_output("=== SESSION STOPPED ===".to_string());
 
@@ -35,6 +35,7 @@ pub fn restore_terminal(
         terminal.backend_mut(),
         PopKeyboardEnhancementFlags,
         crossterm::event::DisableBracketedPaste,
+        crossterm::event::DisableFocusChange,
         crossterm::terminal::LeaveAlternateScreen,
         crossterm::event::DisableMouseCapture
     )?;
@@ -74,7 +75,8 @@ pub fn load_workflow_from_snapshot(
 /// Load workflow config from persisted selection or working directory.
 ///
 /// This function handles the non-CLI loading priority:
-/// 1. Persisted workflow selection → `~/.planning-agent/state/<wd-hash>/workflow-selection.json`
+/// 1. Persisted workflow selection → `~/.planning-agent/state/<wd-hash>/workflow-selection.json`,
+///    else the default selection written by the setup wizard
 /// 2. `./workflow.yaml` in working directory → auto-discover
 /// 3. Fallback → claude_only_config()
 ///
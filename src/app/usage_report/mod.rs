@@ -0,0 +1,231 @@
+//! `planning usage`: token and cost totals across sessions, for chargeback.
+//!
+//! Every agent invocation is appended to its session's `usage.jsonl` as it
+//! finishes. The report reads those ledgers, takes the repository (working
+//! directory) and feature from each session's `session_info.json`, and totals
+//! the invocations recorded since the cutoff by provider, repository and
+//! feature. Sessions from before the ledger existed have no usage to report.
+
+use crate::agents::cost_stats::{load_usage_records, UsageRecord, UsageTotals, USAGE_LEDGER_FILE};
+use crate::planning_paths::{self, SessionInfo};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Parses `--since`: an age like `24h`, `7d` or `2w`, or a date (`2026-10-01`, UTC).
+pub fn parse_since(spec: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let spec = spec.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(spec, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
+    }
+    let invalid = || {
+        anyhow::anyhow!(
+            "Invalid --since '{}' (use e.g. 24h, 7d, 2w or 2026-10-01)",
+            spec
+        )
+    };
+    let unit_start = spec.char_indices().last().ok_or_else(invalid)?.0;
+    let (count, unit) = spec.split_at(unit_start);
+    let count = i64::from(count.parse::<u32>().map_err(|_| invalid())?);
+    let age = match unit {
+        "h" => Duration::hours(count),
+        "d" => Duration::days(count),
+        "w" => Duration::weeks(count),
+        _ => return Err(invalid()),
+    };
+    Ok(now - age)
+}
+
+/// One session's ledger and where it ran.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionUsage {
+    /// Working directory the session was started in
+    pub repo: String,
+    pub feature: String,
+    pub records: Vec<UsageRecord>,
+}
+
+/// Reads the ledgers of every session that has one.
+pub fn load_sessions() -> Result<Vec<SessionUsage>> {
+    let mut sessions = Vec::new();
+    for entry in std::fs::read_dir(planning_paths::sessions_dir()?)?.flatten() {
+        let ledger = entry.path().join(USAGE_LEDGER_FILE);
+        if !ledger.exists() {
+            continue;
+        }
+        let session_id = entry.file_name().to_string_lossy().into_owned();
+        // A session without its info file is still counted, under its ID
+        let (repo, feature) = match SessionInfo::load(&session_id) {
+            Ok(info) => (info.working_dir.display().to_string(), info.feature_name),
+            Err(_) => ("(unknown)".to_string(), session_id),
+        };
+        sessions.push(SessionUsage {
+            repo,
+            feature,
+            records: load_usage_records(&ledger)?,
+        });
+    }
+    Ok(sessions)
+}
+
+/// What usage is grouped by in the report and the CSV.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UsageKey {
+    pub provider: String,
+    pub repo: String,
+    pub feature: String,
+}
+
+/// Usage since a cutoff, totalled per provider, repository and feature.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageReport {
+    pub rows: BTreeMap<UsageKey, UsageTotals>,
+    /// Sessions with at least one invocation in the window
+    pub sessions: usize,
+}
+
+impl UsageReport {
+    pub fn new(sessions: &[SessionUsage], since: DateTime<Utc>) -> Self {
+        let mut report = Self::default();
+        for session in sessions {
+            let mut counted = false;
+            for record in session.records.iter().filter(|r| r.recorded_at >= since) {
+                let key = UsageKey {
+                    provider: record.provider.clone(),
+                    repo: session.repo.clone(),
+                    feature: session.feature.clone(),
+                };
+                report.rows.entry(key).or_default().add(&UsageTotals {
+                    invocations: 1,
+                    tokens: record.tokens,
+                    priced_invocations: u64::from(record.cost.is_some()),
+                    cost: record.cost.unwrap_or(0.0),
+                });
+                counted = true;
+            }
+            report.sessions += usize::from(counted);
+        }
+        report
+    }
+
+    /// Totals per value of one key field, sorted by that value.
+    pub fn by(&self, field: fn(&UsageKey) -> &str) -> Vec<(String, UsageTotals)> {
+        let mut groups: BTreeMap<&str, UsageTotals> = BTreeMap::new();
+        for (key, totals) in &self.rows {
+            groups.entry(field(key)).or_default().add(totals);
+        }
+        groups
+            .into_iter()
+            .map(|(name, totals)| (name.to_string(), totals))
+            .collect()
+    }
+
+    /// The provider, repository and feature tables.
+    pub fn format(&self) -> String {
+        let mut out = String::new();
+        let tables: [(&str, fn(&UsageKey) -> &str); 3] = [
+            ("Provider", |k| k.provider.as_str()),
+            ("Repository", |k| k.repo.as_str()),
+            ("Feature", |k| k.feature.as_str()),
+        ];
+        for (title, field) in tables {
+            out.push_str(&format_table(title, &self.by(field)));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// One line per provider, repository and feature.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            "provider,repo,feature,invocations,tokens,cost_usd,unpriced_invocations\n".to_string();
+        for (key, totals) in &self.rows {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{:.4},{}\n",
+                csv_field(&key.provider),
+                csv_field(&key.repo),
+                csv_field(&key.feature),
+                totals.invocations,
+                totals.tokens,
+                totals.cost,
+                totals.invocations - totals.priced_invocations
+            ));
+        }
+        csv
+    }
+}
+
+fn format_table(title: &str, groups: &[(String, UsageTotals)]) -> String {
+    let width = groups
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .chain([title.len(), "Total".len()])
+        .max()
+        .unwrap_or(0);
+    let row = |name: &str, totals: &UsageTotals| {
+        // Invocations without a reported or estimated cost are flagged
+        let unpriced = totals.invocations - totals.priced_invocations;
+        let cost = match unpriced {
+            0 => format!("${:.2}", totals.cost),
+            n => format!("${:.2} ({} unpriced)", totals.cost, n),
+        };
+        format!(
+            "{:<width$}  {:>11}  {:>12}  {}\n",
+            name,
+            totals.invocations,
+            totals.tokens,
+            cost,
+            width = width
+        )
+    };
+    let mut table = format!(
+        "{:<width$}  {:>11}  {:>12}  {}\n",
+        title,
+        "Invocations",
+        "Tokens",
+        "Cost",
+        width = width
+    );
+    let mut total = UsageTotals::default();
+    for (name, totals) in groups {
+        table.push_str(&row(name, totals));
+        total.add(totals);
+    }
+    table.push_str(&row("Total", &total));
+    table
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Prints usage since `since` and optionally writes it to `csv` as well.
+pub fn run_usage_report(since: &str, csv: Option<&Path>) -> Result<()> {
+    let cutoff = parse_since(since, Utc::now())?;
+    let report = UsageReport::new(&load_sessions()?, cutoff);
+    println!(
+        "Usage since {} ({} session(s))\n",
+        cutoff.format("%Y-%m-%d %H:%M UTC"),
+        report.sessions
+    );
+    if report.rows.is_empty() {
+        println!("No agent usage recorded in this period.");
+    } else {
+        print!("{}", report.format());
+    }
+    if let Some(path) = csv {
+        std::fs::write(path, report.to_csv())
+            .with_context(|| format!("Failed to write CSV: {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/usage_report_tests.rs"]
+mod tests;
@@ -0,0 +1,147 @@
+use super::*;
+use crate::domain::types::Phase;
+use chrono::TimeZone;
+use tempfile::tempdir;
+
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 10, day, 12, 0, 0).unwrap()
+}
+
+fn record(day: u32, provider: &str, tokens: u64, cost: Option<f64>) -> UsageRecord {
+    UsageRecord {
+        recorded_at: at(day),
+        provider: provider.to_string(),
+        agent: provider.to_string(),
+        phase: "Reviewing #1".to_string(),
+        tokens,
+        cost,
+    }
+}
+
+fn session(repo: &str, feature: &str, records: Vec<UsageRecord>) -> SessionUsage {
+    SessionUsage {
+        repo: repo.to_string(),
+        feature: feature.to_string(),
+        records,
+    }
+}
+
+fn sample_sessions() -> Vec<SessionUsage> {
+    vec![
+        session(
+            "/src/api",
+            "auth",
+            vec![
+                record(10, "claude", 10_000, Some(1.5)),
+                record(11, "codex", 4_000, None),
+            ],
+        ),
+        session(
+            "/src/web",
+            "dark-mode",
+            vec![
+                record(1, "claude", 99_000, Some(9.0)),
+                record(12, "claude", 6_000, Some(0.5)),
+            ],
+        ),
+        session("/src/old", "stale", vec![record(2, "gemini", 1_000, None)]),
+    ]
+}
+
+#[test]
+fn test_parse_since_ages_and_dates() {
+    let now = at(15);
+    assert_eq!(parse_since("7d", now).unwrap(), at(8));
+    assert_eq!(parse_since("24h", now).unwrap(), at(14));
+    assert_eq!(parse_since(" 1w ", now).unwrap(), at(8));
+    assert_eq!(
+        parse_since("2026-10-01", now).unwrap(),
+        Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap()
+    );
+    for invalid in ["", "d", "7", "7m", "-7d", "7é", "last week"] {
+        assert!(parse_since(invalid, now).is_err(), "{:?}", invalid);
+    }
+}
+
+#[test]
+fn test_report_groups_records_since_cutoff() {
+    let report = UsageReport::new(&sample_sessions(), at(8));
+    assert_eq!(report.sessions, 2);
+    assert_eq!(report.rows.len(), 3);
+
+    let providers = report.by(|k| k.provider.as_str());
+    assert_eq!(providers[0].0, "claude");
+    assert_eq!(providers[0].1.invocations, 2);
+    assert_eq!(providers[0].1.tokens, 16_000);
+    assert_eq!(providers[0].1.cost, 2.0);
+    assert_eq!(providers[1].0, "codex");
+    assert_eq!(providers[1].1.priced_invocations, 0);
+
+    let repos = report.by(|k| k.repo.as_str());
+    let repos: Vec<(&str, u64)> = repos.iter().map(|(r, t)| (r.as_str(), t.tokens)).collect();
+    assert_eq!(repos, vec![("/src/api", 14_000), ("/src/web", 6_000)]);
+}
+
+#[test]
+fn test_format_flags_unpriced_invocations() {
+    let report = UsageReport::new(&sample_sessions(), at(8));
+    let text = report.format();
+    assert!(text.contains("Provider"));
+    assert!(text.contains("Repository"));
+    assert!(text.contains("Feature"));
+    let codex = text.lines().find(|l| l.starts_with("codex")).unwrap();
+    assert!(codex.ends_with("$0.00 (1 unpriced)"), "{}", codex);
+    let total = text.lines().find(|l| l.starts_with("Total")).unwrap();
+    assert!(total.contains("20000"), "{}", total);
+    assert!(total.ends_with("$2.00 (1 unpriced)"), "{}", total);
+}
+
+#[test]
+fn test_csv_has_a_row_per_key_and_quotes_fields() {
+    let sessions = vec![session(
+        "/src/a,b",
+        "say \"hi\"",
+        vec![record(10, "claude", 500, Some(0.25))],
+    )];
+    let csv = UsageReport::new(&sessions, at(1)).to_csv();
+    assert_eq!(
+        csv,
+        "provider,repo,feature,invocations,tokens,cost_usd,unpriced_invocations\n\
+         claude,\"/src/a,b\",\"say \"\"hi\"\"\",1,500,0.2500,0\n"
+    );
+}
+
+#[test]
+fn test_load_sessions_reads_ledgers_and_session_info() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let usage = crate::agents::cost_stats::InvocationUsage {
+        phase: "Planning".to_string(),
+        agent: "claude".to_string(),
+        tokens: 1_200,
+        cost: Some(0.1),
+    };
+    SessionInfo::new(
+        "s1",
+        "auth",
+        "Add auth",
+        Path::new("/src/api"),
+        Phase::Planning,
+        1,
+    )
+    .save("s1")
+    .unwrap();
+    crate::agents::cost_stats::append_usage_record("s1", "claude", &usage).unwrap();
+    crate::agents::cost_stats::append_usage_record("s2", "codex", &usage).unwrap();
+    // A session without a ledger has nothing to report
+    planning_paths::session_dir("s3").unwrap();
+
+    let mut sessions = load_sessions().unwrap();
+    sessions.sort_by(|a, b| a.feature.cmp(&b.feature));
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].repo, "/src/api");
+    assert_eq!(sessions[0].feature, "auth");
+    assert_eq!(sessions[0].records[0].tokens, 1_200);
+    assert_eq!(sessions[1].repo, "(unknown)");
+    assert_eq!(sessions[1].feature, "s2");
+}
@@ -1,6 +1,8 @@
+use crate::domain::types::{FeatureName, ImplementationPhase, Phase};
 use crate::phases;
 use crate::planning_paths;
-use crate::tui::TabManager;
+use crate::tui::ui::util::truncate_to_width;
+use crate::tui::{Session, TabManager, TerminalProgress};
 use std::collections::HashMap;
 use std::fs::OpenOptions;
 use std::io::Write;
@@ -348,6 +350,9 @@ pub fn shell_quote_path(path: &Path) -> String {
     quoted
 }
 
+/// Maximum width of the plan name shown in the terminal window title.
+const WINDOW_TITLE_NAME_WIDTH: usize = 40;
+
 pub fn format_window_title(tab_manager: &TabManager) -> String {
     use crate::tui::SessionStatus;
 
@@ -367,13 +372,79 @@ pub fn format_window_title(tab_manager: &TabManager) -> String {
     if plan_name.is_empty() || plan_name == "New Tab" {
         "Planning Agent".to_string()
     } else {
-        format!("[{}] {} - Planning Agent", status, plan_name)
+        format!(
+            "[{}] {} - Planning Agent",
+            status,
+            truncate_to_width(plan_name, WINDOW_TITLE_NAME_WIDTH)
+        )
+    }
+}
+
+/// Taskbar progress for the terminal: an attention bar while any tab awaits approval,
+/// otherwise the active session's progress.
+pub fn format_terminal_progress(tab_manager: &TabManager) -> TerminalProgress {
+    use crate::tui::SessionStatus;
+
+    let session = tab_manager.active();
+    let percent = session_progress_percent(session);
+
+    if let Some(waiting) = tab_manager
+        .sessions
+        .iter()
+        .find(|s| s.status == SessionStatus::AwaitingApproval)
+    {
+        let percent = if session.status == SessionStatus::AwaitingApproval {
+            percent
+        } else {
+            session_progress_percent(waiting)
+        };
+        return TerminalProgress::Attention(percent.unwrap_or(100));
+    }
+
+    match session.status {
+        SessionStatus::Planning | SessionStatus::GeneratingSummary => match percent {
+            Some(p) => TerminalProgress::Normal(p),
+            None => TerminalProgress::Indeterminate,
+        },
+        SessionStatus::Error => TerminalProgress::Error(percent.unwrap_or(100)),
+        SessionStatus::InputPending | SessionStatus::Stopped | SessionStatus::Complete => {
+            TerminalProgress::Hidden
+        }
     }
 }
 
+/// Rough percent complete from the current phase within the review/revise iterations.
+fn session_progress_percent(session: &Session) -> Option<u8> {
+    let view = session.workflow_view.as_ref()?;
+    let (iteration, max_iterations) = session.iteration();
+    let implementing = view
+        .implementation_state()
+        .is_some_and(|s| s.phase() != ImplementationPhase::Complete);
+
+    let within_iteration = if implementing {
+        0.5
+    } else {
+        match view.planning_phase()? {
+            Phase::Planning => 0.1,
+            Phase::Reviewing => 0.4,
+            Phase::Revising => 0.7,
+            Phase::AwaitingPlanningDecision => 0.9,
+            Phase::Complete => return Some(100),
+        }
+    };
+    let max_iterations = max_iterations.max(1);
+    let completed = iteration.saturating_sub(1).min(max_iterations - 1);
+    let fraction = (f64::from(completed) + within_iteration) / f64::from(max_iterations);
+    Some((fraction * 100.0).round().clamp(1.0, 99.0) as u8)
+}
+
 /// Extracts a short kebab-case feature name from an objective using Claude.
+///
+/// The answer is slugified and made unique among the sessions and branches of
+/// `working_dir`.
 pub async fn extract_feature_name(
     objective: &str,
+    working_dir: &Path,
     output_tx: Option<&tokio::sync::mpsc::UnboundedSender<crate::tui::Event>>,
 ) -> anyhow::Result<String> {
     use crate::prompt_format::PromptBuilder;
@@ -407,28 +478,21 @@ Example outputs: "sharing-permissions", "user-auth", "api-rate-limiting""#)
         .wait_with_output()
         .await?;
 
-    let mut name: String = String::from_utf8_lossy(&output.stdout)
-        .trim()
-        .to_lowercase()
-        .chars()
-        .filter(|c| c.is_alphanumeric() || *c == '-')
-        .collect();
-
-    // Truncate absurdly long names (Claude sometimes returns garbage)
-    const MAX_FEATURE_NAME_LEN: usize = 50;
-    if name.len() > MAX_FEATURE_NAME_LEN {
-        name.truncate(MAX_FEATURE_NAME_LEN);
-        // Trim trailing hyphens after truncation
-        while name.ends_with('-') {
-            name.pop();
-        }
-    }
+    let name = FeatureName::slugify(String::from_utf8_lossy(&output.stdout).trim());
+    Ok(unique_feature_name(name, working_dir).0)
+}
 
-    if name.is_empty() {
-        Ok("feature".to_string())
-    } else {
-        Ok(name)
-    }
+/// `name`, suffixed with `-2`, `-3`, ... while a session in `working_dir` or a
+/// local branch already uses it.
+pub fn unique_feature_name(name: FeatureName, working_dir: &Path) -> FeatureName {
+    let branches = crate::git_worktree::local_branches(working_dir);
+    name.unique(|candidate| {
+        planning_paths::find_sessions_by_feature_name(candidate, working_dir)
+            .is_ok_and(|sessions| !sessions.is_empty())
+            || branches
+                .iter()
+                .any(|branch| crate::git_worktree::branch_uses_feature(branch, candidate))
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,175 @@
+//! Approval gates: extra points where the workflow waits for the user.
+//!
+//! The workflow config's `approval_gates` lists the phases the user wants to
+//! sign off before the workflow moves on: `planning`, `reviewing`, `revising`
+//! and `implementation_round`. A gate asks through the regular approval prompt.
+//! Accepting passes it; declining with feedback rejects it, which sends the plan
+//! back to the planner with the feedback, or at `implementation_round` runs the
+//! round again with it. At the `reviewing` gate the user can also appeal the
+//! round's rejections to the rejecting reviewers; clearing them all approves the
+//! plan. Each gate step is recorded as its own event, and a gate that is still
+//! open when the session stops is asked again on resume.
+
+use super::planning_decision::appeal_rejections;
+use super::reviewing::WorkflowPhaseContext;
+use super::WorkflowResult;
+use crate::domain::types::{ApprovalGate, GateStep, Iteration, Phase};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::ReviewResult;
+use crate::tui::{SessionEventSender, UserApprovalResponse, WorkflowCommand};
+use tokio::sync::mpsc;
+
+/// What the user answered at a gate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GateAnswer {
+    Pass,
+    Reject(String),
+    /// Counter-argument to the round's rejections.
+    Appeal(String),
+    Stop,
+}
+
+impl GateAnswer {
+    /// The gate step recording this answer. None for a stop or an appeal, which
+    /// leave the gate open.
+    pub fn step(&self) -> Option<GateStep> {
+        match self {
+            Self::Pass => Some(GateStep::Passed),
+            Self::Reject(feedback) => Some(GateStep::Rejected {
+                feedback: feedback.clone(),
+            }),
+            Self::Appeal(_) | Self::Stop => None,
+        }
+    }
+}
+
+/// The gate between two phases of the plan loop. None when the plan loop ends
+/// or waits for another decision anyway.
+pub fn gate_after(completed: Phase, next: Phase) -> Option<ApprovalGate> {
+    match (completed, next) {
+        (Phase::Planning, Phase::Reviewing) => Some(ApprovalGate::Planning),
+        (Phase::Reviewing, Phase::Revising) => Some(ApprovalGate::Reviewing),
+        (Phase::Revising, Phase::Reviewing) => Some(ApprovalGate::Revising),
+        _ => None,
+    }
+}
+
+/// Asks at the gate the view has open, or opens the configured gate after
+/// `completed`. Returns the result to end the run with, or None to go on.
+pub async fn pass_gates(
+    view: &WorkflowView,
+    completed: Option<Phase>,
+    context: &WorkflowPhaseContext<'_>,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+    last_reviews: &mut Vec<ReviewResult>,
+) -> Option<WorkflowResult> {
+    let next = view.planning_phase().unwrap_or(Phase::Planning);
+    let gate = match view.open_approval_gate() {
+        Some(gate) => gate,
+        None => {
+            let gate = completed
+                .and_then(|completed| gate_after(completed, next))
+                .filter(|gate| context.config.approval_gates.contains(gate))?;
+            let iteration = view.iteration().unwrap_or(Iteration::first());
+            context
+                .dispatch_command(DomainCommand::RecordApprovalGate {
+                    gate,
+                    step: GateStep::Opened { iteration },
+                })
+                .await;
+            gate
+        }
+    };
+
+    context.sender.send_output(format!(
+        "[planning] Approval gate {} - waiting for your approval...",
+        gate.as_str()
+    ));
+    let summary = plan_gate_summary(view, gate, next);
+    let answer = loop {
+        let answer = ask_at_gate(summary.clone(), context.sender, approval_rx, control_rx).await;
+        let GateAnswer::Appeal(argument) = answer else {
+            break answer;
+        };
+        if gate != ApprovalGate::Reviewing {
+            context
+                .sender
+                .send_output("[planning] Only review rejections can be appealed".to_string());
+        } else if appeal_rejections(view, context, last_reviews, &argument).await {
+            // The plan is approved; the gate has nothing left to hold back
+            break GateAnswer::Pass;
+        }
+    };
+    let Some(step) = answer.step() else {
+        return Some(WorkflowResult::Stopped);
+    };
+    context.log_workflow(&format!("Approval gate {}: {:?}", gate.as_str(), step));
+    context
+        .dispatch_command(DomainCommand::RecordApprovalGate { gate, step })
+        .await;
+    if matches!(answer, GateAnswer::Reject(_)) {
+        context
+            .sender
+            .send_output("[planning] Sending the plan back to the planner...".to_string());
+    }
+    None
+}
+
+fn plan_gate_summary(view: &WorkflowView, gate: ApprovalGate, next: Phase) -> String {
+    let iteration = view.iteration().unwrap_or(Iteration::first()).0;
+    let mut summary = format!(
+        "Approval gate: {} (iteration {})\n\n\
+         Accept to continue with {}, or decline with feedback to send the plan back to the planner.\n",
+        gate.as_str(),
+        iteration,
+        next.status_label().to_lowercase()
+    );
+    if gate == ApprovalGate::Reviewing {
+        summary.push_str("Appeal to argue against the rejections; the plan is approved if every rejecting reviewer clears its rejection.\n");
+    }
+    if let Some(plan_path) = view.plan_path() {
+        summary.push_str(&format!("\nPlan file: {}\n", plan_path.0.display()));
+        if let Ok(content) = std::fs::read_to_string(&plan_path.0) {
+            summary.push_str("\n---\n\n## Plan Contents\n\n");
+            summary.push_str(&content);
+        }
+    }
+    summary
+}
+
+/// Shows the approval prompt with `summary` and waits for the answer.
+pub async fn ask_at_gate(
+    summary: String,
+    sender: &SessionEventSender,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> GateAnswer {
+    sender.send_approval_request(summary);
+    loop {
+        tokio::select! {
+            Some(cmd) = control_rx.recv() => {
+                return match cmd {
+                    WorkflowCommand::Stop => GateAnswer::Stop,
+                    WorkflowCommand::Interrupt { feedback } => GateAnswer::Reject(feedback),
+                };
+            }
+            response = approval_rx.recv() => match response {
+                Some(UserApprovalResponse::Decline(feedback)) => return GateAnswer::Reject(feedback),
+                Some(UserApprovalResponse::Appeal(argument)) => return GateAnswer::Appeal(argument),
+                Some(
+                    UserApprovalResponse::Accept
+                    | UserApprovalResponse::Implement
+                    | UserApprovalResponse::ApproveWithConditions { .. },
+                ) => return GateAnswer::Pass,
+                Some(_) => continue,
+                None => return GateAnswer::Stop,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/approval_gates_tests.rs"]
+mod tests;
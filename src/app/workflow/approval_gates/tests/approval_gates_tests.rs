@@ -0,0 +1,83 @@
+use super::*;
+
+fn sender() -> SessionEventSender {
+    let (tx, _rx) = mpsc::unbounded_channel();
+    SessionEventSender::new(0, 0, tx)
+}
+
+#[test]
+fn test_gate_after_only_between_plan_loop_phases() {
+    assert_eq!(
+        gate_after(Phase::Planning, Phase::Reviewing),
+        Some(ApprovalGate::Planning)
+    );
+    assert_eq!(
+        gate_after(Phase::Reviewing, Phase::Revising),
+        Some(ApprovalGate::Reviewing)
+    );
+    assert_eq!(
+        gate_after(Phase::Revising, Phase::Reviewing),
+        Some(ApprovalGate::Revising)
+    );
+    // The final decision and the max iterations prompt already ask the user
+    assert_eq!(gate_after(Phase::Reviewing, Phase::Complete), None);
+    assert_eq!(
+        gate_after(Phase::Reviewing, Phase::AwaitingPlanningDecision),
+        None
+    );
+    assert_eq!(
+        gate_after(Phase::AwaitingPlanningDecision, Phase::Revising),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_ask_at_gate_answers() {
+    let sender = sender();
+    let (approval_tx, mut approval_rx) = mpsc::channel(2);
+    let (control_tx, mut control_rx) = mpsc::channel(1);
+
+    approval_tx
+        .send(UserApprovalResponse::ReviewContinue)
+        .await
+        .unwrap();
+    approval_tx
+        .send(UserApprovalResponse::Decline("too broad".to_string()))
+        .await
+        .unwrap();
+    let answer = ask_at_gate(String::new(), &sender, &mut approval_rx, &mut control_rx).await;
+    assert_eq!(answer, GateAnswer::Reject("too broad".to_string()));
+
+    approval_tx
+        .send(UserApprovalResponse::Accept)
+        .await
+        .unwrap();
+    let answer = ask_at_gate(String::new(), &sender, &mut approval_rx, &mut control_rx).await;
+    assert_eq!(answer, GateAnswer::Pass);
+
+    control_tx.send(WorkflowCommand::Stop).await.unwrap();
+    let answer = ask_at_gate(String::new(), &sender, &mut approval_rx, &mut control_rx).await;
+    assert_eq!(answer, GateAnswer::Stop);
+    assert_eq!(answer.step(), None);
+}
+
+#[tokio::test]
+async fn test_ask_at_gate_returns_appeals_without_a_step() {
+    let sender = sender();
+    let (approval_tx, mut approval_rx) = mpsc::channel(1);
+    let (_control_tx, mut control_rx) = mpsc::channel(1);
+
+    approval_tx
+        .send(UserApprovalResponse::Appeal(
+            "the risk is covered".to_string(),
+        ))
+        .await
+        .unwrap();
+    let answer = ask_at_gate(String::new(), &sender, &mut approval_rx, &mut control_rx).await;
+    assert_eq!(
+        answer,
+        GateAnswer::Appeal("the risk is covered".to_string())
+    );
+    // The gate stays open until the appeal is decided
+    assert_eq!(answer.step(), None);
+}
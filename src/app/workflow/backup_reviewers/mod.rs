@@ -0,0 +1,99 @@
+//! Backup reviewers standing in for reviewers that keep failing.
+//!
+//! `workflow.reviewing.backup_reviewers` lists reviewers held in reserve. When a
+//! reviewer fails in a way a retry won't fix (the CLI exits with an error, e.g.
+//! it is missing or its login is broken), or fails more often than
+//! `failure_policy.max_retries` allows, the next unused backup reviews in its
+//! place for the rest of the cycle instead of the round failing. Each
+//! substitution is recorded as a `ReviewerSubstituted` event and flagged in the
+//! review history panel.
+
+use super::reviewing::WorkflowPhaseContext;
+use crate::config::{AgentRef, WorkflowConfig};
+use crate::domain::types::AgentId;
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::ReviewFailure;
+use crate::tui::ReviewKind;
+use std::collections::{HashMap, HashSet};
+
+/// Tracks reviewer failures in one reviewing phase run and hands out backups.
+pub struct BackupReviewers {
+    /// Backups not standing in for anyone yet, in configured order.
+    available: Vec<AgentRef>,
+    /// Failures so far per reviewer display ID.
+    failures: HashMap<String, u32>,
+    max_retries: u32,
+}
+
+impl BackupReviewers {
+    /// Backups already standing in during the view's current cycle are not handed out again.
+    pub fn new(config: &WorkflowConfig, view: &WorkflowView) -> Self {
+        let in_use: HashSet<&str> = view
+            .reviewer_substitutions()
+            .values()
+            .map(AgentId::as_str)
+            .collect();
+        Self {
+            available: config
+                .workflow
+                .reviewing
+                .backup_reviewers
+                .iter()
+                .filter(|backup| !in_use.contains(backup.display_id()))
+                .cloned()
+                .collect(),
+            failures: HashMap::new(),
+            max_retries: config.failure_policy.max_retries(),
+        }
+    }
+
+    /// Counts the failure and returns the backup to run in the failed reviewer's
+    /// place. None while a retry may still help or when no backup is left.
+    pub fn substitute(&mut self, failure: &ReviewFailure) -> Option<AgentRef> {
+        let count = self.failures.entry(failure.agent_name.clone()).or_default();
+        *count += 1;
+        if (failure.kind.is_retryable() && *count <= self.max_retries) || self.available.is_empty()
+        {
+            return None;
+        }
+        Some(self.available.remove(0))
+    }
+}
+
+/// Reports a substitution to the UI and dispatches `RecordReviewerSubstituted`.
+pub async fn record_substitution(
+    context: &WorkflowPhaseContext<'_>,
+    iteration: u32,
+    failure: &ReviewFailure,
+    substitute: &AgentRef,
+) {
+    context.log_workflow(&format!(
+        "Backup reviewer {} replaces {}: {}",
+        substitute.display_id(),
+        failure.agent_name,
+        failure.error
+    ));
+    context.sender.send_output(format!(
+        "[review:{}] Keeps failing; backup reviewer {} takes its place",
+        failure.agent_name,
+        substitute.display_id()
+    ));
+    context.sender.send_reviewer_substituted(
+        ReviewKind::Plan,
+        iteration,
+        failure.agent_name.clone(),
+        substitute.display_id().to_string(),
+    );
+    context
+        .dispatch_command(DomainCommand::RecordReviewerSubstituted {
+            failed_reviewer: AgentId::from(failure.agent_name.as_str()),
+            substitute: AgentId::from(substitute.display_id()),
+            reason: failure.error.clone(),
+        })
+        .await;
+}
+
+#[cfg(test)]
+#[path = "tests/backup_reviewers_tests.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+use crate::domain::failure::FailureKind;
+use crate::domain::types::TimestampUtc;
+use crate::domain::WorkflowEvent;
+
+fn config() -> WorkflowConfig {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+  codex:
+    command: "codex"
+  gemini:
+    command: "gemini"
+
+workflow:
+  planning:
+    agent: claude
+
+  reviewing:
+    agents: [claude]
+    backup_reviewers: [codex, gemini]
+
+failure_policy:
+  max_retries: 1
+"#;
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+fn failure(agent: &str, kind: FailureKind) -> ReviewFailure {
+    ReviewFailure {
+        agent_name: agent.to_string(),
+        error: "claude: command not found".to_string(),
+        bundle_path: None,
+        kind,
+    }
+}
+
+#[test]
+fn test_backup_replaces_reviewer_a_retry_cannot_fix() {
+    let mut backups = BackupReviewers::new(&config(), &WorkflowView::default());
+
+    let substitute = backups.substitute(&failure("claude", FailureKind::ProcessExit(127)));
+    assert_eq!(substitute, Some(AgentRef::Simple("codex".to_string())));
+    // The next failing reviewer gets the next backup, then none are left
+    let substitute = backups.substitute(&failure("codex", FailureKind::Unknown("x".into())));
+    assert_eq!(substitute, Some(AgentRef::Simple("gemini".to_string())));
+    assert_eq!(
+        backups.substitute(&failure("gemini", FailureKind::ProcessExit(1))),
+        None
+    );
+}
+
+#[test]
+fn test_transient_failures_are_retried_before_substituting() {
+    let mut backups = BackupReviewers::new(&config(), &WorkflowView::default());
+
+    assert_eq!(
+        backups.substitute(&failure("claude", FailureKind::Timeout)),
+        None
+    );
+    assert_eq!(
+        backups.substitute(&failure("claude", FailureKind::Timeout)),
+        Some(AgentRef::Simple("codex".to_string()))
+    );
+}
+
+#[test]
+fn test_backups_in_use_this_cycle_are_not_handed_out_again() {
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        "3f2b6c1e-8d4a-4f5e-9b7c-2a1d0e9f8c7b",
+        &WorkflowEvent::ReviewerSubstituted {
+            failed_reviewer: AgentId::from("claude"),
+            substitute: AgentId::from("codex"),
+            reason: "not logged in".to_string(),
+            substituted_at: TimestampUtc::now(),
+        },
+        1,
+    );
+    let mut backups = BackupReviewers::new(&config(), &view);
+
+    assert_eq!(
+        backups.substitute(&failure("claude", FailureKind::ProcessExit(1))),
+        Some(AgentRef::Simple("gemini".to_string()))
+    );
+}
@@ -2,26 +2,93 @@
 
 use super::WorkflowResult;
 use crate::app::util::build_approval_summary;
+use crate::domain::types::Phase;
 use crate::domain::view::WorkflowView;
 use crate::git_worktree;
-use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger, SessionTracker};
 use crate::tui::{SessionEventSender, UserApprovalResponse, WorkflowCommand};
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
+/// Heading of the section approval conditions are appended under.
+pub const CONDITIONS_HEADING: &str = "## Conditions of approval";
+
+/// Renders the conditions section appended to an approved plan.
+///
+/// Each non-empty line of the user's input becomes one bullet.
+pub fn format_conditions_section(conditions: &str) -> String {
+    let bullets: Vec<String> = conditions
+        .lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(['-', '*'])
+                .trim_start()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .map(|line| format!("- {}", line))
+        .collect();
+    format!(
+        "{}\n\nThe user approved this plan on the following conditions. They are mandatory: \
+         the implementation must satisfy every one of them.\n\n{}\n",
+        CONDITIONS_HEADING,
+        bullets.join("\n")
+    )
+}
+
+/// Appends the conditions section to the plan file.
+fn append_approval_conditions(plan_path: &Path, conditions: &str) -> Result<()> {
+    let plan = std::fs::read_to_string(plan_path)
+        .with_context(|| format!("Failed to read plan: {}", plan_path.display()))?;
+    let updated = format!(
+        "{}\n\n{}",
+        plan.trim_end(),
+        format_conditions_section(conditions)
+    );
+    std::fs::write(plan_path, updated)
+        .with_context(|| format!("Failed to write plan: {}", plan_path.display()))
+}
+
+/// Removes the conditions section appended by an approval that was undone.
+pub fn remove_approval_conditions(plan_path: &Path) -> Result<()> {
+    let plan = std::fs::read_to_string(plan_path)
+        .with_context(|| format!("Failed to read plan: {}", plan_path.display()))?;
+    let Some(start) = plan.rfind(CONDITIONS_HEADING) else {
+        return Ok(());
+    };
+    let updated = format!("{}\n", plan.get(..start).unwrap_or_default().trim_end());
+    std::fs::write(plan_path, updated)
+        .with_context(|| format!("Failed to write plan: {}", plan_path.display()))
+}
+
 /// Helper to log completion messages.
 fn log_completion(logger: &SessionLogger, message: &str) {
     logger.log(LogLevel::Info, LogCategory::Workflow, message);
 }
 
+/// Prefixes the approval summary with the issue the session was imported from.
+fn with_source_issue(view: &WorkflowView, summary: String) -> String {
+    match view.issue_link() {
+        Some(issue) => format!(
+            "Source issue: {} - {} ({})\n\n{}",
+            issue.key(),
+            issue.title(),
+            issue.url(),
+            summary
+        ),
+        None => summary,
+    }
+}
+
 pub async fn handle_completion(
     view: &WorkflowView,
     session_logger: &Arc<SessionLogger>,
     sender: &SessionEventSender,
     approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
     control_rx: &mut mpsc::Receiver<WorkflowCommand>,
-) -> Result<WorkflowResult> {
+) -> Result<(WorkflowResult, Option<String>)> {
     log_completion(
         session_logger,
         ">>> Plan complete - requesting user approval",
@@ -61,14 +128,14 @@ pub async fn handle_completion(
         sender.send_output("Waiting for your final decision...".to_string());
 
         let summary = build_approval_summary(&plan_path, true, iteration);
-        sender.send_user_override_approval(summary);
+        sender.send_user_override_approval(with_source_issue(view, summary));
     } else {
         sender.send_output("=== PLAN APPROVED BY AI ===".to_string());
         sender.send_output(format!("Completed after {} iteration(s)", iteration));
         sender.send_output("Waiting for your approval...".to_string());
 
         let summary = build_approval_summary(&plan_path, false, iteration);
-        sender.send_approval_request(summary);
+        sender.send_approval_request(with_source_issue(view, summary));
     };
 
     log_completion(session_logger, "Waiting for user approval response...");
@@ -79,12 +146,12 @@ pub async fn handle_completion(
                     WorkflowCommand::Stop => {
                         log_completion(session_logger, "Stop command received during approval wait");
                         sender.send_output("[workflow] Stopping during approval...".to_string());
-                        return Ok(WorkflowResult::Stopped);
+                        return Ok((WorkflowResult::Stopped, None));
                     }
                     WorkflowCommand::Interrupt { feedback } => {
                         log_completion(session_logger, &format!("Interrupt received during approval: {}", feedback));
                         sender.send_output("[workflow] Interrupted during approval".to_string());
-                        return Ok(WorkflowResult::NeedsRestart { user_feedback: feedback });
+                        return Ok((WorkflowResult::NeedsRestart { user_feedback: feedback }, None));
                     }
                 }
             }
@@ -93,12 +160,30 @@ pub async fn handle_completion(
                     Some(UserApprovalResponse::Accept) => {
                         log_completion(session_logger, "User ACCEPTED the plan");
                         sender.send_output("[planning] User accepted the plan!".to_string());
-                        return Ok(WorkflowResult::Accepted);
+                        return Ok((WorkflowResult::Accepted, None));
                     }
                     Some(UserApprovalResponse::Implement) => {
                         log_completion(session_logger, "User requested IMPLEMENTATION");
                         sender.send_output("[planning] Starting implementation workflow...".to_string());
-                        return Ok(WorkflowResult::ImplementationRequested);
+                        return Ok((WorkflowResult::ImplementationRequested, None));
+                    }
+                    Some(UserApprovalResponse::ApproveWithConditions { conditions, implement }) => {
+                        log_completion(
+                            session_logger,
+                            &format!("User APPROVED WITH CONDITIONS: {}", conditions),
+                        );
+                        append_approval_conditions(&plan_path, &conditions)?;
+                        sender.send_output(
+                            "[planning] User approved the plan with conditions (added to the plan)"
+                                .to_string(),
+                        );
+                        let result = if implement {
+                            sender.send_output("[planning] Starting implementation workflow...".to_string());
+                            WorkflowResult::ImplementationRequested
+                        } else {
+                            WorkflowResult::Accepted
+                        };
+                        return Ok((result, Some(conditions)));
                     }
                     Some(UserApprovalResponse::Decline(feedback)) => {
                         log_completion(
@@ -106,9 +191,12 @@ pub async fn handle_completion(
                             &format!("User DECLINED with feedback: {}", feedback),
                         );
                         sender.send_output(format!("[planning] User requested changes: {}", feedback));
-                        return Ok(WorkflowResult::NeedsRestart {
-                            user_feedback: feedback,
-                        });
+                        return Ok((
+                            WorkflowResult::NeedsRestart {
+                                user_feedback: feedback,
+                            },
+                            None,
+                        ));
                     }
                     Some(UserApprovalResponse::ReviewRetry)
                     | Some(UserApprovalResponse::ReviewContinue) => {
@@ -162,12 +250,64 @@ pub async fn handle_completion(
                         );
                         continue;
                     }
+                    Some(UserApprovalResponse::FeedbackTriaged(_)) => {
+                        log_completion(
+                            session_logger,
+                            "Received FeedbackTriaged while awaiting plan approval, ignoring",
+                        );
+                        continue;
+                    }
+                    Some(UserApprovalResponse::Appeal(_)) => {
+                        log_completion(
+                            session_logger,
+                            "Received Appeal while awaiting plan approval, ignoring",
+                        );
+                        continue;
+                    }
                     None => {
                         log_completion(session_logger, "Approval channel closed - treating as accept");
-                        return Ok(WorkflowResult::Accepted);
+                        return Ok((WorkflowResult::Accepted, None));
                     }
                 }
             }
         }
     }
 }
+
+/// Send error status to daemon tracker when a workflow failure has been recorded.
+/// This enables the host-gui to detect and notify on planning workflow failures.
+///
+/// Should be called BEFORE mark_stopped() when has_failure() returns true.
+pub(super) async fn send_error_status_if_failure(
+    tracker: &SessionTracker,
+    view_rx: &tokio::sync::watch::Receiver<crate::domain::view::WorkflowView>,
+    workflow_session_id: &str,
+) {
+    // Extract all values from view in a block to ensure borrow is dropped before await
+    let update_info = {
+        let view = view_rx.borrow();
+        if view.has_failure() {
+            let phase = view.planning_phase().unwrap_or(Phase::Planning);
+            let iteration = view.iteration().unwrap_or_default().0;
+            Some((phase, iteration))
+        } else {
+            None
+        }
+    }; // view borrow is definitely dropped here
+
+    if let Some((_phase, iteration)) = update_info {
+        let _ = tracker
+            .update(
+                workflow_session_id,
+                "Planning".to_string(), // Planning workflow phase is always "Planning"
+                iteration,
+                "Error".to_string(),
+                None,
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/completion_tests.rs"]
+mod tests;
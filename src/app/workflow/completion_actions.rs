@@ -0,0 +1,486 @@
+//! Completion actions run after the workflow is accepted.
+//!
+//! The workflow config's `completion_actions` run in order once the user accepts the
+//! plan (or its implementation). Each outcome is recorded as a
+//! `CompletionActionRecorded` event and the outcomes are shown in the TUI's
+//! completion summary. A failed action does not stop the ones after it.
+//!
+//! Before the actions run, the run's metrics (time per phase, iterations, usage
+//! per agent, reviewer rejections and files changed) are printed as a summary
+//! card and written to the session's `summary.json`, along with the plan's
+//! front matter when the structured plan format is on and the session's `/notes`.
+
+use super::reviewing::WorkflowPhaseContext;
+use crate::agents::cost_stats::InvocationUsage;
+use crate::config::CompletionAction;
+use crate::domain::view::WorkflowView;
+use crate::domain::{CompletionActionOutcome, PlanMetadata, WorkflowCommand as DomainCommand};
+use crate::phases::feedback_items::load_session_notes;
+use crate::planning_paths;
+use crate::tui::ui::util::{format_duration, format_tokens};
+use crate::tui::SessionEventSender;
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Time a formatter or shell hook may run before it is killed.
+const ACTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Time allowed for a notification webhook request.
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Session details available to the actions.
+pub struct CompletionTarget<'a> {
+    pub view: &'a WorkflowView,
+    pub working_dir: &'a Path,
+    pub session_id: &'a str,
+}
+
+impl CompletionTarget<'_> {
+    fn feature(&self) -> &str {
+        self.view
+            .feature_name()
+            .map(|f| f.as_str())
+            .unwrap_or("plan")
+    }
+
+    fn plan_path(&self) -> Result<&Path> {
+        self.view
+            .plan_path()
+            .map(|p| p.0.as_path())
+            .ok_or_else(|| anyhow!("workflow has no plan"))
+    }
+
+    /// `PLANNING_*` variables passed to formatter and shell commands.
+    fn env(&self, generated: &[PathBuf]) -> Vec<(&'static str, String)> {
+        let plan_path = self
+            .plan_path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        let generated = generated
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut env = vec![
+            ("PLANNING_SESSION_ID", self.session_id.to_string()),
+            ("PLANNING_FEATURE", self.feature().to_string()),
+            ("PLANNING_PLAN_PATH", plan_path),
+            (
+                "PLANNING_WORKING_DIR",
+                self.working_dir.display().to_string(),
+            ),
+            ("PLANNING_GENERATED_FILES", generated),
+        ];
+        if let Some(metadata) = self.view.plan_metadata() {
+            env.push(("PLANNING_PLAN_TITLE", metadata.title.clone()));
+            env.push(("PLANNING_PLAN_RISK", metadata.risk.as_str().to_string()));
+            env.push(("PLANNING_PLAN_EFFORT", metadata.effort.clone()));
+            env.push(("PLANNING_PLAN_COMPONENTS", metadata.components.join("\n")));
+        }
+        env
+    }
+
+    /// The notification text with `{feature}`, `{title}` and `{risk}` filled in.
+    /// Without plan front matter, `{title}` falls back to the feature name.
+    fn notification_text(&self, message: Option<&str>) -> String {
+        let metadata = self.view.plan_metadata();
+        message
+            .unwrap_or("Plan for {feature} accepted")
+            .replace("{feature}", self.feature())
+            .replace(
+                "{title}",
+                metadata.map_or(self.feature(), |m| m.title.as_str()),
+            )
+            .replace("{risk}", metadata.map_or("unknown", |m| m.risk.as_str()))
+    }
+}
+
+/// Runs the actions in order and returns one outcome per action.
+pub async fn run_completion_actions(
+    actions: &[CompletionAction],
+    target: &CompletionTarget<'_>,
+) -> Vec<CompletionActionOutcome> {
+    let mut generated: Vec<PathBuf> = Vec::new();
+    let mut outcomes = Vec::with_capacity(actions.len());
+    for action in actions {
+        let result = run_action(action, target, &mut generated).await;
+        outcomes.push(CompletionActionOutcome {
+            action: action.label(),
+            succeeded: result.is_ok(),
+            detail: result.unwrap_or_else(|e| format!("{:#}", e)),
+        });
+    }
+    outcomes
+}
+
+async fn run_action(
+    action: &CompletionAction,
+    target: &CompletionTarget<'_>,
+    generated: &mut Vec<PathBuf>,
+) -> Result<String> {
+    match action {
+        CompletionAction::CopyPlan { dest } => {
+            let dir = target.working_dir.join(dest);
+            tokio::fs::create_dir_all(&dir)
+                .await
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+            let copy = dir.join(format!("{}.md", target.feature()));
+            tokio::fs::copy(target.plan_path()?, &copy)
+                .await
+                .with_context(|| format!("Failed to write {}", copy.display()))?;
+            generated.push(copy.clone());
+            Ok(format!("wrote {}", copy.display()))
+        }
+        CompletionAction::Format { command } => {
+            if generated.is_empty() {
+                return Ok("skipped: no files were generated".to_string());
+            }
+            // Files follow as positional arguments so paths are never re-quoted
+            let script = format!("{} \"$@\"", command);
+            run_shell(&script, generated, target, generated).await
+        }
+        CompletionAction::Shell { command } => run_shell(command, &[], target, generated).await,
+        CompletionAction::Notify { webhook, message } => {
+            let text = target.notification_text(message.as_deref());
+            let webhook = webhook.clone();
+            tokio::task::spawn_blocking(move || post_notification(&webhook, &text))
+                .await
+                .context("Notification task panicked")??;
+            Ok("notification sent".to_string())
+        }
+    }
+}
+
+/// Runs `sh -c script` in the working directory, returning its last output line.
+async fn run_shell(
+    script: &str,
+    args: &[PathBuf],
+    target: &CompletionTarget<'_>,
+    generated: &[PathBuf],
+) -> Result<String> {
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(script)
+        .arg("sh")
+        .args(args)
+        .current_dir(target.working_dir)
+        .envs(target.env(generated))
+        .stdin(Stdio::null())
+        .kill_on_drop(true);
+
+    let output = tokio::time::timeout(ACTION_TIMEOUT, command.output())
+        .await
+        .map_err(|_| anyhow!("timed out after {}s", ACTION_TIMEOUT.as_secs()))?
+        .context("Failed to run command")?;
+    let last_line = |bytes: &[u8]| {
+        String::from_utf8_lossy(bytes)
+            .lines()
+            .rfind(|l| !l.trim().is_empty())
+            .map(|l| l.trim().to_string())
+    };
+    if !output.status.success() {
+        anyhow::bail!(
+            "{}{}",
+            output.status,
+            last_line(&output.stderr)
+                .map(|l| format!(": {}", l))
+                .unwrap_or_default()
+        );
+    }
+    Ok(last_line(&output.stdout).unwrap_or_else(|| "completed".to_string()))
+}
+
+fn post_notification(webhook: &str, text: &str) -> Result<()> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(NOTIFY_TIMEOUT))
+        .build()
+        .into();
+    agent
+        .post(webhook)
+        .header("Content-Type", "application/json")
+        .send(&serde_json::json!({ "text": text }).to_string())
+        .context("Failed to post notification")?;
+    Ok(())
+}
+
+/// Wall-clock time spent in one phase.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PhaseTime {
+    pub phase: String,
+    pub seconds: u64,
+}
+
+/// Usage of one agent across the run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AgentUsage {
+    pub agent: String,
+    pub invocations: u64,
+    pub tokens: u64,
+    /// None when the agent never reported a cost
+    pub cost_usd: Option<f64>,
+}
+
+/// What a workflow run took, shown on completion and saved as `summary.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WorkflowMetrics {
+    pub feature: String,
+    pub total_seconds: u64,
+    pub phases: Vec<PhaseTime>,
+    pub iterations: u32,
+    pub implementation_rounds: u32,
+    pub reviewer_rejections: u32,
+    pub agents: Vec<AgentUsage>,
+    pub files_changed: Vec<String>,
+    /// Front matter of the accepted plan, if it had one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<PlanMetadata>,
+    /// The user's `/notes`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl WorkflowMetrics {
+    /// Gathers the metrics of the run that produced `view`.
+    pub fn collect(
+        view: &WorkflowView,
+        sender: &SessionEventSender,
+        files_changed: Vec<String>,
+    ) -> Self {
+        let phases: Vec<PhaseTime> = sender
+            .phase_times()
+            .into_iter()
+            .map(|(phase, time)| PhaseTime {
+                phase,
+                seconds: time.as_secs(),
+            })
+            .collect();
+        Self {
+            feature: view
+                .feature_name()
+                .map(|f| f.as_str().to_string())
+                .unwrap_or_default(),
+            total_seconds: phases.iter().map(|p| p.seconds).sum(),
+            phases,
+            iterations: view.iteration().map(|i| i.0).unwrap_or(0),
+            implementation_rounds: view
+                .implementation_state()
+                .map(|state| state.iteration().0)
+                .unwrap_or(0),
+            reviewer_rejections: sender.reviewer_rejections(),
+            agents: agent_usage(&sender.invocation_usage()),
+            files_changed,
+            plan: view.plan_metadata().cloned(),
+            notes: view
+                .workflow_id()
+                .and_then(|id| planning_paths::session_dir(&id.to_string()).ok())
+                .and_then(|dir| load_session_notes(&dir)),
+        }
+    }
+
+    /// Lines of the summary card shown in the output and the completion overlay.
+    pub fn card(&self) -> Vec<String> {
+        let phases = self
+            .phases
+            .iter()
+            .map(|p| {
+                format!(
+                    "{} {}",
+                    p.phase,
+                    format_duration(Duration::from_secs(p.seconds))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut time = format!(
+            "Time: {}",
+            format_duration(Duration::from_secs(self.total_seconds))
+        );
+        if !phases.is_empty() {
+            time.push_str(&format!(" ({})", phases));
+        }
+
+        let mut rounds = format!("Iterations: {}", self.iterations);
+        if self.implementation_rounds > 0 {
+            rounds.push_str(&format!(
+                ", implementation rounds: {}",
+                self.implementation_rounds
+            ));
+        }
+        rounds.push_str(&format!(
+            ", reviewer rejections: {}",
+            self.reviewer_rejections
+        ));
+
+        let mut lines = Vec::new();
+        if let Some(plan) = &self.plan {
+            lines.push(format!(
+                "Plan: {} (risk: {}, effort: {})",
+                plan.title,
+                plan.risk.as_str(),
+                plan.effort
+            ));
+        }
+        lines.extend([time, rounds]);
+        for agent in &self.agents {
+            let cost = agent
+                .cost_usd
+                .map(|cost| format!(", ${:.2}", cost))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{}: {} invocation(s), {} tokens{}",
+                agent.agent,
+                agent.invocations,
+                format_tokens(agent.tokens),
+                cost
+            ));
+        }
+        lines.push(format!("Files changed: {}", self.files_changed.len()));
+        if let Some(notes) = &self.notes {
+            let mut note_lines = notes.lines().filter(|line| !line.trim().is_empty());
+            let first = note_lines.next().unwrap_or_default().trim();
+            let more = note_lines.count();
+            lines.push(match more {
+                0 => format!("Notes: {}", first),
+                _ => format!("Notes: {} (+{} more line(s) in summary.json)", first, more),
+            });
+        }
+        lines
+    }
+}
+
+/// Totals the usage ledger per agent, sorted by agent name.
+fn agent_usage(usage: &[InvocationUsage]) -> Vec<AgentUsage> {
+    let mut by_agent: BTreeMap<&str, AgentUsage> = BTreeMap::new();
+    for invocation in usage {
+        let totals = by_agent
+            .entry(invocation.agent.as_str())
+            .or_insert_with(|| AgentUsage {
+                agent: invocation.agent.clone(),
+                ..AgentUsage::default()
+            });
+        totals.invocations += 1;
+        totals.tokens += invocation.tokens;
+        if let Some(cost) = invocation.cost {
+            *totals.cost_usd.get_or_insert(0.0) += cost;
+        }
+    }
+    by_agent.into_values().collect()
+}
+
+/// Files changed in the working tree, plus those committed on the worktree
+/// branch when it was forked from `source_branch`. Empty outside a git repo.
+async fn changed_files(working_dir: &Path, source_branch: Option<&str>) -> Vec<String> {
+    let git = |args: Vec<String>| async move {
+        Command::new("git")
+            .args(&args)
+            .current_dir(working_dir)
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            .unwrap_or_default()
+    };
+
+    let mut files = BTreeSet::new();
+    // Format: "XY path", or "XY old -> new" for renames
+    for line in git(vec!["status".into(), "--porcelain".into()])
+        .await
+        .lines()
+    {
+        if let Some(path) = line.get(3..) {
+            let path = path.rsplit(" -> ").next().unwrap_or(path).trim();
+            if !path.is_empty() {
+                files.insert(path.to_string());
+            }
+        }
+    }
+    if let Some(source) = source_branch {
+        let range = format!("{}...HEAD", source);
+        let committed = git(vec!["diff".into(), "--name-only".into(), range]).await;
+        files.extend(
+            committed
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string),
+        );
+    }
+    files.into_iter().collect()
+}
+
+/// Prints the run's metrics card and writes `summary.json`, returning the card.
+async fn report_metrics(
+    phase_context: &WorkflowPhaseContext<'_>,
+    view: &WorkflowView,
+    session_id: &str,
+) -> Vec<String> {
+    let source_branch = view.worktree_info().and_then(|w| w.source_branch());
+    let files = changed_files(phase_context.working_dir, source_branch).await;
+    let metrics = WorkflowMetrics::collect(view, phase_context.sender, files);
+    let card = metrics.card();
+
+    let sender = phase_context.sender;
+    sender.send_output("[summary] Workflow complete".to_string());
+    for line in &card {
+        sender.send_output(format!("[summary] {}", line));
+    }
+    let written = planning_paths::session_summary_path(session_id).and_then(|path| {
+        let json = serde_json::to_string_pretty(&metrics)?;
+        std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    });
+    if let Err(e) = written {
+        sender.send_output(format!("[summary] Could not save summary.json: {:#}", e));
+    }
+    card
+}
+
+/// Shows the run's metrics, then runs the configured actions, records each
+/// outcome and shows the summary.
+pub async fn run_completion_pipeline(
+    phase_context: &WorkflowPhaseContext<'_>,
+    view: &WorkflowView,
+    session_id: &str,
+) {
+    let metrics = report_metrics(phase_context, view, session_id).await;
+    let actions = &phase_context.config.completion_actions;
+    let sender = phase_context.sender;
+    if actions.is_empty() {
+        sender.send_completion_summary(metrics, Vec::new());
+        return;
+    }
+    sender.send_output(format!(
+        "[completion] Running {} completion action(s)...",
+        actions.len()
+    ));
+    let target = CompletionTarget {
+        view,
+        working_dir: phase_context.working_dir,
+        session_id,
+    };
+    let outcomes = run_completion_actions(actions, &target).await;
+    for outcome in &outcomes {
+        let status = if outcome.succeeded { "ok" } else { "FAILED" };
+        sender.send_output(format!(
+            "[completion] {} - {}: {}",
+            outcome.action, status, outcome.detail
+        ));
+        phase_context
+            .dispatch_command(DomainCommand::RecordCompletionAction {
+                outcome: outcome.clone(),
+            })
+            .await;
+    }
+    sender.send_completion_summary(metrics, outcomes);
+}
+
+#[cfg(test)]
+#[path = "tests/completion_actions_tests.rs"]
+mod tests;
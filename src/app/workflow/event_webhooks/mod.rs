@@ -0,0 +1,179 @@
+//! Workflow events posted to webhooks for external automation.
+//!
+//! Each entry of the workflow config's `event_webhooks` receives the session's
+//! events as they are recorded, one `POST` per `WorkflowEventEnvelope` with the
+//! envelope as its JSON body, optionally limited to some event types. With a
+//! secret, the body is signed with HMAC-SHA256 and the signature is sent as
+//! `X-Planning-Signature-256: sha256=<hex>`, so receivers can verify it came
+//! from this workflow. Events are delivered in order from a background task; a
+//! failed delivery is logged and not retried.
+
+use crate::domain::view::WorkflowEventEnvelope;
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
+use anyhow::{Context, Result};
+use cqrs_es::DomainEvent;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Time allowed for one webhook request.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A webhook receiving workflow events.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EventWebhook {
+    pub url: String,
+    /// Event types to post (e.g. `PlanningCompleted`); empty posts every event.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
+    /// HMAC-SHA256 signing secret. Prefer `secret_env` to keep secrets out of
+    /// workflow files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Environment variable read when `secret` is unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_env: Option<String>,
+}
+
+impl EventWebhook {
+    pub fn validate(&self) -> Result<()> {
+        if !self.url.starts_with("http://") && !self.url.starts_with("https://") {
+            anyhow::bail!("Event webhook URL '{}' must be http(s)", self.url);
+        }
+        Ok(())
+    }
+
+    pub fn wants(&self, event_type: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event_type)
+    }
+
+    /// The signing secret, or None to post unsigned. Fails when `secret_env`
+    /// names a variable that isn't set, rather than posting unsigned.
+    pub fn resolve_secret(&self) -> Result<Option<String>> {
+        if let Some(secret) = self.secret.as_ref().filter(|s| !s.is_empty()) {
+            return Ok(Some(secret.clone()));
+        }
+        let Some(var) = &self.secret_env else {
+            return Ok(None);
+        };
+        std::env::var(var)
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(Some)
+            .with_context(|| format!("Webhook secret not set (expected ${})", var))
+    }
+}
+
+/// HMAC-SHA256 of `body`, hex encoded.
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    const BLOCK_SIZE: usize = 64;
+    let mut key = if secret.len() > BLOCK_SIZE {
+        Sha256::digest(secret).to_vec()
+    } else {
+        secret.to_vec()
+    };
+    key.resize(BLOCK_SIZE, 0);
+    let padded = |pad: u8| key.iter().map(|k| k ^ pad).collect::<Vec<u8>>();
+    let inner = Sha256::new()
+        .chain_update(padded(0x36))
+        .chain_update(body)
+        .finalize();
+    Sha256::new()
+        .chain_update(padded(0x5c))
+        .chain_update(inner)
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Queues events for the background task posting them.
+pub struct EventWebhooks {
+    tx: Option<mpsc::UnboundedSender<WorkflowEventEnvelope>>,
+}
+
+impl EventWebhooks {
+    pub fn post(&self, event: &WorkflowEventEnvelope) {
+        if let Some(tx) = &self.tx {
+            // The delivery task only stops with the workflow
+            let _ = tx.send(event.clone());
+        }
+    }
+}
+
+/// Starts delivering to the configured webhooks. Webhooks whose secret can't
+/// be resolved are logged and skipped.
+pub fn spawn(webhooks: &[EventWebhook], logger: Arc<SessionLogger>) -> EventWebhooks {
+    let mut targets = Vec::new();
+    for webhook in webhooks {
+        match webhook.resolve_secret() {
+            Ok(secret) => targets.push((webhook.clone(), secret)),
+            Err(e) => logger.log(
+                LogLevel::Warn,
+                LogCategory::Workflow,
+                &format!("Event webhook {} disabled: {:#}", webhook.url, e),
+            ),
+        }
+    }
+    if targets.is_empty() {
+        return EventWebhooks { tx: None };
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<WorkflowEventEnvelope>();
+    tokio::spawn(async move {
+        let targets = Arc::new(targets);
+        while let Some(event) = rx.recv().await {
+            let targets = Arc::clone(&targets);
+            let delivered = tokio::task::spawn_blocking(move || deliver(&targets, &event)).await;
+            for error in delivered.unwrap_or_default() {
+                logger.log(LogLevel::Warn, LogCategory::Workflow, &error);
+            }
+        }
+    });
+    EventWebhooks { tx: Some(tx) }
+}
+
+/// Posts the event to every webhook that wants it. Returns the failures.
+fn deliver(
+    targets: &[(EventWebhook, Option<String>)],
+    event: &WorkflowEventEnvelope,
+) -> Vec<String> {
+    let event_type = event.event.event_type();
+    let body = match serde_json::to_string(event) {
+        Ok(body) => body,
+        Err(e) => {
+            return vec![format!(
+                "Failed to serialize {} for webhooks: {}",
+                event_type, e
+            )]
+        }
+    };
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(WEBHOOK_TIMEOUT))
+        .build()
+        .into();
+    let mut errors = Vec::new();
+    for (webhook, secret) in targets.iter().filter(|(w, _)| w.wants(&event_type)) {
+        let mut request = agent
+            .post(&webhook.url)
+            .header("Content-Type", "application/json")
+            .header("X-Planning-Event", &event_type);
+        if let Some(secret) = secret {
+            let signature = format!("sha256={}", sign(secret.as_bytes(), body.as_bytes()));
+            request = request.header("X-Planning-Signature-256", &signature);
+        }
+        if let Err(e) = request.send(&body) {
+            errors.push(format!(
+                "Event webhook {} failed for {}: {}",
+                webhook.url, event_type, e
+            ));
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+#[path = "tests/event_webhooks_tests.rs"]
+mod tests;
@@ -0,0 +1,140 @@
+use super::*;
+use crate::domain::types::TimestampUtc;
+use crate::domain::WorkflowEvent;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+fn webhook(url: &str) -> EventWebhook {
+    EventWebhook {
+        url: url.to_string(),
+        events: Vec::new(),
+        secret: None,
+        secret_env: None,
+    }
+}
+
+fn planning_started() -> WorkflowEventEnvelope {
+    WorkflowEventEnvelope {
+        aggregate_id: "session-1".to_string(),
+        sequence: 2,
+        event: WorkflowEvent::PlanningStarted {
+            started_at: TimestampUtc::now(),
+        },
+    }
+}
+
+#[test]
+fn test_sign_matches_rfc_4231_vectors() {
+    assert_eq!(
+        sign(b"Jefe", b"what do ya want for nothing?"),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    // Keys longer than the block size are hashed first
+    assert_eq!(
+        sign(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        ),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn test_event_filter_and_validation() {
+    let mut hook = webhook("https://ci.example.com/hooks/planning");
+    assert!(hook.validate().is_ok());
+    assert!(hook.wants("PlanningStarted"));
+
+    hook.events = vec!["PlanningCompleted".to_string()];
+    assert!(hook.wants("PlanningCompleted"));
+    assert!(!hook.wants("PlanningStarted"));
+
+    assert!(webhook("ci.example.com/hook").validate().is_err());
+}
+
+#[test]
+fn test_resolve_secret_prefers_inline_and_requires_set_env() {
+    let mut hook = webhook("https://example.com");
+    assert_eq!(hook.resolve_secret().unwrap(), None);
+
+    hook.secret_env = Some("PLANNING_TEST_WEBHOOK_SECRET_UNSET".to_string());
+    assert!(hook.resolve_secret().is_err());
+
+    hook.secret = Some("inline".to_string());
+    assert_eq!(hook.resolve_secret().unwrap(), Some("inline".to_string()));
+}
+
+#[test]
+fn test_deliver_posts_signed_envelope_to_matching_hooks() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            headers.push(line.trim().to_lowercase());
+        }
+        let length: usize = headers
+            .iter()
+            .find_map(|h| h.strip_prefix("content-length: "))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        (headers, String::from_utf8(body).unwrap())
+    });
+
+    let mut skipped = webhook(&url);
+    skipped.events = vec!["PlanningCompleted".to_string()];
+    let targets = vec![(skipped, None), (webhook(&url), Some("s3cret".to_string()))];
+    assert!(deliver(&targets, &planning_started()).is_empty());
+
+    let (headers, body) = server.join().unwrap();
+    let posted: WorkflowEventEnvelope = serde_json::from_str(&body).unwrap();
+    assert_eq!(posted.aggregate_id, "session-1");
+    assert_eq!(posted.sequence, 2);
+    assert!(headers.contains(&"x-planning-event: planningstarted".to_string()));
+    let signature = format!(
+        "x-planning-signature-256: sha256={}",
+        sign(b"s3cret", body.as_bytes())
+    );
+    assert!(headers.contains(&signature), "{:?}", headers);
+}
+
+#[test]
+fn test_deliver_reports_failures() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/events", listener.local_addr().unwrap());
+    drop(listener);
+    let errors = deliver(&[(webhook(&url), None)], &planning_started());
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].contains("failed for PlanningStarted"),
+        "{}",
+        errors[0]
+    );
+}
+
+#[test]
+fn test_event_webhooks_parse_from_config() {
+    let yaml = r#"
+url: https://example.com/hook
+events: [PlanningCompleted, ReviewerRejected]
+secret_env: PLANNING_WEBHOOK_SECRET
+"#;
+    let hook: EventWebhook = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(hook.events.len(), 2);
+    assert_eq!(hook.secret_env.as_deref(), Some("PLANNING_WEBHOOK_SECRET"));
+    assert_eq!(hook.secret, None);
+}
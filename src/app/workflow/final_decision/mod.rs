@@ -0,0 +1,150 @@
+//! The user's final decision on a completed plan, with a grace window for `/undo`.
+//!
+//! The decision is recorded as soon as it is made, but for `undo_grace_secs`
+//! nothing acts on it: no completion actions, implementation or replanning.
+//! `/undo` from the session's command prompt in that window records a
+//! compensating `UserDecisionUndone` event, removes approval conditions the
+//! decision appended to the plan, and asks for the decision again.
+
+use super::completion::{handle_completion, remove_approval_conditions};
+use super::reviewing::WorkflowPhaseContext;
+use super::WorkflowResult;
+use crate::domain::types::UndoneDecision;
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::tui::{UserApprovalResponse, WorkflowCommand};
+use anyhow::Result;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+
+/// How the grace window after a decision ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraceOutcome {
+    /// The window passed; the decision stands.
+    Stands,
+    /// `/undo` arrived in the window.
+    Undone,
+    /// The workflow was stopped in the window.
+    Stopped,
+}
+
+/// Asks for the final decision, records it and holds it for the grace window.
+/// Returns the decision once it stands.
+pub async fn decide_on_plan(
+    view_rx: &watch::Receiver<WorkflowView>,
+    context: &WorkflowPhaseContext<'_>,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+    undo_rx: &mut mpsc::UnboundedReceiver<()>,
+) -> Result<WorkflowResult> {
+    let grace = Duration::from_secs(context.config.undo_grace_secs);
+    loop {
+        let view = view_rx.borrow().clone();
+        let (result, conditions) = handle_completion(
+            &view,
+            &context.session_logger,
+            context.sender,
+            approval_rx,
+            control_rx,
+        )
+        .await?;
+        let with_conditions = conditions.is_some();
+        let Some(decision) = record_decision(context, &result, conditions).await else {
+            return Ok(result);
+        };
+
+        if grace.is_zero() {
+            return Ok(result);
+        }
+        context.sender.send_output(format!(
+            "[planning] Type /undo within {}s to take this decision back",
+            grace.as_secs()
+        ));
+        match await_undo(grace, undo_rx, control_rx).await {
+            GraceOutcome::Stands => return Ok(result),
+            GraceOutcome::Stopped => return Ok(WorkflowResult::Stopped),
+            GraceOutcome::Undone => {}
+        }
+
+        if with_conditions {
+            if let Some(plan_path) = view.plan_path() {
+                remove_approval_conditions(&plan_path.0)?;
+            }
+        }
+        context.log_workflow(&format!("User undid their decision: {:?}", decision));
+        context
+            .dispatch_command(DomainCommand::UndoUserDecision { decision })
+            .await;
+        context
+            .sender
+            .send_output("[planning] Decision undone - decide again".to_string());
+    }
+}
+
+/// Records the events of the user's decision and returns what `/undo` would
+/// take back. Returns None for results that are not a decision on the plan.
+async fn record_decision(
+    context: &WorkflowPhaseContext<'_>,
+    result: &WorkflowResult,
+    conditions: Option<String>,
+) -> Option<UndoneDecision> {
+    let with_conditions = conditions.is_some();
+    if let Some(conditions) = conditions {
+        context
+            .dispatch_command(DomainCommand::UserApprovedWithConditions { conditions })
+            .await;
+    }
+    match result {
+        WorkflowResult::Accepted => {
+            if !with_conditions {
+                context.dispatch_command(DomainCommand::UserApproved).await;
+            }
+            Some(UndoneDecision::Approved)
+        }
+        WorkflowResult::ImplementationRequested => {
+            context
+                .dispatch_command(DomainCommand::UserRequestedImplementation)
+                .await;
+            Some(UndoneDecision::ImplementationRequested)
+        }
+        WorkflowResult::NeedsRestart { user_feedback } => {
+            context
+                .dispatch_command(DomainCommand::UserDeclined {
+                    feedback: user_feedback.clone(),
+                })
+                .await;
+            Some(UndoneDecision::Declined {
+                feedback: user_feedback.clone(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Waits out the grace window, watching for `/undo` and for a stop.
+pub async fn await_undo(
+    grace: Duration,
+    undo_rx: &mut mpsc::UnboundedReceiver<()>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> GraceOutcome {
+    // Requests sent before the decision was made do not count
+    while undo_rx.try_recv().is_ok() {}
+    let deadline = tokio::time::sleep(grace);
+    tokio::pin!(deadline);
+    loop {
+        tokio::select! {
+            _ = &mut deadline => return GraceOutcome::Stands,
+            Some(()) = undo_rx.recv() => return GraceOutcome::Undone,
+            Some(cmd) = control_rx.recv() => {
+                // An interrupt has nothing to interrupt until the window passes
+                if matches!(cmd, WorkflowCommand::Stop) {
+                    return GraceOutcome::Stopped;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/final_decision_tests.rs"]
+mod tests;
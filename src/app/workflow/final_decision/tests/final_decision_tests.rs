@@ -0,0 +1,45 @@
+use super::*;
+
+const GRACE: Duration = Duration::from_millis(200);
+
+#[tokio::test]
+async fn test_undo_within_the_grace_window() {
+    let (undo_tx, mut undo_rx) = mpsc::unbounded_channel();
+    let (_control_tx, mut control_rx) = mpsc::channel(1);
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        undo_tx.send(()).unwrap();
+    });
+    let outcome = await_undo(Duration::from_secs(5), &mut undo_rx, &mut control_rx).await;
+
+    assert_eq!(outcome, GraceOutcome::Undone);
+}
+
+#[tokio::test]
+async fn test_undo_sent_before_the_decision_does_not_count() {
+    let (undo_tx, mut undo_rx) = mpsc::unbounded_channel();
+    let (_control_tx, mut control_rx) = mpsc::channel(1);
+    undo_tx.send(()).unwrap();
+
+    let outcome = await_undo(GRACE, &mut undo_rx, &mut control_rx).await;
+
+    assert_eq!(outcome, GraceOutcome::Stands);
+}
+
+#[tokio::test]
+async fn test_stop_ends_the_grace_window() {
+    let (_undo_tx, mut undo_rx) = mpsc::unbounded_channel();
+    let (control_tx, mut control_rx) = mpsc::channel(2);
+    control_tx
+        .send(WorkflowCommand::Interrupt {
+            feedback: "too late".to_string(),
+        })
+        .await
+        .unwrap();
+    control_tx.send(WorkflowCommand::Stop).await.unwrap();
+
+    let outcome = await_undo(Duration::from_secs(5), &mut undo_rx, &mut control_rx).await;
+
+    assert_eq!(outcome, GraceOutcome::Stopped);
+}
@@ -35,104 +35,39 @@
 //! }
 //! ```
 
+pub mod approval_gates;
+mod backup_reviewers;
 mod completion;
+mod completion_actions;
+pub mod event_webhooks;
+mod final_decision;
+pub mod phase_timer;
+mod plan_watch;
 mod planning;
+mod planning_decision;
+mod remote_commands;
+mod replan_watch;
+mod review_cache;
 mod reviewing;
 mod revising;
+mod round_retry;
+mod section_review;
+mod section_stream;
+mod session_context;
+mod triage;
+mod updates;
 
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::WorkflowCommand as DomainCommand;
-use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
-use ractor::ActorRef;
+use crate::session_daemon::{LogCategory, LogLevel};
 use tokio::sync::oneshot;
 
-/// Dispatches a domain command to the workflow actor with full error handling.
-///
-/// Handles all three response cases:
-/// - `Ok(Ok(_view))` - command succeeded, logs at Info level
-/// - `Ok(Err(e))` - command was rejected by actor, logs at Warn level
-/// - `Err(_)` - reply channel dropped, logs at Warn level
-pub(crate) async fn dispatch_domain_command(
-    actor_ref: &Option<ActorRef<WorkflowMessage>>,
-    cmd: DomainCommand,
-    session_logger: &SessionLogger,
-) {
-    if let Some(ref actor) = actor_ref {
-        let (reply_tx, reply_rx) = oneshot::channel();
-        if let Err(e) =
-            actor.send_message(WorkflowMessage::Command(Box::new(cmd.clone()), reply_tx))
-        {
-            session_logger.log(
-                LogLevel::Warn,
-                LogCategory::Workflow,
-                &format!("Failed to dispatch command {:?}: {}", cmd, e),
-            );
-            return;
-        }
-        match reply_rx.await {
-            Ok(Ok(_view)) => {
-                session_logger.log(
-                    LogLevel::Info,
-                    LogCategory::Workflow,
-                    &format!("Command dispatched: {:?}", cmd),
-                );
-            }
-            Ok(Err(e)) => {
-                session_logger.log(
-                    LogLevel::Warn,
-                    LogCategory::Workflow,
-                    &format!("Command rejected: {:?}: {:?}", cmd, e),
-                );
-            }
-            Err(_) => {
-                session_logger.log(
-                    LogLevel::Warn,
-                    LogCategory::Workflow,
-                    &format!("Command reply channel dropped: {:?}", cmd),
-                );
-            }
-        }
-    }
-}
-
-/// Send error status to daemon tracker when a workflow failure has been recorded.
-/// This enables the host-gui to detect and notify on planning workflow failures.
-///
-/// Should be called BEFORE mark_stopped() when has_failure() returns true.
-async fn send_error_status_if_failure(
-    tracker: &SessionTracker,
-    view_rx: &tokio::sync::watch::Receiver<crate::domain::view::WorkflowView>,
-    workflow_session_id: &str,
-) {
-    // Extract all values from view in a block to ensure borrow is dropped before await
-    let update_info = {
-        let view = view_rx.borrow();
-        if view.has_failure() {
-            let phase = view.planning_phase().unwrap_or(Phase::Planning);
-            let iteration = view.iteration().unwrap_or_default().0;
-            Some((phase, iteration))
-        } else {
-            None
-        }
-    }; // view borrow is definitely dropped here
-
-    if let Some((_phase, iteration)) = update_info {
-        let _ = tracker
-            .update(
-                workflow_session_id,
-                "Planning".to_string(), // Planning workflow phase is always "Planning"
-                iteration,
-                "Error".to_string(),
-                None,
-            )
-            .await;
-    }
-}
+pub use plan_watch::plan_modified_on_disk;
+pub(crate) use updates::dispatch_domain_command;
 
+use crate::agents::cost_stats;
 use crate::app::implementation::{run_implementation_workflow, ImplementationContext};
-use crate::app::workflow_decisions::{
-    await_max_iterations_decision, IterativePhase, MaxIterationsDecision,
-};
+use crate::app::workflow_decisions::{await_max_iterations_decision, IterativePhase};
 use crate::config::WorkflowConfig;
 use crate::domain::actor::{create_actor_args, WorkflowActor};
 use crate::domain::input::WorkflowInput;
@@ -143,14 +78,16 @@ use crate::session_daemon::SessionTracker;
 use crate::structured_logger::StructuredLogger;
 use crate::tui::{
     CancellationError, Event, SessionEventSender, UserApprovalResponse, WorkflowCommand,
+    WorkflowUpdate,
 };
 use anyhow::Result;
 use ractor::Actor;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
-use completion::handle_completion;
+use completion::send_error_status_if_failure;
+use completion_actions::run_completion_pipeline;
 use planning::run_planning_phase;
 use reviewing::{
     build_max_iterations_summary_from_view, run_reviewing_phase, run_sequential_reviewing_phase,
@@ -170,6 +107,10 @@ pub enum WorkflowResult {
     },
     /// Workflow was cleanly stopped at a phase boundary
     Stopped,
+    /// The session is open in another process, so it is only followed read-only
+    Following {
+        holder_pid: u32,
+    },
 }
 
 pub struct WorkflowRunConfig {
@@ -178,6 +119,8 @@ pub struct WorkflowRunConfig {
     pub output_tx: mpsc::UnboundedSender<Event>,
     pub approval_rx: mpsc::Receiver<UserApprovalResponse>,
     pub control_rx: mpsc::Receiver<WorkflowCommand>,
+    /// Session changes from `/rename` and `/reviewers`.
+    pub update_rx: mpsc::UnboundedReceiver<WorkflowUpdate>,
     pub session_id: usize,
     pub run_id: u64,
     /// If true, disable session daemon tracking (for tests/headless mode)
@@ -190,10 +133,11 @@ pub async fn run_workflow_with_config(
 ) -> Result<WorkflowResult> {
     let WorkflowRunConfig {
         working_dir,
-        config,
+        mut config,
         output_tx,
-        mut approval_rx,
-        mut control_rx,
+        approval_rx,
+        control_rx,
+        update_rx,
         session_id,
         run_id,
         no_daemon,
@@ -204,6 +148,24 @@ pub async fn run_workflow_with_config(
     // Get workflow session ID from input
     let workflow_session_id = input.workflow_session_id();
     let workflow_session_id_str = workflow_session_id.to_string();
+    // Warm agent processes (warm_pool: true) live until this run ends
+    let _warm_pool = crate::agents::claude::warm_pool::WarmPoolGuard::new(&workflow_session_id_str);
+
+    // Hold the session before writing to it; another process's session is only followed
+    let tracker = Arc::new(SessionTracker::new(no_daemon).await);
+    let (mut approval_rx, control_rx) = remote_commands::relay_remote_commands(
+        approval_rx,
+        control_rx,
+        tracker.remote_commands(),
+        workflow_session_id_str.clone(),
+        sender.clone(),
+        tracker.clone(),
+    );
+    let mut control_rx =
+        match updates::claim_session_lease(&input, &tracker, &sender, control_rx).await {
+            Ok(control_rx) => control_rx,
+            Err(result) => return Ok(result),
+        };
 
     // Create session logger for workflow events
     let session_logger = create_session_logger(&workflow_session_id_str)?;
@@ -213,6 +175,16 @@ pub async fn run_workflow_with_config(
         "Session logger initialized",
     );
 
+    if let Err(e) = crate::agents::redaction::configure(&config.redaction) {
+        sender.send_output(format!("[planning] {:#}", e));
+    }
+
+    // Probe the agent CLIs before the first phase, so missing features show up front
+    for warning in crate::agents::capabilities::probe_agents(&config.agents).await {
+        session_logger.log(LogLevel::Warn, LogCategory::Workflow, &warning);
+        sender.send_output(format!("[planning] {}", warning));
+    }
+
     // Create structured JSONL logger for debugging
     let structured_logger = {
         let logs_dir = planning_paths::session_logs_dir(&workflow_session_id_str)?;
@@ -226,6 +198,8 @@ pub async fn run_workflow_with_config(
     let (actor_ref, _actor_handle) = WorkflowActor::spawn(None, WorkflowActor, actor_args)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to spawn workflow actor: {}", e))?;
+    let recorder = updates::spawn_failure_recorder(actor_ref.clone(), session_logger.clone());
+    let sender = sender.with_failure_recorder(recorder);
 
     // Keep view_rx for reading current view in the main loop
     // Also spawn a task to forward CQRS view updates to TUI
@@ -239,16 +213,15 @@ pub async fn run_workflow_with_config(
         }
     });
 
-    // Create session tracker for daemon integration early so we can use it for event streaming
-    let tracker = Arc::new(SessionTracker::new(no_daemon).await);
-
-    // Spawn task to forward CQRS events to daemon for broadcasting to subscribers
+    // Spawn task to forward CQRS events to the daemon and the event webhooks
     {
         let session_id_for_events = workflow_session_id_str.clone();
         let tracker_clone = tracker.clone();
+        let webhooks = event_webhooks::spawn(&config.event_webhooks, session_logger.clone());
         let mut event_rx = event_rx;
         tokio::spawn(async move {
             while let Ok(event) = event_rx.recv().await {
+                webhooks.post(&event);
                 // Forward event to daemon (ignore errors - daemon may not be running)
                 let _ = tracker_clone
                     .workflow_event(&session_id_for_events, event)
@@ -266,6 +239,11 @@ pub async fn run_workflow_with_config(
     // For new workflows, send CreateWorkflow command
     // For resumed workflows, the aggregate state is replayed from the event log
     if let WorkflowInput::New(ref new_input) = input {
+        sender.send_output(format!(
+            "[planning] {}",
+            cost_stats::describe_estimate(&config, new_input.max_iterations.0)
+        ));
+
         let plan_path = planning_paths::session_plan_path(&workflow_session_id_str)?;
         let feedback_path = planning_paths::session_feedback_path(&workflow_session_id_str, 1)?;
 
@@ -274,7 +252,7 @@ pub async fn run_workflow_with_config(
             objective: new_input.objective.clone(),
             working_dir: WorkingDir::from(working_dir.as_path()),
             max_iterations: new_input.max_iterations,
-            plan_path: PlanPath::from(plan_path),
+            plan_path: PlanPath::from(plan_path.clone()),
             feedback_path: FeedbackPath::from(feedback_path),
         };
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -310,23 +288,18 @@ pub async fn run_workflow_with_config(
             }
         }
 
-        // Dispatch StartPlanning command after workflow creation
-        let start_cmd = DomainCommand::StartPlanning;
-        let (reply_tx, reply_rx) = oneshot::channel();
-        if let Err(e) =
-            actor_ref.send_message(WorkflowMessage::Command(Box::new(start_cmd), reply_tx))
-        {
-            session_logger.log(
-                LogLevel::Warn,
-                LogCategory::Workflow,
-                &format!("Failed to send StartPlanning command: {}", e),
-            );
-        } else if reply_rx.await.is_err() {
-            session_logger.log(
-                LogLevel::Warn,
-                LogCategory::Workflow,
-                "StartPlanning reply channel dropped",
-            );
+        // An imported plan is approved as given; otherwise planning starts
+        let actor = Some(actor_ref.clone());
+        if let Some(ref source) = new_input.imported_plan {
+            planning::import_plan(&actor, source, &plan_path, &session_logger).await?;
+        } else {
+            dispatch_domain_command(&actor, DomainCommand::StartPlanning, &session_logger).await;
+        }
+        if let Some(ref watch) = new_input.replan_watch {
+            let cmd = DomainCommand::EnableReplanWatch {
+                watch: watch.clone(),
+            };
+            dispatch_domain_command(&actor, cmd, &session_logger).await;
         }
 
         // Dispatch AttachWorktree command if worktree info is present
@@ -351,6 +324,26 @@ pub async fn run_workflow_with_config(
                 );
             }
         }
+
+        session_context::save_session_context(
+            new_input,
+            &workflow_session_id_str,
+            &working_dir,
+            &config,
+            &actor,
+            &session_logger,
+        )
+        .await?;
+    }
+
+    // An objective edited with /edit-objective replaces the old one before planning resumes
+    if let WorkflowInput::Resume(ref resume) = input {
+        if let Some(ref objective) = resume.amended_objective {
+            let cmd = DomainCommand::AmendObjective {
+                objective: objective.clone(),
+            };
+            dispatch_domain_command(&Some(actor_ref.clone()), cmd, &session_logger).await;
+        }
     }
 
     // Get the initial view from the actor
@@ -362,6 +355,18 @@ pub async fn run_workflow_with_config(
         WorkflowInput::Resume(_) => view.feature_name().map(|f| f.0.clone()).unwrap_or_default(),
     };
     let initial_phase = view.planning_phase().unwrap_or(Phase::Planning);
+    // A preset from --reviewers, or the one a resumed session last reviewed with
+    let initial_preset = match &input {
+        WorkflowInput::New(new_input) => new_input.reviewer_preset.clone(),
+        WorkflowInput::Resume(_) => view.reviewer_preset().map(str::to_string),
+    };
+    if let Some(preset) = initial_preset.filter(|_| config.workflow.reviewing.preset.is_none()) {
+        updates::apply_reviewer_preset(&mut config, &preset, &sender, &session_logger);
+    }
+    // A review mode switched to earlier holds for the rest of the session
+    if let Some(sequential) = view.review_sequential() {
+        config.workflow.reviewing.sequential = sequential;
+    }
     let initial_iteration = view.iteration().unwrap_or(Iteration::first()).0;
 
     // Register session with daemon (now passing session_dir instead of state_path)
@@ -384,6 +389,29 @@ pub async fn run_workflow_with_config(
         );
     }
 
+    let (preset_tx, mut preset_rx) = watch::channel(None);
+    let (review_mode_tx, review_mode_rx) = watch::channel(None);
+    let (undo_tx, mut undo_rx) = mpsc::unbounded_channel();
+    let _update_listener = updates::spawn_update_listener(
+        update_rx,
+        actor_ref.clone(),
+        tracker.clone(),
+        workflow_session_id_str.clone(),
+        sender.clone(),
+        session_logger.clone(),
+        updates::LoopUpdateSenders {
+            preset_tx,
+            review_mode_tx,
+            undo_tx,
+        },
+    );
+    let mut review_mode = updates::ReviewModeSwitch::new(
+        review_mode_rx,
+        actor_ref.clone(),
+        sender.clone(),
+        session_logger.clone(),
+    );
+
     session_logger.log(
         LogLevel::Info,
         LogCategory::Workflow,
@@ -393,15 +421,10 @@ pub async fn run_workflow_with_config(
         ),
     );
 
-    let phase_context = WorkflowPhaseContext {
-        working_dir: &working_dir,
-        config: &config,
-        sender: &sender,
-        session_logger: session_logger.clone(),
-        actor_ref: Some(actor_ref),
-    };
-
     let mut last_reviews: Vec<crate::phases::ReviewResult> = Vec::new();
+    let mut review_cache = review_cache::ReviewCache::default();
+    let mut section_owners = section_review::SectionOwners::default();
+    let mut completed_phase: Option<Phase> = None;
 
     loop {
         // Get the current view at the start of each loop iteration
@@ -411,7 +434,49 @@ pub async fn run_workflow_with_config(
             break;
         }
 
+        // Switch reviewers between rounds, never in the middle of a sequential cycle
+        if view.planning_phase() != Some(Phase::Reviewing)
+            && preset_rx.has_changed().unwrap_or(false)
+        {
+            if let Some(preset) = preset_rx.borrow_and_update().clone() {
+                updates::apply_reviewer_preset(&mut config, &preset, &sender, &session_logger);
+            }
+        }
+        review_mode.apply(&view, &mut config).await;
+        let phase_context = WorkflowPhaseContext {
+            working_dir: &working_dir,
+            config: &config,
+            sender: &sender,
+            session_logger: session_logger.clone(),
+            actor_ref: Some(actor_ref.clone()),
+        };
+
+        // Pick up plan edits made while the session was paused or awaiting a decision
+        let view = if plan_watch::record_external_plan_edit(&view, &phase_context).await {
+            view_rx_for_loop.borrow().clone()
+        } else {
+            view
+        };
+
+        // Approval gates from the config wait between phases of the plan loop
+        if let Some(result) = approval_gates::pass_gates(
+            &view,
+            completed_phase.take(),
+            &phase_context,
+            &mut approval_rx,
+            &mut control_rx,
+            &mut last_reviews,
+        )
+        .await
+        {
+            // Daemon tracking is best-effort - ignore errors if daemon not running
+            let _ = tracker.mark_stopped(&workflow_session_id_str).await;
+            return Ok(result);
+        }
+        let view = view_rx_for_loop.borrow().clone();
+
         let current_phase = view.planning_phase().unwrap_or(Phase::Planning);
+        completed_phase = Some(current_phase);
         match current_phase {
             Phase::Planning => {
                 let result = run_planning_phase(
@@ -491,6 +556,7 @@ pub async fn run_workflow_with_config(
 
             Phase::Reviewing => {
                 // Choose sequential or parallel review based on config
+                review_mode.round_starting(config.workflow.reviewing.sequential);
                 let result = if config.workflow.reviewing.sequential {
                     run_sequential_reviewing_phase(
                         &view,
@@ -498,6 +564,8 @@ pub async fn run_workflow_with_config(
                         &mut approval_rx,
                         &mut control_rx,
                         &mut last_reviews,
+                        &mut review_cache,
+                        &mut section_owners,
                     )
                     .await
                 } else {
@@ -507,6 +575,8 @@ pub async fn run_workflow_with_config(
                         &mut approval_rx,
                         &mut control_rx,
                         &mut last_reviews,
+                        &mut review_cache,
+                        &mut section_owners,
                     )
                     .await
                 };
@@ -578,6 +648,22 @@ pub async fn run_workflow_with_config(
                 // On session resume, last_reviews may be empty - populate from view
                 revising::populate_reviews_from_view(&view, &mut last_reviews, &session_logger);
 
+                if let Some(workflow_result) = triage::triage_feedback_if_needed(
+                    &view,
+                    &phase_context,
+                    &mut approval_rx,
+                    &mut control_rx,
+                    &last_reviews,
+                )
+                .await
+                {
+                    // Daemon tracking is best-effort - ignore errors if daemon not running
+                    let _ = tracker.mark_stopped(&workflow_session_id_str).await;
+                    return Ok(workflow_result);
+                }
+                // Triage decisions are part of the view the revision reads
+                let view = view_rx_for_loop.borrow().clone();
+
                 let result = run_revising_phase(
                     &view,
                     &working_dir,
@@ -673,62 +759,17 @@ pub async fn run_workflow_with_config(
                 )
                 .await?;
 
-                // Apply the decision using command dispatch
-                match decision {
-                    MaxIterationsDecision::ProceedWithoutApproval => {
-                        phase_context
-                            .dispatch_command(DomainCommand::UserOverrideApproval {
-                                override_reason:
-                                    "User proceeded without AI approval at max iterations"
-                                        .to_string(),
-                            })
-                            .await;
-                        sender.send_output(
-                            "[planning] Proceeding without AI approval...".to_string(),
-                        );
-                        // Transition handled by command - loop will pick up Phase::Complete
-                    }
-                    MaxIterationsDecision::Continue(additional) => {
-                        // Extend max_iterations and continue to revising
-                        phase_context
-                            .dispatch_command(DomainCommand::RevisingStarted {
-                                feedback_summary: String::new(),
-                                additional_iterations: Some(additional),
-                            })
-                            .await;
-                        sender.send_output(
-                            "[planning] Continuing with another review cycle...".to_string(),
-                        );
-                    }
-                    MaxIterationsDecision::RestartWithFeedback(feedback) => {
-                        sender.send_output(format!(
-                            "[planning] Restarting with feedback: {}",
-                            feedback
-                        ));
-                        // Daemon tracking is best-effort - ignore errors if daemon not running
-                        let _ = tracker.mark_stopped(&workflow_session_id_str).await;
-                        return Ok(WorkflowResult::NeedsRestart {
-                            user_feedback: feedback,
-                        });
-                    }
-                    MaxIterationsDecision::Abort => {
-                        phase_context
-                            .dispatch_command(DomainCommand::UserAborted {
-                                reason: "User aborted workflow at max iterations".to_string(),
-                            })
-                            .await;
-                        sender.send_output("[planning] Workflow aborted by user".to_string());
-                        // Daemon tracking is best-effort - ignore errors if daemon not running
-                        let _ = tracker.mark_stopped(&workflow_session_id_str).await;
-                        return Ok(WorkflowResult::Aborted {
-                            reason: "User aborted workflow at max iterations".to_string(),
-                        });
-                    }
-                    MaxIterationsDecision::Stopped => {
-                        // Daemon tracking is best-effort - ignore errors if daemon not running
-                        let _ = tracker.mark_stopped(&workflow_session_id_str).await;
-                        return Ok(WorkflowResult::Stopped);
-                    }
+                if let Some(result) = planning_decision::apply_max_iterations_decision(
+                    decision,
+                    &view,
+                    &phase_context,
+                    &mut last_reviews,
+                )
+                .await
+                {
+                    // Daemon tracking is best-effort - ignore errors if daemon not running
+                    let _ = tracker.mark_stopped(&workflow_session_id_str).await;
+                    return Ok(result);
                 }
             }
 
@@ -738,6 +779,14 @@ pub async fn run_workflow_with_config(
         }
     }
 
+    let phase_context = WorkflowPhaseContext {
+        working_dir: &working_dir,
+        config: &config,
+        sender: &sender,
+        session_logger: session_logger.clone(),
+        actor_ref: Some(actor_ref),
+    };
+
     // Get final view for logging
     let final_view = view_rx_for_loop.borrow().clone();
     let final_phase = final_view.planning_phase().unwrap_or(Phase::Complete);
@@ -753,36 +802,52 @@ pub async fn run_workflow_with_config(
     );
 
     if final_phase == Phase::Complete {
-        let result = handle_completion(
-            &final_view,
-            &session_logger,
-            &sender,
-            &mut approval_rx,
-            &mut control_rx,
-        )
-        .await?;
+        if let Err(e) = cost_stats::record_session_usage(&sender.invocation_usage()) {
+            session_logger.log(
+                LogLevel::Warn,
+                LogCategory::Workflow,
+                &format!("Failed to update cost stats: {}", e),
+            );
+        }
 
-        // Dispatch domain command based on user decision
-        match &result {
-            WorkflowResult::Accepted => {
-                phase_context
-                    .dispatch_command(DomainCommand::UserApproved)
-                    .await;
-            }
-            WorkflowResult::ImplementationRequested => {
-                phase_context
-                    .dispatch_command(DomainCommand::UserRequestedImplementation)
-                    .await;
-            }
-            WorkflowResult::NeedsRestart { user_feedback } => {
-                phase_context
-                    .dispatch_command(DomainCommand::UserDeclined {
-                        feedback: user_feedback.clone(),
-                    })
-                    .await;
+        // An imported plan was approved up front, so it goes straight to implementation
+        let result = if final_view.imported_plan().is_some()
+            && final_view.implementation_state().is_none()
+        {
+            phase_context
+                .dispatch_command(DomainCommand::UserRequestedImplementation)
+                .await;
+            WorkflowResult::ImplementationRequested
+        } else {
+            final_decision::decide_on_plan(
+                &view_rx_for_loop,
+                &phase_context,
+                &mut approval_rx,
+                &mut control_rx,
+                &mut undo_rx,
+            )
+            .await?
+        };
+
+        // Later phases read the decision and its conditions from the view
+        let final_view = view_rx_for_loop.borrow().clone();
+        let result = if matches!(result, WorkflowResult::Accepted) {
+            run_completion_pipeline(&phase_context, &final_view, &workflow_session_id_str).await;
+            match final_view.replan_watch() {
+                Some(spec) => {
+                    replan_watch::watch_for_replan(
+                        spec,
+                        &phase_context,
+                        &mut approval_rx,
+                        &mut control_rx,
+                    )
+                    .await?
+                }
+                None => result,
             }
-            _ => {}
-        }
+        } else {
+            result
+        };
 
         // Check if implementation was requested
         if matches!(result, WorkflowResult::ImplementationRequested) {
@@ -823,6 +888,13 @@ pub async fn run_workflow_with_config(
                                 "[implementation] Implementation complete and approved!"
                                     .to_string(),
                             );
+                            let completed_view = view_rx_for_loop.borrow().clone();
+                            run_completion_pipeline(
+                                &phase_context,
+                                &completed_view,
+                                &workflow_session_id_str,
+                            )
+                            .await;
                             return Ok(WorkflowResult::Accepted);
                         }
                         ImplementationWorkflowResult::ApprovedOverridden { iterations_used } => {
@@ -830,6 +902,13 @@ pub async fn run_workflow_with_config(
                                 "[implementation] Implementation accepted by user override after {} iterations",
                                 iterations_used
                             ));
+                            let completed_view = view_rx_for_loop.borrow().clone();
+                            run_completion_pipeline(
+                                &phase_context,
+                                &completed_view,
+                                &workflow_session_id_str,
+                            )
+                            .await;
                             return Ok(WorkflowResult::Accepted);
                         }
                         ImplementationWorkflowResult::Failed {
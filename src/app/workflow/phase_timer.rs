@@ -0,0 +1,170 @@
+//! Phase time limits (`max_phase_duration`).
+//!
+//! A timer runs alongside each planning, reviewing and revising phase. When the
+//! phase overruns, a `PhaseDurationExceeded` event is recorded and the TUI shows a
+//! warning. With `auto_interrupt`, the phase's running agents are also stopped and
+//! re-run with an instruction to wrap up and output their best result now.
+
+use super::dispatch_domain_command;
+use crate::agents::{AgentContext, AgentResult, AgentType};
+use crate::config::PhaseDurationLimit;
+use crate::domain::actor::WorkflowMessage;
+use crate::domain::types::{PhaseLabel, ResumeStrategy};
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
+use crate::tui::SessionEventSender;
+use anyhow::Result;
+use ractor::ActorRef;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Appended to the prompt when an overrunning agent is asked to finish.
+pub const WRAP_UP_INSTRUCTION: &str = "TIME LIMIT REACHED: this phase has run past its time \
+limit. Stop exploring and wrap up now: output your best result so far, complete and in the \
+format requested above.";
+
+/// Watches one phase's running time. Dropping it stops the timer.
+pub struct PhaseTimer {
+    handle: Option<JoinHandle<()>>,
+    wrap_up_rx: Option<watch::Receiver<bool>>,
+}
+
+impl PhaseTimer {
+    /// Starts the timer for `phase`. Without a configured limit the timer does nothing.
+    pub fn start(
+        phase: PhaseLabel,
+        limit: Option<PhaseDurationLimit>,
+        sender: &SessionEventSender,
+        session_logger: Arc<SessionLogger>,
+        actor_ref: Option<ActorRef<WorkflowMessage>>,
+    ) -> Self {
+        let Some(limit) = limit else {
+            return Self {
+                handle: None,
+                wrap_up_rx: None,
+            };
+        };
+        let (wrap_up_tx, wrap_up_rx) = watch::channel(false);
+        let sender = sender.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(limit.duration()).await;
+            on_overrun(
+                phase,
+                limit,
+                &sender,
+                &session_logger,
+                &actor_ref,
+                &wrap_up_tx,
+            )
+            .await;
+        });
+        Self {
+            handle: Some(handle),
+            wrap_up_rx: limit.auto_interrupt.then_some(wrap_up_rx),
+        }
+    }
+
+    /// Returns true once the phase overran and its agents are to wrap up.
+    fn wrap_up_requested(&self) -> bool {
+        self.wrap_up_rx.as_ref().is_some_and(|rx| *rx.borrow())
+    }
+
+    /// Runs an agent under this timer. If the timer interrupts it, the agent is run
+    /// again (resuming its conversation when one was captured) with the prompt
+    /// followed by `WRAP_UP_INSTRUCTION`, and without a time limit.
+    pub async fn execute(
+        &self,
+        agent: &AgentType,
+        prompt: String,
+        system_prompt: Option<String>,
+        max_turns: Option<u32>,
+        mut context: AgentContext,
+    ) -> Result<AgentResult> {
+        if self.wrap_up_requested() {
+            let prompt = format!("{}\n\n{}", prompt, WRAP_UP_INSTRUCTION);
+            return agent
+                .execute_streaming_with_context(prompt, system_prompt, max_turns, context)
+                .await;
+        }
+
+        context.cancel_rx = self.wrap_up_rx.clone();
+        let result = agent
+            .execute_streaming_with_context(
+                prompt.clone(),
+                system_prompt.clone(),
+                max_turns,
+                context.clone(),
+            )
+            .await?;
+        if result.stop_reason.as_deref() != Some("cancelled") || !self.wrap_up_requested() {
+            return Ok(result);
+        }
+
+        context.session_sender.send_output(format!(
+            "[{}] Time limit reached, asking the agent for its best result",
+            context.phase.to_lowercase()
+        ));
+        context.cancel_rx = None;
+        if let Some(conversation_id) = result.conversation_id {
+            context.conversation_id = Some(conversation_id);
+            context.resume_strategy = ResumeStrategy::ConversationResume;
+        }
+        let prompt = format!("{}\n\n{}", prompt, WRAP_UP_INSTRUCTION);
+        agent
+            .execute_streaming_with_context(prompt, system_prompt, max_turns, context)
+            .await
+    }
+}
+
+/// Reports an overrun and, with `auto_interrupt`, signals the phase's agents to wrap up.
+async fn on_overrun(
+    phase: PhaseLabel,
+    limit: PhaseDurationLimit,
+    sender: &SessionEventSender,
+    session_logger: &SessionLogger,
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    wrap_up_tx: &watch::Sender<bool>,
+) {
+    session_logger.log(
+        LogLevel::Warn,
+        LogCategory::Workflow,
+        &format!("{} phase exceeded {}m time limit", phase, limit.minutes),
+    );
+    sender.send_output(format!(
+        "[workflow] Warning: {} phase has run longer than {}m{}",
+        phase,
+        limit.minutes,
+        if limit.auto_interrupt {
+            " - asking agents to wrap up"
+        } else {
+            ""
+        }
+    ));
+    sender.send_phase_duration_exceeded(limit.minutes);
+    dispatch_domain_command(
+        actor_ref,
+        DomainCommand::RecordPhaseDurationExceeded {
+            phase,
+            limit_minutes: limit.minutes,
+            auto_interrupted: limit.auto_interrupt,
+        },
+        session_logger,
+    )
+    .await;
+    if limit.auto_interrupt {
+        let _ = wrap_up_tx.send(true);
+    }
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/phase_timer_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+//! Detection of plan edits made outside the workflow.
+//!
+//! The view remembers when the workflow last wrote the plan file. A later
+//! modification time means the user edited the plan on disk (typically while the
+//! session was paused or awaiting approval). The workflow records such edits as a
+//! `PlanExternallyModified` event before the next phase, so sequential approvals
+//! of the old content are discarded and resumed agents are told to re-read the plan.
+
+use super::reviewing::WorkflowPhaseContext;
+use crate::domain::types::Phase;
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::planning_paths::hex_encode;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::time::SystemTime;
+
+/// Returns the plan file's modification time if it changed after the workflow last wrote it.
+pub fn plan_modified_on_disk(view: &WorkflowView) -> Option<SystemTime> {
+    let written_at = view.plan_written_at()?;
+    let modified = fs::metadata(view.plan_path()?.as_path())
+        .and_then(|m| m.modified())
+        .ok()?;
+    (DateTime::<Utc>::from(modified) > written_at.0).then_some(modified)
+}
+
+/// Records an external plan edit, if any, while the plan is still being iterated on.
+///
+/// Returns true when a `PlanExternallyModified` event was dispatched.
+pub async fn record_external_plan_edit(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+) -> bool {
+    if !matches!(
+        view.planning_phase(),
+        Some(Phase::Reviewing | Phase::Revising | Phase::AwaitingPlanningDecision)
+    ) || plan_modified_on_disk(view).is_none()
+    {
+        return false;
+    }
+    let Some(content) = view.plan_path().and_then(|p| fs::read(p.as_path()).ok()) else {
+        return false;
+    };
+
+    let content_hash = hex_encode(&Sha256::digest(&content));
+    context.log_workflow(&format!(
+        "Plan changed on disk outside the workflow (sha256 {})",
+        content_hash
+    ));
+    context
+        .sender
+        .send_output("[planning] Plan changed on disk - using your edits from now on".to_string());
+    context
+        .dispatch_command(DomainCommand::RecordPlanExternalModification { content_hash })
+        .await;
+    true
+}
+
+#[cfg(test)]
+#[path = "tests/plan_watch_tests.rs"]
+mod tests;
@@ -1,5 +1,7 @@
 //! Planning phase execution.
 
+use super::reviewing::WorkflowPhaseContext;
+use super::section_stream::plan_with_section_reviews;
 use super::{dispatch_domain_command, WorkflowResult};
 use crate::app::util::build_plan_failure_summary;
 use crate::app::workflow_common::plan_file_has_content;
@@ -12,12 +14,38 @@ use crate::domain::WorkflowCommand as DomainCommand;
 use crate::phases::{self, run_planning_phase_with_context};
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::{CancellationError, SessionEventSender, UserApprovalResponse, WorkflowCommand};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use ractor::ActorRef;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
+/// Copies an existing plan into the session and records it as approved, in place
+/// of the planning phase (`planning implement --plan`).
+pub async fn import_plan(
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    source: &Path,
+    plan_path: &Path,
+    session_logger: &SessionLogger,
+) -> Result<()> {
+    let plan = std::fs::read_to_string(source)
+        .with_context(|| format!("Failed to read plan {}", source.display()))?;
+    if plan.trim().is_empty() {
+        anyhow::bail!("Plan {} is empty", source.display());
+    }
+    std::fs::write(plan_path, plan)
+        .with_context(|| format!("Failed to write plan to {}", plan_path.display()))?;
+    dispatch_domain_command(
+        actor_ref,
+        DomainCommand::ImportPlan {
+            source: source.display().to_string(),
+        },
+        session_logger,
+    )
+    .await;
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn run_planning_phase(
     view: &WorkflowView,
@@ -42,6 +70,13 @@ pub async fn run_planning_phase(
     sender.send_output(format!("Agent: {}", config.workflow.planning.agent));
     let plan_path = view.plan_path().map(|p| p.0.clone()).unwrap_or_default();
     sender.send_output(format!("Plan file: {}", plan_path.display()));
+    let phase_context = WorkflowPhaseContext {
+        working_dir,
+        config,
+        sender,
+        session_logger: session_logger.clone(),
+        actor_ref: actor_ref.clone(),
+    };
 
     loop {
         // Check for commands before starting planning
@@ -75,15 +110,29 @@ pub async fn run_planning_phase(
             LogCategory::Workflow,
             "Calling run_planning_phase_with_context...",
         );
-        let planning_result = run_planning_phase_with_context(
+        // With `reviewing.pipelined`, finished sections are reviewed meanwhile
+        let planning_result = match plan_with_section_reviews(
+            |sender| {
+                run_planning_phase_with_context(
+                    view,
+                    working_dir,
+                    config,
+                    sender,
+                    session_logger.clone(),
+                    actor_ref.clone(),
+                    None,
+                )
+            },
             view,
-            working_dir,
-            config,
-            sender.clone(),
-            session_logger.clone(),
-            actor_ref.clone(),
+            &phase_context,
+            control_rx,
         )
-        .await;
+        .await
+        {
+            Ok(Some(result)) => return Ok(Some(result)),
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
 
         match planning_result {
             Ok(()) => {
@@ -248,6 +297,17 @@ pub async fn run_planning_phase(
         }
     }
 
+    enforce_plan_structure(
+        view,
+        working_dir,
+        config,
+        sender,
+        &session_logger,
+        &actor_ref,
+        &plan_path,
+    )
+    .await?;
+
     session_logger.log(
         LogLevel::Info,
         LogCategory::Workflow,
@@ -275,3 +335,141 @@ pub async fn run_planning_phase(
 
     Ok(None)
 }
+
+/// Checks the plan against the `plan_structure` policy, if one is configured, and
+/// asks the planner to add missing sections (or fix its front matter) up to
+/// `max_followups` times. Every check is recorded as a `PlanStructureChecked`
+/// event, and valid front matter as `PlanMetadataRecorded`. A plan that still
+/// lacks structure goes to review anyway, where reviewers can flag it.
+async fn enforce_plan_structure(
+    view: &WorkflowView,
+    working_dir: &Path,
+    config: &WorkflowConfig,
+    sender: &SessionEventSender,
+    session_logger: &Arc<SessionLogger>,
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    plan_path: &Path,
+) -> Result<()> {
+    let Some(policy) = &config.plan_structure else {
+        return Ok(());
+    };
+
+    let mut followups = 0;
+    loop {
+        let plan = std::fs::read_to_string(plan_path).unwrap_or_default();
+        let missing = phases::missing_plan_sections(&plan, &policy.required_sections);
+        let front_matter_problem = if policy.front_matter {
+            match phases::parse_plan_front_matter(&plan) {
+                Ok(metadata) => {
+                    dispatch_domain_command(
+                        actor_ref,
+                        DomainCommand::RecordPlanMetadata { metadata },
+                        session_logger,
+                    )
+                    .await;
+                    None
+                }
+                Err(e) => Some(format!("{:#}", e)),
+            }
+        } else {
+            None
+        };
+        let complete = missing.is_empty() && front_matter_problem.is_none();
+        let followup_requested = !complete && followups < policy.max_followups;
+        dispatch_domain_command(
+            actor_ref,
+            DomainCommand::RecordPlanStructureCheck {
+                missing_sections: missing.clone(),
+                followup_requested,
+            },
+            session_logger,
+        )
+        .await;
+
+        if complete {
+            sender.send_output("[planning] Plan has the required structure".to_string());
+            return Ok(());
+        }
+        let mut problems = Vec::new();
+        if !missing.is_empty() {
+            problems.push(format!("missing sections: {}", missing.join(", ")));
+        }
+        if let Some(problem) = &front_matter_problem {
+            problems.push(format!("front matter: {}", problem));
+        }
+        let problems = problems.join("; ");
+        if !followup_requested {
+            sender.send_output(format!(
+                "[planning] Plan still lacks the required structure ({}); continuing to review",
+                problems
+            ));
+            return Ok(());
+        }
+
+        followups += 1;
+        sender.send_output(format!(
+            "[planning] Plan lacks the required structure ({}); asking the planner to fix it",
+            problems
+        ));
+        session_logger.log(
+            LogLevel::Info,
+            LogCategory::Workflow,
+            &format!("Plan structure follow-up {} for: {}", followups, problems),
+        );
+        let followup =
+            phases::build_plan_structure_followup(view, &missing, front_matter_problem.as_deref());
+        if let Err(e) = run_planning_phase_with_context(
+            view,
+            working_dir,
+            config,
+            sender.clone(),
+            session_logger.clone(),
+            actor_ref.clone(),
+            Some(followup),
+        )
+        .await
+        {
+            if e.downcast_ref::<CancellationError>().is_some() {
+                return Err(e);
+            }
+            sender.send_output(format!(
+                "[planning] Section follow-up failed: {}; continuing to review",
+                e
+            ));
+            return Ok(());
+        }
+    }
+}
+
+/// Records the revised plan's front matter when the structured plan format is
+/// on. Revisions get no follow-up; a broken front matter is only reported.
+pub(super) async fn record_revised_plan_metadata(
+    config: &WorkflowConfig,
+    sender: &SessionEventSender,
+    session_logger: &Arc<SessionLogger>,
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    plan_path: &Path,
+) {
+    if !config
+        .plan_structure
+        .as_ref()
+        .is_some_and(|policy| policy.front_matter)
+    {
+        return;
+    }
+    let plan = std::fs::read_to_string(plan_path).unwrap_or_default();
+    match phases::parse_plan_front_matter(&plan) {
+        Ok(metadata) => {
+            dispatch_domain_command(
+                actor_ref,
+                DomainCommand::RecordPlanMetadata { metadata },
+                session_logger,
+            )
+            .await
+        }
+        Err(e) => sender.send_output(format!(
+            "[revision] Revised plan front matter is invalid: {:#}",
+            e
+        )),
+    }
+}
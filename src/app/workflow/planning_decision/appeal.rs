@@ -0,0 +1,227 @@
+//! Appeals of plan rejections.
+//!
+//! The user's counter-argument goes to each reviewer that rejected the last
+//! round, resuming its review conversation where there is one. Reviewers that
+//! approve on reconsideration have their rejection cleared; the others sustain
+//! it and their more detailed response replaces their feedback.
+
+use super::super::reviewing::WorkflowPhaseContext;
+use crate::agents::{AgentContext, AgentType};
+use crate::domain::types::{AgentId, ResumeStrategy};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::review_prompts::REVIEW_SYSTEM_PROMPT;
+use crate::phases::reviewing::{parse_verdict, VerdictParseResult};
+use crate::phases::{reviewing_conversation_key, ReviewResult};
+use crate::prompt_format::PromptBuilder;
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use std::path::Path;
+
+/// A rejecting reviewer's answer to an appeal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppealOutcome {
+    /// The reviewer now approves; carries its response.
+    Cleared(String),
+    /// The reviewer keeps its rejection; carries its more detailed response.
+    Sustained(String),
+    /// The reviewer could not be asked; carries the error.
+    Failed(String),
+}
+
+impl AppealOutcome {
+    /// Reads the verdict from a reviewer's response. A response without a
+    /// clear approval sustains the rejection.
+    pub fn from_response(response: String) -> Self {
+        match parse_verdict(&response) {
+            VerdictParseResult::Approved => Self::Cleared(response),
+            VerdictParseResult::NeedsRevision | VerdictParseResult::ParseFailure(_) => {
+                Self::Sustained(response)
+            }
+        }
+    }
+}
+
+/// Builds the prompt asking a reviewer to reconsider its rejection.
+pub fn build_appeal_prompt(
+    plan_path: &Path,
+    review: &ReviewResult,
+    argument: &str,
+    language: Option<&str>,
+) -> String {
+    PromptBuilder::new()
+        .phase("appeal")
+        .instructions(
+            r#"The user disagrees with your rejection of the plan and asks you to reconsider.
+Re-read the plan file, then weigh the user's counter-argument against each blocking issue you raised.
+Do not edit the plan. If the argument resolves your blocking issues, approve the plan.
+Otherwise keep your rejection and explain in more detail why each remaining issue blocks the plan."#,
+        )
+        .input("plan-path", &plan_path.display().to_string())
+        .context(&format!(
+            "# Your Review\n\n{}\n\n# User's Counter-Argument\n\n{}",
+            review.feedback, argument
+        ))
+        .output_format(
+            "End your response with exactly one line: \"Overall Assessment: APPROVED\" or \"Overall Assessment: NEEDS REVISION\".",
+        )
+        .language(language)
+        .build()
+}
+
+/// Records the outcomes on the round's reviews and returns the reviewers whose
+/// rejection was cleared.
+pub fn apply_outcomes(
+    reviews: &mut [ReviewResult],
+    outcomes: Vec<(String, AppealOutcome)>,
+) -> Vec<String> {
+    let mut cleared = Vec::new();
+    for (reviewer, outcome) in outcomes {
+        let Some(review) = reviews.iter_mut().find(|r| r.agent_name == reviewer) else {
+            continue;
+        };
+        match outcome {
+            AppealOutcome::Cleared(response) => {
+                review.needs_revision = false;
+                review.low_confidence = false;
+                review.summary = "Rejection cleared on appeal".to_string();
+                review.feedback = response;
+                cleared.push(reviewer);
+            }
+            AppealOutcome::Sustained(response) => {
+                review.summary = "Rejection sustained on appeal".to_string();
+                review.feedback = response;
+            }
+            AppealOutcome::Failed(_) => {}
+        }
+    }
+    cleared
+}
+
+/// Sends the user's counter-argument to the rejecting reviewers and records
+/// cleared rejections. Returns whether the plan is now approved.
+pub async fn run_appeal(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    last_reviews: &mut [ReviewResult],
+    argument: &str,
+) -> bool {
+    let rejecting: Vec<ReviewResult> = last_reviews
+        .iter()
+        .filter(|r| r.needs_revision)
+        .cloned()
+        .collect();
+    if rejecting.is_empty() {
+        context
+            .sender
+            .send_output("[appeal] No rejection to appeal".to_string());
+        return false;
+    }
+    let names: Vec<&str> = rejecting.iter().map(|r| r.agent_name.as_str()).collect();
+    context.log_workflow(&format!("Appealing rejection(s) of {}", names.join(", ")));
+    context.sender.send_output(format!(
+        "[appeal] Asking {} to reconsider...",
+        names.join(", ")
+    ));
+
+    let outcomes = join_all(rejecting.iter().map(|review| async move {
+        let outcome = match ask_reviewer(view, context, review, argument).await {
+            Ok(response) => AppealOutcome::from_response(response),
+            Err(e) => AppealOutcome::Failed(e.to_string()),
+        };
+        (review.agent_name.clone(), outcome)
+    }))
+    .await;
+
+    for (reviewer, outcome) in &outcomes {
+        let message = match outcome {
+            AppealOutcome::Cleared(_) => format!("[appeal] {} cleared its rejection", reviewer),
+            AppealOutcome::Sustained(_) => format!(
+                "[appeal] {} sustains its rejection; see the updated summary",
+                reviewer
+            ),
+            AppealOutcome::Failed(e) => {
+                format!("[appeal] {} could not reconsider: {}", reviewer, e)
+            }
+        };
+        context.sender.send_output(message);
+    }
+
+    let cleared = apply_outcomes(last_reviews, outcomes);
+    let plan_approved = !last_reviews.iter().any(|r| r.needs_revision);
+    for (index, reviewer) in cleared.iter().enumerate() {
+        context
+            .dispatch_command(DomainCommand::ReviewerVerdictRevised {
+                reviewer_id: AgentId::from(reviewer.as_str()),
+                // The last cleared rejection approves the plan
+                plan_approved: plan_approved && index + 1 == cleared.len(),
+            })
+            .await;
+    }
+    plan_approved
+}
+
+/// Runs the reviewer on the appeal prompt and returns its response.
+async fn ask_reviewer(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    review: &ReviewResult,
+    argument: &str,
+) -> Result<String> {
+    let config = context.config;
+    let display_id = review.agent_name.as_str();
+    let agent_name = config
+        .workflow
+        .reviewing
+        .agents
+        .iter()
+        .find(|agent_ref| agent_ref.display_id() == display_id)
+        .map(|agent_ref| agent_ref.agent_name())
+        .unwrap_or(display_id);
+    let agent_config = config
+        .get_agent(agent_name)
+        .with_context(|| format!("Review agent '{}' not found in config", agent_name))?;
+    let agent =
+        AgentType::from_config(agent_name, agent_config, context.working_dir.to_path_buf())?;
+
+    // Resume the reviewer's own conversation so it remembers its review
+    let conversation_key = reviewing_conversation_key(display_id);
+    let (conversation_id, resume_strategy) = view
+        .agent_conversations()
+        .get(&AgentId::from(conversation_key.as_str()))
+        .map(|state| {
+            (
+                state.conversation_id().map(|c| c.0.clone()),
+                state.resume_strategy(),
+            )
+        })
+        .unwrap_or((None, ResumeStrategy::Stateless));
+
+    let plan_path = view
+        .plan_path()
+        .map(|p| p.0.clone())
+        .unwrap_or_else(|| context.working_dir.join("plan.md"));
+    let prompt = build_appeal_prompt(&plan_path, review, argument, config.language.as_deref());
+    let agent_context = AgentContext {
+        session_sender: context.sender.clone(),
+        phase: format!("Appeal: {}", display_id),
+        conversation_id,
+        resume_strategy,
+        cancel_rx: None,
+        session_logger: context.session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
+    };
+    let result = agent
+        .execute_streaming_with_context(
+            prompt,
+            Some(REVIEW_SYSTEM_PROMPT.to_string()),
+            None,
+            agent_context,
+        )
+        .await?;
+    Ok(result.output)
+}
@@ -0,0 +1,99 @@
+//! The planning decision at the max iterations limit, and appeals of plan
+//! rejections.
+//!
+//! When the plan loop stops on rejections - at the max iterations decision or
+//! at the `reviewing` approval gate - the user can appeal them: the
+//! counter-argument goes to the rejecting reviewers only. If every rejection is
+//! cleared the plan is approved without another revision cycle; otherwise the
+//! user is asked again with the reviewers' responses.
+
+mod appeal;
+
+use super::reviewing::WorkflowPhaseContext;
+use super::revising::populate_reviews_from_view;
+use super::WorkflowResult;
+use crate::app::workflow_decisions::MaxIterationsDecision;
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::ReviewResult;
+
+/// Applies the user's decision at the max iterations limit through command
+/// dispatch. Returns the workflow result when the decision ends the run.
+pub async fn apply_max_iterations_decision(
+    decision: MaxIterationsDecision,
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    last_reviews: &mut Vec<ReviewResult>,
+) -> Option<WorkflowResult> {
+    let sender = context.sender;
+    match decision {
+        MaxIterationsDecision::ProceedWithoutApproval => {
+            context
+                .dispatch_command(DomainCommand::UserOverrideApproval {
+                    override_reason: "User proceeded without AI approval at max iterations"
+                        .to_string(),
+                })
+                .await;
+            sender.send_output("[planning] Proceeding without AI approval...".to_string());
+            // Transition handled by command - loop will pick up Phase::Complete
+            None
+        }
+        MaxIterationsDecision::Continue(additional) => {
+            // Extend max_iterations and continue to revising
+            context
+                .dispatch_command(DomainCommand::RevisingStarted {
+                    feedback_summary: String::new(),
+                    additional_iterations: Some(additional),
+                })
+                .await;
+            sender.send_output("[planning] Continuing with another review cycle...".to_string());
+            None
+        }
+        MaxIterationsDecision::RestartWithFeedback(feedback) => {
+            sender.send_output(format!("[planning] Restarting with feedback: {}", feedback));
+            Some(WorkflowResult::NeedsRestart {
+                user_feedback: feedback,
+            })
+        }
+        MaxIterationsDecision::Appeal(argument) => {
+            // A sustained appeal leaves the phase as is, so the modal shows again
+            appeal_rejections(view, context, last_reviews, &argument).await;
+            None
+        }
+        MaxIterationsDecision::Abort => {
+            context
+                .dispatch_command(DomainCommand::UserAborted {
+                    reason: "User aborted workflow at max iterations".to_string(),
+                })
+                .await;
+            sender.send_output("[planning] Workflow aborted by user".to_string());
+            Some(WorkflowResult::Aborted {
+                reason: "User aborted workflow at max iterations".to_string(),
+            })
+        }
+        MaxIterationsDecision::Stopped => Some(WorkflowResult::Stopped),
+    }
+}
+
+/// Appeals the rejections of the last review round. Returns whether every
+/// rejection was cleared, which approves the plan.
+pub async fn appeal_rejections(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    last_reviews: &mut Vec<ReviewResult>,
+    argument: &str,
+) -> bool {
+    // On session resume, last_reviews may be empty - populate from view
+    populate_reviews_from_view(view, last_reviews, &context.session_logger);
+    let approved = appeal::run_appeal(view, context, last_reviews, argument).await;
+    if approved {
+        context.sender.send_output(
+            "[planning] All rejections cleared on appeal - plan APPROVED!".to_string(),
+        );
+    }
+    approved
+}
+
+#[cfg(test)]
+#[path = "tests/appeal_tests.rs"]
+mod tests;
@@ -0,0 +1,77 @@
+use super::appeal::*;
+use crate::phases::ReviewResult;
+use std::path::Path;
+
+fn rejection(agent_name: &str, feedback: &str) -> ReviewResult {
+    ReviewResult {
+        agent_name: agent_name.to_string(),
+        needs_revision: true,
+        feedback: feedback.to_string(),
+        summary: "Missing rollback".to_string(),
+        low_confidence: false,
+    }
+}
+
+#[test]
+fn test_appeal_outcome_needs_a_clear_approval() {
+    assert_eq!(
+        AppealOutcome::from_response("Fair point.\n\nOverall Assessment: APPROVED".to_string()),
+        AppealOutcome::Cleared("Fair point.\n\nOverall Assessment: APPROVED".to_string())
+    );
+    assert!(matches!(
+        AppealOutcome::from_response("Overall Assessment: NEEDS REVISION".to_string()),
+        AppealOutcome::Sustained(_)
+    ));
+    assert!(matches!(
+        AppealOutcome::from_response("I see your point.".to_string()),
+        AppealOutcome::Sustained(_)
+    ));
+}
+
+#[test]
+fn test_appeal_prompt_carries_review_and_counter_argument() {
+    let prompt = build_appeal_prompt(
+        Path::new("/tmp/plan.md"),
+        &rejection("codex", "No down migration."),
+        "The table is new, so dropping it is the rollback.",
+        None,
+    );
+    assert!(prompt.contains("/tmp/plan.md"));
+    assert!(prompt.contains("No down migration."));
+    assert!(prompt.contains("The table is new, so dropping it is the rollback."));
+    assert!(prompt.contains("Overall Assessment: APPROVED"));
+}
+
+#[test]
+fn test_apply_outcomes_clears_and_sustains_rejections() {
+    let mut reviews = vec![
+        rejection("codex", "No down migration."),
+        rejection("gemini", "No load test."),
+        rejection("claude", "No metrics."),
+    ];
+    let cleared = apply_outcomes(
+        &mut reviews,
+        vec![
+            (
+                "codex".to_string(),
+                AppealOutcome::Cleared("Agreed.".to_string()),
+            ),
+            (
+                "gemini".to_string(),
+                AppealOutcome::Sustained("The batch job doubles the load.".to_string()),
+            ),
+            (
+                "claude".to_string(),
+                AppealOutcome::Failed("timeout".to_string()),
+            ),
+        ],
+    );
+
+    assert_eq!(cleared, vec!["codex"]);
+    assert!(!reviews[0].needs_revision);
+    assert_eq!(reviews[0].feedback, "Agreed.");
+    assert!(reviews[1].needs_revision);
+    assert_eq!(reviews[1].feedback, "The batch job doubles the load.");
+    assert!(reviews[2].needs_revision);
+    assert_eq!(reviews[2].feedback, "No metrics.");
+}
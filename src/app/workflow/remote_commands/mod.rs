@@ -0,0 +1,138 @@
+//! Commands relayed from the host GUI to this session.
+//!
+//! For a containerized session the host GUI can approve or decline the pending
+//! approval request, stop the run or send feedback. The host queues the command
+//! for the container, the daemon picks it up with its host heartbeat and the
+//! session tracker takes it from the daemon with its own. A stop stops the run at
+//! the next phase boundary and feedback interrupts the current phase, like the
+//! TUI's `Stop` and interrupt.
+//!
+//! An approval or decline names the approval request it answers. The session
+//! reports the request it waits on to the host, and answers naming any other
+//! request (one the TUI answered meanwhile, or an earlier prompt) are dropped
+//! and reported back as stale instead of answering whatever comes next.
+//!
+//! The relay only holds weak senders, so the TUI dropping its approval sender
+//! still closes the workflow's approval channel.
+
+use crate::rpc::host_service::RemoteCommand;
+use crate::session_daemon::SessionTracker;
+use crate::tui::{SessionEventSender, UserApprovalResponse, WorkflowCommand};
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
+
+/// Merges the commands the host relays for `session_id` into the workflow's
+/// approval and control channels, and keeps the host told which approval
+/// request the session waits on.
+pub(super) fn relay_remote_commands(
+    approval_rx: mpsc::Receiver<UserApprovalResponse>,
+    control_rx: mpsc::Receiver<WorkflowCommand>,
+    mut remote_rx: broadcast::Receiver<(String, RemoteCommand)>,
+    session_id: String,
+    sender: SessionEventSender,
+    tracker: Arc<SessionTracker>,
+) -> (
+    mpsc::Receiver<UserApprovalResponse>,
+    mpsc::Receiver<WorkflowCommand>,
+) {
+    let answered = sender.clone();
+    let (approval_tx, approval_rx) =
+        forward(approval_rx, move || answered.close_approval_request());
+    let (control_tx, control_rx) = forward(control_rx, || {});
+    // A weak tracker, so the relay ends once the run drops the tracker
+    let tracker = Arc::downgrade(&tracker);
+    let mut pending = sender.watch_approval_request();
+    pending.mark_changed();
+    tokio::spawn(async move {
+        loop {
+            let received = tokio::select! {
+                received = remote_rx.recv() => received,
+                changed = pending.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    let request = *pending.borrow_and_update();
+                    if let Some(tracker) = tracker.upgrade() {
+                        // Best-effort: a failed report is repeated with the next change
+                        let _ = tracker.set_approval_request(&session_id, request).await;
+                    }
+                    continue;
+                }
+            };
+            let command = match received {
+                Ok((id, command)) if id == session_id => command,
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                // The tracker is gone with the run
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            sender.send_output(format!(
+                "[planning] Received {} from the host",
+                command.describe()
+            ));
+            let delivered = match command {
+                RemoteCommand::Approve { request } | RemoteCommand::Decline { request, .. }
+                    if !sender.take_approval_request(request) =>
+                {
+                    sender.send_output(format!(
+                        "[planning] Dropped {}: the session no longer waits on it",
+                        command.describe()
+                    ));
+                    if let Some(tracker) = tracker.upgrade() {
+                        // Best-effort: the host is told again if it relays another stale answer
+                        let _ = tracker
+                            .report_stale_command(&session_id, command.describe())
+                            .await;
+                    }
+                    continue;
+                }
+                RemoteCommand::Approve { .. } => {
+                    sender.send_remote_answer();
+                    send(&approval_tx, UserApprovalResponse::Accept).await
+                }
+                RemoteCommand::Decline { feedback, .. } => {
+                    sender.send_remote_answer();
+                    send(&approval_tx, UserApprovalResponse::Decline(feedback)).await
+                }
+                RemoteCommand::Stop => send(&control_tx, WorkflowCommand::Stop).await,
+                RemoteCommand::Feedback { text } => {
+                    send(&control_tx, WorkflowCommand::Interrupt { feedback: text }).await
+                }
+            };
+            if !delivered {
+                sender.send_output("[planning] The run no longer takes that command".to_string());
+            }
+        }
+    });
+    (approval_rx, control_rx)
+}
+
+/// A channel fed from `rx`, and a weak sender for adding to it. `on_message`
+/// runs for each message from `rx` before it is passed on.
+fn forward<T: Send + 'static>(
+    mut rx: mpsc::Receiver<T>,
+    on_message: impl Fn() + Send + 'static,
+) -> (mpsc::WeakSender<T>, mpsc::Receiver<T>) {
+    let (tx, forwarded_rx) = mpsc::channel(1);
+    let weak_tx = tx.downgrade();
+    tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            on_message();
+            if tx.send(message).await.is_err() {
+                break;
+            }
+        }
+    });
+    (weak_tx, forwarded_rx)
+}
+
+/// Sends through a weak sender; false once the channel is closed.
+async fn send<T>(tx: &mpsc::WeakSender<T>, message: T) -> bool {
+    match tx.upgrade() {
+        Some(tx) => tx.send(message).await.is_ok(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/remote_commands_tests.rs"]
+mod tests;
@@ -0,0 +1,112 @@
+use super::*;
+
+#[tokio::test]
+async fn test_host_commands_reach_the_workflow_channels() {
+    let (approval_tx, approval_rx) = mpsc::channel(1);
+    let (_control_tx, control_rx) = mpsc::channel(1);
+    let (remote_tx, remote_rx) = broadcast::channel(4);
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let sender = SessionEventSender::new(0, 0, event_tx);
+    let tracker = Arc::new(SessionTracker::new(true).await);
+    let (mut approvals, mut controls) = relay_remote_commands(
+        approval_rx,
+        control_rx,
+        remote_rx,
+        "s1".to_string(),
+        sender.clone(),
+        tracker,
+    );
+
+    sender.send_approval_request("plan ready".to_string());
+    let request = sender.pending_approval_request().unwrap();
+    remote_tx
+        .send(("other".to_string(), RemoteCommand::Stop))
+        .unwrap();
+    remote_tx
+        .send(("s1".to_string(), RemoteCommand::Approve { request }))
+        .unwrap();
+    remote_tx
+        .send((
+            "s1".to_string(),
+            RemoteCommand::Feedback {
+                text: "add tests".to_string(),
+            },
+        ))
+        .unwrap();
+
+    assert!(matches!(
+        approvals.recv().await,
+        Some(UserApprovalResponse::Accept)
+    ));
+    assert!(matches!(
+        controls.recv().await,
+        Some(WorkflowCommand::Interrupt { feedback }) if feedback == "add tests"
+    ));
+    assert_eq!(sender.pending_approval_request(), None);
+
+    // The TUI's answers still come through, and dropping its sender closes the channel
+    approval_tx
+        .send(UserApprovalResponse::Accept)
+        .await
+        .unwrap();
+    assert!(approvals.recv().await.is_some());
+    drop(approval_tx);
+    assert!(approvals.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn test_answers_to_other_requests_are_dropped() {
+    let (approval_tx, approval_rx) = mpsc::channel(1);
+    let (_control_tx, control_rx) = mpsc::channel(1);
+    let (remote_tx, remote_rx) = broadcast::channel(4);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let sender = SessionEventSender::new(0, 0, event_tx);
+    let tracker = Arc::new(SessionTracker::new(true).await);
+    let (mut approvals, mut controls) = relay_remote_commands(
+        approval_rx,
+        control_rx,
+        remote_rx,
+        "s1".to_string(),
+        sender.clone(),
+        tracker,
+    );
+
+    // The TUI answers the first prompt before the host's approval arrives
+    sender.send_approval_request("plan ready".to_string());
+    let answered = sender.pending_approval_request().unwrap();
+    approval_tx
+        .send(UserApprovalResponse::Decline("more detail".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        approvals.recv().await,
+        Some(UserApprovalResponse::Decline(_))
+    ));
+    sender.send_review_decision_request("reviews in".to_string());
+    let pending = sender.pending_approval_request().unwrap();
+    assert_ne!(pending, answered);
+
+    remote_tx
+        .send((
+            "s1".to_string(),
+            RemoteCommand::Approve { request: answered },
+        ))
+        .unwrap();
+    remote_tx
+        .send(("s1".to_string(), RemoteCommand::Stop))
+        .unwrap();
+    assert!(matches!(controls.recv().await, Some(WorkflowCommand::Stop)));
+
+    // The stale approval did not answer the review decision
+    assert!(approvals.try_recv().is_err());
+    assert_eq!(sender.pending_approval_request(), Some(pending));
+    let mut outputs = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        if let crate::tui::Event::SessionOutput { line, .. } = event {
+            outputs.push(line);
+        }
+    }
+    assert!(outputs
+        .iter()
+        .any(|line| line.contains(&format!("Dropped approval of request {}", answered))));
+}
@@ -0,0 +1,436 @@
+//! Watch mode: re-plan when watched files change after a plan is accepted.
+//!
+//! With `planning --watch <GLOB>` (repeatable) the workflow keeps running after
+//! the plan is accepted; the globs are recorded in the session's view, so a
+//! resumed session watches them too. It remembers the working tree as it was at
+//! acceptance and watches the directories under the globs and git's HEAD, index
+//! and refs for file system events. Once the events have settled (none for a
+//! moment), git is asked what changed under the globs, and if anything did, the
+//! user is asked whether to re-plan: accepting keeps the plan and watches on
+//! from the current tree, declining with notes re-plans. With `--auto` the
+//! re-planning pass starts right away. Re-planning reopens planning with
+//! feedback carrying the diff since acceptance, so the planner revises it
+//! against the code as it is now.
+//!
+//! Only files git can see are watched: tracked files through their diff,
+//! untracked files that are not ignored when they appear.
+
+use super::reviewing::WorkflowPhaseContext;
+use super::WorkflowResult;
+use crate::domain::types::WatchSpec;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::tui::{UserApprovalResponse, WorkflowCommand};
+use anyhow::{Context, Result};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+/// Quiet time after the last file system event before git is asked what changed.
+const SETTLE_DELAY: Duration = Duration::from_secs(2);
+
+/// Longest diff handed to the planner; the rest is cut off.
+const MAX_DIFF_CHARS: usize = 30_000;
+
+/// Watched files that differ from the tree at acceptance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatchedChanges {
+    /// Tracked files that were modified, added to the index or deleted
+    pub changed: Vec<String>,
+    /// Untracked files that did not exist at acceptance
+    pub created: Vec<String>,
+    /// `git diff` of the tracked files
+    pub diff: String,
+}
+
+impl WatchedChanges {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.created.is_empty()
+    }
+}
+
+/// The working tree at acceptance.
+struct Baseline {
+    /// `git stash create` of the tree, or HEAD when it had no local changes
+    rev: String,
+    /// Watched untracked files that already existed
+    untracked: BTreeSet<String>,
+}
+
+impl Baseline {
+    async fn capture(working_dir: &Path, pathspecs: &[String]) -> Result<Self> {
+        // The stash commit is never stored, so a fallback identity is harmless
+        let stash = git(
+            working_dir,
+            &[
+                "-c",
+                "user.name=planning-agent",
+                "-c",
+                "user.email=planning-agent@localhost",
+                "stash",
+                "create",
+            ],
+            &[],
+        )
+        .await?;
+        let rev = match stash.trim() {
+            "" => git(working_dir, &["rev-parse", "HEAD"], &[])
+                .await?
+                .trim()
+                .to_string(),
+            rev => rev.to_string(),
+        };
+        Ok(Self {
+            rev,
+            untracked: untracked_files(working_dir, pathspecs).await?,
+        })
+    }
+
+    async fn changes(&self, working_dir: &Path, pathspecs: &[String]) -> Result<WatchedChanges> {
+        let changed = git(
+            working_dir,
+            &["diff", "--name-only", &self.rev, "--"],
+            pathspecs,
+        )
+        .await?;
+        let diff = git(working_dir, &["diff", &self.rev, "--"], pathspecs).await?;
+        let created = untracked_files(working_dir, pathspecs)
+            .await?
+            .difference(&self.untracked)
+            .cloned()
+            .collect();
+        Ok(WatchedChanges {
+            changed: changed.lines().map(str::to_string).collect(),
+            created,
+            diff,
+        })
+    }
+}
+
+/// Glob pathspecs for the watched globs, relative to the working directory.
+pub fn pathspecs(globs: &[String]) -> Vec<String> {
+    globs
+        .iter()
+        .map(|glob| format!(":(glob){}", glob))
+        .collect()
+}
+
+async fn untracked_files(working_dir: &Path, pathspecs: &[String]) -> Result<BTreeSet<String>> {
+    let output = git(
+        working_dir,
+        &["ls-files", "--others", "--exclude-standard", "--"],
+        pathspecs,
+    )
+    .await?;
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+async fn git(working_dir: &Path, args: &[&str], pathspecs: &[String]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .args(pathspecs)
+        .current_dir(working_dir)
+        .output()
+        .await
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The directory a glob's matches live under: its directories before the first
+/// wildcard, relative to the working directory.
+pub fn glob_root(glob: &str) -> PathBuf {
+    let parts: Vec<&str> = glob.split('/').collect();
+    let Some((_, dirs)) = parts.split_last() else {
+        return PathBuf::new();
+    };
+    dirs.iter()
+        .take_while(|dir| !dir.contains(['*', '?', '[']))
+        .collect()
+}
+
+/// Where git keeps HEAD and the index, and where it keeps the refs. They differ
+/// in a linked worktree.
+#[derive(Debug, Clone)]
+pub struct GitDirs {
+    pub git_dir: PathBuf,
+    pub common_dir: PathBuf,
+}
+
+impl GitDirs {
+    async fn locate(working_dir: &Path) -> Result<Self> {
+        let output = git(
+            working_dir,
+            &[
+                "rev-parse",
+                "--path-format=absolute",
+                "--git-dir",
+                "--git-common-dir",
+            ],
+            &[],
+        )
+        .await?;
+        let mut lines = output.lines().map(PathBuf::from);
+        match (lines.next(), lines.next()) {
+            (Some(git_dir), Some(common_dir)) => Ok(Self {
+                git_dir,
+                common_dir,
+            }),
+            _ => anyhow::bail!("git rev-parse did not name the git directory"),
+        }
+    }
+
+    /// Whether a change at `path` can change what git reports for the watched
+    /// files: any change in the working tree, and in the git directory only a
+    /// moved HEAD, a restaged index or updated refs.
+    pub fn is_relevant(&self, path: &Path) -> bool {
+        if let Ok(rel) = path.strip_prefix(&self.git_dir) {
+            if rel == Path::new("HEAD") || rel == Path::new("index") {
+                return true;
+            }
+        }
+        match path.strip_prefix(&self.common_dir) {
+            Ok(rel) => rel.starts_with("refs") || rel == Path::new("packed-refs"),
+            Err(_) => !path.starts_with(&self.git_dir),
+        }
+    }
+}
+
+/// File system events that can change what the watched globs match. The
+/// watcher stops when this is dropped.
+struct TreeWatcher {
+    _watcher: RecommendedWatcher,
+    changed: mpsc::UnboundedReceiver<()>,
+}
+
+impl TreeWatcher {
+    async fn start(working_dir: &Path, globs: &[String]) -> Result<Self> {
+        let dirs = GitDirs::locate(working_dir).await?;
+        let (tx, changed) = mpsc::unbounded_channel();
+        let filter = dirs.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else { return };
+                // Reads, including git's own while checking, change nothing
+                if !event.kind.is_access() && event.paths.iter().any(|p| filter.is_relevant(p)) {
+                    let _ = tx.send(());
+                }
+            })
+            .context("Failed to start the file watcher")?;
+
+        let mut roots: BTreeSet<PathBuf> = BTreeSet::new();
+        for glob in globs {
+            // A directory that does not exist yet shows up in its nearest existing parent
+            let mut root = working_dir.join(glob_root(glob));
+            while !root.is_dir() && root.pop() {}
+            roots.insert(root);
+        }
+        for root in &roots {
+            watcher.watch(root, RecursiveMode::Recursive)?;
+        }
+        watcher.watch(&dirs.git_dir, RecursiveMode::NonRecursive)?;
+        if dirs.common_dir != dirs.git_dir {
+            watcher.watch(&dirs.common_dir, RecursiveMode::NonRecursive)?;
+        }
+        watcher.watch(&dirs.common_dir.join("refs"), RecursiveMode::Recursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            changed,
+        })
+    }
+}
+
+/// Feedback for the re-planning pass: what changed, the diff and the user's notes.
+pub fn replan_feedback(globs: &[String], changes: &WatchedChanges, notes: &str) -> String {
+    let mut feedback = format!(
+        "Files matching {} changed since this plan was accepted. Update the plan so it \
+         matches the code as it is now.",
+        globs.join(", ")
+    );
+    if !notes.trim().is_empty() {
+        feedback.push_str(&format!("\n\nNotes: {}", notes.trim()));
+    }
+    if !changes.created.is_empty() {
+        feedback.push_str("\n\nNew files:");
+        for file in &changes.created {
+            feedback.push_str(&format!("\n- {}", file));
+        }
+    }
+    if !changes.diff.trim().is_empty() {
+        let mut diff: String = changes.diff.chars().take(MAX_DIFF_CHARS).collect();
+        if diff.len() < changes.diff.len() {
+            diff.push_str("\n... (diff truncated)");
+        }
+        feedback.push_str(&format!(
+            "\n\nDiff since the plan was accepted:\n```diff\n{}\n```",
+            diff.trim_end()
+        ));
+    }
+    feedback
+}
+
+/// What the user answered to the re-plan prompt.
+enum Answer {
+    Replan(String),
+    Keep,
+    Stop,
+}
+
+/// Watches the files after the plan was accepted until a re-plan starts or the
+/// session is stopped. Returns `NeedsRestart` to re-plan, otherwise `Accepted`.
+pub async fn watch_for_replan(
+    spec: &WatchSpec,
+    context: &WorkflowPhaseContext<'_>,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Result<WorkflowResult> {
+    let pathspecs = pathspecs(&spec.globs);
+    let started = async {
+        let baseline = Baseline::capture(context.working_dir, &pathspecs).await?;
+        let watcher = TreeWatcher::start(context.working_dir, &spec.globs).await?;
+        anyhow::Ok((baseline, watcher))
+    };
+    let (mut baseline, mut watcher) = match started.await {
+        Ok(started) => started,
+        Err(e) => {
+            context.sender.send_output(format!(
+                "[planning] Cannot watch for changes, {:#} (--watch needs a git repository with a commit)",
+                e
+            ));
+            return Ok(WorkflowResult::Accepted);
+        }
+    };
+    context.sender.send_output(format!(
+        "[planning] Watching {} for changes - stop the session to end watching",
+        spec.globs.join(", ")
+    ));
+
+    let mut unsettled = false;
+    loop {
+        tokio::select! {
+            // Each event restarts the quiet time
+            Some(()) = watcher.changed.recv() => {
+                unsettled = true;
+                continue;
+            }
+            _ = tokio::time::sleep(SETTLE_DELAY), if unsettled => {}
+            Some(cmd) = control_rx.recv() => match cmd {
+                WorkflowCommand::Stop => {
+                    context.sender.send_output("[planning] Stopped watching".to_string());
+                    return Ok(WorkflowResult::Accepted);
+                }
+                // Feedback typed while watching re-plans with whatever changed so far
+                WorkflowCommand::Interrupt { feedback } => {
+                    let changes = baseline
+                        .changes(context.working_dir, &pathspecs)
+                        .await
+                        .unwrap_or_default();
+                    return Ok(replan(context, spec, &changes, &feedback).await);
+                }
+            },
+            else => return Ok(WorkflowResult::Accepted),
+        }
+        unsettled = false;
+
+        let changes = match baseline.changes(context.working_dir, &pathspecs).await {
+            Ok(changes) => changes,
+            Err(e) => {
+                context.log_workflow(&format!("Watch check failed: {:#}", e));
+                continue;
+            }
+        };
+        if changes.is_empty() {
+            continue;
+        }
+
+        if spec.auto {
+            return Ok(replan(context, spec, &changes, "").await);
+        }
+        match ask_to_replan(context, &changes, approval_rx, control_rx).await {
+            Answer::Replan(notes) => return Ok(replan(context, spec, &changes, &notes).await),
+            Answer::Stop => return Ok(WorkflowResult::Accepted),
+            Answer::Keep => {
+                baseline = Baseline::capture(context.working_dir, &pathspecs).await?;
+                context.sender.send_output(
+                    "[planning] Keeping the plan - watching for further changes".to_string(),
+                );
+            }
+        }
+    }
+}
+
+/// Sends the accepted plan back to planning with the changes as feedback.
+async fn replan(
+    context: &WorkflowPhaseContext<'_>,
+    spec: &WatchSpec,
+    changes: &WatchedChanges,
+    notes: &str,
+) -> WorkflowResult {
+    let feedback = replan_feedback(&spec.globs, changes, notes);
+    context.log_workflow(&format!(
+        "Re-planning after watched files changed: {} changed, {} new",
+        changes.changed.len(),
+        changes.created.len()
+    ));
+    context
+        .sender
+        .send_output("[planning] Watched files changed - re-planning".to_string());
+    context
+        .dispatch_command(DomainCommand::ReopenPlanning {
+            feedback: feedback.clone(),
+        })
+        .await;
+    WorkflowResult::NeedsRestart {
+        user_feedback: feedback,
+    }
+}
+
+async fn ask_to_replan(
+    context: &WorkflowPhaseContext<'_>,
+    changes: &WatchedChanges,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Answer {
+    let mut summary = "Watched files changed since the plan was accepted:\n".to_string();
+    for file in &changes.changed {
+        summary.push_str(&format!("  M {}\n", file));
+    }
+    for file in &changes.created {
+        summary.push_str(&format!("  A {}\n", file));
+    }
+    summary.push_str("\nAccept to keep the plan as it is, or decline with notes to re-plan.");
+    context.sender.send_approval_request(summary);
+
+    loop {
+        tokio::select! {
+            Some(cmd) = control_rx.recv() => {
+                return match cmd {
+                    WorkflowCommand::Stop => Answer::Stop,
+                    WorkflowCommand::Interrupt { feedback } => Answer::Replan(feedback),
+                };
+            }
+            response = approval_rx.recv() => match response {
+                Some(UserApprovalResponse::Decline(notes)) => return Answer::Replan(notes),
+                Some(
+                    UserApprovalResponse::Accept
+                    | UserApprovalResponse::Implement
+                    | UserApprovalResponse::ApproveWithConditions { .. },
+                ) => return Answer::Keep,
+                Some(_) => continue,
+                None => return Answer::Stop,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/replan_watch_tests.rs"]
+mod tests;
@@ -0,0 +1,93 @@
+use super::*;
+use std::process::Command as StdCommand;
+use tempfile::tempdir;
+
+fn git_in(dir: &Path, args: &[&str]) {
+    let status = StdCommand::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@localhost"])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[tokio::test]
+async fn test_baseline_reports_only_watched_changes_since_capture() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    std::fs::create_dir_all(repo.join("src/api")).unwrap();
+    std::fs::write(repo.join("src/api/routes.rs"), "fn routes() {}\n").unwrap();
+    std::fs::write(repo.join("README.md"), "readme\n").unwrap();
+    git_in(repo, &["init", "-q"]);
+    git_in(repo, &["add", "."]);
+    git_in(repo, &["commit", "-q", "-m", "init"]);
+    // Uncommitted and untracked work at acceptance is part of the baseline
+    std::fs::write(repo.join("src/api/routes.rs"), "fn routes() { todo!() }\n").unwrap();
+    std::fs::write(repo.join("src/api/draft.rs"), "draft\n").unwrap();
+
+    let pathspecs = pathspecs(&["src/api/**".to_string()]);
+    let baseline = Baseline::capture(repo, &pathspecs).await.unwrap();
+    assert!(baseline.changes(repo, &pathspecs).await.unwrap().is_empty());
+
+    std::fs::write(repo.join("src/api/routes.rs"), "fn routes() { serve() }\n").unwrap();
+    std::fs::write(repo.join("src/api/auth.rs"), "fn auth() {}\n").unwrap();
+    std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+
+    let changes = baseline.changes(repo, &pathspecs).await.unwrap();
+    assert_eq!(changes.changed, vec!["src/api/routes.rs".to_string()]);
+    assert_eq!(changes.created, vec!["src/api/auth.rs".to_string()]);
+    assert!(changes.diff.contains("-fn routes() { todo!() }"));
+    assert!(changes.diff.contains("+fn routes() { serve() }"));
+}
+
+#[test]
+fn test_replan_feedback_includes_notes_new_files_and_truncated_diff() {
+    let changes = WatchedChanges {
+        changed: vec!["src/api/routes.rs".to_string()],
+        created: vec!["src/api/auth.rs".to_string()],
+        diff: "x".repeat(MAX_DIFF_CHARS + 10),
+    };
+
+    let feedback = replan_feedback(&["src/api/**".to_string()], &changes, " keep v1 routes ");
+
+    assert!(feedback.starts_with("Files matching src/api/** changed"));
+    assert!(feedback.contains("Notes: keep v1 routes\n"));
+    assert!(feedback.contains("New files:\n- src/api/auth.rs"));
+    assert!(feedback.contains("... (diff truncated)"));
+    assert!(!feedback.contains(&"x".repeat(MAX_DIFF_CHARS + 1)));
+}
+
+#[test]
+fn test_glob_root_stops_at_the_first_wildcard() {
+    assert_eq!(glob_root("src/api/**"), PathBuf::from("src/api"));
+    assert_eq!(glob_root("src/*/routes.rs"), PathBuf::from("src"));
+    assert_eq!(glob_root("Cargo.toml"), PathBuf::new());
+    assert_eq!(glob_root("docs/guide.md"), PathBuf::from("docs"));
+}
+
+#[test]
+fn test_only_head_index_and_refs_count_in_the_git_directory() {
+    let dirs = GitDirs {
+        git_dir: PathBuf::from("/repo/.git"),
+        common_dir: PathBuf::from("/repo/.git"),
+    };
+    assert!(dirs.is_relevant(Path::new("/repo/src/api/routes.rs")));
+    assert!(dirs.is_relevant(Path::new("/repo/.git/HEAD")));
+    assert!(dirs.is_relevant(Path::new("/repo/.git/index")));
+    assert!(dirs.is_relevant(Path::new("/repo/.git/refs/heads/main")));
+    assert!(dirs.is_relevant(Path::new("/repo/.git/packed-refs")));
+    assert!(!dirs.is_relevant(Path::new("/repo/.git/objects/ab/cdef")));
+    assert!(!dirs.is_relevant(Path::new("/repo/.git/index.lock")));
+
+    // A linked worktree keeps HEAD and the index apart from the shared refs
+    let worktree = GitDirs {
+        git_dir: PathBuf::from("/repo/.git/worktrees/feature"),
+        common_dir: PathBuf::from("/repo/.git"),
+    };
+    assert!(worktree.is_relevant(Path::new("/repo/.git/worktrees/feature/HEAD")));
+    assert!(worktree.is_relevant(Path::new("/repo/.git/refs/heads/feature")));
+    assert!(!worktree.is_relevant(Path::new("/repo/.git/HEAD")));
+    assert!(!worktree.is_relevant(Path::new("/repo/.git/worktrees/feature/logs/HEAD")));
+    assert!(worktree.is_relevant(Path::new("/work/feature/src/lib.rs")));
+}
@@ -0,0 +1,100 @@
+//! Reviewer verdict reuse for unchanged plans.
+//!
+//! With `reviewing.skip_unchanged`, each reviewer's approval is remembered with a
+//! hash of the plan content it reviewed. When a later cycle presents identical plan
+//! content, that reviewer is skipped and the approval is reused, recorded as a
+//! `ReviewerVerdictReused` event. Rejections are never reused so a reviewer always
+//! gets to re-check a plan it objected to.
+
+use super::reviewing::WorkflowPhaseContext;
+use crate::config::WorkflowConfig;
+use crate::domain::types::AgentId;
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::ReviewResult;
+use crate::tui::ReviewKind;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Hashes the plan file content, or None if it can't be read.
+pub fn plan_content_hash(plan_path: &Path) -> Option<u64> {
+    let content = std::fs::read(plan_path).ok()?;
+    let digest = Sha256::digest(&content);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(digest.get(..8)?);
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// Hash of the plan under review, or None when `skip_unchanged` is off.
+pub fn reviewed_plan_hash(view: &WorkflowView, config: &WorkflowConfig) -> Option<u64> {
+    if !config.workflow.reviewing.skip_unchanged {
+        return None;
+    }
+    plan_content_hash(view.plan_path()?.as_path())
+}
+
+/// Approvals keyed by reviewer display ID, with the plan hash each one covered.
+#[derive(Debug, Default)]
+pub struct ReviewCache {
+    approvals: HashMap<String, (u64, ReviewResult)>,
+}
+
+impl ReviewCache {
+    /// Records a review of the plan with the given hash.
+    /// Approvals are cached; a rejection clears the reviewer's entry.
+    pub fn record(&mut self, plan_hash: u64, review: &ReviewResult) {
+        if review.needs_revision {
+            self.approvals.remove(&review.agent_name);
+        } else {
+            self.approvals
+                .insert(review.agent_name.clone(), (plan_hash, review.clone()));
+        }
+    }
+
+    /// Returns the reviewer's cached approval if it covered exactly this plan content.
+    pub fn reusable(&self, reviewer_id: &str, plan_hash: u64) -> Option<&ReviewResult> {
+        self.approvals
+            .get(reviewer_id)
+            .filter(|(hash, _)| *hash == plan_hash)
+            .map(|(_, review)| review)
+    }
+}
+
+/// Reuses a cached approval: reports it to the UI and dispatches `ReviewerVerdictReused`.
+pub async fn reuse_verdict(
+    context: &WorkflowPhaseContext<'_>,
+    iteration: u32,
+    review: &ReviewResult,
+    plan_hash: u64,
+) {
+    let reviewer_id = review.agent_name.clone();
+    context.log_workflow(&format!(
+        "Reusing approval from {} (plan hash {:016x} unchanged)",
+        reviewer_id, plan_hash
+    ));
+    let sender = context.sender;
+    sender.send_output(format!(
+        "[review] {}: plan unchanged since its approval - reusing verdict",
+        reviewer_id
+    ));
+    sender.send_reviewer_started(ReviewKind::Plan, iteration, reviewer_id.clone());
+    sender.send_reviewer_completed(
+        ReviewKind::Plan,
+        iteration,
+        reviewer_id.clone(),
+        true,
+        format!("Reused (plan unchanged): {}", review.summary),
+        0,
+    );
+    context
+        .dispatch_command(DomainCommand::ReviewerVerdictReused {
+            reviewer_id: AgentId::from(reviewer_id.as_str()),
+            plan_hash,
+        })
+        .await;
+}
+
+#[cfg(test)]
+#[path = "tests/review_cache_tests.rs"]
+mod tests;
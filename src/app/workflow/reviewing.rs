@@ -1,5 +1,12 @@
 //! Reviewing phase execution.
 
+use super::backup_reviewers::{record_substitution, BackupReviewers};
+use super::review_cache::{reuse_verdict, reviewed_plan_hash, ReviewCache};
+use super::round_retry::{
+    output_failure_bundles, retry_reviewers, start_fresh_conversations, wait_before_retry,
+};
+use super::section_review::{SectionOwners, SectionRound};
+use super::section_stream::take_streamed_reviews;
 use super::WorkflowResult;
 use crate::app::util::{build_all_reviewers_failed_summary, build_review_failure_summary};
 use crate::app::workflow_decisions::{
@@ -9,14 +16,14 @@ use crate::app::workflow_decisions::{
 use crate::config::{AgentRef, WorkflowConfig};
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::review::ReviewMode;
-use crate::domain::review::SequentialReviewState;
+use crate::domain::review::{SequentialExitPolicy, SequentialReviewState};
 use crate::domain::types::AgentId;
 use crate::domain::types::{FeedbackPath, FeedbackStatus};
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
 use crate::phases::{
-    self, aggregate_reviews, merge_feedback, run_multi_agent_review_with_context,
-    write_feedback_files,
+    self, aggregate_reviews, feedback_path_for_agent, merge_feedback,
+    run_multi_agent_review_with_context, write_feedback_files, ReviewTargets,
 };
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::{
@@ -25,7 +32,7 @@ use crate::tui::{
 use anyhow::anyhow;
 use anyhow::Result;
 use ractor::ActorRef;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
@@ -81,6 +88,8 @@ pub async fn run_reviewing_phase(
     approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
     control_rx: &mut mpsc::Receiver<WorkflowCommand>,
     last_reviews: &mut Vec<phases::ReviewResult>,
+    review_cache: &mut ReviewCache,
+    section_owners: &mut SectionOwners,
 ) -> Result<Option<WorkflowResult>> {
     let working_dir = context.working_dir;
     let config = context.config;
@@ -93,13 +102,9 @@ pub async fn run_reviewing_phase(
     sender.send_phase_started("Reviewing".to_string());
     sender.send_output("".to_string());
     sender.send_output(format!("=== REVIEW PHASE (Iteration {}) ===", iteration));
-    let reviewer_display_names: Vec<&str> = config
-        .workflow
-        .reviewing
-        .agents
-        .iter()
-        .map(|r| r.display_id())
-        .collect();
+    let enabled_reviewers = config.workflow.reviewing.enabled_agents();
+    let reviewer_display_names: Vec<&str> =
+        enabled_reviewers.iter().map(|r| r.display_id()).collect();
     sender.send_output(format!("Reviewers: {}", reviewer_display_names.join(", ")));
 
     // Dispatch ReviewCycleStarted command to CQRS actor
@@ -111,14 +116,47 @@ pub async fn run_reviewing_phase(
         .dispatch_command(DomainCommand::ReviewCycleStarted {
             mode: ReviewMode::Parallel,
             reviewers: reviewer_ids,
+            preset: config.workflow.reviewing.preset.clone(),
         })
         .await;
 
     let mut reviews_by_agent: HashMap<String, phases::ReviewResult> = HashMap::new();
-    let mut pending_reviewers: Vec<AgentRef> = config.workflow.reviewing.agents.clone();
+    let mut pending_reviewers: Vec<AgentRef> = enabled_reviewers.clone();
     let mut retry_attempts = 0usize;
+    let mut retry_view: Option<WorkflowView> = None;
+    let mut backups = BackupReviewers::new(config, view);
+
+    // Skip reviewers whose approval already covers this exact plan content
+    let plan_hash = reviewed_plan_hash(view, config);
+    let mut reused_ids: HashSet<String> = HashSet::new();
+    if let Some(hash) = plan_hash {
+        for reviewer in &enabled_reviewers {
+            let Some(review) = review_cache.reusable(reviewer.display_id(), hash) else {
+                continue;
+            };
+            if reused_ids.is_empty() {
+                sender.send_review_round_started(ReviewKind::Plan, iteration);
+            }
+            reuse_verdict(context, iteration, review, hash).await;
+            reused_ids.insert(review.agent_name.clone());
+            reviews_by_agent.insert(review.agent_name.clone(), review.clone());
+        }
+        pending_reviewers.retain(|r| !reused_ids.contains(r.display_id()));
+    }
+    // Reviewers that reviewed every section while the plan was written (`pipelined`)
+    let round_started = take_streamed_reviews(
+        context,
+        view,
+        &mut pending_reviewers,
+        &mut reviews_by_agent,
+        !reused_ids.is_empty(),
+    );
 
-    loop {
+    let section_round = SectionRound::start(view, config);
+    let pending_ids: Vec<&str> = pending_reviewers.iter().map(|r| r.display_id()).collect();
+    let review_targets = section_round.targets(section_owners, &pending_ids, context);
+
+    while !pending_reviewers.is_empty() {
         // Check for commands before running reviewers
         if let Ok(cmd) = control_rx.try_recv() {
             match cmd {
@@ -144,15 +182,16 @@ pub async fn run_reviewing_phase(
             pending_reviewers.iter().map(|r| r.display_id()).collect();
         context.log_workflow(&format!("Running reviewers: {:?}", pending_display_ids));
         let batch = run_multi_agent_review_with_context(
-            view,
+            retry_view.as_ref().unwrap_or(view),
             working_dir,
             config,
             &pending_reviewers,
             sender.clone(),
             iteration,
             context.session_logger.clone(),
-            true, // emit_round_started: parallel mode always emits
+            !round_started, // emit_round_started: already emitted for reused or section verdicts
             context.actor_ref.clone(),
+            &review_targets,
         )
         .await;
 
@@ -187,19 +226,41 @@ pub async fn run_reviewing_phase(
             .reviewing
             .agents
             .iter()
+            .chain(&config.workflow.reviewing.backup_reviewers)
             .filter(|r| failed_display_ids.contains(&r.display_id().to_string()))
             .cloned()
             .collect();
 
+        // Backups take over from reviewers that keep failing; the rest run again
+        let mut substitutes = Vec::new();
+        for failure in &batch.failures {
+            if let Some(backup) = backups.substitute(failure) {
+                record_substitution(context, iteration, failure, &backup).await;
+                substitutes.push((failure.agent_name.clone(), backup));
+            }
+        }
+        if !substitutes.is_empty() {
+            pending_reviewers = failed_agent_refs
+                .into_iter()
+                .filter(|r| !substitutes.iter().any(|(id, _)| id == r.display_id()))
+                .chain(substitutes.into_iter().map(|(_, backup)| backup))
+                .collect();
+            continue;
+        }
+
         if reviews_by_agent.is_empty() {
             let max_retries = config.failure_policy.max_retries() as usize;
             if retry_attempts < max_retries {
                 retry_attempts += 1;
-                sender.send_output(format!(
-                    "[review] All reviewers failed; retrying ({}/{})...",
-                    retry_attempts, max_retries
-                ));
-                pending_reviewers = failed_agent_refs.clone();
+                let attempt = retry_attempts as u32;
+                if let Some(result) =
+                    wait_before_retry(context, attempt, &batch.failures, control_rx).await
+                {
+                    return Ok(Some(result));
+                }
+                pending_reviewers = retry_reviewers(&config.failure_policy, &failed_agent_refs);
+                retry_view =
+                    Some(start_fresh_conversations(context, view, &pending_reviewers).await);
                 continue;
             }
 
@@ -275,6 +336,7 @@ pub async fn run_reviewing_phase(
 
     let mut reviews: Vec<phases::ReviewResult> = reviews_by_agent.into_values().collect();
     reviews.sort_by(|a, b| a.agent_name.cmp(&b.agent_name));
+    section_round.record_round(section_owners, &reviews);
 
     // Get feedback path from view (already an absolute path)
     let feedback_path = view
@@ -296,17 +358,29 @@ pub async fn run_reviewing_phase(
         );
     }
 
-    let status = aggregate_reviews(&reviews, &config.workflow.reviewing.aggregation);
+    let status = aggregate_reviews(
+        &reviews,
+        &config.workflow.reviewing.aggregation,
+        &config.workflow.reviewing.confidence,
+    );
     context.log_workflow(&format!("Aggregated status: {:?}", status));
 
-    // Dispatch ReviewerApproved/ReviewerRejected for each reviewer
-    for review in &reviews {
+    // Dispatch ReviewerApproved/ReviewerRejected for each reviewer that ran
+    for review in reviews
+        .iter()
+        .filter(|r| !reused_ids.contains(&r.agent_name))
+    {
+        if let Some(hash) = plan_hash {
+            review_cache.record(hash, review);
+        }
+        section_round.record_review(section_owners, review);
         let reviewer_id = AgentId::from(review.agent_name.as_str());
         if review.needs_revision {
             context
                 .dispatch_command(DomainCommand::ReviewerRejected {
                     reviewer_id,
                     feedback_path: FeedbackPath::from(feedback_path.clone()),
+                    low_confidence: review.low_confidence,
                 })
                 .await;
         } else {
@@ -366,26 +440,6 @@ pub async fn run_reviewing_phase(
     Ok(None)
 }
 
-/// Outputs diagnostics bundle paths for failures that have them.
-fn output_failure_bundles(sender: &SessionEventSender, failures: &[phases::ReviewFailure]) {
-    let mut has_bundles = false;
-    for failure in failures {
-        if let Some(ref path) = failure.bundle_path {
-            sender.send_output(format!(
-                "[diagnostics] {}: {}",
-                failure.agent_name,
-                path.display()
-            ));
-            has_bundles = true;
-        }
-    }
-    if has_bundles {
-        sender.send_output(
-            "[warning] Bundles may contain sensitive information from logs.".to_string(),
-        );
-    }
-}
-
 /// Build max iterations summary using WorkflowView's plan_path.
 pub(crate) fn build_max_iterations_summary_from_view(
     view: &WorkflowView,
@@ -501,13 +555,16 @@ pub(crate) fn build_max_iterations_summary_from_view(
 
     summary.push_str("---\n\n");
     summary.push_str("Choose an action:\n");
-    summary.push_str("- **[p] Proceed**: Accept the current plan and continue to implementation\n");
+    summary.push_str("- **[y] Proceed**: Accept the current plan and continue to implementation\n");
     summary.push_str(
         "- **[c] Continue Review**: Run another review cycle (adds 1 to max iterations)\n",
     );
     summary.push_str(
         "- **[d] Restart with Feedback**: Provide feedback to restart the entire workflow\n",
     );
+    summary.push_str(
+        "- **[p] Appeal**: Ask the rejecting reviewers to reconsider given your counter-argument\n",
+    );
 
     summary
 }
@@ -532,11 +589,13 @@ pub async fn run_sequential_reviewing_phase(
     approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
     control_rx: &mut mpsc::Receiver<WorkflowCommand>,
     last_reviews: &mut Vec<phases::ReviewResult>,
+    review_cache: &mut ReviewCache,
+    section_owners: &mut SectionOwners,
 ) -> Result<Option<WorkflowResult>> {
     let working_dir = context.working_dir;
     let config = context.config;
     let sender = context.sender;
-    let reviewers = &config.workflow.reviewing.agents;
+    let reviewers = config.workflow.reviewing.enabled_agents();
     let iteration = view.iteration().unwrap_or_default().0;
     let reviewer_ids: Vec<&str> = reviewers.iter().map(|r| r.display_id()).collect();
 
@@ -557,11 +616,12 @@ pub async fn run_sequential_reviewing_phase(
 
             context
                 .dispatch_command(DomainCommand::ReviewCycleStarted {
-                    mode: ReviewMode::Sequential(SequentialReviewState::new_with_cycle(
-                        &reviewer_ids,
-                        &review_counts,
-                    )),
+                    mode: ReviewMode::Sequential(
+                        SequentialReviewState::new_with_cycle(&reviewer_ids, &review_counts)
+                            .with_exit_policy(config.workflow.reviewing.sequential_exit),
+                    ),
                     reviewers: reviewer_agent_ids,
+                    preset: config.workflow.reviewing.preset.clone(),
                 })
                 .await;
 
@@ -581,11 +641,12 @@ pub async fn run_sequential_reviewing_phase(
 
         context
             .dispatch_command(DomainCommand::ReviewCycleStarted {
-                mode: ReviewMode::Sequential(SequentialReviewState::new_with_cycle(
-                    &reviewer_ids,
-                    &review_counts,
-                )),
+                mode: ReviewMode::Sequential(
+                    SequentialReviewState::new_with_cycle(&reviewer_ids, &review_counts)
+                        .with_exit_policy(seq_state.exit_policy()),
+                ),
                 reviewers: reviewer_agent_ids,
+                preset: config.workflow.reviewing.preset.clone(),
             })
             .await;
 
@@ -640,13 +701,12 @@ pub async fn run_sequential_reviewing_phase(
                 .dispatch_command(DomainCommand::ReviewCycleCompleted { approved: true })
                 .await;
         } else {
-            // This shouldn't happen in normal flow - if someone rejected, we would have
-            // dispatched ReviewCycleCompleted(false) immediately. But handle it gracefully.
-            context.log_workflow("Not all reviewers approved (unexpected state)");
-            sender.send_review_round_completed(ReviewKind::Plan, iteration, false);
-            context
-                .dispatch_command(DomainCommand::ReviewCycleCompleted { approved: false })
-                .await;
+            // The exit policy let the cycle run on past its rejections
+            sender.send_output(format!(
+                "[sequential] {} reviewer(s) rejected - will revise and restart",
+                seq_state.rejections().len()
+            ));
+            complete_rejected_cycle(view, context, iteration).await;
         }
 
         return Ok(None);
@@ -689,22 +749,32 @@ pub async fn run_sequential_reviewing_phase(
         sender.send_review_round_started(ReviewKind::Plan, iteration);
     }
 
+    // Reuse the reviewer's approval if the plan content hasn't changed since
+    let plan_hash = reviewed_plan_hash(view, config);
+    if let Some(hash) = plan_hash {
+        if let Some(review) = review_cache.reusable(reviewer_id, hash) {
+            // Aggregate advances the index as for an approval
+            reuse_verdict(context, iteration, review, hash).await;
+            return Ok(None);
+        }
+    }
+
     sender.send_output(format!(
         "Running reviewer: {} (plan version {})",
         reviewer_id, plan_version
     ));
 
-    // Run the reviewer with retry loop
-    let review = run_single_reviewer_with_retries(
-        view,
-        reviewer,
-        reviewer_id,
-        iteration,
-        context,
+    let section_round = SectionRound::start(view, config);
+    let inputs = ReviewerInputs {
+        targets: section_round.targets(section_owners, &[reviewer_id], context),
         approval_rx,
         control_rx,
-    )
-    .await?;
+    };
+
+    // Run the reviewer with retry loop
+    let review =
+        run_single_reviewer_with_retries(view, reviewer, reviewer_id, iteration, context, inputs)
+            .await?;
 
     // Handle early exit from retry loop
     let review = match review {
@@ -712,15 +782,29 @@ pub async fn run_sequential_reviewing_phase(
         None => return Ok(Some(WorkflowResult::Stopped)), // User stopped during retries
     };
 
-    // Store review for potential revision feedback
-    last_reviews.clear();
+    if let Some(hash) = plan_hash {
+        review_cache.record(hash, &review);
+    }
+    section_round.record_review(section_owners, &review);
+    // Approvals keep the brief for the rest of the cycle's reviewers
+    if review.needs_revision {
+        section_round.record_round(section_owners, std::slice::from_ref(&review));
+    }
+
+    // Store review for potential revision feedback, with the cycle's earlier rejections
+    if seq_state.rejections().is_empty() {
+        last_reviews.clear();
+    }
     last_reviews.push(review.clone());
 
-    // Write feedback file
-    let feedback_path = view
+    // Write feedback file, one per reviewer when the cycle can outlive a rejection
+    let mut feedback_path = view
         .feedback_path()
         .map(|fp| fp.0.clone())
         .unwrap_or_else(|| std::path::PathBuf::from("feedback.md"));
+    if seq_state.exit_policy() != SequentialExitPolicy::FirstRejection {
+        feedback_path = feedback_path_for_agent(&feedback_path, reviewer_id, reviewers.len());
+    }
     if let Err(e) = write_feedback_files(std::slice::from_ref(&review), &feedback_path) {
         context.session_logger.log(
             LogLevel::Warn,
@@ -732,41 +816,36 @@ pub async fn run_sequential_reviewing_phase(
     // =========================================================================
     // STEP 4: Handle the review result
     // =========================================================================
-    let max_iterations = view.max_iterations().map(|m| m.0).unwrap_or(3);
-
     if review.needs_revision {
         // Reviewer rejected
         context.log_workflow(&format!(
             "Reviewer {} REJECTED (plan version {})",
             reviewer_id, plan_version
         ));
+        let stops = seq_state
+            .exit_policy()
+            .stops_after(seq_state.rejections().len() + 1);
         sender.send_output(format!(
-            "[sequential] {} REJECTED - will revise and restart",
-            reviewer_id
+            "[sequential] {} REJECTED - {}",
+            reviewer_id,
+            if stops {
+                "will revise and restart"
+            } else {
+                "continuing with the next reviewer"
+            }
         ));
 
-        // Dispatch rejection event
+        // Dispatch rejection event - aggregate advances the index if the cycle goes on
         context
             .dispatch_command(DomainCommand::ReviewerRejected {
                 reviewer_id: AgentId::from(reviewer_id),
                 feedback_path: FeedbackPath::from(feedback_path.clone()),
+                low_confidence: review.low_confidence,
             })
             .await;
 
-        sender.send_review_round_completed(ReviewKind::Plan, iteration, false);
-
-        // Complete the cycle (rejected)
-        context
-            .dispatch_command(DomainCommand::ReviewCycleCompleted { approved: false })
-            .await;
-
-        // Check iteration limit
-        if iteration >= max_iterations {
-            // Dispatch PlanningMaxIterationsReached and return - main loop handles AwaitingPlanningDecision
-            context
-                .dispatch_command(DomainCommand::PlanningMaxIterationsReached)
-                .await;
-            return Ok(None);
+        if stops {
+            complete_rejected_cycle(view, context, iteration).await;
         }
     } else {
         // Reviewer approved
@@ -793,7 +872,38 @@ pub async fn run_sequential_reviewing_phase(
     Ok(None)
 }
 
-/// Runs a single reviewer with retry logic for failures.
+/// Completes a sequential cycle that ends in revision, or in the planning
+/// decision once the iteration limit is reached.
+async fn complete_rejected_cycle(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    iteration: u32,
+) {
+    context
+        .sender
+        .send_review_round_completed(ReviewKind::Plan, iteration, false);
+    context
+        .dispatch_command(DomainCommand::ReviewCycleCompleted { approved: false })
+        .await;
+
+    // Main loop handles AwaitingPlanningDecision
+    let max_iterations = view.max_iterations().map(|m| m.0).unwrap_or(3);
+    if iteration >= max_iterations {
+        context
+            .dispatch_command(DomainCommand::PlanningMaxIterationsReached)
+            .await;
+    }
+}
+
+/// What a sequential reviewer reviews and the channels it waits on between retries.
+struct ReviewerInputs<'a> {
+    targets: ReviewTargets,
+    approval_rx: &'a mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &'a mut mpsc::Receiver<WorkflowCommand>,
+}
+
+/// Runs a single reviewer with retry logic for failures, switching to a backup
+/// reviewer if it keeps failing. The backup's verdict counts for `reviewer_id`.
 /// Returns Some(review) on success, None if user stopped.
 async fn run_single_reviewer_with_retries(
     view: &WorkflowView,
@@ -801,13 +911,19 @@ async fn run_single_reviewer_with_retries(
     reviewer_id: &str,
     iteration: u32,
     context: &WorkflowPhaseContext<'_>,
-    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
-    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+    inputs: ReviewerInputs<'_>,
 ) -> Result<Option<phases::ReviewResult>> {
+    let ReviewerInputs {
+        targets,
+        approval_rx,
+        control_rx,
+    } = inputs;
     let working_dir = context.working_dir;
     let config = context.config;
     let sender = context.sender;
     let mut retry_attempts = 0usize;
+    let mut reviewer = reviewer.clone();
+    let mut backups = BackupReviewers::new(config, view);
 
     loop {
         // Check for commands before running
@@ -833,12 +949,13 @@ async fn run_single_reviewer_with_retries(
             view,
             working_dir,
             config,
-            std::slice::from_ref(reviewer),
+            std::slice::from_ref(&reviewer),
             sender.clone(),
             iteration,
             context.session_logger.clone(),
             false, // Don't emit round_started, we handle it in caller
             context.actor_ref.clone(),
+            &targets,
         )
         .await;
 
@@ -858,6 +975,12 @@ async fn run_single_reviewer_with_retries(
 
         // Failure - retry or prompt user
         if let Some(failure) = batch.failures.into_iter().next() {
+            if let Some(backup) = backups.substitute(&failure) {
+                record_substitution(context, iteration, &failure, &backup).await;
+                reviewer = backup;
+                retry_attempts = 0;
+                continue;
+            }
             let max_retries = config.failure_policy.max_retries() as usize;
             if retry_attempts < max_retries {
                 retry_attempts += 1;
@@ -1,5 +1,7 @@
 //! Revising phase execution.
 
+use super::planning::record_revised_plan_metadata;
+use super::triage::write_backlog_section;
 use super::{dispatch_domain_command, WorkflowResult};
 use crate::app::util::build_workflow_failure_summary;
 use crate::app::workflow_decisions::{wait_for_workflow_failure_decision, WorkflowFailureDecision};
@@ -220,6 +222,7 @@ pub async fn run_revising_phase(
         LogCategory::Workflow,
         "run_revision_phase_with_context completed",
     );
+    write_backlog_section(view, &session_logger);
 
     // Log sequential review state if present (actual reset is done by RevisionCompleted event)
     if let Some(ReviewMode::Sequential(ref seq_state)) = view.review_mode() {
@@ -268,6 +271,7 @@ pub async fn run_revising_phase(
         .plan_path()
         .cloned()
         .ok_or_else(|| anyhow::anyhow!("plan_path must be set during Revising phase"))?;
+    record_revised_plan_metadata(config, sender, &session_logger, &actor_ref, &plan_path.0).await;
     dispatch_domain_command(
         &actor_ref,
         DomainCommand::RevisionCompleted { plan_path },
@@ -380,6 +384,7 @@ fn convert_reviewer_result(reviewer_result: &ReviewerResult) -> phases::ReviewRe
         needs_revision,
         feedback,
         summary,
+        low_confidence: false,
     }
 }
 
@@ -0,0 +1,150 @@
+//! Automatic retries of a review round in which every reviewer failed.
+//!
+//! A round without a single verdict usually means a provider outage. Instead of
+//! asking the user straight away, the round runs again up to
+//! `failure_policy.max_retries` times: after `failure_policy.backoff_secs`, then
+//! twice as long before each further attempt, capped at
+//! `failure_policy.review_round_retry.max_backoff_secs`. Retried reviewers start
+//! fresh conversations, and `review_round_retry.reviewers` limits how many of the
+//! failed reviewers run again. Each attempt is recorded in the failure history as
+//! an automatically retried `AllReviewersFailed` failure.
+
+use super::reviewing::WorkflowPhaseContext;
+use super::WorkflowResult;
+use crate::config::AgentRef;
+use crate::domain::failure::{FailureContext, FailureKind, FailurePolicy, RecoveryAction};
+use crate::domain::types::{AgentId, PhaseLabel, ResumeStrategy, TimestampUtc};
+use crate::domain::view::WorkflowView;
+use crate::domain::{WorkflowCommand as DomainCommand, WorkflowEvent};
+use crate::phases::{reviewing_conversation_key, ReviewFailure};
+use crate::tui::{SessionEventSender, WorkflowCommand};
+use tokio::sync::mpsc;
+
+/// The reviewers a retry runs: the failed ones, cut to the configured count.
+pub fn retry_reviewers(policy: &FailurePolicy, failed: &[AgentRef]) -> Vec<AgentRef> {
+    let count = policy
+        .review_round_retry()
+        .reviewers
+        .unwrap_or(failed.len())
+        .max(1);
+    failed.iter().take(count).cloned().collect()
+}
+
+/// Records the attempt and waits out its backoff. Returns the workflow result
+/// when the user stops or interrupts the workflow in the meantime.
+pub async fn wait_before_retry(
+    context: &WorkflowPhaseContext<'_>,
+    attempt: u32,
+    failures: &[ReviewFailure],
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Option<WorkflowResult> {
+    let policy = &context.config.failure_policy;
+    let delay = policy.round_retry_delay(attempt);
+    context
+        .dispatch_command(DomainCommand::RecordFailure {
+            failure: FailureContext::new(
+                FailureKind::AllReviewersFailed,
+                PhaseLabel::Reviewing,
+                None,
+                attempt,
+                policy.max_retries(),
+                TimestampUtc::now(),
+                Some(RecoveryAction::AutoRetried),
+            ),
+        })
+        .await;
+    let errors: Vec<String> = failures
+        .iter()
+        .map(|f| format!("{}: {}", f.agent_name, f.error))
+        .collect();
+    context.log_workflow(&format!(
+        "All reviewers failed ({}); retrying the round in {}s, attempt {}/{}",
+        errors.join("; "),
+        delay.as_secs(),
+        attempt,
+        policy.max_retries()
+    ));
+    context.sender.send_output(format!(
+        "[review] All reviewers failed; retrying the round in {}s ({}/{})...",
+        delay.as_secs(),
+        attempt,
+        policy.max_retries()
+    ));
+
+    tokio::select! {
+        _ = tokio::time::sleep(delay) => None,
+        Some(cmd) = control_rx.recv() => match cmd {
+            WorkflowCommand::Interrupt { feedback } => {
+                context.log_workflow(&format!("Received interrupt before review retry: {}", feedback));
+                context.sender.send_output("[review] Interrupted by user".to_string());
+                Some(WorkflowResult::NeedsRestart {
+                    user_feedback: feedback,
+                })
+            }
+            WorkflowCommand::Stop => {
+                context.log_workflow("Received stop before review retry");
+                context.sender.send_output("[review] Stopping...".to_string());
+                Some(WorkflowResult::Stopped)
+            }
+        },
+    }
+}
+
+/// Drops the reviewers' conversations, in the session and in a copy of `view`
+/// the retry runs with, so they review in fresh conversations.
+pub async fn start_fresh_conversations(
+    context: &WorkflowPhaseContext<'_>,
+    view: &WorkflowView,
+    reviewers: &[AgentRef],
+) -> WorkflowView {
+    let mut fresh = view.clone();
+    let aggregate_id = view
+        .workflow_id()
+        .map(|id| id.0.to_string())
+        .unwrap_or_default();
+    for reviewer in reviewers {
+        let agent_id = AgentId::from(reviewing_conversation_key(reviewer.display_id()).as_str());
+        context
+            .dispatch_command(DomainCommand::RecordAgentConversation {
+                agent_id: agent_id.clone(),
+                resume_strategy: ResumeStrategy::ConversationResume,
+                conversation_id: None,
+            })
+            .await;
+        fresh.apply_event(
+            &aggregate_id,
+            &WorkflowEvent::AgentConversationRecorded {
+                agent_id,
+                resume_strategy: ResumeStrategy::ConversationResume,
+                conversation_id: None,
+                updated_at: TimestampUtc::now(),
+            },
+            view.last_event_sequence(),
+        );
+    }
+    fresh
+}
+
+/// Outputs diagnostics bundle paths for failures that have them.
+pub fn output_failure_bundles(sender: &SessionEventSender, failures: &[ReviewFailure]) {
+    let mut has_bundles = false;
+    for failure in failures {
+        if let Some(ref path) = failure.bundle_path {
+            sender.send_output(format!(
+                "[diagnostics] {}: {}",
+                failure.agent_name,
+                path.display()
+            ));
+            has_bundles = true;
+        }
+    }
+    if has_bundles {
+        sender.send_output(
+            "[warning] Bundles may contain sensitive information from logs.".to_string(),
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/round_retry_tests.rs"]
+mod tests;
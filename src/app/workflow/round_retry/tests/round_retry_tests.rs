@@ -0,0 +1,47 @@
+use super::*;
+use std::time::Duration;
+
+fn policy(yaml: &str) -> FailurePolicy {
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+#[test]
+fn test_round_retry_delay_doubles_up_to_the_cap() {
+    let policy = policy("backoff_secs: 30\nreview_round_retry:\n  max_backoff_secs: 100\n");
+    let delays: Vec<Duration> = (1..=4).map(|n| policy.round_retry_delay(n)).collect();
+    assert_eq!(delays, [30, 60, 100, 100].map(Duration::from_secs).to_vec());
+
+    // Defaults: 5s base, 300s cap
+    let policy = FailurePolicy::default();
+    assert_eq!(policy.round_retry_delay(1), Duration::from_secs(5));
+    assert_eq!(policy.round_retry_delay(40), Duration::from_secs(300));
+}
+
+#[test]
+fn test_retry_reviewers_keeps_the_configured_count() {
+    let failed = vec![
+        AgentRef::Simple("claude".to_string()),
+        AgentRef::Simple("codex".to_string()),
+        AgentRef::Simple("gemini".to_string()),
+    ];
+    let ids = |reviewers: Vec<AgentRef>| -> Vec<String> {
+        reviewers
+            .iter()
+            .map(|r| r.display_id().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        ids(retry_reviewers(&FailurePolicy::default(), &failed)),
+        ["claude", "codex", "gemini"]
+    );
+    let policy = policy("review_round_retry:\n  reviewers: 1\n");
+    assert_eq!(ids(retry_reviewers(&policy, &failed)), ["claude"]);
+}
+
+#[test]
+fn test_zero_retry_reviewers_is_rejected() {
+    assert!(policy("review_round_retry:\n  reviewers: 0\n")
+        .validate()
+        .is_err());
+}
@@ -0,0 +1,338 @@
+//! Targeted re-review of the plan sections a reviewer flagged.
+//!
+//! With `reviewing.targeted_rereview`, a rejecting reviewer lists the plan headings
+//! its concerns apply to under `## Flagged Sections`. The plan it rejected is kept
+//! with those headings; after the revision, the two plan versions are compared
+//! section by section and the reviewer is asked to re-review only the flagged
+//! sections that changed. Reviewers that approved, flagged nothing, or whose flagged
+//! sections were left untouched review the full plan as before.
+//!
+//! Independently of that setting, every review round after a rejection starts with
+//! a brief of what changed: the plan diff in lines and sections, and each feedback
+//! item of the rejected round marked by whether the added lines appear to address
+//! it. The match is a keyword heuristic; the brief says so to the reviewers.
+
+use super::reviewing::WorkflowPhaseContext;
+use crate::config::WorkflowConfig;
+use crate::domain::review::FeedbackItem;
+use crate::domain::view::WorkflowView;
+use crate::phases::feedback_items::{item_source, parse_feedback_items};
+use crate::phases::{extract_flagged_sections, ReviewResult, ReviewTargets};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Longest feedback item excerpt quoted in the brief.
+const BRIEF_ITEM_CHARS: usize = 160;
+
+/// Words too common in plans and feedback to show that an item was addressed.
+const BRIEF_STOPWORDS: [&str; 12] = [
+    "about", "after", "before", "could", "every", "should", "their", "there", "these", "which",
+    "while", "would",
+];
+
+/// Normalizes a heading for matching: no `#` markers or emphasis, lowercase.
+fn normalize_heading(heading: &str) -> String {
+    heading
+        .trim()
+        .trim_matches(|c| c == '`' || c == '*' || c == '"')
+        .trim_start_matches('#')
+        .trim()
+        .to_lowercase()
+}
+
+/// Heading level and text of a markdown heading line.
+pub(super) fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 {
+        return None;
+    }
+    let text = line.split_at(level).1;
+    text.starts_with(' ').then_some((level, text.trim()))
+}
+
+/// Maps each normalized heading to its section content, including nested
+/// subsections. Repeated headings share one entry.
+pub fn plan_sections(plan: &str) -> BTreeMap<String, String> {
+    let lines: Vec<&str> = plan.lines().collect();
+    let mut headings: Vec<(usize, usize, &str)> = Vec::new();
+    let mut in_code_block = false;
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            if let Some((level, text)) = heading(line) {
+                headings.push((index, level, text));
+            }
+        }
+    }
+
+    let mut sections: BTreeMap<String, String> = BTreeMap::new();
+    for (i, &(start, level, text)) in headings.iter().enumerate() {
+        let end = headings
+            .iter()
+            .skip(i + 1)
+            .find(|(_, next_level, _)| *next_level <= level)
+            .map_or(lines.len(), |(index, _, _)| *index);
+        let body = lines
+            .iter()
+            .take(end)
+            .skip(start + 1)
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let entry = sections.entry(normalize_heading(text)).or_default();
+        entry.push_str(body.trim());
+        entry.push('\n');
+    }
+    sections
+}
+
+/// Normalized headings whose content differs between the two plan versions,
+/// including sections that were added or removed.
+pub fn changed_sections(old_plan: &str, new_plan: &str) -> Vec<String> {
+    let old = plan_sections(old_plan);
+    let new = plan_sections(new_plan);
+    let mut changed: Vec<String> = old
+        .iter()
+        .filter(|(heading, body)| new.get(*heading) != Some(*body))
+        .map(|(heading, _)| heading.clone())
+        .collect();
+    changed.extend(new.keys().filter(|h| !old.contains_key(*h)).cloned());
+    changed
+}
+
+/// A rejection's flagged headings and the plan content it rejected.
+#[derive(Debug)]
+struct FlaggedRejection {
+    plan: String,
+    sections: Vec<String>,
+}
+
+/// The plan a rejected round reviewed and the feedback items it raised.
+#[derive(Debug)]
+struct RejectedRound {
+    plan: String,
+    items: Vec<FeedbackItem>,
+}
+
+/// Flagged sections of each reviewer's latest rejection, keyed by display ID, and
+/// the latest rejected round for the change brief.
+#[derive(Debug, Default)]
+pub struct SectionOwners {
+    rejections: HashMap<String, FlaggedRejection>,
+    last_rejected_round: Option<RejectedRound>,
+}
+
+impl SectionOwners {
+    /// Records a review of `plan`. A rejection that flags sections is kept for
+    /// targeting; anything else clears the reviewer's entry.
+    pub fn record(&mut self, plan: &str, review: &ReviewResult) {
+        let sections = extract_flagged_sections(&review.feedback);
+        if review.needs_revision && !sections.is_empty() {
+            self.rejections.insert(
+                review.agent_name.clone(),
+                FlaggedRejection {
+                    plan: plan.to_string(),
+                    sections,
+                },
+            );
+        } else {
+            self.rejections.remove(&review.agent_name);
+        }
+    }
+
+    /// The reviewer's flagged sections that changed since its rejection, or None
+    /// when it should review the full plan.
+    pub fn targets(&self, reviewer_id: &str, plan: &str) -> Option<Vec<String>> {
+        let rejection = self.rejections.get(reviewer_id)?;
+        let changed = changed_sections(&rejection.plan, plan);
+        let targets: Vec<String> = rejection
+            .sections
+            .iter()
+            .filter(|section| changed.contains(&normalize_heading(section)))
+            .cloned()
+            .collect();
+        (!targets.is_empty()).then_some(targets)
+    }
+
+    /// Records a finished round of reviews of `plan`. A round without rejections
+    /// clears the brief, since nothing is left to check off.
+    pub fn record_round(&mut self, plan: &str, reviews: &[ReviewResult]) {
+        let items = parse_feedback_items(reviews);
+        self.last_rejected_round = (!items.is_empty()).then(|| RejectedRound {
+            plan: plan.to_string(),
+            items,
+        });
+    }
+
+    /// Brief of the changes since the last rejected round, or None when no round
+    /// was rejected yet.
+    pub fn change_brief(&self, plan: &str) -> Option<String> {
+        let round = self.last_rejected_round.as_ref()?;
+        Some(build_change_brief(&round.plan, plan, &round.items))
+    }
+}
+
+/// Words of a feedback item that would likely appear in a plan change addressing it.
+fn keywords(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|word| word.chars().count() >= 5)
+        .map(str::to_lowercase)
+        .filter(|word| !BRIEF_STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Whether the added plan lines mention at least a third of the item's keywords.
+fn appears_addressed(item: &FeedbackItem, added: &str) -> bool {
+    let words = keywords(&item.text);
+    let matched = words.iter().filter(|w| added.contains(w.as_str())).count();
+    matched > 0 && matched * 3 >= words.len()
+}
+
+/// Non-blank lines of `new` that `old` does not have (counting repeats), and how
+/// many of `old`'s lines `new` no longer has.
+fn line_changes<'a>(old: &str, new: &'a str) -> (Vec<&'a str>, usize) {
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for line in old.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        *remaining.entry(line).or_default() += 1;
+    }
+    let mut added = Vec::new();
+    for line in new.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match remaining.get_mut(line) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => added.push(line),
+        }
+    }
+    (added, remaining.values().sum())
+}
+
+/// The change brief prepended to review prompts after a rejected round.
+fn build_change_brief(old_plan: &str, new_plan: &str, items: &[FeedbackItem]) -> String {
+    let (added, removed) = line_changes(old_plan, new_plan);
+    let added_text = added.join("\n").to_lowercase();
+    let sections = changed_sections(old_plan, new_plan);
+
+    let mut brief =
+        String::from("###################### CHANGES SINCE LAST REVIEW ######################\n");
+    brief.push_str(&format!(
+        "Plan diff: {} line(s) added, {} removed",
+        added.len(),
+        removed
+    ));
+    if sections.is_empty() {
+        brief.push_str("; no section changed\n");
+    } else {
+        brief.push_str(&format!("; changed sections: {}\n", sections.join(", ")));
+    }
+
+    brief.push_str("\nFeedback from the last round:\n");
+    for item in items {
+        let status = if appears_addressed(item, &added_text) {
+            "appears addressed"
+        } else {
+            "not evidently addressed"
+        };
+        let text = item.text.lines().next().unwrap_or_default();
+        let excerpt: String = text.chars().take(BRIEF_ITEM_CHARS).collect();
+        let ellipsis = if excerpt.len() < text.len() {
+            "..."
+        } else {
+            ""
+        };
+        brief.push_str(&format!(
+            "- [{}] {}: {}{}\n",
+            status,
+            item_source(item),
+            excerpt,
+            ellipsis
+        ));
+    }
+    brief.push_str(
+        "\nThis brief is generated by keyword matching, not by a reviewer. Use it to focus \
+         your review and avoid repeating resolved feedback, but verify every item against \
+         the plan file.\n",
+    );
+    brief.push_str("#######################################################################");
+    brief
+}
+
+/// The plan contents a review round is targeted at and recorded against, read
+/// when the round starts.
+pub struct SectionRound {
+    /// The plan flagged sections are matched in, None when targeted re-review is off
+    reviewed_plan: Option<String>,
+    /// The plan the change brief compares with the last rejected round
+    current_plan: Option<String>,
+}
+
+impl SectionRound {
+    pub fn start(view: &WorkflowView, config: &WorkflowConfig) -> Self {
+        Self {
+            reviewed_plan: reviewed_plan_content(view, config),
+            current_plan: current_plan_content(view),
+        }
+    }
+
+    /// What `reviewer_ids` re-review: reviewers whose flagged sections changed
+    /// review only those, and after a rejected round everyone gets the brief.
+    pub fn targets(
+        &self,
+        owners: &SectionOwners,
+        reviewer_ids: &[&str],
+        context: &WorkflowPhaseContext<'_>,
+    ) -> ReviewTargets {
+        let sections: HashMap<String, Vec<String>> = self
+            .reviewed_plan
+            .as_deref()
+            .map(|plan| {
+                reviewer_ids
+                    .iter()
+                    .filter_map(|id| Some((id.to_string(), owners.targets(id, plan)?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for (reviewer_id, targets) in &sections {
+            context.log_workflow(&format!(
+                "Targeted re-review for {}: {:?}",
+                reviewer_id, targets
+            ));
+        }
+        ReviewTargets {
+            sections,
+            change_brief: self
+                .current_plan
+                .as_deref()
+                .and_then(|plan| owners.change_brief(plan)),
+        }
+    }
+
+    /// Records a reviewer's verdict for targeting its next re-review.
+    pub fn record_review(&self, owners: &mut SectionOwners, review: &ReviewResult) {
+        if let Some(ref plan) = self.reviewed_plan {
+            owners.record(plan, review);
+        }
+    }
+
+    /// Records the round's reviews for the next round's change brief.
+    pub fn record_round(&self, owners: &mut SectionOwners, reviews: &[ReviewResult]) {
+        if let Some(ref plan) = self.current_plan {
+            owners.record_round(plan, reviews);
+        }
+    }
+}
+
+/// Content of the plan under review.
+pub fn current_plan_content(view: &WorkflowView) -> Option<String> {
+    std::fs::read_to_string(view.plan_path()?.as_path()).ok()
+}
+
+/// Content of the plan under review, or None when targeted re-review is off.
+fn reviewed_plan_content(view: &WorkflowView, config: &WorkflowConfig) -> Option<String> {
+    if !config.workflow.reviewing.targeted_rereview {
+        return None;
+    }
+    current_plan_content(view)
+}
+
+#[cfg(test)]
+#[path = "tests/section_review_tests.rs"]
+mod tests;
@@ -0,0 +1,514 @@
+//! Pipelined plan review (`reviewing.pipelined`).
+//!
+//! The planner is asked to write the plan one top-level section at a time. While it
+//! runs, the plan file is read again after each of the planner's tool calls; a
+//! section is finished once the next one starts, and the last one once planning
+//! succeeds. Each reviewer then reviews every finished section on its own, while
+//! later sections are still being written. Both steps are recorded as
+//! `PlanSectionCompleted` and `PlanSectionReviewed` events, and a section rewritten
+//! after it was finished is reviewed again.
+//!
+//! The review phase combines a reviewer's section verdicts from the view into one
+//! review of the plan, which needs revision when any section does and flags the
+//! rejected sections. A reviewer whose verdicts do not cover the final plan,
+//! because a section review failed or a section changed after its review, reviews
+//! the full plan as usual. The verdicts are events, so a resumed session keeps
+//! them and does not review the same section text again.
+
+use super::reviewing::WorkflowPhaseContext;
+use super::section_review::heading;
+use super::WorkflowResult;
+use crate::agents::{AgentContext, AgentType};
+use crate::config::AgentRef;
+use crate::domain::types::{AgentId, PlanSectionReview, ResumeStrategy};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::review_prompts::{
+    build_section_review_prompt_for_agent, DEFAULT_REVIEW_SKILL, REVIEW_SYSTEM_PROMPT,
+};
+use crate::phases::review_schema::FLAGGED_SECTIONS_HEADING;
+use crate::phases::{parse_review_feedback, ReviewResult};
+use crate::planning_paths;
+use crate::prompt_format::language_instruction;
+use crate::tui::{ReviewKind, SessionEventSender, WorkflowCommand};
+use anyhow::{Context, Result};
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use tokio::sync::{mpsc, oneshot, watch};
+
+/// A top-level plan section.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlanSection {
+    pub heading: String,
+    pub body: String,
+}
+
+/// Combined reviews of `plan` for each of `reviewer_ids` whose section verdicts
+/// cover every section as it now reads, using the latest verdict per section.
+pub fn covering_reviews(
+    plan: &str,
+    section_reviews: &[PlanSectionReview],
+    reviewer_ids: &[&str],
+) -> Vec<ReviewResult> {
+    let sections = finished_sections(plan, true);
+    if sections.is_empty() {
+        return Vec::new();
+    }
+    reviewer_ids
+        .iter()
+        .filter_map(|reviewer_id| {
+            let covering = sections
+                .iter()
+                .map(|section| {
+                    section_reviews
+                        .iter()
+                        .rev()
+                        .find(|v| v.reviewer_id.0 == *reviewer_id && covers(v, section))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(combine_section_reviews(reviewer_id, &covering))
+        })
+        .collect()
+}
+
+/// Whether `review` is a verdict on `section` as it now reads.
+fn covers(review: &PlanSectionReview, section: &PlanSection) -> bool {
+    review.section == section.heading && review.body == section.body
+}
+
+/// Top-level sections of `plan` that are finished: every one followed by another
+/// section, and with `planning_done` the last one too. A single heading above all
+/// others is the plan's title, and the level below it holds the sections.
+pub fn finished_sections(plan: &str, planning_done: bool) -> Vec<PlanSection> {
+    let lines: Vec<&str> = plan.lines().collect();
+    let mut headings: Vec<(usize, usize, &str)> = Vec::new();
+    let mut in_code_block = false;
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            if let Some((level, text)) = heading(line) {
+                headings.push((index, level, text));
+            }
+        }
+    }
+
+    let Some(top) = headings.iter().map(|(_, level, _)| *level).min() else {
+        return Vec::new();
+    };
+    let section_level = if headings.iter().filter(|(_, l, _)| *l == top).count() == 1 {
+        match headings
+            .iter()
+            .map(|(_, l, _)| *l)
+            .filter(|l| *l > top)
+            .min()
+        {
+            Some(level) => level,
+            None => return Vec::new(),
+        }
+    } else {
+        top
+    };
+
+    let mut sections = Vec::new();
+    for (i, &(start, level, text)) in headings.iter().enumerate() {
+        if level != section_level {
+            continue;
+        }
+        let end = headings
+            .iter()
+            .skip(i + 1)
+            .find(|(_, next_level, _)| *next_level <= section_level)
+            .map(|(index, _, _)| *index);
+        if end.is_none() && !planning_done {
+            continue;
+        }
+        let body = lines
+            .iter()
+            .take(end.unwrap_or(lines.len()))
+            .skip(start + 1)
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        sections.push(PlanSection {
+            heading: text.to_string(),
+            body: body.trim().to_string(),
+        });
+    }
+    sections
+}
+
+/// One review of the plan from a reviewer's verdicts on its sections, in plan order.
+pub fn combine_section_reviews(reviewer_id: &str, verdicts: &[&PlanSectionReview]) -> ReviewResult {
+    let rejected: Vec<&PlanSectionReview> =
+        verdicts.iter().copied().filter(|v| !v.approved).collect();
+
+    let mut feedback = String::new();
+    for verdict in verdicts {
+        let outcome = if verdict.approved {
+            "APPROVED"
+        } else {
+            "NEEDS REVISION"
+        };
+        feedback.push_str(&format!(
+            "## Section: {} ({})\n\n{}\n\n",
+            verdict.section,
+            outcome,
+            verdict.feedback.trim()
+        ));
+    }
+    if !rejected.is_empty() {
+        feedback.push_str(&format!("{}\n\n", FLAGGED_SECTIONS_HEADING));
+        for verdict in &rejected {
+            feedback.push_str(&format!("- {}\n", verdict.section));
+        }
+    }
+
+    let summary = match rejected.first() {
+        None => format!("All {} sections approved", verdicts.len()),
+        Some(first) => format!(
+            "{} of {} sections need revision; {}: {}",
+            rejected.len(),
+            verdicts.len(),
+            first.section,
+            first.summary
+        ),
+    };
+    ReviewResult {
+        agent_name: reviewer_id.to_string(),
+        needs_revision: !rejected.is_empty(),
+        feedback: feedback.trim_end().to_string(),
+        summary,
+        low_confidence: !rejected.is_empty() && rejected.iter().all(|v| v.low_confidence),
+    }
+}
+
+/// Runs `planning` with the session's sender, and with `reviewing.pipelined`
+/// reviews each plan section as the planner finishes it. A stop or interrupt while
+/// the last reviews finish cancels them and is returned as the workflow's result.
+pub async fn plan_with_section_reviews<Fut>(
+    planning: impl FnOnce(SessionEventSender) -> Fut,
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Result<Option<WorkflowResult>>
+where
+    Fut: Future<Output = Result<()>>,
+{
+    let reviewing = &context.config.workflow.reviewing;
+    if !reviewing.pipelined || reviewing.sequential {
+        return planning(context.sender.clone()).await.map(|()| None);
+    }
+    let (tool_tx, tool_rx) = mpsc::unbounded_channel();
+    let (done_tx, done_rx) = oneshot::channel();
+    let planning = planning(context.sender.clone().with_tool_watcher(tool_tx));
+    let planning = async {
+        let result = planning.await;
+        // Tells the streamer whether the last section is finished too
+        let _ = done_tx.send(result.is_ok());
+        result
+    };
+    let (result, interrupted) = tokio::join!(
+        planning,
+        stream_section_reviews(view, context, tool_rx, done_rx, control_rx)
+    );
+    result.map(|()| interrupted)
+}
+
+async fn stream_section_reviews(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    mut tool_rx: mpsc::UnboundedReceiver<()>,
+    mut done_rx: oneshot::Receiver<bool>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Option<WorkflowResult> {
+    let plan_path = view.plan_path().map(|p| p.0.clone())?;
+    let reviewers = context.config.workflow.reviewing.enabled_agents();
+    // A plan left from an earlier run is not the one being written
+    let initial = std::fs::read_to_string(&plan_path).unwrap_or_default();
+    // Sections every reviewer already reviewed as they read, e.g. before a resume
+    let mut reviewed: HashSet<PlanSection> = finished_sections(&initial, true)
+        .into_iter()
+        .filter(|section| {
+            reviewers.iter().all(|reviewer| {
+                view.section_reviews().iter().any(|review| {
+                    review.reviewer_id.0 == reviewer.display_id() && covers(review, section)
+                })
+            })
+        })
+        .collect();
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    let mut reviews = FuturesUnordered::new();
+    let mut planning_done = false;
+
+    loop {
+        let plan = std::fs::read_to_string(&plan_path).unwrap_or_default();
+        if planning_done || plan != initial {
+            for section in finished_sections(&plan, planning_done) {
+                if !reviewed.insert(section.clone()) {
+                    continue;
+                }
+                context.sender.send_output(format!(
+                    "[review] Section finished: {} - reviewing while planning continues",
+                    section.heading
+                ));
+                context
+                    .dispatch_command(DomainCommand::RecordPlanSectionCompleted {
+                        section: section.heading.clone(),
+                    })
+                    .await;
+                for reviewer in &reviewers {
+                    reviews.push(review_section(
+                        view,
+                        context,
+                        reviewer,
+                        section.clone(),
+                        reviewed.len(),
+                        cancel_rx.clone(),
+                    ));
+                }
+            }
+        }
+        if planning_done {
+            break;
+        }
+        tokio::select! {
+            done = &mut done_rx => {
+                if !matches!(done, Ok(true)) {
+                    // Reviews of a failed run are cancelled
+                    cancel_reviews(&cancel_tx, &mut reviews).await;
+                    return None;
+                }
+                planning_done = true;
+            }
+            Some(()) = reviews.next(), if !reviews.is_empty() => {}
+            // The plan file only changes through the planner's tool calls
+            Some(()) = tool_rx.recv() => {}
+        }
+    }
+
+    while !reviews.is_empty() {
+        tokio::select! {
+            _ = reviews.next() => {}
+            Some(cmd) = control_rx.recv() => {
+                cancel_reviews(&cancel_tx, &mut reviews).await;
+                return Some(match cmd {
+                    WorkflowCommand::Interrupt { feedback } => {
+                        context.log_workflow(&format!(
+                            "Received interrupt during section reviews: {}",
+                            feedback
+                        ));
+                        context.sender.send_output("[planning] Interrupted by user".to_string());
+                        WorkflowResult::NeedsRestart {
+                            user_feedback: feedback,
+                        }
+                    }
+                    WorkflowCommand::Stop => {
+                        context.log_workflow("Received stop during section reviews");
+                        context.sender.send_output("[planning] Stopping...".to_string());
+                        WorkflowResult::Stopped
+                    }
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Signals the section reviewers to stop and waits for them to wind down.
+async fn cancel_reviews<F: Future<Output = ()>>(
+    cancel_tx: &watch::Sender<bool>,
+    reviews: &mut FuturesUnordered<F>,
+) {
+    let _ = cancel_tx.send(true);
+    while reviews.next().await.is_some() {}
+}
+
+/// Reviews one finished section and records the verdict. A failure is reported
+/// and leaves the reviewer to review the full plan in the review phase; a
+/// cancelled review is dropped quietly.
+async fn review_section(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    reviewer: &AgentRef,
+    section: PlanSection,
+    number: usize,
+    cancel_rx: watch::Receiver<bool>,
+) {
+    let reviewer_id = reviewer.display_id();
+    let result = run_section_review(view, context, reviewer, &section, number, &cancel_rx).await;
+    if *cancel_rx.borrow() {
+        return;
+    }
+    match result {
+        Ok(review) => {
+            context.sender.send_output(format!(
+                "[review:{}] Section {}: {}",
+                reviewer_id,
+                section.heading,
+                if review.needs_revision {
+                    "NEEDS REVISION"
+                } else {
+                    "APPROVED"
+                }
+            ));
+            context
+                .dispatch_command(DomainCommand::RecordPlanSectionReview {
+                    review: PlanSectionReview {
+                        section: section.heading,
+                        body: section.body,
+                        reviewer_id: AgentId::from(reviewer_id),
+                        approved: !review.needs_revision,
+                        feedback: review.feedback,
+                        summary: review.summary,
+                        low_confidence: review.low_confidence,
+                    },
+                })
+                .await;
+        }
+        Err(e) => {
+            context.sender.send_output(format!(
+                "[review:{}] Section {} review failed: {:#} - reviewing the full plan later",
+                reviewer_id, section.heading, e
+            ));
+        }
+    }
+}
+
+async fn run_section_review(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    reviewer: &AgentRef,
+    section: &PlanSection,
+    number: usize,
+    cancel_rx: &watch::Receiver<bool>,
+) -> Result<ReviewResult> {
+    let config = context.config;
+    let reviewer_id = reviewer.display_id();
+    let agent_name = reviewer.agent_name();
+    let agent_config = config
+        .get_agent(agent_name)
+        .ok_or_else(|| anyhow::anyhow!("Review agent '{}' not found in config", agent_name))?;
+    let agent =
+        AgentType::from_config(agent_name, agent_config, context.working_dir.to_path_buf())?;
+
+    let session_id = view
+        .workflow_id()
+        .map(|id| id.0.to_string())
+        .unwrap_or_default();
+    let session_folder = planning_paths::session_dir(&session_id)?;
+    let iteration = view.iteration().unwrap_or_default().0;
+    let feedback_path = session_folder.join(format!(
+        "feedback_{}_{}_section_{}.md",
+        iteration, reviewer_id, number
+    ));
+    let plan_path = view
+        .plan_path()
+        .map(|p| p.0.clone())
+        .context("No plan file")?;
+    let prompt = build_section_review_prompt_for_agent(
+        view.objective().map(|o| o.0.as_str()).unwrap_or(""),
+        &plan_path,
+        &feedback_path,
+        context.working_dir,
+        &session_folder,
+        &section.heading,
+        Some(reviewer.skill().unwrap_or(DEFAULT_REVIEW_SKILL)),
+    );
+    let prompt = match &config.language {
+        Some(language) => format!("{}\n\n{}", language_instruction(language), prompt),
+        None => prompt,
+    };
+
+    let agent_context = AgentContext {
+        session_sender: context.sender.clone(),
+        phase: format!("Section review #{}", number),
+        conversation_id: None,
+        resume_strategy: ResumeStrategy::Stateless,
+        cancel_rx: Some(cancel_rx.clone()),
+        session_logger: context.session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
+    };
+    agent
+        .execute_streaming_with_context(
+            prompt,
+            Some(REVIEW_SYSTEM_PROMPT.to_string()),
+            None,
+            agent_context,
+        )
+        .await?;
+
+    let content = std::fs::read_to_string(&feedback_path)
+        .with_context(|| format!("No feedback file at {}", feedback_path.display()))?;
+    let review = parse_review_feedback(
+        &content,
+        config.workflow.reviewing.require_plan_feedback_tags,
+    )
+    .map_err(|failure| anyhow::anyhow!(failure.error))?;
+    let threshold = config.workflow.reviewing.confidence.threshold;
+    Ok(ReviewResult {
+        agent_name: reviewer_id.to_string(),
+        needs_revision: review.needs_revision(),
+        feedback: review.feedback_content(),
+        summary: review.summary.clone(),
+        low_confidence: review.is_low_confidence_rejection(threshold),
+    })
+}
+
+/// Moves the `pending` reviewers whose section verdicts in `view` cover the plan
+/// under review to `reviews_by_agent`, with their combined reviews reported like
+/// finished ones. Starts the round in the UI first unless `round_started`, and
+/// returns whether the round has started.
+pub fn take_streamed_reviews(
+    context: &WorkflowPhaseContext<'_>,
+    view: &WorkflowView,
+    pending: &mut Vec<AgentRef>,
+    reviews_by_agent: &mut HashMap<String, ReviewResult>,
+    round_started: bool,
+) -> bool {
+    if view.section_reviews().is_empty() {
+        return round_started;
+    }
+    let plan = super::section_review::current_plan_content(view).unwrap_or_default();
+    let reviewer_ids: Vec<&str> = pending.iter().map(|r| r.display_id()).collect();
+    let reviews = covering_reviews(&plan, view.section_reviews(), &reviewer_ids);
+    if reviews.is_empty() {
+        return round_started;
+    }
+    let iteration = view.iteration().unwrap_or_default().0;
+    let sender = context.sender;
+    if !round_started {
+        sender.send_review_round_started(ReviewKind::Plan, iteration);
+    }
+    for review in reviews {
+        context.log_workflow(&format!(
+            "Using section reviews of {}: {}",
+            review.agent_name, review.summary
+        ));
+        sender.send_output(format!(
+            "[review] {}: reviewed every section while the plan was written - combining verdicts",
+            review.agent_name
+        ));
+        sender.send_reviewer_started(ReviewKind::Plan, iteration, review.agent_name.clone());
+        sender.send_reviewer_completed(
+            ReviewKind::Plan,
+            iteration,
+            review.agent_name.clone(),
+            !review.needs_revision,
+            format!("Section reviews: {}", review.summary),
+            0,
+        );
+        pending.retain(|r| r.display_id() != review.agent_name);
+        reviews_by_agent.insert(review.agent_name.clone(), review);
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "tests/section_stream_tests.rs"]
+mod tests;
@@ -0,0 +1,111 @@
+use super::*;
+
+const PLAN: &str = "# Plan: Rate limiting
+
+## Overview
+
+Add a token bucket per API key.
+
+## Steps
+
+```markdown
+## Not a section
+```
+
+1. Add the limiter
+
+## Testing
+
+Unit tests for the bucket.
+";
+
+fn verdict(reviewer: &str, section: &PlanSection, approved: bool) -> PlanSectionReview {
+    PlanSectionReview {
+        section: section.heading.clone(),
+        body: section.body.clone(),
+        reviewer_id: AgentId::from(reviewer),
+        approved,
+        feedback: format!("Feedback on {}", section.heading),
+        summary: format!("{} summary", section.heading),
+        low_confidence: false,
+    }
+}
+
+#[test]
+fn test_finished_sections_wait_for_the_next_section() {
+    let headings = |sections: Vec<PlanSection>| -> Vec<String> {
+        sections.into_iter().map(|s| s.heading).collect()
+    };
+    assert_eq!(
+        headings(finished_sections(PLAN, false)),
+        vec!["Overview", "Steps"]
+    );
+    assert_eq!(
+        headings(finished_sections(PLAN, true)),
+        vec!["Overview", "Steps", "Testing"]
+    );
+
+    let sections = finished_sections(PLAN, true);
+    assert_eq!(sections[0].body, "Add a token bucket per API key.");
+    assert!(sections[1].body.contains("## Not a section"));
+    assert!(finished_sections("# Plan\n\nNo sections yet.", true).is_empty());
+}
+
+#[test]
+fn test_covering_reviews_combine_verdicts_on_the_plan_as_it_reads() {
+    let sections = finished_sections(PLAN, true);
+    let mut section_reviews = Vec::new();
+    for (i, section) in sections.iter().enumerate() {
+        section_reviews.push(verdict("claude", section, i != 1));
+        // codex reviewed an older version of the last section
+        if i < 2 {
+            section_reviews.push(verdict("codex", section, true));
+        }
+    }
+    let stale = PlanSection {
+        heading: "Testing".to_string(),
+        body: "TBD".to_string(),
+    };
+    section_reviews.push(verdict("codex", &stale, true));
+
+    let reviews = covering_reviews(PLAN, &section_reviews, &["claude", "codex"]);
+    assert_eq!(reviews.len(), 1);
+    let review = &reviews[0];
+    assert_eq!(review.agent_name, "claude");
+    assert!(review.needs_revision);
+    assert!(!review.low_confidence);
+    assert_eq!(
+        review.summary,
+        "1 of 3 sections need revision; Steps: Steps summary"
+    );
+    assert!(review.feedback.contains("## Section: Overview (APPROVED)"));
+    assert!(review
+        .feedback
+        .contains("## Section: Steps (NEEDS REVISION)"));
+    assert!(review
+        .feedback
+        .ends_with(&format!("{}\n\n- Steps", FLAGGED_SECTIONS_HEADING)));
+
+    // The latest verdict on a section counts
+    section_reviews.push(verdict("claude", &sections[1], true));
+    let reviews = covering_reviews(PLAN, &section_reviews, &["claude"]);
+    assert!(!reviews[0].needs_revision);
+    assert!(
+        covering_reviews("# Plan\n\nNo sections yet.", &section_reviews, &["claude"]).is_empty()
+    );
+}
+
+#[test]
+fn test_combined_review_approves_when_every_section_does() {
+    let sections = finished_sections(PLAN, true);
+    let verdicts: Vec<PlanSectionReview> = sections
+        .iter()
+        .map(|section| verdict("claude", section, true))
+        .collect();
+    let refs: Vec<&PlanSectionReview> = verdicts.iter().collect();
+
+    let review = combine_section_reviews("claude", &refs);
+    assert!(!review.needs_revision);
+    assert_eq!(review.summary, "All 3 sections approved");
+    assert!(!review.feedback.contains(FLAGGED_SECTIONS_HEADING));
+}
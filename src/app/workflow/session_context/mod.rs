@@ -0,0 +1,75 @@
+//! Context a new session starts with.
+//!
+//! The imported issue and the output language are recorded in session_info.json,
+//! and the issue, failing CI logs (`--attach-ci-run`) and package boundaries
+//! (`--package`) are saved in the session dir, where the prompts pick them up.
+
+use super::updates::dispatch_domain_command;
+use crate::config::WorkflowConfig;
+use crate::domain::actor::WorkflowMessage;
+use crate::domain::input::NewWorkflowInput;
+use crate::domain::types::Phase;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::planning_paths;
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
+use anyhow::Result;
+use ractor::ActorRef;
+use std::path::Path;
+
+/// Records and saves the context of a new session.
+pub async fn save_session_context(
+    new_input: &NewWorkflowInput,
+    session_id: &str,
+    working_dir: &Path,
+    config: &WorkflowConfig,
+    actor: &Option<ActorRef<WorkflowMessage>>,
+    session_logger: &SessionLogger,
+) -> Result<()> {
+    // Record the imported issue and the output language in session_info.json
+    let mut session_info = planning_paths::SessionInfo::new(
+        session_id,
+        new_input.feature_name.as_str(),
+        new_input.objective.as_str(),
+        working_dir,
+        Phase::Planning,
+        1,
+    );
+    session_info.issue = new_input.issue.as_ref().map(|issue| issue.link.clone());
+    session_info.language = config.language.clone();
+    if let Err(e) = session_info.save(session_id) {
+        session_logger.log(
+            LogLevel::Warn,
+            LogCategory::Workflow,
+            &format!("Failed to save session_info.json: {}", e),
+        );
+    }
+    if let Some(ref issue) = new_input.issue {
+        let cmd = DomainCommand::LinkIssue {
+            issue: issue.link.clone(),
+        };
+        dispatch_domain_command(actor, cmd, session_logger).await;
+    }
+
+    // Save the issue, failing CI logs and package boundaries where the prompts pick them up
+    let issue_path = planning_paths::session_issue_context_path(session_id)?;
+    let ci_logs_path = planning_paths::session_ci_logs_path(session_id)?;
+    let package_path = planning_paths::session_package_path(session_id)?;
+    for (content, path) in [
+        (
+            new_input.issue.as_ref().map(|issue| &issue.context),
+            issue_path,
+        ),
+        (new_input.ci_context.as_ref(), ci_logs_path),
+        (new_input.package_context.as_ref(), package_path),
+    ] {
+        let Some(content) = content else { continue };
+        if let Err(e) = std::fs::write(&path, content) {
+            session_logger.log(
+                LogLevel::Warn,
+                LogCategory::Workflow,
+                &format!("Failed to write {}: {}", path.display(), e),
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use super::*;
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkingDir,
+};
+use crate::domain::WorkflowEvent;
+use tempfile::tempdir;
+
+const AGGREGATE_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+fn accepted_view(plan: &Path) -> WorkflowView {
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        AGGREGATE_ID,
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from("add-caching"),
+            objective: Objective::from("objective"),
+            working_dir: WorkingDir(plan.parent().unwrap().to_path_buf()),
+            max_iterations: MaxIterations(3),
+            plan_path: PlanPath(plan.to_path_buf()),
+            feedback_path: FeedbackPath::from(plan.with_extension("feedback.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+    view
+}
+
+#[tokio::test]
+async fn test_actions_run_in_order_and_failures_do_not_stop_the_pipeline() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    std::fs::write(&plan, "# Plan\n").unwrap();
+    let view = accepted_view(&plan);
+    let target = CompletionTarget {
+        view: &view,
+        working_dir: dir.path(),
+        session_id: "session-1",
+    };
+    let actions = vec![
+        CompletionAction::Format {
+            command: "true".to_string(),
+        },
+        CompletionAction::CopyPlan {
+            dest: PathBuf::from("docs/plans"),
+        },
+        CompletionAction::Format {
+            command: "wc -l".to_string(),
+        },
+        CompletionAction::Shell {
+            command: "echo broken >&2; exit 3".to_string(),
+        },
+        CompletionAction::Shell {
+            command: "echo \"$PLANNING_FEATURE $PLANNING_GENERATED_FILES\"".to_string(),
+        },
+    ];
+
+    let outcomes = run_completion_actions(&actions, &target).await;
+
+    let copy = dir.path().join("docs/plans/add-caching.md");
+    assert_eq!(std::fs::read_to_string(&copy).unwrap(), "# Plan\n");
+    assert_eq!(outcomes.len(), actions.len());
+    assert_eq!(outcomes[0].detail, "skipped: no files were generated");
+    assert_eq!(outcomes[1].detail, format!("wrote {}", copy.display()));
+    assert!(outcomes[2].succeeded);
+    assert!(outcomes[2].detail.contains("add-caching.md"));
+    assert!(!outcomes[3].succeeded);
+    assert!(
+        outcomes[3].detail.ends_with(": broken"),
+        "{}",
+        outcomes[3].detail
+    );
+    assert!(outcomes[4].succeeded);
+    assert_eq!(
+        outcomes[4].detail,
+        format!("add-caching {}", copy.display())
+    );
+}
+
+#[test]
+fn test_completion_actions_parse_from_config() {
+    let yaml = r#"
+- type: copy_plan
+  dest: docs/plans
+- type: shell
+  command: ./scripts/announce.sh
+- type: notify
+  webhook: https://hooks.example.com/T000
+  message: "{feature} is ready"
+"#;
+    let actions: Vec<CompletionAction> = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        actions[0],
+        CompletionAction::CopyPlan {
+            dest: PathBuf::from("docs/plans")
+        }
+    );
+    assert_eq!(actions[1].label(), "shell: ./scripts/announce.sh");
+    assert_eq!(
+        actions[2],
+        CompletionAction::Notify {
+            webhook: "https://hooks.example.com/T000".to_string(),
+            message: Some("{feature} is ready".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_agent_usage_totals_per_agent() {
+    let usage = |agent: &str, tokens, cost| InvocationUsage {
+        phase: "Reviewing".to_string(),
+        agent: agent.to_string(),
+        tokens,
+        cost,
+    };
+    let agents = agent_usage(&[
+        usage("codex", 500, None),
+        usage("claude", 1_000, Some(0.5)),
+        usage("claude", 2_000, Some(0.25)),
+    ]);
+
+    assert_eq!(
+        agents,
+        vec![
+            AgentUsage {
+                agent: "claude".to_string(),
+                invocations: 2,
+                tokens: 3_000,
+                cost_usd: Some(0.75),
+            },
+            AgentUsage {
+                agent: "codex".to_string(),
+                invocations: 1,
+                tokens: 500,
+                cost_usd: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_metrics_card() {
+    let metrics = WorkflowMetrics {
+        feature: "add-caching".to_string(),
+        total_seconds: 200,
+        phases: vec![
+            PhaseTime {
+                phase: "Planning".to_string(),
+                seconds: 80,
+            },
+            PhaseTime {
+                phase: "Reviewing".to_string(),
+                seconds: 120,
+            },
+        ],
+        iterations: 2,
+        implementation_rounds: 0,
+        reviewer_rejections: 1,
+        agents: vec![AgentUsage {
+            agent: "claude".to_string(),
+            invocations: 3,
+            tokens: 12_500,
+            cost_usd: Some(0.4),
+        }],
+        files_changed: vec!["src/lib.rs".to_string()],
+        plan: None,
+        notes: Some("Ask ops about the cache TTL\n\n- keep v1 API".to_string()),
+    };
+
+    assert_eq!(
+        metrics.card(),
+        vec![
+            "Time: 3m20s (Planning 1m20s, Reviewing 2m0s)",
+            "Iterations: 2, reviewer rejections: 1",
+            "claude: 3 invocation(s), 12.5k tokens, $0.40",
+            "Files changed: 1",
+            "Notes: Ask ops about the cache TTL (+1 more line(s) in summary.json)",
+        ]
+    );
+}
+
+#[test]
+fn test_plan_metadata_reaches_env_notification_and_card() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    let mut view = accepted_view(&plan);
+    let target = CompletionTarget {
+        view: &view,
+        working_dir: dir.path(),
+        session_id: "session",
+    };
+    assert_eq!(
+        target.notification_text(Some("{title} ({risk})")),
+        "add-caching (unknown)"
+    );
+
+    let metadata = PlanMetadata {
+        title: "Cache API responses".to_string(),
+        risk: crate::domain::RiskLevel::High,
+        effort: "1 week".to_string(),
+        components: vec!["api".to_string(), "cache".to_string()],
+    };
+    view.apply_event(
+        AGGREGATE_ID,
+        &WorkflowEvent::PlanMetadataRecorded {
+            metadata: metadata.clone(),
+            recorded_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    let target = CompletionTarget {
+        view: &view,
+        working_dir: dir.path(),
+        session_id: "session",
+    };
+    assert_eq!(
+        target.notification_text(Some("{title} ({risk})")),
+        "Cache API responses (high)"
+    );
+    let env = target.env(&[]);
+    assert!(env.contains(&("PLANNING_PLAN_RISK", "high".to_string())));
+    assert!(env.contains(&("PLANNING_PLAN_COMPONENTS", "api\ncache".to_string())));
+
+    let metrics = WorkflowMetrics {
+        plan: Some(metadata),
+        ..WorkflowMetrics::default()
+    };
+    assert_eq!(
+        metrics.card()[0],
+        "Plan: Cache API responses (risk: high, effort: 1 week)"
+    );
+}
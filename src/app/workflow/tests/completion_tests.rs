@@ -0,0 +1,40 @@
+use super::*;
+use tempfile::tempdir;
+
+#[test]
+fn test_format_conditions_section_bullets_each_line() {
+    let section =
+        format_conditions_section("Keep the API stable\n\n- add a migration note\n* no new deps");
+
+    assert!(section.starts_with(CONDITIONS_HEADING));
+    assert!(section.contains("mandatory"));
+    assert!(section.contains("- Keep the API stable\n- add a migration note\n- no new deps\n"));
+}
+
+#[test]
+fn test_append_approval_conditions_keeps_plan() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    std::fs::write(&plan, "# Plan\n\nDo the thing.\n\n").unwrap();
+
+    append_approval_conditions(&plan, "Ship behind a flag").unwrap();
+
+    let content = std::fs::read_to_string(&plan).unwrap();
+    assert!(content.starts_with("# Plan\n\nDo the thing.\n\n## Conditions of approval\n"));
+    assert!(content.ends_with("- Ship behind a flag\n"));
+}
+
+#[test]
+fn test_remove_approval_conditions_restores_plan() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    std::fs::write(&plan, "# Plan\n\nDo the thing.\n").unwrap();
+
+    append_approval_conditions(&plan, "Ship behind a flag").unwrap();
+    remove_approval_conditions(&plan).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&plan).unwrap(),
+        "# Plan\n\nDo the thing.\n"
+    );
+}
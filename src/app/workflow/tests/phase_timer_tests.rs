@@ -0,0 +1,96 @@
+use super::*;
+use crate::tui::Event;
+use tokio::sync::mpsc;
+
+fn make_sender() -> (SessionEventSender, mpsc::UnboundedReceiver<Event>) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (SessionEventSender::new(0, 0, tx), rx)
+}
+
+fn make_logger() -> Arc<SessionLogger> {
+    let session_id = format!("test-{}", uuid::Uuid::new_v4());
+    Arc::new(SessionLogger::new(&session_id).expect("test logger"))
+}
+
+fn exceeded_minutes(rx: &mut mpsc::UnboundedReceiver<Event>) -> Option<u64> {
+    let mut minutes = None;
+    while let Ok(event) = rx.try_recv() {
+        if let Event::SessionPhaseDurationExceeded { limit_minutes, .. } = event {
+            minutes = Some(limit_minutes);
+        }
+    }
+    minutes
+}
+
+#[tokio::test]
+async fn test_timer_without_limit_never_requests_wrap_up() {
+    let (sender, mut rx) = make_sender();
+    let timer = PhaseTimer::start(PhaseLabel::Planning, None, &sender, make_logger(), None);
+    assert!(timer.handle.is_none());
+    assert!(!timer.wrap_up_requested());
+    assert_eq!(exceeded_minutes(&mut rx), None);
+}
+
+#[tokio::test]
+async fn test_timer_warning_only_has_no_wrap_up_signal() {
+    let (sender, _rx) = make_sender();
+    let limit = PhaseDurationLimit {
+        minutes: 30,
+        auto_interrupt: false,
+    };
+    let timer = PhaseTimer::start(
+        PhaseLabel::Reviewing,
+        Some(limit),
+        &sender,
+        make_logger(),
+        None,
+    );
+    assert!(timer.handle.is_some());
+    assert!(timer.wrap_up_rx.is_none());
+}
+
+#[tokio::test]
+async fn test_overrun_warns_and_signals_wrap_up() {
+    let (sender, mut rx) = make_sender();
+    let (wrap_up_tx, wrap_up_rx) = watch::channel(false);
+    let limit = PhaseDurationLimit {
+        minutes: 20,
+        auto_interrupt: true,
+    };
+
+    on_overrun(
+        PhaseLabel::Revising,
+        limit,
+        &sender,
+        &make_logger(),
+        &None,
+        &wrap_up_tx,
+    )
+    .await;
+
+    assert_eq!(exceeded_minutes(&mut rx), Some(20));
+    assert!(*wrap_up_rx.borrow());
+}
+
+#[tokio::test]
+async fn test_overrun_without_auto_interrupt_only_warns() {
+    let (sender, mut rx) = make_sender();
+    let (wrap_up_tx, wrap_up_rx) = watch::channel(false);
+    let limit = PhaseDurationLimit {
+        minutes: 20,
+        auto_interrupt: false,
+    };
+
+    on_overrun(
+        PhaseLabel::Planning,
+        limit,
+        &sender,
+        &make_logger(),
+        &None,
+        &wrap_up_tx,
+    )
+    .await;
+
+    assert_eq!(exceeded_minutes(&mut rx), Some(20));
+    assert!(!*wrap_up_rx.borrow());
+}
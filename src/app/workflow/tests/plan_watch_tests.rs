@@ -0,0 +1,69 @@
+use super::*;
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkingDir,
+};
+use crate::domain::WorkflowEvent;
+use chrono::Duration;
+use tempfile::tempdir;
+
+const AGGREGATE_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+fn view_with_plan(plan: &std::path::Path, written_at: TimestampUtc) -> WorkflowView {
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        AGGREGATE_ID,
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from("feature"),
+            objective: Objective::from("objective"),
+            working_dir: WorkingDir(plan.parent().unwrap().to_path_buf()),
+            max_iterations: MaxIterations(3),
+            plan_path: PlanPath(plan.to_path_buf()),
+            feedback_path: FeedbackPath::from(plan.with_extension("feedback.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+    view.apply_event(
+        AGGREGATE_ID,
+        &WorkflowEvent::PlanningCompleted {
+            plan_path: PlanPath(plan.to_path_buf()),
+            completed_at: written_at,
+        },
+        2,
+    );
+    view
+}
+
+#[test]
+fn test_plan_modified_after_last_write_is_detected_until_acknowledged() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    fs::write(&plan, "# Plan\n\nEdited by hand").unwrap();
+
+    let mut view = view_with_plan(&plan, TimestampUtc(Utc::now() - Duration::minutes(5)));
+    assert!(plan_modified_on_disk(&view).is_some());
+    assert!(!view.plan_externally_modified());
+
+    view.apply_event(
+        AGGREGATE_ID,
+        &WorkflowEvent::PlanExternallyModified {
+            content_hash: "abc".to_string(),
+            detected_at: TimestampUtc(Utc::now() + Duration::minutes(5)),
+        },
+        3,
+    );
+    assert!(plan_modified_on_disk(&view).is_none());
+    assert!(view.plan_externally_modified());
+}
+
+#[test]
+fn test_plan_written_by_workflow_is_not_an_external_edit() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    fs::write(&plan, "# Plan").unwrap();
+
+    let view = view_with_plan(&plan, TimestampUtc(Utc::now() + Duration::minutes(5)));
+    assert!(plan_modified_on_disk(&view).is_none());
+    // Views without a recorded write (e.g. older sessions) never report edits
+    assert!(plan_modified_on_disk(&WorkflowView::default()).is_none());
+}
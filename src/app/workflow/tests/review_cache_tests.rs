@@ -0,0 +1,52 @@
+use super::*;
+use tempfile::tempdir;
+
+fn review(agent: &str, needs_revision: bool) -> ReviewResult {
+    ReviewResult {
+        agent_name: agent.to_string(),
+        needs_revision,
+        feedback: String::new(),
+        summary: format!("{} summary", agent),
+        low_confidence: false,
+    }
+}
+
+#[test]
+fn test_plan_content_hash_tracks_content() {
+    let dir = tempdir().unwrap();
+    let plan = dir.path().join("plan.md");
+    std::fs::write(&plan, "# Plan\n\nStep 1").unwrap();
+    let first = plan_content_hash(&plan).unwrap();
+
+    assert_eq!(plan_content_hash(&plan), Some(first));
+    std::fs::write(&plan, "# Plan\n\nStep 1\nStep 2").unwrap();
+    assert_ne!(plan_content_hash(&plan), Some(first));
+    assert_eq!(plan_content_hash(&dir.path().join("missing.md")), None);
+}
+
+#[test]
+fn test_approval_reused_only_for_same_plan() {
+    let mut cache = ReviewCache::default();
+    cache.record(1, &review("claude", false));
+
+    assert_eq!(
+        cache.reusable("claude", 1).map(|r| r.summary.as_str()),
+        Some("claude summary")
+    );
+    assert!(cache.reusable("claude", 2).is_none());
+    assert!(cache.reusable("codex", 1).is_none());
+}
+
+#[test]
+fn test_rejection_clears_cached_approval() {
+    let mut cache = ReviewCache::default();
+    cache.record(1, &review("claude", false));
+    cache.record(1, &review("claude", true));
+    assert!(cache.reusable("claude", 1).is_none());
+
+    // A later approval of a revised plan replaces the cached hash
+    cache.record(1, &review("codex", false));
+    cache.record(2, &review("codex", false));
+    assert!(cache.reusable("codex", 1).is_none());
+    assert!(cache.reusable("codex", 2).is_some());
+}
@@ -39,6 +39,7 @@ fn review_cycle_started_event() -> WorkflowEvent {
     WorkflowEvent::ReviewCycleStarted {
         mode: ReviewMode::Parallel,
         reviewers: vec![AgentId::from("reviewer-1"), AgentId::from("reviewer-2")],
+        preset: None,
         started_at: TimestampUtc::now(),
     }
 }
@@ -54,6 +55,7 @@ fn reviewer_rejected_event(reviewer_id: &str, feedback_path: PathBuf) -> Workflo
     WorkflowEvent::ReviewerRejected {
         reviewer_id: AgentId::from(reviewer_id),
         feedback_path: FeedbackPath::from(feedback_path),
+        low_confidence: false,
         rejected_at: TimestampUtc::now(),
     }
 }
@@ -74,6 +76,7 @@ fn populate_reviews_does_nothing_when_last_reviews_not_empty() {
         needs_revision: false,
         feedback: "existing feedback".to_string(),
         summary: "existing summary".to_string(),
+        low_confidence: false,
     }];
 
     populate_reviews_from_view(&view, &mut last_reviews, &*logger);
@@ -0,0 +1,124 @@
+use super::*;
+
+const PLAN_V1: &str = "# Plan\n\n## Overview\n\nAdd caching.\n\n\
+                       ## Implementation Steps\n\n1. Add cache\n\n### Invalidation\n\nTTL only\n\n\
+                       ## Testing\n\nUnit tests\n";
+
+fn rejection(agent: &str, flagged: &[&str]) -> ReviewResult {
+    let mut feedback = "## Critical Issues\n\n- Invalidation is unclear\n".to_string();
+    if !flagged.is_empty() {
+        feedback.push_str("\n## Flagged Sections\n\n");
+        for section in flagged {
+            feedback.push_str(&format!("- {}\n", section));
+        }
+    }
+    feedback.push_str("\n## Overall Assessment: NEEDS REVISION\n");
+    ReviewResult {
+        agent_name: agent.to_string(),
+        needs_revision: true,
+        feedback,
+        summary: format!("{} summary", agent),
+        low_confidence: false,
+    }
+}
+
+#[test]
+fn test_changed_sections_include_parents_of_changed_subsections() {
+    let v2 = PLAN_V1.replace("TTL only", "TTL plus explicit purge on write");
+    let changed = changed_sections(PLAN_V1, &v2);
+    assert_eq!(
+        changed,
+        vec!["implementation steps", "invalidation", "plan"],
+        "untouched sections must not be reported"
+    );
+
+    let v3 = PLAN_V1.replace("## Testing\n\nUnit tests\n", "## Rollout\n\nFlag\n");
+    let changed = changed_sections(PLAN_V1, &v3);
+    assert!(changed.contains(&"testing".to_string()));
+    assert!(changed.contains(&"rollout".to_string()));
+    assert!(!changed.contains(&"overview".to_string()));
+}
+
+#[test]
+fn test_headings_inside_code_blocks_are_ignored() {
+    let plan = "## Steps\n\n```sh\n# not a heading\n```\n";
+    let sections = plan_sections(plan);
+    assert_eq!(sections.len(), 1);
+    assert!(sections["steps"].contains("# not a heading"));
+}
+
+#[test]
+fn test_targets_only_changed_flagged_sections() {
+    let mut owners = SectionOwners::default();
+    owners.record(
+        PLAN_V1,
+        &rejection("claude", &["`## Invalidation`", "Testing"]),
+    );
+
+    let v2 = PLAN_V1.replace("TTL only", "TTL plus explicit purge on write");
+    assert_eq!(
+        owners.targets("claude", &v2),
+        Some(vec!["Invalidation".to_string()])
+    );
+    // Nothing flagged changed: full review
+    assert_eq!(owners.targets("claude", PLAN_V1), None);
+    assert_eq!(owners.targets("codex", &v2), None);
+}
+
+#[test]
+fn test_approval_or_unflagged_rejection_clears_targets() {
+    let v2 = PLAN_V1.replace("Unit tests", "Unit and load tests");
+    let mut owners = SectionOwners::default();
+    owners.record(PLAN_V1, &rejection("claude", &["Testing"]));
+    assert!(owners.targets("claude", &v2).is_some());
+
+    owners.record(PLAN_V1, &rejection("claude", &[]));
+    assert_eq!(owners.targets("claude", &v2), None);
+
+    owners.record(PLAN_V1, &rejection("claude", &["Testing"]));
+    let mut approval = rejection("claude", &["Testing"]);
+    approval.needs_revision = false;
+    owners.record(&v2, &approval);
+    assert_eq!(owners.targets("claude", &v2), None);
+}
+
+#[test]
+fn test_change_brief_marks_items_the_revision_touched() {
+    let mut owners = SectionOwners::default();
+    let mut review = rejection("claude", &[]);
+    review.feedback = "## Critical Issues\n\n- Cache invalidation is unclear\n\
+                       - Rollout needs a feature flag\n"
+        .to_string();
+    owners.record_round(PLAN_V1, std::slice::from_ref(&review));
+
+    let v2 = PLAN_V1.replace(
+        "TTL only",
+        "TTL plus explicit invalidation of the cache on write",
+    );
+    let brief = owners.change_brief(&v2).unwrap();
+    assert!(
+        brief.contains("Plan diff: 1 line(s) added, 1 removed"),
+        "{}",
+        brief
+    );
+    assert!(brief.contains("changed sections: implementation steps, invalidation, plan"));
+    assert!(brief
+        .contains("- [appears addressed] claude (Critical Issues): Cache invalidation is unclear"));
+    assert!(brief.contains(
+        "- [not evidently addressed] claude (Critical Issues): Rollout needs a feature flag"
+    ));
+}
+
+#[test]
+fn test_approved_round_clears_change_brief() {
+    let mut owners = SectionOwners::default();
+    assert!(owners.change_brief(PLAN_V1).is_none());
+
+    owners.record_round(PLAN_V1, &[rejection("claude", &[])]);
+    assert!(owners.change_brief(PLAN_V1).is_some());
+
+    let mut approval = rejection("claude", &[]);
+    approval.needs_revision = false;
+    owners.record_round(PLAN_V1, &[approval]);
+    assert!(owners.change_brief(PLAN_V1).is_none());
+}
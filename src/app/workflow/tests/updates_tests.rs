@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn fallback_needs_consecutive_throttled_rounds() {
+    let mut rounds = 0;
+    assert!(!throttled_round_limit_reached(&mut rounds, true, 2));
+    assert!(!throttled_round_limit_reached(&mut rounds, false, 2));
+    assert!(!throttled_round_limit_reached(&mut rounds, true, 2));
+    assert!(throttled_round_limit_reached(&mut rounds, true, 2));
+}
+
+#[test]
+fn fallback_is_off_at_zero() {
+    let mut rounds = 0;
+    for _ in 0..5 {
+        assert!(!throttled_round_limit_reached(&mut rounds, true, 0));
+    }
+}
@@ -0,0 +1,105 @@
+//! Feedback triage between reviewing and revising.
+//!
+//! With `reviewing.triage_feedback`, rejecting reviews are split into items the
+//! user accepts, parks, or dismisses before the revision runs. Only accepted items
+//! reach the revision prompt; parked items are kept in the plan's backlog section.
+
+use super::reviewing::WorkflowPhaseContext;
+use super::WorkflowResult;
+use crate::app::workflow_decisions::wait_for_feedback_triage;
+use crate::domain::review::{TriageDecision, TriagedFeedback};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::feedback_items::{parse_feedback_items, with_backlog_section};
+use crate::phases::ReviewResult;
+use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
+use crate::tui::{UserApprovalResponse, WorkflowCommand};
+use std::fs;
+use tokio::sync::mpsc;
+
+/// Asks the user to triage the latest review feedback and records the decisions.
+///
+/// Does nothing when triage is disabled or this revision was already triaged
+/// (e.g. on resume). Returns `Some(WorkflowResult::Stopped)` if the workflow was
+/// stopped while waiting for the user.
+pub async fn triage_feedback_if_needed(
+    view: &WorkflowView,
+    context: &WorkflowPhaseContext<'_>,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+    last_reviews: &[ReviewResult],
+) -> Option<WorkflowResult> {
+    if !context.config.workflow.reviewing.triage_feedback || view.feedback_triage().is_some() {
+        return None;
+    }
+
+    let items = parse_feedback_items(last_reviews);
+    if items.is_empty() {
+        context.log_workflow("No feedback items to triage");
+        return None;
+    }
+
+    context.log_workflow(&format!(
+        "Requesting triage of {} feedback item(s)",
+        items.len()
+    ));
+    context.sender.send_output(format!(
+        "[triage] {} feedback item(s) awaiting triage...",
+        items.len()
+    ));
+    context.sender.send_feedback_triage_request(items.clone());
+
+    let decisions = wait_for_feedback_triage(
+        &context.session_logger,
+        approval_rx,
+        control_rx,
+        items.len(),
+    )
+    .await?;
+
+    let count = |decision: TriageDecision| decisions.iter().filter(|d| **d == decision).count();
+    context.sender.send_output(format!(
+        "[triage] {} accepted, {} parked, {} dismissed",
+        count(TriageDecision::Accept),
+        count(TriageDecision::Park),
+        count(TriageDecision::Dismiss)
+    ));
+
+    let items = items
+        .into_iter()
+        .zip(decisions)
+        .map(|(item, decision)| TriagedFeedback { item, decision })
+        .collect();
+    context
+        .dispatch_command(DomainCommand::FeedbackTriaged { items })
+        .await;
+    None
+}
+
+/// Rewrites the plan's backlog section from the parked feedback items.
+///
+/// Runs after the revision so the agent cannot drop parked items. Plans of
+/// workflows that never triaged are left untouched.
+pub fn write_backlog_section(view: &WorkflowView, session_logger: &SessionLogger) {
+    if view.feedback_triage().is_none() && view.parked_feedback().is_empty() {
+        return;
+    }
+    let Some(plan_path) = view.plan_path() else {
+        return;
+    };
+
+    let result = fs::read_to_string(plan_path.as_path()).and_then(|content| {
+        let updated = with_backlog_section(&content, view.parked_feedback());
+        if updated == content {
+            return Ok(());
+        }
+        fs::write(plan_path.as_path(), updated)
+    });
+    if let Err(e) = result {
+        session_logger.log(
+            LogLevel::Warn,
+            LogCategory::Workflow,
+            &format!("Failed to update plan backlog section: {}", e),
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! Applies `/rename`, `/reviewers` and `/review-mode` while the workflow runs.
+//!
+//! Updates arrive from the TUI on their own channel rather than as a
+//! `WorkflowCommand`, so they don't interrupt the current phase. A rename takes
+//! effect at once: it is recorded as a `WorkflowRenamed` event (the TUI's tab and
+//! window title follow the view), then pushed to the daemon record, the saved
+//! snapshot and session_info.json. A reviewer preset is handed to the workflow
+//! loop, which switches the panel before the next review round. A review mode is
+//! handed over the same way; the loop also falls back to sequential review on its
+//! own when parallel rounds keep waiting for provider slots. A worktree the
+//! session was moved into is recorded as `WorktreeAttached` and becomes the
+//! daemon record's working directory; implementation then runs in it. `/undo` is
+//! handed to the workflow loop too, which honors it only during the grace window
+//! after the final plan decision. Output of a user slash command with
+//! `inject_output` is recorded as `CommandOutputQueued`; the next planner or
+//! implementer prompt takes it from the view.
+//!
+//! Agent failures reported through the session sender while a phase runs
+//! (e.g. a memory limit kill that was retried) are recorded from here as well.
+//!
+//! The session lease is also claimed here: a run only writes to a session its
+//! process holds in the daemon. Another process asking to take the session over
+//! reaches the workflow as a `Stop` on its control channel.
+
+use super::WorkflowResult;
+use crate::config::WorkflowConfig;
+use crate::domain::actor::WorkflowMessage;
+use crate::domain::failure::FailureContext;
+use crate::domain::input::WorkflowInput;
+use crate::domain::types::{FeatureName, Phase};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowCommand as DomainCommand;
+use crate::planning_paths::{self, SessionInfo};
+use crate::session_daemon::{
+    get_snapshot_path, load_snapshot, save_snapshot, LogCategory, LogLevel, SessionLogger,
+    SessionTracker,
+};
+use crate::tui::{SessionEventSender, WorkflowCommand, WorkflowUpdate};
+use anyhow::Result;
+use ractor::ActorRef;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+
+/// How often a takeover checks whether the previous holder has released the session.
+const LEASE_RELEASE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Applies updates for the lifetime of one workflow run. Dropping it stops listening.
+pub(super) struct UpdateListener(JoinHandle<()>);
+
+impl Drop for UpdateListener {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Records each failure sent on the returned channel as a `FailureRecorded`
+/// event, until every sender is dropped.
+pub(super) fn spawn_failure_recorder(
+    actor_ref: ActorRef<WorkflowMessage>,
+    session_logger: Arc<SessionLogger>,
+) -> mpsc::UnboundedSender<FailureContext> {
+    let (failure_tx, mut failure_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let actor_ref = Some(actor_ref);
+        while let Some(failure) = failure_rx.recv().await {
+            let command = DomainCommand::RecordFailure { failure };
+            dispatch_domain_command(&actor_ref, command, &session_logger).await;
+        }
+    });
+    failure_tx
+}
+
+/// Updates the listener forwards to the workflow loop.
+pub(super) struct LoopUpdateSenders {
+    /// Reviewer presets, applied between review rounds.
+    pub preset_tx: watch::Sender<Option<String>>,
+    /// Review modes (sequential = true), applied between review rounds.
+    pub review_mode_tx: watch::Sender<Option<bool>>,
+    /// `/undo` requests for the final plan decision.
+    pub undo_tx: mpsc::UnboundedSender<()>,
+}
+
+/// Handles each update received until the TUI drops its sender. Reviewer presets,
+/// review modes and `/undo` are forwarded on `loop_senders` for the workflow loop to apply.
+pub(super) fn spawn_update_listener(
+    mut update_rx: mpsc::UnboundedReceiver<WorkflowUpdate>,
+    actor_ref: ActorRef<WorkflowMessage>,
+    tracker: Arc<SessionTracker>,
+    workflow_session_id: String,
+    sender: SessionEventSender,
+    session_logger: Arc<SessionLogger>,
+    loop_senders: LoopUpdateSenders,
+) -> UpdateListener {
+    UpdateListener(tokio::spawn(async move {
+        while let Some(update) = update_rx.recv().await {
+            let feature_name = match update {
+                WorkflowUpdate::Rename(feature_name) => feature_name,
+                WorkflowUpdate::ReviewerPreset(preset) => {
+                    loop_senders.preset_tx.send_replace(Some(preset));
+                    continue;
+                }
+                WorkflowUpdate::ReviewMode(sequential) => {
+                    loop_senders.review_mode_tx.send_replace(Some(sequential));
+                    continue;
+                }
+                WorkflowUpdate::UndoDecision => {
+                    // The loop is gone once the workflow ends; nothing is left to undo
+                    let _ = loop_senders.undo_tx.send(());
+                    continue;
+                }
+                WorkflowUpdate::QueueCommandOutput(output) => {
+                    let cmd = DomainCommand::QueueCommandOutput { output };
+                    dispatch_domain_command(&Some(actor_ref.clone()), cmd, &session_logger).await;
+                    continue;
+                }
+                WorkflowUpdate::AttachWorktree(worktree_state) => {
+                    let worktree_path = worktree_state.worktree_path().to_path_buf();
+                    let cmd = DomainCommand::AttachWorktree { worktree_state };
+                    dispatch_domain_command(&Some(actor_ref.clone()), cmd, &session_logger).await;
+                    // Daemon tracking is best-effort - ignore errors if daemon not running
+                    let _ = tracker
+                        .set_working_dir(&workflow_session_id, worktree_path)
+                        .await;
+                    continue;
+                }
+            };
+            let view = match rename_workflow(&actor_ref, &feature_name).await {
+                Ok(view) => view,
+                Err(e) => {
+                    session_logger.log(
+                        LogLevel::Warn,
+                        LogCategory::Workflow,
+                        &format!("Rename to '{}' failed: {}", feature_name, e),
+                    );
+                    sender.send_output(format!("[workflow] Rename failed: {}", e));
+                    continue;
+                }
+            };
+
+            // Daemon tracking is best-effort - ignore errors if daemon not running
+            let _ = tracker.rename(&workflow_session_id, &feature_name).await;
+            if let Err(e) = persist_name(&workflow_session_id, &feature_name, &view) {
+                session_logger.log(
+                    LogLevel::Warn,
+                    LogCategory::Workflow,
+                    &format!("Failed to save renamed session metadata: {}", e),
+                );
+            }
+            session_logger.log(
+                LogLevel::Info,
+                LogCategory::Workflow,
+                &format!("Session renamed to '{}'", feature_name),
+            );
+            sender.send_output(format!("[workflow] Session renamed to '{}'", feature_name));
+        }
+    }))
+}
+
+/// Switches `config` to the preset's review panel and reports the outcome.
+pub(super) fn apply_reviewer_preset(
+    config: &mut WorkflowConfig,
+    preset: &str,
+    sender: &SessionEventSender,
+    session_logger: &SessionLogger,
+) {
+    match config.apply_reviewer_preset(preset) {
+        Ok(()) => {
+            session_logger.log(
+                LogLevel::Info,
+                LogCategory::Workflow,
+                &format!("Reviewer preset '{}' selected", preset),
+            );
+            sender.send_output(format!(
+                "[workflow] Reviewers: preset '{}' from the next review round",
+                preset
+            ));
+        }
+        Err(e) => sender.send_output(format!("[workflow] Reviewers unchanged: {}", e)),
+    }
+}
+
+/// Switches reviewing between parallel and sequential for the rest of the session:
+/// on `/review-mode`, or when `reviewing.sequential_fallback_after` parallel rounds
+/// in a row waited for provider slots. Each switch is recorded as a
+/// `ReviewModeChanged` event, which a resumed session applies again. Reviewer
+/// conversations and cached verdicts are shared by both modes, so they carry over.
+pub(super) struct ReviewModeSwitch {
+    requests: watch::Receiver<Option<bool>>,
+    actor_ref: ActorRef<WorkflowMessage>,
+    sender: SessionEventSender,
+    session_logger: Arc<SessionLogger>,
+    /// Provider slot waits counted when the running parallel round started.
+    parallel_round_waits: Option<u32>,
+    /// Parallel rounds in a row that waited for a provider slot.
+    throttled_rounds: u32,
+}
+
+impl ReviewModeSwitch {
+    pub(super) fn new(
+        requests: watch::Receiver<Option<bool>>,
+        actor_ref: ActorRef<WorkflowMessage>,
+        sender: SessionEventSender,
+        session_logger: Arc<SessionLogger>,
+    ) -> Self {
+        Self {
+            requests,
+            actor_ref,
+            sender,
+            session_logger,
+            parallel_round_waits: None,
+            throttled_rounds: 0,
+        }
+    }
+
+    /// Notes the start of a review round run in the given mode.
+    pub(super) fn round_starting(&mut self, sequential: bool) {
+        self.parallel_round_waits = (!sequential).then(|| self.sender.provider_slot_waits());
+    }
+
+    /// Applies a requested mode, or the fallback after throttled parallel rounds.
+    /// Called between phases; a request waits while a sequential cycle is under way.
+    pub(super) async fn apply(&mut self, view: &WorkflowView, config: &mut WorkflowConfig) {
+        if let Some(waits) = self.parallel_round_waits.take() {
+            let limit = config.workflow.reviewing.sequential_fallback_after;
+            if throttled_round_limit_reached(
+                &mut self.throttled_rounds,
+                self.sender.provider_slot_waits() > waits,
+                limit,
+            ) {
+                let reason = format!(
+                    "{} parallel round(s) in a row waited for provider slots",
+                    limit
+                );
+                self.switch(config, true, &reason).await;
+            }
+        }
+        if view.planning_phase() == Some(Phase::Reviewing)
+            || !self.requests.has_changed().unwrap_or(false)
+        {
+            return;
+        }
+        let requested = *self.requests.borrow_and_update();
+        if let Some(sequential) = requested {
+            self.switch(config, sequential, "/review-mode").await;
+        }
+    }
+
+    async fn switch(&mut self, config: &mut WorkflowConfig, sequential: bool, reason: &str) {
+        let mode = if sequential { "sequential" } else { "parallel" };
+        self.throttled_rounds = 0;
+        if config.workflow.reviewing.sequential == sequential {
+            self.sender
+                .send_output(format!("[workflow] Reviewing is already {}", mode));
+            return;
+        }
+        config.workflow.reviewing.sequential = sequential;
+        let cmd = DomainCommand::RecordReviewModeChange {
+            sequential,
+            reason: reason.to_string(),
+        };
+        dispatch_domain_command(&Some(self.actor_ref.clone()), cmd, &self.session_logger).await;
+        self.session_logger.log(
+            LogLevel::Info,
+            LogCategory::Workflow,
+            &format!("Review mode switched to {} ({})", mode, reason),
+        );
+        self.sender.send_output(format!(
+            "[workflow] Reviewing: {} from the next review round ({})",
+            mode, reason
+        ));
+    }
+}
+
+/// Counts a finished parallel round towards the fallback. True once `limit` rounds
+/// in a row waited for provider slots; a limit of 0 never falls back.
+pub(super) fn throttled_round_limit_reached(
+    throttled_rounds: &mut u32,
+    waited_for_slots: bool,
+    limit: u32,
+) -> bool {
+    *throttled_rounds = if waited_for_slots {
+        *throttled_rounds + 1
+    } else {
+        0
+    };
+    limit > 0 && *throttled_rounds >= limit
+}
+
+async fn rename_workflow(
+    actor_ref: &ActorRef<WorkflowMessage>,
+    feature_name: &str,
+) -> Result<WorkflowView> {
+    let cmd = DomainCommand::RenameWorkflow {
+        feature_name: FeatureName::from(feature_name),
+    };
+    let (reply_tx, reply_rx) = oneshot::channel();
+    actor_ref
+        .send_message(WorkflowMessage::Command(Box::new(cmd), reply_tx))
+        .map_err(|e| anyhow::anyhow!("Failed to send rename command: {}", e))?;
+    let view = reply_rx
+        .await
+        .map_err(|_| anyhow::anyhow!("Workflow actor stopped"))??;
+    Ok(view)
+}
+
+/// Rewrites the saved snapshot, which also refreshes session_info.json, or only
+/// session_info.json when the session has no snapshot yet.
+fn persist_name(session_id: &str, feature_name: &str, view: &WorkflowView) -> Result<()> {
+    if get_snapshot_path(session_id)?.exists() {
+        let mut snapshot = load_snapshot(session_id)?;
+        snapshot.ui_state.name = feature_name.to_string();
+        snapshot.workflow_view = view.clone();
+        snapshot.last_event_sequence = view.last_event_sequence();
+        save_snapshot(&snapshot)?;
+    } else if planning_paths::session_info_path(session_id)?.exists() {
+        let mut info = SessionInfo::load(session_id)?;
+        info.feature_name = feature_name.to_string();
+        info.updated_at = chrono::Utc::now().to_rfc3339();
+        info.save(session_id)?;
+    }
+    Ok(())
+}
+
+/// Claims the session for this run before anything is written to it.
+///
+/// A resumed session held by another process is followed read-only unless
+/// `--takeover` was given. A takeover asks the holder to stop and waits until it
+/// has released the session. Returns the control channel the run should use, or
+/// the result to end the run with.
+pub(super) async fn claim_session_lease(
+    input: &WorkflowInput,
+    tracker: &SessionTracker,
+    sender: &SessionEventSender,
+    mut control_rx: mpsc::Receiver<WorkflowCommand>,
+) -> std::result::Result<mpsc::Receiver<WorkflowCommand>, WorkflowResult> {
+    let session_id = input.workflow_session_id().to_string();
+    if let WorkflowInput::Resume(resume) = input {
+        if let Some(holder_pid) = tracker.lease_holder(&session_id).await {
+            if !resume.takeover {
+                return Err(WorkflowResult::Following { holder_pid });
+            }
+            if let Err(e) = tracker.take_over(&session_id).await {
+                sender.send_output(format!("[planning] Takeover request failed: {}", e));
+            }
+            sender.send_output(format!(
+                "[planning] Waiting for PID {} to stop at its next phase boundary...",
+                holder_pid
+            ));
+            while tracker.lease_holder(&session_id).await.is_some() {
+                tokio::select! {
+                    Some(cmd) = control_rx.recv() => {
+                        if matches!(cmd, WorkflowCommand::Stop) {
+                            return Err(WorkflowResult::Stopped);
+                        }
+                    }
+                    _ = tokio::time::sleep(LEASE_RELEASE_POLL_INTERVAL) => {}
+                }
+            }
+            sender.send_output(format!(
+                "[planning] Took the session over from PID {}",
+                holder_pid
+            ));
+        }
+    }
+    Ok(stop_on_lease_lost(
+        control_rx,
+        tracker.lease_lost(),
+        session_id,
+        sender.clone(),
+    ))
+}
+
+/// Forwards control commands, adding a `Stop` once another process takes the
+/// session over. The tab then follows the new holder.
+fn stop_on_lease_lost(
+    mut control_rx: mpsc::Receiver<WorkflowCommand>,
+    mut lease_lost_rx: broadcast::Receiver<(String, u32)>,
+    session_id: String,
+    sender: SessionEventSender,
+) -> mpsc::Receiver<WorkflowCommand> {
+    let (control_tx, forwarded_rx) = mpsc::channel(1);
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                cmd = control_rx.recv() => {
+                    // The workflow dropping its receiver ends the run
+                    let Some(cmd) = cmd else { break };
+                    if control_tx.send(cmd).await.is_err() {
+                        break;
+                    }
+                }
+                lost = lease_lost_rx.recv() => match lost {
+                    Ok((lost_id, holder_pid)) if lost_id == session_id => {
+                        sender.send_output(format!(
+                            "[planning] PID {} is taking this session over, stopping at the next phase boundary",
+                            holder_pid
+                        ));
+                        sender.send_following(holder_pid);
+                        if control_tx.send(WorkflowCommand::Stop).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    // The tracker is gone with the run
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            }
+        }
+    });
+    forwarded_rx
+}
+
+/// Dispatches a domain command to the workflow actor with full error handling.
+///
+/// Handles all three response cases:
+/// - `Ok(Ok(_view))` - command succeeded, logs at Info level
+/// - `Ok(Err(e))` - command was rejected by actor, logs at Warn level
+/// - `Err(_)` - reply channel dropped, logs at Warn level
+pub(crate) async fn dispatch_domain_command(
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    cmd: DomainCommand,
+    session_logger: &SessionLogger,
+) {
+    if let Some(ref actor) = actor_ref {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        if let Err(e) =
+            actor.send_message(WorkflowMessage::Command(Box::new(cmd.clone()), reply_tx))
+        {
+            session_logger.log(
+                LogLevel::Warn,
+                LogCategory::Workflow,
+                &format!("Failed to dispatch command {:?}: {}", cmd, e),
+            );
+            return;
+        }
+        match reply_rx.await {
+            Ok(Ok(_view)) => {
+                session_logger.log(
+                    LogLevel::Info,
+                    LogCategory::Workflow,
+                    &format!("Command dispatched: {:?}", cmd),
+                );
+            }
+            Ok(Err(e)) => {
+                session_logger.log(
+                    LogLevel::Warn,
+                    LogCategory::Workflow,
+                    &format!("Command rejected: {:?}: {:?}", cmd, e),
+                );
+            }
+            Err(_) => {
+                session_logger.log(
+                    LogLevel::Warn,
+                    LogCategory::Workflow,
+                    &format!("Command reply channel dropped: {:?}", cmd),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/updates_tests.rs"]
+mod tests;
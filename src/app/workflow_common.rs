@@ -24,14 +24,15 @@ pub fn pre_create_session_folder_with_working_dir(
     // Create session_info.json for fast listing
     let default_wd = std::env::current_dir().unwrap_or_default();
     let wd = working_dir.unwrap_or(&default_wd);
-    let session_info = SessionInfo::new(
+    let mut session_info = SessionInfo::new(
         &workflow_id.to_string(),
         input.feature_name.as_str(),
         input.objective.as_str(),
         wd,
-        &format!("{:?}", Phase::Planning),
+        Phase::Planning,
         1, // Initial iteration
     );
+    session_info.issue = input.issue.as_ref().map(|issue| issue.link.clone());
     if let Err(e) = session_info.save(&workflow_id.to_string()) {
         // Log warning but don't fail - session_info is optional metadata
         eprintln!(
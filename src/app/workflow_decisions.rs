@@ -1,3 +1,4 @@
+use crate::domain::review::TriageDecision;
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::{SessionEventSender, UserApprovalResponse, WorkflowCommand};
 use anyhow::Result;
@@ -76,6 +77,8 @@ pub enum MaxIterationsDecision {
     Continue(u32),
     /// Restart with user-provided feedback
     RestartWithFeedback(String),
+    /// Ask the rejecting reviewers to reconsider with the user's counter-argument (planning only)
+    Appeal(String),
     /// Abort the workflow
     Abort,
     /// Workflow was stopped via control channel
@@ -151,6 +154,13 @@ pub async fn await_max_iterations_decision(
                         );
                         return Ok(MaxIterationsDecision::RestartWithFeedback(feedback));
                     }
+                    Some(UserApprovalResponse::Appeal(argument)) if phase == IterativePhase::Planning => {
+                        log_decision(
+                            session_logger,
+                            &format!("[{}] User appealed the rejection: {}", phase_name, argument),
+                        );
+                        return Ok(MaxIterationsDecision::Appeal(argument));
+                    }
                     Some(UserApprovalResponse::AbortWorkflow) => {
                         log_decision(
                             session_logger,
@@ -195,7 +205,9 @@ pub async fn wait_for_review_decision(
                 return match response {
                     Some(UserApprovalResponse::ReviewRetry) => ReviewDecision::Retry,
                     Some(UserApprovalResponse::ReviewContinue) => ReviewDecision::Continue,
-                    Some(UserApprovalResponse::Accept) | Some(UserApprovalResponse::Implement) => {
+                    Some(UserApprovalResponse::Accept)
+                    | Some(UserApprovalResponse::Implement)
+                    | Some(UserApprovalResponse::ApproveWithConditions { .. }) => {
                         log_decision(session_logger, "Received plan approval while awaiting review decision, treating as continue");
                         ReviewDecision::Continue
                     }
@@ -229,6 +241,14 @@ pub async fn wait_for_review_decision(
                         log_decision(session_logger, "Received workflow failure response while awaiting review decision, treating as continue");
                         ReviewDecision::Continue
                     }
+                    Some(UserApprovalResponse::FeedbackTriaged(_)) => {
+                        log_decision(session_logger, "Received FeedbackTriaged while awaiting review decision, treating as continue");
+                        ReviewDecision::Continue
+                    }
+                    Some(UserApprovalResponse::Appeal(_)) => {
+                        log_decision(session_logger, "Received Appeal while awaiting review decision, treating as continue");
+                        ReviewDecision::Continue
+                    }
                     None => {
                         log_decision(session_logger, "Review decision channel closed, treating as continue");
                         ReviewDecision::Continue
@@ -373,3 +393,40 @@ pub async fn wait_for_workflow_failure_decision(
         }
     }
 }
+
+/// Waits for the user's triage of `item_count` feedback items.
+/// Returns one decision per item, or None if the workflow was stopped.
+pub async fn wait_for_feedback_triage(
+    session_logger: &Arc<SessionLogger>,
+    approval_rx: &mut mpsc::Receiver<UserApprovalResponse>,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+    item_count: usize,
+) -> Option<Vec<TriageDecision>> {
+    loop {
+        tokio::select! {
+            Some(cmd) = control_rx.recv() => {
+                if matches!(cmd, WorkflowCommand::Stop) {
+                    log_decision(session_logger, "Stop command received during feedback triage");
+                    return None;
+                }
+            }
+            response = approval_rx.recv() => {
+                match response {
+                    Some(UserApprovalResponse::FeedbackTriaged(decisions)) if decisions.len() == item_count => {
+                        log_decision(session_logger, &format!("User triaged {} feedback item(s)", item_count));
+                        return Some(decisions);
+                    }
+                    Some(other) => {
+                        log_decision(session_logger, &format!("Ignoring unexpected response {:?} during feedback triage", other));
+                        continue;
+                    }
+                    None => {
+                        // Nobody can triage; keep the previous behavior of addressing everything
+                        log_decision(session_logger, "Approval channel closed during feedback triage - accepting all items");
+                        return Some(vec![TriageDecision::Accept; item_count]);
+                    }
+                }
+            }
+        }
+    }
+}
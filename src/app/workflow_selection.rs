@@ -29,22 +29,43 @@ impl WorkflowSelection {
         Ok(crate::planning_paths::state_dir(working_dir)?.join("workflow-selection.json"))
     }
 
-    /// Load the workflow selection for a working directory, or return default if not set.
+    /// Path of the selection used by working directories without their own:
+    /// `~/.planning-agent/workflow-selection.json`, written by the setup wizard.
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(crate::planning_paths::planning_agent_home_dir()?.join("workflow-selection.json"))
+    }
+
+    /// Load the workflow selection for a working directory, falling back to the
+    /// default selection and then to the built-in default.
     pub fn load(working_dir: &Path) -> Result<Self> {
         let path = Self::selection_path(working_dir)?;
+        let default_path = Self::default_path()?;
         if path.exists() {
-            let content = fs::read_to_string(&path).with_context(|| {
-                format!("Failed to read workflow selection: {}", path.display())
-            })?;
-            serde_json::from_str(&content).with_context(|| "Failed to parse workflow selection")
+            Self::read(&path)
+        } else if default_path.exists() {
+            Self::read(&default_path)
         } else {
             Ok(Self::default())
         }
     }
 
+    fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read workflow selection: {}", path.display()))?;
+        serde_json::from_str(&content).with_context(|| "Failed to parse workflow selection")
+    }
+
     /// Save the workflow selection atomically using write-then-rename pattern.
     pub fn save(&self, working_dir: &Path) -> Result<()> {
-        let path = Self::selection_path(working_dir)?;
+        self.write(&Self::selection_path(working_dir)?)
+    }
+
+    /// Save this as the selection for working directories without their own.
+    pub fn save_default(&self) -> Result<()> {
+        self.write(&Self::default_path()?)
+    }
+
+    fn write(&self, path: &Path) -> Result<()> {
         let temp_path = path.with_extension("json.tmp");
 
         // state_dir() already creates the directory, but ensure it exists
@@ -57,7 +78,7 @@ impl WorkflowSelection {
             .with_context(|| "Failed to serialize workflow selection")?;
         fs::write(&temp_path, &content)
             .with_context(|| format!("Failed to write temp file: {}", temp_path.display()))?;
-        fs::rename(&temp_path, &path)
+        fs::rename(&temp_path, path)
             .with_context(|| format!("Failed to rename to: {}", path.display()))?;
         Ok(())
     }
@@ -164,31 +185,290 @@ pub fn load_workflow_by_name(name: &str) -> Result<crate::config::WorkflowConfig
         "claude-only" => crate::config::WorkflowConfig::claude_only_config(),
         "codex-only" => crate::config::WorkflowConfig::codex_only_config(),
         "gemini-only" => crate::config::WorkflowConfig::gemini_only_config(),
-        _ => {
-            let workflows_directory = workflows_dir()?;
-            let yaml_path = workflows_directory.join(format!("{}.yaml", name));
-            let yml_path = workflows_directory.join(format!("{}.yml", name));
-
-            let path = if yaml_path.exists() {
-                yaml_path
-            } else if yml_path.exists() {
-                yml_path
-            } else {
-                anyhow::bail!(
-                    "Workflow '{}' not found in {}",
-                    name,
-                    workflows_directory.display()
-                );
-            };
-
-            crate::config::WorkflowConfig::load(&path)?
-        }
+        _ => crate::config::WorkflowConfig::load(&user_workflow_path(name)?)?,
     };
     // Set the workflow name so it can be persisted with snapshots
     config.name = name.to_string();
     Ok(config)
 }
 
+/// Names of the workflows compiled into the binary.
+pub const BUILTIN_WORKFLOWS: [&str; 4] = ["default", "claude-only", "codex-only", "gemini-only"];
+
+/// Returns the path of a user workflow: `~/.planning-agent/workflows/<name>.yaml` (or `.yml`).
+pub fn user_workflow_path(name: &str) -> Result<PathBuf> {
+    let workflows_directory = workflows_dir()?;
+    let yaml_path = workflows_directory.join(format!("{}.yaml", name));
+    let yml_path = workflows_directory.join(format!("{}.yml", name));
+
+    if yaml_path.exists() {
+        Ok(yaml_path)
+    } else if yml_path.exists() {
+        Ok(yml_path)
+    } else {
+        anyhow::bail!(
+            "Workflow '{}' not found in {}",
+            name,
+            workflows_directory.display()
+        );
+    }
+}
+
+/// Where the effective workflow config for a working directory comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowSource {
+    /// A built-in workflow (not editable in place)
+    BuiltIn(String),
+    /// A user workflow in `~/.planning-agent/workflows/`
+    UserFile(PathBuf),
+    /// `./workflow.yaml` in the working directory
+    ProjectFile(PathBuf),
+}
+
+/// Resolves the file behind the effective workflow, mirroring
+/// `load_workflow_from_selection`'s priority order.
+pub fn selected_workflow_source(working_dir: &Path) -> WorkflowSource {
+    if let Ok(selection) = WorkflowSelection::load(working_dir) {
+        if BUILTIN_WORKFLOWS.contains(&selection.workflow.as_str()) {
+            return WorkflowSource::BuiltIn(selection.workflow);
+        }
+        if let Ok(path) = user_workflow_path(&selection.workflow) {
+            if crate::config::WorkflowConfig::load(&path).is_ok() {
+                return WorkflowSource::UserFile(path);
+            }
+        }
+    }
+
+    let project_path = working_dir.join("workflow.yaml");
+    if project_path.exists() && crate::config::WorkflowConfig::load(&project_path).is_ok() {
+        return WorkflowSource::ProjectFile(project_path);
+    }
+    WorkflowSource::BuiltIn("claude-only".to_string())
+}
+
+/// Sets a single value in a workflow YAML file.
+///
+/// Only the lines of the edited key change, so comments and key order elsewhere
+/// in the file are kept. A file the in-place edit cannot handle (such as one
+/// with flow-style sections) is rewritten whole, which drops its comments, after
+/// the original is copied to `<name>.yaml.bak`. The new file is validated before
+/// it replaces the original, so an invalid edit leaves the file untouched.
+pub fn update_workflow_value(
+    path: &Path,
+    key_path: &[&str],
+    value: serde_yaml::Value,
+) -> Result<crate::config::WorkflowConfig> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read workflow: {}", path.display()))?;
+    let mut root: serde_yaml::Value = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse workflow: {}", path.display()))?;
+    set_yaml_path(&mut root, key_path, value.clone())?;
+
+    // The in-place edit is only used if it parses to exactly the intended document
+    let edited = edit_yaml_in_place(&content, key_path, &value).filter(|edited| {
+        serde_yaml::from_str::<serde_yaml::Value>(edited).ok() == Some(root.clone())
+    });
+    let content = match edited {
+        Some(edited) => edited,
+        None => {
+            let backup = path.with_extension("yaml.bak");
+            fs::copy(path, &backup)
+                .with_context(|| format!("Failed to back up workflow to {}", backup.display()))?;
+            serde_yaml::to_string(&root).context("Failed to serialize workflow")?
+        }
+    };
+
+    let temp_path = path.with_extension("yaml.tmp");
+    fs::write(&temp_path, content)
+        .with_context(|| format!("Failed to write temp file: {}", temp_path.display()))?;
+    let config = match crate::config::WorkflowConfig::load(&temp_path) {
+        Ok(config) => config,
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+    };
+    fs::rename(&temp_path, path)
+        .with_context(|| format!("Failed to rename to: {}", path.display()))?;
+    Ok(config)
+}
+
+/// Replaces the lines of `key_path` in block-style YAML `content`, or appends
+/// the key (and any missing parent sections) to the end of its section.
+///
+/// Returns None if the path runs into a flow-style or non-mapping section.
+fn edit_yaml_in_place(
+    content: &str,
+    key_path: &[&str],
+    value: &serde_yaml::Value,
+) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    // Lines of the section being searched, and the indent of the key that owns it
+    let (mut start, mut end, mut owner_indent) = (0, lines.len(), None::<usize>);
+    for (depth, key) in key_path.iter().enumerate() {
+        let is_last = depth + 1 == key_path.len();
+        // Keys of the section are indented like its first line
+        let indent = (start..end)
+            .find(|&i| is_yaml_content(&lines[i]))
+            .map(|i| yaml_indent(&lines[i]))
+            .unwrap_or_else(|| owner_indent.map_or(0, |owner| owner + 2));
+        let Some(at) = (start..end).find(|&i| {
+            is_yaml_content(&lines[i])
+                && yaml_indent(&lines[i]) == indent
+                && yaml_key_rest(&lines[i], key).is_some()
+        }) else {
+            // Append the rest of the path after the section's last content line
+            let insert_at = (start..end)
+                .rev()
+                .find(|&i| is_yaml_content(&lines[i]))
+                .map_or(start, |i| i + 1);
+            let mut added = Vec::new();
+            for (i, key) in key_path.iter().enumerate().skip(depth) {
+                let key_indent = indent + 2 * (i - depth);
+                if i + 1 == key_path.len() {
+                    added.extend(yaml_key_lines(key_indent, key, value)?);
+                } else {
+                    added.push(format!("{}{}:", " ".repeat(key_indent), key));
+                }
+            }
+            lines.splice(insert_at..insert_at, added);
+            return Some(join_yaml_lines(lines, content));
+        };
+        let section_end = yaml_section_end(&lines, at, indent);
+        if is_last {
+            let replacement = yaml_key_lines(indent, key, value)?;
+            lines.splice(at..section_end, replacement);
+            return Some(join_yaml_lines(lines, content));
+        }
+        let rest = yaml_key_rest(&lines[at], key)?;
+        if !(rest.is_empty() || rest.starts_with('#')) {
+            return None;
+        }
+        (start, end, owner_indent) = (at + 1, section_end, Some(indent));
+    }
+    None
+}
+
+fn yaml_indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Whether the line holds YAML rather than only whitespace or a comment.
+fn is_yaml_content(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !trimmed.is_empty() && !trimmed.starts_with('#')
+}
+
+/// The text after `key:` if the line defines `key`.
+fn yaml_key_rest<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = line.trim_start().strip_prefix(key)?.strip_prefix(':')?;
+    (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim())
+}
+
+/// End of the lines that belong to the key on line `at`: its nested lines,
+/// including a sequence written at the key's own indent.
+fn yaml_section_end(lines: &[String], at: usize, indent: usize) -> usize {
+    let mut end = at + 1;
+    for (i, line) in lines.iter().enumerate().skip(at + 1) {
+        if !is_yaml_content(line) {
+            continue;
+        }
+        let line_indent = yaml_indent(line);
+        if line_indent > indent || (line_indent == indent && line.trim_start().starts_with('-')) {
+            end = i + 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// `key: value` at `indent`, with collections and block scalars on the
+/// following lines.
+fn yaml_key_lines(indent: usize, key: &str, value: &serde_yaml::Value) -> Option<Vec<String>> {
+    let text = serde_yaml::to_string(value).ok()?;
+    let pad = " ".repeat(indent);
+    let is_block = match value {
+        serde_yaml::Value::Mapping(mapping) => !mapping.is_empty(),
+        serde_yaml::Value::Sequence(sequence) => !sequence.is_empty(),
+        _ => false,
+    };
+    if is_block {
+        let mut lines = vec![format!("{}{}:", pad, key)];
+        lines.extend(text.lines().map(|line| format!("{}  {}", pad, line)));
+        return Some(lines);
+    }
+    let mut text_lines = text.lines();
+    let mut lines = vec![format!("{}{}: {}", pad, key, text_lines.next()?)];
+    lines.extend(text_lines.map(|line| format!("{}{}", pad, line)));
+    Some(lines)
+}
+
+fn join_yaml_lines(lines: Vec<String>, original: &str) -> String {
+    let mut joined = lines.join("\n");
+    if original.ends_with('\n') || original.is_empty() {
+        joined.push('\n');
+    }
+    joined
+}
+
+/// Sets `value` at `key_path`, creating intermediate mappings as needed.
+fn set_yaml_path(
+    root: &mut serde_yaml::Value,
+    key_path: &[&str],
+    value: serde_yaml::Value,
+) -> Result<()> {
+    let Some((last, parents)) = key_path.split_last() else {
+        anyhow::bail!("Empty config key path");
+    };
+    let mut node = root;
+    for key in parents {
+        if node.is_null() {
+            *node = serde_yaml::Value::Mapping(Default::default());
+        }
+        let mapping = node
+            .as_mapping_mut()
+            .with_context(|| format!("Config key '{}' is not a section", key))?;
+        node = mapping
+            .entry(serde_yaml::Value::from(*key))
+            .or_insert(serde_yaml::Value::Null);
+    }
+    if node.is_null() {
+        *node = serde_yaml::Value::Mapping(Default::default());
+    }
+    node.as_mapping_mut()
+        .with_context(|| format!("Config key '{}' has no parent section", last))?
+        .insert(serde_yaml::Value::from(*last), value);
+    Ok(())
+}
+
+/// Copies a built-in workflow to `~/.planning-agent/workflows/<name>-custom.yaml`
+/// and selects the copy for the working directory so it can be edited.
+pub fn fork_builtin_workflow(
+    config: &crate::config::WorkflowConfig,
+    working_dir: &Path,
+) -> Result<PathBuf> {
+    let dir = workflows_dir()?;
+    let base = format!("{}-custom", config.name);
+    let mut name = base.clone();
+    let mut suffix = 2;
+    while dir.join(format!("{}.yaml", name)).exists() || dir.join(format!("{}.yml", name)).exists()
+    {
+        name = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+
+    let mut copy = config.clone();
+    copy.name = name.clone();
+    let path = dir.join(format!("{}.yaml", name));
+    let content = serde_yaml::to_string(&copy).context("Failed to serialize workflow")?;
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write workflow: {}", path.display()))?;
+
+    WorkflowSelection { workflow: name }.save(working_dir)?;
+    Ok(path)
+}
+
 #[cfg(test)]
 #[path = "tests/workflow_selection_tests.rs"]
 mod tests;
@@ -0,0 +1,187 @@
+//! Workspace trust for the implementation workflow.
+//!
+//! Implementation agents edit files and run arbitrary tool calls, so the first
+//! time implementation starts in a repository the user is asked whether to
+//! trust it. The answer is remembered per repository (worktrees share the
+//! answer of the repository they belong to) in
+//! `~/.planning-agent/trusted-workspaces.json`. In an untrusted repository every
+//! agent runs with `RESTRICTED_ALLOWED_TOOLS` and without its
+//! `--dangerously-*` permission bypass flags.
+
+use crate::config::WorkflowConfig;
+use crate::planning_paths;
+use crate::tui::{SessionEventSender, WorkflowCommand};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tokio::sync::mpsc;
+
+/// Tools implementation agents may use in an untrusted repository: file
+/// reads, searches and edits, but no shell commands or network access.
+pub const RESTRICTED_ALLOWED_TOOLS: &[&str] =
+    &["Read", "Glob", "Grep", "Edit", "MultiEdit", "Write"];
+
+/// Whether implementation agents run with their configured tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceTrust {
+    Trusted,
+    Restricted,
+}
+
+/// A pending trust prompt, carrying the channel used to answer it.
+#[derive(Debug, Clone)]
+pub struct WorkspaceTrustRequest {
+    /// The repository the decision is remembered for
+    pub workspace: PathBuf,
+    pub responder: mpsc::Sender<WorkspaceTrust>,
+}
+
+impl WorkspaceTrustRequest {
+    /// Sends the decision back to the waiting implementation workflow.
+    pub fn respond(&self, decision: WorkspaceTrust) {
+        // A full or closed channel means the workflow already stopped
+        let _ = self.responder.try_send(decision);
+    }
+}
+
+/// A remembered trust decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustEntry {
+    pub trust: WorkspaceTrust,
+    pub decided_at: DateTime<Utc>,
+}
+
+/// Trust decisions keyed by repository path.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrustStore {
+    #[serde(default)]
+    workspaces: BTreeMap<PathBuf, TrustEntry>,
+}
+
+impl TrustStore {
+    /// Loads decisions from `~/.planning-agent/trusted-workspaces.json` (empty if absent).
+    pub fn load() -> Result<Self> {
+        let path = planning_paths::trusted_workspaces_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content =
+            std::fs::read_to_string(&path).context("Failed to read trusted workspaces")?;
+        serde_json::from_str(&content).context("Failed to parse trusted workspaces")
+    }
+
+    /// Persists decisions to `~/.planning-agent/trusted-workspaces.json`.
+    pub fn save(&self) -> Result<()> {
+        let path = planning_paths::trusted_workspaces_path()?;
+        let content =
+            serde_json::to_string_pretty(self).context("Failed to serialize trusted workspaces")?;
+        std::fs::write(&path, content).context("Failed to write trusted workspaces")?;
+        Ok(())
+    }
+
+    pub fn get(&self, workspace: &Path) -> Option<WorkspaceTrust> {
+        self.workspaces.get(workspace).map(|entry| entry.trust)
+    }
+
+    pub fn set(&mut self, workspace: PathBuf, trust: WorkspaceTrust) {
+        let entry = TrustEntry {
+            trust,
+            decided_at: Utc::now(),
+        };
+        self.workspaces.insert(workspace, entry);
+    }
+}
+
+/// The path a directory's trust decision is stored under: the main checkout of
+/// its git repository, so session worktrees share it, or the directory itself.
+pub fn workspace_key(working_dir: &Path) -> PathBuf {
+    let common_dir = Command::new("git")
+        .args(["rev-parse", "--path-format=absolute", "--git-common-dir"])
+        .current_dir(working_dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+    let dir = match common_dir {
+        Some(git_dir) if git_dir.ends_with(".git") => {
+            git_dir.parent().map(Path::to_path_buf).unwrap_or(git_dir)
+        }
+        _ => working_dir.to_path_buf(),
+    };
+    std::fs::canonicalize(&dir).unwrap_or(dir)
+}
+
+/// Whether the user has trusted the repository the working directory belongs to.
+pub fn is_workspace_trusted(working_dir: &Path) -> bool {
+    TrustStore::load()
+        .map(|store| store.get(&workspace_key(working_dir)) == Some(WorkspaceTrust::Trusted))
+        .unwrap_or(false)
+}
+
+/// Returns a copy of the config whose agents run with the restricted tool set.
+pub fn restrict_config(config: &WorkflowConfig) -> WorkflowConfig {
+    let mut restricted = config.clone();
+    for agent in restricted.agents.values_mut() {
+        agent.allowed_tools = RESTRICTED_ALLOWED_TOOLS
+            .iter()
+            .map(|tool| tool.to_string())
+            .collect();
+        agent.args.retain(|arg| !arg.starts_with("--dangerously-"));
+    }
+    restricted
+}
+
+/// Looks up the trust decision for the working directory, asking the user the
+/// first time. Returns None if the workflow is stopped while waiting.
+///
+/// A prompt nobody answers (headless runs, closed tabs) restricts this run
+/// without remembering a decision.
+pub async fn resolve_workspace_trust(
+    working_dir: &Path,
+    sender: &SessionEventSender,
+    control_rx: &mut mpsc::Receiver<WorkflowCommand>,
+) -> Result<Option<WorkspaceTrust>> {
+    let workspace = workspace_key(working_dir);
+    if let Some(trust) = TrustStore::load()?.get(&workspace) {
+        return Ok(Some(trust));
+    }
+
+    sender.send_output(format!(
+        "[implementation] First implementation run in {}, asking whether to trust it",
+        workspace.display()
+    ));
+    let (responder, mut rx) = mpsc::channel(1);
+    sender.send_workspace_trust_request(WorkspaceTrustRequest {
+        workspace: workspace.clone(),
+        responder,
+    });
+    let decision = loop {
+        tokio::select! {
+            Some(cmd) = control_rx.recv() => {
+                if matches!(cmd, WorkflowCommand::Stop) {
+                    return Ok(None);
+                }
+            }
+            decision = rx.recv() => break decision,
+        }
+    };
+
+    match decision {
+        Some(trust) => {
+            // Reloaded so decisions other sessions made meanwhile are kept
+            let mut store = TrustStore::load()?;
+            store.set(workspace, trust);
+            store.save()?;
+            Ok(Some(trust))
+        }
+        None => Ok(Some(WorkspaceTrust::Restricted)),
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/workspace_trust_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::config::AgentConfig;
+use crate::tui::Event;
+use tempfile::tempdir;
+
+fn agent(args: &[&str], allowed_tools: &[&str]) -> AgentConfig {
+    serde_yaml::from_str(&format!(
+        "command: claude\nargs: {:?}\nallowed_tools: {:?}\n",
+        args, allowed_tools
+    ))
+    .unwrap()
+}
+
+/// Spawns a TUI stand-in that answers trust prompts with `answer`, or leaves
+/// them unanswered when it is None.
+fn prompt_answerer(answer: Option<WorkspaceTrust>) -> SessionEventSender {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            if let Event::SessionWorkspaceTrustRequest { request, .. } = event {
+                if let Some(answer) = answer {
+                    request.respond(answer);
+                }
+            }
+        }
+    });
+    SessionEventSender::new(0, 0, tx)
+}
+
+#[test]
+fn test_restrict_config_limits_tools_and_drops_permission_bypass() {
+    let mut config = WorkflowConfig::default_config();
+    config.agents.insert(
+        "claude".to_string(),
+        agent(
+            &["-p", "--dangerously-skip-permissions", "--verbose"],
+            &["Read", "Bash"],
+        ),
+    );
+
+    let restricted = restrict_config(&config);
+    let claude = &restricted.agents["claude"];
+    assert_eq!(claude.allowed_tools, RESTRICTED_ALLOWED_TOOLS);
+    assert_eq!(claude.args, vec!["-p", "--verbose"]);
+    assert_eq!(config.agents["claude"].allowed_tools, vec!["Read", "Bash"]);
+}
+
+#[test]
+fn test_workspace_key_is_the_repository_root() {
+    let dir = tempdir().unwrap();
+    let plain = dir.path().join("plain");
+    std::fs::create_dir(&plain).unwrap();
+    assert_eq!(workspace_key(&plain), plain.canonicalize().unwrap());
+
+    let repo = dir.path().join("repo");
+    let nested = repo.join("src");
+    std::fs::create_dir_all(&nested).unwrap();
+    let init = Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(&repo)
+        .status()
+        .unwrap();
+    assert!(init.success());
+    assert_eq!(workspace_key(&nested), repo.canonicalize().unwrap());
+}
+
+#[tokio::test]
+async fn test_first_run_prompts_and_remembers_the_answer() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let repo = tempdir().unwrap();
+    let (_control_tx, mut control_rx) = mpsc::channel(1);
+    assert!(!is_workspace_trusted(repo.path()));
+
+    let sender = prompt_answerer(Some(WorkspaceTrust::Trusted));
+    let trust = resolve_workspace_trust(repo.path(), &sender, &mut control_rx)
+        .await
+        .unwrap();
+    assert_eq!(trust, Some(WorkspaceTrust::Trusted));
+    assert!(is_workspace_trusted(repo.path()));
+
+    // Remembered: a prompt nobody answers is not shown again
+    let sender = prompt_answerer(None);
+    let trust = resolve_workspace_trust(repo.path(), &sender, &mut control_rx)
+        .await
+        .unwrap();
+    assert_eq!(trust, Some(WorkspaceTrust::Trusted));
+}
+
+#[tokio::test]
+async fn test_unanswered_prompt_restricts_without_remembering() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let repo = tempdir().unwrap();
+    let (_control_tx, mut control_rx) = mpsc::channel(1);
+
+    let sender = prompt_answerer(None);
+    let trust = resolve_workspace_trust(repo.path(), &sender, &mut control_rx)
+        .await
+        .unwrap();
+    assert_eq!(trust, Some(WorkspaceTrust::Restricted));
+    assert_eq!(
+        TrustStore::load().unwrap().get(&workspace_key(repo.path())),
+        None
+    );
+}
@@ -0,0 +1,363 @@
+//! Attaches failing CI logs to a planning session.
+//!
+//! `planning --attach-ci-run <URL-or-ID>` downloads the logs of the failed jobs
+//! in a GitHub Actions run, keeps only the sections around error lines, and saves
+//! the excerpts to the session so the planning prompt can include them. Bare run
+//! IDs are resolved against the `origin` remote of the working directory.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+const API_TIMEOUT: Duration = Duration::from_secs(30);
+const GITHUB_API: &str = "https://api.github.com";
+
+/// Lines kept before and after each error line.
+const CONTEXT_BEFORE: usize = 5;
+const CONTEXT_AFTER: usize = 10;
+/// Upper bound on excerpt lines per job, so one noisy job cannot flood the prompt.
+const MAX_EXCERPT_LINES: usize = 200;
+/// Lines kept from the end of a log that has no recognizable error lines.
+const TAIL_LINES: usize = 50;
+
+/// A GitHub Actions run, optionally narrowed to one job.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CiRunRef {
+    pub owner: String,
+    pub repo: String,
+    pub run_id: u64,
+    pub job_id: Option<u64>,
+}
+
+impl CiRunRef {
+    pub fn url(&self) -> String {
+        format!(
+            "https://github.com/{}/{}/actions/runs/{}",
+            self.owner, self.repo, self.run_id
+        )
+    }
+}
+
+/// A failed job and the trimmed part of its log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedJob {
+    pub name: String,
+    pub failed_steps: Vec<String>,
+    pub excerpt: String,
+}
+
+/// The failed jobs of a CI run.
+#[derive(Debug, Clone)]
+pub struct CiFailure {
+    pub run: CiRunRef,
+    pub jobs: Vec<FailedJob>,
+}
+
+impl CiFailure {
+    /// Objective used when no objective text is given on the command line.
+    pub fn default_objective(&self) -> String {
+        format!("Fix the failing CI run {}", self.run.url())
+    }
+
+    /// Renders the excerpts as markdown for the session's context attachment.
+    pub fn context_markdown(&self) -> String {
+        let mut out = format!("# Failing CI run\n\nSource: {}\n", self.run.url());
+        for job in &self.jobs {
+            out.push_str(&format!("\n## {}\n\n", job.name));
+            if !job.failed_steps.is_empty() {
+                out.push_str(&format!(
+                    "Failed steps: {}\n\n",
+                    job.failed_steps.join(", ")
+                ));
+            }
+            out.push_str("```\n");
+            out.push_str(job.excerpt.trim_end());
+            out.push_str("\n```\n");
+        }
+        out
+    }
+}
+
+/// Parses a run URL (`https://github.com/<owner>/<repo>/actions/runs/<id>[/job/<id>]`)
+/// or a bare run ID. Bare IDs take the repository from the `origin` remote.
+pub fn parse_run_ref(reference: &str, working_dir: &Path) -> Result<CiRunRef> {
+    let reference = reference.trim();
+    if let Ok(run_id) = reference.parse::<u64>() {
+        let remote = origin_remote(working_dir)?;
+        let (owner, repo) = parse_github_remote(&remote).with_context(|| {
+            format!(
+                "Remote '{}' is not a GitHub repository; pass the full run URL",
+                remote
+            )
+        })?;
+        return Ok(CiRunRef {
+            owner,
+            repo,
+            run_id,
+            job_id: None,
+        });
+    }
+    parse_run_url(reference).with_context(|| {
+        format!(
+            "Invalid CI run '{}': expected a GitHub Actions run URL or run ID",
+            reference
+        )
+    })
+}
+
+fn parse_run_url(url: &str) -> Option<CiRunRef> {
+    let path = url
+        .strip_prefix("https://github.com/")
+        .or_else(|| url.strip_prefix("http://github.com/"))
+        .or_else(|| url.strip_prefix("github.com/"))?;
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let parts: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    match parts.as_slice() {
+        [owner, repo, "actions", "runs", run_id, rest @ ..] => {
+            let job_id = match rest {
+                ["job", job_id, ..] => Some(job_id.parse().ok()?),
+                _ => None,
+            };
+            Some(CiRunRef {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                run_id: run_id.parse().ok()?,
+                job_id,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Extracts `(owner, repo)` from an HTTPS or SSH GitHub remote URL.
+fn parse_github_remote(remote: &str) -> Option<(String, String)> {
+    let remote = remote.trim();
+    let path = remote
+        .strip_prefix("git@github.com:")
+        .or_else(|| remote.strip_prefix("ssh://git@github.com/"))
+        .or_else(|| remote.strip_prefix("https://github.com/"))
+        .or_else(|| remote.strip_prefix("http://github.com/"))?;
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+fn origin_remote(working_dir: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .current_dir(working_dir)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "No 'origin' remote in {}; pass the full run URL",
+            working_dir.display()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns `$GITHUB_TOKEN`, `$GH_TOKEN`, or the token of a logged-in `gh` CLI.
+fn resolve_token() -> Option<String> {
+    ["GITHUB_TOKEN", "GH_TOKEN"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|t| !t.is_empty())
+        .or_else(|| {
+            let output = Command::new("gh").args(["auth", "token"]).output().ok()?;
+            let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (output.status.success() && !token.is_empty()).then_some(token)
+        })
+}
+
+/// Downloads and trims the logs of the failed jobs in a run. Blocking.
+pub fn fetch_ci_failure(reference: &str, working_dir: &Path) -> Result<CiFailure> {
+    let run = parse_run_ref(reference, working_dir)?;
+    let token = resolve_token();
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(API_TIMEOUT))
+        .build()
+        .into();
+    let get = |url: &str| -> Result<String> {
+        let mut request = agent
+            .get(url)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "planning-agent");
+        if let Some(token) = &token {
+            request = request.header("Authorization", &format!("Bearer {}", token));
+        }
+        request
+            .call()
+            .with_context(|| {
+                format!(
+                    "GitHub request failed: {} (set GITHUB_TOKEN for private repositories)",
+                    url
+                )
+            })?
+            .body_mut()
+            .read_to_string()
+            .context("Failed to read GitHub response")
+    };
+
+    let body = get(&format!(
+        "{}/repos/{}/{}/actions/runs/{}/jobs?filter=latest&per_page=100",
+        GITHUB_API, run.owner, run.repo, run.run_id
+    ))?;
+    let json: Value = serde_json::from_str(&body).context("Failed to parse GitHub jobs")?;
+    let failed = parse_failed_jobs(&json, run.job_id);
+    if failed.is_empty() {
+        anyhow::bail!("CI run {} has no failed jobs", run.url());
+    }
+
+    let mut jobs = Vec::new();
+    for (job_id, name, failed_steps) in failed {
+        let log = get(&format!(
+            "{}/repos/{}/{}/actions/jobs/{}/logs",
+            GITHUB_API, run.owner, run.repo, job_id
+        ))
+        .with_context(|| format!("Failed to download logs for job '{}'", name))?;
+        jobs.push(FailedJob {
+            name,
+            failed_steps,
+            excerpt: trim_log(&log),
+        });
+    }
+    Ok(CiFailure { run, jobs })
+}
+
+/// Fetches CI logs on the blocking thread pool.
+pub async fn fetch_ci_failure_async(reference: String, working_dir: PathBuf) -> Result<CiFailure> {
+    tokio::task::spawn_blocking(move || fetch_ci_failure(&reference, &working_dir))
+        .await
+        .context("CI log fetch task panicked")?
+}
+
+/// Returns `(job id, job name, failed step names)` for each failed job in a
+/// GitHub "list jobs for a workflow run" response, limited to `only_job` if set.
+fn parse_failed_jobs(json: &Value, only_job: Option<u64>) -> Vec<(u64, String, Vec<String>)> {
+    let is_failure = |value: &Value| {
+        matches!(
+            value["conclusion"].as_str(),
+            Some("failure") | Some("timed_out")
+        )
+    };
+    json["jobs"]
+        .as_array()
+        .map(|jobs| {
+            jobs.iter()
+                .filter(|job| is_failure(job))
+                .filter_map(|job| {
+                    let id = job["id"].as_u64()?;
+                    if only_job.is_some_and(|only| only != id) {
+                        return None;
+                    }
+                    let steps = job["steps"]
+                        .as_array()
+                        .map(|steps| {
+                            steps
+                                .iter()
+                                .filter(|step| is_failure(step))
+                                .filter_map(|step| step["name"].as_str().map(str::to_string))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    Some((id, job["name"].as_str().unwrap_or("job").to_string(), steps))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Keeps the lines around each error line, merging overlapping windows. Logs
+/// without error lines keep their tail, where the failing step usually ends.
+pub fn trim_log(log: &str) -> String {
+    let lines: Vec<String> = log.lines().map(clean_line).collect();
+    let hits: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| is_error_line(line))
+        .map(|(i, _)| i)
+        .collect();
+
+    if hits.is_empty() {
+        let start = lines.len().saturating_sub(TAIL_LINES);
+        let mut out = lines[start..].join("\n");
+        if start > 0 {
+            out.insert_str(0, "...\n");
+        }
+        return out;
+    }
+
+    // Half-open [start, end) windows, merged when they touch
+    let mut windows: Vec<(usize, usize)> = Vec::new();
+    for hit in hits {
+        let start = hit.saturating_sub(CONTEXT_BEFORE);
+        let end = (hit + CONTEXT_AFTER + 1).min(lines.len());
+        match windows.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => windows.push((start, end)),
+        }
+    }
+
+    let mut out: Vec<&str> = Vec::new();
+    let mut kept = 0;
+    for (start, end) in windows {
+        if kept >= MAX_EXCERPT_LINES {
+            out.push("... (further errors omitted)");
+            break;
+        }
+        if start > 0 {
+            out.push("...");
+        }
+        let end = end.min(start + MAX_EXCERPT_LINES - kept);
+        out.extend(lines[start..end].iter().map(String::as_str));
+        kept += end - start;
+    }
+    out.join("\n")
+}
+
+fn is_error_line(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    ["error", "failed", "failure", "panicked"]
+        .iter()
+        .any(|word| lower.contains(word))
+}
+
+/// Drops the leading timestamp GitHub adds to every log line, and ANSI colors.
+fn clean_line(line: &str) -> String {
+    let line = match line.split_once(' ') {
+        Some((stamp, rest))
+            if stamp.len() >= 20
+                && stamp.ends_with('Z')
+                && stamp.starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+            rest
+        }
+        _ => line,
+    };
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip the CSI sequence up to its final letter
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "tests/ci_logs_tests.rs"]
+mod tests;
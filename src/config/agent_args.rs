@@ -0,0 +1,76 @@
+//! Per-run agent CLI arguments from `--agent-arg` and `/agent-arg`.
+
+use anyhow::{Context, Result};
+
+/// Extra CLI arguments for one agent in a single run, written `AGENT=ARGS`
+/// (`--agent-arg claude="--model opus-4"` or `/agent-arg` before starting).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentArgs {
+    pub agent: String,
+    pub args: Vec<String>,
+}
+
+impl AgentArgs {
+    /// Parses `AGENT=ARGS`, splitting ARGS on whitespace outside quotes.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (agent, args) = spec
+            .split_once('=')
+            .context("expected AGENT=ARGS, e.g. claude=\"--model opus-4\"")?;
+        let agent = agent.trim();
+        if agent.is_empty() {
+            anyhow::bail!("missing agent name before '=' in '{}'", spec);
+        }
+        let args = split_args(args)?;
+        if args.is_empty() {
+            anyhow::bail!("no arguments given for agent '{}'", agent);
+        }
+        Ok(Self {
+            agent: agent.to_string(),
+            args,
+        })
+    }
+}
+
+impl std::fmt::Display for AgentArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.agent, self.args.join(" "))
+    }
+}
+
+/// Splits `input` into words like a POSIX shell: single quotes are literal,
+/// double quotes and backslashes escape whitespace.
+fn split_args(input: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '\'' => {
+                let current = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("unterminated ' in '{}'", input),
+                    }
+                }
+            }
+            '"' => {
+                let current = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => current.extend(chars.next()),
+                        Some(c) => current.push(c),
+                        None => anyhow::bail!("unterminated \" in '{}'", input),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_with(String::new).extend(chars.next()),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
@@ -0,0 +1,935 @@
+mod agent_args;
+mod user_command;
+
+use crate::agents::context_limits::ContextLimitsConfig;
+use crate::agents::pricing::{ModelPricing, PricingTable};
+use crate::app::workflow::event_webhooks::EventWebhook;
+use crate::config_modes::{ClaudeModeConfig, CodexModeConfig, GeminiModeConfig};
+use crate::domain::failure::FailurePolicy;
+use crate::domain::review::SequentialExitPolicy;
+use crate::domain::types::{ApprovalGate, ResumeStrategy};
+use crate::git_worktree::SourceSyncMethod;
+use crate::issue_tracker::IssueTrackerConfig;
+use crate::tui::file_filter::FileFilterConfig;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+pub use agent_args::AgentArgs;
+pub use user_command::UserCommand;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkflowConfig {
+    /// Name of this workflow (e.g., "claude-only", "default", "my-custom").
+    /// Used to persist and restore the correct workflow across session resume.
+    #[serde(default)]
+    pub name: String,
+    pub agents: HashMap<String, AgentConfig>,
+    pub workflow: PhaseConfigs,
+    /// Failure handling policy for transient failures and recovery.
+    #[serde(default)]
+    pub failure_policy: FailurePolicy,
+    /// Optional implementation workflow configuration.
+    /// When present and enabled, allows JSON-mode implementation after plan approval.
+    #[serde(default)]
+    pub implementation: ImplementationConfig,
+    /// Claude-mode configuration for --claude flag transformation.
+    #[serde(default)]
+    pub claude_mode: ClaudeModeConfig,
+    /// Codex-mode configuration for codex-only workflow transformation.
+    #[serde(default)]
+    pub codex_mode: CodexModeConfig,
+    /// Gemini-mode configuration for gemini-only workflow transformation.
+    #[serde(default)]
+    pub gemini_mode: GeminiModeConfig,
+    /// Git worktree configuration for isolated branch work.
+    #[serde(default)]
+    pub worktree: WorktreeConfig,
+    /// Issue tracker access for `--from-issue`.
+    #[serde(default)]
+    pub issue_tracker: IssueTrackerConfig,
+    /// Size and binary limits for files offered by @-mention autocomplete.
+    #[serde(default)]
+    pub file_index: FileFilterConfig,
+    /// Per-model token rates used when an agent doesn't report its cost.
+    /// Entries override or extend the built-in table, keyed by model name prefix.
+    #[serde(default)]
+    pub pricing: BTreeMap<String, ModelPricing>,
+    /// Actions run in order after the workflow is accepted.
+    #[serde(default)]
+    pub completion_actions: Vec<CompletionAction>,
+    /// Webhooks receiving the workflow's events as they are recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_webhooks: Vec<EventWebhook>,
+    /// Time limit for each planning, reviewing and revising phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_phase_duration: Option<PhaseDurationLimit>,
+    /// Named reviewer panels selectable with `--reviewers` or `/reviewers`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub reviewer_presets: BTreeMap<String, ReviewerPreset>,
+    /// Maximum simultaneous invocations per provider (`claude`, `codex`, `gemini`),
+    /// shared by every tab. Providers without an entry are not limited.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub provider_concurrency: BTreeMap<String, usize>,
+    /// Soft cost limits in USD for a single round of a phase, keyed by phase
+    /// (`planning`, `reviewing`, `revising`, `implementation`,
+    /// `implementation_review`). Passing one only warns; the workflow keeps running.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub cost_alerts: BTreeMap<String, f64>,
+    /// Context window warnings, and what to do with a prompt that does not fit.
+    #[serde(default, skip_serializing_if = "ContextLimitsConfig::is_default")]
+    pub context_limits: ContextLimitsConfig,
+    /// Sections each plan must have before it goes to review.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_structure: Option<PlanStructurePolicy>,
+    /// Shell snippets run from a session's command prompt, keyed by command name
+    /// without the leading `/`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub slash_commands: BTreeMap<String, UserCommand>,
+    /// Natural language plans, reviews and summaries are written in (e.g.
+    /// "German"); agents choose when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Seconds after the final plan decision during which `/undo` takes it back
+    /// before anything acts on it; 0 acts at once.
+    #[serde(default = "default_undo_grace_secs")]
+    pub undo_grace_secs: u64,
+    /// Extra points where the workflow waits for the user's approval.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approval_gates: Vec<ApprovalGate>,
+    /// Passes the session's `/notes` to the planner in planning and revision prompts.
+    #[serde(default)]
+    pub notes_in_prompts: bool,
+    /// Includes the workspace's `CLAUDE.md`, `AGENTS.md` and `.cursorrules` in the
+    /// planning and review prompts.
+    #[serde(default = "default_repo_instructions")]
+    pub repo_instructions: bool,
+    /// Secret patterns redacted from logs and prompts, on top of the built-in ones.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::agents::redaction::RedactionConfig::is_empty"
+    )]
+    pub redaction: crate::agents::redaction::RedactionConfig,
+}
+
+fn default_undo_grace_secs() -> u64 {
+    10
+}
+
+fn default_repo_instructions() -> bool {
+    true
+}
+
+/// Headings checked after planning. Missing ones get the planner a follow-up
+/// prompt before review starts.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PlanStructurePolicy {
+    /// Section names; a plan heading matches if it starts with the name,
+    /// ignoring case, numbering and punctuation.
+    #[serde(default = "default_required_plan_sections")]
+    pub required_sections: Vec<String>,
+    /// Follow-up prompts the planner gets before the plan goes to review anyway.
+    #[serde(default = "default_plan_structure_followups")]
+    pub max_followups: u32,
+    /// Require YAML front matter (title, risk, effort, components) at the top
+    /// of the plan, parsed into the workflow's plan metadata.
+    #[serde(default)]
+    pub front_matter: bool,
+}
+
+fn default_required_plan_sections() -> Vec<String> {
+    ["Goals", "Non-goals", "Risks", "Test plan"]
+        .map(String::from)
+        .to_vec()
+}
+
+fn default_plan_structure_followups() -> u32 {
+    1
+}
+
+/// How long a planning, reviewing or revising phase may run before it is flagged.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PhaseDurationLimit {
+    pub minutes: u64,
+    /// Stop the phase's agents at the limit and ask them to wrap up with their best
+    /// result so far, instead of only warning.
+    #[serde(default)]
+    pub auto_interrupt: bool,
+}
+
+impl PhaseDurationLimit {
+    pub fn duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.minutes * 60)
+    }
+}
+
+/// Configuration for the JSON-mode implementation workflow.
+/// All fields have defaults to ensure backward compatibility with existing configs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImplementationConfig {
+    /// Whether implementation is enabled. Default: true
+    /// Set to false for single-agent configs where implementation-review requires a distinct reviewer.
+    #[serde(default = "default_implementation_enabled")]
+    pub enabled: bool,
+    /// Maximum implementation/review iterations before stopping. Default: 3
+    #[serde(default = "default_max_implementation_iterations")]
+    pub max_iterations: u32,
+    /// Configuration for the implementing phase agent.
+    /// Defaults to workflow.planning agent with max_turns: 100.
+    #[serde(default)]
+    pub implementing: Option<SingleAgentPhase>,
+    /// Configuration for the implementation-review phase agent.
+    /// Defaults to the first workflow.reviewing agent that differs from implementing agent.
+    #[serde(default)]
+    pub reviewing: Option<SingleAgentPhase>,
+}
+
+fn default_implementation_enabled() -> bool {
+    true
+}
+
+fn default_max_implementation_iterations() -> u32 {
+    3
+}
+
+fn default_implementation_max_turns() -> u32 {
+    100
+}
+
+impl Default for ImplementationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_implementation_enabled(),
+            max_iterations: default_max_implementation_iterations(),
+            implementing: None,
+            reviewing: None,
+        }
+    }
+}
+
+/// Configuration for git worktree feature.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct WorktreeConfig {
+    /// Whether to create a git worktree for the session.
+    /// When enabled, creates an isolated branch for planning work.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Bring the worktree branch up to date with its source branch before
+    /// `/merge-worktree` (`rebase` or `merge`). Unset skips the pre-merge check.
+    #[serde(default)]
+    pub sync_before_merge: Option<SourceSyncMethod>,
+}
+
+/// An action run after the workflow is accepted. A failed action is recorded and
+/// the remaining actions still run.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CompletionAction {
+    /// Copy the plan to `<dest>/<feature-name>.md`; `dest` is relative to the working directory.
+    CopyPlan { dest: PathBuf },
+    /// Run a formatter over the files written by earlier actions (passed as arguments).
+    Format { command: String },
+    /// Run a shell command with the session details in `PLANNING_*` environment variables.
+    Shell { command: String },
+    /// POST a Slack-compatible `{"text": ...}` message to a webhook.
+    Notify {
+        webhook: String,
+        /// Message text; `{feature}`, `{title}` and `{risk}` are replaced with the
+        /// feature name and the plan's front matter.
+        #[serde(default)]
+        message: Option<String>,
+    },
+}
+
+impl CompletionAction {
+    /// Short description for the completion summary.
+    pub fn label(&self) -> String {
+        match self {
+            CompletionAction::CopyPlan { dest } => format!("copy plan to {}", dest.display()),
+            CompletionAction::Format { command } => format!("format: {}", command),
+            CompletionAction::Shell { command } => format!("shell: {}", command),
+            CompletionAction::Notify { .. } => "notify webhook".to_string(),
+        }
+    }
+}
+
+impl ImplementationConfig {
+    /// Normalizes the implementation config by filling in defaults from the workflow config.
+    /// Returns an error if enabled but no valid reviewer can be determined.
+    pub fn normalize(&mut self, workflow: &PhaseConfigs) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Default implementing to workflow.planning with max_turns: 100
+        if self.implementing.is_none() {
+            self.implementing = Some(SingleAgentPhase {
+                agent: workflow.planning.agent.clone(),
+                max_turns: Some(default_implementation_max_turns()),
+            });
+        }
+
+        // Default reviewing to first enabled workflow.reviewing agent that differs from implementing
+        if self.reviewing.is_none() {
+            let implementing_agent = self
+                .implementing
+                .as_ref()
+                .map(|p| p.agent.as_str())
+                .unwrap_or("");
+
+            // Find first reviewer that differs from the implementing agent
+            let enabled = workflow.reviewing.enabled_agents();
+            let reviewer = enabled
+                .iter()
+                .map(|r| r.agent_name())
+                .find(|name| *name != implementing_agent);
+
+            if let Some(reviewer_name) = reviewer {
+                self.reviewing = Some(SingleAgentPhase {
+                    agent: reviewer_name.to_string(),
+                    max_turns: None, // Use agent default
+                });
+            } else if enabled.len() == 1 && enabled[0].agent_name() != implementing_agent {
+                // Single reviewer that is different from implementing agent
+                self.reviewing = Some(SingleAgentPhase {
+                    agent: enabled[0].agent_name().to_string(),
+                    max_turns: None,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the implementing agent name, or None if not configured.
+    pub fn implementing_agent(&self) -> Option<&str> {
+        self.implementing.as_ref().map(|p| p.agent.as_str())
+    }
+
+    /// Returns the reviewing agent name, or None if not configured.
+    pub fn reviewing_agent(&self) -> Option<&str> {
+        self.reviewing.as_ref().map(|p| p.agent.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SessionPersistenceConfig {
+    #[serde(default = "default_session_persistence_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_session_persistence_strategy")]
+    pub strategy: ResumeStrategy,
+}
+
+fn default_session_persistence_enabled() -> bool {
+    true
+}
+
+fn default_session_persistence_strategy() -> ResumeStrategy {
+    ResumeStrategy::ConversationResume
+}
+
+impl Default for SessionPersistenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_session_persistence_enabled(),
+            strategy: default_session_persistence_strategy(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AgentConfig {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub allowed_tools: Vec<String>,
+    #[serde(default)]
+    pub session_persistence: SessionPersistenceConfig,
+    /// Ask about high-risk tool calls (file deletion, `git push`, network). Local
+    /// Claude is asked before the call runs; other agents are paused once it started.
+    #[serde(default)]
+    pub permission_prompts: bool,
+    /// Where the agent CLI runs: omitted or `local` for this machine, or
+    /// `ssh://user@host[:port]` to run it on a remote machine over SSH.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution: Option<String>,
+    /// Local path prefixes and the remote paths they map to (remote execution only).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub path_map: BTreeMap<PathBuf, PathBuf>,
+    /// Keep the agent CLI running between invocations of a session and send
+    /// follow-up prompts over stdin instead of respawning it (claude only)
+    #[serde(default)]
+    pub warm_pool: bool,
+    /// Kill and retry an invocation whose process tree's resident memory passes this many MB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit_mb: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PhaseConfigs {
+    pub planning: SingleAgentPhase,
+    pub reviewing: MultiAgentPhase,
+    // Note: `revising` field was removed - revision now uses the planning agent
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SingleAgentPhase {
+    pub agent: String,
+    #[serde(default)]
+    pub max_turns: Option<u32>,
+}
+
+/// A reference to an agent instance, supporting both simple string references
+/// and extended configurations with custom prompts.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum AgentRef {
+    /// Simple string reference to a pre-defined agent
+    Simple(String),
+    /// Extended configuration with optional customization
+    Extended(AgentInstance),
+}
+
+/// Extended agent instance configuration
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct AgentInstance {
+    /// Name of the base agent (must exist in `agents` section)
+    pub agent: String,
+    /// Optional unique identifier for this instance (for logging/display)
+    /// Defaults to agent name if not specified
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Optional prompt text appended to the system prompt for this instance
+    #[serde(default)]
+    pub prompt: Option<String>,
+    /// Optional skill to invoke for this instance
+    /// For review agents, this specifies which plan-review skill to use
+    #[serde(default)]
+    pub skill: Option<String>,
+}
+
+impl AgentRef {
+    /// Returns the base agent name
+    pub fn agent_name(&self) -> &str {
+        match self {
+            AgentRef::Simple(name) => name,
+            AgentRef::Extended(inst) => &inst.agent,
+        }
+    }
+
+    /// Returns the display ID (instance id or agent name)
+    pub fn display_id(&self) -> &str {
+        match self {
+            AgentRef::Simple(name) => name,
+            AgentRef::Extended(inst) => inst.id.as_deref().unwrap_or(&inst.agent),
+        }
+    }
+
+    /// Returns the optional custom prompt
+    pub fn custom_prompt(&self) -> Option<&str> {
+        match self {
+            AgentRef::Simple(_) => None,
+            AgentRef::Extended(inst) => inst.prompt.as_deref(),
+        }
+    }
+
+    /// Returns the optional skill name for this instance
+    pub fn skill(&self) -> Option<&str> {
+        match self {
+            AgentRef::Simple(_) => None,
+            AgentRef::Extended(inst) => inst.skill.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MultiAgentPhase {
+    pub agents: Vec<AgentRef>,
+    #[serde(default)]
+    pub aggregation: AggregationMode,
+    /// If true, reviews without `<plan-feedback>` tags are treated as parse failures.
+    /// If false (default), raw output is used when tags are missing.
+    #[serde(default)]
+    pub require_plan_feedback_tags: bool,
+    /// If true, run reviewers sequentially with immediate revision on rejection.
+    /// When any reviewer rejects, the plan is revised and all reviewers must
+    /// re-review from the beginning. Default: false (parallel execution).
+    #[serde(default)]
+    pub sequential: bool,
+    /// With `sequential`, when a cycle stops for revision: `first_rejection` (default),
+    /// `run_all`, or a number of rejections. Recorded with the review cycle for resume.
+    #[serde(default)]
+    pub sequential_exit: SequentialExitPolicy,
+    /// Parallel rounds in a row that waited for `provider_concurrency` slots before
+    /// reviewing switches to sequential for the rest of the session. 0 (default): never.
+    #[serde(default)]
+    pub sequential_fallback_after: u32,
+    /// If true, a reviewer that approved is not re-run while the plan content is
+    /// unchanged; its approval is reused instead. Default: false.
+    #[serde(default)]
+    pub skip_unchanged: bool,
+    /// If true, a rejected round's feedback is split into items that the user
+    /// accepts, parks or dismisses before revising. Default: false.
+    #[serde(default)]
+    pub triage_feedback: bool,
+    /// If true, reviewers flag the plan sections their rejection concerns, and after
+    /// a revision re-review only the flagged sections that changed. Default: false.
+    #[serde(default)]
+    pub targeted_rereview: bool,
+    /// If true, the planner writes the plan section by section and reviewers review
+    /// each finished section while later ones are written. Parallel review only.
+    #[serde(default)]
+    pub pipelined: bool,
+    /// How rejections whose issues carry low confidence scores count toward aggregation.
+    #[serde(default)]
+    pub confidence: ConfidencePolicy,
+    /// Display IDs of reviewers that are configured but switched off.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+    /// Reviewers that stand in, in order, for a reviewer that keeps failing
+    /// (missing CLI, broken login) instead of failing the round.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_reviewers: Vec<AgentRef>,
+    /// Name of the reviewer preset that replaced `agents`, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+}
+
+impl MultiAgentPhase {
+    /// Returns the reviewers that are not disabled.
+    pub fn enabled_agents(&self) -> Vec<AgentRef> {
+        self.agents
+            .iter()
+            .filter(|r| !self.disabled.iter().any(|id| id == r.display_id()))
+            .cloned()
+            .collect()
+    }
+
+    /// Mutable access to the reviewers that are not disabled.
+    pub fn enabled_agents_mut(&mut self) -> impl Iterator<Item = &mut AgentRef> {
+        let disabled = &self.disabled;
+        self.agents
+            .iter_mut()
+            .filter(move |r| !disabled.iter().any(|id| id == r.display_id()))
+    }
+}
+
+/// A named reviewer panel that replaces `workflow.reviewing.agents` when selected.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReviewerPreset {
+    pub agents: Vec<AgentRef>,
+    /// Run this panel's reviewers sequentially. Default: false (parallel).
+    #[serde(default)]
+    pub sequential: bool,
+}
+
+/// Policy for rejections where every blocking issue has a low confidence score.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct ConfidencePolicy {
+    /// Issues scored below this confidence (0.0 to 1.0) are low-confidence. Default: 0.5.
+    #[serde(default = "default_low_confidence_threshold")]
+    pub threshold: f32,
+    /// Low-confidence rejections only count toward aggregation once at least this many
+    /// reviewers cast one in the same round. Default: 1 (each one counts).
+    #[serde(default = "default_low_confidence_rejections_required")]
+    pub low_confidence_rejections_required: usize,
+}
+
+fn default_low_confidence_threshold() -> f32 {
+    0.5
+}
+
+fn default_low_confidence_rejections_required() -> usize {
+    1
+}
+
+impl ConfidencePolicy {
+    /// Returns true if a lone low-confidence rejection does not count toward aggregation.
+    pub fn discounts_low_confidence(&self) -> bool {
+        self.low_confidence_rejections_required > 1
+    }
+}
+
+impl Default for ConfidencePolicy {
+    fn default() -> Self {
+        Self {
+            threshold: default_low_confidence_threshold(),
+            low_confidence_rejections_required: default_low_confidence_rejections_required(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationMode {
+    #[default]
+    AnyRejects,
+    AllReject,
+    Majority,
+}
+
+impl WorkflowConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        let mut config: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file as YAML: {}", path.display()))?;
+        // Normalize implementation config defaults before validation
+        config.implementation.normalize(&config.workflow)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn default_config() -> Self {
+        const DEFAULT_WORKFLOW_YAML: &str = include_str!("../workflow.yaml");
+
+        let mut config: Self = serde_yaml::from_str(DEFAULT_WORKFLOW_YAML).expect(
+            "Failed to parse embedded workflow.yaml - this is a bug in the workflow.yaml file",
+        );
+        // Normalize implementation config defaults
+        config
+            .implementation
+            .normalize(&config.workflow)
+            .expect("Failed to normalize implementation config - this is a bug");
+        config
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        // Validate all agent configs have non-empty commands
+        for (name, config) in &self.agents {
+            if config.command.trim().is_empty() {
+                anyhow::bail!(
+                    "Agent '{}' has an empty command. Each agent must specify a valid command.",
+                    name
+                );
+            }
+            if let Some(execution) = &config.execution {
+                crate::agents::remote::SshTarget::parse(execution)
+                    .with_context(|| format!("Agent '{}' has an invalid execution", name))?;
+            }
+            if config.warm_pool && config.command != "claude" {
+                anyhow::bail!(
+                    "Agent '{}' enables warm_pool, which is only supported for the claude CLI",
+                    name
+                );
+            }
+            if config.memory_limit_mb == Some(0) {
+                anyhow::bail!("Agent '{}' has a memory_limit_mb of 0", name);
+            }
+        }
+
+        if self
+            .language
+            .as_deref()
+            .is_some_and(|language| language.trim().is_empty())
+        {
+            anyhow::bail!("language must not be blank");
+        }
+
+        if self
+            .max_phase_duration
+            .is_some_and(|limit| limit.minutes == 0)
+        {
+            anyhow::bail!("max_phase_duration.minutes must be at least 1");
+        }
+
+        if let Some((provider, _)) = self
+            .provider_concurrency
+            .iter()
+            .find(|(_, limit)| **limit == 0)
+        {
+            anyhow::bail!("provider_concurrency.{} must be at least 1", provider);
+        }
+
+        for (phase, threshold) in &self.cost_alerts {
+            if !crate::agents::cost_stats::COST_ALERT_PHASES.contains(&phase.as_str()) {
+                anyhow::bail!(
+                    "cost_alerts.{} is not a phase; expected one of: {}",
+                    phase,
+                    crate::agents::cost_stats::COST_ALERT_PHASES.join(", ")
+                );
+            }
+            if !threshold.is_finite() || *threshold <= 0.0 {
+                anyhow::bail!("cost_alerts.{} must be a positive amount", phase);
+            }
+        }
+
+        self.context_limits.validate()?;
+
+        if let Some(policy) = &self.plan_structure {
+            if policy.required_sections.is_empty() {
+                anyhow::bail!("plan_structure.required_sections must name at least one section");
+            }
+            if policy
+                .required_sections
+                .iter()
+                .any(|section| !section.chars().any(char::is_alphanumeric))
+            {
+                anyhow::bail!("plan_structure.required_sections must not contain blank names");
+            }
+        }
+
+        for (name, command) in &self.slash_commands {
+            if name.is_empty() || name.starts_with('/') || name.contains(char::is_whitespace) {
+                anyhow::bail!(
+                    "slash_commands.{} must be a single word without the leading '/'",
+                    name
+                );
+            }
+            if command.run().trim().is_empty() {
+                anyhow::bail!("slash_commands.{} has an empty command", name);
+            }
+        }
+
+        // Validate max_turns is not zero (which would prevent any work)
+        if let Some(max_turns) = self.workflow.planning.max_turns {
+            if max_turns == 0 {
+                anyhow::bail!(
+                    "Planning phase has max_turns=0, which would prevent any work. \
+                     Either remove max_turns to use the default, or set a positive value."
+                );
+            }
+        }
+
+        if !self.agents.contains_key(&self.workflow.planning.agent) {
+            anyhow::bail!(
+                "Planning agent '{}' not found in agents configuration",
+                self.workflow.planning.agent
+            );
+        }
+
+        // Disabled reviewers never run, so only the enabled ones need an agent
+        for agent_ref in &self.workflow.reviewing.enabled_agents() {
+            let agent_name = agent_ref.agent_name();
+            if !self.agents.contains_key(agent_name) {
+                anyhow::bail!(
+                    "Review agent '{}' not found in agents configuration",
+                    agent_name
+                );
+            }
+        }
+
+        if self.workflow.reviewing.agents.is_empty() {
+            anyhow::bail!("At least one review agent must be configured");
+        }
+
+        let confidence = &self.workflow.reviewing.confidence;
+        if !(0.0..=1.0).contains(&confidence.threshold) {
+            anyhow::bail!(
+                "Review confidence threshold {} must be between 0.0 and 1.0",
+                confidence.threshold
+            );
+        }
+        if confidence.low_confidence_rejections_required == 0 {
+            anyhow::bail!("low_confidence_rejections_required must be at least 1");
+        }
+
+        if self.workflow.reviewing.enabled_agents().is_empty() {
+            anyhow::bail!("At least one review agent must be enabled");
+        }
+
+        // Check for duplicate display IDs in reviewers - each reviewer must write to a unique file
+        let enabled_reviewers = self.workflow.reviewing.enabled_agents();
+        let mut seen_ids = std::collections::HashSet::new();
+        for agent_ref in &enabled_reviewers {
+            let display_id = agent_ref.display_id();
+            if !seen_ids.insert(display_id) {
+                anyhow::bail!(
+                    "Duplicate reviewer display ID '{}' detected in workflow.reviewing.agents. \
+                     Each reviewer must have a unique 'id' field because feedback files are named \
+                     using the display ID (e.g., feedback_1_{}.md). Having duplicate IDs causes \
+                     one reviewer's feedback to overwrite another's.\n\n\
+                     Fix: Change one of the duplicate reviewers to use a distinct 'id', for example:\n\
+                     - agent: claude\n  id: {}-2  # or a descriptive name like '{}-completeness'\n  prompt: ...",
+                    display_id, display_id, display_id, display_id
+                );
+            }
+        }
+
+        for agent_ref in &self.workflow.reviewing.backup_reviewers {
+            if !self.agents.contains_key(agent_ref.agent_name()) {
+                anyhow::bail!(
+                    "Backup reviewer agent '{}' not found in agents configuration",
+                    agent_ref.agent_name()
+                );
+            }
+            // Backups write feedback files under their display ID like any reviewer
+            if !seen_ids.insert(agent_ref.display_id()) {
+                anyhow::bail!(
+                    "Backup reviewer display ID '{}' is already used by another reviewer",
+                    agent_ref.display_id()
+                );
+            }
+        }
+
+        for (name, preset) in &self.reviewer_presets {
+            if preset.agents.is_empty() {
+                anyhow::bail!("Reviewer preset '{}' has no agents", name);
+            }
+            let mut seen_ids = std::collections::HashSet::new();
+            for agent_ref in &preset.agents {
+                if !self.agents.contains_key(agent_ref.agent_name()) {
+                    anyhow::bail!(
+                        "Reviewer preset '{}' uses agent '{}', which is not in agents configuration",
+                        name,
+                        agent_ref.agent_name()
+                    );
+                }
+                if !seen_ids.insert(agent_ref.display_id()) {
+                    anyhow::bail!(
+                        "Reviewer preset '{}' has duplicate reviewer display ID '{}'",
+                        name,
+                        agent_ref.display_id()
+                    );
+                }
+            }
+        }
+
+        for action in &self.completion_actions {
+            let empty = match action {
+                CompletionAction::CopyPlan { dest } => dest.as_os_str().is_empty(),
+                CompletionAction::Format { command } | CompletionAction::Shell { command } => {
+                    command.trim().is_empty()
+                }
+                CompletionAction::Notify { webhook, .. } => webhook.trim().is_empty(),
+            };
+            if empty {
+                anyhow::bail!(
+                    "Completion action '{}' is missing its target",
+                    action.label()
+                );
+            }
+        }
+
+        for webhook in &self.event_webhooks {
+            webhook.validate()?;
+        }
+
+        // Validate failure policy
+        self.failure_policy.validate()?;
+
+        // Only validate implementation agents if implementation is enabled
+        if self.implementation.enabled {
+            // Ensure implementing agent exists
+            if let Some(ref implementing) = self.implementation.implementing {
+                if !self.agents.contains_key(&implementing.agent) {
+                    anyhow::bail!(
+                        "Implementation implementing agent '{}' not found in agents configuration",
+                        implementing.agent
+                    );
+                }
+            }
+
+            // Ensure reviewing agent exists
+            if let Some(ref reviewing) = self.implementation.reviewing {
+                if !self.agents.contains_key(&reviewing.agent) {
+                    anyhow::bail!(
+                        "Implementation reviewing agent '{}' not found in agents configuration",
+                        reviewing.agent
+                    );
+                }
+            }
+
+            // Ensure implementing and reviewing agents are different
+            let impl_agent = self.implementation.implementing_agent();
+            let review_agent = self.implementation.reviewing_agent();
+            if let (Some(impl_a), Some(rev_a)) = (impl_agent, review_agent) {
+                if impl_a == rev_a {
+                    anyhow::bail!(
+                        "Implementation review requires a different agent than the implementing agent. \
+                        Both are set to '{}'. Either configure a distinct reviewer or set implementation.enabled: false.",
+                        impl_a
+                    );
+                }
+            }
+
+            // If enabled but no reviewer could be determined, error
+            if self.implementation.reviewing.is_none() {
+                anyhow::bail!(
+                    "Implementation is enabled but no distinct reviewing agent could be determined. \
+                    Configure implementation.reviewing.agent explicitly or set implementation.enabled: false."
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_agent(&self, name: &str) -> Option<&AgentConfig> {
+        self.agents.get(name)
+    }
+
+    /// Replaces the review panel with the named preset's reviewers. Reviewers
+    /// the user disabled stay disabled if the preset has them too, unless that
+    /// would leave no reviewer enabled.
+    pub fn apply_reviewer_preset(&mut self, name: &str) -> Result<()> {
+        let Some(preset) = self.reviewer_presets.get(name) else {
+            let available: Vec<&str> = self.reviewer_presets.keys().map(String::as_str).collect();
+            anyhow::bail!(
+                "Unknown reviewer preset '{}' (available: {})",
+                name,
+                if available.is_empty() {
+                    "none configured".to_string()
+                } else {
+                    available.join(", ")
+                }
+            );
+        };
+        let reviewing = &mut self.workflow.reviewing;
+        reviewing.agents = preset.agents.clone();
+        reviewing.sequential = preset.sequential;
+        let agents = &reviewing.agents;
+        reviewing
+            .disabled
+            .retain(|id| agents.iter().any(|r| r.display_id() == id));
+        if reviewing.enabled_agents().is_empty() {
+            reviewing.disabled.clear();
+        }
+        reviewing.preset = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Built-in model rates with this workflow's `pricing` overrides applied.
+    pub fn pricing_table(&self) -> PricingTable {
+        PricingTable::with_overrides(&self.pricing)
+    }
+
+    /// Appends per-run extra arguments to the named agents' `args`. Fails
+    /// without changing anything if an agent isn't configured.
+    pub fn apply_agent_args(&mut self, extra: &[AgentArgs]) -> Result<()> {
+        if let Some(unknown) = extra.iter().find(|e| !self.agents.contains_key(&e.agent)) {
+            let mut available: Vec<&str> = self.agents.keys().map(String::as_str).collect();
+            available.sort_unstable();
+            anyhow::bail!(
+                "Unknown agent '{}' in --agent-arg (available: {})",
+                unknown.agent,
+                available.join(", ")
+            );
+        }
+        for entry in extra {
+            if let Some(agent) = self.agents.get_mut(&entry.agent) {
+                agent.args.extend(entry.args.iter().cloned());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/config_tests/config_tests.rs"]
+mod config_tests;
+
+#[cfg(test)]
+#[path = "../tests/config_tests/config_inline_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+#[path = "../tests/config_tests/worktree_config_tests.rs"]
+mod worktree_config_tests;
+
+#[cfg(test)]
+#[path = "../tests/config_tests/issue_tracker_config_tests.rs"]
+mod issue_tracker_config_tests;
@@ -0,0 +1,92 @@
+//! User-defined slash commands from the `slash_commands` config key.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// A slash command defined in `slash_commands`, either just its shell snippet or
+/// the snippet with options.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum UserCommand {
+    Run(String),
+    Full {
+        run: String,
+        /// Add the command's output to the session's next agent prompt.
+        #[serde(default)]
+        inject_output: bool,
+    },
+}
+
+impl UserCommand {
+    pub fn run(&self) -> &str {
+        match self {
+            UserCommand::Run(run) | UserCommand::Full { run, .. } => run,
+        }
+    }
+
+    pub fn inject_output(&self) -> bool {
+        matches!(
+            self,
+            UserCommand::Full {
+                inject_output: true,
+                ..
+            }
+        )
+    }
+
+    /// The `sh -c` script for this command given the arguments it was invoked with.
+    ///
+    /// Each distinct `{name}` placeholder takes one argument, in order of first
+    /// appearance. Arguments are passed to the script as positional parameters, so
+    /// the placeholders become `"$1"`, `"$2"`, ... and are never re-quoted. A
+    /// snippet without placeholders gets all arguments appended.
+    pub fn script(&self, args: &[String]) -> Result<String> {
+        let run = self.run();
+        let mut placeholders: Vec<&str> = Vec::new();
+        let mut script = String::with_capacity(run.len());
+        let mut rest = run;
+        while let Some(start) = rest.find('{') {
+            let name = rest[start + 1..].split_once('}').map(|(name, _)| name);
+            let Some(name) = name.filter(|name| {
+                !rest[..start].ends_with('$')
+                    && !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            }) else {
+                // Not a placeholder (e.g. `${VAR}` or a shell `{ ...; }` group)
+                script.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+                continue;
+            };
+            let position = match placeholders.iter().position(|p| *p == name) {
+                Some(i) => i + 1,
+                None => {
+                    placeholders.push(name);
+                    placeholders.len()
+                }
+            };
+            script.push_str(&rest[..start]);
+            script.push_str(&format!("\"${}\"", position));
+            rest = &rest[start + name.len() + 2..];
+        }
+        script.push_str(rest);
+
+        if placeholders.is_empty() {
+            script.push_str(" \"$@\"");
+        } else if args.len() < placeholders.len() {
+            anyhow::bail!("missing {{{}}}", placeholders[args.len()]);
+        } else if args.len() > placeholders.len() {
+            anyhow::bail!(
+                "takes {} argument(s): {}",
+                placeholders.len(),
+                placeholders
+                    .iter()
+                    .map(|p| format!("{{{}}}", p))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+        Ok(script)
+    }
+}
@@ -226,7 +226,14 @@ impl WorkflowConfig {
             self.workflow.reviewing = reviewing_override;
         } else {
             // Apply substitutions to reviewing agents
-            for agent_ref in &mut self.workflow.reviewing.agents {
+            for agent_ref in self.workflow.reviewing.enabled_agents_mut() {
+                apply_substitution_to_agent_ref(agent_ref, &substitutions);
+            }
+        }
+
+        // Reviewer presets get the same substitutions
+        for preset in self.reviewer_presets.values_mut() {
+            for agent_ref in &mut preset.agents {
                 apply_substitution_to_agent_ref(agent_ref, &substitutions);
             }
         }
@@ -309,7 +316,14 @@ impl WorkflowConfig {
             self.workflow.reviewing = reviewing_override;
         } else {
             // Apply substitutions to reviewing agents
-            for agent_ref in &mut self.workflow.reviewing.agents {
+            for agent_ref in self.workflow.reviewing.enabled_agents_mut() {
+                apply_substitution_to_agent_ref(agent_ref, &substitutions);
+            }
+        }
+
+        // Reviewer presets get the same substitutions
+        for preset in self.reviewer_presets.values_mut() {
+            for agent_ref in &mut preset.agents {
                 apply_substitution_to_agent_ref(agent_ref, &substitutions);
             }
         }
@@ -401,7 +415,14 @@ impl WorkflowConfig {
             self.workflow.reviewing = reviewing_override;
         } else {
             // Apply substitutions to reviewing agents
-            for agent_ref in &mut self.workflow.reviewing.agents {
+            for agent_ref in self.workflow.reviewing.enabled_agents_mut() {
+                apply_substitution_to_agent_ref(agent_ref, &substitutions);
+            }
+        }
+
+        // Reviewer presets get the same substitutions
+        for preset in self.reviewer_presets.values_mut() {
+            for agent_ref in &mut preset.agents {
                 apply_substitution_to_agent_ref(agent_ref, &substitutions);
             }
         }
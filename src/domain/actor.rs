@@ -9,7 +9,9 @@ use crate::domain::services::WorkflowServices;
 use crate::domain::view::{WorkflowEventEnvelope, WorkflowView};
 use crate::domain::WorkflowCommand;
 use crate::domain::WorkflowQuery;
-use crate::event_store::{FileEventStore, StoredEvent};
+use crate::event_store::integrity::repair_event_log;
+use crate::event_store::schema::decode_event;
+use crate::event_store::FileEventStore;
 use crate::planning_paths;
 use async_trait::async_trait;
 use cqrs_es::{AggregateError, CqrsFramework};
@@ -167,7 +169,10 @@ pub fn bootstrap_view_from_events(log_path: &PathBuf, aggregate_id: &str) -> Wor
     let mut skipped_lines = 0;
 
     for line in reader.lines().map_while(Result::ok) {
-        if let Ok(stored) = serde_json::from_str::<StoredEvent>(&line) {
+        let stored = serde_json::from_str(&line)
+            .ok()
+            .and_then(|value| decode_event(value).ok());
+        if let Some(stored) = stored {
             if stored.aggregate_id == aggregate_id {
                 view.apply_event(&stored.aggregate_id, &stored.event, stored.sequence);
             }
@@ -201,6 +206,13 @@ pub fn create_actor_args(
     let log_path = planning_paths::session_event_log_path(session_id)?;
     let snapshot_path = planning_paths::session_aggregate_snapshot_path(session_id)?;
 
+    // Cut a crash-truncated log back to its last valid event before replaying it
+    match repair_event_log(&log_path) {
+        Ok(Some(repair)) => tracing::warn!("{}", repair.describe()),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to verify event log: {}", e),
+    }
+
     // Bootstrap the view from existing events (if any)
     let initial_view = bootstrap_view_from_events(&log_path, session_id);
     let view = Arc::new(RwLock::new(initial_view.clone()));
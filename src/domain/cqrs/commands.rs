@@ -4,12 +4,15 @@
 //! and produces events that are persisted to the event log.
 
 use crate::domain::failure::FailureContext;
-use crate::domain::review::ReviewMode;
+use crate::domain::review::{ReviewMode, TriagedFeedback};
 use crate::domain::types::{
-    AgentId, ConversationId, FeatureName, FeedbackPath, ImplementationVerdict, Iteration,
-    MaxIterations, Objective, PhaseLabel, PlanPath, ResumeStrategy, WorkingDir, WorktreeState,
+    AgentId, ApprovalGate, CompletionActionOutcome, ConversationId, FeatureName, FeedbackPath,
+    GateStep, ImplementationVerdict, IssueLink, Iteration, MaxIterations, Objective, PhaseLabel,
+    PlanMetadata, PlanPath, PlanSectionReview, ResumeStrategy, UndoneDecision, WatchSpec,
+    WorkingDir, WorktreeState,
 };
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Commands that can be executed against the workflow aggregate.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +38,8 @@ pub enum WorkflowCommand {
     ReviewCycleStarted {
         mode: ReviewMode,
         reviewers: Vec<AgentId>,
+        /// Reviewer preset the panel came from, if one was selected.
+        preset: Option<String>,
     },
 
     /// Record reviewer approval.
@@ -44,11 +49,35 @@ pub enum WorkflowCommand {
     ReviewerRejected {
         reviewer_id: AgentId,
         feedback_path: FeedbackPath,
+        low_confidence: bool,
+    },
+
+    /// Record that a reviewer's earlier approval was reused for unchanged plan content.
+    ReviewerVerdictReused {
+        reviewer_id: AgentId,
+        plan_hash: u64,
+    },
+
+    /// Record that a reviewer cleared its rejection after the user's appeal.
+    /// `plan_approved` is set when no rejection of the round remains.
+    ReviewerVerdictRevised {
+        reviewer_id: AgentId,
+        plan_approved: bool,
+    },
+
+    /// Record that a backup reviewer stood in for a reviewer that kept failing.
+    RecordReviewerSubstituted {
+        failed_reviewer: AgentId,
+        substitute: AgentId,
+        reason: String,
     },
 
     /// Aggregate review result to move to Revising or Complete.
     ReviewCycleCompleted { approved: bool },
 
+    /// Record the user's triage of the rejected round's feedback items.
+    FeedbackTriaged { items: Vec<TriagedFeedback> },
+
     /// Begin revision work.
     /// When dispatched from AwaitingPlanningDecision, additional_iterations specifies
     /// how many more iterations to allow (default 1 if not specified).
@@ -68,6 +97,9 @@ pub enum WorkflowCommand {
     /// User approved plan.
     UserApproved,
 
+    /// User approved plan on conditions that later phases must satisfy.
+    UserApprovedWithConditions { conditions: String },
+
     /// User requested implementation workflow.
     /// This command emits both UserRequestedImplementation and ImplementationStarted events.
     UserRequestedImplementation,
@@ -75,6 +107,9 @@ pub enum WorkflowCommand {
     /// User declined with feedback.
     UserDeclined { feedback: String },
 
+    /// User took back their final plan decision before anything acted on it.
+    UndoUserDecision { decision: UndoneDecision },
+
     /// User aborted workflow.
     UserAborted { reason: String },
 
@@ -92,6 +127,8 @@ pub enum WorkflowCommand {
     ImplementationRoundCompleted {
         iteration: Iteration,
         fingerprint: u64,
+        /// Diff of the round's changes, as given to the reviewer
+        diff_path: Option<PathBuf>,
     },
 
     /// Implementation review completed.
@@ -136,4 +173,121 @@ pub enum WorkflowCommand {
 
     /// Persist worktree metadata.
     AttachWorktree { worktree_state: WorktreeState },
+
+    /// Link the workflow to the tracker issue its objective was imported from.
+    LinkIssue { issue: IssueLink },
+
+    /// Record that the user edited the plan file outside the workflow.
+    RecordPlanExternalModification { content_hash: String },
+
+    /// Record the outcome of a completion action run after the plan was accepted.
+    RecordCompletionAction { outcome: CompletionActionOutcome },
+
+    /// Record a check of the plan against the `plan_structure` policy.
+    RecordPlanStructureCheck {
+        missing_sections: Vec<String>,
+        followup_requested: bool,
+    },
+
+    /// Record the front matter of a structured plan.
+    RecordPlanMetadata { metadata: PlanMetadata },
+
+    /// Record that a phase ran past `max_phase_duration`.
+    RecordPhaseDurationExceeded {
+        phase: PhaseLabel,
+        limit_minutes: u64,
+        auto_interrupted: bool,
+    },
+
+    /// Give the workflow a new feature name (`/rename`).
+    RenameWorkflow { feature_name: FeatureName },
+
+    /// Adopt an existing plan as approved instead of running planning (`planning implement`).
+    ImportPlan { source: String },
+
+    /// Replace the objective and plan again from it (`/edit-objective`).
+    AmendObjective { objective: Objective },
+
+    /// Plan an accepted plan again from feedback (`--watch`).
+    ReopenPlanning { feedback: String },
+
+    /// Record a step of an `approval_gates` gate.
+    RecordApprovalGate { gate: ApprovalGate, step: GateStep },
+
+    /// Switch between parallel and sequential review for the rest of the session.
+    RecordReviewModeChange { sequential: bool, reason: String },
+
+    /// Record a plan section the planner finished while still writing (`reviewing.pipelined`).
+    RecordPlanSectionCompleted { section: String },
+
+    /// Record a reviewer's verdict on one finished plan section.
+    RecordPlanSectionReview { review: PlanSectionReview },
+
+    /// Queue the output of a user slash command for the next planner or implementer prompt.
+    QueueCommandOutput { output: String },
+
+    /// Record that the oldest `count` queued command outputs went into a prompt.
+    DeliverCommandOutput { count: usize, phase: PhaseLabel },
+
+    /// Watch files after the plan is accepted and re-plan when they change.
+    EnableReplanWatch { watch: WatchSpec },
+}
+
+impl WorkflowCommand {
+    /// Human-readable command name for error messages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateWorkflow { .. } => "CreateWorkflow",
+            Self::StartPlanning => "StartPlanning",
+            Self::PlanningCompleted { .. } => "PlanningCompleted",
+            Self::ReviewCycleStarted { .. } => "ReviewCycleStarted",
+            Self::ReviewerApproved { .. } => "ReviewerApproved",
+            Self::ReviewerRejected { .. } => "ReviewerRejected",
+            Self::ReviewerVerdictReused { .. } => "ReviewerVerdictReused",
+            Self::ReviewerVerdictRevised { .. } => "ReviewerVerdictRevised",
+            Self::RecordReviewerSubstituted { .. } => "RecordReviewerSubstituted",
+            Self::ReviewCycleCompleted { .. } => "ReviewCycleCompleted",
+            Self::FeedbackTriaged { .. } => "FeedbackTriaged",
+            Self::RevisingStarted { .. } => "RevisingStarted",
+            Self::RevisionCompleted { .. } => "RevisionCompleted",
+            Self::PlanningMaxIterationsReached => "PlanningMaxIterationsReached",
+            Self::UserApproved => "UserApproved",
+            Self::UserApprovedWithConditions { .. } => "UserApprovedWithConditions",
+            Self::UserRequestedImplementation => "UserRequestedImplementation",
+            Self::UserDeclined { .. } => "UserDeclined",
+            Self::UndoUserDecision { .. } => "UndoUserDecision",
+            Self::UserAborted { .. } => "UserAborted",
+            Self::UserOverrideApproval { .. } => "UserOverrideApproval",
+            Self::ImplementationStarted { .. } => "ImplementationStarted",
+            Self::ImplementationRoundStarted { .. } => "ImplementationRoundStarted",
+            Self::ImplementationRoundCompleted { .. } => "ImplementationRoundCompleted",
+            Self::ImplementationReviewCompleted { .. } => "ImplementationReviewCompleted",
+            Self::ImplementationMaxIterationsReached => "ImplementationMaxIterationsReached",
+            Self::ImplementationNoChanges { .. } => "ImplementationNoChanges",
+            Self::ImplementationAccepted => "ImplementationAccepted",
+            Self::ImplementationDeclined { .. } => "ImplementationDeclined",
+            Self::ImplementationCancelled { .. } => "ImplementationCancelled",
+            Self::RecordAgentConversation { .. } => "RecordAgentConversation",
+            Self::RecordInvocation { .. } => "RecordInvocation",
+            Self::RecordFailure { .. } => "RecordFailure",
+            Self::AttachWorktree { .. } => "AttachWorktree",
+            Self::LinkIssue { .. } => "LinkIssue",
+            Self::RecordPlanExternalModification { .. } => "RecordPlanExternalModification",
+            Self::RecordCompletionAction { .. } => "RecordCompletionAction",
+            Self::RecordPlanStructureCheck { .. } => "RecordPlanStructureCheck",
+            Self::RecordPlanMetadata { .. } => "RecordPlanMetadata",
+            Self::RecordPhaseDurationExceeded { .. } => "RecordPhaseDurationExceeded",
+            Self::RenameWorkflow { .. } => "RenameWorkflow",
+            Self::ImportPlan { .. } => "ImportPlan",
+            Self::AmendObjective { .. } => "AmendObjective",
+            Self::ReopenPlanning { .. } => "ReopenPlanning",
+            Self::RecordApprovalGate { .. } => "RecordApprovalGate",
+            Self::RecordReviewModeChange { .. } => "RecordReviewModeChange",
+            Self::RecordPlanSectionCompleted { .. } => "RecordPlanSectionCompleted",
+            Self::RecordPlanSectionReview { .. } => "RecordPlanSectionReview",
+            Self::QueueCommandOutput { .. } => "QueueCommandOutput",
+            Self::DeliverCommandOutput { .. } => "DeliverCommandOutput",
+            Self::EnableReplanWatch { .. } => "EnableReplanWatch",
+        }
+    }
 }
@@ -0,0 +1,190 @@
+//! Accessors for the aggregate's workflow data.
+
+use super::WorkflowData;
+use crate::domain::failure::{FailureContext, MAX_FAILURE_HISTORY};
+use crate::domain::review::ReviewMode;
+use crate::domain::types::{
+    AgentConversationState, AgentId, FeatureName, FeedbackPath, FeedbackStatus,
+    ImplementationPhaseState, InvocationRecord, Iteration, MaxIterations, Objective, Phase,
+    PlanPath, TimestampUtc, WorkingDir, WorktreeState,
+};
+use std::collections::HashMap;
+
+impl WorkflowData {
+    // ========== Public Getters ==========
+
+    /// Returns the feature name.
+    pub fn feature_name(&self) -> &FeatureName {
+        &self.feature_name
+    }
+
+    /// Returns the objective.
+    pub fn objective(&self) -> &Objective {
+        &self.objective
+    }
+
+    /// Returns the working directory.
+    pub fn working_dir(&self) -> &WorkingDir {
+        &self.working_dir
+    }
+
+    /// Returns the creation timestamp.
+    pub fn created_at(&self) -> &TimestampUtc {
+        &self.created_at
+    }
+
+    /// Returns the current planning phase.
+    pub fn planning_phase(&self) -> &Phase {
+        &self.planning_phase
+    }
+
+    /// Returns the current iteration.
+    pub fn iteration(&self) -> &Iteration {
+        &self.iteration
+    }
+
+    /// Returns the maximum iterations allowed.
+    pub fn max_iterations(&self) -> &MaxIterations {
+        &self.max_iterations
+    }
+
+    /// Returns the plan path.
+    pub fn plan_path(&self) -> &PlanPath {
+        &self.plan_path
+    }
+
+    /// Returns the feedback path.
+    pub fn feedback_path(&self) -> &FeedbackPath {
+        &self.feedback_path
+    }
+
+    /// Returns the last feedback status.
+    pub fn last_feedback_status(&self) -> Option<&FeedbackStatus> {
+        self.last_feedback_status.as_ref()
+    }
+
+    /// Returns the review mode.
+    pub fn review_mode(&self) -> Option<&ReviewMode> {
+        self.review_mode.as_ref()
+    }
+
+    /// Returns a mutable reference to the review mode.
+    pub fn review_mode_mut(&mut self) -> Option<&mut ReviewMode> {
+        self.review_mode.as_mut()
+    }
+
+    /// Returns whether approval was overridden.
+    pub fn approval_overridden(&self) -> bool {
+        self.approval_overridden
+    }
+
+    /// Returns the implementation state.
+    pub fn implementation_state(&self) -> Option<&ImplementationPhaseState> {
+        self.implementation_state.as_ref()
+    }
+
+    /// Returns a mutable reference to the implementation state.
+    pub fn implementation_state_mut(&mut self) -> Option<&mut ImplementationPhaseState> {
+        self.implementation_state.as_mut()
+    }
+
+    /// Returns the agent conversations map.
+    pub fn agent_conversations(&self) -> &HashMap<AgentId, AgentConversationState> {
+        &self.agent_conversations
+    }
+
+    /// Returns the invocations list.
+    pub fn invocations(&self) -> &[InvocationRecord] {
+        &self.invocations
+    }
+
+    /// Returns the last failure.
+    pub fn last_failure(&self) -> Option<&FailureContext> {
+        self.last_failure.as_ref()
+    }
+
+    /// Returns the failure history.
+    pub fn failure_history(&self) -> &[FailureContext] {
+        &self.failure_history
+    }
+
+    /// Returns the worktree info.
+    pub fn worktree_info(&self) -> Option<&WorktreeState> {
+        self.worktree_info.as_ref()
+    }
+
+    // ========== Crate-level Setters ==========
+
+    /// Sets the planning phase.
+    pub(crate) fn set_planning_phase(&mut self, phase: Phase) {
+        self.planning_phase = phase;
+    }
+
+    /// Sets the iteration.
+    pub(crate) fn set_iteration(&mut self, iteration: Iteration) {
+        self.iteration = iteration;
+    }
+
+    /// Sets the maximum iterations allowed.
+    pub(crate) fn set_max_iterations(&mut self, max: MaxIterations) {
+        self.max_iterations = max;
+    }
+
+    /// Sets the plan path.
+    pub(crate) fn set_plan_path(&mut self, path: PlanPath) {
+        self.plan_path = path;
+    }
+
+    /// Sets the last feedback status.
+    pub(crate) fn set_last_feedback_status(&mut self, status: Option<FeedbackStatus>) {
+        self.last_feedback_status = status;
+    }
+
+    /// Sets the review mode.
+    pub(crate) fn set_review_mode(&mut self, mode: Option<ReviewMode>) {
+        self.review_mode = mode;
+    }
+
+    /// Sets whether approval was overridden.
+    pub(crate) fn set_approval_overridden(&mut self, overridden: bool) {
+        self.approval_overridden = overridden;
+    }
+
+    /// Sets the implementation state.
+    pub(crate) fn set_implementation_state(&mut self, state: Option<ImplementationPhaseState>) {
+        self.implementation_state = state;
+    }
+
+    /// Inserts an agent conversation.
+    pub(crate) fn insert_agent_conversation(
+        &mut self,
+        agent_id: AgentId,
+        state: AgentConversationState,
+    ) {
+        self.agent_conversations.insert(agent_id, state);
+    }
+
+    /// Adds an invocation record.
+    pub(crate) fn push_invocation(&mut self, record: InvocationRecord) {
+        self.invocations.push(record);
+    }
+
+    /// Sets the last failure.
+    pub(crate) fn set_last_failure(&mut self, failure: Option<FailureContext>) {
+        self.last_failure = failure;
+    }
+
+    /// Adds a failure to history and trims if over limit.
+    pub(crate) fn push_failure_history(&mut self, failure: FailureContext) {
+        self.failure_history.push(failure);
+        if self.failure_history.len() > MAX_FAILURE_HISTORY {
+            let excess = self.failure_history.len() - MAX_FAILURE_HISTORY;
+            self.failure_history.drain(0..excess);
+        }
+    }
+
+    /// Sets the worktree info.
+    pub(crate) fn set_worktree_info(&mut self, info: Option<WorktreeState>) {
+        self.worktree_info = info;
+    }
+}
@@ -4,14 +4,16 @@
 //! for the workflow state and are persisted to the event log.
 
 use crate::domain::failure::FailureContext;
-use crate::domain::review::ReviewMode;
+use crate::domain::review::{ReviewMode, TriagedFeedback};
 use crate::domain::types::{
-    AgentId, ConversationId, FeatureName, FeedbackPath, ImplementationVerdict, Iteration,
-    MaxIterations, Objective, PhaseLabel, PlanPath, ResumeStrategy, TimestampUtc, WorkingDir,
+    AgentId, ApprovalGate, CompletionActionOutcome, ConversationId, FeatureName, FeedbackPath,
+    GateStep, ImplementationVerdict, IssueLink, Iteration, MaxIterations, Objective, PhaseLabel,
+    PlanMetadata, PlanPath, ResumeStrategy, TimestampUtc, UndoneDecision, WatchSpec, WorkingDir,
     WorktreeState,
 };
 use cqrs_es::DomainEvent;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Events emitted by the workflow aggregate.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -41,6 +43,8 @@ pub enum WorkflowEvent {
     ReviewCycleStarted {
         mode: ReviewMode,
         reviewers: Vec<AgentId>,
+        #[serde(default)]
+        preset: Option<String>,
         started_at: TimestampUtc,
     },
 
@@ -54,15 +58,48 @@ pub enum WorkflowEvent {
     ReviewerRejected {
         reviewer_id: AgentId,
         feedback_path: FeedbackPath,
+        /// All blocking issues scored below the confidence threshold.
+        #[serde(default)]
+        low_confidence: bool,
         rejected_at: TimestampUtc,
     },
 
+    /// Reviewer was skipped and its earlier approval reused (plan content unchanged).
+    ReviewerVerdictReused {
+        reviewer_id: AgentId,
+        plan_hash: u64,
+        reused_at: TimestampUtc,
+    },
+
+    /// Reviewer cleared its rejection after reconsidering the user's appeal.
+    ReviewerVerdictRevised {
+        reviewer_id: AgentId,
+        /// No rejection of the round remains, so the plan counts as approved.
+        plan_approved: bool,
+        revised_at: TimestampUtc,
+    },
+
+    /// A backup reviewer stood in for a reviewer that kept failing.
+    ReviewerSubstituted {
+        failed_reviewer: AgentId,
+        substitute: AgentId,
+        /// The failed reviewer's last error.
+        reason: String,
+        substituted_at: TimestampUtc,
+    },
+
     /// Review cycle completed.
     ReviewCycleCompleted {
         approved: bool,
         completed_at: TimestampUtc,
     },
 
+    /// User triaged the rejected round's feedback items before revising.
+    FeedbackTriaged {
+        items: Vec<TriagedFeedback>,
+        triaged_at: TimestampUtc,
+    },
+
     /// Revising phase started.
     RevisingStarted {
         feedback_summary: String,
@@ -87,6 +124,12 @@ pub enum WorkflowEvent {
     /// User approved the plan.
     UserApproved { approved_at: TimestampUtc },
 
+    /// User approved the plan on conditions appended to it.
+    UserApprovedWithConditions {
+        conditions: String,
+        approved_at: TimestampUtc,
+    },
+
     /// User requested implementation.
     UserRequestedImplementation { requested_at: TimestampUtc },
 
@@ -96,6 +139,13 @@ pub enum WorkflowEvent {
         declined_at: TimestampUtc,
     },
 
+    /// User took back their final plan decision within its grace window.
+    /// Compensates the decision's events; nothing had acted on it yet.
+    UserDecisionUndone {
+        decision: UndoneDecision,
+        undone_at: TimestampUtc,
+    },
+
     /// User aborted the workflow.
     UserAborted {
         reason: String,
@@ -125,6 +175,9 @@ pub enum WorkflowEvent {
         iteration: Iteration,
         fingerprint: u64,
         completed_at: TimestampUtc,
+        /// Diff of the round's changes, as given to the reviewer
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        diff_path: Option<PathBuf>,
     },
 
     /// Implementation review completed.
@@ -184,6 +237,158 @@ pub enum WorkflowEvent {
 
     /// Worktree attached.
     WorktreeAttached { worktree_state: WorktreeState },
+
+    /// Workflow linked to the tracker issue its objective was imported from.
+    IssueLinked { issue: IssueLink },
+
+    /// Plan file was changed on disk outside the workflow (e.g. edited while paused).
+    PlanExternallyModified {
+        /// SHA-256 of the plan content as found on disk.
+        content_hash: String,
+        detected_at: TimestampUtc,
+    },
+
+    /// A completion action ran after the workflow was accepted.
+    CompletionActionRecorded {
+        outcome: CompletionActionOutcome,
+        recorded_at: TimestampUtc,
+    },
+
+    /// The plan was checked for the sections the `plan_structure` policy requires.
+    PlanStructureChecked {
+        /// Required sections without a matching heading; empty if the plan passed.
+        missing_sections: Vec<String>,
+        /// The planner was asked to add the missing sections before review.
+        followup_requested: bool,
+        checked_at: TimestampUtc,
+    },
+
+    /// The plan's front matter was parsed (`plan_structure.front_matter`).
+    PlanMetadataRecorded {
+        metadata: PlanMetadata,
+        recorded_at: TimestampUtc,
+    },
+
+    /// A phase ran past `max_phase_duration`.
+    PhaseDurationExceeded {
+        phase: PhaseLabel,
+        limit_minutes: u64,
+        /// The phase's agents were stopped and asked to wrap up.
+        auto_interrupted: bool,
+        detected_at: TimestampUtc,
+    },
+
+    /// The workflow was given a new feature name.
+    WorkflowRenamed {
+        feature_name: FeatureName,
+        renamed_at: TimestampUtc,
+    },
+
+    /// An existing plan was adopted as approved, skipping planning and review.
+    PlanImported {
+        /// Path of the plan file as given on the command line.
+        source: String,
+        imported_at: TimestampUtc,
+    },
+
+    /// The objective was edited; planning restarts from it at the current iteration.
+    ObjectiveAmended {
+        objective: Objective,
+        amended_at: TimestampUtc,
+    },
+
+    /// An accepted plan goes back to planning with feedback on what changed.
+    PlanningReopened {
+        feedback: String,
+        reopened_at: TimestampUtc,
+    },
+
+    /// An approval gate is waiting for the user.
+    ApprovalGateOpened {
+        gate: ApprovalGate,
+        iteration: Iteration,
+        opened_at: TimestampUtc,
+    },
+
+    /// The user let the workflow continue past an approval gate.
+    ApprovalGatePassed {
+        gate: ApprovalGate,
+        passed_at: TimestampUtc,
+    },
+
+    /// The user sent the work back at an approval gate.
+    ApprovalGateRejected {
+        gate: ApprovalGate,
+        feedback: String,
+        rejected_at: TimestampUtc,
+    },
+
+    /// Reviewing switched between parallel and sequential for the rest of the session.
+    ReviewModeChanged {
+        sequential: bool,
+        /// Why: the user's `/review-mode`, or provider concurrency limits.
+        reason: String,
+        changed_at: TimestampUtc,
+    },
+
+    /// The planner finished a top-level plan section while still writing the plan.
+    PlanSectionCompleted {
+        section: String,
+        completed_at: TimestampUtc,
+    },
+
+    /// A reviewer reviewed one finished plan section ahead of the review phase.
+    PlanSectionReviewed {
+        section: String,
+        body: String,
+        reviewer_id: AgentId,
+        approved: bool,
+        feedback: String,
+        summary: String,
+        low_confidence: bool,
+        reviewed_at: TimestampUtc,
+    },
+
+    /// The output of a user slash command waits for the next planner or implementer prompt.
+    CommandOutputQueued {
+        output: String,
+        queued_at: TimestampUtc,
+    },
+
+    /// The oldest `count` queued command outputs were added to a prompt of `phase`.
+    CommandOutputDelivered {
+        count: usize,
+        phase: PhaseLabel,
+        delivered_at: TimestampUtc,
+    },
+
+    /// The session watches files after the plan is accepted and re-plans when they change.
+    ReplanWatchEnabled {
+        watch: WatchSpec,
+        enabled_at: TimestampUtc,
+    },
+}
+
+impl WorkflowEvent {
+    /// The event recording `step` of `gate`.
+    pub fn approval_gate(gate: ApprovalGate, step: GateStep, at: TimestampUtc) -> Self {
+        match step {
+            GateStep::Opened { iteration } => Self::ApprovalGateOpened {
+                gate,
+                iteration,
+                opened_at: at,
+            },
+            GateStep::Passed => Self::ApprovalGatePassed {
+                gate,
+                passed_at: at,
+            },
+            GateStep::Rejected { feedback } => Self::ApprovalGateRejected {
+                gate,
+                feedback,
+                rejected_at: at,
+            },
+        }
+    }
 }
 
 impl DomainEvent for WorkflowEvent {
@@ -195,14 +400,20 @@ impl DomainEvent for WorkflowEvent {
             Self::ReviewCycleStarted { .. } => "ReviewCycleStarted".to_string(),
             Self::ReviewerApproved { .. } => "ReviewerApproved".to_string(),
             Self::ReviewerRejected { .. } => "ReviewerRejected".to_string(),
+            Self::ReviewerVerdictReused { .. } => "ReviewerVerdictReused".to_string(),
+            Self::ReviewerVerdictRevised { .. } => "ReviewerVerdictRevised".to_string(),
+            Self::ReviewerSubstituted { .. } => "ReviewerSubstituted".to_string(),
             Self::ReviewCycleCompleted { .. } => "ReviewCycleCompleted".to_string(),
+            Self::FeedbackTriaged { .. } => "FeedbackTriaged".to_string(),
             Self::RevisingStarted { .. } => "RevisingStarted".to_string(),
             Self::RevisionCompleted { .. } => "RevisionCompleted".to_string(),
             Self::PlanningMaxIterationsReached { .. } => "PlanningMaxIterationsReached".to_string(),
             Self::MaxIterationsExtended { .. } => "MaxIterationsExtended".to_string(),
             Self::UserApproved { .. } => "UserApproved".to_string(),
+            Self::UserApprovedWithConditions { .. } => "UserApprovedWithConditions".to_string(),
             Self::UserRequestedImplementation { .. } => "UserRequestedImplementation".to_string(),
             Self::UserDeclined { .. } => "UserDeclined".to_string(),
+            Self::UserDecisionUndone { .. } => "UserDecisionUndone".to_string(),
             Self::UserAborted { .. } => "UserAborted".to_string(),
             Self::UserOverrideApproval { .. } => "UserOverrideApproval".to_string(),
             Self::ImplementationStarted { .. } => "ImplementationStarted".to_string(),
@@ -222,6 +433,25 @@ impl DomainEvent for WorkflowEvent {
             Self::InvocationRecorded { .. } => "InvocationRecorded".to_string(),
             Self::FailureRecorded { .. } => "FailureRecorded".to_string(),
             Self::WorktreeAttached { .. } => "WorktreeAttached".to_string(),
+            Self::IssueLinked { .. } => "IssueLinked".to_string(),
+            Self::PlanExternallyModified { .. } => "PlanExternallyModified".to_string(),
+            Self::CompletionActionRecorded { .. } => "CompletionActionRecorded".to_string(),
+            Self::PlanStructureChecked { .. } => "PlanStructureChecked".to_string(),
+            Self::PlanMetadataRecorded { .. } => "PlanMetadataRecorded".to_string(),
+            Self::PhaseDurationExceeded { .. } => "PhaseDurationExceeded".to_string(),
+            Self::WorkflowRenamed { .. } => "WorkflowRenamed".to_string(),
+            Self::PlanImported { .. } => "PlanImported".to_string(),
+            Self::ObjectiveAmended { .. } => "ObjectiveAmended".to_string(),
+            Self::PlanningReopened { .. } => "PlanningReopened".to_string(),
+            Self::ApprovalGateOpened { .. } => "ApprovalGateOpened".to_string(),
+            Self::ApprovalGatePassed { .. } => "ApprovalGatePassed".to_string(),
+            Self::ApprovalGateRejected { .. } => "ApprovalGateRejected".to_string(),
+            Self::ReviewModeChanged { .. } => "ReviewModeChanged".to_string(),
+            Self::PlanSectionCompleted { .. } => "PlanSectionCompleted".to_string(),
+            Self::PlanSectionReviewed { .. } => "PlanSectionReviewed".to_string(),
+            Self::CommandOutputQueued { .. } => "CommandOutputQueued".to_string(),
+            Self::CommandOutputDelivered { .. } => "CommandOutputDelivered".to_string(),
+            Self::ReplanWatchEnabled { .. } => "ReplanWatchEnabled".to_string(),
         }
     }
 
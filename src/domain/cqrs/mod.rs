@@ -7,6 +7,7 @@
 //! - **Query**: Read-side queries
 
 pub mod commands;
+mod data;
 pub mod events;
 pub mod query;
 
@@ -15,13 +16,14 @@ pub use events::WorkflowEvent;
 pub use query::WorkflowQuery;
 
 use crate::domain::errors::WorkflowError;
-use crate::domain::failure::{FailureContext, MAX_FAILURE_HISTORY};
+use crate::domain::failure::FailureContext;
 use crate::domain::review::ReviewMode;
 use crate::domain::services::WorkflowServices;
 use crate::domain::types::{
-    AgentConversationState, AgentId, AwaitingDecisionReason, FeatureName, FeedbackPath,
-    FeedbackStatus, ImplementationPhase, ImplementationPhaseState, InvocationRecord, Iteration,
-    MaxIterations, Objective, Phase, PlanPath, TimestampUtc, WorkingDir, WorktreeState,
+    AgentConversationState, AgentId, ApprovalGate, AwaitingDecisionReason, FeatureName,
+    FeedbackPath, FeedbackStatus, ImplementationPhase, ImplementationPhaseState, InvocationRecord,
+    Iteration, MaxIterations, Objective, Phase, PlanPath, TimestampUtc, UndoneDecision, WorkingDir,
+    WorktreeState,
 };
 use async_trait::async_trait;
 use cqrs_es::Aggregate;
@@ -51,185 +53,6 @@ pub struct WorkflowData {
     worktree_info: Option<WorktreeState>,
 }
 
-impl WorkflowData {
-    // ========== Public Getters ==========
-
-    /// Returns the feature name.
-    pub fn feature_name(&self) -> &FeatureName {
-        &self.feature_name
-    }
-
-    /// Returns the objective.
-    pub fn objective(&self) -> &Objective {
-        &self.objective
-    }
-
-    /// Returns the working directory.
-    pub fn working_dir(&self) -> &WorkingDir {
-        &self.working_dir
-    }
-
-    /// Returns the creation timestamp.
-    pub fn created_at(&self) -> &TimestampUtc {
-        &self.created_at
-    }
-
-    /// Returns the current planning phase.
-    pub fn planning_phase(&self) -> &Phase {
-        &self.planning_phase
-    }
-
-    /// Returns the current iteration.
-    pub fn iteration(&self) -> &Iteration {
-        &self.iteration
-    }
-
-    /// Returns the maximum iterations allowed.
-    pub fn max_iterations(&self) -> &MaxIterations {
-        &self.max_iterations
-    }
-
-    /// Returns the plan path.
-    pub fn plan_path(&self) -> &PlanPath {
-        &self.plan_path
-    }
-
-    /// Returns the feedback path.
-    pub fn feedback_path(&self) -> &FeedbackPath {
-        &self.feedback_path
-    }
-
-    /// Returns the last feedback status.
-    pub fn last_feedback_status(&self) -> Option<&FeedbackStatus> {
-        self.last_feedback_status.as_ref()
-    }
-
-    /// Returns the review mode.
-    pub fn review_mode(&self) -> Option<&ReviewMode> {
-        self.review_mode.as_ref()
-    }
-
-    /// Returns a mutable reference to the review mode.
-    pub fn review_mode_mut(&mut self) -> Option<&mut ReviewMode> {
-        self.review_mode.as_mut()
-    }
-
-    /// Returns whether approval was overridden.
-    pub fn approval_overridden(&self) -> bool {
-        self.approval_overridden
-    }
-
-    /// Returns the implementation state.
-    pub fn implementation_state(&self) -> Option<&ImplementationPhaseState> {
-        self.implementation_state.as_ref()
-    }
-
-    /// Returns a mutable reference to the implementation state.
-    pub fn implementation_state_mut(&mut self) -> Option<&mut ImplementationPhaseState> {
-        self.implementation_state.as_mut()
-    }
-
-    /// Returns the agent conversations map.
-    pub fn agent_conversations(&self) -> &HashMap<AgentId, AgentConversationState> {
-        &self.agent_conversations
-    }
-
-    /// Returns the invocations list.
-    pub fn invocations(&self) -> &[InvocationRecord] {
-        &self.invocations
-    }
-
-    /// Returns the last failure.
-    pub fn last_failure(&self) -> Option<&FailureContext> {
-        self.last_failure.as_ref()
-    }
-
-    /// Returns the failure history.
-    pub fn failure_history(&self) -> &[FailureContext] {
-        &self.failure_history
-    }
-
-    /// Returns the worktree info.
-    pub fn worktree_info(&self) -> Option<&WorktreeState> {
-        self.worktree_info.as_ref()
-    }
-
-    // ========== Crate-level Setters ==========
-
-    /// Sets the planning phase.
-    pub(crate) fn set_planning_phase(&mut self, phase: Phase) {
-        self.planning_phase = phase;
-    }
-
-    /// Sets the iteration.
-    pub(crate) fn set_iteration(&mut self, iteration: Iteration) {
-        self.iteration = iteration;
-    }
-
-    /// Sets the maximum iterations allowed.
-    pub(crate) fn set_max_iterations(&mut self, max: MaxIterations) {
-        self.max_iterations = max;
-    }
-
-    /// Sets the plan path.
-    pub(crate) fn set_plan_path(&mut self, path: PlanPath) {
-        self.plan_path = path;
-    }
-
-    /// Sets the last feedback status.
-    pub(crate) fn set_last_feedback_status(&mut self, status: Option<FeedbackStatus>) {
-        self.last_feedback_status = status;
-    }
-
-    /// Sets the review mode.
-    pub(crate) fn set_review_mode(&mut self, mode: Option<ReviewMode>) {
-        self.review_mode = mode;
-    }
-
-    /// Sets whether approval was overridden.
-    pub(crate) fn set_approval_overridden(&mut self, overridden: bool) {
-        self.approval_overridden = overridden;
-    }
-
-    /// Sets the implementation state.
-    pub(crate) fn set_implementation_state(&mut self, state: Option<ImplementationPhaseState>) {
-        self.implementation_state = state;
-    }
-
-    /// Inserts an agent conversation.
-    pub(crate) fn insert_agent_conversation(
-        &mut self,
-        agent_id: AgentId,
-        state: AgentConversationState,
-    ) {
-        self.agent_conversations.insert(agent_id, state);
-    }
-
-    /// Adds an invocation record.
-    pub(crate) fn push_invocation(&mut self, record: InvocationRecord) {
-        self.invocations.push(record);
-    }
-
-    /// Sets the last failure.
-    pub(crate) fn set_last_failure(&mut self, failure: Option<FailureContext>) {
-        self.last_failure = failure;
-    }
-
-    /// Adds a failure to history and trims if over limit.
-    pub(crate) fn push_failure_history(&mut self, failure: FailureContext) {
-        self.failure_history.push(failure);
-        if self.failure_history.len() > MAX_FAILURE_HISTORY {
-            let excess = self.failure_history.len() - MAX_FAILURE_HISTORY;
-            self.failure_history.drain(0..excess);
-        }
-    }
-
-    /// Sets the worktree info.
-    pub(crate) fn set_worktree_info(&mut self, info: Option<WorktreeState>) {
-        self.worktree_info = info;
-    }
-}
-
 /// Workflow aggregate state.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum WorkflowState {
@@ -306,11 +129,16 @@ impl Aggregate for WorkflowAggregate {
             // ReviewCycleStarted
             (
                 WorkflowState::Active(data),
-                WorkflowCommand::ReviewCycleStarted { mode, reviewers },
+                WorkflowCommand::ReviewCycleStarted {
+                    mode,
+                    reviewers,
+                    preset,
+                },
             ) if *data.planning_phase() == Phase::Reviewing => {
                 Ok(vec![WorkflowEvent::ReviewCycleStarted {
                     mode,
                     reviewers,
+                    preset,
                     started_at: now,
                 }])
             }
@@ -331,15 +159,68 @@ impl Aggregate for WorkflowAggregate {
                 WorkflowCommand::ReviewerRejected {
                     reviewer_id,
                     feedback_path,
+                    low_confidence,
                 },
             ) if *data.planning_phase() == Phase::Reviewing => {
                 Ok(vec![WorkflowEvent::ReviewerRejected {
                     reviewer_id,
                     feedback_path,
+                    low_confidence,
                     rejected_at: now,
                 }])
             }
 
+            // ReviewerVerdictReused
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::ReviewerVerdictReused {
+                    reviewer_id,
+                    plan_hash,
+                },
+            ) if *data.planning_phase() == Phase::Reviewing => {
+                Ok(vec![WorkflowEvent::ReviewerVerdictReused {
+                    reviewer_id,
+                    plan_hash,
+                    reused_at: now,
+                }])
+            }
+
+            // ReviewerVerdictRevised - an appeal at max iterations or at the reviewing gate cleared a rejection
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::ReviewerVerdictRevised {
+                    reviewer_id,
+                    plan_approved,
+                },
+            ) if matches!(
+                data.planning_phase(),
+                Phase::AwaitingPlanningDecision | Phase::Revising
+            ) =>
+            {
+                Ok(vec![WorkflowEvent::ReviewerVerdictRevised {
+                    reviewer_id,
+                    plan_approved,
+                    revised_at: now,
+                }])
+            }
+
+            // RecordReviewerSubstituted
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::RecordReviewerSubstituted {
+                    failed_reviewer,
+                    substitute,
+                    reason,
+                },
+            ) if *data.planning_phase() == Phase::Reviewing => {
+                Ok(vec![WorkflowEvent::ReviewerSubstituted {
+                    failed_reviewer,
+                    substitute,
+                    reason,
+                    substituted_at: now,
+                }])
+            }
+
             // ReviewCycleCompleted
             (WorkflowState::Active(data), WorkflowCommand::ReviewCycleCompleted { approved })
                 if *data.planning_phase() == Phase::Reviewing =>
@@ -350,6 +231,16 @@ impl Aggregate for WorkflowAggregate {
                 }])
             }
 
+            // FeedbackTriaged
+            (WorkflowState::Active(data), WorkflowCommand::FeedbackTriaged { items })
+                if *data.planning_phase() == Phase::Revising =>
+            {
+                Ok(vec![WorkflowEvent::FeedbackTriaged {
+                    items,
+                    triaged_at: now,
+                }])
+            }
+
             // RevisingStarted from Revising phase - just emit RevisingStarted
             (
                 WorkflowState::Active(data),
@@ -408,6 +299,15 @@ impl Aggregate for WorkflowAggregate {
                 Ok(vec![WorkflowEvent::UserApproved { approved_at: now }])
             }
 
+            // UserApprovedWithConditions - same gate as UserApproved
+            (
+                WorkflowState::Active(_),
+                WorkflowCommand::UserApprovedWithConditions { conditions },
+            ) => Ok(vec![WorkflowEvent::UserApprovedWithConditions {
+                conditions,
+                approved_at: now,
+            }]),
+
             // UserRequestedImplementation - emits both request and start events
             (WorkflowState::Active(data), WorkflowCommand::UserRequestedImplementation) => {
                 Ok(vec![
@@ -427,6 +327,16 @@ impl Aggregate for WorkflowAggregate {
                 }])
             }
 
+            // UndoUserDecision - the decision is recorded but nothing has run after it
+            (WorkflowState::Active(data), WorkflowCommand::UndoUserDecision { decision })
+                if *data.planning_phase() == Phase::Complete =>
+            {
+                Ok(vec![WorkflowEvent::UserDecisionUndone {
+                    decision,
+                    undone_at: now,
+                }])
+            }
+
             // UserAborted
             (WorkflowState::Active(_), WorkflowCommand::UserAborted { reason }) => {
                 Ok(vec![WorkflowEvent::UserAborted {
@@ -469,12 +379,14 @@ impl Aggregate for WorkflowAggregate {
                 WorkflowCommand::ImplementationRoundCompleted {
                     iteration,
                     fingerprint,
+                    diff_path,
                 },
             ) if data.implementation_state().is_some() => {
                 Ok(vec![WorkflowEvent::ImplementationRoundCompleted {
                     iteration,
                     fingerprint,
                     completed_at: now,
+                    diff_path,
                 }])
             }
 
@@ -589,12 +501,180 @@ impl Aggregate for WorkflowAggregate {
                 Ok(vec![WorkflowEvent::WorktreeAttached { worktree_state }])
             }
 
+            // LinkIssue - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::LinkIssue { issue }) => {
+                Ok(vec![WorkflowEvent::IssueLinked { issue }])
+            }
+
+            // RecordPlanExternalModification - only while the plan is still being iterated on
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::RecordPlanExternalModification { content_hash },
+            ) if matches!(
+                data.planning_phase(),
+                Phase::Reviewing | Phase::Revising | Phase::AwaitingPlanningDecision
+            ) =>
+            {
+                Ok(vec![WorkflowEvent::PlanExternallyModified {
+                    content_hash,
+                    detected_at: now,
+                }])
+            }
+
+            // RecordReviewModeChange - always valid on active aggregate
+            (
+                WorkflowState::Active(_),
+                WorkflowCommand::RecordReviewModeChange { sequential, reason },
+            ) => Ok(vec![WorkflowEvent::ReviewModeChanged {
+                sequential,
+                reason,
+                changed_at: now,
+            }]),
+
+            // RecordPlanSectionCompleted - only while the plan is being written
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::RecordPlanSectionCompleted { section },
+            ) if *data.planning_phase() == Phase::Planning => {
+                Ok(vec![WorkflowEvent::PlanSectionCompleted {
+                    section,
+                    completed_at: now,
+                }])
+            }
+
+            // RecordPlanSectionReview - only while the plan is being written
+            (WorkflowState::Active(data), WorkflowCommand::RecordPlanSectionReview { review })
+                if *data.planning_phase() == Phase::Planning =>
+            {
+                Ok(vec![WorkflowEvent::PlanSectionReviewed {
+                    section: review.section,
+                    body: review.body,
+                    reviewer_id: review.reviewer_id,
+                    approved: review.approved,
+                    feedback: review.feedback,
+                    summary: review.summary,
+                    low_confidence: review.low_confidence,
+                    reviewed_at: now,
+                }])
+            }
+
+            // QueueCommandOutput, DeliverCommandOutput - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::QueueCommandOutput { output }) => {
+                Ok(vec![WorkflowEvent::CommandOutputQueued {
+                    output,
+                    queued_at: now,
+                }])
+            }
+            (WorkflowState::Active(_), WorkflowCommand::DeliverCommandOutput { count, phase }) => {
+                Ok(vec![WorkflowEvent::CommandOutputDelivered {
+                    count,
+                    phase,
+                    delivered_at: now,
+                }])
+            }
+
+            // EnableReplanWatch - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::EnableReplanWatch { watch }) => {
+                Ok(vec![WorkflowEvent::ReplanWatchEnabled {
+                    watch,
+                    enabled_at: now,
+                }])
+            }
+
+            // RecordCompletionAction - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::RecordCompletionAction { outcome }) => {
+                Ok(vec![WorkflowEvent::CompletionActionRecorded {
+                    outcome,
+                    recorded_at: now,
+                }])
+            }
+
+            // RecordPlanStructureCheck - only between planning and review
+            (
+                WorkflowState::Active(data),
+                WorkflowCommand::RecordPlanStructureCheck {
+                    missing_sections,
+                    followup_requested,
+                },
+            ) if *data.planning_phase() == Phase::Planning => {
+                Ok(vec![WorkflowEvent::PlanStructureChecked {
+                    missing_sections,
+                    followup_requested,
+                    checked_at: now,
+                }])
+            }
+
+            // RecordPlanMetadata - always valid on active aggregate (revisions re-record it)
+            (WorkflowState::Active(_), WorkflowCommand::RecordPlanMetadata { metadata }) => {
+                Ok(vec![WorkflowEvent::PlanMetadataRecorded {
+                    metadata,
+                    recorded_at: now,
+                }])
+            }
+
+            // RecordPhaseDurationExceeded - always valid on active aggregate
+            (
+                WorkflowState::Active(_),
+                WorkflowCommand::RecordPhaseDurationExceeded {
+                    phase,
+                    limit_minutes,
+                    auto_interrupted,
+                },
+            ) => Ok(vec![WorkflowEvent::PhaseDurationExceeded {
+                phase,
+                limit_minutes,
+                auto_interrupted,
+                detected_at: now,
+            }]),
+
+            // RenameWorkflow - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::RenameWorkflow { feature_name }) => {
+                Ok(vec![WorkflowEvent::WorkflowRenamed {
+                    feature_name,
+                    renamed_at: now,
+                }])
+            }
+
+            // ImportPlan - only before the first planning round has produced anything
+            (WorkflowState::Active(data), WorkflowCommand::ImportPlan { source })
+                if *data.planning_phase() == Phase::Planning
+                    && *data.iteration() == Iteration::first() =>
+            {
+                Ok(vec![WorkflowEvent::PlanImported {
+                    source,
+                    imported_at: now,
+                }])
+            }
+
+            // AmendObjective - until implementation starts
+            (WorkflowState::Active(data), WorkflowCommand::AmendObjective { objective })
+                if data.implementation_state().is_none() =>
+            {
+                Ok(vec![WorkflowEvent::ObjectiveAmended {
+                    objective,
+                    amended_at: now,
+                }])
+            }
+
+            // ReopenPlanning - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::ReopenPlanning { feedback }) => {
+                Ok(vec![WorkflowEvent::PlanningReopened {
+                    feedback,
+                    reopened_at: now,
+                }])
+            }
+
+            // RecordApprovalGate - always valid on active aggregate
+            (WorkflowState::Active(_), WorkflowCommand::RecordApprovalGate { gate, step }) => {
+                Ok(vec![WorkflowEvent::approval_gate(gate, step, now)])
+            }
+
             // Commands on uninitialized aggregate (except CreateWorkflow which is handled above)
             (WorkflowState::Uninitialized, _cmd) => Err(WorkflowError::NotInitialized),
 
             // All other combinations are invalid transitions on active aggregate
             (WorkflowState::Active(data), cmd) => {
-                let cmd_name = command_name(&cmd);
+                let cmd_name = cmd.name();
                 let phase = data.planning_phase();
                 Err(WorkflowError::InvalidTransition {
                     message: format!("command '{}' not valid in phase '{:?}'", cmd_name, phase),
@@ -657,8 +737,12 @@ impl Aggregate for WorkflowAggregate {
                 data.set_review_mode(Some(mode));
             }
 
-            // ReviewerApproved
-            (WorkflowState::Active(data), WorkflowEvent::ReviewerApproved { reviewer_id, .. }) => {
+            // ReviewerApproved / ReviewerVerdictReused (a reused approval counts the same)
+            (WorkflowState::Active(data), WorkflowEvent::ReviewerApproved { reviewer_id, .. })
+            | (
+                WorkflowState::Active(data),
+                WorkflowEvent::ReviewerVerdictReused { reviewer_id, .. },
+            ) => {
                 if let Some(ReviewMode::Sequential(ref mut state)) = data.review_mode_mut() {
                     state.record_approval_simple(reviewer_id);
                     state.advance_to_next_reviewer();
@@ -672,6 +756,21 @@ impl Aggregate for WorkflowAggregate {
                 }
             }
 
+            // ReviewerVerdictRevised - the plan is approved once no rejection remains
+            (
+                WorkflowState::Active(data),
+                WorkflowEvent::ReviewerVerdictRevised {
+                    plan_approved: true,
+                    ..
+                },
+            ) => {
+                data.set_planning_phase(Phase::Complete);
+                data.set_last_feedback_status(Some(FeedbackStatus::Approved));
+            }
+
+            // ReviewerSubstituted - the backup's verdict is recorded like any reviewer's
+            (WorkflowState::Active(_), WorkflowEvent::ReviewerSubstituted { .. }) => {}
+
             // ReviewCycleCompleted
             (WorkflowState::Active(data), WorkflowEvent::ReviewCycleCompleted { approved, .. }) => {
                 data.set_planning_phase(if approved {
@@ -686,6 +785,9 @@ impl Aggregate for WorkflowAggregate {
                 }));
             }
 
+            // FeedbackTriaged - triage decisions are tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::FeedbackTriaged { .. }) => {}
+
             // RevisingStarted
             (WorkflowState::Active(data), WorkflowEvent::RevisingStarted { .. }) => {
                 data.set_planning_phase(Phase::Revising);
@@ -713,7 +815,8 @@ impl Aggregate for WorkflowAggregate {
             }
 
             // UserApproved
-            (WorkflowState::Active(data), WorkflowEvent::UserApproved { .. }) => {
+            (WorkflowState::Active(data), WorkflowEvent::UserApproved { .. })
+            | (WorkflowState::Active(data), WorkflowEvent::UserApprovedWithConditions { .. }) => {
                 data.set_planning_phase(Phase::Complete);
             }
 
@@ -723,6 +826,13 @@ impl Aggregate for WorkflowAggregate {
             // UserDeclined - no state change
             (WorkflowState::Active(_), WorkflowEvent::UserDeclined { .. }) => {}
 
+            // UserDecisionUndone - implementation requested by the decision never started
+            (WorkflowState::Active(data), WorkflowEvent::UserDecisionUndone { decision, .. }) => {
+                if matches!(decision, UndoneDecision::ImplementationRequested) {
+                    data.set_implementation_state(None);
+                }
+            }
+
             // UserAborted - no state change
             (WorkflowState::Active(_), WorkflowEvent::UserAborted { .. }) => {}
 
@@ -835,7 +945,9 @@ impl Aggregate for WorkflowAggregate {
 
             // FailureRecorded
             (WorkflowState::Active(data), WorkflowEvent::FailureRecorded { failure, .. }) => {
-                data.set_last_failure(Some(failure.clone()));
+                if !failure.is_resolved() {
+                    data.set_last_failure(Some(failure.clone()));
+                }
                 data.push_failure_history(failure);
             }
 
@@ -844,46 +956,79 @@ impl Aggregate for WorkflowAggregate {
                 data.set_worktree_info(Some(worktree_state));
             }
 
+            // IssueLinked - display metadata only, tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::IssueLinked { .. }) => {}
+
+            // PlanExternallyModified - approvals of the previous content no longer count
+            (WorkflowState::Active(data), WorkflowEvent::PlanExternallyModified { .. }) => {
+                if let Some(ReviewMode::Sequential(ref mut state)) = data.review_mode_mut() {
+                    state.increment_version();
+                    state.clear_cycle_order();
+                }
+            }
+
+            // CompletionActionRecorded - outcome only, tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::CompletionActionRecorded { .. }) => {}
+
+            // PlanStructureChecked - informational only (event log)
+            (WorkflowState::Active(_), WorkflowEvent::PlanStructureChecked { .. }) => {}
+
+            // PlanMetadataRecorded - tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::PlanMetadataRecorded { .. }) => {}
+
+            // ReviewModeChanged - tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::ReviewModeChanged { .. }) => {}
+
+            // PlanSectionCompleted - informational only (event log)
+            (WorkflowState::Active(_), WorkflowEvent::PlanSectionCompleted { .. }) => {}
+
+            // PlanSectionReviewed - tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::PlanSectionReviewed { .. }) => {}
+
+            // CommandOutputQueued, CommandOutputDelivered - tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::CommandOutputQueued { .. }) => {}
+            (WorkflowState::Active(_), WorkflowEvent::CommandOutputDelivered { .. }) => {}
+
+            // ReplanWatchEnabled - tracked by the view
+            (WorkflowState::Active(_), WorkflowEvent::ReplanWatchEnabled { .. }) => {}
+
+            // PhaseDurationExceeded - informational only (event log, TUI warning)
+            (WorkflowState::Active(_), WorkflowEvent::PhaseDurationExceeded { .. }) => {}
+
+            // WorkflowRenamed
+            (WorkflowState::Active(data), WorkflowEvent::WorkflowRenamed { feature_name, .. }) => {
+                data.feature_name = feature_name;
+            }
+
+            // PlanImported - the plan is approved as given
+            (WorkflowState::Active(data), WorkflowEvent::PlanImported { .. }) => {
+                data.set_planning_phase(Phase::Complete);
+            }
+
+            // ObjectiveAmended - plan again, keeping the iteration and agent conversations
+            (WorkflowState::Active(data), WorkflowEvent::ObjectiveAmended { objective, .. }) => {
+                data.objective = objective;
+                data.set_planning_phase(Phase::Planning);
+            }
+
+            // PlanningReopened - plan again, keeping the iteration and agent conversations
+            (WorkflowState::Active(data), WorkflowEvent::PlanningReopened { .. }) => {
+                data.set_planning_phase(Phase::Planning);
+            }
+
+            // ApprovalGateRejected - a rejected plan goes back to the planner
+            (WorkflowState::Active(data), WorkflowEvent::ApprovalGateRejected { gate, .. }) => {
+                if gate != ApprovalGate::ImplementationRound {
+                    data.set_planning_phase(Phase::Planning);
+                }
+            }
+
             // Ignore events on wrong state (shouldn't happen with correct event sourcing)
             _ => {}
         }
     }
 }
 
-/// Extracts a human-readable name from a command for error messages.
-fn command_name(cmd: &WorkflowCommand) -> &'static str {
-    match cmd {
-        WorkflowCommand::CreateWorkflow { .. } => "CreateWorkflow",
-        WorkflowCommand::StartPlanning => "StartPlanning",
-        WorkflowCommand::PlanningCompleted { .. } => "PlanningCompleted",
-        WorkflowCommand::ReviewCycleStarted { .. } => "ReviewCycleStarted",
-        WorkflowCommand::ReviewerApproved { .. } => "ReviewerApproved",
-        WorkflowCommand::ReviewerRejected { .. } => "ReviewerRejected",
-        WorkflowCommand::ReviewCycleCompleted { .. } => "ReviewCycleCompleted",
-        WorkflowCommand::RevisingStarted { .. } => "RevisingStarted",
-        WorkflowCommand::RevisionCompleted { .. } => "RevisionCompleted",
-        WorkflowCommand::PlanningMaxIterationsReached => "PlanningMaxIterationsReached",
-        WorkflowCommand::UserApproved => "UserApproved",
-        WorkflowCommand::UserRequestedImplementation => "UserRequestedImplementation",
-        WorkflowCommand::UserDeclined { .. } => "UserDeclined",
-        WorkflowCommand::UserAborted { .. } => "UserAborted",
-        WorkflowCommand::UserOverrideApproval { .. } => "UserOverrideApproval",
-        WorkflowCommand::ImplementationStarted { .. } => "ImplementationStarted",
-        WorkflowCommand::ImplementationRoundStarted { .. } => "ImplementationRoundStarted",
-        WorkflowCommand::ImplementationRoundCompleted { .. } => "ImplementationRoundCompleted",
-        WorkflowCommand::ImplementationReviewCompleted { .. } => "ImplementationReviewCompleted",
-        WorkflowCommand::ImplementationMaxIterationsReached => "ImplementationMaxIterationsReached",
-        WorkflowCommand::ImplementationNoChanges { .. } => "ImplementationNoChanges",
-        WorkflowCommand::ImplementationAccepted => "ImplementationAccepted",
-        WorkflowCommand::ImplementationDeclined { .. } => "ImplementationDeclined",
-        WorkflowCommand::ImplementationCancelled { .. } => "ImplementationCancelled",
-        WorkflowCommand::RecordAgentConversation { .. } => "RecordAgentConversation",
-        WorkflowCommand::RecordInvocation { .. } => "RecordInvocation",
-        WorkflowCommand::RecordFailure { .. } => "RecordFailure",
-        WorkflowCommand::AttachWorktree { .. } => "AttachWorktree",
-    }
-}
-
 #[cfg(test)]
 #[path = "../tests/aggregate_tests.rs"]
 mod tests;
@@ -25,6 +25,8 @@ pub enum FailureKind {
     EmptyOutput,
     /// Workflow-level failure when no reviews completed.
     AllReviewersFailed,
+    /// Agent killed for using more memory than its `memory_limit_mb`.
+    ResourceLimitExceeded { rss_mb: u64, limit_mb: u64 },
     /// Unclassified errors for future extensibility.
     Unknown(String),
 }
@@ -38,6 +40,7 @@ impl FailureKind {
                 | FailureKind::Network
                 | FailureKind::EmptyOutput
                 | FailureKind::AllReviewersFailed
+                | FailureKind::ResourceLimitExceeded { .. }
         )
     }
 
@@ -50,6 +53,7 @@ impl FailureKind {
             FailureKind::ParseFailure(_) => "Parse Failure",
             FailureKind::EmptyOutput => "Empty Output",
             FailureKind::AllReviewersFailed => "All Reviewers Failed",
+            FailureKind::ResourceLimitExceeded { .. } => "Resource Limit Exceeded",
             FailureKind::Unknown(_) => "Unknown",
         }
     }
@@ -67,6 +71,8 @@ pub enum RecoveryAction {
     Aborted,
     /// User chose to continue without full review (partial reviews available).
     ContinuedWithoutFullReview,
+    /// Retried automatically after a backoff, without asking the user.
+    AutoRetried,
 }
 
 /// Context for a workflow failure, persisted in state for recovery.
@@ -145,6 +151,12 @@ impl FailureContext {
         self.recovery_action.as_ref()
     }
 
+    /// Returns true if the failure was already recovered from (e.g. an agent
+    /// retried automatically) and needs no decision on resume.
+    pub fn is_resolved(&self) -> bool {
+        self.recovery_action.is_some()
+    }
+
     /// Returns true if this failure can be retried based on retry_count and max_retries.
     pub fn can_retry(&self) -> bool {
         self.retry_count < self.max_retries && self.kind.is_retryable()
@@ -187,6 +199,33 @@ pub struct FailurePolicy {
     /// Action when all reviewers fail after retries
     #[serde(default)]
     on_all_reviewers_failed: OnAllReviewersFailed,
+    /// How a review round in which all reviewers failed is retried
+    #[serde(default)]
+    review_round_retry: ReviewRoundRetry,
+}
+
+/// Automatic retries of a review round in which every reviewer failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewRoundRetry {
+    /// Longest wait before a retry, in seconds. Default: 300
+    #[serde(default = "default_max_backoff_secs")]
+    pub max_backoff_secs: u32,
+    /// Run only this many of the failed reviewers again. Default: all of them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<usize>,
+}
+
+fn default_max_backoff_secs() -> u32 {
+    300
+}
+
+impl Default for ReviewRoundRetry {
+    fn default() -> Self {
+        Self {
+            max_backoff_secs: default_max_backoff_secs(),
+            reviewers: None,
+        }
+    }
 }
 
 fn default_max_retries() -> u32 {
@@ -203,6 +242,7 @@ impl Default for FailurePolicy {
             max_retries: default_max_retries(),
             backoff_secs: default_backoff_secs(),
             on_all_reviewers_failed: OnAllReviewersFailed::default(),
+            review_round_retry: ReviewRoundRetry::default(),
         }
     }
 }
@@ -218,6 +258,7 @@ impl FailurePolicy {
             max_retries,
             backoff_secs,
             on_all_reviewers_failed,
+            review_round_retry: ReviewRoundRetry::default(),
         }
     }
 
@@ -236,8 +277,25 @@ impl FailurePolicy {
         self.on_all_reviewers_failed
     }
 
+    /// Returns how a review round in which all reviewers failed is retried.
+    pub fn review_round_retry(&self) -> &ReviewRoundRetry {
+        &self.review_round_retry
+    }
+
+    /// Wait before the given round retry (1-indexed): `backoff_secs`, doubled
+    /// for each further attempt and capped at `max_backoff_secs`.
+    pub fn round_retry_delay(&self, attempt: u32) -> std::time::Duration {
+        let doublings = attempt.saturating_sub(1).min(16);
+        let secs = u64::from(self.backoff_secs) << doublings;
+        let max = u64::from(self.review_round_retry.max_backoff_secs);
+        std::time::Duration::from_secs(secs.min(max))
+    }
+
     /// Validates the policy configuration.
     pub fn validate(&self) -> anyhow::Result<()> {
+        if self.review_round_retry.reviewers == Some(0) {
+            anyhow::bail!("failure_policy.review_round_retry.reviewers must be at least 1");
+        }
         Ok(())
     }
 }
@@ -3,7 +3,11 @@
 //! These types replace the legacy `State` struct as input to the workflow runner.
 //! They provide a clean separation between input parameters and derived state.
 
-use crate::domain::types::{FeatureName, MaxIterations, Objective, WorkflowId, WorktreeState};
+use crate::config::AgentArgs;
+use crate::domain::types::{
+    FeatureName, IssueLink, MaxIterations, Objective, WatchSpec, WorkflowId, WorktreeState,
+};
+use std::path::PathBuf;
 
 /// Input parameters for starting a new workflow.
 #[derive(Debug, Clone)]
@@ -16,20 +20,59 @@ pub struct NewWorkflowInput {
     pub max_iterations: MaxIterations,
     /// Optional worktree information for git worktree workflows.
     pub worktree_info: Option<WorktreeState>,
+    /// Optional issue the objective was imported from.
+    pub issue: Option<IssueImport>,
+    /// Optional trimmed logs of a failing CI run (`--attach-ci-run`), as markdown.
+    pub ci_context: Option<String>,
+    /// Optional boundaries of the workspace package targeted with `--package`.
+    pub package_context: Option<String>,
+    /// Optional reviewer preset to review with (`--reviewers`).
+    pub reviewer_preset: Option<String>,
+    /// Optional existing plan to implement without planning (`planning implement`).
+    pub imported_plan: Option<PathBuf>,
+    /// Extra agent CLI arguments for this session only (`--agent-arg`, `/agent-arg`).
+    pub agent_args: Vec<AgentArgs>,
+    /// Optional files to watch for re-planning once the plan is accepted (`--watch`).
+    pub replan_watch: Option<WatchSpec>,
+}
+
+/// An issue imported from an external tracker via `--from-issue`.
+#[derive(Debug, Clone)]
+pub struct IssueImport {
+    /// Link back to the source issue.
+    pub link: IssueLink,
+    /// Markdown context (description and comments) attached to the session.
+    pub context: String,
 }
 
 impl NewWorkflowInput {
     /// Creates a new workflow input with the given parameters.
+    ///
+    /// A feature name that is not a slug is slugified, since it becomes part of
+    /// branch names and session paths.
     pub fn new(
         feature_name: impl Into<FeatureName>,
         objective: impl Into<Objective>,
         max_iterations: u32,
     ) -> Self {
+        let feature_name = feature_name.into();
+        let feature_name = if FeatureName::is_slug(feature_name.as_str()) {
+            feature_name
+        } else {
+            FeatureName::slugify(feature_name.as_str())
+        };
         Self {
-            feature_name: feature_name.into(),
+            feature_name,
             objective: objective.into(),
             max_iterations: MaxIterations(max_iterations),
             worktree_info: None,
+            issue: None,
+            ci_context: None,
+            package_context: None,
+            reviewer_preset: None,
+            imported_plan: None,
+            agent_args: Vec::new(),
+            replan_watch: None,
         }
     }
 
@@ -38,6 +81,48 @@ impl NewWorkflowInput {
         self.worktree_info = Some(worktree_info);
         self
     }
+
+    /// Sets the issue the objective was imported from.
+    pub fn with_issue(mut self, issue: IssueImport) -> Self {
+        self.issue = Some(issue);
+        self
+    }
+
+    /// Attaches failing CI logs for the planning agent.
+    pub fn with_ci_context(mut self, ci_context: String) -> Self {
+        self.ci_context = Some(ci_context);
+        self
+    }
+
+    /// Spells out the targeted package's boundaries for the planner and reviewers.
+    pub fn with_package_context(mut self, package_context: String) -> Self {
+        self.package_context = Some(package_context);
+        self
+    }
+
+    /// Selects a reviewer preset from the workflow config.
+    pub fn with_reviewer_preset(mut self, preset: String) -> Self {
+        self.reviewer_preset = Some(preset);
+        self
+    }
+
+    /// Adds extra agent CLI arguments, merged into the session's workflow config.
+    pub fn with_agent_args(mut self, agent_args: Vec<AgentArgs>) -> Self {
+        self.agent_args = agent_args;
+        self
+    }
+
+    /// Starts from an existing plan file, skipping planning and review.
+    pub fn with_imported_plan(mut self, plan: PathBuf) -> Self {
+        self.imported_plan = Some(plan);
+        self
+    }
+
+    /// Keeps the session watching files for re-planning after the plan is accepted.
+    pub fn with_replan_watch(mut self, watch: WatchSpec) -> Self {
+        self.replan_watch = Some(watch);
+        self
+    }
 }
 
 /// Input parameters for resuming an existing workflow.
@@ -45,14 +130,38 @@ impl NewWorkflowInput {
 pub struct ResumeWorkflowInput {
     /// The workflow session ID to resume.
     pub workflow_id: WorkflowId,
+    /// Optional objective from `/edit-objective` to plan again from.
+    pub amended_objective: Option<Objective>,
+    /// Take the session over from another process holding it (`--takeover`)
+    /// instead of following it read-only.
+    pub takeover: bool,
 }
 
 impl ResumeWorkflowInput {
+    /// Creates a resume input for the given workflow.
+    pub fn new(workflow_id: WorkflowId) -> Self {
+        Self {
+            workflow_id,
+            amended_objective: None,
+            takeover: false,
+        }
+    }
+
     /// Creates a resume input from a workflow session ID string.
     pub fn from_session_id(session_id: &str) -> Result<Self, uuid::Error> {
-        Ok(Self {
-            workflow_id: WorkflowId::from_string(session_id)?,
-        })
+        Ok(Self::new(WorkflowId::from_string(session_id)?))
+    }
+
+    /// Replaces the objective before the workflow resumes.
+    pub fn with_amended_objective(mut self, objective: Objective) -> Self {
+        self.amended_objective = Some(objective);
+        self
+    }
+
+    /// Takes the session over if another process holds it.
+    pub fn with_takeover(mut self, takeover: bool) -> Self {
+        self.takeover = takeover;
+        self
     }
 }
 
@@ -43,13 +43,17 @@ pub use actor::{create_actor_args, WorkflowActor, WorkflowActorArgs, WorkflowMes
 pub use errors::WorkflowError;
 pub use failure::{FailureContext, FailureKind, FailurePolicy, RecoveryAction};
 pub use input::{NewWorkflowInput, ResumeWorkflowInput, WorkflowInput};
-pub use review::{ReviewMode, SequentialReviewState, SerializableReviewResult};
+pub use review::{
+    FeedbackItem, ReviewMode, SequentialReviewState, SerializableReviewResult, TriageDecision,
+    TriagedFeedback,
+};
 pub use services::{WorkflowClock, WorkflowServices};
 pub use supervisor::{SupervisorMsg, WorkflowSupervisor};
 pub use types::{
-    AgentConversationState, AgentId, FeatureName, FeedbackPath, FeedbackStatus,
-    ImplementationPhase, ImplementationPhaseState, ImplementationVerdict, InvocationRecord,
-    Iteration, MaxIterations, Objective, Phase, PhaseLabel, PlanPath, ResumeStrategy, TimestampUtc,
-    UiMode, WorkflowId, WorkingDir, WorktreeState,
+    AgentConversationState, AgentId, CompletionActionOutcome, FeatureName, FeedbackPath,
+    FeedbackStatus, ImplementationPhase, ImplementationPhaseState, ImplementationVerdict,
+    InvocationRecord, Iteration, MaxIterations, Objective, Phase, PhaseLabel, PlanMetadata,
+    PlanPath, ResumeStrategy, RiskLevel, TimestampUtc, UiMode, WorkflowId, WorkingDir,
+    WorktreeState,
 };
 pub use view::{WorkflowEventEnvelope, WorkflowView};
@@ -17,6 +17,66 @@ pub struct SerializableReviewResult {
     pub summary: String,
 }
 
+/// When a sequential review cycle stops running reviewers and goes to revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "ExitPolicyRepr", into = "ExitPolicyRepr")]
+pub enum SequentialExitPolicy {
+    /// Revise as soon as one reviewer rejects (`first_rejection`).
+    #[default]
+    FirstRejection,
+    /// Run every reviewer, then revise against all rejections (`run_all`).
+    RunAll,
+    /// Revise once this many reviewers rejected, or after the last reviewer.
+    AfterRejections(u32),
+}
+
+impl SequentialExitPolicy {
+    /// Whether the cycle stops for revision after `rejections` rejections.
+    pub fn stops_after(&self, rejections: usize) -> bool {
+        match self {
+            Self::FirstRejection => rejections >= 1,
+            Self::RunAll => false,
+            Self::AfterRejections(limit) => rejections >= (*limit).max(1) as usize,
+        }
+    }
+}
+
+/// Config and event form of `SequentialExitPolicy`: a policy name or a rejection count.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ExitPolicyRepr {
+    Count(u32),
+    Name(String),
+}
+
+impl TryFrom<ExitPolicyRepr> for SequentialExitPolicy {
+    type Error = String;
+
+    fn try_from(repr: ExitPolicyRepr) -> Result<Self, Self::Error> {
+        match repr {
+            ExitPolicyRepr::Count(count) => Ok(Self::AfterRejections(count)),
+            ExitPolicyRepr::Name(name) => match name.as_str() {
+                "first_rejection" => Ok(Self::FirstRejection),
+                "run_all" => Ok(Self::RunAll),
+                _ => Err(format!(
+                    "unknown sequential exit policy '{}' (expected first_rejection, run_all or a number of rejections)",
+                    name
+                )),
+            },
+        }
+    }
+}
+
+impl From<SequentialExitPolicy> for ExitPolicyRepr {
+    fn from(policy: SequentialExitPolicy) -> Self {
+        match policy {
+            SequentialExitPolicy::FirstRejection => Self::Name("first_rejection".to_string()),
+            SequentialExitPolicy::RunAll => Self::Name("run_all".to_string()),
+            SequentialExitPolicy::AfterRejections(count) => Self::Count(count),
+        }
+    }
+}
+
 /// Sequential review state: tracks progress through reviewer queue
 /// and ensures all reviewers approve the same plan version.
 ///
@@ -42,6 +102,12 @@ pub struct SequentialReviewState {
     /// The reviewer who rejected the previous plan version.
     #[serde(default)]
     last_rejecting_reviewer: Option<AgentId>,
+    /// When the cycle stops for revision, kept for the whole review so resume honors it.
+    #[serde(default)]
+    exit_policy: SequentialExitPolicy,
+    /// Reviewers who rejected the current plan version, in order.
+    #[serde(default)]
+    rejections: Vec<AgentId>,
 }
 
 impl SequentialReviewState {
@@ -54,6 +120,8 @@ impl SequentialReviewState {
             accumulated_reviews: Vec::new(),
             current_cycle_order: Vec::new(),
             last_rejecting_reviewer: None,
+            exit_policy: SequentialExitPolicy::default(),
+            rejections: Vec::new(),
         }
     }
 
@@ -66,6 +134,12 @@ impl SequentialReviewState {
         state
     }
 
+    /// Sets when the cycle stops for revision.
+    pub fn with_exit_policy(mut self, exit_policy: SequentialExitPolicy) -> Self {
+        self.exit_policy = exit_policy;
+        self
+    }
+
     // ========================================================================
     // READ-ONLY GETTERS (public - safe for external code to call)
     // ========================================================================
@@ -113,6 +187,16 @@ impl SequentialReviewState {
         self.last_rejecting_reviewer.as_ref()
     }
 
+    /// Returns when the cycle stops for revision.
+    pub fn exit_policy(&self) -> SequentialExitPolicy {
+        self.exit_policy
+    }
+
+    /// Returns the reviewers who rejected the current plan version.
+    pub fn rejections(&self) -> &[AgentId] {
+        &self.rejections
+    }
+
     /// Returns accumulated reviews as ReviewResult references for summary generation.
     pub fn get_accumulated_reviews_for_summary(&self) -> Vec<crate::phases::ReviewResult> {
         self.accumulated_reviews
@@ -122,6 +206,7 @@ impl SequentialReviewState {
                 needs_revision: r.needs_revision,
                 feedback: r.feedback.clone(),
                 summary: r.summary.clone(),
+                low_confidence: false,
             })
             .collect()
     }
@@ -144,6 +229,7 @@ impl SequentialReviewState {
         self.plan_version += 1;
         self.approvals.clear();
         self.accumulated_reviews.clear();
+        self.rejections.clear();
     }
 
     /// Advances to next reviewer.
@@ -152,10 +238,15 @@ impl SequentialReviewState {
         self.current_reviewer_index += 1;
     }
 
-    /// Records which reviewer rejected the plan.
+    /// Records which reviewer rejected the plan, moving on to the next reviewer
+    /// unless the exit policy stops the cycle here.
     /// ONLY call from aggregate event handlers.
     pub(crate) fn record_rejection(&mut self, reviewer_id: &str) {
         self.last_rejecting_reviewer = Some(AgentId::from(reviewer_id));
+        self.rejections.push(AgentId::from(reviewer_id));
+        if !self.exit_policy.stops_after(self.rejections.len()) {
+            self.current_reviewer_index += 1;
+        }
     }
 
     /// Starts a new review cycle by computing and storing the reviewer order.
@@ -220,6 +311,46 @@ pub enum ReviewMode {
     Sequential(SequentialReviewState),
 }
 
+/// A discrete item parsed from a reviewer's feedback, triaged before revising.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackItem {
+    pub reviewer: String,
+    /// Feedback section the item came from (e.g. "Critical Issues"), if any.
+    #[serde(default)]
+    pub section: Option<String>,
+    pub text: String,
+}
+
+/// User decision for a feedback item in the triage overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriageDecision {
+    /// Address the item in the next revision.
+    #[default]
+    Accept,
+    /// Defer the item to the plan's backlog section.
+    Park,
+    /// Drop the item.
+    Dismiss,
+}
+
+impl TriageDecision {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TriageDecision::Accept => "accept",
+            TriageDecision::Park => "park",
+            TriageDecision::Dismiss => "dismiss",
+        }
+    }
+}
+
+/// A feedback item together with its triage decision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriagedFeedback {
+    pub item: FeedbackItem,
+    pub decision: TriageDecision,
+}
+
 /// Counts reviewing invocations per agent from the invocation history.
 /// Returns a HashMap from AgentId (raw display_id, no namespace) to count.
 ///
@@ -1,6 +1,6 @@
 //! Unit tests for WorkflowAggregate implementation and advanced features.
 
-use crate::domain::failure::{FailureContext, FailureKind, MAX_FAILURE_HISTORY};
+use crate::domain::failure::{FailureContext, FailureKind, RecoveryAction, MAX_FAILURE_HISTORY};
 use crate::domain::services::WorkflowServices;
 use crate::domain::types::{
     ConversationId, ImplementationPhase, ImplementationVerdict, Iteration, MaxIterations, Phase,
@@ -10,7 +10,7 @@ use crate::domain::WorkflowCommand;
 use crate::domain::WorkflowEvent;
 use crate::domain::{WorkflowAggregate, WorkflowData, WorkflowState};
 use cqrs_es::Aggregate;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Create default services for testing.
 fn test_services() -> WorkflowServices {
@@ -183,6 +183,31 @@ async fn record_failure_adds_to_history() {
     assert_eq!(data.failure_history().len(), 1);
 }
 
+#[tokio::test]
+async fn resolved_failure_recorded_in_history_only() {
+    let mut agg = initialized_aggregate();
+    let failure = FailureContext::new(
+        FailureKind::ResourceLimitExceeded {
+            rss_mb: 2100,
+            limit_mb: 2048,
+        },
+        PhaseLabel::Planning,
+        Some("test-agent".into()),
+        1,
+        1,
+        crate::domain::types::TimestampUtc::now(),
+        Some(RecoveryAction::Retried),
+    );
+    agg.apply(WorkflowEvent::FailureRecorded {
+        failure,
+        recorded_at: crate::domain::types::TimestampUtc::now(),
+    });
+
+    let data = get_data_mut(&mut agg);
+    assert!(data.last_failure().is_none());
+    assert_eq!(data.failure_history().len(), 1);
+}
+
 // ============================================================================
 // Worktree Tests
 // ============================================================================
@@ -428,6 +453,7 @@ async fn implementation_round_completed_succeeds_with_implementation_state() {
             WorkflowCommand::ImplementationRoundCompleted {
                 iteration: Iteration::first(),
                 fingerprint: 12345,
+                diff_path: Some(PathBuf::from("/tmp/implementation_diff_1.diff")),
             },
             &services,
         )
@@ -439,10 +465,15 @@ async fn implementation_round_completed_succeeds_with_implementation_state() {
         WorkflowEvent::ImplementationRoundCompleted {
             iteration,
             fingerprint,
+            diff_path,
             ..
         } => {
             assert_eq!(*iteration, Iteration::first());
             assert_eq!(*fingerprint, 12345);
+            assert_eq!(
+                diff_path.as_deref(),
+                Some(Path::new("/tmp/implementation_diff_1.diff"))
+            );
         }
         _ => panic!("Expected ImplementationRoundCompleted event"),
     }
@@ -183,6 +183,7 @@ async fn review_cycle_started_emits_event_in_reviewing_phase() {
             WorkflowCommand::ReviewCycleStarted {
                 mode: mode.clone(),
                 reviewers: reviewers.clone(),
+                preset: None,
             },
             &services,
         )
@@ -228,6 +229,7 @@ async fn reviewer_approved_records_approval_in_sequential_state() {
     agg.apply(WorkflowEvent::ReviewCycleStarted {
         mode,
         reviewers: reviewers.clone(),
+        preset: None,
         started_at: crate::domain::types::TimestampUtc::now(),
     });
 
@@ -285,6 +287,7 @@ async fn reviewer_rejected_records_rejection_in_sequential_state() {
     agg.apply(WorkflowEvent::ReviewCycleStarted {
         mode,
         reviewers: reviewers.clone(),
+        preset: None,
         started_at: crate::domain::types::TimestampUtc::now(),
     });
 
@@ -295,6 +298,7 @@ async fn reviewer_rejected_records_rejection_in_sequential_state() {
             WorkflowCommand::ReviewerRejected {
                 reviewer_id: reviewer_id.clone(),
                 feedback_path,
+                low_confidence: true,
             },
             &services,
         )
@@ -306,9 +310,11 @@ async fn reviewer_rejected_records_rejection_in_sequential_state() {
         WorkflowEvent::ReviewerRejected {
             reviewer_id: event_reviewer_id,
             feedback_path: event_feedback_path,
+            low_confidence,
             ..
         } => {
             assert_eq!(event_reviewer_id, &reviewer_id);
+            assert!(low_confidence);
             assert_eq!(
                 event_feedback_path.as_path(),
                 PathBuf::from("/feedback/reviewer-1.md")
@@ -328,6 +334,42 @@ async fn reviewer_rejected_records_rejection_in_sequential_state() {
     }
 }
 
+#[tokio::test]
+async fn reviewer_substituted_only_while_reviewing() {
+    use crate::domain::types::AgentId;
+
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    let cmd = || WorkflowCommand::RecordReviewerSubstituted {
+        failed_reviewer: "codex".into(),
+        substitute: "gemini".into(),
+        reason: "codex: command not found".to_string(),
+    };
+
+    assert!(agg.handle(cmd(), &services).await.is_err());
+
+    agg.apply(WorkflowEvent::PlanningCompleted {
+        plan_path: PathBuf::from("/plan.md").into(),
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+    let events = agg.handle(cmd(), &services).await.unwrap();
+    match &events[..] {
+        [WorkflowEvent::ReviewerSubstituted {
+            failed_reviewer,
+            substitute,
+            ..
+        }] => {
+            assert_eq!(failed_reviewer, &AgentId::from("codex"));
+            assert_eq!(substitute, &AgentId::from("gemini"));
+        }
+        _ => panic!("Expected ReviewerSubstituted event"),
+    }
+
+    // Informational: the phase is unchanged
+    agg.apply(events.into_iter().next().unwrap());
+    assert_eq!(*get_data_mut(&mut agg).planning_phase(), Phase::Reviewing);
+}
+
 #[tokio::test]
 async fn review_cycle_completed_approved_transitions_to_complete() {
     let mut agg = initialized_aggregate();
@@ -585,6 +627,70 @@ fn aggregate_in_awaiting_planning_decision() -> WorkflowAggregate {
     agg
 }
 
+#[tokio::test]
+async fn reviewer_verdict_revised_approves_plan_once_no_rejection_remains() {
+    let mut agg = aggregate_in_awaiting_planning_decision();
+    let services = test_services();
+    let cmd = |plan_approved| WorkflowCommand::ReviewerVerdictRevised {
+        reviewer_id: "codex".into(),
+        plan_approved,
+    };
+
+    // Another reviewer still rejects: the decision stays open
+    let events = agg.handle(cmd(false), &services).await.unwrap();
+    agg.apply(events.into_iter().next().unwrap());
+    assert_eq!(
+        *get_data_mut(&mut agg).planning_phase(),
+        Phase::AwaitingPlanningDecision
+    );
+
+    let events = agg.handle(cmd(true), &services).await.unwrap();
+    assert!(matches!(
+        events[..],
+        [WorkflowEvent::ReviewerVerdictRevised {
+            plan_approved: true,
+            ..
+        }]
+    ));
+    agg.apply(events.into_iter().next().unwrap());
+    let data = get_data_mut(&mut agg);
+    assert_eq!(*data.planning_phase(), Phase::Complete);
+    assert_eq!(
+        data.last_feedback_status().cloned(),
+        Some(FeedbackStatus::Approved)
+    );
+
+    // Appeals are only heard while the plan loop stops on rejections
+    assert!(agg.handle(cmd(true), &services).await.is_err());
+}
+
+#[tokio::test]
+async fn reviewer_verdict_revised_at_the_reviewing_gate_approves_plan() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    agg.apply(WorkflowEvent::PlanningCompleted {
+        plan_path: PathBuf::from("/plan.md").into(),
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+    agg.apply(WorkflowEvent::ReviewCycleCompleted {
+        approved: false,
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+
+    let events = agg
+        .handle(
+            WorkflowCommand::ReviewerVerdictRevised {
+                reviewer_id: "codex".into(),
+                plan_approved: true,
+            },
+            &services,
+        )
+        .await
+        .unwrap();
+    agg.apply(events.into_iter().next().unwrap());
+    assert_eq!(*get_data_mut(&mut agg).planning_phase(), Phase::Complete);
+}
+
 #[tokio::test]
 async fn revising_started_from_awaiting_planning_decision_emits_max_iterations_extended() {
     let mut agg = aggregate_in_awaiting_planning_decision();
@@ -794,3 +900,126 @@ async fn user_requested_implementation_from_awaiting_planning_decision_succeeds(
         WorkflowEvent::ImplementationStarted { .. }
     ));
 }
+
+#[tokio::test]
+async fn rename_workflow_updates_feature_name() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+
+    let events = agg
+        .handle(
+            WorkflowCommand::RenameWorkflow {
+                feature_name: "renamed-feature".into(),
+            },
+            &services,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], WorkflowEvent::WorkflowRenamed { .. }));
+    agg.apply(events[0].clone());
+    assert_eq!(
+        get_data_mut(&mut agg).feature_name().as_str(),
+        "renamed-feature"
+    );
+}
+
+#[tokio::test]
+async fn import_plan_completes_planning_once() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    let import = || WorkflowCommand::ImportPlan {
+        source: "/tmp/plan.md".to_string(),
+    };
+
+    let events = agg.handle(import(), &services).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], WorkflowEvent::PlanImported { .. }));
+    agg.apply(events[0].clone());
+    assert_eq!(*get_data_mut(&mut agg).planning_phase(), Phase::Complete);
+
+    // Only a fresh workflow can adopt a plan
+    assert!(agg.handle(import(), &services).await.is_err());
+}
+
+#[tokio::test]
+async fn amend_objective_restarts_planning_at_same_iteration() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    agg.apply(WorkflowEvent::PlanningCompleted {
+        plan_path: PathBuf::from("/plan.md").into(),
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+
+    let events = agg
+        .handle(
+            WorkflowCommand::AmendObjective {
+                objective: "Sharper objective".into(),
+            },
+            &services,
+        )
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    agg.apply(events[0].clone());
+
+    let data = get_data_mut(&mut agg);
+    assert_eq!(data.objective().0, "Sharper objective");
+    assert_eq!(*data.planning_phase(), Phase::Planning);
+    assert_eq!(data.iteration().0, 1);
+}
+
+#[tokio::test]
+async fn plan_structure_check_is_recorded_only_while_planning() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    let check = || WorkflowCommand::RecordPlanStructureCheck {
+        missing_sections: vec!["Risks".to_string()],
+        followup_requested: true,
+    };
+
+    let events = agg.handle(check(), &services).await.unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        WorkflowEvent::PlanStructureChecked {
+            missing_sections,
+            followup_requested,
+            ..
+        } => {
+            assert_eq!(missing_sections, &vec!["Risks".to_string()]);
+            assert!(followup_requested);
+        }
+        _ => panic!("Expected PlanStructureChecked event"),
+    }
+
+    agg.apply(WorkflowEvent::PlanningCompleted {
+        plan_path: PathBuf::from("/plan.md").into(),
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+    assert!(agg.handle(check(), &services).await.is_err());
+}
+
+#[tokio::test]
+async fn plan_metadata_is_recorded_after_revisions_too() {
+    let mut agg = initialized_aggregate();
+    let services = test_services();
+    let record = || WorkflowCommand::RecordPlanMetadata {
+        metadata: crate::domain::PlanMetadata {
+            title: "Add caching".to_string(),
+            risk: crate::domain::RiskLevel::Medium,
+            effort: "2 days".to_string(),
+            components: Vec::new(),
+        },
+    };
+
+    agg.apply(WorkflowEvent::PlanningCompleted {
+        plan_path: PathBuf::from("/plan.md").into(),
+        completed_at: crate::domain::types::TimestampUtc::now(),
+    });
+    let events = agg.handle(record(), &services).await.unwrap();
+    assert!(matches!(
+        &events[..],
+        [WorkflowEvent::PlanMetadataRecorded { metadata, .. }] if metadata.title == "Add caching"
+    ));
+}
@@ -200,3 +200,54 @@ fn new_with_cycle_initializes_with_review_counts() {
     assert_eq!(order[1], AgentId::from("agent-a"), "More reviews last");
     assert_eq!(state.current_reviewer_index(), 0);
 }
+
+// =============================================================================
+// SequentialExitPolicy tests
+// =============================================================================
+
+#[test]
+fn exit_policy_decides_when_rejections_stop_the_cycle() {
+    let counts: HashMap<AgentId, usize> = HashMap::new();
+    let ids = &["agent-a", "agent-b", "agent-c"];
+
+    let mut first = SequentialReviewState::new_with_cycle(ids, &counts);
+    first.record_rejection("agent-a");
+    assert_eq!(first.current_reviewer_index(), 0);
+
+    let mut after_two = SequentialReviewState::new_with_cycle(ids, &counts)
+        .with_exit_policy(SequentialExitPolicy::AfterRejections(2));
+    after_two.record_rejection("agent-a");
+    assert_eq!(after_two.current_reviewer_index(), 1);
+    after_two.record_rejection("agent-b");
+    assert_eq!(after_two.current_reviewer_index(), 1);
+    assert_eq!(after_two.rejections().len(), 2);
+
+    assert!(!SequentialExitPolicy::RunAll.stops_after(3));
+    after_two.increment_version();
+    assert!(after_two.rejections().is_empty());
+}
+
+#[test]
+fn exit_policy_round_trips_as_name_or_count() {
+    let policies: Vec<SequentialExitPolicy> =
+        serde_json::from_str(r#"["first_rejection", "run_all", 2]"#).unwrap();
+    assert_eq!(
+        policies,
+        vec![
+            SequentialExitPolicy::FirstRejection,
+            SequentialExitPolicy::RunAll,
+            SequentialExitPolicy::AfterRejections(2),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&policies).unwrap(),
+        r#"["first_rejection","run_all",2]"#
+    );
+    assert!(serde_json::from_str::<SequentialExitPolicy>(r#""never""#).is_err());
+
+    // States recorded before the policy existed stop at the first rejection
+    let state: SequentialReviewState =
+        serde_json::from_str(r#"{"current_reviewer_index":0,"plan_version":1,"approvals":{}}"#)
+            .unwrap();
+    assert_eq!(state.exit_policy(), SequentialExitPolicy::FirstRejection);
+}
@@ -0,0 +1,44 @@
+//! Tests for feature name slugs.
+
+use super::*;
+
+#[test]
+fn slugify_makes_kebab_case() {
+    assert_eq!(
+        FeatureName::slugify("Add OAuth/SSO login 🚀").as_str(),
+        "add-oauth-sso-login"
+    );
+    assert_eq!(
+        FeatureName::slugify("  \"user_auth\"\n").as_str(),
+        "user-auth"
+    );
+    assert_eq!(FeatureName::slugify("🚀 ✨").as_str(), "feature");
+}
+
+#[test]
+fn slugify_caps_long_names_at_a_hyphen() {
+    let slug = FeatureName::slugify(&"word ".repeat(20));
+    assert!(slug.as_str().len() <= FeatureName::MAX_LEN);
+    assert!(slug.as_str().ends_with("word"));
+    assert!(FeatureName::is_slug(slug.as_str()));
+}
+
+#[test]
+fn is_slug_rejects_unsafe_names() {
+    assert!(FeatureName::is_slug("api-rate-limiting"));
+    for name in ["", "Api", "a b", "a/b", "-a", "a-", "a--b", "naïve"] {
+        assert!(!FeatureName::is_slug(name), "{:?}", name);
+    }
+}
+
+#[test]
+fn unique_appends_the_first_free_suffix() {
+    let taken = ["user-auth", "user-auth-2"];
+    let name = FeatureName::from("user-auth").unique(|name| taken.contains(&name));
+    assert_eq!(name.as_str(), "user-auth-3");
+
+    let long = "x".repeat(FeatureName::MAX_LEN);
+    let name = FeatureName::slugify(&long).unique(|name| name == long);
+    assert_eq!(name.as_str().len(), FeatureName::MAX_LEN);
+    assert!(name.as_str().ends_with("-2"));
+}
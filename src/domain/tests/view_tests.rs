@@ -3,8 +3,8 @@
 use super::*;
 use crate::domain::review::ReviewMode;
 use crate::domain::types::{
-    AgentId, FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc,
-    WorkingDir,
+    AgentId, FeatureName, FeedbackPath, MaxIterations, Objective, PhaseLabel, PlanPath,
+    TimestampUtc, WorkingDir,
 };
 use crate::domain::WorkflowEvent;
 use std::path::PathBuf;
@@ -29,6 +29,7 @@ fn review_cycle_started_event() -> WorkflowEvent {
     WorkflowEvent::ReviewCycleStarted {
         mode: ReviewMode::Parallel,
         reviewers: vec![AgentId::from("reviewer-1"), AgentId::from("reviewer-2")],
+        preset: None,
         started_at: TimestampUtc::now(),
     }
 }
@@ -44,6 +45,7 @@ fn reviewer_rejected_event(reviewer_id: &str, feedback_path: &str) -> WorkflowEv
     WorkflowEvent::ReviewerRejected {
         reviewer_id: AgentId::from(reviewer_id),
         feedback_path: FeedbackPath::from(PathBuf::from(feedback_path)),
+        low_confidence: false,
         rejected_at: TimestampUtc::now(),
     }
 }
@@ -61,6 +63,24 @@ fn current_cycle_reviews_starts_empty() {
     assert!(view.current_cycle_reviews().is_empty());
 }
 
+#[test]
+fn review_cycle_started_records_reviewer_preset() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    let mut event = review_cycle_started_event();
+    if let WorkflowEvent::ReviewCycleStarted { preset, .. } = &mut event {
+        *preset = Some("thorough".to_string());
+    }
+    view.apply_event(&agg_id, &event, 2);
+    assert_eq!(view.reviewer_preset(), Some("thorough"));
+
+    // A later cycle without a preset clears it
+    view.apply_event(&agg_id, &review_cycle_started_event(), 3);
+    assert_eq!(view.reviewer_preset(), None);
+}
+
 #[test]
 fn reviewer_approved_adds_to_current_cycle_reviews() {
     let mut view = WorkflowView::default();
@@ -459,3 +479,468 @@ fn old_sessions_without_user_feedback_history_load_correctly() {
     // user_feedback_history should default to empty Vec
     assert!(view.user_feedback_history().is_empty());
 }
+
+#[test]
+fn issue_linked_sets_issue_link_and_survives_serialization() {
+    use crate::domain::types::{IssueLink, IssueTracker};
+
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert!(view.issue_link().is_none());
+
+    let issue = IssueLink::new(
+        IssueTracker::Jira,
+        "JIRA-1234".to_string(),
+        "https://jira.local/browse/JIRA-1234".to_string(),
+        "Add retry to uploads".to_string(),
+    );
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::IssueLinked {
+            issue: issue.clone(),
+        },
+        2,
+    );
+    assert_eq!(view.issue_link(), Some(&issue));
+
+    let json = serde_json::to_string(&view).expect("serialize");
+    let restored: WorkflowView = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(restored.issue_link(), Some(&issue));
+}
+
+#[test]
+fn feedback_triage_keeps_accepted_items_and_accumulates_parked() {
+    use crate::domain::review::{FeedbackItem, TriageDecision, TriagedFeedback};
+
+    let triaged = |text: &str, decision: TriageDecision| TriagedFeedback {
+        item: FeedbackItem {
+            reviewer: "claude".to_string(),
+            section: None,
+            text: text.to_string(),
+        },
+        decision,
+    };
+    let triage_event = |items: Vec<TriagedFeedback>| WorkflowEvent::FeedbackTriaged {
+        items,
+        triaged_at: TimestampUtc::now(),
+    };
+
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert!(view.accepted_feedback().is_none());
+
+    view.apply_event(
+        &agg_id,
+        &triage_event(vec![
+            triaged("Fix the migration", TriageDecision::Accept),
+            triaged("Add metrics", TriageDecision::Park),
+            triaged("Rename the module", TriageDecision::Dismiss),
+        ]),
+        2,
+    );
+    let accepted = view.accepted_feedback().expect("triaged");
+    assert_eq!(accepted.len(), 1);
+    assert_eq!(accepted[0].text, "Fix the migration");
+    assert_eq!(view.parked_feedback().len(), 1);
+
+    // Triage applies to one revision; parked items carry over without duplicates
+    view.apply_event(&agg_id, &revision_completed_event(), 3);
+    assert!(view.feedback_triage().is_none());
+    view.apply_event(
+        &agg_id,
+        &triage_event(vec![
+            triaged("Add metrics", TriageDecision::Park),
+            triaged("Add docs", TriageDecision::Park),
+        ]),
+        4,
+    );
+    assert_eq!(view.accepted_feedback(), Some(Vec::new()));
+    assert_eq!(view.parked_feedback().len(), 2);
+}
+
+#[test]
+fn plan_externally_modified_resets_sequential_approvals_until_revision() {
+    use crate::domain::review::SequentialReviewState;
+
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ReviewCycleStarted {
+            mode: ReviewMode::Sequential(SequentialReviewState::new_with_cycle(
+                &["reviewer-1", "reviewer-2"],
+                &Default::default(),
+            )),
+            reviewers: vec![AgentId::from("reviewer-1"), AgentId::from("reviewer-2")],
+            preset: None,
+            started_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    view.apply_event(&agg_id, &reviewer_approved_event("reviewer-1"), 3);
+
+    let detected_at = TimestampUtc::now();
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::PlanExternallyModified {
+            content_hash: "abc".to_string(),
+            detected_at,
+        },
+        4,
+    );
+    assert!(view.plan_externally_modified());
+    assert_eq!(view.plan_written_at(), Some(detected_at));
+    let Some(ReviewMode::Sequential(state)) = view.review_mode() else {
+        panic!("expected sequential review mode");
+    };
+    assert_eq!(state.plan_version(), 2);
+    assert!(state.approvals().is_empty());
+    assert!(state.needs_cycle_start());
+
+    view.apply_event(&agg_id, &revision_completed_event(), 5);
+    assert!(!view.plan_externally_modified());
+}
+
+#[test]
+fn completion_actions_are_recorded_until_next_acceptance() {
+    use crate::domain::types::CompletionActionOutcome;
+
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    let outcome = CompletionActionOutcome {
+        action: "copy plan to docs".to_string(),
+        succeeded: false,
+        detail: "Failed to create docs".to_string(),
+    };
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::CompletionActionRecorded {
+            outcome: outcome.clone(),
+            recorded_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(view.completion_actions(), &[outcome]);
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::UserApproved {
+            approved_at: TimestampUtc::now(),
+        },
+        3,
+    );
+    assert!(view.completion_actions().is_empty());
+}
+
+#[test]
+fn plan_imported_completes_planning() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert!(view.imported_plan().is_none());
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::PlanImported {
+            source: "/tmp/plan.md".to_string(),
+            imported_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(view.imported_plan(), Some("/tmp/plan.md"));
+    assert_eq!(view.planning_phase(), Some(Phase::Complete));
+}
+
+#[test]
+fn objective_amended_replaces_objective_and_notes_it() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ObjectiveAmended {
+            objective: "Sharper objective".into(),
+            amended_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(
+        view.objective().map(|o| o.0.as_str()),
+        Some("Sharper objective")
+    );
+    assert_eq!(view.planning_phase(), Some(Phase::Planning));
+    assert_eq!(
+        view.user_feedback_history(),
+        ["The objective was amended to: Sharper objective"]
+    );
+}
+
+#[test]
+fn planning_reopened_plans_again_from_the_feedback() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::UserApproved {
+            approved_at: TimestampUtc::now(),
+        },
+        2,
+    );
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::PlanningReopened {
+            feedback: "src/api/routes.rs changed".to_string(),
+            reopened_at: TimestampUtc::now(),
+        },
+        3,
+    );
+    assert_eq!(view.planning_phase(), Some(Phase::Planning));
+    assert_eq!(view.user_feedback_history(), ["src/api/routes.rs changed"]);
+}
+
+#[test]
+fn approval_gate_rejection_sends_the_plan_back_to_planning() {
+    use crate::domain::types::{ApprovalGate, GateStep, Iteration};
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    view.apply_event(&agg_id, &review_cycle_started_event(), 2);
+
+    let opened = GateStep::Opened {
+        iteration: Iteration(1),
+    };
+    let at = TimestampUtc::now();
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::approval_gate(ApprovalGate::Reviewing, opened.clone(), at),
+        3,
+    );
+    assert_eq!(view.open_approval_gate(), Some(ApprovalGate::Reviewing));
+
+    let rejected = GateStep::Rejected {
+        feedback: "Split the migration".to_string(),
+    };
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::approval_gate(ApprovalGate::Reviewing, rejected, at),
+        4,
+    );
+    assert_eq!(view.open_approval_gate(), None);
+    assert_eq!(view.planning_phase(), Some(Phase::Planning));
+    assert_eq!(view.user_feedback_history(), ["Split the migration"]);
+
+    // Rejecting an implementation round leaves planning alone
+    view.apply_event(&agg_id, &review_cycle_started_event(), 5);
+    let rejected = GateStep::Rejected {
+        feedback: "Keep the old API".to_string(),
+    };
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::approval_gate(ApprovalGate::ImplementationRound, opened, at),
+        6,
+    );
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::approval_gate(ApprovalGate::ImplementationRound, rejected, at),
+        7,
+    );
+    assert_eq!(view.planning_phase(), Some(Phase::Reviewing));
+    assert_eq!(view.user_feedback_history().len(), 1);
+}
+
+#[test]
+fn plan_metadata_recorded_is_kept_until_a_new_workflow() {
+    let agg_id = test_aggregate_id();
+    let mut view = WorkflowView::default();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert!(view.plan_metadata().is_none());
+
+    let metadata = crate::domain::PlanMetadata {
+        title: "Add caching".to_string(),
+        risk: crate::domain::RiskLevel::Low,
+        effort: "2 days".to_string(),
+        components: vec!["cache".to_string()],
+    };
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::PlanMetadataRecorded {
+            metadata: metadata.clone(),
+            recorded_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(view.plan_metadata(), Some(&metadata));
+    view.apply_event(&agg_id, &revision_completed_event(), 3);
+    assert_eq!(view.plan_metadata(), Some(&metadata));
+
+    view.apply_event(&agg_id, &workflow_created_event(), 4);
+    assert!(view.plan_metadata().is_none());
+}
+
+#[test]
+fn user_decision_undone_reverts_the_decision() {
+    use crate::domain::types::UndoneDecision;
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    view.apply_event(&agg_id, &user_declined_event("Earlier feedback"), 2);
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::UserApprovedWithConditions {
+            conditions: "Ship behind a flag".to_string(),
+            approved_at: TimestampUtc::now(),
+        },
+        3,
+    );
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ImplementationStarted {
+            max_iterations: MaxIterations(3),
+            started_at: TimestampUtc::now(),
+        },
+        4,
+    );
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::UserDecisionUndone {
+            decision: UndoneDecision::ImplementationRequested,
+            undone_at: TimestampUtc::now(),
+        },
+        5,
+    );
+    assert_eq!(view.approval_conditions(), None);
+    assert!(view.implementation_state().is_none());
+
+    view.apply_event(&agg_id, &user_declined_event("Use Postgres"), 6);
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::UserDecisionUndone {
+            decision: UndoneDecision::Declined {
+                feedback: "Use Postgres".to_string(),
+            },
+            undone_at: TimestampUtc::now(),
+        },
+        7,
+    );
+    assert_eq!(view.user_feedback_history(), ["Earlier feedback"]);
+}
+
+#[test]
+fn review_mode_changed_is_kept_for_resume() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert_eq!(view.review_sequential(), None);
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ReviewModeChanged {
+            sequential: true,
+            reason: "2 parallel round(s) in a row waited for provider slots".to_string(),
+            changed_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(view.review_sequential(), Some(true));
+}
+
+#[test]
+fn command_output_waits_in_the_view_until_delivered() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    for (seq, output) in [(2, "tests: 2 failed"), (3, "lint: clean")] {
+        view.apply_event(
+            &agg_id,
+            &WorkflowEvent::CommandOutputQueued {
+                output: output.to_string(),
+                queued_at: TimestampUtc::now(),
+            },
+            seq,
+        );
+    }
+    assert_eq!(
+        view.pending_command_output(),
+        ["tests: 2 failed", "lint: clean"]
+    );
+
+    // Output queued after the prompt was built stays for the next one
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::CommandOutputDelivered {
+            count: 1,
+            phase: PhaseLabel::Implementing,
+            delivered_at: TimestampUtc::now(),
+        },
+        4,
+    );
+    assert_eq!(view.pending_command_output(), ["lint: clean"]);
+}
+
+#[test]
+fn replan_watch_is_recorded_in_the_view() {
+    use crate::domain::types::WatchSpec;
+
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    assert!(view.replan_watch().is_none());
+
+    let watch = WatchSpec {
+        globs: vec!["src/api/**".to_string()],
+        auto: true,
+    };
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ReplanWatchEnabled {
+            watch: watch.clone(),
+            enabled_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    assert_eq!(view.replan_watch(), Some(&watch));
+}
+
+#[test]
+fn section_reviews_are_kept_until_the_review_cycle_completes() {
+    let mut view = WorkflowView::default();
+    let agg_id = test_aggregate_id();
+    view.apply_event(&agg_id, &workflow_created_event(), 1);
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::PlanSectionReviewed {
+            section: "Steps".to_string(),
+            body: "1. Add the limiter".to_string(),
+            reviewer_id: AgentId::from("claude"),
+            approved: false,
+            feedback: "Missing rollout".to_string(),
+            summary: "No rollout plan".to_string(),
+            low_confidence: false,
+            reviewed_at: TimestampUtc::now(),
+        },
+        2,
+    );
+    let review = &view.section_reviews()[0];
+    assert_eq!(review.section, "Steps");
+    assert_eq!(review.body, "1. Add the limiter");
+    assert!(!review.approved);
+    assert_eq!(review.feedback, "Missing rollout");
+
+    view.apply_event(
+        &agg_id,
+        &WorkflowEvent::ReviewCycleCompleted {
+            approved: false,
+            completed_at: TimestampUtc::now(),
+        },
+        3,
+    );
+    assert!(view.section_reviews().is_empty());
+}
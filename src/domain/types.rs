@@ -42,9 +42,73 @@ impl std::fmt::Display for WorkflowId {
 pub struct FeatureName(pub String);
 
 impl FeatureName {
+    /// Longest feature name, in characters.
+    pub const MAX_LEN: usize = 50;
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Kebab-case slug of `text`, safe in branch names and file paths.
+    ///
+    /// ASCII letters and digits are lowercased and every other run of characters
+    /// becomes one hyphen. Long names are cut at a hyphen within the cap where
+    /// possible; a name with nothing left becomes `feature`.
+    pub fn slugify(text: &str) -> Self {
+        let mut slug = String::new();
+        for c in text.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        Self(cap_slug(slug, Self::MAX_LEN))
+    }
+
+    /// Whether `name` is already a slug `slugify` would leave unchanged.
+    pub fn is_slug(name: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= Self::MAX_LEN
+            && !name.starts_with('-')
+            && !name.ends_with('-')
+            && !name.contains("--")
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    /// The first of this name, `<name>-2`, `<name>-3`, ... that is not `taken`.
+    pub fn unique(self, taken: impl Fn(&str) -> bool) -> Self {
+        if !taken(self.as_str()) {
+            return self;
+        }
+        let mut n = 2u32;
+        loop {
+            let suffix = format!("-{}", n);
+            let base = cap_slug(self.0.clone(), Self::MAX_LEN - suffix.len());
+            let candidate = format!("{}{}", base, suffix);
+            if !taken(&candidate) {
+                return Self(candidate);
+            }
+            n += 1;
+        }
+    }
+}
+
+/// Shortens an ASCII slug to `max` characters and trims its hyphens.
+fn cap_slug(mut slug: String, max: usize) -> String {
+    if slug.len() > max {
+        slug.truncate(max);
+        // Prefer a word boundary unless it throws away most of the name
+        if let Some(cut) = slug.rfind('-').filter(|cut| *cut > max / 2) {
+            slug.truncate(cut);
+        }
+    }
+    match slug.trim_matches('-') {
+        "" => "feature".to_string(),
+        trimmed => trimmed.to_string(),
+    }
 }
 
 impl From<&str> for FeatureName {
@@ -167,6 +231,22 @@ impl ReviewerResult {
     }
 }
 
+/// A reviewer's verdict on one plan section, given while the planner was still
+/// writing (`reviewing.pipelined`). Stored in the view to survive session resume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanSectionReview {
+    /// Heading of the reviewed section.
+    pub section: String,
+    /// The section's text as reviewed; the verdict no longer applies once it changes.
+    pub body: String,
+    pub reviewer_id: AgentId,
+    pub approved: bool,
+    pub feedback: String,
+    pub summary: String,
+    /// Whether a rejection fell below the confidence threshold.
+    pub low_confidence: bool,
+}
+
 /// Current iteration number (1-indexed).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Iteration(pub u32);
@@ -426,6 +506,57 @@ pub enum FeedbackStatus {
     NeedsRevision,
 }
 
+/// A final plan decision taken back with `/undo` during its grace window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UndoneDecision {
+    /// Approval, with or without conditions.
+    Approved,
+    /// Approval that also started implementation.
+    ImplementationRequested,
+    /// Decline with feedback.
+    Declined { feedback: String },
+}
+
+/// A human approval gate from the workflow config's `approval_gates`, named
+/// after the phase it follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalGate {
+    /// After planning, before the first review starts.
+    Planning,
+    /// After a rejecting review round, before the revision.
+    Reviewing,
+    /// After a revision, before the next review round.
+    Revising,
+    /// After each implementation round, before its review.
+    ImplementationRound,
+}
+
+impl ApprovalGate {
+    /// Name as written in the workflow config.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Planning => "planning",
+            Self::Reviewing => "reviewing",
+            Self::Revising => "revising",
+            Self::ImplementationRound => "implementation_round",
+        }
+    }
+}
+
+/// A step of an approval gate, recorded as its own event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateStep {
+    /// The gate is waiting for the user.
+    Opened { iteration: Iteration },
+    /// The user let the workflow continue.
+    Passed,
+    /// The user sent the work back with feedback.
+    Rejected { feedback: String },
+}
+
 /// Implementation phase state tracking.
 ///
 /// # Invariants
@@ -538,6 +669,16 @@ impl ImplementationPhaseState {
     }
 }
 
+/// Files to watch after the plan is accepted (`--watch`), re-planning when
+/// they change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchSpec {
+    /// Globs relative to the working directory
+    pub globs: Vec<String>,
+    /// Re-plan without asking (`--auto`)
+    pub auto: bool,
+}
+
 /// Persisted worktree state for session resume.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorktreeState {
@@ -584,6 +725,107 @@ impl WorktreeState {
     }
 }
 
+/// Issue tracker a workflow objective was imported from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueTracker {
+    Jira,
+    Linear,
+}
+
+impl IssueTracker {
+    /// Returns the display name of the tracker.
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueTracker::Jira => "Jira",
+            IssueTracker::Linear => "Linear",
+        }
+    }
+}
+
+/// Link from a workflow back to the tracker issue it was imported from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueLink {
+    tracker: IssueTracker,
+    key: String,
+    url: String,
+    title: String,
+}
+
+impl IssueLink {
+    /// Creates a new issue link.
+    pub fn new(tracker: IssueTracker, key: String, url: String, title: String) -> Self {
+        Self {
+            tracker,
+            key,
+            url,
+            title,
+        }
+    }
+
+    /// Returns the tracker the issue lives in.
+    pub fn tracker(&self) -> IssueTracker {
+        self.tracker
+    }
+
+    /// Returns the issue key (e.g., `JIRA-1234`).
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the browser URL of the issue.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the issue title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+/// Outcome of one configured completion action (see `completion_actions` in the workflow config).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionActionOutcome {
+    /// Short description of the action, e.g. "copy plan to docs/plans".
+    pub action: String,
+    /// Whether the action succeeded.
+    pub succeeded: bool,
+    /// What the action did, or why it failed.
+    pub detail: String,
+}
+
+/// Risk level declared in a structured plan's front matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        }
+    }
+}
+
+/// YAML front matter of a structured plan (`plan_structure.front_matter`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanMetadata {
+    pub title: String,
+    pub risk: RiskLevel,
+    /// Estimated effort as the planner wrote it, e.g. "2 days".
+    pub effort: String,
+    /// Parts of the codebase the plan changes.
+    #[serde(default)]
+    pub components: Vec<String>,
+}
+
 /// Agent conversation state for resume.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AgentConversationState {
@@ -695,3 +937,7 @@ impl UiMode {
         }
     }
 }
+
+#[cfg(test)]
+#[path = "tests/types_tests.rs"]
+mod tests;
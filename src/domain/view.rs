@@ -5,12 +5,13 @@
 
 use crate::domain::cqrs::WorkflowAggregate;
 use crate::domain::failure::{FailureContext, MAX_FAILURE_HISTORY};
-use crate::domain::review::ReviewMode;
+use crate::domain::review::{FeedbackItem, ReviewMode, TriageDecision, TriagedFeedback};
 use crate::domain::types::{
-    AgentConversationState, AgentId, AwaitingDecisionReason, FeatureName, FeedbackPath,
-    FeedbackStatus, ImplementationPhase, ImplementationPhaseState, InvocationRecord, Iteration,
-    MaxIterations, Objective, Phase, PlanPath, ReviewerResult, UiMode, WorkflowId, WorkingDir,
-    WorktreeState,
+    AgentConversationState, AgentId, ApprovalGate, AwaitingDecisionReason, CompletionActionOutcome,
+    FeatureName, FeedbackPath, FeedbackStatus, ImplementationPhase, ImplementationPhaseState,
+    InvocationRecord, IssueLink, Iteration, MaxIterations, Objective, Phase, PlanMetadata,
+    PlanPath, PlanSectionReview, ReviewerResult, TimestampUtc, UiMode, UndoneDecision, WatchSpec,
+    WorkflowId, WorkingDir, WorktreeState,
 };
 use crate::domain::WorkflowEvent;
 use serde::{Deserialize, Serialize};
@@ -43,10 +44,62 @@ pub struct WorkflowView {
     /// Cleared when a new review cycle starts or revision completes.
     #[serde(default)]
     current_cycle_reviews: Vec<ReviewerResult>,
+    /// Backup reviewers standing in during the current review cycle, keyed by
+    /// the reviewer they replace. Cleared when a new review cycle starts.
+    #[serde(default)]
+    reviewer_substitutions: HashMap<AgentId, AgentId>,
     /// Accumulated user feedback from interrupts/declines.
     /// Used to provide context to the planning agent on restart.
     #[serde(default)]
     user_feedback_history: Vec<String>,
+    /// Tracker issue the objective was imported from (`--from-issue`).
+    #[serde(default)]
+    issue_link: Option<IssueLink>,
+    /// Triage decisions for the current rejected round's feedback.
+    /// Cleared when a new review cycle starts or revision completes.
+    #[serde(default)]
+    feedback_triage: Option<Vec<TriagedFeedback>>,
+    /// Feedback items parked across all rounds, kept in the plan's backlog section.
+    #[serde(default)]
+    parked_feedback: Vec<FeedbackItem>,
+    /// When the workflow last wrote or acknowledged the plan file.
+    /// Later on-disk changes are edits made outside the workflow.
+    #[serde(default)]
+    plan_written_at: Option<TimestampUtc>,
+    /// Set when the user edited the plan outside the workflow; cleared by the next revision.
+    #[serde(default)]
+    plan_externally_modified: bool,
+    /// Outcomes of the completion actions run after the workflow was last accepted.
+    #[serde(default)]
+    completion_actions: Vec<CompletionActionOutcome>,
+    /// Conditions the user attached to approving the plan.
+    #[serde(default)]
+    approval_conditions: Option<String>,
+    /// Reviewer preset recorded by the latest review cycle, if one was selected.
+    #[serde(default)]
+    reviewer_preset: Option<String>,
+    /// Plan file the workflow was started from (`planning implement`), skipping planning.
+    #[serde(default)]
+    imported_plan: Option<String>,
+    /// Front matter of the latest plan, when the structured plan format is on.
+    #[serde(default)]
+    plan_metadata: Option<PlanMetadata>,
+    /// Approval gate waiting for the user; the workflow asks again on resume.
+    #[serde(default)]
+    open_approval_gate: Option<ApprovalGate>,
+    /// Review mode switched to during the session, overriding `reviewing.sequential`.
+    #[serde(default)]
+    review_sequential: Option<bool>,
+    /// Output of user slash commands waiting for the next planner or implementer prompt.
+    #[serde(default)]
+    pending_command_output: Vec<String>,
+    /// Files watched for re-planning once the plan is accepted (`--watch`).
+    #[serde(default)]
+    replan_watch: Option<WatchSpec>,
+    /// Section verdicts given while the plan was written (`reviewing.pipelined`).
+    /// Cleared when planning starts again or a review cycle completes.
+    #[serde(default)]
+    section_reviews: Vec<PlanSectionReview>,
 }
 
 impl WorkflowView {
@@ -80,31 +133,50 @@ impl WorkflowView {
                 self.review_mode = None;
                 self.last_feedback_status = None;
                 self.approval_overridden = false;
+                self.approval_conditions = None;
                 self.implementation_state = None;
                 self.agent_conversations.clear();
                 self.invocations.clear();
                 self.last_failure = None;
                 self.failure_history.clear();
                 self.worktree_info = None;
+                self.issue_link = None;
+                self.feedback_triage = None;
+                self.parked_feedback.clear();
+                self.plan_written_at = None;
+                self.plan_externally_modified = false;
+                self.open_approval_gate = None;
+                self.completion_actions.clear();
+                self.imported_plan = None;
+                self.plan_metadata = None;
             }
 
             WorkflowEvent::PlanningStarted { .. } => {
                 self.planning_phase = Some(Phase::Planning);
+                self.section_reviews.clear();
             }
 
-            WorkflowEvent::PlanningCompleted { plan_path, .. } => {
+            WorkflowEvent::PlanningCompleted {
+                plan_path,
+                completed_at,
+            } => {
                 self.plan_path = Some(plan_path.clone());
+                self.plan_written_at = Some(*completed_at);
                 self.planning_phase = Some(Phase::Reviewing);
             }
 
-            WorkflowEvent::ReviewCycleStarted { mode, .. } => {
+            WorkflowEvent::ReviewCycleStarted { mode, preset, .. } => {
                 self.review_mode = Some(mode.clone());
+                self.reviewer_preset = preset.clone();
                 self.planning_phase = Some(Phase::Reviewing);
                 // Clear previous cycle's reviews when starting a new cycle
                 self.current_cycle_reviews.clear();
+                self.reviewer_substitutions.clear();
+                self.feedback_triage = None;
             }
 
-            WorkflowEvent::ReviewerApproved { reviewer_id, .. } => {
+            WorkflowEvent::ReviewerApproved { reviewer_id, .. }
+            | WorkflowEvent::ReviewerVerdictReused { reviewer_id, .. } => {
                 if let Some(ReviewMode::Sequential(ref mut state)) = self.review_mode {
                     state.record_approval_simple(reviewer_id.clone());
                     state.advance_to_next_reviewer();
@@ -129,7 +201,33 @@ impl WorkflowView {
                 ));
             }
 
+            WorkflowEvent::ReviewerVerdictRevised {
+                reviewer_id,
+                plan_approved,
+                ..
+            } => {
+                for review in &mut self.current_cycle_reviews {
+                    if review.reviewer_id == *reviewer_id {
+                        *review = ReviewerResult::approved(reviewer_id.clone());
+                    }
+                }
+                if *plan_approved {
+                    self.planning_phase = Some(Phase::Complete);
+                    self.last_feedback_status = Some(FeedbackStatus::Approved);
+                }
+            }
+
+            WorkflowEvent::ReviewerSubstituted {
+                failed_reviewer,
+                substitute,
+                ..
+            } => {
+                self.reviewer_substitutions
+                    .insert(failed_reviewer.clone(), substitute.clone());
+            }
+
             WorkflowEvent::ReviewCycleCompleted { approved, .. } => {
+                self.section_reviews.clear();
                 self.planning_phase = Some(if *approved {
                     Phase::Complete
                 } else {
@@ -142,12 +240,28 @@ impl WorkflowView {
                 });
             }
 
+            WorkflowEvent::FeedbackTriaged { items, .. } => {
+                for triaged in items {
+                    if triaged.decision == TriageDecision::Park
+                        && !self.parked_feedback.contains(&triaged.item)
+                    {
+                        self.parked_feedback.push(triaged.item.clone());
+                    }
+                }
+                self.feedback_triage = Some(items.clone());
+            }
+
             WorkflowEvent::RevisingStarted { .. } => {
                 self.planning_phase = Some(Phase::Revising);
             }
 
-            WorkflowEvent::RevisionCompleted { plan_path, .. } => {
+            WorkflowEvent::RevisionCompleted {
+                plan_path,
+                completed_at,
+            } => {
                 self.plan_path = Some(plan_path.clone());
+                self.plan_written_at = Some(*completed_at);
+                self.plan_externally_modified = false;
                 self.planning_phase = Some(Phase::Reviewing);
                 let current = self
                     .iteration
@@ -159,6 +273,7 @@ impl WorkflowView {
                 }
                 // Clear reviews after revision - new review cycle will start
                 self.current_cycle_reviews.clear();
+                self.feedback_triage = None;
             }
 
             WorkflowEvent::PlanningMaxIterationsReached { .. } => {
@@ -171,10 +286,23 @@ impl WorkflowView {
 
             WorkflowEvent::UserApproved { .. } => {
                 self.planning_phase = Some(Phase::Complete);
+                self.completion_actions.clear();
+            }
+
+            WorkflowEvent::UserApprovedWithConditions {
+                conditions,
+                approved_at,
+            } => {
+                self.planning_phase = Some(Phase::Complete);
+                self.completion_actions.clear();
+                self.approval_conditions = Some(conditions.clone());
+                // The conditions were appended to the plan just before this event
+                self.plan_written_at = Some(*approved_at);
             }
 
             WorkflowEvent::UserRequestedImplementation { .. } => {
-                // No state change - ImplementationStarted follows
+                // Completion actions run again once the implementation is accepted
+                self.completion_actions.clear();
             }
 
             WorkflowEvent::UserOverrideApproval { .. } => {
@@ -189,6 +317,27 @@ impl WorkflowView {
                 }
             }
 
+            WorkflowEvent::UserDecisionUndone {
+                decision,
+                undone_at,
+            } => match decision {
+                UndoneDecision::Approved | UndoneDecision::ImplementationRequested => {
+                    if self.approval_conditions.take().is_some() {
+                        // The conditions were removed from the plan just before this event
+                        self.plan_written_at = Some(*undone_at);
+                    }
+                    if matches!(decision, UndoneDecision::ImplementationRequested) {
+                        self.implementation_state = None;
+                    }
+                }
+                UndoneDecision::Declined { feedback } => {
+                    // Only non-empty feedback was recorded
+                    if !feedback.is_empty() {
+                        self.user_feedback_history.pop();
+                    }
+                }
+            },
+
             WorkflowEvent::UserAborted { .. } => {
                 // No state change
             }
@@ -274,7 +423,10 @@ impl WorkflowView {
             }
 
             WorkflowEvent::FailureRecorded { failure, .. } => {
-                self.last_failure = Some(failure.clone());
+                // Failures already recovered from are kept as history only
+                if !failure.is_resolved() {
+                    self.last_failure = Some(failure.clone());
+                }
                 self.failure_history.push(failure.clone());
                 if self.failure_history.len() > MAX_FAILURE_HISTORY {
                     let excess = self.failure_history.len() - MAX_FAILURE_HISTORY;
@@ -285,6 +437,115 @@ impl WorkflowView {
             WorkflowEvent::WorktreeAttached { worktree_state } => {
                 self.worktree_info = Some(worktree_state.clone());
             }
+
+            WorkflowEvent::IssueLinked { issue } => {
+                self.issue_link = Some(issue.clone());
+            }
+
+            WorkflowEvent::PlanExternallyModified { detected_at, .. } => {
+                self.plan_written_at = Some(*detected_at);
+                self.plan_externally_modified = true;
+                if let Some(ReviewMode::Sequential(ref mut state)) = self.review_mode {
+                    state.increment_version();
+                    state.clear_cycle_order();
+                }
+            }
+
+            WorkflowEvent::CompletionActionRecorded { outcome, .. } => {
+                self.completion_actions.push(outcome.clone());
+            }
+
+            WorkflowEvent::ReviewModeChanged { sequential, .. } => {
+                self.review_sequential = Some(*sequential);
+            }
+
+            WorkflowEvent::CommandOutputQueued { output, .. } => {
+                self.pending_command_output.push(output.clone());
+            }
+
+            WorkflowEvent::CommandOutputDelivered { count, .. } => {
+                let delivered = (*count).min(self.pending_command_output.len());
+                self.pending_command_output.drain(..delivered);
+            }
+
+            WorkflowEvent::ReplanWatchEnabled { watch, .. } => {
+                self.replan_watch = Some(watch.clone());
+            }
+
+            // Shown as a live warning by the TUI; nothing to derive
+            WorkflowEvent::PhaseDurationExceeded { .. } => {}
+
+            // Reported in the output panel as it happens; nothing to derive
+            WorkflowEvent::PlanStructureChecked { .. } => {}
+            WorkflowEvent::PlanSectionCompleted { .. } => {}
+
+            WorkflowEvent::PlanSectionReviewed {
+                section,
+                body,
+                reviewer_id,
+                approved,
+                feedback,
+                summary,
+                low_confidence,
+                ..
+            } => {
+                self.section_reviews.push(PlanSectionReview {
+                    section: section.clone(),
+                    body: body.clone(),
+                    reviewer_id: reviewer_id.clone(),
+                    approved: *approved,
+                    feedback: feedback.clone(),
+                    summary: summary.clone(),
+                    low_confidence: *low_confidence,
+                });
+            }
+
+            WorkflowEvent::PlanMetadataRecorded { metadata, .. } => {
+                self.plan_metadata = Some(metadata.clone());
+            }
+
+            WorkflowEvent::WorkflowRenamed { feature_name, .. } => {
+                self.feature_name = Some(feature_name.clone());
+            }
+
+            WorkflowEvent::PlanImported {
+                source,
+                imported_at,
+            } => {
+                self.imported_plan = Some(source.clone());
+                self.plan_written_at = Some(*imported_at);
+                self.planning_phase = Some(Phase::Complete);
+            }
+
+            WorkflowEvent::PlanningReopened { feedback, .. } => {
+                self.planning_phase = Some(Phase::Planning);
+                self.section_reviews.clear();
+                self.user_feedback_history.push(feedback.clone());
+            }
+
+            WorkflowEvent::ApprovalGateOpened { gate, .. } => {
+                self.open_approval_gate = Some(*gate);
+            }
+
+            WorkflowEvent::ApprovalGatePassed { .. } => {
+                self.open_approval_gate = None;
+            }
+
+            WorkflowEvent::ApprovalGateRejected { gate, feedback, .. } => {
+                self.open_approval_gate = None;
+                if *gate != ApprovalGate::ImplementationRound {
+                    self.planning_phase = Some(Phase::Planning);
+                    self.user_feedback_history.push(feedback.clone());
+                }
+            }
+
+            WorkflowEvent::ObjectiveAmended { objective, .. } => {
+                self.objective = Some(objective.clone());
+                self.planning_phase = Some(Phase::Planning);
+                // Tells a resumed planning conversation why it is planning again
+                self.user_feedback_history
+                    .push(format!("The objective was amended to: {}", objective.0));
+            }
         }
     }
 
@@ -358,6 +619,38 @@ impl WorkflowView {
         self.review_mode.as_ref()
     }
 
+    /// Returns the reviewer preset of the latest review cycle.
+    pub fn reviewer_preset(&self) -> Option<&str> {
+        self.reviewer_preset.as_deref()
+    }
+
+    /// Returns whether reviewing was switched to sequential (`Some(true)`) or parallel
+    /// during the session.
+    pub fn review_sequential(&self) -> Option<bool> {
+        self.review_sequential
+    }
+
+    /// Returns the output of user slash commands queued for the next planner or
+    /// implementer prompt, oldest first.
+    pub fn pending_command_output(&self) -> &[String] {
+        &self.pending_command_output
+    }
+
+    /// Returns the section verdicts given while the plan was written, oldest first.
+    pub fn section_reviews(&self) -> &[PlanSectionReview] {
+        &self.section_reviews
+    }
+
+    /// Returns the files watched for re-planning once the plan is accepted.
+    pub fn replan_watch(&self) -> Option<&WatchSpec> {
+        self.replan_watch.as_ref()
+    }
+
+    /// Returns the plan file the workflow was started from, if planning was skipped.
+    pub fn imported_plan(&self) -> Option<&str> {
+        self.imported_plan.as_deref()
+    }
+
     /// Returns the implementation state.
     pub fn implementation_state(&self) -> Option<&ImplementationPhaseState> {
         self.implementation_state.as_ref()
@@ -373,11 +666,26 @@ impl WorkflowView {
         self.worktree_info.as_ref()
     }
 
+    /// Returns the tracker issue the objective was imported from.
+    pub fn issue_link(&self) -> Option<&IssueLink> {
+        self.issue_link.as_ref()
+    }
+
+    /// Returns the front matter of the latest plan.
+    pub fn plan_metadata(&self) -> Option<&PlanMetadata> {
+        self.plan_metadata.as_ref()
+    }
+
     /// Returns whether approval was overridden.
     pub fn approval_overridden(&self) -> bool {
         self.approval_overridden
     }
 
+    /// Returns the conditions the plan was approved on, if any.
+    pub fn approval_conditions(&self) -> Option<&str> {
+        self.approval_conditions.as_deref()
+    }
+
     /// Returns the last event sequence number.
     pub fn last_event_sequence(&self) -> u64 {
         self.last_event_sequence
@@ -389,11 +697,59 @@ impl WorkflowView {
         &self.current_cycle_reviews
     }
 
+    /// Returns the backup reviewers standing in during the current review cycle,
+    /// keyed by the reviewer they replace.
+    pub fn reviewer_substitutions(&self) -> &HashMap<AgentId, AgentId> {
+        &self.reviewer_substitutions
+    }
+
     /// Returns accumulated user feedback from interrupts/declines.
     pub fn user_feedback_history(&self) -> &[String] {
         &self.user_feedback_history
     }
 
+    /// Returns the triage decisions for the current round, if it was triaged.
+    pub fn feedback_triage(&self) -> Option<&[TriagedFeedback]> {
+        self.feedback_triage.as_deref()
+    }
+
+    /// Returns the feedback items accepted in the current round's triage.
+    /// None when the round was not triaged (all feedback applies).
+    pub fn accepted_feedback(&self) -> Option<Vec<FeedbackItem>> {
+        self.feedback_triage.as_ref().map(|items| {
+            items
+                .iter()
+                .filter(|t| t.decision == TriageDecision::Accept)
+                .map(|t| t.item.clone())
+                .collect()
+        })
+    }
+
+    /// Returns feedback items parked across all rounds.
+    pub fn parked_feedback(&self) -> &[FeedbackItem] {
+        &self.parked_feedback
+    }
+
+    /// Returns when the workflow last wrote or acknowledged the plan file.
+    pub fn plan_written_at(&self) -> Option<TimestampUtc> {
+        self.plan_written_at
+    }
+
+    /// Returns true if the user edited the plan outside the workflow since the last revision.
+    pub fn plan_externally_modified(&self) -> bool {
+        self.plan_externally_modified
+    }
+
+    /// Returns the approval gate waiting for the user, if any.
+    pub fn open_approval_gate(&self) -> Option<ApprovalGate> {
+        self.open_approval_gate
+    }
+
+    /// Returns the outcomes of the completion actions run after the last acceptance.
+    pub fn completion_actions(&self) -> &[CompletionActionOutcome] {
+        &self.completion_actions
+    }
+
     /// Returns the current UI mode based on implementation state.
     pub fn ui_mode(&self) -> UiMode {
         match &self.implementation_state {
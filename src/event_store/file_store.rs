@@ -4,11 +4,15 @@
 //! - Optimistic concurrency via file locking
 //! - Snapshots for faster aggregate loading
 //! - Atomic writes via temp file + rename
+//! - Per-record checksums, verified on load (see [`crate::event_store::integrity`])
+//! - Schema versions, with older records upcast on load (see [`crate::event_store::schema`])
 
 use crate::domain::errors::WorkflowError;
 use crate::domain::types::TimestampUtc;
 use crate::domain::WorkflowAggregate;
 use crate::domain::WorkflowEvent;
+use crate::event_store::integrity::{checksum_matches, record_corruption, with_checksum};
+use crate::event_store::schema::{self, decode_event, CURRENT_SCHEMA_VERSION};
 use async_trait::async_trait;
 use chrono::Utc;
 use cqrs_es::{
@@ -29,6 +33,9 @@ pub struct StoredEvent {
     pub recorded_at: TimestampUtc,
     pub event_type: String,
     pub event_version: String,
+    /// Format version of this record; see [`crate::event_store::schema`].
+    #[serde(default = "schema::legacy_schema_version")]
+    pub schema_version: u32,
     pub event: WorkflowEvent,
     pub metadata: HashMap<String, String>,
 }
@@ -101,7 +108,14 @@ impl EventStore<WorkflowAggregate> for FileEventStore {
 
         for line in reader.lines() {
             let line = line.map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
-            let stored: StoredEvent = serde_json::from_str(&line)
+            let value: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|e| AggregateError::DeserializationError(Box::new(e)))?;
+            if !checksum_matches(&value) {
+                return Err(AggregateError::UnexpectedError(Box::new(
+                    std::io::Error::new(ErrorKind::InvalidData, "event checksum mismatch"),
+                )));
+            }
+            let stored = decode_event(value)
                 .map_err(|e| AggregateError::DeserializationError(Box::new(e)))?;
 
             if stored.aggregate_id == aggregate_id {
@@ -133,16 +147,18 @@ impl EventStore<WorkflowAggregate> for FileEventStore {
         let mut aggregate = WorkflowAggregate::default();
         let mut current_sequence = 0u64;
 
-        // Try to load from snapshot first
+        let events = self.load_events(aggregate_id).await?;
+        let last_logged = events.last().map_or(0, |e| e.sequence as u64);
+
+        // Start from the snapshot, unless it covers events a log repair discarded
         if let Some(snapshot) = load_snapshot(&self.snapshot_path)? {
-            if snapshot.aggregate_id == aggregate_id {
+            if snapshot.aggregate_id == aggregate_id && snapshot.sequence <= last_logged {
                 aggregate = snapshot.state;
                 current_sequence = snapshot.sequence;
             }
         }
 
         // Apply events after snapshot
-        let events = self.load_events(aggregate_id).await?;
         for event in events {
             let seq = event.sequence as u64;
             if seq > current_sequence {
@@ -208,12 +224,14 @@ impl EventStore<WorkflowAggregate> for FileEventStore {
                 recorded_at: TimestampUtc(Utc::now()),
                 event_type: event.event_type(),
                 event_version: event.event_version(),
+                schema_version: CURRENT_SCHEMA_VERSION,
                 event: event.clone(),
                 metadata: metadata.clone(),
             };
 
-            let line = serde_json::to_string(&record)
-                .map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
+            let line = with_checksum(&record)
+                .map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?
+                .to_string();
 
             writeln!(file, "{}", line).map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
 
@@ -253,12 +271,18 @@ impl EventStore<WorkflowAggregate> for FileEventStore {
 }
 
 /// Load a snapshot from disk.
+///
+/// A corrupt snapshot is ignored; the aggregate is then rebuilt from the event log.
 fn load_snapshot(path: &PathBuf) -> Result<Option<StoredSnapshot>, AggregateError<WorkflowError>> {
     let content = match std::fs::read_to_string(path) {
         Ok(c) => c,
         Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
         Err(e) => return Err(AggregateError::UnexpectedError(Box::new(e))),
     };
+    if let Some(reason) = record_corruption(&content) {
+        tracing::warn!("Ignoring aggregate snapshot {}: {}", path.display(), reason);
+        return Ok(None);
+    }
 
     let snapshot: StoredSnapshot = serde_json::from_str(&content)
         .map_err(|e| AggregateError::DeserializationError(Box::new(e)))?;
@@ -277,8 +301,9 @@ fn save_snapshot(
             .map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
     }
 
-    let content = serde_json::to_string(snapshot)
-        .map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
+    let content = with_checksum(snapshot)
+        .map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?
+        .to_string();
 
     // Write to temp file, then rename for atomicity
     let tmp_path = path.with_extension("json.tmp");
@@ -306,8 +331,10 @@ fn read_last_sequence(
 
     for line in reader.lines() {
         let line = line.map_err(|e| AggregateError::UnexpectedError(Box::new(e)))?;
-        let stored: StoredEvent = serde_json::from_str(&line)
+        let value: serde_json::Value = serde_json::from_str(&line)
             .map_err(|e| AggregateError::DeserializationError(Box::new(e)))?;
+        let stored =
+            decode_event(value).map_err(|e| AggregateError::DeserializationError(Box::new(e)))?;
 
         if stored.aggregate_id == aggregate_id {
             last_sequence = stored.sequence;
@@ -0,0 +1,188 @@
+//! Checksums and crash repair for persisted workflow state.
+//!
+//! Event log lines, aggregate snapshots and session snapshots carry a `checksum`
+//! field: a SHA-256 prefix of the record's JSON with that field removed. Records
+//! written before checksums were added have none and are accepted as-is.
+//!
+//! A crash while appending can leave a truncated line at the end of the event log.
+//! [`repair_event_log`] cuts the log back to the last valid event, saving the
+//! discarded bytes next to the log, so the session can still be resumed.
+
+use crate::event_store::schema::{decode_event, record_schema_version, CURRENT_SCHEMA_VERSION};
+use anyhow::{Context, Result};
+use fs2::FileExt;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// JSON field holding a record's checksum.
+const CHECKSUM_FIELD: &str = "checksum";
+
+/// Checksum of a JSON record, ignoring any existing `checksum` field.
+fn json_checksum(value: &Value) -> String {
+    let mut value = value.clone();
+    if let Value::Object(map) = &mut value {
+        map.remove(CHECKSUM_FIELD);
+    }
+    let digest = Sha256::digest(value.to_string().as_bytes());
+    digest
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Serializes a record to a JSON object with its checksum added.
+pub fn with_checksum<T: Serialize>(record: &T) -> serde_json::Result<Value> {
+    let mut value = serde_json::to_value(record)?;
+    let checksum = json_checksum(&value);
+    if let Value::Object(map) = &mut value {
+        map.insert(CHECKSUM_FIELD.to_string(), Value::String(checksum));
+    }
+    Ok(value)
+}
+
+/// Whether the record's checksum matches. Records without one are accepted.
+pub fn checksum_matches(value: &Value) -> bool {
+    match value.get(CHECKSUM_FIELD).and_then(Value::as_str) {
+        Some(expected) => json_checksum(value) == expected,
+        None => true,
+    }
+}
+
+/// Why a persisted record is unusable, or None if it is intact.
+pub fn record_corruption(content: &str) -> Option<String> {
+    match serde_json::from_str::<Value>(content) {
+        Err(e) => Some(format!("unparseable JSON ({})", e)),
+        Ok(value) if !checksum_matches(&value) => Some("checksum mismatch".to_string()),
+        Ok(_) => None,
+    }
+}
+
+/// What was discarded when an event log was repaired.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRepair {
+    /// Events kept (all lines before the first invalid one).
+    pub kept_events: usize,
+    /// Sequence of the last kept event (0 if none).
+    pub last_sequence: u64,
+    /// Lines discarded, starting with the invalid one.
+    pub lost_lines: usize,
+    /// Bytes discarded.
+    pub lost_bytes: u64,
+    /// Why the first discarded line was rejected.
+    pub reason: String,
+    /// Where the discarded bytes were saved.
+    pub backup_path: PathBuf,
+}
+
+impl LogRepair {
+    /// One-line description for the user.
+    pub fn describe(&self) -> String {
+        format!(
+            "Event log repaired: line {} {}; discarded {} line(s) ({} bytes) after event #{}, saved to {}",
+            self.kept_events + 1,
+            self.reason,
+            self.lost_lines,
+            self.lost_bytes,
+            self.last_sequence,
+            self.backup_path.display()
+        )
+    }
+}
+
+/// Checks one event log line, returning its sequence or why it is invalid.
+fn validate_event_line(line: &[u8]) -> Result<u64, String> {
+    let text = std::str::from_utf8(line).map_err(|_| "is not valid UTF-8".to_string())?;
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| format!("is not valid JSON ({})", e))?;
+    if !checksum_matches(&value) {
+        return Err("failed its checksum".to_string());
+    }
+    if record_schema_version(&value) > CURRENT_SCHEMA_VERSION {
+        // Written by a newer release: unreadable here, but not corrupt
+        return value["sequence"]
+            .as_u64()
+            .ok_or_else(|| "has no sequence".to_string());
+    }
+    let event = decode_event(value).map_err(|e| format!("is not a valid event ({})", e))?;
+    Ok(event.sequence)
+}
+
+/// Truncates the event log to its last valid event.
+///
+/// Returns None when the log is missing or intact. A final event that is complete
+/// but lacks its newline (the crash hit between the two writes) is kept and the
+/// newline restored.
+pub fn repair_event_log(log_path: &Path) -> Result<Option<LogRepair>> {
+    let mut file = match OpenOptions::new().read(true).write(true).open(log_path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("Failed to open {}", log_path.display())),
+    };
+    FileExt::lock_exclusive(&file).context("Failed to lock event log")?;
+    let content = fs::read(log_path)
+        .with_context(|| format!("Failed to read event log: {}", log_path.display()))?;
+
+    let mut valid_len = 0usize;
+    let mut kept_events = 0usize;
+    let mut last_sequence = 0u64;
+    let mut failure: Option<String> = None;
+    for line in content.split_inclusive(|b| *b == b'\n') {
+        let body = line.strip_suffix(b"\n").unwrap_or(line);
+        if body.iter().all(u8::is_ascii_whitespace) {
+            valid_len += line.len();
+            continue;
+        }
+        match validate_event_line(body) {
+            Ok(sequence) => {
+                valid_len += line.len();
+                kept_events += 1;
+                last_sequence = sequence;
+            }
+            Err(reason) => {
+                failure = Some(reason);
+                break;
+            }
+        }
+    }
+
+    let Some(reason) = failure else {
+        if !content.is_empty() && !content.ends_with(b"\n") {
+            file.write_all(b"\n")
+                .context("Failed to terminate event log")?;
+        }
+        return Ok(None);
+    };
+
+    let lost = content.split_at(valid_len).1;
+    let backup_path = PathBuf::from(format!(
+        "{}.corrupt-{}",
+        log_path.display(),
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    fs::write(&backup_path, lost)
+        .with_context(|| format!("Failed to save discarded events: {}", backup_path.display()))?;
+    file.set_len(valid_len as u64)
+        .context("Failed to truncate event log")?;
+    file.sync_all().context("Failed to sync event log")?;
+
+    Ok(Some(LogRepair {
+        kept_events,
+        last_sequence,
+        lost_lines: lost
+            .split(|b| *b == b'\n')
+            .filter(|l| !l.is_empty())
+            .count(),
+        lost_bytes: lost.len() as u64,
+        reason,
+        backup_path,
+    }))
+}
+
+#[cfg(test)]
+#[path = "tests/integrity_tests.rs"]
+mod tests;
@@ -4,5 +4,7 @@
 //! for the CQRS/ES workflow aggregate.
 
 pub mod file_store;
+pub mod integrity;
+pub mod schema;
 
 pub use file_store::{FileAggregateContext, FileEventStore, StoredEvent, StoredSnapshot};
@@ -0,0 +1,118 @@
+//! Schema versioning for event log records.
+//!
+//! Every record carries a `schema_version`; records written before the field
+//! existed are version 1. When the stored format of `WorkflowEvent` changes, bump
+//! [`CURRENT_SCHEMA_VERSION`] and add an upcaster that rewrites a record of the
+//! previous version in its JSON form (renaming a field, filling one that was
+//! added, reshaping an event). Records are upcast when read, after the checksum
+//! is verified, so logs are never rewritten and old sessions keep loading.
+//!
+//! Records from a newer release are rejected with [`SchemaError::NewerVersion`]
+//! instead of being misread.
+
+use crate::event_store::StoredEvent;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// Schema version written to new records.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// JSON field holding a record's schema version.
+const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Rewrites a record from `to_version - 1` to `to_version`.
+struct Upcaster {
+    to_version: u32,
+    apply: fn(&mut Value),
+}
+
+/// Ordered by `to_version`, one per version after 1.
+const UPCASTERS: &[Upcaster] = &[Upcaster {
+    to_version: 2,
+    apply: upcast_v1_to_v2,
+}];
+
+/// Version 1 predates envelope versioning; its records may lack fields that were
+/// added to events while it was current.
+fn upcast_v1_to_v2(record: &mut Value) {
+    default_event_field(
+        record,
+        "reviewer_rejected",
+        "low_confidence",
+        Value::Bool(false),
+    );
+}
+
+/// Sets `field` on `event_type` payloads that lack it.
+fn default_event_field(record: &mut Value, event_type: &str, field: &str, default: Value) {
+    if let Some(Value::Object(fields)) = record
+        .get_mut("event")
+        .and_then(|event| event.get_mut(event_type))
+    {
+        fields.entry(field).or_insert(default);
+    }
+}
+
+/// Why a record could not be read under the current schema.
+#[derive(Debug)]
+pub enum SchemaError {
+    /// Written by a newer release.
+    NewerVersion(u32),
+    /// Not a valid event record after upcasting.
+    Invalid(serde_json::Error),
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NewerVersion(version) => write!(
+                f,
+                "event schema version {} is newer than supported version {}; upgrade planning-agent",
+                version, CURRENT_SCHEMA_VERSION
+            ),
+            Self::Invalid(e) => write!(f, "invalid event record: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// Schema version of a raw record, 1 if it has none.
+pub fn record_schema_version(record: &Value) -> u32 {
+    record
+        .get(SCHEMA_VERSION_FIELD)
+        .and_then(Value::as_u64)
+        .map_or(1, |version| version as u32)
+}
+
+/// Brings a raw record up to [`CURRENT_SCHEMA_VERSION`].
+pub fn upcast(mut record: Value) -> Result<Value, SchemaError> {
+    let version = record_schema_version(&record);
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(SchemaError::NewerVersion(version));
+    }
+    for upcaster in UPCASTERS.iter().filter(|u| u.to_version > version) {
+        (upcaster.apply)(&mut record);
+        if let Value::Object(map) = &mut record {
+            map.insert(
+                SCHEMA_VERSION_FIELD.to_string(),
+                Value::from(upcaster.to_version),
+            );
+        }
+    }
+    Ok(record)
+}
+
+/// Upcasts and deserializes a raw event log record.
+pub fn decode_event(record: Value) -> Result<StoredEvent, SchemaError> {
+    serde_json::from_value(upcast(record)?).map_err(SchemaError::Invalid)
+}
+
+/// The version stamped on records that predate the field.
+pub fn legacy_schema_version() -> u32 {
+    1
+}
+
+#[cfg(test)]
+#[path = "tests/schema_tests.rs"]
+mod tests;
@@ -0,0 +1,122 @@
+use super::*;
+use crate::domain::actor::bootstrap_view_from_events;
+use crate::domain::types::{Phase, TimestampUtc};
+use crate::domain::WorkflowEvent;
+use crate::event_store::integrity::{checksum_matches, repair_event_log};
+use crate::event_store::FileEventStore;
+use cqrs_es::EventStore;
+use std::collections::HashMap;
+use tempfile::tempdir;
+
+/// Event log from a release before schema versioning: the first records predate
+/// checksums, and the rejection predates `low_confidence`.
+const V1_LOG: &str = include_str!("fixtures/v1_events.jsonl");
+const V1_AGGREGATE_ID: &str = "5f0c7d2e-8a4b-4c1e-9f3a-2b6d8e1a4c70";
+
+fn v1_rejection() -> Value {
+    serde_json::from_str(V1_LOG.lines().nth(4).unwrap()).unwrap()
+}
+
+#[test]
+fn test_upcast_v1_fills_added_fields_and_stamps_version() {
+    let record = upcast(v1_rejection()).unwrap();
+    assert_eq!(record_schema_version(&record), CURRENT_SCHEMA_VERSION);
+    assert_eq!(
+        record["event"]["reviewer_rejected"]["low_confidence"],
+        Value::Bool(false)
+    );
+}
+
+#[test]
+fn test_current_records_are_not_rewritten() {
+    let mut record = upcast(v1_rejection()).unwrap();
+    record["event"]["reviewer_rejected"]["low_confidence"] = Value::Bool(true);
+    assert_eq!(upcast(record.clone()).unwrap(), record);
+}
+
+#[test]
+fn test_newer_schema_is_rejected() {
+    let mut record = v1_rejection();
+    record["schema_version"] = Value::from(CURRENT_SCHEMA_VERSION + 1);
+    let err = decode_event(record).unwrap_err();
+    assert!(matches!(err, SchemaError::NewerVersion(v) if v == CURRENT_SCHEMA_VERSION + 1));
+    assert!(err.to_string().contains("upgrade planning-agent"));
+}
+
+#[test]
+fn test_v1_fixture_lines_keep_valid_checksums() {
+    for line in V1_LOG.lines() {
+        let value: Value = serde_json::from_str(line).unwrap();
+        assert!(
+            checksum_matches(&value),
+            "fixture line failed checksum: {}",
+            line
+        );
+        assert!(decode_event(value).is_ok());
+    }
+}
+
+#[test]
+fn test_v1_fixture_replays_into_view() {
+    let dir = tempdir().unwrap();
+    let log_path = dir.path().join("events.jsonl");
+    std::fs::write(&log_path, V1_LOG).unwrap();
+
+    let view = bootstrap_view_from_events(&log_path, V1_AGGREGATE_ID);
+    assert_eq!(view.last_event_sequence(), 6);
+    assert_eq!(
+        view.feature_name().map(|f| f.0.as_str()),
+        Some("login-rate-limit")
+    );
+    assert_eq!(view.planning_phase(), Some(Phase::Revising));
+    assert_eq!(view.current_cycle_reviews().len(), 1);
+}
+
+#[tokio::test]
+async fn test_v1_fixture_loads_and_accepts_new_events() {
+    let dir = tempdir().unwrap();
+    let store = FileEventStore::new(
+        dir.path().join("events.jsonl"),
+        dir.path().join("snapshot.json"),
+        0,
+    );
+    std::fs::write(&store.log_path, V1_LOG).unwrap();
+
+    let context = store.load_aggregate(V1_AGGREGATE_ID).await.unwrap();
+    assert_eq!(context.current_sequence, 6);
+
+    let event = WorkflowEvent::RevisingStarted {
+        feedback_summary: "Add a per-IP limit".to_string(),
+        started_at: TimestampUtc::now(),
+    };
+    store
+        .commit(vec![event], context, HashMap::new())
+        .await
+        .unwrap();
+
+    // The mixed-version log reads back in full, and the new record is current
+    let events = store.load_events(V1_AGGREGATE_ID).await.unwrap();
+    assert_eq!(events.len(), 7);
+    let last_line = std::fs::read_to_string(&store.log_path)
+        .unwrap()
+        .lines()
+        .last()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .unwrap();
+    assert_eq!(record_schema_version(&last_line), CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_repair_keeps_records_from_newer_releases() {
+    let dir = tempdir().unwrap();
+    let log_path = dir.path().join("events.jsonl");
+    let newer = serde_json::json!({
+        "aggregate_id": V1_AGGREGATE_ID,
+        "sequence": 7,
+        "schema_version": CURRENT_SCHEMA_VERSION + 1,
+        "event": { "some_future_event": {} },
+    });
+    std::fs::write(&log_path, format!("{}{}\n", V1_LOG, newer)).unwrap();
+
+    assert!(repair_event_log(&log_path).unwrap().is_none());
+}
@@ -4,7 +4,9 @@
 //! allowing each planning session to work in an isolated branch without
 //! affecting the user's main working directory.
 
+use crate::domain::types::FeatureName;
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -31,6 +33,15 @@ pub struct WorktreeInfo {
     pub has_submodules: bool,
 }
 
+impl WorktreeInfo {
+    /// Where `dir`, inside the original repository, is in the worktree.
+    pub fn worktree_dir_for(&self, dir: &Path) -> PathBuf {
+        dir.strip_prefix(&self.original_dir)
+            .map(|relative| self.worktree_path.join(relative))
+            .unwrap_or_else(|_| self.worktree_path.clone())
+    }
+}
+
 /// Result of attempting to set up a worktree.
 pub enum WorktreeSetupResult {
     /// Successfully created a worktree
@@ -119,6 +130,47 @@ fn has_submodules(repo_root: &Path) -> bool {
         .unwrap_or(false)
 }
 
+/// Branch name for a session worktree: `planning-agent/<feature slug>-<session short id>`.
+pub fn session_branch_name(feature_name: &str, session_id: &str) -> String {
+    // Session IDs are UUIDs (ASCII hex + hyphens), safe to slice at char boundary
+    let short_id = session_id.get(..8).unwrap_or(session_id);
+    format!(
+        "planning-agent/{}-{}",
+        FeatureName::slugify(feature_name).0,
+        short_id
+    )
+}
+
+/// Local branch names of the repository at `dir`; empty outside a git repo.
+pub fn local_branches(dir: &Path) -> Vec<String> {
+    match run_git(
+        dir,
+        &["for-each-ref", "--format=%(refname:short)", "refs/heads"],
+    ) {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether `branch` is named after `feature`: the feature itself or a session
+/// worktree branch created for it.
+pub fn branch_uses_feature(branch: &str, feature: &str) -> bool {
+    if branch == feature {
+        return true;
+    }
+    branch
+        .strip_prefix("planning-agent/")
+        .and_then(|rest| rest.strip_prefix(feature))
+        .and_then(|rest| rest.strip_prefix('-'))
+        .and_then(|rest| rest.split('-').next())
+        .is_some_and(|short_id| {
+            short_id.len() == 8 && short_id.chars().all(|c| c.is_ascii_hexdigit())
+        })
+}
+
 /// Create a new worktree for a session.
 ///
 /// If custom_branch is provided, use it; otherwise generate from feature name and session ID.
@@ -147,27 +199,7 @@ pub fn create_session_worktree(
     let branch_name = if let Some(custom) = custom_branch {
         custom.to_string()
     } else {
-        // Session IDs are UUIDs (ASCII hex + hyphens), safe to slice at char boundary
-        let short_id = session_id.get(..8).unwrap_or(session_id);
-        let safe_feature: String = feature_name
-            .chars()
-            .map(|c| {
-                if c.is_alphanumeric() || c == '-' {
-                    c
-                } else {
-                    '-'
-                }
-            })
-            .collect();
-
-        // Validate that sanitized feature name isn't empty after sanitization
-        let safe_feature = if safe_feature.trim_matches('-').is_empty() {
-            "feature".to_string() // Default fallback for empty/all-special-char names
-        } else {
-            safe_feature
-        };
-
-        format!("planning-agent/{}-{}", safe_feature, short_id)
+        session_branch_name(feature_name, session_id)
     };
 
     // Create worktree path
@@ -323,6 +355,230 @@ To remove the worktree after merging:
     )
 }
 
+/// How the worktree branch is brought up to date with a source branch that moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceSyncMethod {
+    /// Replay the worktree commits on top of the source branch (`git rebase`).
+    Rebase,
+    /// Merge the source branch into the worktree branch (`git merge`).
+    Merge,
+}
+
+impl SourceSyncMethod {
+    /// Parses a user-supplied method name ("rebase" or "merge").
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rebase" => Some(Self::Rebase),
+            "merge" => Some(Self::Merge),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Rebase => "rebase",
+            Self::Merge => "merge",
+        }
+    }
+}
+
+/// Result of syncing the worktree branch with its source branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceSyncOutcome {
+    /// The source branch has no commits missing from the worktree branch.
+    UpToDate,
+    /// The worktree branch now contains the source branch commits.
+    Synced,
+    /// The sync stopped on conflicts in these files (relative to the worktree root).
+    Conflicts(Vec<String>),
+}
+
+/// User choice for a single conflicted file.
+///
+/// "Ours" always means the session's worktree changes and "theirs" the source
+/// branch, independent of the sync method (git swaps the two during a rebase).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictChoice {
+    Ours,
+    Theirs,
+    /// The user (or an agent) edited the file by hand; it must be free of conflict markers.
+    Edited,
+}
+
+/// Runs a git command in `dir` and returns its output, failing on spawn errors only.
+fn run_git(dir: &Path, args: &[&str]) -> Result<std::process::Output> {
+    Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))
+}
+
+/// Counts the commits on `source_branch` that are missing from the worktree branch.
+pub fn commits_behind_source(worktree_path: &Path, source_branch: &str) -> Result<usize> {
+    let range = format!("HEAD..{}", source_branch);
+    let output = run_git(worktree_path, &["rev-list", "--count", &range])?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to compare with {}: {}",
+            source_branch,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .context("Unexpected git rev-list output")
+}
+
+/// Lists files with unresolved conflicts in the worktree.
+pub fn conflicted_files(worktree_path: &Path) -> Result<Vec<String>> {
+    let output = run_git(
+        worktree_path,
+        &["diff", "--name-only", "--diff-filter=U", "-z"],
+    )?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list conflicts: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|f| !f.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Interprets the result of a rebase/merge step: success, conflicts, or a hard failure.
+fn sync_step_outcome(
+    worktree_path: &Path,
+    output: std::process::Output,
+) -> Result<SourceSyncOutcome> {
+    if output.status.success() {
+        return Ok(SourceSyncOutcome::Synced);
+    }
+    let conflicts = conflicted_files(worktree_path)?;
+    if conflicts.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let detail = if stderr.trim().is_empty() {
+            stdout.trim().to_string()
+        } else {
+            stderr.trim().to_string()
+        };
+        anyhow::bail!("git failed: {}", detail);
+    }
+    Ok(SourceSyncOutcome::Conflicts(conflicts))
+}
+
+/// Brings the worktree branch up to date with `source_branch`.
+///
+/// Returns `UpToDate` without touching the branch when the source has not moved.
+/// On conflicts the rebase/merge is left in progress so that each file can be
+/// resolved with [`resolve_conflict`] before calling [`continue_source_sync`].
+pub fn sync_with_source(
+    worktree_path: &Path,
+    source_branch: &str,
+    method: SourceSyncMethod,
+) -> Result<SourceSyncOutcome> {
+    if commits_behind_source(worktree_path, source_branch)? == 0 {
+        return Ok(SourceSyncOutcome::UpToDate);
+    }
+    let output = match method {
+        SourceSyncMethod::Rebase => run_git(worktree_path, &["rebase", source_branch])?,
+        SourceSyncMethod::Merge => run_git(worktree_path, &["merge", "--no-edit", source_branch])?,
+    };
+    sync_step_outcome(worktree_path, output)
+}
+
+/// Returns true if the content still contains git conflict markers.
+fn has_conflict_markers(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.starts_with("<<<<<<< ") || line.starts_with(">>>>>>> "))
+}
+
+/// Resolves one conflicted file and stages it.
+pub fn resolve_conflict(
+    worktree_path: &Path,
+    file: &str,
+    method: SourceSyncMethod,
+    choice: ConflictChoice,
+) -> Result<()> {
+    // During a rebase git's "ours" is the source branch being replayed onto.
+    let side = match (choice, method) {
+        (ConflictChoice::Edited, _) => None,
+        (ConflictChoice::Ours, SourceSyncMethod::Merge)
+        | (ConflictChoice::Theirs, SourceSyncMethod::Rebase) => Some("--ours"),
+        (ConflictChoice::Theirs, SourceSyncMethod::Merge)
+        | (ConflictChoice::Ours, SourceSyncMethod::Rebase) => Some("--theirs"),
+    };
+
+    if let Some(side) = side {
+        let output = run_git(worktree_path, &["checkout", side, "--", file])?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to check out {} side of {}: {}",
+                side.trim_start_matches('-'),
+                file,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    } else {
+        let content = std::fs::read_to_string(worktree_path.join(file))
+            .with_context(|| format!("Failed to read {}", file))?;
+        if has_conflict_markers(&content) {
+            anyhow::bail!("{} still contains conflict markers", file);
+        }
+    }
+
+    let output = run_git(worktree_path, &["add", "--", file])?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to stage {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Continues an in-progress sync after all conflicts were resolved.
+///
+/// A rebase may stop again on a later commit, in which case the new conflicts are returned.
+pub fn continue_source_sync(
+    worktree_path: &Path,
+    method: SourceSyncMethod,
+) -> Result<SourceSyncOutcome> {
+    let output = match method {
+        SourceSyncMethod::Rebase => run_git(
+            worktree_path,
+            &["-c", "core.editor=true", "rebase", "--continue"],
+        )?,
+        SourceSyncMethod::Merge => run_git(worktree_path, &["commit", "--no-edit"])?,
+    };
+    sync_step_outcome(worktree_path, output)
+}
+
+/// Aborts an in-progress sync, restoring the worktree branch to its previous state.
+pub fn abort_source_sync(worktree_path: &Path, method: SourceSyncMethod) -> Result<()> {
+    let args: &[&str] = match method {
+        SourceSyncMethod::Rebase => &["rebase", "--abort"],
+        SourceSyncMethod::Merge => &["merge", "--abort"],
+    };
+    let output = run_git(worktree_path, args)?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to abort {}: {}",
+            method.label(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "tests/git_worktree_tests.rs"]
 mod tests;
@@ -1,8 +1,7 @@
 //! Main host application using egui/eframe.
 
 use crate::account_usage::types::AccountId;
-#[cfg(feature = "tray-icon")]
-use crate::host::gui::tray::{HostTray, TrayCommand};
+use crate::host::gui::tray::{create_status_indicator, StatusIndicator, TrayCommand};
 use crate::host::gui::usage_panel::{self, AccountProvider, DisplayAccountRow};
 use crate::host::rpc_server::HostEvent;
 use crate::host::state::HostState;
@@ -21,12 +20,6 @@ use super::session_table::DisplaySessionRow;
 /// Maximum number of log entries to keep.
 const MAX_LOG_ENTRIES: usize = 200;
 
-/// Usage fetch interval when sessions are active (2 minutes).
-const ACTIVE_FETCH_INTERVAL_SECS: u64 = 120;
-
-/// Usage fetch interval when no sessions are active (10 minutes).
-const IDLE_FETCH_INTERVAL_SECS: u64 = 600;
-
 /// Main host application.
 pub struct HostApp {
     state: Arc<Mutex<HostState>>,
@@ -37,17 +30,14 @@ pub struct HostApp {
     last_sync: Instant,
     /// Server port for display
     port: u16,
-    /// System tray icon (requires host-gui-tray feature)
-    #[cfg(feature = "tray-icon")]
-    tray: Option<HostTray>,
+    /// Tray icon, or the window-title fallback where no tray is available
+    status_indicator: Box<dyn StatusIndicator>,
     /// Sessions we've already notified about, keyed by (session_id, reason).
     /// This allows re-notifying if a session transitions from one state to another
     /// (e.g., from NeedsInteraction to Failed).
     notified_sessions: HashSet<(String, NotificationReason)>,
     /// Event log buffer (bounded)
     log_entries: VecDeque<LogEntry>,
-    /// Last time we fetched usage (None = never, triggers initial fetch)
-    last_usage_fetch: Option<Instant>,
     /// Last error message per account to dedupe logging
     account_error_cache: HashMap<AccountId, String>,
     /// Currently selected session for detail view
@@ -106,59 +96,21 @@ enum LogLevel {
 use super::notifications::NotificationReason;
 
 impl HostApp {
-    /// Create a new host application (with tray support).
-    #[cfg(feature = "tray-icon")]
+    /// Create a new host application.
     pub fn new(
         state: Arc<Mutex<HostState>>,
         event_rx: mpsc::UnboundedReceiver<HostEvent>,
         port: u16,
     ) -> Self {
-        // Try to create tray icon (may fail on some platforms)
-        let tray = match HostTray::new() {
-            Ok(t) => {
-                eprintln!("[host] System tray icon created");
-                Some(t)
-            }
-            Err(e) => {
-                eprintln!("[host] Warning: Could not create tray icon: {}", e);
-                None
-            }
-        };
-
         Self {
             state,
             event_rx,
             display_data: DisplayData::default(),
             last_sync: Instant::now(),
             port,
-            tray,
+            status_indicator: create_status_indicator(),
             notified_sessions: HashSet::new(),
             log_entries: VecDeque::new(),
-            last_usage_fetch: None,
-            account_error_cache: HashMap::new(),
-            selected_session_id: None,
-            session_detail: None,
-            pending_file_list: Arc::new(Mutex::new(None)),
-            pending_file_content: Arc::new(Mutex::new(None)),
-        }
-    }
-
-    /// Create a new host application (without tray support).
-    #[cfg(not(feature = "tray-icon"))]
-    pub fn new(
-        state: Arc<Mutex<HostState>>,
-        event_rx: mpsc::UnboundedReceiver<HostEvent>,
-        port: u16,
-    ) -> Self {
-        Self {
-            state,
-            event_rx,
-            display_data: DisplayData::default(),
-            last_sync: Instant::now(),
-            port,
-            notified_sessions: HashSet::new(),
-            log_entries: VecDeque::new(),
-            last_usage_fetch: None,
             account_error_cache: HashMap::new(),
             selected_session_id: None,
             session_detail: None,
@@ -328,6 +280,9 @@ impl HostApp {
                     pid: s.session.pid,
                     updated_ago: format_relative_time(&s.session.updated_at),
                     implementation_phase: s.session.implementation_phase.clone(),
+                    timeline: s.timeline.clone(),
+                    approval_request: s.session.approval_request,
+                    stale_command: s.session.stale_command.clone(),
                 })
                 .collect();
             let sessions_len = sessions.len();
@@ -456,27 +411,20 @@ impl HostApp {
         );
     }
 
-    /// Handle tray icon commands (requires host-gui-tray feature).
-    #[cfg(feature = "tray-icon")]
+    /// Handle tray menu commands (none arrive from the window-title fallback).
     fn handle_tray_commands(&mut self, ctx: &egui::Context) {
-        if let Some(ref tray) = self.tray {
-            while let Some(cmd) = tray.try_recv_command() {
-                match cmd {
-                    TrayCommand::ShowWindow => {
-                        // Request focus on the window
-                        ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
-                    }
-                    TrayCommand::Quit => {
-                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-                    }
+        while let Some(cmd) = self.status_indicator.try_recv_command() {
+            match cmd {
+                TrayCommand::ShowWindow => {
+                    // Request focus on the window
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                }
+                TrayCommand::Quit => {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                 }
             }
         }
     }
-
-    /// No-op without tray-icon feature.
-    #[cfg(not(feature = "tray-icon"))]
-    fn handle_tray_commands(&mut self, _ctx: &egui::Context) {}
 }
 
 /// Render the stats dashboard above the session table.
@@ -536,9 +484,6 @@ impl eframe::App for HostApp {
         // Process events and log them
         self.process_events();
 
-        // Check if we need to fetch usage (on startup or periodically)
-        self.maybe_fetch_usage();
-
         // Check for pending async results (file list / content)
         self.check_pending_results();
 
@@ -551,14 +496,12 @@ impl eframe::App for HostApp {
             // Check for new sessions awaiting approval and notify
             self.check_and_notify();
 
-            // Update tray icon with current counts (only if changed - method handles caching)
-            #[cfg(feature = "tray-icon")]
-            if let Some(ref mut tray) = self.tray {
-                tray.update_icon(
-                    self.display_data.running_count,
-                    self.display_data.awaiting_count,
-                );
-            }
+            // Update tray icon or window title (only if changed - indicator handles caching)
+            self.status_indicator.update_counts(
+                ctx,
+                self.display_data.running_count,
+                self.display_data.awaiting_count,
+            );
         }
 
         // Sync detail panel fields from latest display_data
@@ -694,76 +637,18 @@ impl HostApp {
                 HostEvent::SessionsUpdated => {
                     // Don't log every heartbeat, just note significant changes
                 }
-                HostEvent::CredentialsReported => {
-                    self.add_log_entry(LogEntry {
-                        timestamp: now,
-                        message: "Credentials reported from daemon".to_string(),
-                        level: LogLevel::Info,
-                    });
-                    // Trigger usage fetching (async - will update store)
-                    self.trigger_usage_fetch();
+                HostEvent::UsageReported => {
+                    self.save_usage_store();
                 }
             }
         }
     }
 
-    /// Check if we need to fetch usage (on startup or after activity-based interval).
-    ///
-    /// Polling intervals follow the plan:
-    /// - Active sessions (Planning/Reviewing/Revising): Poll every 2 minutes
-    /// - Idle (no active sessions): Poll every 10 minutes
-    fn maybe_fetch_usage(&mut self) {
-        // Determine if we have active sessions
-        let has_active_sessions = self.display_data.sessions.iter().any(|s| {
-            let phase_lower = s.phase.to_lowercase();
-            phase_lower == "planning" || phase_lower == "reviewing" || phase_lower == "revising"
-        });
-
-        // Choose interval based on activity
-        let interval = if has_active_sessions {
-            ACTIVE_FETCH_INTERVAL_SECS
-        } else {
-            IDLE_FETCH_INTERVAL_SECS
-        };
-
-        let should_fetch = match self.last_usage_fetch {
-            None => true, // Never fetched - fetch on startup
-            Some(last) => last.elapsed().as_secs() >= interval,
-        };
-
-        if should_fetch {
-            let mode = if has_active_sessions {
-                "active"
-            } else {
-                "idle"
-            };
-            eprintln!(
-                "[host-gui] Triggering usage fetch ({} mode, {}s interval)",
-                mode, interval
-            );
-            self.last_usage_fetch = Some(Instant::now());
-            self.trigger_usage_fetch();
-        }
-    }
-
-    /// Trigger background usage fetch using credentials from daemon.
-    /// Uses blocking HTTP calls via ureq, so it's safe to call from async context.
-    fn trigger_usage_fetch(&mut self) {
+    /// Persist usage reported by daemons so it survives a host restart.
+    fn save_usage_store(&self) {
         let state = self.state.clone();
-        // Spawn tokio task to fetch usage (ureq is blocking but tokio handles this)
         tokio::spawn(async move {
             let mut state = state.lock().await;
-            // Get credentials from daemon (stored via RPC)
-            let credentials = state.get_credentials();
-            if credentials.is_empty() {
-                eprintln!("[host-gui] No credentials available from daemon");
-                return;
-            }
-            crate::account_usage::fetcher::fetch_usage_with_credentials(
-                &mut state.usage_store,
-                credentials,
-                None,
-            );
             if let Err(e) = state.usage_store.save() {
                 eprintln!("[host-gui] Failed to save usage store: {}", e);
             }
@@ -832,19 +717,8 @@ impl HostApp {
     }
 
     /// Render the usage sidebar panel.
-    fn render_usage_panel(&mut self, ui: &mut egui::Ui) {
-        ui.horizontal(|ui| {
-            ui.heading("Account Usage");
-            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                if ui
-                    .small_button("↻")
-                    .on_hover_text("Refresh all accounts")
-                    .clicked()
-                {
-                    self.last_usage_fetch = None; // Force immediate refresh
-                }
-            });
-        });
+    fn render_usage_panel(&self, ui: &mut egui::Ui) {
+        ui.heading("Account Usage");
         ui.separator();
 
         egui::ScrollArea::vertical().show(ui, |ui| {
@@ -945,6 +819,7 @@ impl HostApp {
             &mut self.selected_session_id,
             self.pending_file_content.clone(),
             &containers,
+            self.state.clone(),
         );
     }
 }
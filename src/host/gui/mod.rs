@@ -13,5 +13,4 @@ mod status_colors;
 mod usage_extrapolation;
 mod usage_panel;
 
-#[cfg(feature = "tray-icon")]
 pub mod tray;
@@ -1,5 +1,7 @@
 //! Session detail panel for displaying comprehensive session information.
 
+use crate::host::state::TimelineEntry;
+use crate::rpc::host_service::RemoteCommand;
 use crate::rpc::FileEntry;
 use crate::tui::ui::util::format_bytes;
 
@@ -18,12 +20,21 @@ pub struct SessionDetailData {
     pub liveness: LivenessDisplay,
     pub pid: u32,
     pub updated_ago: String,
+    pub timeline: Vec<TimelineEntry>,
     pub files: Vec<FileEntryDisplay>,
     pub selected_file: Option<usize>,
     pub file_content: Option<FileContentDisplay>,
     pub loading_files: bool,
     pub loading_content: bool,
     pub error: Option<String>,
+    /// Feedback text for Decline and Send
+    pub feedback_draft: String,
+    /// The last command relayed to the session
+    pub relay_status: Option<String>,
+    /// Approval request the session waits on, answered by Approve and Decline
+    pub approval_request: Option<u64>,
+    /// Last relayed command the session dropped as stale
+    pub stale_command: Option<String>,
 }
 
 /// Display wrapper for FileEntry from RPC.
@@ -53,15 +64,17 @@ pub struct FileContentDisplay {
 }
 
 /// Render the session detail panel.
-/// Returns (should_close, file_click) - file_click is (session_id, filename) if a file was clicked.
+/// Returns (should_close, file_click, command) - file_click is (session_id, filename) if a file
+/// was clicked, command is the remote command to relay to the session if one was clicked.
 pub fn render_session_detail_panel(
     ui: &mut eframe::egui::Ui,
     detail: &mut SessionDetailData,
-) -> (bool, Option<(String, String)>) {
+) -> (bool, Option<(String, String)>, Option<RemoteCommand>) {
     use eframe::egui;
 
     let mut should_close = false;
     let mut file_click: Option<(String, String)> = None;
+    let mut command: Option<RemoteCommand> = None;
 
     // Header with container name prominently displayed and close button
     ui.horizontal(|ui| {
@@ -99,6 +112,81 @@ pub fn render_session_detail_panel(
         ui.label(format!("Feature: {}", detail.feature_name));
         ui.add_space(8.0);
 
+        // Remote control section
+        ui.strong("Remote Control");
+        let feedback = detail.feedback_draft.trim().to_string();
+        let request = detail.approval_request;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(request.is_some(), egui::Button::new("✔ Approve"))
+                .on_hover_text("Accept the pending approval request")
+                .on_disabled_hover_text("The session is not waiting for approval")
+                .clicked()
+            {
+                command = request.map(|request| RemoteCommand::Approve { request });
+            }
+            if ui
+                .add_enabled(request.is_some(), egui::Button::new("✘ Decline"))
+                .on_hover_text("Decline with the feedback below")
+                .on_disabled_hover_text("The session is not waiting for approval")
+                .clicked()
+            {
+                command = request.map(|request| RemoteCommand::Decline {
+                    request,
+                    feedback: feedback.clone(),
+                });
+            }
+            if ui
+                .button("⏹ Stop")
+                .on_hover_text("Stop at the next phase boundary")
+                .clicked()
+            {
+                command = Some(RemoteCommand::Stop);
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut detail.feedback_draft).hint_text("Feedback"));
+            if ui
+                .add_enabled(!feedback.is_empty(), egui::Button::new("Send"))
+                .on_hover_text("Interrupt the current phase with this feedback")
+                .clicked()
+            {
+                command = Some(RemoteCommand::Feedback {
+                    text: feedback.clone(),
+                });
+            }
+        });
+        if let Some(command) = &command {
+            detail.relay_status = Some(format!("Sent {}", command.describe()));
+            if !matches!(command, RemoteCommand::Approve { .. } | RemoteCommand::Stop) {
+                detail.feedback_draft.clear();
+            }
+        }
+        if let Some(status) = &detail.relay_status {
+            ui.small(status);
+        }
+        if let Some(stale) = &detail.stale_command {
+            ui.small(format!("Dropped as stale: {}", stale));
+        }
+        ui.add_space(8.0);
+
+        // Timeline section (most recent first)
+        if !detail.timeline.is_empty() {
+            ui.strong("Timeline");
+            egui::ScrollArea::vertical()
+                .max_height(150.0)
+                .id_salt("timeline_scroll")
+                .show(ui, |ui| {
+                    for entry in detail.timeline.iter().rev() {
+                        ui.horizontal(|ui| {
+                            ui.small(format!("#{}", entry.sequence));
+                            ui.label(&entry.event_type);
+                        });
+                    }
+                });
+            ui.add_space(8.0);
+        }
+
         // Error display
         if let Some(error) = &detail.error {
             ui.horizontal(|ui| {
@@ -171,5 +259,5 @@ pub fn render_session_detail_panel(
         }
     });
 
-    (should_close, file_click)
+    (should_close, file_click, command)
 }
@@ -7,7 +7,9 @@ use super::session_detail::{
     render_session_detail_panel, FileContentDisplay, FileEntryDisplay, SessionDetailData,
 };
 use super::session_table::DisplaySessionRow;
+use crate::host::state::HostState;
 use crate::rpc::daemon_file_service::{FileContent, FileEntry};
+use crate::rpc::host_service::RemoteCommand;
 use crate::tui::ui::util::format_bytes;
 use eframe::egui;
 use std::sync::Arc;
@@ -190,12 +192,17 @@ impl SessionSelectionManager {
                 liveness: session.liveness,
                 pid: session.pid,
                 updated_ago: session.updated_ago.clone(),
+                timeline: session.timeline.clone(),
                 files: Vec::new(),
                 selected_file: None,
                 file_content: None,
                 loading_files: true,
                 loading_content: false,
                 error: None,
+                feedback_draft: String::new(),
+                relay_status: None,
+                approval_request: session.approval_request,
+                stale_command: session.stale_command.clone(),
             };
 
             // Start async file list fetch
@@ -241,23 +248,36 @@ impl SessionSelectionManager {
             detail.iteration = session.iteration;
             detail.status = session.status.clone();
             detail.liveness = session.liveness;
+            detail.timeline = session.timeline.clone();
+            detail.approval_request = session.approval_request;
+            detail.stale_command = session.stale_command.clone();
         }
     }
 
     /// Wrapper that delegates to session_detail::render_session_detail_panel
-    /// and returns state (should_close, file_click).
+    /// and handles the returned state (close, file clicks, remote commands).
     pub fn render_and_handle_detail_panel(
         ui: &mut egui::Ui,
         session_detail: &mut Option<SessionDetailData>,
         selected_session_id: &mut Option<String>,
         pending_file_content: PendingFileContent,
         containers: &[DisplayContainerRowLite],
+        state: Arc<Mutex<HostState>>,
     ) {
         let Some(mut detail) = session_detail.take() else {
             return;
         };
 
-        let (should_close, file_click) = render_session_detail_panel(ui, &mut detail);
+        let (should_close, file_click, command) = render_session_detail_panel(ui, &mut detail);
+
+        if let Some(command) = command {
+            Self::relay_command(
+                state,
+                detail.container_id.clone(),
+                detail.session_id.clone(),
+                command,
+            );
+        }
 
         if should_close {
             *selected_session_id = None;
@@ -273,4 +293,25 @@ impl SessionSelectionManager {
             Self::fetch_file_content(pending_file_content, host_port, session_id, filename);
         }
     }
+
+    /// Queue a command for the container to relay to its session.
+    fn relay_command(
+        state: Arc<Mutex<HostState>>,
+        container_id: String,
+        session_id: String,
+        command: RemoteCommand,
+    ) {
+        tokio::spawn(async move {
+            let queued = state
+                .lock()
+                .await
+                .queue_command(&container_id, &session_id, command);
+            if !queued {
+                eprintln!(
+                    "[host-gui] Session {} is no longer connected, command not relayed",
+                    session_id
+                );
+            }
+        });
+    }
 }
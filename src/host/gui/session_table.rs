@@ -1,5 +1,6 @@
 //! Session table rendering for the host GUI with click detection and container grouping.
 
+use crate::host::state::TimelineEntry;
 use crate::session_daemon::LivenessState;
 use egui_extras::{Column, TableBuilder};
 use std::collections::BTreeMap;
@@ -91,6 +92,12 @@ pub struct DisplaySessionRow {
     pub updated_ago: String,
     /// Implementation phase if in implementation workflow
     pub implementation_phase: Option<String>,
+    /// Recent workflow events, oldest first
+    pub timeline: Vec<TimelineEntry>,
+    /// Approval request the session waits on
+    pub approval_request: Option<u64>,
+    /// Last relayed command the session dropped as stale
+    pub stale_command: Option<String>,
 }
 
 #[derive(Clone, Copy, Default, Debug)]
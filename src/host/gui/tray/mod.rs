@@ -0,0 +1,86 @@
+//! Status indicator integration for host mode.
+//!
+//! On macOS and Windows builds with the `host-gui-tray` feature, session counts
+//! are shown in a system tray icon with a Show/Quit menu. Everywhere else (Linux,
+//! builds without the tray feature, or when the tray can't be created) the host
+//! runs as a standard window and shows the counts in its title instead.
+
+#[cfg(all(feature = "tray-icon", not(target_os = "linux")))]
+mod native;
+
+use eframe::egui;
+
+/// Title of the host dashboard window.
+pub const WINDOW_TITLE: &str = "Planning Agent Host";
+
+/// Commands from the tray menu.
+#[derive(Debug, Clone)]
+pub enum TrayCommand {
+    ShowWindow,
+    Quit,
+}
+
+/// Platform integration that surfaces session counts outside the dashboard.
+pub trait StatusIndicator {
+    /// Try to receive a menu command (non-blocking).
+    fn try_recv_command(&self) -> Option<TrayCommand>;
+
+    /// Reflect the current session counts.
+    /// Called on every sync; implementations skip updates when counts are unchanged.
+    fn update_counts(&mut self, ctx: &egui::Context, running: usize, awaiting: usize);
+}
+
+/// Fallback indicator that shows session counts in the window title.
+#[derive(Default)]
+pub struct WindowTitleIndicator {
+    last_counts: Option<(usize, usize)>,
+}
+
+impl StatusIndicator for WindowTitleIndicator {
+    fn try_recv_command(&self) -> Option<TrayCommand> {
+        None
+    }
+
+    fn update_counts(&mut self, ctx: &egui::Context, running: usize, awaiting: usize) {
+        if self.last_counts == Some((running, awaiting)) {
+            return;
+        }
+        self.last_counts = Some((running, awaiting));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Title(window_title(
+            running, awaiting,
+        )));
+    }
+}
+
+/// Window title with the running/awaiting counts appended when non-zero.
+pub fn window_title(running: usize, awaiting: usize) -> String {
+    if running == 0 && awaiting == 0 {
+        WINDOW_TITLE.to_string()
+    } else {
+        format!(
+            "{} - {} running, {} awaiting",
+            WINDOW_TITLE, running, awaiting
+        )
+    }
+}
+
+/// Create the status indicator for this platform.
+/// Must be called on the main thread (macOS requirement for the tray icon).
+pub fn create_status_indicator() -> Box<dyn StatusIndicator> {
+    #[cfg(all(feature = "tray-icon", not(target_os = "linux")))]
+    {
+        // Tray creation may still fail, e.g. on Windows sessions without a shell
+        match native::HostTray::new() {
+            Ok(tray) => {
+                eprintln!("[host] System tray icon created");
+                return Box::new(tray);
+            }
+            Err(e) => {
+                eprintln!("[host] Warning: Could not create tray icon: {}", e);
+            }
+        }
+    }
+
+    eprintln!("[host] No system tray; session counts are shown in the window title");
+    Box::new(WindowTitleIndicator::default())
+}
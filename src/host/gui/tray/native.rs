@@ -1,10 +1,11 @@
-//! System tray icon support.
+//! Native system tray icon.
 //!
 //! Provides a menu bar tray icon with session count and notifications.
 //! Only available on macOS and Windows (gtk3-rs on Linux is deprecated).
 //! Requires the `host-gui-tray` feature.
 
-#![cfg(feature = "tray-icon")]
+use super::{StatusIndicator, TrayCommand};
+use eframe::egui;
 
 /// Icon dimensions: width accommodates two 7-pixel digits plus padding and separator
 /// Layout: [3px pad][7px digit][8px gap][7px digit][3px pad] = 28px
@@ -187,13 +188,6 @@ fn draw_digit(
     }
 }
 
-/// Commands from the tray menu.
-#[derive(Debug, Clone)]
-pub enum TrayCommand {
-    ShowWindow,
-    Quit,
-}
-
 /// Tray icon state and handler.
 pub struct HostTray {
     tray_icon: tray_icon::TrayIcon,
@@ -254,14 +248,9 @@ impl HostTray {
         })
     }
 
-    /// Try to receive a tray command (non-blocking).
-    pub fn try_recv_command(&self) -> Option<TrayCommand> {
-        self.command_rx.try_recv().ok()
-    }
-
     /// Update the tray icon and tooltip to reflect current session counts.
     /// Only updates if counts have changed to avoid unnecessary redraws.
-    pub fn update_icon(&mut self, running: usize, awaiting: usize) {
+    fn update_icon(&mut self, running: usize, awaiting: usize) {
         // Skip update if counts haven't changed
         if running == self.last_running && awaiting == self.last_awaiting {
             return;
@@ -287,6 +276,16 @@ impl HostTray {
     }
 }
 
+impl StatusIndicator for HostTray {
+    fn try_recv_command(&self) -> Option<TrayCommand> {
+        self.command_rx.try_recv().ok()
+    }
+
+    fn update_counts(&mut self, _ctx: &egui::Context, running: usize, awaiting: usize) {
+        self.update_icon(running, awaiting);
+    }
+}
+
 /// Check if a point is inside a rounded rectangle.
 fn is_in_rounded_rect(x: f32, y: f32, width: f32, height: f32, radius: f32) -> bool {
     // Check corners
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 pub use crate::session_daemon::LivenessState;
 
 /// Current protocol version.
-pub const PROTOCOL_VERSION: u32 = 1;
+pub const PROTOCOL_VERSION: u32 = 5;
 
 /// Session information for wire transmission.
 /// Uses string fields for phase/status like existing SessionRecord,
@@ -40,6 +40,12 @@ pub struct SessionInfo {
     /// Maximum implementation iterations.
     #[serde(default)]
     pub implementation_max_iterations: Option<u32>,
+    /// Approval prompt the session waits on, named by Approve and Decline commands.
+    #[serde(default)]
+    pub approval_request: Option<u64>,
+    /// Last relayed command the session dropped as stale.
+    #[serde(default)]
+    pub stale_command: Option<String>,
 }
 
 impl SessionInfo {
@@ -58,6 +64,8 @@ impl SessionInfo {
             implementation_phase: record.implementation_phase.clone(),
             implementation_iteration: record.implementation_iteration,
             implementation_max_iterations: record.implementation_max_iterations,
+            approval_request: record.approval_request,
+            stale_command: record.stale_command.clone(),
         }
     }
 }
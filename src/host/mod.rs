@@ -3,9 +3,12 @@
 //! This module provides a native desktop GUI (using egui/eframe) that:
 //! - Runs a TCP server accepting connections from container daemons
 //! - Shows all sessions from all containers in a dashboard
-//! - Provides a macOS menu bar icon with session count
+//! - Shows session counts in a tray icon (macOS/Windows) or the window title (Linux)
 //! - Real-time updates using egui's immediate mode rendering
 //!
+//! `watch` follows a single shared session from the command line, and `rest_api`
+//! serves the aggregated state as read-only JSON for external dashboards.
+//!
 //! The GUI components require the `host-gui` feature to be enabled.
 //! The server and state modules are compiled when testing or when host-gui is enabled.
 
@@ -13,7 +16,10 @@
 pub mod gui;
 
 pub mod host_protocol;
+pub mod watch;
 
+#[cfg(any(feature = "host-gui", test))]
+pub mod rest_api;
 #[cfg(any(feature = "host-gui", test))]
 pub mod rpc_server;
 #[cfg(any(feature = "host-gui", test))]
@@ -0,0 +1,361 @@
+//! Read-only REST API over the host's aggregated state.
+//!
+//! `planning --host --api-port PORT` serves the containers and sessions the
+//! dashboard shows as JSON, so internal dashboards (Grafana, Backstage) can show
+//! planning sessions without speaking tarpc. Every request needs an
+//! `Authorization: Bearer <token>` header. The token is `PLANNING_HOST_API_TOKEN`,
+//! or the one in `~/.planning-agent/host-api-token`, created on first use.
+//!
+//! Routes, all `GET`:
+//! - `/api/v1/summary`: container, session, active and awaiting-approval counts
+//! - `/api/v1/containers`: the connected containers
+//! - `/api/v1/sessions`: all sessions, in the dashboard's order
+//! - `/api/v1/sessions/<id>`: one session with its recent workflow events
+//! - `/api/v1/usage`: account usage
+
+use crate::host::state::{HostState, TimelineEntry};
+use crate::host::SessionInfo;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+/// Environment variable that sets the API token.
+#[cfg(feature = "host-gui")]
+pub const API_TOKEN_ENV: &str = "PLANNING_HOST_API_TOKEN";
+
+/// Token file in `~/.planning-agent/`, used when the variable is unset.
+#[cfg(feature = "host-gui")]
+const API_TOKEN_FILE: &str = "host-api-token";
+
+/// Largest request head accepted; the API reads no request bodies.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Time a client may take to send its request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Counts for a status tile.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiSummary {
+    pub containers: usize,
+    pub sessions: usize,
+    pub active: usize,
+    pub awaiting_approval: usize,
+    pub seconds_since_update: u64,
+}
+
+/// A connected container daemon.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiContainer {
+    pub container_id: String,
+    pub container_name: String,
+    pub working_dir: String,
+    pub git_sha: String,
+    pub build_timestamp: u64,
+    pub connected_seconds: u64,
+    pub seconds_since_message: u64,
+    pub sessions: usize,
+}
+
+/// A session with the container it runs in.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiSession {
+    pub container_name: String,
+    #[serde(flatten)]
+    pub session: SessionInfo,
+    /// Recent workflow events, oldest first; only on the single-session route
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeline: Option<Vec<TimelineEntry>>,
+}
+
+/// Status code and JSON body of a response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl ApiResponse {
+    fn ok(body: impl Serialize) -> Self {
+        Self {
+            status: 200,
+            body: serde_json::to_value(body).unwrap_or(Value::Null),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }),
+        }
+    }
+
+    fn to_http(&self) -> Vec<u8> {
+        let body = serde_json::to_string_pretty(&self.body).unwrap_or_default();
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Error",
+        };
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Cache-Control: no-store\r\nConnection: close\r\n",
+            self.status,
+            reason,
+            body.len()
+        );
+        match self.status {
+            401 => head.push_str("WWW-Authenticate: Bearer\r\n"),
+            405 => head.push_str("Allow: GET\r\n"),
+            _ => {}
+        }
+        format!("{}\r\n{}", head, body).into_bytes()
+    }
+}
+
+/// Answers one request. `authorization` is the request's header value, if any.
+pub fn handle_request(
+    state: &mut HostState,
+    method: &str,
+    path: &str,
+    authorization: Option<&str>,
+    token: &str,
+) -> ApiResponse {
+    let presented = authorization.and_then(|value| value.strip_prefix("Bearer "));
+    if !presented.is_some_and(|presented| tokens_match(presented.trim(), token)) {
+        return ApiResponse::error(401, "missing or invalid bearer token");
+    }
+    if method != "GET" {
+        return ApiResponse::error(405, "the API is read-only");
+    }
+    let path = path.split('?').next().unwrap_or(path).trim_end_matches('/');
+    match path {
+        "/api/v1/summary" => ApiResponse::ok(summary(state)),
+        "/api/v1/containers" => ApiResponse::ok(containers(state)),
+        "/api/v1/sessions" => ApiResponse::ok(sessions(state)),
+        "/api/v1/usage" => ApiResponse::ok(state.get_account_usage()),
+        _ => match path.strip_prefix("/api/v1/sessions/") {
+            Some(id) if !id.is_empty() => match session(state, id) {
+                Some(session) => ApiResponse::ok(session),
+                None => ApiResponse::error(404, "session not found"),
+            },
+            _ => ApiResponse::error(404, "not found"),
+        },
+    }
+}
+
+/// Compares in time independent of where the tokens differ.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn summary(state: &mut HostState) -> ApiSummary {
+    ApiSummary {
+        containers: state.containers.len(),
+        sessions: state.sessions().len(),
+        active: state.active_count(),
+        awaiting_approval: state.approval_count(),
+        seconds_since_update: state.last_update.elapsed().as_secs(),
+    }
+}
+
+fn containers(state: &HostState) -> Vec<ApiContainer> {
+    let mut containers: Vec<ApiContainer> = state
+        .containers
+        .iter()
+        .map(|(id, container)| ApiContainer {
+            container_id: id.clone(),
+            container_name: container.container_name.clone(),
+            working_dir: container.working_dir.display().to_string(),
+            git_sha: container.git_sha.clone(),
+            build_timestamp: container.build_timestamp,
+            connected_seconds: container.connected_at.elapsed().as_secs(),
+            seconds_since_message: container.last_message_at.elapsed().as_secs(),
+            sessions: container.sessions.len(),
+        })
+        .collect();
+    containers.sort_by(|a, b| a.container_name.cmp(&b.container_name));
+    containers
+}
+
+fn sessions(state: &mut HostState) -> Vec<ApiSession> {
+    state
+        .sessions()
+        .iter()
+        .map(|display| ApiSession {
+            container_name: display.container_name.clone(),
+            session: display.session.clone(),
+            timeline: None,
+        })
+        .collect()
+}
+
+fn session(state: &mut HostState, session_id: &str) -> Option<ApiSession> {
+    state
+        .sessions()
+        .iter()
+        .find(|display| display.session.session_id == session_id)
+        .map(|display| ApiSession {
+            container_name: display.container_name.clone(),
+            session: display.session.clone(),
+            timeline: Some(display.timeline.clone()),
+        })
+}
+
+/// The API token from `PLANNING_HOST_API_TOKEN`, or from the token file,
+/// which is created owner-only on first use.
+#[cfg(feature = "host-gui")]
+pub fn load_api_token() -> Result<String> {
+    if let Some(token) = std::env::var(API_TOKEN_ENV)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+    {
+        return Ok(token);
+    }
+    let path = crate::planning_paths::planning_agent_home_dir()?.join(API_TOKEN_FILE);
+    if let Some(token) = std::fs::read_to_string(&path)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+    {
+        return Ok(token);
+    }
+    let token: String =
+        rand::Rng::sample_iter(rand::thread_rng(), &rand::distributions::Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect();
+    write_private(&path, &token).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(token)
+}
+
+#[cfg(feature = "host-gui")]
+fn write_private(path: &std::path::Path, token: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    // Recreate rather than truncate so a file left with wider permissions is not reused
+    match std::fs::remove_file(path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(path)?
+        .write_all(format!("{}\n", token).as_bytes())
+}
+
+/// Serves the API on `port` until the task is aborted.
+#[cfg(feature = "host-gui")]
+pub async fn run_host_rest_api(
+    port: u16,
+    state: Arc<Mutex<HostState>>,
+    token: String,
+) -> Result<()> {
+    let addr = format!("0.0.0.0:{}", port);
+    let listener = TcpListener::bind(&addr).await.with_context(|| {
+        format!(
+            "Failed to listen on {} (use --api-port to pick another)",
+            addr
+        )
+    })?;
+    eprintln!("[host-api] Listening on {}", addr);
+    serve(listener, state, Arc::new(token)).await
+}
+
+/// Accepts connections on `listener`, one request per connection.
+pub async fn serve(
+    listener: TcpListener,
+    state: Arc<Mutex<HostState>>,
+    token: Arc<String>,
+) -> Result<()> {
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                eprintln!("[host-api] Accept failed: {}", e);
+                continue;
+            }
+        };
+        let (state, token) = (state.clone(), token.clone());
+        tokio::spawn(async move {
+            if let Err(e) = serve_connection(stream, &state, &token).await {
+                eprintln!("[host-api] {}: {:#}", peer, e);
+            }
+        });
+    }
+}
+
+async fn serve_connection(
+    mut stream: TcpStream,
+    state: &Mutex<HostState>,
+    token: &str,
+) -> Result<()> {
+    let head = tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream))
+        .await
+        .context("request timed out")??;
+    let response = match parse_request_head(&head) {
+        Some((method, path, authorization)) => {
+            let mut state = state.lock().await;
+            handle_request(&mut state, method, path, authorization, token)
+        }
+        None => ApiResponse::error(400, "malformed request"),
+    };
+    stream.write_all(&response.to_http()).await?;
+    // The client may already have closed its end
+    let _ = stream.shutdown().await;
+    Ok(())
+}
+
+async fn read_request_head(stream: &mut TcpStream) -> Result<String> {
+    let mut head = Vec::new();
+    let mut chunk = [0u8; 1024];
+    loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&chunk[..read]);
+        if head.windows(4).any(|window| window == b"\r\n\r\n") {
+            break;
+        }
+        if head.len() > MAX_REQUEST_BYTES {
+            anyhow::bail!("request head over {} bytes", MAX_REQUEST_BYTES);
+        }
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+/// Method, path and `Authorization` value of a request head.
+fn parse_request_head(head: &str) -> Option<(&str, &str, Option<&str>)> {
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let (method, path) = (request_line.next()?, request_line.next()?);
+    let authorization = lines.take_while(|line| !line.is_empty()).find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("authorization")
+            .then(|| value.trim())
+    });
+    Some((method, path, authorization))
+}
+
+#[cfg(test)]
+#[path = "tests/rest_api_tests.rs"]
+mod tests;
@@ -19,13 +19,14 @@ pub enum HostEvent {
     ContainerDisconnected { container_id: String },
     /// Sessions were updated (sync, update, or removal).
     SessionsUpdated,
-    /// Credentials were reported from a daemon.
-    CredentialsReported,
+    /// A daemon reported the usage of its accounts.
+    UsageReported,
 }
 use crate::rpc::host_service::{
-    AccountUsageInfo, ContainerInfo, CredentialInfo, HostService, SessionInfo, PROTOCOL_VERSION,
+    AccountUsageInfo, ContainerInfo, CredentialInfo, HostService, RelayedCommand, SessionInfo,
+    SharedSessionUpdate, PROTOCOL_VERSION,
 };
-use crate::rpc::HostError;
+use crate::rpc::{HostError, WorkflowEventEnvelope};
 #[cfg(any(feature = "host-gui", test))]
 use futures::StreamExt;
 use std::sync::Arc;
@@ -59,6 +60,11 @@ impl HostServer {
 
 #[cfg(any(feature = "host-gui", test))]
 impl HostService for HostServer {
+    async fn prove_identity(self, _: tarpc::context::Context, nonce: String) -> Option<String> {
+        crate::rpc::host_auth::host_secret()
+            .map(|secret| crate::rpc::host_auth::identity_proof(&secret, &nonce))
+    }
+
     async fn hello(
         self,
         _: tarpc::context::Context,
@@ -182,6 +188,25 @@ impl HostService for HostServer {
         }
     }
 
+    async fn workflow_events(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+        events: Vec<WorkflowEventEnvelope>,
+    ) {
+        let container_id = {
+            let id = self.container_id.lock().await;
+            id.clone()
+        };
+
+        if let Some(container_id) = container_id {
+            let mut state = self.state.lock().await;
+            state.record_workflow_events(&container_id, &session_id, events);
+            // Ignoring send error: receiver may have been dropped if GUI is shutting down
+            let _ = self.event_tx.send(HostEvent::SessionsUpdated);
+        }
+    }
+
     async fn heartbeat(self, _: tarpc::context::Context) {
         let container_id = {
             let id = self.container_id.lock().await;
@@ -212,23 +237,32 @@ impl HostService for HostServer {
             );
             for cred in &credentials {
                 eprintln!(
-                    "[host-rpc]   - {} ({}, valid={}, token_len={})",
-                    cred.provider,
-                    cred.email,
-                    cred.token_valid,
-                    cred.access_token.len()
+                    "[host-rpc]   - {} ({}, valid={}, expires_at={:?})",
+                    cred.provider, cred.email, cred.token_valid, cred.expires_at
                 );
             }
+        }
+    }
 
-            // Store credentials in state for later API calls
+    async fn report_account_usage(self, _: tarpc::context::Context, usage: Vec<AccountUsageInfo>) {
+        let container_id = {
+            let id = self.container_id.lock().await;
+            id.clone()
+        };
+
+        if let Some(container_id) = container_id {
+            eprintln!(
+                "[host-rpc] report_account_usage: {} accounts from {}",
+                usage.len(),
+                container_id
+            );
             {
                 let mut state = self.state.lock().await;
-                state.store_credentials(credentials);
+                state.store_account_usage(usage, &container_id);
             }
 
-            // Send event to trigger usage fetching
             // Ignoring send error: receiver may have been dropped if GUI is shutting down
-            let _ = self.event_tx.send(HostEvent::CredentialsReported);
+            let _ = self.event_tx.send(HostEvent::UsageReported);
         }
     }
 
@@ -236,6 +270,69 @@ impl HostService for HostServer {
         let state = self.state.lock().await;
         state.get_account_usage()
     }
+
+    async fn share_session(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+        token: String,
+    ) -> Result<(), HostError> {
+        let container_id = {
+            let id = self.container_id.lock().await;
+            id.clone()
+        };
+        let container_id = container_id.ok_or(HostError::ContainerNotRegistered)?;
+
+        eprintln!(
+            "[host-rpc] share_session: {} in container {}",
+            session_id, container_id
+        );
+        let mut state = self.state.lock().await;
+        state.share_session(&container_id, &session_id, token);
+        Ok(())
+    }
+
+    async fn session_output(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+        lines: Vec<String>,
+    ) {
+        let container_id = {
+            let id = self.container_id.lock().await;
+            id.clone()
+        };
+
+        if let Some(container_id) = container_id {
+            let mut state = self.state.lock().await;
+            state.record_session_output(&container_id, &session_id, lines);
+        }
+    }
+
+    async fn pending_commands(self, _: tarpc::context::Context) -> Vec<RelayedCommand> {
+        let container_id = {
+            let id = self.container_id.lock().await;
+            id.clone()
+        };
+
+        match container_id {
+            Some(container_id) => self.state.lock().await.take_commands(&container_id),
+            None => Vec::new(),
+        }
+    }
+
+    async fn watch_session(
+        self,
+        _: tarpc::context::Context,
+        token: String,
+        after_sequence: u64,
+        output_cursor: u64,
+    ) -> Result<SharedSessionUpdate, HostError> {
+        let state = self.state.lock().await;
+        state
+            .watch_session(&token, after_sequence, output_cursor)
+            .ok_or(HostError::UnknownShareToken)
+    }
 }
 
 /// Run the host RPC server.
@@ -245,6 +342,7 @@ pub async fn run_host_rpc_server(
     state: Arc<Mutex<HostState>>,
     event_tx: mpsc::UnboundedSender<HostEvent>,
 ) -> anyhow::Result<()> {
+    use anyhow::Context;
     use tarpc::serde_transport::tcp;
 
     let addr = format!("0.0.0.0:{}", port);
@@ -252,7 +350,12 @@ pub async fn run_host_rpc_server(
     // Check if localhost:port is already taken (e.g., by VS Code port forwarding)
     // This can cause silent failures where we bind to 0.0.0.0 but localhost traffic
     // goes to the other listener
-    if let Ok(stream) = std::net::TcpStream::connect(format!("127.0.0.1:{}", port)) {
+    // The short timeout matters on Windows, where a refused localhost connect is
+    // retried for about two seconds before failing.
+    let localhost = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    if let Ok(stream) =
+        std::net::TcpStream::connect_timeout(&localhost, std::time::Duration::from_millis(250))
+    {
         drop(stream);
         anyhow::bail!(
             "Port {} is already in use on localhost (possibly VS Code port forwarding). \
@@ -260,10 +363,27 @@ pub async fn run_host_rpc_server(
             port
         );
     }
-    let mut listener = tcp::listen(&addr, Bincode::default).await?;
+    let mut listener = tcp::listen(&addr, Bincode::default)
+        .await
+        .with_context(|| format!("Failed to listen on {} (use --port to pick another)", addr))?;
 
     eprintln!("[host-rpc] Listening on {}", addr);
 
+    // Lets container daemons on the local network find this host, when opted in
+    if crate::rpc::discovery::discovery_enabled() {
+        if crate::rpc::host_auth::host_secret().is_none() {
+            eprintln!(
+                "[host-rpc] mDNS is on but {} is unset, so daemons will not use this host",
+                crate::rpc::host_auth::HOST_SECRET_ENV
+            );
+        }
+        tokio::spawn(async move {
+            if let Err(e) = crate::rpc::discovery::advertise_host(port).await {
+                eprintln!("[host-rpc] mDNS advertisement unavailable: {}", e);
+            }
+        });
+    }
+
     while let Some(result) = listener.next().await {
         match result {
             Ok(transport) => {
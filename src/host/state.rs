@@ -4,13 +4,68 @@
 //! session data for display in the GUI.
 
 use crate::account_usage::store::UsageStore;
-use crate::account_usage::types::ProviderCredentials;
 use crate::host::SessionInfo;
-use crate::rpc::host_service::{AccountUsageInfo, CredentialInfo};
-use std::collections::HashMap;
+use crate::rpc::host_service::{
+    AccountUsageInfo, RelayedCommand, RemoteCommand, SharedEvent, SharedSessionUpdate,
+};
+use crate::rpc::WorkflowEventEnvelope;
+use cqrs_es::DomainEvent;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::time::Instant;
 
+/// Maximum timeline entries kept per session.
+const TIMELINE_MAX_ENTRIES: usize = 200;
+
+/// Maximum agent output lines kept per shared session.
+const SHARED_OUTPUT_MAX_LINES: usize = 500;
+
+/// A workflow event as shown in a session's timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineEntry {
+    pub sequence: u64,
+    pub event_type: String,
+}
+
+/// Recent agent output of a shared session.
+#[derive(Debug, Clone, Default)]
+pub struct OutputBuffer {
+    lines: VecDeque<String>,
+    /// Lines received in total, including those already dropped.
+    total: u64,
+}
+
+impl OutputBuffer {
+    fn push(&mut self, lines: Vec<String>) {
+        self.total += lines.len() as u64;
+        self.lines.extend(lines);
+        while self.lines.len() > SHARED_OUTPUT_MAX_LINES {
+            self.lines.pop_front();
+        }
+    }
+
+    /// Lines after `cursor` that are still buffered, and the next cursor.
+    /// A cursor past the end comes from before a reconnect reset the buffer,
+    /// so the watcher gets everything buffered since.
+    fn since(&self, cursor: u64) -> (Vec<String>, u64) {
+        let first = self.total - self.lines.len() as u64;
+        let skip = if cursor > self.total {
+            0
+        } else {
+            cursor.saturating_sub(first) as usize
+        };
+        (self.lines.iter().skip(skip).cloned().collect(), self.total)
+    }
+}
+
+/// A session shared with `planning share`.
+#[derive(Debug, Clone)]
+struct SharedSession {
+    container_id: String,
+    session_id: String,
+}
+
 /// Represents a connected container daemon.
 #[derive(Debug, Clone)]
 pub struct ConnectedContainer {
@@ -25,6 +80,12 @@ pub struct ConnectedContainer {
     pub connected_at: Instant,
     pub last_message_at: Instant,
     pub sessions: HashMap<String, SessionInfo>,
+    /// Recent workflow events per session, oldest first.
+    pub timelines: HashMap<String, Vec<TimelineEntry>>,
+    /// Recent agent output of shared sessions.
+    pub outputs: HashMap<String, OutputBuffer>,
+    /// Commands from the GUI waiting for the daemon's next poll, oldest first.
+    pub commands: Vec<RelayedCommand>,
     /// Git commit SHA the daemon was built from.
     pub git_sha: String,
     /// Unix timestamp when the daemon was built.
@@ -49,6 +110,9 @@ impl ConnectedContainer {
             connected_at: now,
             last_message_at: now,
             sessions: HashMap::new(),
+            timelines: HashMap::new(),
+            outputs: HashMap::new(),
+            commands: Vec::new(),
             git_sha,
             build_timestamp,
             file_service_port,
@@ -66,9 +130,9 @@ pub struct HostState {
     pub last_update: Instant,
     /// Account usage tracking store
     pub usage_store: UsageStore,
-    /// Credentials received from daemons, keyed by (provider, email).
-    /// Supports multiple accounts per provider.
-    daemon_credentials: HashMap<(String, String), ProviderCredentials>,
+    /// Shared sessions by share token. Kept across container reconnects,
+    /// since daemons share their sessions again under the same tokens.
+    shares: HashMap<String, SharedSession>,
 }
 
 impl Default for HostState {
@@ -82,6 +146,8 @@ impl Default for HostState {
 pub struct DisplaySession {
     pub container_name: String,
     pub session: SessionInfo,
+    /// Recent workflow events for the session, oldest first.
+    pub timeline: Vec<TimelineEntry>,
 }
 
 impl DisplaySession {
@@ -89,6 +155,7 @@ impl DisplaySession {
         Self {
             container_name,
             session,
+            timeline: Vec::new(),
         }
     }
 }
@@ -106,55 +173,18 @@ impl HostState {
             cached_sessions: None,
             last_update: Instant::now(),
             usage_store,
-            daemon_credentials: HashMap::new(),
+            shares: HashMap::new(),
         }
     }
 
-    /// Store credentials received from a daemon.
-    /// Converts CredentialInfo to ProviderCredentials for API calls.
-    /// Keys by (provider, identifier) to support multiple accounts per provider.
-    /// For Claude where email is unknown until API call, uses token hash as identifier.
-    pub fn store_credentials(&mut self, credentials: Vec<CredentialInfo>) {
-        for cred in credentials {
-            let provider_creds = match cred.provider.as_str() {
-                "claude" => ProviderCredentials::Claude {
-                    access_token: cred.access_token.clone(),
-                    expires_at: cred.expires_at,
-                },
-                "gemini" => ProviderCredentials::Gemini {
-                    access_token: cred.access_token.clone(),
-                    expires_at: cred.expires_at,
-                },
-                "codex" => ProviderCredentials::Codex {
-                    access_token: cred.access_token.clone(),
-                    account_id: cred.account_id.unwrap_or_default(),
-                },
-                _ => continue,
-            };
-            // Use email as identifier, or token hash if email is empty (e.g., Claude before API call)
-            let identifier = if cred.email.is_empty() {
-                use std::collections::hash_map::DefaultHasher;
-                use std::hash::{Hash, Hasher};
-                let mut hasher = DefaultHasher::new();
-                cred.access_token.hash(&mut hasher);
-                format!("token:{:x}", hasher.finish())
-            } else {
-                cred.email
-            };
-            let key = (cred.provider, identifier);
-            self.daemon_credentials.insert(key, provider_creds);
+    /// Store account usage a daemon fetched with its own credentials.
+    pub fn store_account_usage(&mut self, usage: Vec<AccountUsageInfo>, container_id: &str) {
+        for account in usage {
+            self.usage_store
+                .update_account(account.into_usage(), Some(container_id));
         }
     }
 
-    /// Get all stored credentials for fetching usage.
-    #[cfg_attr(not(feature = "host-gui"), allow(dead_code))]
-    pub fn get_credentials(&self) -> Vec<(String, ProviderCredentials)> {
-        self.daemon_credentials
-            .iter()
-            .map(|((provider, _email), creds)| (provider.clone(), creds.clone()))
-            .collect()
-    }
-
     /// Register a new container connection.
     pub fn add_container(
         &mut self,
@@ -240,12 +270,147 @@ impl HostState {
     pub fn remove_session(&mut self, container_id: &str, session_id: &str) {
         if let Some(container) = self.containers.get_mut(container_id) {
             container.sessions.remove(session_id);
+            container.timelines.remove(session_id);
+            container.outputs.remove(session_id);
             container.last_message_at = Instant::now();
             self.last_update = Instant::now();
             self.invalidate_cache();
         }
     }
 
+    /// Append workflow events to a session's timeline.
+    /// Events already recorded (by sequence) are skipped, so journal replays
+    /// after a reconnect do not duplicate entries.
+    pub fn record_workflow_events(
+        &mut self,
+        container_id: &str,
+        session_id: &str,
+        events: Vec<WorkflowEventEnvelope>,
+    ) {
+        let Some(container) = self.containers.get_mut(container_id) else {
+            return;
+        };
+        let timeline = container
+            .timelines
+            .entry(session_id.to_string())
+            .or_default();
+        let mut changed = false;
+        for envelope in events {
+            if timeline
+                .last()
+                .is_some_and(|last| envelope.sequence <= last.sequence)
+            {
+                continue;
+            }
+            timeline.push(TimelineEntry {
+                sequence: envelope.sequence,
+                event_type: envelope.event.event_type(),
+            });
+            changed = true;
+        }
+        if timeline.len() > TIMELINE_MAX_ENTRIES {
+            let excess = timeline.len() - TIMELINE_MAX_ENTRIES;
+            timeline.drain(..excess);
+        }
+        if changed {
+            container.last_message_at = Instant::now();
+            self.last_update = Instant::now();
+            self.invalidate_cache();
+        }
+    }
+
+    /// Make a container's session readable with `token`.
+    pub fn share_session(&mut self, container_id: &str, session_id: &str, token: String) {
+        self.shares.insert(
+            token,
+            SharedSession {
+                container_id: container_id.to_string(),
+                session_id: session_id.to_string(),
+            },
+        );
+    }
+
+    /// Append agent output lines of a shared session.
+    pub fn record_session_output(
+        &mut self,
+        container_id: &str,
+        session_id: &str,
+        lines: Vec<String>,
+    ) {
+        if let Some(container) = self.containers.get_mut(container_id) {
+            container
+                .outputs
+                .entry(session_id.to_string())
+                .or_default()
+                .push(lines);
+            container.last_message_at = Instant::now();
+        }
+    }
+
+    /// Events and output of the session shared under `token` after the
+    /// watcher's cursors, or None if no session is shared under it.
+    pub fn watch_session(
+        &self,
+        token: &str,
+        after_sequence: u64,
+        output_cursor: u64,
+    ) -> Option<SharedSessionUpdate> {
+        let shared = self.shares.get(token)?;
+        let mut update = SharedSessionUpdate {
+            session: None,
+            events: Vec::new(),
+            output: Vec::new(),
+            output_cursor,
+        };
+        let Some(container) = self.containers.get(&shared.container_id) else {
+            return Some(update);
+        };
+        update.session = container.sessions.get(&shared.session_id).cloned();
+        if let Some(timeline) = container.timelines.get(&shared.session_id) {
+            update.events = timeline
+                .iter()
+                .filter(|entry| entry.sequence > after_sequence)
+                .map(|entry| SharedEvent {
+                    sequence: entry.sequence,
+                    event_type: entry.event_type.clone(),
+                })
+                .collect();
+        }
+        if let Some(output) = container.outputs.get(&shared.session_id) {
+            (update.output, update.output_cursor) = output.since(output_cursor);
+        }
+        Some(update)
+    }
+
+    /// Queue a command for one of a container's sessions. False if the
+    /// container is not connected or has no such session.
+    pub fn queue_command(
+        &mut self,
+        container_id: &str,
+        session_id: &str,
+        command: RemoteCommand,
+    ) -> bool {
+        let Some(container) = self.containers.get_mut(container_id) else {
+            return false;
+        };
+        if !container.sessions.contains_key(session_id) {
+            return false;
+        }
+        container.commands.push(RelayedCommand {
+            session_id: session_id.to_string(),
+            command,
+        });
+        true
+    }
+
+    /// Take the commands queued for a container.
+    pub fn take_commands(&mut self, container_id: &str) -> Vec<RelayedCommand> {
+        self.containers
+            .get_mut(container_id)
+            .map(|container| std::mem::take(&mut container.commands))
+            .unwrap_or_default()
+    }
+
     /// Record heartbeat from container.
     pub fn heartbeat(&mut self, container_id: &str) {
         if let Some(container) = self.containers.get_mut(container_id) {
@@ -259,10 +424,12 @@ impl HostState {
             let mut sessions = Vec::new();
             for container in self.containers.values() {
                 for session in container.sessions.values() {
-                    sessions.push(DisplaySession::new(
-                        container.container_name.clone(),
-                        session.clone(),
-                    ));
+                    let mut display =
+                        DisplaySession::new(container.container_name.clone(), session.clone());
+                    if let Some(timeline) = container.timelines.get(&session.session_id) {
+                        display.timeline = timeline.clone();
+                    }
+                    sessions.push(display);
                 }
             }
             // Sort: AwaitingApproval first, then by updated_at descending
@@ -313,18 +480,8 @@ impl HostState {
             .filter_map(|record| {
                 let usage = record.current_usage.as_ref()?;
                 Some(AccountUsageInfo {
-                    account_id: record.account_id.to_string(),
-                    provider: record.provider.clone(),
-                    email: record.email.clone(),
                     plan_type: record.plan_type.clone(),
-                    rate_limit_tier: usage.rate_limit_tier.clone(),
-                    session_percent: usage.session_window.used_percent,
-                    session_reset_at: usage.session_window.reset_at.map(|r| r.epoch_seconds),
-                    weekly_percent: usage.weekly_window.used_percent,
-                    weekly_reset_at: usage.weekly_window.reset_at.map(|r| r.epoch_seconds),
-                    fetched_at: usage.fetched_at.clone(),
-                    token_valid: usage.token_valid,
-                    error: usage.error.clone(),
+                    ..AccountUsageInfo::from_usage(usage)
                 })
             })
             .collect()
@@ -15,6 +15,8 @@ fn test_session_info_serialization() {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     };
 
     let json = serde_json::to_string(&session).unwrap();
@@ -28,7 +30,7 @@ fn test_session_info_from_session_record() {
     use crate::session_daemon::protocol::SessionRecord;
     use std::path::PathBuf;
 
-    let record = SessionRecord::new(
+    let mut record = SessionRecord::new(
         "session-456".to_string(),
         "my-feature".to_string(),
         PathBuf::from("/work/dir"),
@@ -38,6 +40,8 @@ fn test_session_info_from_session_record() {
         "Under Review".to_string(),
         9999,
     );
+    record.approval_request = Some(3);
+    record.stale_command = Some("approval of request 2".to_string());
 
     let session_info = SessionInfo::from_session_record(&record);
 
@@ -50,4 +54,9 @@ fn test_session_info_from_session_record() {
     // Both started_at and updated_at are set from record.updated_at
     assert_eq!(session_info.started_at, record.updated_at);
     assert_eq!(session_info.updated_at, record.updated_at);
+    assert_eq!(session_info.approval_request, Some(3));
+    assert_eq!(
+        session_info.stale_command.as_deref(),
+        Some("approval of request 2")
+    );
 }
@@ -0,0 +1,126 @@
+use super::*;
+use crate::session_daemon::LivenessState;
+use std::path::PathBuf;
+
+const TOKEN: &str = "dashboard-token";
+
+fn make_session(id: &str, status: &str) -> SessionInfo {
+    SessionInfo {
+        session_id: id.to_string(),
+        feature_name: format!("feature-{}", id),
+        phase: "Reviewing".to_string(),
+        iteration: 2,
+        status: status.to_string(),
+        liveness: LivenessState::Running,
+        started_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: chrono::Utc::now().to_rfc3339(),
+        pid: 0,
+        implementation_phase: None,
+        implementation_iteration: None,
+        implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
+    }
+}
+
+fn state() -> HostState {
+    let mut state = HostState::new();
+    state.add_container(
+        "c1".to_string(),
+        "backend".to_string(),
+        PathBuf::from("/work/backend"),
+        "abc123".to_string(),
+        1234567890,
+        0,
+    );
+    state.sync_sessions(
+        "c1",
+        vec![
+            make_session("s1", "Running"),
+            make_session("s2", "AwaitingApproval"),
+        ],
+    );
+    state
+}
+
+fn get(state: &mut HostState, path: &str) -> ApiResponse {
+    handle_request(
+        state,
+        "GET",
+        path,
+        Some(&format!("Bearer {}", TOKEN)),
+        TOKEN,
+    )
+}
+
+#[test]
+fn test_requests_need_the_bearer_token_and_get() {
+    let mut state = state();
+    for authorization in [None, Some("Bearer wrong-token"), Some(TOKEN)] {
+        let response = handle_request(&mut state, "GET", "/api/v1/summary", authorization, TOKEN);
+        assert_eq!(response.status, 401);
+    }
+    let authorization = format!("Bearer {}", TOKEN);
+    let response = handle_request(
+        &mut state,
+        "POST",
+        "/api/v1/sessions",
+        Some(&authorization),
+        TOKEN,
+    );
+    assert_eq!(response.status, 405);
+    assert_eq!(get(&mut state, "/api/v1/nothing").status, 404);
+}
+
+#[test]
+fn test_summary_and_sessions() {
+    let mut state = state();
+
+    let summary = get(&mut state, "/api/v1/summary/");
+    assert_eq!(summary.status, 200);
+    assert_eq!(summary.body["containers"], 1);
+    assert_eq!(summary.body["sessions"], 2);
+    assert_eq!(summary.body["awaiting_approval"], 1);
+
+    let containers = get(&mut state, "/api/v1/containers").body;
+    assert_eq!(containers[0]["container_name"], "backend");
+    assert_eq!(containers[0]["sessions"], 2);
+
+    // Awaiting approval sorts first, as on the dashboard
+    let sessions = get(&mut state, "/api/v1/sessions?fields=all").body;
+    assert_eq!(sessions[0]["session_id"], "s2");
+    assert_eq!(sessions[0]["container_name"], "backend");
+    assert!(sessions[0].get("timeline").is_none());
+
+    let session = get(&mut state, "/api/v1/sessions/s1");
+    assert_eq!(session.body["feature_name"], "feature-s1");
+    assert_eq!(session.body["timeline"], serde_json::json!([]));
+    assert_eq!(get(&mut state, "/api/v1/sessions/missing").status, 404);
+}
+
+#[tokio::test]
+async fn test_serves_json_over_http() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(serve(
+        listener,
+        Arc::new(Mutex::new(state())),
+        Arc::new(TOKEN.to_string()),
+    ));
+
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let request = format!(
+        "GET /api/v1/summary HTTP/1.1\r\nHost: localhost\r\nauthorization: Bearer {}\r\n\r\n",
+        TOKEN
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    server.abort();
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Content-Type: application/json"));
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    let body: Value = serde_json::from_str(body).unwrap();
+    assert_eq!(body["sessions"], 2);
+}
@@ -21,6 +21,8 @@ fn create_test_session(id: &str, status: &str) -> SessionInfo {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     }
 }
 
@@ -606,3 +608,124 @@ async fn test_display_sessions_include_container_name() {
     assert_eq!(sessions[0].container_name, "Display Test Container");
     assert_eq!(sessions[0].session.session_id, "display-session");
 }
+
+// ============================================================================
+// Session Sharing Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_share_and_watch_session() {
+    let server = TestHostServer::start().await;
+    let daemon = server.create_client().await;
+    let watcher = server.create_client().await;
+
+    // Sharing needs the handshake, so the host knows which container owns the session
+    let result = daemon
+        .share_session(tarpc::context::current(), "s1".into(), "tok".into())
+        .await
+        .unwrap();
+    assert!(matches!(result, Err(HostError::ContainerNotRegistered)));
+
+    let info = ContainerInfo {
+        container_id: "container-1".to_string(),
+        container_name: "Test Container".to_string(),
+        working_dir: std::path::PathBuf::from("/work"),
+        git_sha: "test123".to_string(),
+        build_timestamp: 1234567890,
+        file_service_port: 0,
+    };
+    daemon
+        .hello(tarpc::context::current(), info, PROTOCOL_VERSION)
+        .await
+        .unwrap()
+        .unwrap();
+    daemon
+        .session_update(
+            tarpc::context::current(),
+            create_test_session("s1", "Running"),
+        )
+        .await
+        .unwrap();
+    daemon
+        .share_session(tarpc::context::current(), "s1".into(), "tok".into())
+        .await
+        .unwrap()
+        .unwrap();
+    daemon
+        .session_output(tarpc::context::current(), "s1".into(), vec!["hello".into()])
+        .await
+        .unwrap();
+
+    let update = watcher
+        .watch_session(tarpc::context::current(), "tok".into(), 0, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.session.unwrap().status, "Running");
+    assert_eq!(update.output, vec!["hello"]);
+
+    let result = watcher
+        .watch_session(tarpc::context::current(), "other".into(), 0, 0)
+        .await
+        .unwrap();
+    assert!(matches!(result, Err(HostError::UnknownShareToken)));
+}
+
+#[tokio::test]
+async fn test_report_account_usage_updates_host_view() {
+    let mut server = TestHostServer::start().await;
+    let client = server.create_client().await;
+
+    let info = ContainerInfo {
+        container_id: "container-usage".to_string(),
+        container_name: "Usage Container".to_string(),
+        working_dir: std::path::PathBuf::from("/work"),
+        git_sha: "test123".to_string(),
+        build_timestamp: 1234567890,
+        file_service_port: 0,
+    };
+    client
+        .hello(tarpc::context::current(), info, PROTOCOL_VERSION)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let usage = AccountUsageInfo {
+        account_id: String::new(),
+        provider: "claude".to_string(),
+        email: "usage-report@example.test".to_string(),
+        plan_type: Some("max".to_string()),
+        rate_limit_tier: None,
+        session_percent: Some(42),
+        session_reset_at: Some(1_900_000_000),
+        session_window_span: Default::default(),
+        weekly_percent: Some(7),
+        weekly_reset_at: None,
+        weekly_window_span: Default::default(),
+        fetched_at: "2024-01-01T00:00:00Z".to_string(),
+        token_valid: true,
+        error: None,
+    };
+    client
+        .report_account_usage(tarpc::context::current(), vec![usage])
+        .await
+        .unwrap();
+
+    let reported = client
+        .get_account_usage(tarpc::context::current())
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|account| account.email == "usage-report@example.test")
+        .expect("reported account should be tracked");
+    assert_eq!(reported.provider, "claude");
+    assert_eq!(reported.session_percent, Some(42));
+    assert_eq!(reported.session_reset_at, Some(1_900_000_000));
+    assert_eq!(reported.weekly_percent, Some(7));
+
+    let mut saw_usage_event = false;
+    while let Ok(event) = server.event_rx.try_recv() {
+        saw_usage_event |= matches!(event, HostEvent::UsageReported);
+    }
+    assert!(saw_usage_event);
+}
@@ -15,6 +15,8 @@ fn make_session(id: &str, status: &str) -> SessionInfo {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     }
 }
 
@@ -152,3 +154,138 @@ fn test_remove_session() {
 
     assert_eq!(state.active_count(), 1);
 }
+
+fn make_event(sequence: u64) -> WorkflowEventEnvelope {
+    WorkflowEventEnvelope {
+        aggregate_id: "agg-1".to_string(),
+        sequence,
+        event: crate::domain::WorkflowEvent::PlanningStarted {
+            started_at: crate::domain::types::TimestampUtc::now(),
+        },
+    }
+}
+
+#[test]
+fn test_record_workflow_events_dedupes_replay() {
+    let mut state = HostState::new();
+    state.add_container(
+        "c1".to_string(),
+        "Container 1".to_string(),
+        PathBuf::from("/test"),
+        "abc".to_string(),
+        0,
+        0,
+    );
+    state.update_session("c1", make_session("s1", "Running"));
+
+    state.record_workflow_events("c1", "s1", vec![make_event(1), make_event(2)]);
+    // Reconnect replays the journal, overlapping what was already received
+    state.record_workflow_events(
+        "c1",
+        "s1",
+        vec![make_event(1), make_event(2), make_event(3)],
+    );
+
+    let sessions = state.sessions();
+    let sequences: Vec<u64> = sessions[0].timeline.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3]);
+    assert_eq!(sessions[0].timeline[0].event_type, "PlanningStarted");
+}
+
+#[test]
+fn test_watch_shared_session() {
+    let mut state = HostState::new();
+    state.add_container(
+        "c1".to_string(),
+        "Container 1".to_string(),
+        PathBuf::from("/test"),
+        "abc".to_string(),
+        0,
+        0,
+    );
+    state.update_session("c1", make_session("s1", "Running"));
+    state.record_workflow_events("c1", "s1", vec![make_event(1), make_event(2)]);
+    assert!(state.watch_session("tok", 0, 0).is_none());
+
+    state.share_session("c1", "s1", "tok".to_string());
+    state.record_session_output("c1", "s1", vec!["one".to_string(), "two".to_string()]);
+
+    let update = state.watch_session("tok", 1, 0).unwrap();
+    assert_eq!(update.session.unwrap().session_id, "s1");
+    let sequences: Vec<u64> = update.events.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, vec![2]);
+    assert_eq!(update.output, vec!["one", "two"]);
+    assert_eq!(update.output_cursor, 2);
+
+    state.record_session_output("c1", "s1", vec!["three".to_string()]);
+    let update = state.watch_session("tok", 2, 2).unwrap();
+    assert!(update.events.is_empty());
+    assert_eq!(update.output, vec!["three"]);
+
+    // The token outlives a disconnect; the session reappears once the daemon reconnects
+    state.remove_container("c1");
+    let update = state.watch_session("tok", 2, 3).unwrap();
+    assert!(update.session.is_none());
+    assert_eq!(update.output_cursor, 3);
+}
+
+#[test]
+fn test_shared_output_is_bounded() {
+    let mut buffer = OutputBuffer::default();
+    let lines: Vec<String> = (0..SHARED_OUTPUT_MAX_LINES + 10)
+        .map(|i| i.to_string())
+        .collect();
+    buffer.push(lines);
+
+    // A watcher behind the buffer gets what is left
+    let (output, cursor) = buffer.since(0);
+    assert_eq!(output.len(), SHARED_OUTPUT_MAX_LINES);
+    assert_eq!(output[0], "10");
+    assert_eq!(cursor, SHARED_OUTPUT_MAX_LINES as u64 + 10);
+
+    // A cursor from before a reset gets everything
+    let mut fresh = OutputBuffer::default();
+    fresh.push(vec!["a".to_string()]);
+    assert_eq!(fresh.since(cursor), (vec!["a".to_string()], 1));
+}
+
+#[test]
+fn test_commands_are_queued_per_container_until_taken() {
+    let mut state = HostState::new();
+    state.add_container(
+        "c1".to_string(),
+        "Container 1".to_string(),
+        PathBuf::from("/test"),
+        "abc".to_string(),
+        0,
+        0,
+    );
+    state.update_session("c1", make_session("s1", "Running"));
+
+    assert!(state.queue_command("c1", "s1", RemoteCommand::Approve { request: 1 }));
+    assert!(!state.queue_command("c1", "missing", RemoteCommand::Stop));
+    assert!(!state.queue_command("c2", "s1", RemoteCommand::Stop));
+    assert!(state.queue_command(
+        "c1",
+        "s1",
+        RemoteCommand::Feedback {
+            text: "add tests".to_string()
+        }
+    ));
+
+    let commands: Vec<RemoteCommand> = state
+        .take_commands("c1")
+        .into_iter()
+        .map(|relayed| relayed.command)
+        .collect();
+    assert_eq!(
+        commands,
+        vec![
+            RemoteCommand::Approve { request: 1 },
+            RemoteCommand::Feedback {
+                text: "add tests".to_string()
+            }
+        ]
+    );
+    assert!(state.take_commands("c1").is_empty());
+}
@@ -0,0 +1,38 @@
+use super::*;
+use crate::session_daemon::LivenessState;
+
+#[test]
+fn test_host_address_adds_default_port() {
+    assert_eq!(
+        host_address("devbox"),
+        format!("devbox:{}", DEFAULT_HOST_PORT)
+    );
+    assert_eq!(host_address("10.0.0.5:9000"), "10.0.0.5:9000");
+}
+
+#[test]
+fn test_describe_session() {
+    let mut session = SessionInfo {
+        session_id: "s1".to_string(),
+        feature_name: "auth".to_string(),
+        phase: "Reviewing".to_string(),
+        iteration: 2,
+        status: "Running".to_string(),
+        liveness: LivenessState::Running,
+        started_at: String::new(),
+        updated_at: String::new(),
+        pid: 0,
+        implementation_phase: None,
+        implementation_iteration: None,
+        implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
+    };
+    assert_eq!(
+        describe_session(&session),
+        "auth: Reviewing (iteration 2), Running"
+    );
+
+    session.implementation_phase = Some("Implementing".to_string());
+    assert!(describe_session(&session).ends_with(", implementation: Implementing"));
+}
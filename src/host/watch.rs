@@ -0,0 +1,90 @@
+//! `planning watch`: follows a session shared through the host relay.
+//!
+//! Polls the host's `watch_session` RPC with the share token and prints status
+//! changes, workflow events and agent output as they arrive. The token is the
+//! only credential, so no daemon or handshake is involved.
+
+use crate::host::SessionInfo;
+use crate::rpc::host_service::HostServiceClient;
+use crate::session_daemon::rpc_upstream::DEFAULT_HOST_PORT;
+use anyhow::{Context, Result};
+use std::time::Duration;
+use tarpc::client;
+use tarpc::tokio_serde::formats::Bincode;
+
+/// How often the host is asked for new events and output.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Adds the default host port to an address without one.
+fn host_address(host: &str) -> String {
+    let has_port = host
+        .rsplit_once(':')
+        .is_some_and(|(_, port)| port.parse::<u16>().is_ok());
+    if has_port {
+        host.to_string()
+    } else {
+        format!("{}:{}", host, DEFAULT_HOST_PORT)
+    }
+}
+
+/// One-line summary of where a shared session is.
+fn describe_session(session: &SessionInfo) -> String {
+    let mut line = format!(
+        "{}: {} (iteration {}), {}",
+        session.feature_name, session.phase, session.iteration, session.status
+    );
+    if let Some(phase) = &session.implementation_phase {
+        line.push_str(&format!(", implementation: {}", phase));
+    }
+    line
+}
+
+/// Streams the session shared under `token` until interrupted.
+pub async fn run_watch(token: &str, host: &str) -> Result<()> {
+    use tarpc::serde_transport::tcp;
+
+    let addr = host_address(host);
+    let transport = tcp::connect(&addr, Bincode::default)
+        .await
+        .with_context(|| format!("Failed to connect to host at {}", addr))?;
+    let client = HostServiceClient::new(client::Config::default(), transport).spawn();
+
+    let mut after_sequence = 0;
+    let mut output_cursor = 0;
+    let mut last_status = None;
+    loop {
+        let update = client
+            .watch_session(
+                tarpc::context::current(),
+                token.to_string(),
+                after_sequence,
+                output_cursor,
+            )
+            .await
+            .context("Lost connection to host")??;
+
+        let status = update
+            .session
+            .as_ref()
+            .map(describe_session)
+            .unwrap_or_else(|| "Waiting for the session's daemon to reconnect".to_string());
+        if last_status.as_ref() != Some(&status) {
+            println!("== {}", status);
+            last_status = Some(status);
+        }
+        for event in &update.events {
+            println!("-- {}", event.event_type);
+            after_sequence = event.sequence;
+        }
+        for line in &update.output {
+            println!("{}", line);
+        }
+        output_cursor = update.output_cursor;
+
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/watch_tests.rs"]
+mod tests;
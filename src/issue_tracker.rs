@@ -0,0 +1,364 @@
+//! Imports planning objectives from Jira and Linear issues.
+//!
+//! `planning --from-issue <ID>` fetches the issue title, description and
+//! comments. The title becomes the workflow objective, the description and
+//! comments are attached to the session as a context file, and the session is
+//! linked back to the issue.
+
+use crate::domain::input::IssueImport;
+use crate::domain::types::{IssueLink, IssueTracker};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+const API_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Issue tracker access used to import objectives with `--from-issue`.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct IssueTrackerConfig {
+    #[serde(default)]
+    pub jira: Option<JiraConfig>,
+    #[serde(default)]
+    pub linear: Option<LinearConfig>,
+}
+
+/// Jira site and credentials.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JiraConfig {
+    /// Site URL, e.g. `https://example.atlassian.net`.
+    pub base_url: String,
+    /// Account email for Jira Cloud basic auth. Unset sends the token as a
+    /// bearer token (Jira Data Center personal access tokens).
+    #[serde(default)]
+    pub email: Option<String>,
+    /// API token. Prefer `token_env` to keep secrets out of workflow files.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Environment variable read when `token` is unset. Default: JIRA_API_TOKEN
+    #[serde(default = "default_jira_token_env")]
+    pub token_env: String,
+}
+
+/// Linear credentials.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LinearConfig {
+    /// GraphQL endpoint. Default: https://api.linear.app/graphql
+    #[serde(default = "default_linear_api_url")]
+    pub api_url: String,
+    /// API key. Prefer `token_env` to keep secrets out of workflow files.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Environment variable read when `token` is unset. Default: LINEAR_API_KEY
+    #[serde(default = "default_linear_token_env")]
+    pub token_env: String,
+}
+
+fn default_jira_token_env() -> String {
+    "JIRA_API_TOKEN".to_string()
+}
+
+fn default_linear_api_url() -> String {
+    "https://api.linear.app/graphql".to_string()
+}
+
+fn default_linear_token_env() -> String {
+    "LINEAR_API_KEY".to_string()
+}
+
+/// A comment on an imported issue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueComment {
+    pub author: String,
+    pub body: String,
+}
+
+/// An issue fetched from a tracker.
+#[derive(Debug, Clone)]
+pub struct ImportedIssue {
+    pub link: IssueLink,
+    pub description: String,
+    pub comments: Vec<IssueComment>,
+}
+
+impl ImportedIssue {
+    /// Builds the workflow objective, appending extra instructions given on the command line.
+    pub fn objective(&self, extra: &str) -> String {
+        let mut objective = format!("{}: {}", self.link.key(), self.link.title());
+        let extra = extra.trim();
+        if !extra.is_empty() {
+            objective.push_str("\n\n");
+            objective.push_str(extra);
+        }
+        objective
+    }
+
+    /// Renders the issue as markdown for the session's context attachment.
+    pub fn context_markdown(&self) -> String {
+        let mut out = format!(
+            "# {}: {}\n\nSource: {} ({})\n\n## Description\n\n",
+            self.link.key(),
+            self.link.title(),
+            self.link.url(),
+            self.link.tracker().label()
+        );
+        let description = self.description.trim();
+        if description.is_empty() {
+            out.push_str("_No description._\n");
+        } else {
+            out.push_str(description);
+            out.push('\n');
+        }
+        if !self.comments.is_empty() {
+            out.push_str("\n## Comments\n");
+            for comment in &self.comments {
+                out.push_str(&format!(
+                    "\n### {}\n\n{}\n",
+                    comment.author,
+                    comment.body.trim()
+                ));
+            }
+        }
+        out
+    }
+
+    /// Converts the issue into workflow input.
+    pub fn into_import(self) -> IssueImport {
+        let context = self.context_markdown();
+        IssueImport {
+            link: self.link,
+            context,
+        }
+    }
+}
+
+/// Resolves an issue reference to its tracker and key.
+///
+/// References may be prefixed with `jira:` or `linear:`. Unprefixed references
+/// use the only configured tracker.
+pub fn parse_issue_ref(id: &str, config: &IssueTrackerConfig) -> Result<(IssueTracker, String)> {
+    let id = id.trim();
+    let (tracker, key) = if let Some(key) = id.strip_prefix("jira:") {
+        (IssueTracker::Jira, key)
+    } else if let Some(key) = id.strip_prefix("linear:") {
+        (IssueTracker::Linear, key)
+    } else {
+        let tracker = match (&config.jira, &config.linear) {
+            (Some(_), None) => IssueTracker::Jira,
+            (None, Some(_)) => IssueTracker::Linear,
+            (Some(_), Some(_)) => anyhow::bail!(
+                "Both Jira and Linear are configured; prefix the issue with 'jira:' or 'linear:'"
+            ),
+            (None, None) => anyhow::bail!(
+                "No issue tracker configured; add an issue_tracker section to workflow.yaml"
+            ),
+        };
+        (tracker, id)
+    };
+
+    if !is_valid_issue_key(key) {
+        anyhow::bail!("Invalid issue key '{}': expected a key like ABC-123", key);
+    }
+    Ok((tracker, key.to_uppercase()))
+}
+
+/// Returns true for keys of the form `<PROJECT>-<NUMBER>`.
+fn is_valid_issue_key(key: &str) -> bool {
+    let Some((project, number)) = key.rsplit_once('-') else {
+        return false;
+    };
+    project
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && project
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Fetches an issue from the tracker it belongs to. Blocking.
+pub fn fetch_issue(id: &str, config: &IssueTrackerConfig) -> Result<ImportedIssue> {
+    let (tracker, key) = parse_issue_ref(id, config)?;
+    match tracker {
+        IssueTracker::Jira => {
+            let jira = config
+                .jira
+                .as_ref()
+                .context("Jira is not configured in issue_tracker.jira")?;
+            fetch_jira_issue(&key, jira)
+        }
+        IssueTracker::Linear => {
+            let linear = config
+                .linear
+                .as_ref()
+                .context("Linear is not configured in issue_tracker.linear")?;
+            fetch_linear_issue(&key, linear)
+        }
+    }
+}
+
+/// Fetches an issue on the blocking thread pool.
+pub async fn fetch_issue_async(id: String, config: IssueTrackerConfig) -> Result<ImportedIssue> {
+    tokio::task::spawn_blocking(move || fetch_issue(&id, &config))
+        .await
+        .context("Issue fetch task panicked")?
+}
+
+/// Returns the configured token, falling back to the named environment variable.
+fn resolve_token(token: &Option<String>, token_env: &str) -> Result<String> {
+    if let Some(token) = token.as_ref().filter(|t| !t.is_empty()) {
+        return Ok(token.clone());
+    }
+    std::env::var(token_env)
+        .ok()
+        .filter(|t| !t.is_empty())
+        .with_context(|| format!("Issue tracker token not set (expected ${})", token_env))
+}
+
+fn fetch_jira_issue(key: &str, config: &JiraConfig) -> Result<ImportedIssue> {
+    use base64::Engine;
+
+    let token = resolve_token(&config.token, &config.token_env)?;
+    let authorization = match &config.email {
+        Some(email) => format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", email, token))
+        ),
+        None => format!("Bearer {}", token),
+    };
+    let base_url = config.base_url.trim_end_matches('/');
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(API_TIMEOUT))
+        .build()
+        .into();
+    let body: String = agent
+        .get(&format!(
+            "{}/rest/api/2/issue/{}?fields=summary,description,comment",
+            base_url, key
+        ))
+        .header("Authorization", &authorization)
+        .header("Accept", "application/json")
+        .call()
+        .with_context(|| format!("Failed to fetch Jira issue {}", key))?
+        .body_mut()
+        .read_to_string()
+        .context("Failed to read Jira response")?;
+
+    let json: Value = serde_json::from_str(&body).context("Failed to parse Jira response")?;
+    parse_jira_issue(base_url, &json)
+}
+
+/// Parses a Jira REST v2 issue response.
+fn parse_jira_issue(base_url: &str, json: &Value) -> Result<ImportedIssue> {
+    let key = json["key"].as_str().context("Missing key in Jira issue")?;
+    let fields = &json["fields"];
+    let title = fields["summary"]
+        .as_str()
+        .context("Missing summary in Jira issue")?;
+    let comments = fields["comment"]["comments"]
+        .as_array()
+        .map(|comments| {
+            comments
+                .iter()
+                .map(|c| IssueComment {
+                    author: c["author"]["displayName"]
+                        .as_str()
+                        .unwrap_or("Unknown")
+                        .to_string(),
+                    body: c["body"].as_str().unwrap_or_default().to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ImportedIssue {
+        link: IssueLink::new(
+            IssueTracker::Jira,
+            key.to_string(),
+            format!("{}/browse/{}", base_url.trim_end_matches('/'), key),
+            title.to_string(),
+        ),
+        description: fields["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        comments,
+    })
+}
+
+const LINEAR_ISSUE_QUERY: &str = "query Issue($id: String!) { issue(id: $id) { identifier title description url comments { nodes { body user { name } } } } }";
+
+fn fetch_linear_issue(key: &str, config: &LinearConfig) -> Result<ImportedIssue> {
+    let token = resolve_token(&config.token, &config.token_env)?;
+    let request = serde_json::json!({
+        "query": LINEAR_ISSUE_QUERY,
+        "variables": { "id": key },
+    });
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(API_TIMEOUT))
+        .build()
+        .into();
+    let body: String = agent
+        .post(&config.api_url)
+        .header("Authorization", &token)
+        .header("Content-Type", "application/json")
+        .send(&request.to_string())
+        .with_context(|| format!("Failed to fetch Linear issue {}", key))?
+        .body_mut()
+        .read_to_string()
+        .context("Failed to read Linear response")?;
+
+    let json: Value = serde_json::from_str(&body).context("Failed to parse Linear response")?;
+    parse_linear_issue(&json)
+}
+
+/// Parses a Linear GraphQL issue response.
+fn parse_linear_issue(json: &Value) -> Result<ImportedIssue> {
+    if let Some(message) = json["errors"][0]["message"].as_str() {
+        anyhow::bail!("Linear API error: {}", message);
+    }
+    let issue = &json["data"]["issue"];
+    if issue.is_null() {
+        anyhow::bail!("Linear issue not found");
+    }
+    let key = issue["identifier"]
+        .as_str()
+        .context("Missing identifier in Linear issue")?;
+    let title = issue["title"]
+        .as_str()
+        .context("Missing title in Linear issue")?;
+    let comments = issue["comments"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|c| IssueComment {
+                    author: c["user"]["name"].as_str().unwrap_or("Unknown").to_string(),
+                    body: c["body"].as_str().unwrap_or_default().to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ImportedIssue {
+        link: IssueLink::new(
+            IssueTracker::Linear,
+            key.to_string(),
+            issue["url"].as_str().unwrap_or_default().to_string(),
+            title.to_string(),
+        ),
+        description: issue["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        comments,
+    })
+}
+
+#[cfg(test)]
+#[path = "tests/issue_tracker_tests.rs"]
+mod tests;
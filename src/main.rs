@@ -1,6 +1,7 @@
 mod account_usage;
 mod agents;
 mod app;
+mod ci_logs;
 mod config;
 mod config_modes;
 mod daemon_log;
@@ -8,6 +9,7 @@ pub mod domain;
 pub mod event_store;
 mod git_worktree;
 mod host;
+mod issue_tracker;
 mod phases;
 mod planning_paths;
 pub mod prompt_format;
@@ -19,16 +21,14 @@ mod tui;
 mod update;
 mod usage_reset;
 
-use anyhow::Result;
-use app::{cli::Cli, tui_runner::run_tui};
+use anyhow::{Context, Result};
+use app::cli::{Cli, CliCommand, EvalAction, ScheduleAction, SkillsAction};
+use app::tui_runner::run_tui;
 use clap::Parser;
 use std::path::{Path, PathBuf};
+use tui::ui::util::{pad_to_width, truncate_start_to_width, truncate_to_width};
 
 fn main() -> Result<()> {
-    if let Err(e) = skills::install_skills_if_needed() {
-        eprintln!("[planning-agent] Warning: Failed to install skills: {}", e);
-    }
-
     // Build runtime with fast shutdown - don't wait for blocking tasks
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -45,6 +45,12 @@ fn main() -> Result<()> {
 
 async fn async_main() -> Result<()> {
     let start = std::time::Instant::now();
+    let mut cli = Cli::parse();
+
+    // Handle permission hook mode (internal, run by an agent before each risky tool call)
+    if let Some(socket) = &cli.permission_hook {
+        return agents::permissions::gate::run_permission_hook(socket).await;
+    }
 
     // Log startup message to session-scoped startup log (merged into session log later)
     {
@@ -53,7 +59,6 @@ async fn async_main() -> Result<()> {
     }
     session_daemon::log_startup("main starting");
 
-    let cli = Cli::parse();
     session_daemon::log_startup("cli parsed");
 
     // Handle session daemon mode (internal, used by connect-or-spawn)
@@ -63,7 +68,12 @@ async fn async_main() -> Result<()> {
 
     // Handle host mode (desktop GUI aggregating container sessions)
     if cli.host {
-        return run_host(cli.port).await;
+        return run_host(cli.port, cli.api_port).await;
+    }
+
+    if let Some(spec) = &cli.fault_inject {
+        let spec = spec.parse().context("Invalid --fault-inject spec")?;
+        agents::faults::enable_cli_fault_injection(spec);
     }
 
     // Handle session management commands first (no TUI needed)
@@ -80,6 +90,65 @@ async fn async_main() -> Result<()> {
         return cleanup_sessions(&working_dir, cli.older_than);
     }
 
+    if cli.kill_orphans {
+        return kill_orphans().await;
+    }
+
+    if cli.daemon_status {
+        return daemon_status().await;
+    }
+
+    let implement_plan = match cli.command.take() {
+        Some(CliCommand::Schedule { action }) => {
+            return run_schedule_command(action, &working_dir).await;
+        }
+        Some(CliCommand::Skills { action }) => return run_skills_command(action, &working_dir),
+        Some(CliCommand::Doctor) => return app::doctor::run_doctor(&working_dir).await,
+        Some(CliCommand::Update { channel }) => return app::self_update::run_update(channel).await,
+        Some(CliCommand::Usage { since, csv }) => {
+            return app::usage_report::run_usage_report(&since, csv.as_deref())
+        }
+        Some(CliCommand::Eval {
+            action: EvalAction::Reviewers { fixtures },
+        }) => return app::eval::run_eval_reviewers(&cli, &fixtures).await,
+        Some(CliCommand::Experiment {
+            objective,
+            arms,
+            runs,
+        }) => return app::experiment::run_experiment(&cli, &objective, &arms, runs).await,
+        Some(CliCommand::Play {
+            recording,
+            speed,
+            max_idle,
+        }) => {
+            return tokio::task::spawn_blocking(move || {
+                app::tui_runner::recording::play_recording(recording, speed, max_idle)
+            })
+            .await?;
+        }
+        Some(CliCommand::Implement { plan }) => Some(plan),
+        Some(CliCommand::Share { session_id }) => return share_session(&session_id).await,
+        Some(CliCommand::Watch { token, host }) => {
+            return host::watch::run_watch(&token, &host).await
+        }
+        None => None,
+    };
+
+    if let Err(e) = skills::install_skills_if_needed(&working_dir) {
+        eprintln!("[planning-agent] Warning: Failed to install skills: {}", e);
+    }
+
+    if let Some(plan) = implement_plan {
+        return app::headless::run_implement(cli, plan).await;
+    }
+
+    // Run workflow without a TUI (also used by daemon-scheduled workers)
+    if cli.headless {
+        return app::headless::run_headless(cli).await;
+    }
+
+    offer_orphan_cleanup().await;
+
     // Run TUI workflow
     let result = run_tui(cli, start).await;
     session_daemon::log_startup("main function returning");
@@ -213,12 +282,12 @@ async fn list_sessions(_working_dir: &Path) -> Result<()> {
 
     for entry in entries {
         println!(
-            "{:<36} {:<16} {:<12} {:<4} {:<10} {:<12} {}",
-            truncate_string(&entry.session_id, 34),
-            truncate_string(&entry.feature_name, 14),
-            truncate_string(&entry.phase, 10),
+            "{} {} {} {:<4} {} {:<12} {}",
+            pad_to_width(&truncate_to_width(&entry.session_id, 34), 36),
+            pad_to_width(&truncate_to_width(&entry.feature_name, 14), 16),
+            pad_to_width(&truncate_to_width(&entry.phase, 10), 12),
             entry.iteration,
-            truncate_string(&entry.workflow_status, 8),
+            pad_to_width(&truncate_to_width(&entry.workflow_status, 8), 10),
             entry.liveness,
             entry.last_seen,
         );
@@ -246,20 +315,337 @@ fn cleanup_sessions(_working_dir: &Path, older_than: Option<u32>) -> Result<()>
     Ok(())
 }
 
-/// Truncates a string to a max length, adding "..." if truncated
-fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        let prefix = s.get(..max_len.saturating_sub(3)).unwrap_or("");
-        format!("{}...", prefix)
+/// Lists orphaned agent processes, one per line.
+fn print_orphans(orphans: &[agents::orphans::OrphanedAgent]) {
+    for orphan in orphans {
+        println!(
+            "  - pid {} ({}) from session {}, started {}",
+            orphan.record.pid,
+            orphan.record.agent_name,
+            orphan.session_id,
+            format_relative_time(&orphan.record.started_at)
+        );
     }
 }
 
-/// Run the host application with GUI and RPC server.
+/// Handles `--kill-orphans`
+async fn kill_orphans() -> Result<()> {
+    let orphans = agents::orphans::find_orphaned_agents()?;
+    if orphans.is_empty() {
+        println!("No orphaned agent processes found.");
+        return Ok(());
+    }
+    println!("Found {} orphaned agent process(es):", orphans.len());
+    print_orphans(&orphans);
+    let killed = agents::orphans::kill_orphaned_agents(&orphans).await;
+    println!("Killed {} of {}.", killed, orphans.len());
+    Ok(())
+}
+
+/// Handles `--daemon-status`
+async fn daemon_status() -> Result<()> {
+    println!("Session daemon for user {}", planning_paths::daemon_user());
+    println!(
+        "  Home:      {}",
+        planning_paths::planning_agent_home_dir()?.display()
+    );
+    let pid_path = planning_paths::sessiond_pid_path()?;
+    let pid = std::fs::read_to_string(&pid_path).unwrap_or_default();
+    println!(
+        "  PID:       {} ({})",
+        if pid.trim().is_empty() {
+            "-"
+        } else {
+            pid.trim()
+        },
+        pid_path.display()
+    );
+
+    let port_path = planning_paths::sessiond_port_path()?;
+    let port_info = std::fs::read_to_string(&port_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<rpc::PortFileContent>(&content).ok());
+    match &port_info {
+        Some(info) => {
+            println!(
+                "  Ports:     {} (main), {} (subscriber), {} (file service)",
+                info.port, info.subscriber_port, info.file_service_port
+            );
+            match &info.socket_path {
+                Some(path) => println!("  Socket:    {}", path.display()),
+                None => println!("  Socket:    none (TCP only)"),
+            }
+        }
+        None => println!("  Ports:     no port file at {}", port_path.display()),
+    }
+
+    let status = match &port_info {
+        None => "not running".to_string(),
+        Some(info) => {
+            let timeout = std::time::Duration::from_secs(2);
+            match session_daemon::rpc_client::probe_daemon(info, timeout).await {
+                Ok(count) => format!("running, {} session(s) registered", count),
+                Err(e) => format!("unreachable ({})", e),
+            }
+        }
+    };
+    println!("  Status:    {}", status);
+    println!(
+        "  Recovered: {} stale port file(s) left by crashed daemons",
+        session_daemon::rpc_client::stale_recovery_count()
+    );
+    println!(
+        "  Host:      {}",
+        session_daemon::rpc_upstream::describe_host_upstream().await
+    );
+
+    println!();
+    println!("Isolation:");
+    println!("  - Each user runs their own daemon; its files are named sessiond-<user>.*");
+    println!("  - The port file holding the auth token is readable only by its owner");
+    println!("  - Every connection must present that token before any other call");
+    #[cfg(unix)]
+    println!("  - The Unix socket rejects connections from other UIDs (peer credentials)");
+    Ok(())
+}
+
+/// Before the TUI starts, offers to kill agents left running by killed sessions.
+async fn offer_orphan_cleanup() {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let orphans = match agents::orphans::find_orphaned_agents() {
+        Ok(orphans) if !orphans.is_empty() => orphans,
+        Ok(_) => return,
+        Err(e) => {
+            session_daemon::log_startup(&format!("orphan scan failed: {}", e));
+            return;
+        }
+    };
+    println!(
+        "[planning-agent] {} agent process(es) are still running from sessions that were killed:",
+        orphans.len()
+    );
+    print_orphans(&orphans);
+    if !std::io::stdin().is_terminal() {
+        println!("[planning-agent] Run `planning --kill-orphans` to stop them.");
+        return;
+    }
+    print!("Kill them now? [y/N] ");
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    let _ = std::io::stdin().lock().read_line(&mut answer);
+    if answer.trim().eq_ignore_ascii_case("y") {
+        let killed = agents::orphans::kill_orphaned_agents(&orphans).await;
+        println!("Killed {} of {}.", killed, orphans.len());
+    }
+}
+
+/// Handles `planning share` by asking the session daemon for a share token
+async fn share_session(session_id: &str) -> Result<()> {
+    let daemon_client = session_daemon::RpcClient::new(false).await;
+    let token = daemon_client.share_session(session_id).await?;
+    println!("Shared session {} read-only through the host.", session_id);
+    println!("Anyone who can reach the host can follow it with:");
+    println!("  planning watch {} --host <host address>", token);
+    Ok(())
+}
+
+/// Handles `planning schedule add|list|rm` through the session daemon
+async fn run_schedule_command(action: ScheduleAction, working_dir: &Path) -> Result<()> {
+    use session_daemon::schedule::{ScheduleSpec, ScheduledWorkflow};
+
+    let daemon_client = session_daemon::RpcClient::new(false).await;
+
+    match action {
+        ScheduleAction::Add {
+            when,
+            repo,
+            max_iterations,
+            auto_archive,
+            objective,
+        } => {
+            let spec = ScheduleSpec::parse(&when)?;
+            let repo = repo.unwrap_or_else(|| working_dir.to_path_buf());
+            let repo = std::fs::canonicalize(&repo)
+                .with_context(|| format!("Repository not found: {}", repo.display()))?;
+            let schedule = ScheduledWorkflow::new(
+                spec,
+                objective.join(" ").trim().to_string(),
+                repo,
+                max_iterations,
+                auto_archive,
+            );
+            daemon_client.schedule_add(schedule.clone()).await?;
+            println!(
+                "Added schedule {} ({}), next run {}",
+                schedule.id,
+                schedule.spec,
+                schedule.next_run().format("%Y-%m-%d %H:%M")
+            );
+        }
+        ScheduleAction::List => {
+            let schedules = daemon_client.schedule_list().await?;
+            if schedules.is_empty() {
+                println!("No scheduled workflows.");
+                println!(
+                    "Add one with: planning schedule add --when \"every monday 09:00\" <objective>"
+                );
+                return Ok(());
+            }
+
+            println!(
+                "{:<10} {:<22} {:<18} {:<24} Objective",
+                "ID", "Schedule", "Next Run", "Repository"
+            );
+            println!("{}", "-".repeat(100));
+            for schedule in schedules {
+                println!(
+                    "{:<10} {:<22} {:<18} {} {}",
+                    schedule.id,
+                    schedule.spec.to_string(),
+                    schedule.next_run().format("%Y-%m-%d %H:%M").to_string(),
+                    pad_to_width(
+                        &truncate_start_to_width(&schedule.working_dir.display().to_string(), 22),
+                        24
+                    ),
+                    truncate_to_width(&schedule.objective, 40),
+                );
+            }
+        }
+        ScheduleAction::Rm { id } => {
+            daemon_client.schedule_remove(&id).await?;
+            println!("Removed schedule {}", id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles `planning skills list|install|update|remove`
+fn run_skills_command(action: SkillsAction, working_dir: &Path) -> Result<()> {
+    use skills::packs;
+
+    match action {
+        SkillsAction::List => {
+            let builtin: Vec<&str> = skills::BUILTIN_SKILLS.iter().map(|(n, _)| *n).collect();
+            println!(
+                "Built-in skills (v{}): {}",
+                env!("CARGO_PKG_VERSION"),
+                builtin.join(", ")
+            );
+
+            let installed = packs::installed_packs()?;
+            println!();
+            if installed.is_empty() {
+                println!("No skill packs installed.");
+                println!("Install one with: planning skills install <dir> --pin");
+            } else {
+                println!("{:<24} {:<12} {:<14} Skills", "Pack", "Version", "Checksum");
+                println!("{}", "-".repeat(80));
+                for pack in &installed {
+                    let skills: Vec<&str> = pack.skills.keys().map(String::as_str).collect();
+                    println!(
+                        "{:<24} {:<12} {:<14} {}",
+                        truncate_to_width(&pack.name, 24),
+                        truncate_to_width(&pack.version, 12),
+                        truncate_to_width(&pack.checksum(), 12),
+                        truncate_to_width(&skills.join(", "), 40),
+                    );
+                }
+            }
+
+            let pins = packs::read_pins(working_dir)?;
+            println!();
+            if pins.is_empty() {
+                println!("No packs pinned in {}.", packs::PROJECT_CONFIG_FILE);
+            } else {
+                println!("Pinned in {}:", packs::PROJECT_CONFIG_FILE);
+                for pin in &pins {
+                    let status = match packs::verify_pin(pin) {
+                        Ok(_) => "ok".to_string(),
+                        Err(e) => format!("{:#}", e),
+                    };
+                    println!("  {}@{}  {}", pin.name, pin.version, status);
+                }
+            }
+        }
+        SkillsAction::Install { source, pin } => {
+            let manifest = packs::install_pack(&source)?;
+            println!(
+                "Installed {} ({} skills, checksum {})",
+                manifest.label(),
+                manifest.skills.len(),
+                manifest.checksum()
+            );
+            if pin {
+                packs::pin_pack(working_dir, &manifest)?;
+                println!(
+                    "Pinned {} in {}",
+                    manifest.label(),
+                    packs::PROJECT_CONFIG_FILE
+                );
+            }
+        }
+        SkillsAction::Update { name } => {
+            let pins = packs::read_pins(working_dir)?;
+            let mut sources: Vec<(String, PathBuf)> = Vec::new();
+            for pack in packs::installed_packs()? {
+                if name.as_ref().is_some_and(|n| *n != pack.name) {
+                    continue;
+                }
+                let Some(source) = pack.source else {
+                    continue;
+                };
+                // Later versions sort last, so the newest install's source wins
+                sources.retain(|(n, _)| *n != pack.name);
+                sources.push((pack.name, source));
+            }
+            if sources.is_empty() {
+                anyhow::bail!("No installed skill packs to update");
+            }
+
+            for (pack_name, source) in sources {
+                let manifest = match packs::install_pack(&source) {
+                    Ok(manifest) => manifest,
+                    Err(e) => {
+                        eprintln!("{}: update failed: {:#}", pack_name, e);
+                        continue;
+                    }
+                };
+                let pinned = pins.iter().find(|p| p.name == manifest.name);
+                match pinned {
+                    Some(p) if p.version != manifest.version => {
+                        packs::pin_pack(working_dir, &manifest)?;
+                        println!("{}: pin {} -> {}", p.name, p.version, manifest.version);
+                    }
+                    _ => println!("{}: {} is installed", pack_name, manifest.label()),
+                }
+            }
+        }
+        SkillsAction::Remove { name, version } => {
+            let removed = packs::remove_pack(&name, version.as_deref())?;
+            println!("Removed {} {}", name, removed.join(", "));
+
+            let mut pins = packs::read_pins(working_dir)?;
+            let before = pins.len();
+            pins.retain(|p| !(p.name == name && removed.contains(&p.version)));
+            if pins.len() != before {
+                packs::write_pins(working_dir, &pins)?;
+                println!("Unpinned {} from {}", name, packs::PROJECT_CONFIG_FILE);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the host application with GUI and RPC server, plus the REST API when
+/// `api_port` is given.
 #[cfg(feature = "host-gui")]
-async fn run_host(port: u16) -> Result<()> {
+async fn run_host(port: u16, api_port: Option<u16>) -> Result<()> {
     use crate::host::gui::app::HostApp;
+    use crate::host::gui::tray::WINDOW_TITLE;
+    use crate::host::rest_api::{load_api_token, run_host_rest_api};
     use crate::host::rpc_server::run_host_rpc_server;
     use crate::host::state::HostState;
     use eframe::egui;
@@ -276,19 +662,31 @@ async fn run_host(port: u16) -> Result<()> {
             eprintln!("[host] Server error: {}", e);
         }
     });
+    let api_handle = match api_port {
+        Some(api_port) => {
+            let token = load_api_token()?;
+            let api_state = state.clone();
+            Some(tokio::spawn(async move {
+                if let Err(e) = run_host_rest_api(api_port, api_state, token).await {
+                    eprintln!("[host] REST API error: {:#}", e);
+                }
+            }))
+        }
+        None => None,
+    };
 
     // Run GUI on main thread (required for macOS)
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([1200.0, 600.0])
             .with_min_inner_size([800.0, 400.0])
-            .with_title("Planning Agent Host"),
+            .with_title(WINDOW_TITLE),
         ..Default::default()
     };
 
     // This blocks until window is closed
     let gui_result = eframe::run_native(
-        "Planning Agent Host",
+        WINDOW_TITLE,
         native_options,
         Box::new(move |cc| {
             // Force dark visuals for consistent appearance
@@ -299,13 +697,16 @@ async fn run_host(port: u16) -> Result<()> {
 
     // Cleanup
     server_handle.abort();
+    if let Some(api_handle) = api_handle {
+        api_handle.abort();
+    }
 
     gui_result.map_err(|e| anyhow::anyhow!("GUI error: {}", e))
 }
 
 /// Stub for host mode when GUI feature is not enabled.
 #[cfg(not(feature = "host-gui"))]
-async fn run_host(_port: u16) -> Result<()> {
+async fn run_host(_port: u16, _api_port: Option<u16>) -> Result<()> {
     anyhow::bail!(
         "Host mode requires the 'host-gui' feature.\n\
          Build with: cargo build --features host-gui\n\
@@ -0,0 +1,313 @@
+//! Splitting reviewer feedback into discrete items for triage.
+//!
+//! Each rejecting review's markdown is split on its top-level list items, keeping
+//! the heading they appeared under. Nested bullets and continuation lines stay
+//! with their item. Summary/assessment sections are skipped, and a review without
+//! any list items becomes a single item built from its summary.
+//!
+//! Items parked during triage are kept in a backlog section at the end of the plan.
+//!
+//! Notes the user attaches to single review lines in the review modal are stored in
+//! the session directory and passed to the next revision. The free-form notes kept
+//! with `/notes` live there too, and reach the prompts when `notes_in_prompts` is on.
+
+use crate::config::WorkflowConfig;
+use crate::domain::review::FeedbackItem;
+use crate::domain::view::WorkflowView;
+use crate::phases::ReviewResult;
+use crate::planning_paths;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Heading of the plan section listing parked feedback items.
+pub const BACKLOG_HEADING: &str = "## Backlog (Parked Review Feedback)";
+
+/// File in the session directory holding the user's notes on review lines.
+pub const REVIEW_ANNOTATIONS_FILE: &str = "review-annotations.json";
+
+/// Heading of the revision prompt section listing the user's notes on review lines.
+pub const USER_COMMENTS_HEADING: &str = "User comments on reviewer feedback";
+
+/// File in the session directory holding the user's `/notes`.
+pub const SESSION_NOTES_FILE: &str = "notes.md";
+
+/// Heading of the revision prompt section carrying the user's `/notes`.
+pub const SESSION_NOTES_HEADING: &str = "User notes";
+
+/// Headings whose content restates the verdict (or its scope) rather than asking for changes.
+const NON_ACTIONABLE_SECTIONS: [&str; 5] = [
+    "summary",
+    "assessment",
+    "verdict",
+    "strength",
+    "flagged sections",
+];
+
+/// Parses the feedback of every review that requested revision into items.
+pub fn parse_feedback_items(reviews: &[ReviewResult]) -> Vec<FeedbackItem> {
+    reviews
+        .iter()
+        .filter(|r| r.needs_revision)
+        .flat_map(|r| parse_review_items(&r.agent_name, &r.feedback, &r.summary))
+        .collect()
+}
+
+/// Returns the text of a top-level list item (`- `, `* `, `+ `, `1. `, `1) `).
+fn list_item_text(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(marker) {
+            return Some(text.trim());
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = line.split_at(digits).1;
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(str::trim)
+}
+
+fn parse_review_items(reviewer: &str, feedback: &str, summary: &str) -> Vec<FeedbackItem> {
+    let mut items: Vec<FeedbackItem> = Vec::new();
+    let mut section: Option<String> = None;
+    let mut skip_section = false;
+    let mut in_code_block = false;
+    // Whether unindented prose still continues the last item
+    let mut item_open = false;
+
+    for line in feedback.lines() {
+        let trimmed = line.trim();
+        let indented = line.starts_with("  ") || line.starts_with('\t');
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block && trimmed.starts_with('#') {
+            let heading = trimmed.trim_start_matches('#').trim().to_string();
+            let lower = heading.to_lowercase();
+            skip_section = NON_ACTIONABLE_SECTIONS.iter().any(|s| lower.contains(s));
+            section = Some(heading).filter(|h| !h.is_empty());
+            item_open = false;
+            continue;
+        }
+        if skip_section {
+            continue;
+        }
+
+        if !in_code_block && !indented {
+            if let Some(text) = list_item_text(trimmed).filter(|t| !t.is_empty()) {
+                items.push(FeedbackItem {
+                    reviewer: reviewer.to_string(),
+                    section: section.clone(),
+                    text: text.to_string(),
+                });
+                item_open = true;
+                continue;
+            }
+        }
+
+        if trimmed.is_empty() {
+            item_open = false;
+            continue;
+        }
+        if item_open || indented || in_code_block {
+            if let Some(item) = items.last_mut() {
+                item.text.push('\n');
+                item.text
+                    .push_str(if in_code_block { line } else { trimmed });
+                item_open = true;
+            }
+        }
+    }
+
+    if items.is_empty() && !summary.trim().is_empty() {
+        items.push(FeedbackItem {
+            reviewer: reviewer.to_string(),
+            section: None,
+            text: summary.trim().to_string(),
+        });
+    }
+    items
+}
+
+/// `reviewer (section)` label used in prompts and the backlog.
+pub fn item_source(item: &FeedbackItem) -> String {
+    match &item.section {
+        Some(section) => format!("{} ({})", item.reviewer, section),
+        None => item.reviewer.clone(),
+    }
+}
+
+fn push_list_item(out: &mut String, prefix: &str, item: &FeedbackItem) {
+    let mut lines = item.text.lines();
+    out.push_str(&format!(
+        "{}**{}**: {}\n",
+        prefix,
+        item_source(item),
+        lines.next().unwrap_or_default()
+    ));
+    for line in lines {
+        out.push_str(&format!("   {}\n", line));
+    }
+}
+
+/// Numbered list of accepted items for the revision prompt.
+pub fn format_accepted_feedback(items: &[FeedbackItem]) -> String {
+    let mut out = String::new();
+    for (i, item) in items.iter().enumerate() {
+        push_list_item(&mut out, &format!("{}. ", i + 1), item);
+    }
+    out.trim_end().to_string()
+}
+
+/// Replaces the plan's backlog section with the parked items (removing it if none).
+pub fn with_backlog_section(plan: &str, parked: &[FeedbackItem]) -> String {
+    let mut kept: Vec<&str> = Vec::new();
+    let mut in_backlog = false;
+    for line in plan.lines() {
+        if line.trim() == BACKLOG_HEADING {
+            in_backlog = true;
+            continue;
+        }
+        if in_backlog && (line.starts_with("# ") || line.starts_with("## ")) {
+            in_backlog = false;
+        }
+        if !in_backlog {
+            kept.push(line);
+        }
+    }
+
+    let mut out = kept.join("\n").trim_end().to_string();
+    if !parked.is_empty() {
+        out.push_str(&format!(
+            "\n\n{}\n\nReview feedback deferred during triage. Not part of the current scope.\n\n",
+            BACKLOG_HEADING
+        ));
+        for item in parked {
+            push_list_item(&mut out, "- ", item);
+        }
+        out = out.trim_end().to_string();
+    }
+    out.push('\n');
+    out
+}
+
+/// The user's position on an annotated review line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationStance {
+    Agree,
+    Disagree,
+    Context,
+}
+
+impl AnnotationStance {
+    pub fn label(self) -> &'static str {
+        match self {
+            AnnotationStance::Agree => "agree",
+            AnnotationStance::Disagree => "disagree",
+            AnnotationStance::Context => "context",
+        }
+    }
+}
+
+/// A user note attached to one line of a review file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewAnnotation {
+    /// Review file name in the session directory (e.g. `feedback_2_claude.md`)
+    pub review_file: String,
+    /// Zero-based line in the review file
+    pub line: usize,
+    /// Text of the annotated line, kept so the note reads on its own
+    pub feedback: String,
+    pub stance: AnnotationStance,
+    #[serde(default)]
+    pub note: String,
+}
+
+/// Loads the session's review annotations; a missing or unreadable file has none.
+pub fn load_review_annotations(session_dir: &Path) -> Vec<ReviewAnnotation> {
+    std::fs::read_to_string(session_dir.join(REVIEW_ANNOTATIONS_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Replaces the session's review annotations.
+pub fn save_review_annotations(session_dir: &Path, annotations: &[ReviewAnnotation]) -> Result<()> {
+    let path = session_dir.join(REVIEW_ANNOTATIONS_FILE);
+    let content = serde_json::to_string_pretty(annotations)?;
+    std::fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Loads the session's `/notes`; a missing, unreadable or blank file has none.
+pub fn load_session_notes(session_dir: &Path) -> Option<String> {
+    std::fs::read_to_string(session_dir.join(SESSION_NOTES_FILE))
+        .ok()
+        .map(|notes| notes.trim_end().to_string())
+        .filter(|notes| !notes.trim().is_empty())
+}
+
+/// Replaces the session's `/notes`; blank notes remove the file.
+pub fn save_session_notes(session_dir: &Path, notes: &str) -> Result<()> {
+    let path = session_dir.join(SESSION_NOTES_FILE);
+    if notes.trim().is_empty() {
+        return match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("Failed to remove {}", path.display()))
+            }
+            _ => Ok(()),
+        };
+    }
+    std::fs::write(&path, format!("{}\n", notes.trim_end()))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// The session's `/notes` for the planning and revision prompts, when the
+/// workflow config's `notes_in_prompts` is on.
+pub fn prompt_notes(config: &WorkflowConfig, view: &WorkflowView) -> Option<String> {
+    if !config.notes_in_prompts {
+        return None;
+    }
+    let workflow_id = view.workflow_id()?;
+    load_session_notes(&planning_paths::session_dir(&workflow_id.to_string()).ok()?)
+}
+
+/// Notes on the given review files for the revision prompt, or `None` if there are none.
+pub fn format_review_annotations(
+    annotations: &[ReviewAnnotation],
+    review_files: &[String],
+) -> Option<String> {
+    let mut relevant: Vec<&ReviewAnnotation> = annotations
+        .iter()
+        .filter(|a| review_files.contains(&a.review_file))
+        .collect();
+    if relevant.is_empty() {
+        return None;
+    }
+    relevant.sort_by(|a, b| (&a.review_file, a.line).cmp(&(&b.review_file, b.line)));
+
+    let mut out = String::from(
+        "The user commented on specific lines of the reviewer feedback. Take these \
+         comments into account when deciding what to change.\n",
+    );
+    for annotation in relevant {
+        out.push_str(&format!(
+            "\n- **{}** on {} line {}: \"{}\"",
+            annotation.stance.label(),
+            annotation.review_file,
+            annotation.line + 1,
+            annotation.feedback.trim()
+        ));
+        if !annotation.note.trim().is_empty() {
+            out.push_str(&format!("\n  User note: {}", annotation.note.trim()));
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+#[path = "tests/feedback_items_tests.rs"]
+mod tests;
@@ -3,13 +3,16 @@
 //! This module implements the plan execution phase using JSON-mode agents.
 //! It replaces the previous embedded PTY terminal with structured agent execution.
 
+use crate::agents::prompt::with_command_output;
 use crate::agents::{AgentContext, AgentType};
+use crate::app::workspace_trust::{is_workspace_trusted, restrict_config};
 use crate::config::WorkflowConfig;
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::types::{AgentId, ConversationId, PhaseLabel, ResumeStrategy, WorktreeState};
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
-use crate::phases::implementing_conversation_key;
+use crate::git_worktree::SourceSyncMethod;
+use crate::phases::{implementing_conversation_key, pending_command_output};
 use crate::planning_paths;
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::SessionEventSender;
@@ -96,6 +99,9 @@ pub async fn run_implementation_phase(
 
     // Build the prompt
     let prompt = build_implementation_prompt(view, working_dir, iteration, feedback);
+    // Recorded as delivered once the implementer has run with it
+    let command_output = pending_command_output(view, &actor_ref).await;
+    let prompt = with_command_output(prompt, &command_output);
 
     // Get workflow ID from view
     let workflow_id = view
@@ -142,6 +148,11 @@ pub async fn run_implementation_phase(
         resume_strategy: ResumeStrategy::ConversationResume,
         cancel_rx: None,
         session_logger: session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
     // Execute the implementation
@@ -154,6 +165,7 @@ pub async fn run_implementation_phase(
         )
         .await
         .context("Implementation agent execution failed")?;
+    deliver_command_output(&actor_ref, &session_logger, command_output.len()).await;
 
     // Store captured conversation ID for future resume
     if let Some(ref captured_id) = result.conversation_id {
@@ -206,6 +218,14 @@ pub async fn run_implementation_interaction(
     cancel_rx: watch::Receiver<bool>,
     actor_ref: Option<ActorRef<WorkflowMessage>>,
 ) -> Result<()> {
+    // Follow-ups get the same tools the implementation ran with
+    let restricted_config;
+    let config = if is_workspace_trusted(working_dir) {
+        config
+    } else {
+        restricted_config = restrict_config(config);
+        &restricted_config
+    };
     let result = run_implementation_interaction_inner(
         view,
         config,
@@ -270,6 +290,8 @@ async fn run_implementation_interaction_inner(
     ));
 
     let prompt = build_implementation_followup_prompt(view, working_dir, user_message);
+    let command_output = pending_command_output(view, &actor_ref).await;
+    let prompt = with_command_output(prompt, &command_output);
 
     // Dispatch RecordInvocation command to CQRS actor
     // Use Implementing phase for follow-up since it's a continuation of implementation
@@ -292,6 +314,11 @@ async fn run_implementation_interaction_inner(
         resume_strategy: ResumeStrategy::ConversationResume,
         cancel_rx: Some(cancel_rx),
         session_logger: session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
     let result = agent
@@ -303,6 +330,7 @@ async fn run_implementation_interaction_inner(
         )
         .await
         .context("Implementation follow-up agent execution failed")?;
+    deliver_command_output(&actor_ref, &session_logger, command_output.len()).await;
 
     // Store captured conversation ID for future resume via CQRS command
     if let Some(conv_id) = result.conversation_id {
@@ -321,6 +349,20 @@ async fn run_implementation_interaction_inner(
     Ok(())
 }
 
+/// Prompt block restating the conditions the plan was approved on, if any.
+pub fn approval_conditions_section(view: &WorkflowView) -> String {
+    match view.approval_conditions() {
+        Some(conditions) => format!(
+            "\n###################### CONDITIONS OF APPROVAL ######################\n\
+             The user approved the plan only on these conditions (also in the plan's\n\
+             \"Conditions of approval\" section). Every one of them must be satisfied:\n{}\n\
+             ######################################################################\n",
+            conditions.trim()
+        ),
+        None => String::new(),
+    }
+}
+
 /// Builds the implementation prompt with clean format and skill invocation at the end.
 fn build_implementation_prompt(
     view: &WorkflowView,
@@ -341,6 +383,7 @@ fn build_implementation_prompt(
         ),
         None => String::new(),
     };
+    let conditions_section = approval_conditions_section(view);
 
     format!(
         r#"Implement the approved plan.
@@ -350,11 +393,12 @@ fn build_implementation_prompt(
 Paths:
 - Workspace: {workspace}
 - Plan file: {plan}
-{feedback_section}
+{conditions_section}{feedback_section}
 Run the "implementation" skill to execute the plan."#,
         iteration = iteration,
         workspace = working_dir.display(),
         plan = plan_path,
+        conditions_section = conditions_section,
         feedback_section = feedback_section,
     )
 }
@@ -430,6 +474,69 @@ Important:
     )
 }
 
+/// Builds the prompt asking the agent to resolve worktree sync conflicts in place.
+///
+/// The agent edits and stages the files but leaves continuing the rebase/merge to
+/// the user, who reviews the result in the conflict overlay.
+pub fn build_conflict_resolution_prompt(
+    wt_state: &WorktreeState,
+    method: SourceSyncMethod,
+    files: &[String],
+) -> String {
+    let source_branch = wt_state.source_branch().unwrap_or("main");
+    let file_list = files
+        .iter()
+        .map(|f| format!("- {}", f))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"Resolve the merge conflicts in the worktree.
+
+######################### CONFLICT RESOLUTION REQUEST #########################
+
+Current situation:
+- Worktree: {worktree}
+- Worktree branch: {worktree_branch}
+- Source branch: {source_branch}
+- A `git {method}` of {source_branch} into the worktree branch stopped on conflicts
+
+Conflicted files:
+{file_list}
+
+Execute the following steps:
+1. Work in the worktree directory: cd {worktree}
+2. For each conflicted file, read both sides and combine them so that the session's changes are applied on top of the source branch's current code
+3. Remove every conflict marker
+4. Stage each resolved file: git add <file>
+
+Important:
+- Do NOT run `git {method} --continue`, `git commit`, or `git {method} --abort` - the user continues the {method} after reviewing your resolution
+- If a conflict cannot be resolved safely, leave the file conflicted and explain why
+"#,
+        worktree = wt_state.worktree_path().display(),
+        worktree_branch = wt_state.branch_name(),
+        source_branch = source_branch,
+        method = method.label(),
+        file_list = file_list,
+    )
+}
+
+/// Records that the oldest `count` queued command outputs went into an implementer prompt.
+async fn deliver_command_output(
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+    session_logger: &Arc<SessionLogger>,
+    count: usize,
+) {
+    if count > 0 {
+        let cmd = DomainCommand::DeliverCommandOutput {
+            count,
+            phase: PhaseLabel::Implementing,
+        };
+        dispatch_implementation_command(actor_ref, session_logger, cmd).await;
+    }
+}
+
 /// Helper to dispatch implementation commands to the CQRS actor.
 async fn dispatch_implementation_command(
     actor_ref: &Option<ActorRef<WorkflowMessage>>,
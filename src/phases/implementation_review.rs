@@ -7,6 +7,7 @@ use crate::agents::{AgentContext, AgentType};
 use crate::config::WorkflowConfig;
 use crate::domain::types::ResumeStrategy;
 use crate::domain::view::WorkflowView;
+use crate::phases::implementation::approval_conditions_section;
 use crate::phases::implementation_reviewing_conversation_key;
 use crate::phases::verdict::{
     extract_implementation_feedback, parse_verification_verdict, VerificationVerdictResult,
@@ -16,12 +17,110 @@ use crate::session_daemon::SessionLogger;
 use crate::tui::{ReviewKind, SessionEventSender};
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Minimal system prompt - the skill handles the details.
 const IMPLEMENTATION_REVIEW_SYSTEM_PROMPT: &str = "You are an implementation review agent.";
 
+/// Largest diff part the reviewer is pointed at; bigger diffs are split.
+const MAX_DIFF_PART_BYTES: usize = 200_000;
+
+/// The implementation diff written to the session directory for a review round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImplementationDiff {
+    /// The whole diff
+    pub path: PathBuf,
+    /// Parts to read in order, when the diff is larger than one part
+    pub parts: Vec<PathBuf>,
+    /// Size of the whole diff in bytes
+    pub bytes: usize,
+}
+
+/// Writes the workspace's changes against `base` (or `HEAD`), untracked files
+/// included, to the round's diff file. None outside a git repository.
+pub fn write_implementation_diff(
+    working_dir: &Path,
+    base: Option<&str>,
+    session_id: &str,
+    iteration: u32,
+) -> Result<Option<ImplementationDiff>> {
+    if !crate::git_worktree::is_git_repo(working_dir) {
+        return Ok(None);
+    }
+    let git = |args: &[&str]| -> Result<std::process::Output> {
+        std::process::Command::new("git")
+            .current_dir(working_dir)
+            .args(args)
+            .output()
+            .with_context(|| format!("Failed to run git {}", args.join(" ")))
+    };
+
+    let base = base
+        .and_then(|branch| {
+            git(&["merge-base", "HEAD", branch])
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .unwrap_or_else(|| "HEAD".to_string());
+    let output = git(&["diff", base.as_str()])?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git diff {} failed: {}",
+            base,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let mut diff = String::from_utf8_lossy(&output.stdout).into_owned();
+    let untracked = git(&["ls-files", "--others", "--exclude-standard"])?;
+    for file in String::from_utf8_lossy(&untracked.stdout).lines() {
+        // Exits with 1 when the files differ, which they always do here
+        let output = git(&["diff", "--no-index", "--", "/dev/null", file])?;
+        diff.push_str(&String::from_utf8_lossy(&output.stdout));
+    }
+
+    let path = planning_paths::session_implementation_diff_path(session_id, iteration)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &diff)
+        .with_context(|| format!("Failed to save implementation diff: {}", path.display()))?;
+    let mut parts = Vec::new();
+    let chunks = split_diff(&diff, MAX_DIFF_PART_BYTES);
+    if chunks.len() > 1 {
+        for (i, chunk) in chunks.iter().enumerate() {
+            let part = path.with_extension(format!("part{}.diff", i + 1));
+            fs::write(&part, chunk)
+                .with_context(|| format!("Failed to save diff part: {}", part.display()))?;
+            parts.push(part);
+        }
+    }
+    Ok(Some(ImplementationDiff {
+        path,
+        parts,
+        bytes: diff.len(),
+    }))
+}
+
+/// Splits a diff into parts of at most `max_bytes`, preferring file boundaries.
+/// A single line longer than `max_bytes` gets a part of its own.
+pub fn split_diff(diff: &str, max_bytes: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    for line in diff.split_inclusive('\n') {
+        let file_boundary = line.starts_with("diff --git ") && current.len() > max_bytes / 2;
+        if !current.is_empty() && (file_boundary || current.len() + line.len() > max_bytes) {
+            parts.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+    }
+    if !current.is_empty() || parts.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
 /// Result of running the implementation review phase.
 #[derive(Debug, Clone)]
 pub struct ImplementationReviewResult {
@@ -39,6 +138,7 @@ pub struct ImplementationReviewResult {
 /// * `working_dir` - The working directory to review
 /// * `iteration` - The current iteration number (1-indexed)
 /// * `implementation_log_path` - Path to the implementation log from the previous phase
+/// * `diff` - The round's diff, referenced from the prompt
 /// * `session_sender` - Channel to send session events
 /// * `session_logger` - Logger for the session
 ///
@@ -50,6 +150,7 @@ pub async fn run_implementation_review_phase(
     working_dir: &Path,
     iteration: u32,
     implementation_log_path: Option<&Path>,
+    diff: Option<&ImplementationDiff>,
     session_sender: SessionEventSender,
     session_logger: Arc<SessionLogger>,
 ) -> Result<ImplementationReviewResult> {
@@ -79,9 +180,19 @@ pub async fn run_implementation_review_phase(
     // Create agent
     let agent = AgentType::from_config(agent_name, agent_config, working_dir.to_path_buf())?;
 
-    // Build the prompt
-    let prompt =
-        build_implementation_review_prompt(view, working_dir, iteration, implementation_log_path)?;
+    // Build the prompt; remote agents cannot read local files, so they get the diff inline
+    let diff_section = match diff {
+        Some(diff) if crate::agents::remote::is_remote(agent_config) => inline_diff_section(diff),
+        Some(diff) => diff_files_section(diff),
+        None => String::new(),
+    };
+    let prompt = build_implementation_review_prompt(
+        view,
+        working_dir,
+        iteration,
+        implementation_log_path,
+        &diff_section,
+    )?;
 
     // Get report path
     let workflow_id = view
@@ -102,6 +213,11 @@ pub async fn run_implementation_review_phase(
         resume_strategy: ResumeStrategy::Stateless,
         cancel_rx: None,
         session_logger,
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
     let review_started_at = std::time::Instant::now();
@@ -264,12 +380,43 @@ pub async fn run_implementation_review_phase(
     }
 }
 
+/// Path lines pointing the reviewer at the diff file, or at its parts.
+fn diff_files_section(diff: &ImplementationDiff) -> String {
+    if diff.parts.is_empty() {
+        return format!("- Implementation diff: {}\n", diff.path.display());
+    }
+    let mut section = format!(
+        "- Implementation diff ({} bytes, split into {} parts; read them in order):\n",
+        diff.bytes,
+        diff.parts.len()
+    );
+    for part in &diff.parts {
+        section.push_str(&format!("  - {}\n", part.display()));
+    }
+    section
+}
+
+/// The diff pasted into the prompt, cut to its first part.
+fn inline_diff_section(diff: &ImplementationDiff) -> String {
+    let text = fs::read_to_string(diff.parts.first().unwrap_or(&diff.path)).unwrap_or_default();
+    let note = if diff.parts.len() > 1 {
+        format!(
+            "\n[diff truncated: {} more parts not shown]\n",
+            diff.parts.len() - 1
+        )
+    } else {
+        String::new()
+    };
+    format!("\nImplementation diff:\n```diff\n{}```{}\n", text, note)
+}
+
 /// Builds the implementation review prompt with clean format and skill invocation at the end.
 fn build_implementation_review_prompt(
     view: &WorkflowView,
     working_dir: &Path,
     iteration: u32,
     implementation_log_path: Option<&Path>,
+    diff_section: &str,
 ) -> Result<String> {
     // Get plan path from view
     let plan_path_ref = view
@@ -297,6 +444,12 @@ fn build_implementation_review_prompt(
         Some(log) => format!("- Implementation log: {}\n", log.display()),
         None => String::new(),
     };
+    let mut conditions_section = approval_conditions_section(view);
+    if !conditions_section.is_empty() {
+        conditions_section.push_str(
+            "Check each condition explicitly; any unmet condition means NEEDS REVISION.\n",
+        );
+    }
 
     Ok(format!(
         r#"Review the implementation against the approved plan.
@@ -307,7 +460,7 @@ Paths:
 - Workspace: {workspace}
 - Plan file: {plan}
 - Review output: {review_output}
-{log_section}
+{log_section}{diff_section}{conditions_section}
 IMPORTANT: If the verdict is NEEDS REVISION, you MUST include feedback in this exact format:
 
 <implementation-feedback>
@@ -324,6 +477,8 @@ Run the "implementation-review" skill to perform the review."#,
         plan = plan_path.display(),
         review_output = review_output.display(),
         log_section = log_section,
+        diff_section = diff_section,
+        conditions_section = conditions_section,
     ))
 }
 
@@ -1,18 +1,30 @@
+pub mod feedback_items;
 pub mod implementation;
 pub mod implementation_review;
 pub mod planning;
+pub mod repo_instructions;
 mod review_parser;
-mod review_prompts;
+pub mod review_prompts;
 pub mod review_schema;
 pub mod reviewing;
 pub mod revising;
 pub mod summary;
 pub mod verdict;
 
-pub use planning::run_planning_phase_with_context;
+use crate::domain::actor::WorkflowMessage;
+use crate::domain::view::WorkflowView;
+use ractor::ActorRef;
+use tokio::sync::oneshot;
+
+pub use planning::{
+    build_plan_structure_followup, missing_plan_sections, parse_plan_front_matter,
+    run_planning_phase_with_context,
+};
+pub use review_parser::{extract_flagged_sections, parse_review_feedback};
 pub use reviewing::{
-    aggregate_reviews, merge_feedback, run_multi_agent_review_with_context, write_feedback_files,
-    ReviewFailure, ReviewResult,
+    aggregate_reviews, feedback_path_for_agent, merge_feedback,
+    run_multi_agent_review_with_context, write_feedback_files, ReviewFailure, ReviewResult,
+    ReviewTargets,
 };
 pub use revising::run_revision_phase_with_context;
 pub use summary::spawn_summary_generation;
@@ -31,6 +43,27 @@ pub use implementation::{run_implementation_phase, ImplementationResult};
 #[allow(unused_imports)]
 pub use implementation_review::{run_implementation_review_phase, ImplementationReviewResult};
 
+/// Output of the user's slash commands waiting for the next planner or
+/// implementer prompt. The actor's current view is asked, since `view` was
+/// taken before output queued while the workflow ran.
+pub async fn pending_command_output(
+    view: &WorkflowView,
+    actor_ref: &Option<ActorRef<WorkflowMessage>>,
+) -> Vec<String> {
+    if let Some(actor) = actor_ref {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        if actor
+            .send_message(WorkflowMessage::GetView(reply_tx))
+            .is_ok()
+        {
+            if let Ok(current) = reply_rx.await {
+                return current.pending_command_output().to_vec();
+            }
+        }
+    }
+    view.pending_command_output().to_vec()
+}
+
 /// Constructs the conversation key for planning and revision phases.
 /// Both phases MUST use this function to ensure conversation continuity.
 pub fn planning_conversation_key(agent_name: &str) -> String {
@@ -1,17 +1,22 @@
+use crate::agents::prompt::with_command_output;
 use crate::agents::{AgentContext, AgentType};
+use crate::app::workflow::phase_timer::PhaseTimer;
 use crate::config::WorkflowConfig;
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::types::{
-    AgentId, ConversationId, PhaseLabel, ResumeStrategy as DomainResumeStrategy, ResumeStrategy,
+    AgentId, ConversationId, PhaseLabel, PlanMetadata, ResumeStrategy as DomainResumeStrategy,
+    ResumeStrategy,
 };
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
-use crate::phases::planning_conversation_key;
+use crate::phases::feedback_items::prompt_notes;
+use crate::phases::repo_instructions::RepoInstructions;
+use crate::phases::{pending_command_output, planning_conversation_key};
 use crate::planning_paths;
 use crate::prompt_format::PromptBuilder;
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::SessionEventSender;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use ractor::ActorRef;
 use std::path::Path;
 use std::sync::Arc;
@@ -21,6 +26,23 @@ use tokio::sync::oneshot;
 pub const PLANNING_SYSTEM_PROMPT: &str =
     r#"Use the "planning" skill to create the plan. Write your plan to the plan-output-path file."#;
 
+/// Front matter a structured plan starts with (`plan_structure.front_matter`).
+pub const PLAN_FRONT_MATTER_TEMPLATE: &str = "---
+title: <one-line summary of the plan>
+risk: <low | medium | high>
+effort: <estimated effort, e.g. 2 days>
+components:
+  - <affected component or module>
+---";
+
+/// Asks the planner to write the plan in order, so finished sections can be
+/// reviewed while it writes the rest (`reviewing.pipelined`).
+pub const SECTION_BY_SECTION_INSTRUCTIONS: &str = "Reviewers review each plan section as soon as \
+you finish it. Write the plan file one top-level section at a time, in order: create the file \
+with the title and the first section, then append each further section as you finish it. \
+Avoid rewriting sections you already wrote; a changed section is reviewed again.";
+
+/// Runs the planner with the planning prompt, or with `followup` in its place.
 pub async fn run_planning_phase_with_context(
     view: &WorkflowView,
     working_dir: &Path,
@@ -28,6 +50,7 @@ pub async fn run_planning_phase_with_context(
     session_sender: SessionEventSender,
     session_logger: Arc<SessionLogger>,
     actor_ref: Option<ActorRef<WorkflowMessage>>,
+    followup: Option<String>,
 ) -> Result<()> {
     let planning_config = &config.workflow.planning;
     let agent_name = &planning_config.agent;
@@ -41,7 +64,39 @@ pub async fn run_planning_phase_with_context(
 
     let agent = AgentType::from_config(agent_name, agent_config, working_dir.to_path_buf())?;
 
-    let prompt = build_planning_prompt(view, working_dir);
+    let front_matter = config
+        .plan_structure
+        .as_ref()
+        .is_some_and(|policy| policy.front_matter);
+    let prompt = match followup {
+        Some(followup) => followup,
+        None => {
+            let instructions = RepoInstructions::for_config(config, working_dir);
+            if let Some(instructions) = &instructions {
+                session_sender.send_output(format!(
+                    "[planning] Repository instructions: {}",
+                    instructions.summary()
+                ));
+            }
+            let prompt = build_planning_prompt(
+                view,
+                working_dir,
+                config.language.as_deref(),
+                front_matter,
+                prompt_notes(config, view).as_deref(),
+                instructions.map(|i| i.prompt_section()).as_deref(),
+            );
+            let reviewing = &config.workflow.reviewing;
+            if reviewing.pipelined && !reviewing.sequential {
+                format!("{}\n\n{}", prompt, SECTION_BY_SECTION_INSTRUCTIONS)
+            } else {
+                prompt
+            }
+        }
+    };
+    // Recorded as delivered once the planner has run with it
+    let command_output = pending_command_output(view, &actor_ref).await;
+    let prompt = with_command_output(prompt, &command_output);
 
     // Planning always uses ConversationResume to enable revision continuity.
     // The agent will capture its conversation ID on first run, then resume on revision.
@@ -79,16 +134,42 @@ pub async fn run_planning_phase_with_context(
         resume_strategy,
         cancel_rx: None,
         session_logger: session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
-    let result = agent
-        .execute_streaming_with_context(
+    let timer = PhaseTimer::start(
+        PhaseLabel::Planning,
+        config.max_phase_duration,
+        &session_sender,
+        session_logger.clone(),
+        actor_ref.clone(),
+    );
+    let result = timer
+        .execute(
+            &agent,
             prompt,
             Some(PLANNING_SYSTEM_PROMPT.to_string()),
             max_turns,
             context,
         )
         .await?;
+    drop(timer);
+
+    if !command_output.is_empty() {
+        dispatch_planning_command(
+            &actor_ref,
+            &session_logger,
+            DomainCommand::DeliverCommandOutput {
+                count: command_output.len(),
+                phase: PhaseLabel::Planning,
+            },
+        )
+        .await;
+    }
 
     // Store captured conversation ID for future resume (e.g., in revising phase)
     if let Some(ref captured_id) = result.conversation_id {
@@ -122,7 +203,14 @@ pub async fn run_planning_phase_with_context(
     Ok(())
 }
 
-fn build_planning_prompt(view: &WorkflowView, working_dir: &Path) -> String {
+fn build_planning_prompt(
+    view: &WorkflowView,
+    working_dir: &Path,
+    language: Option<&str>,
+    front_matter: bool,
+    notes: Option<&str>,
+    repo_instructions: Option<&str>,
+) -> String {
     // Get plan path from view (absolute path in ~/.planning-agent/sessions/)
     let plan_path = view
         .plan_path()
@@ -155,7 +243,8 @@ fn build_planning_prompt(view: &WorkflowView, working_dir: &Path) -> String {
         .input("feature-name", feature_name)
         .input("objective", objective)
         .input("plan-output-path", &plan_path)
-        .input("session-folder-path", &session_folder);
+        .input("session-folder-path", &session_folder)
+        .language(language);
 
     // Add worktree context if applicable
     if let Some(wt_state) = view.worktree_info() {
@@ -174,6 +263,37 @@ fn build_planning_prompt(view: &WorkflowView, working_dir: &Path) -> String {
         }
     }
 
+    // Point at the imported issue description and comments
+    if let Some(issue) = view.issue_link() {
+        builder = builder
+            .input(
+                "source-issue",
+                &format!("{} ({})", issue.key(), issue.url()),
+            )
+            .input(
+                "issue-context-path",
+                &planning_paths::session_issue_context_path(&workflow_id_str)
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_default(),
+            );
+    }
+
+    // Inline the failing CI log excerpts attached with --attach-ci-run
+    if let Some(ci_logs) = planning_paths::session_ci_logs_path(&workflow_id_str)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+    {
+        builder = builder.input("failing-ci-logs", &ci_logs);
+    }
+
+    // Spell out the boundaries of the package targeted with --package
+    if let Some(package) = planning_paths::session_package_path(&workflow_id_str)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+    {
+        builder = builder.input("target-package", &package);
+    }
+
     // Include accumulated user feedback as additional context
     let user_feedback = view.user_feedback_history();
     if !user_feedback.is_empty() {
@@ -186,6 +306,124 @@ fn build_planning_prompt(view: &WorkflowView, working_dir: &Path) -> String {
         builder = builder.input("user-feedback", &feedback_text);
     }
 
+    // CLAUDE.md, AGENTS.md and .cursorrules of the workspace
+    if let Some(repo_instructions) = repo_instructions {
+        builder = builder.input("repository-instructions", repo_instructions);
+    }
+
+    // The user's `/notes`, when the config passes them on
+    if let Some(notes) = notes {
+        builder = builder.input("user-notes", notes);
+    }
+
+    if front_matter {
+        builder = builder.input("plan-front-matter", PLAN_FRONT_MATTER_TEMPLATE);
+    }
+
+    builder.build()
+}
+
+/// Words of a heading or section name: lowercase, punctuation as spaces.
+fn heading_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Required sections with no markdown heading starting with their name.
+/// Leading numbering ("## 2. Risks") is ignored, so "Goals" is not satisfied
+/// by a "Non-goals" heading.
+pub fn missing_plan_sections(plan: &str, required: &[String]) -> Vec<String> {
+    let headings: Vec<Vec<String>> = plan
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix('#'))
+        .map(|heading| {
+            heading_words(heading)
+                .into_iter()
+                .skip_while(|word| word.chars().all(|c| c.is_ascii_digit()))
+                .collect()
+        })
+        .collect();
+    required
+        .iter()
+        .filter(|section| {
+            let wanted = heading_words(section);
+            !headings.iter().any(|heading| heading.starts_with(&wanted))
+        })
+        .cloned()
+        .collect()
+}
+
+/// The YAML between a plan's opening `---` line and the next `---` line,
+/// and the plan body after it.
+fn split_front_matter(plan: &str) -> Option<(&str, &str)> {
+    let plan = plan.trim_start_matches('\u{feff}');
+    let rest = plan
+        .strip_prefix("---\n")
+        .or_else(|| plan.strip_prefix("---\r\n"))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Parses the YAML front matter a structured plan starts with.
+pub fn parse_plan_front_matter(plan: &str) -> Result<PlanMetadata> {
+    let (yaml, _) = split_front_matter(plan)
+        .context("The plan does not start with a YAML front matter block between --- lines")?;
+    let mut metadata: PlanMetadata =
+        serde_yaml::from_str(yaml).context("Invalid plan front matter")?;
+    metadata.title = metadata.title.trim().to_string();
+    metadata.effort = metadata.effort.trim().to_string();
+    metadata.components.retain(|c| !c.trim().is_empty());
+    if metadata.title.is_empty() {
+        anyhow::bail!("Plan front matter has an empty title");
+    }
+    if metadata.effort.is_empty() {
+        anyhow::bail!("Plan front matter has an empty effort estimate");
+    }
+    Ok(metadata)
+}
+
+/// Prompt asking the planner to add `missing` sections to the plan it wrote,
+/// and to fix its front matter when `front_matter_problem` says what's wrong.
+pub fn build_plan_structure_followup(
+    view: &WorkflowView,
+    missing: &[String],
+    front_matter_problem: Option<&str>,
+) -> String {
+    let plan_path = view
+        .plan_path()
+        .map(|p| p.0.display().to_string())
+        .unwrap_or_default();
+    let mut builder = PromptBuilder::new()
+        .phase("planning")
+        .instructions(
+            "The plan at plan-output-path does not have the structure this project requires. \
+             Add each missing section as a markdown heading with real content for this plan, \
+             fix the front matter if there is a front-matter-problem, \
+             and keep the rest of the plan unchanged.",
+        )
+        .input("plan-output-path", &plan_path);
+    if !missing.is_empty() {
+        let sections = missing
+            .iter()
+            .map(|section| format!("## {}", section))
+            .collect::<Vec<_>>()
+            .join("\n");
+        builder = builder.input("missing-sections", &sections);
+    }
+    if let Some(problem) = front_matter_problem {
+        builder = builder
+            .input("front-matter-problem", problem)
+            .input("plan-front-matter", PLAN_FRONT_MATTER_TEMPLATE);
+    }
     builder.build()
 }
 
@@ -0,0 +1,105 @@
+//! Repository instruction files for agent prompts.
+//!
+//! Repositories often carry instructions for coding agents at the workspace
+//! root: `CLAUDE.md`, `AGENTS.md` or `.cursorrules`. Unless the workflow config
+//! turns `repo_instructions` off, the planning and review prompts include the
+//! ones found, inline while they fit in `MAX_INLINE_BYTES` and as a pointer to
+//! the file beyond that. The output panel lists the files honored when
+//! planning and each review round start.
+
+use crate::config::WorkflowConfig;
+use std::path::{Path, PathBuf};
+
+/// Instruction files looked for at the workspace root, in prompt order.
+pub const INSTRUCTION_FILES: [&str; 3] = ["CLAUDE.md", "AGENTS.md", ".cursorrules"];
+
+/// Instruction text inlined per prompt, across all files.
+const MAX_INLINE_BYTES: usize = 16 * 1024;
+
+/// An instruction file found in the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionFile {
+    pub name: &'static str,
+    pub path: PathBuf,
+    /// None when the file did not fit and the prompt only points at it
+    pub content: Option<String>,
+}
+
+/// The instruction files of a workspace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepoInstructions {
+    pub files: Vec<InstructionFile>,
+}
+
+impl RepoInstructions {
+    /// Finds the instruction files at `working_dir`, skipping blank ones.
+    pub fn detect(working_dir: &Path) -> Self {
+        let mut budget = MAX_INLINE_BYTES;
+        let files = INSTRUCTION_FILES
+            .into_iter()
+            .filter_map(|name| {
+                let path = working_dir.join(name);
+                let content = std::fs::read_to_string(&path).ok()?;
+                let content = content.trim();
+                if content.is_empty() {
+                    return None;
+                }
+                let content = (content.len() <= budget).then(|| {
+                    budget -= content.len();
+                    content.to_string()
+                });
+                Some(InstructionFile {
+                    name,
+                    path,
+                    content,
+                })
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// The workspace's instruction files, or None when the config turns them
+    /// off or there are none.
+    pub fn for_config(config: &WorkflowConfig, working_dir: &Path) -> Option<Self> {
+        if !config.repo_instructions {
+            return None;
+        }
+        let instructions = Self::detect(working_dir);
+        (!instructions.files.is_empty()).then_some(instructions)
+    }
+
+    /// The files for the output panel, e.g. `CLAUDE.md, AGENTS.md (by path)`.
+    pub fn summary(&self) -> String {
+        self.files
+            .iter()
+            .map(|file| match file.content {
+                Some(_) => file.name.to_string(),
+                None => format!("{} (by path)", file.name),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Prompt text carrying the instructions.
+    pub fn prompt_section(&self) -> String {
+        let mut section = String::from(
+            "The repository has instructions for agents working in it. Follow them where they apply to this task.",
+        );
+        for file in &self.files {
+            match &file.content {
+                Some(content) => {
+                    section.push_str(&format!("\n\n--- {} ---\n{}", file.path.display(), content))
+                }
+                None => section.push_str(&format!(
+                    "\n\n--- {} ---\n(Too long to include here; read the file.)",
+                    file.path.display()
+                )),
+            }
+        }
+        section
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/repo_instructions_tests.rs"]
+mod tests;
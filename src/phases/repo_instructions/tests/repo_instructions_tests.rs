@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn test_detect_inlines_files_in_order_and_skips_blank_ones() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("AGENTS.md"), "Run cargo fmt.\n").unwrap();
+    std::fs::write(dir.path().join("CLAUDE.md"), "Use anyhow for errors.").unwrap();
+    std::fs::write(dir.path().join(".cursorrules"), "  \n").unwrap();
+
+    let instructions = RepoInstructions::detect(dir.path());
+    assert_eq!(instructions.summary(), "CLAUDE.md, AGENTS.md");
+    let section = instructions.prompt_section();
+    let claude = section.find("Use anyhow for errors.").unwrap();
+    let agents = section.find("Run cargo fmt.").unwrap();
+    assert!(claude < agents);
+}
+
+#[test]
+fn test_files_over_the_budget_are_pointed_at() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("CLAUDE.md"), "x".repeat(MAX_INLINE_BYTES)).unwrap();
+    std::fs::write(dir.path().join(".cursorrules"), "Prefer small diffs.").unwrap();
+
+    let instructions = RepoInstructions::detect(dir.path());
+    assert_eq!(instructions.summary(), "CLAUDE.md, .cursorrules (by path)");
+    assert!(instructions.files[1].content.is_none());
+    assert!(instructions
+        .prompt_section()
+        .contains(&dir.path().join(".cursorrules").display().to_string()));
+}
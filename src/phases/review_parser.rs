@@ -4,7 +4,7 @@
 //! including verdict extraction, summary parsing, and critical issues identification.
 
 use crate::app::ParseFailureInfo;
-use crate::phases::review_schema::{ReviewVerdict, SubmittedReview};
+use crate::phases::review_schema::{ReviewIssue, ReviewVerdict, SubmittedReview};
 use regex::Regex;
 
 /// Extract content from <plan-feedback> tags if present
@@ -60,15 +60,22 @@ pub fn parse_review_feedback(
             summary: extract_summary_from_feedback(&feedback),
             critical_issues: vec![],
             recommendations: extract_recommendations_from_feedback(&feedback),
+            flagged_sections: vec![],
+            issues: vec![],
             full_feedback: Some(feedback),
         }),
-        VerdictParseResult::NeedsRevision => Ok(SubmittedReview {
-            verdict: ReviewVerdict::NeedsRevision,
-            summary: extract_summary_from_feedback(&feedback),
-            critical_issues: extract_critical_issues_from_feedback(&feedback),
-            recommendations: extract_recommendations_from_feedback(&feedback),
-            full_feedback: Some(feedback),
-        }),
+        VerdictParseResult::NeedsRevision => {
+            let critical_issues = extract_critical_issues_from_feedback(&feedback);
+            Ok(SubmittedReview {
+                verdict: ReviewVerdict::NeedsRevision,
+                summary: extract_summary_from_feedback(&feedback),
+                issues: extract_issue_assessments(&critical_issues),
+                critical_issues,
+                recommendations: extract_recommendations_from_feedback(&feedback),
+                flagged_sections: extract_flagged_sections(&feedback),
+                full_feedback: Some(feedback),
+            })
+        }
         VerdictParseResult::ParseFailure(error) => Err(ParseFailureInfo {
             error,
             plan_feedback_found,
@@ -129,6 +136,47 @@ fn extract_critical_issues_from_feedback(feedback: &str) -> Vec<String> {
     issues
 }
 
+/// Read `(confidence: 0.4)` and `[non-blocking]` markers off critical issue bullets.
+/// Returns no assessments when none of the issues carry a marker.
+fn extract_issue_assessments(critical_issues: &[String]) -> Vec<ReviewIssue> {
+    let confidence_re = Regex::new(r"(?i)\(?\s*confidence\s*[:=]\s*([0-9]*\.?[0-9]+)\s*(%)?\s*\)?")
+        .expect("regex to match a (confidence: N) issue marker");
+    let non_blocking_re = Regex::new(r"(?i)\[?\s*non[-\s]?blocking\s*\]?")
+        .expect("regex to match a [non-blocking] issue marker");
+
+    let mut marked = false;
+    let issues: Vec<ReviewIssue> = critical_issues
+        .iter()
+        .map(|issue| {
+            let confidence = confidence_re.captures(issue).and_then(|caps| {
+                let value: f32 = caps.get(1)?.as_str().parse().ok()?;
+                // Scores above 1 (or with a % sign) are percentages
+                let value = if caps.get(2).is_some() || value > 1.0 {
+                    value / 100.0
+                } else {
+                    value
+                };
+                Some(value.clamp(0.0, 1.0))
+            });
+            let blocking = !non_blocking_re.is_match(issue);
+            marked |= confidence.is_some() || !blocking;
+            let description =
+                non_blocking_re.replace_all(&confidence_re.replace_all(issue, ""), "");
+            ReviewIssue {
+                description: description.trim().to_string(),
+                blocking,
+                confidence,
+            }
+        })
+        .collect();
+
+    if marked {
+        issues
+    } else {
+        vec![]
+    }
+}
+
 /// Extract recommendations from feedback text
 fn extract_recommendations_from_feedback(feedback: &str) -> Vec<String> {
     let mut recs = vec![];
@@ -158,6 +206,39 @@ fn extract_recommendations_from_feedback(feedback: &str) -> Vec<String> {
     recs
 }
 
+/// Extract the plan headings listed under the feedback's `## Flagged Sections`
+pub fn extract_flagged_sections(feedback: &str) -> Vec<String> {
+    let mut sections = vec![];
+    let mut in_section = false;
+
+    for line in feedback.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            let heading = trimmed.trim_start_matches('#').trim().to_lowercase();
+            in_section = heading.trim_end_matches(':') == "flagged sections";
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if let Some(item) = ["-", "*", "•"]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            let section = item
+                .trim()
+                .trim_matches(|c| c == '`' || c == '"' || c == '*')
+                .trim_start_matches('#')
+                .trim();
+            if !section.is_empty() {
+                sections.push(section.to_string());
+            }
+        }
+    }
+
+    sections
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum VerdictParseResult {
     Approved,
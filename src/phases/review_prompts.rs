@@ -12,6 +12,21 @@ pub const REVIEW_SYSTEM_PROMPT: &str = "You are a technical plan reviewer.";
 /// Default skill to use when no skill is specified.
 pub const DEFAULT_REVIEW_SKILL: &str = "plan-review-adversarial";
 
+/// Prepended to prompts of agents with earlier plan versions in their conversation
+/// when the user edited the plan file directly.
+pub const PLAN_EDITED_BY_USER_NOTICE: &str = "NOTE: The user edited the plan file directly since your last look at it. Re-read the plan file from disk; do not rely on earlier versions from this conversation.";
+
+/// Appended to review prompts when targeted re-review is enabled, so rejections
+/// name the plan sections they concern.
+pub const SECTION_FLAGGING_INSTRUCTIONS: &str = "If your verdict is NEEDS REVISION, end the feedback file with a \"## Flagged Sections\" section listing, one bullet each, the exact plan headings your critical issues concern. After the revision you will only be asked to re-review those sections.";
+
+/// Appended to review prompts when low-confidence rejections are discounted, so
+/// critical issues carry confidence scores and a blocking classification.
+pub const CONFIDENCE_SCORING_INSTRUCTIONS: &str = "End each bullet under \"## Critical Issues\" with \"(confidence: N)\", where N from 0.0 to 1.0 is how sure you are the issue is real, and mark issues that should not force a revision on their own with \"[non-blocking]\".";
+
+/// Heads review prompts of sessions targeting one workspace package (`--package`).
+pub const TARGET_PACKAGE_REVIEW_INSTRUCTIONS: &str = "The plan targets one package of a workspace. Flag steps that change files outside it without listing them as cross-package changes.";
+
 /// Build the review prompt that instructs the agent to use a review skill.
 ///
 /// # Arguments
@@ -136,6 +151,129 @@ IMPORTANT: You MUST run the "{skill}" skill again to perform this review. Do not
     )
 }
 
+/// Build a re-review prompt limited to the flagged sections the revision changed.
+///
+/// Used instead of the follow-up prompt when targeted re-review is enabled and the
+/// reviewer's previous rejection flagged plan sections that the planner has since
+/// changed. The rest of the plan is not re-reviewed by this reviewer.
+///
+/// # Arguments
+///
+/// * `objective` - The plan goal/objective
+/// * `plan_path_abs` - Absolute path to the plan file
+/// * `feedback_path_abs` - Absolute path to write feedback
+/// * `working_dir` - The workspace directory
+/// * `session_folder_abs` - The session folder path
+/// * `sections` - Headings of the changed sections to re-review
+/// * `skill_name` - The skill to invoke (should match the original review)
+pub fn build_targeted_review_prompt_for_agent(
+    objective: &str,
+    plan_path_abs: &Path,
+    feedback_path_abs: &Path,
+    working_dir: &Path,
+    session_folder_abs: &Path,
+    sections: &[String],
+    skill_name: Option<&str>,
+) -> String {
+    let skill = skill_name.unwrap_or(DEFAULT_REVIEW_SKILL);
+    let section_list = sections
+        .iter()
+        .map(|s| format!("- {}", s))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"The planner revised the plan in response to your feedback.
+
+##################### SECTIONS TO RE-REVIEW #####################
+{section_list}
+#################################################################
+
+These sections you flagged were changed by the revision. Re-review ONLY these
+sections of the plan file (read the rest only for context). Judge whether your
+earlier concerns about them are resolved and whether the changes introduced new
+problems. Base your verdict on these sections alone.
+
+########################### PLAN GOAL ###########################
+{objective}
+#################################################################
+
+Paths:
+- Workspace: {workspace}
+- Plan file: {plan}
+- Feedback output: {feedback}
+- Session folder: {session}
+
+{flagging}
+
+IMPORTANT: You MUST run the "{skill}" skill again to perform this review. Do not skip invoking the skill."#,
+        section_list = section_list,
+        objective = objective,
+        workspace = working_dir.display(),
+        plan = plan_path_abs.display(),
+        feedback = feedback_path_abs.display(),
+        session = session_folder_abs.display(),
+        flagging = SECTION_FLAGGING_INSTRUCTIONS,
+        skill = skill,
+    )
+}
+
+/// Build a review prompt for one plan section finished while the planner is still
+/// writing the rest (`reviewing.pipelined`).
+///
+/// # Arguments
+///
+/// * `objective` - The plan goal/objective
+/// * `plan_path_abs` - Absolute path to the plan file
+/// * `feedback_path_abs` - Absolute path to write feedback
+/// * `working_dir` - The workspace directory
+/// * `session_folder_abs` - The session folder path
+/// * `section` - Heading of the finished section
+/// * `skill_name` - The skill to invoke
+pub fn build_section_review_prompt_for_agent(
+    objective: &str,
+    plan_path_abs: &Path,
+    feedback_path_abs: &Path,
+    working_dir: &Path,
+    session_folder_abs: &Path,
+    section: &str,
+    skill_name: Option<&str>,
+) -> String {
+    let skill = skill_name.unwrap_or(DEFAULT_REVIEW_SKILL);
+
+    format!(
+        r#"The planner is still writing the plan, one section at a time.
+
+####################### SECTION TO REVIEW #######################
+{section}
+#################################################################
+
+This section of the plan file is finished. Review ONLY this section (read the
+sections before it for context). Later sections may not be written yet: do not
+reject this section for content that belongs in another section. Base your
+verdict on this section alone.
+
+########################### PLAN GOAL ###########################
+{objective}
+#################################################################
+
+Paths:
+- Workspace: {workspace}
+- Plan file: {plan}
+- Feedback output: {feedback}
+- Session folder: {session}
+
+IMPORTANT: You MUST run the "{skill}" skill to perform this review. Do not skip invoking the skill."#,
+        section = section,
+        objective = objective,
+        workspace = working_dir.display(),
+        plan = plan_path_abs.display(),
+        feedback = feedback_path_abs.display(),
+        session = session_folder_abs.display(),
+        skill = skill,
+    )
+}
+
 /// Build a recovery prompt for when the initial review attempt fails to produce valid feedback.
 /// This is used when the skill ran but didn't produce a parseable feedback file.
 ///
@@ -3,8 +3,12 @@
 //! This module contains the core types for structured review feedback,
 //! used by the file-based review system.
 
+use crate::phases::review_parser::extract_flagged_sections;
 use serde::{Deserialize, Serialize};
 
+/// Heading of the feedback section listing the plan sections a rejection concerns.
+pub const FLAGGED_SECTIONS_HEADING: &str = "## Flagged Sections";
+
 /// Structured review feedback
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmittedReview {
@@ -18,6 +22,12 @@ pub struct SubmittedReview {
     /// List of recommendations (non-blocking)
     #[serde(default)]
     pub recommendations: Vec<String>,
+    /// Plan section headings the critical issues concern (used for targeted re-review)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flagged_sections: Vec<String>,
+    /// Per-issue confidence and blocking classification (optional, structured reviews only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<ReviewIssue>,
     /// Full markdown feedback (optional, for detailed review)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_feedback: Option<String>,
@@ -29,10 +39,30 @@ impl SubmittedReview {
         matches!(self.verdict, ReviewVerdict::NeedsRevision)
     }
 
+    /// Returns true if this is a rejection whose blocking issues are all scored below
+    /// `threshold` (a rejection listing only non-blocking issues also qualifies).
+    /// Rejections without issue assessments are never low-confidence.
+    pub fn is_low_confidence_rejection(&self, threshold: f32) -> bool {
+        self.needs_revision()
+            && !self.issues.is_empty()
+            && self
+                .issues
+                .iter()
+                .filter(|issue| issue.blocking)
+                .all(|issue| issue.confidence.is_some_and(|c| c < threshold))
+    }
+
     /// Returns the feedback content, preferring full_feedback if available
     pub fn feedback_content(&self) -> String {
         if let Some(ref full) = self.full_feedback {
-            full.clone()
+            if self.flagged_sections.is_empty() || !extract_flagged_sections(full).is_empty() {
+                return full.clone();
+            }
+            format!(
+                "{}\n\n{}",
+                full.trim_end(),
+                self.flagged_sections_markdown()
+            )
         } else {
             let mut content = format!("## Summary\n\n{}\n", self.summary);
 
@@ -43,6 +73,26 @@ impl SubmittedReview {
                 }
             }
 
+            if !self.issues.is_empty() {
+                content.push_str("\n## Issue Assessment\n\n");
+                for issue in &self.issues {
+                    let kind = if issue.blocking {
+                        "blocking"
+                    } else {
+                        "non-blocking"
+                    };
+                    match issue.confidence {
+                        Some(c) => content.push_str(&format!(
+                            "- [{}, confidence {:.0}%] {}\n",
+                            kind,
+                            c * 100.0,
+                            issue.description
+                        )),
+                        None => content.push_str(&format!("- [{}] {}\n", kind, issue.description)),
+                    }
+                }
+            }
+
             if !self.recommendations.is_empty() {
                 content.push_str("\n## Recommendations\n\n");
                 for rec in &self.recommendations {
@@ -50,6 +100,11 @@ impl SubmittedReview {
                 }
             }
 
+            if !self.flagged_sections.is_empty() {
+                content.push('\n');
+                content.push_str(&self.flagged_sections_markdown());
+            }
+
             content.push_str(&format!(
                 "\n## Overall Assessment: {}\n",
                 match self.verdict {
@@ -61,6 +116,30 @@ impl SubmittedReview {
             content
         }
     }
+
+    fn flagged_sections_markdown(&self) -> String {
+        let mut out = format!("{}\n\n", FLAGGED_SECTIONS_HEADING);
+        for section in &self.flagged_sections {
+            out.push_str(&format!("- {}\n", section));
+        }
+        out
+    }
+}
+
+/// A single review issue with the reviewer's confidence in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewIssue {
+    pub description: String,
+    /// Whether the issue alone justifies a revision (default: true)
+    #[serde(default = "default_blocking")]
+    pub blocking: bool,
+    /// Reviewer's confidence in the issue, from 0.0 to 1.0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+fn default_blocking() -> bool {
+    true
 }
 
 /// Review verdict enum
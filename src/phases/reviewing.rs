@@ -1,7 +1,10 @@
+use crate::agents::context_limits::ContextLimitsConfig;
+use crate::agents::pricing::PricingTable;
 use crate::agents::{AgentContext, AgentType};
+use crate::app::workflow::phase_timer::PhaseTimer;
 use crate::app::workflow_common::is_network_error;
 use crate::app::{create_review_bundle, AttemptTimestamp, BundleConfig};
-use crate::config::{AgentRef, AggregationMode, WorkflowConfig};
+use crate::config::{AgentRef, AggregationMode, ConfidencePolicy, WorkflowConfig};
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::failure::FailureKind;
 use crate::domain::types::{
@@ -10,18 +13,23 @@ use crate::domain::types::{
 };
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
+use crate::phases::repo_instructions::RepoInstructions;
 use crate::phases::review_parser::parse_review_feedback;
 use crate::phases::review_prompts::{
     build_review_follow_up_prompt_for_agent, build_review_prompt_for_agent,
-    build_review_recovery_prompt_for_agent, DEFAULT_REVIEW_SKILL, REVIEW_SYSTEM_PROMPT,
+    build_review_recovery_prompt_for_agent, build_targeted_review_prompt_for_agent,
+    CONFIDENCE_SCORING_INSTRUCTIONS, DEFAULT_REVIEW_SKILL, PLAN_EDITED_BY_USER_NOTICE,
+    REVIEW_SYSTEM_PROMPT, SECTION_FLAGGING_INSTRUCTIONS, TARGET_PACKAGE_REVIEW_INSTRUCTIONS,
 };
 use crate::phases::review_schema::SubmittedReview;
 use crate::phases::reviewing_conversation_key;
 use crate::planning_paths;
+use crate::prompt_format::language_instruction;
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::{ReviewKind, SessionEventSender};
 use anyhow::Result;
 use ractor::ActorRef;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -38,6 +46,8 @@ pub struct ReviewResult {
     pub feedback: String,
     /// Short summary of the review (from structured review or extracted from feedback)
     pub summary: String,
+    /// Rejection whose blocking issues all scored below the confidence threshold
+    pub low_confidence: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +66,15 @@ pub struct ReviewBatchResult {
     pub failures: Vec<ReviewFailure>,
 }
 
+/// What a review round re-reviews after a revision.
+#[derive(Debug, Clone, Default)]
+pub struct ReviewTargets {
+    /// Flagged sections each reviewer re-reviews, by reviewer display ID
+    pub sections: HashMap<String, Vec<String>>,
+    /// What the revision changed, for rounds after a rejection
+    pub change_brief: Option<String>,
+}
+
 /// Result from executing a single review attempt
 struct ReviewAttemptResult {
     output: String,
@@ -108,6 +127,7 @@ pub async fn run_multi_agent_review_with_context(
     session_logger: Arc<SessionLogger>,
     emit_round_started: bool,
     actor_ref: Option<ActorRef<WorkflowMessage>>,
+    review_targets: &ReviewTargets,
 ) -> Result<ReviewBatchResult> {
     if agent_refs.is_empty() {
         anyhow::bail!("No reviewers configured");
@@ -246,17 +266,52 @@ pub async fn run_multi_agent_review_with_context(
         .workflow_id()
         .map(|id| id.0.to_string())
         .unwrap_or_default();
+    // Resumed reviewers may remember a plan version the user has since edited
+    let plan_edited_by_user = view.plan_externally_modified();
+    let flag_sections = config.workflow.reviewing.targeted_rereview;
+    let confidence = config.workflow.reviewing.confidence;
+    let language = config.language.clone();
+    // Boundaries of the package targeted with --package
+    let package_context = planning_paths::session_package_path(&session_id)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok());
+    let repo_instructions = RepoInstructions::for_config(config, working_dir);
+    if let Some(instructions) = &repo_instructions {
+        session_sender.send_output(format!(
+            "[review] Repository instructions: {}",
+            instructions.summary()
+        ));
+    }
+    let repo_instructions = repo_instructions.map(|instructions| instructions.prompt_section());
+    let pricing = config.pricing_table();
+    let provider_concurrency = config.provider_concurrency.clone();
+    let cost_alerts = config.cost_alerts.clone();
+    let context_limits = config.context_limits.clone();
+    let timer = PhaseTimer::start(
+        PhaseLabel::Reviewing,
+        config.max_phase_duration,
+        &session_sender,
+        session_logger.clone(),
+        actor_ref.clone(),
+    );
 
     let futures: Vec<_> = agents
         .into_iter()
         .map(|(display_id, agent, conversation_id, resume_strategy, custom_prompt, skill_name, is_follow_up)| {
+            let timer = &timer;
             let sender = session_sender.clone();
             let phase = format!("Reviewing #{}", iteration);
             let logger = session_logger.clone();
+            let pricing = pricing.clone();
             let working_dir = working_dir.to_path_buf();
             let plan_path_abs = plan_path_abs.clone();
             let objective = objective.clone();
             let session_id = session_id.clone();
+            let targets = review_targets.sections.get(&display_id).cloned();
+            let change_brief = review_targets.change_brief.clone();
+            let package_context = package_context.clone();
+            let repo_instructions = repo_instructions.clone();
+            let language = language.clone();
             let iter = iteration;
 
             // System prompt is minimal - skill handles details
@@ -269,7 +324,9 @@ pub async fn run_multi_agent_review_with_context(
                 // Signal reviewer started
                 sender.send_reviewer_started(ReviewKind::Plan, iter, display_id.clone());
 
-                if is_follow_up {
+                if let Some(ref targets) = targets {
+                    sender.send_output(format!("[review:{}] Re-reviewing {} changed flagged section(s): {}", display_id, targets.len(), targets.join(", ")));
+                } else if is_follow_up {
                     sender.send_output(format!("[review:{}] Resuming review conversation (follow-up after revision)...", display_id));
                 } else {
                     sender.send_output(format!("[review:{}] Starting file-based review...", display_id));
@@ -310,16 +367,31 @@ pub async fn run_multi_agent_review_with_context(
                 };
 
                 // Build the appropriate review prompt based on whether this is a follow-up
-                let review_prompt = if is_follow_up {
-                    build_review_follow_up_prompt_for_agent(
+                let review_prompt = if let Some(ref targets) = targets {
+                    build_targeted_review_prompt_for_agent(
                         &objective,
                         &plan_path_abs,
                         &feedback_path,
                         &working_dir,
                         &session_folder,
-                        custom_prompt.as_deref(),
+                        targets,
                         Some(&skill_name),
                     )
+                } else if is_follow_up {
+                    let prompt = build_review_follow_up_prompt_for_agent(
+                        &objective,
+                        &plan_path_abs,
+                        &feedback_path,
+                        &working_dir,
+                        &session_folder,
+                        custom_prompt.as_deref(),
+                        Some(&skill_name),
+                    );
+                    if plan_edited_by_user {
+                        format!("{}\n\n{}", PLAN_EDITED_BY_USER_NOTICE, prompt)
+                    } else {
+                        prompt
+                    }
                 } else {
                     build_review_prompt_for_agent(
                         &objective,
@@ -331,6 +403,40 @@ pub async fn run_multi_agent_review_with_context(
                         Some(&skill_name),
                     )
                 };
+                // Resumed reviewers got the instructions with their first prompt
+                let review_prompt = match repo_instructions {
+                    Some(ref instructions) if !is_follow_up && targets.is_none() => format!(
+                        "####################### REPOSITORY INSTRUCTIONS #######################\n{}\n##################################################################\n\n{}",
+                        instructions, review_prompt
+                    ),
+                    _ => review_prompt,
+                };
+                let review_prompt = match change_brief {
+                    Some(ref brief) => format!("{}\n\n{}", brief, review_prompt),
+                    None => review_prompt,
+                };
+                let review_prompt = match package_context {
+                    Some(ref package) => format!(
+                        "{}\n\n########################## TARGET PACKAGE ##########################\n{}\n##################################################################\n\n{}",
+                        TARGET_PACKAGE_REVIEW_INSTRUCTIONS, package, review_prompt
+                    ),
+                    None => review_prompt,
+                };
+                // Targeted prompts already ask for flagged sections
+                let review_prompt = if flag_sections && targets.is_none() {
+                    format!("{}\n\n{}", SECTION_FLAGGING_INSTRUCTIONS, review_prompt)
+                } else {
+                    review_prompt
+                };
+                let review_prompt = if confidence.discounts_low_confidence() {
+                    format!("{}\n\n{}", CONFIDENCE_SCORING_INSTRUCTIONS, review_prompt)
+                } else {
+                    review_prompt
+                };
+                let review_prompt = match language {
+                    Some(ref language) => format!("{}\n\n{}", language_instruction(language), review_prompt),
+                    None => review_prompt,
+                };
 
                 sender.send_output(format!(
                     "[review:{}] Plan: {}, Feedback: {}",
@@ -349,6 +455,11 @@ pub async fn run_multi_agent_review_with_context(
                     &phase,
                     &system_prompt,
                     logger.clone(),
+                    &pricing,
+                    &provider_concurrency,
+                    &cost_alerts,
+                    &context_limits,
+                    timer,
                 )
                 .await;
 
@@ -411,6 +522,11 @@ pub async fn run_multi_agent_review_with_context(
                             &format!("{} (recovery)", phase),
                             &system_prompt,
                             logger.clone(),
+                            &pricing,
+                            &provider_concurrency,
+                            &cost_alerts,
+                            &context_limits,
+                            timer,
                         )
                         .await;
 
@@ -484,6 +600,7 @@ pub async fn run_multi_agent_review_with_context(
         .collect();
 
     let results = futures::future::join_all(futures).await;
+    drop(timer);
 
     // Get run_id for bundle creation
     let run_id = crate::app::util::get_run_id();
@@ -519,6 +636,7 @@ pub async fn run_multi_agent_review_with_context(
         match result {
             ReviewExecutionResult::Success(review) => {
                 let needs_revision = review.needs_revision();
+                let low_confidence = review.is_low_confidence_rejection(confidence.threshold);
                 let feedback = review.feedback_content();
 
                 let verdict_str = if needs_revision {
@@ -529,6 +647,12 @@ pub async fn run_multi_agent_review_with_context(
 
                 session_sender
                     .send_output(format!("[review:{}] Verdict: {}", agent_name, verdict_str));
+                if low_confidence {
+                    session_sender.send_output(format!(
+                        "[review:{}] Rejection is low-confidence (all blocking issues below {:.2})",
+                        agent_name, confidence.threshold
+                    ));
+                }
 
                 // Use summary, with fallback to default if empty
                 let summary = if review.summary.trim().is_empty() {
@@ -552,6 +676,7 @@ pub async fn run_multi_agent_review_with_context(
                     needs_revision,
                     feedback,
                     summary,
+                    low_confidence,
                 });
             }
             ReviewExecutionResult::ParseFailure {
@@ -686,6 +811,11 @@ async fn execute_review_attempt(
     phase: &str,
     system_prompt: &str,
     session_logger: Arc<SessionLogger>,
+    pricing: &PricingTable,
+    provider_concurrency: &BTreeMap<String, usize>,
+    cost_alerts: &BTreeMap<String, f64>,
+    context_limits: &ContextLimitsConfig,
+    timer: &PhaseTimer,
 ) -> Result<ReviewAttemptResult> {
     let started_at = chrono::Utc::now().to_rfc3339();
 
@@ -696,10 +826,16 @@ async fn execute_review_attempt(
         resume_strategy: *resume_strategy,
         cancel_rx: None,
         session_logger,
+        pricing: pricing.clone(),
+        provider_concurrency: provider_concurrency.clone(),
+        cost_alerts: cost_alerts.clone(),
+        context_limits: context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
-    let result = agent
-        .execute_streaming_with_context(
+    let result = timer
+        .execute(
+            agent,
             prompt.to_string(),
             Some(system_prompt.to_string()),
             None,
@@ -760,12 +896,29 @@ fn classify_execution_error(error: &str) -> FailureKind {
     FailureKind::Unknown(error.chars().take(500).collect())
 }
 
-pub fn aggregate_reviews(reviews: &[ReviewResult], mode: &AggregationMode) -> FeedbackStatus {
+pub fn aggregate_reviews(
+    reviews: &[ReviewResult],
+    mode: &AggregationMode,
+    confidence: &ConfidencePolicy,
+) -> FeedbackStatus {
     if reviews.is_empty() {
         return FeedbackStatus::NeedsRevision;
     }
 
-    let rejections = reviews.iter().filter(|r| r.needs_revision).count();
+    // Low-confidence rejections only count once enough reviewers cast one
+    let low_confidence = reviews
+        .iter()
+        .filter(|r| r.needs_revision && r.low_confidence)
+        .count();
+    let confident = reviews
+        .iter()
+        .filter(|r| r.needs_revision && !r.low_confidence)
+        .count();
+    let rejections = if low_confidence >= confidence.low_confidence_rejections_required {
+        confident + low_confidence
+    } else {
+        confident
+    };
     let total = reviews.len();
 
     match mode {
@@ -793,7 +946,7 @@ pub fn aggregate_reviews(reviews: &[ReviewResult], mode: &AggregationMode) -> Fe
     }
 }
 
-fn feedback_path_for_agent(
+pub fn feedback_path_for_agent(
     base_feedback_path: &Path,
     agent_name: &str,
     total_reviewers: usize,
@@ -1,13 +1,20 @@
+use crate::agents::prompt::with_command_output;
 use crate::agents::{AgentContext, AgentType};
+use crate::app::workflow::phase_timer::PhaseTimer;
 use crate::config::WorkflowConfig;
 use crate::domain::actor::WorkflowMessage;
 use crate::domain::types::{AgentId, ConversationId, PhaseLabel, ResumeStrategy};
 use crate::domain::view::WorkflowView;
 use crate::domain::WorkflowCommand as DomainCommand;
-use crate::phases::planning_conversation_key;
+use crate::phases::feedback_items::{
+    format_accepted_feedback, format_review_annotations, load_review_annotations, prompt_notes,
+    BACKLOG_HEADING, SESSION_NOTES_HEADING, USER_COMMENTS_HEADING,
+};
+use crate::phases::review_prompts::PLAN_EDITED_BY_USER_NOTICE;
 use crate::phases::ReviewResult;
+use crate::phases::{pending_command_output, planning_conversation_key};
 use crate::planning_paths;
-use crate::prompt_format::PromptBuilder;
+use crate::prompt_format::{language_instruction, PromptBuilder};
 use crate::session_daemon::{LogCategory, LogLevel, SessionLogger};
 use crate::tui::SessionEventSender;
 use anyhow::Result;
@@ -40,6 +47,18 @@ pub async fn run_revision_phase_with_context(
     let agent_name = &planning_config.agent;
     let max_turns = planning_config.max_turns;
 
+    // Triage left nothing to address; parked items are written to the backlog separately
+    if view
+        .accepted_feedback()
+        .is_some_and(|items| items.is_empty())
+    {
+        session_sender.send_output(
+            "[revision] No feedback items accepted in triage - plan content left unchanged"
+                .to_string(),
+        );
+        return Ok(());
+    }
+
     let agent_config = config
         .get_agent(agent_name)
         .ok_or_else(|| anyhow::anyhow!("Planning agent '{}' not found in config", agent_name))?;
@@ -74,7 +93,12 @@ pub async fn run_revision_phase_with_context(
         &session_folder,
         session_resume_active,
         iteration,
+        config.language.as_deref(),
+        prompt_notes(config, view).as_deref(),
     );
+    // Recorded as delivered once the planner has run with it
+    let command_output = pending_command_output(view, &actor_ref).await;
+    let prompt = with_command_output(prompt, &command_output);
 
     let phase_name = format!("Revising #{}", iteration);
     // Revision always uses ConversationResume to continue the planning conversation.
@@ -128,16 +152,42 @@ pub async fn run_revision_phase_with_context(
         resume_strategy,
         cancel_rx: None,
         session_logger: session_logger.clone(),
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
-    let result = agent
-        .execute_streaming_with_context(
+    let timer = PhaseTimer::start(
+        PhaseLabel::Revising,
+        config.max_phase_duration,
+        &session_sender,
+        session_logger.clone(),
+        actor_ref.clone(),
+    );
+    let result = timer
+        .execute(
+            &agent,
             prompt,
             Some(REVISION_SYSTEM_PROMPT.to_string()),
             max_turns,
             context,
         )
         .await?;
+    drop(timer);
+
+    if !command_output.is_empty() {
+        dispatch_revising_command(
+            &actor_ref,
+            &session_logger,
+            DomainCommand::DeliverCommandOutput {
+                count: command_output.len(),
+                phase: PhaseLabel::Revising,
+            },
+        )
+        .await;
+    }
 
     session_sender.send_output(format!("[revision:{}] Revision phase complete", agent_name));
     session_sender.send_output(format!(
@@ -156,6 +206,8 @@ fn build_revision_prompt_with_reviews(
     session_folder: &Path,
     session_resume_active: bool,
     iteration: u32,
+    language: Option<&str>,
+    notes: Option<&str>,
 ) -> String {
     let plan_path = view
         .plan_path()
@@ -213,6 +265,61 @@ fn build_revision_prompt_with_reviews(
         }
     }
 
+    // After triage, only the accepted items are handed to the agent
+    let (feedback_heading, feedback_body, closing) = match view.accepted_feedback() {
+        Some(accepted) => (
+            "Accepted Feedback",
+            format!(
+                "The user triaged the review feedback. Address ONLY these items; other \
+                 feedback was parked or dismissed and must not be acted on. Leave the \
+                 \"{}\" section of the plan unchanged.\n\n{}",
+                BACKLOG_HEADING.trim_start_matches("## "),
+                format_accepted_feedback(&accepted)
+            ),
+            "Please address the accepted feedback items.",
+        ),
+        None => (
+            "Feedback Files",
+            feedback_files,
+            "Please address all issues raised by reviewers who requested revision.",
+        ),
+    };
+
+    // Notes the user attached to lines of this round's reviews in the review modal
+    let review_files: Vec<String> = reviews
+        .iter()
+        .map(|r| format!("feedback_{}_{}.md", iteration, r.agent_name))
+        .collect();
+    let feedback_body =
+        match format_review_annotations(&load_review_annotations(session_folder), &review_files) {
+            Some(comments) => format!(
+                "{}\n\n# {}\n\n{}",
+                feedback_body, USER_COMMENTS_HEADING, comments
+            ),
+            None => feedback_body,
+        };
+    let feedback_body = match notes {
+        Some(notes) => format!(
+            "{}\n\n# {}\n\n{}",
+            feedback_body, SESSION_NOTES_HEADING, notes
+        ),
+        None => feedback_body,
+    };
+
+    // The resumed planning session remembers its own last version of the plan
+    let closing = if session_resume_active && view.plan_externally_modified() {
+        format!("{}\n\n{}", PLAN_EDITED_BY_USER_NOTICE, closing)
+    } else {
+        closing.to_string()
+    };
+    // The resumed conversation may predate a change of `language`
+    let closing = match language {
+        Some(language) if session_resume_active => {
+            format!("{}\n\n{}", closing, language_instruction(language))
+        }
+        _ => closing,
+    };
+
     if session_resume_active {
         // Continuation prompt - leverages existing session context
         // The agent already knows the workspace, plan file, and original context
@@ -223,12 +330,14 @@ fn build_revision_prompt_with_reviews(
              (e.g., \"in two weeks\", \"Sprint 1\", \"Q1 delivery\").\n\n\
              You may create supplementary files in the session folder: {}\n\n\
              # Review Summary\n\n{}\n\n\
-             # Feedback Files\n\n{}\n\n\
-             Please address all issues raised by reviewers who requested revision.",
+             # {}\n\n{}\n\n\
+             {}",
             plan_path,
             session_folder.display(),
             summary_table,
-            feedback_files
+            feedback_heading,
+            feedback_body,
+            closing
         )
     } else {
         // Full context prompt - for fresh sessions (Codex, Gemini, or session persistence disabled)
@@ -242,17 +351,26 @@ IMPORTANT: Update the plan file at: {}"#,
         );
 
         let context = format!(
-            "# Review Summary\n\n{}\n\n# Feedback Files\n\n{}\n\n\
-             Please address all issues raised by reviewers who requested revision.",
-            summary_table, feedback_files
+            "# Review Summary\n\n{}\n\n# {}\n\n{}\n\n{}",
+            summary_table, feedback_heading, feedback_body, closing
         );
 
-        PromptBuilder::new()
+        let mut builder = PromptBuilder::new()
             .phase("revising")
             .instructions(&instructions)
             .input("workspace-root", &working_dir.display().to_string())
             .input("plan-output-path", &plan_path)
             .input("session-folder-path", &session_folder.display().to_string())
+            .language(language);
+        // A fresh planner session also needs the --package boundaries
+        if let Some(package) = view
+            .workflow_id()
+            .and_then(|id| planning_paths::session_package_path(&id.0.to_string()).ok())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+        {
+            builder = builder.input("target-package", &package);
+        }
+        builder
             .context(&context)
             .constraint("Use absolute paths for all file references in the revised plan")
             .build()
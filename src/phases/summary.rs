@@ -1,4 +1,4 @@
-use crate::agents::{AgentContext, AgentType};
+use crate::agents::{AgentContext, AgentResult, AgentType};
 use crate::config::WorkflowConfig;
 use crate::domain::types::ResumeStrategy;
 use crate::domain::view::WorkflowView;
@@ -9,6 +9,7 @@ use crate::tui::SessionEventSender;
 use anyhow::Result;
 use std::path::Path;
 use std::sync::Arc;
+use tokio::sync::watch;
 
 const SUMMARY_SYSTEM_PROMPT: &str = r#"You are a concise technical summarizer.
 Your task is to provide a brief, focused summary of the content provided.
@@ -35,18 +36,20 @@ pub fn spawn_summary_generation(
 
     let summary_input = if phase.starts_with("Reviewing") {
         if let Some(reviews) = reviews {
-            build_review_summary_input(reviews)
+            build_review_summary_input(reviews, config.language.as_deref())
         } else {
             "No review data available.".to_string()
         }
     } else {
         match std::fs::read_to_string(&plan_path) {
-            Ok(content) => build_plan_summary_input(&content, &phase),
+            Ok(content) => build_plan_summary_input(&content, &phase, config.language.as_deref()),
             Err(e) => format!("Failed to read plan file: {}", e),
         }
     };
 
-    sender.send_run_tab_summary_generating(phase.clone());
+    // The summary streams into the Summary panel; the user can cancel it from there
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    sender.send_run_tab_summary_generating(phase.clone(), cancel_tx);
 
     tokio::spawn(async move {
         match run_summary_generation(
@@ -54,13 +57,16 @@ pub fn spawn_summary_generation(
             &summary_input,
             &working_dir,
             &config,
-            sender.clone(),
+            sender.for_summary(phase_clone.clone()),
+            cancel_rx,
             session_logger,
         )
         .await
         {
-            Ok(summary) => {
-                sender.send_run_tab_summary_ready(phase_clone, summary);
+            // A cancelled summary keeps the text the TUI already has
+            Ok(result) if result.stop_reason.as_deref() == Some("cancelled") => {}
+            Ok(result) => {
+                sender.send_run_tab_summary_ready(phase_clone, result.output);
             }
             Err(e) => {
                 sender.send_run_tab_summary_error(phase_clone, e.to_string());
@@ -69,7 +75,7 @@ pub fn spawn_summary_generation(
     });
 }
 
-fn build_plan_summary_input(plan_content: &str, phase: &str) -> String {
+fn build_plan_summary_input(plan_content: &str, phase: &str, language: Option<&str>) -> String {
     let max_len = 8000;
     let content = if plan_content.len() > max_len {
         // Find valid UTF-8 boundary at or before max_len
@@ -96,10 +102,11 @@ fn build_plan_summary_input(plan_content: &str, phase: &str) -> String {
             phase
         ))
         .context(&format!("# Plan Content\n\n{}", content))
+        .language(language)
         .build()
 }
 
-fn build_review_summary_input(reviews: &[ReviewResult]) -> String {
+fn build_review_summary_input(reviews: &[ReviewResult], language: Option<&str>) -> String {
     let mut review_content = String::new();
     for review in reviews {
         review_content.push_str(&format!("\n## Reviewer: {}\n", review.agent_name));
@@ -121,6 +128,7 @@ fn build_review_summary_input(reviews: &[ReviewResult]) -> String {
 - Main recommendations"#,
         )
         .context(&format!("# Review Results\n{}", review_content))
+        .language(language)
         .build()
 }
 
@@ -130,8 +138,9 @@ async fn run_summary_generation(
     working_dir: &Path,
     config: &WorkflowConfig,
     sender: SessionEventSender,
+    cancel_rx: watch::Receiver<bool>,
     session_logger: Arc<SessionLogger>,
-) -> Result<String> {
+) -> Result<AgentResult> {
     let agent_name = &config.workflow.planning.agent;
 
     let agent_config = config
@@ -147,20 +156,23 @@ async fn run_summary_generation(
         phase: phase.to_string(),
         conversation_id: None,
         resume_strategy: ResumeStrategy::Stateless,
-        cancel_rx: None,
+        cancel_rx: Some(cancel_rx),
         session_logger,
+        pricing: config.pricing_table(),
+        provider_concurrency: config.provider_concurrency.clone(),
+        cost_alerts: config.cost_alerts.clone(),
+        context_limits: config.context_limits.clone(),
+        fault_injector: crate::agents::faults::cli_fault_injector(),
     };
 
-    let result = agent
+    agent
         .execute_streaming_with_context(
             input.to_string(),
             Some(SUMMARY_SYSTEM_PROMPT.to_string()),
             max_turns,
             context,
         )
-        .await?;
-
-    Ok(result.output)
+        .await
 }
 
 #[cfg(test)]
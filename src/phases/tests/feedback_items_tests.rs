@@ -0,0 +1,107 @@
+use super::*;
+
+fn review(agent: &str, needs_revision: bool, feedback: &str) -> ReviewResult {
+    ReviewResult {
+        agent_name: agent.to_string(),
+        needs_revision,
+        feedback: feedback.to_string(),
+        summary: format!("{} summary", agent),
+        low_confidence: false,
+    }
+}
+
+#[test]
+fn test_parse_items_by_section_skipping_summary() {
+    let feedback = "## Summary\n\n- The plan is mostly fine\n\n\
+                    ## Critical Issues\n\n\
+                    - Missing migration for the users table\n  - affects rollback too\n\
+                    1. No error handling in the importer\n\n\
+                    ## Recommendations\n\n\
+                    * Add a metrics dashboard\n\n\
+                    ## Overall Assessment: NEEDS REVISION\n";
+    let items = parse_feedback_items(&[review("claude", true, feedback)]);
+
+    let texts: Vec<(&str, &str)> = items
+        .iter()
+        .map(|i| (i.section.as_deref().unwrap_or(""), i.text.as_str()))
+        .collect();
+    assert_eq!(
+        texts,
+        vec![
+            (
+                "Critical Issues",
+                "Missing migration for the users table\n- affects rollback too"
+            ),
+            ("Critical Issues", "No error handling in the importer"),
+            ("Recommendations", "Add a metrics dashboard"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_items_only_from_rejecting_reviews() {
+    let items = parse_feedback_items(&[
+        review("codex", false, "- Looks good"),
+        review("gemini", true, "The plan ignores the cache entirely."),
+    ]);
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].reviewer, "gemini");
+    // No list items: the review summary becomes the single item
+    assert_eq!(items[0].text, "gemini summary");
+}
+
+#[test]
+fn test_backlog_section_is_replaced_not_duplicated() {
+    let item = |text: &str| FeedbackItem {
+        reviewer: "claude".to_string(),
+        section: Some("Recommendations".to_string()),
+        text: text.to_string(),
+    };
+    let plan = "# Plan\n\n## Steps\n\n1. Do it\n";
+
+    let once = with_backlog_section(plan, &[item("Add metrics")]);
+    assert!(once.contains(BACKLOG_HEADING));
+    assert!(once.contains("- **claude (Recommendations)**: Add metrics"));
+
+    let twice = with_backlog_section(&once, &[item("Add metrics"), item("Add docs")]);
+    assert_eq!(twice.matches(BACKLOG_HEADING).count(), 1);
+    assert!(twice.contains("Add docs"));
+    assert!(twice.starts_with("# Plan\n\n## Steps\n\n1. Do it\n\n"));
+
+    assert_eq!(with_backlog_section(&twice, &[]), plan);
+}
+
+#[test]
+fn test_review_annotations_round_trip_and_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(load_review_annotations(dir.path()).is_empty());
+
+    let annotations = vec![ReviewAnnotation {
+        review_file: "feedback_1_codex.md".to_string(),
+        line: 4,
+        feedback: "- Add a metrics dashboard".to_string(),
+        stance: AnnotationStance::Context,
+        note: "We already export Prometheus metrics".to_string(),
+    }];
+    save_review_annotations(dir.path(), &annotations).unwrap();
+    assert_eq!(load_review_annotations(dir.path()), annotations);
+    assert!(
+        format_review_annotations(&annotations, &["feedback_2_codex.md".to_string()]).is_none()
+    );
+}
+
+#[test]
+fn test_session_notes_round_trip_and_blank_notes_remove_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(load_session_notes(dir.path()), None);
+
+    save_session_notes(dir.path(), "- ask about the v1 API\n\n").unwrap();
+    assert_eq!(
+        load_session_notes(dir.path()).as_deref(),
+        Some("- ask about the v1 API")
+    );
+
+    save_session_notes(dir.path(), "  \n").unwrap();
+    assert!(!dir.path().join(SESSION_NOTES_FILE).exists());
+    save_session_notes(dir.path(), "").unwrap();
+}
@@ -51,7 +51,7 @@ fn minimal_view() -> WorkflowView {
 fn test_build_implementation_review_prompt_basic() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_implementation_review_prompt(&view, &working_dir, 1, None)
+    let prompt = build_implementation_review_prompt(&view, &working_dir, 1, None, "")
         .expect("build_implementation_review_prompt failed");
 
     // Check paths are included
@@ -70,7 +70,7 @@ fn test_build_implementation_review_prompt_with_log_path() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
     let log_path = PathBuf::from("/tmp/session/implementation_1.log");
-    let prompt = build_implementation_review_prompt(&view, &working_dir, 1, Some(&log_path))
+    let prompt = build_implementation_review_prompt(&view, &working_dir, 1, Some(&log_path), "")
         .expect("build_implementation_review_prompt failed");
 
     // Should include the implementation log path
@@ -91,3 +91,47 @@ fn test_implementation_review_result_with_needs_revision_verdict() {
     assert!(result.verdict.needs_revision());
     assert_eq!(result.feedback, Some("Fix this".to_string()));
 }
+
+#[test]
+fn test_build_implementation_review_prompt_with_diff_parts() {
+    let view = minimal_view();
+    let working_dir = PathBuf::from("/tmp/workspace");
+    let diff = ImplementationDiff {
+        path: PathBuf::from("/tmp/session/implementation_diff_1.diff"),
+        parts: vec![
+            PathBuf::from("/tmp/session/implementation_diff_1.part1.diff"),
+            PathBuf::from("/tmp/session/implementation_diff_1.part2.diff"),
+        ],
+        bytes: 300_000,
+    };
+    let prompt = build_implementation_review_prompt(
+        &view,
+        &working_dir,
+        1,
+        None,
+        &diff_files_section(&diff),
+    )
+    .expect("build_implementation_review_prompt failed");
+
+    assert!(prompt.contains("split into 2 parts"));
+    assert!(prompt.contains("implementation_diff_1.part2.diff"));
+    assert!(!prompt.contains("implementation_diff_1.diff"));
+}
+
+#[test]
+fn test_split_diff_prefers_file_boundaries() {
+    let file = |name: &str, lines: usize| {
+        let mut text = format!("diff --git a/{name} b/{name}\n");
+        text.push_str(&"+line\n".repeat(lines));
+        text
+    };
+    let diff = format!("{}{}{}", file("a", 10), file("b", 10), file("c", 2));
+
+    let parts = split_diff(&diff, 100);
+    assert!(parts.iter().all(|part| part.len() <= 100));
+    assert!(parts[1].starts_with("diff --git a/b"));
+    assert_eq!(parts.concat(), diff);
+
+    assert_eq!(split_diff("", 100), vec![String::new()]);
+    assert_eq!(split_diff(&diff, 1_000), vec![diff.clone()]);
+}
@@ -82,6 +82,28 @@ fn test_build_implementation_prompt_with_feedback() {
     assert!(prompt.ends_with(r#"Run the "implementation" skill to execute the plan."#));
 }
 
+#[test]
+fn test_build_implementation_prompt_with_approval_conditions() {
+    let mut view = minimal_view();
+    let working_dir = PathBuf::from("/tmp/workspace");
+    assert!(!build_implementation_prompt(&view, &working_dir, 1, None)
+        .contains("CONDITIONS OF APPROVAL"));
+
+    view.apply_event(
+        &Uuid::new_v4().to_string(),
+        &WorkflowEvent::UserApprovedWithConditions {
+            conditions: "Keep the public API unchanged".to_string(),
+            approved_at: TimestampUtc::now(),
+        },
+        4,
+    );
+    let prompt = build_implementation_prompt(&view, &working_dir, 1, None);
+
+    assert!(prompt.contains("CONDITIONS OF APPROVAL"));
+    assert!(prompt.contains("Keep the public API unchanged"));
+    assert!(prompt.ends_with(r#"Run the "implementation" skill to execute the plan."#));
+}
+
 #[test]
 fn test_build_implementation_followup_prompt() {
     let view = minimal_view();
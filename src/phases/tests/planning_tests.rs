@@ -52,7 +52,7 @@ fn planning_system_prompt_references_skill() {
 fn build_planning_prompt_includes_plan_output_path() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     assert!(
         prompt.contains("<plan-output-path>"),
@@ -68,7 +68,7 @@ fn build_planning_prompt_includes_plan_output_path() {
 fn build_planning_prompt_includes_session_folder() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     assert!(
         prompt.contains("<session-folder-path>"),
@@ -80,7 +80,7 @@ fn build_planning_prompt_includes_session_folder() {
 fn build_planning_prompt_includes_workspace_root() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     assert!(
         prompt.contains("<workspace-root>"),
@@ -96,7 +96,7 @@ fn build_planning_prompt_includes_workspace_root() {
 fn build_planning_prompt_includes_objective() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     assert!(
         prompt.contains("<objective>"),
@@ -112,7 +112,7 @@ fn build_planning_prompt_includes_objective() {
 fn build_planning_prompt_references_skill() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     assert!(
         prompt.contains("planning"),
@@ -145,7 +145,7 @@ fn build_planning_prompt_includes_accumulated_user_feedback() {
     view.apply_event(&agg_id, &user_declined_event("Add integration tests"), 3);
 
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     // Prompt should contain the user-feedback tag and both feedback items
     assert!(
@@ -175,7 +175,7 @@ fn build_planning_prompt_includes_accumulated_user_feedback() {
 fn build_planning_prompt_excludes_user_feedback_when_empty() {
     let view = minimal_view();
     let working_dir = PathBuf::from("/tmp/workspace");
-    let prompt = build_planning_prompt(&view, &working_dir);
+    let prompt = build_planning_prompt(&view, &working_dir, None, false, None, None);
 
     // Prompt should NOT contain user-feedback tag when there's no feedback
     assert!(
@@ -183,3 +183,102 @@ fn build_planning_prompt_excludes_user_feedback_when_empty() {
         "Planning prompt should not contain <user-feedback> tag when no feedback exists"
     );
 }
+
+fn required_sections() -> Vec<String> {
+    ["Goals", "Non-goals", "Risks", "Test plan"]
+        .map(String::from)
+        .to_vec()
+}
+
+#[test]
+fn missing_plan_sections_matches_headings() {
+    let plan = "# Auth plan\n\n## 1. Goals\n\n- Log in\n\n### Risks and mitigations\n\nNone\n\n## Test Plan\n";
+    assert_eq!(
+        missing_plan_sections(plan, &required_sections()),
+        vec!["Non-goals".to_string()]
+    );
+}
+
+#[test]
+fn missing_plan_sections_does_not_confuse_non_goals_with_goals() {
+    let plan = "## Non-goals\n\n## Risks\n\n## Test plan\n\nGoals are listed above.\n";
+    assert_eq!(
+        missing_plan_sections(plan, &required_sections()),
+        vec!["Goals".to_string()]
+    );
+}
+
+#[test]
+fn plan_structure_followup_lists_missing_sections() {
+    let view = minimal_view();
+    let prompt = build_plan_structure_followup(&view, &["Risks".to_string()], None);
+    assert!(prompt.contains("<missing-sections>"));
+    assert!(prompt.contains("## Risks"));
+    assert!(prompt.contains("/tmp/test-plan.md"));
+    assert!(!prompt.contains("<plan-front-matter>"));
+}
+
+#[test]
+fn plan_structure_followup_explains_front_matter_problem() {
+    let view = minimal_view();
+    let prompt = build_plan_structure_followup(&view, &[], Some("missing title"));
+    assert!(!prompt.contains("<missing-sections>"));
+    assert!(prompt.contains("missing title"));
+    assert!(prompt.contains("<plan-front-matter>"));
+}
+
+#[test]
+fn build_planning_prompt_includes_front_matter_template_when_enabled() {
+    let view = minimal_view();
+    let working_dir = PathBuf::from("/tmp/workspace");
+    assert!(!build_planning_prompt(&view, &working_dir, None, false, None, None).contains("risk:"));
+    let prompt = build_planning_prompt(&view, &working_dir, None, true, None, None);
+    assert!(prompt.contains("<plan-front-matter>"));
+    assert!(prompt.contains("risk: <low | medium | high>"));
+}
+
+#[test]
+fn parse_plan_front_matter_reads_metadata() {
+    let plan = "---\ntitle: Add SSO login\nrisk: medium\neffort: 3 days\ncomponents:\n  - auth\n  - web\n---\n\n# Plan\n";
+    let metadata = parse_plan_front_matter(plan).unwrap();
+    assert_eq!(
+        metadata,
+        PlanMetadata {
+            title: "Add SSO login".to_string(),
+            risk: crate::domain::types::RiskLevel::Medium,
+            effort: "3 days".to_string(),
+            components: vec!["auth".to_string(), "web".to_string()],
+        }
+    );
+}
+
+#[test]
+fn parse_plan_front_matter_rejects_missing_or_invalid_front_matter() {
+    assert!(parse_plan_front_matter("# Plan\n\n## Goals\n").is_err());
+    assert!(parse_plan_front_matter("---\ntitle: x\nrisk: medium\neffort: 1d\n").is_err());
+    let bad_risk = "---\ntitle: x\nrisk: extreme\neffort: 1d\n---\n";
+    assert!(parse_plan_front_matter(bad_risk).is_err());
+    let blank_title = "---\ntitle: ' '\nrisk: low\neffort: 1d\n---\n";
+    let err = parse_plan_front_matter(blank_title).unwrap_err();
+    assert!(err.to_string().contains("empty title"));
+}
+
+#[test]
+fn build_planning_prompt_includes_user_notes_when_given() {
+    let view = minimal_view();
+    let working_dir = PathBuf::from("/tmp/workspace");
+    assert!(
+        !build_planning_prompt(&view, &working_dir, None, false, None, None)
+            .contains("<user-notes>")
+    );
+    let prompt = build_planning_prompt(
+        &view,
+        &working_dir,
+        None,
+        false,
+        Some("Keep the v1 API stable"),
+        None,
+    );
+    assert!(prompt.contains("<user-notes>"));
+    assert!(prompt.contains("Keep the v1 API stable"));
+}
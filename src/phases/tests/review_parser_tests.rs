@@ -108,3 +108,43 @@ fn test_parse_review_feedback_with_tags_when_required() {
     let review = result.unwrap();
     assert_eq!(review.verdict, ReviewVerdict::Approved);
 }
+
+#[test]
+fn test_parse_review_feedback_extracts_flagged_sections() {
+    let content = "## Summary\nCache design is incomplete.\n\n\
+                   ## Critical Issues\n- No invalidation strategy\n\n\
+                   ## Flagged Sections\n- `## Implementation Steps`\n* Testing\n\n\
+                   ## Overall Assessment: NEEDS REVISION";
+    let review = parse_review_feedback(content, false).unwrap();
+    assert_eq!(
+        review.flagged_sections,
+        vec!["Implementation Steps".to_string(), "Testing".to_string()]
+    );
+    assert_eq!(review.critical_issues, vec!["No invalidation strategy"]);
+}
+
+#[test]
+fn test_parse_review_feedback_extracts_issue_assessments() {
+    let content = "## Summary\nSome doubts.\n\n\
+                   ## Critical Issues\n- Cache may go stale (confidence: 0.3)\n\
+                   - [non-blocking] Naming is inconsistent (confidence: 80%)\n\n\
+                   ## Overall Assessment: NEEDS REVISION";
+    let review = parse_review_feedback(content, false).unwrap();
+    assert_eq!(review.issues.len(), 2);
+    assert_eq!(review.issues[0].description, "Cache may go stale");
+    assert_eq!(review.issues[0].confidence, Some(0.3));
+    assert!(review.issues[0].blocking);
+    assert_eq!(review.issues[1].description, "Naming is inconsistent");
+    assert_eq!(review.issues[1].confidence, Some(0.8));
+    assert!(!review.issues[1].blocking);
+    assert!(review.is_low_confidence_rejection(0.5));
+}
+
+#[test]
+fn test_parse_review_feedback_without_markers_has_no_issue_assessments() {
+    let content =
+        "## Critical Issues\n- No invalidation strategy\n\n## Overall Assessment: NEEDS REVISION";
+    let review = parse_review_feedback(content, false).unwrap();
+    assert!(review.issues.is_empty());
+    assert!(!review.is_low_confidence_rejection(0.5));
+}
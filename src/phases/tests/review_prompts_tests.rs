@@ -204,3 +204,24 @@ fn test_build_review_prompt_includes_session_folder() {
 
     assert!(prompt.contains("/home/user/.planning-agent/sessions/abc123"));
 }
+
+#[test]
+fn test_build_targeted_review_prompt_lists_sections() {
+    let prompt = build_targeted_review_prompt_for_agent(
+        "Implement feature X",
+        Path::new("/home/user/plan.md"),
+        Path::new("/home/user/feedback.md"),
+        Path::new("/home/user/project"),
+        Path::new("/home/user/.planning-agent/sessions/abc123"),
+        &["Implementation Steps".to_string(), "Testing".to_string()],
+        Some("plan-review-codebase"),
+    );
+
+    assert!(prompt.contains("SECTIONS TO RE-REVIEW"));
+    assert!(prompt.contains("- Implementation Steps\n- Testing"));
+    assert!(prompt.contains("/home/user/feedback.md"));
+    assert!(prompt.contains("## Flagged Sections"));
+    assert!(prompt.ends_with(
+        r#"You MUST run the "plan-review-codebase" skill again to perform this review. Do not skip invoking the skill."#
+    ));
+}
@@ -27,6 +27,8 @@ fn test_submitted_review_needs_revision() {
         summary: "Looks good".to_string(),
         critical_issues: vec![],
         recommendations: vec![],
+        flagged_sections: vec![],
+        issues: vec![],
         full_feedback: None,
     };
     assert!(!approved.needs_revision());
@@ -36,6 +38,8 @@ fn test_submitted_review_needs_revision() {
         summary: "Issues found".to_string(),
         critical_issues: vec!["Missing error handling".to_string()],
         recommendations: vec![],
+        flagged_sections: vec![],
+        issues: vec![],
         full_feedback: None,
     };
     assert!(needs_rev.needs_revision());
@@ -48,6 +52,8 @@ fn test_submitted_review_feedback_content() {
         summary: "The plan has some issues".to_string(),
         critical_issues: vec!["Issue 1".to_string(), "Issue 2".to_string()],
         recommendations: vec!["Suggestion 1".to_string()],
+        flagged_sections: vec![],
+        issues: vec![],
         full_feedback: None,
     };
 
@@ -67,9 +73,72 @@ fn test_submitted_review_full_feedback_takes_precedence() {
         summary: "Looks good".to_string(),
         critical_issues: vec![],
         recommendations: vec![],
+        flagged_sections: vec![],
+        issues: vec![],
         full_feedback: Some("# Custom Review\n\nFull custom content here.".to_string()),
     };
 
     let content = review.feedback_content();
     assert_eq!(content, "# Custom Review\n\nFull custom content here.");
 }
+
+#[test]
+fn test_submitted_review_flagged_sections_survive_full_feedback() {
+    let review = SubmittedReview {
+        verdict: ReviewVerdict::NeedsRevision,
+        summary: "Issues found".to_string(),
+        critical_issues: vec![],
+        recommendations: vec![],
+        flagged_sections: vec!["Testing".to_string()],
+        issues: vec![],
+        full_feedback: Some("## Overall Assessment: NEEDS REVISION".to_string()),
+    };
+
+    let content = review.feedback_content();
+    assert!(content.ends_with("## Flagged Sections\n\n- Testing\n"));
+    assert_eq!(extract_flagged_sections(&content), vec!["Testing"]);
+}
+
+fn issue(blocking: bool, confidence: Option<f32>) -> ReviewIssue {
+    ReviewIssue {
+        description: "Issue".to_string(),
+        blocking,
+        confidence,
+    }
+}
+
+#[test]
+fn test_low_confidence_rejection_requires_all_blocking_issues_below_threshold() {
+    let mut review = SubmittedReview {
+        verdict: ReviewVerdict::NeedsRevision,
+        summary: "Issues found".to_string(),
+        critical_issues: vec![],
+        recommendations: vec![],
+        flagged_sections: vec![],
+        issues: vec![issue(true, Some(0.3)), issue(false, Some(0.9))],
+        full_feedback: None,
+    };
+    assert!(review.is_low_confidence_rejection(0.5));
+
+    review.issues.push(issue(true, Some(0.8)));
+    assert!(!review.is_low_confidence_rejection(0.5));
+
+    // An unscored blocking issue counts as confident
+    review.issues = vec![issue(true, None)];
+    assert!(!review.is_low_confidence_rejection(0.5));
+
+    review.issues.clear();
+    assert!(!review.is_low_confidence_rejection(0.5));
+}
+
+#[test]
+fn test_submitted_review_deserializes_issue_assessments() {
+    let json = r#"{"verdict":"NEEDS_REVISION","summary":"s","issues":[{"description":"d","confidence":0.2},{"description":"e","blocking":false}]}"#;
+    let review: SubmittedReview = serde_json::from_str(json).unwrap();
+    assert!(review.issues[0].blocking);
+    assert_eq!(review.issues[0].confidence, Some(0.2));
+    assert!(!review.issues[1].blocking);
+    assert!(review
+        .feedback_content()
+        .contains("- [blocking, confidence 20%] d"));
+}
@@ -12,16 +12,22 @@ fn test_aggregate_any_rejects_none() {
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Plan looks good".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "No issues found".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AnyRejects),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved
     );
 }
@@ -34,16 +40,22 @@ fn test_aggregate_any_rejects_one() {
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Plan looks good".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Missing error handling".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AnyRejects),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 }
@@ -56,16 +68,22 @@ fn test_aggregate_all_reject_partial() {
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Plan looks good".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Missing error handling".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AllReject),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AllReject,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved
     );
 }
@@ -78,16 +96,22 @@ fn test_aggregate_all_reject_full() {
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Architecture concerns".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Missing error handling".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AllReject),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AllReject,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 }
@@ -100,23 +124,30 @@ fn test_aggregate_majority_one_of_three() {
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Plan looks good".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "No issues found".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "gemini".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Minor issues found".to_string(),
+            low_confidence: false,
         },
     ];
 
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved
     );
 }
@@ -129,23 +160,30 @@ fn test_aggregate_majority_two_of_three() {
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Architecture concerns".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION".to_string(),
             summary: "Missing error handling".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "gemini".to_string(),
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Plan looks good".to_string(),
+            low_confidence: false,
         },
     ];
 
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 }
@@ -154,7 +192,63 @@ fn test_aggregate_majority_two_of_three() {
 fn test_aggregate_empty_reviews() {
     let reviews: Vec<ReviewResult> = vec![];
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AnyRejects),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
+        FeedbackStatus::NeedsRevision
+    );
+}
+
+fn low_confidence_rejection(agent: &str) -> ReviewResult {
+    ReviewResult {
+        agent_name: agent.to_string(),
+        needs_revision: true,
+        feedback: "NEEDS REVISION".to_string(),
+        summary: "Unsure about caching".to_string(),
+        low_confidence: true,
+    }
+}
+
+#[test]
+fn test_aggregate_low_confidence_rejections_policy() {
+    let policy = ConfidencePolicy {
+        threshold: 0.5,
+        low_confidence_rejections_required: 2,
+    };
+    let approval = ReviewResult {
+        agent_name: "codex".to_string(),
+        needs_revision: false,
+        feedback: "APPROVED".to_string(),
+        summary: "Fine".to_string(),
+        low_confidence: false,
+    };
+
+    // A single low-confidence rejection is discounted
+    let reviews = vec![low_confidence_rejection("claude"), approval.clone()];
+    assert_eq!(
+        aggregate_reviews(&reviews, &AggregationMode::AnyRejects, &policy),
+        FeedbackStatus::Approved
+    );
+    // The default policy counts it
+    assert_eq!(
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
+        FeedbackStatus::NeedsRevision
+    );
+
+    // Two low-confidence rejections force a revision
+    let reviews = vec![
+        low_confidence_rejection("claude"),
+        low_confidence_rejection("gemini"),
+        approval,
+    ];
+    assert_eq!(
+        aggregate_reviews(&reviews, &AggregationMode::AnyRejects, &policy),
         FeedbackStatus::NeedsRevision
     );
 }
@@ -197,12 +291,14 @@ fn test_write_feedback_files_with_display_ids() {
             needs_revision: true,
             feedback: "Security concerns found".to_string(),
             summary: "Security review".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-architecture".to_string(),
             needs_revision: false,
             feedback: "Architecture looks good".to_string(),
             summary: "Architecture review".to_string(),
+            low_confidence: false,
         },
     ];
 
@@ -234,12 +330,14 @@ fn test_merge_feedback_with_display_ids() {
             needs_revision: true,
             feedback: "Found SQL injection vulnerability".to_string(),
             summary: "Security issues".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-architecture".to_string(),
             needs_revision: false,
             feedback: "Clean separation of concerns".to_string(),
             summary: "Good architecture".to_string(),
+            low_confidence: false,
         },
     ];
 
@@ -268,30 +366,41 @@ fn test_reviews_with_same_base_agent_different_display_ids() {
             needs_revision: true,
             feedback: "NEEDS REVISION: security issues".to_string(),
             summary: "Security concerns".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-architecture".to_string(),
             needs_revision: true,
             feedback: "NEEDS REVISION: architecture issues".to_string(),
             summary: "Architecture concerns".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: false,
             feedback: "APPROVED".to_string(),
             summary: "Looks good".to_string(),
+            low_confidence: false,
         },
     ];
 
     // With any_rejects, even one rejection means needs revision
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AnyRejects),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 
     // With majority (2/3 reject), needs revision
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 
@@ -331,6 +440,7 @@ fn test_five_agent_review_aggregation() {
             needs_revision: false,
             feedback: "APPROVED: Code looks correct".to_string(),
             summary: "No issues found".to_string(),
+            low_confidence: false,
         },
         // Agent 2: gemini (simple) - rejects
         ReviewResult {
@@ -338,6 +448,7 @@ fn test_five_agent_review_aggregation() {
             needs_revision: true,
             feedback: "NEEDS REVISION: Missing tests".to_string(),
             summary: "Test coverage needed".to_string(),
+            low_confidence: false,
         },
         // Agent 3: claude-security (extended) - rejects
         ReviewResult {
@@ -345,6 +456,7 @@ fn test_five_agent_review_aggregation() {
             needs_revision: true,
             feedback: "NEEDS REVISION: SQL injection vulnerability".to_string(),
             summary: "Security issues found".to_string(),
+            low_confidence: false,
         },
         // Agent 4: claude-architecture (extended) - approves
         ReviewResult {
@@ -352,6 +464,7 @@ fn test_five_agent_review_aggregation() {
             needs_revision: false,
             feedback: "APPROVED: Good separation of concerns".to_string(),
             summary: "Architecture is solid".to_string(),
+            low_confidence: false,
         },
         // Agent 5: claude (extended, no custom id) - approves
         ReviewResult {
@@ -359,6 +472,7 @@ fn test_five_agent_review_aggregation() {
             needs_revision: false,
             feedback: "APPROVED: Logic is correct".to_string(),
             summary: "Correctness verified".to_string(),
+            low_confidence: false,
         },
     ];
 
@@ -374,21 +488,33 @@ fn test_five_agent_review_aggregation() {
 
     // AnyRejects: 2 rejections -> needs revision
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AnyRejects),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AnyRejects,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision,
         "AnyRejects should return NeedsRevision with 2 rejections"
     );
 
     // AllReject: Not all reject (only 2/5) -> approved
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::AllReject),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::AllReject,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved,
         "AllReject should return Approved when not all reject"
     );
 
     // Majority: 3/5 approve -> approved (majority approves)
     assert_eq!(
-        aggregate_reviews(&reviews, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved,
         "Majority should return Approved with 3/5 approvals"
     );
@@ -408,30 +534,35 @@ fn test_five_agent_feedback_files() {
             needs_revision: false,
             feedback: "Codex feedback content".to_string(),
             summary: "Codex summary".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "gemini".to_string(),
             needs_revision: true,
             feedback: "Gemini feedback content".to_string(),
             summary: "Gemini summary".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-security".to_string(),
             needs_revision: true,
             feedback: "Security review feedback".to_string(),
             summary: "Security summary".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-architecture".to_string(),
             needs_revision: false,
             feedback: "Architecture review feedback".to_string(),
             summary: "Architecture summary".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude".to_string(),
             needs_revision: false,
             feedback: "Correctness review feedback".to_string(),
             summary: "Correctness summary".to_string(),
+            low_confidence: false,
         },
     ];
 
@@ -479,30 +610,35 @@ fn test_five_agent_merge_feedback() {
             needs_revision: false,
             feedback: "All tests pass".to_string(),
             summary: "Tests OK".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "gemini".to_string(),
             needs_revision: true,
             feedback: "Missing edge case handling".to_string(),
             summary: "Edge cases".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-security".to_string(),
             needs_revision: true,
             feedback: "Found XSS vulnerability in input handler".to_string(),
             summary: "XSS found".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude-architecture".to_string(),
             needs_revision: false,
             feedback: "Good use of dependency injection".to_string(),
             summary: "Good DI".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "claude".to_string(),
             needs_revision: false,
             feedback: "Logic verified correct".to_string(),
             summary: "Logic OK".to_string(),
+            low_confidence: false,
         },
     ];
 
@@ -546,34 +682,43 @@ fn test_five_agent_majority_edge_cases() {
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "b".to_string(),
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "c".to_string(),
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "d".to_string(),
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "e".to_string(),
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews_3_approve, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews_3_approve,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved
     );
 
@@ -584,34 +729,43 @@ fn test_five_agent_majority_edge_cases() {
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "b".to_string(),
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "c".to_string(),
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "d".to_string(),
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "e".to_string(),
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         },
     ];
     assert_eq!(
-        aggregate_reviews(&reviews_3_reject, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews_3_reject,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 
@@ -622,10 +776,15 @@ fn test_five_agent_majority_edge_cases() {
             needs_revision: false,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         })
         .collect();
     assert_eq!(
-        aggregate_reviews(&reviews_all_approve, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews_all_approve,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::Approved
     );
 
@@ -636,10 +795,15 @@ fn test_five_agent_majority_edge_cases() {
             needs_revision: true,
             feedback: "".to_string(),
             summary: "".to_string(),
+            low_confidence: false,
         })
         .collect();
     assert_eq!(
-        aggregate_reviews(&reviews_all_reject, &AggregationMode::Majority),
+        aggregate_reviews(
+            &reviews_all_reject,
+            &AggregationMode::Majority,
+            &ConfidencePolicy::default()
+        ),
         FeedbackStatus::NeedsRevision
     );
 }
@@ -14,12 +14,14 @@ fn test_reviews() -> Vec<ReviewResult> {
             needs_revision: true,
             feedback: "Issue 1: Missing tests".to_string(),
             summary: "Missing test coverage".to_string(),
+            low_confidence: false,
         },
         ReviewResult {
             agent_name: "codex".to_string(),
             needs_revision: true,
             feedback: "Issue 2: Unclear architecture".to_string(),
             summary: "Architecture needs clarification".to_string(),
+            low_confidence: false,
         },
     ]
 }
@@ -79,8 +81,16 @@ fn test_revision_prompt_includes_plan_path() {
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
     // Test with session_resume_active = false (full context prompt)
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, false, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        false,
+        1,
+        None,
+        None,
+    );
 
     eprintln!("Generated revision prompt:\n{}", prompt);
 
@@ -104,8 +114,16 @@ fn test_build_revision_prompt_full_context() {
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
     // Test with session_resume_active = false (full context prompt)
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, false, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        false,
+        1,
+        None,
+        None,
+    );
 
     // Check XML structure
     assert!(prompt.starts_with("<user-prompt>"));
@@ -133,8 +151,16 @@ fn test_build_revision_prompt_session_resume() {
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
     // Test with session_resume_active = true (simplified continuation prompt)
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, true, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        true,
+        1,
+        None,
+        None,
+    );
 
     // Should NOT be XML structured
     assert!(!prompt.starts_with("<user-prompt>"));
@@ -178,8 +204,16 @@ fn revision_prompt_session_resume_contains_no_timeline_directive() {
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
     // Test with session_resume_active = true (simplified continuation prompt)
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, true, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        true,
+        1,
+        None,
+        None,
+    );
 
     assert!(
         prompt.contains("Do not add timelines"),
@@ -196,8 +230,16 @@ fn revision_prompt_full_context_contains_no_timeline_directive() {
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
     // Test with session_resume_active = false (full context prompt)
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, false, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        false,
+        1,
+        None,
+        None,
+    );
 
     assert!(
         prompt.contains("DO NOT include timelines"),
@@ -213,8 +255,16 @@ fn test_revision_prompt_includes_session_folder_full_context() {
     let working_dir = Path::new("/workspaces/myproject");
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, false, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        false,
+        1,
+        None,
+        None,
+    );
 
     assert!(prompt.contains("<session-folder-path>"));
     assert!(prompt.contains("/home/user/.planning-agent/sessions/abc123"));
@@ -228,9 +278,66 @@ fn test_revision_prompt_includes_session_folder_session_resume() {
     let working_dir = Path::new("/workspaces/myproject");
     let session_folder = Path::new("/home/user/.planning-agent/sessions/abc123");
 
-    let prompt =
-        build_revision_prompt_with_reviews(&view, &reviews, working_dir, session_folder, true, 1);
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        working_dir,
+        session_folder,
+        true,
+        1,
+        None,
+        None,
+    );
 
     assert!(prompt.contains("session folder"));
     assert!(prompt.contains("/home/user/.planning-agent/sessions/abc123"));
 }
+
+#[test]
+fn test_revision_prompt_includes_user_comments_on_this_rounds_feedback() {
+    use crate::phases::feedback_items::{
+        save_review_annotations, AnnotationStance, ReviewAnnotation,
+    };
+
+    let view = minimal_view();
+    let reviews = test_reviews();
+    let session_folder = tempfile::tempdir().unwrap();
+    let annotation = |review_file: &str, stance, note: &str| ReviewAnnotation {
+        review_file: review_file.to_string(),
+        line: 0,
+        feedback: "Issue 1: Missing tests".to_string(),
+        stance,
+        note: note.to_string(),
+    };
+    save_review_annotations(
+        session_folder.path(),
+        &[
+            annotation(
+                "feedback_1_claude.md",
+                AnnotationStance::Disagree,
+                "Covered by the existing suite",
+            ),
+            annotation(
+                "feedback_0_claude.md",
+                AnnotationStance::Agree,
+                "stale round",
+            ),
+        ],
+    )
+    .unwrap();
+
+    let prompt = build_revision_prompt_with_reviews(
+        &view,
+        &reviews,
+        Path::new("/workspaces/myproject"),
+        session_folder.path(),
+        true,
+        1,
+        None,
+        None,
+    );
+    assert!(prompt.contains("# User comments on reviewer feedback"));
+    assert!(prompt.contains("**disagree** on feedback_1_claude.md line 1"));
+    assert!(prompt.contains("User note: Covered by the existing suite"));
+    assert!(!prompt.contains("stale round"));
+}
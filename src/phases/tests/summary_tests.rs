@@ -8,7 +8,7 @@ fn test_build_plan_summary_input_utf8_boundary() {
     let content = format!("{}───", padding); // 7998 + 9 = 8007 bytes
 
     // This should not panic
-    let result = build_plan_summary_input(&content, "test");
+    let result = build_plan_summary_input(&content, "test", None);
     assert!(result.contains("Content truncated"));
 }
 
@@ -16,14 +16,14 @@ fn test_build_plan_summary_input_utf8_boundary() {
 fn test_build_plan_summary_input_just_over_boundary() {
     // Content just over the boundary (8001 bytes)
     let content = "a".repeat(8001);
-    let result = build_plan_summary_input(&content, "test");
+    let result = build_plan_summary_input(&content, "test", None);
     assert!(result.contains("Content truncated"));
 }
 
 #[test]
 fn test_build_plan_summary_input_under_limit() {
     let content = "short content";
-    let result = build_plan_summary_input(content, "test");
+    let result = build_plan_summary_input(content, "test", None);
     assert!(!result.contains("Content truncated"));
     assert!(result.contains("short content"));
 }
@@ -15,6 +15,7 @@
 //! All path functions in this module use `planning_agent_home_dir()` as the
 //! single source of truth.
 
+use crate::domain::types::{IssueLink, Phase};
 use anyhow::{Context, Result};
 use sha2::{Digest, Sha256};
 use std::fs;
@@ -164,6 +165,26 @@ pub fn version_cache_path() -> Result<PathBuf> {
     Ok(planning_agent_home_dir()?.join("version-cache.json"))
 }
 
+/// Returns the update channel path: `~/.planning-agent/update-channel`
+pub fn update_channel_path() -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join("update-channel"))
+}
+
+/// Returns the trusted release signers path: `~/.planning-agent/release-signers`
+pub fn release_signers_path() -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join("release-signers"))
+}
+
+/// Returns the release download directory: `~/.planning-agent/updates/`
+///
+/// Creates the directory if it doesn't exist.
+pub fn updates_dir() -> Result<PathBuf> {
+    let dir = planning_agent_home_dir()?.join("updates");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create updates directory: {}", dir.display()))?;
+    Ok(dir)
+}
+
 /// Computes a working directory hash (SHA256 truncated to 12 hex characters).
 ///
 /// Attempts to canonicalize the path first for consistency across symlinks.
@@ -250,6 +271,14 @@ pub fn session_aggregate_snapshot_path(session_id: &str) -> Result<PathBuf> {
     Ok(session_dir(session_id)?.join("snapshot.json"))
 }
 
+/// Returns the session metrics summary: `~/.planning-agent/sessions/<session-id>/summary.json`
+///
+/// Written when the workflow completes: phase timings, iterations, usage per
+/// agent and the files changed.
+pub fn session_summary_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join("summary.json"))
+}
+
 /// Returns the session logs directory: `~/.planning-agent/sessions/<session-id>/logs/`
 ///
 /// Creates the directory if it doesn't exist.
@@ -260,6 +289,25 @@ pub fn session_logs_dir(session_id: &str) -> Result<PathBuf> {
     Ok(dir)
 }
 
+/// Returns the running agent registry: `~/.planning-agent/sessions/<session-id>/agent-processes.json`
+///
+/// Lists the agent processes the session has spawned and not yet reaped.
+pub fn session_agent_processes_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join(crate::agents::orphans::AGENT_PROCESSES_FILE))
+}
+
+/// Returns the session usage ledger: `~/.planning-agent/sessions/<session-id>/usage.jsonl`
+///
+/// One line per finished agent invocation, read by `planning usage`.
+pub fn session_usage_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join(crate::agents::cost_stats::USAGE_LEDGER_FILE))
+}
+
+/// Returns the autosaved input drafts directory: `~/.planning-agent/sessions/<session-id>/drafts/`
+pub fn session_drafts_dir(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join("drafts"))
+}
+
 // ============================================================================
 // Implementation Phase Paths
 // ============================================================================
@@ -276,6 +324,27 @@ pub fn session_implementation_review_path(session_id: &str, iteration: u32) -> R
     Ok(session_dir(session_id)?.join(format!("implementation_review_{}.md", iteration)))
 }
 
+/// Returns the implementation diff written for a review round.
+/// Format: `~/.planning-agent/sessions/<session-id>/implementation_diff_<iteration>.diff`
+pub fn session_implementation_diff_path(session_id: &str, iteration: u32) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join(format!("implementation_diff_{}.diff", iteration)))
+}
+
+/// Returns the imported issue context file: `~/.planning-agent/sessions/<session-id>/issue.md`
+pub fn session_issue_context_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join("issue.md"))
+}
+
+/// Returns the attached CI failure logs file: `~/.planning-agent/sessions/<session-id>/ci-logs.md`
+pub fn session_ci_logs_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join("ci-logs.md"))
+}
+
+/// Returns the targeted package's boundaries (`--package`): `~/.planning-agent/sessions/<session-id>/package.md`
+pub fn session_package_path(session_id: &str) -> Result<PathBuf> {
+    Ok(session_dir(session_id)?.join("package.md"))
+}
+
 /// Returns the session info metadata file: `~/.planning-agent/sessions/<session-id>/session_info.json`
 pub fn session_info_path(session_id: &str) -> Result<PathBuf> {
     Ok(session_dir(session_id)?.join("session_info.json"))
@@ -285,35 +354,135 @@ pub fn session_info_path(session_id: &str) -> Result<PathBuf> {
 // Session Daemon Paths
 // ============================================================================
 
-/// Returns the session daemon PID file path: `~/.planning-agent/sessiond.pid`
+/// Identifies the current user in the daemon's file names.
+///
+/// The home directory can be shared between users (via `PLANNING_AGENT_HOME` or a
+/// shared account home), so each user gets their own daemon. On Unix this is the
+/// effective UID; elsewhere the sanitized user name.
+pub fn daemon_user() -> String {
+    #[cfg(unix)]
+    {
+        format!("uid{}", unsafe { nix::libc::geteuid() })
+    }
+    #[cfg(not(unix))]
+    {
+        let name = std::env::var("USERNAME").unwrap_or_else(|_| "default".to_string());
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+}
+
+fn sessiond_file(suffix: &str) -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join(format!("sessiond-{}.{}", daemon_user(), suffix)))
+}
+
+/// Returns the session daemon PID file path: `~/.planning-agent/sessiond-<user>.pid`
 pub fn sessiond_pid_path() -> Result<PathBuf> {
-    Ok(planning_agent_home_dir()?.join("sessiond.pid"))
+    sessiond_file("pid")
 }
 
-/// Returns the session daemon lock file path: `~/.planning-agent/sessiond.lock`
+/// Returns the session daemon lock file path: `~/.planning-agent/sessiond-<user>.lock`
 pub fn sessiond_lock_path() -> Result<PathBuf> {
-    Ok(planning_agent_home_dir()?.join("sessiond.lock"))
+    sessiond_file("lock")
 }
 
-/// Returns the session daemon build SHA file path: `~/.planning-agent/sessiond.sha`
+/// Returns the session daemon build SHA file path: `~/.planning-agent/sessiond-<user>.sha`
 ///
 /// Used for version detection when daemon is unresponsive.
 pub fn sessiond_build_sha_path() -> Result<PathBuf> {
-    Ok(planning_agent_home_dir()?.join("sessiond.sha"))
+    sessiond_file("sha")
 }
 
-/// Returns the session daemon port file path: `~/.planning-agent/sessiond.port`
+/// Returns the session daemon port file path: `~/.planning-agent/sessiond-<user>.port`
 ///
 /// Contains JSON with port number, subscriber port, and authentication token.
 pub fn sessiond_port_path() -> Result<PathBuf> {
-    Ok(planning_agent_home_dir()?.join("sessiond.port"))
+    sessiond_file("port")
+}
+
+/// Returns the stale daemon recovery counter path: `~/.planning-agent/sessiond-<user>.recoveries`
+///
+/// Counts how often clients found the daemon dead behind a leftover port file.
+pub fn sessiond_recoveries_path() -> Result<PathBuf> {
+    sessiond_file("recoveries")
 }
 
-/// Returns the session daemon registry file path: `~/.planning-agent/sessiond.registry.json`
+/// Returns the session daemon socket path: `~/.planning-agent/sessiond-<user>.sock`
+///
+/// Unix only; clients prefer it over TCP because the daemon can check the peer's UID.
+pub fn sessiond_socket_path() -> Result<PathBuf> {
+    sessiond_file("sock")
+}
+
+/// Returns the session daemon registry file path: `~/.planning-agent/sessiond-<user>.registry.json`
 ///
 /// Used for faster recovery after daemon restart.
 pub fn sessiond_registry_path() -> Result<PathBuf> {
-    Ok(planning_agent_home_dir()?.join("sessiond.registry.json"))
+    sessiond_file("registry.json")
+}
+
+// ============================================================================
+// Cost Statistics Paths
+// ============================================================================
+
+/// Returns the historical agent usage file path: `~/.planning-agent/cost-stats.json`
+///
+/// Updated after each completed session and used to estimate workflow cost.
+pub fn cost_stats_path() -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join("cost-stats.json"))
+}
+
+/// Returns the workspace trust decisions file path: `~/.planning-agent/trusted-workspaces.json`
+///
+/// Records, per repository, whether implementation agents may run unrestricted.
+pub fn trusted_workspaces_path() -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join("trusted-workspaces.json"))
+}
+
+// ============================================================================
+// Scheduled Workflow Paths
+// ============================================================================
+
+/// Returns the scheduled workflow definitions file path: `~/.planning-agent/schedules.json`
+///
+/// Owned by the session daemon; clients manage schedules through daemon RPC.
+pub fn schedules_path() -> Result<PathBuf> {
+    Ok(planning_agent_home_dir()?.join("schedules.json"))
+}
+
+/// Returns the worker log path for a scheduled workflow: `~/.planning-agent/schedules/<id>.log`
+///
+/// Creates the parent directory if it doesn't exist.
+pub fn schedule_log_path(schedule_id: &str) -> Result<PathBuf> {
+    let dir = planning_agent_home_dir()?.join("schedules");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create schedules directory: {}", dir.display()))?;
+    Ok(dir.join(format!("{}.log", schedule_id)))
+}
+
+// ============================================================================
+// Skill Pack Paths
+// ============================================================================
+
+/// Returns the installed skill pack store: `~/.planning-agent/skill-packs/`
+///
+/// Packs live under `<name>/<version>/`. Creates the directory if it doesn't exist.
+pub fn skill_packs_dir() -> Result<PathBuf> {
+    let dir = planning_agent_home_dir()?.join("skill-packs");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create skill pack directory: {}", dir.display()))?;
+    Ok(dir)
+}
+
+/// Returns the archive directory for finished headless sessions: `~/.planning-agent/archive/`
+///
+/// Creates the directory if it doesn't exist.
+pub fn archive_dir() -> Result<PathBuf> {
+    let dir = planning_agent_home_dir()?.join("archive");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create archive directory: {}", dir.display()))?;
+    Ok(dir)
 }
 
 /// Returns the diagnostics directory for a working directory: `~/.planning-agent/diagnostics/<wd-hash>/`
@@ -340,12 +509,22 @@ pub fn review_bundle_path(
     Ok(diagnostics_dir(working_dir)?.join(filename))
 }
 
+/// Schema version written to new `session_info.json` files.
+///
+/// Files written before the field existed are version 1; they stored the phase
+/// as its Rust name (`"AwaitingPlanningDecision"`) and are upgraded when read.
+pub const SESSION_INFO_SCHEMA_VERSION: u32 = 2;
+
 /// Lightweight session info for fast listing without loading full snapshots.
 ///
 /// This struct is stored in `session_info.json` within each session directory
-/// and updated on each state save for efficient session listing.
+/// and updated on each state save for efficient session listing. Always read it
+/// through [`SessionInfo::parse`] or [`SessionInfo::load`] so older files are
+/// upgraded first.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SessionInfo {
+    /// Schema version of the stored file
+    pub schema_version: u32,
     /// The workflow session ID
     pub session_id: String,
     /// Human-readable feature name
@@ -359,9 +538,15 @@ pub struct SessionInfo {
     /// Last update timestamp (RFC3339)
     pub updated_at: String,
     /// Current workflow phase
-    pub phase: String,
+    pub phase: Phase,
     /// Current iteration number
     pub iteration: u32,
+    /// Issue tracker item the session was imported from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueLink>,
+    /// Output language the session was started with (`language` in the config)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 impl SessionInfo {
@@ -371,19 +556,22 @@ impl SessionInfo {
         feature_name: &str,
         objective: &str,
         working_dir: &Path,
-        phase: &str,
+        phase: Phase,
         iteration: u32,
     ) -> Self {
         let now = chrono::Utc::now().to_rfc3339();
         Self {
+            schema_version: SESSION_INFO_SCHEMA_VERSION,
             session_id: session_id.to_string(),
             feature_name: feature_name.to_string(),
             objective: objective.to_string(),
             working_dir: working_dir.to_path_buf(),
             created_at: now.clone(),
             updated_at: now,
-            phase: phase.to_string(),
+            phase,
             iteration,
+            issue: None,
+            language: None,
         }
     }
 
@@ -398,13 +586,94 @@ impl SessionInfo {
     }
 
     /// Loads session info from the session_info.json file.
-    #[cfg(test)]
     pub fn load(session_id: &str) -> Result<Self> {
         let path = session_info_path(session_id)?;
         let content = fs::read_to_string(&path)
             .with_context(|| format!("Failed to read session info: {}", path.display()))?;
-        serde_json::from_str(&content).with_context(|| "Failed to parse session info")
+        Self::parse(&content)
     }
+
+    /// Parses the contents of a session_info.json file, upgrading files written
+    /// under an older schema in memory. The upgraded form is written on the
+    /// next save. Files from a newer release are refused instead of misread.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(content).with_context(|| "Failed to parse session info")?;
+        let version = value
+            .get("schema_version")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(1, |version| version as u32);
+        if version > SESSION_INFO_SCHEMA_VERSION {
+            anyhow::bail!(
+                "Session info schema version {} is newer than supported version {}; upgrade planning-agent",
+                version,
+                SESSION_INFO_SCHEMA_VERSION
+            );
+        }
+        if version < 2 {
+            upgrade_session_info_v1(&mut value);
+        }
+        serde_json::from_value(value).with_context(|| "Failed to parse session info")
+    }
+}
+
+/// Version 1 stored the phase as its Rust name; unknown names fall back to
+/// planning rather than hiding the session from listings.
+fn upgrade_session_info_v1(value: &mut serde_json::Value) {
+    let Some(fields) = value.as_object_mut() else {
+        return;
+    };
+    let phase = [
+        Phase::Planning,
+        Phase::Reviewing,
+        Phase::Revising,
+        Phase::AwaitingPlanningDecision,
+        Phase::Complete,
+    ]
+    .into_iter()
+    .find(|phase| {
+        fields.get("phase").and_then(serde_json::Value::as_str) == Some(phase.status_label())
+    })
+    .unwrap_or_default();
+    fields.insert("phase".to_string(), serde_json::json!(phase));
+    fields.insert(
+        "schema_version".to_string(),
+        serde_json::Value::from(SESSION_INFO_SCHEMA_VERSION),
+    );
+}
+
+/// Finds every session named `feature_name` that was started in `working_dir`.
+///
+/// Matches are ordered most recently modified first. Sessions without a
+/// `session_info.json` are skipped.
+pub fn find_sessions_by_feature_name(
+    feature_name: &str,
+    working_dir: &Path,
+) -> Result<Vec<SessionInfo>> {
+    let sessions_dir = sessions_dir()?;
+    if !sessions_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries: Vec<_> = fs::read_dir(&sessions_dir)?
+        .filter_map(|e| e.ok())
+        .collect();
+
+    // Sort by modification time (most recent first)
+    entries.sort_by(|a, b| {
+        let a_time = a.metadata().and_then(|m| m.modified()).ok();
+        let b_time = b.metadata().and_then(|m| m.modified()).ok();
+        b_time.cmp(&a_time)
+    });
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| {
+            let content = fs::read_to_string(entry.path().join("session_info.json")).ok()?;
+            SessionInfo::parse(&content).ok()
+        })
+        .filter(|info| info.feature_name == feature_name && info.working_dir == working_dir)
+        .collect())
 }
 
 #[cfg(test)]
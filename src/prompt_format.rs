@@ -42,6 +42,15 @@ pub fn xml_escape(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+/// Asks for prose in `language` (the workflow config's `language`), keeping
+/// everything the workflow parses in English.
+pub fn language_instruction(language: &str) -> String {
+    format!(
+        "Write all prose (plan text, review feedback and summaries) in {}. Keep code, commands, file paths, identifiers, the required section headings and verdict keywords in English, exactly as specified.",
+        language
+    )
+}
+
 /// Builder for constructing XML-structured prompts.
 ///
 /// Enforces consistent section ordering: phase, instructions, context/inputs, constraints, tools, output-format.
@@ -99,6 +108,14 @@ impl PromptBuilder {
         self
     }
 
+    /// Adds the output language constraint when a language is configured.
+    pub fn language(mut self, language: Option<&str>) -> Self {
+        if let Some(language) = language {
+            self.constraints.push(language_instruction(language));
+        }
+        self
+    }
+
     /// Sets the tools section.
     pub fn tools(mut self, tools: &str) -> Self {
         self.tools = Some(tools.to_string());
@@ -1,6 +1,7 @@
 //! Daemon service definitions for client ↔ daemon RPC.
 
-use crate::rpc::{DaemonResult, SessionRecord, WorkflowEventEnvelope};
+use crate::rpc::host_service::RemoteCommand;
+use crate::rpc::{DaemonResult, ScheduledWorkflow, SessionRecord, WorkflowEventEnvelope};
 
 /// Service exposed by the session daemon to clients.
 #[tarpc::service]
@@ -26,6 +27,16 @@ pub trait DaemonService {
     /// Force-stop a session.
     async fn force_stop(session_id: String) -> DaemonResult<()>;
 
+    /// Ask the process holding a session to hand it over to `pid`.
+    /// The holder is told through `LeaseLost` on its next heartbeat and releases the
+    /// session by marking it stopped. Returns the holder's PID, or None if the
+    /// session isn't held by another running process.
+    async fn take_over(session_id: String, pid: u32) -> DaemonResult<Option<u32>>;
+
+    /// Take the commands the host relayed for a session, oldest first.
+    /// Polled by the session's process with its heartbeat.
+    async fn take_commands(session_id: String) -> DaemonResult<Vec<RemoteCommand>>;
+
     /// Request daemon shutdown (for updates).
     async fn shutdown() -> DaemonResult<()>;
 
@@ -48,6 +59,20 @@ pub trait DaemonService {
     /// Called by workflow processes to push CQRS events to the daemon,
     /// which then broadcasts them to all connected subscribers.
     async fn workflow_event(session_id: String, event: WorkflowEventEnvelope) -> DaemonResult<()>;
+
+    /// Add a scheduled workflow. The daemon persists it and launches a
+    /// headless worker each time it becomes due.
+    async fn schedule_add(schedule: ScheduledWorkflow) -> DaemonResult<()>;
+
+    /// List scheduled workflows ordered by next run time.
+    async fn schedule_list() -> DaemonResult<Vec<ScheduledWorkflow>>;
+
+    /// Remove a scheduled workflow by ID.
+    async fn schedule_remove(schedule_id: String) -> DaemonResult<()>;
+
+    /// Share a session read-only through the host relay.
+    /// Returns the session's share token, the same one on repeated calls.
+    async fn share_session(session_id: String) -> DaemonResult<String>;
 }
 
 /// Callback service for push notifications (daemon → subscriber).
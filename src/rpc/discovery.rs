@@ -0,0 +1,290 @@
+//! mDNS discovery of the host aggregator.
+//!
+//! `planning --host` answers multicast DNS queries for [`SERVICE_TYPE`] with the
+//! port of its RPC server. Container daemons with no configured host address send
+//! such a query when localhost and host.docker.internal are unreachable, and
+//! connect to whichever host answers, at the address the answer came from.
+//!
+//! Only the records this needs are handled: a PTR question, answered with PTR and
+//! SRV records. Discovery is off unless `PLANNING_AGENT_MDNS=1`, and daemons only
+//! use a discovered host that proves it knows the shared secret (see
+//! [`super::host_auth`]). `PLANNING_AGENT_HOST_ADDRESS` skips discovery.
+
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+/// Service type advertised by the host.
+pub const SERVICE_TYPE: &str = "_planning-agent._tcp.local";
+
+/// How long a daemon waits for a host to answer.
+pub const DISCOVERY_TIMEOUT: Duration = Duration::from_millis(1500);
+
+const MDNS_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+const MDNS_PORT: u16 = 5353;
+
+const TYPE_PTR: u16 = 12;
+const TYPE_SRV: u16 = 33;
+#[cfg(any(feature = "host-gui", test))]
+const TYPE_ANY: u16 = 255;
+const CLASS_IN: u16 = 1;
+/// Top bit of a question's class: the querier wants a unicast reply.
+const UNICAST_RESPONSE: u16 = 0x8000;
+/// Header flag set on responses.
+const FLAG_RESPONSE: u16 = 0x8000;
+/// Header flags of an authoritative response.
+#[cfg(any(feature = "host-gui", test))]
+const RESPONSE_FLAGS: u16 = FLAG_RESPONSE | 0x0400;
+#[cfg(any(feature = "host-gui", test))]
+const RECORD_TTL: u32 = 120;
+
+/// Whether the host advertises itself and daemons look for it via mDNS
+/// (off unless `PLANNING_AGENT_MDNS=1`).
+pub fn discovery_enabled() -> bool {
+    matches!(
+        std::env::var("PLANNING_AGENT_MDNS").as_deref(),
+        Ok("1") | Ok("true") | Ok("on")
+    )
+}
+
+fn push_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_name(out: &mut Vec<u8>, name: &str) {
+    for label in name.split('.').filter(|label| !label.is_empty()) {
+        out.push(label.len() as u8);
+        out.extend_from_slice(label.as_bytes());
+    }
+    out.push(0);
+}
+
+fn push_header(out: &mut Vec<u8>, id: u16, flags: u16, questions: u16, answers: u16) {
+    for value in [id, flags, questions, answers, 0, 0] {
+        push_u16(out, value);
+    }
+}
+
+/// A PTR query for the host service, asking for a unicast reply.
+pub fn encode_query(id: u16) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_header(&mut out, id, 0, 1, 0);
+    push_name(&mut out, SERVICE_TYPE);
+    push_u16(&mut out, TYPE_PTR);
+    push_u16(&mut out, CLASS_IN | UNICAST_RESPONSE);
+    out
+}
+
+#[cfg(any(feature = "host-gui", test))]
+fn push_record(out: &mut Vec<u8>, name: &str, record_type: u16, data: &[u8]) {
+    push_name(out, name);
+    push_u16(out, record_type);
+    push_u16(out, CLASS_IN);
+    out.extend_from_slice(&RECORD_TTL.to_be_bytes());
+    push_u16(out, data.len() as u16);
+    out.extend_from_slice(data);
+}
+
+/// PTR and SRV answers pointing at the host's RPC port.
+#[cfg(any(feature = "host-gui", test))]
+pub fn encode_response(id: u16, instance: &str, port: u16) -> Vec<u8> {
+    let instance_name = format!("{}.{}", instance, SERVICE_TYPE);
+    let mut out = Vec::new();
+    push_header(&mut out, id, RESPONSE_FLAGS, 0, 2);
+
+    let mut ptr = Vec::new();
+    push_name(&mut ptr, &instance_name);
+    push_record(&mut out, SERVICE_TYPE, TYPE_PTR, &ptr);
+
+    // Priority, weight, port, target
+    let mut srv = Vec::new();
+    push_u16(&mut srv, 0);
+    push_u16(&mut srv, 0);
+    push_u16(&mut srv, port);
+    push_name(&mut srv, &format!("{}.local", instance));
+    push_record(&mut out, &instance_name, TYPE_SRV, &srv);
+    out
+}
+
+/// DNS label for this machine: its hostname's letters, digits and dashes.
+#[cfg(any(feature = "host-gui", test))]
+pub fn instance_label(hostname: &str) -> String {
+    let label: String = hostname
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .take(63)
+        .collect();
+    if label.is_empty() {
+        "planning-host".to_string()
+    } else {
+        label
+    }
+}
+
+/// Cursor over a DNS message.
+struct Reader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(packet: &'a [u8]) -> Self {
+        Self { packet, pos: 0 }
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.packet.get(self.pos..self.pos + 2)?;
+        self.pos += 2;
+        Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        if self.pos + len > self.packet.len() {
+            return None;
+        }
+        self.pos += len;
+        Some(())
+    }
+
+    /// Reads a name, following compression pointers, lowercased.
+    fn name(&mut self) -> Option<String> {
+        let mut labels = Vec::new();
+        let mut pos = self.pos;
+        let mut jumped = false;
+        // Bounds the pointer chain so a malicious loop cannot spin forever
+        for _ in 0..128 {
+            let len = *self.packet.get(pos)? as usize;
+            if len == 0 {
+                if !jumped {
+                    self.pos = pos + 1;
+                }
+                return Some(labels.join("."));
+            }
+            if len & 0xC0 == 0xC0 {
+                let low = *self.packet.get(pos + 1)? as usize;
+                if !jumped {
+                    self.pos = pos + 2;
+                }
+                jumped = true;
+                pos = ((len & 0x3F) << 8) | low;
+                continue;
+            }
+            let label = self.packet.get(pos + 1..pos + 1 + len)?;
+            labels.push(String::from_utf8_lossy(label).to_lowercase());
+            pos += 1 + len;
+        }
+        None
+    }
+}
+
+/// Returns the query ID and whether a unicast reply was asked for, if the
+/// packet is a query for the host service.
+#[cfg(any(feature = "host-gui", test))]
+pub fn parse_query(packet: &[u8]) -> Option<(u16, bool)> {
+    let mut reader = Reader::new(packet);
+    let id = reader.u16()?;
+    if reader.u16()? & FLAG_RESPONSE != 0 {
+        return None;
+    }
+    let questions = reader.u16()?;
+    reader.skip(6)?;
+
+    let (mut matched, mut unicast) = (false, false);
+    for _ in 0..questions {
+        let name = reader.name()?;
+        let question_type = reader.u16()?;
+        let class = reader.u16()?;
+        if name == SERVICE_TYPE && matches!(question_type, TYPE_PTR | TYPE_ANY) {
+            matched = true;
+            unicast |= class & UNICAST_RESPONSE != 0;
+        }
+    }
+    matched.then_some((id, unicast))
+}
+
+/// Returns the RPC port from the SRV record of a host service response.
+pub fn parse_response(packet: &[u8]) -> Option<u16> {
+    let mut reader = Reader::new(packet);
+    reader.skip(2)?;
+    if reader.u16()? & FLAG_RESPONSE == 0 {
+        return None;
+    }
+    let questions = reader.u16()?;
+    let records = (0..3).try_fold(0u32, |sum, _| Some(sum + reader.u16()? as u32))?;
+    for _ in 0..questions {
+        reader.name()?;
+        reader.skip(4)?;
+    }
+
+    let instance_suffix = format!(".{}", SERVICE_TYPE);
+    for _ in 0..records {
+        let name = reader.name()?;
+        let record_type = reader.u16()?;
+        reader.skip(6)?;
+        let len = reader.u16()? as usize;
+        if record_type == TYPE_SRV && name.ends_with(&instance_suffix) {
+            reader.skip(4)?;
+            return reader.u16();
+        }
+        reader.skip(len)?;
+    }
+    None
+}
+
+/// Asks the local network for a host and returns the address of the first one
+/// that answers within `timeout`.
+pub async fn discover_host(timeout: Duration) -> std::io::Result<Option<SocketAddr>> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    socket
+        .send_to(
+            &encode_query(rand::random()),
+            SocketAddrV4::new(MDNS_GROUP, MDNS_PORT),
+        )
+        .await?;
+
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut buf = [0u8; 9000];
+    loop {
+        let (len, from) = match tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await
+        {
+            Ok(received) => received?,
+            Err(_) => return Ok(None),
+        };
+        if let Some(port) = buf.get(..len).and_then(parse_response) {
+            return Ok(Some(SocketAddr::new(from.ip(), port)));
+        }
+    }
+}
+
+/// Answers host service queries for the RPC server on `port`. Runs until the
+/// task is dropped; fails at once if another responder holds the mDNS port.
+#[cfg(feature = "host-gui")]
+pub async fn advertise_host(port: u16) -> std::io::Result<()> {
+    let socket = std::net::UdpSocket::bind((Ipv4Addr::UNSPECIFIED, MDNS_PORT))?;
+    socket.join_multicast_v4(&MDNS_GROUP, &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_nonblocking(true)?;
+    let socket = UdpSocket::from_std(socket)?;
+    let instance = instance_label(&gethostname::gethostname().to_string_lossy());
+
+    let mut buf = [0u8; 9000];
+    loop {
+        let (len, from) = socket.recv_from(&mut buf).await?;
+        let Some((id, unicast)) = buf.get(..len).and_then(parse_query) else {
+            continue;
+        };
+        // One-shot queries from other ports expect the reply sent straight back
+        let to = if unicast || from.port() != MDNS_PORT {
+            from
+        } else {
+            SocketAddr::from((MDNS_GROUP, MDNS_PORT))
+        };
+        // A failed reply only affects that querier, which will ask again
+        let _ = socket
+            .send_to(&encode_response(id, &instance, port), to)
+            .await;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/discovery_tests.rs"]
+mod tests;
@@ -0,0 +1,61 @@
+//! Shared-secret check of the host a container daemon connects to.
+//!
+//! With `PLANNING_AGENT_HOST_SECRET` set on both sides, the daemon sends a fresh
+//! nonce before anything else and the host answers with a hash of the secret and
+//! the nonce. A host that cannot answer is disconnected without having been sent
+//! any session data. Hosts found via mDNS are only used when a secret is set.
+
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Environment variable holding the secret shared by the host and its daemons.
+pub const HOST_SECRET_ENV: &str = "PLANNING_AGENT_HOST_SECRET";
+
+/// Length of the daemon's challenge.
+const NONCE_LEN: usize = 32;
+
+/// The shared secret, if one is configured.
+pub fn host_secret() -> Option<String> {
+    std::env::var(HOST_SECRET_ENV)
+        .ok()
+        .filter(|secret| !secret.is_empty())
+}
+
+/// A fresh challenge for the host.
+pub fn new_nonce() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(NONCE_LEN)
+        .map(char::from)
+        .collect()
+}
+
+/// The host's answer to `nonce`, which only a holder of `secret` can compute.
+pub fn identity_proof(secret: &str, nonce: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"planning-agent host\0");
+    hasher.update(secret.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(nonce.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Whether `proof` answers `nonce` for `secret`, compared in constant time.
+pub fn verify_proof(secret: &str, nonce: &str, proof: &str) -> bool {
+    let expected = identity_proof(secret, nonce);
+    expected.len() == proof.len()
+        && expected
+            .bytes()
+            .zip(proof.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+#[path = "tests/host_auth_tests.rs"]
+mod tests;
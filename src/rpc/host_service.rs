@@ -1,6 +1,8 @@
 //! Host service definitions for daemon ↔ host RPC.
 
-use crate::rpc::HostError;
+use crate::account_usage::types::{AccountId, AccountUsageState};
+use crate::rpc::{HostError, WorkflowEventEnvelope};
+use crate::usage_reset::{ResetTimestamp, UsageWindow, UsageWindowSpan};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -25,20 +27,19 @@ pub struct ContainerInfo {
     pub file_service_port: u16,
 }
 
-/// Credential info sent from daemon to host (includes tokens for API calls).
+/// Which provider logins a daemon has and when they expire. Access tokens never
+/// leave the daemon; it fetches usage itself and reports [`AccountUsageInfo`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CredentialInfo {
     pub provider: String,
     pub email: String,
     pub token_valid: bool,
     pub expires_at: Option<i64>,
-    /// Access token for API calls (OAuth token or JWT).
-    pub access_token: String,
     /// Account ID (only for Codex, None for others).
     pub account_id: Option<String>,
 }
 
-/// Usage info returned from host to daemon/GUI.
+/// Usage of one account, reported by daemons and returned to the GUI.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountUsageInfo {
     pub account_id: String,
@@ -48,16 +49,129 @@ pub struct AccountUsageInfo {
     pub rate_limit_tier: Option<String>,
     pub session_percent: Option<u8>,
     pub session_reset_at: Option<i64>,
+    #[serde(default)]
+    pub session_window_span: UsageWindowSpan,
     pub weekly_percent: Option<u8>,
     pub weekly_reset_at: Option<i64>,
+    #[serde(default)]
+    pub weekly_window_span: UsageWindowSpan,
     pub fetched_at: String,
     pub token_valid: bool,
     pub error: Option<String>,
 }
 
+impl AccountUsageInfo {
+    pub fn from_usage(usage: &AccountUsageState) -> Self {
+        Self {
+            account_id: usage.account_id.to_string(),
+            provider: usage.provider.clone(),
+            email: usage.email.clone(),
+            plan_type: usage.plan_type.clone(),
+            rate_limit_tier: usage.rate_limit_tier.clone(),
+            session_percent: usage.session_window.used_percent,
+            session_reset_at: usage.session_window.reset_at.map(|r| r.epoch_seconds),
+            session_window_span: usage.session_window.window_span,
+            weekly_percent: usage.weekly_window.used_percent,
+            weekly_reset_at: usage.weekly_window.reset_at.map(|r| r.epoch_seconds),
+            weekly_window_span: usage.weekly_window.window_span,
+            fetched_at: usage.fetched_at.clone(),
+            token_valid: usage.token_valid,
+            error: usage.error.clone(),
+        }
+    }
+
+    /// The usage state the host stores for the account.
+    #[cfg_attr(not(feature = "host-gui"), allow(dead_code))]
+    pub fn into_usage(self) -> AccountUsageState {
+        let window = |used_percent, reset_at: Option<i64>, window_span| UsageWindow {
+            used_percent,
+            reset_at: reset_at.map(ResetTimestamp::from_epoch_seconds),
+            window_span,
+        };
+        AccountUsageState {
+            account_id: AccountId::new(&self.provider, &self.email),
+            session_window: window(
+                self.session_percent,
+                self.session_reset_at,
+                self.session_window_span,
+            ),
+            weekly_window: window(
+                self.weekly_percent,
+                self.weekly_reset_at,
+                self.weekly_window_span,
+            ),
+            provider: self.provider,
+            email: self.email,
+            plan_type: self.plan_type,
+            rate_limit_tier: self.rate_limit_tier,
+            fetched_at: self.fetched_at,
+            error: self.error,
+            token_valid: self.token_valid,
+        }
+    }
+}
+
+/// A workflow event of a shared session, as relayed to watchers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedEvent {
+    pub sequence: u64,
+    pub event_type: String,
+}
+
+/// What changed in a shared session since a watcher's cursors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedSessionUpdate {
+    /// None while the session's daemon is disconnected from the host.
+    pub session: Option<SessionInfo>,
+    /// Events with a sequence above the watcher's, oldest first.
+    pub events: Vec<SharedEvent>,
+    /// Agent output lines after the watcher's output cursor.
+    pub output: Vec<String>,
+    /// Output cursor to pass on the next call.
+    pub output_cursor: u64,
+}
+
+/// A command for a container's session, sent from the host GUI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RemoteCommand {
+    /// Accept approval request `request`, if it is still pending.
+    Approve { request: u64 },
+    /// Decline approval request `request` with feedback, if it is still pending.
+    Decline { request: u64, feedback: String },
+    /// Stop the workflow.
+    Stop,
+    /// Interrupt the current phase with feedback.
+    Feedback { text: String },
+}
+
+impl RemoteCommand {
+    /// Short description for the session's output.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Approve { request } => format!("approval of request {}", request),
+            Self::Decline { request, feedback } => {
+                format!("decline of request {}: {}", request, feedback)
+            }
+            Self::Stop => "stop".to_string(),
+            Self::Feedback { text } => format!("feedback: {}", text),
+        }
+    }
+}
+
+/// A command queued on the host for one of a daemon's sessions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayedCommand {
+    pub session_id: String,
+    pub command: RemoteCommand,
+}
+
 /// Service exposed by the host to container daemons.
 #[tarpc::service]
 pub trait HostService {
+    /// Answers the daemon's challenge with `host_auth::identity_proof` of the
+    /// shared secret, or None without one. Sent before anything else.
+    async fn prove_identity(nonce: String) -> Option<String>;
+
     /// Initial handshake - returns host version if protocol compatible.
     async fn hello(info: ContainerInfo, protocol_version: u32) -> Result<String, HostError>;
 
@@ -70,14 +184,41 @@ pub trait HostService {
     /// Remove a session.
     async fn session_gone(session_id: String);
 
+    /// Workflow events for a session, oldest first.
+    /// Sent with the journal catch-up on connect and for each live event afterwards.
+    async fn workflow_events(session_id: String, events: Vec<WorkflowEventEnvelope>);
+
     /// Heartbeat to maintain connection liveness.
     async fn heartbeat();
 
-    /// Report available credentials from a daemon.
+    /// Report which logins a daemon has, without their tokens.
     /// Called on connect/reconnect and when credentials change.
     async fn report_credentials(credentials: Vec<CredentialInfo>);
 
+    /// Report usage the daemon fetched with its own credentials.
+    /// Called with the credentials and periodically in between.
+    async fn report_account_usage(usage: Vec<AccountUsageInfo>);
+
     /// Get current usage for all accounts.
     /// Returns the host's view of all tracked accounts.
     async fn get_account_usage() -> Vec<AccountUsageInfo>;
+
+    /// Make one of the calling daemon's sessions readable with `token`.
+    /// Sent by `planning share` and again on every reconnect.
+    async fn share_session(session_id: String, token: String) -> Result<(), HostError>;
+
+    /// New agent output lines of a shared session, oldest first.
+    async fn session_output(session_id: String, lines: Vec<String>);
+
+    /// Commands queued for the calling daemon's sessions since its last call,
+    /// oldest first. Polled with every heartbeat.
+    async fn pending_commands() -> Vec<RelayedCommand>;
+
+    /// Read-only view of a shared session for `planning watch`. Needs no
+    /// handshake: the token is the only credential.
+    async fn watch_session(
+        token: String,
+        after_sequence: u64,
+        output_cursor: u64,
+    ) -> Result<SharedSessionUpdate, HostError>;
 }
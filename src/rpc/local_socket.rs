@@ -0,0 +1,62 @@
+//! Unix domain socket transport for the daemon's main RPC service (Unix only).
+//!
+//! TCP on localhost cannot tell which user is connecting, so on shared hosts the
+//! daemon also listens on a per-user socket. The socket file is created with mode
+//! 0600 and every accepted connection is checked against the daemon's own UID via
+//! the kernel-provided peer credentials, on top of the usual token authentication.
+
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use tarpc::serde_transport::{self, Transport};
+use tarpc::tokio_serde::formats::Bincode;
+use tarpc::tokio_util::codec::{Framed, LengthDelimitedCodec};
+use tokio::net::{UnixListener, UnixStream};
+
+/// Framed bincode transport over a Unix stream.
+pub type SocketTransport<Item, SinkItem> =
+    Transport<UnixStream, Item, SinkItem, Bincode<Item, SinkItem>>;
+
+/// Effective UID of this process.
+pub fn current_uid() -> u32 {
+    unsafe { nix::libc::geteuid() }
+}
+
+/// Binds the socket, replacing a stale one, and restricts it to the owner.
+pub fn bind(path: &Path) -> io::Result<UnixListener> {
+    match std::fs::remove_file(path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(listener)
+}
+
+/// UID of the process on the other end of the connection.
+pub fn peer_uid(stream: &UnixStream) -> io::Result<u32> {
+    Ok(stream.peer_cred()?.uid())
+}
+
+/// Wraps a connected stream in the same framing and codec as the TCP transport.
+pub fn transport<Item, SinkItem>(stream: UnixStream) -> SocketTransport<Item, SinkItem>
+where
+    Item: for<'de> Deserialize<'de>,
+    SinkItem: Serialize,
+{
+    serde_transport::new(
+        Framed::new(stream, LengthDelimitedCodec::new()),
+        Bincode::default(),
+    )
+}
+
+/// Connects to the daemon socket.
+pub async fn connect<Item, SinkItem>(path: &Path) -> io::Result<SocketTransport<Item, SinkItem>>
+where
+    Item: for<'de> Deserialize<'de>,
+    SinkItem: Serialize,
+{
+    Ok(transport(UnixStream::connect(path).await?))
+}
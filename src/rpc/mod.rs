@@ -6,7 +6,11 @@
 
 pub mod daemon_file_service;
 pub mod daemon_service;
+pub mod discovery;
+pub mod host_auth;
 pub mod host_service;
+#[cfg(unix)]
+pub mod local_socket;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +28,9 @@ use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
 pub use crate::session_daemon::protocol::{LivenessState, PortFileContent, SessionRecord};
 
+// Re-export scheduled workflow types from session_daemon::schedule
+pub use crate::session_daemon::schedule::ScheduledWorkflow;
+
 // Re-export SessionInfo from host_protocol
 #[allow(unused_imports)]
 pub use crate::host::SessionInfo;
@@ -55,6 +62,10 @@ pub enum DaemonError {
         session_id: String,
         existing_pid: u32,
     },
+    /// Session is being taken over by another process, which waits for the holder to stop
+    LeaseLost { session_id: String, holder_pid: u32 },
+    /// Scheduled workflow not found
+    ScheduleNotFound { schedule_id: String },
     /// Daemon is shutting down
     ShuttingDown,
     /// Authentication failed
@@ -79,6 +90,19 @@ impl std::fmt::Display for DaemonError {
                     session_id, existing_pid
                 )
             }
+            DaemonError::LeaseLost {
+                session_id,
+                holder_pid,
+            } => {
+                write!(
+                    f,
+                    "Session {} is being taken over by PID {}",
+                    session_id, holder_pid
+                )
+            }
+            DaemonError::ScheduleNotFound { schedule_id } => {
+                write!(f, "Schedule not found: {}", schedule_id)
+            }
             DaemonError::ShuttingDown => write!(f, "Daemon is shutting down"),
             DaemonError::AuthenticationFailed => write!(f, "Authentication failed"),
             DaemonError::Internal { message } => write!(f, "Internal error: {}", message),
@@ -98,6 +122,8 @@ pub enum HostError {
     ProtocolMismatch { got: u32, expected: u32 },
     /// Container not registered
     ContainerNotRegistered,
+    /// No session is shared under this token
+    UnknownShareToken,
 }
 
 impl std::fmt::Display for HostError {
@@ -111,6 +137,7 @@ impl std::fmt::Display for HostError {
                 )
             }
             HostError::ContainerNotRegistered => write!(f, "Container not registered"),
+            HostError::UnknownShareToken => write!(f, "Unknown share token"),
         }
     }
 }
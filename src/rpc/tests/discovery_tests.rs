@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn test_query_round_trip() {
+    let query = encode_query(42);
+    assert_eq!(parse_query(&query), Some((42, true)));
+    // A query is not mistaken for an answer
+    assert_eq!(parse_response(&query), None);
+}
+
+#[test]
+fn test_response_carries_port() {
+    let response = encode_response(7, "devbox", 17718);
+    assert_eq!(parse_response(&response), Some(17718));
+    assert_eq!(parse_query(&response), None);
+}
+
+#[test]
+fn test_other_services_are_ignored() {
+    let mut query = Vec::new();
+    push_header(&mut query, 1, 0, 1, 0);
+    push_name(&mut query, "_http._tcp.local");
+    push_u16(&mut query, TYPE_PTR);
+    push_u16(&mut query, CLASS_IN);
+    assert_eq!(parse_query(&query), None);
+}
+
+#[test]
+fn test_response_with_compressed_names() {
+    // The answer's name points back at the question's name at offset 12
+    let mut packet = Vec::new();
+    push_header(&mut packet, 0, RESPONSE_FLAGS, 1, 1);
+    push_name(&mut packet, SERVICE_TYPE);
+    push_u16(&mut packet, TYPE_PTR);
+    push_u16(&mut packet, CLASS_IN);
+    packet.extend_from_slice(&[3, b'B', b'o', b'x', 0xC0, 12]);
+    push_u16(&mut packet, TYPE_SRV);
+    push_u16(&mut packet, CLASS_IN);
+    packet.extend_from_slice(&RECORD_TTL.to_be_bytes());
+    push_u16(&mut packet, 7);
+    packet.extend_from_slice(&[0, 0, 0, 0, 0x45, 0x25, 0]);
+    assert_eq!(parse_response(&packet), Some(0x4525));
+
+    // Truncated packets are rejected instead of read past the end
+    packet.truncate(packet.len() - 4);
+    assert_eq!(parse_response(&packet), None);
+}
+
+#[test]
+fn test_instance_label_keeps_dns_safe_characters() {
+    assert_eq!(instance_label("dev-box.lan"), "dev-boxlan");
+    assert_eq!(instance_label("..."), "planning-host");
+    assert_eq!(instance_label(&"a".repeat(80)).len(), 63);
+}
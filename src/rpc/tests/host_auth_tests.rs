@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn test_only_the_secret_answers_the_nonce() {
+    let nonce = new_nonce();
+    assert_eq!(nonce.len(), NONCE_LEN);
+    assert_ne!(nonce, new_nonce());
+
+    let proof = identity_proof("s3cret", &nonce);
+    assert!(verify_proof("s3cret", &nonce, &proof));
+    assert!(!verify_proof("guess", &nonce, &proof));
+    assert!(!verify_proof("s3cret", &new_nonce(), &proof));
+    assert!(!verify_proof("s3cret", &nonce, ""));
+}
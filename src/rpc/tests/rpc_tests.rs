@@ -20,6 +20,18 @@ fn test_daemon_error_display_already_registered() {
     );
 }
 
+#[test]
+fn test_daemon_error_display_lease_lost() {
+    let err = DaemonError::LeaseLost {
+        session_id: "sess-456".to_string(),
+        holder_pid: 4242,
+    };
+    assert_eq!(
+        format!("{}", err),
+        "Session sess-456 is being taken over by PID 4242"
+    );
+}
+
 #[test]
 fn test_daemon_error_display_shutting_down() {
     let err = DaemonError::ShuttingDown;
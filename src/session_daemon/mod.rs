@@ -13,6 +13,10 @@
 //! - **Client (`rpc_client.rs`)**: Connect-or-spawn client that registers sessions and
 //!   sends heartbeats using tarpc RPC.
 //! - **Subscription (`rpc_subscription.rs`)**: tarpc-based push notification subscriber.
+//! - **Sweeps (`sweeps/`)**: Subscriber pings and process liveness checks, paused
+//!   while no session is live; an idle daemon exits until a client needs it again.
+//! - **Schedules (`schedule.rs`)**: Persistent cron-like workflow definitions executed
+//!   by the daemon through detached headless workers.
 //! - **Protocol (`protocol.rs`)**: Message types and session records.
 
 pub mod file_service_impl;
@@ -21,10 +25,12 @@ pub mod rpc_client;
 pub mod rpc_server;
 pub mod rpc_subscription;
 pub mod rpc_upstream;
+pub mod schedule;
 pub mod server;
 pub mod session_logger;
 pub mod session_store;
 pub mod session_tracking;
+pub mod sweeps;
 
 #[cfg(test)]
 #[path = "tests/server_tests.rs"]
@@ -4,7 +4,7 @@
 //! The actual RPC service definitions are in the `crate::rpc` module.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Liveness state tracked by the daemon.
 ///
@@ -68,6 +68,12 @@ pub struct SessionRecord {
     /// Maximum implementation iterations. None when not in implementation workflow.
     #[serde(default)]
     pub implementation_max_iterations: Option<u32>,
+    /// Approval prompt the session waits on; host approvals must name it
+    #[serde(default)]
+    pub approval_request: Option<u64>,
+    /// Last host command the session dropped because its prompt was already answered
+    #[serde(default)]
+    pub stale_command: Option<String>,
 }
 
 impl SessionRecord {
@@ -99,6 +105,8 @@ impl SessionRecord {
             implementation_phase: None,
             implementation_iteration: None,
             implementation_max_iterations: None,
+            approval_request: None,
+            stale_command: None,
         }
     }
 
@@ -135,7 +143,8 @@ impl SessionRecord {
 }
 
 /// Port file content with authentication token.
-/// Used on all platforms for TCP-based RPC communication.
+/// Used on all platforms for TCP-based RPC communication; on Unix it also names the
+/// per-user socket.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortFileContent {
     /// Main RPC port
@@ -147,6 +156,29 @@ pub struct PortFileContent {
     pub file_service_port: u16,
     /// Authentication token
     pub token: String,
+    /// Per-user Unix socket for the main RPC service (Unix only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket_path: Option<PathBuf>,
+}
+
+impl PortFileContent {
+    /// Writes the port file so that only the daemon's user can read the token.
+    pub fn write_private(&self, path: &Path) -> std::io::Result<()> {
+        use std::io::Write;
+
+        // Recreate rather than truncate so a file left with wider permissions is not reused
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        options.open(path)?.write_all(json.as_bytes())
+    }
 }
 
 #[cfg(test)]
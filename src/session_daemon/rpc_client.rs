@@ -1,14 +1,20 @@
 //! RPC client for session daemon using tarpc.
 //!
 //! This module provides a tarpc-based client that replaces the old JSON-over-socket
-//! implementation. Uses TCP on all platforms for consistency.
+//! implementation. Uses TCP on all platforms, preferring the per-user Unix socket
+//! where available.
 
 use crate::daemon_log::daemon_log;
 use crate::planning_paths;
 use crate::rpc::daemon_service::DaemonServiceClient;
-use crate::rpc::{DaemonError, PortFileContent, SessionRecord, WorkflowEventEnvelope};
+use crate::rpc::host_service::RemoteCommand;
+use crate::rpc::{
+    DaemonError, PortFileContent, ScheduledWorkflow, SessionRecord, WorkflowEventEnvelope,
+};
+use crate::session_daemon::server::process_exists;
 use anyhow::{Context, Result};
 use fs2::FileExt;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 use tarpc::client;
@@ -40,6 +46,115 @@ struct ClientState {
     authenticated: bool,
 }
 
+/// Connects to the daemon's main RPC service described by the port file.
+///
+/// Prefers the per-user Unix socket, where the daemon verifies the peer's UID, and
+/// falls back to TCP. Either way the token must still be sent via `authenticate`.
+pub async fn connect_daemon(port_info: &PortFileContent) -> std::io::Result<DaemonServiceClient> {
+    #[cfg(unix)]
+    if let Some(socket_path) = &port_info.socket_path {
+        match crate::rpc::local_socket::connect(socket_path).await {
+            Ok(transport) => {
+                return Ok(DaemonServiceClient::new(client::Config::default(), transport).spawn())
+            }
+            Err(e) => daemon_log(
+                "rpc_client",
+                &format!("Socket connect failed, falling back to TCP: {}", e),
+            ),
+        }
+    }
+    let addr = format!("127.0.0.1:{}", port_info.port);
+    let transport = tarpc::serde_transport::tcp::connect(&addr, Bincode::default).await?;
+    Ok(DaemonServiceClient::new(client::Config::default(), transport).spawn())
+}
+
+/// Why the daemon recorded in the PID file is known to be gone, if it is.
+///
+/// The daemon writes its PID file before its port file, so a port file without a
+/// running PID behind it was left by a daemon that crashed or was killed.
+fn stale_daemon_reason(pid_path: &Path) -> Option<String> {
+    let Ok(content) = std::fs::read_to_string(pid_path) else {
+        return Some("no PID file".to_string());
+    };
+    match content.trim().parse::<u32>() {
+        Ok(pid) if process_exists(pid) => None,
+        Ok(pid) => Some(format!("daemon PID {} is not running", pid)),
+        Err(_) => Some("unreadable PID file".to_string()),
+    }
+}
+
+/// Adds one to the persistent stale recovery counter and returns the new total.
+fn record_stale_recovery(path: &Path) -> u64 {
+    let count = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.trim().parse::<u64>().ok())
+        .unwrap_or(0)
+        + 1;
+    // Best-effort: the counter is diagnostic only
+    let _ = std::fs::write(path, count.to_string());
+    count
+}
+
+/// Number of stale daemon recoveries recorded so far, for `--daemon-status`.
+pub fn stale_recovery_count() -> u64 {
+    planning_paths::sessiond_recoveries_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| content.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Removes the port, PID and build SHA files if the daemon behind them is dead.
+/// Returns true if it did, so the caller can respawn at once.
+fn recover_stale_daemon(failed_since: std::time::Instant) -> bool {
+    let Ok(pid_path) = planning_paths::sessiond_pid_path() else {
+        return false;
+    };
+    let Some(reason) = stale_daemon_reason(&pid_path) else {
+        return false;
+    };
+
+    // Failures are OK - files may already be deleted by a racing client
+    if let Ok(port_path) = planning_paths::sessiond_port_path() {
+        let _ = std::fs::remove_file(port_path);
+    }
+    let _ = std::fs::remove_file(&pid_path);
+    if let Ok(sha_path) = planning_paths::sessiond_build_sha_path() {
+        let _ = std::fs::remove_file(sha_path);
+    }
+
+    let count = planning_paths::sessiond_recoveries_path()
+        .map(|path| record_stale_recovery(&path))
+        .unwrap_or(0);
+    daemon_log(
+        "rpc_client",
+        &format!(
+            "Removed stale daemon port file ({}) {}ms after connecting started; {} stale recoveries so far",
+            reason,
+            failed_since.elapsed().as_millis(),
+            count
+        ),
+    );
+    true
+}
+
+/// Connects, authenticates and lists sessions without spawning a daemon.
+///
+/// Returns the number of registered sessions; used by diagnostics.
+pub async fn probe_daemon(port_info: &PortFileContent, timeout: Duration) -> Result<usize> {
+    let probe = async {
+        let client = connect_daemon(port_info).await?;
+        client
+            .authenticate(tarpc::context::current(), port_info.token.clone())
+            .await??;
+        let sessions = client.list(tarpc::context::current()).await??;
+        anyhow::Ok(sessions.len())
+    };
+    tokio::time::timeout(timeout, probe)
+        .await
+        .map_err(|_| anyhow::anyhow!("no response within {}s", timeout.as_secs()))?
+}
+
 impl RpcClient {
     /// Creates a new RPC client, connecting to or spawning the daemon.
     ///
@@ -137,6 +252,8 @@ impl RpcClient {
                 let sha = state.client.build_sha(tarpc::context::current()).await?;
                 Ok(sha)
             }
+            // Kept typed so the tracker can tell a takeover from a connection problem
+            Err(e @ DaemonError::LeaseLost { .. }) => Err(e.into()),
             Err(e) => anyhow::bail!("Daemon error: {}", e),
         }
     }
@@ -182,6 +299,49 @@ impl RpcClient {
         }
     }
 
+    /// Asks the process holding a session to hand it over to `pid`.
+    /// Returns the holder's PID, or None if no other running process holds it.
+    pub async fn take_over(&self, session_id: &str, pid: u32) -> Result<Option<u32>> {
+        if self.degraded {
+            return Ok(None);
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .take_over(tarpc::context::current(), session_id.to_string(), pid)
+            .await?
+        {
+            Ok(holder) => Ok(holder),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
+    /// Takes the commands the host relayed for a session.
+    pub async fn take_commands(&self, session_id: &str) -> Result<Vec<RemoteCommand>> {
+        if self.degraded {
+            return Ok(Vec::new());
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .take_commands(tarpc::context::current(), session_id.to_string())
+            .await?
+        {
+            Ok(commands) => Ok(commands),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
     /// Requests daemon shutdown (for updates).
     pub async fn shutdown(&self) -> Result<String> {
         if self.degraded {
@@ -243,6 +403,93 @@ impl RpcClient {
         }
     }
 
+    /// Adds a scheduled workflow to the daemon.
+    ///
+    /// Unlike session tracking, schedules require a running daemon, so this
+    /// fails in degraded mode.
+    pub async fn schedule_add(&self, schedule: ScheduledWorkflow) -> Result<()> {
+        if self.degraded {
+            anyhow::bail!("Session daemon is not available");
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .schedule_add(tarpc::context::current(), schedule)
+            .await?
+        {
+            Ok(()) => Ok(()),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
+    /// Lists scheduled workflows from the daemon.
+    pub async fn schedule_list(&self) -> Result<Vec<ScheduledWorkflow>> {
+        if self.degraded {
+            anyhow::bail!("Session daemon is not available");
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .schedule_list(tarpc::context::current())
+            .await?
+        {
+            Ok(schedules) => Ok(schedules),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
+    /// Removes a scheduled workflow from the daemon.
+    pub async fn schedule_remove(&self, schedule_id: &str) -> Result<()> {
+        if self.degraded {
+            anyhow::bail!("Session daemon is not available");
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .schedule_remove(tarpc::context::current(), schedule_id.to_string())
+            .await?
+        {
+            Ok(()) => Ok(()),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
+    /// Shares a session through the host relay and returns its share token.
+    pub async fn share_session(&self, session_id: &str) -> Result<String> {
+        if self.degraded {
+            anyhow::bail!("Session daemon is not available");
+        }
+
+        let mut guard = self.inner.lock().await;
+        let state = guard.as_mut().context("Not connected to daemon")?;
+
+        self.ensure_authenticated(state).await?;
+
+        match state
+            .client
+            .share_session(tarpc::context::current(), session_id.to_string())
+            .await?
+        {
+            Ok(token) => Ok(token),
+            Err(e) => anyhow::bail!("Daemon error: {}", e),
+        }
+    }
+
     /// Ensure the client is authenticated before making RPC calls.
     async fn ensure_authenticated(&self, state: &mut ClientState) -> Result<()> {
         if state.authenticated {
@@ -273,7 +520,12 @@ impl RpcClient {
         if port_path.exists() {
             if let Ok(content) = std::fs::read_to_string(&port_path) {
                 if let Ok(port_info) = serde_json::from_str::<PortFileContent>(&content) {
-                    if let Ok(state) = Self::try_connect(&port_info).await {
+                    let started = std::time::Instant::now();
+                    let connected = Self::try_connect(&port_info).await;
+                    if connected.is_err() {
+                        recover_stale_daemon(started);
+                    }
+                    if let Ok(state) = connected {
                         // Check if we should upgrade the daemon (only if we're newer)
                         let our_timestamp = crate::update::BUILD_TIMESTAMP;
                         let daemon_timestamp = state
@@ -330,6 +582,22 @@ impl RpcClient {
             }
         }
 
+        // A daemon that dies before accepting connections is respawned once
+        let started = std::time::Instant::now();
+        match Self::spawn_and_connect(&port_path).await {
+            Err(e) if recover_stale_daemon(started) => {
+                daemon_log(
+                    "rpc_client",
+                    &format!("Daemon died during connect ({}), respawning", e),
+                );
+                Self::spawn_and_connect(&port_path).await
+            }
+            result => result,
+        }
+    }
+
+    /// Spawn the daemon (or wait for another client spawning it) and connect.
+    async fn spawn_and_connect(port_path: &Path) -> Result<ClientState> {
         // Need to spawn daemon - acquire lock
         let lock_path = planning_paths::sessiond_lock_path()?;
         let lock_file = std::fs::OpenOptions::new()
@@ -349,7 +617,7 @@ impl RpcClient {
             }
             Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
                 // Another process is spawning - wait for daemon
-                Self::wait_for_port_file(&port_path).await?;
+                Self::wait_for_port_file(port_path).await?;
             }
             Err(e) => {
                 return Err(e).context("Failed to acquire lock");
@@ -357,19 +625,13 @@ impl RpcClient {
         }
 
         // Connect with retry
-        Self::connect_with_retry(&port_path).await
+        Self::connect_with_retry(port_path).await
     }
 
     /// Try to connect to daemon using port info.
     async fn try_connect(port_info: &PortFileContent) -> Result<ClientState> {
-        use tarpc::serde_transport::tcp;
-
-        let addr = format!("127.0.0.1:{}", port_info.port);
-        let transport = tcp::connect(&addr, Bincode::default).await?;
-        let client = DaemonServiceClient::new(client::Config::default(), transport).spawn();
-
         Ok(ClientState {
-            client,
+            client: connect_daemon(port_info).await?,
             auth_token: port_info.token.clone(),
             authenticated: false,
         })
@@ -458,16 +720,19 @@ impl RpcClient {
         Self::wait_for_port_file(&port_path).await
     }
 
-    /// Wait for port file to appear.
-    async fn wait_for_port_file(port_path: &std::path::Path) -> Result<()> {
+    /// Wait for a port file with a running daemon behind it to appear.
+    async fn wait_for_port_file(port_path: &Path) -> Result<()> {
         let start = std::time::Instant::now();
         let timeout = Duration::from_millis(DAEMON_INIT_TIMEOUT_MS);
+        let pid_path = planning_paths::sessiond_pid_path()?;
 
         while start.elapsed() < timeout {
             if port_path.exists() {
-                // Verify we can read and parse it
+                // Verify we can read and parse it, and that it is not a crashed daemon's
                 if let Ok(content) = std::fs::read_to_string(port_path) {
-                    if serde_json::from_str::<PortFileContent>(&content).is_ok() {
+                    if serde_json::from_str::<PortFileContent>(&content).is_ok()
+                        && stale_daemon_reason(&pid_path).is_none()
+                    {
                         return Ok(());
                     }
                 }
@@ -479,8 +744,10 @@ impl RpcClient {
     }
 
     /// Connect with retry and exponential backoff.
-    async fn connect_with_retry(port_path: &std::path::Path) -> Result<ClientState> {
+    /// Gives up early once the daemon is known to be dead.
+    async fn connect_with_retry(port_path: &Path) -> Result<ClientState> {
         let mut delay_ms = BASE_DELAY_MS;
+        let pid_path = planning_paths::sessiond_pid_path()?;
 
         for attempt in 1..=MAX_CONNECT_ATTEMPTS {
             if let Ok(content) = std::fs::read_to_string(port_path) {
@@ -491,6 +758,10 @@ impl RpcClient {
                 }
             }
 
+            if let Some(reason) = stale_daemon_reason(&pid_path) {
+                anyhow::bail!("Daemon is not running ({})", reason);
+            }
+
             if attempt < MAX_CONNECT_ATTEMPTS {
                 // Add jitter (±25%)
                 let jitter = (delay_ms as f64 * 0.25 * (rand::random::<f64>() * 2.0 - 1.0)) as i64;
@@ -503,3 +774,7 @@ impl RpcClient {
         anyhow::bail!("Failed to connect after {} attempts", MAX_CONNECT_ATTEMPTS)
     }
 }
+
+#[cfg(test)]
+#[path = "tests/rpc_client_tests.rs"]
+mod tests;
@@ -6,136 +6,23 @@ use crate::daemon_log::daemon_log;
 use crate::planning_paths;
 use crate::rpc::daemon_file_service::DaemonFileService;
 use crate::rpc::daemon_service::{DaemonService, SubscriberCallbackClient};
+use crate::rpc::host_service::RemoteCommand;
 use crate::rpc::{
-    DaemonError, DaemonResult, LivenessState, PortFileContent, SessionRecord, WorkflowEventEnvelope,
+    DaemonError, DaemonResult, LivenessState, PortFileContent, ScheduledWorkflow, SessionRecord,
+    WorkflowEventEnvelope,
 };
 use crate::session_daemon::file_service_impl::DaemonFileServer;
 use crate::session_daemon::rpc_upstream::UpstreamEvent;
-use crate::session_daemon::server::DaemonState;
+use crate::session_daemon::schedule::{run_schedule_executor, ScheduleRegistry};
+use crate::session_daemon::server::{DaemonState, SubscriberRegistry};
+use crate::session_daemon::sweeps::{run_process_liveness_monitor, run_subscriber_cleanup};
 use crate::update::{BUILD_SHA, BUILD_TIMESTAMP};
 use futures::StreamExt;
-use std::collections::HashMap;
 use std::sync::Arc;
 use tarpc::server::{self, Channel};
 use tarpc::tokio_serde::formats::Bincode;
 use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 
-/// Unique subscriber ID for tracking connected subscribers.
-type SubscriberId = u64;
-
-/// Subscriber tracking - stores callback clients for push notifications.
-pub struct SubscriberRegistry {
-    /// Map of subscriber ID to their callback client.
-    subscribers: HashMap<SubscriberId, SubscriberCallbackClient>,
-    /// Next subscriber ID to assign.
-    next_id: SubscriberId,
-}
-
-impl SubscriberRegistry {
-    pub fn new() -> Self {
-        Self {
-            subscribers: HashMap::new(),
-            next_id: 0,
-        }
-    }
-
-    /// Add a new subscriber with their callback client. Returns the assigned ID.
-    pub fn add(&mut self, client: SubscriberCallbackClient) -> SubscriberId {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.subscribers.insert(id, client);
-        id
-    }
-
-    /// Remove a subscriber by ID.
-    pub fn remove(&mut self, id: &SubscriberId) {
-        self.subscribers.remove(id);
-    }
-
-    /// Broadcast a session change to all subscribers.
-    /// Returns IDs of failed subscribers for cleanup.
-    pub async fn broadcast_session_changed(&self, record: SessionRecord) -> Vec<SubscriberId> {
-        let mut failed = Vec::new();
-
-        for (id, client) in &self.subscribers {
-            if client
-                .session_changed(tarpc::context::current(), record.clone())
-                .await
-                .is_err()
-            {
-                failed.push(*id);
-            }
-        }
-
-        failed
-    }
-
-    /// Broadcast daemon restart to all subscribers.
-    pub async fn broadcast_restarting(&self, new_sha: String) -> Vec<SubscriberId> {
-        let mut failed = Vec::new();
-
-        for (id, client) in &self.subscribers {
-            if client
-                .daemon_restarting(tarpc::context::current(), new_sha.clone())
-                .await
-                .is_err()
-            {
-                failed.push(*id);
-            }
-        }
-
-        failed
-    }
-
-    /// Get count of active subscribers.
-    pub fn count(&self) -> usize {
-        self.subscribers.len()
-    }
-
-    /// Broadcast a workflow event to all subscribers.
-    /// Returns IDs of failed subscribers for cleanup.
-    pub async fn broadcast_workflow_event(
-        &self,
-        session_id: String,
-        event: WorkflowEventEnvelope,
-    ) -> Vec<SubscriberId> {
-        let mut failed = Vec::new();
-
-        for (id, client) in &self.subscribers {
-            if client
-                .workflow_event(tarpc::context::current(), session_id.clone(), event.clone())
-                .await
-                .is_err()
-            {
-                failed.push(*id);
-            }
-        }
-
-        failed
-    }
-
-    /// Ping all subscribers to check if they're alive.
-    /// Returns IDs of subscribers that failed to respond.
-    pub async fn ping_all(&self) -> Vec<SubscriberId> {
-        let mut failed = Vec::new();
-
-        for (id, client) in &self.subscribers {
-            match client.ping(tarpc::context::current()).await {
-                Ok(true) => {} // Healthy
-                _ => failed.push(*id),
-            }
-        }
-
-        failed
-    }
-}
-
-impl Default for SubscriberRegistry {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// Server implementation for DaemonService.
 #[derive(Clone)]
 pub struct DaemonServer {
@@ -279,9 +166,12 @@ impl DaemonService for DaemonServer {
                 }
             }
 
+            // A registration completes any takeover of the session
+            state.handoffs.remove(&record.workflow_session_id);
             state
                 .sessions
                 .insert(record.workflow_session_id.clone(), record);
+            state.activity.send_replace(());
             daemon_log(
                 "rpc_server",
                 &format!("Session stored, total sessions: {}", state.sessions.len()),
@@ -311,6 +201,9 @@ impl DaemonService for DaemonServer {
                         record.iteration,
                         record.workflow_status.clone(),
                     );
+                    existing.feature_name = record.feature_name.clone();
+                    existing.approval_request = record.approval_request;
+                    existing.stale_command = record.stale_command.clone();
                     if record.liveness == LivenessState::Stopped {
                         existing.liveness = LivenessState::Stopped;
                     }
@@ -329,20 +222,28 @@ impl DaemonService for DaemonServer {
     async fn heartbeat(self, _: tarpc::context::Context, session_id: String) -> DaemonResult<()> {
         self.check_authenticated().await?;
 
-        let maybe_record = {
+        let (maybe_record, handoff) = {
             let mut state = self.state.lock().await;
+            let handoff = state.handoffs.get(&session_id).copied();
             if let Some(record) = state.sessions.get_mut(&session_id) {
+                // The holder stays live while it winds down after a takeover request
                 record.update_heartbeat();
-                Some(record.clone())
+                (Some(record.clone()), handoff)
             } else {
-                None
+                (None, handoff)
             }
         };
 
         match maybe_record {
             Some(record) => {
                 self.notify_subscribers(record).await;
-                Ok(())
+                match handoff {
+                    Some(holder_pid) => Err(DaemonError::LeaseLost {
+                        session_id,
+                        holder_pid,
+                    }),
+                    None => Ok(()),
+                }
             }
             None => Err(DaemonError::SessionNotFound {
                 session_id: session_id.clone(),
@@ -380,6 +281,48 @@ impl DaemonService for DaemonServer {
         }
     }
 
+    async fn take_over(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+        pid: u32,
+    ) -> DaemonResult<Option<u32>> {
+        self.check_authenticated().await?;
+
+        let mut state = self.state.lock().await;
+        state.update_liveness_states();
+        let holder = state
+            .sessions
+            .get(&session_id)
+            .filter(|record| record.pid != pid && record.liveness != LivenessState::Stopped)
+            .map(|record| record.pid);
+        if let Some(holder_pid) = holder {
+            daemon_log(
+                "rpc_server",
+                &format!(
+                    "PID {} is taking over session {} from PID {}",
+                    pid, session_id, holder_pid
+                ),
+            );
+            state.handoffs.insert(session_id, pid);
+        }
+        Ok(holder)
+    }
+
+    async fn take_commands(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+    ) -> DaemonResult<Vec<RemoteCommand>> {
+        self.check_authenticated().await?;
+
+        let mut state = self.state.lock().await;
+        Ok(state
+            .remote_commands
+            .remove(&session_id)
+            .unwrap_or_default())
+    }
+
     async fn shutdown(self, _: tarpc::context::Context) -> DaemonResult<()> {
         self.check_authenticated().await?;
 
@@ -463,6 +406,20 @@ impl DaemonService for DaemonServer {
     ) -> DaemonResult<()> {
         self.check_authenticated().await?;
 
+        // Journal the event so subscribers and the host can catch up after reconnecting
+        self.state
+            .lock()
+            .await
+            .journal
+            .record(&session_id, event.clone());
+        if let Some(upstream_tx) = &self.upstream_tx {
+            // Channel send can fail if upstream receiver dropped - safe to ignore
+            let _ = upstream_tx.send(UpstreamEvent::WorkflowEvent(
+                session_id.clone(),
+                event.clone(),
+            ));
+        }
+
         // Broadcast to all subscribers
         let failed = {
             let registry = self.subscribers.read().await;
@@ -485,6 +442,104 @@ impl DaemonService for DaemonServer {
 
         Ok(())
     }
+
+    async fn schedule_add(
+        self,
+        _: tarpc::context::Context,
+        schedule: ScheduledWorkflow,
+    ) -> DaemonResult<()> {
+        self.check_authenticated().await?;
+
+        daemon_log(
+            "rpc_server",
+            &format!(
+                "Adding schedule {} ({}) for {}",
+                schedule.id,
+                schedule.spec,
+                schedule.working_dir.display()
+            ),
+        );
+
+        let mut state = self.state.lock().await;
+        state.schedules.add(schedule);
+        state
+            .schedules
+            .persist()
+            .map_err(|e| DaemonError::Internal {
+                message: format!("Failed to persist schedules: {}", e),
+            })?;
+        state.schedule_changed.notify_one();
+        state.activity.send_replace(());
+        Ok(())
+    }
+
+    async fn schedule_list(
+        self,
+        _: tarpc::context::Context,
+    ) -> DaemonResult<Vec<ScheduledWorkflow>> {
+        self.check_authenticated().await?;
+
+        let state = self.state.lock().await;
+        Ok(state.schedules.list())
+    }
+
+    async fn schedule_remove(
+        self,
+        _: tarpc::context::Context,
+        schedule_id: String,
+    ) -> DaemonResult<()> {
+        self.check_authenticated().await?;
+
+        let mut state = self.state.lock().await;
+        if !state.schedules.remove(&schedule_id) {
+            return Err(DaemonError::ScheduleNotFound { schedule_id });
+        }
+        state
+            .schedules
+            .persist()
+            .map_err(|e| DaemonError::Internal {
+                message: format!("Failed to persist schedules: {}", e),
+            })?;
+        state.schedule_changed.notify_one();
+        daemon_log("rpc_server", &format!("Removed schedule {}", schedule_id));
+        Ok(())
+    }
+
+    async fn share_session(
+        self,
+        _: tarpc::context::Context,
+        session_id: String,
+    ) -> DaemonResult<String> {
+        self.check_authenticated().await?;
+
+        let Some(upstream_tx) = &self.upstream_tx else {
+            return Err(DaemonError::Internal {
+                message: "Host relay is disabled (PLANNING_AGENT_HOST_PORT=0)".to_string(),
+            });
+        };
+
+        let token = {
+            let mut state = self.state.lock().await;
+            if !state.sessions.contains_key(&session_id) {
+                return Err(DaemonError::SessionNotFound { session_id });
+            }
+            state
+                .shares
+                .entry(session_id.clone())
+                .or_insert_with(|| {
+                    rand::Rng::sample_iter(rand::thread_rng(), &rand::distributions::Alphanumeric)
+                        .take(24)
+                        .map(char::from)
+                        .collect()
+                })
+                .clone()
+        };
+
+        daemon_log("rpc_server", &format!("Sharing session {}", session_id));
+        // Channel send can fail if upstream receiver dropped - safe to ignore
+        let _ = upstream_tx.send(UpstreamEvent::ShareSession(session_id, token.clone()));
+        Ok(token)
+    }
 }
 
 /// Run the daemon RPC server (TCP - all platforms).
@@ -541,6 +596,84 @@ pub async fn run_daemon_server(
     Ok(())
 }
 
+/// Run the daemon RPC server on the per-user Unix socket.
+///
+/// Connections from a different UID are dropped before any RPC is served; the
+/// auth token is still required, exactly as on the TCP listener.
+#[cfg(unix)]
+pub async fn run_daemon_socket_server(
+    state: Arc<Mutex<DaemonState>>,
+    subscribers: Arc<RwLock<SubscriberRegistry>>,
+    shutdown_tx: broadcast::Sender<()>,
+    upstream_tx: Option<mpsc::UnboundedSender<UpstreamEvent>>,
+    auth_token: String,
+    socket_path: std::path::PathBuf,
+) -> anyhow::Result<()> {
+    use crate::rpc::local_socket;
+
+    let listener = local_socket::bind(&socket_path)?;
+    let own_uid = local_socket::current_uid();
+
+    daemon_log(
+        "rpc_server",
+        &format!("RPC server listening on {}", socket_path.display()),
+    );
+
+    let mut shutdown_rx = shutdown_tx.subscribe();
+
+    loop {
+        tokio::select! {
+            result = listener.accept() => {
+                let stream = match result {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        daemon_log("rpc_server", &format!("Socket accept error: {}", e));
+                        continue;
+                    }
+                };
+                match local_socket::peer_uid(&stream) {
+                    Ok(uid) if uid == own_uid => {}
+                    Ok(uid) => {
+                        daemon_log(
+                            "rpc_server",
+                            &format!("Rejected socket connection from uid {}", uid),
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        daemon_log(
+                            "rpc_server",
+                            &format!("Rejected socket connection without credentials: {}", e),
+                        );
+                        continue;
+                    }
+                }
+
+                let server = DaemonServer::new(
+                    state.clone(),
+                    subscribers.clone(),
+                    shutdown_tx.clone(),
+                    upstream_tx.clone(),
+                ).with_auth_token(auth_token.clone());
+
+                let channel = server::BaseChannel::with_defaults(local_socket::transport(stream));
+
+                tokio::spawn(async move {
+                    channel.execute(server.serve()).for_each(|response| async {
+                        tokio::spawn(response);
+                    }).await;
+                });
+            }
+            _ = shutdown_rx.recv() => {
+                break;
+            }
+        }
+    }
+
+    let _ = std::fs::remove_file(&socket_path);
+    Ok(())
+}
+
 /// Run the subscriber listener (TCP - all platforms).
 ///
 /// Security model: Since the port binds to 127.0.0.1 (localhost only), and
@@ -548,6 +681,7 @@ pub async fn run_daemon_server(
 /// machine, any process that can read the token can connect.
 pub async fn run_subscriber_listener(
     subscribers: Arc<RwLock<SubscriberRegistry>>,
+    state: Arc<Mutex<DaemonState>>,
     shutdown_tx: broadcast::Sender<()>,
     subscriber_port: u16,
 ) -> anyhow::Result<()> {
@@ -581,7 +715,10 @@ pub async fn run_subscriber_listener(
 
                         let subscriber_id = {
                             let mut registry = subscribers.write().await;
-                            registry.add(callback_client)
+                            let catch_up = state.lock().await.journal.catch_up();
+                            let id = registry.add_with_replay(callback_client, catch_up).await;
+                            state.lock().await.activity.send_replace(());
+                            id
                         };
 
                         daemon_log("rpc_server", &format!("Subscriber connected: {}", subscriber_id));
@@ -649,113 +786,6 @@ pub async fn run_file_service_listener(
     Ok(())
 }
 
-/// Background task to periodically clean up dead subscriber connections.
-/// Sends a ping to each subscriber and removes those that don't respond.
-pub async fn run_subscriber_cleanup(
-    subscribers: Arc<RwLock<SubscriberRegistry>>,
-    shutdown_tx: broadcast::Sender<()>,
-) {
-    let mut shutdown_rx = shutdown_tx.subscribe();
-    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
-
-    loop {
-        tokio::select! {
-            _ = interval.tick() => {
-                // Ping all subscribers and collect those that failed
-                let failed = {
-                    let registry = subscribers.read().await;
-                    if registry.count() == 0 {
-                        continue;
-                    }
-                    registry.ping_all().await
-                };
-
-                // Remove failed subscribers
-                if !failed.is_empty() {
-                    let mut registry = subscribers.write().await;
-                    for id in &failed {
-                        registry.remove(id);
-                        daemon_log("rpc_server", &format!("Cleanup: removed dead subscriber {}", id));
-                    }
-                    daemon_log("rpc_server", &format!("Cleanup: {} dead subscribers removed, {} remaining",
-                        failed.len(), registry.count()));
-                }
-            }
-            _ = shutdown_rx.recv() => {
-                break;
-            }
-        }
-    }
-}
-
-/// Background task to periodically check if session-owning processes have exited.
-/// This provides instant detection of crashed/killed processes without waiting for
-/// heartbeat timeouts. Runs every 500ms to match the heartbeat interval.
-///
-/// NOTE: This task sends SessionUpdate through subscribers and upstream_tx.
-/// The notify_subscribers() method always sends SessionUpdate (not SessionGone),
-/// keeping disconnected sessions visible in the host GUI.
-pub async fn run_process_liveness_monitor(
-    state: Arc<Mutex<DaemonState>>,
-    subscribers: Arc<RwLock<SubscriberRegistry>>,
-    upstream_tx: Option<mpsc::UnboundedSender<UpstreamEvent>>,
-    shutdown_tx: broadcast::Sender<()>,
-) {
-    let mut shutdown_rx = shutdown_tx.subscribe();
-    // Check every 500ms to match heartbeat interval
-    let mut interval = tokio::time::interval(std::time::Duration::from_millis(500));
-
-    loop {
-        tokio::select! {
-            _ = interval.tick() => {
-                // Check for dead processes
-                let changed = {
-                    let mut state = state.lock().await;
-                    state.check_process_liveness()
-                };
-
-                // Notify subscribers and upstream for each changed session
-                for record in changed {
-                    daemon_log(
-                        "liveness_monitor",
-                        &format!(
-                            "Process {} exited, marking session {} as Stopped",
-                            record.pid, record.workflow_session_id
-                        ),
-                    );
-
-                    // Notify local subscribers
-                    {
-                        let failed = {
-                            let registry = subscribers.read().await;
-                            registry.broadcast_session_changed(record.clone()).await
-                        };
-                        if !failed.is_empty() {
-                            let mut registry = subscribers.write().await;
-                            for id in failed {
-                                registry.remove(&id);
-                            }
-                        }
-                    }
-
-                    // Forward to upstream host as SessionUpdate.
-                    // Note: We send SessionUpdate directly here rather than going through
-                    // a shared method to avoid re-acquiring locks. The effect is the same
-                    // since we always send SessionUpdate for liveness changes.
-                    if let Some(ref tx) = upstream_tx {
-                        // Channel send can fail if upstream receiver dropped (e.g., during shutdown).
-                        // This is expected and safe to ignore.
-                        let _ = tx.send(UpstreamEvent::SessionUpdate(record));
-                    }
-                }
-            }
-            _ = shutdown_rx.recv() => {
-                break;
-            }
-        }
-    }
-}
-
 /// Find an available TCP port.
 pub async fn find_available_port() -> anyhow::Result<u16> {
     use tokio::net::TcpListener;
@@ -797,6 +827,13 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
                 &format!("Warning: Failed to load registry: {}", e),
             );
         }
+        match ScheduleRegistry::load() {
+            Ok(schedules) => state_guard.schedules = schedules,
+            Err(e) => daemon_log(
+                "rpc_server",
+                &format!("Warning: Failed to load schedules: {}", e),
+            ),
+        }
     }
 
     // Create shutdown broadcast channel
@@ -814,6 +851,12 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
     let subscriber_port = find_available_port().await?;
     let file_service_port = find_available_port().await?;
 
+    // Unix also serves the main RPC on a per-user socket with peer-credential checks
+    #[cfg(unix)]
+    let socket_path = Some(planning_paths::sessiond_socket_path()?);
+    #[cfg(not(unix))]
+    let socket_path: Option<std::path::PathBuf> = None;
+
     // Write port file with PortFileContent
     let port_path = planning_paths::sessiond_port_path()?;
     let port_content = PortFileContent {
@@ -821,8 +864,11 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
         subscriber_port,
         file_service_port,
         token: auth_token.clone(),
+        socket_path: socket_path.clone(),
     };
-    std::fs::write(&port_path, serde_json::to_string(&port_content)?)?;
+    port_content
+        .write_private(&port_path)
+        .context("Failed to write port file")?;
 
     daemon_log(
         "rpc_server",
@@ -860,10 +906,11 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
 
     // Spawn subscriber listener
     let sub_subscribers = subscribers.clone();
+    let sub_state = state.clone();
     let sub_shutdown = shutdown_tx.clone();
     tokio::spawn(async move {
         if let Err(e) =
-            run_subscriber_listener(sub_subscribers, sub_shutdown, subscriber_port).await
+            run_subscriber_listener(sub_subscribers, sub_state, sub_shutdown, subscriber_port).await
         {
             daemon_log("rpc_server", &format!("Subscriber listener error: {}", e));
         }
@@ -879,12 +926,13 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
 
     // Spawn subscriber cleanup task
     let cleanup_subscribers = subscribers.clone();
+    let cleanup_activity = state.lock().await.activity.subscribe();
     let cleanup_shutdown = shutdown_tx.clone();
     tokio::spawn(async move {
-        run_subscriber_cleanup(cleanup_subscribers, cleanup_shutdown).await;
+        run_subscriber_cleanup(cleanup_subscribers, cleanup_activity, cleanup_shutdown).await;
     });
 
-    // Spawn process liveness monitor (checks for dead processes every 500ms)
+    // Spawn process liveness monitor (checks for dead processes every 500ms, waits out idle periods)
     {
         let monitor_state = state.clone();
         let monitor_subscribers = subscribers.clone();
@@ -901,6 +949,38 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
         });
     }
 
+    // Spawn schedule executor (launches headless workers for due schedules)
+    {
+        let schedule_state = state.clone();
+        let schedule_shutdown_tx = shutdown_tx.clone();
+        tokio::spawn(async move {
+            run_schedule_executor(schedule_state, schedule_shutdown_tx).await;
+        });
+    }
+
+    #[cfg(unix)]
+    if let Some(socket_path) = socket_path {
+        let socket_state = state.clone();
+        let socket_subscribers = subscribers.clone();
+        let socket_shutdown = shutdown_tx.clone();
+        let socket_upstream = upstream_tx.clone();
+        let socket_token = auth_token.clone();
+        tokio::spawn(async move {
+            if let Err(e) = run_daemon_socket_server(
+                socket_state,
+                socket_subscribers,
+                socket_shutdown,
+                socket_upstream,
+                socket_token,
+                socket_path,
+            )
+            .await
+            {
+                daemon_log("rpc_server", &format!("Socket server error: {}", e));
+            }
+        });
+    }
+
     // Run main RPC server (blocks until shutdown)
     run_daemon_server(
         state.clone(),
@@ -927,6 +1007,10 @@ pub async fn run_daemon_rpc() -> anyhow::Result<()> {
     // by another process or may not exist.
     let _ = std::fs::remove_file(&port_path);
     let _ = std::fs::remove_file(&pid_path);
+    #[cfg(unix)]
+    if let Ok(socket_path) = planning_paths::sessiond_socket_path() {
+        let _ = std::fs::remove_file(socket_path);
+    }
 
     Ok(())
 }
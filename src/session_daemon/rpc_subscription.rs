@@ -1,17 +1,53 @@
 //! RPC subscription for receiving push notifications from daemon.
 //!
 //! This module provides `RpcSubscription` which connects to the daemon's
-//! subscriber port and receives push notifications via tarpc callbacks.
+//! subscriber port and receives push notifications via tarpc callbacks, and
+//! `ReconnectBackoff` to pace reconnection attempts when the daemon is away.
 
 use crate::daemon_log::daemon_log;
 use crate::planning_paths;
-use crate::rpc::daemon_service::{DaemonServiceClient, SubscriberCallback};
+use crate::rpc::daemon_service::SubscriberCallback;
 use crate::rpc::{PortFileContent, SessionRecord, WorkflowEventEnvelope};
+use crate::session_daemon::rpc_client::connect_daemon;
 use futures::StreamExt;
+use std::time::Duration;
 use tarpc::server::{self, Channel};
 use tarpc::tokio_serde::formats::Bincode;
 use tokio::sync::mpsc;
 
+/// Delay before the first reconnection attempt after a failure.
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest delay between reconnection attempts.
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Exponential backoff with ±25% jitter for subscription reconnects.
+///
+/// The delay doubles with each consecutive failure up to `RECONNECT_MAX_DELAY`;
+/// jitter keeps several TUIs from retrying in lockstep after the daemon restarts.
+#[derive(Debug, Default)]
+pub struct ReconnectBackoff {
+    failures: u32,
+}
+
+impl ReconnectBackoff {
+    /// Delay to wait after another failed attempt.
+    pub fn next_delay(&mut self) -> Duration {
+        let exponential = RECONNECT_BASE_DELAY.saturating_mul(1 << self.failures.min(16));
+        self.failures = self.failures.saturating_add(1);
+        let jitter = 0.75 + rand::random::<f64>() * 0.5;
+        exponential
+            .min(RECONNECT_MAX_DELAY)
+            .mul_f64(jitter)
+            .min(RECONNECT_MAX_DELAY)
+    }
+
+    /// Starts over from the base delay after a successful connection.
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+}
+
 /// Events received from daemon via subscription.
 #[derive(Debug, Clone)]
 pub enum SubscriptionEvent {
@@ -88,8 +124,8 @@ impl RpcSubscription {
     ///
     /// Returns None if unable to connect (daemon not running or connection failed).
     pub async fn connect() -> Option<Self> {
+        use tarpc::context;
         use tarpc::serde_transport::tcp;
-        use tarpc::{client, context};
 
         daemon_log("rpc_subscription", "connect() called");
 
@@ -107,9 +143,8 @@ impl RpcSubscription {
         );
 
         // First, authenticate via main RPC to establish trust
-        let main_addr = format!("127.0.0.1:{}", port_info.port);
-        let auth_transport = match tcp::connect(&main_addr, Bincode::default).await {
-            Ok(t) => t,
+        let auth_client = match connect_daemon(&port_info).await {
+            Ok(client) => client,
             Err(e) => {
                 daemon_log(
                     "rpc_subscription",
@@ -119,9 +154,6 @@ impl RpcSubscription {
             }
         };
 
-        let auth_client =
-            DaemonServiceClient::new(client::Config::default(), auth_transport).spawn();
-
         // Authenticate - this validates we have the correct token
         match auth_client
             .authenticate(context::current(), port_info.token.clone())
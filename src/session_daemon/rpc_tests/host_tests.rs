@@ -92,6 +92,8 @@ async fn test_upstream_session_update_flow() {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     }];
     client
         .sync_sessions(tarpc::context::current(), sessions)
@@ -118,6 +120,8 @@ async fn test_upstream_session_update_flow() {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     };
     client
         .session_update(tarpc::context::current(), updated)
@@ -258,6 +262,8 @@ async fn test_upstream_client_sends_session_updates() {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     };
     client
         .session_update(tarpc::context::current(), session_info)
@@ -307,6 +313,8 @@ async fn test_upstream_client_sync_sessions() {
             implementation_phase: None,
             implementation_iteration: None,
             implementation_max_iterations: None,
+            approval_request: None,
+            stale_command: None,
         },
         SessionInfo {
             session_id: "sync-2".to_string(),
@@ -321,6 +329,8 @@ async fn test_upstream_client_sync_sessions() {
             implementation_phase: None,
             implementation_iteration: None,
             implementation_max_iterations: None,
+            approval_request: None,
+            stale_command: None,
         },
         SessionInfo {
             session_id: "sync-3".to_string(),
@@ -335,6 +345,8 @@ async fn test_upstream_client_sync_sessions() {
             implementation_phase: None,
             implementation_iteration: None,
             implementation_max_iterations: None,
+            approval_request: None,
+            stale_command: None,
         },
     ];
     client
@@ -391,6 +403,8 @@ async fn test_upstream_client_session_gone() {
         implementation_phase: None,
         implementation_iteration: None,
         implementation_max_iterations: None,
+        approval_request: None,
+        stale_command: None,
     };
     client
         .sync_sessions(tarpc::context::current(), vec![session])
@@ -500,8 +514,9 @@ async fn test_full_stack_client_to_daemon_to_host() {
     use super::find_test_port;
     use crate::rpc::daemon_service::DaemonServiceClient;
     use crate::rpc::SessionRecord;
-    use crate::session_daemon::rpc_server::{run_daemon_server, SubscriberRegistry};
+    use crate::session_daemon::rpc_server::run_daemon_server;
     use crate::session_daemon::server::DaemonState;
+    use crate::session_daemon::server::SubscriberRegistry;
     use std::sync::Arc;
     use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 
@@ -57,7 +57,7 @@ pub struct TestServer {
 impl TestServer {
     /// Start a real RPC server for testing.
     pub async fn start() -> Self {
-        use crate::session_daemon::rpc_server::SubscriberRegistry;
+        use crate::session_daemon::server::SubscriberRegistry;
 
         let port = find_test_port();
         let subscriber_port = find_test_port();
@@ -89,9 +89,11 @@ impl TestServer {
         // Start subscriber listener
         let subscriber_handle = {
             let subscribers = subscribers.clone();
+            let state = state.clone();
             let shutdown_tx = shutdown_tx.clone();
             tokio::spawn(async move {
-                let _ = run_subscriber_listener(subscribers, shutdown_tx, subscriber_port).await;
+                let _ =
+                    run_subscriber_listener(subscribers, state, shutdown_tx, subscriber_port).await;
             })
         };
 
@@ -124,6 +126,7 @@ impl TestServer {
             subscriber_port: self.subscriber_port,
             file_service_port: 0,
             token: self.auth_token.clone(),
+            socket_path: None,
         };
         std::fs::write(path, serde_json::to_string(&content).unwrap()).unwrap();
     }
@@ -381,6 +381,83 @@ async fn test_error_already_registered() {
     }
 }
 
+#[tokio::test]
+async fn test_take_over_waits_for_holder_release() {
+    let server = TestServer::start().await;
+    let client = server.create_client().await;
+
+    client
+        .authenticate(tarpc::context::current(), server.auth_token.clone())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Nobody holds an unknown session
+    let holder = client
+        .take_over(tarpc::context::current(), "session-1".to_string(), 2000)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(holder, None);
+
+    let record1 = create_test_record("session-1", 1000);
+    client
+        .register(tarpc::context::current(), record1.clone())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let holder = client
+        .take_over(tarpc::context::current(), "session-1".to_string(), 2000)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(holder, Some(1000));
+
+    // The holder learns about the takeover from its heartbeat
+    let result = client
+        .heartbeat(tarpc::context::current(), "session-1".to_string())
+        .await
+        .unwrap();
+    match result {
+        Err(DaemonError::LeaseLost {
+            session_id,
+            holder_pid,
+        }) => {
+            assert_eq!(session_id, "session-1");
+            assert_eq!(holder_pid, 2000);
+        }
+        _ => panic!("Expected LeaseLost error, got {:?}", result),
+    }
+
+    // Until the holder releases the session, it can't be registered
+    let record2 = create_test_record("session-1", 2000);
+    let result = client
+        .register(tarpc::context::current(), record2.clone())
+        .await
+        .unwrap();
+    assert!(matches!(result, Err(DaemonError::AlreadyRegistered { .. })));
+
+    let mut released = record1;
+    released.liveness = LivenessState::Stopped;
+    client
+        .update(tarpc::context::current(), released)
+        .await
+        .unwrap()
+        .unwrap();
+
+    client
+        .register(tarpc::context::current(), record2)
+        .await
+        .unwrap()
+        .unwrap();
+    let result = client
+        .heartbeat(tarpc::context::current(), "session-1".to_string())
+        .await
+        .unwrap();
+    assert!(result.is_ok(), "New holder's heartbeat should succeed");
+}
+
 #[tokio::test]
 async fn test_register_same_pid_succeeds() {
     let server = TestServer::start().await;
@@ -481,7 +481,7 @@ async fn test_subscriber_partial_failure_cleanup() {
 
 #[tokio::test]
 async fn test_subscriber_ping_detects_healthy_subscriber() {
-    use crate::session_daemon::rpc_server::SubscriberRegistry;
+    use crate::session_daemon::server::SubscriberRegistry;
 
     let server = TestServer::start().await;
 
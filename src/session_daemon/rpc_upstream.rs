@@ -2,36 +2,61 @@
 //!
 //! This module manages the connection from a container daemon to the host
 //! application using tarpc RPC. It:
-//! - Connects to the host on port 17717 (or PLANNING_AGENT_HOST_PORT)
-//! - Sends session updates via RPC calls
-//! - Reports credentials on connect/reconnect
+//! - Connects to the host on port 17717 (or PLANNING_AGENT_HOST_PORT), falling
+//!   back to mDNS discovery (opt-in) when localhost and host.docker.internal are
+//!   unreachable
+//! - Checks that the host knows `PLANNING_AGENT_HOST_SECRET` before sending it anything
+//! - Sends session updates and workflow events via RPC calls
+//! - Replays the daemon event journal on connect/reconnect
+//! - Reports which logins it has (never their tokens) and the account usage it
+//!   fetches with them, on connect/reconnect and periodically
+//! - Relays shared sessions' agent output, tailed from their agent-stream logs
 //! - Watches credential files for changes (30-second polling)
 //! - Handles disconnection and reconnection with exponential backoff
 //! - Sends periodic heartbeats
 
 use crate::account_usage::credentials::{credential_file_paths, read_all_credential_info};
+use crate::account_usage::fetcher::fetch_local_usage;
 use crate::daemon_log::daemon_log;
-use crate::rpc::host_service::{ContainerInfo, HostServiceClient, SessionInfo, PROTOCOL_VERSION};
-use crate::rpc::SessionRecord;
+use crate::planning_paths;
+use crate::rpc::discovery::{discover_host, discovery_enabled, DISCOVERY_TIMEOUT};
+use crate::rpc::host_auth::{host_secret, new_nonce, verify_proof, HOST_SECRET_ENV};
+use crate::rpc::host_service::{
+    AccountUsageInfo, ContainerInfo, HostServiceClient, SessionInfo, PROTOCOL_VERSION,
+};
+use crate::rpc::{SessionRecord, WorkflowEventEnvelope};
 use crate::session_daemon::server::DaemonState;
 use anyhow::Result;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tarpc::client;
 use tarpc::tokio_serde::formats::Bincode;
 use tokio::sync::{mpsc, Mutex};
 
 /// Default port for host connection.
-const DEFAULT_HOST_PORT: u16 = 17717;
+pub(crate) const DEFAULT_HOST_PORT: u16 = 17717;
 
 /// Interval for checking credential file changes (30 seconds per plan).
 const CREDENTIAL_CHECK_INTERVAL_SECS: u64 = 30;
 
+/// Usage fetch interval when sessions are active (2 minutes).
+const ACTIVE_USAGE_INTERVAL_SECS: u64 = 120;
+
+/// Usage fetch interval when no sessions are active (10 minutes).
+const IDLE_USAGE_INTERVAL_SECS: u64 = 600;
+
 /// Heartbeat interval to host (500ms to match session-to-daemon timing).
 const HOST_HEARTBEAT_INTERVAL_MS: u64 = 500;
 
+/// How much existing agent output a newly shared session starts with.
+const SHARE_BACKLOG_BYTES: u64 = 16 * 1024;
+
+/// Most agent output relayed per shared session and heartbeat.
+const OUTPUT_CHUNK_BYTES: u64 = 256 * 1024;
+
 /// Events to send upstream to host.
 #[derive(Debug, Clone)]
 pub enum UpstreamEvent {
@@ -40,6 +65,10 @@ pub enum UpstreamEvent {
     /// so they remain visible in the host GUI. There is no SessionGone variant because
     /// we don't currently have explicit session deletion/cleanup.
     SessionUpdate(SessionRecord),
+    /// A workflow event broadcast by a session, forwarded to the host timeline.
+    WorkflowEvent(String, WorkflowEventEnvelope),
+    /// A session shared with `planning share`: session ID and share token.
+    ShareSession(String, String),
 }
 
 /// Get the host port from environment or default.
@@ -60,6 +89,29 @@ pub fn host_port() -> Option<u16> {
     }
 }
 
+/// Describes where this daemon looks for the host, for `--daemon-status`.
+/// Runs a discovery query, so a host found via mDNS is shown with its address.
+pub async fn describe_host_upstream() -> String {
+    let Some(port) = host_port() else {
+        return "disabled (PLANNING_AGENT_HOST_PORT=0)".to_string();
+    };
+    if let Ok(host) = std::env::var("PLANNING_AGENT_HOST_ADDRESS") {
+        return format!("{}:{} (PLANNING_AGENT_HOST_ADDRESS)", host, port);
+    }
+    let fallback = format!("localhost or host.docker.internal, port {}", port);
+    if !discovery_enabled() {
+        return format!("{} (mDNS off, set PLANNING_AGENT_MDNS=1)", fallback);
+    }
+    if host_secret().is_none() {
+        return format!("{} (mDNS needs {})", fallback, HOST_SECRET_ENV);
+    }
+    match discover_host(DISCOVERY_TIMEOUT).await {
+        Ok(Some(addr)) => format!("{} (discovered via mDNS)", addr),
+        Ok(None) => format!("{} (no host answered mDNS)", fallback),
+        Err(e) => format!("{} (mDNS query failed: {})", fallback, e),
+    }
+}
+
 /// Manages upstream RPC connection to host application.
 pub struct RpcUpstream {
     host: String,
@@ -109,9 +161,14 @@ impl RpcUpstream {
     /// This function runs indefinitely, reconnecting on disconnect.
     pub async fn run(self, mut session_rx: mpsc::UnboundedReceiver<UpstreamEvent>) {
         let mut consecutive_failures = 0u32;
+        // Read offsets into shared sessions' agent-stream logs, kept across reconnects
+        let mut output_offsets: HashMap<String, u64> = HashMap::new();
 
         loop {
-            match self.connect_and_run(&mut session_rx).await {
+            match self
+                .connect_and_run(&mut session_rx, &mut output_offsets)
+                .await
+            {
                 Ok(()) => {
                     // Clean disconnect
                     daemon_log("rpc_upstream", "Host disconnected, reconnecting...");
@@ -138,7 +195,8 @@ impl RpcUpstream {
     }
 
     /// Try to connect to the host, racing localhost and host.docker.internal in parallel.
-    async fn connect_to_host(&self) -> Result<HostServiceClient> {
+    /// Also returns whether the host was found via mDNS.
+    async fn connect_to_host(&self) -> Result<(HostServiceClient, bool)> {
         use tarpc::serde_transport::tcp;
 
         // If explicit host is set, only try that
@@ -146,7 +204,7 @@ impl RpcUpstream {
             let addr = format!("{}:{}", self.host, self.port);
             let transport = tcp::connect(&addr, Bincode::default).await?;
             let client = HostServiceClient::new(client::Config::default(), transport).spawn();
-            return Ok(client);
+            return Ok((client, false));
         }
 
         // Try both localhost and host.docker.internal in parallel.
@@ -205,35 +263,50 @@ impl RpcUpstream {
         })
         .await;
 
-        match result {
+        let error = match result {
             Ok(Ok((transport, source))) => {
                 daemon_log("rpc_upstream", &format!("Connected via {}", source));
                 let client = HostServiceClient::new(client::Config::default(), transport).spawn();
-                Ok(client)
-            }
-            Ok(Err(e)) => {
-                anyhow::bail!(
-                    "Failed to connect to host on port {} (tried localhost and host.docker.internal): {}",
-                    self.port,
-                    e
-                )
-            }
-            Err(_) => {
-                anyhow::bail!(
-                    "Failed to connect to host on port {} (connection timed out)",
-                    self.port
-                )
+                return Ok((client, false));
             }
+            Ok(Err(e)) => format!("tried localhost and host.docker.internal: {}", e),
+            Err(_) => "connection timed out".to_string(),
+        };
+
+        if let Some(client) = self.connect_discovered().await {
+            return Ok((client, true));
+        }
+        anyhow::bail!(
+            "Failed to connect to host on port {} ({})",
+            self.port,
+            error
+        )
+    }
+
+    /// Looks for a host on the local network via mDNS and connects to it.
+    /// Anyone on the network can answer, so this needs the shared secret that
+    /// the host must then prove it knows.
+    async fn connect_discovered(&self) -> Option<HostServiceClient> {
+        use tarpc::serde_transport::tcp;
+
+        if !discovery_enabled() || host_secret().is_none() {
+            return None;
         }
+        let addr = discover_host(DISCOVERY_TIMEOUT).await.ok().flatten()?;
+        let transport = tcp::connect(addr, Bincode::default).await.ok()?;
+        daemon_log("rpc_upstream", &format!("Connected via mDNS ({})", addr));
+        Some(HostServiceClient::new(client::Config::default(), transport).spawn())
     }
 
     async fn connect_and_run(
         &self,
         session_rx: &mut mpsc::UnboundedReceiver<UpstreamEvent>,
+        output_offsets: &mut HashMap<String, u64>,
     ) -> Result<()> {
         daemon_log("rpc_upstream", "connect_and_run: starting");
 
-        let client = self.connect_to_host().await?;
+        let (client, discovered) = self.connect_to_host().await?;
+        authenticate_host(&client, discovered).await?;
 
         daemon_log(
             "rpc_upstream",
@@ -293,7 +366,21 @@ impl RpcUpstream {
             }
         }
 
-        // Report credentials on connect
+        // Replay journaled workflow events so the host timeline survives reconnects
+        let catch_up = self.daemon_state.lock().await.journal.catch_up();
+        for (session_id, events) in catch_up {
+            client
+                .workflow_events(tarpc::context::current(), session_id, events)
+                .await?;
+        }
+
+        // Share sessions again, since a restarted host has forgotten their tokens
+        let shares = self.daemon_state.lock().await.shares.clone();
+        for (session_id, token) in shares {
+            share_with_host(&client, session_id, token, output_offsets).await?;
+        }
+
+        // Report credentials on connect; usage follows with the first usage tick
         let credentials = read_all_credential_info();
         if !credentials.is_empty() {
             daemon_log(
@@ -324,6 +411,12 @@ impl RpcUpstream {
         let credential_check_interval = Duration::from_secs(CREDENTIAL_CHECK_INTERVAL_SECS);
         let mut credential_timer = tokio::time::interval(credential_check_interval);
 
+        // Usage is fetched off the event loop since the provider APIs can be slow
+        let mut usage_timer =
+            tokio::time::interval(Duration::from_secs(ACTIVE_USAGE_INTERVAL_SECS));
+        let mut last_usage_fetch: Option<Instant> = None;
+        let (usage_tx, mut usage_rx) = mpsc::unbounded_channel::<Vec<AccountUsageInfo>>();
+
         loop {
             tokio::select! {
                 event = session_rx.recv() => {
@@ -339,6 +432,14 @@ impl RpcUpstream {
                             let session = SessionInfo::from_session_record(&record);
                             client.session_update(tarpc::context::current(), session).await?;
                         }
+                        Some(UpstreamEvent::WorkflowEvent(session_id, event)) => {
+                            client
+                                .workflow_events(tarpc::context::current(), session_id, vec![event])
+                                .await?;
+                        }
+                        Some(UpstreamEvent::ShareSession(session_id, token)) => {
+                            share_with_host(&client, session_id, token, output_offsets).await?;
+                        }
                         None => {
                             // Channel closed, exit
                             break;
@@ -347,6 +448,51 @@ impl RpcUpstream {
                 }
                 _ = heartbeat_timer.tick() => {
                     client.heartbeat(tarpc::context::current()).await?;
+                    let commands = client.pending_commands(tarpc::context::current()).await?;
+                    if !commands.is_empty() {
+                        daemon_log(
+                            "rpc_upstream",
+                            &format!("Received {} command(s) from host", commands.len()),
+                        );
+                        let mut state = self.daemon_state.lock().await;
+                        for relayed in commands {
+                            state
+                                .remote_commands
+                                .entry(relayed.session_id)
+                                .or_default()
+                                .push(relayed.command);
+                        }
+                    }
+                    for (session_id, offset) in output_offsets.iter_mut() {
+                        let Ok(path) = agent_stream_log(session_id) else {
+                            continue;
+                        };
+                        let lines = read_new_lines(&path, offset);
+                        if !lines.is_empty() {
+                            client
+                                .session_output(tarpc::context::current(), session_id.clone(), lines)
+                                .await?;
+                        }
+                    }
+                }
+                _ = usage_timer.tick() => {
+                    let active = self.has_active_sessions().await;
+                    let due = last_usage_fetch.is_none_or(|last| {
+                        active || last.elapsed().as_secs() >= IDLE_USAGE_INTERVAL_SECS
+                    });
+                    if due {
+                        last_usage_fetch = Some(Instant::now());
+                        spawn_usage_fetch(usage_tx.clone());
+                    }
+                }
+                Some(usage) = usage_rx.recv() => {
+                    daemon_log(
+                        "rpc_upstream",
+                        &format!("Reporting usage of {} accounts to host", usage.len()),
+                    );
+                    client
+                        .report_account_usage(tarpc::context::current(), usage)
+                        .await?;
                 }
                 _ = credential_timer.tick() => {
                     // Check for credential file changes
@@ -380,6 +526,8 @@ impl RpcUpstream {
                         client
                             .report_credentials(tarpc::context::current(), credentials)
                             .await?;
+                        last_usage_fetch = Some(Instant::now());
+                        spawn_usage_fetch(usage_tx.clone());
                     }
                 }
             }
@@ -387,6 +535,118 @@ impl RpcUpstream {
 
         Ok(())
     }
+
+    /// Whether any session is in a phase that uses up account quota.
+    async fn has_active_sessions(&self) -> bool {
+        let state = self.daemon_state.lock().await;
+        state.sessions.values().any(|s| {
+            let phase = s.phase.to_lowercase();
+            phase == "planning" || phase == "reviewing" || phase == "revising"
+        })
+    }
+}
+
+/// Makes a host prove it knows the shared secret before it is sent anything.
+/// Without a secret, hosts at configured or well-known addresses are trusted;
+/// a discovered host never is.
+async fn authenticate_host(client: &HostServiceClient, discovered: bool) -> Result<()> {
+    let Some(secret) = host_secret() else {
+        if discovered {
+            anyhow::bail!("Refusing host found via mDNS without {}", HOST_SECRET_ENV);
+        }
+        return Ok(());
+    };
+    let nonce = new_nonce();
+    let proof = client
+        .prove_identity(tarpc::context::current(), nonce.clone())
+        .await?;
+    match proof {
+        Some(proof) if verify_proof(&secret, &nonce, &proof) => Ok(()),
+        _ => anyhow::bail!(
+            "Host did not prove it knows {}; check that both sides use the same secret",
+            HOST_SECRET_ENV
+        ),
+    }
+}
+
+/// Fetches the local accounts' usage in the background and sends it to `usage_tx`.
+fn spawn_usage_fetch(usage_tx: mpsc::UnboundedSender<Vec<AccountUsageInfo>>) {
+    tokio::task::spawn_blocking(move || {
+        let usage: Vec<AccountUsageInfo> = fetch_local_usage()
+            .iter()
+            .map(AccountUsageInfo::from_usage)
+            .collect();
+        if !usage.is_empty() {
+            // The connection may have closed meanwhile; the next one fetches again
+            let _ = usage_tx.send(usage);
+        }
+    });
+}
+
+/// Shares a session with the host and starts relaying its agent output.
+async fn share_with_host(
+    client: &HostServiceClient,
+    session_id: String,
+    token: String,
+    output_offsets: &mut HashMap<String, u64>,
+) -> Result<()> {
+    if !output_offsets.contains_key(&session_id) {
+        // Start a little before the end so watchers see what the agent is doing
+        let offset = agent_stream_log(&session_id)
+            .and_then(|path| Ok(std::fs::metadata(path)?.len()))
+            .map(|len| len.saturating_sub(SHARE_BACKLOG_BYTES))
+            .unwrap_or(0);
+        output_offsets.insert(session_id.clone(), offset);
+    }
+    if let Err(e) = client
+        .share_session(tarpc::context::current(), session_id.clone(), token)
+        .await?
+    {
+        daemon_log(
+            "rpc_upstream",
+            &format!("Host refused to share {}: {}", session_id, e),
+        );
+    }
+    Ok(())
+}
+
+fn agent_stream_log(session_id: &str) -> Result<PathBuf> {
+    Ok(planning_paths::session_logs_dir(session_id)?.join("agent-stream.log"))
+}
+
+/// Reads the complete lines written to `path` since `offset` and advances it
+/// past them. A partial last line is left for the next read; a log shorter
+/// than `offset` was recreated and is read from the start.
+fn read_new_lines(path: &Path, offset: &mut u64) -> Vec<String> {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+    let Ok(len) = file.metadata().map(|meta| meta.len()) else {
+        return Vec::new();
+    };
+    if len < *offset {
+        *offset = 0;
+    }
+    let mut buf = Vec::new();
+    if file.seek(SeekFrom::Start(*offset)).is_err()
+        || file.take(OUTPUT_CHUNK_BYTES).read_to_end(&mut buf).is_err()
+    {
+        return Vec::new();
+    }
+
+    let Some(end) = buf.iter().rposition(|&b| b == b'\n') else {
+        // A single line longer than a chunk is relayed in pieces
+        if buf.len() as u64 == OUTPUT_CHUNK_BYTES {
+            *offset += buf.len() as u64;
+            return vec![String::from_utf8_lossy(&buf).into_owned()];
+        }
+        return Vec::new();
+    };
+    *offset += end as u64 + 1;
+    String::from_utf8_lossy(&buf[..end])
+        .lines()
+        .map(str::to_string)
+        .collect()
 }
 
 #[cfg(test)]
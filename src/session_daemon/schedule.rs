@@ -0,0 +1,436 @@
+//! Daemon-side scheduled workflows.
+//!
+//! Schedules are cron-like definitions ("every monday 09:00 plan objective X in
+//! repo Y") owned by the session daemon. The daemon persists them to
+//! `~/.planning-agent/schedules.json` and, when one becomes due, launches a
+//! detached headless worker (`planning --headless ...`) the same way clients
+//! spawn the daemon itself. Clients manage schedules via `planning schedule`.
+
+use crate::daemon_log::daemon_log;
+use crate::planning_paths;
+use crate::session_daemon::server::DaemonState;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveTime, TimeZone, Weekday};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, Mutex};
+
+/// Upper bound on how long the executor sleeps before re-checking due schedules.
+/// Keeps schedules accurate across wall-clock jumps (suspend/resume, DST).
+const MAX_EXECUTOR_SLEEP_SECS: u64 = 60;
+
+/// When a scheduled workflow should run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleSpec {
+    /// Every day at the given local time.
+    Daily { hour: u32, minute: u32 },
+    /// Every week on the given day at the given local time.
+    Weekly {
+        weekday: Weekday,
+        hour: u32,
+        minute: u32,
+    },
+    /// Every N minutes, starting from when the schedule was added.
+    Interval { minutes: u32 },
+}
+
+impl ScheduleSpec {
+    /// Parses a human-friendly schedule expression.
+    ///
+    /// Accepted forms (case-insensitive, optional `at`):
+    /// - `daily 09:00`, `every day at 09:00`
+    /// - `weekly mon 09:00`, `every monday at 9:00`
+    /// - `every 30m`, `every 6h`
+    pub fn parse(input: &str) -> Result<Self> {
+        let lowered = input.to_lowercase();
+        let tokens: Vec<&str> = lowered
+            .split_whitespace()
+            .filter(|token| *token != "at")
+            .collect();
+
+        match tokens.as_slice() {
+            ["daily", time] | ["every", "day", time] => {
+                let (hour, minute) = parse_time_of_day(time)?;
+                Ok(ScheduleSpec::Daily { hour, minute })
+            }
+            ["weekly", day, time] | ["every", day, time] => {
+                let weekday = day
+                    .parse::<Weekday>()
+                    .map_err(|_| anyhow::anyhow!("Unknown weekday '{}'", day))?;
+                let (hour, minute) = parse_time_of_day(time)?;
+                Ok(ScheduleSpec::Weekly {
+                    weekday,
+                    hour,
+                    minute,
+                })
+            }
+            ["every", interval] => Ok(ScheduleSpec::Interval {
+                minutes: parse_interval_minutes(interval)?,
+            }),
+            _ => anyhow::bail!(
+                "Unrecognized schedule '{}'. Expected e.g. \"every monday 09:00\", \"daily 18:30\", or \"every 6h\"",
+                input
+            ),
+        }
+    }
+
+    /// Returns the first run time strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Local>) -> DateTime<Local> {
+        match *self {
+            ScheduleSpec::Interval { minutes } => {
+                after + chrono::Duration::minutes(i64::from(minutes.max(1)))
+            }
+            ScheduleSpec::Daily { hour, minute } => {
+                next_matching_day(after, hour, minute, |_| true)
+            }
+            ScheduleSpec::Weekly {
+                weekday,
+                hour,
+                minute,
+            } => next_matching_day(after, hour, minute, |date| date.weekday() == weekday),
+        }
+    }
+}
+
+impl std::fmt::Display for ScheduleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleSpec::Daily { hour, minute } => write!(f, "daily {:02}:{:02}", hour, minute),
+            ScheduleSpec::Weekly {
+                weekday,
+                hour,
+                minute,
+            } => write!(
+                f,
+                "every {} {:02}:{:02}",
+                weekday.to_string().to_lowercase(),
+                hour,
+                minute
+            ),
+            ScheduleSpec::Interval { minutes } if minutes % 60 == 0 => {
+                write!(f, "every {}h", minutes / 60)
+            }
+            ScheduleSpec::Interval { minutes } => write!(f, "every {}m", minutes),
+        }
+    }
+}
+
+/// Parses `HH:MM` (24-hour clock).
+fn parse_time_of_day(input: &str) -> Result<(u32, u32)> {
+    let (hour, minute) = input
+        .split_once(':')
+        .with_context(|| format!("Invalid time '{}', expected HH:MM", input))?;
+    let hour: u32 = hour
+        .parse()
+        .with_context(|| format!("Invalid hour in '{}'", input))?;
+    let minute: u32 = minute
+        .parse()
+        .with_context(|| format!("Invalid minute in '{}'", input))?;
+    if hour > 23 || minute > 59 {
+        anyhow::bail!("Time '{}' is out of range", input);
+    }
+    Ok((hour, minute))
+}
+
+/// Parses an interval such as `30m` or `6h` into minutes.
+fn parse_interval_minutes(input: &str) -> Result<u32> {
+    let (value, multiplier) = if let Some(value) = input.strip_suffix('m') {
+        (value, 1)
+    } else if let Some(value) = input.strip_suffix('h') {
+        (value, 60)
+    } else {
+        anyhow::bail!("Invalid interval '{}', expected e.g. 30m or 6h", input);
+    };
+    let value: u32 = value
+        .parse()
+        .with_context(|| format!("Invalid interval '{}'", input))?;
+    if value == 0 {
+        anyhow::bail!("Interval must be greater than zero");
+    }
+    value
+        .checked_mul(multiplier)
+        .with_context(|| format!("Interval '{}' is too large", input))
+}
+
+/// Finds the first day (starting from `after`'s date) accepted by `matches`
+/// whose local `hour:minute` falls strictly after `after`.
+fn next_matching_day(
+    after: DateTime<Local>,
+    hour: u32,
+    minute: u32,
+    matches: impl Fn(NaiveDate) -> bool,
+) -> DateTime<Local> {
+    let time = NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or(NaiveTime::MIN);
+    let start = after.date_naive();
+    // 8 days covers a full week plus a DST gap on the matching day.
+    (0..=8)
+        .filter_map(|offset| start.checked_add_days(Days::new(offset)))
+        .filter(|date| matches(*date))
+        .filter_map(|date| Local.from_local_datetime(&date.and_time(time)).earliest())
+        .find(|candidate| *candidate > after)
+        .unwrap_or_else(|| after + chrono::Duration::days(1))
+}
+
+/// A persisted scheduled workflow definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledWorkflow {
+    /// Short identifier used by `planning schedule rm`.
+    pub id: String,
+    /// When the workflow runs.
+    pub spec: ScheduleSpec,
+    /// Planning objective passed to the headless worker.
+    pub objective: String,
+    /// Repository the workflow runs in.
+    pub working_dir: PathBuf,
+    /// Maximum review iterations for each run.
+    pub max_iterations: u32,
+    /// Whether the worker archives the finished session as a ZIP.
+    pub auto_archive: bool,
+    /// Timestamp the schedule was created (RFC3339)
+    pub created_at: String,
+    /// Timestamp of the next planned run (RFC3339)
+    pub next_run_at: String,
+    /// Timestamp of the most recent run (RFC3339), if any
+    #[serde(default)]
+    pub last_run_at: Option<String>,
+}
+
+impl ScheduledWorkflow {
+    /// Creates a new schedule whose first run is the next occurrence after now.
+    pub fn new(
+        spec: ScheduleSpec,
+        objective: String,
+        working_dir: PathBuf,
+        max_iterations: u32,
+        auto_archive: bool,
+    ) -> Self {
+        let now = Local::now();
+        let id: String = uuid::Uuid::new_v4()
+            .simple()
+            .to_string()
+            .chars()
+            .take(8)
+            .collect();
+        Self {
+            id,
+            spec,
+            objective,
+            working_dir,
+            max_iterations,
+            auto_archive,
+            created_at: now.to_rfc3339(),
+            next_run_at: spec.next_after(now).to_rfc3339(),
+            last_run_at: None,
+        }
+    }
+
+    /// Parses `next_run_at`. Unparseable timestamps are treated as due immediately.
+    pub fn next_run(&self) -> DateTime<Local> {
+        DateTime::parse_from_rfc3339(&self.next_run_at)
+            .map(|dt| dt.with_timezone(&Local))
+            .unwrap_or_else(|_| DateTime::<chrono::Utc>::default().with_timezone(&Local))
+    }
+}
+
+/// In-memory set of scheduled workflows held by the daemon.
+#[derive(Debug, Default)]
+pub struct ScheduleRegistry {
+    schedules: Vec<ScheduledWorkflow>,
+}
+
+impl ScheduleRegistry {
+    /// Loads schedules from `~/.planning-agent/schedules.json` (empty if absent).
+    pub fn load() -> Result<Self> {
+        let path = planning_paths::schedules_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path).context("Failed to read schedules file")?;
+        let schedules = serde_json::from_str(&content).context("Failed to parse schedules file")?;
+        Ok(Self { schedules })
+    }
+
+    /// Persists schedules to `~/.planning-agent/schedules.json`.
+    ///
+    /// The file is first written to a temporary file, then renamed into place.
+    pub fn persist(&self) -> Result<()> {
+        let path = planning_paths::schedules_path()?;
+        let temp_path = path.with_extension("json.tmp");
+        let content = serde_json::to_string_pretty(&self.schedules)
+            .context("Failed to serialize schedules")?;
+        std::fs::write(&temp_path, content).context("Failed to write schedules file")?;
+        std::fs::rename(&temp_path, &path)
+            .with_context(|| format!("Failed to rename temp file to: {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Returns all schedules ordered by next run time.
+    pub fn list(&self) -> Vec<ScheduledWorkflow> {
+        let mut schedules = self.schedules.clone();
+        schedules.sort_by_key(|s| s.next_run());
+        schedules
+    }
+
+    /// Adds a schedule.
+    pub fn add(&mut self, schedule: ScheduledWorkflow) {
+        self.schedules.push(schedule);
+    }
+
+    /// Removes a schedule by ID. Returns true if it existed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.schedules.len();
+        self.schedules.retain(|s| s.id != id);
+        self.schedules.len() != before
+    }
+
+    /// Returns the earliest upcoming run across all schedules.
+    pub fn earliest_next_run(&self) -> Option<DateTime<Local>> {
+        self.schedules.iter().map(|s| s.next_run()).min()
+    }
+
+    /// Returns schedules due at `now`, recording the run and advancing each to its next slot.
+    ///
+    /// Missed slots (e.g. daemon was down) collapse into a single run.
+    pub fn take_due(&mut self, now: DateTime<Local>) -> Vec<ScheduledWorkflow> {
+        let mut due = Vec::new();
+        for schedule in &mut self.schedules {
+            if schedule.next_run() <= now {
+                schedule.last_run_at = Some(now.to_rfc3339());
+                schedule.next_run_at = schedule.spec.next_after(now).to_rfc3339();
+                due.push(schedule.clone());
+            }
+        }
+        due
+    }
+}
+
+/// Spawns a detached headless worker for a scheduled workflow.
+///
+/// Worker stdout/stderr are appended to `~/.planning-agent/schedules/<id>.log`.
+/// A background task waits on the worker so the long-lived daemon reaps it
+/// when it exits. Returns the worker PID.
+pub fn spawn_scheduled_worker(schedule: &ScheduledWorkflow) -> Result<u32> {
+    let exe = std::env::current_exe()
+        .or_else(|_| which::which("planning"))
+        .context("Failed to find planning binary")?;
+    let home_dir = planning_paths::planning_agent_home_dir()?;
+
+    let log_path = planning_paths::schedule_log_path(&schedule.id)?;
+    let log_file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .with_context(|| format!("Failed to open worker log: {}", log_path.display()))?;
+
+    let mut command = tokio::process::Command::new(&exe);
+    command
+        .arg("--headless")
+        .arg("--working-dir")
+        .arg(&schedule.working_dir)
+        .arg("--max-iterations")
+        .arg(schedule.max_iterations.to_string());
+    if schedule.auto_archive {
+        command.arg("--auto-archive");
+    }
+    command
+        .arg("--")
+        .arg(&schedule.objective)
+        .current_dir(&schedule.working_dir)
+        .env("PLANNING_AGENT_HOME", &home_dir)
+        .stdin(std::process::Stdio::null())
+        .stdout(log_file.try_clone()?)
+        .stderr(log_file);
+
+    #[cfg(windows)]
+    command.creation_flags(0x00000008); // DETACHED_PROCESS
+
+    let mut child = command.spawn().context("Failed to spawn headless worker")?;
+    let pid = child.id().unwrap_or_default();
+    let schedule_id = schedule.id.clone();
+    tokio::spawn(async move {
+        match child.wait().await {
+            Ok(status) => daemon_log(
+                "schedule",
+                &format!(
+                    "Scheduled workflow {} (pid {}) exited: {}",
+                    schedule_id, pid, status
+                ),
+            ),
+            Err(e) => daemon_log(
+                "schedule",
+                &format!(
+                    "Failed to wait on scheduled workflow {}: {}",
+                    schedule_id, e
+                ),
+            ),
+        }
+    });
+    Ok(pid)
+}
+
+/// Background task that launches scheduled workflows when they become due.
+///
+/// Sleeps until the earliest upcoming run and wakes early when the schedule set
+/// changes (via `DaemonState::schedule_changed`) or the daemon shuts down.
+pub async fn run_schedule_executor(
+    state: Arc<Mutex<DaemonState>>,
+    shutdown_tx: broadcast::Sender<()>,
+) {
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    let schedule_changed = state.lock().await.schedule_changed.clone();
+
+    loop {
+        let now = Local::now();
+        let (due, next_run) = {
+            let mut state = state.lock().await;
+            let due = state.schedules.take_due(now);
+            if !due.is_empty() {
+                if let Err(e) = state.schedules.persist() {
+                    daemon_log(
+                        "schedule",
+                        &format!("Warning: Failed to persist schedules: {}", e),
+                    );
+                }
+            }
+            (due, state.schedules.earliest_next_run())
+        };
+
+        for schedule in due {
+            match spawn_scheduled_worker(&schedule) {
+                Ok(pid) => daemon_log(
+                    "schedule",
+                    &format!(
+                        "Started scheduled workflow {} (pid {}) in {}",
+                        schedule.id,
+                        pid,
+                        schedule.working_dir.display()
+                    ),
+                ),
+                Err(e) => daemon_log(
+                    "schedule",
+                    &format!("Failed to start scheduled workflow {}: {}", schedule.id, e),
+                ),
+            }
+        }
+
+        let max_sleep = Duration::from_secs(MAX_EXECUTOR_SLEEP_SECS);
+        let sleep_for = next_run
+            .map(|next| (next - Local::now()).to_std().unwrap_or(Duration::ZERO))
+            .map_or(max_sleep, |until_next| until_next.min(max_sleep));
+
+        // Without schedules there is nothing to wake up for until one is added
+        tokio::select! {
+            _ = tokio::time::sleep(sleep_for), if next_run.is_some() => {}
+            _ = schedule_changed.notified() => {}
+            _ = shutdown_rx.recv() => {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/schedule_tests.rs"]
+mod tests;
@@ -1,11 +1,18 @@
 //! Session daemon state management.
 //!
-//! Contains the shared daemon state used by both old and new RPC implementations.
+//! Contains the shared daemon state used by both old and new RPC implementations,
+//! the subscriber registry, and the journal of recent workflow events.
 
 use crate::planning_paths;
+use crate::rpc::daemon_service::SubscriberCallbackClient;
+use crate::rpc::host_service::RemoteCommand;
+use crate::rpc::WorkflowEventEnvelope;
 use crate::session_daemon::protocol::{LivenessState, SessionRecord};
+use crate::session_daemon::schedule::ScheduleRegistry;
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::sync::{watch, Notify};
 
 /// Check if a process with the given PID is still running.
 /// Returns false if the process has exited.
@@ -37,12 +44,225 @@ const DEFAULT_UNRESPONSIVE_TIMEOUT_SECS: u64 = 3;
 /// Can be overridden via PLANNING_SESSIOND_STALE_SECS environment variable.
 const DEFAULT_STALE_TIMEOUT_SECS: u64 = 10;
 
+/// Events kept per session for replay to subscribers that connect later.
+pub(crate) const JOURNAL_EVENTS_PER_SESSION: usize = 200;
+
+/// Sessions kept in the journal; the least recently active is dropped first.
+pub(crate) const JOURNAL_MAX_SESSIONS: usize = 50;
+
+/// Bounded in-memory journal of recent workflow events per session.
+///
+/// Subscribers and the host only receive events while connected, so a newly
+/// connected one is first sent the journal to fill in what it missed.
+#[derive(Default)]
+pub(crate) struct EventJournal {
+    sessions: HashMap<String, VecDeque<WorkflowEventEnvelope>>,
+    /// Session IDs ordered from least to most recently recorded.
+    recency: VecDeque<String>,
+}
+
+impl EventJournal {
+    /// Records an event, ignoring ones already journaled (same aggregate, same or older sequence).
+    pub(crate) fn record(&mut self, session_id: &str, event: WorkflowEventEnvelope) {
+        let events = self.sessions.entry(session_id.to_string()).or_default();
+        let duplicate = events
+            .iter()
+            .rev()
+            .find(|e| e.aggregate_id == event.aggregate_id)
+            .is_some_and(|last| event.sequence <= last.sequence);
+        if duplicate {
+            return;
+        }
+        if events.len() == JOURNAL_EVENTS_PER_SESSION {
+            events.pop_front();
+        }
+        events.push_back(event);
+
+        self.recency.retain(|id| id != session_id);
+        self.recency.push_back(session_id.to_string());
+        if self.recency.len() > JOURNAL_MAX_SESSIONS {
+            if let Some(oldest) = self.recency.pop_front() {
+                self.sessions.remove(&oldest);
+            }
+        }
+    }
+
+    /// Journaled events of one session, oldest first.
+    pub(crate) fn session_events(&self, session_id: &str) -> Vec<WorkflowEventEnvelope> {
+        self.sessions
+            .get(session_id)
+            .map(|events| events.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// All journaled events grouped by session, least recently active session first.
+    pub(crate) fn catch_up(&self) -> Vec<(String, Vec<WorkflowEventEnvelope>)> {
+        self.recency
+            .iter()
+            .map(|id| (id.clone(), self.session_events(id)))
+            .collect()
+    }
+}
+
+/// Unique subscriber ID for tracking connected subscribers.
+pub(crate) type SubscriberId = u64;
+
+/// Subscriber tracking - stores callback clients for push notifications.
+pub struct SubscriberRegistry {
+    /// Map of subscriber ID to their callback client.
+    subscribers: HashMap<SubscriberId, SubscriberCallbackClient>,
+    /// Next subscriber ID to assign.
+    next_id: SubscriberId,
+}
+
+impl SubscriberRegistry {
+    pub fn new() -> Self {
+        Self {
+            subscribers: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a new subscriber with their callback client. Returns the assigned ID.
+    pub fn add(&mut self, client: SubscriberCallbackClient) -> SubscriberId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.subscribers.insert(id, client);
+        id
+    }
+
+    /// Remove a subscriber by ID.
+    pub fn remove(&mut self, id: &SubscriberId) {
+        self.subscribers.remove(id);
+    }
+
+    /// Broadcast a session change to all subscribers.
+    /// Returns IDs of failed subscribers for cleanup.
+    pub async fn broadcast_session_changed(&self, record: SessionRecord) -> Vec<SubscriberId> {
+        let mut failed = Vec::new();
+
+        for (id, client) in &self.subscribers {
+            if client
+                .session_changed(tarpc::context::current(), record.clone())
+                .await
+                .is_err()
+            {
+                failed.push(*id);
+            }
+        }
+
+        failed
+    }
+
+    /// Broadcast daemon restart to all subscribers.
+    pub async fn broadcast_restarting(&self, new_sha: String) -> Vec<SubscriberId> {
+        let mut failed = Vec::new();
+
+        for (id, client) in &self.subscribers {
+            if client
+                .daemon_restarting(tarpc::context::current(), new_sha.clone())
+                .await
+                .is_err()
+            {
+                failed.push(*id);
+            }
+        }
+
+        failed
+    }
+
+    /// Add a subscriber after replaying journaled events to it.
+    /// Callers hold the write lock, so no live event can overtake the replay.
+    pub async fn add_with_replay(
+        &mut self,
+        client: SubscriberCallbackClient,
+        catch_up: Vec<(String, Vec<WorkflowEventEnvelope>)>,
+    ) -> SubscriberId {
+        'replay: for (session_id, events) in catch_up {
+            for event in events {
+                let ctx = tarpc::context::current();
+                if client
+                    .workflow_event(ctx, session_id.clone(), event)
+                    .await
+                    .is_err()
+                {
+                    break 'replay; // Dead subscribers are removed by the next broadcast
+                }
+            }
+        }
+        self.add(client)
+    }
+
+    /// Get count of active subscribers.
+    pub fn count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Broadcast a workflow event to all subscribers.
+    /// Returns IDs of failed subscribers for cleanup.
+    pub async fn broadcast_workflow_event(
+        &self,
+        session_id: String,
+        event: WorkflowEventEnvelope,
+    ) -> Vec<SubscriberId> {
+        let mut failed = Vec::new();
+
+        for (id, client) in &self.subscribers {
+            if client
+                .workflow_event(tarpc::context::current(), session_id.clone(), event.clone())
+                .await
+                .is_err()
+            {
+                failed.push(*id);
+            }
+        }
+
+        failed
+    }
+
+    /// Ping all subscribers to check if they're alive.
+    /// Returns IDs of subscribers that failed to respond.
+    pub async fn ping_all(&self) -> Vec<SubscriberId> {
+        let mut failed = Vec::new();
+
+        for (id, client) in &self.subscribers {
+            match client.ping(tarpc::context::current()).await {
+                Ok(true) => {} // Healthy
+                _ => failed.push(*id),
+            }
+        }
+
+        failed
+    }
+}
+
+impl Default for SubscriberRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Shared daemon state.
 pub(crate) struct DaemonState {
     /// Session registry keyed by workflow_session_id
     pub(crate) sessions: HashMap<String, SessionRecord>,
     /// Flag indicating daemon is shutting down
     pub(crate) shutting_down: bool,
+    /// Scheduled workflow definitions
+    pub(crate) schedules: ScheduleRegistry,
+    /// Wakes the schedule executor when schedules are added or removed
+    pub(crate) schedule_changed: Arc<Notify>,
+    /// Recent workflow events, replayed to newly connected subscribers and hosts
+    pub(crate) journal: EventJournal,
+    /// Share tokens of sessions shared through the host, by session ID
+    pub(crate) shares: HashMap<String, String>,
+    /// Sessions being taken over, by session ID, with the PID waiting to take each over
+    pub(crate) handoffs: HashMap<String, u32>,
+    /// Commands relayed from the host, by session ID, until the session takes them
+    pub(crate) remote_commands: HashMap<String, Vec<RemoteCommand>>,
+    /// Bumped when a session registers, a client subscribes or a schedule is added,
+    /// waking the background sweeps out of idle mode
+    pub(crate) activity: watch::Sender<()>,
 }
 
 impl DaemonState {
@@ -50,9 +270,25 @@ impl DaemonState {
         Self {
             sessions: HashMap::new(),
             shutting_down: false,
+            schedules: ScheduleRegistry::default(),
+            schedule_changed: Arc::new(Notify::new()),
+            journal: EventJournal::default(),
+            shares: HashMap::new(),
+            handoffs: HashMap::new(),
+            remote_commands: HashMap::new(),
+            activity: watch::Sender::new(()),
         }
     }
 
+    /// No session is live, no schedule is waiting to run and no takeover is pending.
+    pub(crate) fn is_idle(&self) -> bool {
+        self.sessions
+            .values()
+            .all(|record| record.liveness == LivenessState::Stopped)
+            && self.schedules.earliest_next_run().is_none()
+            && self.handoffs.is_empty()
+    }
+
     /// Load sessions from persisted registry file.
     pub(crate) fn load_from_disk(&mut self) -> Result<()> {
         let registry_path = planning_paths::sessiond_registry_path()?;
@@ -21,6 +21,7 @@
 //! [2026-01-15T14:30:01.456Z] [AGENT:claude] stdout: Processing...
 //! ```
 
+use crate::agents::redaction::redact;
 use crate::planning_paths;
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -97,6 +98,7 @@ impl std::fmt::Display for LogCategory {
 ///
 /// All timestamps are in UTC ISO 8601 format for consistency and portability.
 pub struct SessionLogger {
+    session_id: String,
     main_log: Arc<Mutex<File>>,
     agent_log: Arc<Mutex<File>>,
     /// Minimum log level to output. Messages below this level are ignored.
@@ -164,12 +166,18 @@ impl SessionLogger {
         merge_startup_logs(&mut main);
 
         Ok(Self {
+            session_id: session_id.to_string(),
             main_log: Arc::new(Mutex::new(main)),
             agent_log: Arc::new(Mutex::new(agent)),
             log_level,
         })
     }
 
+    /// The session this logger writes to.
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
     /// Checks if a message at the given level should be logged.
     pub fn should_log(&self, level: LogLevel) -> bool {
         level <= self.log_level
@@ -187,6 +195,7 @@ impl SessionLogger {
         if let Ok(mut file) = self.main_log.lock() {
             let timestamp = format_timestamp();
             // Best-effort logging - failure here should not crash the workflow
+            let message = redact(message, &self.session_id);
             let _ = writeln!(
                 file,
                 "[{}] [{}] [{}] {}",
@@ -206,6 +215,7 @@ impl SessionLogger {
         if let Ok(mut file) = self.agent_log.lock() {
             let timestamp = format_timestamp();
             // Best-effort logging - failure here should not crash the workflow
+            let line = redact(line, &self.session_id);
             let _ = writeln!(file, "[{}][{}][{}] {}", timestamp, agent_name, kind, line);
             let _ = file.flush();
         }
@@ -9,15 +9,19 @@
 //!   NOT the entire TabManager state. This provides clear resume semantics.
 //! - **Snapshot location**: `~/.planning-agent/sessions/<workflow_session_id>.json`
 //! - **Versioned format**: Snapshots include a version field for future migrations.
+//! - **Integrity checks**: Snapshots carry a checksum. On load, a crash-truncated event
+//!   log is repaired and a corrupt snapshot is rebuilt from the event log; what was
+//!   lost is reported in `integrity_notes` instead of failing the resume.
 
 use crate::app::AccountUsage;
 use crate::domain::types::Phase;
 use crate::domain::view::WorkflowView;
+use crate::event_store::integrity::{record_corruption, repair_event_log, with_checksum};
 use crate::planning_paths;
 use crate::tui::scroll::ScrollState;
 use crate::tui::session::model::{
-    ApprovalContext, ApprovalMode, FeedbackTarget, FocusedPanel, InputMode, PasteBlock,
-    ReviewRound, RunTab, SessionStatus, TodoItem,
+    ApprovalContext, ApprovalMode, ChatVerbosity, FeedbackTarget, FocusedPanel, InputMode,
+    PasteBlock, ReviewRound, RunTab, SessionStatus, TodoItem,
 };
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -68,6 +72,9 @@ pub struct SessionSnapshot {
     /// Events after this sequence need to be replayed on resume.
     #[serde(default)]
     pub last_event_sequence: u64,
+    /// Repairs made while loading this snapshot, for display on resume.
+    #[serde(skip)]
+    pub integrity_notes: Vec<String>,
 }
 
 /// Serializable subset of Session that captures UI state.
@@ -90,6 +97,8 @@ pub struct SessionUiState {
 
     // Cost and metrics
     pub total_cost: f64,
+    #[serde(default)]
+    pub cost_estimated: bool,
     pub bytes_received: usize,
     pub total_input_tokens: u64,
     pub total_output_tokens: u64,
@@ -162,6 +171,12 @@ pub struct SessionUiState {
     pub review_history_spinner_frame: u8,
     #[serde(default)]
     pub review_history_scroll: ScrollState,
+
+    // Layout preferences (serde(default) for snapshots saved before they were persisted)
+    #[serde(default)]
+    pub todos_collapsed: bool,
+    #[serde(default)]
+    pub chat_verbosity: ChatVerbosity,
 }
 
 /// Information about a session snapshot for listing purposes.
@@ -200,6 +215,7 @@ impl SessionSnapshot {
             workflow_name,
             workflow_view,
             last_event_sequence,
+            integrity_notes: Vec::new(),
         }
     }
 
@@ -248,8 +264,9 @@ pub fn save_snapshot(snapshot: &SessionSnapshot) -> Result<PathBuf> {
     let snapshot_path = get_snapshot_path(&snapshot.workflow_session_id)?;
     let temp_path = snapshot_path.with_extension("json.tmp");
 
-    let content =
-        serde_json::to_string_pretty(snapshot).context("Failed to serialize session snapshot")?;
+    let content = with_checksum(snapshot)
+        .and_then(|value| serde_json::to_string_pretty(&value))
+        .context("Failed to serialize session snapshot")?;
 
     fs::write(&temp_path, &content).with_context(|| {
         format!(
@@ -271,30 +288,28 @@ pub fn save_snapshot(snapshot: &SessionSnapshot) -> Result<PathBuf> {
 
 /// Updates the session_info.json file for fast session listing.
 fn update_session_info(snapshot: &SessionSnapshot) -> Result<()> {
-    let info = planning_paths::SessionInfo {
-        session_id: snapshot.workflow_session_id.clone(),
-        feature_name: snapshot
-            .workflow_view
-            .feature_name()
-            .map(|f| f.0.clone())
-            .unwrap_or_default(),
-        objective: snapshot
-            .workflow_view
-            .objective()
-            .map(|o| o.0.clone())
+    let view = &snapshot.workflow_view;
+    let existing = planning_paths::SessionInfo::load(&snapshot.workflow_session_id).ok();
+    let mut info = planning_paths::SessionInfo::new(
+        &snapshot.workflow_session_id,
+        view.feature_name()
+            .map(|f| f.0.as_str())
             .unwrap_or_default(),
-        working_dir: snapshot.working_dir.clone(),
-        created_at: snapshot.saved_at.clone(), // Use saved_at as approximation
-        updated_at: snapshot.saved_at.clone(),
-        phase: format!(
-            "{:?}",
-            snapshot
-                .workflow_view
-                .planning_phase()
-                .unwrap_or(Phase::Planning)
-        ),
-        iteration: snapshot.workflow_view.iteration().map(|i| i.0).unwrap_or(1),
-    };
+        view.objective().map(|o| o.0.as_str()).unwrap_or_default(),
+        &snapshot.working_dir,
+        view.planning_phase().unwrap_or(Phase::Planning),
+        view.iteration().map(|i| i.0).unwrap_or(1),
+    );
+    info.updated_at = snapshot.saved_at.clone();
+    info.issue = view.issue_link().cloned();
+    match existing {
+        Some(existing) => {
+            info.created_at = existing.created_at;
+            // Only recorded when the session is created
+            info.language = existing.language;
+        }
+        None => info.created_at = snapshot.saved_at.clone(), // Use saved_at as approximation
+    }
     info.save(&snapshot.workflow_session_id)
 }
 
@@ -305,22 +320,67 @@ fn update_session_info(snapshot: &SessionSnapshot) -> Result<()> {
 /// If no snapshot file exists, attempts fallback recovery from the daemon registry
 /// and state file. This enables crash recovery when periodic auto-save didn't
 /// complete before the crash.
+///
+/// The event log is repaired first if a crash left it truncated. A snapshot that
+/// fails its checksum is moved aside and rebuilt the same way as a missing one.
 pub fn load_snapshot(session_id: &str) -> Result<SessionSnapshot> {
     let snapshot_path = get_snapshot_path(session_id)?;
+    let log_path = planning_paths::session_event_log_path(session_id)?;
+
+    let mut notes = Vec::new();
+    let log_repair = repair_event_log(&log_path)?;
+    if let Some(ref repair) = log_repair {
+        notes.push(repair.describe());
+    }
 
     if snapshot_path.exists() {
-        return load_snapshot_from_path(&snapshot_path);
+        let corruption = fs::read_to_string(&snapshot_path)
+            .ok()
+            .and_then(|content| record_corruption(&content));
+        match corruption {
+            None => {
+                let mut snapshot = load_snapshot_from_path(&snapshot_path)?;
+                // The snapshot may include events the repair discarded
+                let kept_sequence = log_repair.as_ref().map(|r| r.last_sequence);
+                if kept_sequence.is_some_and(|seq| snapshot.last_event_sequence > seq) {
+                    snapshot.workflow_view =
+                        crate::domain::actor::bootstrap_view_from_events(&log_path, session_id);
+                    snapshot.last_event_sequence = snapshot.workflow_view.last_event_sequence();
+                    notes.push(format!(
+                        "Workflow state rebuilt from the event log (event #{})",
+                        snapshot.last_event_sequence
+                    ));
+                }
+                snapshot.integrity_notes = notes;
+                return Ok(snapshot);
+            }
+            Some(reason) => {
+                let corrupt_path = snapshot_path.with_extension("json.corrupt");
+                fs::rename(&snapshot_path, &corrupt_path).with_context(|| {
+                    format!(
+                        "Failed to move aside corrupt snapshot: {}",
+                        corrupt_path.display()
+                    )
+                })?;
+                notes.push(format!(
+                    "Session snapshot was corrupt ({}); moved to {} and rebuilt from the event log (UI state reset)",
+                    reason,
+                    corrupt_path.display()
+                ));
+            }
+        }
     }
 
     // Try fallback recovery from state file + daemon registry
     match recover_from_state_file(session_id) {
-        Ok(snapshot) => {
+        Ok(mut snapshot) => {
             if let Err(e) = save_snapshot(&snapshot) {
                 eprintln!(
                     "[recovery] Warning: Failed to save recovered snapshot: {}",
                     e
                 );
             }
+            snapshot.integrity_notes = notes;
             Ok(snapshot)
         }
         Err(e) => {
@@ -406,7 +466,6 @@ pub fn list_snapshots() -> Result<Vec<SessionSnapshotInfo>> {
 ///
 /// Only the session.json file is deleted, not the entire session directory
 /// (which may contain other files like logs and state).
-#[cfg(test)]
 pub fn delete_snapshot(session_id: &str) -> Result<()> {
     let snapshot_path = get_snapshot_path(session_id)?;
     if snapshot_path.exists() {
@@ -569,6 +628,7 @@ impl SessionUiState {
             streaming_scroll: ScrollState::new(),
             focused_panel: FocusedPanel::Output,
             total_cost: 0.0,
+            cost_estimated: false,
             bytes_received: 0,
             total_input_tokens: 0,
             total_output_tokens: 0,
@@ -614,6 +674,8 @@ impl SessionUiState {
             review_history: Vec::new(),
             review_history_spinner_frame: 0,
             review_history_scroll: ScrollState::new(),
+            todos_collapsed: false,
+            chat_verbosity: ChatVerbosity::default(),
         }
     }
 }
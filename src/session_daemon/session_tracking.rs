@@ -4,12 +4,14 @@
 //! sessions with the session daemon, including background heartbeat tasks.
 
 use crate::domain::types::ImplementationPhase;
+use crate::rpc::host_service::RemoteCommand;
+use crate::rpc::DaemonError;
 use crate::session_daemon::{LivenessState, RpcClient, SessionRecord, WorkflowEventEnvelope};
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{broadcast, mpsc, Mutex};
 
 /// Implementation state for tracker updates.
 /// Uses `ImplementationPhase` enum for type safety in workflow code.
@@ -38,6 +40,9 @@ const HEARTBEAT_INTERVAL_MS: u64 = 500;
 /// Number of consecutive heartbeat failures before attempting reconnection.
 const RECONNECT_THRESHOLD: u32 = 2;
 
+/// First backoff interval after a failed reconnection attempt (seconds).
+const INITIAL_BACKOFF_SECS: u64 = 1;
+
 /// Maximum backoff interval for reconnection attempts (seconds).
 const MAX_BACKOFF_SECS: u64 = 60;
 
@@ -61,6 +66,10 @@ pub struct SessionTracker {
     active_sessions: Arc<Mutex<SessionMap>>,
     /// Channel to stop the heartbeat task
     _heartbeat_stop_tx: Option<mpsc::Sender<()>>,
+    /// Sessions another process asked to take over, with that process's PID
+    lease_lost_tx: broadcast::Sender<(String, u32)>,
+    /// Commands relayed from the host GUI, by session ID
+    remote_commands_tx: broadcast::Sender<(String, RemoteCommand)>,
     /// Whether session tracking is disabled
     disabled: bool,
 }
@@ -70,11 +79,15 @@ impl SessionTracker {
     ///
     /// If `no_daemon` is true, creates a disabled tracker that does nothing.
     pub async fn new(no_daemon: bool) -> Self {
+        let (lease_lost_tx, _) = broadcast::channel(4);
+        let (remote_commands_tx, _) = broadcast::channel(16);
         if no_daemon {
             return Self {
                 client: Arc::new(Mutex::new(RpcClient::new(true).await)),
                 active_sessions: Arc::new(Mutex::new(SessionMap::new())),
                 _heartbeat_stop_tx: None,
+                lease_lost_tx,
+                remote_commands_tx,
                 disabled: true,
             };
         }
@@ -86,6 +99,8 @@ impl SessionTracker {
         let (stop_tx, mut stop_rx) = mpsc::channel::<()>(1);
         let heartbeat_client = client.clone();
         let heartbeat_sessions = active_sessions.clone();
+        let heartbeat_lease_lost_tx = lease_lost_tx.clone();
+        let heartbeat_remote_commands_tx = remote_commands_tx.clone();
 
         tokio::spawn(async move {
             let mut interval =
@@ -96,8 +111,10 @@ impl SessionTracker {
             let mut last_error_log = std::time::Instant::now()
                 .checked_sub(std::time::Duration::from_secs(ERROR_LOG_INTERVAL_SECS))
                 .unwrap_or_else(std::time::Instant::now);
-            let mut backoff_secs = HEARTBEAT_INTERVAL_MS;
+            let mut backoff_secs = INITIAL_BACKOFF_SECS;
             let mut in_reconnect_mode = false;
+            // Takeovers already reported, so each is reported once
+            let mut lost_leases: HashSet<String> = HashSet::new();
 
             loop {
                 tokio::select! {
@@ -112,12 +129,33 @@ impl SessionTracker {
 
                         // Try heartbeat for each session
                         let mut any_failed = false;
+                        lost_leases.retain(|id| sessions.contains_key(id));
 
                         {
                             let client = heartbeat_client.lock().await;
                             for session_id in sessions.keys() {
-                                if client.heartbeat(session_id).await.is_err() {
-                                    any_failed = true;
+                                let Err(e) = client.heartbeat(session_id).await else {
+                                    // Best-effort: commands stay queued in the daemon on failure
+                                    let commands = client
+                                        .take_commands(session_id)
+                                        .await
+                                        .unwrap_or_default();
+                                    for command in commands {
+                                        let _ = heartbeat_remote_commands_tx
+                                            .send((session_id.clone(), command));
+                                    }
+                                    continue;
+                                };
+                                match e.downcast_ref::<DaemonError>() {
+                                    // The session stays registered until the workflow stops
+                                    Some(DaemonError::LeaseLost { holder_pid, .. }) => {
+                                        if lost_leases.insert(session_id.clone()) {
+                                            // No receiver means no workflow is listening
+                                            let _ = heartbeat_lease_lost_tx
+                                                .send((session_id.clone(), *holder_pid));
+                                        }
+                                    }
+                                    _ => any_failed = true,
                                 }
                             }
                         }
@@ -144,7 +182,7 @@ impl SessionTracker {
                                 match client.reconnect().await {
                                     Ok(()) => {
                                         consecutive_failures = 0;
-                                        backoff_secs = HEARTBEAT_INTERVAL_MS;
+                                        backoff_secs = INITIAL_BACKOFF_SECS;
                                         in_reconnect_mode = false;
 
                                         // Re-register sessions after reconnect.
@@ -167,7 +205,7 @@ impl SessionTracker {
                             // Success - reset failure state
                             if consecutive_failures > 0 || in_reconnect_mode {
                                 consecutive_failures = 0;
-                                backoff_secs = HEARTBEAT_INTERVAL_MS;
+                                backoff_secs = INITIAL_BACKOFF_SECS;
                                 in_reconnect_mode = false;
                                 interval = tokio::time::interval(
                                     tokio::time::Duration::from_millis(HEARTBEAT_INTERVAL_MS)
@@ -186,10 +224,53 @@ impl SessionTracker {
             client,
             active_sessions,
             _heartbeat_stop_tx: Some(stop_tx),
+            lease_lost_tx,
+            remote_commands_tx,
             disabled: false,
         }
     }
 
+    /// Sessions this process is asked to hand over, with the PID taking each over.
+    pub fn lease_lost(&self) -> broadcast::Receiver<(String, u32)> {
+        self.lease_lost_tx.subscribe()
+    }
+
+    /// Commands the host GUI relayed to this process's sessions, by session ID.
+    pub fn remote_commands(&self) -> broadcast::Receiver<(String, RemoteCommand)> {
+        self.remote_commands_tx.subscribe()
+    }
+
+    /// PID of another running process that holds the session, if any.
+    ///
+    /// Without a daemon connection no holder is known.
+    pub async fn lease_holder(&self, workflow_session_id: &str) -> Option<u32> {
+        if self.disabled {
+            return None;
+        }
+
+        let client = self.client.lock().await;
+        let own_pid = std::process::id();
+        client.list().await.ok()?.into_iter().find_map(|record| {
+            (record.workflow_session_id == workflow_session_id
+                && record.pid != own_pid
+                && record.liveness != LivenessState::Stopped)
+                .then_some(record.pid)
+        })
+    }
+
+    /// Asks the process holding the session to stop and hand it over to this one.
+    /// Returns the holder's PID; the session can be registered once it's released.
+    pub async fn take_over(&self, workflow_session_id: &str) -> Result<Option<u32>> {
+        if self.disabled {
+            return Ok(None);
+        }
+
+        let client = self.client.lock().await;
+        client
+            .take_over(workflow_session_id, std::process::id())
+            .await
+    }
+
     /// Returns true if session tracking is enabled and connected.
     #[cfg(test)]
     pub async fn is_connected(&self) -> bool {
@@ -228,10 +309,13 @@ impl SessionTracker {
             pid,
         );
 
-        // Register with daemon
+        // Register with daemon, starting it again if it exited while idle
         {
-            let client = self.client.lock().await;
-            client.register(record.clone()).await?;
+            let mut client = self.client.lock().await;
+            if client.register(record.clone()).await.is_err() {
+                client.reconnect().await?;
+                client.register(record.clone()).await?;
+            }
         }
 
         // Track locally
@@ -279,6 +363,94 @@ impl SessionTracker {
         Ok(())
     }
 
+    /// Updates a session's feature name in the daemon after `/rename`.
+    pub async fn rename(&self, workflow_session_id: &str, feature_name: &str) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+
+        let mut sessions = self.active_sessions.lock().await;
+
+        if let Some(info) = sessions.get_mut(workflow_session_id) {
+            info.record.feature_name = feature_name.to_string();
+            info.record.updated_at = chrono::Utc::now().to_rfc3339();
+
+            let client = self.client.lock().await;
+            client.update(info.record.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates a session's working directory in the daemon after it moved into a worktree.
+    pub async fn set_working_dir(
+        &self,
+        workflow_session_id: &str,
+        working_dir: PathBuf,
+    ) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+
+        let mut sessions = self.active_sessions.lock().await;
+
+        if let Some(info) = sessions.get_mut(workflow_session_id) {
+            info.record.working_dir = working_dir;
+            info.record.updated_at = chrono::Utc::now().to_rfc3339();
+
+            let client = self.client.lock().await;
+            client.update(info.record.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Reports the approval prompt the session waits on, for the host GUI to answer.
+    pub async fn set_approval_request(
+        &self,
+        workflow_session_id: &str,
+        request: Option<u64>,
+    ) -> Result<()> {
+        self.update_record(workflow_session_id, |record| {
+            record.approval_request = request;
+        })
+        .await
+    }
+
+    /// Reports a host command the session dropped because its prompt was already answered.
+    pub async fn report_stale_command(
+        &self,
+        workflow_session_id: &str,
+        command: String,
+    ) -> Result<()> {
+        self.update_record(workflow_session_id, |record| {
+            record.stale_command = Some(command);
+        })
+        .await
+    }
+
+    async fn update_record(
+        &self,
+        workflow_session_id: &str,
+        change: impl FnOnce(&mut SessionRecord),
+    ) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+
+        let mut sessions = self.active_sessions.lock().await;
+
+        if let Some(info) = sessions.get_mut(workflow_session_id) {
+            change(&mut info.record);
+            info.record.updated_at = chrono::Utc::now().to_rfc3339();
+
+            let client = self.client.lock().await;
+            client.update(info.record.clone()).await?;
+        }
+
+        Ok(())
+    }
+
     /// Updates a session with a terminal implementation state.
     /// Used for "Failed" and "Cancelled" which are protocol-only strings,
     /// not part of the ImplementationPhase enum.
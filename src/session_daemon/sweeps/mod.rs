@@ -0,0 +1,210 @@
+//! Background sweeps of the session daemon and its idle mode.
+//!
+//! While sessions are live the daemon pings subscribers every 30 seconds and
+//! checks session processes every 500ms. With no live session, schedule or
+//! pending takeover the daemon is idle: both sweeps stop and wait for activity,
+//! leaving only the accept loops, and after `PLANNING_SESSIOND_IDLE_EXIT_SECS`
+//! (10 minutes by default, 0 to never exit) without activity or subscribers the
+//! daemon shuts down. Clients start it again when they next register a session.
+
+use crate::daemon_log::daemon_log;
+use crate::session_daemon::rpc_upstream::UpstreamEvent;
+use crate::session_daemon::server::{DaemonState, SubscriberRegistry};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, watch, Mutex, RwLock};
+
+/// Idle period after which the daemon exits unless configured otherwise.
+pub const DEFAULT_IDLE_EXIT_SECS: u64 = 600;
+
+/// How long the daemon may stay idle before exiting; None keeps it running.
+pub fn idle_exit_after() -> Option<Duration> {
+    let secs = std::env::var("PLANNING_SESSIOND_IDLE_EXIT_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_IDLE_EXIT_SECS);
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// Waits for the next activity. Returns true if the daemon shuts down first.
+async fn wait_for_activity(
+    activity: &mut watch::Receiver<()>,
+    shutdown_rx: &mut broadcast::Receiver<()>,
+) -> bool {
+    tokio::select! {
+        changed = activity.changed() => changed.is_err(),
+        _ = shutdown_rx.recv() => true,
+    }
+}
+
+/// Waits while the daemon is idle, shutting it down once it has been idle for
+/// `exit_after` with no subscribers left. Returns immediately if the daemon is
+/// busy; returns true if the daemon is shutting down.
+pub(crate) async fn wait_while_idle(
+    state: &Mutex<DaemonState>,
+    subscribers: &RwLock<SubscriberRegistry>,
+    activity: &mut watch::Receiver<()>,
+    shutdown_tx: &broadcast::Sender<()>,
+    exit_after: Option<Duration>,
+) -> bool {
+    // Marked seen before checking, so activity from here on ends the wait
+    activity.borrow_and_update();
+    if !state.lock().await.is_idle() {
+        return false;
+    }
+    daemon_log("sweeps", "No live sessions, pausing background sweeps");
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    loop {
+        let idle_timeout = async {
+            match exit_after {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            changed = activity.changed() => {
+                if changed.is_err() {
+                    return true;
+                }
+                daemon_log("sweeps", "Activity, resuming background sweeps");
+                return false;
+            }
+            _ = idle_timeout => {
+                let no_subscribers = subscribers.read().await.count() == 0;
+                if no_subscribers && state.lock().await.is_idle() {
+                    daemon_log("sweeps", "Idle with no clients, shutting down");
+                    // Receivers only disappear once the daemon is already stopping
+                    let _ = shutdown_tx.send(());
+                    return true;
+                }
+                // Subscribed clients keep the daemon around for another idle period
+            }
+            _ = shutdown_rx.recv() => return true,
+        }
+    }
+}
+
+/// Background task to periodically clean up dead subscriber connections.
+/// Sends a ping to each subscriber and removes those that don't respond.
+/// Without subscribers it waits for activity instead of pinging.
+pub(crate) async fn run_subscriber_cleanup(
+    subscribers: Arc<RwLock<SubscriberRegistry>>,
+    mut activity: watch::Receiver<()>,
+    shutdown_tx: broadcast::Sender<()>,
+) {
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+
+    loop {
+        activity.borrow_and_update();
+        if subscribers.read().await.count() == 0 {
+            if wait_for_activity(&mut activity, &mut shutdown_rx).await {
+                break;
+            }
+            interval.reset();
+            continue;
+        }
+
+        tokio::select! {
+            _ = interval.tick() => {
+                // Ping all subscribers and collect those that failed
+                let failed = subscribers.read().await.ping_all().await;
+
+                // Remove failed subscribers
+                if !failed.is_empty() {
+                    let mut registry = subscribers.write().await;
+                    for id in &failed {
+                        registry.remove(id);
+                        daemon_log("rpc_server", &format!("Cleanup: removed dead subscriber {}", id));
+                    }
+                    daemon_log("rpc_server", &format!("Cleanup: {} dead subscribers removed, {} remaining",
+                        failed.len(), registry.count()));
+                }
+            }
+            _ = shutdown_rx.recv() => {
+                break;
+            }
+        }
+    }
+}
+
+/// Background task to periodically check if session-owning processes have exited.
+/// This provides instant detection of crashed/killed processes without waiting for
+/// heartbeat timeouts. Runs every 500ms to match the heartbeat interval, and is
+/// where the daemon waits out idle periods. With an upstream host the daemon
+/// never exits on its own, since the host relies on it.
+///
+/// NOTE: This task sends SessionUpdate through subscribers and upstream_tx.
+/// The notify_subscribers() method always sends SessionUpdate (not SessionGone),
+/// keeping disconnected sessions visible in the host GUI.
+pub(crate) async fn run_process_liveness_monitor(
+    state: Arc<Mutex<DaemonState>>,
+    subscribers: Arc<RwLock<SubscriberRegistry>>,
+    upstream_tx: Option<mpsc::UnboundedSender<UpstreamEvent>>,
+    shutdown_tx: broadcast::Sender<()>,
+) {
+    let mut shutdown_rx = shutdown_tx.subscribe();
+    let mut activity = state.lock().await.activity.subscribe();
+    let exit_after = idle_exit_after().filter(|_| upstream_tx.is_none());
+    // Check every 500ms to match heartbeat interval
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(500));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                // Check for dead processes
+                let changed = {
+                    let mut state = state.lock().await;
+                    state.check_process_liveness()
+                };
+
+                // Notify subscribers and upstream for each changed session
+                for record in changed {
+                    daemon_log(
+                        "liveness_monitor",
+                        &format!(
+                            "Process {} exited, marking session {} as Stopped",
+                            record.pid, record.workflow_session_id
+                        ),
+                    );
+
+                    // Notify local subscribers
+                    {
+                        let failed = {
+                            let registry = subscribers.read().await;
+                            registry.broadcast_session_changed(record.clone()).await
+                        };
+                        if !failed.is_empty() {
+                            let mut registry = subscribers.write().await;
+                            for id in failed {
+                                registry.remove(&id);
+                            }
+                        }
+                    }
+
+                    // Forward to upstream host as SessionUpdate.
+                    // Note: We send SessionUpdate directly here rather than going through
+                    // a shared method to avoid re-acquiring locks. The effect is the same
+                    // since we always send SessionUpdate for liveness changes.
+                    if let Some(ref tx) = upstream_tx {
+                        // Channel send can fail if upstream receiver dropped (e.g., during shutdown).
+                        // This is expected and safe to ignore.
+                        let _ = tx.send(UpstreamEvent::SessionUpdate(record));
+                    }
+                }
+
+                if wait_while_idle(&state, &subscribers, &mut activity, &shutdown_tx, exit_after).await {
+                    break;
+                }
+                interval.reset();
+            }
+            _ = shutdown_rx.recv() => {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/sweeps_tests.rs"]
+mod tests;
@@ -0,0 +1,121 @@
+//! Tests for the daemon's idle mode.
+
+use super::*;
+use crate::rpc::{LivenessState, SessionRecord};
+use std::path::PathBuf;
+
+fn record(id: &str, liveness: LivenessState) -> SessionRecord {
+    let mut record = SessionRecord::new(
+        id.to_string(),
+        "test-feature".to_string(),
+        PathBuf::from("/test"),
+        PathBuf::from("/test/sessions").join(id),
+        "Planning".to_string(),
+        1,
+        "Planning".to_string(),
+        std::process::id(),
+    );
+    record.liveness = liveness;
+    record
+}
+
+#[test]
+fn test_daemon_is_idle_without_live_sessions_or_handoffs() {
+    let mut state = DaemonState::new();
+    assert!(state.is_idle());
+
+    let stopped = record("stopped", LivenessState::Stopped);
+    state.sessions.insert("stopped".to_string(), stopped);
+    assert!(state.is_idle());
+
+    let running = record("running", LivenessState::Running);
+    state.sessions.insert("running".to_string(), running);
+    assert!(!state.is_idle());
+
+    state.sessions.remove("running");
+    state.handoffs.insert("stopped".to_string(), 42);
+    assert!(!state.is_idle());
+}
+
+#[tokio::test]
+async fn test_wait_while_idle_returns_at_once_when_busy() {
+    let mut state = DaemonState::new();
+    let running = record("running", LivenessState::Running);
+    state.sessions.insert("running".to_string(), running);
+    let mut activity = state.activity.subscribe();
+    let state = Mutex::new(state);
+    let subscribers = RwLock::new(SubscriberRegistry::new());
+    let (shutdown_tx, _) = broadcast::channel(1);
+
+    let stopping = wait_while_idle(
+        &state,
+        &subscribers,
+        &mut activity,
+        &shutdown_tx,
+        Some(Duration::from_millis(10)),
+    )
+    .await;
+    assert!(!stopping);
+}
+
+#[tokio::test]
+async fn test_wait_while_idle_resumes_on_activity() {
+    let state = Arc::new(Mutex::new(DaemonState::new()));
+    let mut activity = state.lock().await.activity.subscribe();
+    let subscribers = RwLock::new(SubscriberRegistry::new());
+    let (shutdown_tx, mut shutdown_rx) = broadcast::channel(1);
+
+    let waker_state = state.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        waker_state.lock().await.activity.send_replace(());
+    });
+    let stopping = tokio::time::timeout(
+        Duration::from_secs(5),
+        wait_while_idle(&state, &subscribers, &mut activity, &shutdown_tx, None),
+    )
+    .await
+    .expect("activity should end the idle wait");
+    assert!(!stopping);
+    assert!(shutdown_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_idle_daemon_shuts_down_after_idle_period() {
+    let state = Mutex::new(DaemonState::new());
+    let mut activity = state.lock().await.activity.subscribe();
+    let subscribers = RwLock::new(SubscriberRegistry::new());
+    let (shutdown_tx, mut shutdown_rx) = broadcast::channel(1);
+
+    let stopping = tokio::time::timeout(
+        Duration::from_secs(5),
+        wait_while_idle(
+            &state,
+            &subscribers,
+            &mut activity,
+            &shutdown_tx,
+            Some(Duration::from_millis(20)),
+        ),
+    )
+    .await
+    .expect("idle period should end the wait");
+    assert!(stopping);
+    assert!(shutdown_rx.try_recv().is_ok());
+}
+
+#[test]
+#[serial_test::serial]
+fn test_idle_exit_after_reads_environment() {
+    std::env::remove_var("PLANNING_SESSIOND_IDLE_EXIT_SECS");
+    assert_eq!(
+        idle_exit_after(),
+        Some(Duration::from_secs(DEFAULT_IDLE_EXIT_SECS))
+    );
+
+    std::env::set_var("PLANNING_SESSIOND_IDLE_EXIT_SECS", "0");
+    assert_eq!(idle_exit_after(), None);
+
+    std::env::set_var("PLANNING_SESSIOND_IDLE_EXIT_SECS", "30");
+    assert_eq!(idle_exit_after(), Some(Duration::from_secs(30)));
+    std::env::remove_var("PLANNING_SESSIOND_IDLE_EXIT_SECS");
+}
@@ -52,6 +52,7 @@ fn test_port_file_content_serialization() {
         subscriber_port: 12346,
         file_service_port: 12347,
         token: "secret-token".to_string(),
+        socket_path: None,
     };
 
     let json = serde_json::to_string(&content).unwrap();
@@ -115,3 +116,30 @@ fn test_session_record_serialization() {
     assert_eq!(parsed.pid, 12345);
     assert_eq!(parsed.liveness, LivenessState::Running);
 }
+
+#[test]
+fn test_port_file_is_private_and_replaced() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("sessiond.port");
+    std::fs::write(&path, "stale").unwrap();
+    let content = PortFileContent {
+        port: 1,
+        subscriber_port: 2,
+        file_service_port: 3,
+        token: "secret-token".to_string(),
+        socket_path: Some(dir.path().join("sessiond.sock")),
+    };
+
+    content.write_private(&path).unwrap();
+
+    let parsed: PortFileContent =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(parsed.token, "secret-token");
+    assert_eq!(parsed.socket_path, content.socket_path);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn test_stale_daemon_reason() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid_path = dir.path().join("sessiond.pid");
+    assert_eq!(
+        stale_daemon_reason(&pid_path).as_deref(),
+        Some("no PID file")
+    );
+
+    std::fs::write(&pid_path, std::process::id().to_string()).unwrap();
+    assert_eq!(stale_daemon_reason(&pid_path), None);
+
+    std::fs::write(&pid_path, "garbage").unwrap();
+    assert_eq!(
+        stale_daemon_reason(&pid_path).as_deref(),
+        Some("unreadable PID file")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_exited_daemon_is_stale() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid_path = dir.path().join("sessiond.pid");
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+
+    std::fs::write(&pid_path, pid.to_string()).unwrap();
+    assert_eq!(
+        stale_daemon_reason(&pid_path),
+        Some(format!("daemon PID {} is not running", pid))
+    );
+}
+
+#[test]
+fn test_record_stale_recovery_counts_up() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("sessiond.recoveries");
+    assert_eq!(record_stale_recovery(&path), 1);
+    assert_eq!(record_stale_recovery(&path), 2);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "2");
+}
@@ -21,3 +21,24 @@ fn test_subscription_event_debug() {
     let debug_str = format!("{:?}", event);
     assert!(debug_str.contains("DaemonRestarting"));
 }
+
+#[test]
+fn test_reconnect_backoff_grows_with_jitter_up_to_max() {
+    let mut backoff = ReconnectBackoff::default();
+
+    let first = backoff.next_delay();
+    assert!(first >= RECONNECT_BASE_DELAY.mul_f64(0.75));
+    assert!(first <= RECONNECT_BASE_DELAY.mul_f64(1.25));
+
+    let second = backoff.next_delay();
+    assert!(second >= (RECONNECT_BASE_DELAY * 2).mul_f64(0.75));
+    assert!(second <= (RECONNECT_BASE_DELAY * 2).mul_f64(1.25));
+
+    for _ in 0..40 {
+        assert!(backoff.next_delay() <= RECONNECT_MAX_DELAY);
+    }
+    assert!(backoff.next_delay() >= RECONNECT_MAX_DELAY.mul_f64(0.75));
+
+    backoff.reset();
+    assert!(backoff.next_delay() <= RECONNECT_BASE_DELAY.mul_f64(1.25));
+}
@@ -24,3 +24,23 @@ fn test_host_port_disabled() {
     assert_eq!(host_port(), None);
     std::env::remove_var("PLANNING_AGENT_HOST_PORT");
 }
+
+#[test]
+fn test_read_new_lines_leaves_partial_line() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("agent-stream.log");
+    std::fs::write(&path, "first\nsecond\nthi").unwrap();
+
+    let mut offset = 0;
+    assert_eq!(read_new_lines(&path, &mut offset), vec!["first", "second"]);
+    assert_eq!(offset, 13);
+    assert!(read_new_lines(&path, &mut offset).is_empty());
+
+    std::fs::write(&path, "first\nsecond\nthird\n").unwrap();
+    assert_eq!(read_new_lines(&path, &mut offset), vec!["third"]);
+
+    // A recreated log is read from the start
+    std::fs::write(&path, "new\n").unwrap();
+    assert_eq!(read_new_lines(&path, &mut offset), vec!["new"]);
+    assert_eq!(offset, 4);
+}
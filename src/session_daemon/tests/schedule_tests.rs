@@ -0,0 +1,174 @@
+//! Tests for scheduled workflow parsing, timing, and persistence.
+
+use super::*;
+use crate::planning_paths::{set_home_for_test, TestHomeGuard};
+use tempfile::tempdir;
+
+/// Helper to set up an isolated test home directory.
+fn test_env() -> (tempfile::TempDir, TestHomeGuard) {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let guard = set_home_for_test(dir.path().to_path_buf());
+    (dir, guard)
+}
+
+/// Monday 2026-01-05 at the given local time.
+fn monday_at(hour: u32, minute: u32) -> DateTime<Local> {
+    Local
+        .with_ymd_and_hms(2026, 1, 5, hour, minute, 0)
+        .single()
+        .expect("unambiguous local time")
+}
+
+fn test_schedule(spec: ScheduleSpec) -> ScheduledWorkflow {
+    ScheduledWorkflow::new(
+        spec,
+        "Refresh dependency plan".to_string(),
+        PathBuf::from("/tmp/repo"),
+        3,
+        true,
+    )
+}
+
+#[test]
+fn test_parse_weekly_forms() {
+    let expected = ScheduleSpec::Weekly {
+        weekday: Weekday::Mon,
+        hour: 9,
+        minute: 0,
+    };
+    assert_eq!(ScheduleSpec::parse("every monday 09:00").unwrap(), expected);
+    assert_eq!(
+        ScheduleSpec::parse("Every Monday at 9:00").unwrap(),
+        expected
+    );
+    assert_eq!(ScheduleSpec::parse("weekly mon 09:00").unwrap(), expected);
+}
+
+#[test]
+fn test_parse_daily_and_interval() {
+    assert_eq!(
+        ScheduleSpec::parse("daily 18:30").unwrap(),
+        ScheduleSpec::Daily {
+            hour: 18,
+            minute: 30
+        }
+    );
+    assert_eq!(
+        ScheduleSpec::parse("every day at 07:05").unwrap(),
+        ScheduleSpec::Daily { hour: 7, minute: 5 }
+    );
+    assert_eq!(
+        ScheduleSpec::parse("every 6h").unwrap(),
+        ScheduleSpec::Interval { minutes: 360 }
+    );
+    assert_eq!(
+        ScheduleSpec::parse("every 45m").unwrap(),
+        ScheduleSpec::Interval { minutes: 45 }
+    );
+}
+
+#[test]
+fn test_parse_rejects_invalid() {
+    assert!(ScheduleSpec::parse("").is_err());
+    assert!(ScheduleSpec::parse("every funday 09:00").is_err());
+    assert!(ScheduleSpec::parse("daily 24:00").is_err());
+    assert!(ScheduleSpec::parse("daily 9").is_err());
+    assert!(ScheduleSpec::parse("every 0m").is_err());
+    assert!(ScheduleSpec::parse("every 5s").is_err());
+}
+
+#[test]
+fn test_display_round_trips() {
+    for input in ["every mon 09:00", "daily 18:30", "every 6h", "every 45m"] {
+        let spec = ScheduleSpec::parse(input).unwrap();
+        assert_eq!(ScheduleSpec::parse(&spec.to_string()).unwrap(), spec);
+    }
+}
+
+#[test]
+fn test_next_after_weekly() {
+    let spec = ScheduleSpec::parse("every monday 09:00").unwrap();
+
+    // Before the slot on the same day: runs today
+    assert_eq!(spec.next_after(monday_at(8, 0)), monday_at(9, 0));
+
+    // Exactly at or after the slot: runs next week
+    let next = spec.next_after(monday_at(9, 0));
+    assert_eq!(next.weekday(), Weekday::Mon);
+    assert_eq!(
+        next.date_naive(),
+        monday_at(9, 0).date_naive() + Days::new(7)
+    );
+}
+
+#[test]
+fn test_next_after_daily_and_interval() {
+    let daily = ScheduleSpec::parse("daily 07:00").unwrap();
+    let next = daily.next_after(monday_at(8, 0));
+    assert_eq!(
+        next.date_naive(),
+        monday_at(8, 0).date_naive() + Days::new(1)
+    );
+    assert_eq!(next.format("%H:%M").to_string(), "07:00");
+
+    let interval = ScheduleSpec::parse("every 30m").unwrap();
+    assert_eq!(interval.next_after(monday_at(8, 0)), monday_at(8, 30));
+}
+
+#[test]
+fn test_take_due_advances_schedule() {
+    let mut registry = ScheduleRegistry::default();
+    let mut schedule = test_schedule(ScheduleSpec::parse("daily 09:00").unwrap());
+    schedule.next_run_at = monday_at(9, 0).to_rfc3339();
+    registry.add(schedule.clone());
+
+    assert!(registry.take_due(monday_at(8, 59)).is_empty());
+
+    let due = registry.take_due(monday_at(9, 1));
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id, schedule.id);
+    assert_eq!(due[0].last_run_at, Some(monday_at(9, 1).to_rfc3339()));
+    assert!(due[0].next_run() > monday_at(9, 1));
+
+    // Already advanced: not due again at the same instant
+    assert!(registry.take_due(monday_at(9, 1)).is_empty());
+}
+
+#[test]
+fn test_registry_add_remove_list() {
+    let mut registry = ScheduleRegistry::default();
+    let hourly = test_schedule(ScheduleSpec::parse("every 1h").unwrap());
+    let minutely = test_schedule(ScheduleSpec::parse("every 5m").unwrap());
+    registry.add(hourly.clone());
+    registry.add(minutely.clone());
+
+    let listed = registry.list();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].id, minutely.id, "Soonest schedule listed first");
+    assert_eq!(registry.earliest_next_run(), Some(minutely.next_run()));
+
+    assert!(registry.remove(&hourly.id));
+    assert!(!registry.remove(&hourly.id));
+    assert_eq!(registry.list().len(), 1);
+}
+
+#[test]
+fn test_registry_persist_and_load() {
+    let (_dir, _guard) = test_env();
+
+    let mut registry = ScheduleRegistry::default();
+    let schedule = test_schedule(ScheduleSpec::parse("every friday 17:00").unwrap());
+    registry.add(schedule.clone());
+    registry.persist().unwrap();
+
+    let loaded = ScheduleRegistry::load().unwrap();
+    assert_eq!(loaded.list(), vec![schedule]);
+}
+
+#[test]
+fn test_registry_load_missing_file() {
+    let (_dir, _guard) = test_env();
+
+    let loaded = ScheduleRegistry::load().unwrap();
+    assert!(loaded.list().is_empty());
+}
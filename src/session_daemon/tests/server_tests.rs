@@ -1,7 +1,9 @@
 //! Tests for the session daemon state management.
 
-use crate::rpc::{LivenessState, SessionRecord};
-use crate::session_daemon::server::DaemonState;
+use crate::domain::types::TimestampUtc;
+use crate::domain::WorkflowEvent;
+use crate::rpc::{LivenessState, SessionRecord, WorkflowEventEnvelope};
+use crate::session_daemon::server::{DaemonState, EventJournal};
 use crate::update::BUILD_SHA;
 use std::path::PathBuf;
 
@@ -19,6 +21,65 @@ fn create_test_record(id: &str, pid: u32) -> SessionRecord {
     )
 }
 
+/// Create a test event envelope for the journal.
+fn create_test_event(aggregate_id: &str, sequence: u64) -> WorkflowEventEnvelope {
+    WorkflowEventEnvelope {
+        aggregate_id: aggregate_id.to_string(),
+        sequence,
+        event: WorkflowEvent::PlanningStarted {
+            started_at: TimestampUtc::now(),
+        },
+    }
+}
+
+#[test]
+fn test_event_journal_skips_replayed_sequences() {
+    let mut journal = EventJournal::default();
+    journal.record("s1", create_test_event("agg-1", 1));
+    journal.record("s1", create_test_event("agg-1", 2));
+    journal.record("s1", create_test_event("agg-1", 2));
+    journal.record("s1", create_test_event("agg-1", 1));
+
+    let sequences: Vec<u64> = journal
+        .session_events("s1")
+        .iter()
+        .map(|e| e.sequence)
+        .collect();
+    assert_eq!(sequences, vec![1, 2]);
+}
+
+#[test]
+fn test_event_journal_bounds_events_per_session() {
+    let mut journal = EventJournal::default();
+    for seq in 1..=250 {
+        journal.record("s1", create_test_event("agg-1", seq));
+    }
+
+    let events = journal.session_events("s1");
+    assert_eq!(events.len(), 200);
+    assert_eq!(events[0].sequence, 51);
+    assert_eq!(events[199].sequence, 250);
+}
+
+#[test]
+fn test_event_journal_catch_up_orders_by_recency_and_evicts() {
+    let mut journal = EventJournal::default();
+    journal.record("a", create_test_event("agg-a", 1));
+    journal.record("b", create_test_event("agg-b", 1));
+    journal.record("a", create_test_event("agg-a", 2));
+
+    let ids: Vec<String> = journal.catch_up().into_iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, vec!["b".to_string(), "a".to_string()]);
+
+    for i in 0..50 {
+        journal.record(&format!("extra-{}", i), create_test_event("agg-x", 1));
+    }
+    let catch_up = journal.catch_up();
+    assert_eq!(catch_up.len(), 50);
+    assert!(journal.session_events("b").is_empty());
+    assert!(journal.session_events("a").is_empty());
+}
+
 #[test]
 fn test_daemon_state_insert_and_get() {
     let mut state = DaemonState::new();
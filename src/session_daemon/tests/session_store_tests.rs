@@ -51,6 +51,7 @@ fn create_test_ui_state() -> SessionUiState {
         streaming_scroll: ScrollState::new(),
         focused_panel: FocusedPanel::Output,
         total_cost: 0.0,
+        cost_estimated: false,
         bytes_received: 100,
         total_input_tokens: 50,
         total_output_tokens: 30,
@@ -96,6 +97,8 @@ fn create_test_ui_state() -> SessionUiState {
         review_history: Vec::new(),
         review_history_spinner_frame: 0,
         review_history_scroll: ScrollState::new(),
+        todos_collapsed: false,
+        chat_verbosity: ChatVerbosity::default(),
     }
 }
 
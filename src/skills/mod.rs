@@ -0,0 +1,138 @@
+//! Agent skill installation.
+//!
+//! Built-in skills ship with the binary. A project can override or extend them with
+//! pinned skill packs (see [`packs`]); the resulting set is written to the Claude and
+//! Codex skill directories before a workflow starts.
+
+pub mod packs;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const PLANNING_SKILL: &str = include_str!("../../skills/planning/SKILL.md");
+const PLAN_REVIEW_ADVERSARIAL_SKILL: &str =
+    include_str!("../../skills/plan-review-adversarial/SKILL.md");
+const PLAN_REVIEW_OPERATIONAL_SKILL: &str =
+    include_str!("../../skills/plan-review-operational/SKILL.md");
+const PLAN_REVIEW_CODEBASE_SKILL: &str = include_str!("../../skills/plan-review-codebase/SKILL.md");
+const METHODICAL_DEBUGGING_SKILL: &str = include_str!("../../skills/methodical-debugging/SKILL.md");
+const IMPLEMENTATION_SKILL: &str = include_str!("../../skills/implementation/SKILL.md");
+const IMPLEMENTATION_REVIEW_SKILL: &str =
+    include_str!("../../skills/implementation-review/SKILL.md");
+
+/// Marker written into skill directories installed from a pack, holding `name@version`.
+/// Marked directories no longer provided by the project's pins are removed.
+const PACK_MARKER_FILE: &str = ".planning-agent-pack";
+
+/// Built-in skills as (name, content) pairs.
+pub const BUILTIN_SKILLS: [(&str, &str); 7] = [
+    ("planning", PLANNING_SKILL),
+    ("plan-review-adversarial", PLAN_REVIEW_ADVERSARIAL_SKILL),
+    ("plan-review-operational", PLAN_REVIEW_OPERATIONAL_SKILL),
+    ("plan-review-codebase", PLAN_REVIEW_CODEBASE_SKILL),
+    ("methodical-debugging", METHODICAL_DEBUGGING_SKILL),
+    ("implementation", IMPLEMENTATION_SKILL),
+    ("implementation-review", IMPLEMENTATION_REVIEW_SKILL),
+];
+
+fn skills_dirs() -> anyhow::Result<[PathBuf; 2]> {
+    let home =
+        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+    let codex_home = std::env::var_os("CODEX_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".codex"));
+    Ok([home.join(".claude/skills"), codex_home.join("skills")])
+}
+
+/// Installs the built-in skills, overlaid with skills from packs pinned in
+/// `working_dir`'s `.planning-agent.toml`.
+///
+/// A pin that is missing or fails checksum verification is reported and the
+/// built-in skills are used instead.
+pub fn install_skills_if_needed(working_dir: &Path) -> anyhow::Result<()> {
+    let mut skills: BTreeMap<String, (Option<String>, String)> = BUILTIN_SKILLS
+        .iter()
+        .map(|(name, content)| (name.to_string(), (None, content.to_string())))
+        .collect();
+    match packs::pinned_skills(working_dir) {
+        Ok(pinned) => {
+            for (name, skill) in pinned {
+                skills.insert(name, (Some(skill.pack), skill.content));
+            }
+        }
+        Err(e) => eprintln!(
+            "[planning-agent] Warning: Ignoring pinned skill packs: {:#}",
+            e
+        ),
+    }
+
+    for skills_dir in &skills_dirs()? {
+        install_skill_set(skills_dir, &skills)?;
+    }
+    Ok(())
+}
+
+/// Writes `skills` (name -> (pack label, content)) into one agent skill directory.
+fn install_skill_set(
+    skills_dir: &Path,
+    skills: &BTreeMap<String, (Option<String>, String)>,
+) -> anyhow::Result<()> {
+    for (name, (pack, content)) in skills {
+        let skill_dir = skills_dir.join(name);
+        let skill_file = skill_dir.join("SKILL.md");
+        let marker_file = skill_dir.join(PACK_MARKER_FILE);
+        let installed_pack = fs::read_to_string(&marker_file).ok();
+
+        let mut action = "Installing";
+        let should_write = match fs::read_to_string(&skill_file) {
+            Ok(existing) => {
+                if existing == *content && installed_pack == *pack {
+                    false
+                } else {
+                    action = "Updating";
+                    true
+                }
+            }
+            Err(_) => true,
+        };
+
+        if should_write {
+            match pack {
+                Some(pack) => eprintln!("[planning-agent] {} skill: {} ({})", action, name, pack),
+                None => eprintln!("[planning-agent] {} skill: {}", action, name),
+            }
+            fs::create_dir_all(&skill_dir)?;
+            fs::write(&skill_file, content)?;
+            match pack {
+                Some(pack) => fs::write(&marker_file, pack)?,
+                None if installed_pack.is_some() => fs::remove_file(&marker_file)?,
+                None => {}
+            }
+        }
+    }
+
+    // Remove pack-only skills the project no longer pins
+    if let Ok(entries) = fs::read_dir(skills_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !skills.contains_key(&name) && entry.path().join(PACK_MARKER_FILE).exists() {
+                eprintln!("[planning-agent] Removing unpinned pack skill: {}", name);
+                fs::remove_dir_all(entry.path())?;
+            }
+        }
+    }
+
+    // Clean up deprecated skill: plan-review (replaced by specialized variants)
+    let old_skill_dir = skills_dir.join("plan-review");
+    if old_skill_dir.exists() {
+        eprintln!("[planning-agent] Removing deprecated skill: plan-review");
+        fs::remove_dir_all(&old_skill_dir)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/skills_tests.rs"]
+mod tests;
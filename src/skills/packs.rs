@@ -0,0 +1,392 @@
+//! Versioned skill packs and per-project pinning.
+//!
+//! A skill pack is a directory with a `pack.json` manifest and one `<skill>/SKILL.md`
+//! per skill. Installing a pack verifies the manifest checksums and copies it into
+//! `~/.planning-agent/skill-packs/<name>/<version>/`. Projects pin pack versions in
+//! `.planning-agent.toml`:
+//!
+//! ```toml
+//! [skills.acme-planning]
+//! version = "1.2.0"
+//! sha256 = "<pack checksum>"
+//! ```
+//!
+//! Pinned packs are re-verified against their manifest and the pinned checksum
+//! before their skills are installed, so a team plans with identical prompts.
+
+use crate::planning_paths::{self, hex_encode};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manifest file at the root of every skill pack.
+pub const MANIFEST_FILE: &str = "pack.json";
+
+/// Per-project settings file holding skill pack pins.
+pub const PROJECT_CONFIG_FILE: &str = ".planning-agent.toml";
+
+/// Contents of a pack's `pack.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackManifest {
+    pub name: String,
+    pub version: String,
+    /// Skill name -> SHA-256 of its SKILL.md. Empty checksums are filled in on install.
+    #[serde(default)]
+    pub skills: BTreeMap<String, String>,
+    /// Directory the pack was installed from, used by `planning skills update`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PathBuf>,
+}
+
+impl PackManifest {
+    /// Checksum over all skill checksums, identifying the pack's exact content.
+    pub fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        for (skill, sum) in &self.skills {
+            hasher.update(format!("{}:{}\n", skill, sum));
+        }
+        hex_encode(&hasher.finalize())
+    }
+
+    /// `name@version` label used in messages and install markers.
+    pub fn label(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+}
+
+/// A pack version pinned by a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackPin {
+    pub name: String,
+    pub version: String,
+    pub sha256: Option<String>,
+}
+
+/// A skill provided by a pinned pack.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackSkill {
+    pub pack: String,
+    pub content: String,
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    hex_encode(&Sha256::digest(content))
+}
+
+/// Names and versions become directory names, so keep them to a safe charset.
+fn validate_component(kind: &str, value: &str) -> Result<()> {
+    let valid = !value.is_empty()
+        && !value.starts_with('.')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    if !valid {
+        bail!(
+            "Invalid {} '{}': use letters, digits, '.', '_' and '-'",
+            kind,
+            value
+        );
+    }
+    Ok(())
+}
+
+fn read_manifest(pack_dir: &Path) -> Result<PackManifest> {
+    let path = pack_dir.join(MANIFEST_FILE);
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// Reads a pack directory and verifies every skill against its manifest checksum.
+///
+/// Missing checksums are computed unless `require_checksums` is set, in which case
+/// they are an error (installed packs always carry them).
+pub fn load_pack(
+    pack_dir: &Path,
+    require_checksums: bool,
+) -> Result<(PackManifest, BTreeMap<String, String>)> {
+    let mut manifest = read_manifest(pack_dir)?;
+    validate_component("pack name", &manifest.name)?;
+    validate_component("pack version", &manifest.version)?;
+    if manifest.skills.is_empty() {
+        bail!("Skill pack {} lists no skills", manifest.label());
+    }
+
+    let label = manifest.label();
+    let mut contents = BTreeMap::new();
+    for (skill, expected) in manifest.skills.iter_mut() {
+        validate_component("skill name", skill)?;
+        let path = pack_dir.join(skill.as_str()).join("SKILL.md");
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let actual = sha256_hex(content.as_bytes());
+        if expected.is_empty() {
+            if require_checksums {
+                bail!("Skill '{}' in {} has no checksum", skill, label);
+            }
+            *expected = actual;
+        } else if *expected != actual {
+            bail!(
+                "Checksum mismatch for skill '{}' in {} (expected {}, found {})",
+                skill,
+                label,
+                expected,
+                actual
+            );
+        }
+        contents.insert(skill.clone(), content);
+    }
+    Ok((manifest, contents))
+}
+
+fn installed_dir(name: &str, version: &str) -> Result<PathBuf> {
+    validate_component("pack name", name)?;
+    validate_component("pack version", version)?;
+    Ok(planning_paths::skill_packs_dir()?.join(name).join(version))
+}
+
+/// Installs a pack from a source directory into the pack store.
+///
+/// Reinstalling identical content is a no-op; a version that is already installed
+/// with different content is rejected so a pinned version never changes underneath
+/// a project.
+pub fn install_pack(source: &Path) -> Result<PackManifest> {
+    let source = fs::canonicalize(source)
+        .with_context(|| format!("Skill pack not found: {}", source.display()))?;
+    let (mut manifest, contents) = load_pack(&source, false)?;
+    manifest.source = Some(source);
+
+    let dest = installed_dir(&manifest.name, &manifest.version)?;
+    if let Ok(existing) = read_manifest(&dest) {
+        if existing.checksum() != manifest.checksum() {
+            bail!(
+                "{} is already installed with different content; bump the pack version",
+                manifest.label()
+            );
+        }
+    }
+
+    for (skill, content) in &contents {
+        let skill_dir = dest.join(skill);
+        fs::create_dir_all(&skill_dir)
+            .with_context(|| format!("Failed to create {}", skill_dir.display()))?;
+        fs::write(skill_dir.join("SKILL.md"), content)?;
+    }
+    // Manifest last, so a partially copied pack is never listed as installed
+    fs::write(
+        dest.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    Ok(manifest)
+}
+
+/// Loads an installed pack, verifying its skills against the stored checksums.
+pub fn load_installed(
+    name: &str,
+    version: &str,
+) -> Result<(PackManifest, BTreeMap<String, String>)> {
+    load_pack(&installed_dir(name, version)?, true)
+}
+
+/// All installed pack versions, sorted by name then version.
+pub fn installed_packs() -> Result<Vec<PackManifest>> {
+    let store = planning_paths::skill_packs_dir()?;
+    let mut packs = Vec::new();
+    for name_entry in fs::read_dir(&store)?.flatten() {
+        let Ok(versions) = fs::read_dir(name_entry.path()) else {
+            continue;
+        };
+        for version_entry in versions.flatten() {
+            if let Ok(manifest) = read_manifest(&version_entry.path()) {
+                packs.push(manifest);
+            }
+        }
+    }
+    packs.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    Ok(packs)
+}
+
+/// Removes a pack from the store (all versions unless one is given).
+/// Returns the removed versions.
+pub fn remove_pack(name: &str, version: Option<&str>) -> Result<Vec<String>> {
+    let removed: Vec<String> = installed_packs()?
+        .into_iter()
+        .filter(|p| p.name == name && version.is_none_or(|v| p.version == v))
+        .map(|p| p.version)
+        .collect();
+    if removed.is_empty() {
+        match version {
+            Some(v) => bail!("Skill pack {}@{} is not installed", name, v),
+            None => bail!("Skill pack {} is not installed", name),
+        }
+    }
+    for v in &removed {
+        let dir = installed_dir(name, v)?;
+        fs::remove_dir_all(&dir).with_context(|| format!("Failed to remove {}", dir.display()))?;
+    }
+    let name_dir = planning_paths::skill_packs_dir()?.join(name);
+    if fs::read_dir(&name_dir).is_ok_and(|mut entries| entries.next().is_none()) {
+        let _ = fs::remove_dir(&name_dir);
+    }
+    Ok(removed)
+}
+
+// ============================================================================
+// Project pins (.planning-agent.toml)
+// ============================================================================
+
+/// Returns the `<pack>` of a `[skills.<pack>]` section header.
+fn pin_section_name(line: &str) -> Option<&str> {
+    let name = line.trim().strip_prefix("[skills.")?.strip_suffix(']')?;
+    Some(name.trim().trim_matches('"'))
+}
+
+/// Parses pins from `.planning-agent.toml` content.
+///
+/// Only the `[skills.<pack>]` tables are read, line by line, to avoid a TOML dependency.
+pub fn parse_pins(content: &str) -> Vec<PackPin> {
+    let mut pins: Vec<PackPin> = Vec::new();
+    let mut current: Option<PackPin> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            pins.extend(current.take().filter(|p| !p.version.is_empty()));
+            current = pin_section_name(trimmed).map(|name| PackPin {
+                name: name.to_string(),
+                version: String::new(),
+                sha256: None,
+            });
+            continue;
+        }
+        let (Some(pin), Some((key, value))) = (current.as_mut(), trimmed.split_once('=')) else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim() {
+            "version" => pin.version = value,
+            "sha256" => pin.sha256 = Some(value).filter(|v| !v.is_empty()),
+            _ => {}
+        }
+    }
+    pins.extend(current.filter(|p| !p.version.is_empty()));
+    pins
+}
+
+/// Replaces the `[skills.*]` tables in `existing` with `pins`, keeping everything else.
+pub fn render_pins(existing: &str, pins: &[PackPin]) -> String {
+    let mut out = String::new();
+    let mut in_pin_section = false;
+    for line in existing.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_pin_section = pin_section_name(trimmed).is_some();
+        }
+        if !in_pin_section {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+
+    let mut out = out.trim_end().to_string();
+    for pin in pins {
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        out.push_str(&format!(
+            "[skills.{}]\nversion = \"{}\"",
+            pin.name, pin.version
+        ));
+        if let Some(sum) = &pin.sha256 {
+            out.push_str(&format!("\nsha256 = \"{}\"", sum));
+        }
+    }
+    out.push('\n');
+    out
+}
+
+/// Reads the project's pins (none if it has no `.planning-agent.toml`).
+pub fn read_pins(working_dir: &Path) -> Result<Vec<PackPin>> {
+    let path = working_dir.join(PROJECT_CONFIG_FILE);
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(parse_pins(&content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+/// Writes the project's pins, preserving any other settings in the file.
+pub fn write_pins(working_dir: &Path, pins: &[PackPin]) -> Result<()> {
+    let path = working_dir.join(PROJECT_CONFIG_FILE);
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    fs::write(&path, render_pins(&existing, pins))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Pins `manifest` in the project, replacing any previous pin of the same pack.
+pub fn pin_pack(working_dir: &Path, manifest: &PackManifest) -> Result<()> {
+    let mut pins = read_pins(working_dir)?;
+    pins.retain(|p| p.name != manifest.name);
+    pins.push(PackPin {
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        sha256: Some(manifest.checksum()),
+    });
+    write_pins(working_dir, &pins)
+}
+
+/// Loads and verifies the pinned pack, returning its manifest and skills.
+pub fn verify_pin(pin: &PackPin) -> Result<(PackManifest, BTreeMap<String, String>)> {
+    let (manifest, contents) = load_installed(&pin.name, &pin.version).with_context(|| {
+        format!(
+            "Pinned skill pack {}@{} is not installed or is corrupt (run `planning skills install`)",
+            pin.name, pin.version
+        )
+    })?;
+    if let Some(expected) = &pin.sha256 {
+        let actual = manifest.checksum();
+        if *expected != actual {
+            bail!(
+                "Skill pack {} does not match the pinned checksum (expected {}, found {})",
+                manifest.label(),
+                expected,
+                actual
+            );
+        }
+    }
+    Ok((manifest, contents))
+}
+
+/// Skills from the project's pinned packs, keyed by skill name.
+pub fn pinned_skills(working_dir: &Path) -> Result<BTreeMap<String, PackSkill>> {
+    let mut skills: BTreeMap<String, PackSkill> = BTreeMap::new();
+    for pin in read_pins(working_dir)? {
+        let (manifest, contents) = verify_pin(&pin)?;
+        let pack = manifest.label();
+        for (skill, content) in contents {
+            if let Some(other) = skills.get(&skill) {
+                bail!(
+                    "Skill '{}' is provided by both {} and {}",
+                    skill,
+                    other.pack,
+                    pack
+                );
+            }
+            skills.insert(
+                skill,
+                PackSkill {
+                    pack: pack.clone(),
+                    content,
+                },
+            );
+        }
+    }
+    Ok(skills)
+}
+
+#[cfg(test)]
+#[path = "tests/packs_tests.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+use super::*;
+use tempfile::tempdir;
+
+fn write_pack(dir: &Path, version: &str, planning: &str) {
+    fs::create_dir_all(dir.join("planning")).unwrap();
+    fs::write(dir.join("planning/SKILL.md"), planning).unwrap();
+    fs::write(
+        dir.join(MANIFEST_FILE),
+        format!(
+            r#"{{"name": "acme", "version": "{}", "skills": {{"planning": ""}}}}"#,
+            version
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_install_fills_checksums_and_verifies() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let source = tempdir().unwrap();
+    write_pack(source.path(), "1.0.0", "# Acme planning");
+
+    let manifest = install_pack(source.path()).unwrap();
+    assert_eq!(
+        manifest.skills.get("planning").map(String::as_str),
+        Some(sha256_hex(b"# Acme planning").as_str())
+    );
+    assert_eq!(installed_packs().unwrap(), vec![manifest.clone()]);
+
+    // Tampering with an installed skill fails verification
+    let installed = installed_dir("acme", "1.0.0").unwrap();
+    fs::write(installed.join("planning/SKILL.md"), "# Edited").unwrap();
+    let err = load_installed("acme", "1.0.0").unwrap_err();
+    assert!(err.to_string().contains("Checksum mismatch"));
+}
+
+#[test]
+fn test_reinstalling_version_with_different_content_is_rejected() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let source = tempdir().unwrap();
+    write_pack(source.path(), "1.0.0", "# Acme planning");
+    install_pack(source.path()).unwrap();
+    install_pack(source.path()).unwrap();
+
+    write_pack(source.path(), "1.0.0", "# Acme planning v2");
+    assert!(install_pack(source.path()).is_err());
+    write_pack(source.path(), "1.1.0", "# Acme planning v2");
+    install_pack(source.path()).unwrap();
+
+    assert_eq!(remove_pack("acme", Some("1.0.0")).unwrap(), vec!["1.0.0"]);
+    assert_eq!(installed_packs().unwrap().len(), 1);
+}
+
+#[test]
+fn test_pack_names_cannot_escape_store() {
+    assert!(validate_component("pack name", "../evil").is_err());
+    assert!(validate_component("pack version", "").is_err());
+    assert!(validate_component("pack version", "1.2.0-rc_1").is_ok());
+}
+
+#[test]
+fn test_render_pins_round_trips_and_keeps_other_settings() {
+    let existing =
+        "# Project settings\n[review]\nstrict = true\n\n[skills.old]\nversion = \"0.1\"\n";
+    let pins = vec![PackPin {
+        name: "acme".to_string(),
+        version: "1.2.0".to_string(),
+        sha256: Some("abc".to_string()),
+    }];
+
+    let rendered = render_pins(existing, &pins);
+    assert!(rendered.starts_with("# Project settings\n[review]\nstrict = true\n\n"));
+    assert!(!rendered.contains("skills.old"));
+    assert_eq!(parse_pins(&rendered), pins);
+}
+
+#[test]
+fn test_pinned_skills_verify_pin_checksum() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let source = tempdir().unwrap();
+    let project = tempdir().unwrap();
+    write_pack(source.path(), "1.0.0", "# Acme planning");
+    let manifest = install_pack(source.path()).unwrap();
+    pin_pack(project.path(), &manifest).unwrap();
+
+    let skills = pinned_skills(project.path()).unwrap();
+    assert_eq!(skills.get("planning").unwrap().pack, "acme@1.0.0");
+
+    let mut pins = read_pins(project.path()).unwrap();
+    pins[0].sha256 = Some("0000".to_string());
+    write_pins(project.path(), &pins).unwrap();
+    assert!(pinned_skills(project.path()).is_err());
+}
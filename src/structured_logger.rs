@@ -15,6 +15,7 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
 
+use crate::agents::redaction::redact;
 use crate::domain::WorkflowCommand;
 use crate::domain::WorkflowEvent;
 
@@ -97,7 +98,7 @@ impl StructuredLogger {
 
         if let Ok(mut file) = self.log_file.lock() {
             if let Ok(line) = serde_json::to_string(&entry) {
-                let _ = writeln!(file, "{}", line);
+                let _ = writeln!(file, "{}", redact(&line, &self.session_id));
                 let _ = file.flush();
             }
         }
@@ -0,0 +1,122 @@
+use super::*;
+
+#[test]
+fn test_parse_run_url_with_and_without_job() {
+    let run = parse_run_ref(
+        "https://github.com/acme/widgets/actions/runs/123456",
+        Path::new("."),
+    )
+    .unwrap();
+    assert_eq!(run.owner, "acme");
+    assert_eq!(run.repo, "widgets");
+    assert_eq!(run.run_id, 123456);
+    assert_eq!(run.job_id, None);
+    assert_eq!(
+        run.url(),
+        "https://github.com/acme/widgets/actions/runs/123456"
+    );
+
+    let run = parse_run_ref(
+        "https://github.com/acme/widgets/actions/runs/123456/job/789?pr=4",
+        Path::new("."),
+    )
+    .unwrap();
+    assert_eq!(run.job_id, Some(789));
+
+    assert!(parse_run_ref("https://github.com/acme/widgets/pull/4", Path::new(".")).is_err());
+}
+
+#[test]
+fn test_parse_github_remote_forms() {
+    let expected = Some(("acme".to_string(), "widgets".to_string()));
+    assert_eq!(
+        parse_github_remote("git@github.com:acme/widgets.git"),
+        expected
+    );
+    assert_eq!(
+        parse_github_remote("https://github.com/acme/widgets"),
+        expected
+    );
+    assert_eq!(
+        parse_github_remote("ssh://git@github.com/acme/widgets.git\n"),
+        expected
+    );
+    assert_eq!(parse_github_remote("https://gitlab.com/acme/widgets"), None);
+}
+
+#[test]
+fn test_parse_failed_jobs_keeps_failures_and_failed_steps() {
+    let json = serde_json::json!({
+        "jobs": [
+            { "id": 1, "name": "lint", "conclusion": "success", "steps": [] },
+            {
+                "id": 2,
+                "name": "test",
+                "conclusion": "failure",
+                "steps": [
+                    { "name": "Checkout", "conclusion": "success" },
+                    { "name": "cargo test", "conclusion": "failure" }
+                ]
+            },
+            { "id": 3, "name": "e2e", "conclusion": "timed_out" }
+        ]
+    });
+    let jobs = parse_failed_jobs(&json, None);
+    assert_eq!(
+        jobs,
+        vec![
+            (2, "test".to_string(), vec!["cargo test".to_string()]),
+            (3, "e2e".to_string(), vec![]),
+        ]
+    );
+    assert_eq!(parse_failed_jobs(&json, Some(3)).len(), 1);
+}
+
+#[test]
+fn test_trim_log_keeps_windows_around_errors() {
+    let mut log: Vec<String> = (0..100)
+        .map(|i| format!("2024-05-01T12:00:00.0000000Z line {}", i))
+        .collect();
+    log[50] = "2024-05-01T12:00:00.0000000Z \u{1b}[31merror[E0308]: mismatched types\u{1b}[0m"
+        .to_string();
+    let excerpt = trim_log(&log.join("\n"));
+    let lines: Vec<&str> = excerpt.lines().collect();
+
+    assert_eq!(lines[0], "...");
+    assert_eq!(lines[1], "line 45");
+    assert!(lines.contains(&"error[E0308]: mismatched types"));
+    assert_eq!(lines.last(), Some(&"line 60"));
+    assert!(!excerpt.contains("line 44"));
+}
+
+#[test]
+fn test_trim_log_without_errors_keeps_tail() {
+    let log: Vec<String> = (0..80).map(|i| format!("step {}", i)).collect();
+    let excerpt = trim_log(&log.join("\n"));
+    assert!(excerpt.starts_with("...\nstep 30"));
+    assert!(excerpt.ends_with("step 79"));
+}
+
+#[test]
+fn test_context_markdown_lists_jobs() {
+    let failure = CiFailure {
+        run: CiRunRef {
+            owner: "acme".to_string(),
+            repo: "widgets".to_string(),
+            run_id: 7,
+            job_id: None,
+        },
+        jobs: vec![FailedJob {
+            name: "test".to_string(),
+            failed_steps: vec!["cargo test".to_string()],
+            excerpt: "error: boom".to_string(),
+        }],
+    };
+    let markdown = failure.context_markdown();
+    assert!(markdown.contains("Source: https://github.com/acme/widgets/actions/runs/7"));
+    assert!(markdown.contains("## test\n\nFailed steps: cargo test\n\n```\nerror: boom\n```"));
+    assert_eq!(
+        failure.default_objective(),
+        "Fix the failing CI run https://github.com/acme/widgets/actions/runs/7"
+    );
+}
@@ -164,6 +164,76 @@ workflow:
     assert!(config.validate().is_err());
 }
 
+#[test]
+fn test_disabled_reviewers_are_skipped() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+  codex:
+    command: "codex"
+
+workflow:
+  planning:
+    agent: claude
+
+  reviewing:
+    agents: [claude, codex]
+    disabled: [codex]
+"#;
+    let mut config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(
+        config.workflow.reviewing.enabled_agents(),
+        vec![AgentRef::Simple("claude".to_string())]
+    );
+
+    // Disabling every reviewer leaves nothing to review the plan
+    config
+        .workflow
+        .reviewing
+        .disabled
+        .push("claude".to_string());
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_backup_reviewers_validate() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+  codex:
+    command: "codex"
+  gemini:
+    command: "gemini"
+
+workflow:
+  planning:
+    agent: claude
+
+  reviewing:
+    agents: [codex]
+    backup_reviewers: [gemini]
+"#;
+    let mut config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(
+        config.workflow.reviewing.backup_reviewers,
+        vec![AgentRef::Simple("gemini".to_string())]
+    );
+
+    // A backup can't share a display ID with a reviewer
+    config.workflow.reviewing.backup_reviewers = vec![AgentRef::Simple("codex".to_string())];
+    let err = config.validate().unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Backup reviewer display ID 'codex'"));
+
+    config.workflow.reviewing.backup_reviewers = vec![AgentRef::Simple("missing".to_string())];
+    assert!(config.validate().is_err());
+}
+
 #[test]
 fn test_aggregation_modes() {
     let yaml_any = r#"
@@ -394,3 +464,245 @@ workflow:
         err
     );
 }
+
+#[test]
+fn test_max_phase_duration_parses_and_rejects_zero() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+max_phase_duration:
+  minutes: 30
+  auto_interrupt: true
+"#;
+    let config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    config.validate().unwrap();
+    let limit = config.max_phase_duration.unwrap();
+    assert!(limit.auto_interrupt);
+    assert_eq!(limit.duration(), std::time::Duration::from_secs(30 * 60));
+
+    let zero = yaml.replace("minutes: 30", "minutes: 0");
+    let config: WorkflowConfig = serde_yaml::from_str(&zero).unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_cost_alerts_validate_phase_and_amount() {
+    let mut config = WorkflowConfig::default_config();
+    config.cost_alerts.insert("reviewing".to_string(), 2.0);
+    config.validate().unwrap();
+
+    config.cost_alerts.insert("review".to_string(), 2.0);
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("cost_alerts.review"), "{}", err);
+
+    config.cost_alerts.remove("review");
+    config.cost_alerts.insert("planning".to_string(), 0.0);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_plan_structure_defaults_and_validation() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+plan_structure: {}
+"#;
+    let mut config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    let policy = config.plan_structure.clone().unwrap();
+    assert_eq!(
+        policy.required_sections,
+        vec!["Goals", "Non-goals", "Risks", "Test plan"]
+    );
+    assert_eq!(policy.max_followups, 1);
+    assert!(!policy.front_matter);
+    config.validate().unwrap();
+
+    config.plan_structure.as_mut().unwrap().required_sections = vec!["--".to_string()];
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_reviewer_presets_apply_and_validate() {
+    let mut config = WorkflowConfig::default_config();
+    for name in ["fast", "thorough", "security"] {
+        assert!(
+            config.reviewer_presets.contains_key(name),
+            "missing {}",
+            name
+        );
+    }
+
+    // A reviewer disabled before stays disabled if the preset has it too
+    config.workflow.reviewing.disabled = vec!["codex".to_string(), "opus".to_string()];
+    config.apply_reviewer_preset("thorough").unwrap();
+    let reviewing = &config.workflow.reviewing;
+    assert_eq!(reviewing.preset.as_deref(), Some("thorough"));
+    assert!(reviewing.sequential);
+    assert_eq!(reviewing.disabled, vec!["codex".to_string()]);
+    assert_eq!(reviewing.enabled_agents().len(), 2);
+    assert!(config.validate().is_ok());
+
+    // ...unless that would leave no reviewer enabled
+    config.workflow.reviewing.disabled = vec!["claude".to_string()];
+    config.apply_reviewer_preset("fast").unwrap();
+    assert!(config.workflow.reviewing.disabled.is_empty());
+
+    let err = config.apply_reviewer_preset("exhaustive").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("available: fast, security, thorough"));
+
+    // Substitutions keep every preset valid in single-model modes
+    for mode_config in [
+        WorkflowConfig::claude_only_config(),
+        WorkflowConfig::codex_only_config(),
+        WorkflowConfig::gemini_only_config(),
+    ] {
+        assert!(mode_config.validate().is_ok());
+    }
+
+    config
+        .reviewer_presets
+        .get_mut("fast")
+        .unwrap()
+        .agents
+        .push(AgentRef::Simple("missing".to_string()));
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_agent_args_parse_and_apply() {
+    let entry =
+        AgentArgs::parse(r#"claude=--model opus-4 --append-system-prompt "be brief""#).unwrap();
+    assert_eq!(entry.agent, "claude");
+    assert_eq!(
+        entry.args,
+        vec!["--model", "opus-4", "--append-system-prompt", "be brief"]
+    );
+    assert_eq!(
+        AgentArgs::parse("codex='-c sandbox=read-only'")
+            .unwrap()
+            .args,
+        vec!["-c sandbox=read-only"]
+    );
+    assert!(AgentArgs::parse("--model opus-4").is_err());
+    assert!(AgentArgs::parse("claude=").is_err());
+    assert!(AgentArgs::parse("claude=\"--model").is_err());
+
+    let mut config = WorkflowConfig::default_config();
+    let configured = config.agents["claude"].args.clone();
+    config.apply_agent_args(&[entry]).unwrap();
+    assert_eq!(
+        config.agents["claude"].args[configured.len()..],
+        ["--model", "opus-4", "--append-system-prompt", "be brief"]
+    );
+
+    let unknown = AgentArgs::parse("missing=--flag").unwrap();
+    let before = config.agents["claude"].args.clone();
+    let err = config.apply_agent_args(&[unknown]).unwrap_err();
+    assert!(
+        err.to_string().contains("Unknown agent 'missing'"),
+        "{}",
+        err
+    );
+    assert_eq!(config.agents["claude"].args, before);
+}
+
+#[test]
+fn test_slash_commands_parse_and_expand_placeholders() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+slash_commands:
+  test: "cargo test -p {package} && echo {package} ${HOME} { true; }"
+  lint:
+    run: "cargo clippy"
+    inject_output: true
+"#;
+    let mut config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    config.validate().unwrap();
+
+    let test = &config.slash_commands["test"];
+    assert!(!test.inject_output());
+    assert_eq!(
+        test.script(&["core".to_string()]).unwrap(),
+        r#"cargo test -p "$1" && echo "$1" ${HOME} { true; }"#
+    );
+    assert!(test
+        .script(&[])
+        .unwrap_err()
+        .to_string()
+        .contains("{package}"));
+    assert!(test.script(&["a".to_string(), "b".to_string()]).is_err());
+
+    let lint = &config.slash_commands["lint"];
+    assert!(lint.inject_output());
+    assert_eq!(
+        lint.script(&["--fix".to_string()]).unwrap(),
+        r#"cargo clippy "$@""#
+    );
+
+    config.slash_commands.insert(
+        "/bad name".to_string(),
+        UserCommand::Run("true".to_string()),
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_language_is_optional_and_not_blank() {
+    let mut config = WorkflowConfig::default_config();
+    assert_eq!(config.language, None);
+
+    config.language = Some("German".to_string());
+    config.validate().unwrap();
+
+    config.language = Some("  ".to_string());
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("language"), "{}", err);
+}
+
+#[test]
+fn test_disabled_reviewers_are_not_validated_or_substituted() {
+    let mut config = WorkflowConfig::default_config();
+    config
+        .workflow
+        .reviewing
+        .agents
+        .push(AgentRef::Simple("retired".to_string()));
+    assert!(config.validate().is_err());
+
+    config.workflow.reviewing.disabled = vec!["retired".to_string()];
+    config.validate().unwrap();
+    let enabled: Vec<String> = config
+        .workflow
+        .reviewing
+        .enabled_agents_mut()
+        .map(|r| r.display_id().to_string())
+        .collect();
+    assert!(!enabled.contains(&"retired".to_string()));
+}
@@ -0,0 +1,72 @@
+//! Tests for IssueTrackerConfig parsing and defaults.
+
+use super::*;
+
+#[test]
+fn test_issue_tracker_config_missing_defaults_to_none() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+"#;
+    let config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    assert!(config.issue_tracker.jira.is_none());
+    assert!(config.issue_tracker.linear.is_none());
+}
+
+#[test]
+fn test_issue_tracker_config_defaults_token_env() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+issue_tracker:
+  jira:
+    base_url: "https://example.atlassian.net"
+    email: "dev@example.com"
+  linear: {}
+"#;
+    let config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    let jira = config.issue_tracker.jira.unwrap();
+    assert_eq!(jira.base_url, "https://example.atlassian.net");
+    assert_eq!(jira.email.as_deref(), Some("dev@example.com"));
+    assert_eq!(jira.token, None);
+    assert_eq!(jira.token_env, "JIRA_API_TOKEN");
+
+    let linear = config.issue_tracker.linear.unwrap();
+    assert_eq!(linear.api_url, "https://api.linear.app/graphql");
+    assert_eq!(linear.token_env, "LINEAR_API_KEY");
+}
+
+#[test]
+fn test_jira_config_requires_base_url() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+issue_tracker:
+  jira:
+    token_env: MY_TOKEN
+"#;
+    assert!(serde_yaml::from_str::<WorkflowConfig>(yaml).is_err());
+}
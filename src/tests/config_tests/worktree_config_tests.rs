@@ -79,3 +79,27 @@ worktree: {}
     // Empty section should default to false
     assert!(!config.worktree.enabled);
 }
+
+#[test]
+fn test_worktree_config_sync_before_merge() {
+    let yaml = r#"
+agents:
+  claude:
+    command: "claude"
+
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+
+worktree:
+  enabled: true
+  sync_before_merge: rebase
+"#;
+    let config: WorkflowConfig = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.worktree.sync_before_merge,
+        Some(SourceSyncMethod::Rebase)
+    );
+}
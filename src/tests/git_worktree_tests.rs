@@ -52,39 +52,38 @@ fn test_create_session_worktree_not_git_repo() {
 
 #[test]
 fn test_branch_name_sanitization() {
-    // Test that feature names with special characters get sanitized
-    let sanitized: String = "test/feature@with!special#chars"
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' {
-                c
-            } else {
-                '-'
-            }
-        })
-        .collect();
-    assert_eq!(sanitized, "test-feature-with-special-chars");
+    // Feature names with special characters use the feature name slug
+    assert_eq!(
+        session_branch_name("test/feature@with!special#chars", "0123456789abcdef"),
+        "planning-agent/test-feature-with-special-chars-01234567"
+    );
 }
 
 #[test]
 fn test_branch_name_empty_after_sanitization() {
-    // Test fallback for names that become empty after sanitization
-    let sanitized: String = "!!@@##"
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' {
-                c
-            } else {
-                '-'
-            }
-        })
-        .collect();
-    let safe = if sanitized.trim_matches('-').is_empty() {
-        "feature".to_string()
-    } else {
-        sanitized
-    };
-    assert_eq!(safe, "feature");
+    // Names that become empty after sanitization fall back to "feature"
+    assert_eq!(
+        session_branch_name("!!@@##", "0123456789abcdef"),
+        "planning-agent/feature-01234567"
+    );
+}
+
+#[test]
+fn test_branch_uses_feature() {
+    assert!(branch_uses_feature("user-auth", "user-auth"));
+    assert!(branch_uses_feature(
+        "planning-agent/user-auth-01234567",
+        "user-auth"
+    ));
+    assert!(branch_uses_feature(
+        "planning-agent/user-auth-01234567-1700000000000",
+        "user-auth"
+    ));
+    assert!(!branch_uses_feature(
+        "planning-agent/user-auth-flow-01234567",
+        "user-auth"
+    ));
+    assert!(!branch_uses_feature("user-auth-2", "user-auth"));
 }
 
 #[test]
@@ -142,3 +141,192 @@ fn test_generate_merge_instructions_no_source_branch() {
     // Should default to "main" when source_branch is None
     assert!(instructions.contains("Target branch for merge: main"));
 }
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Creates a repo on branch `main` (with a local identity so commits work without
+/// global config) and a worktree on branch `feature` inside a separate session dir.
+fn repo_with_worktree() -> (tempfile::TempDir, tempfile::TempDir, PathBuf) {
+    let repo = tempdir().unwrap();
+    let session = tempdir().unwrap();
+    git(repo.path(), &["init", "-b", "main"]);
+    git(repo.path(), &["config", "user.name", "Test"]);
+    git(repo.path(), &["config", "user.email", "test@example.com"]);
+    std::fs::write(repo.path().join("shared.txt"), "base\n").unwrap();
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "-m", "base"]);
+
+    let worktree = session.path().join("worktree");
+    git(
+        repo.path(),
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "feature",
+            &worktree.to_string_lossy(),
+        ],
+    );
+    (repo, session, worktree)
+}
+
+/// Commits conflicting changes to shared.txt on both main and the worktree branch.
+fn diverged_repo() -> (tempfile::TempDir, tempfile::TempDir, PathBuf) {
+    let (repo, session, worktree) = repo_with_worktree();
+    std::fs::write(worktree.join("shared.txt"), "feature\n").unwrap();
+    git(&worktree, &["commit", "-am", "feature change"]);
+    std::fs::write(repo.path().join("shared.txt"), "main\n").unwrap();
+    git(repo.path(), &["commit", "-am", "main change"]);
+    (repo, session, worktree)
+}
+
+#[test]
+fn test_source_sync_method_parse() {
+    assert_eq!(
+        SourceSyncMethod::parse("Rebase"),
+        Some(SourceSyncMethod::Rebase)
+    );
+    assert_eq!(
+        SourceSyncMethod::parse(" merge "),
+        Some(SourceSyncMethod::Merge)
+    );
+    assert_eq!(SourceSyncMethod::parse("squash"), None);
+}
+
+#[test]
+fn test_has_conflict_markers() {
+    assert!(has_conflict_markers(
+        "<<<<<<< HEAD\na\n=======\nb\n>>>>>>> main\n"
+    ));
+    assert!(!has_conflict_markers("a\n=======\nb\n"));
+}
+
+#[test]
+fn test_sync_with_source_up_to_date() {
+    let (_repo, _session, worktree) = repo_with_worktree();
+    std::fs::write(worktree.join("shared.txt"), "feature\n").unwrap();
+    git(&worktree, &["commit", "-am", "feature change"]);
+
+    let outcome = sync_with_source(&worktree, "main", SourceSyncMethod::Rebase).unwrap();
+    assert_eq!(outcome, SourceSyncOutcome::UpToDate);
+}
+
+#[test]
+fn test_sync_with_source_clean_rebase() {
+    let (repo, _session, worktree) = repo_with_worktree();
+    std::fs::write(worktree.join("b.txt"), "b\n").unwrap();
+    git(&worktree, &["add", "."]);
+    git(&worktree, &["commit", "-m", "feature"]);
+    std::fs::write(repo.path().join("c.txt"), "c\n").unwrap();
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "-m", "main"]);
+
+    assert_eq!(commits_behind_source(&worktree, "main").unwrap(), 1);
+    let outcome = sync_with_source(&worktree, "main", SourceSyncMethod::Rebase).unwrap();
+    assert_eq!(outcome, SourceSyncOutcome::Synced);
+    assert_eq!(commits_behind_source(&worktree, "main").unwrap(), 0);
+    assert!(worktree.join("c.txt").exists());
+}
+
+#[test]
+fn test_merge_conflict_resolved_with_ours() {
+    let (_repo, _session, worktree) = diverged_repo();
+
+    let outcome = sync_with_source(&worktree, "main", SourceSyncMethod::Merge).unwrap();
+    assert_eq!(
+        outcome,
+        SourceSyncOutcome::Conflicts(vec!["shared.txt".to_string()])
+    );
+
+    resolve_conflict(
+        &worktree,
+        "shared.txt",
+        SourceSyncMethod::Merge,
+        ConflictChoice::Ours,
+    )
+    .unwrap();
+    assert!(conflicted_files(&worktree).unwrap().is_empty());
+    assert_eq!(
+        continue_source_sync(&worktree, SourceSyncMethod::Merge).unwrap(),
+        SourceSyncOutcome::Synced
+    );
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("shared.txt")).unwrap(),
+        "feature\n"
+    );
+    assert_eq!(commits_behind_source(&worktree, "main").unwrap(), 0);
+}
+
+#[test]
+fn test_rebase_conflict_theirs_keeps_source_branch() {
+    let (_repo, _session, worktree) = diverged_repo();
+
+    let outcome = sync_with_source(&worktree, "main", SourceSyncMethod::Rebase).unwrap();
+    assert!(matches!(outcome, SourceSyncOutcome::Conflicts(_)));
+
+    // "theirs" is the source branch even though git swaps sides during a rebase
+    resolve_conflict(
+        &worktree,
+        "shared.txt",
+        SourceSyncMethod::Rebase,
+        ConflictChoice::Theirs,
+    )
+    .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("shared.txt")).unwrap(),
+        "main\n"
+    );
+    continue_source_sync(&worktree, SourceSyncMethod::Rebase).unwrap();
+    assert_eq!(commits_behind_source(&worktree, "main").unwrap(), 0);
+}
+
+#[test]
+fn test_resolve_edited_rejects_conflict_markers() {
+    let (_repo, _session, worktree) = diverged_repo();
+    sync_with_source(&worktree, "main", SourceSyncMethod::Merge).unwrap();
+
+    let err = resolve_conflict(
+        &worktree,
+        "shared.txt",
+        SourceSyncMethod::Merge,
+        ConflictChoice::Edited,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("conflict markers"));
+
+    std::fs::write(worktree.join("shared.txt"), "main and feature\n").unwrap();
+    resolve_conflict(
+        &worktree,
+        "shared.txt",
+        SourceSyncMethod::Merge,
+        ConflictChoice::Edited,
+    )
+    .unwrap();
+    assert!(conflicted_files(&worktree).unwrap().is_empty());
+}
+
+#[test]
+fn test_abort_source_sync_restores_branch() {
+    let (_repo, _session, worktree) = diverged_repo();
+    sync_with_source(&worktree, "main", SourceSyncMethod::Rebase).unwrap();
+
+    abort_source_sync(&worktree, SourceSyncMethod::Rebase).unwrap();
+    assert!(conflicted_files(&worktree).unwrap().is_empty());
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("shared.txt")).unwrap(),
+        "feature\n"
+    );
+    assert_eq!(commits_behind_source(&worktree, "main").unwrap(), 1);
+}
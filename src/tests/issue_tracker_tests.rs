@@ -0,0 +1,168 @@
+use super::*;
+
+fn jira_config() -> JiraConfig {
+    JiraConfig {
+        base_url: "https://example.atlassian.net/".to_string(),
+        email: None,
+        token: None,
+        token_env: "JIRA_API_TOKEN".to_string(),
+    }
+}
+
+fn linear_config() -> LinearConfig {
+    LinearConfig {
+        api_url: "https://api.linear.app/graphql".to_string(),
+        token: None,
+        token_env: "LINEAR_API_KEY".to_string(),
+    }
+}
+
+#[test]
+fn test_parse_issue_ref_uses_only_configured_tracker() {
+    let config = IssueTrackerConfig {
+        jira: Some(jira_config()),
+        linear: None,
+    };
+    let (tracker, key) = parse_issue_ref("jira-1234", &config).unwrap();
+    assert_eq!(tracker, IssueTracker::Jira);
+    assert_eq!(key, "JIRA-1234");
+
+    let config = IssueTrackerConfig {
+        jira: None,
+        linear: Some(linear_config()),
+    };
+    let (tracker, _) = parse_issue_ref("ENG-7", &config).unwrap();
+    assert_eq!(tracker, IssueTracker::Linear);
+}
+
+#[test]
+fn test_parse_issue_ref_prefix_selects_tracker() {
+    let config = IssueTrackerConfig {
+        jira: Some(jira_config()),
+        linear: Some(linear_config()),
+    };
+    assert!(parse_issue_ref("ENG-7", &config).is_err());
+    let (tracker, key) = parse_issue_ref("linear:ENG-7", &config).unwrap();
+    assert_eq!(tracker, IssueTracker::Linear);
+    assert_eq!(key, "ENG-7");
+    let (tracker, _) = parse_issue_ref("jira:OPS-12", &config).unwrap();
+    assert_eq!(tracker, IssueTracker::Jira);
+}
+
+#[test]
+fn test_parse_issue_ref_rejects_invalid_keys() {
+    let config = IssueTrackerConfig {
+        jira: Some(jira_config()),
+        linear: None,
+    };
+    for id in ["1234", "ABC-", "-12", "ABC-12x", "../ABC-1", "A B-1"] {
+        assert!(parse_issue_ref(id, &config).is_err(), "accepted {}", id);
+    }
+    assert!(parse_issue_ref("ABC-1", &IssueTrackerConfig::default()).is_err());
+}
+
+#[test]
+fn test_parse_jira_issue() {
+    let json = serde_json::json!({
+        "key": "JIRA-1234",
+        "fields": {
+            "summary": "Add retry to uploads",
+            "description": "Uploads fail on flaky networks.",
+            "comment": {
+                "comments": [
+                    { "author": { "displayName": "Ada" }, "body": "Use exponential backoff." }
+                ]
+            }
+        }
+    });
+    let issue = parse_jira_issue("https://example.atlassian.net/", &json).unwrap();
+    assert_eq!(issue.link.tracker(), IssueTracker::Jira);
+    assert_eq!(issue.link.key(), "JIRA-1234");
+    assert_eq!(issue.link.title(), "Add retry to uploads");
+    assert_eq!(
+        issue.link.url(),
+        "https://example.atlassian.net/browse/JIRA-1234"
+    );
+    assert_eq!(issue.description, "Uploads fail on flaky networks.");
+    assert_eq!(
+        issue.comments,
+        vec![IssueComment {
+            author: "Ada".to_string(),
+            body: "Use exponential backoff.".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_parse_jira_issue_tolerates_missing_description_and_comments() {
+    let json = serde_json::json!({
+        "key": "JIRA-1",
+        "fields": { "summary": "Title", "description": null }
+    });
+    let issue = parse_jira_issue("https://jira.local", &json).unwrap();
+    assert!(issue.description.is_empty());
+    assert!(issue.comments.is_empty());
+}
+
+#[test]
+fn test_parse_linear_issue() {
+    let json = serde_json::json!({
+        "data": {
+            "issue": {
+                "identifier": "ENG-7",
+                "title": "Cache avatars",
+                "description": "Avatars reload on every page.",
+                "url": "https://linear.app/acme/issue/ENG-7",
+                "comments": { "nodes": [ { "body": "CDN?", "user": { "name": "Grace" } } ] }
+            }
+        }
+    });
+    let issue = parse_linear_issue(&json).unwrap();
+    assert_eq!(issue.link.tracker(), IssueTracker::Linear);
+    assert_eq!(issue.link.key(), "ENG-7");
+    assert_eq!(issue.link.url(), "https://linear.app/acme/issue/ENG-7");
+    assert_eq!(issue.comments.len(), 1);
+    assert_eq!(issue.comments[0].author, "Grace");
+}
+
+#[test]
+fn test_parse_linear_issue_reports_api_errors() {
+    let json = serde_json::json!({ "errors": [ { "message": "Entity not found" } ] });
+    let err = parse_linear_issue(&json).unwrap_err();
+    assert!(err.to_string().contains("Entity not found"));
+
+    let json = serde_json::json!({ "data": { "issue": null } });
+    assert!(parse_linear_issue(&json).is_err());
+}
+
+#[test]
+fn test_objective_and_context() {
+    let issue = ImportedIssue {
+        link: IssueLink::new(
+            IssueTracker::Jira,
+            "JIRA-1234".to_string(),
+            "https://jira.local/browse/JIRA-1234".to_string(),
+            "Add retry to uploads".to_string(),
+        ),
+        description: "Uploads fail.".to_string(),
+        comments: vec![IssueComment {
+            author: "Ada".to_string(),
+            body: "Use backoff.".to_string(),
+        }],
+    };
+    assert_eq!(issue.objective(""), "JIRA-1234: Add retry to uploads");
+    assert_eq!(
+        issue.objective(" keep it small "),
+        "JIRA-1234: Add retry to uploads\n\nkeep it small"
+    );
+
+    let context = issue.context_markdown();
+    assert!(context.starts_with("# JIRA-1234: Add retry to uploads"));
+    assert!(context.contains("Source: https://jira.local/browse/JIRA-1234 (Jira)"));
+    assert!(context.contains("## Description\n\nUploads fail."));
+    assert!(context.contains("### Ada\n\nUse backoff."));
+
+    let import = issue.into_import();
+    assert_eq!(import.link.key(), "JIRA-1234");
+    assert_eq!(import.context, context);
+}
@@ -220,7 +220,7 @@ fn test_session_info_save_and_load() {
         "test-feature",
         "Test objective",
         Path::new("/tmp/test"),
-        "Planning",
+        Phase::Planning,
         1,
     );
 
@@ -234,10 +234,87 @@ fn test_session_info_save_and_load() {
     assert_eq!(loaded.session_id, session_id);
     assert_eq!(loaded.feature_name, "test-feature");
     assert_eq!(loaded.objective, "Test objective");
-    assert_eq!(loaded.phase, "Planning");
+    assert_eq!(loaded.phase, Phase::Planning);
     assert_eq!(loaded.iteration, 1);
 }
 
+#[test]
+fn test_find_sessions_by_feature_name_returns_every_match() {
+    let (_temp_dir, _guard) = test_env();
+
+    let save = |feature: &str, dir: &str| {
+        let session_id = format!("test-session-{}", uuid::Uuid::new_v4());
+        SessionInfo::new(
+            &session_id,
+            feature,
+            "objective",
+            Path::new(dir),
+            Phase::Planning,
+            1,
+        )
+        .save(&session_id)
+        .unwrap();
+        session_id
+    };
+    let first = save("auth", "/tmp/repo");
+    let second = save("auth", "/tmp/repo");
+    save("auth", "/tmp/other-repo");
+    save("billing", "/tmp/repo");
+
+    let matches = find_sessions_by_feature_name("auth", Path::new("/tmp/repo")).unwrap();
+    let mut ids: Vec<_> = matches.into_iter().map(|info| info.session_id).collect();
+    ids.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    assert!(
+        find_sessions_by_feature_name("missing", Path::new("/tmp/repo"))
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_session_info_upgrades_version_1_files() {
+    let legacy = r#"{
+        "session_id": "s1",
+        "feature_name": "auth",
+        "objective": "Add auth",
+        "working_dir": "/tmp/repo",
+        "created_at": "2025-01-01T00:00:00Z",
+        "updated_at": "2025-01-02T00:00:00Z",
+        "phase": "AwaitingPlanningDecision",
+        "iteration": 3
+    }"#;
+
+    let info = SessionInfo::parse(legacy).unwrap();
+    assert_eq!(info.schema_version, SESSION_INFO_SCHEMA_VERSION);
+    assert_eq!(info.phase, Phase::AwaitingPlanningDecision);
+    assert_eq!(info.iteration, 3);
+
+    let saved = serde_json::to_string(&info).unwrap();
+    assert!(saved.contains(r#""phase":"awaiting_planning_decision""#));
+    assert_eq!(SessionInfo::parse(&saved).unwrap().phase, info.phase);
+}
+
+#[test]
+fn test_session_info_refuses_newer_schema() {
+    let mut info = serde_json::to_value(SessionInfo::new(
+        "s1",
+        "auth",
+        "Add auth",
+        Path::new("/tmp/repo"),
+        Phase::Planning,
+        1,
+    ))
+    .unwrap();
+    info["schema_version"] = serde_json::json!(SESSION_INFO_SCHEMA_VERSION + 1);
+
+    let err = SessionInfo::parse(&info.to_string()).unwrap_err();
+    assert!(err.to_string().contains("upgrade planning-agent"));
+}
+
 #[test]
 fn test_session_implementation_log_path() {
     let (_temp_dir, _guard) = test_env();
@@ -271,3 +348,23 @@ fn test_session_implementation_review_path() {
     let path2 = result2.unwrap();
     assert!(path2.ends_with("implementation_review_2.md"));
 }
+
+#[test]
+fn test_sessiond_paths_are_namespaced_per_user() {
+    let (temp_dir, _guard) = test_env();
+
+    let user = daemon_user();
+    assert!(!user.is_empty());
+    let port = sessiond_port_path().unwrap();
+    assert_eq!(port.parent().unwrap(), temp_dir.path());
+    assert_eq!(
+        port.file_name().unwrap().to_string_lossy(),
+        format!("sessiond-{}.port", user)
+    );
+    assert!(sessiond_socket_path()
+        .unwrap()
+        .ends_with(format!("sessiond-{}.sock", user)));
+    assert!(sessiond_registry_path()
+        .unwrap()
+        .ends_with(format!("sessiond-{}.registry.json", user)));
+}
@@ -128,3 +128,20 @@ fn test_prompt_builder_full_example() {
     assert!(prompt.contains("<plan-feedback>"));
     assert!(prompt.contains("Overall Assessment:"));
 }
+
+#[test]
+fn test_prompt_builder_language_constraint() {
+    let prompt = PromptBuilder::new()
+        .phase("planning")
+        .language(Some("Japanese"))
+        .build();
+    assert!(prompt.contains("- Write all prose"));
+    assert!(prompt.contains("in Japanese"));
+    assert!(prompt.contains("verdict keywords in English"));
+
+    let prompt = PromptBuilder::new()
+        .phase("planning")
+        .language(None)
+        .build();
+    assert!(!prompt.contains("<constraints>"));
+}
@@ -0,0 +1,698 @@
+//! Config editor overlay for inspecting and editing the effective workflow config.
+//!
+//! This module provides a modal overlay that shows the merged `WorkflowConfig`
+//! as a collapsible tree, allowing users to:
+//! - See where each value comes from (CLI flag, session, project file, user config)
+//! - Edit scalar values such as retry counts, backoff and max iterations
+//! - Switch individual reviewers on and off
+//!
+//! Edits are written back to the workflow file that provides the config. Built-in
+//! workflows are first copied to `~/.planning-agent/workflows/` and the copy is
+//! selected for the working directory.
+
+use crate::app::tui_runner::load_workflow_from_selection;
+use crate::app::{
+    fork_builtin_workflow, selected_workflow_source, update_workflow_value, WorkflowSource,
+};
+use crate::config::WorkflowConfig;
+use serde_yaml::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Pseudo-section for settings that live outside the workflow file.
+const SESSION_SECTION: &str = "session";
+
+/// Sections expanded when the editor opens.
+const DEFAULT_EXPANDED: [&str; 4] = [
+    SESSION_SECTION,
+    "workflow",
+    "workflow.reviewing",
+    "workflow.reviewing.agents",
+];
+
+/// Where a config value comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource {
+    /// Set by a command-line flag
+    CliFlag,
+    /// Set in this TUI session (e.g. `/max-iterations`)
+    Session,
+    /// `./workflow.yaml` in the working directory
+    ProjectFile,
+    /// A workflow in `~/.planning-agent/workflows/`
+    UserConfig,
+    /// A built-in workflow
+    BuiltIn,
+    /// Not set anywhere; the default applies
+    Default,
+}
+
+impl ValueSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValueSource::CliFlag => "cli flag",
+            ValueSource::Session => "session",
+            ValueSource::ProjectFile => "project file",
+            ValueSource::UserConfig => "user config",
+            ValueSource::BuiltIn => "built-in",
+            ValueSource::Default => "default",
+        }
+    }
+}
+
+/// Settings that come from the command line or the TUI session rather than the workflow file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeSettings {
+    /// Max planning iterations for new workflows
+    pub max_iterations: u32,
+    /// Whether `max_iterations` was set with `/max-iterations` rather than `--max-iterations`
+    pub max_iterations_overridden: bool,
+    /// Whether `--worktree` was passed
+    pub worktree_flag: bool,
+}
+
+impl Default for RuntimeSettings {
+    fn default() -> Self {
+        Self {
+            max_iterations: 3,
+            max_iterations_overridden: false,
+            worktree_flag: false,
+        }
+    }
+}
+
+/// What a row in the tree represents.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowKind {
+    /// A mapping that can be expanded
+    Section { expanded: bool },
+    /// A leaf value
+    Value { value: Value, editable: bool },
+    /// A reviewer that can be switched on and off
+    Reviewer { enabled: bool },
+}
+
+/// A visible row in the config tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigRow {
+    /// Key path from the config root (reviewers use their display ID as the last element)
+    pub path: Vec<String>,
+    /// Nesting depth for indentation
+    pub depth: usize,
+    pub label: String,
+    pub kind: RowKind,
+    /// Where the value comes from (None for sections)
+    pub source: Option<ValueSource>,
+}
+
+impl ConfigRow {
+    fn key(&self) -> String {
+        self.path.join(".")
+    }
+}
+
+/// A change the caller should apply to the running TUI after an edit.
+#[derive(Debug, Clone)]
+pub enum ConfigChange {
+    /// The workflow file was rewritten; the reloaded config
+    Workflow(Box<WorkflowConfig>),
+    /// Max iterations for new workflows changed (session only)
+    MaxIterations(u32),
+}
+
+/// State for the config editor overlay.
+#[derive(Debug, Clone)]
+pub struct ConfigEditorState {
+    /// Whether the overlay is open
+    pub open: bool,
+    /// Visible rows of the config tree
+    pub rows: Vec<ConfigRow>,
+    /// Currently selected row
+    pub selected_idx: usize,
+    /// Scroll offset for the list
+    pub scroll_offset: usize,
+    /// Edit buffer while a value is being edited
+    pub editing: Option<String>,
+    /// Error from the last edit
+    pub error: Option<String>,
+    /// Notice from the last edit
+    pub notice: Option<String>,
+    /// Current working directory (for workflow selection)
+    pub working_dir: PathBuf,
+    expanded: HashSet<String>,
+    config: Option<WorkflowConfig>,
+    raw: Option<Value>,
+    source: Option<WorkflowSource>,
+    settings: RuntimeSettings,
+}
+
+impl Default for ConfigEditorState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            rows: Vec::new(),
+            selected_idx: 0,
+            scroll_offset: 0,
+            editing: None,
+            error: None,
+            notice: None,
+            working_dir: PathBuf::new(),
+            expanded: DEFAULT_EXPANDED.iter().map(|s| s.to_string()).collect(),
+            config: None,
+            raw: None,
+            source: None,
+            settings: RuntimeSettings::default(),
+        }
+    }
+}
+
+impl ConfigEditorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the editor for the workflow selected in a working directory.
+    pub fn open(&mut self, working_dir: &Path, settings: RuntimeSettings) {
+        *self = Self {
+            open: true,
+            working_dir: working_dir.to_path_buf(),
+            settings,
+            ..Self::default()
+        };
+        self.source = Some(selected_workflow_source(working_dir));
+        self.config = Some(load_workflow_from_selection(working_dir));
+        self.reload_raw();
+        self.rebuild_rows();
+    }
+
+    /// Closes the editor overlay.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.editing = None;
+        self.rows.clear();
+    }
+
+    /// Describes the workflow being edited and the file edits are written to.
+    pub fn header(&self) -> String {
+        let name = self
+            .config
+            .as_ref()
+            .map(|c| c.name.as_str())
+            .filter(|n| !n.is_empty())
+            .unwrap_or("workflow");
+        match &self.source {
+            Some(WorkflowSource::BuiltIn(builtin)) => format!(
+                "{} (built-in; edits create a copy in ~/.planning-agent/workflows/)",
+                builtin
+            ),
+            Some(WorkflowSource::UserFile(path)) | Some(WorkflowSource::ProjectFile(path)) => {
+                format!("{} ({})", name, path.display())
+            }
+            None => name.to_string(),
+        }
+    }
+
+    /// Returns the currently selected row, if any.
+    pub fn selected_row(&self) -> Option<&ConfigRow> {
+        self.rows.get(self.selected_idx)
+    }
+
+    /// Moves selection up with wrapping.
+    pub fn select_prev(&mut self) {
+        if !self.rows.is_empty() {
+            if self.selected_idx == 0 {
+                self.selected_idx = self.rows.len() - 1;
+            } else {
+                self.selected_idx -= 1;
+            }
+            self.ensure_visible();
+        }
+    }
+
+    /// Moves selection down with wrapping.
+    pub fn select_next(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected_idx = (self.selected_idx + 1) % self.rows.len();
+            self.ensure_visible();
+        }
+    }
+
+    /// Expands the selected section.
+    pub fn expand_selected(&mut self) {
+        if let Some(row) = self.selected_row() {
+            if matches!(row.kind, RowKind::Section { expanded: false }) {
+                self.expanded.insert(row.key());
+                self.rebuild_rows();
+            }
+        }
+    }
+
+    /// Collapses the selected section, or moves to the enclosing section.
+    pub fn collapse_selected(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        if matches!(row.kind, RowKind::Section { expanded: true }) {
+            self.expanded.remove(&row.key());
+            self.rebuild_rows();
+            return;
+        }
+        let Some((_, parent)) = row.path.split_last() else {
+            return;
+        };
+        let parent = parent.join(".");
+        if let Some(idx) = self.rows.iter().position(|r| r.key() == parent) {
+            self.selected_idx = idx;
+            self.ensure_visible();
+        }
+    }
+
+    /// Toggles the selected section, bool or reviewer, or starts editing a scalar.
+    pub fn activate_selected(&mut self) -> Option<ConfigChange> {
+        let row = self.selected_row()?.clone();
+        match row.kind {
+            RowKind::Section { expanded } => {
+                if expanded {
+                    self.expanded.remove(&row.key());
+                } else {
+                    self.expanded.insert(row.key());
+                }
+                self.rebuild_rows();
+                None
+            }
+            RowKind::Reviewer { enabled } => {
+                let id = row.label.clone();
+                let mut disabled = self
+                    .config
+                    .as_ref()
+                    .map(|c| c.workflow.reviewing.disabled.clone())
+                    .unwrap_or_default();
+                if enabled {
+                    disabled.push(id);
+                } else {
+                    disabled.retain(|d| *d != id);
+                }
+                let value = Value::Sequence(disabled.into_iter().map(Value::from).collect());
+                self.write_value(&["workflow", "reviewing", "disabled"], value)
+            }
+            RowKind::Value {
+                value: Value::Bool(current),
+                editable: true,
+            } => self.apply(&row, Value::Bool(!current)),
+            RowKind::Value {
+                ref value,
+                editable: true,
+            } => {
+                self.editing = Some(if value.is_null() {
+                    String::new()
+                } else {
+                    display_value(value)
+                });
+                self.error = None;
+                None
+            }
+            RowKind::Value { .. } => {
+                self.error = Some(format!("{} can't be edited here", row.label));
+                None
+            }
+        }
+    }
+
+    /// Applies the edit buffer to the selected value.
+    pub fn commit_edit(&mut self) -> Option<ConfigChange> {
+        let text = self.editing.take()?;
+        let row = self.selected_row()?.clone();
+        let RowKind::Value { value: current, .. } = &row.kind else {
+            return None;
+        };
+        match parse_edit(current, &text) {
+            Ok(value) => self.apply(&row, value),
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+
+    /// Discards the edit buffer.
+    pub fn cancel_edit(&mut self) {
+        self.editing = None;
+    }
+
+    fn apply(&mut self, row: &ConfigRow, value: Value) -> Option<ConfigChange> {
+        if row.path.first().map(String::as_str) == Some(SESSION_SECTION) {
+            let Some(n) = value.as_u64().and_then(|n| u32::try_from(n).ok()) else {
+                self.error = Some("max_iterations must be a positive number".to_string());
+                return None;
+            };
+            if n == 0 {
+                self.error = Some("max_iterations must be a positive number".to_string());
+                return None;
+            }
+            self.settings.max_iterations = n;
+            self.settings.max_iterations_overridden = true;
+            self.error = None;
+            self.notice = Some(format!("max_iterations set to {} for new workflows", n));
+            self.rebuild_rows();
+            return Some(ConfigChange::MaxIterations(n));
+        }
+        let path: Vec<&str> = row.path.iter().map(String::as_str).collect();
+        self.write_value(&path, value)
+    }
+
+    /// Writes a value to the workflow file, copying a built-in workflow first.
+    fn write_value(&mut self, key_path: &[&str], value: Value) -> Option<ConfigChange> {
+        let path = match self.target_file() {
+            Ok(path) => path,
+            Err(e) => {
+                self.error = Some(format!("Failed to copy built-in workflow: {}", e));
+                return None;
+            }
+        };
+        match update_workflow_value(&path, key_path, value) {
+            Ok(mut config) => {
+                if let Some(WorkflowSource::UserFile(_)) = self.source {
+                    if let Some(stem) = path.file_stem() {
+                        config.name = stem.to_string_lossy().to_string();
+                    }
+                }
+                self.config = Some(config.clone());
+                self.error = None;
+                if self.notice.is_none() {
+                    self.notice = Some(format!("Saved {}", key_path.join(".")));
+                }
+                self.reload_raw();
+                self.rebuild_rows();
+                Some(ConfigChange::Workflow(Box::new(config)))
+            }
+            Err(e) => {
+                self.error = Some(format!("{:#}", e));
+                None
+            }
+        }
+    }
+
+    /// Returns the file edits go to, copying a built-in workflow if necessary.
+    fn target_file(&mut self) -> anyhow::Result<PathBuf> {
+        self.notice = None;
+        match &self.source {
+            Some(WorkflowSource::UserFile(path)) | Some(WorkflowSource::ProjectFile(path)) => {
+                Ok(path.clone())
+            }
+            _ => {
+                let mut config = self
+                    .config
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("No workflow loaded"))?;
+                if let Some(WorkflowSource::BuiltIn(name)) = &self.source {
+                    config.name = name.clone();
+                }
+                let path = fork_builtin_workflow(&config, &self.working_dir)?;
+                self.notice = Some(format!("Created {}", path.display()));
+                self.source = Some(WorkflowSource::UserFile(path.clone()));
+                Ok(path)
+            }
+        }
+    }
+
+    fn reload_raw(&mut self) {
+        self.raw = match &self.source {
+            Some(WorkflowSource::UserFile(path)) | Some(WorkflowSource::ProjectFile(path)) => {
+                std::fs::read_to_string(path)
+                    .ok()
+                    .and_then(|content| serde_yaml::from_str(&content).ok())
+            }
+            _ => None,
+        };
+    }
+
+    fn file_source(&self) -> ValueSource {
+        match self.source {
+            Some(WorkflowSource::UserFile(_)) => ValueSource::UserConfig,
+            Some(WorkflowSource::ProjectFile(_)) => ValueSource::ProjectFile,
+            _ => ValueSource::BuiltIn,
+        }
+    }
+
+    fn rebuild_rows(&mut self) {
+        let selected_key = self.selected_row().map(ConfigRow::key);
+        self.rows = match &self.config {
+            Some(config) => build_rows(
+                config,
+                self.raw.as_ref(),
+                self.file_source(),
+                &self.settings,
+                &self.expanded,
+            ),
+            None => Vec::new(),
+        };
+        if let Some(idx) = selected_key.and_then(|k| self.rows.iter().position(|r| r.key() == k)) {
+            self.selected_idx = idx;
+        }
+        self.selected_idx = self.selected_idx.min(self.rows.len().saturating_sub(1));
+        self.ensure_visible();
+    }
+
+    /// Ensure the selected item is visible in the viewport.
+    fn ensure_visible(&mut self) {
+        const VIEWPORT_SIZE: usize = 16;
+
+        if self.selected_idx < self.scroll_offset {
+            self.scroll_offset = self.selected_idx;
+        } else if self.selected_idx >= self.scroll_offset + VIEWPORT_SIZE {
+            self.scroll_offset = self.selected_idx.saturating_sub(VIEWPORT_SIZE - 1);
+        }
+    }
+}
+
+/// Formats a value for display in the tree.
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "(none)".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Sequence(items) => {
+            let scalars: Option<Vec<String>> = items
+                .iter()
+                .map(|item| match item {
+                    Value::Sequence(_) | Value::Mapping(_) => None,
+                    other => Some(display_value(other)),
+                })
+                .collect();
+            match scalars {
+                Some(scalars) => format!("[{}]", scalars.join(", ")),
+                None => format!("[{} items]", items.len()),
+            }
+        }
+        Value::Mapping(_) => "{…}".to_string(),
+        Value::Tagged(tagged) => display_value(&tagged.value),
+    }
+}
+
+/// Parses an edit buffer as a value of the same type as the current value.
+fn parse_edit(current: &Value, text: &str) -> Result<Value, String> {
+    let text = text.trim();
+    match current {
+        Value::String(_) => Ok(Value::from(text)),
+        Value::Bool(_) => match text {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err("Expected true or false".to_string()),
+        },
+        _ if text.is_empty() => Ok(Value::Null),
+        Value::Number(_) => match serde_yaml::from_str::<Value>(text) {
+            Ok(value @ Value::Number(_)) => Ok(value),
+            _ => Err(format!("Expected a number, got '{}'", text)),
+        },
+        _ => match serde_yaml::from_str::<Value>(text) {
+            Ok(Value::Sequence(_) | Value::Mapping(_) | Value::Tagged(_)) | Err(_) => {
+                Ok(Value::from(text))
+            }
+            Ok(value) => Ok(value),
+        },
+    }
+}
+
+/// Returns true if the raw workflow file sets the value at `path`.
+fn raw_contains(raw: Option<&Value>, path: &[String]) -> bool {
+    let mut node = match raw {
+        Some(node) => node,
+        None => return false,
+    };
+    for key in path {
+        match node.get(key.as_str()) {
+            Some(child) => node = child,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Everything `build_rows` needs to attribute and lay out values.
+struct RowContext<'a> {
+    raw: Option<&'a Value>,
+    file_source: ValueSource,
+    settings: &'a RuntimeSettings,
+    expanded: &'a HashSet<String>,
+    disabled: &'a [String],
+}
+
+impl RowContext<'_> {
+    fn source(&self, path: &[String]) -> ValueSource {
+        if self.settings.worktree_flag && path == ["worktree", "enabled"] {
+            return ValueSource::CliFlag;
+        }
+        match self.file_source {
+            ValueSource::BuiltIn => ValueSource::BuiltIn,
+            file => {
+                if raw_contains(self.raw, path) {
+                    file
+                } else {
+                    ValueSource::Default
+                }
+            }
+        }
+    }
+}
+
+/// Flattens the config into the rows visible with the given sections expanded.
+fn build_rows(
+    config: &WorkflowConfig,
+    raw: Option<&Value>,
+    file_source: ValueSource,
+    settings: &RuntimeSettings,
+    expanded: &HashSet<String>,
+) -> Vec<ConfigRow> {
+    let ctx = RowContext {
+        raw,
+        file_source,
+        settings,
+        expanded,
+        disabled: &config.workflow.reviewing.disabled,
+    };
+    let session_expanded = expanded.contains(SESSION_SECTION);
+    let mut rows = vec![ConfigRow {
+        path: vec![SESSION_SECTION.to_string()],
+        depth: 0,
+        label: SESSION_SECTION.to_string(),
+        kind: RowKind::Section {
+            expanded: session_expanded,
+        },
+        source: None,
+    }];
+    if session_expanded {
+        rows.push(ConfigRow {
+            path: vec![SESSION_SECTION.to_string(), "max_iterations".to_string()],
+            depth: 1,
+            label: "max_iterations".to_string(),
+            kind: RowKind::Value {
+                value: Value::from(settings.max_iterations),
+                editable: true,
+            },
+            source: Some(if settings.max_iterations_overridden {
+                ValueSource::Session
+            } else {
+                ValueSource::CliFlag
+            }),
+        });
+    }
+
+    let Ok(Value::Mapping(root)) = serde_yaml::to_value(config) else {
+        return rows;
+    };
+    for (key, value) in &root {
+        if let Some(key) = key.as_str() {
+            push_value(&mut rows, &ctx, vec![key.to_string()], value);
+        }
+    }
+    rows
+}
+
+fn push_value(rows: &mut Vec<ConfigRow>, ctx: &RowContext<'_>, path: Vec<String>, value: &Value) {
+    let depth = path.len() - 1;
+    let label = path.last().cloned().unwrap_or_default();
+
+    if path == ["workflow", "reviewing", "agents"] {
+        let expanded = ctx.expanded.contains(&path.join("."));
+        rows.push(ConfigRow {
+            path: path.clone(),
+            depth,
+            label,
+            kind: RowKind::Section { expanded },
+            source: None,
+        });
+        if expanded {
+            let reviewers: Vec<crate::config::AgentRef> =
+                serde_yaml::from_value(value.clone()).unwrap_or_default();
+            let source = ctx.source(&path);
+            for reviewer in reviewers {
+                let id = reviewer.display_id().to_string();
+                let mut reviewer_path = path.clone();
+                reviewer_path.push(id.clone());
+                rows.push(ConfigRow {
+                    path: reviewer_path,
+                    depth: depth + 1,
+                    kind: RowKind::Reviewer {
+                        enabled: !ctx.disabled.contains(&id),
+                    },
+                    label: id,
+                    source: Some(source),
+                });
+            }
+        }
+        return;
+    }
+    // Reviewer switches are shown on the reviewer rows
+    if path == ["workflow", "reviewing", "disabled"] {
+        return;
+    }
+
+    match value {
+        Value::Mapping(mapping) => {
+            let expanded = ctx.expanded.contains(&path.join("."));
+            rows.push(ConfigRow {
+                path: path.clone(),
+                depth,
+                label,
+                kind: RowKind::Section { expanded },
+                source: None,
+            });
+            if !expanded {
+                return;
+            }
+            let mut entries: Vec<(&str, &Value)> = mapping
+                .iter()
+                .filter_map(|(k, v)| k.as_str().map(|k| (k, v)))
+                .collect();
+            // Agent names come from a HashMap; sort them for a stable tree
+            if path == ["agents"] {
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+            }
+            for (key, child) in entries {
+                let mut child_path = path.clone();
+                child_path.push(key.to_string());
+                push_value(rows, ctx, child_path, child);
+            }
+        }
+        _ => {
+            let editable = !matches!(value, Value::Sequence(_)) && path != ["name"];
+            let value = if ctx.settings.worktree_flag && path == ["worktree", "enabled"] {
+                Value::Bool(true)
+            } else {
+                value.clone()
+            };
+            rows.push(ConfigRow {
+                source: Some(ctx.source(&path)),
+                path,
+                depth,
+                label,
+                kind: RowKind::Value { value, editable },
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/config_editor_tests.rs"]
+mod tests;
@@ -1,14 +1,20 @@
 use anyhow::Result;
 use crossterm::event::{Event as CrosstermEvent, KeyEvent, KeyEventKind, MouseEvent};
 use futures::StreamExt;
+use std::collections::HashMap;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
+use crate::agents::permissions::ToolPermissionRequest;
+use crate::agents::resources::ProcessUsage;
 use crate::app::workflow_decisions::IterativePhase;
+use crate::app::workspace_trust::WorkspaceTrustRequest;
 use crate::app::AccountUsage;
+use crate::domain::review::{FeedbackItem, TriageDecision};
+use crate::domain::types::{CompletionActionOutcome, WorktreeState};
 use crate::domain::view::WorkflowView;
-use crate::tui::file_index::FileIndex;
-use crate::tui::session::{CliInstanceId, ReviewKind, TodoItem, ToolResultSummary};
+use crate::tui::file_index::{FileIndex, FileIndexDelta};
+use crate::tui::session::{CliInstanceId, DirConflict, ReviewKind, TodoItem, ToolResultSummary};
 use crate::update::{UpdateResult, UpdateStatus, VersionInfo};
 use std::time::Instant;
 
@@ -25,6 +31,25 @@ pub enum WorkflowCommand {
     Stop,
 }
 
+/// Session changes sent from UI to a running workflow, applied without
+/// interrupting it.
+#[derive(Debug, Clone)]
+pub enum WorkflowUpdate {
+    /// New feature name from `/rename`.
+    Rename(String),
+    /// Reviewer preset from `/reviewers`, used from the next review round.
+    ReviewerPreset(String),
+    /// Sequential (true) or parallel review from `/review-mode`, used from the next
+    /// review round.
+    ReviewMode(bool),
+    /// Worktree the session was moved into to stop sharing a checkout.
+    AttachWorktree(WorktreeState),
+    /// `/undo` of the final plan decision during its grace window.
+    UndoDecision,
+    /// Output of a user slash command for the next planner or implementer prompt.
+    QueueCommandOutput(String),
+}
+
 /// Custom error type for cancellation - avoids fragile string matching.
 #[derive(Debug, Clone)]
 pub struct CancellationError {
@@ -112,6 +137,14 @@ pub enum Event {
         display_id: String,
         error: String,
     },
+    /// A backup reviewer stands in for a failed reviewer within a round
+    SessionReviewerSubstituted {
+        session_id: usize,
+        kind: ReviewKind,
+        round: u32,
+        failed_id: String,
+        substitute_id: String,
+    },
     /// A review round has completed with aggregate verdict
     SessionReviewRoundCompleted {
         session_id: usize,
@@ -164,6 +197,21 @@ pub enum Event {
         session_id: usize,
         reason: String,
     },
+    /// An agent is paused on a high-risk tool call, waiting for allow/deny
+    SessionToolPermissionRequest {
+        session_id: usize,
+        request: ToolPermissionRequest,
+    },
+    /// Implementation is about to run in a repository with no trust decision yet
+    SessionWorkspaceTrustRequest {
+        session_id: usize,
+        request: WorkspaceTrustRequest,
+    },
+    /// A rejected round's feedback items are waiting for accept/park/dismiss triage
+    SessionFeedbackTriageRequest {
+        session_id: usize,
+        items: Vec<FeedbackItem>,
+    },
 
     SessionPlanGenerationFailed {
         session_id: usize,
@@ -195,6 +243,14 @@ pub enum Event {
         message: String,
     },
 
+    /// Coalesced fragment of the response an agent is generating; `None` ends it
+    SessionPartialText {
+        session_id: usize,
+        agent_name: String,
+        phase: String,
+        delta: Option<String>,
+    },
+
     /// Generic workflow failure that can be recovered via retry/stop/abort
     SessionWorkflowFailure {
         session_id: usize,
@@ -211,6 +267,16 @@ pub enum Event {
         session_id: usize,
         phase: String,
         run_id: u64,
+        /// Set to true to stop the summary agent, keeping the text streamed so far.
+        cancel_tx: watch::Sender<bool>,
+    },
+
+    /// Text the summary agent streamed since the last delta.
+    SessionRunTabSummaryDelta {
+        session_id: usize,
+        phase: String,
+        delta: String,
+        run_id: u64,
     },
 
     SessionRunTabSummaryReady {
@@ -248,14 +314,35 @@ pub enum Event {
         id: CliInstanceId,
         activity_at: Instant,
     },
+    SessionCliInstanceResources {
+        session_id: usize,
+        id: CliInstanceId,
+        usage: ProcessUsage,
+    },
     SessionCliInstanceFinished {
         session_id: usize,
         id: CliInstanceId,
     },
+    /// An invocation is waiting for a provider slot (`provider_concurrency`).
+    SessionCliInstanceQueued {
+        session_id: usize,
+        id: CliInstanceId,
+        agent_name: String,
+        queued_at: Instant,
+    },
 
     /// File index ready for @-mention auto-complete
     FileIndexReady(FileIndex),
 
+    /// Files added or removed since the file index was built or last updated
+    FileIndexUpdated(FileIndexDelta),
+
+    /// A worktree git operation (pre-merge sync or conflict resolution) finished
+    WorktreeGitDone {
+        session_id: usize,
+        step: crate::tui::session::WorktreeGitStep,
+    },
+
     /// Slash command execution result
     SlashCommandResult {
         command: String,
@@ -263,6 +350,12 @@ pub enum Event {
         error: Option<String>,
     },
 
+    /// Daemon registry checked for live sessions sharing a working directory,
+    /// keyed by workflow session ID
+    DirConflictsChecked {
+        conflicts: HashMap<String, DirConflict>,
+    },
+
     /// Session browser async refresh completed
     SessionBrowserRefreshComplete {
         entries: Vec<crate::tui::session_browser::SessionEntry>,
@@ -276,9 +369,20 @@ pub enum Event {
         error: Option<String>,
     },
 
+    /// Session browser bulk archive/delete completed
+    SessionBrowserBulkComplete {
+        summary: String,
+    },
+
     /// Push notification from daemon: session state changed
     DaemonSessionChanged(crate::session_daemon::SessionRecord),
 
+    /// Push notification from daemon: a workflow in another process recorded an event
+    DaemonWorkflowEvent {
+        session_id: String,
+        event: Box<crate::domain::view::WorkflowEventEnvelope>,
+    },
+
     /// Daemon subscription disconnected
     DaemonDisconnected,
 
@@ -298,6 +402,38 @@ pub enum Event {
     SessionImplementationInteractionFinished {
         session_id: usize,
     },
+    /// An agent invocation failed - keep its stderr tail for the error overlay
+    SessionAgentStderr {
+        session_id: usize,
+        agent_name: String,
+        lines: Vec<String>,
+        log_path: Option<std::path::PathBuf>,
+    },
+    /// Workflow accepted - show the run metrics and completion action outcomes
+    SessionCompletionSummary {
+        session_id: usize,
+        metrics: Vec<String>,
+        outcomes: Vec<CompletionActionOutcome>,
+    },
+    /// The current phase ran past `max_phase_duration` - show a warning
+    SessionPhaseDurationExceeded {
+        session_id: usize,
+        limit_minutes: u64,
+    },
+    /// A round passed its `cost_alerts` threshold - show a warning
+    SessionCostAlert {
+        session_id: usize,
+        message: String,
+    },
+    /// Another process took the session over - the tab follows it read-only
+    SessionFollowing {
+        session_id: usize,
+        holder_pid: u32,
+    },
+    /// The host GUI answered the approval prompt - close it
+    SessionRemoteAnswer {
+        session_id: usize,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -306,6 +442,11 @@ pub enum UserApprovalResponse {
     /// Accept and start implementation workflow
     Implement,
     Decline(String),
+    /// Approve with conditions appended to the plan, optionally starting implementation
+    ApproveWithConditions {
+        conditions: String,
+        implement: bool,
+    },
     ReviewRetry,
     ReviewContinue,
 
@@ -315,30 +456,59 @@ pub enum UserApprovalResponse {
     ProceedWithoutApproval,
     /// Continue with additional iterations (default 1 if 0 provided)
     ContinueReviewing(u32),
+    /// Counter-argument asking the rejecting reviewers to reconsider
+    Appeal(String),
 
     // Workflow failure recovery responses
     WorkflowFailureRetry,
     WorkflowFailureStop,
     WorkflowFailureAbort,
+
+    /// Triage decisions, one per feedback item in request order
+    FeedbackTriaged(Vec<TriageDecision>),
 }
 
+/// Without input for this long the TUI counts as idle.
+const IDLE_AFTER: Duration = Duration::from_secs(5 * 60);
+
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<Event>,
     _tx: mpsc::UnboundedSender<Event>,
+    tick_rate_tx: watch::Sender<Duration>,
+    /// Whether the terminal has focus and received input within `IDLE_AFTER`.
+    attended_rx: watch::Receiver<bool>,
 }
 
 impl EventHandler {
     pub fn new(tick_rate: Duration) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
         let event_tx = tx.clone();
+        let (attended_tx, attended_rx) = watch::channel(true);
+        let (tick_rate_tx, mut tick_rate_rx) = watch::channel(tick_rate);
 
         tokio::spawn(async move {
             let mut event_stream = crossterm::event::EventStream::new();
             let mut tick_interval = tokio::time::interval(tick_rate);
+            // Terminals without focus reporting never send FocusLost
+            let mut focused = true;
+            let mut last_input = Instant::now();
 
             loop {
                 tokio::select! {
                     maybe_event = event_stream.next() => {
+                        match &maybe_event {
+                            Some(Ok(CrosstermEvent::FocusLost)) => focused = false,
+                            Some(Ok(CrosstermEvent::FocusGained)) => {
+                                focused = true;
+                                last_input = Instant::now();
+                            }
+                            Some(Ok(
+                                CrosstermEvent::Key(_)
+                                | CrosstermEvent::Paste(_)
+                                | CrosstermEvent::Mouse(_),
+                            )) => last_input = Instant::now(),
+                            _ => {}
+                        }
                         match maybe_event {
                             Some(Ok(CrosstermEvent::Key(key))) => {
                                 if key.kind == KeyEventKind::Press
@@ -379,17 +549,44 @@ impl EventHandler {
                             break;
                         }
                     }
+                    Ok(()) = tick_rate_rx.changed() => {
+                        let rate = *tick_rate_rx.borrow_and_update();
+                        tick_interval = tokio::time::interval_at(tokio::time::Instant::now() + rate, rate);
+                    }
                 }
+
+                let attended = focused && last_input.elapsed() < IDLE_AFTER;
+                attended_tx
+                    .send_if_modified(|current| std::mem::replace(current, attended) != attended);
             }
         });
 
-        Self { rx, _tx: tx }
+        Self {
+            rx,
+            _tx: tx,
+            tick_rate_tx,
+            attended_rx,
+        }
+    }
+
+    /// Changes how often `Event::Tick` is sent; the next tick comes one period from now.
+    pub fn set_tick_rate(&self, rate: Duration) {
+        self.tick_rate_tx.send_if_modified(|current| {
+            let changed = *current != rate;
+            *current = rate;
+            changed
+        });
     }
 
     pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
         self._tx.clone()
     }
 
+    /// Follows whether someone is using the TUI, so background retries can pause.
+    pub fn attended(&self) -> watch::Receiver<bool> {
+        self.attended_rx.clone()
+    }
+
     pub async fn next(&mut self) -> Result<Event> {
         self.rx
             .recv()
@@ -0,0 +1,222 @@
+//! Which repository files the @-mention index offers.
+//!
+//! Git's own exclusions (`.gitignore`, `.git/info/exclude`, the global excludes
+//! file) apply to untracked files when the repository is listed. On top of
+//! that, `.ignore` and `.planningignore` files in the repository root or any
+//! folder below it are read with gitignore syntax; unlike `.gitignore` they
+//! also hide tracked files. Files larger than `file_index.max_file_size_kb` and
+//! binary files are left out as well, so build output and vendored blobs never
+//! show up in autocomplete or get mentioned in a prompt.
+
+use crate::app::package::glob_match;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+/// Ignore files read in addition to git's exclusions.
+pub const IGNORE_FILES: [&str; 2] = [".ignore", ".planningignore"];
+
+/// Bytes read from the start of a file to decide whether it is binary.
+const BINARY_SNIFF_BYTES: u64 = 8 * 1024;
+
+/// `file_index` section of the workflow config.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FileFilterConfig {
+    /// Files larger than this many KB are left out; 0 keeps every size.
+    #[serde(default = "default_max_file_size_kb")]
+    pub max_file_size_kb: u64,
+    /// Leave out files with a NUL byte near their start.
+    #[serde(default = "default_skip_binary")]
+    pub skip_binary: bool,
+}
+
+impl Default for FileFilterConfig {
+    fn default() -> Self {
+        Self {
+            max_file_size_kb: default_max_file_size_kb(),
+            skip_binary: default_skip_binary(),
+        }
+    }
+}
+
+fn default_max_file_size_kb() -> u64 {
+    1024
+}
+
+fn default_skip_binary() -> bool {
+    true
+}
+
+/// One line of an ignore file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnorePattern {
+    /// Folder holding the ignore file, relative to the root ("" for the root)
+    base: String,
+    segments: Vec<String>,
+    /// Matched against the whole path below `base` rather than any file name
+    anchored: bool,
+    dir_only: bool,
+    negated: bool,
+}
+
+/// Patterns from every ignore file, parents before the folders below them, so
+/// the deepest and last matching pattern decides like in git.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreRules {
+    patterns: Vec<IgnorePattern>,
+}
+
+impl IgnoreRules {
+    /// Reads the `.ignore` and `.planningignore` files found in a repository listing.
+    pub fn from_listing(root: &Path, files: &[String]) -> Self {
+        let mut ignore_files: Vec<&str> = files
+            .iter()
+            .map(String::as_str)
+            .filter(|path| {
+                IGNORE_FILES
+                    .iter()
+                    .any(|name| path.rsplit('/').next() == Some(*name))
+            })
+            .collect();
+        ignore_files.sort_by_key(|path| (path.matches('/').count(), *path));
+        let mut rules = Self::default();
+        for path in ignore_files {
+            if let Ok(content) = std::fs::read_to_string(root.join(path)) {
+                let base = path.rsplit_once('/').map_or("", |(base, _)| base);
+                rules.add(base, &content);
+            }
+        }
+        rules
+    }
+
+    /// Adds the patterns of an ignore file in the folder `base`.
+    pub fn add(&mut self, base: &str, content: &str) {
+        for line in content.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line.strip_prefix('\\').unwrap_or(line)),
+            };
+            let (dir_only, line) = match line.strip_suffix('/') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            // A slash anywhere but the end ties the pattern to `base`
+            let anchored = line.contains('/');
+            let line = line.strip_prefix('/').unwrap_or(line);
+            if line.is_empty() {
+                continue;
+            }
+            self.patterns.push(IgnorePattern {
+                base: base.to_string(),
+                segments: line.split('/').map(str::to_string).collect(),
+                anchored,
+                dir_only,
+                negated,
+            });
+        }
+    }
+
+    /// Whether the repository-relative `path`, or a folder above it, is ignored.
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        // Like git, nothing inside an ignored folder can be re-included
+        path.match_indices('/')
+            .any(|(end, _)| self.matches(&path[..end], true))
+            || self.matches(path, is_dir)
+    }
+
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        let mut ignored = false;
+        for pattern in &self.patterns {
+            if pattern.dir_only && !is_dir {
+                continue;
+            }
+            let relative = if pattern.base.is_empty() {
+                Some(path)
+            } else {
+                path.strip_prefix(pattern.base.as_str())
+                    .and_then(|rest| rest.strip_prefix('/'))
+            };
+            let Some(relative) = relative else {
+                continue;
+            };
+            let glob: Vec<&str> = pattern.segments.iter().map(String::as_str).collect();
+            let segments: Vec<&str> = relative.split('/').collect();
+            let hit = if pattern.anchored {
+                glob_match(&glob, &segments)
+            } else {
+                glob_match(&glob, &segments[segments.len() - 1..])
+            };
+            if hit {
+                ignored = !pattern.negated;
+            }
+        }
+        ignored
+    }
+}
+
+/// Applies the ignore files and the size and binary filters to repository
+/// listings, remembering which files were binary so each is only read once.
+#[derive(Debug, Default)]
+pub struct FileFilter {
+    config: FileFilterConfig,
+    binary: HashMap<String, bool>,
+}
+
+impl FileFilter {
+    pub fn new(config: FileFilterConfig) -> Self {
+        Self {
+            config,
+            binary: HashMap::new(),
+        }
+    }
+
+    /// Keeps the repository-relative files that should be offered.
+    pub fn apply(&mut self, root: &Path, files: Vec<String>) -> Vec<String> {
+        let rules = IgnoreRules::from_listing(root, &files);
+        let files: Vec<String> = files
+            .into_iter()
+            .filter(|path| !rules.is_ignored(path, false) && self.keeps(root, path))
+            .collect();
+        if self.binary.len() > files.len() {
+            let listed: HashSet<&str> = files.iter().map(String::as_str).collect();
+            self.binary.retain(|path, _| listed.contains(path.as_str()));
+        }
+        files
+    }
+
+    fn keeps(&mut self, root: &Path, path: &str) -> bool {
+        let full_path = root.join(path);
+        let limit = self.config.max_file_size_kb * 1024;
+        // Tracked files deleted from the working tree have no size and stay listed
+        let too_large =
+            limit > 0 && std::fs::metadata(&full_path).is_ok_and(|metadata| metadata.len() > limit);
+        if too_large {
+            return false;
+        }
+        !self.config.skip_binary
+            || !*self
+                .binary
+                .entry(path.to_string())
+                .or_insert_with(|| is_binary(&full_path))
+    }
+}
+
+/// Whether the file has a NUL byte in its first few KB, as git decides it.
+pub fn is_binary(path: &Path) -> bool {
+    let mut head = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|file| file.take(BINARY_SNIFF_BYTES).read_to_end(&mut head))
+        .is_ok_and(|_| head.contains(&0))
+}
+
+#[cfg(test)]
+#[path = "tests/file_filter_tests.rs"]
+mod tests;
@@ -0,0 +1,98 @@
+use super::*;
+use tempfile::tempdir;
+
+fn rules(files: &[(&str, &str)]) -> IgnoreRules {
+    let mut rules = IgnoreRules::default();
+    for (base, content) in files {
+        rules.add(base, content);
+    }
+    rules
+}
+
+#[test]
+fn test_unanchored_patterns_match_names_at_any_depth() {
+    let rules = rules(&[("", "# build output\nnode_modules/\n*.min.js\n")]);
+    assert!(rules.is_ignored("node_modules/react/index.js", false));
+    assert!(rules.is_ignored("web/node_modules/a.js", false));
+    assert!(rules.is_ignored("web/dist/app.min.js", false));
+    assert!(!rules.is_ignored("web/app.js", false));
+    // Folder-only patterns don't hide a file with that name
+    assert!(!rules.is_ignored("docs/node_modules", false));
+}
+
+#[test]
+fn test_anchored_patterns_are_relative_to_their_file() {
+    let rules = rules(&[("", "/target\nfixtures/**/*.bin\n"), ("web", "/dist/\n")]);
+    assert!(rules.is_ignored("target/debug/app", false));
+    assert!(!rules.is_ignored("crates/x/target/debug/app", false));
+    assert!(rules.is_ignored("fixtures/a/b/c.bin", false));
+    assert!(rules.is_ignored("web/dist/app.js", false));
+    assert!(!rules.is_ignored("dist/app.js", false));
+}
+
+#[test]
+fn test_later_and_deeper_negations_win() {
+    let rules = rules(&[
+        ("", "*.log\n!keep.log\nvendor/\n"),
+        ("logs", "!debug.log\n"),
+        ("vendor", "!lib.rs\n"),
+    ]);
+    assert!(rules.is_ignored("a/trace.log", false));
+    assert!(!rules.is_ignored("a/keep.log", false));
+    assert!(!rules.is_ignored("logs/debug.log", false));
+    // Files inside an ignored folder can't be re-included
+    assert!(rules.is_ignored("vendor/lib.rs", false));
+}
+
+#[test]
+fn test_filter_reads_ignore_files_and_limits_from_listing() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("web/build")).unwrap();
+    std::fs::write(root.join(".planningignore"), "*.snap\n").unwrap();
+    std::fs::write(root.join("web/.ignore"), "build/\n").unwrap();
+    std::fs::write(root.join("web/build/out.js"), "x").unwrap();
+    std::fs::write(root.join("web/app.js"), "let a = 1;").unwrap();
+    std::fs::write(root.join("ui.snap"), "snapshot").unwrap();
+    std::fs::write(root.join("logo.png"), [0x89, b'P', b'N', b'G', 0, 0]).unwrap();
+    std::fs::write(root.join("huge.sql"), vec![b'a'; 3 * 1024]).unwrap();
+
+    let listing: Vec<String> = [
+        ".planningignore",
+        "web/.ignore",
+        "web/build/out.js",
+        "web/app.js",
+        "ui.snap",
+        "logo.png",
+        "huge.sql",
+        "deleted.rs",
+    ]
+    .map(str::to_string)
+    .to_vec();
+
+    let mut filter = FileFilter::new(FileFilterConfig {
+        max_file_size_kb: 2,
+        skip_binary: true,
+    });
+    assert_eq!(
+        filter.apply(root, listing.clone()),
+        vec![".planningignore", "web/.ignore", "web/app.js", "deleted.rs"]
+    );
+
+    let mut unlimited = FileFilter::new(FileFilterConfig {
+        max_file_size_kb: 0,
+        skip_binary: false,
+    });
+    let kept = unlimited.apply(root, listing);
+    assert!(kept.contains(&"logo.png".to_string()));
+    assert!(kept.contains(&"huge.sql".to_string()));
+    assert!(!kept.contains(&"ui.snap".to_string()));
+}
+
+#[test]
+fn test_file_index_config_defaults() {
+    let config: FileFilterConfig = serde_yaml::from_str("{}").unwrap();
+    assert_eq!(config, FileFilterConfig::default());
+    assert_eq!(config.max_file_size_kb, 1024);
+    assert!(config.skip_binary);
+}
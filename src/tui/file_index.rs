@@ -1,8 +1,21 @@
 //! File index for @-mention auto-complete functionality.
-//! Built once at TUI startup from `git ls-files` output.
-//! Includes both files and folders from the working directory.
+//! Built at TUI startup from `git ls-files` output, then kept current by a
+//! watcher that re-lists the repository and sends the differences.
+//! Includes both files and folders from the working directory, minus what
+//! [`super::file_filter`] leaves out.
+use super::file_filter::{FileFilter, FileFilterConfig};
+use super::Event;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// How often the watcher lists the repository's files.
+pub const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Age after which the dropdown says how old the index is.
+pub const STALE_AFTER: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone)]
 pub struct FileEntry {
@@ -65,6 +78,33 @@ pub struct FileIndex {
     /// When `Some`, `find_matches` will produce absolute paths for insertion.
     /// When `None` (for tests or loading state), insertion falls back to relative display paths.
     pub repo_root: Option<PathBuf>,
+    /// When the watcher last confirmed the index matches the repository.
+    pub synced_at: Option<Instant>,
+    /// Files changed and the watcher is waiting for writes to settle.
+    pub pending_changes: bool,
+}
+
+/// Files added and removed since the watcher's last update.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileIndexDelta {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// The listing changed but is not settled yet; nothing is applied.
+    pub pending: bool,
+}
+
+impl FileIndexDelta {
+    pub fn between(old: &HashSet<String>, new: &HashSet<String>) -> Self {
+        let mut added: Vec<String> = new.difference(old).cloned().collect();
+        let mut removed: Vec<String> = old.difference(new).cloned().collect();
+        added.sort();
+        removed.sort();
+        Self {
+            added,
+            removed,
+            pending: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -101,6 +141,8 @@ impl FileIndex {
             status: FileIndexStatus::Loading,
             entries: Vec::new(),
             repo_root: None,
+            synced_at: None,
+            pending_changes: false,
         }
     }
 
@@ -112,6 +154,8 @@ impl FileIndex {
             status: FileIndexStatus::Ready,
             entries,
             repo_root: None,
+            synced_at: None,
+            pending_changes: false,
         }
     }
 
@@ -121,6 +165,8 @@ impl FileIndex {
             status: FileIndexStatus::Ready,
             entries,
             repo_root: Some(repo_root),
+            synced_at: Some(Instant::now()),
+            pending_changes: false,
         }
     }
 
@@ -129,13 +175,94 @@ impl FileIndex {
             status: FileIndexStatus::Error,
             entries: Vec::new(),
             repo_root: None,
+            synced_at: None,
+            pending_changes: false,
         }
     }
 
+    /// Index of the given repository-relative file paths and their folders.
+    pub fn from_files(file_paths: Vec<String>, repo_root: PathBuf) -> Self {
+        // Folders first (sorted for consistent ordering), then files
+        let mut folders: Vec<String> = parent_folders(file_paths.iter().map(String::as_str))
+            .into_iter()
+            .collect();
+        folders.sort();
+        let mut entries: Vec<FileEntry> = Vec::with_capacity(folders.len() + file_paths.len());
+        entries.extend(folders.into_iter().map(FileEntry::new_folder));
+        entries.extend(file_paths.into_iter().map(FileEntry::new));
+        Self::with_entries_and_root(entries, repo_root)
+    }
+
     pub fn is_ready(&self) -> bool {
         matches!(self.status, FileIndexStatus::Ready)
     }
 
+    /// Applies a watcher update: adds and removes files, adds their new folders
+    /// and drops folders left empty.
+    pub fn apply_delta(&mut self, delta: FileIndexDelta, now: Instant) {
+        if !self.is_ready() {
+            return;
+        }
+        self.pending_changes = delta.pending;
+        if delta.pending {
+            return;
+        }
+        self.synced_at = Some(now);
+        if delta.added.is_empty() && delta.removed.is_empty() {
+            return;
+        }
+
+        let removed: HashSet<&str> = delta.removed.iter().map(String::as_str).collect();
+        self.entries
+            .retain(|entry| entry.path.ends_with('/') || !removed.contains(entry.path.as_str()));
+        let known: HashSet<String> = self.entries.iter().map(|e| e.path.clone()).collect();
+        self.entries.extend(
+            delta
+                .added
+                .into_iter()
+                .filter(|path| !known.contains(path))
+                .map(FileEntry::new),
+        );
+
+        let live = parent_folders(
+            self.entries
+                .iter()
+                .filter(|entry| !entry.path.ends_with('/'))
+                .map(|entry| entry.path.as_str()),
+        );
+        self.entries.retain(|entry| {
+            !entry.path.ends_with('/') || live.contains(entry.path.trim_end_matches('/'))
+        });
+        let mut new_folders: Vec<String> = live
+            .into_iter()
+            .filter(|folder| !known.contains(&format!("{}/", folder)))
+            .collect();
+        new_folders.sort();
+        self.entries
+            .extend(new_folders.into_iter().map(FileEntry::new_folder));
+    }
+
+    /// Note for the autocomplete dropdown when the index may be missing files:
+    /// changes are settling, or the watcher has not synced for a while.
+    pub fn staleness_note(&self, now: Instant) -> Option<String> {
+        if !self.is_ready() {
+            return None;
+        }
+        if self.pending_changes {
+            return Some("updating…".to_string());
+        }
+        let age = now.duration_since(self.synced_at?);
+        if age < STALE_AFTER {
+            return None;
+        }
+        let secs = age.as_secs();
+        Some(if secs < 60 {
+            format!("index {}s old", secs)
+        } else {
+            format!("index {}m old", secs / 60)
+        })
+    }
+
     /// Find matches for the given query string.
     /// Returns up to `limit` matches sorted by relevance score.
     pub fn find_matches(&self, query: &str, limit: usize) -> Vec<MentionMatch> {
@@ -222,38 +349,44 @@ impl FileIndex {
     }
 }
 
-/// Build a file index by running `git ls-files` in the given directory.
-/// Includes both files and folders (extracted from file paths).
+/// Every folder above the given paths, without trailing `/`.
+fn parent_folders<'a>(paths: impl Iterator<Item = &'a str>) -> HashSet<String> {
+    let mut folders = HashSet::new();
+    for path in paths {
+        let mut current = path;
+        while let Some((parent, _)) = current.rsplit_once('/') {
+            if !parent.is_empty() {
+                folders.insert(parent.to_string());
+            }
+            current = parent;
+        }
+    }
+    folders
+}
+
+/// Lists the repository root and its tracked and untracked, not ignored files
+/// (`.gitignore` and other exclude files apply). `None` outside a git repo.
 /// This function is meant to be called via `tokio::task::spawn_blocking`.
 ///
 /// Note: `git ls-files` outputs paths relative to the **repository root**, not the working_dir.
 /// We use `git rev-parse --show-toplevel` to get the repo root for computing correct absolute paths.
-pub fn build_file_index(working_dir: &std::path::Path) -> FileIndex {
+fn list_repo_files(working_dir: &Path) -> Option<(PathBuf, Vec<String>)> {
     use std::process::Command;
 
-    // First, get the repository root using `git rev-parse --show-toplevel`.
-    // This is necessary because `git ls-files` outputs repo-root-relative paths,
-    // not working_dir-relative paths. Using working_dir as base would produce
-    // incorrect paths when working_dir is a subdirectory.
-    let repo_root_output = Command::new("git")
+    // `git ls-files` outputs repo-root-relative paths, not working_dir-relative
+    // paths, so using working_dir as base would produce incorrect paths when
+    // working_dir is a subdirectory.
+    let output = Command::new("git")
         .args(["rev-parse", "--show-toplevel"])
         .current_dir(working_dir)
-        .output();
-
-    let repo_root = match repo_root_output {
-        Ok(output) if output.status.success() => {
-            let raw_root = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            let root_path = PathBuf::from(&raw_root);
-            // Canonicalize to resolve symlinks; fall back to raw path if canonicalization fails
-            std::fs::canonicalize(&root_path).unwrap_or(root_path)
-        }
-        _ => {
-            // Not a git repo or git not available
-            return FileIndex::with_error();
-        }
-    };
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let raw_root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let root_path = PathBuf::from(&raw_root);
+    // Canonicalize to resolve symlinks; fall back to raw path if canonicalization fails
+    let repo_root = std::fs::canonicalize(&root_path).unwrap_or(root_path);
 
-    // Try to run git ls-files
     let output = Command::new("git")
         .args([
             "ls-files",
@@ -263,51 +396,90 @@ pub fn build_file_index(working_dir: &std::path::Path) -> FileIndex {
             "--exclude-standard",
         ])
         .current_dir(working_dir)
-        .output();
-
-    match output {
-        Ok(output) => {
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-
-                // Collect all file entries
-                let file_paths: Vec<&str> = stdout.split('\0').filter(|s| !s.is_empty()).collect();
-
-                // Extract unique folder paths from file paths
-                let mut folder_set: HashSet<String> = HashSet::new();
-                for path in &file_paths {
-                    // Extract all parent directories
-                    let mut current = *path;
-                    while let Some((parent, _)) = current.rsplit_once('/') {
-                        if !parent.is_empty() && folder_set.insert(parent.to_string()) {
-                            // Continue to extract parent folders
-                        }
-                        current = parent;
-                    }
-                }
-
-                // Build entries: folders first, then files
-                let mut entries: Vec<FileEntry> =
-                    Vec::with_capacity(folder_set.len() + file_paths.len());
-
-                // Add folders (sorted for consistent ordering)
-                let mut folders: Vec<String> = folder_set.into_iter().collect();
-                folders.sort();
-                for folder in folders {
-                    entries.push(FileEntry::new_folder(folder));
-                }
-
-                // Add files
-                for path in file_paths {
-                    entries.push(FileEntry::new(path.to_string()));
-                }
-
-                FileIndex::with_entries_and_root(entries, repo_root)
-            } else {
-                FileIndex::with_error()
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let files = String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some((repo_root, files))
+}
+
+/// Lists the repository and drops the files the filter leaves out.
+async fn list_indexed_files(
+    working_dir: &Path,
+    filter: &Arc<Mutex<FileFilter>>,
+) -> Option<(PathBuf, Vec<String>)> {
+    let dir = working_dir.to_path_buf();
+    let filter = Arc::clone(filter);
+    tokio::task::spawn_blocking(move || {
+        let (repo_root, files) = list_repo_files(&dir)?;
+        let files = filter
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .apply(&repo_root, files);
+        Some((repo_root, files))
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Builds the index, sends it as `FileIndexReady`, then re-lists the repository
+/// every [`WATCH_POLL_INTERVAL`] and sends the differences as `FileIndexUpdated`.
+///
+/// Changes are debounced: a new listing is applied only once it has stayed the
+/// same for a whole interval, so an agent writing many files causes one update.
+/// Runs until the TUI stops receiving events.
+pub async fn watch_file_index(
+    working_dir: PathBuf,
+    filter_config: FileFilterConfig,
+    tx: mpsc::UnboundedSender<Event>,
+) {
+    let filter = Arc::new(Mutex::new(FileFilter::new(filter_config)));
+    let Some((repo_root, files)) = list_indexed_files(&working_dir, &filter).await else {
+        // Receiver dropped means TUI is shutting down - safe to ignore
+        let _ = tx.send(Event::FileIndexReady(FileIndex::with_error()));
+        return;
+    };
+    let mut known: HashSet<String> = files.iter().cloned().collect();
+    if tx
+        .send(Event::FileIndexReady(FileIndex::from_files(
+            files, repo_root,
+        )))
+        .is_err()
+    {
+        return;
+    }
+
+    let mut settling: Option<HashSet<String>> = None;
+    loop {
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+        // A failed listing leaves the index as it is; it shows as stale
+        let Some((_, files)) = list_indexed_files(&working_dir, &filter).await else {
+            continue;
+        };
+        let current: HashSet<String> = files.into_iter().collect();
+        let delta = if current == known {
+            settling = None;
+            FileIndexDelta::default()
+        } else if settling.as_ref() == Some(&current) {
+            let delta = FileIndexDelta::between(&known, &current);
+            known = current;
+            settling = None;
+            delta
+        } else {
+            settling = Some(current);
+            FileIndexDelta {
+                pending: true,
+                ..FileIndexDelta::default()
             }
+        };
+        if tx.send(Event::FileIndexUpdated(delta)).is_err() {
+            return;
         }
-        Err(_) => FileIndex::with_error(),
     }
 }
 
@@ -18,6 +18,8 @@ pub struct MentionState {
     pub matches: Vec<MentionMatch>,
     /// Currently selected match index
     pub selected_idx: usize,
+    /// Shown in the dropdown when the file index may be missing recent files
+    pub index_note: Option<String>,
 }
 
 impl MentionState {
@@ -32,6 +34,7 @@ impl MentionState {
         self.start_byte = 0;
         self.matches.clear();
         self.selected_idx = 0;
+        self.index_note = None;
     }
 
     /// Move selection up
@@ -170,6 +173,7 @@ pub fn update_mention_state(
         Some((start_byte, query)) => {
             mention_state.active = true;
             mention_state.start_byte = start_byte;
+            mention_state.index_note = file_index.staleness_note(std::time::Instant::now());
 
             // Only update matches if query changed
             if mention_state.query != query {
@@ -1,5 +1,7 @@
+pub mod config_editor;
 pub mod cursor_utils;
 mod event;
+pub mod file_filter;
 pub mod file_index;
 pub mod mention;
 pub mod scroll;
@@ -14,13 +16,14 @@ pub mod workflow_browser;
 
 pub use event::{
     CancellationError, Event, EventHandler, SessionEventSender, TokenUsage, UserApprovalResponse,
-    WorkflowCommand,
+    WorkflowCommand, WorkflowUpdate,
 };
 pub use scroll::ScrollableRegions;
 pub use session::{
-    ApprovalContext, ApprovalMode, CliInstanceId, FeedbackTarget, FocusedPanel, InputMode,
-    ReviewKind, RunTab, RunTabEntry, Session, SessionContext, SessionStatus, SummaryState,
+    ApprovalContext, ApprovalMode, ChatVerbosity, CliInstanceId, DirConflict, DraftKind,
+    ErrorReport, ErrorSeverity, FeedbackTarget, FocusedPanel, InputMode, ReviewKind, RunTab,
+    RunTabEntry, Session, SessionContext, SessionStatus, SuggestedAction, SummaryState, TailTarget,
     TodoItem, TodoStatus, ToolKind, ToolResultSummary, ToolTimelineEntry,
 };
-pub use tabs::TabManager;
-pub use title::TerminalTitleManager;
+pub use tabs::{MacroKeys, NotificationKind, SuspendedSession, TabManager};
+pub use title::{copy_to_clipboard, NotifyMode, TerminalProgress, TerminalTitleManager};
@@ -2,9 +2,12 @@
 //!
 //! - `ScrollableRegions`: Tracks screen positions and scroll bounds of all scrollable regions
 //! - `ScrollState`: Encapsulates scroll position and follow mode for a scrollable panel
+//! - `NavState`: Vim-style cursor, search and selection in the output and chat panels
 
+mod navigation;
 mod regions;
 mod state;
 
+pub use navigation::{line_matches, scroll_to_show, NavState};
 pub use regions::{ScrollRegion, ScrollableRegions};
 pub use state::ScrollState;
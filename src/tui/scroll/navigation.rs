@@ -0,0 +1,135 @@
+use crate::tui::FocusedPanel;
+
+/// Vim-style navigation of the output or chat panel.
+///
+/// The cursor moves over the panel's lines (one per output line or chat entry);
+/// the panel scrolls to keep it in view. `v` anchors a selection at the cursor,
+/// `/` types a search pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavState {
+    /// The panel being navigated
+    pub panel: FocusedPanel,
+    /// Line the cursor is on
+    pub cursor: usize,
+    /// Where the selection started, while one is active
+    pub anchor: Option<usize>,
+    /// Search pattern being typed after `/`
+    pub search_input: Option<String>,
+    /// Last searched pattern, repeated with `n` and `N`
+    pub last_search: Option<String>,
+    /// `g` was pressed and waits for a second `g`
+    pub pending_g: bool,
+    /// Result of the last command, shown in the panel title
+    pub message: Option<String>,
+}
+
+impl NavState {
+    pub fn new(panel: FocusedPanel, cursor: usize) -> Self {
+        Self {
+            panel,
+            cursor,
+            anchor: None,
+            search_input: None,
+            last_search: None,
+            pending_g: false,
+            message: None,
+        }
+    }
+
+    /// Moves the cursor by `delta` lines, staying within `line_count` lines.
+    pub fn move_by(&mut self, delta: isize, line_count: usize) {
+        let target = self.cursor.saturating_add_signed(delta);
+        self.move_to(target, line_count);
+    }
+
+    /// Moves the cursor to `line`, clamped to the last of `line_count` lines.
+    pub fn move_to(&mut self, line: usize, line_count: usize) {
+        self.cursor = line.min(line_count.saturating_sub(1));
+    }
+
+    /// First and last selected line. Without a selection, the cursor line.
+    pub fn selection(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+        (anchor.min(self.cursor), anchor.max(self.cursor))
+    }
+
+    /// Whether `line` is in the active selection.
+    pub fn is_selected(&self, line: usize) -> bool {
+        let (first, last) = self.selection();
+        self.anchor.is_some() && (first..=last).contains(&line)
+    }
+
+    /// Moves the cursor to the next line matching `pattern` (the previous one
+    /// when `forward` is false), wrapping around. Returns false without a match.
+    pub fn find(&mut self, lines: &[String], pattern: &str, forward: bool) -> bool {
+        let count = lines.len();
+        let found = (1..=count)
+            .map(|step| {
+                if forward {
+                    (self.cursor + step) % count
+                } else {
+                    (self.cursor + count - step % count) % count
+                }
+            })
+            .find(|&line| line_matches(&lines[line], pattern));
+        if let Some(line) = found {
+            self.cursor = line;
+        }
+        found.is_some()
+    }
+
+    /// The selected lines (or the cursor line) joined for the clipboard.
+    pub fn selected_text(&self, lines: &[String]) -> String {
+        let (first, last) = self.selection();
+        lines
+            .iter()
+            .skip(first)
+            .take(last + 1 - first)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Mode indicator for the panel title.
+    pub fn title_tag(&self) -> String {
+        if let Some(input) = &self.search_input {
+            return format!("/{}", input);
+        }
+        if let Some(message) = &self.message {
+            return message.clone();
+        }
+        match self.anchor {
+            Some(_) => {
+                let (first, last) = self.selection();
+                format!("VISUAL {} lines", last + 1 - first)
+            }
+            None => "NAV".to_string(),
+        }
+    }
+}
+
+/// Whether `line` contains `pattern`; case-insensitive unless the pattern has
+/// an uppercase letter, like Vim's `smartcase`.
+pub fn line_matches(line: &str, pattern: &str) -> bool {
+    if pattern.chars().any(char::is_uppercase) {
+        line.contains(pattern)
+    } else {
+        line.to_lowercase().contains(&pattern.to_lowercase())
+    }
+}
+
+/// Scroll position that shows rows `first..=last` of content in a viewport of
+/// `height` rows, moving as little as possible from `position`.
+pub fn scroll_to_show(position: usize, first: usize, last: usize, height: usize) -> usize {
+    if first < position {
+        first
+    } else if last >= position + height.max(1) {
+        (last + 1).saturating_sub(height.max(1)).min(first)
+    } else {
+        position
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/navigation_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+
+fn lines(text: &[&str]) -> Vec<String> {
+    text.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_cursor_stays_within_the_lines() {
+    let mut nav = NavState::new(FocusedPanel::Output, 2);
+    nav.move_by(-5, 10);
+    assert_eq!(nav.cursor, 0);
+    nav.move_by(20, 10);
+    assert_eq!(nav.cursor, 9);
+    nav.move_to(4, 3);
+    assert_eq!(nav.cursor, 2);
+}
+
+#[test]
+fn test_find_wraps_around_and_uses_smartcase() {
+    let lines = lines(&[
+        "[planning] Starting",
+        "[claude] error: timeout",
+        "[planning] Done",
+        "[claude] Error: disk full",
+    ]);
+    let mut nav = NavState::new(FocusedPanel::Output, 1);
+
+    assert!(nav.find(&lines, "error", true));
+    assert_eq!(nav.cursor, 3);
+    assert!(nav.find(&lines, "error", true));
+    assert_eq!(nav.cursor, 1);
+    assert!(nav.find(&lines, "Error", true));
+    assert_eq!(nav.cursor, 3);
+    assert!(nav.find(&lines, "planning", false));
+    assert_eq!(nav.cursor, 2);
+    assert!(!nav.find(&lines, "missing", true));
+    assert_eq!(nav.cursor, 2);
+}
+
+#[test]
+fn test_selection_runs_either_way_from_the_anchor() {
+    let lines = lines(&["a", "b", "c", "d"]);
+    let mut nav = NavState::new(FocusedPanel::Chat, 2);
+    assert_eq!(nav.selected_text(&lines), "c");
+    assert!(!nav.is_selected(2));
+
+    nav.anchor = Some(2);
+    nav.move_by(-2, lines.len());
+    assert_eq!(nav.selection(), (0, 2));
+    assert!(nav.is_selected(1));
+    assert_eq!(nav.selected_text(&lines), "a\nb\nc");
+    assert_eq!(nav.title_tag(), "VISUAL 3 lines");
+}
+
+#[test]
+fn test_scroll_to_show_moves_as_little_as_possible() {
+    assert_eq!(scroll_to_show(10, 12, 12, 5), 10);
+    assert_eq!(scroll_to_show(10, 4, 4, 5), 4);
+    assert_eq!(scroll_to_show(10, 16, 17, 5), 13);
+    // A line taller than the viewport shows its start
+    assert_eq!(scroll_to_show(0, 20, 30, 5), 20);
+}
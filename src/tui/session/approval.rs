@@ -1,9 +1,13 @@
 //! Approval-related methods for Session.
 
 use super::{ApprovalContext, ApprovalMode, FeedbackTarget, Session, SessionStatus};
+use crate::domain::types::{ApprovalGate, Phase};
+use crate::tui::UserApprovalResponse;
+use tokio::sync::mpsc;
 
 impl Session {
     pub fn start_approval(&mut self, summary: String) {
+        self.approval_conditions = None;
         self.plan_summary = summary;
         self.plan_summary_scroll = 0;
         self.approval_mode = ApprovalMode::AwaitingChoice;
@@ -33,6 +37,23 @@ impl Session {
         self.status = SessionStatus::AwaitingApproval;
     }
 
+    /// Whether the prompt holds back rejections that can be appealed: planning's
+    /// max iterations prompt or the `reviewing` approval gate.
+    pub fn can_appeal_rejection(&self) -> bool {
+        let Some(view) = self.workflow_view.as_ref() else {
+            return false;
+        };
+        match self.approval_context {
+            ApprovalContext::MaxIterationsReached => {
+                view.planning_phase() == Some(Phase::AwaitingPlanningDecision)
+            }
+            ApprovalContext::PlanApproval => {
+                view.open_approval_gate() == Some(ApprovalGate::Reviewing)
+            }
+            _ => false,
+        }
+    }
+
     pub fn start_plan_generation_failed(&mut self, error: String) {
         self.plan_summary = error;
         self.plan_summary_scroll = 0;
@@ -44,6 +65,7 @@ impl Session {
     }
 
     pub fn start_user_override_approval(&mut self, summary: String) {
+        self.approval_conditions = None;
         self.plan_summary = summary;
         self.plan_summary_scroll = 0;
         self.approval_mode = ApprovalMode::AwaitingChoice;
@@ -87,11 +109,59 @@ impl Session {
         self.start_feedback_input_for(FeedbackTarget::ApprovalDecline);
     }
 
+    /// Opens the conditions editor, prefilled with conditions entered earlier.
+    pub fn start_conditions_input(&mut self) {
+        let existing = self.approval_conditions.clone().unwrap_or_default();
+        self.start_feedback_input_for(FeedbackTarget::ApprovalConditions);
+        self.cursor_position = existing.len();
+        self.user_feedback = existing;
+    }
+
+    /// The response for accepting the plan, carrying any entered conditions.
+    pub fn take_accept_response(&mut self, implement: bool) -> UserApprovalResponse {
+        match self.approval_conditions.take() {
+            Some(conditions) => UserApprovalResponse::ApproveWithConditions {
+                conditions,
+                implement,
+            },
+            None if implement => UserApprovalResponse::Implement,
+            None => UserApprovalResponse::Accept,
+        }
+    }
+
+    /// Whether the approval prompt is an approval gate, after which the run goes on.
+    pub fn at_approval_gate(&self) -> bool {
+        self.workflow_view
+            .as_ref()
+            .and_then(|view| view.open_approval_gate())
+            .is_some()
+    }
+
+    /// The sender for answering the approval prompt. The final decision is the
+    /// run's last use of it, unless the run goes on past an approval gate, into
+    /// implementation or into watching files with `--watch`.
+    pub fn take_approval_tx(
+        &mut self,
+        run_goes_on: bool,
+    ) -> Option<mpsc::Sender<UserApprovalResponse>> {
+        let watching = self
+            .workflow_view
+            .as_ref()
+            .and_then(|view| view.replan_watch())
+            .is_some();
+        if run_goes_on || self.at_approval_gate() || watching {
+            self.approval_tx.clone()
+        } else {
+            self.approval_tx.take()
+        }
+    }
+
     pub fn start_feedback_input_for(&mut self, target: FeedbackTarget) {
         self.approval_mode = ApprovalMode::EnteringFeedback;
         self.feedback_target = target;
         self.user_feedback.clear();
         self.cursor_position = 0;
         self.feedback_scroll = 0;
+        self.restore_draft();
     }
 }
@@ -1,6 +1,10 @@
 use super::model::{ChatMessage, RunTab, RunTabEntry, SummaryState, ToolTimelineEntry};
-use super::Session;
+use super::{FocusedPanel, Session};
+use crate::planning_paths;
 use crate::tui::scroll::ScrollState;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::sync::watch;
 
 /// Normalize a phase name by stripping trailing " Summary" suffix.
 /// This is a defensive measure to ensure summary agent output is routed
@@ -34,12 +38,50 @@ impl Session {
             }
         };
 
-        self.run_tabs[idx]
-            .entries
-            .push(RunTabEntry::Text(ChatMessage {
+        let tab = &mut self.run_tabs[idx];
+        // The finished message replaces the agent's streamed text
+        tab.partial.retain(|p| p.agent_name != agent_name);
+        tab.entries.push(RunTabEntry::Text(ChatMessage {
+            agent_name: agent_name.to_string(),
+            message,
+        }));
+    }
+
+    /// Appends streamed text to the agent's in-progress response in the phase tab.
+    pub fn append_partial_text(&mut self, agent_name: &str, phase: &str, delta: &str) {
+        let normalized_phase = normalize_phase(phase);
+        let idx = match self
+            .run_tabs
+            .iter()
+            .position(|t| t.phase == normalized_phase)
+        {
+            Some(i) => i,
+            None => {
+                self.add_run_tab(normalized_phase.to_string());
+                self.run_tabs.len() - 1
+            }
+        };
+
+        let partial = &mut self.run_tabs[idx].partial;
+        match partial.iter_mut().find(|p| p.agent_name == agent_name) {
+            Some(message) => message.message.push_str(delta),
+            None => partial.push(ChatMessage {
                 agent_name: agent_name.to_string(),
-                message,
-            }));
+                message: delta.to_string(),
+            }),
+        }
+    }
+
+    /// Discards the agent's in-progress response in the phase tab.
+    pub fn clear_partial_text(&mut self, agent_name: &str, phase: &str) {
+        let normalized_phase = normalize_phase(phase);
+        if let Some(tab) = self
+            .run_tabs
+            .iter_mut()
+            .find(|t| t.phase == normalized_phase)
+        {
+            tab.partial.retain(|p| p.agent_name != agent_name);
+        }
     }
 
     pub fn add_tool_entry(&mut self, phase: &str, entry: ToolTimelineEntry) {
@@ -111,27 +153,64 @@ impl Session {
         }
     }
 
-    pub fn set_summary_generating(&mut self, phase: &str) {
+    pub fn set_summary_generating(&mut self, phase: &str, cancel_tx: watch::Sender<bool>) {
         if let Some(tab) = self.run_tabs.iter_mut().find(|t| t.phase == phase) {
             tab.summary_state = SummaryState::Generating;
             tab.summary_spinner_frame = 0;
             tab.summary_text.clear();
+            tab.summary_cancel = Some(cancel_tx);
+        }
+    }
+
+    /// Appends streamed summary text while the summary is still generating.
+    pub fn append_summary_text(&mut self, phase: &str, delta: &str) {
+        if let Some(tab) = self
+            .run_tabs
+            .iter_mut()
+            .find(|t| t.phase == phase && t.summary_state == SummaryState::Generating)
+        {
+            tab.summary_text.push_str(delta);
         }
     }
 
     pub fn set_summary_ready(&mut self, phase: &str, summary: String) {
         if let Some(tab) = self.run_tabs.iter_mut().find(|t| t.phase == phase) {
+            if tab.summary_state == SummaryState::Cancelled {
+                return;
+            }
             tab.summary_text = summary;
             tab.summary_state = SummaryState::Ready;
             tab.summary_scroll = ScrollState::new();
+            tab.summary_cancel = None;
         }
     }
 
     pub fn set_summary_error(&mut self, phase: &str, error: String) {
         if let Some(tab) = self.run_tabs.iter_mut().find(|t| t.phase == phase) {
+            if tab.summary_state == SummaryState::Cancelled {
+                return;
+            }
             tab.summary_text = error;
             tab.summary_state = SummaryState::Error;
+            tab.summary_cancel = None;
+        }
+    }
+
+    /// Stops the active run tab's summary agent, keeping the text streamed so far.
+    /// Returns false if that summary is not generating.
+    pub fn cancel_active_summary(&mut self) -> bool {
+        let Some(tab) = self.run_tabs.get_mut(self.active_run_tab) else {
+            return false;
+        };
+        if tab.summary_state != SummaryState::Generating {
+            return false;
         }
+        if let Some(cancel_tx) = tab.summary_cancel.take() {
+            // Watch channel send may fail if the agent already finished - safe to ignore
+            let _ = cancel_tx.send(true);
+        }
+        tab.summary_state = SummaryState::Cancelled;
+        true
     }
 
     pub fn advance_summary_spinners(&mut self) {
@@ -141,4 +220,175 @@ impl Session {
             }
         }
     }
+
+    /// Whether any run tab is generating its summary.
+    pub fn has_generating_summary(&self) -> bool {
+        self.run_tabs
+            .iter()
+            .any(|tab| tab.summary_state == SummaryState::Generating)
+    }
+}
+
+/// Tool call duration as the chat panel shows it.
+fn tool_duration(duration_ms: u64) -> String {
+    let secs = duration_ms as f64 / 1000.0;
+    if secs < 10.0 {
+        format!("{:.1}s", secs)
+    } else {
+        format!("{}s", secs as u64)
+    }
+}
+
+/// A run tab's chat as markdown: agent messages, tool calls with their
+/// durations, and the phase summary.
+pub fn run_tab_markdown(tab: &RunTab, session_name: &str) -> String {
+    let mut markdown = format!("# {} - {}\n", session_name, tab.phase);
+    let mut in_tool_list = false;
+    for entry in &tab.entries {
+        match entry {
+            RunTabEntry::Text(ChatMessage {
+                agent_name,
+                message,
+            }) => {
+                markdown.push_str(&format!("\n## {}\n\n{}\n", agent_name, message.trim_end()));
+                in_tool_list = false;
+            }
+            RunTabEntry::Tool(tool) => {
+                let line = match tool {
+                    ToolTimelineEntry::Started {
+                        agent_name,
+                        display_name,
+                        input_preview,
+                        ..
+                    } => format!(
+                        "- ▶ [{}] `{}` {} (running)",
+                        agent_name, display_name, input_preview
+                    ),
+                    ToolTimelineEntry::Finished {
+                        agent_name,
+                        display_name,
+                        input_preview,
+                        duration_ms,
+                        is_error,
+                        result_summary,
+                        ..
+                    } => {
+                        let mut line = format!(
+                            "- {} [{}] `{}` {} ({})",
+                            if *is_error { "✗" } else { "✓" },
+                            agent_name,
+                            display_name,
+                            input_preview,
+                            tool_duration(*duration_ms)
+                        );
+                        if !result_summary.first_line.is_empty() {
+                            line.push_str(&format!(" - {}", result_summary.first_line));
+                        }
+                        line
+                    }
+                };
+                if !in_tool_list {
+                    markdown.push('\n');
+                    in_tool_list = true;
+                }
+                markdown.push_str(&line);
+                markdown.push('\n');
+            }
+        }
+    }
+    if !tab.summary_text.trim().is_empty() {
+        markdown.push_str(&format!(
+            "\n## Summary\n\n{}\n",
+            tab.summary_text.trim_end()
+        ));
+    }
+    markdown
 }
+
+/// Writes a run tab's markdown to `dir` and returns the file's path. The file
+/// name carries the phase and the time, so earlier exports are kept.
+pub fn export_run_tab(tab: &RunTab, session_name: &str, dir: &Path) -> Result<PathBuf> {
+    let phase: String = tab
+        .phase
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let path = dir.join(format!(
+        "run-tab-{}-{}.md",
+        phase,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::write(&path, run_tab_markdown(tab, session_name))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// A chat entry's text as the chat panel shows it.
+fn entry_text(entry: &RunTabEntry) -> String {
+    match entry {
+        RunTabEntry::Text(msg) => format!("[{}] {}", msg.agent_name, msg.message),
+        RunTabEntry::Tool(ToolTimelineEntry::Started {
+            agent_name,
+            display_name,
+            input_preview,
+            ..
+        }) => format!("[{}] ▶ {} {}", agent_name, display_name, input_preview),
+        RunTabEntry::Tool(ToolTimelineEntry::Finished {
+            agent_name,
+            display_name,
+            input_preview,
+            duration_ms,
+            is_error,
+            ..
+        }) => format!(
+            "[{}] {} {} {} ({})",
+            agent_name,
+            if *is_error { "✗" } else { "✓" },
+            display_name,
+            input_preview,
+            tool_duration(*duration_ms)
+        ),
+    }
+}
+
+impl Session {
+    /// The lines of `panel` for navigation: the output lines, or the chat
+    /// entries shown at the current verbosity.
+    pub fn nav_lines(&self, panel: FocusedPanel) -> Vec<String> {
+        match panel {
+            FocusedPanel::Chat => self
+                .run_tabs
+                .get(self.active_run_tab)
+                .map(|tab| {
+                    tab.entries
+                        .iter()
+                        .filter(|entry| self.chat_verbosity.shows(entry))
+                        .map(entry_text)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => self.output_lines.clone(),
+        }
+    }
+
+    /// Exports the focused run tab to the session directory (`/export-tab`).
+    pub fn export_active_run_tab(&self) -> Result<PathBuf> {
+        let tab = self
+            .run_tabs
+            .get(self.active_run_tab)
+            .context("this session has no run tabs yet")?;
+        let workflow_id = self
+            .workflow_view
+            .as_ref()
+            .and_then(|view| view.workflow_id())
+            .context("no workflow is loaded")?;
+        let dir = planning_paths::session_dir(&workflow_id.to_string())?;
+        export_run_tab(tab, &self.name, &dir)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/chat_tests.rs"]
+mod tests;
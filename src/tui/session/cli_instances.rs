@@ -4,6 +4,7 @@
 //! for the TUI session. It tracks active CLI processes with elapsed runtime and idle time.
 
 use super::Session;
+use crate::agents::resources::ProcessUsage;
 use std::time::Instant;
 
 /// Unique identifier for a CLI instance within a session.
@@ -23,6 +24,10 @@ pub struct CliInstance {
     pub started_at: Instant,
     /// When the process last produced output.
     pub last_activity_at: Instant,
+    /// Waiting for a provider slot; no process has been spawned yet.
+    pub queued: bool,
+    /// Latest CPU and memory sample of the process tree, if any was taken.
+    pub usage: Option<ProcessUsage>,
 }
 
 impl CliInstance {
@@ -39,6 +44,8 @@ impl CliInstance {
             pid,
             started_at,
             last_activity_at: started_at,
+            queued: false,
+            usage: None,
         }
     }
 
@@ -76,6 +83,18 @@ impl Session {
         self.cli_instances.push(instance);
     }
 
+    /// Record an invocation waiting for a provider slot.
+    pub fn cli_instance_queued(
+        &mut self,
+        id: CliInstanceId,
+        agent_name: String,
+        queued_at: Instant,
+    ) {
+        let mut instance = CliInstance::new(id, agent_name, None, queued_at);
+        instance.queued = true;
+        self.cli_instances.push(instance);
+    }
+
     /// Record activity for a CLI instance (updates last_activity_at).
     pub fn cli_instance_activity(&mut self, id: CliInstanceId, activity_at: Instant) {
         if let Some(instance) = self.cli_instances.iter_mut().find(|i| i.id == id) {
@@ -83,6 +102,26 @@ impl Session {
         }
     }
 
+    /// Record the latest resource sample of a CLI instance.
+    pub fn cli_instance_resources(&mut self, id: CliInstanceId, usage: ProcessUsage) {
+        if let Some(instance) = self.cli_instances.iter_mut().find(|i| i.id == id) {
+            instance.usage = Some(usage);
+        }
+    }
+
+    /// Combined usage of the running CLI instances that have been sampled.
+    pub fn agent_resource_usage(&self) -> Option<ProcessUsage> {
+        let samples: Vec<&ProcessUsage> = self
+            .cli_instances
+            .iter()
+            .filter_map(|i| i.usage.as_ref())
+            .collect();
+        (!samples.is_empty()).then(|| ProcessUsage {
+            cpu_percent: samples.iter().map(|u| u.cpu_percent).sum(),
+            rss_bytes: samples.iter().map(|u| u.rss_bytes).sum(),
+        })
+    }
+
     /// Record that a CLI instance has finished and remove it.
     pub fn cli_instance_finished(&mut self, id: CliInstanceId) {
         self.cli_instances.retain(|i| i.id != id);
@@ -0,0 +1,164 @@
+//! Autosaved input drafts.
+//!
+//! Decline and interrupt feedback and follow-up chat messages are written to
+//! the session's `drafts/` directory every few seconds, so a crash or a stray
+//! Esc does not lose them. Re-entering the same input restores the draft and
+//! submitting it deletes the file.
+
+use super::{ApprovalMode, FeedbackTarget, FocusedPanel, InputMode, Session};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// How often the active draft is checked and written when it changed.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DraftKind {
+    /// Feedback for declining the plan
+    Decline,
+    /// Feedback for interrupting a running workflow
+    Interrupt,
+    /// Follow-up message for the implementation agent
+    Chat,
+}
+
+impl DraftKind {
+    fn for_feedback(target: FeedbackTarget) -> Option<Self> {
+        match target {
+            FeedbackTarget::ApprovalDecline => Some(Self::Decline),
+            FeedbackTarget::WorkflowInterrupt => Some(Self::Interrupt),
+            FeedbackTarget::ApprovalConditions
+            | FeedbackTarget::Command
+            | FeedbackTarget::Objective
+            | FeedbackTarget::Notes
+            | FeedbackTarget::Appeal => None,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Decline => "decline.txt",
+            Self::Interrupt => "interrupt.txt",
+            Self::Chat => "chat.txt",
+        }
+    }
+}
+
+/// Runtime-only autosave bookkeeping: the text last written per draft, so
+/// unchanged drafts are not rewritten on every check.
+#[derive(Debug, Default)]
+pub struct DraftAutosave {
+    last_check: Option<Instant>,
+    saved: HashMap<DraftKind, String>,
+}
+
+fn draft_path(dir: &Path, kind: DraftKind) -> PathBuf {
+    dir.join(kind.file_name())
+}
+
+/// Writes a draft, or removes its file when the text is blank.
+pub fn save_draft(dir: &Path, kind: DraftKind, text: &str) -> std::io::Result<()> {
+    let path = draft_path(dir, kind);
+    if text.trim().is_empty() {
+        return match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(path, text)
+}
+
+pub fn load_draft(dir: &Path, kind: DraftKind) -> Option<String> {
+    std::fs::read_to_string(draft_path(dir, kind))
+        .ok()
+        .filter(|text| !text.trim().is_empty())
+}
+
+impl Session {
+    /// The input being typed that is autosaved, with its full text.
+    fn active_draft(&self) -> Option<(DraftKind, String)> {
+        if self.approval_mode == ApprovalMode::EnteringFeedback {
+            let kind = DraftKind::for_feedback(self.feedback_target)?;
+            return Some((kind, self.get_submit_text_feedback()));
+        }
+        let chatting = self.approval_mode == ApprovalMode::None
+            && self.input_mode == InputMode::Normal
+            && self.focused_panel == FocusedPanel::ChatInput;
+        chatting.then(|| (DraftKind::Chat, self.get_submit_text_tab()))
+    }
+
+    fn drafts_dir(&self) -> Option<PathBuf> {
+        let workflow_id = self.workflow_view.as_ref()?.workflow_id()?;
+        crate::planning_paths::session_drafts_dir(&workflow_id.to_string()).ok()
+    }
+
+    /// Writes the active draft if it changed, at most every few seconds.
+    pub fn autosave_draft(&mut self) {
+        let due = self
+            .draft_autosave
+            .last_check
+            .is_none_or(|at| at.elapsed() >= AUTOSAVE_INTERVAL);
+        if due {
+            self.draft_autosave.last_check = Some(Instant::now());
+            self.flush_draft();
+        }
+    }
+
+    /// Writes the active draft now if it changed.
+    pub fn flush_draft(&mut self) {
+        let Some((kind, text)) = self.active_draft() else {
+            return;
+        };
+        let saved = self
+            .draft_autosave
+            .saved
+            .get(&kind)
+            .map_or("", String::as_str);
+        if saved == text {
+            return;
+        }
+        let Some(dir) = self.drafts_dir() else {
+            return;
+        };
+        // Autosave is best effort - a failed write is retried on the next change
+        if save_draft(&dir, kind, &text).is_ok() {
+            self.draft_autosave.saved.insert(kind, text);
+        }
+    }
+
+    /// Fills an empty input with its saved draft on entering it.
+    pub fn restore_draft(&mut self) {
+        let Some((kind, text)) = self.active_draft() else {
+            return;
+        };
+        if text.is_empty() {
+            if let Some(draft) = self.drafts_dir().and_then(|dir| load_draft(&dir, kind)) {
+                if kind == DraftKind::Chat {
+                    self.tab_input_cursor = draft.len();
+                    self.tab_input = draft.clone();
+                } else {
+                    self.cursor_position = draft.len();
+                    self.user_feedback = draft.clone();
+                }
+                self.draft_autosave.saved.insert(kind, draft);
+                return;
+            }
+        }
+        self.draft_autosave.saved.insert(kind, text);
+    }
+
+    /// Deletes a draft once its text has been submitted.
+    pub fn discard_draft(&mut self, kind: DraftKind) {
+        self.draft_autosave.saved.remove(&kind);
+        if let Some(dir) = self.drafts_dir() {
+            // A leftover draft only means the text is offered again - safe to ignore
+            let _ = save_draft(&dir, kind, "");
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/drafts_tests.rs"]
+mod tests;
@@ -0,0 +1,235 @@
+//! Structured reading of a session error for the error overlay.
+//!
+//! Errors reach the session as plain strings (`error_state`), so the report is
+//! derived when the overlay needs it: the message is classified by the subsystem
+//! it mentions, paired with the last warning and error lines from the session
+//! output and the failed agent's stderr, and given the actions that usually
+//! resolve that kind of failure.
+
+use super::{AgentStderr, Session};
+
+/// Output lines shown under "Recent log lines".
+const RECENT_LINE_LIMIT: usize = 5;
+
+/// Agent CLIs named in the component line when an error mentions them.
+const AGENT_NAMES: &[&str] = &["claude", "codex", "gemini"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSeverity {
+    /// The workflow stopped by choice (abort, max iterations).
+    Warning,
+    /// The phase failed and can usually be retried.
+    Error,
+    /// Retrying will fail the same way until something outside the session is fixed.
+    Fatal,
+}
+
+impl ErrorSeverity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+            Self::Fatal => "Fatal",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    Agent,
+    Config,
+    Git,
+    Daemon,
+    Io,
+    Workflow,
+}
+
+impl ErrorCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Agent => "agent failure",
+            Self::Config => "config error",
+            Self::Git => "git error",
+            Self::Daemon => "daemon error",
+            Self::Io => "I/O error",
+            Self::Workflow => "workflow error",
+        }
+    }
+
+    fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        let mentions = |words: &[&str]| words.iter().any(|w| lower.contains(w));
+        // Most specific first: "failed to read workflow.yaml" is a config error, not I/O
+        if mentions(&["workflow.yaml", "config", "yaml", "workflow file"]) {
+            Self::Config
+        } else if mentions(&["git", "worktree", "branch", "merge conflict"]) {
+            Self::Git
+        } else if mentions(&["daemon", "rpc"]) {
+            Self::Daemon
+        } else if mentions(&["agent", "reviewer", "plan generation", "cli"])
+            || AGENT_NAMES.iter().any(|name| lower.contains(name))
+        {
+            Self::Agent
+        } else if mentions(&[
+            "no such file",
+            "permission denied",
+            "failed to read",
+            "failed to write",
+            "failed to create",
+            "i/o",
+            "disk",
+        ]) {
+            Self::Io
+        } else {
+            Self::Workflow
+        }
+    }
+}
+
+/// Something the user can do from the error overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestedAction {
+    /// Resume the workflow, re-running the phase that failed.
+    RetryPhase,
+    /// Follow this session's log in the plan modal.
+    OpenLogs,
+    /// Run the `planning doctor` checks and print the report to the output panel.
+    RunDoctor,
+}
+
+impl SuggestedAction {
+    pub fn key(self) -> char {
+        match self {
+            Self::RetryPhase => 'r',
+            Self::OpenLogs => 'l',
+            Self::RunDoctor => 'd',
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::RetryPhase => "Retry the phase",
+            Self::OpenLogs => "Open the session log",
+            Self::RunDoctor => "Run doctor",
+        }
+    }
+
+    pub fn from_key(key: char) -> Option<Self> {
+        [Self::RetryPhase, Self::OpenLogs, Self::RunDoctor]
+            .into_iter()
+            .find(|action| action.key() == key)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    pub severity: ErrorSeverity,
+    pub category: ErrorCategory,
+    /// The part of the system that failed, e.g. `codex` or `git`.
+    pub component: String,
+    pub message: String,
+    /// Last warning and error lines from the session output, oldest first.
+    pub recent_lines: Vec<String>,
+    /// Stderr of the failed agent invocation, for agent and workflow failures.
+    pub agent_stderr: Option<AgentStderr>,
+    /// Most useful first; Enter runs the first one.
+    pub actions: Vec<SuggestedAction>,
+}
+
+impl ErrorReport {
+    /// Classifies `message`. `can_retry` is false when the workflow cannot be
+    /// resumed (it is still running, or never got a workflow ID).
+    pub fn classify(message: &str, output_lines: &[String], can_retry: bool) -> Self {
+        let category = ErrorCategory::from_message(message);
+        let lower = message.to_lowercase();
+
+        let severity = if lower.contains("panicked") || category == ErrorCategory::Config {
+            ErrorSeverity::Fatal
+        } else if lower.contains("aborted") {
+            ErrorSeverity::Warning
+        } else {
+            ErrorSeverity::Error
+        };
+
+        let component = match category {
+            ErrorCategory::Agent => AGENT_NAMES
+                .iter()
+                .find(|name| lower.contains(*name))
+                .copied()
+                .unwrap_or("agent"),
+            ErrorCategory::Config => "workflow config",
+            ErrorCategory::Git => "git",
+            ErrorCategory::Daemon => "session daemon",
+            ErrorCategory::Io => "filesystem",
+            ErrorCategory::Workflow => "workflow",
+        }
+        .to_string();
+
+        let mut recent_lines: Vec<String> = output_lines
+            .iter()
+            .rev()
+            .filter(|line| {
+                let line = line.to_lowercase();
+                line.contains("error") || line.contains("fail") || line.contains("warn")
+            })
+            .filter(|line| line.as_str() != message)
+            .take(RECENT_LINE_LIMIT)
+            .cloned()
+            .collect();
+        recent_lines.reverse();
+
+        let mut actions = match category {
+            ErrorCategory::Agent | ErrorCategory::Workflow => vec![
+                SuggestedAction::RetryPhase,
+                SuggestedAction::OpenLogs,
+                SuggestedAction::RunDoctor,
+            ],
+            ErrorCategory::Daemon => vec![SuggestedAction::RunDoctor, SuggestedAction::RetryPhase],
+            ErrorCategory::Config | ErrorCategory::Git | ErrorCategory::Io => {
+                vec![SuggestedAction::RunDoctor, SuggestedAction::OpenLogs]
+            }
+        };
+        if !can_retry {
+            actions.retain(|action| *action != SuggestedAction::RetryPhase);
+        }
+
+        Self {
+            severity,
+            category,
+            component,
+            message: message.to_string(),
+            recent_lines,
+            agent_stderr: None,
+            actions,
+        }
+    }
+}
+
+impl Session {
+    /// The structured report for the current error, if any.
+    pub fn error_report(&self) -> Option<ErrorReport> {
+        let message = self.error_state.as_ref()?;
+        let mut report =
+            ErrorReport::classify(message, &self.output_lines, self.can_retry_workflow());
+        if matches!(
+            report.category,
+            ErrorCategory::Agent | ErrorCategory::Workflow
+        ) {
+            report.agent_stderr = self.agent_stderr.clone();
+        }
+        Some(report)
+    }
+
+    /// True when the stopped workflow can be resumed from its event log.
+    pub fn can_retry_workflow(&self) -> bool {
+        self.workflow_handle.is_none()
+            && self
+                .workflow_view
+                .as_ref()
+                .is_some_and(|view| view.workflow_id().is_some())
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/error_report_tests.rs"]
+mod tests;
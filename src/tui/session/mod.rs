@@ -2,6 +2,8 @@ mod approval;
 mod chat;
 mod cli_instances;
 pub mod context;
+mod drafts;
+mod error_report;
 mod input;
 pub mod modals;
 pub mod model;
@@ -10,21 +12,27 @@ mod snapshot;
 mod tools;
 
 pub use cli_instances::{CliInstance, CliInstanceId};
+pub use drafts::{DraftAutosave, DraftKind};
+pub use error_report::{ErrorReport, ErrorSeverity, SuggestedAction};
 
+use crate::agents::permissions::{ToolPermissionRequest, ToolRisk};
+use crate::app::workspace_trust::WorkspaceTrustRequest;
 use crate::app::AccountUsage;
 use crate::app::WorkflowResult;
 use crate::domain::types::{AgentId, ImplementationPhase, Phase, UiMode};
-use crate::domain::view::WorkflowView;
+use crate::domain::view::{WorkflowEventEnvelope, WorkflowView};
+use crate::phases::feedback_items::{AnnotationStance, ReviewAnnotation};
 use crate::phases::implementing_conversation_key;
 use crate::tui::cursor_utils::{slice_from_cursor, slice_up_to_cursor};
-use crate::tui::event::{TokenUsage, WorkflowCommand};
+use crate::tui::event::{TokenUsage, WorkflowCommand, WorkflowUpdate};
 use crate::tui::mention::MentionState;
-use crate::tui::scroll::ScrollState;
+use crate::tui::scroll::{NavState, ScrollState};
 use crate::tui::slash::SlashState;
 use anyhow::Result;
 pub use context::SessionContext;
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use cqrs_es::DomainEvent;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::{mpsc, watch};
 use tokio::task::JoinHandle;
 
@@ -46,10 +54,12 @@ fn sanitize_for_display(s: &str) -> String {
 }
 
 pub use model::{
-    ApprovalContext, ApprovalMode, FeedbackTarget, FocusedPanel, ImplementationSuccessModal,
-    InputMode, PasteBlock, ReviewKind, ReviewModalEntry, ReviewRound, ReviewerEntry,
-    ReviewerStatus, RunTab, RunTabEntry, SessionStatus, SummaryState, TodoItem, TodoStatus,
-    ToolKind, ToolResultSummary, ToolTimelineEntry,
+    AgentStderr, ApprovalContext, ApprovalMode, ChatVerbosity, CompletionSummary, ConflictModal,
+    DirConflict, FeedbackTarget, FeedbackTriageModal, FileViewer, FocusedPanel,
+    ImplementationSuccessModal, InputMode, ModalTail, PasteBlock, PlanReference, ReviewKind,
+    ReviewModalEntry, ReviewRound, ReviewerEntry, ReviewerStatus, RunTab, RunTabEntry,
+    SessionStatus, SummaryState, TailTarget, TodoItem, TodoStatus, ToolKind, ToolResultSummary,
+    ToolTimelineEntry, WorktreeGitStep,
 };
 
 /// Represents an active tool call with optional ID for correlation
@@ -98,11 +108,15 @@ pub struct Session {
     pub streaming_lines: Vec<String>,
     pub streaming_scroll: ScrollState,
     pub focused_panel: FocusedPanel,
+    /// Vim-style navigation of the output or chat panel, while it is on.
+    pub nav: Option<NavState>,
 
     /// Event-sourced workflow view for UI state.
     pub workflow_view: Option<WorkflowView>,
     pub start_time: Instant,
     pub total_cost: f64,
+    /// Whether `total_cost` includes costs estimated from token usage
+    pub cost_estimated: bool,
     pub running: bool,
     /// Active tools grouped by agent name
     pub active_tools_by_agent: HashMap<String, Vec<ActiveTool>>,
@@ -131,6 +145,8 @@ pub struct Session {
     pub tab_input_pastes: Vec<PasteBlock>,
 
     pub feedback_pastes: Vec<PasteBlock>,
+    /// Autosave state for feedback and chat drafts (runtime-only)
+    pub draft_autosave: DraftAutosave,
 
     pub error_state: Option<String>,
     pub error_scroll: usize,
@@ -156,8 +172,14 @@ pub struct Session {
     pub approval_tx: Option<mpsc::Sender<UserApprovalResponse>>,
     /// Channel to send commands (like interrupt) to the running workflow.
     pub workflow_control_tx: Option<mpsc::Sender<WorkflowCommand>>,
+    /// Channel to send `/rename` and `/reviewers` changes to the running workflow.
+    pub workflow_update_tx: Option<mpsc::UnboundedSender<WorkflowUpdate>>,
     /// Tracks the target of the current feedback entry mode.
     pub feedback_target: FeedbackTarget,
+    /// Conditions entered for the pending plan approval, sent with the accept choice.
+    pub approval_conditions: Option<String>,
+    /// Objective from `/edit-objective`, applied when the interrupted workflow restarts.
+    pub pending_objective: Option<String>,
     /// Tracks the current run ID for scoping summary events.
     pub current_run_id: u64,
 
@@ -176,6 +198,10 @@ pub struct Session {
 
     pub todos: HashMap<String, Vec<TodoItem>>,
     pub todo_scroll: ScrollState,
+    /// Hides the Todos panel even when there are todos (toggled with `T`).
+    pub todos_collapsed: bool,
+    /// Which entries the chat panel shows.
+    pub chat_verbosity: ChatVerbosity,
 
     /// @-mention state for tab input field
     pub tab_mention_state: MentionState,
@@ -190,6 +216,12 @@ pub struct Session {
     pub plan_modal_scroll: usize,
     /// Cached plan modal content (runtime-only, not serialized)
     pub plan_modal_content: String,
+    /// Set when the plan modal follows a file as it is written (runtime-only)
+    pub plan_modal_tail: Option<ModalTail>,
+    /// File reference selected in the plan modal (runtime-only)
+    pub plan_modal_reference: Option<PlanReference>,
+    /// File viewer opened from a plan reference, shown over the plan modal (runtime-only)
+    pub file_viewer: Option<FileViewer>,
 
     /// Whether the review modal is currently open
     pub review_modal_open: bool,
@@ -202,6 +234,12 @@ pub struct Session {
     /// Loaded review entries: (display_name, file_path, content, sort_key)
     /// Sorted by (iteration DESC, agent_name ASC) for deterministic ordering.
     pub review_modal_entries: Vec<ReviewModalEntry>,
+    /// Selected line of the current review while selecting lines to annotate
+    pub review_modal_cursor: Option<usize>,
+    /// Note being typed for the selected review line
+    pub review_annotation_input: Option<(AnnotationStance, String)>,
+    /// User notes on review lines, loaded from the session directory on open
+    pub review_annotations: Vec<ReviewAnnotation>,
 
     /// Per-session context tracking working directory, paths, and configuration.
     /// None for sessions created before this feature or not yet initialized.
@@ -210,9 +248,40 @@ pub struct Session {
     /// Runtime-only modal for implementation success display.
     /// Not serialized - always None on snapshot restore.
     pub implementation_success_modal: Option<ImplementationSuccessModal>,
+    /// Run metrics and completion action outcomes shown after acceptance until
+    /// dismissed (runtime-only).
+    pub completion_summary: Option<CompletionSummary>,
+    /// Stderr of the last failed agent invocation, shown in the error overlay
+    /// until the error is dismissed (runtime-only).
+    pub agent_stderr: Option<AgentStderr>,
+    /// Runtime-only overlay for resolving worktree sync conflicts before a merge.
+    pub conflict_modal: Option<ConflictModal>,
+    /// Runtime-only overlay for triaging review feedback before a revision.
+    pub feedback_triage: Option<FeedbackTriageModal>,
+    /// Runtime-only queue of high-risk tool calls awaiting allow/deny; the front is shown.
+    pub tool_permission_queue: VecDeque<ToolPermissionRequest>,
+    /// Tool risks the user allowed for the rest of this session.
+    pub session_allowed_tool_risks: HashSet<ToolRisk>,
+    /// Runtime-only trust prompt shown before implementation first runs in a repository.
+    pub workspace_trust_request: Option<WorkspaceTrustRequest>,
+    /// Runtime-only warning about other live sessions in the same working directory.
+    pub dir_conflict: Option<DirConflict>,
     /// Runtime-only state for post-implementation interaction.
     /// Not serialized - always reset on snapshot restore.
     pub implementation_interaction: ImplementationInteractionState,
+    /// When the session started waiting on the current approval prompt (runtime-only).
+    pub awaiting_approval_since: Option<Instant>,
+    /// Set when the session was stopped for sitting idle; its tab is freed once stopped.
+    pub idle_suspended: bool,
+    /// PID of the process holding the session while this tab follows it read-only
+    /// (runtime-only).
+    pub following_pid: Option<u32>,
+    /// Plan file modification time last reported as an external edit (runtime-only).
+    pub plan_change_noticed: Option<SystemTime>,
+    /// Time limit in minutes the current phase ran past; cleared when the next phase starts.
+    pub phase_overrun_minutes: Option<u64>,
+    /// Latest `cost_alerts` warning, shown in the footer until a newer one replaces it.
+    pub cost_alert: Option<String>,
 }
 
 /// Runtime-only state for post-implementation interaction.
@@ -236,10 +305,12 @@ impl Session {
             streaming_lines: Vec::new(),
             streaming_scroll: ScrollState::new(),
             focused_panel: FocusedPanel::default(),
+            nav: None,
 
             workflow_view: None,
             start_time: Instant::now(),
             total_cost: 0.0,
+            cost_estimated: false,
             running: true,
             active_tools_by_agent: HashMap::new(),
             completed_tools_by_agent: HashMap::new(),
@@ -262,6 +333,7 @@ impl Session {
 
             tab_input_pastes: Vec::new(),
             feedback_pastes: Vec::new(),
+            draft_autosave: DraftAutosave::default(),
 
             error_state: None,
             error_scroll: 0,
@@ -286,7 +358,10 @@ impl Session {
             workflow_handle: None,
             approval_tx: None,
             workflow_control_tx: None,
+            workflow_update_tx: None,
             feedback_target: FeedbackTarget::default(),
+            approval_conditions: None,
+            pending_objective: None,
             current_run_id: 0,
 
             account_usage: AccountUsage::default(),
@@ -302,6 +377,8 @@ impl Session {
 
             todos: HashMap::new(),
             todo_scroll: ScrollState::new(),
+            todos_collapsed: false,
+            chat_verbosity: ChatVerbosity::default(),
 
             tab_mention_state: MentionState::new(),
             feedback_mention_state: MentionState::new(),
@@ -310,20 +387,40 @@ impl Session {
             plan_modal_open: false,
             plan_modal_scroll: 0,
             plan_modal_content: String::new(),
+            plan_modal_tail: None,
+            plan_modal_reference: None,
+            file_viewer: None,
 
             review_modal_open: false,
             review_modal_scroll: 0,
             review_modal_tab: 0,
             review_modal_tab_scroll: 0,
             review_modal_entries: Vec::new(),
+            review_modal_cursor: None,
+            review_annotation_input: None,
+            review_annotations: Vec::new(),
 
             context: None,
 
             implementation_success_modal: None,
+            completion_summary: None,
+            agent_stderr: None,
+            conflict_modal: None,
+            feedback_triage: None,
+            tool_permission_queue: VecDeque::new(),
+            workspace_trust_request: None,
+            dir_conflict: None,
+            session_allowed_tool_risks: HashSet::new(),
             implementation_interaction: ImplementationInteractionState {
                 running: false,
                 cancel_tx: None,
             },
+            awaiting_approval_since: None,
+            idle_suspended: false,
+            following_pid: None,
+            plan_change_noticed: None,
+            phase_overrun_minutes: None,
+            cost_alert: None,
         }
     }
 
@@ -340,12 +437,28 @@ impl Session {
         self.error_scroll = 0;
         self.workflow_handle = None;
         self.workflow_control_tx = None;
+        self.workflow_update_tx = None;
+        self.pending_objective = None;
         self.status = SessionStatus::Error;
     }
 
     pub fn clear_error(&mut self) {
         self.error_state = None;
         self.error_scroll = 0;
+        self.agent_stderr = None;
+    }
+
+    /// Applies an event recorded by the process this tab follows.
+    pub fn apply_followed_event(&mut self, event: &WorkflowEventEnvelope) {
+        let Some(view) = self.workflow_view.as_mut() else {
+            return;
+        };
+        // Replays of the daemon's journal repeat events the view already has
+        if event.sequence <= view.last_event_sequence() {
+            return;
+        }
+        view.apply_event(&event.aggregate_id, &event.event, event.sequence);
+        self.add_output(format!("[follow] {}", event.event.event_type()));
     }
 
     /// Returns the feature name from workflow view or session name.
@@ -395,6 +508,7 @@ impl Session {
             *self.phase_times.entry(prev_phase).or_default() += duration;
         }
         self.current_phase_start = Some((phase, Instant::now()));
+        self.phase_overrun_minutes = None;
     }
 
     // Note: Approval methods (start_approval, start_review_decision, etc.) are in approval.rs
@@ -487,6 +601,9 @@ impl Session {
             }
             FocusedPanel::Summary => FocusedPanel::Output,
         };
+        if self.focused_panel == FocusedPanel::ChatInput {
+            self.restore_draft();
+        }
     }
 
     /// Check if the current focused panel is Todos but todos are not visible.
@@ -562,6 +679,22 @@ impl Session {
         }
     }
 
+    /// What the tab is waiting on the user for, if anything.
+    pub fn attention(&self) -> Option<&'static str> {
+        if self.error_state.is_some() || self.status == SessionStatus::Error {
+            Some("error")
+        } else if self.current_tool_permission().is_some() || self.workspace_trust_request.is_some()
+        {
+            Some("permission")
+        } else if self.approval_mode != ApprovalMode::None
+            || self.status == SessionStatus::AwaitingApproval
+        {
+            Some("approval")
+        } else {
+            None
+        }
+    }
+
     pub fn iteration(&self) -> (u32, u32) {
         match &self.workflow_view {
             Some(view) => {
@@ -606,6 +739,29 @@ impl Session {
         self.todo_scroll.scroll_to_bottom(max_scroll);
     }
 
+    /// Whether the Todos panel has content to show (todos exist and it is not collapsed).
+    pub fn shows_todos(&self) -> bool {
+        !self.todos.is_empty() && !self.todos_collapsed
+    }
+
+    pub fn toggle_todos_collapsed(&mut self) {
+        self.todos_collapsed = !self.todos_collapsed;
+        let state = if self.todos_collapsed {
+            "collapsed"
+        } else {
+            "expanded"
+        };
+        self.add_output(format!("[ui] Todos panel {}", state));
+    }
+
+    pub fn cycle_chat_verbosity(&mut self) {
+        self.chat_verbosity = self.chat_verbosity.next();
+        self.add_output(format!(
+            "[ui] Chat verbosity: {}",
+            self.chat_verbosity.label()
+        ));
+    }
+
     pub fn get_todos_display(&self) -> Vec<String> {
         let mut lines = Vec::new();
 
@@ -658,6 +814,14 @@ impl Session {
         self.total_cost
     }
 
+    /// Adds the cost from an agent's `Cost: $x` (or estimated `Cost: ~$x`) output line.
+    pub fn record_cost_output(&mut self, line: &str) {
+        if let Some((cost, estimated)) = parse_cost_output(line) {
+            self.total_cost += cost;
+            self.cost_estimated |= estimated;
+        }
+    }
+
     // Review history methods are implemented in review_history.rs
 
     // Note: `to_ui_state` and `from_ui_state` are implemented in snapshot.rs
@@ -734,6 +898,19 @@ impl Default for Session {
     }
 }
 
+/// The cost in an agent's `Cost: $x` (or estimated `Cost: ~$x`) output line,
+/// and whether it was estimated.
+pub fn parse_cost_output(line: &str) -> Option<(f64, bool)> {
+    let (_, rest) = line.split_once("Cost: ")?;
+    let (estimated, rest) = match rest.strip_prefix('~') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let amount = rest.strip_prefix('$')?;
+    let amount = amount.split_whitespace().next().unwrap_or_default();
+    amount.parse::<f64>().ok().map(|cost| (cost, estimated))
+}
+
 #[cfg(test)]
 #[path = "tests/mod.rs"]
 mod tests;
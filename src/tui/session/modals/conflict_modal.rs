@@ -0,0 +1,202 @@
+//! Worktree conflict resolution modal methods for Session.
+//!
+//! The modal lists files left conflicted by a pre-merge sync with the source
+//! branch. Its git operations run off the input path (see `worktree_input`);
+//! these methods start them and apply the [`WorktreeGitStep`] results.
+
+use super::super::model::{ConflictFileEntry, ConflictModal, WorktreeGitStep};
+use super::super::Session;
+use crate::domain::types::WorktreeState;
+use crate::git_worktree::{SourceSyncMethod, SourceSyncOutcome};
+use std::path::PathBuf;
+
+fn conflict_entries(files: Vec<String>) -> Vec<ConflictFileEntry> {
+    files
+        .into_iter()
+        .map(|path| ConflictFileEntry {
+            path,
+            resolution: None,
+        })
+        .collect()
+}
+
+impl Session {
+    pub fn open_conflict_modal(
+        &mut self,
+        worktree: WorktreeState,
+        method: SourceSyncMethod,
+        files: Vec<String>,
+    ) {
+        self.conflict_modal = Some(ConflictModal {
+            worktree,
+            method,
+            files: conflict_entries(files),
+            selected: 0,
+            status: None,
+            busy: false,
+        });
+    }
+
+    pub fn conflict_modal_select_next(&mut self) {
+        if let Some(modal) = self.conflict_modal.as_mut() {
+            if modal.selected + 1 < modal.files.len() {
+                modal.selected += 1;
+            }
+        }
+    }
+
+    pub fn conflict_modal_select_prev(&mut self) {
+        if let Some(modal) = self.conflict_modal.as_mut() {
+            modal.selected = modal.selected.saturating_sub(1);
+        }
+    }
+
+    /// Marks the modal busy with `status` while a git operation runs.
+    ///
+    /// Returns the worktree path and sync method, or None if no modal is open
+    /// or an operation is already running.
+    pub fn begin_conflict_git(&mut self, status: &str) -> Option<(PathBuf, SourceSyncMethod)> {
+        let modal = self.conflict_modal.as_mut()?;
+        if modal.busy {
+            return None;
+        }
+        modal.busy = true;
+        modal.status = Some(status.to_string());
+        Some((modal.worktree.worktree_path().to_path_buf(), modal.method))
+    }
+
+    /// Path of the selected file.
+    pub fn selected_conflict_file(&self) -> Option<String> {
+        let modal = self.conflict_modal.as_ref()?;
+        modal.files.get(modal.selected).map(|f| f.path.clone())
+    }
+
+    pub fn all_conflicts_resolved(&self) -> bool {
+        self.conflict_modal
+            .as_ref()
+            .is_some_and(|m| m.files.iter().all(|f| f.resolution.is_some()))
+    }
+
+    /// Applies the result of a worktree git operation.
+    ///
+    /// Returns the worktree when the sync finished and the merge can proceed.
+    /// A rebase that stops on a later commit repopulates the modal instead.
+    pub fn apply_worktree_git_step(&mut self, step: WorktreeGitStep) -> Option<WorktreeState> {
+        match step {
+            WorktreeGitStep::PreMergeSync {
+                worktree,
+                method,
+                source,
+                outcome,
+            } => self.apply_pre_merge_sync(worktree, method, &source, outcome),
+            WorktreeGitStep::Aborted(result) => {
+                let method = self.conflict_modal.take()?.method;
+                match result {
+                    Ok(()) => self.add_output(format!(
+                        "[merge-worktree] Aborted {} - worktree branch unchanged",
+                        method.label()
+                    )),
+                    Err(e) => self.add_output(format!("[merge-worktree] {}", e)),
+                }
+                None
+            }
+            step => self.apply_conflict_step(step),
+        }
+    }
+
+    fn apply_conflict_step(&mut self, step: WorktreeGitStep) -> Option<WorktreeState> {
+        let modal = self.conflict_modal.as_mut()?;
+        modal.busy = false;
+        modal.status = None;
+        match step {
+            WorktreeGitStep::Resolved {
+                path,
+                choice,
+                result,
+            } => match result {
+                Ok(()) => {
+                    if let Some(entry) = modal.files.iter_mut().find(|f| f.path == path) {
+                        entry.resolution = Some(choice);
+                    }
+                    if modal
+                        .files
+                        .get(modal.selected)
+                        .is_some_and(|f| f.path == path)
+                        && modal.selected + 1 < modal.files.len()
+                    {
+                        modal.selected += 1;
+                    }
+                }
+                Err(e) => modal.status = Some(e),
+            },
+            // Files that git no longer reports as conflicted are treated as edited
+            WorktreeGitStep::Refreshed(result) => match result {
+                Ok(remaining) => {
+                    for entry in modal.files.iter_mut() {
+                        if entry.resolution.is_none() && !remaining.contains(&entry.path) {
+                            entry.resolution = Some(crate::git_worktree::ConflictChoice::Edited);
+                        }
+                    }
+                    modal.status = Some(format!("{} file(s) still conflicted", remaining.len()));
+                }
+                Err(e) => modal.status = Some(e),
+            },
+            WorktreeGitStep::Continued(result) => match result {
+                Ok(SourceSyncOutcome::Conflicts(files)) => {
+                    modal.files = conflict_entries(files);
+                    modal.selected = 0;
+                    modal.status = Some(format!(
+                        "{} stopped on another commit - resolve the new conflicts",
+                        modal.method.label()
+                    ));
+                }
+                Ok(SourceSyncOutcome::Synced | SourceSyncOutcome::UpToDate) => {
+                    let worktree = self.conflict_modal.take().map(|m| m.worktree);
+                    self.add_output("[merge-worktree] Conflicts resolved".to_string());
+                    return worktree;
+                }
+                Err(e) => modal.status = Some(e),
+            },
+            WorktreeGitStep::PreMergeSync { .. } | WorktreeGitStep::Aborted(_) => {}
+        }
+        None
+    }
+
+    fn apply_pre_merge_sync(
+        &mut self,
+        worktree: WorktreeState,
+        method: SourceSyncMethod,
+        source: &str,
+        outcome: Result<SourceSyncOutcome, String>,
+    ) -> Option<WorktreeState> {
+        match outcome {
+            Ok(SourceSyncOutcome::UpToDate) => Some(worktree),
+            Ok(SourceSyncOutcome::Synced) => {
+                self.add_output(format!(
+                    "[merge-worktree] {} moved - updated worktree branch ({})",
+                    source,
+                    method.label()
+                ));
+                Some(worktree)
+            }
+            Ok(SourceSyncOutcome::Conflicts(files)) => {
+                self.add_output(format!(
+                    "[merge-worktree] {} moved - {} conflicted file(s) during {}",
+                    source,
+                    files.len(),
+                    method.label()
+                ));
+                self.open_conflict_modal(worktree, method, files);
+                None
+            }
+            Err(e) => {
+                self.add_output(format!(
+                    "[merge-worktree] Pre-merge {} failed: {}",
+                    method.label(),
+                    e
+                ));
+                None
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //! Modal components for session management.
 
+pub mod conflict_modal;
+pub mod permission_modal;
 pub mod plan_modal;
+pub mod plan_references;
 pub mod review_history;
 pub mod review_modal;
+pub mod triage_modal;
@@ -0,0 +1,80 @@
+//! Tool permission prompt methods for Session.
+//!
+//! Agents with `permission_prompts` enabled pause on high-risk tool calls and
+//! queue a request here. Risks allowed "for this session" are answered without
+//! showing the prompt again. The workspace trust prompt, shown before
+//! implementation first runs in a repository, is answered here too.
+
+use super::super::Session;
+use crate::agents::permissions::{ToolPermissionDecision, ToolPermissionRequest};
+use crate::app::workspace_trust::{WorkspaceTrust, RESTRICTED_ALLOWED_TOOLS};
+
+impl Session {
+    /// Queues a permission request, auto-allowing risks already allowed for the session.
+    pub fn queue_tool_permission(&mut self, request: ToolPermissionRequest) {
+        if self.session_allowed_tool_risks.contains(&request.risk) {
+            request.respond(ToolPermissionDecision::Allow);
+            return;
+        }
+        self.tool_permission_queue.push_back(request);
+    }
+
+    /// The request currently shown in the prompt, if any.
+    pub fn current_tool_permission(&self) -> Option<&ToolPermissionRequest> {
+        self.tool_permission_queue.front()
+    }
+
+    /// Answers the front request and reports the decision in the output panel.
+    ///
+    /// Allowing for the session also answers queued requests of the same risk.
+    pub fn answer_tool_permission(&mut self, decision: ToolPermissionDecision) {
+        let Some(request) = self.tool_permission_queue.pop_front() else {
+            return;
+        };
+        request.respond(decision);
+        let verdict = match decision {
+            ToolPermissionDecision::Allow => "Allowed",
+            ToolPermissionDecision::AllowForSession => "Allowed for this session",
+            ToolPermissionDecision::Deny => "Denied",
+        };
+        self.add_output(format!(
+            "[permissions] {} {} by {}: {}",
+            verdict,
+            request.risk.label(),
+            request.agent_name,
+            request.display_name
+        ));
+
+        if decision == ToolPermissionDecision::AllowForSession {
+            self.session_allowed_tool_risks.insert(request.risk);
+            self.tool_permission_queue.retain(|queued| {
+                let allowed = queued.risk == request.risk;
+                if allowed {
+                    queued.respond(ToolPermissionDecision::Allow);
+                }
+                !allowed
+            });
+        }
+    }
+
+    /// Answers the workspace trust prompt and reports the decision in the output panel.
+    pub fn answer_workspace_trust(&mut self, trust: WorkspaceTrust) {
+        let Some(request) = self.workspace_trust_request.take() else {
+            return;
+        };
+        request.respond(trust);
+        let workspace = request.workspace.display();
+        self.add_output(match trust {
+            WorkspaceTrust::Trusted => format!("[permissions] Trusted {}", workspace),
+            WorkspaceTrust::Restricted => format!(
+                "[permissions] Restricted {}: agents may only use {}",
+                workspace,
+                RESTRICTED_ALLOWED_TOOLS.join(", ")
+            ),
+        });
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/permission_modal_tests.rs"]
+mod tests;
@@ -1,33 +1,21 @@
 //! Plan modal methods for Session.
 
-use super::super::{ImplementationSuccessModal, Session};
+use super::super::{ImplementationSuccessModal, ModalTail, Session, TailTarget};
+use crate::planning_paths;
+use std::path::{Path, PathBuf};
 
 impl Session {
     /// Toggle the plan modal open/closed.
     /// When opening, reads the plan file from disk and populates plan_modal_content.
     /// Returns true if the modal was opened, false if it was closed or no plan file exists.
-    pub fn toggle_plan_modal(&mut self, working_dir: &std::path::Path) -> bool {
+    pub fn toggle_plan_modal(&mut self, working_dir: &Path) -> bool {
         if self.plan_modal_open {
             // Close the modal
-            self.plan_modal_open = false;
-            self.plan_modal_content.clear();
+            self.close_plan_modal();
             false
         } else {
             // Try to open the modal
-            let plan_path_opt = self
-                .workflow_view
-                .as_ref()
-                .and_then(|v| v.plan_path())
-                .map(|p| {
-                    let path = p.as_path();
-                    if path.is_absolute() {
-                        path.to_path_buf()
-                    } else {
-                        working_dir.join(path)
-                    }
-                });
-
-            if let Some(plan_path) = plan_path_opt {
+            if let Some(plan_path) = self.resolved_plan_path(working_dir) {
                 match std::fs::read_to_string(&plan_path) {
                     Ok(content) => {
                         self.plan_modal_content = content;
@@ -53,10 +41,103 @@ impl Session {
         }
     }
 
+    /// The plan file of this session, relative paths resolved against `working_dir`.
+    fn resolved_plan_path(&self, working_dir: &Path) -> Option<PathBuf> {
+        self.workflow_view
+            .as_ref()
+            .and_then(|v| v.plan_path())
+            .map(|p| {
+                let path = p.as_path();
+                if path.is_absolute() {
+                    path.to_path_buf()
+                } else {
+                    working_dir.join(path)
+                }
+            })
+    }
+
+    /// This session's main log file, once the workflow has an ID.
+    fn session_log_path(&self) -> Option<PathBuf> {
+        let workflow_id = self.workflow_view.as_ref()?.workflow_id()?;
+        planning_paths::session_logs_dir(&workflow_id.to_string())
+            .ok()
+            .map(|dir| dir.join("session.log"))
+    }
+
+    /// Opens the plan modal following the plan, the latest feedback file or the session log.
+    /// Returns false if the session has no plan (or, for the log, no workflow) to locate
+    /// the files by.
+    pub fn open_tail_modal(&mut self, target: TailTarget, working_dir: &Path) -> bool {
+        let located = match target {
+            TailTarget::Log => self.session_log_path().is_some(),
+            TailTarget::Plan | TailTarget::Feedback => {
+                self.resolved_plan_path(working_dir).is_some()
+            }
+        };
+        if !located {
+            return false;
+        }
+        self.plan_modal_open = true;
+        self.plan_modal_scroll = 0;
+        self.plan_modal_tail = Some(ModalTail {
+            target,
+            path: None,
+            modified: None,
+        });
+        self.refresh_tail_modal(working_dir);
+        true
+    }
+
+    /// Re-reads the followed file if it changed, or if a newer feedback file appeared.
+    /// Returns true if the modal content was replaced.
+    pub fn refresh_tail_modal(&mut self, working_dir: &Path) -> bool {
+        let Some(tail) = self.plan_modal_tail.as_ref() else {
+            return false;
+        };
+        let target = tail.target;
+        let plan_path = self.resolved_plan_path(working_dir);
+        let path = match target {
+            TailTarget::Plan => plan_path,
+            TailTarget::Feedback => plan_path
+                .as_deref()
+                .and_then(Path::parent)
+                .and_then(latest_feedback_file),
+            TailTarget::Log => self.session_log_path(),
+        };
+        let modified = path
+            .as_deref()
+            .and_then(|p| std::fs::metadata(p).ok())
+            .and_then(|m| m.modified().ok());
+        if !self.plan_modal_content.is_empty() && tail.path == path && tail.modified == modified {
+            return false;
+        }
+
+        self.plan_modal_content = match (&path, modified) {
+            (Some(path), Some(_)) => std::fs::read_to_string(path)
+                .unwrap_or_else(|e| format!("Unable to read {}:\n{}", path.display(), e)),
+            (Some(path), None) => format!("Waiting for {} to be written...", path.display()),
+            (None, _) => format!(
+                "No {} has been written yet. This view updates as soon as it appears.",
+                target.label()
+            ),
+        };
+        self.plan_modal_tail = Some(ModalTail {
+            target,
+            path,
+            modified,
+        });
+        // The selected reference may point into the old content
+        self.plan_modal_reference = None;
+        true
+    }
+
     /// Close the plan modal if it's open.
     pub fn close_plan_modal(&mut self) {
         self.plan_modal_open = false;
         self.plan_modal_content.clear();
+        self.plan_modal_tail = None;
+        self.plan_modal_reference = None;
+        self.file_viewer = None;
     }
 
     /// Scroll the plan modal up by one line.
@@ -106,3 +187,22 @@ impl Session {
         self.implementation_success_modal = None;
     }
 }
+
+/// Most recently modified `feedback*.md` file in a session directory.
+pub fn latest_feedback_file(session_dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(session_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("feedback") && name.ends_with(".md")
+        })
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .max()
+        .map(|(_, path)| path)
+}
+
+#[cfg(test)]
+#[path = "../tests/plan_modal_tests.rs"]
+mod tests;
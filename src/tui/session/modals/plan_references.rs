@@ -0,0 +1,135 @@
+//! File references in the plan modal and the read-only viewer they open.
+//!
+//! Paths the plan mentions, with or without a line (`src/foo/bar.rs:42`), are
+//! looked up in the file index so only files of the repository count. `n`/`N`
+//! select them in the plan modal and `Enter` opens the file at that line, to
+//! check what the plan claims without leaving the TUI.
+
+use super::super::{FileViewer, PlanReference, Session};
+use crate::tui::file_index::FileIndex;
+use regex::Regex;
+use std::path::Path;
+
+/// Finds the references to indexed files in plan content, in reading order.
+pub fn find_plan_references(content: &str, file_index: &FileIndex) -> Vec<PlanReference> {
+    let re = Regex::new(r"([\w@+\-./]+)(?::(\d+)(?:-\d+)?)?").expect("valid reference regex");
+    let mut references = Vec::new();
+    for (plan_line, text) in content.lines().enumerate() {
+        for caps in re.captures_iter(text) {
+            let whole = caps.get(0).expect("regex match");
+            let path_match = caps.get(1).expect("path group");
+            // Sentence punctuation after a path isn't part of it
+            let mention = path_match.as_str().trim_end_matches('.');
+            let Some(path) = resolve_indexed_path(mention, file_index) else {
+                continue;
+            };
+            let line = caps
+                .get(2)
+                .filter(|_| mention.len() == path_match.len())
+                .and_then(|line| line.as_str().parse::<usize>().ok());
+            let end = match line {
+                Some(_) => whole.end(),
+                None => path_match.start() + mention.len(),
+            };
+            references.push(PlanReference {
+                plan_line,
+                span: path_match.start()..end,
+                path,
+                line: line.filter(|line| *line > 0),
+            });
+        }
+    }
+    references
+}
+
+/// The index path a mention refers to: repository-relative, `./`-prefixed or
+/// absolute below the repository root. Folders don't count.
+fn resolve_indexed_path(mention: &str, file_index: &FileIndex) -> Option<String> {
+    let mut path = mention.strip_prefix("./").unwrap_or(mention);
+    if let Some(root) = &file_index.repo_root {
+        let root = root.to_string_lossy();
+        if let Some(relative) = path
+            .strip_prefix(root.as_ref())
+            .and_then(|rest| rest.strip_prefix('/'))
+        {
+            path = relative;
+        }
+    }
+    if path.is_empty() || path.ends_with('/') {
+        return None;
+    }
+    file_index
+        .entries
+        .iter()
+        .find(|entry| entry.path == path)
+        .map(|entry| entry.path.clone())
+}
+
+impl Session {
+    /// Selects the next (or previous) file reference in the plan modal, wrapping
+    /// around. Returns false if the plan mentions no indexed files.
+    pub fn select_plan_reference(&mut self, forward: bool, file_index: &FileIndex) -> bool {
+        let references = find_plan_references(&self.plan_modal_content, file_index);
+        if references.is_empty() {
+            self.plan_modal_reference = None;
+            return false;
+        }
+        let current = self
+            .plan_modal_reference
+            .as_ref()
+            .and_then(|selected| references.iter().position(|r| r == selected));
+        let len = references.len();
+        let index = match (current, forward) {
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        self.plan_modal_reference = references.into_iter().nth(index);
+        true
+    }
+
+    /// Opens the selected plan reference in the file viewer, scrolled so its line
+    /// sits near the top of `visible_height` rows. Returns false if nothing is selected.
+    pub fn open_file_viewer(&mut self, repo_root: &Path, visible_height: usize) -> bool {
+        let Some(reference) = self.plan_modal_reference.clone() else {
+            return false;
+        };
+        let full_path = repo_root.join(&reference.path);
+        let lines = match std::fs::read(&full_path) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes)
+                .lines()
+                .map(|line| line.replace('\t', "    "))
+                .collect(),
+            Err(e) => vec![format!("Unable to read {}: {}", full_path.display(), e)],
+        };
+        let mut viewer = FileViewer {
+            path: reference.path,
+            lines,
+            line: reference.line,
+            scroll: 0,
+        };
+        if let Some(line) = viewer.line {
+            let max_scroll = viewer.lines.len().saturating_sub(visible_height);
+            viewer.scroll = line.saturating_sub(1 + visible_height / 3).min(max_scroll);
+        }
+        self.file_viewer = Some(viewer);
+        true
+    }
+
+    pub fn close_file_viewer(&mut self) {
+        self.file_viewer = None;
+    }
+
+    /// Scrolls the file viewer by `delta` lines, keeping a full page in view.
+    pub fn file_viewer_scroll_by(&mut self, delta: isize, visible_height: usize) {
+        if let Some(viewer) = self.file_viewer.as_mut() {
+            let max_scroll = viewer.lines.len().saturating_sub(visible_height);
+            viewer.scroll = viewer.scroll.saturating_add_signed(delta).min(max_scroll);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/plan_references_tests.rs"]
+mod tests;
@@ -26,9 +26,17 @@ impl Session {
             review_round
                 .reviewers
                 .retain(|r| r.display_id != display_id);
+            // A backup reviewer keeps showing whom it stands in for
+            let substitute_for = review_round.reviewers.iter().find_map(|r| match &r.status {
+                ReviewerStatus::Substituted { substitute_id } if *substitute_id == display_id => {
+                    Some(r.display_id.clone())
+                }
+                _ => None,
+            });
             review_round.reviewers.push(ReviewerEntry {
                 display_id,
                 status: ReviewerStatus::Running,
+                substitute_for,
             });
             self.review_history_scroll.follow = true;
         }
@@ -88,6 +96,30 @@ impl Session {
         }
     }
 
+    /// Mark a failed reviewer as replaced by a backup reviewer in the current round
+    pub fn reviewer_substituted(
+        &mut self,
+        kind: ReviewKind,
+        round: u32,
+        failed_id: String,
+        substitute_id: String,
+    ) {
+        if let Some(review_round) = self
+            .review_history
+            .iter_mut()
+            .find(|r| r.kind == kind && r.round == round)
+        {
+            if let Some(entry) = review_round
+                .reviewers
+                .iter_mut()
+                .find(|r| r.display_id == failed_id)
+            {
+                entry.status = ReviewerStatus::Substituted { substitute_id };
+                self.review_history_scroll.follow = true;
+            }
+        }
+    }
+
     /// Set aggregate verdict for a round
     pub fn set_round_verdict(&mut self, kind: ReviewKind, round: u32, approved: bool) {
         if let Some(review_round) = self
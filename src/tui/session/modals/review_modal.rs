@@ -1,13 +1,20 @@
 //! Review modal methods for Session.
 //!
-//! Provides functionality to toggle, navigate, and scroll the review feedback modal.
+//! Provides functionality to toggle, navigate, and scroll the review feedback modal,
+//! and to attach notes to single review lines for the next revision.
 
 use super::super::model::{ReviewKind, ReviewModalEntry};
 use super::super::Session;
+use crate::phases::feedback_items::{
+    load_review_annotations, save_review_annotations, AnnotationStance, ReviewAnnotation,
+};
 use std::collections::hash_map::DefaultHasher;
 use std::fs;
 use std::hash::{Hash, Hasher};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Maximum length of a review line note, in characters.
+pub const MAX_ANNOTATION_LEN: usize = 200;
 use unicode_width::UnicodeWidthStr;
 
 /// Compute which tabs are visible given scroll offset and available width.
@@ -77,20 +84,16 @@ impl Session {
         }
     }
 
+    /// Session directory holding the review files and annotations.
+    fn review_session_dir(&self) -> Option<PathBuf> {
+        let workflow_id = self.workflow_view.as_ref()?.workflow_id()?;
+        crate::planning_paths::session_dir(&workflow_id.0.to_string()).ok()
+    }
+
     fn open_review_modal(&mut self) -> bool {
-        let Some(ref view) = self.workflow_view else {
+        let Some(session_dir) = self.review_session_dir() else {
             return false;
         };
-        let Some(workflow_id) = view.workflow_id() else {
-            return false;
-        };
-
-        // Get session directory
-        let session_id = workflow_id.0.to_string();
-        let session_dir = match crate::planning_paths::session_dir(&session_id) {
-            Ok(dir) => dir,
-            Err(_) => return false,
-        };
 
         // Scan for feedback files
         let mut entries = Vec::new();
@@ -117,6 +120,9 @@ impl Session {
         });
 
         self.review_modal_entries = entries;
+        self.review_annotations = load_review_annotations(&session_dir);
+        self.review_modal_cursor = None;
+        self.review_annotation_input = None;
         self.review_modal_tab = 0; // Select most recent
         self.review_modal_scroll = 0;
         self.review_modal_tab_scroll = 0; // Reset horizontal scroll
@@ -187,6 +193,7 @@ impl Session {
             kind,
             display_name,
             content,
+            file_name: filename.to_string(),
             sort_key,
         })
     }
@@ -197,12 +204,16 @@ impl Session {
         self.review_modal_scroll = 0;
         self.review_modal_tab = 0;
         self.review_modal_tab_scroll = 0;
+        self.review_modal_cursor = None;
+        self.review_annotation_input = None;
+        self.review_annotations.clear();
     }
 
     pub fn review_modal_next_tab(&mut self, available_width: usize) {
         if !self.review_modal_entries.is_empty() {
             self.review_modal_tab = (self.review_modal_tab + 1) % self.review_modal_entries.len();
             self.review_modal_scroll = 0; // Reset content scroll
+            self.review_modal_cursor = self.review_modal_cursor.map(|_| 0);
             self.ensure_review_tab_visible(available_width);
         }
     }
@@ -215,6 +226,7 @@ impl Session {
                 self.review_modal_tab - 1
             };
             self.review_modal_scroll = 0; // Reset content scroll
+            self.review_modal_cursor = self.review_modal_cursor.map(|_| 0);
             self.ensure_review_tab_visible(available_width);
         }
     }
@@ -301,6 +313,130 @@ impl Session {
             .map(|e| e.content.as_str())
             .unwrap_or("")
     }
+
+    /// File name of the currently selected review.
+    fn current_review_file(&self) -> Option<&str> {
+        self.review_modal_entries
+            .get(self.review_modal_tab)
+            .map(|e| e.file_name.as_str())
+    }
+
+    /// The user's note on a line of the currently selected review.
+    pub fn review_line_annotation(&self, line: usize) -> Option<&ReviewAnnotation> {
+        let file = self.current_review_file()?;
+        self.review_annotations
+            .iter()
+            .find(|a| a.review_file == file && a.line == line)
+    }
+
+    /// Enters line selection at `first_line`, or leaves it.
+    pub fn toggle_review_line_selection(&mut self, first_line: usize) {
+        let line_count = self.current_review_content().lines().count();
+        self.review_modal_cursor = match self.review_modal_cursor {
+            Some(_) => None,
+            None if line_count > 0 => Some(first_line.min(line_count - 1)),
+            None => None,
+        };
+    }
+
+    pub fn review_cursor_down(&mut self) {
+        let line_count = self.current_review_content().lines().count();
+        if let Some(cursor) = self.review_modal_cursor.as_mut() {
+            if *cursor + 1 < line_count {
+                *cursor += 1;
+            }
+        }
+    }
+
+    pub fn review_cursor_up(&mut self) {
+        if let Some(cursor) = self.review_modal_cursor.as_mut() {
+            *cursor = cursor.saturating_sub(1);
+        }
+    }
+
+    /// Starts a note on the selected line, pre-filled with any existing note.
+    pub fn start_review_annotation(&mut self, stance: AnnotationStance) {
+        let Some(line) = self.review_modal_cursor else {
+            return;
+        };
+        let note = self
+            .review_line_annotation(line)
+            .map(|a| a.note.clone())
+            .unwrap_or_default();
+        self.review_annotation_input = Some((stance, note));
+    }
+
+    pub fn review_annotation_insert_char(&mut self, c: char) {
+        if let Some((_, note)) = self.review_annotation_input.as_mut() {
+            if note.chars().count() < MAX_ANNOTATION_LEN {
+                note.push(c);
+            }
+        }
+    }
+
+    pub fn review_annotation_backspace(&mut self) {
+        if let Some((_, note)) = self.review_annotation_input.as_mut() {
+            note.pop();
+        }
+    }
+
+    pub fn cancel_review_annotation(&mut self) {
+        self.review_annotation_input = None;
+    }
+
+    /// Saves the note being typed for the selected line, replacing any earlier one.
+    pub fn submit_review_annotation(&mut self) {
+        let (Some((stance, note)), Some(line)) = (
+            self.review_annotation_input.take(),
+            self.review_modal_cursor,
+        ) else {
+            return;
+        };
+        let Some(review_file) = self.current_review_file().map(str::to_string) else {
+            return;
+        };
+        let feedback = self
+            .current_review_content()
+            .lines()
+            .nth(line)
+            .unwrap_or_default()
+            .to_string();
+        self.review_annotations
+            .retain(|a| !(a.review_file == review_file && a.line == line));
+        self.review_annotations.push(ReviewAnnotation {
+            review_file,
+            line,
+            feedback,
+            stance,
+            note: note.trim().to_string(),
+        });
+        self.persist_review_annotations();
+    }
+
+    /// Removes the note on the selected line.
+    pub fn remove_review_annotation(&mut self) {
+        let (Some(line), Some(review_file)) = (
+            self.review_modal_cursor,
+            self.current_review_file().map(str::to_string),
+        ) else {
+            return;
+        };
+        let before = self.review_annotations.len();
+        self.review_annotations
+            .retain(|a| !(a.review_file == review_file && a.line == line));
+        if self.review_annotations.len() != before {
+            self.persist_review_annotations();
+        }
+    }
+
+    fn persist_review_annotations(&mut self) {
+        let Some(session_dir) = self.review_session_dir() else {
+            return;
+        };
+        if let Err(e) = save_review_annotations(&session_dir, &self.review_annotations) {
+            self.add_output(format!("[ui] Failed to save review note: {:#}", e));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,67 @@
+//! Review feedback triage overlay methods for Session.
+//!
+//! Before a revision, the workflow can ask the user to accept, park, or dismiss
+//! each item of the rejecting reviews. Every item starts as accepted.
+
+use super::super::model::FeedbackTriageModal;
+use super::super::{Session, SessionStatus};
+use crate::domain::review::{FeedbackItem, TriageDecision};
+
+impl Session {
+    /// Opens the triage overlay and marks the session as waiting on the user.
+    pub fn start_feedback_triage(&mut self, items: Vec<FeedbackItem>) {
+        self.add_output(format!(
+            "[triage] {} feedback item(s) - accept, park, or dismiss each before revising",
+            items.len()
+        ));
+        self.feedback_triage = Some(FeedbackTriageModal {
+            decisions: vec![TriageDecision::Accept; items.len()],
+            items,
+            selected: 0,
+        });
+        self.status = SessionStatus::AwaitingApproval;
+    }
+
+    pub fn feedback_triage_select_next(&mut self) {
+        if let Some(modal) = self.feedback_triage.as_mut() {
+            if modal.selected + 1 < modal.items.len() {
+                modal.selected += 1;
+            }
+        }
+    }
+
+    pub fn feedback_triage_select_prev(&mut self) {
+        if let Some(modal) = self.feedback_triage.as_mut() {
+            modal.selected = modal.selected.saturating_sub(1);
+        }
+    }
+
+    /// Sets the decision for the selected item and moves to the next one.
+    pub fn set_feedback_triage_decision(&mut self, decision: TriageDecision) {
+        let Some(modal) = self.feedback_triage.as_mut() else {
+            return;
+        };
+        if let Some(current) = modal.decisions.get_mut(modal.selected) {
+            *current = decision;
+        }
+        self.feedback_triage_select_next();
+    }
+
+    /// Sets the same decision for every item.
+    pub fn set_all_feedback_triage_decisions(&mut self, decision: TriageDecision) {
+        if let Some(modal) = self.feedback_triage.as_mut() {
+            modal.decisions.fill(decision);
+        }
+    }
+
+    /// Closes the overlay, returning the decisions to send to the workflow.
+    pub fn submit_feedback_triage(&mut self) -> Option<Vec<TriageDecision>> {
+        let modal = self.feedback_triage.take()?;
+        self.status = SessionStatus::Planning;
+        Some(modal.decisions)
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/triage_modal_tests.rs"]
+mod tests;
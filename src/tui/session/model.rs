@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::types::CompletionActionOutcome;
 use crate::tui::scroll::ScrollState;
+use tokio::sync::watch;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TodoStatus {
@@ -81,6 +83,48 @@ pub enum RunTabEntry {
     Tool(ToolTimelineEntry),
 }
 
+/// Which run tab entries the chat panel shows (cycled with `V`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatVerbosity {
+    /// Agent messages and every tool call.
+    #[default]
+    Full,
+    /// Agent messages and failed tool calls.
+    Compact,
+    /// Agent messages only.
+    Quiet,
+}
+
+impl ChatVerbosity {
+    pub fn next(self) -> Self {
+        match self {
+            ChatVerbosity::Full => ChatVerbosity::Compact,
+            ChatVerbosity::Compact => ChatVerbosity::Quiet,
+            ChatVerbosity::Quiet => ChatVerbosity::Full,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ChatVerbosity::Full => "full",
+            ChatVerbosity::Compact => "compact",
+            ChatVerbosity::Quiet => "quiet",
+        }
+    }
+
+    /// Returns true if the entry is shown at this verbosity.
+    pub fn shows(self, entry: &RunTabEntry) -> bool {
+        match (self, entry) {
+            (_, RunTabEntry::Text(_)) | (ChatVerbosity::Full, _) => true,
+            (ChatVerbosity::Compact, RunTabEntry::Tool(tool)) => {
+                matches!(tool, ToolTimelineEntry::Finished { is_error: true, .. })
+            }
+            (ChatVerbosity::Quiet, RunTabEntry::Tool(_)) => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum SummaryState {
     #[default]
@@ -88,6 +132,8 @@ pub enum SummaryState {
     Generating,
     Ready,
     Error,
+    /// Stopped by the user; `summary_text` holds whatever streamed before.
+    Cancelled,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +147,12 @@ pub struct RunTab {
     pub summary_state: SummaryState,
     #[serde(default)]
     pub summary_spinner_frame: u8,
+    /// Responses still being generated, one per agent; never persisted
+    #[serde(skip)]
+    pub partial: Vec<ChatMessage>,
+    /// Stops the summary agent while the summary is generating; never persisted
+    #[serde(skip)]
+    pub summary_cancel: Option<watch::Sender<bool>>,
 }
 
 impl RunTab {
@@ -113,6 +165,8 @@ impl RunTab {
             summary_scroll: ScrollState::new(),
             summary_state: SummaryState::None,
             summary_spinner_frame: 0,
+            partial: Vec::new(),
+            summary_cancel: None,
         }
     }
 }
@@ -130,6 +184,8 @@ pub enum ReviewerStatus {
     },
     /// Reviewer failed (execution error, not a rejection)
     Failed { error: String },
+    /// Reviewer kept failing and a backup reviewer took its place
+    Substituted { substitute_id: String },
 }
 
 /// A single reviewer's state within a round
@@ -139,6 +195,9 @@ pub struct ReviewerEntry {
     pub display_id: String,
     /// Current status
     pub status: ReviewerStatus,
+    /// Reviewer this backup reviewer stands in for, if any
+    #[serde(default)]
+    pub substitute_for: Option<String>,
 }
 
 /// A single review round (iteration)
@@ -217,6 +276,16 @@ pub enum FeedbackTarget {
     #[default]
     ApprovalDecline, // Existing: decline with feedback in approval flow
     WorkflowInterrupt, // New: interrupt active workflow with feedback
+    /// Conditions attached to approving the plan
+    ApprovalConditions,
+    /// A slash command typed while a workflow is running (e.g. `/tail plan`)
+    Command,
+    /// The objective being edited with `/edit-objective`
+    Objective,
+    /// The session's notes being edited with `/notes`
+    Notes,
+    /// Counter-argument appealing the plan's rejections at max iterations
+    Appeal,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
@@ -262,6 +331,152 @@ pub struct ImplementationSuccessModal {
     pub iterations_used: u32,
 }
 
+/// Runtime-only completion summary: the run's metrics card and the outcome of
+/// each completion action.
+#[derive(Debug, Clone)]
+pub struct CompletionSummary {
+    pub metrics: Vec<String>,
+    pub outcomes: Vec<CompletionActionOutcome>,
+}
+
+/// Stderr tail of the agent invocation that most recently failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentStderr {
+    pub agent_name: String,
+    /// Last lines, oldest first
+    pub lines: Vec<String>,
+    /// The invocation's full stderr under the session's `logs/stderr/`
+    pub log_path: Option<std::path::PathBuf>,
+}
+
+/// File followed by the plan modal when opened with `/tail`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailTarget {
+    Plan,
+    /// The most recently written review feedback file.
+    Feedback,
+    /// This session's `session.log`.
+    Log,
+}
+
+impl TailTarget {
+    pub fn parse(arg: &str) -> Option<Self> {
+        match arg.to_lowercase().as_str() {
+            "plan" => Some(Self::Plan),
+            "feedback" => Some(Self::Feedback),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Plan => "plan",
+            Self::Feedback => "feedback",
+            Self::Log => "session log",
+        }
+    }
+}
+
+/// Runtime-only live-follow state of the plan modal.
+/// Not serialized - a restored session reopens the modal without following.
+#[derive(Debug, Clone)]
+pub struct ModalTail {
+    pub target: TailTarget,
+    /// File currently shown; for feedback this moves to whichever file was written last.
+    pub path: Option<std::path::PathBuf>,
+    /// Modification time of `path` when it was last read.
+    pub modified: Option<std::time::SystemTime>,
+}
+
+/// A file the plan modal's content mentions, like `src/foo/bar.rs:42`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanReference {
+    /// Line of the plan content holding the reference.
+    pub plan_line: usize,
+    /// Byte range of the reference within that line.
+    pub span: std::ops::Range<usize>,
+    /// Path relative to the repository root, as listed in the file index.
+    pub path: String,
+    /// 1-based line the reference points at, if it names one.
+    pub line: Option<usize>,
+}
+
+/// Runtime-only state of the read-only file viewer opened from a plan reference.
+#[derive(Debug, Clone)]
+pub struct FileViewer {
+    pub path: String,
+    pub lines: Vec<String>,
+    /// 1-based line highlighted as the reference's target.
+    pub line: Option<usize>,
+    pub scroll: usize,
+}
+
+/// Runtime-only state for the worktree conflict resolution overlay.
+/// Not serialized - an interrupted sync is aborted or resolved by hand after restore.
+#[derive(Debug, Clone)]
+pub struct ConflictModal {
+    pub worktree: crate::domain::types::WorktreeState,
+    pub method: crate::git_worktree::SourceSyncMethod,
+    pub files: Vec<ConflictFileEntry>,
+    pub selected: usize,
+    /// Last action result or error, shown below the file list.
+    pub status: Option<String>,
+    /// A git operation is running; further actions wait for its result.
+    pub busy: bool,
+}
+
+/// Result of a worktree git operation run off the input path, reported back
+/// through `Event::WorktreeGitDone`. Errors are kept as display strings.
+#[derive(Debug, Clone)]
+pub enum WorktreeGitStep {
+    /// `/merge-worktree` synced the worktree branch with its moved source branch.
+    PreMergeSync {
+        worktree: crate::domain::types::WorktreeState,
+        method: crate::git_worktree::SourceSyncMethod,
+        source: String,
+        outcome: Result<crate::git_worktree::SourceSyncOutcome, String>,
+    },
+    /// A resolution choice was applied to a conflicted file.
+    Resolved {
+        path: String,
+        choice: crate::git_worktree::ConflictChoice,
+        result: Result<(), String>,
+    },
+    /// The conflict list was re-read from git.
+    Refreshed(Result<Vec<String>, String>),
+    /// The sync was continued after every file was resolved.
+    Continued(Result<crate::git_worktree::SourceSyncOutcome, String>),
+    /// The sync was aborted.
+    Aborted(Result<(), String>),
+}
+
+/// A conflicted file and the choice applied to it, if any.
+#[derive(Debug, Clone)]
+pub struct ConflictFileEntry {
+    pub path: String,
+    pub resolution: Option<crate::git_worktree::ConflictChoice>,
+}
+
+/// Runtime-only state for the review feedback triage overlay.
+/// Not serialized - the workflow asks again on resume if triage was not recorded.
+#[derive(Debug, Clone)]
+pub struct FeedbackTriageModal {
+    pub items: Vec<crate::domain::review::FeedbackItem>,
+    /// Decision per item, parallel to `items`.
+    pub decisions: Vec<crate::domain::review::TriageDecision>,
+    pub selected: usize,
+}
+
+/// Other live sessions working in the same directory as this one, found in the
+/// daemon registry. Runtime-only; refreshed while sessions run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirConflict {
+    /// The directory the sessions overlap in
+    pub working_dir: std::path::PathBuf,
+    /// Feature names of the other sessions
+    pub others: Vec<String>,
+}
+
 /// Entry representing a single review file for modal display.
 ///
 /// Entries are sorted by (iteration DESC, agent_name ASC) to show most recent
@@ -275,6 +490,8 @@ pub struct ReviewModalEntry {
     pub display_name: String,
     /// Cached content of the feedback file
     pub content: String,
+    /// Name of the feedback file in the session directory
+    pub file_name: String,
     /// Sort key for ordering: iteration * 1_000_000_000 + (kind_rank * 1_000_000) + (1_000_000 - agent_ordinal)
     /// Higher values = more recent iteration, then by kind, then by agent
     pub sort_key: u64,
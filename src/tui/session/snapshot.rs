@@ -24,6 +24,7 @@ impl Session {
             streaming_scroll: self.streaming_scroll.clone(),
             focused_panel: self.focused_panel,
             total_cost: self.total_cost,
+            cost_estimated: self.cost_estimated,
             bytes_received: self.bytes_received,
             total_input_tokens: self.total_input_tokens,
             total_output_tokens: self.total_output_tokens,
@@ -69,6 +70,8 @@ impl Session {
             review_history: self.review_history.clone(),
             review_history_spinner_frame: self.review_history_spinner_frame,
             review_history_scroll: self.review_history_scroll.clone(),
+            todos_collapsed: self.todos_collapsed,
+            chat_verbosity: self.chat_verbosity,
         }
     }
 
@@ -84,9 +87,11 @@ impl Session {
             streaming_lines: ui_state.streaming_lines,
             streaming_scroll: ui_state.streaming_scroll,
             focused_panel: ui_state.focused_panel,
+            nav: None,
             workflow_view,
             start_time: Instant::now(), // Reset to now
             total_cost: ui_state.total_cost,
+            cost_estimated: ui_state.cost_estimated,
             running: false,                        // Will be set when workflow resumes
             active_tools_by_agent: HashMap::new(), // Reset
             completed_tools_by_agent: HashMap::new(), // Reset
@@ -106,6 +111,7 @@ impl Session {
             last_key_was_backslash: ui_state.last_key_was_backslash,
             tab_input_pastes: ui_state.tab_input_pastes,
             feedback_pastes: ui_state.feedback_pastes,
+            draft_autosave: Default::default(), // Runtime-only, reset on resume
             error_state: ui_state.error_state,
             error_scroll: ui_state.error_scroll,
             bytes_received: ui_state.bytes_received,
@@ -127,7 +133,10 @@ impl Session {
             workflow_handle: None,     // Reset
             approval_tx: None,         // Reset
             workflow_control_tx: None, // Reset
+            workflow_update_tx: None,  // Reset
             feedback_target: ui_state.feedback_target,
+            approval_conditions: None,
+            pending_objective: None,
             current_run_id: ui_state.current_run_id,
             account_usage: ui_state.account_usage,
             spinner_frame: ui_state.spinner_frame,
@@ -141,6 +150,9 @@ impl Session {
             plan_modal_open: ui_state.plan_modal_open,
             plan_modal_scroll: ui_state.plan_modal_scroll,
             plan_modal_content: String::new(), // Content is re-read from disk when modal opens
+            plan_modal_tail: None,
+            plan_modal_reference: None,
+            file_viewer: None,
 
             // Review modal state - entries cleared on restore (re-loaded when modal opens)
             review_modal_open: ui_state.review_modal_open,
@@ -148,18 +160,37 @@ impl Session {
             review_modal_tab: ui_state.review_modal_tab,
             review_modal_tab_scroll: 0, // Reset horizontal tab scroll on restore
             review_modal_entries: Vec::new(), // Runtime-only, re-populated on open
+            review_modal_cursor: None,
+            review_annotation_input: None,
+            review_annotations: Vec::new(),
 
             review_history: ui_state.review_history,
             review_history_spinner_frame: ui_state.review_history_spinner_frame,
             review_history_scroll: ui_state.review_history_scroll,
+            todos_collapsed: ui_state.todos_collapsed,
+            chat_verbosity: ui_state.chat_verbosity,
 
             context: None, // Context is set by resume/new-session flows, not serialized
 
             implementation_success_modal: None, // Runtime-only, reset on restore
+            completion_summary: None,
+            agent_stderr: None,
+            conflict_modal: None,  // Runtime-only, reset on restore
+            feedback_triage: None, // Runtime-only, reset on restore
+            tool_permission_queue: std::collections::VecDeque::new(), // Agents are not running
+            workspace_trust_request: None,
+            dir_conflict: None, // Refreshed from the daemon registry
+            session_allowed_tool_risks: std::collections::HashSet::new(),
             implementation_interaction: super::ImplementationInteractionState {
                 running: false,
                 cancel_tx: None,
             },
+            awaiting_approval_since: None, // Idle timer restarts on resume
+            idle_suspended: false,
+            following_pid: None,
+            plan_change_noticed: None,
+            phase_overrun_minutes: None,
+            cost_alert: None,
         }
     }
 
@@ -0,0 +1,74 @@
+use super::*;
+use crate::tui::session::model::{ToolKind, ToolResultSummary};
+use tempfile::tempdir;
+
+fn review_tab() -> RunTab {
+    let mut tab = RunTab::new("Reviewing #2".to_string());
+    tab.entries.push(RunTabEntry::Text(ChatMessage {
+        agent_name: "codex".to_string(),
+        message: "Checking the migration.\n".to_string(),
+    }));
+    tab.entries
+        .push(RunTabEntry::Tool(ToolTimelineEntry::Finished {
+            agent_name: "codex".to_string(),
+            kind: ToolKind::Read,
+            display_name: "Read".to_string(),
+            input_preview: "db/migrate.sql".to_string(),
+            duration_ms: 1300,
+            is_error: false,
+            result_summary: ToolResultSummary {
+                first_line: "ALTER TABLE users".to_string(),
+                line_count: 12,
+                truncated: false,
+            },
+        }));
+    tab.entries
+        .push(RunTabEntry::Tool(ToolTimelineEntry::Started {
+            agent_name: "codex".to_string(),
+            kind: ToolKind::Bash,
+            display_name: "Bash".to_string(),
+            input_preview: "cargo test".to_string(),
+        }));
+    tab.entries.push(RunTabEntry::Text(ChatMessage {
+        agent_name: "codex".to_string(),
+        message: "The down migration is missing.".to_string(),
+    }));
+    tab.summary_text = "Rejected: no rollback.".to_string();
+    tab
+}
+
+#[test]
+fn test_run_tab_markdown_lists_messages_tools_and_summary() {
+    assert_eq!(
+        run_tab_markdown(&review_tab(), "add-sso"),
+        "# add-sso - Reviewing #2\n\
+         \n## codex\n\nChecking the migration.\n\
+         \n- ✓ [codex] `Read` db/migrate.sql (1.3s) - ALTER TABLE users\n\
+         - ▶ [codex] `Bash` cargo test (running)\n\
+         \n## codex\n\nThe down migration is missing.\n\
+         \n## Summary\n\nRejected: no rollback.\n"
+    );
+}
+
+#[test]
+fn test_export_run_tab_writes_markdown_named_after_the_phase() {
+    let dir = tempdir().unwrap();
+    let path = export_run_tab(&review_tab(), "add-sso", dir.path()).unwrap();
+
+    let name = path.file_name().unwrap().to_string_lossy().to_string();
+    assert!(name.starts_with("run-tab-reviewing-2-"), "{}", name);
+    assert!(name.ends_with(".md"));
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("# add-sso - Reviewing #2\n"));
+}
+
+#[test]
+fn test_export_active_run_tab_needs_a_tab_and_a_workflow() {
+    let mut session = Session::new(0);
+    let err = session.export_active_run_tab().unwrap_err();
+    assert!(err.to_string().contains("no run tabs"));
+
+    session.add_run_tab("Planning".to_string());
+    let err = session.export_active_run_tab().unwrap_err();
+    assert!(err.to_string().contains("no workflow"));
+}
@@ -0,0 +1,95 @@
+use super::*;
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkingDir,
+};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowEvent;
+use crate::planning_paths;
+use tempfile::tempdir;
+
+const WORKFLOW_ID: &str = "3f2b6c1e-8d4a-4f5e-9b7c-2a1d0e9f8c7b";
+
+fn session_with_workflow(dir: &Path) -> Session {
+    let mut session = Session::new(0);
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        WORKFLOW_ID,
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from("test-feature"),
+            objective: Objective::from("Test objective"),
+            working_dir: WorkingDir::from(dir),
+            max_iterations: MaxIterations(3),
+            plan_path: PlanPath::from(dir.join("plan.md")),
+            feedback_path: FeedbackPath::from(dir.join("feedback_1.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+    session.workflow_view = Some(view);
+    session
+}
+
+#[test]
+fn test_save_draft_removes_blank_drafts() {
+    let dir = tempdir().unwrap();
+    let drafts = dir.path().join("drafts");
+
+    save_draft(&drafts, DraftKind::Chat, "follow up").unwrap();
+    assert_eq!(
+        load_draft(&drafts, DraftKind::Chat).as_deref(),
+        Some("follow up")
+    );
+    assert_eq!(load_draft(&drafts, DraftKind::Decline), None);
+
+    save_draft(&drafts, DraftKind::Chat, "  \n").unwrap();
+    assert!(!drafts.join("chat.txt").exists());
+    save_draft(&drafts, DraftKind::Chat, "").unwrap();
+}
+
+#[test]
+fn test_decline_feedback_survives_esc_until_submitted() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let mut session = session_with_workflow(home.path());
+    let drafts = planning_paths::session_drafts_dir(WORKFLOW_ID).unwrap();
+
+    session.start_feedback_input();
+    session.user_feedback = "Split the migration into two steps".to_string();
+    session.flush_draft();
+    assert_eq!(
+        load_draft(&drafts, DraftKind::Decline).as_deref(),
+        Some("Split the migration into two steps")
+    );
+
+    // Esc clears the input; re-entering the same mode restores it
+    session.user_feedback.clear();
+    session.approval_mode = ApprovalMode::AwaitingChoice;
+    session.start_feedback_input();
+    assert_eq!(session.user_feedback, "Split the migration into two steps");
+    assert_eq!(session.cursor_position, session.user_feedback.len());
+
+    // Other feedback targets have their own drafts
+    session.start_feedback_input_for(FeedbackTarget::WorkflowInterrupt);
+    assert!(session.user_feedback.is_empty());
+
+    session.discard_draft(DraftKind::Decline);
+    assert_eq!(load_draft(&drafts, DraftKind::Decline), None);
+}
+
+#[test]
+fn test_chat_draft_restored_when_focusing_input() {
+    let home = tempdir().unwrap();
+    let _guard = planning_paths::set_home_for_test(home.path().to_path_buf());
+    let drafts = planning_paths::session_drafts_dir(WORKFLOW_ID).unwrap();
+    save_draft(&drafts, DraftKind::Chat, "also update the docs").unwrap();
+
+    let mut session = session_with_workflow(home.path());
+    session.focused_panel = FocusedPanel::ChatInput;
+    session.restore_draft();
+    assert_eq!(session.tab_input, "also update the docs");
+
+    // Clearing the input removes the draft on the next autosave
+    session.tab_input.clear();
+    session.autosave_draft();
+    assert_eq!(load_draft(&drafts, DraftKind::Chat), None);
+}
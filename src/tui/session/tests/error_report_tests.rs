@@ -0,0 +1,101 @@
+use super::*;
+
+#[test]
+fn test_classify_agent_failure_names_the_cli() {
+    let output = vec![
+        "[planning] Starting review".to_string(),
+        "[review] codex failed: exit status 1".to_string(),
+        "[review] Retrying codex".to_string(),
+    ];
+    let report = ErrorReport::classify("Workflow failed: codex exited with 1", &output, true);
+
+    assert_eq!(report.category, ErrorCategory::Agent);
+    assert_eq!(report.severity, ErrorSeverity::Error);
+    assert_eq!(report.component, "codex");
+    assert_eq!(report.recent_lines, vec![output[1].clone()]);
+    assert_eq!(
+        report.actions,
+        vec![
+            SuggestedAction::RetryPhase,
+            SuggestedAction::OpenLogs,
+            SuggestedAction::RunDoctor
+        ]
+    );
+}
+
+#[test]
+fn test_classify_config_error_is_fatal_and_not_retried() {
+    let report = ErrorReport::classify(
+        "Initialization failed: failed to read workflow.yaml",
+        &[],
+        true,
+    );
+    assert_eq!(report.category, ErrorCategory::Config);
+    assert_eq!(report.severity, ErrorSeverity::Fatal);
+    assert_eq!(report.actions.first(), Some(&SuggestedAction::RunDoctor));
+    assert!(!report.actions.contains(&SuggestedAction::RetryPhase));
+}
+
+#[test]
+fn test_classify_other_categories() {
+    let category = |msg: &str| ErrorReport::classify(msg, &[], false).category;
+    assert_eq!(category("Failed to create worktree"), ErrorCategory::Git);
+    assert_eq!(
+        category("Session daemon unreachable"),
+        ErrorCategory::Daemon
+    );
+    assert_eq!(
+        category("Permission denied (os error 13)"),
+        ErrorCategory::Io
+    );
+    assert_eq!(
+        category("Workflow aborted by user"),
+        ErrorCategory::Workflow
+    );
+}
+
+#[test]
+fn test_user_abort_is_a_warning_and_retry_needs_a_stopped_workflow() {
+    let report = ErrorReport::classify("Workflow aborted by user", &[], false);
+    assert_eq!(report.severity, ErrorSeverity::Warning);
+    assert_eq!(
+        report.actions,
+        vec![SuggestedAction::OpenLogs, SuggestedAction::RunDoctor]
+    );
+
+    let session = Session::new(0);
+    assert!(session.error_report().is_none());
+    assert!(!session.can_retry_workflow());
+}
+
+#[test]
+fn test_action_keys_round_trip() {
+    for action in [
+        SuggestedAction::RetryPhase,
+        SuggestedAction::OpenLogs,
+        SuggestedAction::RunDoctor,
+    ] {
+        assert_eq!(SuggestedAction::from_key(action.key()), Some(action));
+    }
+    assert_eq!(SuggestedAction::from_key('x'), None);
+}
+
+#[test]
+fn test_agent_stderr_is_attached_to_agent_failures_only() {
+    let mut session = Session::new(0);
+    session.agent_stderr = Some(AgentStderr {
+        agent_name: "codex".to_string(),
+        lines: vec!["rate limit exceeded, retrying in 30s".to_string()],
+        log_path: None,
+    });
+
+    session.handle_error("Workflow failed: codex exited with 1");
+    let report = session.error_report().unwrap();
+    assert_eq!(report.agent_stderr, session.agent_stderr);
+
+    session.handle_error("Failed to create worktree");
+    assert!(session.error_report().unwrap().agent_stderr.is_none());
+
+    session.clear_error();
+    assert!(session.agent_stderr.is_none());
+}
@@ -548,6 +548,25 @@ fn test_add_chat_message() {
     }
 }
 
+#[test]
+fn test_partial_text_is_replaced_by_finished_message() {
+    let mut session = Session::new(0);
+
+    session.append_partial_text("claude", "Planning", "Hel");
+    session.append_partial_text("codex", "Planning", "Other");
+    session.append_partial_text("claude", "Planning", "lo\nwor");
+    assert_eq!(session.run_tabs.len(), 1);
+    assert_eq!(session.run_tabs[0].partial.len(), 2);
+    assert_eq!(session.run_tabs[0].partial[0].message, "Hello\nwor");
+
+    session.add_chat_message("claude", "Planning", "Hello".to_string());
+    assert_eq!(session.run_tabs[0].partial.len(), 1);
+    assert_eq!(session.run_tabs[0].partial[0].agent_name, "codex");
+
+    session.clear_partial_text("codex", "Planning");
+    assert!(session.run_tabs[0].partial.is_empty());
+}
+
 #[test]
 fn test_add_chat_message_creates_tab_if_needed() {
     let mut session = Session::new(0);
@@ -712,6 +731,27 @@ fn test_toggle_focus_with_summary() {
     assert_eq!(session.focused_panel, FocusedPanel::Output);
 }
 
+#[test]
+fn test_cancelled_summary_keeps_streamed_text() {
+    let mut session = Session::new(0);
+    session.add_run_tab("Planning".to_string());
+    let (cancel_tx, cancel_rx) = tokio::sync::watch::channel(false);
+    session.set_summary_generating("Planning", cancel_tx);
+
+    session.append_summary_text("Planning", "- Adds ");
+    session.append_summary_text("Planning", "login");
+    assert!(session.cancel_active_summary());
+    assert!(*cancel_rx.borrow());
+
+    // Late output from the stopped agent does not replace the partial text
+    session.append_summary_text("Planning", " and logout");
+    session.set_summary_ready("Planning", String::new());
+    let tab = &session.run_tabs[0];
+    assert_eq!(tab.summary_state, SummaryState::Cancelled);
+    assert_eq!(tab.summary_text, "- Adds login");
+    assert!(!session.cancel_active_summary());
+}
+
 fn build_interactive_session() -> Session {
     use crate::domain::WorkflowEvent;
 
@@ -1049,3 +1089,31 @@ fn test_open_implementation_success_closes_plan_modal() {
     // Success modal should be open
     assert!(session.implementation_success_modal.is_some());
 }
+
+#[test]
+fn test_layout_preferences_survive_snapshot_round_trip() {
+    let mut session = Session::new(0);
+    session.cycle_chat_verbosity();
+    session.toggle_todos_collapsed();
+    session.focused_panel = FocusedPanel::Chat;
+    session.output_scroll.scroll_up();
+
+    let restored = Session::from_ui_state(session.to_ui_state(), None);
+
+    assert_eq!(restored.chat_verbosity, ChatVerbosity::Compact);
+    assert!(restored.todos_collapsed);
+    assert_eq!(restored.focused_panel, FocusedPanel::Chat);
+    assert!(!restored.output_scroll.follow);
+}
+
+#[test]
+fn test_record_cost_output_tracks_estimates() {
+    let mut session = Session::new(0);
+    session.record_cost_output("[agent:claude] Cost: $0.5000");
+    assert!(!session.cost_estimated);
+    session.record_cost_output("[agent:codex] Cost: ~$0.2500 (estimated)");
+    session.record_cost_output("[planning] Nothing to see here");
+
+    assert!((session.total_cost - 0.75).abs() < 1e-9);
+    assert!(session.cost_estimated);
+}
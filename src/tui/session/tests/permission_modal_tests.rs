@@ -0,0 +1,100 @@
+use super::*;
+use crate::agents::permissions::ToolRisk;
+use crate::app::workspace_trust::WorkspaceTrustRequest;
+use tokio::sync::mpsc;
+
+fn request(
+    risk: ToolRisk,
+    display_name: &str,
+) -> (
+    ToolPermissionRequest,
+    mpsc::Receiver<ToolPermissionDecision>,
+) {
+    let (responder, rx) = mpsc::channel(1);
+    let request = ToolPermissionRequest {
+        agent_name: "claude".to_string(),
+        phase: "Planning".to_string(),
+        risk,
+        display_name: display_name.to_string(),
+        input_preview: String::new(),
+        responder,
+    };
+    (request, rx)
+}
+
+#[test]
+fn test_answer_sends_decision_and_pops_request() {
+    let mut session = Session::new(0);
+    let (req, mut rx) = request(ToolRisk::GitPush, "git push");
+    session.queue_tool_permission(req);
+    assert_eq!(
+        session.current_tool_permission().map(|r| r.risk),
+        Some(ToolRisk::GitPush)
+    );
+
+    session.answer_tool_permission(ToolPermissionDecision::Deny);
+
+    assert!(session.current_tool_permission().is_none());
+    assert_eq!(rx.try_recv().ok(), Some(ToolPermissionDecision::Deny));
+    assert!(session.session_allowed_tool_risks.is_empty());
+}
+
+#[test]
+fn test_allow_for_session_answers_matching_queued_requests() {
+    let mut session = Session::new(0);
+    let (first, mut first_rx) = request(ToolRisk::Network, "curl");
+    let (second, mut second_rx) = request(ToolRisk::Network, "wget");
+    let (other, mut other_rx) = request(ToolRisk::FileDeletion, "rm");
+    session.queue_tool_permission(first);
+    session.queue_tool_permission(second);
+    session.queue_tool_permission(other);
+
+    session.answer_tool_permission(ToolPermissionDecision::AllowForSession);
+
+    assert_eq!(
+        first_rx.try_recv().ok(),
+        Some(ToolPermissionDecision::AllowForSession)
+    );
+    assert_eq!(
+        second_rx.try_recv().ok(),
+        Some(ToolPermissionDecision::Allow)
+    );
+    assert!(other_rx.try_recv().is_err());
+    assert_eq!(
+        session.current_tool_permission().map(|r| r.risk),
+        Some(ToolRisk::FileDeletion)
+    );
+}
+
+#[test]
+fn test_session_allowed_risk_is_not_queued() {
+    let mut session = Session::new(0);
+    session.session_allowed_tool_risks.insert(ToolRisk::Network);
+    let (req, mut rx) = request(ToolRisk::Network, "curl");
+
+    session.queue_tool_permission(req);
+
+    assert!(session.current_tool_permission().is_none());
+    assert_eq!(rx.try_recv().ok(), Some(ToolPermissionDecision::Allow));
+}
+
+#[test]
+fn test_answer_workspace_trust_responds_and_reports() {
+    let mut session = Session::new(0);
+    let (responder, mut rx) = mpsc::channel(1);
+    session.workspace_trust_request = Some(WorkspaceTrustRequest {
+        workspace: std::path::PathBuf::from("/repo"),
+        responder,
+    });
+    assert_eq!(session.attention(), Some("permission"));
+
+    session.answer_workspace_trust(WorkspaceTrust::Restricted);
+
+    assert!(session.workspace_trust_request.is_none());
+    assert_eq!(rx.try_recv().ok(), Some(WorkspaceTrust::Restricted));
+    assert!(session
+        .output_lines
+        .last()
+        .unwrap()
+        .starts_with("[permissions] Restricted /repo"));
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::domain::types::{
+    FeatureName, FeedbackPath, MaxIterations, Objective, PlanPath, TimestampUtc, WorkingDir,
+};
+use crate::domain::view::WorkflowView;
+use crate::domain::WorkflowEvent;
+use std::time::{Duration, SystemTime};
+use tempfile::tempdir;
+
+fn session_with_plan(dir: &Path) -> Session {
+    let mut session = Session::new(0);
+    let mut view = WorkflowView::default();
+    view.apply_event(
+        "workflow",
+        &WorkflowEvent::WorkflowCreated {
+            feature_name: FeatureName::from("test-feature"),
+            objective: Objective::from("Test objective"),
+            working_dir: WorkingDir::from(dir),
+            max_iterations: MaxIterations(3),
+            plan_path: PlanPath::from(dir.join("plan.md")),
+            feedback_path: FeedbackPath::from(dir.join("feedback_1.md")),
+            created_at: TimestampUtc::now(),
+        },
+        1,
+    );
+    session.workflow_view = Some(view);
+    session
+}
+
+fn write_with_mtime(path: &Path, content: &str, secs_ago: u64) {
+    std::fs::write(path, content).unwrap();
+    let file = std::fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() - Duration::from_secs(secs_ago))
+        .unwrap();
+}
+
+#[test]
+fn test_tail_plan_picks_up_new_content() {
+    let dir = tempdir().unwrap();
+    let mut session = session_with_plan(dir.path());
+
+    assert!(session.open_tail_modal(TailTarget::Plan, dir.path()));
+    assert!(session.plan_modal_content.starts_with("Waiting for"));
+
+    write_with_mtime(&dir.path().join("plan.md"), "# Plan\n\nStep 1", 10);
+    assert!(session.refresh_tail_modal(dir.path()));
+    assert_eq!(session.plan_modal_content, "# Plan\n\nStep 1");
+    assert!(!session.refresh_tail_modal(dir.path()));
+
+    write_with_mtime(&dir.path().join("plan.md"), "# Plan\n\nStep 1\nStep 2", 0);
+    assert!(session.refresh_tail_modal(dir.path()));
+    assert!(session.plan_modal_content.ends_with("Step 2"));
+}
+
+#[test]
+fn test_tail_feedback_follows_latest_file() {
+    let dir = tempdir().unwrap();
+    let mut session = session_with_plan(dir.path());
+    assert!(session.open_tail_modal(TailTarget::Feedback, dir.path()));
+    assert!(session.plan_modal_content.starts_with("No feedback"));
+
+    write_with_mtime(&dir.path().join("feedback_1_claude.md"), "first", 20);
+    write_with_mtime(&dir.path().join("plan.md"), "plan", 0);
+    assert!(session.refresh_tail_modal(dir.path()));
+    assert_eq!(session.plan_modal_content, "first");
+
+    write_with_mtime(&dir.path().join("feedback_1_codex.md"), "second", 5);
+    assert!(session.refresh_tail_modal(dir.path()));
+    assert_eq!(session.plan_modal_content, "second");
+}
+
+#[test]
+fn test_close_plan_modal_stops_tailing() {
+    let dir = tempdir().unwrap();
+    let mut session = session_with_plan(dir.path());
+    session.open_tail_modal(TailTarget::Plan, dir.path());
+
+    session.close_plan_modal();
+
+    assert!(session.plan_modal_tail.is_none());
+    assert!(!session.refresh_tail_modal(dir.path()));
+}
+
+#[test]
+fn test_tail_requires_a_plan() {
+    let mut session = Session::new(0);
+    assert!(!session.open_tail_modal(TailTarget::Plan, Path::new("/tmp")));
+    assert!(!session.plan_modal_open);
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::tui::file_index::FileIndex;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+fn index(root: &Path) -> FileIndex {
+    FileIndex::from_files(
+        vec!["src/main.rs".to_string(), "src/app/mod.rs".to_string()],
+        root.to_path_buf(),
+    )
+}
+
+#[test]
+fn test_find_plan_references_only_counts_indexed_files() {
+    let file_index = index(Path::new("/repo"));
+    let content = "# Plan\n\
+        Change `src/main.rs:42` and ./src/app/mod.rs.\n\
+        Leave src/missing.rs:3 and /repo/src/main.rs:7-9 alone.";
+
+    let references = find_plan_references(content, &file_index);
+    let found: Vec<(usize, &str, Option<usize>)> = references
+        .iter()
+        .map(|r| (r.plan_line, r.path.as_str(), r.line))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, "src/main.rs", Some(42)),
+            (1, "src/app/mod.rs", None),
+            (2, "src/main.rs", Some(7)),
+        ]
+    );
+    let line = content.lines().nth(1).unwrap();
+    assert_eq!(&line[references[0].span.clone()], "src/main.rs:42");
+    assert_eq!(&line[references[1].span.clone()], "./src/app/mod.rs");
+}
+
+#[test]
+fn test_select_plan_reference_wraps_around() {
+    let file_index = index(Path::new("/repo"));
+    let mut session = Session::new(0);
+    session.plan_modal_content = "src/main.rs:1\nsrc/app/mod.rs:2".to_string();
+
+    assert!(session.select_plan_reference(false, &file_index));
+    assert_eq!(session.plan_modal_reference.as_ref().unwrap().plan_line, 1);
+    assert!(session.select_plan_reference(true, &file_index));
+    assert_eq!(session.plan_modal_reference.as_ref().unwrap().plan_line, 0);
+
+    session.plan_modal_content = "No files here".to_string();
+    assert!(!session.select_plan_reference(true, &file_index));
+    assert!(session.plan_modal_reference.is_none());
+}
+
+#[test]
+fn test_open_file_viewer_scrolls_to_the_referenced_line() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    let source: String = (1..=100).map(|i| format!("line {}\n", i)).collect();
+    std::fs::write(dir.path().join("src/main.rs"), source).unwrap();
+    let file_index = index(dir.path());
+    let mut session = Session::new(0);
+    session.plan_modal_content = "See src/main.rs:50".to_string();
+
+    assert!(!session.open_file_viewer(dir.path(), 30));
+    session.select_plan_reference(true, &file_index);
+    assert!(session.open_file_viewer(dir.path(), 30));
+    let viewer = session.file_viewer.as_ref().unwrap();
+    assert_eq!(viewer.path, "src/main.rs");
+    assert_eq!(viewer.line, Some(50));
+    assert_eq!(viewer.scroll, 39);
+    assert_eq!(viewer.lines[49], "line 50");
+
+    session.file_viewer_scroll_by(isize::MAX, 30);
+    assert_eq!(session.file_viewer.as_ref().unwrap().scroll, 70);
+    session.close_plan_modal();
+    assert!(session.file_viewer.is_none());
+}
+
+#[test]
+fn test_open_file_viewer_reports_unreadable_files() {
+    let mut session = Session::new(0);
+    session.plan_modal_content = "src/main.rs".to_string();
+    session.select_plan_reference(true, &index(Path::new("/repo")));
+
+    assert!(session.open_file_viewer(&PathBuf::from("/nonexistent-repo"), 10));
+    let viewer = session.file_viewer.as_ref().unwrap();
+    assert!(viewer.lines[0].starts_with("Unable to read"));
+}
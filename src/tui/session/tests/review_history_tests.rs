@@ -43,3 +43,35 @@ fn test_review_history_separates_kinds() {
     assert_eq!(plan_round.reviewers.len(), 1);
     assert_eq!(impl_round.reviewers.len(), 1);
 }
+
+#[test]
+fn test_substituted_reviewer_flags_its_backup() {
+    let mut session = Session::new(0);
+    session.start_review_round(ReviewKind::Plan, 1);
+    session.reviewer_started(ReviewKind::Plan, 1, "codex".to_string());
+    session.reviewer_failed(
+        ReviewKind::Plan,
+        1,
+        "codex".to_string(),
+        "not logged in".to_string(),
+    );
+
+    session.reviewer_substituted(
+        ReviewKind::Plan,
+        1,
+        "codex".to_string(),
+        "gemini".to_string(),
+    );
+    session.reviewer_started(ReviewKind::Plan, 1, "gemini".to_string());
+
+    let reviewers = &session.review_history[0].reviewers;
+    assert_eq!(
+        reviewers[0].status,
+        ReviewerStatus::Substituted {
+            substitute_id: "gemini".to_string()
+        }
+    );
+    assert_eq!(reviewers[0].substitute_for, None);
+    assert_eq!(reviewers[1].display_id, "gemini");
+    assert_eq!(reviewers[1].substitute_for.as_deref(), Some("codex"));
+}
@@ -92,6 +92,7 @@ fn create_test_entries(names: &[&str]) -> Vec<crate::tui::session::model::Review
             kind: crate::tui::session::model::ReviewKind::Plan,
             display_name: name.to_string(),
             content: String::new(),
+            file_name: format!("feedback_{}.md", i + 1),
             sort_key: (i as u64 + 1) * 1_000_000_000,
         })
         .collect()
@@ -319,3 +320,42 @@ fn test_review_modal_prev_tab_wraps_with_visibility() {
         "Tab 4 should be visible after wrapping"
     );
 }
+
+#[test]
+fn test_review_line_annotation_is_persisted_in_session_dir() {
+    let temp = tempdir().expect("tempdir");
+    let _guard = set_home_for_test(temp.path().to_path_buf());
+
+    let (mut session, session_id) = setup_session();
+    let dir = session_dir(&session_id).expect("session dir");
+    fs::create_dir_all(&dir).expect("create dir");
+    fs::write(
+        dir.join("feedback_1_claude.md"),
+        "## Issues\n- Missing tests\n- Unclear rollout",
+    )
+    .expect("write review");
+
+    assert!(session.toggle_review_modal(Path::new(".")));
+    session.toggle_review_line_selection(0);
+    session.review_cursor_down();
+    session.review_cursor_down();
+    session.start_review_annotation(AnnotationStance::Disagree);
+    for c in "rollout is documented".chars() {
+        session.review_annotation_insert_char(c);
+    }
+    session.submit_review_annotation();
+    session.close_review_modal();
+
+    let saved = crate::phases::feedback_items::load_review_annotations(&dir);
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].review_file, "feedback_1_claude.md");
+    assert_eq!(saved[0].line, 2);
+    assert_eq!(saved[0].feedback, "- Unclear rollout");
+    assert_eq!(saved[0].note, "rollout is documented");
+
+    assert!(session.toggle_review_modal(Path::new(".")));
+    assert!(session.review_line_annotation(2).is_some());
+    session.toggle_review_line_selection(2);
+    session.remove_review_annotation();
+    assert!(crate::phases::feedback_items::load_review_annotations(&dir).is_empty());
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+fn item(text: &str) -> FeedbackItem {
+    FeedbackItem {
+        reviewer: "claude".to_string(),
+        section: Some("Critical Issues".to_string()),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn test_triage_defaults_to_accept_and_advances_on_decision() {
+    let mut session = Session::new(0);
+    session.start_feedback_triage(vec![item("a"), item("b"), item("c")]);
+    assert_eq!(session.status, SessionStatus::AwaitingApproval);
+
+    session.set_feedback_triage_decision(TriageDecision::Park);
+    session.set_feedback_triage_decision(TriageDecision::Dismiss);
+    // Deciding on the last item keeps it selected
+    session.set_feedback_triage_decision(TriageDecision::Park);
+    session.set_feedback_triage_decision(TriageDecision::Accept);
+
+    assert_eq!(
+        session.submit_feedback_triage(),
+        Some(vec![
+            TriageDecision::Park,
+            TriageDecision::Dismiss,
+            TriageDecision::Accept
+        ])
+    );
+    assert!(session.feedback_triage.is_none());
+    assert_eq!(session.status, SessionStatus::Planning);
+    assert_eq!(session.submit_feedback_triage(), None);
+}
+
+#[test]
+fn test_triage_set_all_overrides_every_item() {
+    let mut session = Session::new(0);
+    session.start_feedback_triage(vec![item("a"), item("b")]);
+    session.feedback_triage_select_next();
+    session.set_feedback_triage_decision(TriageDecision::Dismiss);
+    session.set_all_feedback_triage_decisions(TriageDecision::Park);
+    assert_eq!(
+        session.submit_feedback_triage(),
+        Some(vec![TriageDecision::Park, TriageDecision::Park])
+    );
+}
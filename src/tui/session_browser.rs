@@ -5,12 +5,13 @@
 //! - View running and stopped sessions with live status updates
 //! - Resume stopped sessions in new tabs or terminals
 //! - Force-stop unresponsive sessions
-//! - Filter sessions by working directory
+//! - Filter sessions by working directory, or group them by repository
+//! - Mark several sessions and resume, archive, delete or force-stop them together
 
 use crate::session_daemon::{self, LivenessState, SessionRecord};
 use crate::session_daemon::{list_snapshots, SessionSnapshotInfo};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 /// Confirmation state for pending user actions.
@@ -23,6 +24,48 @@ pub enum ConfirmationState {
     },
     /// Confirm force-stopping a running/unresponsive session
     ForceStop { session_id: String },
+    /// Confirm a bulk action on the marked sessions
+    Bulk {
+        action: BulkAction,
+        session_ids: Vec<String>,
+        /// Marked sessions the action doesn't apply to
+        skipped: usize,
+    },
+}
+
+/// Actions that can be applied to several marked sessions at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    /// Resume each session in its own tab
+    Resume,
+    /// Export each session to `~/.planning-agent/archive/` and delete its snapshot
+    Archive,
+    /// Delete each session's snapshot (logs and plans stay on disk)
+    DeleteSnapshots,
+    /// Mark each live session as stopped
+    ForceStop,
+}
+
+impl BulkAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkAction::Resume => "Resume",
+            BulkAction::Archive => "Archive",
+            BulkAction::DeleteSnapshots => "Delete snapshots",
+            BulkAction::ForceStop => "Force-stop",
+        }
+    }
+
+    /// Whether the action can be applied to an entry.
+    pub fn applies_to(&self, entry: &SessionEntry) -> bool {
+        match self {
+            BulkAction::Resume => entry.is_resumable,
+            BulkAction::Archive | BulkAction::DeleteSnapshots => {
+                entry.has_snapshot && entry.liveness != LivenessState::Running
+            }
+            BulkAction::ForceStop => entry.is_live,
+        }
+    }
 }
 
 /// A session entry in the browser list, merging live and snapshot data.
@@ -46,6 +89,8 @@ pub struct SessionEntry {
     pub last_seen_relative: String,
     /// Working directory
     pub working_dir: PathBuf,
+    /// Repository containing the working directory (grouping key)
+    pub repository: PathBuf,
     /// Whether the snapshot is from the current working directory
     pub is_current_dir: bool,
     /// Whether this entry has a snapshot file (required for resume)
@@ -72,6 +117,7 @@ impl SessionEntry {
             last_seen_at: snapshot.saved_at.clone(),
             last_seen_relative,
             working_dir: snapshot.working_dir.clone(),
+            repository: repository_root(&snapshot.working_dir),
             is_current_dir,
             has_snapshot: true,
             is_resumable: true, // Snapshots are always resumable
@@ -94,6 +140,7 @@ impl SessionEntry {
             last_seen_at: record.last_heartbeat_at.clone(),
             last_seen_relative,
             working_dir: record.working_dir.clone(),
+            repository: repository_root(&record.working_dir),
             is_current_dir,
             has_snapshot,
             is_resumable,
@@ -101,6 +148,34 @@ impl SessionEntry {
             is_live: true,
         }
     }
+
+    /// Shell command that resumes this session from any directory.
+    pub fn resume_command(&self) -> String {
+        format!(
+            "planning --working-dir {} --resume-session {}",
+            shell_quote(&self.working_dir.display().to_string()),
+            self.session_id
+        )
+    }
+}
+
+/// Returns the nearest ancestor of `dir` that is a git checkout, or `dir` itself.
+pub fn repository_root(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .unwrap_or(dir)
+        .to_path_buf()
+}
+
+fn shell_quote(arg: &str) -> String {
+    let is_plain = arg
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "/._-+:@".contains(c));
+    if is_plain && !arg.is_empty() {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
 }
 
 /// Formats a timestamp as relative time (e.g., "2m ago", "1h ago").
@@ -153,6 +228,8 @@ pub struct SessionBrowserState {
     pub scroll_offset: usize,
     /// Filter: show only current directory sessions
     pub filter_current_dir: bool,
+    /// Global view: sessions from every directory, grouped by repository
+    pub group_by_repo: bool,
     /// Error message if loading failed
     pub error: Option<String>,
     /// Whether we're in the process of resuming
@@ -169,6 +246,10 @@ pub struct SessionBrowserState {
     pub daemon_connected: bool,
     /// Current working directory (cached for filtering)
     pub current_working_dir: PathBuf,
+    /// Session IDs marked for a bulk action
+    pub marked: HashSet<String>,
+    /// Whether a bulk archive/delete is running
+    pub bulk_in_progress: bool,
 }
 
 impl Default for SessionBrowserState {
@@ -179,6 +260,7 @@ impl Default for SessionBrowserState {
             selected_idx: 0,
             scroll_offset: 0,
             filter_current_dir: false,
+            group_by_repo: false,
             error: None,
             resuming: false,
             confirmation_pending: None,
@@ -187,6 +269,8 @@ impl Default for SessionBrowserState {
             exporting_zip: false,
             daemon_connected: false,
             current_working_dir: PathBuf::new(),
+            marked: HashSet::new(),
+            bulk_in_progress: false,
         }
     }
 }
@@ -340,10 +424,12 @@ impl SessionBrowserState {
         error: Option<String>,
     ) {
         self.entries = entries;
+        self.sort_entries();
         self.daemon_connected = daemon_connected;
         self.error = error;
         self.loading = false;
         self.last_refresh_at = Some(Instant::now());
+        self.prune_marks();
 
         // Ensure selection is still valid
         let filtered_len = self.filtered_entries().len();
@@ -353,9 +439,13 @@ impl SessionBrowserState {
         self.ensure_visible();
     }
 
-    /// Sort entries by liveness and last_seen.
+    /// Sort entries by liveness and last_seen (by repository first when grouped).
     fn sort_entries(&mut self) {
+        let group_by_repo = self.group_by_repo;
         self.entries.sort_by(|a, b| {
+            if group_by_repo && a.repository != b.repository {
+                return a.repository.cmp(&b.repository);
+            }
             let liveness_order = |l: &LivenessState| match l {
                 LivenessState::Running => 0,
                 LivenessState::Unresponsive => 1,
@@ -380,6 +470,8 @@ impl SessionBrowserState {
         self.confirmation_pending = None;
         self.loading = false;
         self.exporting_zip = false;
+        self.marked.clear();
+        self.bulk_in_progress = false;
     }
 
     /// Returns the filtered list of entries based on current filter settings.
@@ -418,6 +510,10 @@ impl SessionBrowserState {
     /// Toggles the current directory filter.
     pub fn toggle_filter(&mut self) {
         self.filter_current_dir = !self.filter_current_dir;
+        if self.filter_current_dir {
+            self.group_by_repo = false;
+            self.sort_entries();
+        }
         // Reset selection if it would be out of bounds
         let entries = self.filtered_entries();
         if self.selected_idx >= entries.len() {
@@ -427,6 +523,17 @@ impl SessionBrowserState {
         self.ensure_visible();
     }
 
+    /// Toggles the global view grouped by repository (clears the directory filter).
+    pub fn toggle_group_by_repo(&mut self) {
+        self.group_by_repo = !self.group_by_repo;
+        if self.group_by_repo {
+            self.filter_current_dir = false;
+        }
+        self.sort_entries();
+        self.selected_idx = 0;
+        self.scroll_offset = 0;
+    }
+
     /// Returns the currently selected entry, if any.
     pub fn selected_entry(&self) -> Option<&SessionEntry> {
         let entries = self.filtered_entries();
@@ -477,6 +584,9 @@ impl SessionBrowserState {
             entry.liveness = record.liveness;
             entry.last_seen_at = record.last_heartbeat_at.clone();
             entry.last_seen_relative = format_relative_time(&record.last_heartbeat_at);
+            if entry.working_dir != record.working_dir {
+                entry.repository = repository_root(&record.working_dir);
+            }
             entry.working_dir = record.working_dir.clone();
             entry.is_current_dir = is_current_dir;
             entry.is_resumable = entry.has_snapshot && record.liveness != LivenessState::Running;
@@ -511,6 +621,78 @@ impl SessionBrowserState {
         });
     }
 
+    /// Toggles the mark on the selected entry and moves to the next one.
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(session_id) = self.selected_entry().map(|e| e.session_id.clone()) else {
+            return;
+        };
+        if !self.marked.remove(&session_id) {
+            self.marked.insert(session_id);
+        }
+        let len = self.filtered_entries().len();
+        if self.selected_idx + 1 < len {
+            self.selected_idx += 1;
+            self.ensure_visible();
+        }
+    }
+
+    /// Clears all marks.
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    /// Returns true if the session is marked for a bulk action.
+    pub fn is_marked(&self, session_id: &str) -> bool {
+        self.marked.contains(session_id)
+    }
+
+    /// Drops marks for sessions that are no longer listed.
+    fn prune_marks(&mut self) {
+        let entries = &self.entries;
+        self.marked
+            .retain(|id| entries.iter().any(|e| &e.session_id == id));
+    }
+
+    /// Returns the marked sessions the action applies to, plus how many were skipped.
+    ///
+    /// Without marks, the selected entry is the only candidate.
+    pub fn bulk_targets(&self, action: BulkAction) -> (Vec<String>, usize) {
+        let candidates: Vec<&SessionEntry> = if self.marked.is_empty() {
+            self.selected_entry().into_iter().collect()
+        } else {
+            self.entries
+                .iter()
+                .filter(|e| self.marked.contains(&e.session_id))
+                .collect()
+        };
+        let targets: Vec<String> = candidates
+            .iter()
+            .filter(|e| action.applies_to(e))
+            .map(|e| e.session_id.clone())
+            .collect();
+        let skipped = candidates.len() - targets.len();
+        (targets, skipped)
+    }
+
+    /// Start a confirmation dialog for a bulk action.
+    ///
+    /// Sets an error instead if the action applies to none of the candidates.
+    pub fn start_bulk_confirmation(&mut self, action: BulkAction) {
+        let (session_ids, skipped) = self.bulk_targets(action);
+        if session_ids.is_empty() {
+            self.error = Some(format!(
+                "{}: no applicable sessions selected",
+                action.label()
+            ));
+            return;
+        }
+        self.confirmation_pending = Some(ConfirmationState::Bulk {
+            action,
+            session_ids,
+            skipped,
+        });
+    }
+
     /// Cancel the pending confirmation.
     pub fn cancel_confirmation(&mut self) {
         self.confirmation_pending = None;
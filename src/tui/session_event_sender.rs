@@ -3,12 +3,21 @@
 //! This module provides `SessionEventSender` which wraps the event channel
 //! to automatically inject session IDs into all events.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch};
 
+use crate::agents::cost_stats::InvocationUsage;
+use crate::agents::permissions::ToolPermissionRequest;
+use crate::agents::resources::ProcessUsage;
 use crate::app::workflow_decisions::IterativePhase;
+use crate::app::workspace_trust::WorkspaceTrustRequest;
+use crate::domain::failure::FailureContext;
+use crate::domain::review::FeedbackItem;
+use crate::domain::types::CompletionActionOutcome;
 use crate::domain::view::WorkflowView;
 use crate::tui::session::{CliInstanceId, ReviewKind, TodoItem, ToolResultSummary};
 
@@ -22,6 +31,51 @@ pub struct SessionEventSender {
     inner: mpsc::UnboundedSender<Event>,
     /// Monotonic counter for generating unique CLI instance IDs per session.
     cli_instance_counter: Arc<AtomicU64>,
+    /// Usage of every agent invocation in this run, folded into cost stats on completion.
+    usage: Arc<Mutex<Vec<InvocationUsage>>>,
+    /// Cost so far of each round with a `cost_alerts` threshold, keyed by round label.
+    round_costs: Arc<Mutex<HashMap<String, f64>>>,
+    /// Set on senders handed to summary agents: streamed text goes to this run tab's
+    /// Summary panel instead of its chat.
+    summary_phase: Option<String>,
+    /// Phase timings and reviewer rejections of this run, for the completion metrics.
+    run_stats: Arc<Mutex<RunStats>>,
+    /// Agent failures to record as workflow events (set by the workflow run).
+    failure_tx: Option<mpsc::UnboundedSender<FailureContext>>,
+    /// The approval prompt the run waits on, for matching answers relayed by the host.
+    approval_requests: Arc<ApprovalRequests>,
+    /// Told of each finished tool call (set for the planner under `reviewing.pipelined`).
+    tool_finished_tx: Option<mpsc::UnboundedSender<()>>,
+}
+
+/// Numbers the approval prompts of a run; at most one is pending at a time.
+struct ApprovalRequests {
+    last_id: AtomicU64,
+    pending: watch::Sender<Option<u64>>,
+}
+
+/// Wall-clock time per phase and rejection count of one workflow run.
+#[derive(Clone, Default)]
+struct RunStats {
+    current_phase: Option<(String, Instant)>,
+    /// Totals in first-started order; a phase's time runs until the next one starts.
+    phase_times: Vec<(String, Duration)>,
+    reviewer_rejections: u32,
+    /// Invocations that had to wait for a `provider_concurrency` slot.
+    provider_slot_waits: u32,
+}
+
+impl RunStats {
+    fn close_current_phase(&mut self, now: Instant) {
+        let Some((phase, started)) = self.current_phase.take() else {
+            return;
+        };
+        let elapsed = now.saturating_duration_since(started);
+        match self.phase_times.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, total)) => *total += elapsed,
+            None => self.phase_times.push((phase, elapsed)),
+        }
+    }
 }
 
 /// Some methods may not be used in all code paths but are part of the
@@ -40,9 +94,116 @@ impl SessionEventSender {
             run_id,
             inner: sender,
             cli_instance_counter: Arc::new(AtomicU64::new(0)),
+            usage: Arc::new(Mutex::new(Vec::new())),
+            round_costs: Arc::new(Mutex::new(HashMap::new())),
+            summary_phase: None,
+            run_stats: Arc::new(Mutex::new(RunStats::default())),
+            failure_tx: None,
+            approval_requests: Arc::new(ApprovalRequests {
+                last_id: AtomicU64::new(0),
+                pending: watch::Sender::new(None),
+            }),
+            tool_finished_tx: None,
         }
     }
 
+    /// A sender that passes failures from [`Self::record_failure`] to `failure_tx`.
+    pub fn with_failure_recorder(self, failure_tx: mpsc::UnboundedSender<FailureContext>) -> Self {
+        Self {
+            failure_tx: Some(failure_tx),
+            ..self
+        }
+    }
+
+    /// A sender that also tells `tool_finished_tx` of each finished tool call, so
+    /// files the agent writes can be read as soon as they may have changed.
+    pub fn with_tool_watcher(self, tool_finished_tx: mpsc::UnboundedSender<()>) -> Self {
+        Self {
+            tool_finished_tx: Some(tool_finished_tx),
+            ..self
+        }
+    }
+
+    /// Records an agent failure as a `FailureRecorded` event of the workflow.
+    /// Outside a workflow run (e.g. implementation follow-ups) it is dropped.
+    pub fn record_failure(&self, failure: FailureContext) {
+        if let Some(tx) = &self.failure_tx {
+            let _ = tx.send(failure);
+        }
+    }
+
+    /// A sender for the agent generating `phase`'s summary.
+    pub fn for_summary(&self, phase: String) -> Self {
+        Self {
+            summary_phase: Some(phase),
+            ..self.clone()
+        }
+    }
+
+    /// Records a finished agent invocation for the session's usage ledger.
+    pub fn record_invocation_usage(&self, usage: InvocationUsage) {
+        if let Ok(mut ledger) = self.usage.lock() {
+            ledger.push(usage);
+        }
+    }
+
+    /// Returns the invocations recorded so far.
+    pub fn invocation_usage(&self) -> Vec<InvocationUsage> {
+        self.usage
+            .lock()
+            .map(|ledger| ledger.clone())
+            .unwrap_or_default()
+    }
+
+    /// Wall-clock time spent in each phase so far, including the running one.
+    pub fn phase_times(&self) -> Vec<(String, Duration)> {
+        let Ok(stats) = self.run_stats.lock() else {
+            return Vec::new();
+        };
+        let mut stats = stats.clone();
+        stats.close_current_phase(Instant::now());
+        stats.phase_times
+    }
+
+    /// Number of reviewer verdicts that rejected the plan in this run.
+    pub fn reviewer_rejections(&self) -> u32 {
+        self.run_stats
+            .lock()
+            .map(|stats| stats.reviewer_rejections)
+            .unwrap_or(0)
+    }
+
+    /// Number of agent invocations in this run that waited for a provider slot.
+    pub fn provider_slot_waits(&self) -> u32 {
+        self.run_stats
+            .lock()
+            .map(|stats| stats.provider_slot_waits)
+            .unwrap_or(0)
+    }
+
+    /// Adds an invocation's cost to its round, warning once when the round's
+    /// total passes `threshold`. The workflow is not interrupted.
+    pub fn record_round_cost(&self, round: &str, cost: f64, threshold: f64) {
+        let Ok(mut costs) = self.round_costs.lock() else {
+            return;
+        };
+        let total = costs.entry(round.to_string()).or_insert(0.0);
+        *total += cost;
+        if *total <= threshold || *total - cost > threshold {
+            return;
+        }
+        let message = format!(
+            "{} has cost ${:.2}, over its ${:.2} alert",
+            round, total, threshold
+        );
+        drop(costs);
+        self.send_output(format!("[planning] Cost alert: {}", message));
+        let _ = self.inner.send(Event::SessionCostAlert {
+            session_id: self.session_id,
+            message,
+        });
+    }
+
     pub fn send_output(&self, line: String) {
         let _ = self.inner.send(Event::SessionOutput {
             session_id: self.session_id,
@@ -66,6 +227,7 @@ impl SessionEventSender {
     }
 
     pub fn send_approval_request(&self, summary: String) {
+        self.open_approval_request();
         let _ = self.inner.send(Event::SessionApprovalRequest {
             session_id: self.session_id,
             summary,
@@ -73,6 +235,7 @@ impl SessionEventSender {
     }
 
     pub fn send_review_decision_request(&self, summary: String) {
+        self.open_approval_request();
         let _ = self.inner.send(Event::SessionReviewDecisionRequest {
             session_id: self.session_id,
             summary,
@@ -105,6 +268,9 @@ impl SessionEventSender {
     }
 
     pub fn send_tool_finished(&self, tool_id: Option<String>, agent_name: String) {
+        if let Some(tx) = &self.tool_finished_tx {
+            let _ = tx.send(());
+        }
         let _ = self.inner.send(Event::SessionToolFinished {
             session_id: self.session_id,
             tool_id,
@@ -120,6 +286,11 @@ impl SessionEventSender {
     }
 
     pub fn send_phase_started(&self, phase: String) {
+        if let Ok(mut stats) = self.run_stats.lock() {
+            let now = Instant::now();
+            stats.close_current_phase(now);
+            stats.current_phase = Some((phase.clone(), now));
+        }
         let _ = self.inner.send(Event::SessionPhaseStarted {
             session_id: self.session_id,
             phase,
@@ -157,6 +328,30 @@ impl SessionEventSender {
         });
     }
 
+    /// Ask the user to allow or deny a high-risk tool call.
+    pub fn send_tool_permission_request(&self, request: ToolPermissionRequest) {
+        let _ = self.inner.send(Event::SessionToolPermissionRequest {
+            session_id: self.session_id,
+            request,
+        });
+    }
+
+    /// Ask the user whether to trust the repository implementation runs in.
+    pub fn send_workspace_trust_request(&self, request: WorkspaceTrustRequest) {
+        let _ = self.inner.send(Event::SessionWorkspaceTrustRequest {
+            session_id: self.session_id,
+            request,
+        });
+    }
+
+    /// Ask the user to triage a rejected round's feedback items.
+    pub fn send_feedback_triage_request(&self, items: Vec<FeedbackItem>) {
+        let _ = self.inner.send(Event::SessionFeedbackTriageRequest {
+            session_id: self.session_id,
+            items,
+        });
+    }
+
     pub fn send_stop_reason(&self, reason: String) {
         let _ = self.inner.send(Event::SessionStopReason {
             session_id: self.session_id,
@@ -204,6 +399,11 @@ impl SessionEventSender {
         summary: String,
         duration_ms: u64,
     ) {
+        if !approved {
+            if let Ok(mut stats) = self.run_stats.lock() {
+                stats.reviewer_rejections += 1;
+            }
+        }
         let _ = self.inner.send(Event::SessionReviewerCompleted {
             session_id: self.session_id,
             kind,
@@ -231,6 +431,22 @@ impl SessionEventSender {
         });
     }
 
+    pub fn send_reviewer_substituted(
+        &self,
+        kind: ReviewKind,
+        round: u32,
+        failed_id: String,
+        substitute_id: String,
+    ) {
+        let _ = self.inner.send(Event::SessionReviewerSubstituted {
+            session_id: self.session_id,
+            kind,
+            round,
+            failed_id,
+            substitute_id,
+        });
+    }
+
     pub fn send_review_round_completed(&self, kind: ReviewKind, round: u32, approved: bool) {
         let _ = self.inner.send(Event::SessionReviewRoundCompleted {
             session_id: self.session_id,
@@ -249,6 +465,7 @@ impl SessionEventSender {
 
     /// Sends a max iterations reached event to trigger the decision modal.
     pub fn send_max_iterations_reached(&self, phase: IterativePhase, summary: String) {
+        self.open_approval_request();
         let _ = self.inner.send(Event::SessionMaxIterationsReached {
             session_id: self.session_id,
             phase,
@@ -257,6 +474,7 @@ impl SessionEventSender {
     }
 
     pub fn send_user_override_approval(&self, summary: String) {
+        self.open_approval_request();
         let _ = self.inner.send(Event::SessionUserOverrideApproval {
             session_id: self.session_id,
             summary,
@@ -272,6 +490,28 @@ impl SessionEventSender {
         });
     }
 
+    pub fn send_partial_text(&self, agent_name: String, phase: String, delta: Option<String>) {
+        if let Some(summary_phase) = &self.summary_phase {
+            // The finished summary arrives with the ready event, so the end of the
+            // stream needs no event of its own
+            if let Some(delta) = delta {
+                let _ = self.inner.send(Event::SessionRunTabSummaryDelta {
+                    session_id: self.session_id,
+                    phase: summary_phase.clone(),
+                    delta,
+                    run_id: self.run_id,
+                });
+            }
+            return;
+        }
+        let _ = self.inner.send(Event::SessionPartialText {
+            session_id: self.session_id,
+            agent_name,
+            phase,
+            delta,
+        });
+    }
+
     pub fn send_todos_update(&self, agent_name: String, todos: Vec<TodoItem>) {
         let _ = self.inner.send(Event::SessionTodosUpdate {
             session_id: self.session_id,
@@ -280,11 +520,12 @@ impl SessionEventSender {
         });
     }
 
-    pub fn send_run_tab_summary_generating(&self, phase: String) {
+    pub fn send_run_tab_summary_generating(&self, phase: String, cancel_tx: watch::Sender<bool>) {
         let _ = self.inner.send(Event::SessionRunTabSummaryGenerating {
             session_id: self.session_id,
             phase,
             run_id: self.run_id,
+            cancel_tx,
         });
     }
 
@@ -339,6 +580,15 @@ impl SessionEventSender {
         });
     }
 
+    /// Sends the latest CPU and memory sample of a CLI instance.
+    pub fn send_cli_instance_resources(&self, id: CliInstanceId, usage: ProcessUsage) {
+        let _ = self.inner.send(Event::SessionCliInstanceResources {
+            session_id: self.session_id,
+            id,
+            usage,
+        });
+    }
+
     /// Sends a CLI instance finished event.
     pub fn send_cli_instance_finished(&self, id: CliInstanceId) {
         let _ = self.inner.send(Event::SessionCliInstanceFinished {
@@ -347,6 +597,19 @@ impl SessionEventSender {
         });
     }
 
+    /// Sends a CLI instance queued event; finish the instance once a slot is free.
+    pub fn send_cli_instance_queued(&self, id: CliInstanceId, agent_name: String) {
+        if let Ok(mut stats) = self.run_stats.lock() {
+            stats.provider_slot_waits += 1;
+        }
+        let _ = self.inner.send(Event::SessionCliInstanceQueued {
+            session_id: self.session_id,
+            id,
+            agent_name,
+            queued_at: Instant::now(),
+        });
+    }
+
     /// Sends an implementation success event to trigger the success modal.
     pub fn send_implementation_success(&self, iterations_used: u32) {
         let _ = self.inner.send(Event::SessionImplementationSuccess {
@@ -355,6 +618,92 @@ impl SessionEventSender {
         });
     }
 
+    /// Sends the stderr tail of a failed agent invocation.
+    pub fn send_agent_stderr(
+        &self,
+        agent_name: String,
+        lines: Vec<String>,
+        log_path: Option<PathBuf>,
+    ) {
+        let _ = self.inner.send(Event::SessionAgentStderr {
+            session_id: self.session_id,
+            agent_name,
+            lines,
+            log_path,
+        });
+    }
+
+    /// Sends the completion action outcomes to show in the completion summary.
+    pub fn send_completion_summary(
+        &self,
+        metrics: Vec<String>,
+        outcomes: Vec<CompletionActionOutcome>,
+    ) {
+        let _ = self.inner.send(Event::SessionCompletionSummary {
+            session_id: self.session_id,
+            metrics,
+            outcomes,
+        });
+    }
+
+    /// Sends that another process took the session over.
+    pub fn send_following(&self, holder_pid: u32) {
+        let _ = self.inner.send(Event::SessionFollowing {
+            session_id: self.session_id,
+            holder_pid,
+        });
+    }
+
+    /// Numbers a new approval prompt, replacing any that was left unanswered.
+    fn open_approval_request(&self) {
+        let requests = &self.approval_requests;
+        let id = requests.last_id.fetch_add(1, Ordering::Relaxed) + 1;
+        requests.pending.send_replace(Some(id));
+    }
+
+    /// The approval prompt the run waits on, if any.
+    pub fn pending_approval_request(&self) -> Option<u64> {
+        *self.approval_requests.pending.borrow()
+    }
+
+    /// Follows the pending approval prompt as prompts open and get answered.
+    pub fn watch_approval_request(&self) -> watch::Receiver<Option<u64>> {
+        self.approval_requests.pending.subscribe()
+    }
+
+    /// Marks the pending approval prompt as answered (e.g. from the TUI).
+    pub fn close_approval_request(&self) {
+        self.approval_requests
+            .pending
+            .send_if_modified(|pending| pending.take().is_some());
+    }
+
+    /// Marks `request` as answered; false if it is not the pending prompt.
+    pub fn take_approval_request(&self, request: u64) -> bool {
+        self.approval_requests.pending.send_if_modified(|pending| {
+            let matches = *pending == Some(request);
+            if matches {
+                *pending = None;
+            }
+            matches
+        })
+    }
+
+    /// Sends that the host GUI answered the approval prompt.
+    pub fn send_remote_answer(&self) {
+        let _ = self.inner.send(Event::SessionRemoteAnswer {
+            session_id: self.session_id,
+        });
+    }
+
+    /// Sends a warning that the current phase ran past its time limit.
+    pub fn send_phase_duration_exceeded(&self, limit_minutes: u64) {
+        let _ = self.inner.send(Event::SessionPhaseDurationExceeded {
+            session_id: self.session_id,
+            limit_minutes,
+        });
+    }
+
     /// Sends an implementation interaction finished event.
     pub fn send_implementation_interaction_finished(&self) {
         let _ = self
@@ -23,6 +23,10 @@ pub const SLASH_COMMANDS: &[SlashCommandInfo] = &[
         command: "/update",
         description: "Install an available update",
     },
+    SlashCommandInfo {
+        command: "/config",
+        description: "Edit the effective workflow config",
+    },
     SlashCommandInfo {
         command: "/config-dangerous",
         description: "Configure CLI tools to bypass approvals",
@@ -31,6 +35,10 @@ pub const SLASH_COMMANDS: &[SlashCommandInfo] = &[
         command: "/sessions",
         description: "View and resume workflow sessions",
     },
+    SlashCommandInfo {
+        command: "/recent",
+        description: "Switch to a recently used tab (also Ctrl+Tab)",
+    },
     SlashCommandInfo {
         command: "/max-iterations",
         description: "Set max iterations (e.g., /max-iterations 5)",
@@ -43,6 +51,10 @@ pub const SLASH_COMMANDS: &[SlashCommandInfo] = &[
         command: "/parallel",
         description: "Enable parallel review mode",
     },
+    SlashCommandInfo {
+        command: "/review-mode",
+        description: "Review mode: /review-mode sequential or /review-mode parallel",
+    },
     SlashCommandInfo {
         command: "/aggregation",
         description: "Set aggregation: any-rejects, all-reject, majority",
@@ -51,6 +63,14 @@ pub const SLASH_COMMANDS: &[SlashCommandInfo] = &[
         command: "/workflow",
         description: "Select workflow configuration",
     },
+    SlashCommandInfo {
+        command: "/reviewers",
+        description: "Select a reviewer preset (e.g., /reviewers thorough)",
+    },
+    SlashCommandInfo {
+        command: "/agent-arg",
+        description: "Extra agent args for new runs (e.g., /agent-arg claude=--model opus-4)",
+    },
 ];
 
 /// Commands that support dynamic argument completion.
@@ -1,8 +1,15 @@
+use super::config_editor::ConfigEditorState;
 use super::file_index::FileIndex;
-use super::session::Session;
+use super::session::{ApprovalContext, ApprovalMode, Session};
 use super::session_browser::SessionBrowserState;
 use super::workflow_browser::WorkflowBrowserState;
+use crate::config::AgentArgs;
 use crate::update::{UpdateStatus, VersionInfo};
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 pub struct TabManager {
     pub sessions: Vec<Session>,
@@ -40,8 +47,323 @@ pub struct TabManager {
     /// Workflow browser overlay state
     pub workflow_browser: WorkflowBrowserState,
 
+    /// Config editor overlay state
+    pub config_editor: ConfigEditorState,
+
     /// Whether the session daemon is connected (for footer status indicator)
     pub daemon_connected: bool,
+
+    /// Cost estimate shown when naming a new tab (None until computed for the current workflow)
+    pub cost_estimate: Option<String>,
+
+    /// Max iterations set via `/max-iterations` for new workflows (overrides `--max-iterations`)
+    pub max_iterations_override: Option<u32>,
+    /// Agent arguments added via `/agent-arg` for new workflows (after `--agent-arg`)
+    pub agent_args_override: Vec<AgentArgs>,
+
+    /// Sessions suspended after idling at an approval prompt, most recent last (Ctrl+R resumes)
+    pub suspended_sessions: Vec<SuspendedSession>,
+
+    /// Recorded keyboard macros (`q<register>` records, `@<register>` replays)
+    pub macros: KeyMacros,
+
+    /// Events from background tabs (`n` opens the list)
+    pub notifications: NotificationCenter,
+    /// Ctrl+Tab switcher over tabs in most-recently-used order.
+    pub recent_tabs: RecentTabs,
+    /// Plan approvals pending across tabs (`a` opens the list)
+    pub approval_queue: ApprovalQueue,
+}
+
+/// A session that was snapshotted and stopped after sitting idle at an approval prompt.
+#[derive(Debug, Clone)]
+pub struct SuspendedSession {
+    pub feature_name: String,
+    pub session_id: String,
+    pub working_dir: PathBuf,
+}
+
+/// How long a `q` or `@` waits for its register key before it is handled as typed.
+pub const MACRO_PREFIX_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Keys to handle after macro processing.
+#[derive(Debug, PartialEq)]
+pub enum MacroKeys {
+    /// Handle these keys in order, as if they were typed.
+    Dispatch(Vec<KeyEvent>),
+    /// The key started or stopped a recording, or awaits its register key.
+    Consumed,
+}
+
+/// Keyboard macros for repeating key sequences across tabs.
+///
+/// `q` followed by a register (`a`-`z` except `q`, or `0`-`9`) records the keys
+/// that follow until the next `q`; `@` followed by a register replays them. A
+/// `q` or `@` not followed by a register key is handled as typed, so a lone `q`
+/// still quits once [`MACRO_PREFIX_TIMEOUT`] passes.
+#[derive(Debug, Default)]
+pub struct KeyMacros {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    recording: Option<(char, Vec<KeyEvent>)>,
+    pending: Option<(KeyEvent, Instant)>,
+}
+
+impl KeyMacros {
+    /// The register being recorded into, if any.
+    pub fn recording_register(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    /// Processes a typed key. With `commands_allowed` false (while the user types
+    /// text), `q` and `@` are ordinary characters; keys are still recorded.
+    pub fn handle_key(&mut self, key: KeyEvent, commands_allowed: bool) -> MacroKeys {
+        if let Some((prefix, _)) = self.pending.take() {
+            let Some(register) = macro_register(&key) else {
+                return self.dispatch(vec![prefix, key]);
+            };
+            if prefix.code == KeyCode::Char('q') {
+                self.recording = Some((register, Vec::new()));
+                return MacroKeys::Consumed;
+            }
+            let keys = self.registers.get(&register).cloned().unwrap_or_default();
+            return self.dispatch(keys);
+        }
+
+        let plain = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char('q') if commands_allowed && plain => {
+                match self.recording.take() {
+                    Some((register, keys)) => {
+                        self.registers.insert(register, keys);
+                    }
+                    None => self.pending = Some((key, Instant::now())),
+                }
+                MacroKeys::Consumed
+            }
+            KeyCode::Char('@') if commands_allowed && plain => {
+                self.pending = Some((key, Instant::now()));
+                MacroKeys::Consumed
+            }
+            _ => self.dispatch(vec![key]),
+        }
+    }
+
+    /// Whether a `q` or `@` is waiting for its register key.
+    pub fn has_pending_prefix(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Returns a `q` or `@` whose register key did not arrive in time, to be
+    /// handled as typed.
+    pub fn take_expired_prefix(&mut self, now: Instant) -> Option<KeyEvent> {
+        let (_, since) = self.pending?;
+        if now.duration_since(since) < MACRO_PREFIX_TIMEOUT {
+            return None;
+        }
+        let (prefix, _) = self.pending.take()?;
+        if let Some((_, recorded)) = &mut self.recording {
+            recorded.push(prefix);
+        }
+        Some(prefix)
+    }
+
+    fn dispatch(&mut self, keys: Vec<KeyEvent>) -> MacroKeys {
+        if let Some((_, recorded)) = &mut self.recording {
+            recorded.extend(keys.iter().copied());
+        }
+        MacroKeys::Dispatch(keys)
+    }
+}
+
+/// Most notifications kept; older ones are dropped.
+pub const MAX_NOTIFICATIONS: usize = 100;
+
+/// What happened in a background tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    ApprovalRequired,
+    PhaseComplete,
+    Error,
+    BudgetAlert,
+}
+
+impl NotificationKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationKind::ApprovalRequired => "Approval",
+            NotificationKind::PhaseComplete => "Phase",
+            NotificationKind::Error => "Error",
+            NotificationKind::BudgetAlert => "Budget",
+        }
+    }
+}
+
+/// An event from a tab the user was not looking at.
+#[derive(Debug, Clone)]
+pub struct TabNotification {
+    pub session_id: usize,
+    pub tab_name: String,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub at: DateTime<Local>,
+}
+
+/// Collects background tab events for the notification overlay, newest first.
+///
+/// Opening the overlay marks everything read. The newest notification since the
+/// main loop last asked is also kept for the terminal bell or desktop alert.
+#[derive(Debug, Default)]
+pub struct NotificationCenter {
+    entries: VecDeque<TabNotification>,
+    unread: usize,
+    pending_alert: Option<TabNotification>,
+    pub open: bool,
+    pub selected_idx: usize,
+}
+
+impl NotificationCenter {
+    pub fn push(&mut self, notification: TabNotification) {
+        self.pending_alert = Some(notification.clone());
+        self.entries.push_front(notification);
+        self.entries.truncate(MAX_NOTIFICATIONS);
+        self.unread = (self.unread + 1).min(self.entries.len());
+    }
+
+    /// Notifications, newest first.
+    pub fn entries(&self) -> impl Iterator<Item = &TabNotification> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn unread(&self) -> usize {
+        self.unread
+    }
+
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open = true;
+            self.selected_idx = 0;
+            self.unread = 0;
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected_idx + 1 < self.entries.len() {
+            self.selected_idx += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected_idx = self.selected_idx.saturating_sub(1);
+    }
+
+    pub fn selected_entry(&self) -> Option<&TabNotification> {
+        self.entries.get(self.selected_idx)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.unread = 0;
+        self.selected_idx = 0;
+    }
+
+    /// The newest notification not yet alerted on, if any.
+    pub fn take_alert(&mut self) -> Option<TabNotification> {
+        self.pending_alert.take()
+    }
+}
+
+/// State of the approval queue overlay. The pending approvals themselves are
+/// read from the tabs each time, so answering one in its own tab drops it here too.
+#[derive(Debug, Default)]
+pub struct ApprovalQueue {
+    pub open: bool,
+    pub selected_idx: usize,
+    /// Feedback being typed for declining the selected plan
+    pub decline_input: Option<String>,
+}
+
+impl ApprovalQueue {
+    pub fn close(&mut self) {
+        self.open = false;
+        self.decline_input = None;
+    }
+
+    pub fn select_next(&mut self, len: usize) {
+        if self.selected_idx + 1 < len {
+            self.selected_idx += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected_idx = self.selected_idx.saturating_sub(1);
+    }
+
+    /// Keeps the selection on the list after entries were answered.
+    pub fn clamp(&mut self, len: usize) {
+        self.selected_idx = self.selected_idx.min(len.saturating_sub(1));
+    }
+}
+
+/// Tabs in most-recently-used order and the state of their switcher overlay.
+#[derive(Debug, Default)]
+pub struct RecentTabs {
+    /// Session IDs, most recently active first
+    order: Vec<usize>,
+    pub open: bool,
+    pub selected_idx: usize,
+}
+
+impl RecentTabs {
+    /// Moves the session to the front. The main loop calls this with the active
+    /// tab every frame, so every way of switching tabs counts as a use.
+    pub fn touch(&mut self, session_id: usize) {
+        if self.order.first() != Some(&session_id) {
+            self.order.retain(|id| *id != session_id);
+            self.order.insert(0, session_id);
+        }
+    }
+
+    /// Moves the selection down, wrapping around like an IDE's tab switcher.
+    pub fn select_next(&mut self, len: usize) {
+        if len > 0 {
+            self.selected_idx = (self.selected_idx + 1) % len;
+        }
+    }
+
+    pub fn select_prev(&mut self, len: usize) {
+        if len > 0 {
+            self.selected_idx = (self.selected_idx + len - 1) % len;
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+}
+
+fn macro_register(key: &KeyEvent) -> Option<char> {
+    match key.code {
+        KeyCode::Char(c)
+            if (c.is_ascii_lowercase() || c.is_ascii_digit())
+                && c != 'q'
+                && !key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            Some(c)
+        }
+        _ => None,
+    }
 }
 
 /// TabManager provides the full API surface for multi-tab management.
@@ -64,7 +386,16 @@ impl TabManager {
             version_info: None,
             session_browser: SessionBrowserState::new(),
             workflow_browser: WorkflowBrowserState::new(),
+            config_editor: ConfigEditorState::new(),
             daemon_connected: false,
+            cost_estimate: None,
+            max_iterations_override: None,
+            agent_args_override: Vec::new(),
+            suspended_sessions: Vec::new(),
+            macros: KeyMacros::default(),
+            notifications: NotificationCenter::default(),
+            recent_tabs: RecentTabs::default(),
+            approval_queue: ApprovalQueue::default(),
         };
 
         manager.add_session();
@@ -75,6 +406,8 @@ impl TabManager {
         let id = self.next_id;
         self.next_id += 1;
         self.sessions.push(Session::new(id));
+        // Completed sessions update the cost stats; re-estimate for the new tab
+        self.cost_estimate = None;
         let idx = self.sessions.len() - 1;
         self.active_tab = idx;
         &mut self.sessions[idx]
@@ -160,6 +493,93 @@ impl TabManager {
     pub fn sessions_mut(&mut self) -> impl Iterator<Item = &mut Session> {
         self.sessions.iter_mut()
     }
+
+    /// Records an event for the notification center, unless it happened in the
+    /// active tab, where the user already sees it.
+    pub fn notify(&mut self, session_id: usize, kind: NotificationKind, message: String) {
+        let Some(index) = self.sessions.iter().position(|s| s.id == session_id) else {
+            return;
+        };
+        if index == self.active_tab {
+            return;
+        }
+        let session = &self.sessions[index];
+        let tab_name = if session.name.is_empty() {
+            "New Tab".to_string()
+        } else {
+            session.name.clone()
+        };
+        self.notifications.push(TabNotification {
+            session_id,
+            tab_name,
+            kind,
+            message,
+            at: Local::now(),
+        });
+    }
+
+    /// Open tabs, most recently active first; tabs never shown come last.
+    pub fn recent_sessions(&self) -> Vec<&Session> {
+        let order = &self.recent_tabs.order;
+        let mut recent: Vec<&Session> = order
+            .iter()
+            .filter_map(|id| self.sessions.iter().find(|s| s.id == *id))
+            .collect();
+        recent.extend(self.sessions.iter().filter(|s| !order.contains(&s.id)));
+        recent
+    }
+
+    /// Opens the recent-tab switcher with the previously active tab selected.
+    pub fn open_recent_tabs(&mut self) {
+        self.recent_tabs.touch(self.active().id);
+        self.recent_tabs.open = true;
+        self.recent_tabs.selected_idx = usize::from(self.sessions.len() > 1);
+    }
+
+    /// Switches to the tab selected in the recent-tab switcher and closes it.
+    pub fn accept_recent_tab(&mut self) {
+        let selected = self
+            .recent_sessions()
+            .get(self.recent_tabs.selected_idx)
+            .map(|session| session.id);
+        if let Some(session_id) = selected {
+            self.switch_to_session(session_id);
+        }
+        self.recent_tabs.close();
+    }
+
+    /// Tabs waiting for a plan approval that can be answered from the approval
+    /// queue, in tab order.
+    pub fn pending_approvals(&self) -> Vec<&Session> {
+        self.sessions
+            .iter()
+            .filter(|s| {
+                s.approval_mode == ApprovalMode::AwaitingChoice
+                    && s.approval_context == ApprovalContext::PlanApproval
+                    && s.approval_tx.is_some()
+            })
+            .collect()
+    }
+
+    pub fn toggle_approval_queue(&mut self) {
+        if self.approval_queue.open {
+            self.approval_queue.close();
+        } else {
+            self.approval_queue.open = true;
+            self.approval_queue.selected_idx = 0;
+        }
+    }
+
+    /// Switches to the tab of the given session. Returns false if it was closed.
+    pub fn switch_to_session(&mut self, session_id: usize) -> bool {
+        match self.sessions.iter().position(|s| s.id == session_id) {
+            Some(index) => {
+                self.active_tab = index;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl Default for TabManager {
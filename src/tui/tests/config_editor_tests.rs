@@ -0,0 +1,208 @@
+use super::*;
+use crate::app::{workflows_dir, WorkflowSelection};
+use crate::planning_paths::{set_home_for_test, TestHomeGuard};
+use tempfile::tempdir;
+
+const USER_WORKFLOW: &str = r#"
+agents:
+  claude:
+    command: claude
+  codex:
+    command: codex
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude, codex]
+implementation:
+  enabled: false
+"#;
+
+fn test_env() -> (tempfile::TempDir, TestHomeGuard) {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let guard = set_home_for_test(dir.path().to_path_buf());
+    (dir, guard)
+}
+
+/// Selects a user workflow named "mine" for `working_dir`.
+fn select_user_workflow(working_dir: &Path) -> PathBuf {
+    let path = workflows_dir().unwrap().join("mine.yaml");
+    std::fs::write(&path, USER_WORKFLOW).unwrap();
+    WorkflowSelection {
+        workflow: "mine".to_string(),
+    }
+    .save(working_dir)
+    .unwrap();
+    path
+}
+
+fn row<'a>(state: &'a ConfigEditorState, key: &str) -> &'a ConfigRow {
+    state
+        .rows
+        .iter()
+        .find(|r| r.key() == key)
+        .unwrap_or_else(|| panic!("row {} not visible", key))
+}
+
+fn select(state: &mut ConfigEditorState, key: &str) {
+    state.selected_idx = state.rows.iter().position(|r| r.key() == key).unwrap();
+}
+
+#[test]
+fn test_open_attributes_sources() {
+    let (dir, _guard) = test_env();
+    select_user_workflow(dir.path());
+
+    let mut state = ConfigEditorState::new();
+    let settings = RuntimeSettings {
+        max_iterations: 5,
+        max_iterations_overridden: false,
+        worktree_flag: true,
+    };
+    state.open(dir.path(), settings);
+
+    assert!(state.header().starts_with("mine ("));
+    let max_iterations = row(&state, "session.max_iterations");
+    assert_eq!(max_iterations.source, Some(ValueSource::CliFlag));
+    assert_eq!(
+        max_iterations.kind,
+        RowKind::Value {
+            value: Value::from(5u32),
+            editable: true
+        }
+    );
+    assert_eq!(
+        row(&state, "workflow.planning.agent").source,
+        Some(ValueSource::UserConfig)
+    );
+    assert_eq!(
+        row(&state, "workflow.planning.max_turns").source,
+        Some(ValueSource::Default)
+    );
+    // Collapsed sections hide their values
+    assert!(state.rows.iter().all(|r| r.key() != "worktree.enabled"));
+    select(&mut state, "worktree");
+    state.expand_selected();
+    assert_eq!(
+        row(&state, "worktree.enabled").source,
+        Some(ValueSource::CliFlag)
+    );
+}
+
+#[test]
+fn test_reviewer_toggle_writes_disabled_list() {
+    let (dir, _guard) = test_env();
+    let path = select_user_workflow(dir.path());
+
+    let mut state = ConfigEditorState::new();
+    state.open(dir.path(), RuntimeSettings::default());
+    assert_eq!(
+        row(&state, "workflow.reviewing.agents.codex").kind,
+        RowKind::Reviewer { enabled: true }
+    );
+
+    select(&mut state, "workflow.reviewing.agents.codex");
+    let change = state.activate_selected();
+    let Some(ConfigChange::Workflow(config)) = change else {
+        panic!("expected a workflow change, got {:?}", change);
+    };
+    assert_eq!(
+        config.workflow.reviewing.disabled,
+        vec!["codex".to_string()]
+    );
+    assert_eq!(
+        row(&state, "workflow.reviewing.agents.codex").kind,
+        RowKind::Reviewer { enabled: false }
+    );
+    assert!(std::fs::read_to_string(&path).unwrap().contains("disabled"));
+
+    // The last enabled reviewer can't be switched off
+    select(&mut state, "workflow.reviewing.agents.claude");
+    assert!(state.activate_selected().is_none());
+    assert!(state.error.is_some());
+}
+
+#[test]
+fn test_edit_scalar_value() {
+    let (dir, _guard) = test_env();
+    let path = select_user_workflow(dir.path());
+
+    let mut state = ConfigEditorState::new();
+    state.open(dir.path(), RuntimeSettings::default());
+    select(&mut state, "workflow.planning.max_turns");
+    assert!(state.activate_selected().is_none());
+    assert_eq!(state.editing.as_deref(), Some(""));
+
+    // max_turns is Option<u32>; a string fails validation and the file is kept
+    state.editing = Some("abc".to_string());
+    assert!(state.commit_edit().is_none());
+    assert!(state.error.is_some());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), USER_WORKFLOW);
+
+    state.editing = Some("40".to_string());
+    assert!(state.commit_edit().is_some());
+    assert_eq!(
+        row(&state, "workflow.planning.max_turns").source,
+        Some(ValueSource::UserConfig)
+    );
+    let config = crate::config::WorkflowConfig::load(&path).unwrap();
+    assert_eq!(config.workflow.planning.max_turns, Some(40));
+}
+
+#[test]
+fn test_max_iterations_is_session_only() {
+    let (dir, _guard) = test_env();
+    let path = select_user_workflow(dir.path());
+    let before = std::fs::read_to_string(&path).unwrap();
+
+    let mut state = ConfigEditorState::new();
+    state.open(dir.path(), RuntimeSettings::default());
+    select(&mut state, "session.max_iterations");
+    state.activate_selected();
+    state.editing = Some("7".to_string());
+    assert!(matches!(
+        state.commit_edit(),
+        Some(ConfigChange::MaxIterations(7))
+    ));
+    assert_eq!(
+        row(&state, "session.max_iterations").source,
+        Some(ValueSource::Session)
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+
+    state.editing = Some("0".to_string());
+    assert!(state.commit_edit().is_none());
+    assert!(state.error.is_some());
+}
+
+#[test]
+fn test_editing_builtin_creates_user_copy() {
+    let (dir, _guard) = test_env();
+
+    let mut state = ConfigEditorState::new();
+    state.open(dir.path(), RuntimeSettings::default());
+    assert!(state.header().contains("built-in"));
+    assert_eq!(
+        row(&state, "workflow.planning.agent").source,
+        Some(ValueSource::BuiltIn)
+    );
+
+    select(&mut state, "workflow.reviewing.sequential");
+    assert!(matches!(
+        state.activate_selected(),
+        Some(ConfigChange::Workflow(_))
+    ));
+
+    let copy = workflows_dir().unwrap().join("claude-only-custom.yaml");
+    assert!(copy.exists());
+    assert_eq!(
+        WorkflowSelection::load(dir.path()).unwrap().workflow,
+        "claude-only-custom"
+    );
+    let config = crate::app::load_workflow_by_name("claude-only-custom").unwrap();
+    assert!(config.workflow.reviewing.sequential);
+    assert_eq!(
+        row(&state, "workflow.reviewing.sequential").source,
+        Some(ValueSource::UserConfig)
+    );
+}
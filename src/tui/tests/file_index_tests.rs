@@ -142,3 +142,73 @@ fn test_error_index_returns_no_matches() {
     let matches = index.find_matches("test", 10);
     assert!(matches.is_empty());
 }
+
+fn paths(index: &FileIndex) -> Vec<String> {
+    let mut paths: Vec<String> = index.entries.iter().map(|e| e.path.clone()).collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn test_apply_delta_adds_and_prunes_folders() {
+    let mut index = FileIndex::from_files(
+        vec!["src/main.rs".to_string(), "docs/old/guide.md".to_string()],
+        PathBuf::from("/repo"),
+    );
+    assert_eq!(
+        paths(&index),
+        vec![
+            "docs/",
+            "docs/old/",
+            "docs/old/guide.md",
+            "src/",
+            "src/main.rs"
+        ]
+    );
+
+    let old: HashSet<String> = ["src/main.rs", "docs/old/guide.md"]
+        .map(String::from)
+        .into();
+    let new: HashSet<String> = ["src/main.rs", "src/tui/new.rs"].map(String::from).into();
+    let delta = FileIndexDelta::between(&old, &new);
+    assert_eq!(delta.added, vec!["src/tui/new.rs"]);
+    assert_eq!(delta.removed, vec!["docs/old/guide.md"]);
+
+    index.apply_delta(delta, Instant::now());
+    assert_eq!(
+        paths(&index),
+        vec!["src/", "src/main.rs", "src/tui/", "src/tui/new.rs"]
+    );
+    assert_eq!(
+        index.find_matches("new", 5)[0].display_path,
+        "src/tui/new.rs"
+    );
+}
+
+#[test]
+fn test_staleness_note() {
+    let mut index = FileIndex::from_files(vec!["a.rs".to_string()], PathBuf::from("/repo"));
+    let synced = index.synced_at.unwrap();
+    assert_eq!(index.staleness_note(synced), None);
+
+    let later = synced + STALE_AFTER + Duration::from_secs(5);
+    assert_eq!(index.staleness_note(later).unwrap(), "index 15s old");
+    assert_eq!(
+        index
+            .staleness_note(synced + Duration::from_secs(180))
+            .unwrap(),
+        "index 3m old"
+    );
+
+    index.apply_delta(
+        FileIndexDelta {
+            pending: true,
+            ..FileIndexDelta::default()
+        },
+        later,
+    );
+    assert_eq!(index.staleness_note(later).unwrap(), "updating…");
+
+    index.apply_delta(FileIndexDelta::default(), later);
+    assert_eq!(index.staleness_note(later), None);
+}
@@ -85,6 +85,7 @@ fn test_mention_state_select_prev() {
             },
         ],
         selected_idx: 0,
+        index_note: None,
     };
 
     state.select_prev();
@@ -114,6 +115,7 @@ fn test_mention_state_select_next() {
             },
         ],
         selected_idx: 0,
+        index_note: None,
     };
 
     state.select_next();
@@ -136,6 +138,7 @@ fn test_mention_state_clear() {
             score: 10,
         }],
         selected_idx: 0,
+        index_note: None,
     };
 
     state.clear();
@@ -166,6 +169,7 @@ fn test_selected_match() {
             },
         ],
         selected_idx: 1,
+        index_note: None,
     };
 
     assert_eq!(state.selected_match().unwrap().display_path, "b");
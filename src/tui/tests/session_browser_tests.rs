@@ -27,6 +27,7 @@ fn test_session_browser_close() {
         last_seen_at: "2024-01-01T00:00:00Z".to_string(),
         last_seen_relative: "just now".to_string(),
         working_dir: PathBuf::from("/test"),
+        repository: PathBuf::from("/test"),
         is_current_dir: true,
         has_snapshot: true,
         is_resumable: false,
@@ -63,6 +64,7 @@ fn test_session_entry_resumability() {
         last_seen_at: "2024-01-01T00:00:00Z".to_string(),
         last_seen_relative: "just now".to_string(),
         working_dir: PathBuf::from("/test"),
+        repository: PathBuf::from("/test"),
         is_current_dir: true,
         has_snapshot: true,
         is_resumable: false, // Running sessions aren't resumable
@@ -105,6 +107,7 @@ fn create_test_entry(id: &str, phase: &str, iteration: u32) -> SessionEntry {
         last_seen_at: "2024-01-01T00:00:00Z".to_string(),
         last_seen_relative: "just now".to_string(),
         working_dir: PathBuf::from("/tmp/test"),
+        repository: PathBuf::from("/tmp/test"),
         is_current_dir: false,
         has_snapshot: false,
         is_resumable: false,
@@ -245,3 +248,137 @@ fn test_apply_session_update_multiple_sessions() {
     assert_eq!(session_b.phase, "Reviewing");
     assert_eq!(session_b.iteration, 2);
 }
+
+fn create_stopped_entry(id: &str) -> SessionEntry {
+    SessionEntry {
+        liveness: LivenessState::Stopped,
+        has_snapshot: true,
+        is_resumable: true,
+        pid: None,
+        is_live: false,
+        ..create_test_entry(id, "Planning", 1)
+    }
+}
+
+#[test]
+fn test_toggle_mark_selected_advances() {
+    let mut state = SessionBrowserState::new();
+    state.entries.push(create_stopped_entry("a"));
+    state.entries.push(create_stopped_entry("b"));
+
+    state.toggle_mark_selected();
+    assert!(state.is_marked("a"));
+    assert_eq!(state.selected_idx, 1);
+
+    // Marking the last entry keeps the selection in place
+    state.toggle_mark_selected();
+    assert!(state.is_marked("b"));
+    assert_eq!(state.selected_idx, 1);
+
+    // Toggling again unmarks
+    state.toggle_mark_selected();
+    assert!(!state.is_marked("b"));
+}
+
+#[test]
+fn test_bulk_targets_skip_inapplicable_sessions() {
+    let mut state = SessionBrowserState::new();
+    state
+        .entries
+        .push(create_test_entry("running", "Planning", 1));
+    state.entries.push(create_stopped_entry("stopped-a"));
+    state.entries.push(create_stopped_entry("stopped-b"));
+    state
+        .marked
+        .extend(["running", "stopped-a", "stopped-b"].map(String::from));
+
+    let (targets, skipped) = state.bulk_targets(BulkAction::Resume);
+    assert_eq!(targets, vec!["stopped-a", "stopped-b"]);
+    assert_eq!(skipped, 1);
+
+    let (targets, skipped) = state.bulk_targets(BulkAction::ForceStop);
+    assert_eq!(targets, vec!["running"]);
+    assert_eq!(skipped, 2);
+}
+
+#[test]
+fn test_bulk_targets_fall_back_to_selection() {
+    let mut state = SessionBrowserState::new();
+    state.entries.push(create_stopped_entry("a"));
+    state.entries.push(create_stopped_entry("b"));
+    state.selected_idx = 1;
+
+    let (targets, skipped) = state.bulk_targets(BulkAction::DeleteSnapshots);
+    assert_eq!(targets, vec!["b"]);
+    assert_eq!(skipped, 0);
+}
+
+#[test]
+fn test_start_bulk_confirmation_requires_applicable_sessions() {
+    let mut state = SessionBrowserState::new();
+    state
+        .entries
+        .push(create_test_entry("running", "Planning", 1));
+    state.marked.insert("running".to_string());
+
+    state.start_bulk_confirmation(BulkAction::Archive);
+    assert!(state.confirmation_pending.is_none());
+    assert!(state.error.is_some());
+
+    state.start_bulk_confirmation(BulkAction::ForceStop);
+    assert!(matches!(
+        state.confirmation_pending,
+        Some(ConfirmationState::Bulk {
+            action: BulkAction::ForceStop,
+            skipped: 0,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_refresh_drops_marks_for_removed_sessions() {
+    let mut state = SessionBrowserState::new();
+    state.marked.extend(["kept", "removed"].map(String::from));
+
+    state.apply_refresh(vec![create_stopped_entry("kept")], false, None);
+    assert!(state.is_marked("kept"));
+    assert!(!state.is_marked("removed"));
+
+    state.close();
+    assert!(state.marked.is_empty());
+}
+
+#[test]
+fn test_group_by_repo_sorts_by_repository_and_clears_filter() {
+    let mut state = SessionBrowserState::new();
+    state.filter_current_dir = true;
+    let entry = |id: &str, repo: &str| {
+        let mut entry = create_stopped_entry(id);
+        entry.repository = PathBuf::from(repo);
+        entry.working_dir = PathBuf::from(format!("{}/sub dir", repo));
+        entry
+    };
+    let entries = vec![
+        entry("b1", "/repos/b"),
+        entry("a1", "/repos/a"),
+        entry("b2", "/repos/b"),
+    ];
+    state.apply_refresh(entries, false, None);
+
+    state.toggle_group_by_repo();
+    assert!(!state.filter_current_dir);
+    let order: Vec<&str> = state
+        .filtered_entries()
+        .iter()
+        .map(|e| e.session_id.as_str())
+        .collect();
+    assert_eq!(order[0], "a1");
+    assert_eq!(
+        state.selected_entry().unwrap().resume_command(),
+        "planning --working-dir '/repos/a/sub dir' --resume-session a1"
+    );
+
+    state.toggle_filter();
+    assert!(!state.group_by_repo);
+}
@@ -46,7 +46,8 @@ fn test_summary_events_include_run_id() {
     let (tx, mut rx) = mpsc::unbounded_channel();
     let sender = SessionEventSender::new(1, 42, tx);
 
-    sender.send_run_tab_summary_generating("Planning".to_string());
+    let (cancel_tx, _cancel_rx) = tokio::sync::watch::channel(false);
+    sender.send_run_tab_summary_generating("Planning".to_string(), cancel_tx);
     sender.send_run_tab_summary_ready("Planning".to_string(), "Summary content".to_string());
     sender.send_run_tab_summary_error("Planning".to_string(), "Error message".to_string());
 
@@ -55,6 +56,7 @@ fn test_summary_events_include_run_id() {
             session_id,
             phase,
             run_id,
+            ..
         } => {
             assert_eq!(session_id, 1);
             assert_eq!(phase, "Planning");
@@ -93,3 +95,89 @@ fn test_summary_events_include_run_id() {
         _ => panic!("Expected SessionRunTabSummaryError event"),
     }
 }
+
+#[test]
+fn test_summary_sender_streams_into_summary_panel() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let sender = SessionEventSender::new(1, 7, tx).for_summary("Planning".to_string());
+
+    sender.send_partial_text(
+        "claude".to_string(),
+        "Planning".to_string(),
+        Some("- A".to_string()),
+    );
+    sender.send_partial_text("claude".to_string(), "Planning".to_string(), None);
+
+    match rx.try_recv().unwrap() {
+        Event::SessionRunTabSummaryDelta {
+            phase,
+            delta,
+            run_id,
+            ..
+        } => {
+            assert_eq!(phase, "Planning");
+            assert_eq!(delta, "- A");
+            assert_eq!(run_id, 7);
+        }
+        _ => panic!("Expected SessionRunTabSummaryDelta event"),
+    }
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_round_cost_alert_fires_once_per_round() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let sender = SessionEventSender::new(1, 0, tx);
+
+    sender.record_round_cost("Reviewing #1", 1.5, 2.0);
+    assert!(rx.try_recv().is_err());
+
+    sender.record_round_cost("Reviewing #1", 0.75, 2.0);
+    match rx.try_recv().unwrap() {
+        Event::SessionOutput { line, .. } => {
+            assert_eq!(
+                line,
+                "[planning] Cost alert: Reviewing #1 has cost $2.25, over its $2.00 alert"
+            );
+        }
+        _ => panic!("Expected SessionOutput event"),
+    }
+    match rx.try_recv().unwrap() {
+        Event::SessionCostAlert { message, .. } => assert!(message.starts_with("Reviewing #1")),
+        _ => panic!("Expected SessionCostAlert event"),
+    }
+
+    // Later spending in the same round stays quiet; the next round starts from zero
+    sender.record_round_cost("Reviewing #1", 1.0, 2.0);
+    sender.record_round_cost("Reviewing #2", 1.0, 2.0);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_run_stats_track_phases_and_rejections() {
+    let (tx, _rx) = mpsc::unbounded_channel();
+    let sender = SessionEventSender::new(1, 0, tx);
+    assert!(sender.phase_times().is_empty());
+
+    sender.send_phase_started("Planning".to_string());
+    sender.send_phase_started("Reviewing".to_string());
+    sender.send_phase_started("Planning".to_string());
+    let phases: Vec<String> = sender
+        .phase_times()
+        .into_iter()
+        .map(|(phase, _)| phase)
+        .collect();
+    assert_eq!(phases, vec!["Planning", "Reviewing"]);
+
+    for approved in [false, true, false] {
+        sender.send_reviewer_completed(
+            ReviewKind::Plan,
+            1,
+            "claude".to_string(),
+            approved,
+            String::new(),
+            0,
+        );
+    }
+    assert_eq!(sender.reviewer_rejections(), 2);
+}
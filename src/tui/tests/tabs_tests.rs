@@ -91,3 +91,207 @@ fn test_add_session_with_name() {
     assert_eq!(manager.len(), 2);
     assert_eq!(manager.active().name, "test-feature");
 }
+
+fn char_key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+#[test]
+fn test_macro_records_and_replays() {
+    let mut macros = KeyMacros::default();
+    assert_eq!(macros.handle_key(char_key('q'), true), MacroKeys::Consumed);
+    assert_eq!(macros.handle_key(char_key('a'), true), MacroKeys::Consumed);
+    assert_eq!(macros.recording_register(), Some('a'));
+
+    // Text typed while recording is kept, including `q`
+    let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+    for key in [char_key('c'), char_key('q'), enter] {
+        assert_eq!(
+            macros.handle_key(key, key.code != KeyCode::Char('q')),
+            MacroKeys::Dispatch(vec![key])
+        );
+    }
+    assert_eq!(macros.handle_key(char_key('q'), true), MacroKeys::Consumed);
+    assert_eq!(macros.recording_register(), None);
+
+    assert_eq!(macros.handle_key(char_key('@'), true), MacroKeys::Consumed);
+    assert_eq!(
+        macros.handle_key(char_key('a'), true),
+        MacroKeys::Dispatch(vec![char_key('c'), char_key('q'), enter])
+    );
+    assert_eq!(macros.handle_key(char_key('@'), true), MacroKeys::Consumed);
+    assert_eq!(
+        macros.handle_key(char_key('b'), true),
+        MacroKeys::Dispatch(vec![])
+    );
+}
+
+#[test]
+fn test_macro_prefix_without_register_is_handled_as_typed() {
+    let mut macros = KeyMacros::default();
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+    macros.handle_key(char_key('q'), true);
+    assert_eq!(
+        macros.handle_key(esc, true),
+        MacroKeys::Dispatch(vec![char_key('q'), esc])
+    );
+
+    // `qq` is not a register, so it still quits
+    macros.handle_key(char_key('q'), true);
+    assert_eq!(
+        macros.handle_key(char_key('q'), true),
+        MacroKeys::Dispatch(vec![char_key('q'), char_key('q')])
+    );
+
+    // A lone `q` is released once the timeout passes
+    macros.handle_key(char_key('q'), true);
+    let start = Instant::now();
+    assert_eq!(macros.take_expired_prefix(start), None);
+    assert_eq!(
+        macros.take_expired_prefix(start + MACRO_PREFIX_TIMEOUT),
+        Some(char_key('q'))
+    );
+    assert_eq!(
+        macros.take_expired_prefix(start + MACRO_PREFIX_TIMEOUT),
+        None
+    );
+}
+
+#[test]
+fn test_notify_records_only_background_tabs() {
+    let mut manager = TabManager::new();
+    let background = manager.sessions[0].id;
+    manager.sessions[0].name = "auth".to_string();
+    let active = manager.add_session().id;
+
+    manager.notify(active, NotificationKind::Error, "ignored".to_string());
+    manager.notify(
+        background,
+        NotificationKind::ApprovalRequired,
+        "Plan ready for approval".to_string(),
+    );
+    manager.notify(
+        background,
+        NotificationKind::PhaseComplete,
+        "Planning complete".to_string(),
+    );
+
+    let center = &mut manager.notifications;
+    assert_eq!(center.unread(), 2);
+    let newest = center.entries().next().unwrap();
+    assert_eq!(newest.kind, NotificationKind::PhaseComplete);
+    assert_eq!(newest.tab_name, "auth");
+    assert_eq!(center.take_alert().unwrap().message, "Planning complete");
+    assert!(center.take_alert().is_none());
+
+    center.toggle();
+    assert!(center.open);
+    assert_eq!(center.unread(), 0);
+    center.select_next();
+    assert_eq!(
+        center.selected_entry().unwrap().kind,
+        NotificationKind::ApprovalRequired
+    );
+}
+
+#[test]
+fn test_switch_to_session_jumps_to_open_tabs_only() {
+    let mut manager = TabManager::new();
+    let first = manager.sessions[0].id;
+    let second = manager.add_session().id;
+    assert_eq!(manager.active_tab, 1);
+
+    assert!(manager.switch_to_session(first));
+    assert_eq!(manager.active_tab, 0);
+
+    manager.close_tab(1);
+    assert!(!manager.switch_to_session(second));
+    assert_eq!(manager.active_tab, 0);
+}
+
+#[test]
+fn test_notifications_are_bounded() {
+    let mut manager = TabManager::new();
+    let background = manager.sessions[0].id;
+    manager.add_session();
+    for i in 0..MAX_NOTIFICATIONS + 5 {
+        manager.notify(background, NotificationKind::BudgetAlert, i.to_string());
+    }
+    assert_eq!(manager.notifications.entries().count(), MAX_NOTIFICATIONS);
+    assert_eq!(manager.notifications.unread(), MAX_NOTIFICATIONS);
+}
+
+#[test]
+fn test_recent_tabs_switch_back_to_previous_tab() {
+    let mut manager = TabManager::new();
+    let first = manager.sessions[0].id;
+    manager.recent_tabs.touch(first);
+    let second = manager.add_session().id;
+    manager.recent_tabs.touch(second);
+    let third = manager.add_session().id;
+    manager.recent_tabs.touch(third);
+    manager.switch_to_session(first);
+
+    manager.open_recent_tabs();
+    let order: Vec<usize> = manager.recent_sessions().iter().map(|s| s.id).collect();
+    assert_eq!(order, vec![first, third, second]);
+    assert_eq!(manager.recent_tabs.selected_idx, 1);
+
+    manager.accept_recent_tab();
+    assert!(!manager.recent_tabs.open);
+    assert_eq!(manager.active().id, third);
+}
+
+#[test]
+fn test_recent_tabs_selection_wraps_and_skips_closed_tabs() {
+    let mut manager = TabManager::new();
+    let first = manager.sessions[0].id;
+    manager.recent_tabs.touch(first);
+    let second = manager.add_session().id;
+    manager.recent_tabs.touch(second);
+    manager.add_session();
+    manager.close_tab(2);
+
+    manager.open_recent_tabs();
+    let order: Vec<usize> = manager.recent_sessions().iter().map(|s| s.id).collect();
+    assert_eq!(order, vec![second, first]);
+
+    manager.recent_tabs.select_next(2);
+    assert_eq!(manager.recent_tabs.selected_idx, 0);
+    manager.recent_tabs.select_prev(2);
+    assert_eq!(manager.recent_tabs.selected_idx, 1);
+}
+
+#[test]
+fn test_pending_approvals_lists_plan_approvals_with_a_channel() {
+    let mut manager = TabManager::new();
+    let (tx, _rx) = tokio::sync::mpsc::channel(1);
+    for name in ["auth", "billing", "search"] {
+        let session = manager.add_session_with_name(name.to_string());
+        session.start_approval(format!("{} plan", name));
+        session.approval_tx = Some(tx.clone());
+    }
+    manager.sessions[2].approval_context = ApprovalContext::ReviewDecision;
+    manager.sessions[3].approval_tx = None;
+
+    let pending: Vec<&str> = manager
+        .pending_approvals()
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(pending, vec!["auth"]);
+
+    manager.toggle_approval_queue();
+    let queue = &mut manager.approval_queue;
+    assert!(queue.open);
+    queue.select_next(3);
+    queue.select_next(3);
+    queue.select_next(3);
+    assert_eq!(queue.selected_idx, 2);
+    queue.clamp(1);
+    assert_eq!(queue.selected_idx, 0);
+    queue.decline_input = Some("too broad".to_string());
+    manager.toggle_approval_queue();
+    assert!(!manager.approval_queue.open);
+    assert!(manager.approval_queue.decline_input.is_none());
+}
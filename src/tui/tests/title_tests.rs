@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_progress_escape_sequence() {
+    assert_eq!(
+        TerminalProgress::Attention(40).escape_sequence(),
+        "\x1b]9;4;4;40\x07"
+    );
+    assert_eq!(
+        TerminalProgress::Normal(250).escape_sequence(),
+        "\x1b]9;4;1;100\x07"
+    );
+}
+
+#[test]
+fn test_desktop_notification_sequence_strips_control_characters() {
+    assert_eq!(
+        desktop_notification_sequence("auth; api", "Approval\x07 required\n"),
+        "\x1b]777;notify;auth, api;Approval required\x07"
+    );
+}
+
+#[test]
+fn test_clipboard_sequence_base64_encodes_text() {
+    assert_eq!(
+        clipboard_sequence("/tmp/plan.md"),
+        "\x1b]52;c;L3RtcC9wbGFuLm1k\x07"
+    );
+}
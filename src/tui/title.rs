@@ -1,6 +1,78 @@
 use crossterm::{execute, terminal::SetTitle};
 use std::io::{self, IsTerminal, Write};
 
+/// Taskbar/tab progress reported with the `OSC 9;4` sequence (Windows Terminal,
+/// ConEmu, WezTerm). Terminals without support ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalProgress {
+    /// No progress indicator.
+    Hidden,
+    /// Running, with percent complete.
+    Normal(u8),
+    /// Running, but progress is unknown.
+    Indeterminate,
+    /// Waiting on the user (shown as a paused/attention bar), with percent complete.
+    Attention(u8),
+    /// Failed, with percent complete.
+    Error(u8),
+}
+
+impl TerminalProgress {
+    /// The `OSC 9;4;<state>;<percent>` escape sequence for this progress.
+    pub fn escape_sequence(self) -> String {
+        let (state, percent) = match self {
+            TerminalProgress::Hidden => (0, 0),
+            TerminalProgress::Normal(p) => (1, p),
+            TerminalProgress::Error(p) => (2, p),
+            TerminalProgress::Indeterminate => (3, 0),
+            TerminalProgress::Attention(p) => (4, p),
+        };
+        format!("\x1b]9;4;{};{}\x07", state, percent.min(100))
+    }
+}
+
+/// How the terminal is told about background tab notifications (`--notify`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum NotifyMode {
+    /// Only the tab bar counter and the notification center.
+    Off,
+    /// Ring the terminal bell.
+    Bell,
+    /// Send an `OSC 777` desktop notification (foot, WezTerm, rxvt, many others).
+    Desktop,
+}
+
+/// The `OSC 777;notify;<title>;<body>` escape sequence. Control characters are
+/// dropped so the text cannot end the sequence early; `;` would split the title.
+pub fn desktop_notification_sequence(title: &str, body: &str) -> String {
+    let clean = |text: &str| text.chars().filter(|c| !c.is_control()).collect::<String>();
+    format!(
+        "\x1b]777;notify;{};{}\x07",
+        clean(title).replace(';', ","),
+        clean(body)
+    )
+}
+
+/// The `OSC 52` sequence setting the system clipboard to `text`. Works in most
+/// terminals, also over SSH; terminals without support ignore it.
+pub fn clipboard_sequence(text: &str) -> String {
+    use base64::Engine;
+    format!(
+        "\x1b]52;c;{}\x07",
+        base64::engine::general_purpose::STANDARD.encode(text)
+    )
+}
+
+/// Copies `text` to the system clipboard through the terminal.
+/// Uses `let _ =` because the copy is a convenience; if stdout
+/// is unavailable (pipe closed, terminal gone), there's nothing useful to do.
+pub fn copy_to_clipboard(text: &str) {
+    if TerminalTitleManager::new().is_supported {
+        let _ = io::stdout().write_all(clipboard_sequence(text).as_bytes());
+        let _ = io::stdout().flush();
+    }
+}
+
 pub struct TerminalTitleManager {
     is_supported: bool,
 }
@@ -42,6 +114,32 @@ impl TerminalTitleManager {
             let _ = execute!(io::stdout(), SetTitle(title));
         }
     }
+
+    /// Sets the taskbar/tab progress indicator.
+    /// Uses `let _ =` because progress reporting is cosmetic; if stdout
+    /// is unavailable (pipe closed, terminal gone), there's nothing useful to do.
+    pub fn set_progress(&self, progress: TerminalProgress) {
+        if self.is_supported {
+            let _ = io::stdout().write_all(progress.escape_sequence().as_bytes());
+            let _ = io::stdout().flush();
+        }
+    }
+
+    /// Alerts the user the way `mode` asks.
+    /// Uses `let _ =` because alerts are cosmetic; if stdout
+    /// is unavailable (pipe closed, terminal gone), there's nothing useful to do.
+    pub fn alert(&self, mode: NotifyMode, title: &str, body: &str) {
+        if !self.is_supported {
+            return;
+        }
+        let sequence = match mode {
+            NotifyMode::Off => return,
+            NotifyMode::Bell => "\x07".to_string(),
+            NotifyMode::Desktop => desktop_notification_sequence(title, body),
+        };
+        let _ = io::stdout().write_all(sequence.as_bytes());
+        let _ = io::stdout().flush();
+    }
 }
 
 impl Default for TerminalTitleManager {
@@ -49,3 +147,7 @@ impl Default for TerminalTitleManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+#[path = "tests/title_tests.rs"]
+mod tests;
@@ -1,10 +1,14 @@
 use super::theme::Theme;
-use super::util::{compute_wrapped_line_count, parse_markdown_line, wrap_text_at_width};
+use super::util::{
+    compute_wrapped_line_count, highlight_nav_lines, parse_markdown_line, truncate_to_width,
+    wrap_text_at_width,
+};
 use super::SPINNER_CHARS;
 use crate::tui::scroll::{ScrollRegion, ScrollableRegions};
-use crate::tui::session::ReviewerStatus;
+use crate::tui::session::{ChatMessage, ReviewerStatus};
 use crate::tui::{
-    FocusedPanel, RunTab, RunTabEntry, Session, SummaryState, ToolResultSummary, ToolTimelineEntry,
+    ChatVerbosity, FocusedPanel, RunTab, RunTabEntry, Session, SummaryState, ToolResultSummary,
+    ToolTimelineEntry,
 };
 use ratatui::{
     layout::Rect,
@@ -15,6 +19,12 @@ use ratatui::{
 };
 use unicode_width::UnicodeWidthStr;
 
+/// Lines of an in-progress response shown in the chat pane.
+const PARTIAL_TAIL_LINES: usize = 40;
+
+/// Typing cursor drawn after an in-progress response.
+const PARTIAL_CURSOR: &str = "▌";
+
 pub(super) fn draw_chat_content(
     frame: &mut Frame,
     session: &Session,
@@ -30,33 +40,28 @@ pub(super) fn draw_chat_content(
         theme.success
     };
 
-    let title = if let Some(tab) = active_tab {
+    let mut title = if let Some(tab) = active_tab {
+        let phase = match session.chat_verbosity {
+            ChatVerbosity::Full => tab.phase.clone(),
+            verbosity => format!("{} ({})", tab.phase, verbosity.label()),
+        };
         if tab.chat_scroll.follow {
             if is_focused {
-                format!(" {} [*] ", tab.phase)
+                format!(" {} [*] ", phase)
             } else {
-                format!(" {} ", tab.phase)
+                format!(" {} ", phase)
             }
         } else if is_focused {
-            format!(" {} [SCROLLED *] ", tab.phase)
+            format!(" {} [SCROLLED *] ", phase)
         } else {
-            format!(" {} [SCROLLED] ", tab.phase)
+            format!(" {} [SCROLLED] ", phase)
         }
     } else {
         " Chat ".to_string()
     };
 
-    let chat_block = Block::default()
-        .borders(Borders::ALL)
-        .title(title)
-        .border_style(Style::default().fg(border_color));
-
-    let inner_area = chat_block.inner(area);
-    let visible_height = inner_area.height as usize;
-    let inner_width = inner_area.width;
-
-    let lines: Vec<Line> = if let Some(tab) = active_tab {
-        if tab.entries.is_empty() {
+    let mut lines: Vec<Line> = if let Some(tab) = active_tab {
+        if tab.entries.is_empty() && tab.partial.is_empty() {
             vec![Line::from(Span::styled(
                 "Waiting for agent output...",
                 Style::default().fg(Color::DarkGray),
@@ -64,23 +69,13 @@ pub(super) fn draw_chat_content(
         } else {
             let max_preview_len = 60;
             let max_summary_len = 60;
-            tab.entries
+            let mut lines: Vec<Line> = tab
+                .entries
                 .iter()
+                .filter(|entry| session.chat_verbosity.shows(entry))
                 .map(|entry| match entry {
                     RunTabEntry::Text(msg) => {
-                        let agent_color = match msg.agent_name.as_str() {
-                            "user" => theme.accent,
-                            "claude" => Color::Cyan,
-                            "codex" => Color::Magenta,
-                            "gemini" => Color::Blue,
-                            _ => Color::Yellow,
-                        };
-                        let badge = Span::styled(
-                            format!("[{}] ", msg.agent_name),
-                            Style::default()
-                                .fg(agent_color)
-                                .add_modifier(Modifier::BOLD),
-                        );
+                        let badge = agent_badge(&msg.agent_name, &theme);
                         let content =
                             Span::styled(msg.message.clone(), Style::default().fg(theme.text));
                         Line::from(vec![badge, content])
@@ -131,18 +126,7 @@ pub(super) fn draw_chat_content(
                             }
                         };
 
-                        let agent_color = match agent_name.as_str() {
-                            "claude" => Color::Cyan,
-                            "codex" => Color::Magenta,
-                            "gemini" => Color::Blue,
-                            _ => Color::Yellow,
-                        };
-                        let badge = Span::styled(
-                            format!("[{}] ", agent_name),
-                            Style::default()
-                                .fg(agent_color)
-                                .add_modifier(Modifier::BOLD),
-                        );
+                        let badge = agent_badge(agent_name, &theme);
                         let details = format!("{}{}", label, suffix);
                         Line::from(vec![
                             badge,
@@ -151,7 +135,11 @@ pub(super) fn draw_chat_content(
                         ])
                     }
                 })
-                .collect()
+                .collect();
+            for msg in &tab.partial {
+                lines.extend(partial_message_lines(msg, &theme));
+            }
+            lines
         }
     } else {
         vec![Line::from(Span::styled(
@@ -159,6 +147,19 @@ pub(super) fn draw_chat_content(
             Style::default().fg(Color::DarkGray),
         ))]
     };
+    if let Some(tag) = highlight_nav_lines(&mut lines, session.nav.as_ref(), FocusedPanel::Chat) {
+        let phase = active_tab.map_or("Chat", |tab| tab.phase.as_str());
+        title = format!(" {} [{}] ", phase, tag);
+    }
+
+    let chat_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(border_color));
+
+    let inner_area = chat_block.inner(area);
+    let visible_height = inner_area.height as usize;
+    let inner_width = inner_area.width;
 
     let paragraph_for_count = Paragraph::new(lines.clone()).wrap(Wrap { trim: false });
     let wrapped_line_count = paragraph_for_count.line_count(inner_width);
@@ -192,6 +193,55 @@ pub(super) fn draw_chat_content(
     }
 }
 
+/// Bold `[agent] ` prefix in the agent's color.
+fn agent_badge(agent_name: &str, theme: &Theme) -> Span<'static> {
+    let agent_color = match agent_name {
+        "user" => theme.accent,
+        "claude" => Color::Cyan,
+        "codex" => Color::Magenta,
+        "gemini" => Color::Blue,
+        _ => Color::Yellow,
+    };
+    Span::styled(
+        format!("[{}] ", agent_name),
+        Style::default()
+            .fg(agent_color)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+/// Lines of a response still being generated, ending in a typing cursor.
+///
+/// Only the tail is rendered so a long response doesn't make every redraw slower;
+/// the full text arrives as regular chat entries when the message completes.
+fn partial_message_lines(msg: &ChatMessage, theme: &Theme) -> Vec<Line<'static>> {
+    let text_lines: Vec<&str> = msg.message.split('\n').collect();
+    let skip = text_lines.len().saturating_sub(PARTIAL_TAIL_LINES);
+    let last = text_lines.len() - 1;
+    text_lines
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, text)| {
+            let mut spans = Vec::with_capacity(3);
+            if i == skip {
+                spans.push(agent_badge(&msg.agent_name, theme));
+            }
+            spans.push(Span::styled(
+                text.to_string(),
+                Style::default().fg(theme.text),
+            ));
+            if i == last {
+                spans.push(Span::styled(
+                    PARTIAL_CURSOR,
+                    Style::default().fg(theme.accent),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
 pub(super) fn draw_chat_input(frame: &mut Frame, session: &Session, area: Rect) {
     if area.width < 3 || area.height < 3 {
         return;
@@ -313,12 +363,11 @@ pub(super) fn draw_summary_panel(
                 let spinner =
                     SPINNER_CHARS[(tab.summary_spinner_frame as usize) % SPINNER_CHARS.len()];
                 let title = if is_focused {
-                    format!(" {} Summary [*] ", spinner)
+                    format!(" {} Summary [*] x: cancel ", spinner)
                 } else {
                     format!(" {} Summary ", spinner)
                 };
-                (
-                    title,
+                let lines = if tab.summary_text.is_empty() {
                     vec![
                         Line::from(""),
                         Line::from(vec![
@@ -333,8 +382,12 @@ pub(super) fn draw_summary_panel(
                             "  This may take a moment.",
                             Style::default().fg(Color::DarkGray),
                         )),
-                    ],
-                )
+                    ]
+                } else {
+                    // Show the summary as it streams in
+                    tab.summary_text.lines().map(parse_markdown_line).collect()
+                };
+                (title, lines)
             }
             SummaryState::Ready => {
                 let title = if is_focused {
@@ -345,6 +398,22 @@ pub(super) fn draw_summary_panel(
                 let lines: Vec<Line> = tab.summary_text.lines().map(parse_markdown_line).collect();
                 (title, lines)
             }
+            SummaryState::Cancelled => {
+                let title = if is_focused {
+                    " Summary (cancelled) [*] ".to_string()
+                } else {
+                    " Summary (cancelled) ".to_string()
+                };
+                let lines: Vec<Line> = if tab.summary_text.is_empty() {
+                    vec![Line::from(Span::styled(
+                        "Summary cancelled before any text arrived",
+                        Style::default().fg(Color::DarkGray),
+                    ))]
+                } else {
+                    tab.summary_text.lines().map(parse_markdown_line).collect()
+                };
+                (title, lines)
+            }
             SummaryState::Error => {
                 let title = if is_focused {
                     " Summary Error [*] ".to_string()
@@ -424,12 +493,7 @@ pub(super) fn draw_run_tabs(frame: &mut Frame, session: &Session, area: Rect) {
     for (i, tab) in session.run_tabs.iter().enumerate() {
         let is_active = i == session.active_run_tab;
 
-        let display_name: String = if tab.phase.chars().count() > 12 {
-            let truncated: String = tab.phase.chars().take(9).collect();
-            format!("{}...", truncated)
-        } else {
-            tab.phase.clone()
-        };
+        let display_name = truncate_to_width(&tab.phase, 12);
 
         let style = if is_active {
             Style::default()
@@ -453,22 +517,12 @@ pub(super) fn draw_run_tabs(frame: &mut Frame, session: &Session, area: Rect) {
     frame.render_widget(tabs, area);
 }
 
-/// Truncate input_preview to a maximum length with ellipsis
-fn truncate_input_preview(preview: &str, max_len: usize) -> String {
-    if preview.chars().count() <= max_len {
-        preview.to_string()
-    } else {
-        let truncated: String = preview.chars().take(max_len.saturating_sub(3)).collect();
-        format!("{}...", truncated)
-    }
-}
-
 /// Format tool label with display_name and optional input_preview
 fn format_tool_label(display_name: &str, input_preview: &str, max_preview_len: usize) -> String {
     if input_preview.is_empty() {
         display_name.to_string()
     } else {
-        let truncated = truncate_input_preview(input_preview, max_preview_len);
+        let truncated = truncate_to_width(input_preview, max_preview_len);
         format!("{}: {}", display_name, truncated)
     }
 }
@@ -484,7 +538,7 @@ fn format_result_summary(summary: &ToolResultSummary, max_len: usize) -> String
     } else {
         first_line
     };
-    let truncated_line = truncate_input_preview(display_line, max_len);
+    let truncated_line = truncate_to_width(display_line, max_len);
 
     if summary.line_count == 1 && !summary.truncated {
         return truncated_line;
@@ -591,12 +645,7 @@ pub(super) fn draw_reviewer_history_panel(
                         } else {
                             ("✗".to_string(), theme.error)
                         };
-                        let summary_preview = if summary.chars().count() > 30 {
-                            let truncated: String = summary.chars().take(27).collect();
-                            format!("{}...", truncated)
-                        } else {
-                            summary.clone()
-                        };
+                        let summary_preview = truncate_to_width(summary, 30);
                         (
                             icon,
                             color,
@@ -604,14 +653,18 @@ pub(super) fn draw_reviewer_history_panel(
                         )
                     }
                     ReviewerStatus::Failed { error } => {
-                        let error_preview = if error.chars().count() > 25 {
-                            let truncated: String = error.chars().take(22).collect();
-                            format!("{}...", truncated)
-                        } else {
-                            error.clone()
-                        };
+                        let error_preview = truncate_to_width(error, 25);
                         ("!".to_string(), theme.error, format!(" {}", error_preview))
                     }
+                    ReviewerStatus::Substituted { substitute_id } => (
+                        "⇄".to_string(),
+                        theme.warning,
+                        format!(" replaced by {}", substitute_id),
+                    ),
+                };
+                let suffix = match &entry.substitute_for {
+                    Some(failed_id) => format!(" (backup for {}){}", failed_id, suffix),
+                    None => suffix,
                 };
 
                 lines.push(Line::from(vec![
@@ -22,6 +22,7 @@ pub const CLI_INSTANCES_MIN_HEIGHT: u16 = 3;
 /// - Agent name and PID (or #id fallback)
 /// - Elapsed time since start
 /// - Idle time since last activity
+/// - Invocations queued for a provider slot, with how long they have waited
 ///
 /// Shows "(none)" when no instances are active.
 pub fn draw_cli_instances(frame: &mut Frame, session: &Session, area: Rect) {
@@ -52,6 +53,21 @@ pub fn draw_cli_instances(frame: &mut Frame, session: &Session, area: Rect) {
         };
 
         for instance in instances.iter().take(display_capacity) {
+            if instance.queued {
+                result.push(Line::from(vec![
+                    Span::styled("⏸ ", Style::default().fg(theme.muted)),
+                    Span::styled(instance.agent_name.clone(), Style::default().fg(theme.text)),
+                    Span::styled(
+                        " | waiting for provider slot ",
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(
+                        format_duration(instance.elapsed()),
+                        Style::default().fg(theme.cli_idle),
+                    ),
+                ]));
+                continue;
+            }
             let elapsed = format_duration(instance.elapsed());
             let idle = format_duration(instance.idle());
 
@@ -2,6 +2,7 @@
 //!
 //! Contains the rendering logic for @-mention and slash command dropdowns.
 
+use super::util::truncate_to_width;
 use crate::tui::mention::MentionState;
 use crate::tui::slash::SlashState;
 use ratatui::{
@@ -98,10 +99,16 @@ pub fn draw_mention_dropdown(
         })
         .collect();
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan))
         .title(" Files & Folders (↑/↓ to navigate, Tab/Enter to select) ");
+    if let Some(note) = &mention_state.index_note {
+        block = block.title_bottom(Line::from(Span::styled(
+            format!(" {} ", note),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
 
     let dropdown = Paragraph::new(items).block(block);
     frame.render_widget(dropdown, dropdown_area);
@@ -203,16 +210,7 @@ pub fn draw_slash_dropdown(
                 .saturating_sub(separator.len());
 
             // Truncate description if needed
-            let desc: String = if m.description.chars().count() > desc_space {
-                let truncated: String = m
-                    .description
-                    .chars()
-                    .take(desc_space.saturating_sub(3))
-                    .collect();
-                format!("{}...", truncated)
-            } else {
-                m.description.clone()
-            };
+            let desc = truncate_to_width(&m.description, desc_space);
 
             let style = if is_selected {
                 Style::default()
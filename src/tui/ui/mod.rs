@@ -12,11 +12,13 @@ pub mod util;
 #[path = "tests/overlays_tests.rs"]
 mod overlays_tests;
 
+pub use overlays::{error_overlay_lines, review_modal_lines};
+
 use crate::tui::scroll::ScrollableRegions;
-use crate::tui::{ApprovalMode, InputMode, Session, SessionStatus, TabManager};
+use crate::tui::{ApprovalMode, DirConflict, InputMode, Session, SessionStatus, TabManager};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -117,20 +119,25 @@ pub fn draw(frame: &mut Frame, tab_manager: &TabManager, scroll_regions: &mut Sc
     // Clear scroll regions at start of each frame
     scroll_regions.clear();
 
+    let dir_conflict = tab_manager.active().dir_conflict.as_ref();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(2),
+            Constraint::Length(u16::from(dir_conflict.is_some())),
             Constraint::Min(0),
             Constraint::Length(3),
         ])
         .split(frame.area());
 
     draw_tab_bar(frame, tab_manager, chunks[0]);
+    if let Some(conflict) = dir_conflict {
+        draw_dir_conflict_banner(frame, tab_manager.active(), conflict, chunks[1]);
+    }
 
     let session = tab_manager.active();
-    panels::draw_main(frame, session, chunks[1], scroll_regions);
-    overlays::draw_footer(frame, session, tab_manager, chunks[2]);
+    panels::draw_main(frame, session, chunks[2], scroll_regions);
+    overlays::draw_footer(frame, session, tab_manager, chunks[3]);
 
     let session = tab_manager.active();
     if session.approval_mode != ApprovalMode::None {
@@ -143,6 +150,9 @@ pub fn draw(frame: &mut Frame, tab_manager: &TabManager, scroll_regions: &mut Sc
     let session = tab_manager.active();
     if session.plan_modal_open {
         overlays::draw_plan_modal(frame, session, scroll_regions);
+        if let Some(viewer) = &session.file_viewer {
+            overlays::draw_file_viewer(frame, viewer);
+        }
     }
     // Render review modal BEFORE error overlay so errors always take precedence
     let session = tab_manager.active();
@@ -157,17 +167,67 @@ pub fn draw(frame: &mut Frame, tab_manager: &TabManager, scroll_regions: &mut Sc
     if tab_manager.workflow_browser.open {
         overlays::draw_workflow_browser_overlay(frame, tab_manager);
     }
-    // Render implementation success modal after session browser, before error overlay
+    // Render config editor overlay
+    if tab_manager.config_editor.open {
+        overlays::draw_config_editor_overlay(frame, tab_manager);
+    }
+    if tab_manager.notifications.open {
+        overlays::draw_notification_overlay(frame, tab_manager);
+    }
+    if tab_manager.approval_queue.open {
+        overlays::draw_approval_queue_overlay(frame, tab_manager);
+    }
+    if tab_manager.recent_tabs.open {
+        overlays::draw_recent_tabs_overlay(frame, tab_manager);
+    }
+    // Render implementation success modal after session browser, before error overlay.
+    // It takes keys before the completion summary, so it is drawn on top of it.
     let session = tab_manager.active();
+    if session.completion_summary.is_some() {
+        overlays::draw_completion_summary_overlay(frame, session);
+    }
     if session.implementation_success_modal.is_some() {
         overlays::draw_implementation_success_overlay(frame, session);
     }
     let session = tab_manager.active();
+    if session.conflict_modal.is_some() {
+        overlays::draw_conflict_overlay(frame, session);
+    }
+    if session.feedback_triage.is_some() {
+        overlays::draw_triage_overlay(frame, session);
+    }
+    let session = tab_manager.active();
+    if session.current_tool_permission().is_some() {
+        overlays::draw_permission_overlay(frame, session);
+    } else if session.workspace_trust_request.is_some() {
+        overlays::draw_workspace_trust_overlay(frame, session);
+    }
+    let session = tab_manager.active();
     if session.error_state.is_some() {
         overlays::draw_error_overlay(frame, session, scroll_regions);
     }
 }
 
+/// One-line warning that other live sessions work in the same checkout.
+fn draw_dir_conflict_banner(
+    frame: &mut Frame,
+    session: &Session,
+    conflict: &DirConflict,
+    area: Rect,
+) {
+    let theme = theme::Theme::for_session(session);
+    let style = Style::default()
+        .fg(Color::Black)
+        .bg(theme.warning)
+        .add_modifier(Modifier::BOLD);
+    let text = format!(
+        " ⚠ {} also works in {} - edits can collide. [W] move this session into a worktree",
+        conflict.others.join(", "),
+        conflict.working_dir.display()
+    );
+    frame.render_widget(Paragraph::new(Line::from(text)).style(style), area);
+}
+
 fn draw_tab_bar(frame: &mut Frame, tab_manager: &TabManager, area: Rect) {
     use overlays::{build_phase_spans, PhaseDisplayMode};
     use unicode_width::UnicodeWidthStr;
@@ -231,6 +291,23 @@ fn draw_tab_bar(frame: &mut Frame, tab_manager: &TabManager, area: Rect) {
     } else {
         "Planning Agent ".to_string()
     };
+    let right_section = match tab_manager.macros.recording_register() {
+        Some(register) => format!("recording @{} | {}", register, right_section),
+        None => right_section,
+    };
+    let waiting = tab_manager
+        .pending_approvals()
+        .iter()
+        .filter(|session| session.id != active_session.id)
+        .count();
+    let right_section = match waiting {
+        0 => right_section,
+        waiting => format!("⏳ {} [a] | {}", waiting, right_section),
+    };
+    let right_section = match tab_manager.notifications.unread() {
+        0 => right_section,
+        unread => format!("🔔 {} [n] | {}", unread, right_section),
+    };
 
     // Build middle section: session tabs (refactored from existing logic)
     let mut tab_spans: Vec<Span> = Vec::new();
@@ -242,12 +319,7 @@ fn draw_tab_bar(frame: &mut Frame, tab_manager: &TabManager, area: Rect) {
         } else {
             &session.name
         };
-        let display_name: String = if name.chars().count() > 15 {
-            let truncated: String = name.chars().take(12).collect();
-            format!("{}...", truncated)
-        } else {
-            name.to_string()
-        };
+        let display_name = util::truncate_to_width(name, 15);
         let label = if status_icon_str.is_empty() {
             format!("[{}]", display_name)
         } else {
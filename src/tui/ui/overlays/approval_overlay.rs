@@ -156,16 +156,31 @@ fn draw_choice_popup(
     }
 
     let instructions = match session.approval_context {
-        ApprovalContext::PlanApproval => Paragraph::new(vec![Line::from(vec![
-            Span::styled("  [a] ", Style::default().fg(Color::Green).bold()),
-            Span::raw("Accept  "),
-            Span::styled("  [i] ", Style::default().fg(Color::Magenta).bold()),
-            Span::raw("Implement  "),
-            Span::styled("  [d] ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw("Decline  "),
-            Span::styled("  [j/k] ", Style::default().fg(Color::Cyan).bold()),
-            Span::raw("Scroll"),
-        ])]),
+        ApprovalContext::PlanApproval => {
+            let mut spans = vec![
+                Span::styled("  [a] ", Style::default().fg(Color::Green).bold()),
+                Span::raw("Accept  "),
+                Span::styled("  [i] ", Style::default().fg(Color::Magenta).bold()),
+                Span::raw("Implement  "),
+                Span::styled("  [c] ", Style::default().fg(Color::Green).bold()),
+                Span::raw("Conditions  "),
+                Span::styled("  [d] ", Style::default().fg(Color::Yellow).bold()),
+                Span::raw("Decline  "),
+            ];
+            if session.can_appeal_rejection() {
+                spans.push(Span::styled(
+                    "  [p] ",
+                    Style::default().fg(Color::Magenta).bold(),
+                ));
+                spans.push(Span::raw("Appeal  "));
+            }
+            spans.push(Span::styled(
+                "  [j/k] ",
+                Style::default().fg(Color::Cyan).bold(),
+            ));
+            spans.push(Span::raw("Scroll"));
+            Paragraph::new(vec![Line::from(spans), conditions_line(session)])
+        }
         ApprovalContext::ReviewDecision => Paragraph::new(vec![Line::from(vec![
             Span::styled("  [c] ", Style::default().fg(Color::Green).bold()),
             Span::raw("Continue  "),
@@ -184,24 +199,42 @@ fn draw_choice_popup(
             Span::styled("  [j/k] ", Style::default().fg(Color::Cyan).bold()),
             Span::raw("Scroll"),
         ])]),
-        ApprovalContext::MaxIterationsReached => Paragraph::new(vec![Line::from(vec![
-            Span::styled("  [y] ", Style::default().fg(Color::Green).bold()),
-            Span::raw("Proceed  "),
-            Span::styled("  [c] ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw("Continue Review  "),
-            Span::styled("  [d] ", Style::default().fg(Color::Cyan).bold()),
-            Span::raw("Decline/Restart  "),
-            Span::styled("  [a] ", Style::default().fg(Color::Red).bold()),
-            Span::raw("Abort"),
-        ])]),
-        ApprovalContext::UserOverrideApproval => Paragraph::new(vec![Line::from(vec![
-            Span::styled("  [i] ", Style::default().fg(Color::Green).bold()),
-            Span::raw("Implement  "),
-            Span::styled("  [d] ", Style::default().fg(Color::Yellow).bold()),
-            Span::raw("Decline  "),
-            Span::styled("  [j/k] ", Style::default().fg(Color::Cyan).bold()),
-            Span::raw("Scroll"),
-        ])]),
+        ApprovalContext::MaxIterationsReached => {
+            let mut spans = vec![
+                Span::styled("  [y] ", Style::default().fg(Color::Green).bold()),
+                Span::raw("Proceed  "),
+                Span::styled("  [c] ", Style::default().fg(Color::Yellow).bold()),
+                Span::raw("Continue Review  "),
+                Span::styled("  [d] ", Style::default().fg(Color::Cyan).bold()),
+                Span::raw("Decline/Restart  "),
+            ];
+            if session.can_appeal_rejection() {
+                spans.push(Span::styled(
+                    "  [p] ",
+                    Style::default().fg(Color::Magenta).bold(),
+                ));
+                spans.push(Span::raw("Appeal  "));
+            }
+            spans.push(Span::styled(
+                "  [a] ",
+                Style::default().fg(Color::Red).bold(),
+            ));
+            spans.push(Span::raw("Abort"));
+            Paragraph::new(vec![Line::from(spans)])
+        }
+        ApprovalContext::UserOverrideApproval => Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("  [i] ", Style::default().fg(Color::Green).bold()),
+                Span::raw("Implement  "),
+                Span::styled("  [c] ", Style::default().fg(Color::Green).bold()),
+                Span::raw("Conditions  "),
+                Span::styled("  [d] ", Style::default().fg(Color::Yellow).bold()),
+                Span::raw("Decline  "),
+                Span::styled("  [j/k] ", Style::default().fg(Color::Cyan).bold()),
+                Span::raw("Scroll"),
+            ]),
+            conditions_line(session),
+        ]),
         ApprovalContext::AllReviewersFailed | ApprovalContext::WorkflowFailure => {
             Paragraph::new(vec![Line::from(vec![
                 Span::styled("  [r] ", Style::default().fg(Color::Yellow).bold()),
@@ -223,6 +256,20 @@ fn draw_choice_popup(
     frame.render_widget(instructions, chunks[2]);
 }
 
+/// Shows how many approval conditions will be sent with the accept choice.
+fn conditions_line(session: &Session) -> Line<'static> {
+    match session.approval_conditions.as_deref() {
+        Some(conditions) => {
+            let count = conditions.lines().filter(|l| !l.trim().is_empty()).count();
+            Line::from(Span::styled(
+                format!("  {} condition(s) will be appended to the plan", count),
+                Style::default().fg(Color::Green),
+            ))
+        }
+        None => Line::default(),
+    }
+}
+
 fn draw_feedback_popup(frame: &mut Frame, session: &Session, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -242,6 +289,19 @@ fn draw_feedback_popup(frame: &mut Frame, session: &Session, area: Rect) {
             " Interrupt Workflow ",
             Color::Magenta,
         ),
+        FeedbackTarget::ApprovalConditions => (
+            " Conditions of approval ",
+            " Approve with Conditions ",
+            Color::Green,
+        ),
+        FeedbackTarget::Command => (" Run a command ", " Command ", Color::Cyan),
+        FeedbackTarget::Objective => (" Edit the objective ", " Objective ", Color::Yellow),
+        FeedbackTarget::Notes => (" Session notes ", " Notes ", Color::Cyan),
+        FeedbackTarget::Appeal => (
+            " Why should the reviewers reconsider? ",
+            " Appeal Rejection ",
+            Color::Cyan,
+        ),
     };
 
     let title = Paragraph::new(Line::from(vec![Span::styled(
@@ -270,6 +330,13 @@ fn draw_feedback_popup(frame: &mut Frame, session: &Session, area: Rect) {
     let input_title = match session.feedback_target {
         FeedbackTarget::ApprovalDecline => " Your Feedback ",
         FeedbackTarget::WorkflowInterrupt => " Interrupt Message ",
+        FeedbackTarget::ApprovalConditions => " Conditions (one per line) ",
+        FeedbackTarget::Command => {
+            " /tail plan | /tail feedback | /rename <name> | /reviewers [preset] | /edit-objective | /notes "
+        }
+        FeedbackTarget::Objective => " Objective ",
+        FeedbackTarget::Notes => " Notes (saved in the session directory) ",
+        FeedbackTarget::Appeal => " Counter-Argument ",
     };
 
     let input_block = Block::default()
@@ -325,6 +392,11 @@ fn draw_feedback_popup(frame: &mut Frame, session: &Session, area: Rect) {
     let submit_label = match session.feedback_target {
         FeedbackTarget::ApprovalDecline => "Submit  ",
         FeedbackTarget::WorkflowInterrupt => "Interrupt & Restart  ",
+        FeedbackTarget::ApprovalConditions => "Save Conditions  ",
+        FeedbackTarget::Command => "Run  ",
+        FeedbackTarget::Objective => "Restart Planning  ",
+        FeedbackTarget::Notes => "Save Notes  ",
+        FeedbackTarget::Appeal => "Send Appeal  ",
     };
     let instructions = Paragraph::new(Line::from(vec![
         Span::styled("  [Enter] ", Style::default().fg(Color::Green).bold()),
@@ -0,0 +1,213 @@
+//! Config editor overlay for viewing and editing the effective workflow config.
+
+use crate::tui::config_editor::{display_value, ConfigRow, RowKind};
+use crate::tui::ui::util::truncate_to_width;
+use crate::tui::TabManager;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Draw the config editor overlay showing the workflow config tree.
+pub fn draw_config_editor_overlay(frame: &mut Frame, tab_manager: &TabManager) {
+    let editor = &tab_manager.config_editor;
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.75).min(100.0) as u16;
+    let popup_height = (area.height as f32 * 0.80).min(36.0) as u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Min(0),    // Config tree
+            Constraint::Length(1), // Error / notice
+            Constraint::Length(2), // Instructions
+        ])
+        .split(popup_area);
+
+    // Title block
+    let title_width = chunks[0].width.saturating_sub(4) as usize;
+    let title = Paragraph::new(Line::from(vec![Span::styled(
+        format!(" {}", truncate_to_width(&editor.header(), title_width)),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" /config "),
+    );
+    frame.render_widget(title, chunks[0]);
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .title(" Effective config ");
+    let inner_area = list_block.inner(chunks[1]);
+    let visible_height = inner_area.height as usize;
+    let inner_width = inner_area.width as usize;
+
+    let lines: Vec<Line> = editor
+        .rows
+        .iter()
+        .enumerate()
+        .skip(editor.scroll_offset)
+        .take(visible_height)
+        .map(|(i, row)| {
+            let is_selected = i == editor.selected_idx;
+            let editing = editor.editing.as_deref().filter(|_| is_selected);
+            render_row(row, is_selected, editing, inner_width)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    if editor.rows.len() > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(editor.rows.len())
+            .viewport_content_length(visible_height)
+            .position(editor.scroll_offset);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("↑"))
+                .end_symbol(Some("↓")),
+            chunks[1],
+            &mut scrollbar_state,
+        );
+    }
+
+    // Error or notice from the last edit
+    let status = if let Some(ref error) = editor.error {
+        Span::styled(format!(" {}", error), Style::default().fg(Color::Red))
+    } else if let Some(ref notice) = editor.notice {
+        Span::styled(format!(" {}", notice), Style::default().fg(Color::Green))
+    } else {
+        Span::styled(
+            " Edits rewrite the workflow file; YAML comments are not kept",
+            Style::default().fg(Color::DarkGray),
+        )
+    };
+    frame.render_widget(Paragraph::new(Line::from(status)), chunks[2]);
+
+    // Instructions
+    let instructions = if editor.editing.is_some() {
+        vec![
+            Span::styled(
+                " [Enter] ",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Save "),
+            Span::styled(
+                " [Esc] ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Cancel edit"),
+        ]
+    } else {
+        vec![
+            Span::styled(
+                " [j/k] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Navigate "),
+            Span::styled(
+                " [h/l] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Collapse/Expand "),
+            Span::styled(
+                " [Enter] ",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Edit/Toggle "),
+            Span::styled(
+                " [Esc] ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Close"),
+        ]
+    };
+    let instructions = Paragraph::new(Line::from(instructions)).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(instructions, chunks[3]);
+}
+
+/// Renders one tree row: indented label, value, and right-aligned source.
+fn render_row(
+    row: &ConfigRow,
+    is_selected: bool,
+    editing: Option<&str>,
+    width: usize,
+) -> Line<'static> {
+    let style = if is_selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+    let prefix = if is_selected { " > " } else { "   " };
+    let indent = "  ".repeat(row.depth);
+
+    let (marker, value) = match &row.kind {
+        RowKind::Section { expanded: true } => ("▾ ", String::new()),
+        RowKind::Section { expanded: false } => ("▸ ", String::new()),
+        RowKind::Reviewer { enabled: true } => ("[x] ", String::new()),
+        RowKind::Reviewer { enabled: false } => ("[ ] ", String::new()),
+        RowKind::Value { value, .. } => match editing {
+            Some(buffer) => ("", format!(": {}_", buffer)),
+            None => ("", format!(": {}", display_value(value))),
+        },
+    };
+    let source = row
+        .source
+        .map(|s| format!(" [{}]", s.label()))
+        .unwrap_or_default();
+
+    let left = format!("{}{}{}{}", prefix, indent, marker, row.label);
+    let value_width = width
+        .saturating_sub(left.width())
+        .saturating_sub(source.width());
+    let value = truncate_to_width(&value, value_width);
+    let padding = value_width.saturating_sub(value.width());
+
+    let value_style = match (&row.kind, editing) {
+        (_, Some(_)) => Style::default().fg(Color::Yellow),
+        (RowKind::Reviewer { enabled: false }, _) => Style::default().fg(Color::DarkGray),
+        (
+            RowKind::Value {
+                editable: false, ..
+            },
+            _,
+        ) => Style::default().fg(Color::DarkGray),
+        _ => Style::default().fg(Color::White),
+    };
+
+    Line::from(vec![
+        Span::styled(left, style),
+        Span::styled(value, value_style),
+        Span::raw(" ".repeat(padding)),
+        Span::styled(source, Style::default().fg(Color::DarkGray)),
+    ])
+}
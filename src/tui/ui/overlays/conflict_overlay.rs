@@ -0,0 +1,130 @@
+//! Worktree conflict resolution overlay rendering.
+
+use crate::git_worktree::ConflictChoice;
+use crate::tui::Session;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+fn resolution_span(resolution: Option<ConflictChoice>) -> Span<'static> {
+    match resolution {
+        None => Span::styled("[conflict]", Style::default().fg(Color::Red)),
+        Some(ConflictChoice::Ours) => Span::styled("[ours]    ", Style::default().fg(Color::Green)),
+        Some(ConflictChoice::Theirs) => {
+            Span::styled("[theirs]  ", Style::default().fg(Color::Green))
+        }
+        Some(ConflictChoice::Edited) => {
+            Span::styled("[edited]  ", Style::default().fg(Color::Green))
+        }
+    }
+}
+
+pub fn draw_conflict_overlay(frame: &mut Frame, session: &Session) {
+    let Some(ref modal) = session.conflict_modal else {
+        return;
+    };
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.7).min(90.0) as u16;
+    // Borders (2) + header (2) + files + status (2) + instructions (1)
+    let max_popup_height = (area.height as f32 * 0.8) as u16;
+    let ideal_popup_height = (modal.files.len() as u16).saturating_add(7);
+    let popup_height = ideal_popup_height.clamp(8u16.min(max_popup_height), max_popup_height);
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Instructions
+        ])
+        .split(popup_area);
+
+    let source = modal.worktree.source_branch().unwrap_or("main");
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!(" Resolve {} Conflicts ", modal.method.label()));
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw(" ours = "),
+            Span::styled(
+                modal.worktree.branch_name().to_string(),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("  theirs = "),
+            Span::styled(source.to_string(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    // Keep the selected file visible when the list is taller than the popup
+    let inner_height = block.inner(chunks[0]).height as usize;
+    let list_height = inner_height.saturating_sub(4).max(1);
+    let first_visible = modal.selected.saturating_sub(list_height.saturating_sub(1));
+
+    for (i, file) in modal
+        .files
+        .iter()
+        .enumerate()
+        .skip(first_visible)
+        .take(list_height)
+    {
+        let selected = i == modal.selected;
+        let marker = if selected { "> " } else { "  " };
+        let name_style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::raw(marker),
+            resolution_span(file.resolution),
+            Span::raw(" "),
+            Span::styled(file.path.clone(), name_style),
+        ]));
+    }
+
+    if let Some(ref status) = modal.status {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            status.clone(),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    let content = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(content, chunks[0]);
+
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Yellow));
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::raw(" "),
+        key("[o]"),
+        Span::raw(" ours "),
+        key("[t]"),
+        Span::raw(" theirs "),
+        key("[e]"),
+        Span::raw(" edited "),
+        key("[a]"),
+        Span::raw(" agent "),
+        key("[r]"),
+        Span::raw(" refresh "),
+        key("[Enter]"),
+        Span::raw(" continue "),
+        key("[Esc]"),
+        Span::raw(" abort"),
+    ]));
+    frame.render_widget(instructions, chunks[1]);
+}
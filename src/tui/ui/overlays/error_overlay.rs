@@ -1,11 +1,11 @@
 //! Error overlay rendering.
 
-use super::super::util::compute_wrapped_line_count_text;
+use super::super::util::compute_wrapped_line_count;
 use crate::tui::scroll::{ScrollRegion, ScrollableRegions};
-use crate::tui::Session;
+use crate::tui::{ErrorReport, ErrorSeverity, Session};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
         Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
@@ -13,23 +13,96 @@ use ratatui::{
     Frame,
 };
 
+fn severity_color(severity: ErrorSeverity) -> Color {
+    match severity {
+        ErrorSeverity::Warning => Color::Yellow,
+        ErrorSeverity::Error => Color::Red,
+        ErrorSeverity::Fatal => Color::LightRed,
+    }
+}
+
+/// Scrollable body of the error overlay. Shared with the input handler so scroll
+/// limits match what is drawn.
+pub fn error_overlay_lines(report: &ErrorReport) -> Vec<Line<'static>> {
+    let color = severity_color(report.severity);
+    let label = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Component: ", label),
+            Span::raw(report.component.clone()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            report.message.clone(),
+            Style::default().fg(color),
+        )),
+    ];
+
+    if !report.recent_lines.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Recent log lines:", label)));
+        for line in &report.recent_lines {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", line),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+    }
+
+    if let Some(stderr) = &report.agent_stderr {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("{} stderr:", stderr.agent_name),
+            label,
+        )));
+        for line in &stderr.lines {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", line),
+                Style::default().fg(Color::Magenta),
+            )));
+        }
+        if let Some(path) = &stderr.log_path {
+            lines.push(Line::from(Span::styled(
+                format!("  Full output: {}", path.display()),
+                label,
+            )));
+        }
+    }
+
+    if !report.actions.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Suggested actions:", label)));
+        for action in &report.actions {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  [{}] ", action.key()),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(action.label()),
+            ]));
+        }
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
 pub fn draw_error_overlay(frame: &mut Frame, session: &Session, regions: &mut ScrollableRegions) {
-    if let Some(ref error) = session.error_state {
+    if let Some(report) = session.error_report() {
         let area = frame.area();
 
-        let popup_width = (area.width as f32 * 0.6).min(70.0) as u16;
+        let popup_width = (area.width as f32 * 0.7).min(90.0) as u16;
         // Calculate inner width for wrapping (popup width minus borders)
         let inner_width = popup_width.saturating_sub(2);
 
-        // Compute wrapped line count for the error text
-        let wrapped_error_lines = compute_wrapped_line_count_text(error, inner_width);
+        let content_lines = error_overlay_lines(&report);
+        let total_content_lines = compute_wrapped_line_count(&content_lines, inner_width);
 
-        // Error layout: border (1) + empty line (1) + error text + empty line (1) + instructions (1) + border (1)
-        // = 5 + wrapped_error_lines
+        // Layout: border (1) + content + border (1) + instructions (1)
         // Cap popup height at 80% of terminal height
         let max_popup_height = (area.height as f32 * 0.8) as u16;
         let min_popup_height = 8u16;
-        let ideal_popup_height = (wrapped_error_lines as u16).saturating_add(5);
+        let ideal_popup_height = (total_content_lines as u16).saturating_add(3);
         let popup_height = ideal_popup_height.clamp(min_popup_height, max_popup_height);
 
         let popup_x = (area.width.saturating_sub(popup_width)) / 2;
@@ -48,16 +121,21 @@ pub fn draw_error_overlay(frame: &mut Frame, session: &Session, regions: &mut Sc
             ])
             .split(popup_area);
 
+        let color = severity_color(report.severity);
         let error_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Red))
-            .title(" Error (j/k to scroll) ");
+            .border_style(Style::default().fg(color))
+            .title(Span::styled(
+                format!(
+                    " {}: {} (j/k to scroll) ",
+                    report.severity.label(),
+                    report.category.label()
+                ),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
 
         let inner_area = error_block.inner(chunks[0]);
         let visible_height = inner_area.height as usize;
-
-        // Total lines = 1 (empty) + error lines + 1 (empty)
-        let total_content_lines = wrapped_error_lines + 2;
         let max_scroll = total_content_lines.saturating_sub(visible_height);
 
         // Register scrollable region with computed max_scroll
@@ -65,14 +143,10 @@ pub fn draw_error_overlay(frame: &mut Frame, session: &Session, regions: &mut Sc
 
         let scroll_pos = session.error_scroll.min(max_scroll);
 
-        let error_paragraph = Paragraph::new(vec![
-            Line::from(""),
-            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
-            Line::from(""),
-        ])
-        .block(error_block)
-        .wrap(Wrap { trim: false })
-        .scroll((scroll_pos as u16, 0));
+        let error_paragraph = Paragraph::new(content_lines)
+            .block(error_block)
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_pos as u16, 0));
         frame.render_widget(error_paragraph, chunks[0]);
 
         // Show scrollbar if content exceeds visible area
@@ -89,13 +163,18 @@ pub fn draw_error_overlay(frame: &mut Frame, session: &Session, regions: &mut Sc
             );
         }
 
-        // Instructions line
-        let instructions = Paragraph::new(Line::from(vec![
+        // Instructions line, Enter runs the first suggested action
+        let mut instructions = Vec::new();
+        if let Some(action) = report.actions.first() {
+            instructions.push(Span::styled("  [Enter]", Style::default().fg(Color::Green)));
+            instructions.push(Span::raw(format!(" {} ", action.label())));
+        }
+        instructions.extend([
             Span::styled("  [Esc]", Style::default().fg(Color::Yellow)),
             Span::raw(" Close  "),
             Span::styled("[Ctrl+W]", Style::default().fg(Color::Red)),
             Span::raw(" Close Tab"),
-        ]));
-        frame.render_widget(instructions, chunks[1]);
+        ]);
+        frame.render_widget(Paragraph::new(Line::from(instructions)), chunks[1]);
     }
 }
@@ -1,14 +1,26 @@
 pub mod approval_overlay;
+pub mod config_editor_overlay;
+pub mod conflict_overlay;
 pub mod error_overlay;
+pub mod notification_overlay;
+pub mod permission_overlay;
 mod render_helpers;
 pub mod session_browser_overlay;
 pub mod success_overlay;
+pub mod triage_overlay;
 pub mod workflow_browser_overlay;
 
 pub use approval_overlay::draw_approval_overlay;
-pub use error_overlay::draw_error_overlay;
+pub use config_editor_overlay::draw_config_editor_overlay;
+pub use conflict_overlay::draw_conflict_overlay;
+pub use error_overlay::{draw_error_overlay, error_overlay_lines};
+pub use notification_overlay::{
+    draw_approval_queue_overlay, draw_notification_overlay, draw_recent_tabs_overlay,
+};
+pub use permission_overlay::{draw_permission_overlay, draw_workspace_trust_overlay};
 pub use session_browser_overlay::draw_session_browser_overlay;
-pub use success_overlay::draw_implementation_success_overlay;
+pub use success_overlay::{draw_completion_summary_overlay, draw_implementation_success_overlay};
+pub use triage_overlay::draw_triage_overlay;
 pub use workflow_browser_overlay::draw_workflow_browser_overlay;
 
 use render_helpers::{render_command_line, render_update_line};
@@ -17,13 +29,15 @@ use super::dropdowns::{draw_mention_dropdown, draw_slash_dropdown};
 use super::theme::Theme;
 use super::util::{compute_wrapped_line_count, parse_markdown_line, wrap_text_at_width};
 use crate::domain::types::{ImplementationPhase, Phase, UiMode};
+use crate::phases::feedback_items::AnnotationStance;
 use crate::tui::scroll::{ScrollRegion, ScrollableRegions};
 use crate::tui::session::modals::review_modal::compute_tab_viewport;
+use crate::tui::session::FileViewer;
 use crate::tui::{ApprovalMode, FocusedPanel, Session, TabManager};
 use crate::update::UpdateStatus;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
         Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
@@ -233,7 +247,7 @@ pub fn draw_footer(frame: &mut Frame, session: &Session, tab_manager: &TabManage
         ));
     } else {
         spans.push(Span::styled(
-            "Tabs: [Ctrl+PgUp/Dn] Switch  [Ctrl+W] Close",
+            "Tabs: [Ctrl+PgUp/Dn] Switch  [Ctrl+Tab] Recent  [Ctrl+W] Close",
             Style::default().fg(theme.muted),
         ));
     }
@@ -241,11 +255,32 @@ pub fn draw_footer(frame: &mut Frame, session: &Session, tab_manager: &TabManage
     if session.workflow_view.is_some() {
         spans.push(Span::styled(" │ ", Style::default().fg(theme.muted)));
         spans.push(Span::styled(
-            "[p] Plan  [v] Reviews",
+            "[p] Plan  [v] Reviews  [/] Command",
             Style::default().fg(theme.border),
         ));
     }
 
+    if let Some(alert) = &session.cost_alert {
+        spans.push(Span::styled(" │ ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled(
+            format!("⚠ {}", alert),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if let Some(suspended) = tab_manager.suspended_sessions.last() {
+        spans.push(Span::styled(" │ ", Style::default().fg(theme.muted)));
+        spans.push(Span::styled(
+            format!(
+                "'{}' suspended (idle)  [Ctrl+R] Resume",
+                suspended.feature_name
+            ),
+            Style::default().fg(theme.warning),
+        ));
+    }
+
     let daemon_indicator = if tab_manager.daemon_connected {
         Span::styled("● ", Style::default().fg(theme.success))
     } else {
@@ -338,11 +373,17 @@ pub fn draw_tab_input_overlay(frame: &mut Frame, session: &Session, tab_manager:
         .constraints(constraints)
         .split(popup_area);
 
-    let title = Paragraph::new(Line::from(vec![Span::styled(
+    let mut title_spans = vec![Span::styled(
         "Enter planning objective:",
         Style::default().fg(Color::Cyan).bold(),
-    )]))
-    .block(
+    )];
+    if let Some(ref estimate) = tab_manager.cost_estimate {
+        title_spans.push(Span::styled(
+            format!("  {}", estimate),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    let title = Paragraph::new(Line::from(title_spans)).block(
         Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan))
@@ -509,16 +550,31 @@ pub fn draw_plan_modal(frame: &mut Frame, session: &Session, regions: &mut Scrol
         .and_then(|v| v.plan_path())
         .map(|p| p.as_path().display().to_string())
         .unwrap_or_else(|| "Plan".to_string());
+    let (shown_path, block_title, content_title) = match &session.plan_modal_tail {
+        Some(tail) => (
+            tail.path
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| format!("waiting for {}", tail.target.label())),
+            format!(" Tail: {} ", tail.target.label()),
+            " Live (j/k to scroll, G to follow) ",
+        ),
+        None => (
+            plan_path,
+            " Plan File ".to_string(),
+            " Content (j/k to scroll) ",
+        ),
+    };
 
     let title = Paragraph::new(Line::from(vec![Span::styled(
-        format!(" {} ", plan_path),
+        format!(" {} ", shown_path),
         Style::default().fg(Color::Cyan).bold(),
     )]))
     .block(
         Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan))
-            .title(" Plan File "),
+            .title(block_title),
     );
     frame.render_widget(title, chunks[0]);
 
@@ -526,16 +582,29 @@ pub fn draw_plan_modal(frame: &mut Frame, session: &Session, regions: &mut Scrol
     let content_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
-        .title(" Content (j/k to scroll) ");
+        .title(content_title);
 
     let inner_area = content_block.inner(chunks[1]);
     let visible_height = inner_area.height as usize;
     let inner_width = inner_area.width;
 
+    let reference = session.plan_modal_reference.as_ref();
     let content_lines: Vec<Line> = session
         .plan_modal_content
         .lines()
-        .map(parse_markdown_line)
+        .enumerate()
+        .map(|(i, line)| match reference {
+            // The selected reference's line is shown as written to highlight it
+            Some(r) if r.plan_line == i && line.get(r.span.clone()).is_some() => Line::from(vec![
+                Span::raw(line[..r.span.start].to_string()),
+                Span::styled(
+                    line[r.span.clone()].to_string(),
+                    Style::default().fg(Color::Black).bg(Color::Yellow),
+                ),
+                Span::raw(line[r.span.end..].to_string()),
+            ]),
+            _ => parse_markdown_line(line),
+        })
         .collect();
 
     let total_lines = compute_wrapped_line_count(&content_lines, inner_width);
@@ -574,6 +643,10 @@ pub fn draw_plan_modal(frame: &mut Frame, session: &Session, regions: &mut Scrol
         Span::raw("Top/Bottom  "),
         Span::styled("  [PgUp/Dn] ", Style::default().fg(Color::Cyan).bold()),
         Span::raw("Page  "),
+        Span::styled("  [Tab] ", Style::default().fg(Color::Cyan).bold()),
+        Span::raw("Select file  "),
+        Span::styled("  [Enter] ", Style::default().fg(Color::Green).bold()),
+        Span::raw("Open  "),
         Span::styled("  [Esc/p] ", Style::default().fg(Color::Yellow).bold()),
         Span::raw("Close"),
     ]))
@@ -585,6 +658,150 @@ pub fn draw_plan_modal(frame: &mut Frame, session: &Session, regions: &mut Scrol
     frame.render_widget(instructions, chunks[2]);
 }
 
+/// Draw the read-only file viewer opened from a plan reference, over the plan modal.
+///
+/// Uses the plan modal's layout; lines are numbered and the referenced line is highlighted.
+pub fn draw_file_viewer(frame: &mut Frame, viewer: &FileViewer) {
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.8) as u16;
+    let popup_height = (area.height as f32 * 0.8) as u16;
+    let popup_x = (area.width - popup_width) / 2;
+    let popup_y = (area.height - popup_height) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup_area);
+
+    let location = match viewer.line {
+        Some(line) => format!(" {}:{} ", viewer.path, line),
+        None => format!(" {} ", viewer.path),
+    };
+    let title = Paragraph::new(Line::from(vec![Span::styled(
+        location,
+        Style::default().fg(Color::Cyan).bold(),
+    )]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" File (read-only) "),
+    );
+    frame.render_widget(title, chunks[0]);
+
+    let content_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+    let visible_height = content_block.inner(chunks[1]).height as usize;
+    let number_width = viewer.lines.len().max(1).to_string().len();
+    let lines: Vec<Line> = viewer
+        .lines
+        .iter()
+        .enumerate()
+        .skip(viewer.scroll)
+        .take(visible_height)
+        .map(|(i, text)| {
+            let is_target = viewer.line == Some(i + 1);
+            let text_style = if is_target {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>width$} ", i + 1, width = number_width),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(text.clone(), text_style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(content_block), chunks[1]);
+
+    if viewer.lines.len() > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(viewer.lines.len())
+            .viewport_content_length(visible_height)
+            .position(viewer.scroll);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("↑"))
+                .end_symbol(Some("↓")),
+            chunks[1],
+            &mut scrollbar_state,
+        );
+    }
+
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::styled("  [j/k] ", Style::default().fg(Color::Cyan).bold()),
+        Span::raw("Scroll  "),
+        Span::styled("  [g/G] ", Style::default().fg(Color::Cyan).bold()),
+        Span::raw("Top/Bottom  "),
+        Span::styled("  [PgUp/Dn] ", Style::default().fg(Color::Cyan).bold()),
+        Span::raw("Page  "),
+        Span::styled("  [Esc] ", Style::default().fg(Color::Yellow).bold()),
+        Span::raw("Back to plan"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(instructions, chunks[2]);
+}
+
+/// Lines of the selected review with the user's notes below the lines they annotate.
+///
+/// Also returns the index of the line-selection cursor within the rendered lines.
+pub fn review_modal_lines(session: &Session) -> (Vec<Line<'static>>, Option<usize>) {
+    let mut lines = Vec::new();
+    let mut cursor_index = None;
+    for (i, text) in session.current_review_content().lines().enumerate() {
+        let mut line = parse_markdown_line(text);
+        let selected = session.review_modal_cursor == Some(i);
+        if selected {
+            cursor_index = Some(lines.len());
+            line = line.patch_style(Style::default().bg(Color::DarkGray));
+        }
+        lines.push(line);
+
+        let draft = session
+            .review_annotation_input
+            .as_ref()
+            .filter(|_| selected);
+        let note = match draft {
+            Some((stance, note)) => Some((*stance, format!("{}▌", note))),
+            None => session
+                .review_line_annotation(i)
+                .map(|a| (a.stance, a.note.clone())),
+        };
+        if let Some((stance, note)) = note {
+            let color = match stance {
+                AnnotationStance::Agree => Color::Green,
+                AnnotationStance::Disagree => Color::Red,
+                AnnotationStance::Context => Color::Yellow,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  ↳ [{}] ", stance.label()),
+                    Style::default()
+                        .fg(color)
+                        .add_modifier(ratatui::style::Modifier::BOLD),
+                ),
+                Span::styled(note, Style::default().fg(color)),
+            ]));
+        }
+    }
+    (lines, cursor_index)
+}
+
 /// Draw the review modal overlay showing review feedback with tabs.
 ///
 /// The modal is 80% of the terminal size with a tab bar for switching reviews.
@@ -672,8 +889,7 @@ pub fn draw_review_modal(frame: &mut Frame, session: &Session, regions: &mut Scr
     let visible_height = inner_area.height as usize;
     let inner_width = inner_area.width;
 
-    let content_text = session.current_review_content();
-    let content_lines: Vec<Line> = content_text.lines().map(parse_markdown_line).collect();
+    let (content_lines, _) = review_modal_lines(session);
 
     let total_lines = compute_wrapped_line_count(&content_lines, inner_width);
     let max_scroll = total_lines.saturating_sub(visible_height);
@@ -704,36 +920,38 @@ pub fn draw_review_modal(frame: &mut Frame, session: &Session, regions: &mut Scr
     }
 
     // Instructions
-    let instructions = Paragraph::new(Line::from(vec![
-        Span::styled(
-            "  [Tab/Arrow] ",
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(ratatui::style::Modifier::BOLD),
-        ),
-        Span::raw("Switch Review  "),
-        Span::styled(
-            "  [j/k] ",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(ratatui::style::Modifier::BOLD),
-        ),
-        Span::raw("Scroll  "),
-        Span::styled(
-            "  [g/G] ",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(ratatui::style::Modifier::BOLD),
-        ),
-        Span::raw("Top/Bottom  "),
-        Span::styled(
-            "  [Esc/r] ",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(ratatui::style::Modifier::BOLD),
-        ),
-        Span::raw("Close"),
-    ]))
+    let key_style = |color: Color| {
+        Style::default()
+            .fg(color)
+            .add_modifier(ratatui::style::Modifier::BOLD)
+    };
+    let hints: Vec<(&str, Color, &str)> = if session.review_annotation_input.is_some() {
+        vec![
+            ("  [Enter] ", Color::Green, "Save Note  "),
+            ("  [Esc] ", Color::Yellow, "Cancel"),
+        ]
+    } else if session.review_modal_cursor.is_some() {
+        vec![
+            ("  [j/k] ", Color::Cyan, "Select Line  "),
+            ("  [a/d/c] ", Color::Green, "Agree/Disagree/Context  "),
+            ("  [x] ", Color::Red, "Remove Note  "),
+            ("  [Esc/s] ", Color::Yellow, "Done"),
+        ]
+    } else {
+        vec![
+            ("  [Tab/Arrow] ", Color::Green, "Switch Review  "),
+            ("  [j/k] ", Color::Cyan, "Scroll  "),
+            ("  [g/G] ", Color::Cyan, "Top/Bottom  "),
+            ("  [s] ", Color::Green, "Annotate  "),
+            ("  [Esc/r] ", Color::Yellow, "Close"),
+        ]
+    };
+    let instructions = Paragraph::new(Line::from(
+        hints
+            .into_iter()
+            .flat_map(|(key, color, label)| [Span::styled(key, key_style(color)), Span::raw(label)])
+            .collect::<Vec<_>>(),
+    ))
     .block(
         Block::default()
             .borders(Borders::ALL)
@@ -0,0 +1,343 @@
+//! Overlays for getting to other tabs: the notification center listing events
+//! from background tabs, the approval queue over plans waiting in any tab, and
+//! the Ctrl+Tab switcher over recently used tabs.
+
+use crate::tui::ui::util::truncate_to_width;
+use crate::tui::{NotificationKind, TabManager};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+fn kind_color(kind: NotificationKind) -> Color {
+    match kind {
+        NotificationKind::ApprovalRequired => Color::Yellow,
+        NotificationKind::PhaseComplete => Color::Green,
+        NotificationKind::Error => Color::Red,
+        NotificationKind::BudgetAlert => Color::Magenta,
+    }
+}
+
+/// Draw the notification center, newest first.
+pub fn draw_notification_overlay(frame: &mut Frame, tab_manager: &TabManager) {
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.75).min(100.0) as u16;
+    let popup_height = (area.height as f32 * 0.60).min(24.0) as u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Notification list
+            Constraint::Length(2), // Instructions
+        ])
+        .split(popup_area);
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Notifications ");
+    let inner_area = list_block.inner(chunks[0]);
+    let center = &tab_manager.notifications;
+
+    if center.is_empty() {
+        let empty_para = Paragraph::new(Line::from(Span::styled(
+            " No notifications from background tabs. ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(list_block);
+        frame.render_widget(empty_para, chunks[0]);
+    } else {
+        // Keep the selection on screen
+        let visible_height = inner_area.height.max(1) as usize;
+        let scroll_offset = center
+            .selected_idx
+            .saturating_sub(visible_height.saturating_sub(1));
+
+        let lines: Vec<Line> = center
+            .entries()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(visible_height)
+            .map(|(i, entry)| {
+                let is_selected = i == center.selected_idx;
+                let style = if is_selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                Line::from(vec![
+                    Span::styled(if is_selected { " > " } else { "   " }, style),
+                    Span::styled(
+                        entry.at.format("%H:%M:%S ").to_string(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:<9}", entry.kind.label()),
+                        Style::default().fg(kind_color(entry.kind)),
+                    ),
+                    Span::styled(
+                        format!("{:<16} ", truncate_to_width(&entry.tab_name, 15)),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(entry.message.clone(), style),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(list_block), chunks[0]);
+    }
+
+    let key = |k: &'static str, color: Color| {
+        Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD))
+    };
+    let instructions = Paragraph::new(Line::from(vec![
+        key(" [j/k] ", Color::Cyan),
+        Span::raw("Navigate "),
+        key(" [Enter] ", Color::Green),
+        Span::raw("Go to tab "),
+        key(" [c] ", Color::Yellow),
+        Span::raw("Clear "),
+        key(" [Esc] ", Color::Red),
+        Span::raw("Close"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(instructions, chunks[1]);
+}
+
+/// Summary lines shown under each pending approval.
+const APPROVAL_SUMMARY_LINES: usize = 3;
+
+/// Draw the approval queue: every tab waiting for a plan approval, with the
+/// start of its plan summary.
+pub fn draw_approval_queue_overlay(frame: &mut Frame, tab_manager: &TabManager) {
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.8).min(110.0) as u16;
+    let popup_height = (area.height as f32 * 0.75).min(32.0) as u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Approval list
+            Constraint::Length(2), // Instructions or decline feedback
+        ])
+        .split(popup_area);
+
+    let pending = tab_manager.pending_approvals();
+    let queue = &tab_manager.approval_queue;
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!(" Pending Approvals ({}) ", pending.len()));
+    let inner_area = list_block.inner(chunks[0]);
+
+    if pending.is_empty() {
+        let empty_para = Paragraph::new(Line::from(Span::styled(
+            " No tabs are waiting for a plan approval. ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(list_block);
+        frame.render_widget(empty_para, chunks[0]);
+    } else {
+        let summary_width = inner_area.width.saturating_sub(6) as usize;
+        let entry_height = APPROVAL_SUMMARY_LINES + 2;
+        // Keep the selected entry on screen
+        let visible_entries = (inner_area.height as usize / entry_height).max(1);
+        let scroll_offset = queue
+            .selected_idx
+            .saturating_sub(visible_entries.saturating_sub(1));
+
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, session) in pending.iter().enumerate().skip(scroll_offset) {
+            let is_selected = i == queue.selected_idx;
+            let style = if is_selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let name = if session.name.is_empty() {
+                "New Tab"
+            } else {
+                &session.name
+            };
+            let iteration = session
+                .workflow_view
+                .as_ref()
+                .and_then(|view| view.iteration())
+                .map(|i| format!("iteration {}", i.0))
+                .unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(if is_selected { " > " } else { "   " }, style),
+                Span::styled(format!("{:<26} ", truncate_to_width(name, 25)), style),
+                Span::styled(iteration, Style::default().fg(Color::Cyan)),
+            ]));
+            for summary_line in session
+                .plan_summary
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .take(APPROVAL_SUMMARY_LINES)
+            {
+                lines.push(Line::from(Span::styled(
+                    format!("      {}", truncate_to_width(summary_line, summary_width)),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            lines.push(Line::from(""));
+        }
+        frame.render_widget(Paragraph::new(lines).block(list_block), chunks[0]);
+    }
+
+    let key = |k: &'static str, color: Color| {
+        Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD))
+    };
+    let footer = match &queue.decline_input {
+        Some(feedback) => Line::from(vec![
+            key(" Decline feedback: ", Color::Red),
+            Span::raw(feedback.clone()),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "  [Enter] Send  [Esc] Cancel",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        None => Line::from(vec![
+            key(" [j/k] ", Color::Cyan),
+            Span::raw("Navigate "),
+            key(" [a] ", Color::Green),
+            Span::raw("Approve "),
+            key(" [d] ", Color::Red),
+            Span::raw("Decline "),
+            key(" [Enter] ", Color::Yellow),
+            Span::raw("Go to tab "),
+            key(" [Esc] ", Color::Red),
+            Span::raw("Close"),
+        ]),
+    };
+    let instructions = Paragraph::new(footer).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(instructions, chunks[1]);
+}
+
+/// Draw the recent-tab switcher, most recently used tab first.
+pub fn draw_recent_tabs_overlay(frame: &mut Frame, tab_manager: &TabManager) {
+    let area = frame.area();
+    let recent = tab_manager.recent_sessions();
+
+    let popup_width = (area.width as f32 * 0.6).min(80.0) as u16;
+    let popup_height = (recent.len() as u16 + 4).min(area.height.saturating_sub(2));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Tab list
+            Constraint::Length(2), // Instructions
+        ])
+        .split(popup_area);
+
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Recent Tabs ");
+    let inner_area = list_block.inner(chunks[0]);
+    let selected_idx = tab_manager.recent_tabs.selected_idx;
+    let visible_height = inner_area.height.max(1) as usize;
+    let scroll_offset = selected_idx.saturating_sub(visible_height.saturating_sub(1));
+    let active_id = tab_manager.active().id;
+
+    let lines: Vec<Line> = recent
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(visible_height)
+        .map(|(i, session)| {
+            let is_selected = i == selected_idx;
+            let style = if is_selected {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let name = if session.name.is_empty() {
+                "New Tab"
+            } else {
+                &session.name
+            };
+            let mut spans = vec![
+                Span::styled(if is_selected { " > " } else { "   " }, style),
+                Span::styled(format!("{:<26} ", truncate_to_width(name, 25)), style),
+                Span::styled(
+                    format!("{:<18}", session.phase_name()),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ];
+            if let Some(reason) = session.attention() {
+                let color = if reason == "error" {
+                    Color::Red
+                } else {
+                    Color::Yellow
+                };
+                spans.push(Span::styled(
+                    format!("● {}", reason),
+                    Style::default().fg(color),
+                ));
+            }
+            if session.id == active_id {
+                spans.push(Span::styled(
+                    " (current)",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[0]);
+
+    let key = |k: &'static str, color: Color| {
+        Span::styled(k, Style::default().fg(color).add_modifier(Modifier::BOLD))
+    };
+    let instructions = Paragraph::new(Line::from(vec![
+        key(" [Ctrl+Tab/j/k] ", Color::Cyan),
+        Span::raw("Cycle "),
+        key(" [Enter] ", Color::Green),
+        Span::raw("Go to tab "),
+        key(" [Esc] ", Color::Red),
+        Span::raw("Close"),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(instructions, chunks[1]);
+}
@@ -0,0 +1,155 @@
+//! Tool permission and workspace trust prompt overlay rendering.
+
+use crate::app::workspace_trust::RESTRICTED_ALLOWED_TOOLS;
+use crate::tui::Session;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_permission_overlay(frame: &mut Frame, session: &Session) {
+    let Some(request) = session.current_tool_permission() else {
+        return;
+    };
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.6).min(80.0) as u16;
+    let popup_height = 12u16.min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Instructions
+        ])
+        .split(popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red))
+        .title(format!(" Allow {}? ", request.risk.label()));
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::DarkGray));
+    let mut lines = vec![
+        Line::from(vec![
+            label(" Agent:  "),
+            Span::styled(request.agent_name.clone(), Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" ({})", request.phase)),
+        ]),
+        Line::from(vec![
+            label(" Tool:   "),
+            Span::styled(
+                request.display_name.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    if !request.input_preview.is_empty() {
+        lines.push(Line::from(vec![
+            label(" Input:  "),
+            Span::raw(request.input_preview.clone()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " The agent is paused until you answer.",
+        Style::default().fg(Color::Yellow),
+    )));
+    let queued = session.tool_permission_queue.len().saturating_sub(1);
+    if queued > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(" {} more request(s) waiting", queued),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let content = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(content, chunks[0]);
+
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Yellow));
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::raw(" "),
+        key("[y]"),
+        Span::raw(" allow "),
+        key("[a]"),
+        Span::raw(" always allow for this session "),
+        key("[n]"),
+        Span::raw(" deny"),
+    ]));
+    frame.render_widget(instructions, chunks[1]);
+}
+
+pub fn draw_workspace_trust_overlay(frame: &mut Frame, session: &Session) {
+    let Some(request) = session.workspace_trust_request.as_ref() else {
+        return;
+    };
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.6).min(80.0) as u16;
+    let popup_height = 11u16.min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Instructions
+        ])
+        .split(popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red))
+        .title(" Trust this repository? ");
+
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {}", request.workspace.display()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(" Implementation agents edit files and run tool calls, including"),
+        Line::from(" shell commands, in this repository."),
+        Line::from(Span::styled(
+            format!(
+                " Untrusted, they may only use {}.",
+                RESTRICTED_ALLOWED_TOOLS.join(", ")
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            " Your answer is remembered for this repository.",
+            Style::default().fg(Color::Yellow),
+        )),
+    ];
+
+    let content = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(content, chunks[0]);
+
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Yellow));
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::raw(" "),
+        key("[t]"),
+        Span::raw(" trust "),
+        key("[r]"),
+        Span::raw(" restrict tools"),
+    ]));
+    frame.render_widget(instructions, chunks[1]);
+}
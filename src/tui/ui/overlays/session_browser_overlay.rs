@@ -2,7 +2,8 @@
 
 use super::super::SPINNER_CHARS;
 use crate::session_daemon::LivenessState;
-use crate::tui::session_browser::ConfirmationState;
+use crate::tui::session_browser::{BulkAction, ConfirmationState};
+use crate::tui::ui::util::{truncate_start_to_width, truncate_to_width};
 use crate::tui::TabManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -53,7 +54,7 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
             Constraint::Length(1), // Selected session detail (working dir)
             Constraint::Length(2), // Column headers
             Constraint::Min(0),    // Session list
-            Constraint::Length(3), // Instructions
+            Constraint::Length(4), // Instructions
         ])
         .split(popup_area);
 
@@ -62,6 +63,8 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
         " Loading session... "
     } else if tab_manager.session_browser.exporting_zip {
         " Exporting ZIP... "
+    } else if tab_manager.session_browser.bulk_in_progress {
+        " Applying bulk action... "
     } else if tab_manager.session_browser.loading {
         " Refreshing... "
     } else {
@@ -118,6 +121,14 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
             entries.len(),
             total_entries
         )
+    } else if tab_manager.session_browser.group_by_repo {
+        let mut repositories: Vec<_> = entries.iter().map(|e| &e.repository).collect();
+        repositories.dedup();
+        format!(
+            "Showing all {} sessions in {} repositories ",
+            total_entries,
+            repositories.len()
+        )
     } else {
         format!("Showing all {} sessions ", total_entries)
     };
@@ -132,11 +143,23 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
     if live_count > 0 {
         status_spans.push(Span::styled("│ ", Style::default().fg(Color::DarkGray)));
         status_spans.push(Span::styled(
-            format!("{} live", live_count),
+            format!("{} live ", live_count),
             Style::default().fg(Color::Green),
         ));
     }
 
+    // Marked session count
+    let marked_count = tab_manager.session_browser.marked.len();
+    if marked_count > 0 {
+        status_spans.push(Span::styled("│ ", Style::default().fg(Color::DarkGray)));
+        status_spans.push(Span::styled(
+            format!("{} marked", marked_count),
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let filter_line = Paragraph::new(Line::from(status_spans));
     frame.render_widget(filter_line, chunks[1]);
 
@@ -147,20 +170,22 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
             .selected_idx
             .min(entries.len().saturating_sub(1));
         let selected = &entries[selected_idx];
-        let dir_str = selected.working_dir.display().to_string();
         let max_len = popup_width.saturating_sub(6) as usize; // " → " prefix + margins
-        let char_count = dir_str.chars().count();
-        let truncated_dir = if char_count > max_len {
-            let skip_count = char_count.saturating_sub(max_len.saturating_sub(3));
-            let truncated: String = dir_str.chars().skip(skip_count).collect();
-            format!("...{}", truncated)
+        if tab_manager.session_browser.group_by_repo {
+            // Global view: show how to resume the session from any directory
+            let command = truncate_to_width(&selected.resume_command(), max_len);
+            Paragraph::new(Line::from(vec![
+                Span::styled(" $ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(command, Style::default().fg(Color::Cyan)),
+            ]))
         } else {
-            dir_str
-        };
-        Paragraph::new(Line::from(vec![
-            Span::styled(" → ", Style::default().fg(Color::DarkGray)),
-            Span::styled(truncated_dir, Style::default().fg(Color::Cyan)),
-        ]))
+            let dir_str = selected.working_dir.display().to_string();
+            let truncated_dir = truncate_start_to_width(&dir_str, max_len);
+            Paragraph::new(Line::from(vec![
+                Span::styled(" → ", Style::default().fg(Color::DarkGray)),
+                Span::styled(truncated_dir, Style::default().fg(Color::Cyan)),
+            ]))
+        }
     } else {
         Paragraph::new(Line::from(vec![]))
     };
@@ -218,7 +243,7 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
     let list_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
-        .title(" Sessions (j/k navigate, Enter resume, s force-stop) ");
+        .title(" Sessions (j/k navigate, Space mark, Enter resume, s force-stop) ");
 
     let inner_area = list_block.inner(chunks[4]);
 
@@ -240,6 +265,70 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
         )]))
         .block(list_block);
         frame.render_widget(empty_para, chunks[4]);
+    } else if tab_manager.session_browser.group_by_repo {
+        let visible_height = inner_area.height as usize;
+        let scroll_offset = tab_manager.session_browser.scroll_offset;
+        let selected_idx = tab_manager.session_browser.selected_idx;
+
+        // Entries are sorted by repository; start a section at each change
+        let mut all_lines: Vec<Line> = Vec::new();
+        for (idx, entry) in entries.iter().enumerate() {
+            let starts_group = idx == 0 || entries[idx - 1].repository != entry.repository;
+            if starts_group {
+                if idx > 0 {
+                    all_lines.push(Line::from("")); // Spacer
+                }
+                let count = entries
+                    .iter()
+                    .filter(|e| e.repository == entry.repository)
+                    .count();
+                let name = entry
+                    .repository
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| entry.repository.display().to_string());
+                all_lines.push(Line::from(vec![
+                    Span::styled("▸ ", Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("{} ({})", name, count),
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  {}", entry.repository.display()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]));
+            }
+            all_lines.push(render_session_line(
+                entry,
+                idx == selected_idx,
+                tab_manager.session_browser.is_marked(&entry.session_id),
+                tab_manager.update_spinner_frame,
+            ));
+        }
+
+        let total_lines = all_lines.len();
+        let lines: Vec<Line> = all_lines
+            .into_iter()
+            .skip(scroll_offset)
+            .take(visible_height)
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(list_block), chunks[4]);
+
+        if total_lines > visible_height {
+            let mut scrollbar_state = ScrollbarState::new(total_lines)
+                .viewport_content_length(visible_height)
+                .position(scroll_offset);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("↑"))
+                    .end_symbol(Some("↓")),
+                chunks[4],
+                &mut scrollbar_state,
+            );
+        }
     } else {
         // Partition entries into live and disconnected
         let (live_entries, disconnected_entries): (Vec<_>, Vec<_>) = entries
@@ -277,6 +366,7 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
                     render_session_line(
                         entry,
                         *original_idx == selected_idx,
+                        tab_manager.session_browser.is_marked(&entry.session_id),
                         tab_manager.update_spinner_frame,
                     ),
                 ));
@@ -307,6 +397,7 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
                     render_session_line(
                         entry,
                         *original_idx == selected_idx,
+                        tab_manager.session_browser.is_marked(&entry.session_id),
                         tab_manager.update_spinner_frame,
                     ),
                 ));
@@ -340,53 +431,95 @@ pub fn draw_session_browser_overlay(frame: &mut Frame, tab_manager: &TabManager)
     }
 
     // Instructions
-    let instructions = Paragraph::new(Line::from(vec![
+    let bulk_instructions = Line::from(vec![
         Span::styled(
-            "  [j/k] ",
+            "  [Space] ",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::raw("Navigate "),
+        Span::raw("Mark "),
         Span::styled(
-            " [Enter] ",
+            " [u] ",
             Style::default()
-                .fg(Color::Green)
+                .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::raw("Resume "),
+        Span::raw("Unmark all "),
         Span::styled(
-            " [s] ",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-        ),
-        Span::raw("Stop "),
-        Span::styled(
-            " [f] ",
+            " [a] ",
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::raw("Filter "),
+        Span::raw("Archive "),
         Span::styled(
-            " [r] ",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::raw("Refresh "),
-        Span::styled(
-            " [z] ",
-            Style::default()
-                .fg(Color::Magenta)
-                .add_modifier(Modifier::BOLD),
+            " [d] ",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         ),
-        Span::raw("Export "),
+        Span::raw("Delete snapshot "),
         Span::styled(
-            " [Esc/q] ",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            "(Enter/s/a/d apply to all marked)",
+            Style::default().fg(Color::DarkGray),
         ),
-        Span::raw("Close"),
-    ]))
+    ]);
+    let instructions = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(
+                "  [j/k] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Navigate "),
+            Span::styled(
+                " [Enter] ",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Resume "),
+            Span::styled(
+                " [s] ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Stop "),
+            Span::styled(
+                " [f] ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Filter "),
+            Span::styled(
+                " [g] ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("By repo "),
+            Span::styled(
+                " [r] ",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Refresh "),
+            Span::styled(
+                " [z] ",
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Export "),
+            Span::styled(
+                " [Esc/q] ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("Close"),
+        ]),
+        bulk_instructions,
+    ])
     .block(
         Block::default()
             .borders(Borders::ALL)
@@ -415,9 +548,9 @@ fn draw_confirmation_dialog(
 
     let (title, message_lines, warning_color) = match confirmation {
         ConfirmationState::ForceStop { session_id } => {
-            let truncated_id = truncate_str(session_id, 30);
+            let truncated_id = truncate_to_width(session_id, 30);
             (
-                " Force Stop Session ",
+                " Force Stop Session ".to_string(),
                 vec![
                     format!("Stop session: {}", truncated_id),
                     String::new(),
@@ -431,11 +564,11 @@ fn draw_confirmation_dialog(
             session_id,
             target_dir,
         } => {
-            let truncated_id = truncate_str(session_id, 25);
+            let truncated_id = truncate_to_width(session_id, 25);
             let dir_str = target_dir.display().to_string();
-            let truncated_dir = truncate_str(&dir_str, 40);
+            let truncated_dir = truncate_to_width(&dir_str, 40);
             (
-                " Cross-Directory Resume ",
+                " Cross-Directory Resume ".to_string(),
                 vec![
                     format!("Session: {}", truncated_id),
                     format!("Directory: {}", truncated_dir),
@@ -445,6 +578,57 @@ fn draw_confirmation_dialog(
                 Color::Yellow,
             )
         }
+        ConfirmationState::Bulk {
+            action,
+            session_ids,
+            skipped,
+        } => {
+            let (details, color) = match action {
+                BulkAction::Resume => (
+                    [
+                        "Each session opens in its own tab.",
+                        "Other directories resume in place.",
+                    ],
+                    Color::Yellow,
+                ),
+                BulkAction::Archive => (
+                    [
+                        "Sessions are zipped to ~/.planning-agent/archive/",
+                        "and their snapshots deleted.",
+                    ],
+                    Color::Yellow,
+                ),
+                BulkAction::DeleteSnapshots => (
+                    [
+                        "Deleted sessions can no longer be resumed.",
+                        "Plans and logs stay on disk.",
+                    ],
+                    Color::Red,
+                ),
+                BulkAction::ForceStop => (
+                    [
+                        "Sessions will be marked as stopped.",
+                        "Processes may continue running.",
+                    ],
+                    Color::Red,
+                ),
+            };
+            let mut lines = vec![format!(
+                "{} {} session(s)?",
+                action.label(),
+                session_ids.len()
+            )];
+            if *skipped > 0 {
+                lines.push(format!(
+                    "Skipping {} marked session(s) it doesn't apply to.",
+                    skipped
+                ));
+            } else {
+                lines.push(String::new());
+            }
+            lines.extend(details.iter().map(|line| line.to_string()));
+            (format!(" {} ", action.label()), lines, color)
+        }
     };
 
     let chunks = Layout::default()
@@ -523,30 +707,25 @@ fn draw_confirmation_dialog(
 fn render_session_line(
     entry: &crate::tui::session_browser::SessionEntry,
     is_selected: bool,
+    is_marked: bool,
     spinner_frame: u8,
 ) -> Line<'static> {
-    // Show spinner for Running sessions, selection indicator otherwise
+    // Mark in the first column; spinner for Running sessions, selection indicator otherwise
+    let mark = if is_marked { "✓" } else { " " };
     let prefix = if entry.liveness == LivenessState::Running {
         let spinner_char = SPINNER_CHARS[(spinner_frame as usize) % SPINNER_CHARS.len()];
-        format!(" {} ", spinner_char)
+        format!("{}{} ", mark, spinner_char)
     } else if is_selected {
-        " > ".to_string()
+        format!("{}> ", mark)
     } else {
-        "   ".to_string()
+        format!("{}  ", mark)
     };
     let dir_indicator = if entry.is_current_dir { "*" } else { " " };
     // Snapshot indicator for resumable sessions
     let snapshot_indicator = if entry.has_snapshot { "◉" } else { " " };
 
-    // Truncate feature name if too long (expanded from 16 to 23 chars)
-    let max_name_len = 23;
-    let char_count = entry.feature_name.chars().count();
-    let feature_name: String = if char_count > max_name_len {
-        let truncated: String = entry.feature_name.chars().take(max_name_len - 3).collect();
-        format!("{}...", truncated)
-    } else {
-        entry.feature_name.clone()
-    };
+    // Truncate feature name if too long (expanded from 16 to 23 columns)
+    let feature_name = truncate_to_width(&entry.feature_name, 23);
 
     let style = if is_selected {
         Style::default()
@@ -588,8 +767,8 @@ fn render_session_line(
     let live_style = liveness_style(&entry.liveness);
 
     // Truncate workflow_status and phase
-    let phase_display = truncate_str(&entry.phase, 10);
-    let status_display = truncate_str(&entry.workflow_status, 10);
+    let phase_display = truncate_to_width(&entry.phase, 10);
+    let status_display = truncate_to_width(&entry.workflow_status, 10);
 
     // Style the prefix based on whether it's a spinner or selection indicator
     let prefix_style = if entry.liveness == LivenessState::Running {
@@ -627,16 +806,3 @@ fn render_session_line(
         ),
     ])
 }
-
-/// Truncate a string to max length with ellipsis.
-fn truncate_str(s: &str, max_len: usize) -> String {
-    let char_count = s.chars().count();
-    if char_count <= max_len {
-        s.to_string()
-    } else if max_len > 3 {
-        let truncated: String = s.chars().take(max_len - 3).collect();
-        format!("{}...", truncated)
-    } else {
-        s.chars().take(max_len).collect()
-    }
-}
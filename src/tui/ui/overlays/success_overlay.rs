@@ -70,3 +70,80 @@ pub fn draw_implementation_success_overlay(frame: &mut Frame, session: &Session)
         frame.render_widget(instructions, chunks[1]);
     }
 }
+
+/// Completion summary: the run's metrics card, then one line per completion
+/// action with its outcome.
+pub fn draw_completion_summary_overlay(frame: &mut Frame, session: &Session) {
+    let Some(ref summary) = session.completion_summary else {
+        return;
+    };
+    let outcomes = &summary.outcomes;
+    let area = frame.area();
+
+    let popup_width = 80u16.min(area.width.saturating_sub(4));
+    // Borders, blank line, metrics, a blank line between the metrics and the
+    // actions, one line per action, blank line, instructions
+    let separator = usize::from(!summary.metrics.is_empty() && !outcomes.is_empty());
+    let content_lines = summary.metrics.len() + separator + outcomes.len();
+    let popup_height = (content_lines as u16 + 5).min(area.height.saturating_sub(2));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Content
+            Constraint::Length(1), // Instructions
+        ])
+        .split(popup_area);
+
+    let all_succeeded = outcomes.iter().all(|o| o.succeeded);
+    let border_color = if all_succeeded {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .title(" Workflow Complete ");
+
+    let mut lines = vec![Line::from("")];
+    for metric in &summary.metrics {
+        lines.push(Line::from(Span::styled(
+            format!(" {}", metric),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    if separator == 1 {
+        lines.push(Line::from(""));
+    }
+    for outcome in outcomes {
+        let (mark, color) = if outcome.succeeded {
+            (" ✓ ", Color::Green)
+        } else {
+            (" ✗ ", Color::Red)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(mark, Style::default().fg(color)),
+            Span::raw(format!("{}: ", outcome.action)),
+            Span::styled(outcome.detail.clone(), Style::default().fg(Color::Gray)),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), chunks[0]);
+
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::raw("  "),
+        Span::styled("[Esc]", Style::default().fg(Color::Yellow)),
+        Span::raw(" or "),
+        Span::styled("[Enter]", Style::default().fg(Color::Yellow)),
+        Span::raw(" to dismiss"),
+    ]));
+    frame.render_widget(instructions, chunks[1]);
+}
@@ -0,0 +1,124 @@
+//! Review feedback triage overlay rendering.
+
+use crate::domain::review::TriageDecision;
+use crate::phases::feedback_items::item_source;
+use crate::tui::Session;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+fn decision_span(decision: TriageDecision) -> Span<'static> {
+    match decision {
+        TriageDecision::Accept => Span::styled("[accept] ", Style::default().fg(Color::Green)),
+        TriageDecision::Park => Span::styled("[park]   ", Style::default().fg(Color::Yellow)),
+        TriageDecision::Dismiss => Span::styled("[dismiss]", Style::default().fg(Color::DarkGray)),
+    }
+}
+
+pub fn draw_triage_overlay(frame: &mut Frame, session: &Session) {
+    let Some(ref modal) = session.feedback_triage else {
+        return;
+    };
+    let area = frame.area();
+
+    let popup_width = (area.width as f32 * 0.8).min(110.0) as u16;
+    let popup_height = (area.height as f32 * 0.8) as u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(50), // Item list
+            Constraint::Min(0),         // Selected item
+            Constraint::Length(1),      // Instructions
+        ])
+        .split(popup_area);
+
+    let accepted = modal
+        .decisions
+        .iter()
+        .filter(|d| **d == TriageDecision::Accept)
+        .count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!(
+            " Triage Review Feedback ({}/{} accepted) ",
+            accepted,
+            modal.items.len()
+        ));
+
+    // Keep the selected item visible when the list is taller than the popup
+    let list_height = (block.inner(chunks[0]).height as usize).max(1);
+    let first_visible = modal.selected.saturating_sub(list_height.saturating_sub(1));
+
+    let mut lines = Vec::new();
+    for (i, (item, decision)) in modal
+        .items
+        .iter()
+        .zip(modal.decisions.iter())
+        .enumerate()
+        .skip(first_visible)
+        .take(list_height)
+    {
+        let selected = i == modal.selected;
+        let marker = if selected { "> " } else { "  " };
+        let text_style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::raw(marker),
+            decision_span(*decision),
+            Span::raw(" "),
+            Span::styled(
+                format!("{}: ", item_source(item)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                item.text.lines().next().unwrap_or_default().to_string(),
+                text_style,
+            ),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines).block(block), chunks[0]);
+
+    if let Some(item) = modal.items.get(modal.selected) {
+        let detail = Paragraph::new(item.text.clone())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue))
+                    .title(format!(" {} ", item_source(item))),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(detail, chunks[1]);
+    }
+
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(Color::Yellow));
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::raw(" "),
+        key("[↑/↓]"),
+        Span::raw(" select "),
+        key("[a]"),
+        Span::raw(" accept "),
+        key("[p]"),
+        Span::raw(" park "),
+        key("[d]"),
+        Span::raw(" dismiss "),
+        key("[A/P/D]"),
+        Span::raw(" all "),
+        key("[Enter]"),
+        Span::raw(" revise"),
+    ]));
+    frame.render_widget(instructions, chunks[2]);
+}
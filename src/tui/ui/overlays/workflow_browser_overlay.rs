@@ -1,5 +1,6 @@
 //! Workflow browser overlay for viewing and selecting workflow configurations.
 
+use crate::tui::ui::util::truncate_to_width;
 use crate::tui::TabManager;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -119,25 +120,10 @@ pub fn draw_workflow_browser_overlay(frame: &mut Frame, tab_manager: &TabManager
             };
             let active_indicator = if entry.is_selected { "✓" } else { " " };
 
-            // Truncate names to fit columns (use char count to handle UTF-8)
-            let name_display: String = if entry.name.chars().count() > 14 {
-                let truncated: String = entry.name.chars().take(11).collect();
-                format!("{}...", truncated)
-            } else {
-                entry.name.clone()
-            };
-            let planning_display: String = if entry.planning_agent.chars().count() > 10 {
-                let truncated: String = entry.planning_agent.chars().take(7).collect();
-                format!("{}...", truncated)
-            } else {
-                entry.planning_agent.clone()
-            };
-            let reviewing_display: String = if entry.reviewing_agents.chars().count() > 20 {
-                let truncated: String = entry.reviewing_agents.chars().take(17).collect();
-                format!("{}...", truncated)
-            } else {
-                entry.reviewing_agents.clone()
-            };
+            // Truncate names to fit columns
+            let name_display = truncate_to_width(&entry.name, 14);
+            let planning_display = truncate_to_width(&entry.planning_agent, 10);
+            let reviewing_display = truncate_to_width(&entry.reviewing_agents, 20);
 
             let style = if is_selected {
                 Style::default()
@@ -8,7 +8,7 @@ use super::objective::{
 };
 use super::stats::draw_stats;
 use super::theme::Theme;
-use super::util::compute_wrapped_line_count;
+use super::util::{compute_wrapped_line_count, highlight_nav_lines};
 use crate::tui::scroll::{ScrollRegion, ScrollableRegions};
 use crate::tui::{FocusedPanel, Session, SummaryState};
 use ratatui::{
@@ -84,7 +84,7 @@ pub fn draw_main(
 }
 
 fn draw_output(frame: &mut Frame, session: &Session, area: Rect, regions: &mut ScrollableRegions) {
-    let show_todos = area.width >= 80 && !session.todos.is_empty();
+    let show_todos = area.width >= 80 && session.shows_todos();
 
     if show_todos {
         let chunks = Layout::default()
@@ -107,7 +107,7 @@ fn draw_output_panel(
 ) {
     let theme = Theme::for_session(session);
     let is_focused = session.focused_panel == FocusedPanel::Output;
-    let title = if session.output_scroll.follow {
+    let mut title = if session.output_scroll.follow {
         if is_focused {
             " Output [*] "
         } else {
@@ -117,7 +117,8 @@ fn draw_output_panel(
         " Output [SCROLLED *] "
     } else {
         " Output [SCROLLED] "
-    };
+    }
+    .to_string();
 
     let border_color = if is_focused {
         theme.border_focused
@@ -125,17 +126,8 @@ fn draw_output_panel(
         theme.border
     };
 
-    let output_block = Block::default()
-        .borders(Borders::ALL)
-        .title(title)
-        .border_style(Style::default().fg(border_color));
-
-    let inner_area = output_block.inner(area);
-    let visible_height = inner_area.height as usize;
-    let inner_width = inner_area.width;
-
     // Build all lines (not sliced) for proper scroll handling with wrapping
-    let lines: Vec<Line> = if session.output_lines.is_empty() {
+    let mut lines: Vec<Line> = if session.output_lines.is_empty() {
         vec![Line::from(Span::styled(
             "Waiting for output...",
             Style::default().fg(theme.muted),
@@ -168,6 +160,18 @@ fn draw_output_panel(
             })
             .collect()
     };
+    if let Some(tag) = highlight_nav_lines(&mut lines, session.nav.as_ref(), FocusedPanel::Output) {
+        title = format!(" Output [{}] ", tag);
+    }
+
+    let output_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(border_color));
+
+    let inner_area = output_block.inner(area);
+    let visible_height = inner_area.height as usize;
+    let inner_width = inner_area.width;
 
     // Compute wrapped line count for proper scroll bounds
     let total_lines = compute_wrapped_line_count(&lines, inner_width);
@@ -1,6 +1,7 @@
 use super::theme::Theme;
 use super::util::{format_bytes, format_duration, format_tokens};
 use super::SPINNER_CHARS;
+use crate::domain::types::RiskLevel;
 use crate::tui::{Session, SessionStatus};
 use ratatui::{
     layout::Rect,
@@ -30,7 +31,11 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
         Line::from(vec![
             Span::styled(" Cost: ", Style::default().fg(theme.text)),
             Span::styled(
-                format!("${:.4}", cost),
+                if session.cost_estimated {
+                    format!("~${:.4}", cost)
+                } else {
+                    format!("${:.4}", cost)
+                },
                 Style::default()
                     .fg(theme.stats_cost)
                     .add_modifier(Modifier::BOLD),
@@ -52,6 +57,23 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
 
     stats_text.extend(build_account_usage(session, &theme));
 
+    let redacted = session
+        .workflow_view
+        .as_ref()
+        .and_then(|view| view.workflow_id())
+        .map_or(0, |id| {
+            crate::agents::redaction::redaction_count(&id.to_string())
+        });
+    if redacted > 0 {
+        stats_text.push(Line::from(vec![
+            Span::styled(" Redacted: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("{} secret(s)", redacted),
+                Style::default().fg(theme.warning),
+            ),
+        ]));
+    }
+
     stats_text.push(Line::from(""));
     stats_text.push(Line::from(vec![Span::styled(
         " Status",
@@ -108,6 +130,34 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
         ),
     ]));
     stats_text.push(Line::from(format!(" Time: {}m {:02}s", minutes, seconds)));
+    if let Some(usage) = session.agent_resource_usage() {
+        stats_text.push(Line::from(vec![
+            Span::styled(" Agents: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!(
+                    "{:.0}% CPU, {} RSS",
+                    usage.cpu_percent,
+                    format_bytes(usage.rss_bytes as usize)
+                ),
+                Style::default().fg(theme.text),
+            ),
+        ]));
+    }
+    if let Some(limit) = session.phase_overrun_minutes {
+        stats_text.push(Line::from(Span::styled(
+            format!(" ⚠ Phase over {}m limit", limit),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    if let Some(pid) = session.following_pid {
+        stats_text.push(Line::from(Span::styled(
+            format!(" Read-only: PID {} owns it", pid),
+            Style::default().fg(theme.warning),
+        )));
+    }
 
     // Show workflow indicator when session context is available
     if let Some(ref ctx) = session.context {
@@ -121,6 +171,8 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
         ]));
     }
 
+    stats_text.extend(build_plan_metadata(session, &theme));
+
     if session.status == SessionStatus::GeneratingSummary {
         stats_text.push(Line::from(""));
         stats_text.push(Line::from(vec![Span::styled(
@@ -143,6 +195,8 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
 
     stats_text.extend(build_model_info(session, &theme));
 
+    stats_text.extend(build_agent_versions(&theme));
+
     stats_text.push(Line::from(""));
 
     stats_text.extend(build_cache_stats(session, &theme));
@@ -167,6 +221,13 @@ pub fn draw_stats(frame: &mut Frame, session: &Session, area: Rect, show_live_to
         Span::styled("q", Style::default().fg(theme.text)),
         Span::styled(" quit", Style::default().fg(theme.muted)),
     ]));
+    stats_text.push(Line::from(vec![
+        Span::styled("       ", Style::default().fg(theme.muted)),
+        Span::styled("V", Style::default().fg(theme.text)),
+        Span::styled(" verbosity ", Style::default().fg(theme.muted)),
+        Span::styled("T", Style::default().fg(theme.text)),
+        Span::styled(" todos", Style::default().fg(theme.muted)),
+    ]));
 
     let stats = Paragraph::new(stats_text)
         .block(
@@ -343,6 +404,47 @@ fn build_account_usage(session: &Session, theme: &Theme) -> Vec<Line<'static>> {
     lines
 }
 
+/// Front matter of a structured plan: title, risk, effort and components.
+fn build_plan_metadata(session: &Session, theme: &Theme) -> Vec<Line<'static>> {
+    let Some(metadata) = session
+        .workflow_view
+        .as_ref()
+        .and_then(|view| view.plan_metadata())
+    else {
+        return Vec::new();
+    };
+    let risk_color = match metadata.risk {
+        RiskLevel::Low => theme.success,
+        RiskLevel::Medium => theme.warning,
+        RiskLevel::High => theme.error,
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" Plan: ", Style::default().fg(theme.muted)),
+            Span::styled(metadata.title.clone(), Style::default().fg(theme.text)),
+        ]),
+        Line::from(vec![
+            Span::styled(" Risk: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                metadata.risk.as_str().to_string(),
+                Style::default().fg(risk_color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Effort: ", Style::default().fg(theme.muted)),
+            Span::styled(metadata.effort.clone(), Style::default().fg(theme.text)),
+        ]),
+    ];
+    if !metadata.components.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled(" Affects: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                metadata.components.join(", "),
+                Style::default().fg(theme.text),
+            ),
+        ]));
+    }
+    lines
+}
+
 fn build_model_info(session: &Session, theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
@@ -370,6 +472,28 @@ fn build_model_info(session: &Session, theme: &Theme) -> Vec<Line<'static>> {
     lines
 }
 
+/// Detected agent CLI versions and features, once the CLIs were probed.
+fn build_agent_versions(theme: &Theme) -> Vec<Line<'static>> {
+    crate::agents::capabilities::profiles()
+        .into_iter()
+        .map(|(agent, profile)| {
+            let version = profile
+                .version
+                .clone()
+                .unwrap_or_else(|| "unknown version".to_string());
+            let (detail, color) = match profile.probe_error {
+                Some(_) => ("not probed".to_string(), theme.warning),
+                None => (profile.feature_tags(), theme.muted),
+            };
+            Line::from(vec![
+                Span::styled(format!(" {}: ", agent), Style::default().fg(theme.muted)),
+                Span::styled(version, Style::default().fg(theme.text)),
+                Span::styled(format!(" [{}]", detail), Style::default().fg(color)),
+            ])
+        })
+        .collect()
+}
+
 fn build_cache_stats(session: &Session, theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
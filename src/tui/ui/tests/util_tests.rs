@@ -0,0 +1,117 @@
+use super::*;
+use proptest::prelude::*;
+
+#[test]
+fn test_truncate_to_width_short_text_unchanged() {
+    assert_eq!(truncate_to_width("plan", 10), "plan");
+    assert_eq!(truncate_to_width("exactly-10", 10), "exactly-10");
+    assert_eq!(truncate_to_width("", 0), "");
+}
+
+#[test]
+fn test_truncate_to_width_adds_ellipsis() {
+    assert_eq!(truncate_to_width("feature-name-long", 10), "feature...");
+    assert_eq!(truncate_to_width("abcdef", 3), "...");
+    assert_eq!(truncate_to_width("abcdef", 2), "ab");
+}
+
+#[test]
+fn test_truncate_to_width_multibyte() {
+    // Byte-based slicing would panic inside these characters
+    assert_eq!(truncate_to_width("ñandú-añadido", 8), "ñandú...");
+    // CJK characters are two columns wide
+    assert_eq!(truncate_to_width("計画エージェント", 9), "計画エ...");
+    assert_eq!(truncate_to_width("計画エージェント", 8), "計画...");
+}
+
+#[test]
+fn test_truncate_to_width_keeps_grapheme_clusters() {
+    // "e" + combining acute accent must not be separated
+    let text = "cafe\u{301}-cafe\u{301}-cafe\u{301}";
+    assert_eq!(truncate_to_width(text, 7), "cafe\u{301}...");
+    // Family emoji is a single ZWJ sequence
+    let family = "👨\u{200d}👩\u{200d}👧 family";
+    assert!(truncate_to_width(family, 6).starts_with("👨\u{200d}👩\u{200d}👧"));
+}
+
+#[test]
+fn test_pad_to_width_counts_columns() {
+    assert_eq!(pad_to_width("plan", 6), "plan  ");
+    assert_eq!(pad_to_width("exactly-10", 4), "exactly-10");
+    // A truncated wide-character name fills its column like an ASCII one
+    for name in [
+        "計画エージェントの名前",
+        "🚀🚀🚀🚀🚀🚀🚀🚀",
+        "feature-name-long",
+    ] {
+        let cell = pad_to_width(&truncate_to_width(name, 14), 16);
+        assert_eq!(cell.width(), 16, "{:?}", cell);
+    }
+}
+
+#[test]
+fn test_truncate_start_to_width_keeps_end() {
+    assert_eq!(
+        truncate_start_to_width("/home/user/projects/app", 12),
+        "...jects/app"
+    );
+    assert_eq!(truncate_start_to_width("/tmp", 12), "/tmp");
+    assert_eq!(
+        truncate_start_to_width("/home/ユーザー/app", 9),
+        "...ー/app"
+    );
+}
+
+/// Text fragments that stress width and grapheme handling.
+fn unicode_text() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        "[a-z0-9 _-]{1,4}",
+        Just("ñ".to_string()),
+        Just("e\u{301}".to_string()),
+        Just("計画".to_string()),
+        Just("🚀".to_string()),
+        Just("👨\u{200d}👩\u{200d}👧".to_string()),
+        Just("🇯🇵".to_string()),
+        Just("\u{200b}".to_string()),
+        "\\PC{1,3}",
+    ];
+    prop::collection::vec(fragment, 0..12).prop_map(|parts| parts.concat())
+}
+
+proptest! {
+    #[test]
+    fn prop_truncate_to_width_fits(text in unicode_text(), max_width in 0usize..40) {
+        let truncated = truncate_to_width(&text, max_width);
+        prop_assert!(truncated.width() <= max_width);
+    }
+
+    #[test]
+    fn prop_truncate_to_width_is_grapheme_prefix(text in unicode_text(), max_width in 0usize..40) {
+        let truncated = truncate_to_width(&text, max_width);
+        if text.width() <= max_width {
+            prop_assert_eq!(&truncated, &text);
+        } else {
+            let kept = truncated.strip_suffix(ELLIPSIS).unwrap_or(&truncated);
+            let kept_graphemes: Vec<&str> = kept.graphemes(true).collect();
+            let text_graphemes: Vec<&str> = text.graphemes(true).collect();
+            prop_assert!(text_graphemes.starts_with(&kept_graphemes));
+        }
+    }
+
+    #[test]
+    fn prop_truncate_start_to_width_is_grapheme_suffix(
+        text in unicode_text(),
+        max_width in 0usize..40,
+    ) {
+        let truncated = truncate_start_to_width(&text, max_width);
+        prop_assert!(truncated.width() <= max_width);
+        if text.width() <= max_width {
+            prop_assert_eq!(&truncated, &text);
+        } else {
+            let kept = truncated.strip_prefix(ELLIPSIS).unwrap_or(&truncated);
+            let kept_graphemes: Vec<&str> = kept.graphemes(true).collect();
+            let text_graphemes: Vec<&str> = text.graphemes(true).collect();
+            prop_assert!(text_graphemes.ends_with(&kept_graphemes));
+        }
+    }
+}
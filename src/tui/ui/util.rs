@@ -1,9 +1,83 @@
+use crate::tui::scroll::NavState;
+use crate::tui::FocusedPanel;
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Paragraph, Wrap},
 };
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Marker appended (or prepended) to truncated text.
+pub const ELLIPSIS: &str = "...";
+
+/// Truncates `text` to at most `max_width` terminal columns, ending with `...` when cut.
+///
+/// Cuts only between grapheme clusters, so wide characters, combining marks and
+/// emoji sequences are never split. Widths too small for the ellipsis are cut
+/// without one.
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    let ellipsis = if max_width >= ELLIPSIS.len() {
+        ELLIPSIS
+    } else {
+        ""
+    };
+    let budget = max_width - ellipsis.len();
+
+    let mut result = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let width = grapheme.width();
+        if used + width > budget {
+            break;
+        }
+        result.push_str(grapheme);
+        used += width;
+    }
+    result.push_str(ellipsis);
+    result
+}
+
+/// Pads `text` with spaces to `width` terminal columns.
+///
+/// Unlike `{:<N}`, which counts chars, wide characters count as two columns, so
+/// columns of printed tables stay aligned.
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.width());
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Truncates `text` from the start, keeping its end and prefixing `...` when cut.
+///
+/// Used for paths, where the last components are the most informative.
+pub fn truncate_start_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    let ellipsis = if max_width >= ELLIPSIS.len() {
+        ELLIPSIS
+    } else {
+        ""
+    };
+    let budget = max_width - ellipsis.len();
+
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for grapheme in text.graphemes(true).rev() {
+        let width = grapheme.width();
+        if used + width > budget {
+            break;
+        }
+        kept.push(grapheme);
+        used += width;
+    }
+    kept.push(ellipsis);
+    kept.reverse();
+    kept.concat()
+}
 
 pub fn wrap_text_at_width(text: &str, width: usize) -> String {
     if width == 0 {
@@ -173,6 +247,24 @@ pub fn compute_wrapped_line_count(lines: &[Line], width: u16) -> usize {
     paragraph.line_count(width)
 }
 
+/// Marks the navigation cursor (reversed) and the selected lines (dark background)
+/// when `nav` is on `panel`. Returns the title tag to show for it.
+pub fn highlight_nav_lines(
+    lines: &mut [Line],
+    nav: Option<&NavState>,
+    panel: FocusedPanel,
+) -> Option<String> {
+    let nav = nav.filter(|nav| nav.panel == panel)?;
+    for (index, line) in lines.iter_mut().enumerate() {
+        if index == nav.cursor {
+            line.style = line.style.add_modifier(Modifier::REVERSED);
+        } else if nav.is_selected(index) {
+            line.style = line.style.bg(Color::DarkGray);
+        }
+    }
+    Some(nav.title_tag())
+}
+
 /// Compute the wrapped line count for plain text content.
 ///
 /// Uses a block-less `Paragraph` with wrapping to get accurate line counts
@@ -271,3 +363,7 @@ pub fn compute_plan_modal_inner_size(terminal_width: u16, terminal_height: u16)
 
     (inner_width, inner_height)
 }
+
+#[cfg(test)]
+#[path = "tests/util_tests.rs"]
+mod tests;
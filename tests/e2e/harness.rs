@@ -0,0 +1,294 @@
+//! PTY harness: runs the `planning` binary against a mock agent in a temp dir.
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use serde_json::Value;
+use std::fs;
+use std::io::{Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// How long one workflow step may take before the test fails.
+const STEP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How much of the terminal output failure messages show.
+const SCREEN_TAIL_CHARS: usize = 4000;
+
+const MOCK_CLAUDE: &str = include_str!("mock_claude.sh");
+
+/// Picked up from the working directory by new sessions: one Claude agent plans
+/// and reviews, and implementation is off so approval ends the workflow.
+const WORKFLOW_YAML: &str = r#"name: e2e
+agents:
+  claude:
+    command: "claude"
+    args: ["-p", "--output-format", "stream-json", "--verbose"]
+workflow:
+  planning:
+    agent: claude
+  reviewing:
+    agents: [claude]
+    aggregation: any_rejects
+implementation:
+  enabled: false
+"#;
+
+/// A planning home, working directory and mock agent private to one test.
+pub struct E2eEnv {
+    dir: TempDir,
+}
+
+impl E2eEnv {
+    pub fn new() -> Self {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        for sub in ["home", "repo", "bin", "mock-state"] {
+            fs::create_dir_all(dir.path().join(sub)).expect("create test dirs");
+        }
+        let mock = dir.path().join("bin").join("claude");
+        fs::write(&mock, MOCK_CLAUDE).expect("write mock agent");
+        fs::set_permissions(&mock, fs::Permissions::from_mode(0o755))
+            .expect("make mock agent executable");
+        fs::write(dir.path().join("repo").join("workflow.yaml"), WORKFLOW_YAML)
+            .expect("write workflow");
+        Self { dir }
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.path().join(name)
+    }
+
+    /// Starts `planning` in an 120x40 PTY. `args` follow the flags every test
+    /// shares; `env` adds variables for the mock agent.
+    pub fn spawn(&self, args: &[&str], env: &[(&str, &str)]) -> Tui {
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows: 40,
+                cols: 120,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .expect("open pty");
+
+        let mut cmd = CommandBuilder::new(env!("CARGO_BIN_EXE_planning"));
+        cmd.cwd(self.path("repo"));
+        cmd.args(["--no-daemon", "--working-dir"]);
+        cmd.arg(self.path("repo"));
+        cmd.args(args);
+
+        let path = std::env::var("PATH").unwrap_or_default();
+        cmd.env("PATH", format!("{}:{}", self.path("bin").display(), path));
+        cmd.env("HOME", self.path("home"));
+        cmd.env(
+            "PLANNING_AGENT_HOME",
+            self.path("home").join(".planning-agent"),
+        );
+        cmd.env("MOCK_AGENT_STATE", self.path("mock-state"));
+        cmd.env("TERM", "xterm-256color");
+        for (key, value) in env {
+            cmd.env(key, value);
+        }
+
+        let child = pair.slave.spawn_command(cmd).expect("spawn planning");
+        drop(pair.slave);
+
+        // The TUI blocks once the PTY buffer fills, so output is drained continuously
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let mut reader = pair.master.try_clone_reader().expect("pty reader");
+        let sink = Arc::clone(&output);
+        thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            while let Ok(len) = reader.read(&mut buf) {
+                if len == 0 {
+                    break;
+                }
+                sink.lock().unwrap().extend_from_slice(&buf[..len]);
+            }
+        });
+        let writer = pair.master.take_writer().expect("pty writer");
+
+        Tui {
+            child,
+            writer,
+            output,
+            _master: pair.master,
+        }
+    }
+
+    /// ID of the test's session, once it has been created.
+    pub fn session_id(&self) -> Option<String> {
+        let sessions = self.path("home").join(".planning-agent").join("sessions");
+        fs::read_dir(sessions)
+            .ok()?
+            .flatten()
+            .find(|entry| entry.path().join("events.jsonl").exists())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+    }
+
+    fn events(&self) -> Vec<Value> {
+        let Some(id) = self.session_id() else {
+            return Vec::new();
+        };
+        let log = self
+            .path("home")
+            .join(".planning-agent")
+            .join("sessions")
+            .join(id)
+            .join("events.jsonl");
+        fs::read_to_string(log)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    /// Types of the events recorded so far, in order.
+    pub fn event_types(&self) -> Vec<String> {
+        self.events()
+            .iter()
+            .filter_map(|event| event["event_type"].as_str().map(str::to_string))
+            .collect()
+    }
+
+    pub fn count(&self, event_type: &str) -> usize {
+        self.event_types()
+            .iter()
+            .filter(|t| *t == event_type)
+            .count()
+    }
+
+    /// Contents of the session's plan file.
+    pub fn plan(&self) -> String {
+        let events = self.events();
+        let plan_path = events
+            .iter()
+            .find_map(|event| event["event"]["workflow_created"]["plan_path"].as_str())
+            .expect("workflow created");
+        fs::read_to_string(plan_path).expect("read plan")
+    }
+
+    /// Waits until `event_type` has been recorded `count` times.
+    pub fn wait_for_event(&self, tui: &mut Tui, event_type: &str, count: usize) {
+        let what = format!("{} x{}", event_type, count);
+        self.wait_until(tui, &what, |env, _| env.count(event_type) >= count);
+    }
+
+    /// Presses `a` on the approval prompt until the plan is approved.
+    pub fn approve(&self, tui: &mut Tui) {
+        self.wait_until(tui, "plan approval", |env, tui| {
+            if env.count("UserApproved") > 0 {
+                return true;
+            }
+            tui.press("a");
+            thread::sleep(Duration::from_millis(400));
+            false
+        });
+    }
+
+    fn wait_until(&self, tui: &mut Tui, what: &str, mut done: impl FnMut(&Self, &mut Tui) -> bool) {
+        let deadline = Instant::now() + STEP_TIMEOUT;
+        loop {
+            if done(self, tui) {
+                return;
+            }
+            if !tui.is_running() {
+                panic!(
+                    "planning exited while waiting for {}\nevents: {:?}\n--- terminal ---\n{}",
+                    what,
+                    self.event_types(),
+                    tui.screen_text()
+                );
+            }
+            if Instant::now() > deadline {
+                panic!(
+                    "timed out waiting for {}\nevents: {:?}\n--- terminal ---\n{}",
+                    what,
+                    self.event_types(),
+                    tui.screen_text()
+                );
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+}
+
+/// A running `planning` process attached to a PTY.
+pub struct Tui {
+    child: Box<dyn Child + Send + Sync>,
+    writer: Box<dyn Write + Send>,
+    output: Arc<Mutex<Vec<u8>>>,
+    _master: Box<dyn MasterPty + Send>,
+}
+
+impl Tui {
+    /// Types `keys` into the terminal.
+    pub fn press(&mut self, keys: &str) {
+        self.writer
+            .write_all(keys.as_bytes())
+            .and_then(|_| self.writer.flush())
+            .expect("write to pty");
+    }
+
+    pub fn is_running(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    /// Kills the process without letting it shut down, like a crash.
+    pub fn kill(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+    /// Quits with `q` and waits for the process to exit.
+    pub fn quit(&mut self) {
+        self.press("q");
+        let deadline = Instant::now() + STEP_TIMEOUT;
+        while self.is_running() {
+            if Instant::now() > deadline {
+                panic!(
+                    "planning did not quit\n--- terminal ---\n{}",
+                    self.screen_text()
+                );
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// The end of the terminal output with escape sequences removed.
+    pub fn screen_text(&self) -> String {
+        let raw = self.output.lock().unwrap();
+        let text = strip_escapes(&String::from_utf8_lossy(&raw));
+        let skip = text.chars().count().saturating_sub(SCREEN_TAIL_CHARS);
+        text.chars().skip(skip).collect()
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        if self.is_running() {
+            self.kill();
+        }
+    }
+}
+
+/// Removes CSI and two-byte escape sequences, keeping the text drawn between them.
+fn strip_escapes(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            text.push(c);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    text
+}
@@ -0,0 +1,71 @@
+#!/bin/sh
+# Stand-in for the claude CLI in end-to-end tests.
+#
+# Planning and revising prompts get a plan written to the path they name;
+# review prompts get feedback that requests revision for the first
+# MOCK_REJECTIONS reviews (default 1) and approves after that. Every run ends
+# with the stream-json a real `claude -p --output-format stream-json` prints.
+#
+# MOCK_AGENT_STATE is a directory for the plan and review counters.
+# MOCK_REVISE_DELAY delays revisions, leaving time to kill the TUI mid-phase.
+
+state="${MOCK_AGENT_STATE:?MOCK_AGENT_STATE must be set}"
+
+prompt=""
+for arg in "$@"; do
+    case "$arg" in
+        *"<phase>"* | *"Feedback output:"* | *"revise the plan at "*) prompt="$arg" ;;
+    esac
+done
+
+# Bumps a counter file and prints its new value
+bump() {
+    count=$(cat "$state/$1" 2>/dev/null || echo 0)
+    count=$((count + 1))
+    echo "$count" > "$state/$1"
+    echo "$count"
+}
+
+# Value of <name>...</name> on a single prompt line
+tag() {
+    printf '%s\n' "$prompt" | sed -n "s|.*<$1>\(.*\)</$1>.*|\1|p" | head -n 1
+}
+
+write_plan() {
+    count=$(bump plans)
+    printf '# Plan\n\nRevision %s of the mock plan.\n' "$count" > "$1"
+}
+
+reply="Done."
+case "$prompt" in
+    *"<phase>planning</phase>"*)
+        write_plan "$(tag plan-output-path)"
+        reply="Plan written."
+        ;;
+    *"<phase>revising</phase>"* | *"revise the plan at "*)
+        sleep "${MOCK_REVISE_DELAY:-0}"
+        plan=$(tag plan-output-path)
+        if [ -z "$plan" ]; then
+            plan=$(printf '%s\n' "$prompt" | sed -n 's|.*revise the plan at \(.*\) to address.*|\1|p' | head -n 1)
+        fi
+        write_plan "$plan"
+        reply="Plan revised."
+        ;;
+    *"<phase>"*)
+        ;;
+    *"Feedback output:"*)
+        feedback=$(printf '%s\n' "$prompt" | sed -n 's|^.*Feedback output: ||p' | head -n 1)
+        count=$(bump reviews)
+        if [ "$count" -le "${MOCK_REJECTIONS:-1}" ]; then
+            verdict="NEEDS REVISION"
+        else
+            verdict="APPROVED"
+        fi
+        printf '<plan-feedback>\n## Summary\n\nMock review %s.\n\n## Overall Assessment: %s\n</plan-feedback>\n' \
+            "$count" "$verdict" > "$feedback"
+        reply="Review written."
+        ;;
+esac
+
+printf '{"type":"assistant","message":{"model":"mock","content":[{"type":"text","text":"%s"}]}}\n' "$reply"
+printf '{"type":"result","result":"%s","is_error":false,"total_cost_usd":0}\n' "$reply"
@@ -0,0 +1,77 @@
+//! End-to-end tests that drive the `planning` binary in a pseudo-terminal.
+//!
+//! Each test gets its own planning home and working directory, with a mock
+//! `claude` first on `PATH` (see `e2e/mock_claude.sh`). Progress is asserted on the
+//! session's event log rather than on screen contents, which keeps the tests
+//! independent of layout; the terminal output is printed when a step fails.
+//!
+//! Run with `cargo test --test e2e_tests`.
+
+#![cfg(unix)]
+
+#[path = "e2e/harness.rs"]
+mod harness;
+
+use harness::E2eEnv;
+
+/// Index of the first `event_type` in `events`, failing the test if absent.
+fn position(events: &[String], event_type: &str) -> usize {
+    events
+        .iter()
+        .position(|e| e == event_type)
+        .unwrap_or_else(|| panic!("no {} in {:?}", event_type, events))
+}
+
+#[test]
+fn test_rejection_revision_and_approval() {
+    let env = E2eEnv::new();
+    let mut tui = env.spawn(&["--name", "e2e-flow", "Add a health check endpoint"], &[]);
+
+    env.wait_for_event(&mut tui, "ReviewerRejected", 1);
+    env.wait_for_event(&mut tui, "RevisionCompleted", 1);
+    env.wait_for_event(&mut tui, "ReviewerApproved", 1);
+    env.approve(&mut tui);
+    tui.quit();
+
+    let events = env.event_types();
+    let order = [
+        "PlanningCompleted",
+        "ReviewerRejected",
+        "RevisingStarted",
+        "RevisionCompleted",
+        "ReviewerApproved",
+        "UserApproved",
+    ];
+    for pair in order.windows(2) {
+        assert!(
+            position(&events, pair[0]) < position(&events, pair[1]),
+            "{} should come before {} in {:?}",
+            pair[0],
+            pair[1],
+            events
+        );
+    }
+    assert!(env.plan().contains("Revision 2"), "plan: {}", env.plan());
+}
+
+#[test]
+fn test_resume_after_kill_during_revision() {
+    let env = E2eEnv::new();
+    let mut tui = env.spawn(
+        &["--name", "e2e-resume", "Add a health check endpoint"],
+        &[("MOCK_REVISE_DELAY", "30")],
+    );
+    env.wait_for_event(&mut tui, "RevisingStarted", 1);
+    tui.kill();
+
+    let session_id = env.session_id().expect("session was created");
+    let mut tui = env.spawn(&["--resume-session", &session_id], &[]);
+
+    // The interrupted revision is run again rather than skipped or replanned
+    env.wait_for_event(&mut tui, "RevisionCompleted", 1);
+    env.wait_for_event(&mut tui, "ReviewerApproved", 1);
+    env.approve(&mut tui);
+
+    assert_eq!(env.count("WorkflowCreated"), 1);
+    assert_eq!(env.count("ReviewerRejected"), 1);
+}